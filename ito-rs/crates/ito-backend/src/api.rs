@@ -186,6 +186,12 @@ pub struct ApiTaskDetail {
     /// Last updated date when present.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<String>,
+    /// RFC 3339 timestamp of the first move to in-progress.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    /// RFC 3339 completion timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
     /// Explicit task dependencies.
     pub dependencies: Vec<String>,
     /// Referenced files.
@@ -372,6 +378,8 @@ fn api_task_detail(task: ito_core::TaskItem) -> ApiTaskDetail {
         wave: task.wave,
         status: task.status.as_enhanced_label().to_string(),
         updated_at: task.updated_at,
+        started_at: task.started_at,
+        completed_at: task.completed_at,
         dependencies: task.dependencies,
         files: task.files,
         action: task.action,
@@ -670,6 +678,16 @@ pub async fn unshelve_change_task(
     Ok(Json(api_task_mutation_result(result)))
 }
 
+/// `POST /api/v1/projects/{org}/{repo}/changes/{change_id}/tasks/{task_id}/release` — return an in-progress task to pending.
+pub async fn release_change_task(
+    State(state): State<Arc<AppState>>,
+    Path((org, repo, change_id, task_id)): Path<(String, String, String, String)>,
+) -> Result<Json<ApiTaskMutationResult>, ApiErrorResponse> {
+    let task_mutations = map_domain_err(state.store.task_mutation_service(&org, &repo))?;
+    let result = map_task_mutation_err(task_mutations.release_task(&change_id, &task_id))?;
+    Ok(Json(api_task_mutation_result(result)))
+}
+
 /// `POST /api/v1/projects/{org}/{repo}/changes/{change_id}/tasks/add` — add a task.
 pub async fn add_change_task(
     State(state): State<Arc<AppState>>,
@@ -956,6 +974,10 @@ fn project_router() -> Router<Arc<AppState>> {
             "/changes/{change_id}/tasks/{task_id}/unshelve",
             post(unshelve_change_task),
         )
+        .route(
+            "/changes/{change_id}/tasks/{task_id}/release",
+            post(release_change_task),
+        )
         .route("/changes/{change_id}/tasks/add", post(add_change_task))
         .route("/modules", get(list_modules))
         .route("/modules/{module_id}", get(get_module))
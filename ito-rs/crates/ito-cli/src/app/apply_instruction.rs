@@ -7,7 +7,7 @@ use ito_core::implementation_readiness::{
 };
 use ito_core::templates::{self as core_templates, ApplyInstructionsResponse, TemplatesError};
 
-use crate::cli_error::{CliResult, fail, to_cli_error};
+use crate::cli_error::{CliError, CliResult, fail, to_cli_error};
 use crate::runtime::Runtime;
 
 pub(super) struct PreparedApplySource {
@@ -83,7 +83,10 @@ fn compute_from_authority(
         Ok(response) => response,
         Err(TemplatesError::InvalidChangeName) => return fail("Invalid change name"),
         Err(TemplatesError::ChangeNotFound(name)) => {
-            return fail(format!("Change '{name}' not found"));
+            return Err(CliError::not_found(
+                "CHANGE_NOT_FOUND",
+                format!("Change '{name}' not found"),
+            ));
         }
         Err(TemplatesError::SchemaNotFound(name)) => {
             return fail(super::common::schema_not_found_message(ctx, &name));
@@ -98,13 +101,16 @@ fn overlay_execute_ready_tracking(
     rt: &Runtime,
     apply: &ApplyInstructionsResponse,
 ) -> CliResult<bool> {
-    let Some(tracks_file) = apply.tracks_file.as_deref() else {
+    let tracks_files: Vec<&str> = apply.tracks.iter().map(|t| t.file.as_str()).collect();
+    if tracks_files.is_empty() {
         return Ok(false);
-    };
-    if !safe_tracking_filename(tracks_file) {
-        return fail(format!(
-            "Authority schema selected unsafe apply tracking filename '{tracks_file}'"
-        ));
+    }
+    for tracks_file in &tracks_files {
+        if !safe_tracking_filename(tracks_file) {
+            return fail(format!(
+                "Authority schema selected unsafe apply tracking filename '{tracks_file}'"
+            ));
+        }
     }
 
     let request = ReadinessRequest::new(
@@ -119,6 +125,17 @@ fn overlay_execute_ready_tracking(
         return Ok(false);
     }
 
+    for tracks_file in tracks_files {
+        stage_live_tracking_file(prepared, rt, tracks_file)?;
+    }
+    Ok(true)
+}
+
+fn stage_live_tracking_file(
+    prepared: &PreparedApplySource,
+    rt: &Runtime,
+    tracks_file: &str,
+) -> CliResult<()> {
     let checkout_tracking = rt
         .ito_path()
         .join("changes")
@@ -168,7 +185,7 @@ fn overlay_execute_ready_tracking(
             ));
         }
     }
-    Ok(true)
+    Ok(())
 }
 
 fn safe_tracking_filename(value: &str) -> bool {
@@ -190,6 +207,9 @@ fn make_worktree_relative_paths(
     let rebase = |path: &str| worktree_relative_path(&source_change, &worktree_change, path);
     apply.change_dir = worktree_change.to_string_lossy().to_string();
     apply.tracks_path = apply.tracks_path.as_deref().map(&rebase).transpose()?;
+    for track in &mut apply.tracks {
+        track.path = rebase(&track.path)?;
+    }
     for path in apply.context_files.values_mut() {
         *path = rebase(path)?;
     }
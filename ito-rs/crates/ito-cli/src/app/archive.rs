@@ -4,11 +4,13 @@ use crate::commands::sync::best_effort_sync_coordination;
 use crate::runtime::Runtime;
 #[cfg(any(feature = "backend", feature = "coordination-branch"))]
 use ito_config::load_cascading_project_config;
+use ito_config::output;
 use ito_config::types::ArchiveMainIntegrationMode;
 #[cfg(feature = "coordination-branch")]
 use ito_config::types::CoordinationStorage;
 #[cfg(any(feature = "backend", feature = "coordination-branch"))]
 use ito_config::types::ItoConfig;
+use ito_core::archive::ArchiveOutstanding;
 use ito_core::audit::{Actor, AuditEventBuilder, EntityType, ops};
 #[cfg(feature = "backend")]
 use ito_core::backend_client::{BackendRuntime, resolve_backend_runtime};
@@ -18,7 +20,10 @@ use ito_core::backend_coordination;
 use ito_core::backend_http::BackendHttpClient;
 #[cfg(feature = "coordination-branch")]
 use ito_core::coordination_worktree::{CoordinationSyncOutcome, sync_coordination_worktree};
+use ito_core::errors::CoreError;
 use ito_core::paths as core_paths;
+use ito_core::process::SystemProcessRunner;
+use std::io::IsTerminal;
 
 fn requires_local_changes_dir(mode: ito_core::repository_runtime::PersistenceMode) -> bool {
     mode == ito_core::repository_runtime::PersistenceMode::Filesystem
@@ -63,6 +68,98 @@ fn print_archive_follow_up(mode: ArchiveMainIntegrationMode, change_name: &str)
     }
 }
 
+/// Confirm archiving over outstanding work.
+///
+/// `--yes` confirms up front; otherwise the user is prompted, and running
+/// non-interactively without `--yes` is an error.
+fn confirm_forced_archive(auto_confirm: bool) -> CliResult<bool> {
+    if auto_confirm {
+        return Ok(true);
+    }
+    let ui = output::resolve_ui_options(
+        false,
+        std::env::var("NO_COLOR").ok().as_deref(),
+        false,
+        std::env::var("ITO_INTERACTIVE").ok().as_deref(),
+    );
+    let is_tty = std::io::stdin().is_terminal() && std::io::stdout().is_terminal();
+    if !(ui.interactive && is_tty) {
+        return fail("Non-interactive forced archive requires --yes.");
+    }
+
+    println!("Archive anyway? [y/N]: ");
+    let mut input = String::new();
+    std::io::stdin()
+        .read_line(&mut input)
+        .map_err(|_| CliError::msg("Failed to read input"))?;
+    let input = input.trim().to_lowercase();
+    Ok(input == "y" || input == "yes")
+}
+
+fn print_forced_report(outstanding: &ArchiveOutstanding) {
+    eprintln!("  Forced: archived with outstanding work");
+    for line in outstanding.describe().lines() {
+        eprintln!("  {line}");
+    }
+}
+
+/// Refuse to archive when spec deltas conflict with the current specs.
+///
+/// With `force` the conflicts are reported and the conflicting operations are
+/// skipped when the specs are updated.
+fn check_spec_conflicts(
+    ito_path: &std::path::Path,
+    change_name: &str,
+    spec_names: &[String],
+    force: bool,
+) -> CliResult<()> {
+    let mut lines = Vec::new();
+    for spec in spec_names {
+        let preview = match ito_core::archive::preview_change_spec(ito_path, change_name, spec) {
+            Ok(preview) => preview,
+            Err(CoreError::NotFound(_)) => continue,
+            Err(err) => return Err(to_cli_error(err)),
+        };
+        lines.extend(
+            preview
+                .conflicts
+                .iter()
+                .map(|conflict| format!("  - {spec}: {}", conflict.message)),
+        );
+    }
+    if lines.is_empty() {
+        return Ok(());
+    }
+    let details = lines.join("\n");
+    if !force {
+        return fail(format!(
+            "Refusing to archive '{change_name}': spec deltas conflict with the current specs.\n{details}\nFix the deltas (preview with 'ito show {change_name} --preview-spec <spec>'), or re-run with --force to skip the conflicting operations."
+        ));
+    }
+    eprintln!("Warning: skipping spec delta operations that conflict with the current specs:");
+    eprintln!("{details}");
+    Ok(())
+}
+
+/// Merge the change's spec deltas into the main specs.
+fn update_main_specs(
+    ito_path: &std::path::Path,
+    change_name: &str,
+    spec_names: &[String],
+    force: bool,
+) -> CliResult<Vec<String>> {
+    if force {
+        let (updated, _skipped) = ito_core::archive::copy_specs_to_main_skipping_conflicts(
+            ito_path,
+            change_name,
+            spec_names,
+        )
+        .map_err(to_cli_error)?;
+        return Ok(updated);
+    }
+    ito_core::archive::copy_specs_to_main(ito_path, change_name, spec_names).map_err(to_cli_error)
+}
+
 #[cfg(feature = "coordination-branch")]
 fn sync_archived_coordination_state(
     rt: &Runtime,
@@ -136,6 +233,8 @@ pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
     let skip_validation = args.iter().any(|a| a == "--no-validate");
     let skip_specs = args.iter().any(|a| a == "--skip-specs");
     let auto_confirm = args.iter().any(|a| a == "--yes" || a == "-y");
+    let force = args.iter().any(|a| a == "--force");
+    let exact = args.iter().any(|a| a == "--exact");
 
     // Get change name (first positional arg)
     let change_name = args
@@ -147,10 +246,7 @@ pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
     let runtime = repository_runtime;
     let change_repo = runtime.repositories().changes.as_ref();
     let change_name = if let Some(name) = change_name {
-        match super::common::resolve_change_target(change_repo, name) {
-            Ok(resolved) => resolved,
-            Err(msg) => return fail(msg),
-        }
+        super::change_target::resolve_change_target_with(change_repo, name, exact)?
     } else {
         let available = change_repo.list().unwrap_or_default();
         if available.is_empty() {
@@ -170,43 +266,61 @@ pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
 
     // Verify change exists
     if !change_repo.exists(&change_name) {
-        return fail(format!("Change '{}' not found", change_name));
+        return Err(CliError::not_found(
+            "CHANGE_NOT_FOUND",
+            format!("Change '{}' not found", change_name),
+        ));
     }
 
-    // Check task completion unless skipping validation
+    // Refuse to archive outstanding work unless forced.
+    let mut outstanding = ArchiveOutstanding::default();
     if !skip_validation {
         let task_repo = runtime.repositories().tasks.as_ref();
-        let (completed, total) = task_repo.get_task_counts(&change_name).unwrap_or((0, 0));
-        if total > 0 {
-            if completed < total {
-                let pending = total - completed;
-                println!(
-                    "Warning: Change has {} incomplete tasks out of {}",
-                    pending, total
-                );
-                if !auto_confirm {
-                    println!("Continue with archive anyway? [y/N]: ");
-                    let mut input = String::new();
-                    std::io::stdin()
-                        .read_line(&mut input)
-                        .map_err(|_| CliError::msg("Failed to read input"))?;
-                    let input = input.trim().to_lowercase();
-                    if input != "y" && input != "yes" {
-                        println!("Archive cancelled.");
-                        return Ok(());
-                    }
-                }
-            } else {
-                eprintln!("✔ All tasks complete");
+        outstanding = archive::check_archive_readiness(task_repo, ito_path, &change_name, rt.ctx())
+            .map_err(to_cli_error)?;
+        if outstanding.is_empty() {
+            eprintln!("✔ All tasks and required artifacts complete");
+        } else if !force {
+            return fail(format!(
+                "Refusing to archive '{}': work is still outstanding.\n{}\nFinish the work, or re-run with --force to archive anyway.",
+                change_name,
+                outstanding.describe()
+            ));
+        } else {
+            eprintln!(
+                "Warning: '{}' still has outstanding work.\n{}",
+                change_name,
+                outstanding.describe()
+            );
+            if !confirm_forced_archive(auto_confirm)? {
+                println!("Archive cancelled.");
+                return Ok(());
             }
         }
     }
+    let forced = (!outstanding.is_empty()).then_some(&outstanding);
+
+    let uncommitted =
+        archive::uncommitted_change_paths(&SystemProcessRunner, ito_path, &change_name);
+    if !uncommitted.is_empty() {
+        eprintln!("Warning: '{}' has uncommitted changes:", change_name);
+        for path in &uncommitted {
+            eprintln!("  - {path}");
+        }
+    }
 
     // Check for backend mode — if enabled, run the repository-backed archive flow.
     #[cfg(feature = "backend")]
     {
         if let Some(runtime) = try_backend_runtime(rt)? {
-            return handle_backend_archive(rt, ito_path, &change_name, skip_specs, &runtime);
+            return handle_backend_archive(
+                rt,
+                ito_path,
+                &change_name,
+                skip_specs,
+                forced,
+                &runtime,
+            );
         }
     }
 
@@ -228,6 +342,7 @@ pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
             archive::discover_change_specs(ito_path, &change_name).map_err(to_cli_error)?;
 
         if !spec_names.is_empty() {
+            check_spec_conflicts(ito_path, &change_name, &spec_names, force)?;
             let (new_specs, existing_specs) = archive::categorize_specs(ito_path, &spec_names);
 
             // Show confirmation
@@ -267,15 +382,12 @@ pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
                     } else {
                         // Copy specs to main
                         specs_updated =
-                            archive::copy_specs_to_main(ito_path, &change_name, &spec_names)
-                                .map_err(to_cli_error)?;
+                            update_main_specs(ito_path, &change_name, &spec_names, force)?;
                         eprintln!("✔ Updated {} specs", specs_updated.len());
                     }
                 } else {
                     // Copy specs to main
-                    specs_updated =
-                        archive::copy_specs_to_main(ito_path, &change_name, &spec_names)
-                            .map_err(to_cli_error)?;
+                    specs_updated = update_main_specs(ito_path, &change_name, &spec_names, force)?;
                     eprintln!("✔ Updated {} specs", specs_updated.len());
                 }
             }
@@ -305,6 +417,11 @@ pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
         .by(rt.user_identity())
         .meta(serde_json::json!({
             "archive_name": archive_name,
+            "destination": archive::archive_destination(ito_path, &archive_name).display().to_string(),
+            "forced": forced.is_some(),
+            "outstanding_tasks": forced.map_or(0, |o| o.tasks.len()),
+            "outstanding_artifacts": forced.map_or(0, |o| o.artifacts.len()),
+            "outstanding": forced,
         }))
         .ctx(rt.event_context().clone())
         .build()
@@ -339,6 +456,9 @@ pub(crate) fn handle_archive(rt: &Runtime, args: &[String]) -> CliResult<()> {
     if !specs_updated.is_empty() {
         eprintln!("  Updated specs: {}", specs_updated.join(", "));
     }
+    if let Some(outstanding) = forced {
+        print_forced_report(outstanding);
+    }
 
     if let Some(mode) = sync_archived_coordination_state(rt, &change_name)? {
         print_archive_follow_up(mode, &change_name);
@@ -374,7 +494,7 @@ pub(crate) fn handle_archive_clap(rt: &Runtime, args: &ArchiveArgs) -> CliResult
 /// Build the argv vector for a single-change archive invocation.
 ///
 /// Combines an optional change id with the shared flags (`-y`, `--skip-specs`,
-/// `--no-validate`) from `ArchiveArgs`.
+/// `--no-validate`, `--force`, `--exact`) from `ArchiveArgs`.
 fn build_single_archive_argv(change_id: Option<&str>, args: &ArchiveArgs) -> Vec<String> {
     let mut argv: Vec<String> = Vec::new();
     if let Some(id) = change_id {
@@ -389,6 +509,12 @@ fn build_single_archive_argv(change_id: Option<&str>, args: &ArchiveArgs) -> Vec
     if args.no_validate {
         argv.push("--no-validate".to_string());
     }
+    if args.force {
+        argv.push("--force".to_string());
+    }
+    if args.exact {
+        argv.push("--exact".to_string());
+    }
     argv
 }
 
@@ -419,6 +545,7 @@ fn handle_backend_archive(
     ito_path: &std::path::Path,
     change_name: &str,
     skip_specs: bool,
+    forced: Option<&ArchiveOutstanding>,
     runtime: &BackendRuntime,
 ) -> CliResult<()> {
     eprintln!("Backend mode enabled — syncing from backend before archiving...");
@@ -458,7 +585,12 @@ fn handle_backend_archive(
         .by(rt.user_identity())
         .meta(serde_json::json!({
             "archive_name": outcome.archive_name,
+            "destination": ito_core::archive::archive_destination(ito_path, &outcome.archive_name).display().to_string(),
             "backend_archived_at": outcome.backend_result.archived_at,
+            "forced": forced.is_some(),
+            "outstanding_tasks": forced.map_or(0, |o| o.tasks.len()),
+            "outstanding_artifacts": forced.map_or(0, |o| o.artifacts.len()),
+            "outstanding": forced,
         }))
         .ctx(rt.event_context().clone())
         .build()
@@ -490,6 +622,9 @@ fn handle_backend_archive(
     if !outcome.specs_updated.is_empty() {
         eprintln!("  Updated specs: {}", outcome.specs_updated.join(", "));
     }
+    if let Some(outstanding) = forced {
+        print_forced_report(outstanding);
+    }
 
     if let Some(mode) = sync_archived_coordination_state(rt, change_name)? {
         print_archive_follow_up(mode, change_name);
@@ -2,16 +2,90 @@ use ito_core::implementation_readiness::{
     AuthoritativeChangeSource, ReadinessCondition, ReadinessPhase, ReadinessReport,
     ReadinessRequest, evaluate_readiness, materialize_authoritative_change, render_readiness_text,
 };
+use ito_core::templates::{self as core_templates, SchemaRebaselineResult, TemplatesError};
 use std::path::Path;
 
-use crate::cli::{ChangeArgs, ChangeCommand, ChangePreflightArgs, ReadinessPhaseArg};
-use crate::cli_error::{CliResult, silent_fail, to_cli_error};
+use crate::cli::{
+    ChangeArgs, ChangeCommand, ChangePreflightArgs, ChangeRebaselineArgs, ReadinessPhaseArg,
+};
+use crate::cli_error::{CliError, CliResult, fail, silent_fail, to_cli_error};
 use crate::runtime::Runtime;
 
 pub(crate) fn handle_change_clap(rt: &Runtime, args: &ChangeArgs) -> CliResult<()> {
     match &args.command {
         ChangeCommand::Preflight(args) => handle_preflight(rt, args),
+        ChangeCommand::Rebaseline(args) => handle_rebaseline(rt, args),
+        ChangeCommand::Rename(args) => super::change_rename::handle_rename(rt, args),
+        ChangeCommand::Split(args) => super::change_split::handle_split(rt, args),
+        ChangeCommand::Sync(args) => super::change_sync::handle_sync(rt, args),
+    }
+}
+
+fn handle_rebaseline(rt: &Runtime, args: &ChangeRebaselineArgs) -> CliResult<()> {
+    let ito_path = rt.ito_path();
+    let mut ctx = rt.ctx().clone();
+    if ctx.project_dir.is_none() {
+        ctx.project_dir = ito_path.parent().map(Path::to_path_buf);
+    }
+
+    let result = if args.pin {
+        core_templates::pin_change_schema(ito_path, &args.change_id, &ctx)
+    } else {
+        core_templates::rebaseline_change_schema(ito_path, &args.change_id, &ctx)
+    };
+    let result = match result {
+        Ok(result) => result,
+        Err(TemplatesError::InvalidChangeName) => return fail("Invalid change name"),
+        Err(TemplatesError::ChangeNotFound(name)) => {
+            return Err(CliError::not_found(
+                "CHANGE_NOT_FOUND",
+                format!("Change '{name}' not found"),
+            ));
+        }
+        Err(TemplatesError::SchemaNotFound(name)) => {
+            return fail(super::common::schema_not_found_message(&ctx, &name));
+        }
+        Err(TemplatesError::SchemaBaselineMissing(name)) => {
+            return fail(format!(
+                "Change '{name}' has no stored schema copy to pin. Run `ito change rebaseline {name}` to record one."
+            ));
+        }
+        Err(error) => return Err(to_cli_error(error)),
+    };
+
+    if args.json {
+        let rendered = serde_json::to_string_pretty(&result).map_err(to_cli_error)?;
+        println!("{rendered}");
+        return Ok(());
+    }
+    print!("{}", render_rebaseline_text(&result));
+    Ok(())
+}
+
+fn render_rebaseline_text(result: &SchemaRebaselineResult) -> String {
+    let short_hash = result.schema_hash.get(..12).unwrap_or(&result.schema_hash);
+    let mut out = if result.pinned {
+        format!(
+            "Pinned {} to its stored copy of schema '{}' ({short_hash}).\n",
+            result.change_name, result.schema_name
+        )
+    } else {
+        format!(
+            "Rebaselined {} on schema '{}' ({short_hash}).\n",
+            result.change_name, result.schema_name
+        )
+    };
+    if result.status_changes.is_empty() {
+        out.push_str("No artifact status changes.\n");
+        return out;
+    }
+    out.push_str("Artifact status changes:\n");
+    for change in &result.status_changes {
+        let before = change.before.as_deref().unwrap_or("(not in schema)");
+        let after = change.after.as_deref().unwrap_or("(not in schema)");
+        out.push_str(&format!("  - {}: {before} -> {after}\n", change.id));
     }
+    out
 }
 
 fn handle_preflight(rt: &Runtime, args: &ChangePreflightArgs) -> CliResult<()> {
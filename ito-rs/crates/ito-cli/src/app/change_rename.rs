@@ -0,0 +1,109 @@
+use std::path::Path;
+
+use ito_core::audit::{Actor, AuditEventBuilder, EntityType, ops};
+use ito_core::change_rename::{ChangeRenamePlan, apply_change_rename, plan_change_rename};
+use ito_core::process::SystemProcessRunner;
+
+use crate::cli::ChangeRenameArgs;
+use crate::cli_error::{CliResult, to_cli_error};
+use crate::runtime::Runtime;
+
+pub(super) fn handle_rename(rt: &Runtime, args: &ChangeRenameArgs) -> CliResult<()> {
+    let ito_path = rt.ito_path();
+    let runner = SystemProcessRunner;
+    let plan = plan_change_rename(ito_path, &runner, &args.old_id, &args.new_name)
+        .map_err(to_cli_error)?;
+    let root = ito_path.parent().unwrap_or(ito_path);
+
+    if args.dry_run {
+        print!("{}", render_plan(&plan, root, args.rename_branch, true));
+        return Ok(());
+    }
+
+    apply_change_rename(&plan, &runner, root, args.rename_branch).map_err(to_cli_error)?;
+
+    if let Some(event) = AuditEventBuilder::new()
+        .entity(EntityType::Change)
+        .entity_id(&plan.old_id)
+        .op(ops::CHANGE_RENAME)
+        .from(&plan.old_id)
+        .to(&plan.new_id)
+        .actor(Actor::Cli)
+        .by(rt.user_identity())
+        .meta(serde_json::json!({
+            "branches_renamed": args.rename_branch,
+            "branches": plan.branches.iter().map(|b| &b.from).collect::<Vec<_>>(),
+        }))
+        .ctx(rt.event_context().clone())
+        .build()
+    {
+        rt.emit_audit_event(&event);
+    }
+
+    print!("{}", render_plan(&plan, root, args.rename_branch, false));
+    Ok(())
+}
+
+fn render_plan(plan: &ChangeRenamePlan, root: &Path, rename_branch: bool, dry_run: bool) -> String {
+    let display = |path: &Path| {
+        path.strip_prefix(root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+    let (moved, edited, renamed) = if dry_run {
+        ("Would move", "Would update", "Would rename branch")
+    } else {
+        ("Moved", "Updated", "Renamed branch")
+    };
+
+    let mut out = if dry_run {
+        format!(
+            "Dry run: renaming change '{}' to '{}' would make these changes:\n",
+            plan.old_id, plan.new_id
+        )
+    } else {
+        format!("✔ Renamed change '{}' to '{}'\n", plan.old_id, plan.new_id)
+    };
+    for mv in &plan.moves {
+        out.push_str(&format!(
+            "  {moved} {} -> {}\n",
+            display(&mv.from),
+            display(&mv.to)
+        ));
+    }
+    for edit in &plan.edits {
+        let noun = if edit.occurrences == 1 {
+            "reference"
+        } else {
+            "references"
+        };
+        out.push_str(&format!(
+            "  {edited} {} ({} {noun})\n",
+            display(&edit.path),
+            edit.occurrences
+        ));
+    }
+    if rename_branch {
+        for branch in &plan.branches {
+            out.push_str(&format!("  {renamed} {} -> {}\n", branch.from, branch.to));
+        }
+    } else if !plan.branches.is_empty() {
+        out.push_str(
+            "Branches named after the change were left unchanged (use --rename-branch):\n",
+        );
+        for branch in &plan.branches {
+            out.push_str(&format!("  - {}\n", branch.from));
+        }
+    }
+    if !plan.worktrees.is_empty() {
+        out.push_str(&format!(
+            "Worktrees for '{}' were not moved; use `git worktree move` if needed:\n",
+            plan.old_id
+        ));
+        for path in &plan.worktrees {
+            out.push_str(&format!("  - {}\n", path.display()));
+        }
+    }
+    out
+}
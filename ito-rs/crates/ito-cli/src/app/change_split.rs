@@ -0,0 +1,112 @@
+use ito_core::audit::{Actor, AuditEventBuilder, EntityType, ops};
+use ito_core::change_split::{ChangeSplitRequest, ChangeSplitResult, split_change};
+
+use crate::cli::ChangeSplitArgs;
+use crate::cli_error::{CliResult, to_cli_error};
+use crate::runtime::Runtime;
+
+pub(super) fn handle_split(rt: &Runtime, args: &ChangeSplitArgs) -> CliResult<()> {
+    let request = ChangeSplitRequest::new(&args.change_id, args.tasks.clone(), &args.name)
+        .with_dependents(args.with_dependents)
+        .renumber(args.renumber);
+    let result = split_change(rt.ito_path(), &request).map_err(to_cli_error)?;
+
+    let renumbered: serde_json::Map<String, serde_json::Value> = result
+        .renumbered
+        .iter()
+        .map(|r| (r.from.clone(), serde_json::Value::from(r.to.clone())))
+        .collect();
+    if let Some(event) = AuditEventBuilder::new()
+        .entity(EntityType::Change)
+        .entity_id(&result.old_id)
+        .op(ops::CHANGE_SPLIT)
+        .from(&result.old_id)
+        .to(&result.new_id)
+        .actor(Actor::Cli)
+        .by(rt.user_identity())
+        .meta(serde_json::json!({
+            "tasks": result.moved_tasks,
+            "dependents": result.dependents,
+            "renumbered": renumbered,
+            "moved_specs": result.moved_specs,
+            "copied_specs": result.copied_specs,
+        }))
+        .ctx(rt.event_context().clone())
+        .build()
+    {
+        rt.emit_audit_event(&event);
+    }
+    if let Some(event) = AuditEventBuilder::new()
+        .entity(EntityType::Change)
+        .entity_id(&result.new_id)
+        .op(ops::CHANGE_CREATE)
+        .actor(Actor::Cli)
+        .by(rt.user_identity())
+        .meta(serde_json::json!({
+            "split_from": result.old_id,
+            "tasks": result.moved_tasks,
+        }))
+        .ctx(rt.event_context().clone())
+        .build()
+    {
+        rt.emit_audit_event(&event);
+    }
+
+    print!("{}", render_result(&result));
+    Ok(())
+}
+
+fn render_result(result: &ChangeSplitResult) -> String {
+    let noun = if result.moved_tasks.len() == 1 {
+        "task"
+    } else {
+        "tasks"
+    };
+    let mut out = format!(
+        "✔ Split {} {noun} from '{}' into '{}'\n",
+        result.moved_tasks.len(),
+        result.old_id,
+        result.new_id
+    );
+    out.push_str(&format!(
+        "  Moved tasks: {}\n",
+        result.moved_tasks.join(", ")
+    ));
+    if !result.dependents.is_empty() {
+        out.push_str(&format!(
+            "  Including dependents: {}\n",
+            result.dependents.join(", ")
+        ));
+    }
+    for renumber in &result.renumbered {
+        out.push_str(&format!(
+            "  Renumbered task {} -> {}\n",
+            renumber.from, renumber.to
+        ));
+    }
+    if !result.moved_specs.is_empty() {
+        out.push_str(&format!(
+            "  Moved delta specs: {}\n",
+            result.moved_specs.join(", ")
+        ));
+    }
+    if !result.copied_specs.is_empty() {
+        out.push_str("Delta specs copied to both changes; review them by hand:\n");
+        for spec in &result.copied_specs {
+            out.push_str(&format!("  - {spec}\n"));
+        }
+    }
+    if !result.detached_dependencies.is_empty() {
+        out.push_str(&format!(
+            "Dependencies on tasks left in '{}' were dropped:\n",
+            result.old_id
+        ));
+        for dep in &result.detached_dependencies {
+            out.push_str(&format!(
+                "  - {} depended on {}\n",
+                dep.task, dep.depends_on
+            ));
+        }
+    }
+    out
+}
@@ -0,0 +1,99 @@
+use crate::cli::ChangeSyncArgs;
+use crate::cli_error::{CliError, CliResult};
+#[cfg(feature = "coordination-branch")]
+use crate::cli_error::{silent_fail, to_cli_error};
+use crate::runtime::Runtime;
+#[cfg(feature = "coordination-branch")]
+use crate::util::render_json;
+#[cfg(feature = "coordination-branch")]
+use ito_core::change_sync::{ChangeSyncOptions, ChangeSyncReport, sync_changes};
+
+/// Publish and pull change metadata through the coordination branch.
+///
+/// Exits non-zero when some files changed on both sides and could not be
+/// merged; everything else is still synced.
+#[cfg(feature = "coordination-branch")]
+pub(super) fn handle_sync(rt: &Runtime, args: &ChangeSyncArgs) -> CliResult<()> {
+    let config = rt.typed_config().map_err(to_cli_error)?;
+    let coordination = &config.changes.coordination_branch;
+    if !coordination.enabled.0 {
+        return Err(CliError::msg(
+            "ito change sync requires changes.coordination_branch.enabled = true",
+        ));
+    }
+
+    let ito_path = rt.ito_path();
+    let project_root = ito_path.parent().unwrap_or(ito_path);
+    let options = ChangeSyncOptions {
+        branch: coordination.name.clone(),
+        include_artifacts: coordination.sync_artifacts,
+    };
+    let report = sync_changes(project_root, ito_path, &options).map_err(to_cli_error)?;
+
+    if args.json {
+        let rendered =
+            render_json(&report).map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
+        println!("{rendered}");
+    } else {
+        print!("{}", render_report(&report));
+    }
+    if report.conflicts.is_empty() {
+        Ok(())
+    } else {
+        silent_fail()
+    }
+}
+
+#[cfg(not(feature = "coordination-branch"))]
+pub(super) fn handle_sync(_rt: &Runtime, args: &ChangeSyncArgs) -> CliResult<()> {
+    let error = CliError::feature_unavailable(
+        "coordination-branch",
+        "ito change sync",
+        "install an experimental build with the coordination-branch feature",
+    );
+    if args.json {
+        let value = error
+            .feature_unavailable_json()
+            .expect("feature-unavailable errors have JSON details");
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&value).expect("JSON value serializes")
+        );
+        return Err(CliError::silent());
+    }
+    Err(error)
+}
+
+#[cfg(feature = "coordination-branch")]
+fn render_report(report: &ChangeSyncReport) -> String {
+    let mut out = String::new();
+    if report.published.is_empty() && report.pulled.is_empty() && report.conflicts.is_empty() {
+        out.push_str(&format!(
+            "Change metadata already in sync with '{}'.\n",
+            report.branch
+        ));
+        return out;
+    }
+    if !report.published.is_empty() {
+        out.push_str(&format!("Published to '{}':\n", report.branch));
+        for file in &report.published {
+            out.push_str(&format!("  - {file}\n"));
+        }
+    }
+    if !report.pulled.is_empty() {
+        out.push_str(&format!("Updated from '{}':\n", report.branch));
+        for file in &report.pulled {
+            out.push_str(&format!("  - {file}\n"));
+        }
+    }
+    if !report.conflicts.is_empty() {
+        out.push_str("Conflicts (left unchanged on both sides):\n");
+        for conflict in &report.conflicts {
+            out.push_str(&format!(
+                "  - {}/{}: {}\n",
+                conflict.change_id, conflict.file, conflict.reason
+            ));
+        }
+    }
+    out
+}
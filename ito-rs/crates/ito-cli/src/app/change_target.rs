@@ -0,0 +1,118 @@
+//! Change target resolution shared by every command that takes a change id.
+//!
+//! Prefix matching, `--exact`, and the ambiguity listing all go through here
+//! so each command reports the same candidates and error codes.
+
+use std::borrow::Cow;
+
+use crate::cli_error::{CliError, CliResult, to_cli_error};
+use crate::runtime::Runtime;
+use ito_core::{ChangeRepository, ChangeTargetMatch, ChangeTargetResolution};
+
+/// Resolve `input` to a canonical change ID.
+///
+/// Missing changes fail with `CHANGE_NOT_FOUND` (exit 3) and ambiguous
+/// prefixes with `AMBIGUOUS_TARGET` (exit 2).
+pub(crate) fn resolve_change_target(
+    change_repo: &(impl ChangeRepository + ?Sized),
+    input: &str,
+) -> CliResult<String> {
+    resolve_change_target_with(change_repo, input, false)
+}
+
+/// Resolve `input` to a canonical change ID, accepting only an exact id when
+/// `exact` is set (`--exact`).
+pub(crate) fn resolve_change_target_with(
+    change_repo: &(impl ChangeRepository + ?Sized),
+    input: &str,
+    exact: bool,
+) -> CliResult<String> {
+    let resolution = if exact {
+        change_repo.resolve_target_exact(input)
+    } else {
+        change_repo.resolve_target(input)
+    };
+    match resolution {
+        ChangeTargetResolution::Unique(id) => Ok(id),
+        ChangeTargetResolution::Ambiguous(matches) => Err(ambiguous_change_error(input, &matches)),
+        ChangeTargetResolution::NotFound => {
+            let mut msg = format!("Change '{input}' not found");
+            let suggestions = change_repo.suggest_targets(input, 5);
+            if !suggestions.is_empty() {
+                msg.push_str("\n\nDid you mean:\n");
+                for suggestion in suggestions {
+                    msg.push_str(&format!("  {}\n", suggestion));
+                }
+            } else {
+                let changes = change_repo.list().unwrap_or_default();
+                if !changes.is_empty() {
+                    msg.push_str("\n\nAvailable changes:\n");
+                    for c in changes {
+                        msg.push_str(&format!("  {}\n", c.id));
+                    }
+                }
+            }
+            Err(CliError::not_found("CHANGE_NOT_FOUND", msg))
+        }
+    }
+}
+
+/// Usage error (`AMBIGUOUS_TARGET`, exit 2) listing the candidates of an
+/// ambiguous change target.
+pub(crate) fn ambiguous_change_error(input: &str, matches: &[ChangeTargetMatch]) -> CliError {
+    CliError::usage(format_ambiguous_change(input, matches)).with_error_code("AMBIGUOUS_TARGET")
+}
+
+/// Render the candidates of an ambiguous change target.
+///
+/// Every command reports ambiguity with this listing: one aligned
+/// `id  title  [status]` row per match (at most eight), then a hint.
+pub(crate) fn format_ambiguous_change(input: &str, matches: &[ChangeTargetMatch]) -> String {
+    const MAX_LISTED: usize = 8;
+
+    let shown = &matches[..matches.len().min(MAX_LISTED)];
+    let id_width = shown.iter().map(|m| m.id.len()).max().unwrap_or(0);
+    let title_width = shown.iter().map(|m| m.title.len()).max().unwrap_or(0);
+
+    let mut msg = format!("Change '{input}' is ambiguous. Matches:\n");
+    for m in shown {
+        let status = m
+            .work_status
+            .map(|status| status.to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        let row = format!("  {:id_width$}  {:title_width$}  [{status}]", m.id, m.title);
+        msg.push_str(row.trim_end());
+        msg.push('\n');
+    }
+    if matches.len() > MAX_LISTED {
+        msg.push_str(&format!("  ... and {} more\n", matches.len() - MAX_LISTED));
+    }
+    msg.push_str("Use a longer prefix or the full canonical change ID.");
+    msg
+}
+
+/// With `--exact`, replace the `--change` value with the exact change id it
+/// names.
+///
+/// The value must then be a full change id; a prefix that would otherwise
+/// resolve is reported as not found. Without `--exact`, `args` is returned
+/// unchanged.
+pub(crate) fn pin_exact_change<'a>(
+    rt: &Runtime,
+    args: &'a [String],
+) -> CliResult<Cow<'a, [String]>> {
+    if !args.iter().any(|a| a == "--exact") {
+        return Ok(Cow::Borrowed(args));
+    }
+    let mut args = args.to_vec();
+    let Some(idx) = args.iter().position(|a| a == "--change") else {
+        return Ok(Cow::Owned(args));
+    };
+    let Some(input) = args.get(idx + 1).filter(|v| !v.starts_with('-')).cloned() else {
+        return Ok(Cow::Owned(args));
+    };
+    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+    args[idx + 1] =
+        resolve_change_target_with(runtime.repositories().changes.as_ref(), &input, true)?;
+    Ok(Cow::Owned(args))
+}
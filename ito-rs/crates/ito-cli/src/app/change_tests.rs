@@ -3,7 +3,9 @@ use ito_core::implementation_readiness::{
     AuthorityEvidence, ReadinessCondition, ReadinessPhase, ReadinessReport,
 };
 
-use super::render_readiness_text;
+use ito_core::templates::{ArtifactStatusChange, SchemaRebaselineResult};
+
+use super::{render_readiness_text, render_rebaseline_text};
 
 #[test]
 fn text_report_preserves_unresolved_authority_and_remediation() {
@@ -34,3 +36,33 @@ fn text_report_preserves_unresolved_authority_and_remediation() {
     assert!(rendered.contains("[FAIL] authority_ref"));
     assert!(rendered.contains("Fix: Configure the target upstream and retry."));
 }
+
+#[test]
+fn rebaseline_text_lists_artifact_status_changes() {
+    let result = SchemaRebaselineResult {
+        change_name: "001-01_demo".to_string(),
+        schema_name: "spec-driven".to_string(),
+        previous_hash: Some("0".repeat(64)),
+        schema_hash: "abcdef0123456789".to_string(),
+        pinned: false,
+        status_changes: vec![
+            ArtifactStatusChange {
+                id: "notes".to_string(),
+                before: Some("done".to_string()),
+                after: None,
+            },
+            ArtifactStatusChange {
+                id: "design".to_string(),
+                before: None,
+                after: Some("ready".to_string()),
+            },
+        ],
+    };
+
+    let rendered = render_rebaseline_text(&result);
+    assert!(
+        rendered.starts_with("Rebaselined 001-01_demo on schema 'spec-driven' (abcdef012345).")
+    );
+    assert!(rendered.contains("  - notes: done -> (not in schema)\n"));
+    assert!(rendered.contains("  - design: (not in schema) -> ready\n"));
+}
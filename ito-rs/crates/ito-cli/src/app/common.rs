@@ -1,8 +1,11 @@
 use crate::cli::Cli;
+use crate::cli_error::{CliError, CliResult, to_cli_error};
 use crate::runtime::Runtime;
 use clap::ColorChoice;
 use clap::CommandFactory;
 use ito_config::ConfigContext;
+use ito_core::project_layout::{LayoutWarning, check_project_layout};
+use ito_core::repository_runtime::PersistenceMode;
 use ito_core::templates as core_templates;
 use ito_core::{ChangeRepository, ChangeTargetResolution, SpecRepository};
 
@@ -11,10 +14,34 @@ pub(crate) fn schema_not_found_message(ctx: &ConfigContext, name: &str) -> Strin
     let mut msg = format!("Schema '{name}' not found");
     if !schemas.is_empty() {
         msg.push_str(&format!(". Available schemas:\n  {}", schemas.join("\n  ")));
+        let suggestions = ito_core::nearest_matches(name, &schemas, 3);
+        msg.push_str(&format!("\nDid you mean: {}?", suggestions.join(", ")));
     }
     msg
 }
 
+/// Check the Ito directory before a filesystem-backed read command.
+///
+/// A missing or unreadable Ito directory becomes an error with a help hint.
+/// Problems with individual `subdirs` are returned so the caller can report
+/// them and continue with what exists. Remote persistence skips the check.
+pub(crate) fn check_layout(rt: &Runtime, subdirs: &[&str]) -> CliResult<Vec<LayoutWarning>> {
+    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+    if runtime.mode() != PersistenceMode::Filesystem {
+        return Ok(Vec::new());
+    }
+    check_project_layout(rt.ito_path(), subdirs)
+        .map_err(|e| CliError::with_help(e.to_string(), e.help()))
+}
+
+/// Print layout warnings to stderr, each with its help hint.
+pub(crate) fn print_layout_warnings(warnings: &[LayoutWarning]) {
+    for warning in warnings {
+        eprintln!("warning: {}", warning.message);
+        eprintln!("  help: {}", warning.help);
+    }
+}
+
 pub(crate) fn render_command_long_help(path: &[&str], bin_name: &str) -> String {
     let mut cmd = Cli::command();
     cmd = cmd.color(ColorChoice::Never);
@@ -53,11 +80,11 @@ pub(crate) fn detect_item_type(
     spec_repo: &(impl SpecRepository + ?Sized),
     item: &str,
 ) -> String {
+    // Ambiguous change targets count as changes so callers report the
+    // candidate listing instead of an unknown item.
     let is_change = match change_repo.resolve_target(item) {
-        ChangeTargetResolution::Unique(_) => true,
-        ChangeTargetResolution::Ambiguous(_) | ChangeTargetResolution::NotFound => {
-            change_repo.exists(item)
-        }
+        ChangeTargetResolution::Unique(_) | ChangeTargetResolution::Ambiguous(_) => true,
+        ChangeTargetResolution::NotFound => change_repo.exists(item),
     };
     let is_spec = spec_repo.get(item).is_ok();
     match (is_change, is_spec) {
@@ -87,45 +114,6 @@ pub(crate) fn list_change_ids(change_repo: &(impl ChangeRepository + ?Sized)) ->
         .unwrap_or_default()
 }
 
-pub(crate) fn resolve_change_target(
-    change_repo: &(impl ChangeRepository + ?Sized),
-    input: &str,
-) -> Result<String, String> {
-    match change_repo.resolve_target(input) {
-        ChangeTargetResolution::Unique(id) => Ok(id),
-        ChangeTargetResolution::Ambiguous(matches) => {
-            let mut msg = format!("Change '{input}' is ambiguous. Matches:\n");
-            for id in matches.iter().take(8) {
-                msg.push_str(&format!("  {id}\n"));
-            }
-            if matches.len() > 8 {
-                msg.push_str(&format!("  ... and {} more\n", matches.len() - 8));
-            }
-            msg.push_str("Use a longer prefix or the full canonical change ID.");
-            Err(msg)
-        }
-        ChangeTargetResolution::NotFound => {
-            let mut msg = format!("Change '{input}' not found");
-            let suggestions = change_repo.suggest_targets(input, 5);
-            if !suggestions.is_empty() {
-                msg.push_str("\n\nDid you mean:\n");
-                for suggestion in suggestions {
-                    msg.push_str(&format!("  {}\n", suggestion));
-                }
-            } else {
-                let changes = change_repo.list().unwrap_or_default();
-                if !changes.is_empty() {
-                    msg.push_str("\n\nAvailable changes:\n");
-                    for c in changes {
-                        msg.push_str(&format!("  {}\n", c.id));
-                    }
-                }
-            }
-            Err(msg)
-        }
-    }
-}
-
 pub(crate) fn list_candidate_items(
     change_repo: &(impl ChangeRepository + ?Sized),
     rt: &Runtime,
@@ -149,7 +137,10 @@ pub(crate) fn last_positional(args: &[String]) -> Option<String> {
             || a == "--concurrency"
             || a == "--requirement"
             || a == "--tools"
+            || a == "--channel"
             || a == "--schema"
+            || a == "--migrate-from"
+            || a == "--preview-spec"
             || a == "-r"
         {
             skip_next = true;
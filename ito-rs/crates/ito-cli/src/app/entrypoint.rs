@@ -10,6 +10,14 @@ pub(crate) fn main() {
     let args: Vec<String> = std::env::args().skip(1).collect();
 
     if let Err(e) = super::run::run(&args) {
+        // Exit codes and JSON error codes follow the contract documented in
+        // `ito help exit-codes`. `--json` callers get the error on stdout too.
+        if !e.is_silent() && args.iter().any(|arg| arg == "--json") {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&e.to_json()).expect("JSON value serializes")
+            );
+        }
         if !e.is_silent() {
             eprintln!();
             eprintln!("✖ Error: {e}");
@@ -20,7 +20,10 @@ use ito_core::config as core_config;
 use ito_core::coordination_worktree::provision_coordination_worktree;
 #[cfg(feature = "coordination-branch")]
 use ito_core::git::{CoordinationBranchSetupStatus, ensure_coordination_branch_on_origin};
-use ito_core::installers::{InitOptions, InstallMode, install_default_templates};
+use ito_core::installers::{
+    DetectedTool, InitOptions, InstallMode, detect_installed_tools, install_default_templates,
+};
+use ito_core::process::SystemProcessRunner;
 use ito_templates::project_templates::WorktreeTemplateContext;
 use std::collections::BTreeSet;
 use std::io::IsTerminal;
@@ -67,43 +70,28 @@ pub(super) fn handle_init(rt: &Runtime, args: &[String]) -> CliResult<()> {
         ));
     }
     let tools_arg = parse_string_flag(args, "--tools");
+    let migrate_from = parse_string_flag(args, "--migrate-from");
+    let move_legacy = args.iter().any(|a| a == "--move");
+    if move_legacy && migrate_from.is_none() {
+        return fail("--move requires --migrate-from");
+    }
     let worktree_overrides = parse_worktree_overrides(args)?;
     if cleanup && !upgrade {
         return fail("--cleanup requires --upgrade");
     }
 
-    // Positional path (defaults to current directory).
-    let target = super::common::last_positional(args).unwrap_or_else(|| ".".to_string());
-    let target_path = std::path::Path::new(&target);
+    // Positional path (defaults to the `-C` target or current directory).
+    let target = super::common::last_positional(args)
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| rt.default_target_dir());
+    let target_path = target.as_path();
     let ctx = rt.ctx();
 
-    let all_ids = ito_core::installers::available_tool_ids();
-
     let tools: BTreeSet<String> = if let Some(raw) = tools_arg.as_deref() {
-        let raw = raw.trim();
-        if raw.is_empty() {
-            return fail("--tools cannot be empty");
-        }
-
-        if raw == "none" {
-            BTreeSet::new()
-        } else if raw == "all" {
-            all_ids.iter().map(|s| (*s).to_string()).collect()
+        if raw.trim() == "auto" {
+            auto_detected_tools(target_path)?
         } else {
-            let valid = all_ids.join(", ");
-            let mut selected: BTreeSet<String> = BTreeSet::new();
-            for part in raw.split(',') {
-                let id = part.trim();
-                if id.is_empty() {
-                    continue;
-                }
-                if all_ids.contains(&id) {
-                    selected.insert(id.to_string());
-                } else {
-                    return fail(format!("Unknown tool id '{id}'. Valid tool ids: {valid}"));
-                }
-            }
-            selected
+            ito_core::installers::parse_tool_selection(raw).map_err(to_cli_error)?
         }
     } else {
         use std::io::BufRead;
@@ -119,7 +107,7 @@ pub(super) fn handle_init(rt: &Runtime, args: &[String]) -> CliResult<()> {
         let is_tty = stdin().is_terminal() && stdout().is_terminal();
         if !(ui.interactive && is_tty) {
             return fail(
-                "Non-interactive init requires --tools (all, none, or comma-separated ids).",
+                "Non-interactive init requires --tools (all, none, auto, or comma-separated ids).",
             );
         }
 
@@ -136,22 +124,7 @@ pub(super) fn handle_init(rt: &Runtime, args: &[String]) -> CliResult<()> {
             "\nStep 2/3\n\nWhich natively supported AI tools do you use?\nUse ↑/↓ to move · Space to toggle · Enter reviews.\n"
         );
 
-        let mut detected: BTreeSet<&'static str> = BTreeSet::new();
-        if target_path.join("CLAUDE.md").exists() || target_path.join(".claude").exists() {
-            detected.insert(ito_core::installers::TOOL_CLAUDE);
-        }
-        if target_path.join(".opencode").exists() {
-            detected.insert(ito_core::installers::TOOL_OPENCODE);
-        }
-        if target_path.join(".github").exists() {
-            detected.insert(ito_core::installers::TOOL_GITHUB_COPILOT);
-        }
-        if target_path.join(".codex").exists() {
-            detected.insert(ito_core::installers::TOOL_CODEX);
-        }
-        if target_path.join(".pi").exists() {
-            detected.insert(ito_core::installers::TOOL_PI);
-        }
+        let detected = detect_installed_tools(&SystemProcessRunner, target_path);
 
         let tool_items: Vec<(&'static str, &str)> = vec![
             (ito_core::installers::TOOL_CLAUDE, "Claude Code"),
@@ -160,13 +133,17 @@ pub(super) fn handle_init(rt: &Runtime, args: &[String]) -> CliResult<()> {
             (ito_core::installers::TOOL_OPENCODE, "OpenCode"),
             (ito_core::installers::TOOL_PI, "Pi"),
         ];
+        let detected_tool = |id: &str| detected.iter().find(|tool| tool.id == id);
         let labels: Vec<String> = tool_items
             .iter()
-            .map(|(id, label)| format!("{label} ({id})"))
+            .map(|(id, label)| match detected_tool(id) {
+                Some(tool) => format!("{label} ({id}) - {}", detection_note(tool)),
+                None => format!("{label} ({id})"),
+            })
             .collect();
         let defaults: Vec<bool> = tool_items
             .iter()
-            .map(|(id, _)| detected.contains(id))
+            .map(|(id, _)| detected_tool(id).is_some())
             .collect();
 
         let indices =
@@ -209,6 +186,10 @@ pub(super) fn handle_init(rt: &Runtime, args: &[String]) -> CliResult<()> {
         resolve_worktree_config(ctx, target_path, is_interactive, &worktree_overrides)?;
     let worktree_ctx = worktree_template_context(&worktree_result, target_path, ctx);
 
+    // Rerunning `--migrate-from` against a project it already initialized
+    // keeps the existing config and files instead of refusing to overwrite.
+    let ito_path = ito_dir::get_ito_path(target_path, ctx);
+    let update = update || (migrate_from.is_some() && ito_path.join("config.json").is_file());
     let opts = if upgrade {
         InitOptions::new_upgrade(tools)
     } else {
@@ -222,19 +203,24 @@ pub(super) fn handle_init(rt: &Runtime, args: &[String]) -> CliResult<()> {
         Some(&worktree_ctx),
     )
     .map_err(to_cli_error)?;
+    ito_core::installers::record_tools(&ito_path, &opts.tools).map_err(to_cli_error)?;
+    let audit = ito_core::audit::default_audit_store(&ito_path);
+    ito_core::installers::record_install_event(Some(&*audit), &ito_path, InstallMode::Init, &opts);
 
     if upgrade {
         let legacy_hits = ito_core::installers::detect_legacy_paths(target_path);
         if !legacy_hits.is_empty() {
-            eprintln!("warning: found legacy Ito-managed cleanup candidates:");
+            ito_config::notice!("warning: found legacy Ito-managed cleanup candidates:");
             for hit in &legacy_hits {
                 if let Some(replacement) = hit.replacement {
-                    eprintln!(
+                    ito_config::notice!(
                         "  - {} ({}) replacement: {}",
-                        hit.relative_path, hit.description, replacement
+                        hit.relative_path,
+                        hit.description,
+                        replacement
                     );
                 } else {
-                    eprintln!("  - {} ({})", hit.relative_path, hit.description);
+                    ito_config::notice!("  - {} ({})", hit.relative_path, hit.description);
                 }
             }
             if cleanup {
@@ -245,12 +231,12 @@ pub(super) fn handle_init(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 }
                 ito_core::installers::remove_legacy_paths(target_path, &legacy_hits)
                     .map_err(to_cli_error)?;
-                eprintln!(
+                ito_config::notice!(
                     "Removed {} legacy Ito-managed cleanup candidate(s).",
                     legacy_hits.len()
                 );
             } else {
-                eprintln!(
+                ito_config::notice!(
                     "Run `ito agent instruction cleanup` for an audit, or rerun `ito init --upgrade --cleanup --force` to remove these known legacy paths."
                 );
             }
@@ -261,6 +247,8 @@ pub(super) fn handle_init(rt: &Runtime, args: &[String]) -> CliResult<()> {
         save_worktree_config(&worktree_project_config_path, &worktree_result)?;
     }
 
+    migrate_legacy_layout(target_path, ctx, migrate_from.as_deref(), move_legacy)?;
+
     #[cfg(feature = "coordination-branch")]
     if setup_coordination_branch {
         let ito_path = ito_dir::get_ito_path(target_path, ctx);
@@ -282,10 +270,10 @@ pub(super) fn handle_init(rt: &Runtime, args: &[String]) -> CliResult<()> {
 
         match setup_result {
             CoordinationBranchSetupStatus::Ready => {
-                println!("Coordination branch ready on origin: {coord_branch}");
+                ito_config::info!("Coordination branch ready on origin: {coord_branch}");
             }
             CoordinationBranchSetupStatus::Created => {
-                println!("Coordination branch created on origin: {coord_branch}");
+                ito_config::info!("Coordination branch created on origin: {coord_branch}");
             }
         }
     }
@@ -308,6 +296,68 @@ pub(super) fn handle_init(rt: &Runtime, args: &[String]) -> CliResult<()> {
     Ok(())
 }
 
+/// Copy a legacy layout into the Ito directory when `--migrate-from` is given;
+/// otherwise point out any legacy layout detected in the target.
+/// Resolve `--tools auto` to exactly the tools detected on PATH or in the project.
+fn auto_detected_tools(target_path: &std::path::Path) -> CliResult<BTreeSet<String>> {
+    let detected = detect_installed_tools(&SystemProcessRunner, target_path);
+    if detected.is_empty() {
+        return fail(format!(
+            "--tools auto detected no AI tools: no claude, codex, copilot, or opencode CLI answered `--version` on PATH, and {} has no tool directories (.claude/, .opencode/, ...).\nPass the tools explicitly instead, e.g. --tools claude,codex (available: {}), or --tools none.",
+            target_path.display(),
+            ito_core::installers::available_tool_ids().join(", ")
+        ));
+    }
+    for tool in &detected {
+        ito_config::info!("Detected {} ({})", tool.id, detection_note(tool));
+    }
+    Ok(detected.into_iter().map(|tool| tool.id).collect())
+}
+
+/// How `tool` was detected, e.g. `detected on PATH: 2.1.0` or `detected in repo`.
+fn detection_note(tool: &DetectedTool) -> String {
+    match &tool.version {
+        Some(version) => format!("detected {}: {version}", tool.source.as_str()),
+        None => format!("detected {}", tool.source.as_str()),
+    }
+}
+
+fn migrate_legacy_layout(
+    target_path: &std::path::Path,
+    ctx: &ConfigContext,
+    migrate_from: Option<&str>,
+    move_legacy: bool,
+) -> CliResult<()> {
+    use ito_core::legacy_layout::{
+        LegacyLayoutDetection, LegacyMigrationOptions, detect_legacy_layouts,
+    };
+
+    let Some(source) = migrate_from else {
+        for dir in detect_legacy_layouts(target_path, &LegacyLayoutDetection::default()) {
+            ito_config::notice!(
+                "Detected a legacy layout at {}. Rerun with `--migrate-from {}` to copy its specs and changes into Ito.",
+                dir.display(),
+                dir.display()
+            );
+        }
+        return Ok(());
+    };
+
+    let ito_path = ito_dir::get_ito_path(target_path, ctx);
+    let schema = ito_core::templates::default_schema_name();
+    let report = ito_core::legacy_layout::migrate_legacy_layout(
+        std::path::Path::new(source),
+        &ito_path,
+        LegacyMigrationOptions {
+            schema,
+            move_files: move_legacy,
+        },
+    )
+    .map_err(to_cli_error)?;
+    print!("{}", report.describe());
+    Ok(())
+}
+
 /// Print a brief advisory with the direct hook command when at least one
 /// repository validation rule is active and no `ito validate repo` pre-commit
 /// hook is detected.
@@ -337,13 +387,13 @@ fn print_repo_validation_advisory(target_path: &std::path::Path, ctx: &ConfigCon
 
     let detected = ito_core::validate_repo::detect_pre_commit_system(target_path);
 
-    eprintln!();
-    eprintln!("Tip: `ito validate repo` is not wired into your pre-commit hook.");
-    eprintln!(
+    ito_config::notice!();
+    ito_config::notice!("Tip: `ito validate repo` is not wired into your pre-commit hook.");
+    ito_config::notice!(
         "    Detected pre-commit system: {detected}. {} active validation rule(s) would run.",
         rules.iter().filter(|r| r.active).count(),
     );
-    eprintln!(
+    ito_config::notice!(
         "    If desired, add `ito validate repo --staged --strict` to that hook configuration and review the diff before committing."
     );
 }
@@ -396,15 +446,15 @@ fn print_post_init_guidance(target_path: &std::path::Path, ctx: &ConfigContext)
 
     let needs_project_setup = project_setup_is_incomplete(&ito_dir);
 
-    println!("\nIto initialized in {}\n", abs_target.display());
+    ito_config::info!("\nIto initialized in {}\n", abs_target.display());
 
     if needs_project_setup {
-        println!(
+        ito_config::info!(
             "Next step: Ask your AI assistant to run `ito agent instruction project-setup` and follow the emitted prompt.\n"
         );
     }
 
-    println!(
+    ito_config::info!(
         r#"Or manually edit:
   {}/project.md        Project overview, tech stack, architecture
   {}/user-prompts/     Shared + artifact-specific instruction guidance
@@ -458,6 +508,8 @@ fn project_setup_is_incomplete(ito_dir: &std::path::Path) -> bool {
 ///     no_worktrees: false,
 ///     worktree_strategy: None,
 ///     worktree_integration_mode: None,
+///     migrate_from: None,
+///     move_legacy: false,
 ///     path: Some(".".to_string()),
 /// };
 /// let _ = handle_init_clap(&rt, &args);
@@ -511,6 +563,13 @@ pub(crate) fn handle_init_clap(rt: &Runtime, args: &InitArgs) -> CliResult<()> {
         argv.push("--worktree-integration-mode".to_string());
         argv.push(integration_mode.clone());
     }
+    if let Some(migrate_from) = &args.migrate_from {
+        argv.push("--migrate-from".to_string());
+        argv.push(migrate_from.clone());
+    }
+    if args.move_legacy {
+        argv.push("--move".to_string());
+    }
     if let Some(path) = &args.path {
         argv.push(path.clone());
     }
@@ -529,7 +588,7 @@ fn resolve_worktree_config(
     overrides: &WorktreeWizardOverrides,
 ) -> CliResult<(WorktreeWizardResult, std::path::PathBuf, bool)> {
     let ito_path = ito_dir::get_ito_path(target_path, ctx);
-    let project_config_path = ito_path.join("config.json");
+    let project_config_path = ito_config::ito_dir_config_path(&ito_path);
     let project_local_config_path = ito_path.join("config.local.json");
     let global_config_path = ito_config::global_config_path(ctx);
 
@@ -657,10 +716,10 @@ fn load_interactive_worktree_defaults(
 #[cfg(feature = "coordination-branch")]
 fn setup_coordination_worktree(target_path: &std::path::Path, ctx: &ConfigContext, skip: bool) {
     let ito_path = ito_dir::get_ito_path(target_path, ctx);
-    let config_path = ito_path.join("config.json");
+    let config_path = ito_config::ito_dir_config_path(&ito_path);
 
     let Some(project_root) = ito_path.parent() else {
-        eprintln!(
+        ito_config::notice!(
             "warning: could not determine project root from Ito path '{}'; \
              skipping coordination worktree setup.\n\
              Continuing with embedded storage mode.\n\
@@ -668,7 +727,7 @@ fn setup_coordination_worktree(target_path: &std::path::Path, ctx: &ConfigContex
             ito_path.display()
         );
         if let Err(e) = write_coordination_mode(&config_path, CoordinationStorage::Embedded) {
-            eprintln!(
+            ito_config::notice!(
                 "warning: could not write coordination storage config to {}: {e}\n\
                  Fix: manually set `changes.coordination_branch.storage` to \"embedded\" in {}",
                 config_path.display(),
@@ -681,7 +740,7 @@ fn setup_coordination_worktree(target_path: &std::path::Path, ctx: &ConfigContex
     match provision_coordination_worktree(project_root, &ito_path, skip) {
         Ok(Some(storage)) => {
             if let Err(e) = write_coordination_mode(&config_path, storage) {
-                eprintln!(
+                ito_config::notice!(
                     "warning: could not write coordination storage config to {}: {e}\n\
                      Fix: manually set `changes.coordination_branch.storage` in {}",
                     config_path.display(),
@@ -693,10 +752,10 @@ fn setup_coordination_worktree(target_path: &std::path::Path, ctx: &ConfigContex
             // Backend mode is active — backend owns coordination; nothing to do.
         }
         Err(err) => {
-            eprintln!("Warning: coordination worktree setup failed: {err}");
-            eprintln!("Continuing with embedded storage mode.");
+            ito_config::notice!("Warning: coordination worktree setup failed: {err}");
+            ito_config::notice!("Continuing with embedded storage mode.");
             if let Err(e) = write_coordination_mode(&config_path, CoordinationStorage::Embedded) {
-                eprintln!(
+                ito_config::notice!(
                     "warning: could not write coordination storage config to {}: {e}\n\
                      Fix: manually set `changes.coordination_branch.storage` to \"embedded\" in {}",
                     config_path.display(),
@@ -0,0 +1,65 @@
+use std::path::PathBuf;
+
+use ito_core::templates::{
+    InstructionExportOptions, TemplatesError, export_change_instructions, write_instruction_bundle,
+};
+
+use crate::cli::{AgentExportArgs, AgentExportFormat};
+use crate::cli_error::{CliResult, fail, to_cli_error};
+use crate::runtime::Runtime;
+
+/// File name used for `--format json` when `--out` is given.
+const EXPORT_JSON_FILE: &str = "instructions.json";
+
+pub(super) fn handle_agent_export(rt: &Runtime, args: &AgentExportArgs) -> CliResult<()> {
+    let ctx = rt.ctx();
+    let ito_path = rt.ito_path();
+    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+    let change = super::change_target::resolve_change_target(
+        runtime.repositories().changes.as_ref(),
+        &args.change,
+    )?;
+
+    let opts = InstructionExportOptions {
+        max_file_bytes: args.max_file_bytes,
+        generated_at: args
+            .stamp
+            .then(|| chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)),
+    };
+    let export =
+        match export_change_instructions(ito_path, &change, args.schema.as_deref(), ctx, &opts) {
+            Ok(export) => export,
+            Err(TemplatesError::SchemaNotFound(name)) => {
+                return fail(super::common::schema_not_found_message(ctx, &name));
+            }
+            Err(e) => return Err(to_cli_error(e)),
+        };
+
+    match args.format {
+        AgentExportFormat::Json => {
+            let mut json = serde_json::to_string_pretty(&export).map_err(to_cli_error)?;
+            json.push('\n');
+            let Some(out) = &args.out else {
+                print!("{json}");
+                return Ok(());
+            };
+            std::fs::create_dir_all(out).map_err(to_cli_error)?;
+            let path = out.join(EXPORT_JSON_FILE);
+            std::fs::write(&path, json).map_err(to_cli_error)?;
+            println!("✔ Exported instructions for {change} to {}", path.display());
+        }
+        AgentExportFormat::Bundle => {
+            let out = args
+                .out
+                .clone()
+                .unwrap_or_else(|| PathBuf::from(format!("{change}-instructions")));
+            let manifest = write_instruction_bundle(&export, &out).map_err(to_cli_error)?;
+            println!(
+                "✔ Exported {} instruction files for {change} to {}",
+                manifest.files.len(),
+                out.display()
+            );
+        }
+    }
+    Ok(())
+}
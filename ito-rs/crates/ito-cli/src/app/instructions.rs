@@ -1,8 +1,8 @@
 use crate::cli::{AgentArgs, AgentCommand, AgentInstructionArgs};
-use crate::cli_error::{CliResult, fail, to_cli_error};
+use crate::cli_error::{CliError, CliResult, fail, to_cli_error};
 use crate::commands::sync::best_effort_sync_coordination;
 use crate::runtime::Runtime;
-use crate::util::parse_string_flag;
+use crate::util::{parse_string_flag, render_json};
 use ito_config::types::ItoConfig;
 
 use super::cleanup_instructions::generate_cleanup_instruction;
@@ -12,7 +12,7 @@ use ito_config::resolve_coordination_branch_settings;
 use ito_core::harness_context;
 use ito_core::templates as core_templates;
 use std::collections::BTreeMap;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
 pub(super) use super::worktree_instruction_config::{
     WorktreeConfig, worktree_config_from_merged_with_paths, worktree_config_from_resolved,
@@ -74,6 +74,8 @@ pub(crate) fn handle_agent_instruction(rt: &Runtime, args: &[String]) -> CliResu
     if artifact.is_empty() || artifact.starts_with('-') {
         return fail("Missing required argument <artifact>");
     }
+    let args = super::change_target::pin_exact_change(rt, args)?;
+    let args = args.as_ref();
 
     if artifact == "bootstrap" {
         let tool = parse_string_flag(args, "--tool");
@@ -303,11 +305,11 @@ After creating it, rerun `ito agent instruction orchestrate`. The retained lifec
         return emit_instruction(want_json, "migrate-to-coordination-worktree", instruction);
     }
     if artifact == "context" {
-        let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+        let cwd = rt.invocation_dir();
         let inferred = harness_context::infer_context_from_cwd(&cwd).map_err(to_cli_error)?;
 
         if want_json {
-            let rendered = serde_json::to_string_pretty(&inferred)
+            let rendered = render_json(&inferred)
                 .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
             println!("{rendered}");
             return Ok(());
@@ -338,7 +340,7 @@ After creating it, rerun `ito agent instruction orchestrate`. The retained lifec
         let response = core_templates::list_schemas_detail(ctx);
 
         if want_json {
-            let rendered = serde_json::to_string_pretty(&response)
+            let rendered = render_json(&response)
                 .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
             println!("{rendered}");
             return Ok(());
@@ -481,10 +483,10 @@ After creating it, rerun `ito agent instruction orchestrate`. The retained lifec
 
         let resolved_change = if let Some(ref raw) = change {
             let change_repo = runtime.repositories().changes.as_ref();
-            match super::common::resolve_change_target(change_repo, raw) {
-                Ok(resolved) => Some(resolved),
-                Err(msg) => return fail(msg),
-            }
+            Some(super::change_target::resolve_change_target(
+                change_repo,
+                raw,
+            )?)
         } else {
             None
         };
@@ -567,16 +569,24 @@ After creating it, rerun `ito agent instruction orchestrate`. The retained lifec
                 .as_ref()
                 .expect("non-apply instructions initialize repository runtime");
             let change_repo = runtime.repositories().changes.as_ref();
-            match super::common::resolve_change_target(change_repo, &change) {
-                Ok(resolved) => resolved,
-                Err(msg) => return fail(msg),
-            }
+            super::change_target::resolve_change_target(change_repo, &change)?
         }
     };
     let project_root = ito_path.parent().unwrap_or(ito_path);
     let resolved = rt.resolved_config();
     let testing_policy = testing_policy_from_merged(&resolved.merged);
 
+    if artifact == "next" {
+        return emit_next_artifact(
+            ito_path,
+            &change,
+            schema.as_deref(),
+            ctx,
+            &testing_policy,
+            want_json,
+        );
+    }
+
     let guidance_ito_path = authoritative_apply
         .as_ref()
         .map_or(ito_path, |prepared| prepared.source().ito_path());
@@ -604,9 +614,10 @@ After creating it, rerun `ito agent instruction orchestrate`. The retained lifec
             .as_mut()
             .expect("apply readiness creates an authoritative render source");
         let apply = super::apply_instruction::compute(prepared, rt)?;
+        warn_schema_drift(&apply.change_name, apply.schema_drift.as_ref());
 
         if want_json {
-            let rendered = serde_json::to_string_pretty(&apply)
+            let rendered = render_json(&apply)
                 .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
             println!("{rendered}");
             return Ok(());
@@ -644,7 +655,10 @@ After creating it, rerun `ito agent instruction orchestrate`. The retained lifec
                 return fail("Invalid change name");
             }
             Err(core_templates::TemplatesError::ChangeNotFound(name)) => {
-                return fail(format!("Change '{name}' not found"));
+                return Err(CliError::not_found(
+                    "CHANGE_NOT_FOUND",
+                    format!("Change '{name}' not found"),
+                ));
             }
             Err(core_templates::TemplatesError::SchemaNotFound(name)) => {
                 return fail(super::common::schema_not_found_message(ctx, &name));
@@ -654,33 +668,43 @@ After creating it, rerun `ito agent instruction orchestrate`. The retained lifec
 
         let instruction =
             ito_templates::instructions::render_instruction_template("agent/review.md.j2", &review)
-                .map_err(to_cli_error)?;
+                .map_err(|e| to_cli_error(e.to_string()))?;
 
         return emit_instruction(want_json, artifact, instruction);
     }
 
-    let resolved_instr = match core_templates::resolve_instructions(
-        ito_path,
-        &change,
-        schema.as_deref(),
-        artifact,
-        ctx,
-    ) {
+    let want_rendered = args.iter().any(|a| a == "--rendered");
+    let resolve = if want_rendered {
+        core_templates::resolve_rendered_instructions
+    } else {
+        core_templates::resolve_instructions
+    };
+    let resolved_instr = match resolve(ito_path, &change, schema.as_deref(), artifact, ctx) {
         Ok(r) => r,
         Err(core_templates::TemplatesError::InvalidChangeName) => {
             return fail("Invalid change name");
         }
         Err(core_templates::TemplatesError::ChangeNotFound(name)) => {
-            return fail(format!("Change '{name}' not found"));
+            return Err(CliError::not_found(
+                "CHANGE_NOT_FOUND",
+                format!("Change '{name}' not found"),
+            ));
         }
         Err(core_templates::TemplatesError::SchemaNotFound(name)) => {
             return fail(super::common::schema_not_found_message(ctx, &name));
         }
         Err(e) => return Err(to_cli_error(e)),
     };
+    warn_schema_drift(
+        &resolved_instr.change_name,
+        resolved_instr.schema_drift.as_ref(),
+    );
+    if let Some(warning) = &resolved_instr.render_warning {
+        eprintln!("Warning: {warning}");
+    }
 
     if want_json {
-        let rendered = serde_json::to_string_pretty(&resolved_instr)
+        let rendered = render_json(&resolved_instr)
             .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
         println!("{rendered}");
         return Ok(());
@@ -695,6 +719,71 @@ After creating it, rerun `ito agent instruction orchestrate`. The retained lifec
     Ok(())
 }
 
+/// Emit instructions for the next ready artifact, or a hint when none is ready.
+fn emit_next_artifact(
+    ito_path: &Path,
+    change: &str,
+    schema: Option<&str>,
+    ctx: &ito_config::ConfigContext,
+    testing_policy: &TestingPolicy,
+    want_json: bool,
+) -> CliResult<()> {
+    let next = match core_templates::next_artifact(ito_path, change, schema, ctx) {
+        Ok(r) => r,
+        Err(core_templates::TemplatesError::InvalidChangeName) => {
+            return fail("Invalid change name");
+        }
+        Err(core_templates::TemplatesError::ChangeNotFound(name)) => {
+            return Err(CliError::not_found(
+                "CHANGE_NOT_FOUND",
+                format!("Change '{name}' not found"),
+            ));
+        }
+        Err(core_templates::TemplatesError::SchemaNotFound(name)) => {
+            return fail(super::common::schema_not_found_message(ctx, &name));
+        }
+        Err(e) => return Err(to_cli_error(e)),
+    };
+
+    if want_json {
+        let rendered =
+            render_json(&next).map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let Some(instructions) = next.instructions.as_deref() else {
+        println!("{}", core_templates::next_step_hint(&next.step));
+        if let core_templates::NextStep::Blocked { blocked } = &next.step {
+            for artifact in blocked.iter().skip(1) {
+                println!(
+                    "  {} (blocked by: {})",
+                    artifact.id,
+                    artifact.missing_deps.join(", ")
+                );
+            }
+        }
+        return Ok(());
+    };
+
+    warn_schema_drift(
+        &instructions.change_name,
+        instructions.schema_drift.as_ref(),
+    );
+    let user_guidance =
+        match core_templates::load_composed_user_guidance(ito_path, &instructions.artifact_id) {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Warning: failed to read user guidance files: {e}");
+                None
+            }
+        };
+    let out =
+        render_artifact_instructions_text(instructions, user_guidance.as_deref(), testing_policy)?;
+    print!("{out}");
+    Ok(())
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 pub(super) struct TestingPolicy {
     tdd_workflow: String,
@@ -733,6 +822,21 @@ pub(super) fn testing_policy_from_merged(merged: &serde_json::Value) -> TestingP
     out
 }
 
+/// Print a schema drift notice to stderr, with the commands that resolve it.
+fn warn_schema_drift(change: &str, drift: Option<&core_templates::SchemaDrift>) {
+    let Some(drift) = drift else {
+        return;
+    };
+    let label = match drift.severity {
+        core_templates::SchemaDriftSeverity::High => "Warning",
+        core_templates::SchemaDriftSeverity::Low => "Note",
+    };
+    eprintln!("{label}: {}", core_templates::describe_schema_drift(drift));
+    eprintln!(
+        "  Run `ito change rebaseline {change}` to accept the current schema, or `ito change rebaseline {change} --pin` to keep the baselined copy."
+    );
+}
+
 /// Whether to sync the coordination branch before resolving a change artifact.
 ///
 /// `proposal` and `review` always sync (they need up-to-date remote state).
@@ -762,6 +866,9 @@ fn json_get<'a>(root: &'a serde_json::Value, keys: &[&str]) -> Option<&'a serde_
 pub(crate) fn handle_agent_clap(rt: &Runtime, args: &AgentArgs) -> CliResult<()> {
     let result = match &args.command {
         Some(AgentCommand::Instruction(instr)) => handle_agent_instruction_clap(rt, instr),
+        Some(AgentCommand::Export(export)) => {
+            super::instruction_export::handle_agent_export(rt, export)
+        }
         Some(AgentCommand::External(v)) => handle_agent(rt, v),
         None => handle_agent(rt, &[]),
     };
@@ -784,6 +891,10 @@ fn reconstruct_agent_args(args: &AgentArgs) -> Vec<String> {
             raw.push("instruction".to_string());
             raw.extend(instr.to_argv());
         }
+        Some(AgentCommand::Export(export)) => {
+            raw.push("export".to_string());
+            raw.push(export.change.clone());
+        }
         Some(AgentCommand::External(v)) => {
             raw.extend(v.iter().cloned());
         }
@@ -802,7 +913,7 @@ fn emit_instruction(want_json: bool, artifact_id: &str, instruction: String) ->
             artifact_id: artifact_id.to_string(),
             instruction,
         };
-        let rendered = serde_json::to_string_pretty(&response)
+        let rendered = render_json(&response)
             .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
         println!("{rendered}");
     } else {
@@ -954,7 +1065,9 @@ pub(super) fn render_artifact_instructions_text(
         let p = Path::new(&instructions.change_dir).join(&dep.path);
         dependencies.push(TemplateDependency {
             id: dep.id.clone(),
-            status: if dep.done {
+            status: if dep.skipped {
+                "skipped".to_string()
+            } else if dep.done {
                 "done".to_string()
             } else {
                 "missing".to_string()
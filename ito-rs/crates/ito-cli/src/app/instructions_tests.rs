@@ -425,9 +425,11 @@ fn agent_instruction_args_round_trips_sync_flag() {
     let args_no_sync = AgentInstructionArgs {
         artifact: "apply".to_string(),
         change: Some("001-01_test".to_string()),
+        exact: false,
         tool: None,
         schema: None,
         json: false,
+        rendered: false,
         sync: false,
         variant: None,
         profile: None,
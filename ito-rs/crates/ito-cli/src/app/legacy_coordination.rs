@@ -1,9 +1,11 @@
 use crate::cli::BackendAction;
 use crate::cli::{
-    AgentCommand, ChangeCommand, Commands, ConfigCommand, PlanAction, TasksAction, WorktreeCommand,
+    AgentCommand, ChangeCommand, Commands, ConfigCommand, PlanAction, SpecCommand, TasksAction,
+    WorktreeCommand,
 };
 use crate::cli_error::{CliResult, to_cli_error};
 use crate::commands::audit::AuditAction;
+use crate::commands::run::RunAction;
 use crate::diagnostics::{
     LegacyCoordinationMutationBlocked, format_legacy_coordination_read_warning,
 };
@@ -36,22 +38,40 @@ pub(crate) fn command_intent(command: &Commands) -> CommandIntent {
         | Commands::Validate(_)
         | Commands::Grep(_)
         | Commands::Path(_)
+        | Commands::Paths(_)
         | Commands::View(_)
         | Commands::Util(_)
         | Commands::Trace(_)
         | Commands::Completions(_)
         | Commands::Stats(_)
+        | Commands::Models(_)
+        | Commands::Harness(_)
+        | Commands::Transcripts(_)
         | Commands::Help(_) => CommandIntent::ReadOnly,
+        // Doctor must diagnose configs the coordination guard cannot parse.
+        Commands::Doctor(_) => CommandIntent::Recovery,
         Commands::Change(args) => match &args.command {
             ChangeCommand::Preflight(args) if args.refresh => CommandIntent::Mutating,
             ChangeCommand::Preflight(_) => CommandIntent::ReadOnly,
+            ChangeCommand::Rebaseline(_) => CommandIntent::Mutating,
+            ChangeCommand::Rename(args) if args.dry_run => CommandIntent::ReadOnly,
+            ChangeCommand::Rename(_) => CommandIntent::Mutating,
+            ChangeCommand::Split(_) => CommandIntent::Mutating,
+            ChangeCommand::Sync(_) => CommandIntent::Mutating,
+        },
+        Commands::Spec(args) => match &args.command {
+            SpecCommand::Rename(args) if args.dry_run => CommandIntent::ReadOnly,
+            SpecCommand::Rename(_) => CommandIntent::Mutating,
         },
         Commands::Tasks(args) => match &args.action {
             Some(
                 TasksAction::Status { .. }
                 | TasksAction::Next { .. }
                 | TasksAction::Ready { .. }
-                | TasksAction::Show { .. },
+                | TasksAction::Show { .. }
+                | TasksAction::Lint { .. }
+                | TasksAction::Plan { .. }
+                | TasksAction::History { .. },
             ) => CommandIntent::ReadOnly,
             Some(
                 TasksAction::Init { .. }
@@ -77,7 +97,9 @@ pub(crate) fn command_intent(command: &Commands) -> CommandIntent {
             Some(AgentCommand::Instruction(args)) if args.artifact == "migrate-to-main" => {
                 CommandIntent::Recovery
             }
-            Some(AgentCommand::Instruction(_)) => CommandIntent::ReadOnly,
+            Some(AgentCommand::Instruction(_)) | Some(AgentCommand::Export(_)) => {
+                CommandIntent::ReadOnly
+            }
             Some(AgentCommand::External(_)) | None => CommandIntent::Mutating,
         },
         Commands::Config(args) => match &args.command {
@@ -93,11 +115,14 @@ pub(crate) fn command_intent(command: &Commands) -> CommandIntent {
         },
         Commands::Worktree(args) => match &args.command {
             WorktreeCommand::Validate(_) => CommandIntent::ReadOnly,
-            WorktreeCommand::Ensure(_) | WorktreeCommand::Setup(_) => CommandIntent::Mutating,
+            WorktreeCommand::Create(_) | WorktreeCommand::Ensure(_) | WorktreeCommand::Setup(_) => {
+                CommandIntent::Mutating
+            }
         },
         Commands::Audit(args) => match &args.action {
             Some(
                 AuditAction::Log { .. }
+                | AuditAction::Follow { .. }
                 | AuditAction::Validate { .. }
                 | AuditAction::Stats { .. }
                 | AuditAction::Stream { .. },
@@ -112,9 +137,14 @@ pub(crate) fn command_intent(command: &Commands) -> CommandIntent {
             | BackendAction::Import { dry_run: true } => CommandIntent::ReadOnly,
             BackendAction::Import { dry_run: false } => CommandIntent::Mutating,
         },
+        Commands::Run(args) => match &args.action {
+            RunAction::Artifact(args) if args.dry_run => CommandIntent::ReadOnly,
+            RunAction::Artifact(_) => CommandIntent::Mutating,
+        },
         Commands::ServeApiRemoved(_) => CommandIntent::ReadOnly,
         Commands::Create(_)
         | Commands::Archive(_)
+        | Commands::Workflow(_)
         | Commands::Patch(_)
         | Commands::Write(_)
         | Commands::Sync(_)
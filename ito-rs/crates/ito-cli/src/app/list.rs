@@ -1,22 +1,30 @@
-use crate::cli::{ListArgs, ListSortOrder};
+use crate::cli::{ListArgs, ListSortOrder, ListStatus};
 use crate::cli_error::{CliResult, fail, to_cli_error};
 use crate::runtime::Runtime;
+use crate::ui::{Cell, Column, Table, Tone, Ui, status_tone};
+use crate::util::render_json;
 use chrono::{DateTime, Utc};
-use ito_core::repository_runtime::PersistenceMode;
+use ito_core::project_layout::{CHANGES_SUBDIR, LayoutWarning, MODULES_SUBDIR, SPECS_SUBDIR};
 
 #[derive(Debug, serde::Serialize)]
 struct ModulesResponse {
     modules: Vec<ito_core::list::ModuleListItem>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<LayoutWarning>,
 }
 
 #[derive(Debug, serde::Serialize)]
 struct ChangesResponse {
     changes: Vec<ito_core::list::ChangeListItem>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<LayoutWarning>,
 }
 
 #[derive(Debug, serde::Serialize)]
 struct SpecsResponse {
     specs: Vec<ito_core::list::SpecListItem>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<LayoutWarning>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -35,7 +43,8 @@ pub(crate) fn handle_list(rt: &Runtime, args: &[String]) -> CliResult<()> {
 
     let want_specs = args.iter().any(|a| a == "--specs");
     let want_changes = args.iter().any(|a| a == "--changes");
-    let want_modules = args.iter().any(|a| a == "--modules");
+    let want_graph = args.iter().any(|a| a == "--graph");
+    let want_modules = want_graph || args.iter().any(|a| a == "--modules");
     let want_archived = args.iter().any(|a| a == "--archived");
     let want_json = args.iter().any(|a| a == "--json");
     let want_ready = args.iter().any(|a| a == "--ready");
@@ -45,6 +54,12 @@ pub(crate) fn handle_list(rt: &Runtime, args: &[String]) -> CliResult<()> {
     let want_sort = args
         .iter()
         .any(|a| a == "--sort" || a.starts_with("--sort="));
+    let status_filters = match parse_status_filters(args) {
+        Ok(filters) => filters,
+        Err(msg) => return fail(msg),
+    };
+    let label_filters = flag_values(args, "--label");
+    let owner_filter = flag_values(args, "--owner").pop();
 
     let progress_filter_count =
         u8::from(want_completed) + u8::from(want_partial) + u8::from(want_pending);
@@ -59,10 +74,11 @@ pub(crate) fn handle_list(rt: &Runtime, args: &[String]) -> CliResult<()> {
             || want_completed
             || want_partial
             || want_pending
-            || want_sort)
+            || want_sort
+            || !status_filters.is_empty())
     {
         return fail(
-            "Flag --archived cannot be combined with --specs, --changes, --modules, --ready, --completed, --partial, --pending, or --sort.",
+            "Flag --archived cannot be combined with --specs, --changes, --modules, --ready, --completed, --partial, --pending, --status, or --sort.",
         );
     }
 
@@ -83,15 +99,36 @@ pub(crate) fn handle_list(rt: &Runtime, args: &[String]) -> CliResult<()> {
     let ito_path = rt.ito_path();
     let runtime = rt.repository_runtime().map_err(to_cli_error)?;
     let repos = runtime.repositories();
+    let subdir = match mode {
+        "modules" => MODULES_SUBDIR,
+        "specs" => SPECS_SUBDIR,
+        _ => CHANGES_SUBDIR,
+    };
+    // Report unreadable parts of `.ito/` and list nothing from them.
+    let warnings = super::common::check_layout(rt, &[subdir])?;
+    super::common::print_layout_warnings(&warnings);
+    let readable = warnings.is_empty();
 
     match mode {
         "modules" => {
-            let modules =
-                ito_core::list::list_modules(repos.modules.as_ref()).map_err(to_cli_error)?;
+            let modules = if readable {
+                ito_core::list::list_modules(repos.modules.as_ref()).map_err(to_cli_error)?
+            } else {
+                Vec::new()
+            };
+
+            if want_graph {
+                let graph = ito_core::validate::load_module_graph(ito_path);
+                print!(
+                    "{}",
+                    ito_core::list::render_module_graph_mermaid(&modules, &graph)
+                );
+                return Ok(());
+            }
 
             if want_json {
-                let payload = ModulesResponse { modules };
-                let rendered = serde_json::to_string_pretty(&payload)
+                let payload = ModulesResponse { modules, warnings };
+                let rendered = render_json(&payload)
                     .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
                 println!("{rendered}");
                 return Ok(());
@@ -126,23 +163,27 @@ pub(crate) fn handle_list(rt: &Runtime, args: &[String]) -> CliResult<()> {
             }
 
             if want_json {
-                let payload = SpecsResponse { specs };
-                let rendered = serde_json::to_string_pretty(&payload)
+                let payload = SpecsResponse { specs, warnings };
+                let rendered = render_json(&payload)
                     .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
                 println!("{rendered}");
                 return Ok(());
             }
 
-            println!("Specs:");
-            let padding = "  ";
-            let name_width = specs.iter().map(|s| s.id.len()).max().unwrap_or(0);
+            let ui = Ui::stdout();
+            println!("{}", ui.heading("Specs:"));
+            let mut table = Table::new(vec![Column::new("spec").shrink(), Column::new("")]).gap(5);
             for s in specs {
-                let padded = format!("{id: <width$}", id = s.id, width = name_width);
-                println!("{padding}{padded}     requirements {}", s.requirement_count);
+                table.row(vec![
+                    Cell::plain(s.id),
+                    Cell::toned(format!("requirements {}", s.requirement_count), Tone::Muted),
+                ]);
             }
+            print!("{}", table.render(&ui));
         }
         _ => {
             // changes
+            print_stale_change_scaffolds(ito_path);
             let progress_filter = if want_ready {
                 ito_core::list::ChangeProgressFilter::Ready
             } else if want_completed {
@@ -155,23 +196,16 @@ pub(crate) fn handle_list(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 ito_core::list::ChangeProgressFilter::All
             };
 
-            let sort_order = if sort == "name" {
-                ito_core::list::ChangeSortOrder::Name
-            } else {
-                ito_core::list::ChangeSortOrder::Recent
+            let sort_order = match sort {
+                "name" | "id" => ito_core::list::ChangeSortOrder::Name,
+                "status" => ito_core::list::ChangeSortOrder::Status,
+                "progress" => ito_core::list::ChangeSortOrder::Progress,
+                _ => ito_core::list::ChangeSortOrder::Recent,
             };
 
-            if runtime.mode() == PersistenceMode::Filesystem {
-                let changes_dir = ito_path.join("changes");
-                if !changes_dir.exists() {
-                    return Err(to_cli_error(miette::miette!(
-                        "No Ito changes directory found at {}",
-                        changes_dir.display()
-                    )));
-                }
-            }
-
-            let summaries = if want_ready {
+            let summaries = if !readable {
+                Vec::new()
+            } else if want_ready {
                 let config = rt.typed_config().map_err(to_cli_error)?;
                 ito_core::list::list_prepare_ready_changes(
                     repos.changes.as_ref(),
@@ -190,12 +224,21 @@ pub(crate) fn handle_list(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 )
                 .map_err(to_cli_error)?
             };
+            let summaries = ito_core::list::filter_changes_by_status(summaries, &status_filters);
+            let summaries = ito_core::list::filter_changes_by_metadata(
+                summaries,
+                &label_filters,
+                owner_filter.as_deref(),
+            );
 
             if summaries.is_empty() {
                 if want_json {
-                    let rendered =
-                        serde_json::to_string_pretty(&serde_json::json!({ "changes": [] }))
-                            .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
+                    let payload = ChangesResponse {
+                        changes: Vec::new(),
+                        warnings,
+                    };
+                    let rendered = render_json(&payload)
+                        .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
                     println!("{rendered}");
                 } else if want_ready {
                     println!("No implementation-ready changes found.");
@@ -209,6 +252,13 @@ pub(crate) fn handle_list(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 } else if want_pending {
                     println!("No pending changes found.");
                     println!("Run `ito list` to see all changes.");
+                } else if !label_filters.is_empty() || owner_filter.is_some() {
+                    println!("No changes matching the label/owner filters found.");
+                    println!("Run `ito list` to see all changes.");
+                } else if !status_filters.is_empty() {
+                    let names: Vec<&str> = status_filters.iter().map(|f| f.as_str()).collect();
+                    println!("No changes with status {} found.", names.join(" or "));
+                    println!("Run `ito list` to see all changes.");
                 } else {
                     println!("No active changes found.");
                 }
@@ -216,43 +266,57 @@ pub(crate) fn handle_list(rt: &Runtime, args: &[String]) -> CliResult<()> {
             }
 
             if want_json {
-                let changes: Vec<ito_core::list::ChangeListItem> = summaries
-                    .iter()
-                    .map(|s| ito_core::list::ChangeListItem {
-                        name: s.name.clone(),
-                        completed_tasks: s.completed_tasks,
-                        shelved_tasks: s.shelved_tasks,
-                        in_progress_tasks: s.in_progress_tasks,
-                        pending_tasks: s.pending_tasks,
-                        total_tasks: s.total_tasks,
-                        last_modified: ito_core::list::to_iso_millis(s.last_modified),
-                        status: s.status.clone(),
-                        work_status: s.work_status.clone(),
-                        completed: s.completed,
-                    })
-                    .collect();
-                let payload = ChangesResponse { changes };
-                let rendered = serde_json::to_string_pretty(&payload)
+                let changes: Vec<ito_core::list::ChangeListItem> =
+                    summaries.iter().map(Into::into).collect();
+                let payload = ChangesResponse { changes, warnings };
+                let rendered = render_json(&payload)
                     .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
                 println!("{rendered}");
                 return Ok(());
             }
 
-            println!("Changes:");
-            let name_width = summaries.iter().map(|s| s.name.len()).max().unwrap_or(0);
+            let ui = Ui::stdout();
+            println!("{}", ui.heading("Changes:"));
+            let mut table = Table::new(vec![
+                Column::new("change").shrink(),
+                Column::new("module").min_width(3),
+                Column::new("status").min_width(20),
+                Column::new("updated"),
+            ]);
             for s in &summaries {
-                let status = format_task_status(s);
-                let time_ago = format_relative_time(s.last_modified);
-                let padded = format!("{: <width$}", s.name, width = name_width);
-                println!("  {padded}     {: <20}  {time_ago}", status);
+                table.row(vec![
+                    Cell::plain(s.name.as_str()),
+                    Cell::plain(s.module_id.as_deref().unwrap_or("-")),
+                    Cell::toned(format_task_status(s), status_tone(s.list_status())),
+                    Cell::toned(format_relative_time(s.last_modified), Tone::Muted),
+                ]);
             }
+            print!("{}", table.render(&ui));
         }
     }
 
     Ok(())
 }
 
+/// Report staging directories left by an interrupted `ito create change`.
+fn print_stale_change_scaffolds(ito_path: &std::path::Path) {
+    let changes_dir = ito_path.join(CHANGES_SUBDIR);
+    for dir in ito_core::staging::find_stale_staged_dirs(
+        &changes_dir,
+        ito_core::staging::STALE_STAGING_AGE,
+    ) {
+        eprintln!(
+            "warning: incomplete change scaffold from an interrupted create: {}",
+            dir.display()
+        );
+        eprintln!("  help: the next `ito create change` removes it, or delete it manually.");
+    }
+}
+
 pub(crate) fn handle_list_clap(rt: &Runtime, args: &ListArgs) -> CliResult<()> {
+    if let Some(rev) = &args.at {
+        rt.pin_revision(rev).map_err(to_cli_error)?;
+    }
     let mut argv: Vec<String> = Vec::new();
     if args.specs {
         argv.push("--specs".to_string());
@@ -263,6 +327,9 @@ pub(crate) fn handle_list_clap(rt: &Runtime, args: &ListArgs) -> CliResult<()> {
     if args.modules {
         argv.push("--modules".to_string());
     }
+    if args.graph {
+        argv.push("--graph".to_string());
+    }
     if args.archived {
         argv.push("--archived".to_string());
     }
@@ -278,6 +345,26 @@ pub(crate) fn handle_list_clap(rt: &Runtime, args: &ListArgs) -> CliResult<()> {
     if args.pending {
         argv.push("--pending".to_string());
     }
+    for status in &args.status {
+        let status = match status {
+            ListStatus::Draft => "draft",
+            ListStatus::Ready => "ready",
+            ListStatus::InProgress => "in-progress",
+            ListStatus::Paused => "paused",
+            ListStatus::Complete => "complete",
+            ListStatus::Blocked => "blocked",
+        };
+        argv.push("--status".to_string());
+        argv.push(status.to_string());
+    }
+    for label in &args.label {
+        argv.push("--label".to_string());
+        argv.push(label.clone());
+    }
+    if let Some(owner) = &args.owner {
+        argv.push("--owner".to_string());
+        argv.push(owner.clone());
+    }
     if args.json {
         argv.push("--json".to_string());
     }
@@ -286,6 +373,8 @@ pub(crate) fn handle_list_clap(rt: &Runtime, args: &ListArgs) -> CliResult<()> {
         let sort = match args.sort {
             ListSortOrder::Recent => "recent",
             ListSortOrder::Name => "name",
+            ListSortOrder::Status => "status",
+            ListSortOrder::Progress => "progress",
         };
         argv.push("--sort".to_string());
         argv.push(sort.to_string());
@@ -302,7 +391,7 @@ pub(crate) fn handle_list_archive(rt: &Runtime, want_json: bool) -> CliResult<()
 
     if want_json {
         let payload = ArchivedChangesResponse { archived };
-        let rendered = serde_json::to_string_pretty(&payload)
+        let rendered = render_json(&payload)
             .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
         println!("{rendered}");
         return Ok(());
@@ -342,6 +431,57 @@ fn parse_sort_order(args: &[String]) -> Option<&str> {
     None
 }
 
+/// Collect every value passed to `flag` (as `flag value` or `flag=value`).
+fn flag_values(args: &[String], flag: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut iter = args.iter();
+    while let Some(a) = iter.next() {
+        if a == flag {
+            if let Some(v) = iter.next() {
+                values.push(v.clone());
+            }
+        } else if let Some(v) = a.strip_prefix(flag).and_then(|v| v.strip_prefix('=')) {
+            values.push(v.to_string());
+        }
+    }
+    values
+}
+
+/// Collect every `--status` value (repeatable; OR semantics).
+fn parse_status_filters(
+    args: &[String],
+) -> Result<Vec<ito_core::list::ChangeStatusFilter>, String> {
+    let mut raw: Vec<&str> = Vec::new();
+    let mut iter = args.iter();
+    while let Some(a) = iter.next() {
+        if a == "--status" {
+            if let Some(v) = iter.next() {
+                raw.push(v.as_str());
+            }
+        } else if let Some(v) = a.strip_prefix("--status=") {
+            raw.push(v);
+        }
+    }
+
+    let mut filters = Vec::new();
+    for value in raw {
+        let Some(filter) = ito_core::list::ChangeStatusFilter::parse(value) else {
+            let valid: Vec<&str> = ito_core::list::ChangeStatusFilter::ALL
+                .iter()
+                .map(|f| f.as_str())
+                .collect();
+            return Err(format!(
+                "Invalid --status '{value}'. Valid values: {}",
+                valid.join(", ")
+            ));
+        };
+        if !filters.contains(&filter) {
+            filters.push(filter);
+        }
+    }
+    Ok(filters)
+}
+
 fn format_task_status(s: &ito_core::list::ChangeListSummary) -> String {
     if s.total_tasks == 0 {
         return "No tasks".to_string();
@@ -366,7 +506,8 @@ fn format_task_status(s: &ito_core::list::ChangeListSummary) -> String {
     let counts = parts.join("/");
 
     // Add work status indicator
-    match s.work_status.as_str() {
+    match s.list_status() {
+        "blocked" => format!("\u{2298} Blocked ({})", counts),
         "complete" => format!("\u{2713} Complete ({})", counts),
         "paused" => format!("\u{2016} Paused ({})", counts),
         "in-progress" => format!("\u{25B6} Active ({})", counts),
@@ -376,7 +517,7 @@ fn format_task_status(s: &ito_core::list::ChangeListSummary) -> String {
     }
 }
 
-fn format_relative_time(then: DateTime<Utc>) -> String {
+pub(super) fn format_relative_time(then: DateTime<Utc>) -> String {
     let now = Utc::now();
     let diff = now.signed_duration_since(then);
     let secs = diff.num_seconds();
@@ -1,4 +1,6 @@
-use super::{format_relative_time, format_task_status, handle_list, parse_sort_order};
+use super::{
+    format_relative_time, format_task_status, handle_list, parse_sort_order, parse_status_filters,
+};
 use crate::runtime::Runtime;
 use chrono::{Duration, Utc};
 
@@ -25,6 +27,9 @@ fn format_task_status_handles_various_states() {
             pending_tasks: pending,
             total_tasks: total,
             last_modified: Utc::now(),
+            module_id: Some("000".to_string()),
+            blocked_by: Vec::new(),
+            metadata: Default::default(),
         }
     };
 
@@ -53,6 +58,30 @@ fn format_task_status_handles_various_states() {
     let status = format_task_status(&s);
     assert!(status.contains("1c"));
     assert!(status.contains("2p"));
+    // Blocked (ready, but waiting on a dependency)
+    let mut s = make_summary(0, 0, 0, 2, 2, "ready");
+    s.blocked_by = vec!["000-01_base".to_string()];
+    assert!(format_task_status(&s).contains("Blocked"));
+    assert!(format_task_status(&s).contains("2p"));
+}
+
+#[test]
+fn parse_status_filters_collects_repeated_values() {
+    use ito_core::list::ChangeStatusFilter;
+
+    let args: Vec<String> = ["--status", "ready", "--status=blocked", "--status", "ready"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    assert_eq!(
+        parse_status_filters(&args).unwrap(),
+        vec![ChangeStatusFilter::Ready, ChangeStatusFilter::Blocked]
+    );
+
+    let args = vec!["--status".to_string(), "done".to_string()];
+    let err = parse_status_filters(&args).unwrap_err();
+    assert!(err.contains("Invalid --status 'done'"), "{err}");
+    assert!(err.contains("in-progress"), "{err}");
 }
 
 #[test]
@@ -73,7 +102,7 @@ fn archived_filter_rejects_incompatible_raw_flags() {
     let err = handle_list(&rt, &args).unwrap_err();
     assert_eq!(
         err.to_string(),
-        "Flag --archived cannot be combined with --specs, --changes, --modules, --ready, --completed, --partial, --pending, or --sort."
+        "Flag --archived cannot be combined with --specs, --changes, --modules, --ready, --completed, --partial, --pending, --status, or --sort."
     );
 }
 
@@ -88,7 +117,7 @@ fn archived_filter_rejects_sort_raw_flag() {
     let err = handle_list(&rt, &args).unwrap_err();
     assert_eq!(
         err.to_string(),
-        "Flag --archived cannot be combined with --specs, --changes, --modules, --ready, --completed, --partial, --pending, or --sort."
+        "Flag --archived cannot be combined with --specs, --changes, --modules, --ready, --completed, --partial, --pending, --status, or --sort."
     );
 }
 
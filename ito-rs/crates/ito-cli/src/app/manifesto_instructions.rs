@@ -1,4 +1,4 @@
-use crate::cli_error::{CliResult, fail, to_cli_error};
+use crate::cli_error::{CliError, CliResult, fail, to_cli_error};
 #[cfg(feature = "coordination-branch")]
 use crate::commands::sync::best_effort_sync_coordination;
 use crate::runtime::Runtime;
@@ -227,7 +227,9 @@ pub(super) fn handle_manifesto_instruction(
             status
                 .artifacts
                 .iter()
-                .filter(|artifact| artifact.status != "done" && artifact.status != "optional")
+                .filter(|artifact| {
+                    !matches!(artifact.status.as_str(), "done" | "optional" | "skipped")
+                })
                 .map(|artifact| artifact.id.clone())
                 .collect::<Vec<_>>()
         } else {
@@ -476,11 +478,13 @@ fn resolve_manifesto_change(
 
     match change_repo.resolve_target(input) {
         ChangeTargetResolution::Unique(id) => Ok(id),
-        ChangeTargetResolution::Ambiguous(ids) => fail(format!(
-            "Change target '{input}' is ambiguous. Matches: {}",
-            ids.join(", ")
+        ChangeTargetResolution::Ambiguous(matches) => Err(
+            super::change_target::ambiguous_change_error(input, &matches),
+        ),
+        ChangeTargetResolution::NotFound => Err(CliError::not_found(
+            "CHANGE_NOT_FOUND",
+            format!("Change '{input}' not found"),
         )),
-        ChangeTargetResolution::NotFound => fail(format!("Change '{input}' not found")),
     }
 }
 
@@ -530,10 +534,9 @@ fn apply_requirements_complete(status: &core_templates::ChangeStatus) -> bool {
     }
 
     status.apply_requires.iter().all(|required| {
-        status
-            .artifacts
-            .iter()
-            .any(|artifact| artifact.id == *required && artifact.status == "done")
+        status.artifacts.iter().any(|artifact| {
+            artifact.id == *required && matches!(artifact.status.as_str(), "done" | "skipped")
+        })
     })
 }
 
@@ -640,7 +643,7 @@ fn render_manifesto_instruction_body(
             )
             .map_err(to_cli_error)?;
             ito_templates::instructions::render_instruction_template("agent/review.md.j2", &review)
-                .map_err(to_cli_error)
+                .map_err(|e| to_cli_error(e.to_string()))
         }
         "archive" => {
             let archive = archive_instruction_config_from_merged(&cfg.merged)?;
@@ -652,7 +655,7 @@ fn render_manifesto_instruction_body(
                     "available_changes": Vec::<String>::new(),
                 }),
             )
-            .map_err(to_cli_error)
+            .map_err(|e| to_cli_error(e.to_string()))
         }
         "finish" => {
             let worktree =
@@ -669,7 +672,7 @@ fn render_manifesto_instruction_body(
                     "archive_prompt_rendered": true,
                 }),
             )
-            .map_err(to_cli_error)
+            .map_err(|e| to_cli_error(e.to_string()))
         }
         _ => Ok(String::new()),
     }
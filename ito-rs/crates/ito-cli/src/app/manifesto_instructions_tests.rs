@@ -25,6 +25,7 @@ fn draft_change_with_pending_task() -> Change {
             },
         },
         orchestrate: Default::default(),
+        metadata: Default::default(),
         last_modified: Utc::now(),
     }
 }
@@ -42,6 +43,7 @@ fn manifesto_state_uses_schema_status_for_custom_artifacts() {
             output_path: "proposal.md".to_string(),
             status: "done".to_string(),
             missing_deps: Vec::new(),
+            skip_reason: None,
         }],
     };
 
@@ -92,12 +94,14 @@ fn manifesto_state_uses_apply_requirements_not_all_required_artifacts() {
                 output_path: "proposal.md".to_string(),
                 status: "done".to_string(),
                 missing_deps: Vec::new(),
+                skip_reason: None,
             },
             core_templates::ArtifactStatus {
                 id: "analysis".to_string(),
                 output_path: "analysis.md".to_string(),
                 status: "ready".to_string(),
                 missing_deps: Vec::new(),
+                skip_reason: None,
             },
         ],
     };
@@ -126,6 +130,7 @@ fn manifesto_state_treats_empty_apply_requirements_as_apply_ready() {
             output_path: "proposal.md".to_string(),
             status: "ready".to_string(),
             missing_deps: Vec::new(),
+            skip_reason: None,
         }],
     };
 
@@ -1,11 +1,16 @@
 mod apply_instruction;
 mod archive;
 pub(crate) mod change;
+mod change_rename;
+mod change_split;
+mod change_sync;
+pub(crate) mod change_target;
 mod cleanup_instructions;
 pub(crate) mod common;
 mod entrypoint;
 mod grep;
 mod init;
+mod instruction_export;
 mod instructions;
 mod legacy_coordination;
 mod list;
@@ -13,11 +18,16 @@ mod manifesto_instructions;
 mod memory_instructions;
 mod run;
 mod show;
+mod spec;
 mod status;
 pub(crate) mod trace;
 mod update;
 mod validate;
+mod validate_fix;
 mod validate_repo;
+#[cfg(feature = "watch")]
+mod validate_watch;
+mod workflow;
 mod worktree_instruction_config;
 mod worktree_wizard;
 
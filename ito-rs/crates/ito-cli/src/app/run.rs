@@ -1,11 +1,13 @@
 use crate::cli::{Cli, Commands};
 use crate::cli_error::{CliError, CliResult, fail};
-use crate::runtime::Runtime;
+use crate::runtime::{Runtime, resolve_start_dir};
 use crate::{commands, util};
 use clap::Parser;
 use clap::error::ErrorKind;
 use ito_config::ConfigContext;
+use ito_config::output::{self, Verbosity};
 use ito_core::capabilities::CapabilityPreflight;
+use std::path::Path;
 
 /// Parse CLI arguments, initialize the runtime and logging context, and dispatch the selected subcommand.
 ///
@@ -21,6 +23,14 @@ use ito_core::capabilities::CapabilityPreflight;
 /// let _ = ito_cli::run(&args);
 /// ```
 pub(super) fn run(args: &[String]) -> CliResult<()> {
+    // Completion scripts call this hidden helper on every keypress; skip clap
+    // parsing, runtime guards, and config warnings entirely.
+    if let Some((first, rest)) = args.split_first()
+        && first == commands::DYNAMIC_COMPLETE_COMMAND
+    {
+        return commands::handle_dynamic_complete(rest);
+    }
+
     // Match TS behavior: `--no-color` sets NO_COLOR=1 globally before command execution.
     if args.iter().any(|a| a == "--no-color") {
         // Rust 1.93+ marks `set_var` unsafe due to potential UB when racing with
@@ -67,11 +77,19 @@ pub(super) fn run(args: &[String]) -> CliResult<()> {
                 return Ok(());
             }
             _ => {
-                let rt = Runtime::new();
+                let start_dir = split_global_cwd(args)
+                    .0
+                    .and_then(|raw| resolve_start_dir(Path::new(&raw)).ok());
+                let rt = Runtime::with_start_dir(start_dir);
                 super::legacy_coordination::enforce_legacy_coordination_parse_failure_guard(&rt)?;
                 let ctx = ConfigContext::from_process_env();
-                util::maybe_log_invalid_command_early(&ctx, args, &e.to_string());
-                return fail(e.to_string());
+                util::maybe_log_invalid_command_early(
+                    &rt.invocation_dir(),
+                    &ctx,
+                    args,
+                    &e.to_string(),
+                );
+                return Err(CliError::usage(e.to_string()));
             }
         },
     };
@@ -80,7 +98,23 @@ pub(super) fn run(args: &[String]) -> CliResult<()> {
         return commands::handle_help_all_flags(false);
     }
 
-    let rt = Runtime::new();
+    // Core crates print progress through `ito_config`'s output macros; set the
+    // level before anything loads config or prints. `--json` keeps stdout and
+    // stderr free of chatter regardless of `-v`.
+    output::set_verbosity(Verbosity::resolve(
+        cli.quiet,
+        cli.verbose,
+        args.iter().any(|arg| arg == "--json"),
+    ));
+
+    let start_dir = match cli.cwd.as_deref().map(resolve_start_dir).transpose() {
+        Ok(dir) => dir,
+        Err(message) => return fail(message),
+    };
+    let rt = Runtime::with_start_dir(start_dir);
+    // Raw-argument helpers below classify commands by their positionals, so
+    // drop the global `-C/--cwd` option and its value first.
+    let args = &split_global_cwd(args).1;
 
     if let Some(command) = cli.command.as_ref()
         && let Some(result) = preflight_explicit_feature_request(&rt, command)
@@ -249,6 +283,15 @@ pub(super) fn run(args: &[String]) -> CliResult<()> {
                 || super::status::handle_status_clap(&rt, args),
             );
         }
+        Some(Commands::Workflow(args)) => {
+            return util::with_logging(
+                &rt,
+                &command_id,
+                &project_root,
+                &ito_path_for_logging,
+                || super::workflow::handle_workflow_clap(&rt, args),
+            );
+        }
         Some(Commands::Stats(args)) => {
             return util::with_logging(
                 &rt,
@@ -258,6 +301,24 @@ pub(super) fn run(args: &[String]) -> CliResult<()> {
                 || commands::handle_stats_clap(&rt, args),
             );
         }
+        Some(Commands::Models(args)) => {
+            return util::with_logging(
+                &rt,
+                &command_id,
+                &project_root,
+                &ito_path_for_logging,
+                || commands::handle_models_clap(&rt, args),
+            );
+        }
+        Some(Commands::Doctor(args)) => {
+            return util::with_logging(
+                &rt,
+                &command_id,
+                &project_root,
+                &ito_path_for_logging,
+                || commands::handle_doctor_clap(&rt, args),
+            );
+        }
         Some(Commands::Config(args)) => {
             return util::with_logging(
                 &rt,
@@ -276,6 +337,11 @@ pub(super) fn run(args: &[String]) -> CliResult<()> {
                 || commands::handle_path_clap(&rt, args),
             );
         }
+        Some(Commands::Paths(args)) => {
+            // Execution logging creates the global config dir (and its
+            // telemetry salt), which `paths` reports on; run it unlogged.
+            return commands::handle_paths_clap(&rt, args);
+        }
         Some(Commands::Worktree(args)) => {
             return util::with_logging(
                 &rt,
@@ -385,7 +451,7 @@ pub(super) fn run(args: &[String]) -> CliResult<()> {
             );
         }
         Some(Commands::Util(args)) => {
-            return commands::handle_util_clap(args);
+            return commands::handle_util_clap(&rt, args);
         }
         Some(Commands::Trace(args)) => {
             return util::with_logging(
@@ -405,6 +471,15 @@ pub(super) fn run(args: &[String]) -> CliResult<()> {
                 || super::archive::handle_archive_clap(&rt, args),
             );
         }
+        Some(Commands::Spec(args)) => {
+            return util::with_logging(
+                &rt,
+                &command_id,
+                &project_root,
+                &ito_path_for_logging,
+                || super::spec::handle_spec_clap(&rt, args),
+            );
+        }
         Some(Commands::Sync(args)) => {
             return util::with_logging(
                 &rt,
@@ -423,6 +498,33 @@ pub(super) fn run(args: &[String]) -> CliResult<()> {
                 || commands::handle_audit_clap(&rt, args),
             );
         }
+        Some(Commands::Run(args)) => {
+            return util::with_logging(
+                &rt,
+                &command_id,
+                &project_root,
+                &ito_path_for_logging,
+                || commands::handle_run_clap(&rt, args),
+            );
+        }
+        Some(Commands::Harness(args)) => {
+            return util::with_logging(
+                &rt,
+                &command_id,
+                &project_root,
+                &ito_path_for_logging,
+                || commands::handle_harness_clap(args),
+            );
+        }
+        Some(Commands::Transcripts(args)) => {
+            return util::with_logging(
+                &rt,
+                &command_id,
+                &project_root,
+                &ito_path_for_logging,
+                || commands::handle_transcripts_clap(&rt, args),
+            );
+        }
         Some(Commands::Dashboard(_)) => {
             return fail("dashboard is not implemented in ito-cli yet");
         }
@@ -445,6 +547,39 @@ pub(super) fn run(args: &[String]) -> CliResult<()> {
     )
 }
 
+/// Split the global `-C/--cwd` option out of raw arguments.
+///
+/// Returns the last value given (matching clap) and the remaining arguments.
+/// Anything after `--` is left untouched.
+fn split_global_cwd(args: &[String]) -> (Option<String>, Vec<String>) {
+    let mut cwd = None;
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--" {
+            rest.push(arg.clone());
+            rest.extend(iter.cloned());
+            break;
+        }
+        if arg == "-C" || arg == "--cwd" {
+            cwd = iter.next().cloned();
+            continue;
+        }
+        if let Some(value) = arg.strip_prefix("--cwd=") {
+            cwd = Some(value.to_string());
+            continue;
+        }
+        if let Some(value) = arg.strip_prefix("-C")
+            && !value.is_empty()
+        {
+            cwd = Some(value.trim_start_matches('=').to_string());
+            continue;
+        }
+        rest.push(arg.clone());
+    }
+    (cwd, rest)
+}
+
 fn is_recovery_safe_invocation(args: &[String]) -> bool {
     let positional = args
         .iter()
@@ -455,6 +590,7 @@ fn is_recovery_safe_invocation(args: &[String]) -> bool {
         ["help", ..]
         | ["completions", ..]
         | ["config", ..]
+        | ["doctor", ..]
         | ["init", ..]
         | ["update", ..]
         | ["serve-api", ..] => true,
@@ -463,8 +599,15 @@ fn is_recovery_safe_invocation(args: &[String]) -> bool {
         ["backend", "serve", ..] => true,
         ["backend", ..] => !cfg!(feature = "backend"),
         ["sync", ..] => !cfg!(feature = "coordination-branch"),
-        ["tasks", operation, ..]
-            if matches!(*operation, "claim" | "release" | "allocate" | "sync") =>
+        ["change" | "changes", "sync", ..] => !cfg!(feature = "coordination-branch"),
+        ["tasks", operation, ..] if matches!(*operation, "claim" | "allocate" | "sync") => {
+            !cfg!(feature = "backend")
+        }
+        // Only the change-lease form of `tasks release` is a backend operation.
+        ["tasks", "release", _]
+            if !args
+                .iter()
+                .any(|arg| arg == "--all-stale" || arg.starts_with("--older-than")) =>
         {
             !cfg!(feature = "backend")
         }
@@ -492,6 +635,13 @@ fn preflight_explicit_feature_request(rt: &Runtime, command: &Commands) -> Optio
         return Some(commands::handle_sync_clap(rt, args));
     }
 
+    #[cfg(not(feature = "coordination-branch"))]
+    if let Commands::Change(args) = command
+        && let crate::cli::ChangeCommand::Sync(sync) = &args.command
+    {
+        return Some(super::change_sync::handle_sync(rt, sync));
+    }
+
     #[cfg(not(feature = "coordination-branch"))]
     if let Commands::Agent(args) = command
         && let Some(crate::cli::AgentCommand::Instruction(instruction)) = &args.command
@@ -508,12 +658,18 @@ fn preflight_explicit_feature_request(rt: &Runtime, command: &Commands) -> Optio
         match &args.action {
             Some(
                 crate::cli::TasksAction::Claim { .. }
-                | crate::cli::TasksAction::Release { .. }
+                | crate::cli::TasksAction::Release {
+                    task_id: None,
+                    older_than: None,
+                    all_stale: false,
+                    ..
+                }
                 | crate::cli::TasksAction::Allocate
                 | crate::cli::TasksAction::Sync(_),
             ) => return Some(commands::handle_tasks_clap(rt, args)),
             Some(
-                crate::cli::TasksAction::Init { .. }
+                crate::cli::TasksAction::Release { .. }
+                | crate::cli::TasksAction::Init { .. }
                 | crate::cli::TasksAction::Status { .. }
                 | crate::cli::TasksAction::Next { .. }
                 | crate::cli::TasksAction::Ready { .. }
@@ -523,6 +679,9 @@ fn preflight_explicit_feature_request(rt: &Runtime, command: &Commands) -> Optio
                 | crate::cli::TasksAction::Unshelve { .. }
                 | crate::cli::TasksAction::Add { .. }
                 | crate::cli::TasksAction::Show { .. }
+                | crate::cli::TasksAction::Lint { .. }
+                | crate::cli::TasksAction::Plan { .. }
+                | crate::cli::TasksAction::History { .. }
                 | crate::cli::TasksAction::External(_),
             )
             | None => {}
@@ -1,12 +1,15 @@
-use crate::cli::{ShowArgs, ShowCommand, ShowItemType};
+use crate::cli::{ShowArgs, ShowCommand, ShowDiffArgs, ShowItemType, ShowTimelineArgs};
 use crate::cli_error::{CliError, CliResult, fail, to_cli_error};
 use crate::runtime::Runtime;
-use crate::util::parse_string_flag;
+use crate::util::{parse_string_flag, render_json};
 use ito_common::io::read_to_string_or_default;
 use ito_config::output;
 use ito_core::DomainError;
 use ito_core::nearest_matches;
+use ito_core::process::SystemProcessRunner;
 use ito_core::show as core_show;
+use ito_core::show::diff::FileDiffStatus;
+use ito_core::show::timeline::group_by_day;
 
 fn handle_show_specs(rt: &Runtime, want_json: bool) -> CliResult<()> {
     let runtime = rt.repository_runtime().map_err(to_cli_error)?;
@@ -25,6 +28,179 @@ fn handle_show_specs(rt: &Runtime, want_json: bool) -> CliResult<()> {
     Ok(())
 }
 
+fn handle_show_coverage(rt: &Runtime, args: &[String]) -> CliResult<()> {
+    let want_json = args.iter().any(|a| a == "--json");
+    let spec_id = super::common::last_positional(args);
+
+    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+    let repos = runtime.repositories();
+    let report = core_show::coverage::compute_spec_coverage(
+        repos.specs.as_ref(),
+        repos.changes.as_ref(),
+        spec_id.as_deref(),
+    )
+    .map_err(to_cli_error)?;
+
+    if want_json {
+        let rendered = serde_json::to_string_pretty(&report)
+            .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    for spec in &report.specs {
+        println!("{}", spec.spec_id);
+        for requirement in &spec.requirements {
+            if requirement.is_uncovered() {
+                println!("  {}  (uncovered)", requirement.title);
+                continue;
+            }
+            println!("  {}", requirement.title);
+            for r in &requirement.touched_by {
+                let archived = if r.archived { ", archived" } else { "" };
+                let possible = if r.possible_match {
+                    format!(", possible match: \"{}\"", r.title)
+                } else {
+                    String::new()
+                };
+                println!("    {} ({}{archived}{possible})", r.change_id, r.operation);
+            }
+        }
+        for r in &spec.orphaned {
+            println!(
+                "  orphaned: \"{}\" ({} in {})",
+                r.title, r.operation, r.change_id
+            );
+        }
+        println!();
+    }
+    let s = report.summary;
+    println!(
+        "{} requirements: {} covered, {} uncovered, {} orphaned references",
+        s.requirements, s.covered, s.uncovered, s.orphaned
+    );
+    Ok(())
+}
+
+fn handle_show_diff(rt: &Runtime, args: &ShowDiffArgs) -> CliResult<()> {
+    super::common::check_layout(rt, &[])?;
+    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+    let diff = core_show::diff::diff_changes(
+        runtime.repositories().changes.as_ref(),
+        rt.ctx(),
+        &args.change_a,
+        &args.change_b,
+        args.artifact.as_deref(),
+    )
+    .map_err(to_cli_error)?;
+
+    if args.json {
+        let rendered = serde_json::to_string_pretty(&diff)
+            .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!("A: {}", diff.change_a);
+    println!("B: {}", diff.change_b);
+    println!();
+    println!("Artifacts:");
+    let width = diff.artifacts.iter().map(|a| a.id.len()).max().unwrap_or(0);
+    for artifact in &diff.artifacts {
+        let a = if artifact.in_a { "A" } else { "-" };
+        let b = if artifact.in_b { "B" } else { "-" };
+        println!("  {:<width$}  {a} {b}", artifact.id);
+    }
+
+    for file in diff.artifacts.iter().flat_map(|a| &a.files) {
+        match file.status {
+            FileDiffStatus::Identical => {}
+            FileDiffStatus::OnlyInA => println!("\nonly in A: {}", file.path),
+            FileDiffStatus::OnlyInB => println!("\nonly in B: {}", file.path),
+            FileDiffStatus::Changed => {
+                println!();
+                if let Some(patch) = &file.diff {
+                    print!("{patch}");
+                } else {
+                    let note = file.note.as_deref().unwrap_or("not diffed");
+                    println!("{}: {note}", file.path);
+                }
+            }
+        }
+    }
+
+    let tasks = &diff.tasks;
+    println!();
+    if tasks.only_in_a.is_empty() && tasks.only_in_b.is_empty() && tasks.changed.is_empty() {
+        println!("Tasks: no differences");
+        return Ok(());
+    }
+    println!("Tasks:");
+    for t in &tasks.only_in_a {
+        println!("  only in A: {} {} ({})", t.id, t.name, t.status);
+    }
+    for t in &tasks.only_in_b {
+        println!("  only in B: {} {} ({})", t.id, t.name, t.status);
+    }
+    for t in &tasks.changed {
+        if t.a.name != t.b.name {
+            println!("  renamed:   {} {} -> {}", t.id, t.a.name, t.b.name);
+        }
+        if t.a.status != t.b.status {
+            println!(
+                "  status:    {} {}: {} -> {}",
+                t.id, t.b.name, t.a.status, t.b.status
+            );
+        }
+    }
+    Ok(())
+}
+
+fn handle_show_timeline(rt: &Runtime, args: &ShowTimelineArgs) -> CliResult<()> {
+    super::common::check_layout(rt, &[])?;
+    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+    let audit_store = ito_core::audit::default_audit_store(rt.ito_path());
+    let timeline = core_show::timeline::change_timeline(
+        runtime.repositories().changes.as_ref(),
+        audit_store.as_ref(),
+        &SystemProcessRunner,
+        rt.ito_path(),
+        &args.change_id,
+        args.commits,
+    )
+    .map_err(to_cli_error)?;
+
+    if args.json {
+        let rendered = render_json(&timeline.entries)
+            .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if timeline.entries.is_empty() {
+        println!("No timeline entries for '{}'.", timeline.change_id);
+        return Ok(());
+    }
+    println!("Timeline for {}", timeline.change_id);
+    for (day, entries) in group_by_day(&timeline.entries) {
+        println!();
+        println!("{}", day.format("%Y-%m-%d"));
+        for entry in entries {
+            println!(
+                "  {}  {:<6}  {} ({})",
+                entry.timestamp.format("%H:%M:%S"),
+                entry.kind.as_str(),
+                entry.summary,
+                super::list::format_relative_time(entry.timestamp)
+            );
+            if !entry.details.is_empty() {
+                println!("                    {}", entry.details);
+            }
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn handle_show(rt: &Runtime, args: &[String]) -> CliResult<()> {
     if args.iter().any(|a| a == "--help" || a == "-h") {
         println!(
@@ -33,6 +209,7 @@ pub(crate) fn handle_show(rt: &Runtime, args: &[String]) -> CliResult<()> {
         );
         return Ok(());
     }
+    super::common::check_layout(rt, &[])?;
 
     // Parse subcommand: `ito show specs`
     if args.first().map(|s| s.as_str()) == Some("specs") {
@@ -40,6 +217,11 @@ pub(crate) fn handle_show(rt: &Runtime, args: &[String]) -> CliResult<()> {
         return handle_show_specs(rt, want_json);
     }
 
+    // Parse subcommand: `ito show coverage [spec-id]`
+    if args.first().map(|s| s.as_str()) == Some("coverage") {
+        return handle_show_coverage(rt, &args[1..]);
+    }
+
     // Parse subcommand: `ito show module <id>`
     if args.first().map(|s| s.as_str()) == Some("module") {
         return handle_show_module(rt, &args[1..]);
@@ -65,9 +247,10 @@ pub(crate) fn handle_show(rt: &Runtime, args: &[String]) -> CliResult<()> {
 
     let requirements = args.iter().any(|a| a == "--requirements");
     let scenarios = !args.iter().any(|a| a == "--no-scenarios");
-    let requirement_idx = parse_string_flag(args, "--requirement")
-        .or_else(|| parse_string_flag(args, "-r"))
-        .and_then(|s| s.parse::<usize>().ok());
+    let requirement_selector =
+        parse_string_flag(args, "--requirement").or_else(|| parse_string_flag(args, "-r"));
+    let preview_spec = parse_string_flag(args, "--preview-spec");
+    let exact = args.iter().any(|a| a == "--exact");
 
     let item = super::common::last_positional(args);
     if item.is_none() {
@@ -119,7 +302,7 @@ pub(crate) fn handle_show(rt: &Runtime, args: &[String]) -> CliResult<()> {
             requirements_only,
             requirements,
             scenarios,
-            requirement_idx,
+            requirement_selector.is_some(),
         );
         if !ignored.is_empty() {
             eprintln!(
@@ -131,10 +314,13 @@ pub(crate) fn handle_show(rt: &Runtime, args: &[String]) -> CliResult<()> {
 
     match resolved_type.as_str() {
         "spec" => {
+            if preview_spec.is_some() {
+                return fail("--preview-spec only applies to changes");
+            }
             let md = core_show::read_spec_markdown_from_repository(spec_repo, &item)
                 .map_err(|e| CliError::msg(format!("Spec '{item}' not found: {e}")))?;
             if want_json {
-                if requirements && requirement_idx.is_some() {
+                if requirements && requirement_selector.is_some() {
                     return fail("Cannot use --requirement with --requirements");
                 }
                 let mut json = core_show::parse_spec_show_json(&item, &md);
@@ -145,33 +331,39 @@ pub(crate) fn handle_show(rt: &Runtime, args: &[String]) -> CliResult<()> {
                         r.scenarios.clear();
                     }
                 }
-                if let Some(one_based) = requirement_idx {
-                    if one_based == 0 || one_based > json.requirements.len() {
-                        return fail(format!(
-                            "Requirement index out of range. Expected 1..={}",
-                            json.requirements.len()
-                        ));
-                    }
-                    json.requirements = vec![json.requirements[one_based - 1].clone()];
+                if let Some(selector) = &requirement_selector {
+                    let selected = core_show::select_requirement(&json, selector)
+                        .map_err(to_cli_error)?
+                        .clone();
+                    json.requirements = vec![selected];
                     json.requirement_count = json.requirements.len() as u32;
                 }
                 let rendered = serde_json::to_string_pretty(&json)
                     .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
                 println!("{rendered}");
+            } else if let Some(selector) = &requirement_selector {
+                let json = core_show::parse_spec_show_json(&item, &md);
+                let selected =
+                    core_show::select_requirement(&json, selector).map_err(to_cli_error)?;
+                print!("{}", core_show::render_requirement_markdown(selected));
             } else {
                 print!("{md}");
             }
             Ok(())
         }
         "change" => {
-            let resolved_change = match super::common::resolve_change_target(change_repo, &item) {
-                Ok(id) => id,
-                Err(msg) => return fail(msg),
-            };
+            let resolved_change =
+                super::change_target::resolve_change_target_with(change_repo, &item, exact)?;
+            if let Some(spec) = &preview_spec {
+                return handle_show_spec_preview(rt, &resolved_change, spec, want_json);
+            }
             if want_json {
                 let files = core_show::read_change_delta_spec_files(change_repo, &resolved_change)
                     .unwrap_or_default();
-                let json = core_show::parse_change_show_json(&resolved_change, &files);
+                let mut json = core_show::parse_change_show_json(&resolved_change, &files);
+                core_show::link_delta_base_anchors(&mut json, spec_repo);
+                json.metadata = core_show::read_change_metadata(change_repo, &resolved_change)
+                    .map_err(to_cli_error)?;
                 let rendered = serde_json::to_string_pretty(&json)
                     .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
                 println!("{rendered}");
@@ -187,7 +379,53 @@ pub(crate) fn handle_show(rt: &Runtime, args: &[String]) -> CliResult<()> {
     }
 }
 
+/// Print how a spec would look after archiving a change: the diff against the
+/// current spec and any delta operations that conflict with it.
+fn handle_show_spec_preview(
+    rt: &Runtime,
+    change_id: &str,
+    spec: &str,
+    want_json: bool,
+) -> CliResult<()> {
+    let preview = ito_core::archive::preview_change_spec(rt.ito_path(), change_id, spec)
+        .map_err(to_cli_error)?;
+
+    if want_json {
+        let rendered = serde_json::to_string_pretty(&serde_json::json!({
+            "change": change_id,
+            "spec": spec,
+            "merged": &preview.merged,
+            "conflicts": &preview.conflicts,
+            "diff": &preview.diff,
+        }))
+        .map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if preview.merged.is_none() && preview.conflicts.is_empty() {
+        println!("Archiving '{change_id}' retires specs/{spec}/spec.md.");
+    } else if preview.diff.is_empty() {
+        println!("Archiving '{change_id}' leaves specs/{spec}/spec.md unchanged.");
+    }
+    print!("{}", preview.diff);
+    if !preview.conflicts.is_empty() {
+        println!();
+        println!("Conflicts (skipped; archive refuses without --force):");
+        for conflict in &preview.conflicts {
+            println!(
+                "  - {} '{}': {}",
+                conflict.operation, conflict.requirement, conflict.message
+            );
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn handle_show_clap(rt: &Runtime, args: &ShowArgs) -> CliResult<()> {
+    if let Some(rev) = &args.at {
+        rt.pin_revision(rev).map_err(to_cli_error)?;
+    }
     let mut argv: Vec<String> = Vec::new();
 
     if args.json {
@@ -216,9 +454,16 @@ pub(crate) fn handle_show_clap(rt: &Runtime, args: &ShowArgs) -> CliResult<()> {
     if args.no_scenarios {
         argv.push("--no-scenarios".to_string());
     }
-    if let Some(idx) = args.requirement {
+    if let Some(selector) = &args.requirement {
         argv.push("--requirement".to_string());
-        argv.push(idx.to_string());
+        argv.push(selector.clone());
+    }
+    if let Some(spec) = &args.preview_spec {
+        argv.push("--preview-spec".to_string());
+        argv.push(spec.clone());
+    }
+    if args.exact {
+        argv.push("--exact".to_string());
     }
 
     match &args.command {
@@ -245,6 +490,18 @@ pub(crate) fn handle_show_clap(rt: &Runtime, args: &ShowArgs) -> CliResult<()> {
             }
             return handle_show(rt, &argv);
         }
+        Some(ShowCommand::Diff(d)) => return handle_show_diff(rt, d),
+        Some(ShowCommand::Timeline(t)) => return handle_show_timeline(rt, t),
+        Some(ShowCommand::Coverage(c)) => {
+            argv.push("coverage".to_string());
+            if c.json {
+                argv.push("--json".to_string());
+            }
+            if let Some(spec_id) = &c.spec_id {
+                argv.push(spec_id.clone());
+            }
+            return handle_show(rt, &argv);
+        }
         None => {}
     }
 
@@ -261,7 +518,7 @@ fn ignored_show_flags(
     requirements_only: bool,
     requirements: bool,
     scenarios: bool,
-    requirement_idx: Option<usize>,
+    requirement_selected: bool,
 ) -> Vec<&'static str> {
     let mut out: Vec<&'static str> = Vec::new();
     if typ == "spec" {
@@ -279,7 +536,7 @@ fn ignored_show_flags(
         if requirements {
             out.push("requirements");
         }
-        if requirement_idx.is_some() {
+        if requirement_selected {
             out.push("requirement");
         }
     }
@@ -0,0 +1,114 @@
+use std::path::Path;
+
+use ito_core::audit::{Actor, AuditEventBuilder, EntityType, ops};
+use ito_core::spec_rename::{SpecRenamePlan, apply_spec_rename, plan_spec_rename};
+
+use crate::cli::{SpecArgs, SpecCommand, SpecRenameArgs};
+use crate::cli_error::{CliResult, to_cli_error};
+use crate::runtime::Runtime;
+
+pub(crate) fn handle_spec_clap(rt: &Runtime, args: &SpecArgs) -> CliResult<()> {
+    match &args.command {
+        SpecCommand::Rename(args) => handle_rename(rt, args),
+    }
+}
+
+fn handle_rename(rt: &Runtime, args: &SpecRenameArgs) -> CliResult<()> {
+    let ito_path = rt.ito_path();
+    let plan = plan_spec_rename(ito_path, &args.old_id, &args.new_id).map_err(to_cli_error)?;
+    let root = ito_path.parent().unwrap_or(ito_path);
+
+    if args.dry_run {
+        print!("{}", render_plan(&plan, root, true));
+        return Ok(());
+    }
+
+    if let Some(event) = AuditEventBuilder::new()
+        .entity(EntityType::Spec)
+        .entity_id(&plan.old_id)
+        .op(ops::SPEC_RENAME)
+        .from(&plan.old_id)
+        .to(&plan.new_id)
+        .actor(Actor::Cli)
+        .by(rt.user_identity())
+        .meta(serde_json::json!({
+            "changes": plan.affected_changes(),
+            "archived_references": plan.archived_references,
+        }))
+        .ctx(rt.event_context().clone())
+        .build()
+    {
+        rt.emit_audit_event(&event);
+    }
+    for change_id in plan.affected_changes() {
+        if let Some(event) = AuditEventBuilder::new()
+            .entity(EntityType::Change)
+            .entity_id(&change_id)
+            .op(ops::CHANGE_SPEC_RENAMED)
+            .from(&plan.old_id)
+            .to(&plan.new_id)
+            .actor(Actor::Cli)
+            .by(rt.user_identity())
+            .ctx(rt.event_context().clone())
+            .build()
+        {
+            rt.emit_audit_event(&event);
+        }
+    }
+
+    apply_spec_rename(&plan).map_err(to_cli_error)?;
+    print!("{}", render_plan(&plan, root, false));
+    Ok(())
+}
+
+fn render_plan(plan: &SpecRenamePlan, root: &Path, dry_run: bool) -> String {
+    let display = |path: &Path| {
+        path.strip_prefix(root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+    let (moved, edited) = if dry_run {
+        ("Would move", "Would update")
+    } else {
+        ("Moved", "Updated")
+    };
+
+    let mut out = if dry_run {
+        format!(
+            "Dry run: renaming spec '{}' to '{}' would make these changes:\n",
+            plan.old_id, plan.new_id
+        )
+    } else {
+        format!("✔ Renamed spec '{}' to '{}'\n", plan.old_id, plan.new_id)
+    };
+    for mv in &plan.moves {
+        out.push_str(&format!(
+            "  {moved} {} -> {}\n",
+            display(&mv.from),
+            display(&mv.to)
+        ));
+    }
+    for edit in &plan.edits {
+        let noun = if edit.occurrences == 1 {
+            "reference"
+        } else {
+            "references"
+        };
+        out.push_str(&format!(
+            "  {edited} {} ({} {noun})\n",
+            display(&edit.path),
+            edit.occurrences
+        ));
+    }
+    if !plan.archived_references.is_empty() {
+        out.push_str(&format!(
+            "Archived changes referencing '{}' were left untouched:\n",
+            plan.old_id
+        ));
+        for name in &plan.archived_references {
+            out.push_str(&format!("  - {name}\n"));
+        }
+    }
+    out
+}
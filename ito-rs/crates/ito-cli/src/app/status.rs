@@ -1,7 +1,9 @@
 use crate::cli::StatusArgs;
-use crate::cli_error::{CliResult, fail, to_cli_error};
+use crate::cli_error::{CliError, CliResult, fail, to_cli_error};
 use crate::runtime::Runtime;
-use crate::util::parse_string_flag;
+use crate::ui::{Tone, Ui, status_tone};
+use crate::util::{parse_string_flag, render_json};
+use ito_core::project_layout::CHANGES_SUBDIR;
 use ito_core::templates as core_templates;
 
 pub(crate) fn handle_status(rt: &Runtime, args: &[String]) -> CliResult<()> {
@@ -13,6 +15,9 @@ pub(crate) fn handle_status(rt: &Runtime, args: &[String]) -> CliResult<()> {
         return Ok(());
     }
 
+    let warnings = super::common::check_layout(rt, &[CHANGES_SUBDIR])?;
+    super::common::print_layout_warnings(&warnings);
+
     let want_json = args.iter().any(|a| a == "--json");
     let change = parse_string_flag(args, "--change");
     if change.as_deref().unwrap_or("").is_empty() {
@@ -34,33 +39,44 @@ pub(crate) fn handle_status(rt: &Runtime, args: &[String]) -> CliResult<()> {
     let runtime = rt.repository_runtime().map_err(to_cli_error)?;
     let change_repo = runtime.repositories().changes.as_ref();
 
+    // Resolve first so an ambiguous target reports the same listing as every
+    // other command, without the spinner line in front of it.
+    let change = change.expect("checked above");
+    let change = super::change_target::resolve_change_target(change_repo, &change)?;
+
     // Match TS/ora: spinner output is written to stderr.
     eprintln!("- Loading change status...");
-
-    let change = change.expect("checked above");
-    let change = match super::common::resolve_change_target(change_repo, &change) {
-        Ok(resolved) => resolved,
-        Err(msg) => return fail(msg),
+    let status = match runtime.revision() {
+        Some(fs) => ito_core::revision::compute_change_status_at(
+            fs,
+            ito_path,
+            &change,
+            schema.as_deref(),
+            ctx,
+        ),
+        None => core_templates::compute_change_status(ito_path, &change, schema.as_deref(), ctx),
+    };
+    let status = match status {
+        Ok(s) => s,
+        Err(core_templates::TemplatesError::InvalidChangeName) => {
+            return fail("Invalid change name");
+        }
+        Err(core_templates::TemplatesError::ChangeNotFound(name)) => {
+            return Err(CliError::not_found(
+                "CHANGE_NOT_FOUND",
+                format!("Change '{name}' not found"),
+            ));
+        }
+        Err(core_templates::TemplatesError::SchemaNotFound(name)) => {
+            return fail(super::common::schema_not_found_message(ctx, &name));
+        }
+        Err(e) => {
+            return Err(to_cli_error(e));
+        }
     };
-    let status =
-        match core_templates::compute_change_status(ito_path, &change, schema.as_deref(), ctx) {
-            Ok(s) => s,
-            Err(core_templates::TemplatesError::InvalidChangeName) => {
-                return fail("Invalid change name");
-            }
-            Err(core_templates::TemplatesError::ChangeNotFound(name)) => {
-                return fail(format!("Change '{name}' not found"));
-            }
-            Err(core_templates::TemplatesError::SchemaNotFound(name)) => {
-                return fail(super::common::schema_not_found_message(ctx, &name));
-            }
-            Err(e) => {
-                return Err(to_cli_error(e));
-            }
-        };
 
     if want_json {
-        let rendered = serde_json::to_string_pretty(&status).expect("json should serialize");
+        let rendered = render_json(&status).expect("json should serialize");
         println!("{rendered}");
         return Ok(());
     }
@@ -73,43 +89,67 @@ pub(crate) fn handle_status(rt: &Runtime, args: &[String]) -> CliResult<()> {
     let done = status
         .artifacts
         .iter()
-        .filter(|a| a.status == "done")
+        .filter(|a| a.status == "done" || a.status == "skipped")
         .count();
 
-    println!("Change: {}", status.change_name);
-    println!("Schema: {}", status.schema_name);
-    println!("Progress: {done}/{total} artifacts complete\n");
+    let ui = Ui::stdout();
+    println!("{}", ui.kv("Change", &status.change_name));
+    println!("{}", ui.kv("Schema", &status.schema_name));
+    println!(
+        "{}\n",
+        ui.kv("Progress", format!("{done}/{total} artifacts complete"))
+    );
     for a in &status.artifacts {
         let mark = if a.status == "done" {
             "[x]"
         } else if a.status == "optional" {
             "[~]"
+        } else if a.status == "skipped" {
+            "[s]"
         } else if a.status == "blocked" {
             "[-]"
         } else {
             "[ ]"
         };
+        let mark = ui.paint(mark, status_tone(&a.status));
 
         if a.status == "blocked" && !a.missing_deps.is_empty() {
             println!(
-                "{mark} {} (blocked by: {})",
+                "{mark} {} {}",
                 a.id,
-                a.missing_deps.join(", ")
+                ui.paint(
+                    &format!("(blocked by: {})", a.missing_deps.join(", ")),
+                    Tone::Muted
+                )
             );
         } else if a.status == "optional" {
-            println!("{mark} {} (optional)", a.id);
+            println!("{mark} {} {}", a.id, ui.paint("(optional)", Tone::Muted));
+        } else if a.status == "skipped" {
+            let note = match &a.skip_reason {
+                Some(reason) => format!("(skipped: {reason})"),
+                None => "(skipped)".to_string(),
+            };
+            println!("{mark} {} {}", a.id, ui.paint(&note, Tone::Muted));
         } else {
             println!("{mark} {}", a.id);
         }
     }
     if status.is_complete {
-        println!("\nAll required artifacts complete!");
+        println!(
+            "\n{}",
+            ui.paint("All required artifacts complete!", Tone::Success)
+        );
     }
+    let next = core_templates::select_next_step(&status);
+    println!("\n{}", core_templates::next_step_hint(&next));
 
     Ok(())
 }
 
 pub(crate) fn handle_status_clap(rt: &Runtime, args: &StatusArgs) -> CliResult<()> {
+    if let Some(rev) = &args.at {
+        rt.pin_revision(rev).map_err(to_cli_error)?;
+    }
     let mut argv: Vec<String> = Vec::new();
     if let Some(change) = &args.change {
         argv.push("--change".to_string());
@@ -58,10 +58,7 @@ pub(crate) fn handle_trace_clap(rt: &Runtime, args: &TraceArgs) -> CliResult<()>
     let runtime = rt.repository_runtime().map_err(to_cli_error)?;
     let change_repo = runtime.repositories().changes.as_ref();
 
-    let actual = match super::common::resolve_change_target(change_repo, change_input) {
-        Ok(id) => id,
-        Err(msg) => return fail(msg),
-    };
+    let actual = super::change_target::resolve_change_target(change_repo, change_input)?;
 
     let output = compute_trace_output(change_repo, &actual).map_err(to_cli_error)?;
 
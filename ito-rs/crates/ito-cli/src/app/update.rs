@@ -6,9 +6,12 @@ use crate::app::worktree_wizard::{
 use crate::cli::UpdateArgs;
 use crate::cli_error::{CliResult, to_cli_error};
 use crate::runtime::Runtime;
+use crate::util::parse_string_flag;
 use ito_config::ito_dir;
 use ito_config::output;
 use ito_core::installers::{InitOptions, InstallMode, install_default_templates};
+use ito_core::template_bundle;
+use ito_templates::asset_source::AssetSource;
 use ito_templates::project_templates::WorktreeTemplateContext;
 use std::collections::BTreeSet;
 use std::io::IsTerminal;
@@ -25,8 +28,10 @@ pub(super) fn handle_update(rt: &Runtime, args: &[String]) -> CliResult<()> {
     // `--json` is accepted for parity with TS but not implemented yet.
     let _want_json = args.iter().any(|a| a == "--json");
     let worktree_overrides = parse_worktree_overrides(args)?;
-    let target = super::common::last_positional(args).unwrap_or_else(|| ".".to_string());
-    let target_path = std::path::Path::new(&target);
+    let target = super::common::last_positional(args)
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| rt.default_target_dir());
+    let target_path = target.as_path();
     let ctx = rt.ctx();
 
     // Resolve worktree config BEFORE template installation.
@@ -43,11 +48,20 @@ pub(super) fn handle_update(rt: &Runtime, args: &[String]) -> CliResult<()> {
     let (worktree_ctx, post_install_save) =
         resolve_update_worktree_config(ctx, target_path, is_interactive, &worktree_overrides)?;
 
-    let tools: BTreeSet<String> = ito_core::installers::available_tool_ids()
-        .iter()
-        .map(|s| (*s).to_string())
-        .collect();
-    let opts = InitOptions::new(tools, false, true);
+    // Without --tools, refresh the adapters recorded by init so updates never
+    // silently drop (or add) tool integrations.
+    let ito_path = ito_dir::get_ito_path(target_path, ctx);
+    let tools_arg = parse_string_flag(args, "--tools");
+    let tools: BTreeSet<String> = match tools_arg.as_deref() {
+        Some(raw) => ito_core::installers::parse_tool_selection(raw).map_err(to_cli_error)?,
+        None => ito_core::installers::resolve_update_tools(target_path, &ito_path),
+    };
+    let force_managed = args.iter().any(|a| a == "--force-managed");
+    let channel = parse_string_flag(args, "--channel");
+    let asset_source = resolve_asset_source(ctx, target_path, &ito_path, channel.as_deref())?;
+    let opts = InitOptions::new(tools, false, true)
+        .with_force_managed(force_managed)
+        .with_asset_source(asset_source);
 
     install_default_templates(
         target_path,
@@ -57,6 +71,16 @@ pub(super) fn handle_update(rt: &Runtime, args: &[String]) -> CliResult<()> {
         Some(&worktree_ctx),
     )
     .map_err(to_cli_error)?;
+    if tools_arg.is_some() {
+        ito_core::installers::record_tools(&ito_path, &opts.tools).map_err(to_cli_error)?;
+    }
+    let audit = ito_core::audit::default_audit_store(&ito_path);
+    ito_core::installers::record_install_event(
+        Some(&*audit),
+        &ito_path,
+        InstallMode::Update,
+        &opts,
+    );
 
     if let Some((path, result)) = post_install_save {
         save_worktree_config(&path, &result)?;
@@ -65,6 +89,46 @@ pub(super) fn handle_update(rt: &Runtime, args: &[String]) -> CliResult<()> {
     Ok(())
 }
 
+/// Pick the template assets to install: the `--channel` release, then the
+/// `templates.pin` release, then the assets embedded in this binary.
+///
+/// A pinned release that cannot be downloaded (and is not cached yet) falls
+/// back to the embedded assets with a warning; a bundle that fails checksum
+/// verification aborts the update.
+fn resolve_asset_source(
+    ctx: &ito_config::ConfigContext,
+    target_path: &std::path::Path,
+    ito_path: &std::path::Path,
+    channel: Option<&str>,
+) -> CliResult<AssetSource> {
+    let tag = match channel {
+        Some(raw) => Some(template_bundle::normalize_tag(raw).map_err(to_cli_error)?),
+        None => {
+            template_bundle::configured_pin(target_path, ito_path, ctx).map_err(to_cli_error)?
+        }
+    };
+    let Some(tag) = tag else {
+        return Ok(AssetSource::Embedded);
+    };
+    let Some(cache_root) = template_bundle::bundle_cache_root(ctx) else {
+        ito_config::notice!(
+            "warning: no global config directory to cache templates {tag}; using the templates embedded in this binary"
+        );
+        return Ok(AssetSource::Embedded);
+    };
+
+    let resolved = template_bundle::resolve_pinned_source(
+        &cache_root,
+        &tag,
+        &template_bundle::bundle_base_url(),
+    )
+    .map_err(to_cli_error)?;
+    if let Some(warning) = &resolved.warning {
+        ito_config::notice!("warning: {warning}");
+    }
+    Ok(resolved.source)
+}
+
 /// Determine the worktree template context for the `ito update` flow and whether a
 /// per-developer (project-local) worktree result should be saved.
 ///
@@ -106,7 +170,7 @@ fn resolve_update_worktree_config(
     Option<(std::path::PathBuf, WorktreeWizardResult)>,
 )> {
     let ito_path = ito_dir::get_ito_path(target_path, ctx);
-    let project_config_path = ito_path.join("config.json");
+    let project_config_path = ito_config::ito_dir_config_path(&ito_path);
     let project_local_config_path = ito_path.join("config.local.json");
     let global_config_path = ito_config::global_config_path(ctx);
 
@@ -220,6 +284,17 @@ pub(crate) fn handle_update_clap(rt: &Runtime, args: &UpdateArgs) -> CliResult<(
     if args.json {
         argv.push("--json".to_string());
     }
+    if let Some(tools) = &args.tools {
+        argv.push("--tools".to_string());
+        argv.push(tools.clone());
+    }
+    if args.force_managed {
+        argv.push("--force-managed".to_string());
+    }
+    if let Some(channel) = &args.channel {
+        argv.push("--channel".to_string());
+        argv.push(channel.clone());
+    }
     if args.worktrees {
         argv.push("--worktrees".to_string());
     }
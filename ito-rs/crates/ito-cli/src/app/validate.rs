@@ -1,11 +1,14 @@
 //! CLI adapter for validation commands.
 
 use crate::cli::{ValidateArgs, ValidateCommand, ValidateItemType};
-use crate::cli_error::{CliResult, fail, silent_fail, to_cli_error};
+use crate::cli_error::{CliError, CliResult, ErrorCategory, fail, to_cli_error};
 use crate::runtime::Runtime;
-use crate::util::parse_string_flag;
+use crate::ui::{Cell, Column, Table, Tone, Ui, status_tone};
+use crate::util::{parse_string_flag, render_json};
 use ito_core::audit;
 use ito_core::nearest_matches;
+use ito_core::process::SystemProcessRunner;
+use ito_core::project_layout::{CHANGES_SUBDIR, MODULES_SUBDIR, skipped_entry_warnings};
 use ito_core::templates;
 use ito_core::validate as core_validate;
 use std::collections::BTreeSet;
@@ -23,6 +26,10 @@ fn format_issue_loc(i: &core_validate::ValidationIssue) -> String {
     out
 }
 
+fn format_issue_level(ui: &Ui, level: &str) -> String {
+    ui.paint(&format!("[{level}]"), status_tone(level))
+}
+
 pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
     if args.iter().any(|a| a == "--help" || a == "-h") {
         println!(
@@ -40,7 +47,14 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
     let strict = args.iter().any(|a| a == "--strict");
     let want_audit_only = args.iter().any(|a| a == "--audit");
     let skip_audit = args.iter().any(|a| a == "--no-audit");
+    let want_fix = args.iter().any(|a| a == "--fix");
     let typ = parse_string_flag(args, "--type");
+    let want_list_rules = args.iter().any(|a| a == "--list-rules");
+    let artifact_options = core_validate::ArtifactValidationOptions {
+        run_commands: !args.iter().any(|a| a == "--no-artifact-commands"),
+        trust_non_project_schemas: args.iter().any(|a| a == "--trust-embedded"),
+        ..Default::default()
+    };
     #[allow(clippy::match_like_matches_macro)]
     let bulk = args.iter().any(|a| {
         let arg = a.as_str();
@@ -53,6 +67,12 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
         }
     });
 
+    let (custom_rules, custom_rule_warnings) = load_custom_rules(rt);
+    print_custom_rule_warnings(&custom_rule_warnings);
+    if want_list_rules {
+        return list_rules(&custom_rules, want_json);
+    }
+
     let item = super::common::last_positional(args);
     if item.is_none() && !bulk {
         return fail(
@@ -65,17 +85,35 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
     let change_repo = runtime.repositories().changes.as_ref();
     let module_repo = runtime.repositories().modules.as_ref();
     let spec_repo = runtime.repositories().specs.as_ref();
+    let is_filesystem = runtime.mode() == ito_core::repository_runtime::PersistenceMode::Filesystem;
+    if want_fix && !is_filesystem {
+        return fail("--fix requires filesystem persistence");
+    }
 
     if bulk {
         let repo_index = rt.repo_index();
-        let is_filesystem =
-            runtime.mode() == ito_core::repository_runtime::PersistenceMode::Filesystem;
+        if is_filesystem {
+            let warnings = skipped_entry_warnings(ito_path, &[CHANGES_SUBDIR, MODULES_SUBDIR]);
+            super::common::print_layout_warnings(&warnings);
+        }
 
         let want_all = args.iter().any(|a| a == "--all");
         let want_changes = want_all || args.iter().any(|a| a == "--changes");
         let want_specs = want_all || args.iter().any(|a| a == "--specs");
         let want_modules = want_all || args.iter().any(|a| a == "--modules");
 
+        let fixes = if want_fix {
+            Some(super::validate_fix::fix_bulk(
+                module_repo,
+                ito_path,
+                want_changes,
+                want_specs,
+                want_modules,
+            )?)
+        } else {
+            None
+        };
+
         #[derive(serde::Serialize)]
         struct Item {
             id: String,
@@ -96,7 +134,9 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 .into_iter()
                 .map(|m| m.id)
                 .collect();
-            let change_summaries = change_repo.list().unwrap_or_default();
+            let mut change_summaries = change_repo.list().unwrap_or_default();
+            // Remote backends do not guarantee listing order.
+            change_summaries.sort_by(|a, b| a.id.cmp(&b.id));
             let repo_integrity = if is_filesystem {
                 core_validate::validate_change_dirs_repo_integrity(ito_path).unwrap_or_default()
             } else {
@@ -158,11 +198,22 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
                     issues.extend(task_issues);
                 }
 
+                if is_filesystem {
+                    issues.extend(core_validate::validate_change_artifacts(
+                        ito_path,
+                        &dir_name,
+                        &SystemProcessRunner,
+                        &artifact_options,
+                    ));
+                }
+
                 // Audit consistency check (warnings only)
                 if is_filesystem && !skip_audit {
                     issues.extend(validate_audit_consistency(ito_path, &dir_name));
                 }
 
+                issues.extend(custom_change_issues(&custom_rules, change_repo, &dir_name));
+
                 let merged = if want_audit_only {
                     // --audit: only include audit issues
                     issues
@@ -198,6 +249,13 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
                             strict,
                         )
                     });
+                let report = with_extra_issues(
+                    report,
+                    custom_rules
+                        .check_spec(ito_path, &spec_id)
+                        .unwrap_or_default(),
+                    strict,
+                );
                 items.push(Item {
                     id: spec_id,
                     typ: "spec".to_string(),
@@ -224,6 +282,13 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
                                 ),
                             )
                         });
+                let report = with_extra_issues(
+                    report,
+                    custom_rules
+                        .check_module(module_repo, ito_path, &m)
+                        .unwrap_or_default(),
+                    strict,
+                );
 
                 items.push(Item {
                     id: m,
@@ -235,6 +300,19 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
             }
         }
 
+        // Cross-module dependency checks (missing modules, cycles).
+        if want_modules && is_filesystem {
+            let report = core_validate::validate_module_graph(ito_path);
+            let report = core_validate::ValidationReport::new(report.issues, strict);
+            items.push(Item {
+                id: "module-graph".to_string(),
+                typ: "module-graph".to_string(),
+                valid: report.valid,
+                issues: report.issues,
+                duration_ms: 1,
+            });
+        }
+
         let passed = items.iter().filter(|i| i.valid).count() as u32;
         let failed = items.len() as u32 - passed;
 
@@ -261,6 +339,8 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
             struct Envelope {
                 items: Vec<Item>,
                 summary: Summary,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                fixes: Option<core_validate::FixReport>,
                 version: &'static str,
             }
 
@@ -290,39 +370,57 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
                     },
                     by_type,
                 },
+                fixes,
                 version: "1.0",
             };
-            let rendered = serde_json::to_string_pretty(&env).expect("json should serialize");
+            let rendered = render_json(&env).expect("json should serialize");
             println!("{rendered}");
             if failed > 0 {
-                return silent_fail();
+                return validation_failed();
             }
             return Ok(());
         }
 
+        if let Some(fixes) = &fixes {
+            super::validate_fix::print_fix_report(fixes);
+        }
         if failed == 0 {
-            println!("All items valid ({passed} checked)");
+            let ui = Ui::stdout();
+            println!(
+                "{}",
+                ui.paint(
+                    &format!("All items valid ({passed} checked)"),
+                    Tone::Success
+                )
+            );
             return Ok(());
         }
+        let ui = Ui::stderr();
         eprintln!(
-            "Validation failed: {failed} of {} items invalid",
-            passed + failed
+            "{}",
+            ui.paint(
+                &format!(
+                    "Validation failed: {failed} of {} items invalid",
+                    passed + failed
+                ),
+                Tone::Error
+            )
         );
         for it in &items {
             if it.valid {
                 continue;
             }
-            eprintln!("- {} {} has issues", it.typ, it.id);
+            eprintln!("- {} {} has issues", it.typ, ui.heading(&it.id));
             for issue in &it.issues {
                 eprintln!(
-                    "  - [{}] {}: {}",
-                    issue.level,
+                    "  - {} {}: {}",
+                    format_issue_level(&ui, &issue.level),
                     format_issue_loc(issue),
                     issue.message
                 );
             }
         }
-        return silent_fail();
+        return validation_failed();
     }
 
     let item = item.expect("checked");
@@ -331,49 +429,69 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
     let resolved_type = match explicit {
         Some("change") | Some("spec") | Some("module") => explicit.unwrap().to_string(),
         Some(_) => {
-            return fail("Invalid type. Expected 'change', 'spec', or 'module'.");
+            return Err(CliError::usage(
+                "Invalid type. Expected 'change', 'spec', or 'module'.",
+            ));
         }
         None => super::common::detect_item_type(change_repo, spec_repo, &item),
     };
 
     // Special-case: TS `--type module <id>` behaves like validating a spec by id.
     if resolved_type == "module" {
+        let fixes = if want_fix {
+            Some(super::validate_fix::fix_spec(ito_path, &item)?)
+        } else {
+            None
+        };
         let report = validate_spec_by_id_or_enoent(ito_path, &item, strict);
-        let ok = render_validate_result("spec", &item, report, want_json);
+        let ok = render_validate_result("spec", &item, report, fixes.as_ref(), want_json);
         if !ok {
-            return silent_fail();
+            return validation_failed();
         }
         return Ok(());
     }
 
     if resolved_type == "ambiguous" {
-        return fail(format!(
+        return Err(CliError::usage(format!(
             "Ambiguous item '{item}' matches both a change and a spec.\nUse --type change or --type spec to disambiguate."
-        ));
+        ))
+        .with_error_code("AMBIGUOUS_TARGET"));
     }
 
     match resolved_type.as_str() {
         "spec" => {
+            let fixes = if want_fix {
+                Some(super::validate_fix::fix_spec(ito_path, &item)?)
+            } else {
+                None
+            };
             let report = validate_spec_by_id_or_enoent(ito_path, &item, strict);
             if !report.valid && report.issues.iter().any(|i| i.message.contains("ENOENT")) {
                 let candidates = super::common::list_spec_ids(rt);
                 let suggestions = nearest_matches(&item, &candidates, 5);
-                return fail(super::common::unknown_with_suggestions(
-                    "spec",
-                    &item,
-                    &suggestions,
+                return Err(CliError::not_found(
+                    "SPEC_NOT_FOUND",
+                    super::common::unknown_with_suggestions("spec", &item, &suggestions),
                 ));
             }
-            let ok = render_validate_result("spec", &item, report, want_json);
+            let report = with_extra_issues(
+                report,
+                custom_rules.check_spec(ito_path, &item).unwrap_or_default(),
+                strict,
+            );
+            let ok = render_validate_result("spec", &item, report, fixes.as_ref(), want_json);
             if !ok {
-                return silent_fail();
+                return validation_failed();
             }
             Ok(())
         }
         "change" => {
-            let actual = match super::common::resolve_change_target(change_repo, &item) {
-                Ok(id) => id,
-                Err(msg) => return fail(msg),
+            let actual = super::change_target::resolve_change_target(change_repo, &item)?;
+            let (fixes, actual) = if want_fix {
+                let (report, actual) = super::validate_fix::fix_change(ito_path, &actual)?;
+                (Some(report), actual)
+            } else {
+                (None, actual)
             };
 
             let mut issues = Vec::new();
@@ -400,16 +518,25 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
             };
             let mut merged = report.issues.clone();
             merged.extend(issues);
+            if is_filesystem {
+                merged.extend(core_validate::validate_change_artifacts(
+                    ito_path,
+                    &actual,
+                    &SystemProcessRunner,
+                    &artifact_options,
+                ));
+            }
 
             // Audit consistency check (warnings only)
             if !skip_audit {
                 merged.extend(validate_audit_consistency(ito_path, &actual));
             }
+            merged.extend(custom_change_issues(&custom_rules, change_repo, &actual));
 
             let report = core_validate::ValidationReport::new(merged, strict);
-            let ok = render_validate_result("change", &item, report, want_json);
+            let ok = render_validate_result("change", &item, report, fixes.as_ref(), want_json);
             if !ok {
-                return silent_fail();
+                return validation_failed();
             }
             Ok(())
         }
@@ -417,15 +544,101 @@ pub(crate) fn handle_validate(rt: &Runtime, args: &[String]) -> CliResult<()> {
             // unknown
             let candidates = super::common::list_candidate_items(change_repo, rt);
             let suggestions = nearest_matches(&item, &candidates, 5);
-            fail(super::common::unknown_with_suggestions(
-                "item",
-                &item,
-                &suggestions,
+            Err(CliError::not_found(
+                "ITEM_NOT_FOUND",
+                super::common::unknown_with_suggestions("item", &item, &suggestions),
             ))
         }
     }
 }
 
+/// Silent failure for invalid items, whose issues were already reported.
+fn validation_failed<T>() -> CliResult<T> {
+    Err(CliError::silent_with_code(
+        ErrorCategory::Validation.exit_code(),
+    ))
+}
+
+/// Compile `validation.custom_rules` once for this validation run.
+fn load_custom_rules(
+    rt: &Runtime,
+) -> (
+    core_validate::CustomRuleSet,
+    Vec<core_validate::ValidationIssue>,
+) {
+    let config = rt.typed_config().unwrap_or_default();
+    core_validate::CustomRuleSet::compile(&config.validation)
+}
+
+/// Report custom rule definitions that could not be compiled.
+fn print_custom_rule_warnings(warnings: &[core_validate::ValidationIssue]) {
+    for w in warnings {
+        eprintln!("Warning: {}: {}", w.path, w.message);
+    }
+}
+
+fn custom_change_issues(
+    custom_rules: &core_validate::CustomRuleSet,
+    change_repo: &(impl ito_core::ChangeRepository + ?Sized),
+    change_id: &str,
+) -> Vec<core_validate::ValidationIssue> {
+    custom_rules
+        .check_change(change_repo, change_id)
+        .unwrap_or_else(|e| {
+            vec![core_validate::error(
+                "validation.custom_rules",
+                format!("Custom rules failed: {e}"),
+            )]
+        })
+}
+
+/// Merge extra issues into a finished report, recomputing its summary.
+fn with_extra_issues(
+    report: core_validate::ValidationReport,
+    extra: Vec<core_validate::ValidationIssue>,
+    strict: bool,
+) -> core_validate::ValidationReport {
+    if extra.is_empty() {
+        return report;
+    }
+    let mut issues = report.issues;
+    issues.extend(extra);
+    core_validate::ValidationReport::new(issues, strict)
+}
+
+fn list_rules(custom_rules: &core_validate::CustomRuleSet, want_json: bool) -> CliResult<()> {
+    let rules = core_validate::list_validation_rules(custom_rules);
+
+    if want_json {
+        let rendered =
+            render_json(&serde_json::json!({ "rules": rules })).expect("json should serialize");
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let ui = Ui::stdout();
+    println!("{}", ui.heading("Validation rules:"));
+    println!();
+    let mut table = Table::new(vec![
+        Column::new("rule").min_width(36).shrink(),
+        Column::new("source").min_width(9),
+        Column::new("level").min_width(8),
+        Column::new("scope"),
+    ])
+    .gap(1);
+    for rule in &rules {
+        let level = rule.level.as_deref().unwrap_or("(schema)");
+        table.row(vec![
+            Cell::plain(rule.rule_id.as_str()),
+            Cell::toned(rule.source, Tone::Muted),
+            Cell::toned(level, status_tone(level)),
+            Cell::plain(rule.scope.as_str()),
+        ]);
+    }
+    print!("{}", table.render(&ui));
+    Ok(())
+}
+
 /// Check audit log consistency for a change. Returns warnings for any drift detected.
 fn validate_audit_consistency(
     ito_path: &Path,
@@ -443,6 +656,7 @@ fn validate_audit_consistency(
             message: format!("Audit drift: {drift}"),
             rule_id: None,
             metadata: None,
+            fix: None,
         });
     }
 
@@ -450,6 +664,28 @@ fn validate_audit_consistency(
 }
 
 pub(crate) fn handle_validate_clap(rt: &Runtime, args: &ValidateArgs) -> CliResult<()> {
+    #[cfg(feature = "watch")]
+    if args.watch {
+        return super::validate_watch::handle_validate_watch(rt, args);
+    }
+    #[cfg(not(feature = "watch"))]
+    if args.watch {
+        return Err(CliError::feature_unavailable(
+            "watch",
+            "ito validate --watch",
+            "omit --watch, or install a build with the watch feature",
+        ));
+    }
+    if let Some(ValidateCommand::Repo(repo_args)) = &args.command {
+        return super::validate_repo::handle_validate_repo(rt, repo_args);
+    }
+    handle_validate(rt, &validate_argv(args))
+}
+
+/// Rebuild the argv `handle_validate` expects from parsed arguments.
+///
+/// `ito validate repo` has its own handler and is not covered here.
+pub(super) fn validate_argv(args: &ValidateArgs) -> Vec<String> {
     let mut argv: Vec<String> = Vec::new();
 
     if let Some(ValidateCommand::Module { module_id }) = &args.command {
@@ -457,11 +693,7 @@ pub(crate) fn handle_validate_clap(rt: &Runtime, args: &ValidateArgs) -> CliResu
         if let Some(module_id) = module_id {
             argv.push(module_id.clone());
         }
-        return handle_validate(rt, &argv);
-    }
-
-    if let Some(ValidateCommand::Repo(repo_args)) = &args.command {
-        return super::validate_repo::handle_validate_repo(rt, repo_args);
+        return argv;
     }
 
     if args.all {
@@ -491,17 +723,29 @@ pub(crate) fn handle_validate_clap(rt: &Runtime, args: &ValidateArgs) -> CliResu
     if args.json {
         argv.push("--json".to_string());
     }
+    if args.list_rules {
+        argv.push("--list-rules".to_string());
+    }
     if args.audit {
         argv.push("--audit".to_string());
     }
     if args.no_audit {
         argv.push("--no-audit".to_string());
     }
+    if args.fix {
+        argv.push("--fix".to_string());
+    }
+    if args.no_artifact_commands {
+        argv.push("--no-artifact-commands".to_string());
+    }
+    if args.trust_embedded {
+        argv.push("--trust-embedded".to_string());
+    }
     if let Some(item) = &args.item {
         argv.push(item.clone());
     }
 
-    handle_validate(rt, &argv)
+    argv
 }
 
 fn handle_validate_module(rt: &Runtime, args: &[String]) -> CliResult<()> {
@@ -522,6 +766,15 @@ fn handle_validate_module(rt: &Runtime, args: &[String]) -> CliResult<()> {
     let (full_name, report) =
         core_validate::validate_module(module_repo, ito_path, &module_id, false)
             .map_err(to_cli_error)?;
+    let (custom_rules, custom_rule_warnings) = load_custom_rules(rt);
+    print_custom_rule_warnings(&custom_rule_warnings);
+    let report = with_extra_issues(
+        report,
+        custom_rules
+            .check_module(module_repo, ito_path, &module_id)
+            .unwrap_or_default(),
+        false,
+    );
     if report.valid {
         println!("Module '{full_name}' is valid");
         return Ok(());
@@ -552,6 +805,7 @@ fn render_validate_result(
     typ: &str,
     id: &str,
     report: core_validate::ValidationReport,
+    fixes: Option<&core_validate::FixReport>,
     want_json: bool,
 ) -> bool {
     if want_json {
@@ -588,6 +842,8 @@ fn render_validate_result(
         struct Envelope<'a> {
             items: Vec<Item<'a>>,
             summary: Summary,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            fixes: Option<&'a core_validate::FixReport>,
             version: &'static str,
         }
 
@@ -619,13 +875,17 @@ fn render_validate_result(
                 },
                 by_type,
             },
+            fixes,
             version: "1.0",
         };
-        let rendered = serde_json::to_string_pretty(&env).expect("json should serialize");
+        let rendered = render_json(&env).expect("json should serialize");
         println!("{rendered}");
         return report.valid;
     }
 
+    if let Some(fixes) = fixes {
+        super::validate_fix::print_fix_report(fixes);
+    }
     let label = if typ == "spec" {
         "Specification"
     } else if typ == "change" {
@@ -635,15 +895,21 @@ fn render_validate_result(
     };
 
     if report.valid {
-        println!("{label} '{id}' is valid");
+        let ui = Ui::stdout();
+        println!(
+            "{}",
+            ui.paint(&format!("{label} '{id}' is valid"), Tone::Success)
+        );
         return true;
     }
 
+    let ui = Ui::stderr();
     eprintln!("{label} '{id}' has issues");
     for issue in &report.issues {
         eprintln!(
-            "✗ [{}] {}: {}",
-            issue.level,
+            "{} {} {}: {}",
+            ui.paint("✗", status_tone(&issue.level)),
+            format_issue_level(&ui, &issue.level),
             format_issue_loc(issue),
             issue.message
         );
@@ -0,0 +1,94 @@
+//! `ito validate --fix`: apply mechanical fixes before validating.
+
+use crate::cli_error::{CliResult, to_cli_error};
+use crate::ui::{Tone, Ui};
+use ito_core::validate::{self as core_validate, FixAction, FixReport, ValidationIssue};
+
+/// Apply fixes for the selected bulk scopes.
+pub(super) fn fix_bulk(
+    module_repo: &(impl ito_core::ModuleRepository + ?Sized),
+    ito_path: &std::path::Path,
+    changes: bool,
+    specs: bool,
+    modules: bool,
+) -> CliResult<FixReport> {
+    let mut issues: Vec<ValidationIssue> = Vec::new();
+    if changes {
+        issues.extend(core_validate::fixable_change_issues(ito_path, None).map_err(to_cli_error)?);
+    }
+    if specs {
+        issues.extend(core_validate::fixable_spec_issues(ito_path, None).map_err(to_cli_error)?);
+    }
+    if modules {
+        issues.extend(
+            core_validate::fixable_module_issues(module_repo, ito_path, None)
+                .map_err(to_cli_error)?,
+        );
+    }
+    Ok(core_validate::apply_fixes(&issues))
+}
+
+/// Apply fixes for one change and return its directory name afterwards.
+pub(super) fn fix_change(
+    ito_path: &std::path::Path,
+    change_id: &str,
+) -> CliResult<(FixReport, String)> {
+    let issues =
+        core_validate::fixable_change_issues(ito_path, Some(change_id)).map_err(to_cli_error)?;
+    let report = core_validate::apply_fixes(&issues);
+    let renamed = report
+        .applied
+        .iter()
+        .find_map(|outcome| match &outcome.fix {
+            FixAction::RenamePath { to, .. } => {
+                to.file_name().map(|n| n.to_string_lossy().to_string())
+            }
+            _ => None,
+        });
+    Ok((report, renamed.unwrap_or_else(|| change_id.to_string())))
+}
+
+/// Apply fixes for one spec.
+pub(super) fn fix_spec(ito_path: &std::path::Path, spec_id: &str) -> CliResult<FixReport> {
+    let issues =
+        core_validate::fixable_spec_issues(ito_path, Some(spec_id)).map_err(to_cli_error)?;
+    Ok(core_validate::apply_fixes(&issues))
+}
+
+/// Print what `--fix` changed (stdout) and what it could not change (stderr).
+pub(super) fn print_fix_report(report: &FixReport) {
+    if report.is_empty() {
+        println!("No fixable issues found.");
+        return;
+    }
+    if !report.applied.is_empty() {
+        let ui = Ui::stdout();
+        println!(
+            "{}",
+            ui.paint(
+                &format!("Fixed {} issue(s):", report.applied.len()),
+                Tone::Success
+            )
+        );
+        for outcome in &report.applied {
+            println!("  - {}", outcome.fix.describe());
+        }
+    }
+    if !report.failed.is_empty() {
+        let ui = Ui::stderr();
+        eprintln!(
+            "{}",
+            ui.paint(
+                &format!("Could not fix {} issue(s):", report.failed.len()),
+                Tone::Error
+            )
+        );
+        for outcome in &report.failed {
+            eprintln!(
+                "  - {}: {}",
+                outcome.fix.describe(),
+                outcome.error.as_deref().unwrap_or("unknown error")
+            );
+        }
+    }
+}
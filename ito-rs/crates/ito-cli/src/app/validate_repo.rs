@@ -5,12 +5,15 @@
 //! outcome to documented exit codes:
 //!
 //! - **0** — no `ERROR` issues (and no `WARNING` issues under `--strict`).
-//! - **1** — at least one `ERROR` issue (or any `WARNING` under `--strict`).
+//! - **4** — at least one `ERROR` issue (or any `WARNING` under `--strict`).
 //! - **2** — usage error (mutually exclusive flags) or unloadable config.
+//!
+//! These follow the CLI-wide contract in `ito help exit-codes`.
 
 use crate::cli::RepoValidateArgs;
-use crate::cli_error::{CliError, CliResult, to_cli_error};
+use crate::cli_error::{CliError, CliResult, ErrorCategory, to_cli_error};
 use crate::runtime::Runtime;
+use crate::util::render_json;
 use ito_config::ConfigContext;
 use ito_config::load_cascading_project_config;
 
@@ -27,8 +30,7 @@ pub(crate) fn handle_validate_repo(rt: &Runtime, args: &RepoValidateArgs) -> Cli
     // we belt-and-braces the check here so the string-arg path is also
     // protected.
     if !args.rule.is_empty() && !args.no_rule.is_empty() {
-        return Err(CliError::with_code(
-            2,
+        return Err(CliError::usage(
             "`--rule` and `--no-rule` are mutually exclusive.",
         ));
     }
@@ -79,7 +81,9 @@ pub(crate) fn handle_validate_repo(rt: &Runtime, args: &RepoValidateArgs) -> Cli
     // `report.valid` already accounts for `--strict` (warnings are
     // promoted to failures via [`core_validate::ValidationReport::new`]).
     if !report.valid {
-        return Err(CliError::silent_with_code(1));
+        return Err(CliError::silent_with_code(
+            ErrorCategory::Validation.exit_code(),
+        ));
     }
     Ok(())
 }
@@ -88,34 +92,27 @@ fn load_config(rt: &Runtime) -> CliResult<ItoConfig> {
     let ctx = ConfigContext::from_process_env();
     let cfg_value = load_cascading_project_config(rt.cwd(), rt.ito_path(), &ctx).merged;
     serde_json::from_value::<ItoConfig>(cfg_value).map_err(|e| {
-        CliError::with_code(
-            2,
-            format!(
-                "Cannot load `.ito/config.json` for `ito validate repo`.\n\
-                 Why: configuration failed to deserialize.\n\
-                 Details: {e}\n\
-                 Fix: validate the JSON syntax (`cat .ito/config.json | jq .`) and confirm \
-                 every field matches the schema.",
-            ),
-        )
+        CliError::usage(format!(
+            "Cannot load `.ito/config.json` for `ito validate repo`.\n\
+             Why: configuration failed to deserialize.\n\
+             Details: {e}\n\
+             Fix: validate the JSON syntax (`cat .ito/config.json | jq .`) and confirm \
+             every field matches the schema.",
+        ))
     })
 }
 
 fn explain_rule(config: &ItoConfig, rule_id: &str, want_json: bool) -> CliResult<()> {
     let rules = list_active_rules_for(&RuleRegistry::built_in(), config);
     let Some(rule) = rules.iter().find(|r| r.rule_id.as_str() == rule_id) else {
-        return Err(CliError::with_code(
-            2,
-            format!(
-                "Unknown rule `{rule_id}`. Run `ito validate repo --list-rules` to see all rules.",
-            ),
-        ));
+        return Err(CliError::usage(format!(
+            "Unknown rule `{rule_id}`. Run `ito validate repo --list-rules` to see all rules.",
+        )));
     };
 
     if want_json {
         let json = active_rule_json(rule);
-        let body = serde_json::to_string_pretty(&json)
-            .map_err(|e| CliError::with_code(2, e.to_string()))?;
+        let body = render_json(&json).map_err(|e| CliError::usage(e.to_string()))?;
         println!("{body}");
     } else {
         println!("rule: {}", rule.rule_id.as_str());
@@ -134,8 +131,8 @@ fn list_rules(config: &ItoConfig, want_json: bool) -> CliResult<()> {
 
     if want_json {
         let arr: Vec<serde_json::Value> = rules.iter().map(active_rule_json).collect();
-        let body = serde_json::to_string_pretty(&serde_json::json!({ "rules": arr }))
-            .map_err(|e| CliError::with_code(2, e.to_string()))?;
+        let body = render_json(&serde_json::json!({ "rules": arr }))
+            .map_err(|e| CliError::usage(e.to_string()))?;
         println!("{body}");
         return Ok(());
     }
@@ -165,8 +162,7 @@ fn active_rule_json(rule: &ActiveRule) -> serde_json::Value {
 }
 
 fn print_report_json(report: &core_validate::ValidationReport) -> CliResult<()> {
-    let body =
-        serde_json::to_string_pretty(report).map_err(|e| CliError::with_code(2, e.to_string()))?;
+    let body = render_json(report).map_err(|e| CliError::usage(e.to_string()))?;
     println!("{body}");
     Ok(())
 }
@@ -0,0 +1,283 @@
+//! `ito validate --watch`: re-validate on file changes and report deltas.
+//!
+//! Each run spawns the current binary with the same validation arguments
+//! plus `--json`, so it sees fresh repository state and behaves exactly like
+//! the non-watch command.
+
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use ito_common::paths;
+use ito_core::time::now_time;
+use ito_core::validate::{
+    RunIssue, ValidationDelta, ValidationRun, WatchOutcome, watch_validation,
+};
+use ito_core::watch::{DEFAULT_DEBOUNCE, FsWatchSource, IgnoreGlobs, StopHandle};
+
+use crate::cli::{ValidateArgs, ValidateCommand, ValidateItemType};
+use crate::cli_error::{CliResult, fail, silent_fail, to_cli_error};
+use crate::runtime::Runtime;
+use crate::ui::{Tone, Ui, status_tone};
+
+/// Exit code used when a second Ctrl-C interrupts a stuck run.
+const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+pub(super) fn handle_validate_watch(rt: &Runtime, args: &ValidateArgs) -> CliResult<()> {
+    if let Some(ValidateCommand::Repo(_)) = &args.command {
+        return fail("--watch is not supported for `ito validate repo`");
+    }
+    let roots = watch_roots(rt, args)?;
+    let ignore = IgnoreGlobs::with_defaults(&args.watch_ignore).map_err(to_cli_error)?;
+    let mut source =
+        FsWatchSource::new(roots.clone(), ignore, DEFAULT_DEBOUNCE).map_err(to_cli_error)?;
+    let stop = source.stop_handle();
+    install_stop_handler(stop.clone());
+
+    let ui = Ui::stdout();
+    let watched: Vec<String> = roots.iter().map(|r| r.display().to_string()).collect();
+    println!(
+        "{}",
+        ui.paint(
+            &format!(
+                "Watching {} for changes (Ctrl-C to stop)",
+                watched.join(", ")
+            ),
+            Tone::Muted
+        )
+    );
+
+    let argv = super::validate::validate_argv(args);
+    let mut last = ValidationRun::default();
+    let summary = watch_validation(
+        &mut source,
+        || {
+            let run = run_validation(&argv);
+            // Ctrl-C also reaches the child; keep the last complete result.
+            if stop.is_stopped() {
+                return last.clone();
+            }
+            last = run.clone();
+            run
+        },
+        |changed, run, delta| {
+            if !stop.is_stopped() {
+                print!("{}", render_run(&ui, changed, run, delta));
+            }
+        },
+    );
+
+    match &summary.outcome {
+        WatchOutcome::Stopped => eprintln!("Stopped watching."),
+        WatchOutcome::RootRemoved(root) => {
+            eprintln!("Stopped watching: {} was removed.", root.display());
+        }
+    }
+    if summary.last.valid {
+        return Ok(());
+    }
+    silent_fail()
+}
+
+/// Directories whose changes can affect the requested validation.
+fn watch_roots(rt: &Runtime, args: &ValidateArgs) -> CliResult<Vec<PathBuf>> {
+    let ito_path = rt.ito_path();
+    let specs_dir = paths::specs_dir(ito_path);
+    let bulk = args.all || args.changes || args.specs || args.modules;
+    let mut roots = vec![ito_path.to_path_buf()];
+
+    if args.command.is_none() && args.specs && !(args.all || args.changes || args.modules) {
+        roots = vec![specs_dir];
+    } else if let (None, false, Some(item)) = (&args.command, bulk, &args.item) {
+        match args.typ {
+            Some(ValidateItemType::Module) => {}
+            Some(ValidateItemType::Spec) => roots = vec![specs_dir],
+            Some(ValidateItemType::Change) | None => {
+                let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+                let change_repo = runtime.repositories().changes.as_ref();
+                match super::change_target::resolve_change_target(change_repo, item) {
+                    Ok(id) => {
+                        roots = vec![paths::change_dir(ito_path, &id)];
+                        // Delta specs are checked against the main specs.
+                        if specs_dir.is_dir() {
+                            roots.push(specs_dir);
+                        }
+                    }
+                    Err(e) if args.typ.is_some() => return Err(e),
+                    Err(_) if specs_dir.join(item).is_dir() => roots = vec![specs_dir],
+                    Err(_) => {}
+                }
+            }
+        }
+    }
+    Ok(roots)
+}
+
+fn install_stop_handler(stop: StopHandle) {
+    let installed = ctrlc::set_handler(move || {
+        if stop.is_stopped() {
+            std::process::exit(INTERRUPTED_EXIT_CODE);
+        }
+        stop.stop();
+    });
+    if let Err(e) = installed {
+        tracing::warn!("failed to install watch signal handler: {e}");
+    }
+}
+
+/// Run the non-watch validation in a child process and collect its result.
+fn run_validation(argv: &[String]) -> ValidationRun {
+    let exe = match std::env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => return failed_run(format!("Failed to locate the ito binary: {e}")),
+    };
+    let output = Command::new(exe)
+        .arg("validate")
+        .args(argv)
+        .arg("--json")
+        .stdin(Stdio::null())
+        .output();
+    let output = match output {
+        Ok(output) => output,
+        Err(e) => return failed_run(format!("Failed to run validation: {e}")),
+    };
+    if let Some(run) = parse_run(&String::from_utf8_lossy(&output.stdout)) {
+        return run;
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let message = stderr
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("Validation exited with {}", output.status));
+    failed_run(message)
+}
+
+fn failed_run(message: String) -> ValidationRun {
+    ValidationRun {
+        valid: false,
+        items: 0,
+        issues: vec![RunIssue {
+            item: String::new(),
+            level: "ERROR".to_string(),
+            path: String::new(),
+            message,
+            line: None,
+        }],
+    }
+}
+
+/// Parse the `ito validate --json` envelope.
+fn parse_run(stdout: &str) -> Option<ValidationRun> {
+    let value: serde_json::Value = serde_json::from_str(stdout.trim()).ok()?;
+    let items = value.get("items")?.as_array()?;
+    let mut run = ValidationRun {
+        valid: true,
+        items: items.len(),
+        issues: Vec::new(),
+    };
+    for item in items {
+        let id = item.get("id").and_then(|v| v.as_str()).unwrap_or_default();
+        if !item.get("valid").and_then(|v| v.as_bool()).unwrap_or(false) {
+            run.valid = false;
+        }
+        let issues = item.get("issues").and_then(|v| v.as_array());
+        for issue in issues.into_iter().flatten() {
+            let field = |name: &str| {
+                issue
+                    .get(name)
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            run.issues.push(RunIssue {
+                item: id.to_string(),
+                level: field("level"),
+                path: field("path"),
+                message: field("message"),
+                line: issue
+                    .get("line")
+                    .and_then(|v| v.as_u64())
+                    .and_then(|line| u32::try_from(line).ok()),
+            });
+        }
+    }
+    Some(run)
+}
+
+fn render_run(
+    ui: &Ui,
+    changed: &[PathBuf],
+    run: &ValidationRun,
+    delta: &ValidationDelta,
+) -> String {
+    let mut out = format!("{}\n", ui.rule(60));
+    let status = if run.valid {
+        ui.paint(
+            &format!("All items valid ({} checked)", run.items),
+            Tone::Success,
+        )
+    } else {
+        ui.paint(
+            &format!("Validation failed ({} checked)", run.items),
+            Tone::Error,
+        )
+    };
+    out.push_str(&format!("[{}] {status}", now_time()));
+    if !changed.is_empty() {
+        let noun = if changed.len() == 1 { "file" } else { "files" };
+        out.push_str(&ui.paint(
+            &format!(" after {} changed {noun}", changed.len()),
+            Tone::Muted,
+        ));
+    }
+    out.push('\n');
+
+    for issue in &delta.new {
+        out.push_str(&format!(
+            "  {} {}\n",
+            ui.paint("+ new", Tone::Error),
+            describe(ui, issue)
+        ));
+    }
+    for issue in &delta.resolved {
+        out.push_str(&format!(
+            "  {} {}\n",
+            ui.paint("- resolved", Tone::Success),
+            describe(ui, issue)
+        ));
+    }
+    if delta.unchanged > 0 {
+        let noun = if delta.unchanged == 1 {
+            "issue"
+        } else {
+            "issues"
+        };
+        out.push_str(&ui.paint(
+            &format!("  {} unchanged {noun}\n", delta.unchanged),
+            Tone::Muted,
+        ));
+    }
+    out
+}
+
+fn describe(ui: &Ui, issue: &RunIssue) -> String {
+    let level = ui.paint(&format!("[{}]", issue.level), status_tone(&issue.level));
+    if issue.item.is_empty() {
+        return format!("{level} {}", issue.message);
+    }
+    let mut loc = issue.item.clone();
+    if !issue.path.is_empty() {
+        loc.push(' ');
+        loc.push_str(&issue.path);
+    }
+    if let Some(line) = issue.line {
+        loc.push_str(&format!(":{line}"));
+    }
+    format!("{level} {loc}: {}", issue.message)
+}
+
+#[cfg(test)]
+#[path = "validate_watch_tests.rs"]
+mod validate_watch_tests;
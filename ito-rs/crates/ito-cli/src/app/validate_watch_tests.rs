@@ -0,0 +1,46 @@
+use super::*;
+
+#[test]
+fn parse_run_reads_the_validate_json_envelope() {
+    let stdout = r#"{
+      "items": [
+        {"id": "000-01_a", "type": "change", "valid": false, "durationMs": 1,
+         "issues": [{"level": "ERROR", "path": "proposal.md", "message": "Missing Why", "line": 3}]},
+        {"id": "alpha", "type": "spec", "valid": true, "durationMs": 1, "issues": []}
+      ],
+      "summary": {"totals": {"items": 2, "passed": 1, "failed": 1}, "byType": {}},
+      "version": "1.0"
+    }"#;
+
+    let run = parse_run(stdout).unwrap();
+
+    assert!(!run.valid);
+    assert_eq!(run.items, 2);
+    assert_eq!(
+        run.issues,
+        vec![RunIssue {
+            item: "000-01_a".to_string(),
+            level: "ERROR".to_string(),
+            path: "proposal.md".to_string(),
+            message: "Missing Why".to_string(),
+            line: Some(3),
+        }]
+    );
+    assert!(parse_run("Validation failed").is_none());
+}
+
+#[test]
+fn describe_includes_item_location_and_message() {
+    let ui = Ui::stdout();
+    let issue = RunIssue {
+        item: "alpha".to_string(),
+        level: "WARNING".to_string(),
+        path: "spec.md".to_string(),
+        message: "Purpose too short".to_string(),
+        line: Some(4),
+    };
+
+    assert!(describe(&ui, &issue).ends_with("alpha spec.md:4: Purpose too short"));
+    let failed = failed_run("Change 'x' not found".to_string());
+    assert!(describe(&ui, &failed.issues[0]).ends_with("Change 'x' not found"));
+}
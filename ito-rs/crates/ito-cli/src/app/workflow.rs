@@ -0,0 +1,75 @@
+use ito_config::ConfigContext;
+use ito_core::templates::{self as core_templates, TemplatesError};
+use std::path::Path;
+
+use crate::cli::{WorkflowArgs, WorkflowCommand, WorkflowSkipArgs, WorkflowUnskipArgs};
+use crate::cli_error::{CliError, CliResult, fail, to_cli_error};
+use crate::runtime::Runtime;
+
+pub(crate) fn handle_workflow_clap(rt: &Runtime, args: &WorkflowArgs) -> CliResult<()> {
+    match &args.command {
+        WorkflowCommand::Skip(args) => handle_skip(rt, args),
+        WorkflowCommand::Unskip(args) => handle_unskip(rt, args),
+    }
+}
+
+fn handle_skip(rt: &Runtime, args: &WorkflowSkipArgs) -> CliResult<()> {
+    let change = resolve_change(rt, &args.change)?;
+    let ito_path = rt.ito_path();
+    let mut ctx = rt.ctx().clone();
+    if ctx.project_dir.is_none() {
+        ctx.project_dir = ito_path.parent().map(Path::to_path_buf);
+    }
+
+    match core_templates::skip_artifact(ito_path, &change, &args.artifact, &args.reason, &ctx) {
+        Ok(()) => {}
+        Err(TemplatesError::ArtifactNotSkippable(id)) => {
+            return fail(format!(
+                "Artifact '{id}' cannot be skipped: the schema marks it `skippable: false`"
+            ));
+        }
+        Err(error) => return Err(workflow_error(&ctx, error)),
+    }
+
+    println!(
+        "Skipped '{}' for {change}: {}",
+        args.artifact,
+        args.reason.trim()
+    );
+    Ok(())
+}
+
+fn handle_unskip(rt: &Runtime, args: &WorkflowUnskipArgs) -> CliResult<()> {
+    let change = resolve_change(rt, &args.change)?;
+    let removed = core_templates::unskip_artifact(rt.ito_path(), &change, &args.artifact)
+        .map_err(|error| workflow_error(rt.ctx(), error))?;
+
+    if removed {
+        println!("'{}' is no longer skipped for {change}", args.artifact);
+    } else {
+        println!("'{}' was not skipped for {change}", args.artifact);
+    }
+    Ok(())
+}
+
+fn resolve_change(rt: &Runtime, input: &str) -> CliResult<String> {
+    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+    let change_repo = runtime.repositories().changes.as_ref();
+    super::change_target::resolve_change_target(change_repo, input)
+}
+
+fn workflow_error(ctx: &ConfigContext, error: TemplatesError) -> CliError {
+    match error {
+        TemplatesError::InvalidChangeName => CliError::msg("Invalid change name"),
+        TemplatesError::ChangeNotFound(name) => {
+            CliError::not_found("CHANGE_NOT_FOUND", format!("Change '{name}' not found"))
+        }
+        TemplatesError::SchemaNotFound(name) => {
+            CliError::msg(super::common::schema_not_found_message(ctx, &name))
+        }
+        TemplatesError::ArtifactNotFound(id) => CliError::msg(format!(
+            "Artifact '{id}' is not defined by the change's schema"
+        )),
+        error => to_cli_error(error),
+    }
+}
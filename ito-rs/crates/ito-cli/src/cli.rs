@@ -5,20 +5,30 @@ use clap::builder::styling::{AnsiColor, Color, Style};
 use clap::{Args, Parser, Subcommand, ValueEnum};
 
 mod agent;
+mod archive;
 mod artifact;
 mod backend;
 mod change;
+mod config;
 mod grep;
 mod init_update;
+mod models;
 mod path;
 mod ralph;
+mod show;
+mod spec;
 mod split;
+mod stats;
 mod status_args;
 mod util;
 mod validate;
+mod workflow;
 mod worktree;
 pub use crate::app::trace::TraceArgs;
-pub use agent::{AgentArgs, AgentCommand, AgentInstructionArgs};
+pub use agent::{
+    AgentArgs, AgentCommand, AgentExportArgs, AgentExportFormat, AgentInstructionArgs,
+};
+pub use archive::ArchiveArgs;
 pub use artifact::{
     ChangeArtifactSelector, ChangeArtifactTargetArgs, ChangeArtifactTargetCommand, PatchArgs,
     WriteArgs,
@@ -26,16 +36,25 @@ pub use artifact::{
 #[cfg(feature = "backend")]
 pub use backend::ServeArgs as BackendServeArgs;
 pub use backend::{BackendAction, BackendArgs, RemovedServeApiArgs};
-pub use change::{ChangeArgs, ChangeCommand, ChangePreflightArgs, ReadinessPhaseArg};
+pub use change::{
+    ChangeArgs, ChangeCommand, ChangePreflightArgs, ChangeRebaselineArgs, ChangeRenameArgs,
+    ChangeSplitArgs, ChangeSyncArgs, ReadinessPhaseArg,
+};
+pub use config::{ConfigArgs, ConfigCommand};
 pub use grep::GrepArgs;
 pub use init_update::{InitArgs, UpdateArgs};
-pub use path::{PathArgs, PathCommand, PathCommonArgs, PathRootsArgs, PathWorktreeArgs};
+pub use models::{ModelsArgs, ModelsCommand, ModelsPinsArgs};
+pub use path::{PathArgs, PathCommand, PathCommonArgs, PathRootsArgs, PathWorktreeArgs, PathsArgs};
 pub use ralph::{HarnessArg, RalphArgs};
+pub use show::{ShowArgs, ShowCommand, ShowDiffArgs, ShowItemType, ShowTimelineArgs};
+pub use spec::{SpecArgs, SpecCommand, SpecRenameArgs};
 pub use split::SplitArgs;
+pub use stats::{StatsArgs, StatsCommand, StatsCostArgs, StatsSkillsArgs};
 pub use status_args::{StatusArgs, SyncArgs};
-pub use util::{ParseIdArgs, UtilArgs, UtilCommand};
-pub use validate::{RepoValidateArgs, ValidateCommand, ValidateItemType};
-pub use worktree::{WorktreeArgs, WorktreeCommand, WorktreeValidateArgs};
+pub use util::{ParseIdArgs, RecordSkillArgs, UtilArgs, UtilCommand};
+pub use validate::{RepoValidateArgs, ValidateArgs, ValidateCommand, ValidateItemType};
+pub use workflow::{WorkflowArgs, WorkflowCommand, WorkflowSkipArgs, WorkflowUnskipArgs};
+pub use worktree::{WorktreeArgs, WorktreeCommand, WorktreeCreateArgs, WorktreeValidateArgs};
 #[cfg(test)]
 #[path = "cli_tests.rs"]
 mod cli_tests;
@@ -78,6 +97,30 @@ pub struct Cli {
     #[arg(long = "help-all", global = true)]
     pub help_all: bool,
 
+    /// Run as if ito was started in PATH instead of the current directory
+    #[arg(
+        short = 'C',
+        long = "cwd",
+        global = true,
+        value_name = "PATH",
+        display_order = 0
+    )]
+    pub cwd: Option<std::path::PathBuf>,
+
+    /// Only print errors and the command's result
+    #[arg(
+        short = 'q',
+        long = "quiet",
+        global = true,
+        conflicts_with = "verbose",
+        display_order = 0
+    )]
+    pub quiet: bool,
+
+    /// Print extra detail (prompt previews, installer actions, config sources)
+    #[arg(short = 'v', long = "verbose", global = true, display_order = 0)]
+    pub verbose: bool,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -86,6 +129,7 @@ pub struct Cli {
 pub enum Commands {
     // ─── Change Lifecycle ───────────────────────────────────────────────────────
     /// Inspect readiness for an Ito change
+    #[command(alias = "changes")]
     Change(ChangeArgs),
 
     /// Create a new module or change proposal
@@ -120,6 +164,9 @@ pub enum Commands {
     ///   ito show --type spec auth-service
     ///   ito show module 005
     ///   ito show specs
+    ///   ito show coverage auth-service
+    ///   ito show diff 005-01_add-auth 005-02_add-sso
+    ///   ito show timeline 005-01_add-auth
     #[command(verbatim_doc_comment, visible_alias = "sh")]
     Show(ShowArgs),
 
@@ -133,6 +180,17 @@ pub enum Commands {
     #[command(verbatim_doc_comment, visible_alias = "st")]
     Status(StatusArgs),
 
+    /// Skip or unskip schema artifacts for a change
+    ///
+    /// Skipped artifacts count as satisfied for dependencies and apply, and
+    /// are reported with status "skipped" and the recorded reason.
+    ///
+    /// Examples:
+    ///   ito workflow skip 005-01_add-auth design --reason "One-line fix"
+    ///   ito workflow unskip 005-01_add-auth design
+    #[command(verbatim_doc_comment)]
+    Workflow(WorkflowArgs),
+
     /// Check changes, specs, and modules for errors and warnings
     ///
     /// Validates markdown structure, required fields, and cross-references.
@@ -142,6 +200,7 @@ pub enum Commands {
     ///   ito validate --all
     ///   ito validate 005-01_add-auth
     ///   ito validate --specs --strict
+    ///   ito validate 005-01_add-auth --watch
     #[command(verbatim_doc_comment, visible_alias = "va")]
     Validate(ValidateArgs),
 
@@ -156,6 +215,18 @@ pub enum Commands {
     #[command(verbatim_doc_comment, visible_alias = "ar")]
     Archive(ArchiveArgs),
 
+    /// Manage promoted specs (rename with cross-reference updates)
+    ///
+    /// Renaming a spec moves `.ito/specs/<id>/`, the matching delta directories
+    /// in every active change, and rewrites references in change proposals,
+    /// design docs, and module files. Archived changes are reported, not edited.
+    ///
+    /// Examples:
+    ///   ito spec rename cli-init cli-setup --dry-run
+    ///   ito spec rename cli-init cli-setup
+    #[command(verbatim_doc_comment)]
+    Spec(SpecArgs),
+
     /// Apply a targeted patch to an active change artifact
     ///
     /// Uses repository-runtime-selected persistence to patch an active-work
@@ -265,6 +336,18 @@ pub enum Commands {
     #[command(hide = true)]
     Loop(RalphArgs),
 
+    /// Run one change artifact through an AI harness
+    ///
+    /// Builds a prompt from the artifact's instructions, template, completed
+    /// dependencies, and user guidance, runs the harness once
+    /// non-interactively, and fails when the artifact was not written.
+    ///
+    /// Examples:
+    ///   ito run artifact 005-01_add-auth design --harness claude
+    ///   ito run artifact 005-01_add-auth design --dry-run
+    #[command(verbatim_doc_comment)]
+    Run(crate::commands::run::RunArgs),
+
     // ─── Project Setup ──────────────────────────────────────────────────────────
     /// Set up Ito in a project
     ///
@@ -274,6 +357,7 @@ pub enum Commands {
     /// Examples:
     ///   ito init
     ///   ito init --tools claude,codex
+    ///   ito init --tools auto             # tools detected on PATH or in the repo
     ///   ito init --tools all --force
     ///   ito init --upgrade              # refresh managed template blocks only
     #[command(verbatim_doc_comment, visible_alias = "in")]
@@ -305,7 +389,20 @@ pub enum Commands {
     #[command(verbatim_doc_comment)]
     Path(PathArgs),
 
-    /// Manage change worktrees (ensure, setup)
+    /// Show every location Ito reads or writes for this project
+    ///
+    /// Lists the project root, the Ito directory, each config file in
+    /// precedence order (and whether it was loaded), the global config and
+    /// data directories, Ralph state, the audit log, and transcripts, with
+    /// existence, size, and modification time.
+    ///
+    /// Examples:
+    ///   ito paths
+    ///   ito paths --json
+    #[command(verbatim_doc_comment)]
+    Paths(PathsArgs),
+
+    /// Manage change worktrees (create, ensure, setup)
     #[command(verbatim_doc_comment)]
     Worktree(WorktreeArgs),
 
@@ -326,6 +423,7 @@ pub enum Commands {
     ///   ito serve start
     ///   ito serve status
     ///   ito serve stop
+    ///   ito serve --stdio
     #[command(verbatim_doc_comment, visible_alias = "se")]
     #[cfg(feature = "web")]
     Serve(ServeArgs),
@@ -370,9 +468,32 @@ pub enum Commands {
     ///   ito audit reconcile --change 005-01_add-auth
     ///   ito audit validate --change 005-01_add-auth
     ///   ito audit stats
+    ///   ito audit follow --change 005-01_add-auth
     #[command(verbatim_doc_comment, visible_alias = "au")]
     Audit(crate::commands::audit::AuditArgs),
 
+    /// Check that AI harnesses can run
+    ///
+    /// Verifies the harness binary resolves, its login is valid, and the
+    /// model answers a trivial prompt.
+    ///
+    /// Examples:
+    ///   ito harness check claude
+    ///   ito harness check --all --json
+    #[command(verbatim_doc_comment)]
+    Harness(crate::commands::harness::HarnessArgs),
+
+    /// Inspect recorded harness run transcripts
+    ///
+    /// Ralph records the prompt and output of each harness run under
+    /// `.ito/.state/transcripts/<change-id>/<iteration>.md`.
+    ///
+    /// Examples:
+    ///   ito transcripts list 005-01_add-auth
+    ///   ito transcripts show 005-01_add-auth 3
+    #[command(verbatim_doc_comment)]
+    Transcripts(crate::commands::transcripts::TranscriptsArgs),
+
     // ─── Utilities ──────────────────────────────────────────────────────────────
     /// Low-level utility commands for scripting and agent tooling.
     ///
@@ -406,10 +527,35 @@ pub enum Commands {
     /// Display command execution counts and history
     ///
     /// Shows statistics about ito command usage in this project.
-    /// Useful for understanding workflow patterns.
+    /// Useful for understanding workflow patterns. Use `ito stats cost` for
+    /// estimated harness cost per change and `ito stats skills` for skill
+    /// invocation counts.
     #[command(visible_alias = "ss")]
     Stats(StatsArgs),
 
+    /// Show the models Ito agents are installed with
+    ///
+    /// Agent models come from the `models` config section, falling back to
+    /// the defaults embedded in the agent templates.
+    ///
+    /// Examples:
+    ///   ito models pins
+    ///   ito models pins --json
+    #[command(verbatim_doc_comment)]
+    Models(ModelsArgs),
+
+    /// Check installation health and report fixes
+    ///
+    /// Checks git, the Ito directory, config.json, installed adapter files,
+    /// harness CLIs, the local audit log, and state-directory permissions.
+    /// Exits non-zero when any check fails.
+    ///
+    /// Examples:
+    ///   ito doctor
+    ///   ito doctor --json
+    #[command(verbatim_doc_comment)]
+    Doctor(DoctorArgs),
+
     /// Show help for ito commands
     ///
     /// Displays help for a specific command or the full CLI reference.
@@ -445,6 +591,10 @@ pub struct ServeArgs {
     /// Bind to the Tailscale IP address (requires tailscale CLI)
     #[arg(short, long, global = true)]
     pub tailscale: bool,
+
+    /// Speak JSON-RPC 2.0 over stdin/stdout (for editor integrations) instead of HTTP
+    #[arg(long, conflicts_with_all = ["port", "bind", "tailscale"])]
+    pub stdio: bool,
 }
 
 #[derive(Subcommand, Debug, Clone)]
@@ -487,9 +637,13 @@ pub enum NewAction {
     External(Vec<String>),
 }
 
-/// Show local execution usage stats.
+/// Installation health checks.
 #[derive(Args, Debug, Clone)]
-pub struct StatsArgs {}
+pub struct DoctorArgs {
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
 
 /// Project planning tools.
 #[derive(Args, Debug, Clone)]
@@ -560,81 +714,12 @@ pub struct HelpArgs {
     #[arg(long)]
     pub json: bool,
 
-    /// Command path to show help for (e.g., `ito help tasks`)
+    /// Command path to show help for (e.g., `ito help tasks`), or the
+    /// `exit-codes` topic
     #[arg(value_name = "COMMAND", num_args = 0..)]
     pub command: Vec<String>,
 }
 
-/// View and modify global Ito configuration.
-#[derive(Args, Debug, Clone)]
-pub struct ConfigArgs {
-    #[command(subcommand)]
-    pub command: Option<ConfigCommand>,
-}
-
-#[derive(Subcommand, Debug, Clone)]
-pub enum ConfigCommand {
-    /// Print config file path
-    #[command(visible_alias = "pa")]
-    Path(ConfigCommonArgs),
-
-    /// Print config JSON
-    #[command(visible_alias = "ls")]
-    List(ConfigCommonArgs),
-
-    /// Read value by path
-    #[command(visible_alias = "ge")]
-    Get {
-        /// Key path (dot-separated)
-        key: String,
-
-        #[command(flatten)]
-        common: ConfigCommonArgs,
-    },
-
-    /// Set value by path
-    #[command(visible_alias = "se")]
-    Set {
-        /// Key path (dot-separated)
-        key: String,
-
-        /// Value (JSON or string)
-        value: String,
-
-        #[command(flatten)]
-        common: ConfigCommonArgs,
-    },
-
-    /// Remove value by path
-    #[command(visible_alias = "un")]
-    Unset {
-        /// Key path (dot-separated)
-        key: String,
-
-        #[command(flatten)]
-        common: ConfigCommonArgs,
-    },
-
-    /// Print JSON schema for Ito config
-    #[command(visible_alias = "sc")]
-    Schema {
-        /// Write schema to file instead of stdout
-        #[arg(long, value_name = "PATH")]
-        output: Option<std::path::PathBuf>,
-    },
-
-    /// Forward unknown subcommands to legacy handler
-    #[command(external_subcommand)]
-    External(Vec<String>),
-}
-
-#[derive(Args, Debug, Clone, Default)]
-pub struct ConfigCommonArgs {
-    /// Treat `value` as a string.
-    #[arg(long)]
-    pub string: bool,
-}
-
 /// Create items.
 #[derive(Args, Debug, Clone)]
 pub struct CreateArgs {
@@ -650,6 +735,10 @@ pub enum CreateAction {
         /// Module name (kebab-case)
         name: Option<String>,
 
+        /// Explicit 3-digit module id (default: next after the highest existing id)
+        #[arg(long)]
+        id: Option<String>,
+
         /// Module scope (comma-separated, default: "*")
         #[arg(long)]
         scope: Option<String>,
@@ -684,6 +773,10 @@ pub enum CreateAction {
         /// Description (writes README.md)
         #[arg(long)]
         description: Option<String>,
+
+        /// Project profile supplying schema, harness, and Ralph defaults
+        #[arg(long)]
+        profile: Option<String>,
     },
 
     /// Create a sub-module under an existing module (e.g. `ito create sub-module auth --module 024`)
@@ -706,92 +799,6 @@ pub enum CreateAction {
     External(Vec<String>),
 }
 
-/// Show a change, spec, or module.
-#[derive(Args, Debug, Clone)]
-#[command(
-    args_conflicts_with_subcommands = true,
-    override_usage = "ito show [OPTIONS] <ITEM>\nito show module <MODULE_ID>\nito show specs [--json]"
-)]
-pub struct ShowArgs {
-    /// Output as JSON
-    #[arg(long)]
-    pub json: bool,
-
-    /// Type: change or spec
-    #[arg(long = "type", value_enum)]
-    pub typ: Option<ShowItemType>,
-
-    /// Disable interactive prompts
-    #[arg(long = "no-interactive")]
-    pub no_interactive: bool,
-
-    /// Change JSON only: only include deltas (deprecated)
-    #[arg(long = "deltas-only")]
-    pub deltas_only: bool,
-
-    /// Change JSON only: only include deltas (deprecated)
-    #[arg(long = "requirements-only")]
-    pub requirements_only: bool,
-
-    /// Spec JSON only: exclude scenarios
-    #[arg(long)]
-    pub requirements: bool,
-
-    /// Spec JSON only: exclude scenarios
-    #[arg(long = "no-scenarios")]
-    pub no_scenarios: bool,
-
-    /// Spec JSON only: select requirement (1-based)
-    #[arg(short = 'r', long = "requirement")]
-    pub requirement: Option<usize>,
-
-    #[command(subcommand)]
-    pub command: Option<ShowCommand>,
-
-    /// Item name (change id or spec id)
-    #[arg(value_name = "ITEM")]
-    pub item: Option<String>,
-}
-
-#[derive(Subcommand, Debug, Clone)]
-pub enum ShowCommand {
-    /// Show a module
-    Module(ShowModuleArgs),
-
-    /// Show a sub-module by composite id (e.g. 024.01)
-    #[command(name = "sub-module", visible_alias = "sm")]
-    SubModule(ShowSubModuleArgs),
-
-    /// Show all specs as one bundled prompt
-    Specs(ShowSpecsArgs),
-}
-
-#[derive(Args, Debug, Clone)]
-pub struct ShowSubModuleArgs {
-    /// Output as JSON
-    #[arg(long)]
-    pub json: bool,
-    /// Sub-module composite id (e.g. 024.01)
-    pub sub_module_id: String,
-}
-
-#[derive(Args, Debug, Clone)]
-pub struct ShowModuleArgs {
-    /// Output as JSON (not implemented)
-    #[arg(long)]
-    pub json: bool,
-
-    /// Module id
-    pub module_id: String,
-}
-
-#[derive(Args, Debug, Clone)]
-pub struct ShowSpecsArgs {
-    /// Output as JSON
-    #[arg(long)]
-    pub json: bool,
-}
-
 /// View Ito artifacts using a selected renderer.
 #[derive(Args, Debug, Clone)]
 #[command(subcommand_required = true, arg_required_else_help = true)]
@@ -822,12 +829,6 @@ pub struct ViewProposalArgs {
     pub viewer: Option<String>,
 }
 
-#[derive(ValueEnum, Debug, Clone, Copy)]
-pub enum ShowItemType {
-    Change,
-    Spec,
-}
-
 /// List items (changes by default).
 #[derive(Args, Debug, Clone)]
 pub struct ListArgs {
@@ -842,8 +843,12 @@ pub struct ListArgs {
     /// List modules instead of changes
     #[arg(long)]
     pub modules: bool,
+
+    /// Print the module dependency graph as Mermaid (implies --modules)
+    #[arg(long, conflicts_with_all = ["specs", "changes", "archived", "ready", "completed", "partial", "pending", "status", "json"])]
+    pub graph: bool,
     /// List archived changes
-    #[arg(long, conflicts_with_all = ["specs", "changes", "modules", "ready", "completed", "partial", "pending", "sort"])]
+    #[arg(long, conflicts_with_all = ["specs", "changes", "modules", "ready", "completed", "partial", "pending", "status", "sort"])]
     pub archived: bool,
 
     /// Filter to changes that pass centralized authoritative prepare readiness
@@ -862,6 +867,18 @@ pub struct ListArgs {
     #[arg(long, conflicts_with_all = ["completed", "partial"])]
     pub pending: bool,
 
+    /// Filter changes by listing status (repeatable; matches any)
+    #[arg(long, value_enum, value_name = "STATUS", conflicts_with_all = ["specs", "modules"])]
+    pub status: Vec<ListStatus>,
+
+    /// Filter changes by proposal label (repeatable; matches all)
+    #[arg(long, value_name = "LABEL", conflicts_with_all = ["specs", "modules", "archived"])]
+    pub label: Vec<String>,
+
+    /// Filter changes by proposal owner
+    #[arg(long, value_name = "OWNER", conflicts_with_all = ["specs", "modules", "archived"])]
+    pub owner: Option<String>,
+
     /// Sort order
     #[arg(long, value_enum, default_value_t = ListSortOrder::Name)]
     pub sort: ListSortOrder,
@@ -869,6 +886,10 @@ pub struct ListArgs {
     /// Output as JSON
     #[arg(long)]
     pub json: bool,
+
+    /// Read state as of a git revision (tag, branch, or commit)
+    #[arg(long, value_name = "REV")]
+    pub at: Option<String>,
 }
 
 /// Arguments for `ito list-archive`.
@@ -882,7 +903,21 @@ pub struct ListArchiveArgs {
 #[derive(ValueEnum, Debug, Clone, Copy)]
 pub enum ListSortOrder {
     Recent,
+    #[value(alias = "id")]
     Name,
+    Status,
+    Progress,
+}
+
+/// Listing status accepted by `ito list --status`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListStatus {
+    Draft,
+    Ready,
+    InProgress,
+    Paused,
+    Complete,
+    Blocked,
 }
 
 #[derive(Args, Debug, Clone)]
@@ -907,6 +942,10 @@ pub struct TasksArgs {
     #[arg(long, global = true)]
     pub json: bool,
 
+    /// Require the change id to be a full change id (no prefix matching)
+    #[arg(long, global = true)]
+    pub exact: bool,
+
     #[command(subcommand)]
     pub action: Option<TasksAction>,
 }
@@ -929,6 +968,14 @@ pub enum TasksAction {
         /// Wave number (optional)
         #[arg(long)]
         wave: Option<u32>,
+
+        /// Show task and wave cycle times
+        #[arg(long)]
+        timings: bool,
+
+        /// Read state as of a git revision (tag, branch, or commit)
+        #[arg(long, value_name = "REV")]
+        at: Option<String>,
     },
 
     /// Show the next available task
@@ -1008,6 +1055,40 @@ pub enum TasksAction {
         change_id: String,
     },
 
+    /// Check tasks.md for structural problems
+    Lint {
+        /// Change id (e.g. 005-08_migrate-cli-to-clap)
+        change_id: String,
+    },
+
+    /// Plan parallel subagent dispatch as ordered batches
+    ///
+    /// Tasks in the same batch are ready together and touch disjoint files.
+    Plan {
+        /// Change id (e.g. 005-08_migrate-cli-to-clap)
+        change_id: String,
+
+        /// Maximum tasks per batch
+        #[arg(long, default_value_t = 4)]
+        max_parallel: usize,
+    },
+
+    /// Show who changed each task's status, and when
+    ///
+    /// Reads task events from the audit log. Humans are shown with their git
+    /// identity; agent harnesses with their model and Ralph iteration.
+    ///
+    /// Examples:
+    ///   ito tasks history 005-01_my-change
+    ///   ito tasks history 005-01_my-change 1.2 --json
+    #[command(verbatim_doc_comment)]
+    History {
+        /// Change id (e.g. 005-08_migrate-cli-to-clap)
+        change_id: String,
+        /// Task id (optional - if omitted, shows every task)
+        task_id: Option<String>,
+    },
+
     /// Claim a change lease (backend mode)
     #[cfg_attr(feature = "backend", command(visible_alias = "cl"))]
     #[cfg_attr(not(feature = "backend"), command(hide = true))]
@@ -1016,12 +1097,32 @@ pub enum TasksAction {
         change_id: String,
     },
 
-    /// Release a change lease (backend mode)
+    /// Return stale in-progress tasks to pending, or release a change lease
+    ///
+    /// With a task id, --older-than, or --all-stale, in-progress tasks idle
+    /// for longer than the threshold (default: tasks.staleAfter, 24h) go back
+    /// to pending so another agent can pick them up. The previous holder is
+    /// recorded in the audit log. With only a change id, releases the change
+    /// lease (backend mode).
+    ///
+    /// Examples:
+    ///   ito tasks release 005-01_my-change --all-stale
+    ///   ito tasks release 005-01_my-change --older-than 3d
+    ///   ito tasks release 005-01_my-change 1.2
+    #[command(verbatim_doc_comment)]
     #[cfg_attr(feature = "backend", command(visible_alias = "rl"))]
-    #[cfg_attr(not(feature = "backend"), command(hide = true))]
     Release {
-        /// Change id to release
+        /// Change id (e.g. 005-08_migrate-cli-to-clap)
         change_id: String,
+        /// Release only this task (it must be stale)
+        #[arg(conflicts_with = "all_stale")]
+        task_id: Option<String>,
+        /// Staleness threshold (e.g. 24h, 3d); overrides tasks.staleAfter
+        #[arg(long, value_name = "DURATION")]
+        older_than: Option<String>,
+        /// Release every stale in-progress task
+        #[arg(long)]
+        all_stale: bool,
     },
 
     /// Allocate the next available change (backend mode)
@@ -1069,95 +1170,3 @@ pub struct DashboardArgs {
     #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
     pub args: Vec<String>,
 }
-
-/// Archive a completed change and update main specs.
-#[derive(Args, Debug, Clone)]
-pub struct ArchiveArgs {
-    /// Change id (directory name)
-    #[arg(
-        value_name = "CHANGE",
-        required_unless_present_any = ["completed", "change_flag"],
-        conflicts_with = "completed",
-    )]
-    pub change: Option<String>,
-
-    /// Change id (directory name)
-    #[arg(short = 'c', long = "change", value_name = "CHANGE", conflicts_with_all = ["completed", "change"])]
-    pub change_flag: Option<String>,
-
-    /// Archive all completed changes (mutually exclusive with CHANGE)
-    #[arg(long = "completed", conflicts_with = "change")]
-    pub completed: bool,
-
-    /// Skip confirmation prompts
-    #[arg(short = 'y', long = "yes")]
-    pub yes: bool,
-
-    /// Skip spec updates
-    #[arg(long = "skip-specs")]
-    pub skip_specs: bool,
-
-    /// Skip validation checks
-    #[arg(long = "no-validate")]
-    pub no_validate: bool,
-}
-
-/// Validate changes, specs, and modules.
-#[derive(Args, Debug, Clone)]
-#[command(args_conflicts_with_subcommands = true)]
-pub struct ValidateArgs {
-    #[command(subcommand)]
-    pub command: Option<ValidateCommand>,
-
-    /// Validate everything
-    #[arg(long)]
-    pub all: bool,
-
-    /// Validate changes
-    #[arg(long)]
-    pub changes: bool,
-
-    /// Validate specs
-    #[arg(long)]
-    pub specs: bool,
-
-    /// Validate modules
-    #[arg(long)]
-    pub modules: bool,
-
-    /// Validate a module by id
-    #[arg(long)]
-    pub module: Option<String>,
-
-    /// Type: change, spec, or module
-    #[arg(long = "type", value_enum)]
-    pub typ: Option<ValidateItemType>,
-
-    /// Treat warnings as errors
-    #[arg(long)]
-    pub strict: bool,
-
-    /// Output as JSON
-    #[arg(long)]
-    pub json: bool,
-
-    /// Concurrency (default: 6)
-    #[arg(long, default_value_t = 6)]
-    pub concurrency: u32,
-
-    /// Disable interactive prompts
-    #[arg(long = "no-interactive")]
-    pub no_interactive: bool,
-
-    /// Run audit consistency checks only
-    #[arg(long)]
-    pub audit: bool,
-
-    /// Skip audit consistency checks
-    #[arg(long = "no-audit", conflicts_with = "audit")]
-    pub no_audit: bool,
-
-    /// Item name (change id or spec id)
-    #[arg(value_name = "ITEM")]
-    pub item: Option<String>,
-}
@@ -1,4 +1,6 @@
-use clap::{Args, Subcommand};
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand, ValueEnum};
 
 macro_rules! agent_instruction_after_help {
     (
@@ -25,6 +27,7 @@ macro_rules! agent_instruction_after_help {
             "  tasks                              Show the implementation task list\n",
             "  apply                              Show implementation instructions\n",
             "  review                             Show review instructions\n",
+            "  next                               Show instructions for the next ready artifact\n",
             "  archive                            Show archive instructions\n",
             "  finish                             Cleanup worktrees and branches after merge\n",
             "  memory-capture                     Capture durable knowledge through configured memory\n",
@@ -47,6 +50,7 @@ macro_rules! agent_instruction_after_help {
             "  ito agent instruction proposal --change 005-08_migrate-cli-to-clap\n",
             "  ito agent instruction apply --change 005-08_migrate-cli-to-clap\n",
             $sync_example,
+            "  ito agent instruction next --change 005-08_migrate-cli-to-clap\n",
             "  ito agent instruction archive\n",
             "  ito agent instruction archive --change 005-08_migrate-cli-to-clap\n",
             "  ito agent instruction finish --change 005-08_migrate-cli-to-clap\n",
@@ -100,6 +104,9 @@ pub enum AgentCommand {
     #[command(visible_alias = "in")]
     Instruction(Box<AgentInstructionArgs>),
 
+    /// Export every instruction for a change for offline agent runs
+    Export(AgentExportArgs),
+
     /// Forward unknown subcommands to legacy handler
     #[command(external_subcommand)]
     External(Vec<String>),
@@ -120,6 +127,10 @@ pub struct AgentInstructionArgs {
     #[arg(short = 'c', long)]
     pub change: Option<String>,
 
+    /// Require --change to be a full change id (no prefix matching)
+    #[arg(long, requires = "change")]
+    pub exact: bool,
+
     /// Tool name for bootstrap (opencode|claude|codex|github-copilot|pi)
     #[arg(long)]
     pub tool: Option<String>,
@@ -132,6 +143,10 @@ pub struct AgentInstructionArgs {
     #[arg(long)]
     pub json: bool,
 
+    /// Render the artifact template with change context pre-filled
+    #[arg(long)]
+    pub rendered: bool,
+
     /// Manifesto output variant (light|full)
     #[arg(long)]
     pub variant: Option<String>,
@@ -175,6 +190,41 @@ pub struct AgentInstructionArgs {
     pub scope: Option<String>,
 }
 
+/// Arguments for `ito agent export`.
+#[derive(Args, Debug, Clone)]
+pub struct AgentExportArgs {
+    /// Change id (directory name)
+    #[arg(value_name = "CHANGE_ID")]
+    pub change: String,
+
+    /// Output directory (defaults to `<change>-instructions` for bundles, stdout for JSON)
+    #[arg(long, value_name = "DIR")]
+    pub out: Option<PathBuf>,
+
+    /// Bundle of numbered markdown files plus manifest.json, or one JSON document
+    #[arg(long, value_enum, default_value_t = AgentExportFormat::Bundle)]
+    pub format: AgentExportFormat,
+
+    /// Workflow schema name
+    #[arg(long)]
+    pub schema: Option<String>,
+
+    /// Maximum bytes of each dependency artifact to inline
+    #[arg(long, value_name = "BYTES", default_value_t = ito_core::templates::DEFAULT_EXPORT_FILE_LIMIT)]
+    pub max_file_bytes: usize,
+
+    /// Record the export time (omitted by default so exports are reproducible)
+    #[arg(long)]
+    pub stamp: bool,
+}
+
+/// Output layout for `ito agent export`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgentExportFormat {
+    Bundle,
+    Json,
+}
+
 impl AgentInstructionArgs {
     /// Convert back to a raw argument vector suitable for the legacy string-based handler.
     ///
@@ -184,9 +234,11 @@ impl AgentInstructionArgs {
         let AgentInstructionArgs {
             artifact,
             change,
+            exact,
             tool,
             schema,
             json,
+            rendered,
             sync,
             variant,
             profile,
@@ -204,6 +256,9 @@ impl AgentInstructionArgs {
             argv.push("--change".to_string());
             argv.push(v.clone());
         }
+        if *exact {
+            argv.push("--exact".to_string());
+        }
         if let Some(v) = tool {
             argv.push("--tool".to_string());
             argv.push(v.clone());
@@ -215,6 +270,9 @@ impl AgentInstructionArgs {
         if *json {
             argv.push("--json".to_string());
         }
+        if *rendered {
+            argv.push("--rendered".to_string());
+        }
         if *sync {
             argv.push("--sync".to_string());
         }
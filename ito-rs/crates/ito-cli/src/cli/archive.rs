@@ -0,0 +1,41 @@
+use clap::Args;
+
+/// Archive a completed change and update main specs.
+#[derive(Args, Debug, Clone)]
+pub struct ArchiveArgs {
+    /// Change id (directory name)
+    #[arg(
+        value_name = "CHANGE",
+        required_unless_present_any = ["completed", "change_flag"],
+        conflicts_with = "completed",
+    )]
+    pub change: Option<String>,
+
+    /// Change id (directory name)
+    #[arg(short = 'c', long = "change", value_name = "CHANGE", conflicts_with_all = ["completed", "change"])]
+    pub change_flag: Option<String>,
+
+    /// Archive all completed changes (mutually exclusive with CHANGE)
+    #[arg(long = "completed", conflicts_with = "change")]
+    pub completed: bool,
+
+    /// Skip confirmation prompts
+    #[arg(short = 'y', long = "yes")]
+    pub yes: bool,
+
+    /// Skip spec updates
+    #[arg(long = "skip-specs")]
+    pub skip_specs: bool,
+
+    /// Skip validation checks
+    #[arg(long = "no-validate")]
+    pub no_validate: bool,
+
+    /// Archive even when work is incomplete or spec deltas conflict
+    #[arg(long = "force")]
+    pub force: bool,
+
+    /// Require CHANGE to be a full change id (no prefix matching)
+    #[arg(long = "exact", conflicts_with = "completed")]
+    pub exact: bool,
+}
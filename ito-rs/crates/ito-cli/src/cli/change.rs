@@ -12,6 +12,19 @@ pub struct ChangeArgs {
 pub enum ChangeCommand {
     /// Prove that a proposal is ready for preparation or implementation.
     Preflight(ChangePreflightArgs),
+
+    /// Accept the current schema as the change's baseline, or pin the stored copy.
+    Rebaseline(ChangeRebaselineArgs),
+
+    /// Rename a change and migrate references, Ralph state, and branches.
+    Rename(ChangeRenameArgs),
+
+    /// Move selected tasks and their delta specs into a new follow-up change.
+    Split(ChangeSplitArgs),
+
+    /// Sync task status and change metadata through the coordination branch.
+    #[cfg_attr(not(feature = "coordination-branch"), command(hide = true))]
+    Sync(ChangeSyncArgs),
 }
 
 /// Arguments for `ito change preflight`.
@@ -33,6 +46,70 @@ pub struct ChangePreflightArgs {
     pub json: bool,
 }
 
+/// Arguments for `ito change rebaseline`.
+#[derive(Args, Debug, Clone)]
+pub struct ChangeRebaselineArgs {
+    /// Full canonical Ito change ID.
+    pub change_id: String,
+
+    /// Keep using the schema copy stored in the change directory instead.
+    #[arg(long)]
+    pub pin: bool,
+
+    /// Output as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Arguments for `ito change rename`.
+#[derive(Args, Debug, Clone)]
+pub struct ChangeRenameArgs {
+    /// Current change id.
+    pub old_id: String,
+
+    /// New slug (keeps the module and sequence prefix) or full change id in the same module.
+    pub new_name: String,
+
+    /// List planned moves and edits without changing anything.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Also rename git branches named after the change.
+    #[arg(long)]
+    pub rename_branch: bool,
+}
+
+/// Arguments for `ito change split`.
+#[derive(Args, Debug, Clone)]
+pub struct ChangeSplitArgs {
+    /// Change id to take the tasks from.
+    pub change_id: String,
+
+    /// Comma-separated ids of the tasks to move (e.g. 2.1,2.3).
+    #[arg(long, value_name = "IDS", value_delimiter = ',', required = true)]
+    pub tasks: Vec<String>,
+
+    /// Slug of the new change; it joins the same module with the next sequence number.
+    #[arg(long, value_name = "SLUG")]
+    pub name: String,
+
+    /// Also move tasks that depend on the selected ones.
+    #[arg(long)]
+    pub with_dependents: bool,
+
+    /// Renumber the tasks left in the original change.
+    #[arg(long)]
+    pub renumber: bool,
+}
+
+/// Arguments for `ito change sync`.
+#[derive(Args, Debug, Clone)]
+pub struct ChangeSyncArgs {
+    /// Output as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
 /// Readiness phase accepted by the preflight CLI.
 #[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ReadinessPhaseArg {
@@ -0,0 +1,71 @@
+use clap::{Args, Subcommand};
+
+/// View and modify global Ito configuration.
+#[derive(Args, Debug, Clone)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: Option<ConfigCommand>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ConfigCommand {
+    /// Print config file path
+    #[command(visible_alias = "pa")]
+    Path(ConfigCommonArgs),
+
+    /// Print config JSON
+    #[command(visible_alias = "ls")]
+    List(ConfigCommonArgs),
+
+    /// Read value by path
+    #[command(visible_alias = "ge")]
+    Get {
+        /// Key path (dot-separated)
+        key: String,
+
+        #[command(flatten)]
+        common: ConfigCommonArgs,
+    },
+
+    /// Set value by path
+    #[command(visible_alias = "se")]
+    Set {
+        /// Key path (dot-separated)
+        key: String,
+
+        /// Value (JSON or string)
+        value: String,
+
+        #[command(flatten)]
+        common: ConfigCommonArgs,
+    },
+
+    /// Remove value by path
+    #[command(visible_alias = "un")]
+    Unset {
+        /// Key path (dot-separated)
+        key: String,
+
+        #[command(flatten)]
+        common: ConfigCommonArgs,
+    },
+
+    /// Print JSON schema for Ito config
+    #[command(visible_alias = "sc")]
+    Schema {
+        /// Write schema to file instead of stdout
+        #[arg(long, value_name = "PATH")]
+        output: Option<std::path::PathBuf>,
+    },
+
+    /// Forward unknown subcommands to legacy handler
+    #[command(external_subcommand)]
+    External(Vec<String>),
+}
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct ConfigCommonArgs {
+    /// Treat `value` as a string.
+    #[arg(long)]
+    pub string: bool,
+}
@@ -3,7 +3,10 @@ use clap::Args;
 /// Initialize Ito instruction files in a project directory.
 #[derive(Args, Debug, Clone)]
 pub struct InitArgs {
-    /// Configure AI tools non-interactively (all, none, or comma-separated ids)
+    /// Configure AI tools non-interactively (all, none, auto, or comma-separated ids)
+    ///
+    /// `auto` installs exactly the tools whose CLI is on PATH or whose
+    /// directory already exists in the project.
     #[arg(long)]
     pub tools: Option<String>,
 
@@ -49,6 +52,14 @@ pub struct InitArgs {
     #[arg(long = "worktree-integration-mode", value_name = "MODE")]
     pub worktree_integration_mode: Option<String>,
 
+    /// Copy specs, changes, and modules from a legacy (OpenSpec-style) directory into .ito/
+    #[arg(long = "migrate-from", value_name = "DIR")]
+    pub migrate_from: Option<String>,
+
+    /// Move legacy entries instead of copying them (requires --migrate-from)
+    #[arg(long = "move", requires = "migrate_from")]
+    pub move_legacy: bool,
+
     /// Override HOME used for locating global Ito config (for parity/testing)
     #[arg(long, value_name = "HOME")]
     pub home: Option<std::path::PathBuf>,
@@ -65,6 +76,18 @@ pub struct UpdateArgs {
     #[arg(long)]
     pub json: bool,
 
+    /// AI tools to refresh (all, none, or comma-separated ids; defaults to the tools recorded by init)
+    #[arg(long)]
+    pub tools: Option<String>,
+
+    /// Overwrite Ito-managed files even when they were modified locally
+    #[arg(long = "force-managed")]
+    pub force_managed: bool,
+
+    /// Install templates from this release's bundle instead of the embedded ones (overrides templates.pin)
+    #[arg(long, value_name = "TAG")]
+    pub channel: Option<String>,
+
     /// Enable Git worktree-based workspace layout
     #[arg(long = "worktrees", conflicts_with = "no_worktrees")]
     pub worktrees: bool,
@@ -0,0 +1,29 @@
+use clap::{Args, Subcommand};
+
+/// Inspect the models Ito agents are installed with.
+#[derive(Args, Debug, Clone)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+pub struct ModelsArgs {
+    #[command(subcommand)]
+    pub command: ModelsCommand,
+}
+
+/// Models subcommands.
+#[derive(Subcommand, Debug, Clone)]
+pub enum ModelsCommand {
+    /// Show the effective model per harness and agent tier
+    ///
+    /// Lists the model each installed `ito-quick`, `ito-general` and
+    /// `ito-thinking` agent is rendered with, and whether it is pinned in the
+    /// `models` config section or the embedded default. Run `ito update` to
+    /// apply changed pins to installed agent files.
+    Pins(ModelsPinsArgs),
+}
+
+/// Arguments for `ito models pins`.
+#[derive(Args, Debug, Clone)]
+pub struct ModelsPinsArgs {
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
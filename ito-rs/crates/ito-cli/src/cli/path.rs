@@ -57,3 +57,11 @@ pub struct PathRootsArgs {
     #[command(flatten)]
     pub common: PathCommonArgs,
 }
+
+/// Show every location Ito may read or write for this project.
+#[derive(Args, Debug, Clone)]
+pub struct PathsArgs {
+    /// Output as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
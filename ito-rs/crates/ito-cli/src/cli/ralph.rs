@@ -28,6 +28,34 @@ impl From<HarnessArg> for ito_core::harness::HarnessName {
     }
 }
 
+impl From<ito_config::types::ProfileHarness> for HarnessArg {
+    fn from(value: ito_config::types::ProfileHarness) -> Self {
+        match value {
+            ito_config::types::ProfileHarness::Opencode => HarnessArg::Opencode,
+            ito_config::types::ProfileHarness::Claude => HarnessArg::Claude,
+            ito_config::types::ProfileHarness::Codex => HarnessArg::Codex,
+            ito_config::types::ProfileHarness::Copilot => HarnessArg::Copilot,
+        }
+    }
+}
+
+/// CLI-facing selector for `ito ralph --completion-format`.
+#[derive(ValueEnum, Debug, Clone, Copy, Default)]
+pub enum CompletionFormatArg {
+    #[default]
+    Tag,
+    Json,
+}
+
+impl From<CompletionFormatArg> for ito_core::ralph::CompletionFormat {
+    fn from(value: CompletionFormatArg) -> Self {
+        match value {
+            CompletionFormatArg::Tag => ito_core::ralph::CompletionFormat::Tag,
+            CompletionFormatArg::Json => ito_core::ralph::CompletionFormat::Json,
+        }
+    }
+}
+
 /// Run iterative AI loop against a change proposal.
 #[derive(Args, Debug, Clone)]
 pub struct RalphArgs {
@@ -35,6 +63,10 @@ pub struct RalphArgs {
     #[arg(short = 'c', long)]
     pub change: Option<String>,
 
+    /// Require --change to be a full change id (no prefix matching)
+    #[arg(long, requires = "change")]
+    pub exact: bool,
+
     /// Target a module.
     ///
     /// Note: when running with `--no-interactive`, `--module` implies `--continue-module`.
@@ -65,6 +97,15 @@ pub struct RalphArgs {
     /// Completion promise token
     #[arg(long = "completion-promise", default_value = "COMPLETE")]
     pub completion_promise: String,
+    /// How the harness signals completion
+    #[arg(long = "completion-format", value_enum, default_value_t = CompletionFormatArg::Tag)]
+    pub completion_format: CompletionFormatArg,
+    /// Tag wrapping the completion token in tag format
+    #[arg(long = "completion-tag", default_value = "promise")]
+    pub completion_tag: String,
+    /// JSON pointer to the status field in json format (default: /status)
+    #[arg(long = "completion-json-pointer", value_name = "POINTER")]
+    pub completion_json_pointer: Option<String>,
     /// Skip completion validation (tasks + project checks/tests)
     ///
     /// When set, Ralph trusts the completion promise and exits immediately.
@@ -87,6 +128,26 @@ pub struct RalphArgs {
     /// Do not create git commits per iteration
     #[arg(long = "no-commit")]
     pub no_commit: bool,
+    /// Recent loop commits summarized in each prompt
+    #[arg(
+        long = "git-context-commits",
+        value_name = "N",
+        default_value_t = ito_core::ralph::DEFAULT_GIT_CONTEXT_COMMITS
+    )]
+    pub git_context_commits: usize,
+    /// Character cap for the recent git activity prompt section
+    #[arg(
+        long = "git-context-max-chars",
+        value_name = "N",
+        default_value_t = ito_core::ralph::DEFAULT_GIT_CONTEXT_MAX_CHARS
+    )]
+    pub git_context_max_chars: usize,
+    /// Do not include recent git activity in prompts
+    #[arg(long = "no-git-context")]
+    pub no_git_context: bool,
+    /// Fail instead of warning when a prompt likely exceeds the model's context window
+    #[arg(long = "strict-budget")]
+    pub strict_budget: bool,
     /// Show current Ralph state for the change
     #[arg(long)]
     pub status: bool,
@@ -99,15 +160,33 @@ pub struct RalphArgs {
     /// Do not prompt for selections
     #[arg(long = "no-interactive")]
     pub no_interactive: bool,
-    /// Verbose output
-    #[arg(short = 'v', long)]
-    pub verbose: bool,
     /// Hidden testing flag
     #[arg(long = "stub-script", hide = true)]
     pub stub_script: Option<String>,
     /// Inactivity timeout (e.g. 15m)
     #[arg(long = "timeout")]
     pub timeout: Option<String>,
+    /// Treat in-progress tasks untouched for this long as available (e.g. 24h)
+    #[arg(long = "steal-stale", value_name = "DURATION")]
+    pub steal_stale: Option<String>,
+    /// Resume the previous harness session after crashes and inactivity restarts
+    #[arg(long = "resume-sessions")]
+    pub resume_sessions: bool,
+    /// Check the harness can run (binary, auth, model) before the first iteration
+    #[arg(long = "preflight")]
+    pub preflight: bool,
+    /// Pass an extra environment variable through the harness env policy (repeatable)
+    #[arg(long = "env-allow", value_name = "VAR")]
+    pub env_allow: Vec<String>,
+    /// Apply the named tool permission policy from `permission_profiles`
+    #[arg(long = "permissions-profile", value_name = "NAME")]
+    pub permissions_profile: Option<String>,
+    /// Record a transcript of each harness run under .ito/.state/transcripts (default)
+    #[arg(long = "transcript", overrides_with = "no_transcript")]
+    pub transcript: bool,
+    /// Do not record harness run transcripts
+    #[arg(long = "no-transcript", overrides_with = "transcript")]
+    pub no_transcript: bool,
     /// Read prompt text from a file
     #[arg(long = "file", value_name = "FILE")]
     pub file: Option<String>,
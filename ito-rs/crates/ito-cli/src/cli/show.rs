@@ -0,0 +1,155 @@
+use clap::{Args, Subcommand, ValueEnum};
+
+/// Show a change, spec, or module.
+#[derive(Args, Debug, Clone)]
+#[command(
+    args_conflicts_with_subcommands = true,
+    override_usage = "ito show [OPTIONS] <ITEM>\nito show module <MODULE_ID>\nito show specs [--json]\nito show coverage [SPEC_ID] [--json]\nito show diff <CHANGE_A> <CHANGE_B> [--artifact <ID>] [--json]\nito show timeline <CHANGE_ID> [--commits <N>] [--json]"
+)]
+pub struct ShowArgs {
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Type: change or spec
+    #[arg(long = "type", value_enum)]
+    pub typ: Option<ShowItemType>,
+
+    /// Disable interactive prompts
+    #[arg(long = "no-interactive")]
+    pub no_interactive: bool,
+
+    /// Change JSON only: only include deltas (deprecated)
+    #[arg(long = "deltas-only")]
+    pub deltas_only: bool,
+
+    /// Change JSON only: only include deltas (deprecated)
+    #[arg(long = "requirements-only")]
+    pub requirements_only: bool,
+
+    /// Spec JSON only: exclude scenarios
+    #[arg(long)]
+    pub requirements: bool,
+
+    /// Spec JSON only: exclude scenarios
+    #[arg(long = "no-scenarios")]
+    pub no_scenarios: bool,
+
+    /// Spec only: select a requirement by anchor or 1-based index
+    #[arg(short = 'r', long = "requirement")]
+    pub requirement: Option<String>,
+
+    /// Change only: preview a spec as it would look after archiving the change
+    #[arg(long = "preview-spec", value_name = "SPEC")]
+    pub preview_spec: Option<String>,
+
+    /// Read state as of a git revision (tag, branch, or commit)
+    #[arg(long, value_name = "REV")]
+    pub at: Option<String>,
+
+    /// Change only: require a full change id (no prefix matching)
+    #[arg(long)]
+    pub exact: bool,
+
+    #[command(subcommand)]
+    pub command: Option<ShowCommand>,
+
+    /// Item name (change id or spec id)
+    #[arg(value_name = "ITEM")]
+    pub item: Option<String>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ShowCommand {
+    /// Show a module
+    Module(ShowModuleArgs),
+
+    /// Show a sub-module by composite id (e.g. 024.01)
+    #[command(name = "sub-module", visible_alias = "sm")]
+    SubModule(ShowSubModuleArgs),
+
+    /// Show all specs as one bundled prompt
+    Specs(ShowSpecsArgs),
+
+    /// Report which changes touch each spec requirement
+    Coverage(ShowCoverageArgs),
+
+    /// Compare the artifacts and tasks of two changes
+    Diff(ShowDiffArgs),
+
+    /// Merge audit events, Ralph iterations, and git commits for a change
+    Timeline(ShowTimelineArgs),
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct ShowSubModuleArgs {
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+    /// Sub-module composite id (e.g. 024.01)
+    pub sub_module_id: String,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct ShowModuleArgs {
+    /// Output as JSON (not implemented)
+    #[arg(long)]
+    pub json: bool,
+
+    /// Module id
+    pub module_id: String,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct ShowSpecsArgs {
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct ShowCoverageArgs {
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Spec id (defaults to every spec)
+    pub spec_id: Option<String>,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct ShowDiffArgs {
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Only compare this schema artifact (e.g. proposal, specs, tasks)
+    #[arg(long, value_name = "ID")]
+    pub artifact: Option<String>,
+
+    /// First change id (active or archived)
+    pub change_a: String,
+
+    /// Second change id (active or archived)
+    pub change_b: String,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct ShowTimelineArgs {
+    /// Output as JSON (a flat array sorted by time)
+    #[arg(long)]
+    pub json: bool,
+
+    /// Maximum number of git commits to include (most recent first)
+    #[arg(long, value_name = "N", default_value_t = ito_core::show::timeline::DEFAULT_COMMIT_LIMIT)]
+    pub commits: usize,
+
+    /// Change id (active or archived)
+    pub change_id: String,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum ShowItemType {
+    Change,
+    Spec,
+}
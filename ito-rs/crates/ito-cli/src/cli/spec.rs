@@ -0,0 +1,29 @@
+use clap::{Args, Subcommand};
+
+/// Manage promoted specs.
+#[derive(Args, Debug, Clone)]
+pub struct SpecArgs {
+    #[command(subcommand)]
+    pub command: SpecCommand,
+}
+
+/// Supported `ito spec` operations.
+#[derive(Subcommand, Debug, Clone)]
+pub enum SpecCommand {
+    /// Rename a spec and update active changes that reference it.
+    Rename(SpecRenameArgs),
+}
+
+/// Arguments for `ito spec rename`.
+#[derive(Args, Debug, Clone)]
+pub struct SpecRenameArgs {
+    /// Current spec id (directory name under `.ito/specs/`).
+    pub old_id: String,
+
+    /// New spec id.
+    pub new_id: String,
+
+    /// List planned moves and edits without changing anything.
+    #[arg(long)]
+    pub dry_run: bool,
+}
@@ -0,0 +1,55 @@
+use clap::{Args, Subcommand};
+
+/// Show local execution usage stats.
+#[derive(Args, Debug, Clone)]
+pub struct StatsArgs {
+    #[command(subcommand)]
+    pub command: Option<StatsCommand>,
+}
+
+/// Stats subcommands.
+#[derive(Subcommand, Debug, Clone)]
+pub enum StatsCommand {
+    /// Estimate harness cost per change and model from recorded token usage
+    ///
+    /// Totals token usage recorded by `ito ralph` and `ito run artifact` and
+    /// prices it with the per-model rates in the `stats.pricing` config key.
+    /// Runs without reported usage and models without a price are shown as
+    /// "unknown" rather than zero.
+    Cost(StatsCostArgs),
+
+    /// Count skill invocations per skill and per harness
+    ///
+    /// Aggregates the `skill.invoked` events recorded by the adapter hooks.
+    /// Skills that were not in the skill catalog when invoked are flagged as
+    /// unknown.
+    Skills(StatsSkillsArgs),
+}
+
+/// Arguments for `ito stats cost`.
+#[derive(Args, Debug, Clone)]
+pub struct StatsCostArgs {
+    /// Only include runs for this change (supports prefixes like 014-03)
+    #[arg(long)]
+    pub change: Option<String>,
+
+    /// Only include runs from this recent window (e.g. 30d, 12h)
+    #[arg(long, value_name = "DURATION")]
+    pub since: Option<String>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Arguments for `ito stats skills`.
+#[derive(Args, Debug, Clone)]
+pub struct StatsSkillsArgs {
+    /// Only include invocations since this time (e.g. 30d, 12h, 2025-01-01)
+    #[arg(long, value_name = "TIME")]
+    pub since: Option<String>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
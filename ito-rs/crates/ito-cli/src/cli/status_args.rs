@@ -26,4 +26,8 @@ pub struct StatusArgs {
     /// Output as JSON
     #[arg(long)]
     pub json: bool,
+
+    /// Read state as of a git revision (tag, branch, or commit)
+    #[arg(long, value_name = "REV")]
+    pub at: Option<String>,
 }
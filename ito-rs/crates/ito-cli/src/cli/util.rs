@@ -28,6 +28,21 @@ pub enum UtilCommand {
     ///   ito util parse-id
     #[command(verbatim_doc_comment)]
     ParseId(ParseIdArgs),
+
+    /// Record that an agent harness invoked a skill.
+    ///
+    /// Called by the Ito adapter hooks; appends a `skill.invoked` audit event
+    /// that `ito stats skills` aggregates. Ids missing from the skill catalog
+    /// are recorded but flagged as unknown.
+    ///
+    /// Always exits 0 so it can never break the calling hook; failures are
+    /// written to .ito/.state/stats/record-skill.log.
+    ///
+    /// Examples:
+    ///   ito util record-skill ito-apply --harness opencode --change 005-01_add-auth
+    ///   echo "$HOOK_PAYLOAD" | ito util record-skill --harness claude --hook-payload
+    #[command(verbatim_doc_comment)]
+    RecordSkill(RecordSkillArgs),
 }
 
 /// Arguments for `ito util parse-id`.
@@ -39,3 +54,23 @@ pub struct ParseIdArgs {
     #[arg(trailing_var_arg = true)]
     pub input: Vec<String>,
 }
+
+/// Arguments for `ito util record-skill`.
+#[derive(Args, Debug, Clone)]
+pub struct RecordSkillArgs {
+    /// Skill id (e.g. ito-apply)
+    #[arg(required_unless_present = "hook_payload")]
+    pub skill: Option<String>,
+
+    /// Harness that invoked the skill (defaults to $ITO_AGENT_HARNESS)
+    #[arg(long)]
+    pub harness: Option<String>,
+
+    /// Change the agent is working on
+    #[arg(long)]
+    pub change: Option<String>,
+
+    /// Read the skill id from a harness hook payload on stdin (`tool_input.skill`)
+    #[arg(long)]
+    pub hook_payload: bool,
+}
@@ -1,5 +1,93 @@
 use clap::{Args, Subcommand, ValueEnum};
 
+/// Validate changes, specs, and modules.
+#[derive(Args, Debug, Clone)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct ValidateArgs {
+    #[command(subcommand)]
+    pub command: Option<ValidateCommand>,
+
+    /// Validate everything
+    #[arg(long)]
+    pub all: bool,
+
+    /// Validate changes
+    #[arg(long)]
+    pub changes: bool,
+
+    /// Validate specs
+    #[arg(long)]
+    pub specs: bool,
+
+    /// Validate modules
+    #[arg(long)]
+    pub modules: bool,
+
+    /// Validate a module by id
+    #[arg(long)]
+    pub module: Option<String>,
+
+    /// Type: change, spec, or module
+    #[arg(long = "type", value_enum)]
+    pub typ: Option<ValidateItemType>,
+
+    /// Treat warnings as errors
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Print built-in and custom (`validation.custom_rules`) rules with their
+    /// levels, then exit without validating anything
+    #[arg(long = "list-rules")]
+    pub list_rules: bool,
+
+    /// Concurrency (default: 6)
+    #[arg(long, default_value_t = 6)]
+    pub concurrency: u32,
+
+    /// Disable interactive prompts
+    #[arg(long = "no-interactive")]
+    pub no_interactive: bool,
+
+    /// Run audit consistency checks only
+    #[arg(long)]
+    pub audit: bool,
+
+    /// Skip audit consistency checks
+    #[arg(long = "no-audit", conflicts_with = "audit")]
+    pub no_audit: bool,
+
+    /// Apply mechanical fixes, then validate again
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Skip `validate.command` entries declared by change schemas (builtin
+    /// validators still run)
+    #[arg(long = "no-artifact-commands")]
+    pub no_artifact_commands: bool,
+
+    /// Also run `validate.command` entries from user, embedded, and package
+    /// schemas (project-local schemas always run)
+    #[arg(long = "trust-embedded", conflicts_with = "no_artifact_commands")]
+    pub trust_embedded: bool,
+
+    /// Re-run validation when files change and report what changed (Ctrl-C to stop)
+    #[arg(long, conflicts_with_all = ["json", "fix", "list_rules"])]
+    pub watch: bool,
+
+    /// Extra glob to ignore while watching (repeatable; `.state/` and editor
+    /// temp files are always ignored)
+    #[arg(long = "watch-ignore", value_name = "GLOB", requires = "watch")]
+    pub watch_ignore: Vec<String>,
+
+    /// Item name (change id or spec id)
+    #[arg(value_name = "ITEM")]
+    pub item: Option<String>,
+}
+
 #[derive(Subcommand, Debug, Clone)]
 pub enum ValidateCommand {
     /// Validate a module
@@ -0,0 +1,42 @@
+use clap::{Args, Subcommand};
+
+/// Adjust how a change moves through its schema workflow.
+#[derive(Args, Debug, Clone)]
+pub struct WorkflowArgs {
+    #[command(subcommand)]
+    pub command: WorkflowCommand,
+}
+
+/// Supported `ito workflow` operations.
+#[derive(Subcommand, Debug, Clone)]
+pub enum WorkflowCommand {
+    /// Mark a schema artifact as not applicable to a change.
+    Skip(WorkflowSkipArgs),
+
+    /// Remove a previously recorded artifact skip.
+    Unskip(WorkflowUnskipArgs),
+}
+
+/// Arguments for `ito workflow skip`.
+#[derive(Args, Debug, Clone)]
+pub struct WorkflowSkipArgs {
+    /// Change id.
+    pub change: String,
+
+    /// Schema artifact id (e.g. `design`).
+    pub artifact: String,
+
+    /// Why the artifact does not apply to this change.
+    #[arg(long)]
+    pub reason: String,
+}
+
+/// Arguments for `ito workflow unskip`.
+#[derive(Args, Debug, Clone)]
+pub struct WorkflowUnskipArgs {
+    /// Change id.
+    pub change: String,
+
+    /// Schema artifact id.
+    pub artifact: String,
+}
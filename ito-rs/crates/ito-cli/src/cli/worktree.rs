@@ -13,6 +13,15 @@ pub struct WorktreeArgs {
 /// Sub-commands under `ito worktree`.
 #[derive(Subcommand, Debug, Clone)]
 pub enum WorktreeCommand {
+    /// Create a git worktree for a change
+    ///
+    /// Adds a worktree on a new branch (default `ito/<change-id>`) under the
+    /// configured layout directory and copies `worktrees.apply.copy_from_main`
+    /// files from the main worktree. Unlike `ensure`, no readiness checks or
+    /// setup commands are run. Prints the new worktree path.
+    #[command(verbatim_doc_comment)]
+    Create(WorktreeCreateArgs),
+
     /// Ensure the correct change worktree exists and is initialized
     ///
     /// Resolves the expected worktree path for a change, proves prepare
@@ -49,6 +58,25 @@ pub struct WorktreeChangeArgs {
     pub change: String,
 }
 
+/// Arguments for `ito worktree create`.
+#[derive(Args, Debug, Clone)]
+pub struct WorktreeCreateArgs {
+    /// Change ID to create a worktree for (e.g. `012-05_my-change`)
+    pub change_id: String,
+
+    /// Branch name to create (default: `ito/<change-id>`)
+    #[arg(long)]
+    pub branch: Option<String>,
+
+    /// Check out an existing branch instead of creating one
+    #[arg(long)]
+    pub existing_branch: bool,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
 /// Arguments for validating the current checkout against a change worktree.
 #[derive(Args, Debug, Clone)]
 pub struct WorktreeValidateArgs {
@@ -1,12 +1,84 @@
 use std::fmt;
 
+use ito_core::DomainError;
 use ito_core::errors::CoreError;
 
+/// Failure category behind Ito's exit-code contract (`ito help exit-codes`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCategory {
+    /// Any failure without a more specific category (exit 1).
+    Failure,
+    /// Invalid arguments or usage (exit 2).
+    Usage,
+    /// A requested change, module, spec or task does not exist (exit 3).
+    NotFound,
+    /// Validation of artifacts or input failed (exit 4).
+    Validation,
+    /// A subprocess or harness failed (exit 5).
+    Process,
+    /// An unexpected internal error (exit 10).
+    Internal,
+}
+
+impl ErrorCategory {
+    /// Process exit code for this category.
+    pub fn exit_code(self) -> i32 {
+        match self {
+            Self::Failure => 1,
+            Self::Usage => 2,
+            Self::NotFound => 3,
+            Self::Validation => 4,
+            Self::Process => 5,
+            Self::Internal => 10,
+        }
+    }
+
+    /// Category whose exit code is `exit_code`, if any.
+    pub fn from_exit_code(exit_code: i32) -> Option<Self> {
+        [
+            Self::Failure,
+            Self::Usage,
+            Self::NotFound,
+            Self::Validation,
+            Self::Process,
+            Self::Internal,
+        ]
+        .into_iter()
+        .find(|c| c.exit_code() == exit_code)
+    }
+
+    /// Generic `error.code` for errors without a more specific code.
+    pub fn default_code(self) -> &'static str {
+        match self {
+            Self::Failure => "ERROR",
+            Self::Usage => "USAGE_ERROR",
+            Self::NotFound => "NOT_FOUND",
+            Self::Validation => "VALIDATION_FAILED",
+            Self::Process => "PROCESS_FAILED",
+            Self::Internal => "INTERNAL_ERROR",
+        }
+    }
+
+    /// Stable snake_case name used in JSON output.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Failure => "failure",
+            Self::Usage => "usage",
+            Self::NotFound => "not_found",
+            Self::Validation => "validation",
+            Self::Process => "process",
+            Self::Internal => "internal",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CliError {
     message: String,
     silent: bool,
     exit_code: i32,
+    category: ErrorCategory,
+    code: &'static str,
     #[allow(dead_code)]
     feature_unavailable: Option<FeatureUnavailableDetails>,
 }
@@ -21,44 +93,72 @@ struct FeatureUnavailableDetails {
 
 impl CliError {
     pub fn msg(message: impl Into<String>) -> Self {
-        Self {
-            message: message.into(),
-            silent: false,
-            exit_code: 1,
-            feature_unavailable: None,
-        }
+        Self::categorized(ErrorCategory::Failure, message)
     }
 
     pub fn silent() -> Self {
         Self {
-            message: String::new(),
             silent: true,
-            exit_code: 1,
+            ..Self::msg(String::new())
+        }
+    }
+
+    /// Construct a `CliError` in `category`, exiting with its exit code.
+    pub fn categorized(category: ErrorCategory, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            silent: false,
+            exit_code: category.exit_code(),
+            category,
+            code: category.default_code(),
             feature_unavailable: None,
         }
     }
 
+    /// Invalid arguments or usage (exit 2).
+    pub fn usage(message: impl Into<String>) -> Self {
+        Self::categorized(ErrorCategory::Usage, message)
+    }
+
+    /// A missing entity (exit 3), with a specific `code` such as `CHANGE_NOT_FOUND`.
+    pub fn not_found(code: &'static str, message: impl Into<String>) -> Self {
+        Self::categorized(ErrorCategory::NotFound, message).with_error_code(code)
+    }
+
+    /// Failed validation (exit 4).
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::categorized(ErrorCategory::Validation, message)
+    }
+
+    /// Replace the machine-readable `error.code` reported in JSON output.
+    pub fn with_error_code(mut self, code: &'static str) -> Self {
+        self.code = code;
+        self
+    }
+
     /// Construct a `CliError` with a specific exit code.
     ///
-    /// Used by `ito validate repo` to honour the documented exit codes:
-    /// `1` for validation failures and `2` for usage errors / unloadable
-    /// configuration.
+    /// Used where a command documents its own exit codes (e.g. Ralph's
+    /// cancellation code). Codes from the exit-code contract keep their
+    /// category.
     pub fn with_code(exit_code: i32, message: impl Into<String>) -> Self {
+        let category = ErrorCategory::from_exit_code(exit_code).unwrap_or(ErrorCategory::Failure);
         Self {
-            message: message.into(),
-            silent: false,
             exit_code,
-            feature_unavailable: None,
+            ..Self::categorized(category, message)
         }
     }
 
+    /// Construct a `CliError` whose message is followed by a `help:` hint.
+    pub fn with_help(message: impl Into<String>, help: impl Into<String>) -> Self {
+        Self::msg(format!("{}\n\n  help: {}", message.into(), help.into()))
+    }
+
     /// Construct a silent `CliError` with a specific exit code.
     pub fn silent_with_code(exit_code: i32) -> Self {
         Self {
-            message: String::new(),
             silent: true,
-            exit_code,
-            feature_unavailable: None,
+            ..Self::with_code(exit_code, String::new())
         }
     }
 
@@ -74,13 +174,12 @@ impl CliError {
             recovery: recovery.into(),
         };
         Self {
-            message: format!(
+            feature_unavailable: Some(details.clone()),
+            ..Self::msg(format!(
                 "feature '{}' is unavailable (requested by {}). Recovery: {}",
                 details.feature, details.requested_by, details.recovery
-            ),
-            silent: false,
-            exit_code: 1,
-            feature_unavailable: Some(details),
+            ))
+            .with_error_code("FEATURE_UNAVAILABLE")
         }
     }
 
@@ -90,6 +189,7 @@ impl CliError {
             serde_json::json!({
                 "error": {
                     "kind": "feature_unavailable",
+                    "code": self.error_code(),
                     "feature": details.feature,
                     "requested_by": details.requested_by,
                     "recovery": details.recovery,
@@ -98,15 +198,56 @@ impl CliError {
         })
     }
 
-    /// Preserve typed core capability errors at the CLI presentation boundary.
+    /// Machine-readable representation printed for `--json` invocations.
+    pub fn to_json(&self) -> serde_json::Value {
+        if let Some(value) = self.feature_unavailable_json() {
+            return value;
+        }
+        serde_json::json!({
+            "error": {
+                "code": self.error_code(),
+                "category": self.category().as_str(),
+                "exit_code": self.exit_code(),
+                "message": self.message,
+            }
+        })
+    }
+
+    /// Preserve typed core errors at the CLI presentation boundary.
+    ///
+    /// The error's category (and so the exit code) follows the core variant;
+    /// see `ito help exit-codes`.
     pub fn from_core(error: CoreError) -> Self {
+        let message = error.to_string();
         match error {
             CoreError::FeatureUnavailable {
                 feature,
                 requested_by,
                 recovery,
             } => Self::feature_unavailable(feature.as_str(), requested_by, recovery),
-            other => Self::msg(other.to_string()),
+            CoreError::Domain(domain) => Self::from_domain(domain),
+            CoreError::NotFound(_) => Self::categorized(ErrorCategory::NotFound, message),
+            CoreError::Validation(_) => Self::validation(message),
+            CoreError::Parse(_) => Self::validation(message).with_error_code("PARSE_ERROR"),
+            CoreError::Process(_) => Self::categorized(ErrorCategory::Process, message),
+            CoreError::Io { .. } => Self::msg(message).with_error_code("IO_ERROR"),
+            CoreError::Sqlite(_) | CoreError::Serde { .. } => {
+                Self::categorized(ErrorCategory::Internal, message)
+            }
+        }
+    }
+
+    /// Preserve typed domain errors at the CLI presentation boundary.
+    pub fn from_domain(error: DomainError) -> Self {
+        let message = error.to_string();
+        match error {
+            DomainError::NotFound { entity, .. } => {
+                Self::not_found(not_found_code(entity), message)
+            }
+            DomainError::AmbiguousTarget { .. } => {
+                Self::usage(message).with_error_code("AMBIGUOUS_TARGET")
+            }
+            DomainError::Io { .. } => Self::msg(message).with_error_code("IO_ERROR"),
         }
     }
 
@@ -118,6 +259,27 @@ impl CliError {
     pub fn exit_code(&self) -> i32 {
         self.exit_code
     }
+
+    /// Failure category of this error.
+    pub fn category(&self) -> ErrorCategory {
+        self.category
+    }
+
+    /// Machine-readable error code (e.g. `CHANGE_NOT_FOUND`).
+    pub fn error_code(&self) -> &'static str {
+        self.code
+    }
+}
+
+/// `error.code` for a missing domain entity.
+fn not_found_code(entity: &str) -> &'static str {
+    match entity {
+        "change" => "CHANGE_NOT_FOUND",
+        "module" => "MODULE_NOT_FOUND",
+        "spec" => "SPEC_NOT_FOUND",
+        "task" => "TASK_NOT_FOUND",
+        _ => ErrorCategory::NotFound.default_code(),
+    }
 }
 
 impl fmt::Display for CliError {
@@ -138,8 +300,61 @@ pub fn silent_fail<T>() -> CliResult<T> {
     Err(CliError::silent())
 }
 
-pub fn to_cli_error<E: fmt::Display>(e: E) -> CliError {
-    CliError::msg(e.to_string())
+impl From<CoreError> for CliError {
+    fn from(error: CoreError) -> Self {
+        Self::from_core(error)
+    }
+}
+
+impl From<DomainError> for CliError {
+    fn from(error: DomainError) -> Self {
+        Self::from_domain(error)
+    }
+}
+
+impl From<String> for CliError {
+    fn from(message: String) -> Self {
+        Self::msg(message)
+    }
+}
+
+impl From<&str> for CliError {
+    fn from(message: &str) -> Self {
+        Self::msg(message)
+    }
+}
+
+/// Errors without a category of their own become generic failures carrying
+/// their display text.
+macro_rules! impl_from_uncategorized {
+    ($($error:ty),+ $(,)?) => {
+        $(
+            impl From<$error> for CliError {
+                fn from(error: $error) -> Self {
+                    Self::msg(error.to_string())
+                }
+            }
+        )+
+    };
+}
+
+impl_from_uncategorized!(
+    std::io::Error,
+    serde_json::Error,
+    miette::Report,
+    dialoguer::Error,
+    ito_core::TaskMutationError,
+    ito_core::templates::WorkflowError,
+    ito_core::create::CreateError,
+    crate::diagnostics::LegacyCoordinationMutationBlocked,
+);
+
+/// Convert an error into a [`CliError`].
+///
+/// Core and domain errors keep their category (and exit code); see
+/// `ito help exit-codes`.
+pub fn to_cli_error<E: Into<CliError>>(e: E) -> CliError {
+    e.into()
 }
 
 #[cfg(test)]
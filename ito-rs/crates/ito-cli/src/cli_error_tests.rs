@@ -1,7 +1,8 @@
+use ito_core::DomainError;
 use ito_core::capabilities::CompiledFeature;
 use ito_core::errors::CoreError;
 
-use super::CliError;
+use super::{CliError, ErrorCategory, to_cli_error};
 
 #[test]
 fn core_feature_error_retains_stable_json_fields() {
@@ -13,6 +14,7 @@ fn core_feature_error_retains_stable_json_fields() {
     let json = error.feature_unavailable_json().expect("typed JSON");
 
     assert_eq!(json["error"]["kind"], "feature_unavailable");
+    assert_eq!(json["error"]["code"], "FEATURE_UNAVAILABLE");
     assert_eq!(json["error"]["feature"], "coordination-branch");
     assert_eq!(
         json["error"]["requested_by"],
@@ -20,3 +22,67 @@ fn core_feature_error_retains_stable_json_fields() {
     );
     assert_eq!(json["error"]["recovery"], "migrate-to-main");
 }
+
+#[test]
+fn core_errors_map_onto_the_exit_code_contract() {
+    let cases = [
+        (CoreError::not_found("missing"), 3, "NOT_FOUND"),
+        (CoreError::validation("bad"), 4, "VALIDATION_FAILED"),
+        (CoreError::process("harness crashed"), 5, "PROCESS_FAILED"),
+        (
+            CoreError::io("reading file", std::io::Error::other("boom")),
+            1,
+            "IO_ERROR",
+        ),
+    ];
+
+    for (core, exit_code, code) in cases {
+        let error = CliError::from_core(core);
+        assert_eq!(error.exit_code(), exit_code, "{error}");
+        assert_eq!(error.error_code(), code, "{error}");
+    }
+}
+
+#[test]
+fn domain_not_found_reports_entity_specific_code() {
+    let error = to_cli_error(CoreError::Domain(DomainError::NotFound {
+        entity: "change",
+        id: "001-01_missing".to_string(),
+    }));
+
+    assert_eq!(error.category(), ErrorCategory::NotFound);
+    assert_eq!(error.exit_code(), 3);
+    assert_eq!(error.error_code(), "CHANGE_NOT_FOUND");
+}
+
+#[test]
+fn untyped_errors_are_generic_failures() {
+    let error = to_cli_error("something went wrong".to_string());
+
+    assert_eq!(error.exit_code(), 1);
+    assert_eq!(error.error_code(), "ERROR");
+    assert_eq!(error.to_string(), "something went wrong");
+}
+
+#[test]
+fn explicit_exit_codes_keep_their_category() {
+    assert_eq!(
+        CliError::with_code(2, "bad flag").category(),
+        ErrorCategory::Usage
+    );
+    assert_eq!(
+        CliError::with_code(130, "cancelled").category(),
+        ErrorCategory::Failure
+    );
+    assert_eq!(CliError::with_code(130, "cancelled").exit_code(), 130);
+}
+
+#[test]
+fn json_error_carries_code_category_and_exit_code() {
+    let json = CliError::not_found("CHANGE_NOT_FOUND", "Change 'x' not found").to_json();
+
+    assert_eq!(json["error"]["code"], "CHANGE_NOT_FOUND");
+    assert_eq!(json["error"]["category"], "not_found");
+    assert_eq!(json["error"]["exit_code"], 3);
+    assert_eq!(json["error"]["message"], "Change 'x' not found");
+}
@@ -67,7 +67,9 @@ fn parses_change_preflight_prepare_defaults() {
     let Some(Commands::Change(args)) = cli.command else {
         panic!("expected change command");
     };
-    let ChangeCommand::Preflight(args) = args.command;
+    let ChangeCommand::Preflight(args) = args.command else {
+        panic!("expected change preflight command");
+    };
     assert_eq!(args.change_id, "031-02_enforce-main-first-implementation");
     assert_eq!(args.phase, ReadinessPhaseArg::Prepare);
     assert!(!args.refresh);
@@ -89,12 +91,29 @@ fn parses_change_preflight_execute_refresh_json() {
     let Some(Commands::Change(args)) = cli.command else {
         panic!("expected change command");
     };
-    let ChangeCommand::Preflight(args) = args.command;
+    let ChangeCommand::Preflight(args) = args.command else {
+        panic!("expected change preflight command");
+    };
     assert_eq!(args.phase, ReadinessPhaseArg::Execute);
     assert!(args.refresh);
     assert!(args.json);
 }
 
+#[test]
+fn parses_change_rebaseline_pin() {
+    let cli = Cli::parse_from(["ito", "change", "rebaseline", "001-01_demo", "--pin"]);
+
+    let Some(Commands::Change(args)) = cli.command else {
+        panic!("expected change command");
+    };
+    let ChangeCommand::Rebaseline(args) = args.command else {
+        panic!("expected change rebaseline command");
+    };
+    assert_eq!(args.change_id, "001-01_demo");
+    assert!(args.pin);
+    assert!(!args.json);
+}
+
 #[cfg(not(feature = "backend"))]
 #[test]
 fn default_build_parses_backend_compatibility_command() {
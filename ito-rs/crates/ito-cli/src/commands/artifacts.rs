@@ -2,7 +2,7 @@ use std::io::{IsTerminal, Read};
 
 use ito_core::{ChangeArtifactKind, ChangeArtifactRef};
 
-use crate::app::common::resolve_change_target;
+use crate::app::change_target::resolve_change_target;
 use crate::cli::{
     ChangeArtifactSelector, ChangeArtifactTargetArgs, ChangeArtifactTargetCommand, PatchArgs,
     WriteArgs,
@@ -41,11 +41,7 @@ fn handle_change_target(
     args: &ChangeArtifactTargetArgs,
     mode: MutationMode,
 ) -> CliResult<()> {
-    let change_id =
-        match resolve_change_target(runtime.repositories().changes.as_ref(), &args.change) {
-            Ok(change_id) => change_id,
-            Err(message) => return fail(message),
-        };
+    let change_id = resolve_change_target(runtime.repositories().changes.as_ref(), &args.change)?;
     let artifact = artifact_kind_from_selector(&args.artifact);
     let target = ChangeArtifactRef {
         change_id,
@@ -1,9 +1,16 @@
+use std::io::Write;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
 use clap::{Args, Subcommand};
 
 use crate::cli_error::{CliResult, fail, to_cli_error};
 use crate::runtime::Runtime;
 use ito_core::audit::AuditEvent;
-use ito_core::audit::{self, EventFilter, read_audit_events, read_audit_events_filtered};
+use ito_core::audit::{
+    self, EventFilter, read_audit_events, read_audit_events_filtered,
+    read_audit_events_filtered_with_stats,
+};
 
 /// Query and manage audit event log.
 #[derive(Args, Debug, Clone)]
@@ -32,6 +39,18 @@ pub enum AuditAction {
         #[arg(long)]
         op: Option<String>,
 
+        /// Only show events at or after this time (e.g. 7d, 2h, 2025-01-01)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Only show events at or before this time (e.g. 1d, 2025-01-31)
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Include events from all worktrees
+        #[arg(long)]
+        all_worktrees: bool,
+
         /// Maximum number of events to show
         #[arg(short = 'n', long)]
         limit: Option<usize>,
@@ -96,6 +115,30 @@ pub enum AuditAction {
         #[arg(long)]
         json: bool,
     },
+
+    /// Print recent events, then keep printing new events as they are appended
+    #[command(visible_alias = "fo")]
+    Follow {
+        /// Filter to a specific change
+        #[arg(long)]
+        change: Option<String>,
+
+        /// Include events from all worktrees
+        #[arg(long)]
+        all_worktrees: bool,
+
+        /// Show last N events on startup
+        #[arg(short = 'n', long, default_value_t = 20)]
+        last: usize,
+
+        /// Poll interval in milliseconds
+        #[arg(long, default_value_t = 500)]
+        interval_ms: u64,
+
+        /// Output as JSON lines
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 pub(crate) fn handle_audit_clap(rt: &Runtime, args: &AuditArgs) -> CliResult<()> {
@@ -110,31 +153,50 @@ pub(crate) fn handle_audit_clap(rt: &Runtime, args: &AuditArgs) -> CliResult<()>
             change,
             entity,
             op,
+            since,
+            until,
+            all_worktrees,
             limit,
             json,
         } => {
-            let events = if entity.is_some() || change.is_some() || op.is_some() {
-                let filter = EventFilter {
-                    entity: entity.clone(),
-                    scope: change.clone(),
-                    op: op.clone(),
-                };
-                read_audit_events_filtered(ito_path, &filter)
-            } else {
-                read_audit_events(ito_path)
+            let now = chrono::Utc::now();
+            let parse_bound = |raw: &Option<String>| {
+                raw.as_deref()
+                    .map(|r| audit::parse_time_bound(r, now))
+                    .transpose()
+                    .map_err(to_cli_error)
+            };
+            let filter = EventFilter {
+                entity: entity.clone(),
+                scope: change.clone(),
+                op: op.clone(),
+                since: parse_bound(since)?,
+                until: parse_bound(until)?,
             };
+            if let (Some(since), Some(until)) = (filter.since, filter.until)
+                && since > until
+            {
+                return fail("--since must not be later than --until");
+            }
 
-            let events: Vec<&AuditEvent> = if let Some(n) = limit {
+            let (events, unparseable) = read_log_events(ito_path, &filter, *all_worktrees);
+            if unparseable > 0 {
+                eprintln!(
+                    "Warning: excluded {unparseable} audit event(s) with missing or unparseable timestamps"
+                );
+            }
+
+            let events: &[(Option<String>, AuditEvent)] = if let Some(n) = limit {
                 let start = events.len().saturating_sub(*n);
-                events[start..].iter().collect()
+                &events[start..]
             } else {
-                events.iter().collect()
+                &events
             };
 
             if *json {
                 let json_events: Vec<serde_json::Value> = events
                     .iter()
-                    .map(|e| serde_json::to_value(e).unwrap())
+                    .map(|(_, e)| serde_json::to_value(e).unwrap())
                     .collect();
                 let rendered = serde_json::to_string_pretty(&json_events).map_err(to_cli_error)?;
                 println!("{rendered}");
@@ -143,7 +205,15 @@ pub(crate) fn handle_audit_clap(rt: &Runtime, args: &AuditArgs) -> CliResult<()>
                     println!("No audit events found.");
                     return Ok(());
                 }
-                for event in &events {
+                let mut current_source: Option<&str> = None;
+                for (source, event) in events {
+                    if let Some(source) = source.as_deref()
+                        && current_source != Some(source)
+                    {
+                        println!();
+                        println!("── Worktree: {source} ──");
+                        current_source = Some(source);
+                    }
                     print_event_line(event);
                 }
                 println!();
@@ -360,11 +430,106 @@ pub(crate) fn handle_audit_clap(rt: &Runtime, args: &AuditArgs) -> CliResult<()>
 
             Ok(())
         }
+        AuditAction::Follow {
+            change,
+            all_worktrees,
+            last,
+            interval_ms,
+            json,
+        } => {
+            if *interval_ms == 0 {
+                return fail("--interval-ms must be greater than zero");
+            }
+            let config = audit::StreamConfig {
+                poll_interval: Duration::from_millis(*interval_ms),
+                all_worktrees: *all_worktrees,
+                last: *last,
+                scope: change.clone(),
+            };
+            let (events, mut sources) = audit::read_initial_events(ito_path, &config);
+
+            let json = *json;
+            let mut out = std::io::stdout();
+            let mut emit = |streamed: &audit::StreamEvent| {
+                write_followed_event(&mut out, streamed, *all_worktrees, json).is_ok()
+            };
+            for streamed in &events {
+                if !emit(streamed) {
+                    return Ok(());
+                }
+            }
+
+            // Nothing is buffered or written to disk while following, so the
+            // default Ctrl-C handling (process exit) is a clean shutdown.
+            let stop = AtomicBool::new(false);
+            audit::follow_events(&mut sources, config.poll_interval, &stop, emit);
+            Ok(())
+        }
     }
 }
 
+/// Read events for `ito audit log`, labelled with their worktree when
+/// `all_worktrees` is set, plus the count of events excluded for
+/// unparseable timestamps.
+fn read_log_events(
+    ito_path: &std::path::Path,
+    filter: &EventFilter,
+    all_worktrees: bool,
+) -> (Vec<(Option<String>, AuditEvent)>, usize) {
+    if all_worktrees {
+        let worktrees = audit::discover_worktrees(ito_path);
+        if !worktrees.is_empty() {
+            let (grouped, unparseable) =
+                audit::aggregate_worktree_events_filtered(&worktrees, filter);
+            let mut events = Vec::new();
+            for (wt, wt_events) in grouped {
+                let label = wt
+                    .branch
+                    .clone()
+                    .unwrap_or_else(|| wt.path.display().to_string());
+                events.extend(wt_events.into_iter().map(|e| (Some(label.clone()), e)));
+            }
+            return (events, unparseable);
+        }
+    }
+
+    let filtered = read_audit_events_filtered_with_stats(ito_path, filter);
+    let events = filtered.events.into_iter().map(|e| (None, e)).collect();
+    (events, filtered.unparseable_timestamps)
+}
+
+/// Write one followed event and flush, so lines appear as they arrive.
+///
+/// Errors (typically a closed pipe) end the follow loop.
+fn write_followed_event(
+    out: &mut impl Write,
+    streamed: &audit::StreamEvent,
+    all_worktrees: bool,
+    json: bool,
+) -> std::io::Result<()> {
+    if json {
+        let line = serde_json::to_string(&streamed.event).map_err(std::io::Error::other)?;
+        writeln!(out, "{line}")?;
+    } else if all_worktrees {
+        writeln!(
+            out,
+            "[{}] {}",
+            streamed.source,
+            format_event_line(&streamed.event)
+        )?;
+    } else {
+        writeln!(out, "{}", format_event_line(&streamed.event))?;
+    }
+    out.flush()
+}
+
 /// Print a human-readable single line for an audit event.
 fn print_event_line(event: &AuditEvent) {
+    println!("{}", format_event_line(event));
+}
+
+/// Render a human-readable single line for an audit event.
+fn format_event_line(event: &AuditEvent) -> String {
     let scope = event.scope.as_deref().unwrap_or("-");
     let transition = match (&event.from, &event.to) {
         (Some(from), Some(to)) => format!("{from} -> {to}"),
@@ -372,7 +537,7 @@ fn print_event_line(event: &AuditEvent) {
         (Some(from), None) => format!("{from} ->"),
         (None, None) => String::new(),
     };
-    println!(
+    format!(
         "{ts}  {actor:<10} {entity}/{id} ({scope})  {op}  {transition}",
         ts = &event.ts[..19.min(event.ts.len())],
         actor = event.actor,
@@ -381,5 +546,5 @@ fn print_event_line(event: &AuditEvent) {
         scope = scope,
         op = event.op,
         transition = transition,
-    );
+    )
 }
@@ -1,18 +1,256 @@
+use std::path::Path;
+
 use clap::CommandFactory;
+use ito_config::ConfigContext;
+use ito_core::change_repository::FsChangeRepository;
+use ito_core::module_repository::FsModuleRepository;
 
 use crate::cli::{Cli, CompletionShell};
 use crate::cli_error::CliResult;
 
+/// Hidden subcommand used by generated completion scripts to list dynamic ids.
+pub(crate) const DYNAMIC_COMPLETE_COMMAND: &str = "__complete";
+
+const DYNAMIC_SECTION_START: &str = "# --- ito dynamic completion ---";
+const DYNAMIC_SECTION_END: &str = "# --- end ito dynamic completion ---";
+
+/// Kinds of ids `ito __complete` can list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DynamicKind {
+    Changes,
+    Modules,
+    Schemas,
+}
+
+impl DynamicKind {
+    fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "changes" => Some(Self::Changes),
+            "modules" => Some(Self::Modules),
+            "schemas" => Some(Self::Schemas),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Changes => "changes",
+            Self::Modules => "modules",
+            Self::Schemas => "schemas",
+        }
+    }
+
+    fn zsh_function(self) -> &'static str {
+        match self {
+            Self::Changes => "_ito_complete_changes",
+            Self::Modules => "_ito_complete_modules",
+            Self::Schemas => "_ito_complete_schemas",
+        }
+    }
+}
+
 pub(crate) fn handle_completions(shell: CompletionShell) -> CliResult<()> {
+    print!("{}", render_completion_script(shell));
+    Ok(())
+}
+
+/// Render the completion script for `shell`.
+///
+/// zsh and fish scripts are extended to complete change, module, and schema
+/// ids by calling `ito __complete <kind>` at completion time.
+pub(crate) fn render_completion_script(shell: CompletionShell) -> String {
     let mut cmd = Cli::command();
 
-    let shell = match shell {
+    let clap_shell = match shell {
         CompletionShell::Bash => clap_complete::Shell::Bash,
         CompletionShell::Zsh => clap_complete::Shell::Zsh,
         CompletionShell::Fish => clap_complete::Shell::Fish,
         CompletionShell::PowerShell => clap_complete::Shell::PowerShell,
     };
 
-    clap_complete::generate(shell, &mut cmd, "ito", &mut std::io::stdout());
+    let mut buf: Vec<u8> = Vec::new();
+    clap_complete::generate(clap_shell, &mut cmd, "ito", &mut buf);
+    let script = String::from_utf8_lossy(&buf).into_owned();
+
+    match shell {
+        CompletionShell::Zsh => with_zsh_dynamic_completion(&script),
+        CompletionShell::Fish => with_fish_dynamic_completion(&script),
+        CompletionShell::Bash | CompletionShell::PowerShell => script,
+    }
+}
+
+/// Handle `ito __complete <kind>`: print one id per line.
+///
+/// Completion must never fail noisily, so unknown kinds and directories
+/// outside an Ito repository print nothing and exit successfully.
+pub(crate) fn handle_dynamic_complete(args: &[String]) -> CliResult<()> {
+    let Some(kind) = args.first().and_then(|a| DynamicKind::parse(a)) else {
+        return Ok(());
+    };
+    let root = crate::runtime::Runtime::new().cwd().to_path_buf();
+    for id in dynamic_completion_ids(kind, &root) {
+        println!("{id}");
+    }
     Ok(())
 }
+
+/// List the ids of `kind` available in the project rooted at `project_root`.
+pub(crate) fn dynamic_completion_ids(kind: DynamicKind, project_root: &Path) -> Vec<String> {
+    // Skip global config so completion never prints config warnings.
+    let quiet_ctx = ConfigContext {
+        project_dir: Some(project_root.to_path_buf()),
+        ..Default::default()
+    };
+    let ito_path = ito_config::ito_dir::get_ito_path(project_root, &quiet_ctx);
+    if !ito_path.is_dir() {
+        return Vec::new();
+    }
+
+    match kind {
+        DynamicKind::Changes => FsChangeRepository::new(&ito_path)
+            .list()
+            .map(|changes| changes.into_iter().map(|c| c.id).collect())
+            .unwrap_or_default(),
+        DynamicKind::Modules => FsModuleRepository::new(&ito_path)
+            .list()
+            .map(|modules| modules.into_iter().map(|m| m.id).collect())
+            .unwrap_or_default(),
+        DynamicKind::Schemas => {
+            let mut ctx = ConfigContext::from_process_env();
+            ctx.project_dir = Some(project_root.to_path_buf());
+            ito_core::templates::list_available_schemas(&ctx)
+        }
+    }
+}
+
+/// Return the dynamic kind completed by a zsh `_arguments` spec line, if any.
+///
+/// Options look like `'--change=[desc]:CHANGE:_default'`; positionals look
+/// like `'::change -- desc:_default'`.
+fn zsh_line_kind(line: &str) -> Option<DynamicKind> {
+    let spec = line.trim_start();
+    let positional = spec
+        .strip_prefix('\'')
+        .map(|rest| rest.trim_start_matches(':'))
+        .and_then(|rest| rest.split_once(" -- "))
+        .map(|(name, _)| name.to_ascii_lowercase());
+    if let Some(name) = positional {
+        return match name.as_str() {
+            "change" | "change_id" => Some(DynamicKind::Changes),
+            "module" | "module_id" => Some(DynamicKind::Modules),
+            _ => None,
+        };
+    }
+
+    if spec.contains(":CHANGE:") || spec.contains(":CHANGE_ID:") {
+        return Some(DynamicKind::Changes);
+    }
+    if spec.contains(":MODULE:") {
+        return Some(DynamicKind::Modules);
+    }
+    if spec.contains(":SCHEMA:") {
+        return Some(DynamicKind::Schemas);
+    }
+    None
+}
+
+fn with_zsh_dynamic_completion(script: &str) -> String {
+    let mut out = String::with_capacity(script.len() + 1024);
+    let mut lines = script.split_inclusive('\n');
+
+    // Keep the `#compdef` header first so the file still autoloads.
+    if let Some(first) = lines.next() {
+        out.push_str(first);
+    }
+    out.push('\n');
+    out.push_str(&zsh_dynamic_section());
+    out.push('\n');
+
+    for line in lines {
+        match zsh_line_kind(line) {
+            Some(kind) if line.contains(":_default'") => {
+                let action = format!(":{}'", kind.zsh_function());
+                out.push_str(&line.replacen(":_default'", &action, 1));
+            }
+            _ => out.push_str(line),
+        }
+    }
+    out
+}
+
+/// zsh helper functions inserted after the generated script's `#compdef` header.
+pub(crate) fn zsh_dynamic_section() -> String {
+    let mut out = String::new();
+    out.push_str(DYNAMIC_SECTION_START);
+    out.push('\n');
+    for kind in [
+        DynamicKind::Changes,
+        DynamicKind::Modules,
+        DynamicKind::Schemas,
+    ] {
+        out.push_str(&format!(
+            "(( $+functions[{func}] )) ||\n{func}() {{\n    local -a ids\n    ids=(${{(f)\"$(ito {cmd} {kind} 2>/dev/null)\"}})\n    compadd -a ids\n}}\n",
+            func = kind.zsh_function(),
+            cmd = DYNAMIC_COMPLETE_COMMAND,
+            kind = kind.as_str(),
+        ));
+    }
+    out.push_str(DYNAMIC_SECTION_END);
+    out.push('\n');
+    out
+}
+
+/// Return the dynamic kind completed by a fish `complete` line, if any.
+fn fish_line_kind(line: &str) -> Option<DynamicKind> {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    if tokens.first() != Some(&"complete") || !tokens.contains(&"-r") {
+        return None;
+    }
+    let long = tokens
+        .windows(2)
+        .find(|pair| pair[0] == "-l")
+        .map(|pair| pair[1])?;
+    match long {
+        "change" => Some(DynamicKind::Changes),
+        "module" => Some(DynamicKind::Modules),
+        "schema" => Some(DynamicKind::Schemas),
+        _ => None,
+    }
+}
+
+fn with_fish_dynamic_completion(script: &str) -> String {
+    let mut out = String::with_capacity(script.len() + 1024);
+    for line in script.lines() {
+        out.push_str(line);
+        if let Some(kind) = fish_line_kind(line) {
+            out.push_str(&format!(
+                " -f -a \"(ito {DYNAMIC_COMPLETE_COMMAND} {} 2>/dev/null)\"",
+                kind.as_str()
+            ));
+        }
+        out.push('\n');
+    }
+    out.push('\n');
+    out.push_str(&fish_dynamic_section());
+    out
+}
+
+/// fish completions for positional change ids, appended to the generated script.
+pub(crate) fn fish_dynamic_section() -> String {
+    let mut out = String::new();
+    out.push_str(DYNAMIC_SECTION_START);
+    out.push('\n');
+    out.push_str(
+        "function __ito_completing_change_positional\n    set -l tokens (commandline -opc)\n    switch (count $tokens)\n        case 2\n            contains -- $tokens[2] archive\n        case 3\n            contains -- $tokens[2] tasks ts\n        case '*'\n            return 1\n    end\nend\n",
+    );
+    out.push_str(&format!(
+        "complete -c ito -n __ito_completing_change_positional -f -a \"(ito {DYNAMIC_COMPLETE_COMMAND} changes 2>/dev/null)\"\n"
+    ));
+    out.push_str(DYNAMIC_SECTION_END);
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+#[path = "completions_tests.rs"]
+mod completions_tests;
@@ -0,0 +1,95 @@
+use super::*;
+
+fn make_fixture_repo() -> tempfile::TempDir {
+    let td = tempfile::tempdir().expect("tempdir");
+    let ito = td.path().join(".ito");
+    for module in ["000_ungrouped", "001_auth"] {
+        let dir = ito.join("modules").join(module);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("module.md"), format!("# {module}\n")).unwrap();
+    }
+    for change in ["000-01_first-change", "001-01_login"] {
+        let dir = ito.join("changes").join(change);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("proposal.md"), "## Why\nDemo\n").unwrap();
+    }
+    td
+}
+
+#[test]
+fn dynamic_complete_lists_change_ids() {
+    let repo = make_fixture_repo();
+    let mut ids = dynamic_completion_ids(DynamicKind::Changes, repo.path());
+    ids.sort();
+    assert_eq!(ids, vec!["000-01_first-change", "001-01_login"]);
+}
+
+#[test]
+fn dynamic_complete_lists_module_ids() {
+    let repo = make_fixture_repo();
+    let mut ids = dynamic_completion_ids(DynamicKind::Modules, repo.path());
+    ids.sort();
+    assert_eq!(ids, vec!["000", "001"]);
+}
+
+#[test]
+fn dynamic_complete_lists_builtin_schemas() {
+    let repo = make_fixture_repo();
+    let ids = dynamic_completion_ids(DynamicKind::Schemas, repo.path());
+    assert!(ids.iter().any(|id| id == "spec-driven"), "{ids:?}");
+}
+
+#[test]
+fn dynamic_complete_outside_repo_is_empty() {
+    let td = tempfile::tempdir().expect("tempdir");
+    for kind in [
+        DynamicKind::Changes,
+        DynamicKind::Modules,
+        DynamicKind::Schemas,
+    ] {
+        assert!(dynamic_completion_ids(kind, td.path()).is_empty());
+    }
+}
+
+#[test]
+fn zsh_change_and_schema_specs_use_dynamic_functions() {
+    let script = "#compdef ito\n\
+'-c+[Target change]:CHANGE:_default' \\\n\
+'--schema=[Schema]:SCHEMA:_default' \\\n\
+':change_id -- Change id:_default' \\\n\
+'--output=[Output]:OUTPUT:_default' \\\n";
+    let out = with_zsh_dynamic_completion(script);
+
+    assert!(out.starts_with("#compdef ito\n"));
+    assert!(out.contains("'-c+[Target change]:CHANGE:_ito_complete_changes'"));
+    assert!(out.contains("'--schema=[Schema]:SCHEMA:_ito_complete_schemas'"));
+    assert!(out.contains("':change_id -- Change id:_ito_complete_changes'"));
+    assert!(out.contains("'--output=[Output]:OUTPUT:_default'"));
+    assert!(out.contains("ito __complete changes 2>/dev/null"));
+}
+
+#[test]
+fn fish_long_options_complete_dynamic_ids() {
+    let script = "complete -c ito -n \"__fish_ito_using_subcommand ralph\" -s c -l change -d 'Target change' -r\n\
+complete -c ito -n \"__fish_ito_using_subcommand ralph\" -l changed -d 'Other' -r\n\
+complete -c ito -n \"__fish_ito_using_subcommand list\" -l module -d 'Module filter' -r\n";
+    let out = with_fish_dynamic_completion(script);
+
+    assert!(out.contains(
+        "-l change -d 'Target change' -r -f -a \"(ito __complete changes 2>/dev/null)\""
+    ));
+    assert!(out.contains("-l changed -d 'Other' -r\n"));
+    assert!(out.contains(
+        "-l module -d 'Module filter' -r -f -a \"(ito __complete modules 2>/dev/null)\""
+    ));
+    assert!(out.contains("function __ito_completing_change_positional"));
+}
+
+#[test]
+fn bash_script_is_unchanged_by_dynamic_completion() {
+    let script = render_completion_script(CompletionShell::Bash);
+    // Clap's own functions (e.g. `ito__subcmd__tasks__subcmd__complete`) also
+    // contain the helper's name, so look for the hook invocation itself.
+    assert!(!script.contains(&format!("ito {DYNAMIC_COMPLETE_COMMAND} ")));
+    assert!(!script.contains("_ito_complete_"));
+}
@@ -12,7 +12,7 @@ use ito_core::create::{create_change_in_sub_module, create_sub_module};
 #[cfg(feature = "coordination-branch")]
 use ito_core::git::reserve_change_on_coordination_branch;
 use ito_core::repository_runtime::PersistenceMode;
-use ito_core::{create as core_create, templates as core_templates};
+use ito_core::{create as core_create, profiles as core_profiles, templates as core_templates};
 use std::path::Path;
 
 /// Return an error if the runtime is in remote persistence mode.
@@ -114,6 +114,7 @@ pub(crate) fn handle_create_clap(rt: &Runtime, args: &CreateArgs) -> CliResult<(
     let forwarded: Vec<String> = match action {
         CreateAction::Module {
             name,
+            id,
             scope,
             depends_on,
             description,
@@ -122,6 +123,10 @@ pub(crate) fn handle_create_clap(rt: &Runtime, args: &CreateArgs) -> CliResult<(
             if let Some(name) = name {
                 out.push(name.clone());
             }
+            if let Some(id) = id {
+                out.push("--id".to_string());
+                out.push(id.clone());
+            }
             if let Some(scope) = scope {
                 out.push("--scope".to_string());
                 out.push(scope.clone());
@@ -142,6 +147,7 @@ pub(crate) fn handle_create_clap(rt: &Runtime, args: &CreateArgs) -> CliResult<(
             module,
             sub_module,
             description,
+            profile,
         } => {
             let mut out = vec!["change".to_string()];
             if let Some(name) = name {
@@ -163,6 +169,10 @@ pub(crate) fn handle_create_clap(rt: &Runtime, args: &CreateArgs) -> CliResult<(
                 out.push("--description".to_string());
                 out.push(description.clone());
             }
+            if let Some(profile) = profile {
+                out.push("--profile".to_string());
+                out.push(profile.clone());
+            }
             out
         }
         CreateAction::SubModule {
@@ -224,12 +234,14 @@ pub(crate) fn handle_create(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 .map(|raw| split_csv(&raw))
                 .unwrap_or_default();
             let description = parse_string_flag(args, "--description");
+            let id = parse_string_flag(args, "--id");
 
             best_effort_sync_coordination(rt, "before module create");
 
-            let r = core_create::create_module(
+            let r = core_create::create_module_with_id(
                 ito_path,
                 name,
+                id.as_deref(),
                 scope,
                 depends_on,
                 description.as_deref(),
@@ -268,7 +280,14 @@ pub(crate) fn handle_create(rt: &Runtime, args: &[String]) -> CliResult<()> {
             if name.is_empty() || name.starts_with('-') {
                 return fail("Missing required argument <name>");
             }
-            let schema_opt = parse_string_flag(args, "--schema");
+            let profile_name = parse_string_flag(args, "--profile");
+            let project_root = ito_path.parent().unwrap_or(ito_path);
+            let profiles = core_profiles::load_profiles(project_root, ito_path, rt.ctx())
+                .map_err(to_cli_error)?;
+            let profile = core_profiles::resolve_profile(&profiles, profile_name.as_deref())
+                .map_err(to_cli_error)?;
+            let schema_opt = parse_string_flag(args, "--schema")
+                .or_else(|| profile.and_then(|p| p.schema.clone()));
             let schema = schema_opt
                 .clone()
                 .unwrap_or_else(|| core_templates::default_schema_name().to_string());
@@ -335,6 +354,11 @@ pub(crate) fn handle_create(rt: &Runtime, args: &[String]) -> CliResult<()> {
 
             match create_result {
                 Ok(r) => {
+                    if let Some(profile_name) = &profile_name {
+                        core_profiles::record_change_profile(ito_path, &r.change_id, profile_name)
+                            .map_err(to_cli_error)?;
+                    }
+
                     // Emit audit event for change creation
                     if let Some(event) = AuditEventBuilder::new()
                         .entity(EntityType::Change)
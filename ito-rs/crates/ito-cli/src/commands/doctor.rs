@@ -0,0 +1,65 @@
+use crate::cli::DoctorArgs;
+use crate::cli_error::{CliResult, silent_fail, to_cli_error};
+use crate::runtime::Runtime;
+use ito_common::fs::StdFs;
+use ito_core::doctor::{DoctorContext, DoctorReport, DoctorStatus, run_doctor};
+use ito_core::process::SystemProcessRunner;
+
+pub(crate) fn handle_doctor_clap(rt: &Runtime, args: &DoctorArgs) -> CliResult<()> {
+    let ito_path = rt.ito_path();
+    let project_root = ito_path.parent().unwrap_or(ito_path);
+    let ctx = DoctorContext {
+        project_root,
+        ito_path,
+        fs: &StdFs,
+        runner: &SystemProcessRunner,
+    };
+    let report = run_doctor(&ctx);
+
+    if args.json {
+        let body = serde_json::to_string_pretty(&report).map_err(to_cli_error)?;
+        println!("{body}");
+    } else {
+        print_report(&report);
+    }
+
+    if report.has_failures() {
+        return silent_fail();
+    }
+    Ok(())
+}
+
+fn print_report(report: &DoctorReport) {
+    let width = report
+        .checks
+        .iter()
+        .map(|check| check.name.len())
+        .max()
+        .unwrap_or(0);
+    for check in &report.checks {
+        println!(
+            "{status:<4}  {name:<width$}  {detail}",
+            status = check.status.as_str().to_uppercase(),
+            name = check.name,
+            detail = check.detail,
+        );
+        if let Some(fix) = &check.fix_hint {
+            println!("      {:width$}  fix: {fix}", "");
+        }
+    }
+
+    let count = |status: DoctorStatus| {
+        report
+            .checks
+            .iter()
+            .filter(|check| check.status == status)
+            .count()
+    };
+    println!();
+    println!(
+        "{} passed, {} warning(s), {} failed",
+        count(DoctorStatus::Pass),
+        count(DoctorStatus::Warn),
+        count(DoctorStatus::Fail)
+    );
+}
@@ -0,0 +1,116 @@
+use clap::{Args, Subcommand};
+
+use crate::cli::HarnessArg;
+use crate::cli_error::{CliResult, silent_fail, to_cli_error};
+use ito_core::harness::{HarnessHealth, HarnessName};
+
+use super::ralph::make_harness;
+
+/// Check that AI harnesses can run.
+#[derive(Args, Debug, Clone)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+#[command(disable_help_subcommand = true)]
+pub struct HarnessArgs {
+    #[command(subcommand)]
+    pub action: HarnessAction,
+}
+
+/// Harness subcommands.
+#[derive(Subcommand, Debug, Clone)]
+pub enum HarnessAction {
+    /// Check that a harness can run a prompt (binary, auth, model)
+    ///
+    /// Runs a cheap native probe (e.g. `claude --version`) and then a trivial
+    /// prompt with a short timeout. Exits non-zero when any harness fails.
+    ///
+    /// Examples:
+    ///   ito harness check claude
+    ///   ito harness check --all --json
+    #[command(verbatim_doc_comment)]
+    Check {
+        /// Harness to check
+        #[arg(value_enum, required_unless_present = "all", conflicts_with = "all")]
+        name: Option<HarnessArg>,
+
+        /// Check every harness
+        #[arg(long)]
+        all: bool,
+
+        /// Model id to check
+        #[arg(long)]
+        model: Option<String>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+pub(crate) fn handle_harness_clap(args: &HarnessArgs) -> CliResult<()> {
+    match &args.action {
+        HarnessAction::Check {
+            name,
+            all,
+            model,
+            json,
+        } => {
+            let selected = if *all {
+                vec![
+                    HarnessArg::Opencode,
+                    HarnessArg::Claude,
+                    HarnessArg::Codex,
+                    HarnessArg::Copilot,
+                ]
+            } else {
+                name.iter().copied().collect()
+            };
+            let cwd = std::env::current_dir().map_err(to_cli_error)?;
+
+            let mut results = Vec::new();
+            for arg in selected {
+                let mut harness = make_harness(arg, None)?;
+                let health = harness.health_check(&cwd, model.as_deref());
+                results.push((harness.name(), health));
+            }
+
+            if *json {
+                let items: Vec<_> = results
+                    .iter()
+                    .map(|(name, health)| {
+                        serde_json::json!({
+                            "harness": name.as_str(),
+                            "status": health.kind(),
+                            "ok": health.is_ok(),
+                            "detail": health.to_string(),
+                        })
+                    })
+                    .collect();
+                let output = serde_json::json!({ "harnesses": items });
+                let rendered = serde_json::to_string_pretty(&output).map_err(to_cli_error)?;
+                println!("{rendered}");
+            } else {
+                print_results(&results);
+            }
+
+            if results.iter().any(|(_, health)| !health.is_ok()) {
+                return silent_fail();
+            }
+            Ok(())
+        }
+    }
+}
+
+fn print_results(results: &[(HarnessName, HarnessHealth)]) {
+    let width = results
+        .iter()
+        .map(|(name, _)| name.as_str().len())
+        .max()
+        .unwrap_or(0);
+    for (name, health) in results {
+        let status = if health.is_ok() { "PASS" } else { "FAIL" };
+        println!(
+            "{status:<4}  {name:<width$}  {health}",
+            name = name.as_str()
+        );
+    }
+}
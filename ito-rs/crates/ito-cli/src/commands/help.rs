@@ -1,7 +1,49 @@
 use crate::cli::HelpArgs;
-use crate::cli_error::CliResult;
+use crate::cli_error::{CliResult, ErrorCategory};
 use clap::CommandFactory;
 
+/// `ito help` topic documenting exit codes and JSON error codes.
+const EXIT_CODES_TOPIC: &str = "exit-codes";
+
+/// Exit-code contract, in the order `ito help exit-codes` lists it.
+const EXIT_CODES: &[(i32, &str, &str)] = &[
+    (0, "success", "The command completed successfully"),
+    (1, "failure", "The command failed for any other reason"),
+    (2, "usage", "Invalid arguments, flags or ambiguous targets"),
+    (
+        3,
+        "not_found",
+        "A change, module, spec or task does not exist",
+    ),
+    (4, "validation", "Validation of artifacts or input failed"),
+    (5, "process", "A subprocess or harness failed"),
+    (
+        10,
+        "internal",
+        "An unexpected internal error (please report it)",
+    ),
+];
+
+/// Machine-readable `error.code` values with a more specific meaning than
+/// their category's default.
+const ERROR_CODES: &[(&str, &str)] = &[
+    (
+        "CHANGE_NOT_FOUND",
+        "No change matches the given ID or prefix",
+    ),
+    ("MODULE_NOT_FOUND", "No module matches the given ID"),
+    ("SPEC_NOT_FOUND", "No spec matches the given ID"),
+    ("TASK_NOT_FOUND", "No task matches the given ID"),
+    ("ITEM_NOT_FOUND", "No change or spec matches the given ID"),
+    ("AMBIGUOUS_TARGET", "A prefix matches more than one item"),
+    ("PARSE_ERROR", "An artifact could not be parsed"),
+    ("IO_ERROR", "A file could not be read or written"),
+    (
+        "FEATURE_UNAVAILABLE",
+        "The command needs a feature this build lacks",
+    ),
+];
+
 /// Collects the stable set of CLI command paths (as parts) for which help text should be emitted.
 ///
 /// This returns the base command plus a curated list of top-level and nested commands
@@ -39,6 +81,9 @@ fn help_all_parts() -> Vec<Vec<String>> {
         &["tasks"],
         &["change"],
         &["change", "preflight"],
+        &["change", "rebaseline"],
+        &["change", "rename"],
+        &["change", "split"],
         &["plan"],
         &["list"],
         &["list-archive"],
@@ -51,10 +96,12 @@ fn help_all_parts() -> Vec<Vec<String>> {
         &["show"],
         &["agent"],
         &["agent", "instruction"],
+        &["agent", "export"],
         &["ralph"],
         &["status"],
         &["completions"],
         &["stats"],
+        &["doctor"],
         &["util"],
         &["util", "parse-id"],
         &["agent-config"],
@@ -89,6 +136,10 @@ pub(crate) fn handle_help_clap(args: &HelpArgs) -> CliResult<()> {
         return handle_help_all_flags(args.json);
     }
 
+    if args.command.len() == 1 && args.command[0] == EXIT_CODES_TOPIC {
+        return handle_help_exit_codes(args.json);
+    }
+
     if !args.command.is_empty() {
         let mut bin_name = "ito".to_string();
         for p in &args.command {
@@ -104,6 +155,63 @@ pub(crate) fn handle_help_clap(args: &HelpArgs) -> CliResult<()> {
     Ok(())
 }
 
+/// Prints the exit-code contract and JSON error codes (`ito help exit-codes`).
+fn handle_help_exit_codes(json_output: bool) -> CliResult<()> {
+    if json_output {
+        let exit_codes: Vec<serde_json::Value> = EXIT_CODES
+            .iter()
+            .map(|(code, category, description)| {
+                let default_code = ErrorCategory::from_exit_code(*code).map(|c| c.default_code());
+                serde_json::json!({
+                    "exit_code": code,
+                    "category": category,
+                    "default_error_code": default_code,
+                    "description": description,
+                })
+            })
+            .collect();
+        let error_codes: Vec<serde_json::Value> = ERROR_CODES
+            .iter()
+            .map(|(code, description)| serde_json::json!({ "code": code, "description": description }))
+            .collect();
+        let value = serde_json::json!({
+            "exit_codes": exit_codes,
+            "error_codes": error_codes,
+        });
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&value).expect("JSON value serializes")
+        );
+        return Ok(());
+    }
+
+    println!("EXIT CODES");
+    println!();
+    for (code, _, description) in EXIT_CODES {
+        let default_code = ErrorCategory::from_exit_code(*code)
+            .map(|c| c.default_code())
+            .unwrap_or("-");
+        println!("  {code:>2}  {default_code:<18} {description}");
+    }
+    println!();
+    println!("JSON ERRORS");
+    println!();
+    println!("  With --json, failures also print an error object on stdout:");
+    println!(
+        r#"    {{"error": {{"code": "CHANGE_NOT_FOUND", "category": "not_found", "exit_code": 3, "message": "..."}}}}"#
+    );
+    println!();
+    println!("  Specific error codes:");
+    for (code, description) in ERROR_CODES {
+        println!("    {code:<20} {description}");
+    }
+    println!();
+    println!(
+        "  Commands that report their own JSON result (e.g. `ito validate --json`) print that report instead."
+    );
+    Ok(())
+}
+
 /// Prints help entries for all stable CLI commands.
 ///
 /// When the provided `args` slice contains `"--json"`, emits a JSON document with a
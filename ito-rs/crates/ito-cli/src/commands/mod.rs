@@ -5,18 +5,25 @@ pub(crate) mod backend;
 pub(crate) mod completions;
 pub(crate) mod config;
 pub(crate) mod create;
+pub(crate) mod doctor;
+pub(crate) mod harness;
 pub(crate) mod help;
+pub(crate) mod models;
 pub(crate) mod path;
 pub(crate) mod plan;
 pub(crate) mod ralph;
+pub(crate) mod run;
 #[cfg(feature = "web")]
 pub(crate) mod serve;
 #[cfg(feature = "backend")]
 pub(crate) mod serve_api;
+#[cfg(feature = "web")]
+pub(crate) mod serve_stdio;
 pub(crate) mod stats;
 pub(crate) mod sync;
 pub(crate) mod tasks;
 pub(crate) mod templates;
+pub(crate) mod transcripts;
 pub(crate) mod util;
 pub(crate) mod view;
 pub(crate) mod worktree;
@@ -25,16 +32,22 @@ pub(crate) use artifacts::{handle_patch_clap, handle_write_clap};
 pub(crate) use audit::handle_audit_clap;
 #[cfg(feature = "backend")]
 pub(crate) use backend::handle_backend_clap;
-pub(crate) use completions::handle_completions;
+pub(crate) use completions::{
+    DYNAMIC_COMPLETE_COMMAND, handle_completions, handle_dynamic_complete,
+};
 pub(crate) use config::handle_config_clap;
 pub(crate) use create::handle_create_clap;
 pub(crate) use create::handle_new_clap;
+pub(crate) use doctor::handle_doctor_clap;
+pub(crate) use harness::handle_harness_clap;
 pub(crate) use help::handle_help_all_flags;
 pub(crate) use help::handle_help_clap;
-pub(crate) use path::handle_path_clap;
+pub(crate) use models::handle_models_clap;
+pub(crate) use path::{handle_path_clap, handle_paths_clap};
 pub(crate) use plan::handle_plan_clap;
 pub(crate) use ralph::handle_loop_clap;
 pub(crate) use ralph::handle_ralph_clap;
+pub(crate) use run::handle_run_clap;
 #[cfg(feature = "web")]
 pub(crate) use serve::handle_serve_clap;
 #[cfg(feature = "backend")]
@@ -43,6 +56,7 @@ pub(crate) use stats::handle_stats_clap;
 pub(crate) use sync::handle_sync_clap;
 pub(crate) use tasks::handle_tasks_clap;
 pub(crate) use templates::handle_templates_clap;
+pub(crate) use transcripts::handle_transcripts_clap;
 pub(crate) use util::handle_util_clap;
 pub(crate) use view::handle_view_clap;
 pub(crate) use worktree::handle_worktree_clap;
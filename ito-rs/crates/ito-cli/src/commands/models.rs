@@ -0,0 +1,47 @@
+use crate::cli::{ModelsArgs, ModelsCommand, ModelsPinsArgs};
+use crate::cli_error::{CliResult, to_cli_error};
+use crate::runtime::Runtime;
+use crate::ui::{Cell, Column, Table, Tone, Ui};
+use crate::util::render_json;
+use ito_core::installers::{ModelSource, configured_models, model_pins};
+
+pub(crate) fn handle_models_clap(rt: &Runtime, args: &ModelsArgs) -> CliResult<()> {
+    match &args.command {
+        ModelsCommand::Pins(pins) => handle_models_pins(rt, pins),
+    }
+}
+
+fn handle_models_pins(rt: &Runtime, args: &ModelsPinsArgs) -> CliResult<()> {
+    let ito_path = rt.ito_path();
+    let project_root = ito_path.parent().unwrap_or(ito_path);
+    let models = configured_models(project_root, ito_path, rt.ctx()).map_err(to_cli_error)?;
+    let pins = model_pins(models.as_ref());
+
+    if args.json {
+        let rendered = render_json(&pins).map_err(to_cli_error)?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let ui = Ui::stdout();
+    let mut table = Table::new(vec![
+        Column::new("harness"),
+        Column::new("tier"),
+        Column::new("model"),
+        Column::new("source"),
+    ]);
+    for pin in &pins {
+        let tone = match pin.source {
+            ModelSource::Config => Tone::Active,
+            ModelSource::Default => Tone::Muted,
+        };
+        table.row(vec![
+            Cell::plain(pin.tool),
+            Cell::plain(pin.tier),
+            Cell::plain(&pin.model),
+            Cell::toned(pin.source.as_str(), tone),
+        ]);
+    }
+    print!("{}", table.render(&ui));
+    Ok(())
+}
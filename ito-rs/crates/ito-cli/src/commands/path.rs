@@ -1,8 +1,14 @@
-use crate::cli::{PathArgs, PathCommand, PathCommonArgs, PathRootsArgs, PathWorktreeArgs};
+use crate::cli::{
+    PathArgs, PathCommand, PathCommonArgs, PathRootsArgs, PathWorktreeArgs, PathsArgs,
+};
 use crate::cli_error::{CliResult, fail, to_cli_error};
 use crate::runtime::Runtime;
+use crate::ui::{Cell, Column, Table, Tone, Ui};
+use crate::util::render_json;
+use ito_core::locations::{ItoLocation, collect_locations};
 use ito_core::repo_paths::{
-    ResolvedEnv, ResolvedWorktreePaths, WorktreeSelector, resolve_env, resolve_worktree_paths,
+    ResolvedEnv, ResolvedWorktreePaths, WorktreeSelector, resolve_env_from_cwd,
+    resolve_worktree_paths,
 };
 use std::path::Path;
 
@@ -29,19 +35,19 @@ pub(crate) fn handle_path_clap(rt: &Runtime, args: &PathArgs) -> CliResult<()> {
 
     match cmd {
         PathCommand::ProjectRoot(common) => {
-            let env = resolve_env(rt.ctx()).map_err(to_cli_error)?;
+            let env = resolve_env_from_cwd(&rt.invocation_dir(), rt.ctx()).map_err(to_cli_error)?;
             print_path(&env.project_root, common)
         }
         PathCommand::WorktreeRoot(common) => {
-            let env = resolve_env(rt.ctx()).map_err(to_cli_error)?;
+            let env = resolve_env_from_cwd(&rt.invocation_dir(), rt.ctx()).map_err(to_cli_error)?;
             print_path(&env.worktree_root, common)
         }
         PathCommand::ItoRoot(common) => {
-            let env = resolve_env(rt.ctx()).map_err(to_cli_error)?;
+            let env = resolve_env_from_cwd(&rt.invocation_dir(), rt.ctx()).map_err(to_cli_error)?;
             print_path(&env.ito_root, common)
         }
         PathCommand::WorktreesRoot(common) => {
-            let env = resolve_env(rt.ctx()).map_err(to_cli_error)?;
+            let env = resolve_env_from_cwd(&rt.invocation_dir(), rt.ctx()).map_err(to_cli_error)?;
             let paths = resolve_worktree_paths(&env, rt.ctx()).map_err(to_cli_error)?;
             let Some(worktrees_root) = paths.worktrees_root else {
                 return fail("Worktrees are not enabled for this project");
@@ -49,7 +55,7 @@ pub(crate) fn handle_path_clap(rt: &Runtime, args: &PathArgs) -> CliResult<()> {
             print_path(&worktrees_root, common)
         }
         PathCommand::Worktree(args) => {
-            let env = resolve_env(rt.ctx()).map_err(to_cli_error)?;
+            let env = resolve_env_from_cwd(&rt.invocation_dir(), rt.ctx()).map_err(to_cli_error)?;
             let paths = resolve_worktree_paths(&env, rt.ctx()).map_err(to_cli_error)?;
             let selector = selector_from_args(args)?;
             let Some(out) = paths.path_for_selector(&selector) else {
@@ -58,7 +64,7 @@ pub(crate) fn handle_path_clap(rt: &Runtime, args: &PathArgs) -> CliResult<()> {
             print_path(&out, &args.common)
         }
         PathCommand::Roots(args) => {
-            let env = resolve_env(rt.ctx()).map_err(to_cli_error)?;
+            let env = resolve_env_from_cwd(&rt.invocation_dir(), rt.ctx()).map_err(to_cli_error)?;
             let worktree_paths = resolve_worktree_paths(&env, rt.ctx()).ok();
             print_roots(&env, worktree_paths.as_ref(), args)
         }
@@ -194,3 +200,62 @@ fn print_roots(
     }
     Ok(())
 }
+
+/// Print every location Ito may read or write for the current project.
+pub(crate) fn handle_paths_clap(rt: &Runtime, args: &PathsArgs) -> CliResult<()> {
+    let report = collect_locations(rt.project());
+
+    if args.json {
+        let rendered =
+            render_json(&report).map_err(|e| to_cli_error(format!("serializing response: {e}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let ui = Ui::stdout();
+    println!("{}", ui.kv("project root", report.project_root.display()));
+    println!("{}", ui.kv("ito dir", &report.ito_dir_name));
+    println!();
+    let mut table = Table::new(vec![
+        Column::new("location"),
+        Column::new("path"),
+        Column::new("state").min_width(7),
+        Column::new("size"),
+    ]);
+    for location in &report.locations {
+        let (state, tone) = location_state(location);
+        table.row(vec![
+            Cell::plain(location.kind.label()),
+            Cell::plain(location_target(location)),
+            Cell::toned(state, tone),
+            Cell::toned(
+                location
+                    .size_bytes
+                    .map(|size| format!("{size} B"))
+                    .unwrap_or_default(),
+                Tone::Muted,
+            ),
+        ]);
+    }
+    print!("{}", table.render(&ui));
+    Ok(())
+}
+
+fn location_target(location: &ItoLocation) -> String {
+    match (&location.path, &location.storage) {
+        (Some(path), _) => path.display().to_string(),
+        (None, Some(storage)) => storage.clone(),
+        (None, None) => "(unavailable)".to_string(),
+    }
+}
+
+fn location_state(location: &ItoLocation) -> (&'static str, Tone) {
+    if location.path.is_none() {
+        return ("-", Tone::Muted);
+    }
+    match (location.exists, location.loaded) {
+        (true, Some(true)) => ("loaded", Tone::Success),
+        (true, _) => ("exists", Tone::Plain),
+        (false, _) => ("missing", Tone::Muted),
+    }
+}
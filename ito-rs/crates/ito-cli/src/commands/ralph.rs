@@ -1,16 +1,32 @@
 use crate::app::change::require_runtime_readiness_at;
+use crate::app::change_target::{
+    ambiguous_change_error, resolve_change_target, resolve_change_target_with,
+};
 use crate::cli::{HarnessArg, RalphArgs};
-use crate::cli_error::{CliResult, fail, to_cli_error};
+use crate::cli_error::{CliError, CliResult, fail, to_cli_error};
 use crate::runtime::Runtime;
-use ito_core::ChangeRepository;
+use ito_config::output::{self, Verbosity};
+use ito_config::types::{
+    HarnessEnvMode, HarnessEnvPolicyConfig, HarnessPermissionsConfig, ProfileConfig,
+};
+use ito_core::errors::CoreError;
+use ito_core::harness::CancellationToken;
 use ito_core::harness::ClaudeCodeHarness;
 use ito_core::harness::CodexHarness;
+use ito_core::harness::EnvMode;
 use ito_core::harness::GitHubCopilotHarness;
 use ito_core::harness::Harness;
+use ito_core::harness::HarnessEnvPolicy;
+use ito_core::harness::HarnessName;
 use ito_core::harness::OpencodeHarness;
+use ito_core::harness::PermissionPolicy;
+use ito_core::harness::budget::load_context_windows;
 use ito_core::harness::stub::StubHarness;
 use ito_core::implementation_readiness::ReadinessPhase;
+use ito_core::profiles as core_profiles;
 use ito_core::ralph as core_ralph;
+use ito_core::{ChangeRepository, ChangeTargetResolution};
+use std::collections::BTreeMap;
 use std::io::IsTerminal;
 use std::path::Path;
 
@@ -49,6 +65,169 @@ fn load_worktree_config(ito_path: &std::path::Path, rt: &Runtime) -> core_ralph:
     core_ralph::WorktreeConfig { enabled, dir_name }
 }
 
+/// Exit code of a Ralph run stopped by SIGINT or SIGTERM (`128 + SIGINT`).
+const RALPH_CANCELLED_EXIT_CODE: i32 = 130;
+
+/// Install a SIGINT/SIGTERM handler that cancels the returned token.
+///
+/// The first signal lets Ralph stop the harness and save state for the partial
+/// iteration; a second one exits immediately.
+fn install_cancel_handler() -> CancellationToken {
+    let token = CancellationToken::new();
+    let handler_token = token.clone();
+    let installed = ctrlc::set_handler(move || {
+        if handler_token.is_cancelled() {
+            std::process::exit(RALPH_CANCELLED_EXIT_CODE);
+        }
+        eprintln!(
+            "\nCancelling Ralph: stopping the harness and saving state (interrupt again to exit immediately)..."
+        );
+        handler_token.cancel();
+    });
+    if let Err(e) = installed {
+        tracing::warn!("failed to install Ralph signal handler: {e}");
+    }
+    token
+}
+
+/// Map a Ralph error to the CLI, using the cancellation exit code when the run was cancelled.
+fn ralph_error(err: CoreError, cancel: &CancellationToken) -> CliError {
+    if cancel.is_cancelled() {
+        return CliError::with_code(RALPH_CANCELLED_EXIT_CODE, err.to_string());
+    }
+    to_cli_error(err)
+}
+
+/// Config key under `harnesses` for the selected harness.
+fn harness_config_key(harness: HarnessArg) -> Option<&'static str> {
+    match harness {
+        HarnessArg::Opencode => Some("opencode"),
+        HarnessArg::Claude => Some("claude-code"),
+        HarnessArg::Codex => Some("codex"),
+        HarnessArg::Copilot => Some("github-copilot"),
+        HarnessArg::Stub => None,
+    }
+}
+
+/// Read `harnesses.<harness>.<field>` from the merged project config.
+fn load_harness_setting(
+    ito_path: &std::path::Path,
+    rt: &Runtime,
+    harness: HarnessArg,
+    field: &str,
+) -> Option<serde_json::Value> {
+    let key = harness_config_key(harness)?;
+    let project_root = ito_path
+        .parent()
+        .unwrap_or_else(|| std::path::Path::new("."));
+    let cfg = ito_config::load_cascading_project_config(project_root, ito_path, rt.ctx());
+    cfg.merged
+        .pointer(&format!("/harnesses/{key}/{field}"))
+        .cloned()
+}
+
+/// Read `harnesses.<harness>.promptBudgetTokens` for the selected harness.
+fn load_prompt_budget(
+    ito_path: &std::path::Path,
+    rt: &Runtime,
+    harness: HarnessArg,
+) -> Option<core_ralph::PromptBudget> {
+    let tokens = load_harness_setting(ito_path, rt, harness, "promptBudgetTokens")?.as_u64()?;
+    Some(core_ralph::PromptBudget::MaxApproxTokens(tokens as usize))
+}
+
+/// Build the harness environment policy from `harnesses.<harness>.env_policy`
+/// plus any `--env-allow` variables.
+///
+/// Without a configured policy the harness inherits the full environment.
+pub(super) fn load_env_policy(
+    ito_path: &std::path::Path,
+    rt: &Runtime,
+    harness: HarnessArg,
+    env_allow: &[String],
+) -> CliResult<HarnessEnvPolicy> {
+    let Some(raw) = load_harness_setting(ito_path, rt, harness, "env_policy") else {
+        return Ok(HarnessEnvPolicy::default());
+    };
+    let cfg: HarnessEnvPolicyConfig = serde_json::from_value(raw).map_err(|e| {
+        to_cli_error(format!(
+            "Invalid harnesses.{}.env_policy: {e}",
+            harness_config_key(harness).unwrap_or_default()
+        ))
+    })?;
+    let required = cfg.required.unwrap_or_else(|| {
+        HarnessName::from(harness)
+            .default_required_env()
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
+    });
+    let mode = match cfg.mode {
+        HarnessEnvMode::Inherit => EnvMode::Inherit,
+        HarnessEnvMode::AllowList => EnvMode::AllowList(cfg.allow),
+        HarnessEnvMode::Clean => EnvMode::Clean,
+    };
+    let mut policy = HarnessEnvPolicy { mode, required };
+    policy.allow(env_allow.iter().cloned());
+    Ok(policy)
+}
+
+/// Build the harness tool permission policy.
+///
+/// `--permissions-profile <name>` selects `permission_profiles.<name>`;
+/// otherwise `harnesses.<harness>.permissions` applies. Rules the harness
+/// cannot enforce are dropped with a warning.
+pub(super) fn load_permissions(
+    ito_path: &std::path::Path,
+    rt: &Runtime,
+    harness: HarnessArg,
+    permissions_profile: Option<&str>,
+) -> CliResult<Option<PermissionPolicy>> {
+    let (raw, source) = match permissions_profile {
+        Some(name) => {
+            let project_root = ito_path
+                .parent()
+                .unwrap_or_else(|| std::path::Path::new("."));
+            let cfg = ito_config::load_cascading_project_config(project_root, ito_path, rt.ctx());
+            let profiles: BTreeMap<String, serde_json::Value> = cfg
+                .merged
+                .get("permission_profiles")
+                .cloned()
+                .and_then(|v| serde_json::from_value(v).ok())
+                .unwrap_or_default();
+            let Some(raw) = profiles.get(name).cloned() else {
+                let names: Vec<String> = profiles.keys().cloned().collect();
+                let suggestions = ito_core::nearest_matches(name, &names, 3);
+                return fail(crate::app::common::unknown_with_suggestions(
+                    "permissions profile",
+                    name,
+                    &suggestions,
+                ));
+            };
+            (raw, format!("permission_profiles.{name}"))
+        }
+        None => {
+            let Some(raw) = load_harness_setting(ito_path, rt, harness, "permissions") else {
+                return Ok(None);
+            };
+            let key = harness_config_key(harness).unwrap_or_default();
+            (raw, format!("harnesses.{key}.permissions"))
+        }
+    };
+    let cfg: HarnessPermissionsConfig =
+        serde_json::from_value(raw).map_err(|e| to_cli_error(format!("Invalid {source}: {e}")))?;
+    let policy = PermissionPolicy {
+        allow_paths: cfg.allow_paths,
+        deny_paths: cfg.deny_paths,
+        allow_commands: cfg.allow_commands,
+        deny_commands: cfg.deny_commands,
+    };
+    if let Some(warning) = policy.unsupported_warning(HarnessName::from(harness)) {
+        eprintln!("Warning: {warning}");
+    }
+    Ok(Some(policy))
+}
+
 /// Handle the `ito ralph` command using parsed `RalphArgs`.
 ///
 /// Validates mutually dependent flags, composes the prompt from an optional
@@ -198,6 +377,17 @@ pub(crate) fn handle_ralph_clap(
         None
     };
 
+    let steal_stale = if let Some(raw) = &args.steal_stale {
+        match core_ralph::parse_duration(raw) {
+            Ok(d) => Some(d),
+            Err(e) => {
+                return fail(format!("Invalid --steal-stale '{raw}': {e}"));
+            }
+        }
+    } else {
+        None
+    };
+
     let error_threshold = args
         .error_threshold
         .unwrap_or(core_ralph::DEFAULT_ERROR_THRESHOLD);
@@ -208,6 +398,21 @@ pub(crate) fn handle_ralph_clap(
     let module_repo = repositories.modules.as_ref();
     let task_repo = repositories.tasks.as_ref();
 
+    // Ambiguous targets (and `--exact` misses) are reported with the same
+    // listing as every other command before the loop sees the change.
+    let change = match args.change.as_deref() {
+        Some(input) if args.exact => Some(resolve_change_target_with(change_repo, input, true)?),
+        Some(input) => match change_repo.resolve_target(input) {
+            ChangeTargetResolution::Ambiguous(matches) => {
+                return Err(ambiguous_change_error(input, &matches));
+            }
+            ChangeTargetResolution::Unique(_) | ChangeTargetResolution::NotFound => {
+                Some(input.to_string())
+            }
+        },
+        None => None,
+    };
+
     // Interactive target selection lives in the CLI layer.
     // When no explicit change is provided, prompt for one or more changes and
     // run Ralph sequentially for each selection.
@@ -252,7 +457,9 @@ pub(crate) fn handle_ralph_clap(
             overrides = prompt_missing_ralph_options(raw_args, overrides)?;
         }
 
-        let mut harness_impl: Box<dyn Harness> = make_harness(overrides.harness, args)?;
+        let mut harness_impl: Box<dyn Harness> =
+            make_harness(overrides.harness, args.stub_script.as_deref())?;
+        let cancel = install_cancel_handler();
         let base_opts = core_ralph::RalphOptions {
             prompt,
             change_id: None,
@@ -261,21 +468,44 @@ pub(crate) fn handle_ralph_clap(
             min_iterations: overrides.min_iterations,
             max_iterations: overrides.max_iterations,
             completion_promise: args.completion_promise.clone(),
+            completion_format: args.completion_format.into(),
+            completion_tag: args.completion_tag.clone(),
+            completion_json_pointer: args.completion_json_pointer.clone(),
             allow_all: overrides.allow_all,
             no_commit: overrides.no_commit,
             interactive,
             status: args.status,
             add_context: args.add_context.clone(),
             clear_context: args.clear_context,
-            verbose: args.verbose,
+            verbose: output::enabled(Verbosity::Verbose),
             continue_module,
             continue_ready: args.continue_ready,
+            steal_stale,
             inactivity_timeout,
+            resume_sessions: args.resume_sessions,
+            preflight: args.preflight,
             skip_validation: args.skip_validation,
             validation_command: args.validation_command.clone(),
+            validation_timeout: core_ralph::DEFAULT_VALIDATION_TIMEOUT,
+            validation_max_output_bytes: Some(core_ralph::DEFAULT_VALIDATION_MAX_OUTPUT_BYTES),
+            prompt_budget: load_prompt_budget(ito_path, rt, overrides.harness),
+            context_windows: load_context_windows(repo_root, ito_path, rt.ctx())
+                .map_err(to_cli_error)?,
+            strict_budget: args.strict_budget,
+            git_context_commits: (!args.no_git_context).then_some(args.git_context_commits),
+            git_context_max_chars: args.git_context_max_chars,
+            env_policy: load_env_policy(ito_path, rt, overrides.harness, &args.env_allow)?,
+            permissions: load_permissions(
+                ito_path,
+                rt,
+                overrides.harness,
+                args.permissions_profile.as_deref(),
+            )?,
+            transcripts: !args.no_transcript,
             exit_on_error: overrides.exit_on_error,
             error_threshold,
             worktree: worktree_config,
+            cancel: Some(cancel.clone()),
         };
 
         for (idx, change_id) in selected.iter().enumerate() {
@@ -290,6 +520,8 @@ pub(crate) fn handle_ralph_clap(
 
             let mut per_change = base_opts.clone();
             per_change.change_id = Some(change_id.clone());
+            // One harness check covers every selected change.
+            per_change.preflight = base_opts.preflight && idx == 0;
 
             core_ralph::run_ralph(
                 ito_path,
@@ -299,7 +531,7 @@ pub(crate) fn handle_ralph_clap(
                 per_change,
                 harness_impl.as_mut(),
             )
-            .map_err(to_cli_error)?;
+            .map_err(|e| ralph_error(e, &cancel))?;
         }
 
         return Ok(());
@@ -316,31 +548,61 @@ pub(crate) fn handle_ralph_clap(
         None
     };
 
-    let mut harness_impl: Box<dyn Harness> = make_harness(args.harness, args)?;
-    let opts = core_ralph::RalphOptions {
+    let profile = resolve_ralph_profile(rt, change_repo, change.as_deref())?;
+    let harness = match profile.as_ref().and_then(|p| p.harness) {
+        Some(profile_harness) if !argv_has_flag(raw_args, "--harness") => profile_harness.into(),
+        _ => args.harness,
+    };
+    let mut harness_impl: Box<dyn Harness> = make_harness(harness, args.stub_script.as_deref())?;
+    let cancel = install_cancel_handler();
+    let mut opts = core_ralph::RalphOptions {
         prompt,
-        change_id: args.change.clone(),
+        change_id: change,
         module_id: args.module.clone(),
         model: args.model.clone(),
         min_iterations: args.min_iterations,
         max_iterations: args.max_iterations,
         completion_promise: args.completion_promise.clone(),
+        completion_format: args.completion_format.into(),
+        completion_tag: args.completion_tag.clone(),
+        completion_json_pointer: args.completion_json_pointer.clone(),
         allow_all: args.allow_all,
         no_commit: args.no_commit,
         interactive,
         status: args.status,
         add_context: args.add_context.clone(),
         clear_context: args.clear_context,
-        verbose: args.verbose,
+        verbose: output::enabled(Verbosity::Verbose),
         continue_module,
         continue_ready: args.continue_ready,
+        steal_stale,
         inactivity_timeout,
+        resume_sessions: args.resume_sessions,
+        preflight: args.preflight,
         skip_validation: args.skip_validation,
         validation_command: args.validation_command.clone(),
+        validation_timeout: core_ralph::DEFAULT_VALIDATION_TIMEOUT,
+        validation_max_output_bytes: Some(core_ralph::DEFAULT_VALIDATION_MAX_OUTPUT_BYTES),
+        prompt_budget: load_prompt_budget(ito_path, rt, harness),
+        context_windows: load_context_windows(repo_root, ito_path, rt.ctx())
+            .map_err(to_cli_error)?,
+        strict_budget: args.strict_budget,
+        git_context_commits: (!args.no_git_context).then_some(args.git_context_commits),
+        git_context_max_chars: args.git_context_max_chars,
+        env_policy: load_env_policy(ito_path, rt, harness, &args.env_allow)?,
+        permissions: load_permissions(ito_path, rt, harness, args.permissions_profile.as_deref())?,
+        transcripts: !args.no_transcript,
         exit_on_error: args.exit_on_error,
         error_threshold,
         worktree: worktree_config,
+        cancel: Some(cancel.clone()),
     };
+    if let Some(profile) = &profile {
+        core_profiles::apply_profile_to_ralph_options(profile, &mut opts, &|key| {
+            argv_has_flag(raw_args, &format!("--{}", key.replace('_', "-")))
+        })
+        .map_err(to_cli_error)?;
+    }
 
     let result = core_ralph::run_ralph(
         ito_path,
@@ -353,7 +615,7 @@ pub(crate) fn handle_ralph_clap(
     if args.notify {
         notify_run_result(result.as_ref().err().map(|e| e.to_string()));
     }
-    result.map_err(to_cli_error)?;
+    result.map_err(|e| ralph_error(e, &cancel))?;
 
     if let Some(source) = task_source.as_ref() {
         let completed = ralph_run_completed(ito_path, "unscoped")?;
@@ -404,14 +666,31 @@ impl RalphWizardOverrides {
     }
 }
 
-fn make_harness(selected: HarnessArg, args: &RalphArgs) -> CliResult<Box<dyn Harness>> {
+/// Construct the harness selected on the command line.
+/// Profile applying to the targeted change, or the `default` profile.
+fn resolve_ralph_profile(
+    rt: &Runtime,
+    change_repo: &dyn ChangeRepository,
+    change: Option<&str>,
+) -> CliResult<Option<ProfileConfig>> {
+    let ito_path = rt.ito_path();
+    let project_root = ito_path.parent().unwrap_or(ito_path);
+    let change_id = change.and_then(|c| resolve_change_target(change_repo, c).ok());
+    core_profiles::resolve_change_profile(project_root, ito_path, rt.ctx(), change_id.as_deref())
+        .map_err(to_cli_error)
+}
+
+pub(super) fn make_harness(
+    selected: HarnessArg,
+    stub_script: Option<&str>,
+) -> CliResult<Box<dyn Harness>> {
     Ok(match selected {
         HarnessArg::Claude => Box::new(ClaudeCodeHarness),
         HarnessArg::Codex => Box::new(CodexHarness),
         HarnessArg::Copilot => Box::new(GitHubCopilotHarness),
         HarnessArg::Opencode => Box::new(OpencodeHarness),
         HarnessArg::Stub => {
-            let p = args.stub_script.as_ref().map(std::path::PathBuf::from);
+            let p = stub_script.map(std::path::PathBuf::from);
             let h = StubHarness::from_env_or_default(p).map_err(to_cli_error)?;
             Box::new(h)
         }
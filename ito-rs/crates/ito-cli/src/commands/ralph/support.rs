@@ -418,7 +418,14 @@ fn spawn_parallel_worker(
         .arg("--min-iterations")
         .arg(args.min_iterations.to_string())
         .arg("--completion-promise")
-        .arg(&args.completion_promise);
+        .arg(&args.completion_promise)
+        .arg("--completion-format")
+        .arg(core_ralph::CompletionFormat::from(args.completion_format).as_str())
+        .arg("--completion-tag")
+        .arg(&args.completion_tag);
+    if let Some(pointer) = &args.completion_json_pointer {
+        cmd.arg("--completion-json-pointer").arg(pointer);
+    }
     if let Some(max) = args.max_iterations {
         cmd.arg("--max-iterations").arg(max.to_string());
     }
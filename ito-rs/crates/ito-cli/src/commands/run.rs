@@ -0,0 +1,185 @@
+use clap::{Args, Subcommand};
+
+use crate::cli::HarnessArg;
+use crate::cli_error::{CliResult, fail, to_cli_error};
+use crate::runtime::Runtime;
+use ito_core::artifact_run::{
+    ArtifactRunOptions, DEFAULT_DEPENDENCY_BUDGET_CHARS, build_artifact_prompt, run_artifact,
+};
+use ito_core::harness::budget::load_context_windows;
+use ito_core::profiles as core_profiles;
+use ito_core::ralph as core_ralph;
+
+/// Run change artifacts through an AI harness.
+#[derive(Args, Debug, Clone)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+#[command(disable_help_subcommand = true)]
+pub struct RunArgs {
+    #[command(subcommand)]
+    pub action: RunAction,
+}
+
+/// Run subcommands.
+#[derive(Subcommand, Debug, Clone)]
+pub enum RunAction {
+    /// Generate one artifact of a change with a single harness run
+    Artifact(RunArtifactArgs),
+}
+
+/// Arguments for `ito run artifact`.
+#[derive(Args, Debug, Clone)]
+pub struct RunArtifactArgs {
+    /// Change id (supports prefixes)
+    pub change: String,
+
+    /// Artifact id from the change's schema (e.g. design)
+    pub artifact: String,
+
+    /// Harness to run (default: the change profile's harness, else opencode)
+    #[arg(long, value_enum)]
+    pub harness: Option<HarnessArg>,
+
+    /// Model id for the harness (default: the change profile's model)
+    #[arg(long)]
+    pub model: Option<String>,
+
+    /// Schema override (defaults to the change's schema)
+    #[arg(long)]
+    pub schema: Option<String>,
+
+    /// Print the prompt instead of running the harness
+    #[arg(long = "dry-run")]
+    pub dry_run: bool,
+
+    /// Allow all tool actions (dangerous)
+    #[arg(long = "allow-all", alias = "yolo", alias = "dangerously-allow-all")]
+    pub allow_all: bool,
+
+    /// Inactivity timeout (e.g. 15m)
+    #[arg(long = "timeout")]
+    pub timeout: Option<String>,
+
+    /// Character cap for dependency contents inlined into the prompt
+    #[arg(
+        long = "dependency-max-chars",
+        value_name = "N",
+        default_value_t = DEFAULT_DEPENDENCY_BUDGET_CHARS
+    )]
+    pub dependency_max_chars: usize,
+
+    /// Fail instead of warning when the prompt likely exceeds the model's context window
+    #[arg(long = "strict-budget")]
+    pub strict_budget: bool,
+
+    /// Pass an extra environment variable through the harness env policy (repeatable)
+    #[arg(long = "env-allow", value_name = "VAR")]
+    pub env_allow: Vec<String>,
+
+    /// Apply the named tool permission policy from `permission_profiles`
+    #[arg(long = "permissions-profile", value_name = "NAME")]
+    pub permissions_profile: Option<String>,
+
+    /// Hidden testing flag
+    #[arg(long = "stub-script", hide = true)]
+    pub stub_script: Option<String>,
+}
+
+pub(crate) fn handle_run_clap(rt: &Runtime, args: &RunArgs) -> CliResult<()> {
+    match &args.action {
+        RunAction::Artifact(args) => handle_run_artifact(rt, args),
+    }
+}
+
+fn handle_run_artifact(rt: &Runtime, args: &RunArtifactArgs) -> CliResult<()> {
+    let ito_path = rt.ito_path();
+    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+    let change_repo = runtime.repositories().changes.as_ref();
+    let change = crate::app::change_target::resolve_change_target(change_repo, &args.change)?;
+    let project_root = ito_path.parent().unwrap_or(ito_path);
+    let profile =
+        core_profiles::resolve_change_profile(project_root, ito_path, rt.ctx(), Some(&change))
+            .map_err(to_cli_error)?
+            .unwrap_or_default();
+    let harness = args
+        .harness
+        .or(profile.harness.map(HarnessArg::from))
+        .unwrap_or(HarnessArg::Opencode);
+
+    let inactivity_timeout = match &args.timeout {
+        Some(raw) => match core_ralph::parse_duration(raw) {
+            Ok(d) => Some(d),
+            Err(e) => return fail(format!("Invalid --timeout '{raw}': {e}")),
+        },
+        None => None,
+    };
+
+    let opts = ArtifactRunOptions {
+        schema: args.schema.clone(),
+        model: args.model.clone().or(profile.model),
+        env_policy: super::ralph::load_env_policy(ito_path, rt, harness, &args.env_allow)?,
+        permissions: super::ralph::load_permissions(
+            ito_path,
+            rt,
+            harness,
+            args.permissions_profile.as_deref(),
+        )?,
+        allow_all: args.allow_all,
+        inactivity_timeout,
+        dependency_budget_chars: args.dependency_max_chars,
+        context_windows: load_context_windows(project_root, ito_path, rt.ctx())
+            .map_err(to_cli_error)?,
+        strict_budget: args.strict_budget,
+    };
+
+    if args.dry_run {
+        let prompt = build_artifact_prompt(ito_path, &change, &args.artifact, &opts, rt.ctx())
+            .map_err(to_cli_error)?;
+        print!("{}", prompt.text);
+        return Ok(());
+    }
+
+    let mut harness = super::ralph::make_harness(harness, args.stub_script.as_deref())?;
+    let outcome = run_artifact(
+        harness.as_mut(),
+        ito_path,
+        &change,
+        &args.artifact,
+        &opts,
+        rt.ctx(),
+        rt.audit_store(),
+    )
+    .map_err(to_cli_error)?;
+
+    let output = outcome.prompt.change_dir.join(&outcome.prompt.output_path);
+    if !outcome.produced {
+        if !harness.streams_output() {
+            print!("{}", outcome.result.stdout);
+            eprint!("{}", outcome.result.stderr);
+        }
+        let reason = if outcome.result.timed_out {
+            " (harness timed out)".to_string()
+        } else if outcome.result.exit_code != 0 {
+            format!(" (harness exited with code {})", outcome.result.exit_code)
+        } else {
+            String::new()
+        };
+        return fail(format!(
+            "Artifact '{}' was not produced at {}{reason}",
+            args.artifact,
+            output.display()
+        ));
+    }
+
+    if outcome.result.exit_code != 0 {
+        eprintln!(
+            "Warning: harness exited with code {} after writing the artifact",
+            outcome.result.exit_code
+        );
+    }
+    println!(
+        "Generated '{}' for {change}: {}",
+        args.artifact,
+        output.display()
+    );
+    Ok(())
+}
@@ -47,6 +47,10 @@ pub(crate) fn handle_serve_clap(rt: &Runtime, args: &ServeArgs) -> CliResult<()>
     let ito_path = rt.ito_path();
     ensure_ito_dir_exists(ito_path)?;
 
+    if args.stdio {
+        return super::serve_stdio::run_stdio(rt);
+    }
+
     // Project root is parent of .ito
     let project_root = ito_path
         .parent()
@@ -68,6 +72,10 @@ pub(crate) fn handle_serve_clap(rt: &Runtime, args: &ServeArgs) -> CliResult<()>
         port,
     };
 
+    // Keep the repository index warm for the lifetime of the server.
+    #[cfg(feature = "repo-index-watch")]
+    let _repo_index_watcher = start_repo_index_watcher(ito_path);
+
     // Run the async server
     let runtime = tokio::runtime::Runtime::new().map_err(|e| {
         crate::cli_error::CliError::msg(format!("Failed to create tokio runtime: {e}"))
@@ -82,5 +90,18 @@ pub(crate) fn handle_serve_clap(rt: &Runtime, args: &ServeArgs) -> CliResult<()>
     Ok(())
 }
 
+#[cfg(feature = "repo-index-watch")]
+fn start_repo_index_watcher(ito_path: &Path) -> Option<ito_core::repo_index::RepoIndexWatcher> {
+    let started = ito_core::repo_index::IncrementalRepoIndex::load_or_build(ito_path)
+        .and_then(ito_core::repo_index::RepoIndexWatcher::spawn);
+    match started {
+        Ok(watcher) => Some(watcher),
+        Err(e) => {
+            eprintln!("Warning: repository index watcher unavailable: {e}");
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod serve_tests;
@@ -0,0 +1,295 @@
+//! JSON-RPC 2.0 over stdin/stdout for editor integrations (`ito serve --stdio`).
+//!
+//! Messages use LSP-style `Content-Length` framing. Requests are handled one
+//! at a time, every method is read-only, and the loop exits cleanly when stdin
+//! reaches EOF. Clients call `initialize` first to compare protocol versions.
+
+use crate::cli_error::{CliError, CliResult};
+use crate::commands::tasks::{json_task, resolve_change_id, tasks_format_label};
+use crate::runtime::Runtime;
+use ito_core::list::{ChangeListItem, ListChangesInput};
+use ito_core::tasks as core_tasks;
+use ito_core::templates as core_templates;
+use serde_json::{Value, json};
+use std::io::{BufRead, Write};
+
+/// Version of the stdio protocol; bump on incompatible method or payload changes.
+pub(crate) const PROTOCOL_VERSION: u64 = 1;
+
+/// Methods served by the stdio loop, in the order reported by `initialize`.
+const METHODS: &[&str] = &[
+    "initialize",
+    "changes/list",
+    "changes/status",
+    "changes/instructions",
+    "tasks/list",
+    "validate/spec",
+    "schemas/list",
+];
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+/// Application error: the underlying Ito operation failed.
+const SERVER_ERROR: i64 = -32000;
+/// Application error: the client speaks a different protocol version.
+const INCOMPATIBLE_PROTOCOL: i64 = -32001;
+
+#[derive(Debug)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    fn server(e: impl std::fmt::Display) -> Self {
+        Self::new(SERVER_ERROR, e.to_string())
+    }
+}
+
+/// Run the JSON-RPC loop on the process stdin/stdout until EOF.
+pub(crate) fn run_stdio(rt: &Runtime) -> CliResult<()> {
+    let stdin = std::io::stdin();
+    let stdout = std::io::stdout();
+    serve_stream(rt, &mut stdin.lock(), &mut stdout.lock())
+        .map_err(|e| CliError::msg(format!("stdio transport error: {e}")))
+}
+
+fn serve_stream(
+    rt: &Runtime,
+    reader: &mut impl BufRead,
+    writer: &mut impl Write,
+) -> std::io::Result<()> {
+    while let Some(body) = read_message(reader)? {
+        if let Some(response) = handle_message(rt, &body) {
+            write_message(writer, &response)?;
+        }
+    }
+    Ok(())
+}
+
+/// Read one `Content-Length` framed message; `None` at a clean EOF.
+fn read_message(reader: &mut impl BufRead) -> std::io::Result<Option<Vec<u8>>> {
+    let mut content_length: Option<usize> = None;
+    let mut saw_header = false;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            if saw_header {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "EOF inside message headers",
+                ));
+            }
+            return Ok(None);
+        }
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() {
+            if !saw_header {
+                continue;
+            }
+            break;
+        }
+        saw_header = true;
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse().ok();
+        }
+    }
+
+    let Some(len) = content_length else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "message is missing a valid Content-Length header",
+        ));
+    };
+    let mut body = vec![0; len];
+    reader.read_exact(&mut body)?;
+    Ok(Some(body))
+}
+
+fn write_message(writer: &mut impl Write, message: &Value) -> std::io::Result<()> {
+    let body = serde_json::to_vec(message)?;
+    write!(writer, "Content-Length: {}\r\n\r\n", body.len())?;
+    writer.write_all(&body)?;
+    writer.flush()
+}
+
+/// Handle one raw message body; returns `None` for notifications.
+fn handle_message(rt: &Runtime, body: &[u8]) -> Option<Value> {
+    let request: Value = match serde_json::from_slice(body) {
+        Ok(v) => v,
+        Err(e) => {
+            return Some(error_response(
+                Value::Null,
+                RpcError::new(PARSE_ERROR, format!("Parse error: {e}")),
+            ));
+        }
+    };
+
+    let id = request.get("id").cloned();
+    let Some(method) = request.get("method").and_then(Value::as_str) else {
+        return Some(error_response(
+            id.unwrap_or(Value::Null),
+            RpcError::new(INVALID_REQUEST, "Invalid request: missing method"),
+        ));
+    };
+    let params = request.get("params").cloned().unwrap_or(Value::Null);
+
+    let result = dispatch(rt, method, &params);
+    let id = id?;
+    Some(match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(e) => error_response(id, e),
+    })
+}
+
+fn error_response(id: Value, error: RpcError) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": error.code, "message": error.message },
+    })
+}
+
+fn dispatch(rt: &Runtime, method: &str, params: &Value) -> Result<Value, RpcError> {
+    match method {
+        "initialize" => initialize(params),
+        "changes/list" => changes_list(rt),
+        "changes/status" => {
+            let change = resolve_change(rt, params)?;
+            let schema = optional_str(params, "schema")?;
+            let status =
+                core_templates::compute_change_status(rt.ito_path(), &change, schema, rt.ctx())
+                    .map_err(RpcError::server)?;
+            to_value(&status)
+        }
+        "changes/instructions" => {
+            let change = resolve_change(rt, params)?;
+            let artifact = required_str(params, "artifact")?;
+            let schema = optional_str(params, "schema")?;
+            let instructions = core_templates::resolve_instructions(
+                rt.ito_path(),
+                &change,
+                schema,
+                artifact,
+                rt.ctx(),
+            )
+            .map_err(RpcError::server)?;
+            to_value(&instructions)
+        }
+        "tasks/list" => tasks_list(rt, params),
+        "validate/spec" => {
+            let spec = required_str(params, "spec")?;
+            let strict = params
+                .get("strict")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            let report = ito_core::validate::validate_spec(rt.ito_path(), spec, strict)
+                .map_err(RpcError::server)?;
+            to_value(&report)
+        }
+        "schemas/list" => to_value(&core_templates::list_schemas_detail(rt.ctx())),
+        other => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("Method not found: {other}"),
+        )),
+    }
+}
+
+fn initialize(params: &Value) -> Result<Value, RpcError> {
+    if let Some(requested) = params.get("protocolVersion") {
+        let Some(requested) = requested.as_u64() else {
+            return Err(RpcError::new(
+                INVALID_PARAMS,
+                "protocolVersion must be a positive integer",
+            ));
+        };
+        if requested != PROTOCOL_VERSION {
+            return Err(RpcError::new(
+                INCOMPATIBLE_PROTOCOL,
+                format!(
+                    "Unsupported protocol version {requested}; this ito speaks version {PROTOCOL_VERSION}"
+                ),
+            ));
+        }
+    }
+    Ok(json!({
+        "protocolVersion": PROTOCOL_VERSION,
+        "serverVersion": env!("CARGO_PKG_VERSION"),
+        "methods": METHODS,
+    }))
+}
+
+fn changes_list(rt: &Runtime) -> Result<Value, RpcError> {
+    let runtime = rt.repository_runtime().map_err(RpcError::server)?;
+    let summaries = ito_core::list::list_changes(
+        runtime.repositories().changes.as_ref(),
+        ListChangesInput::default(),
+    )
+    .map_err(RpcError::server)?;
+    let changes: Vec<ChangeListItem> = summaries.iter().map(Into::into).collect();
+    Ok(json!({ "changes": changes }))
+}
+
+fn tasks_list(rt: &Runtime, params: &Value) -> Result<Value, RpcError> {
+    let change = resolve_change(rt, params)?;
+    let runtime = rt.repository_runtime().map_err(RpcError::server)?;
+    let status =
+        core_tasks::get_task_status_from_repository(runtime.repositories().tasks.as_ref(), &change)
+            .map_err(RpcError::server)?;
+    let tasks: Vec<Value> = status.items.iter().map(json_task).collect();
+    Ok(json!({
+        "change_id": change,
+        "format": tasks_format_label(status.format),
+        "progress": {
+            "total": status.progress.total,
+            "complete": status.progress.complete,
+            "shelved": status.progress.shelved,
+            "in_progress": status.progress.in_progress,
+            "pending": status.progress.pending,
+            "remaining": status.progress.remaining,
+        },
+        "tasks": tasks,
+    }))
+}
+
+/// Resolve the `change` param (full id or unique prefix) to a canonical change id.
+fn resolve_change(rt: &Runtime, params: &Value) -> Result<String, RpcError> {
+    let input = required_str(params, "change")?;
+    let runtime = rt.repository_runtime().map_err(RpcError::server)?;
+    resolve_change_id(runtime.repositories().changes.as_ref(), input).map_err(RpcError::server)
+}
+
+fn required_str<'a>(params: &'a Value, key: &str) -> Result<&'a str, RpcError> {
+    optional_str(params, key)?
+        .ok_or_else(|| RpcError::new(INVALID_PARAMS, format!("Missing required param '{key}'")))
+}
+
+fn optional_str<'a>(params: &'a Value, key: &str) -> Result<Option<&'a str>, RpcError> {
+    match params.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s)),
+        Some(_) => Err(RpcError::new(
+            INVALID_PARAMS,
+            format!("Param '{key}' must be a string"),
+        )),
+    }
+}
+
+fn to_value(value: &impl serde::Serialize) -> Result<Value, RpcError> {
+    serde_json::to_value(value).map_err(RpcError::server)
+}
+
+#[cfg(test)]
+#[path = "serve_stdio_tests.rs"]
+mod serve_stdio_tests;
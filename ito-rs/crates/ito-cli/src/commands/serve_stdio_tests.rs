@@ -0,0 +1,71 @@
+use super::*;
+
+fn framed(body: &str) -> String {
+    format!("Content-Length: {}\r\n\r\n{body}", body.len())
+}
+
+#[test]
+fn read_message_reads_framed_bodies_and_stops_at_eof() {
+    let input = format!("{}{}", framed("{\"a\":1}"), framed("[]"));
+    let mut reader = std::io::Cursor::new(input.into_bytes());
+
+    assert_eq!(
+        read_message(&mut reader).expect("first"),
+        Some(b"{\"a\":1}".to_vec())
+    );
+    assert_eq!(
+        read_message(&mut reader).expect("second"),
+        Some(b"[]".to_vec())
+    );
+    assert_eq!(read_message(&mut reader).expect("eof"), None);
+}
+
+#[test]
+fn read_message_rejects_missing_content_length_and_truncated_headers() {
+    let mut no_length = std::io::Cursor::new(b"Content-Type: json\r\n\r\n{}".to_vec());
+    let err = read_message(&mut no_length).expect_err("missing length");
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    let mut truncated = std::io::Cursor::new(b"Content-Length: 2\r\n".to_vec());
+    let err = read_message(&mut truncated).expect_err("truncated");
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn write_message_frames_json() {
+    let mut out = Vec::new();
+    write_message(&mut out, &json!({ "id": 1 })).expect("write");
+    assert_eq!(String::from_utf8(out).expect("utf8"), framed("{\"id\":1}"));
+}
+
+#[test]
+fn initialize_checks_protocol_version() {
+    let ok = initialize(&json!({ "protocolVersion": PROTOCOL_VERSION })).expect("compatible");
+    assert_eq!(ok["protocolVersion"], PROTOCOL_VERSION);
+    assert_eq!(ok["methods"].as_array().map(Vec::len), Some(METHODS.len()));
+
+    assert!(initialize(&Value::Null).is_ok());
+
+    let err = initialize(&json!({ "protocolVersion": PROTOCOL_VERSION + 1 })).expect_err("newer");
+    assert_eq!(err.code, INCOMPATIBLE_PROTOCOL);
+
+    let err = initialize(&json!({ "protocolVersion": "1" })).expect_err("not a number");
+    assert_eq!(err.code, INVALID_PARAMS);
+}
+
+#[test]
+fn string_params_are_type_checked() {
+    let params = json!({ "change": "000-01", "schema": 3 });
+    assert_eq!(required_str(&params, "change").expect("change"), "000-01");
+    assert_eq!(optional_str(&params, "missing").expect("absent"), None);
+    assert_eq!(
+        optional_str(&params, "schema")
+            .expect_err("wrong type")
+            .code,
+        INVALID_PARAMS
+    );
+    assert_eq!(
+        required_str(&params, "artifact").expect_err("missing").code,
+        INVALID_PARAMS
+    );
+}
@@ -1,8 +1,22 @@
-use crate::cli::StatsArgs;
+use crate::cli::{StatsArgs, StatsCommand, StatsCostArgs, StatsSkillsArgs};
 use crate::cli_error::{CliResult, to_cli_error};
 use crate::runtime::Runtime;
+use crate::util::render_json;
+use ito_core::audit::{
+    EntityType, EventFilter, ops, parse_time_bound, read_audit_events_filtered_from_store,
+};
+use ito_core::stats::cost::{
+    CostFilter, CostGroup, CostReport, compute_cost_report, load_harness_usage, load_pricing,
+};
+use ito_core::stats::skills::{SkillUsageReport, compute_skill_usage};
+
+pub(crate) fn handle_stats_clap(rt: &Runtime, args: &StatsArgs) -> CliResult<()> {
+    match &args.command {
+        Some(StatsCommand::Cost(cost)) => return handle_stats_cost(rt, cost),
+        Some(StatsCommand::Skills(skills)) => return handle_stats_skills(rt, skills),
+        None => {}
+    }
 
-pub(crate) fn handle_stats_clap(rt: &Runtime, _args: &StatsArgs) -> CliResult<()> {
     let Some(config_dir) = ito_config::ito_config_dir(rt.ctx()) else {
         println!("No Ito config directory found.");
         return Ok(());
@@ -25,3 +39,151 @@ pub(crate) fn handle_stats_clap(rt: &Runtime, _args: &StatsArgs) -> CliResult<()
 
     Ok(())
 }
+
+fn handle_stats_cost(rt: &Runtime, args: &StatsCostArgs) -> CliResult<()> {
+    let ito_path = rt.ito_path();
+    let project_root = ito_path.parent().unwrap_or(ito_path);
+
+    let since_ms = match args.since.as_deref() {
+        Some(since) => {
+            let window = ito_core::ralph::duration::parse_duration(since).map_err(to_cli_error)?;
+            let window_ms = i64::try_from(window.as_millis()).unwrap_or(i64::MAX);
+            Some(
+                chrono::Utc::now()
+                    .timestamp_millis()
+                    .saturating_sub(window_ms),
+            )
+        }
+        None => None,
+    };
+    let filter = CostFilter {
+        change_id: args.change.clone(),
+        since_ms,
+    };
+
+    let records = load_harness_usage(ito_path).map_err(to_cli_error)?;
+    let pricing = load_pricing(project_root, ito_path, rt.ctx()).map_err(to_cli_error)?;
+    let report = compute_cost_report(&records, &pricing, &filter);
+
+    if args.json {
+        let rendered = render_json(&report).map_err(to_cli_error)?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    print_cost_report(&report);
+    Ok(())
+}
+
+fn print_cost_report(report: &CostReport) {
+    println!("Harness Cost");
+    println!("────────────────────────────────────────");
+    if report.groups.is_empty() {
+        println!("No harness runs recorded.");
+        return;
+    }
+
+    for group in &report.groups {
+        println!("{}", render_group(group));
+    }
+    println!();
+    if report.incomplete {
+        println!(
+            "Total: {} (plus unknown: some runs lack usage or pricing)",
+            format_usd(report.total_cost_usd)
+        );
+    } else {
+        println!("Total: {}", format_usd(report.total_cost_usd));
+    }
+}
+
+fn render_group(group: &CostGroup) -> String {
+    let change = group.change_id.as_deref().unwrap_or("(no change)");
+    let model = group.model.as_deref().unwrap_or("(default)");
+    let usage = if group.runs_without_usage == group.runs {
+        "tokens unknown".to_string()
+    } else {
+        let mut usage = format!(
+            "in {} / out {} / cache {}",
+            group.usage.input_tokens, group.usage.output_tokens, group.usage.cache_read_tokens
+        );
+        if group.runs_without_usage > 0 {
+            usage.push_str(&format!(" (+{} unknown)", group.runs_without_usage));
+        }
+        usage
+    };
+    let cost = group
+        .cost_usd
+        .map(format_usd)
+        .unwrap_or_else(|| "unknown".to_string());
+    format!(
+        "{change}  {model}  runs {runs}  {usage}  cost {cost}",
+        runs = group.runs
+    )
+}
+
+fn handle_stats_skills(rt: &Runtime, args: &StatsSkillsArgs) -> CliResult<()> {
+    let since = match args.since.as_deref() {
+        Some(raw) => Some(parse_time_bound(raw, chrono::Utc::now()).map_err(to_cli_error)?),
+        None => None,
+    };
+    let filter = EventFilter {
+        entity: Some(EntityType::Skill.as_str().to_string()),
+        op: Some(ops::SKILL_INVOKED.to_string()),
+        since,
+        ..EventFilter::default()
+    };
+    let events = read_audit_events_filtered_from_store(rt.audit_store(), &filter);
+    let report = compute_skill_usage(&events);
+
+    if args.json {
+        let rendered = render_json(&report).map_err(to_cli_error)?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    print_skill_report(&report);
+    Ok(())
+}
+
+fn print_skill_report(report: &SkillUsageReport) {
+    println!("Skill Usage");
+    println!("────────────────────────────────────────");
+    if report.skills.is_empty() {
+        println!("No skill invocations recorded.");
+        return;
+    }
+
+    for skill in &report.skills {
+        let harnesses: Vec<String> = skill
+            .by_harness
+            .iter()
+            .map(|(harness, count)| format!("{harness} {count}"))
+            .collect();
+        let flag = if skill.known { "" } else { "  (unknown skill)" };
+        println!(
+            "{}: {}  [{}]{flag}",
+            skill.skill_id,
+            skill.invocations,
+            harnesses.join(", ")
+        );
+    }
+    println!();
+    println!("By harness:");
+    for (harness, count) in &report.harnesses {
+        println!("  {harness}: {count}");
+    }
+    println!();
+    if report.unknown > 0 {
+        println!(
+            "Total: {} ({} of unknown skills)",
+            report.total, report.unknown
+        );
+    } else {
+        println!("Total: {}", report.total);
+    }
+}
+
+fn format_usd(amount: f64) -> String {
+    format!("${amount:.4}")
+}
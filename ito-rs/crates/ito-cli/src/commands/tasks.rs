@@ -4,7 +4,8 @@ use crate::cli_error::{CliError, CliResult, fail, to_cli_error};
 use crate::commands::sync::{best_effort_sync_coordination, best_effort_sync_coordination_bg};
 use crate::diagnostics;
 use crate::runtime::Runtime;
-use ito_core::audit::{Actor, AuditEventBuilder, EntityType, ops};
+use crate::ui::{Tone, Ui};
+use ito_core::audit::ops;
 #[cfg(feature = "coordination-branch")]
 use ito_core::coordination_worktree::maybe_auto_commit_coordination;
 use ito_core::implementation_readiness::ReadinessPhase;
@@ -16,6 +17,11 @@ use ito_core::tasks::{DiagnosticLevel, TaskStatus, TasksFormat};
 mod backend;
 #[cfg(not(feature = "backend"))]
 mod backend_unavailable;
+mod events;
+mod history;
+mod plan;
+mod ready;
+mod release;
 mod support;
 
 #[cfg(feature = "backend")]
@@ -28,10 +34,12 @@ use backend_unavailable::{
     handle_backend_allocate, handle_backend_claim, handle_backend_release, handle_backend_sync,
     sync_after_mutation,
 };
+use events::emit_task_event;
 use support::{
-    backend_tasks_path, json_diagnostic, json_task, missing_tasks_message, print_json,
-    resolve_change_id, summarize_status, task_status_label, tasks_format_label,
+    backend_tasks_path, json_diagnostic, json_timing, missing_tasks_message, print_json,
+    print_timings, resolve_change_id_with, summarize_status, task_status_label,
 };
+pub(crate) use support::{json_task, resolve_change_id, tasks_format_label};
 
 /// Attempt to auto-commit the coordination worktree after a task mutation.
 ///
@@ -54,26 +62,107 @@ fn require_task_mutation_readiness(rt: &Runtime, change_id: &str, json: bool) ->
 #[cfg(not(feature = "coordination-branch"))]
 fn auto_commit_after_task_mutation(_rt: &Runtime, _change_id: &str, _action: &str) {}
 
+/// Warn when the coordination branch already records the task as claimed.
+///
+/// Only checked for filesystem-backed changes with the coordination branch
+/// enabled; the check is advisory and never blocks the start.
+#[cfg(feature = "coordination-branch")]
+fn warn_on_coordination_claim(rt: &Runtime, mode: PersistenceMode, change_id: &str, task_id: &str) {
+    if mode != PersistenceMode::Filesystem || rt.command_side_effects_suppressed() {
+        return;
+    }
+    let Ok(config) = rt.typed_config() else {
+        return;
+    };
+    let coordination = &config.changes.coordination_branch;
+    if !coordination.enabled.0 {
+        return;
+    }
+
+    let ito_path = rt.ito_path();
+    let project_root = ito_path.parent().unwrap_or(ito_path);
+    let branch = &coordination.name;
+    match ito_core::change_sync::coordination_task_status(
+        project_root,
+        ito_path,
+        branch,
+        change_id,
+        task_id,
+    ) {
+        Some(TaskStatus::InProgress) => eprintln!(
+            "Warning: task \"{task_id}\" is already in progress on coordination branch '{branch}'; someone else may be working on it"
+        ),
+        Some(TaskStatus::Complete) => eprintln!(
+            "Warning: task \"{task_id}\" is already complete on coordination branch '{branch}'"
+        ),
+        Some(TaskStatus::Pending | TaskStatus::Shelved) | None => {}
+    }
+}
+
+#[cfg(not(feature = "coordination-branch"))]
+fn warn_on_coordination_claim(
+    _rt: &Runtime,
+    _mode: PersistenceMode,
+    _change_id: &str,
+    _task_id: &str,
+) {
+}
+
 pub(crate) fn handle_tasks_clap(rt: &Runtime, args: &TasksArgs) -> CliResult<()> {
     let Some(action) = &args.action else {
         // Preserve legacy behavior: `ito tasks` errors.
         return fail("Missing required argument <change-id>");
     };
 
+    if args.exact
+        && let Some(change_id) = action_change_id(action)
+    {
+        let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+        resolve_change_id_with(runtime.repositories().changes.as_ref(), change_id, true)?;
+    }
+
     // Handle backend coordination commands directly (they don't use the legacy handler)
     match action {
         TasksAction::Claim { change_id } => {
             return handle_backend_claim(rt, change_id, args.json);
         }
-        TasksAction::Release { change_id } => {
+        TasksAction::Release {
+            change_id,
+            task_id: None,
+            older_than: None,
+            all_stale: false,
+        } => {
             return handle_backend_release(rt, change_id, args.json);
         }
+        TasksAction::Release {
+            change_id,
+            task_id,
+            older_than,
+            ..
+        } => {
+            return release::handle_tasks_release(
+                rt,
+                change_id,
+                task_id.as_deref(),
+                older_than.as_deref(),
+                args.json,
+            );
+        }
         TasksAction::Allocate => {
             return handle_backend_allocate(rt, args.json);
         }
         TasksAction::Sync(sync_action) => {
             return handle_backend_sync(rt, sync_action, args.json);
         }
+        TasksAction::Plan {
+            change_id,
+            max_parallel,
+        } => {
+            return plan::handle_tasks_plan(rt, change_id, *max_parallel, args.json);
+        }
+        TasksAction::History { change_id, task_id } => {
+            return history::handle_tasks_history(rt, change_id, task_id.as_deref(), args.json);
+        }
         // All other actions fall through to the legacy forwarding handler below
         TasksAction::Init { .. }
         | TasksAction::Status { .. }
@@ -85,17 +174,30 @@ pub(crate) fn handle_tasks_clap(rt: &Runtime, args: &TasksArgs) -> CliResult<()>
         | TasksAction::Unshelve { .. }
         | TasksAction::Add { .. }
         | TasksAction::Show { .. }
+        | TasksAction::Lint { .. }
         | TasksAction::External(_) => {}
     }
 
+    if let TasksAction::Status { at: Some(rev), .. } = action {
+        rt.pin_revision(rev).map_err(to_cli_error)?;
+    }
+
     let mut forwarded: Vec<String> = match action {
         TasksAction::Init { change_id } => vec!["init".to_string(), change_id.clone()],
-        TasksAction::Status { change_id, wave } => {
+        TasksAction::Status {
+            change_id,
+            wave,
+            timings,
+            ..
+        } => {
             let mut out = vec!["status".to_string(), change_id.clone()];
             if let Some(wave) = wave {
                 out.push("--wave".to_string());
                 out.push(wave.to_string());
             }
+            if *timings {
+                out.push("--timings".to_string());
+            }
             out
         }
         TasksAction::Next { change_id } => vec!["next".to_string(), change_id.clone()],
@@ -130,12 +232,15 @@ pub(crate) fn handle_tasks_clap(rt: &Runtime, args: &TasksArgs) -> CliResult<()>
             wave.to_string(),
         ],
         TasksAction::Show { change_id } => vec!["show".to_string(), change_id.clone()],
+        TasksAction::Lint { change_id } => vec!["lint".to_string(), change_id.clone()],
         TasksAction::External(rest) => rest.clone(),
         // Backend commands handled above
         TasksAction::Claim { .. }
         | TasksAction::Release { .. }
         | TasksAction::Allocate
-        | TasksAction::Sync(_) => unreachable!(),
+        | TasksAction::Sync(_)
+        | TasksAction::Plan { .. }
+        | TasksAction::History { .. } => unreachable!(),
     };
 
     if args.json {
@@ -144,6 +249,31 @@ pub(crate) fn handle_tasks_clap(rt: &Runtime, args: &TasksArgs) -> CliResult<()>
     handle_tasks(rt, &forwarded)
 }
 
+/// Change id targeted by `action`, when it takes one.
+///
+/// With `--exact` this id is checked up front; an exact id then resolves to
+/// itself wherever the action resolves it again.
+fn action_change_id(action: &TasksAction) -> Option<&str> {
+    match action {
+        TasksAction::Init { change_id }
+        | TasksAction::Status { change_id, .. }
+        | TasksAction::Next { change_id }
+        | TasksAction::Start { change_id, .. }
+        | TasksAction::Complete { change_id, .. }
+        | TasksAction::Shelve { change_id, .. }
+        | TasksAction::Unshelve { change_id, .. }
+        | TasksAction::Add { change_id, .. }
+        | TasksAction::Show { change_id }
+        | TasksAction::Lint { change_id }
+        | TasksAction::Plan { change_id, .. }
+        | TasksAction::History { change_id, .. }
+        | TasksAction::Claim { change_id }
+        | TasksAction::Release { change_id, .. } => Some(change_id),
+        TasksAction::Ready { change_id } => change_id.as_deref(),
+        TasksAction::Allocate | TasksAction::Sync(_) | TasksAction::External(_) => None,
+    }
+}
+
 pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
     fn parse_wave_flag(args: &[String]) -> u32 {
         args.iter()
@@ -168,6 +298,7 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
 
     let sub = args.first().map(|s| s.as_str()).unwrap_or("");
     let want_json = args.iter().any(|a| a == "--json");
+    let want_timings = args.iter().any(|a| a == "--timings");
     let ito_path = rt.ito_path();
     let runtime = rt.repository_runtime().map_err(to_cli_error)?;
     let repos = runtime.repositories();
@@ -177,7 +308,7 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
 
     // Handle "ready" specially since change_id is optional
     if sub == "ready" {
-        return handle_tasks_ready(rt, args);
+        return ready::handle_tasks_ready(rt, args);
     }
     let input_change_id = args.get(1).map(|s| s.as_str()).unwrap_or("");
     if input_change_id.is_empty() || input_change_id.starts_with('-') {
@@ -191,7 +322,10 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
             if runtime.mode() == PersistenceMode::Filesystem {
                 let change_dir = ito_path.join("changes").join(&change_id);
                 if !change_dir.exists() {
-                    return fail(format!("Change '{change_id}' not found"));
+                    return Err(CliError::not_found(
+                        "CHANGE_NOT_FOUND",
+                        format!("Change '{change_id}' not found"),
+                    ));
                 }
             }
 
@@ -230,8 +364,14 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
             Ok(())
         }
         "status" => {
-            let (path, status) = if runtime.mode() == PersistenceMode::Remote {
-                let path = backend_tasks_path();
+            let (path, status) = if runtime.mode() == PersistenceMode::Remote
+                || runtime.revision().is_some()
+            {
+                let path = if runtime.revision().is_some() {
+                    core_tasks::tracking_file_path(ito_path, &change_id).map_err(to_cli_error)?
+                } else {
+                    backend_tasks_path()
+                };
                 let raw = task_mutations
                     .load_tasks_markdown(&change_id)
                     .map_err(to_cli_error)?;
@@ -280,6 +420,7 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
             {
                 return Err(CliError::msg(msg));
             }
+            let stale = release::configured_stale_tasks(rt, &change_id, &status.items)?;
 
             if want_json {
                 let warnings: Vec<serde_json::Value> = status
@@ -301,7 +442,7 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                     })
                     .collect();
 
-                return print_json(&serde_json::json!({
+                let mut body = serde_json::json!({
                     "action": "status",
                     "change_id": change_id,
                     "path": path.display().to_string(),
@@ -317,11 +458,18 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                     "warnings": warnings,
                     "ready_tasks": ready_tasks,
                     "blocked_tasks": blocked_tasks,
-                }));
+                    "stale_tasks": release::stale_tasks_json(&stale, &status.items),
+                });
+                if want_timings {
+                    let timings = core_tasks::cycle_times_for(status.format, &status.items);
+                    body["timings"] = timings.iter().map(json_timing).collect();
+                }
+                return print_json(&body);
             }
 
-            println!("Tasks for: {change_id}");
-            println!("──────────────────────────────────────────────────");
+            let ui = Ui::stdout();
+            println!("{}", ui.kv("Tasks for", &change_id));
+            println!("{}", ui.rule(50));
             println!();
 
             let warnings = diagnostics::render_task_diagnostics(
@@ -330,7 +478,7 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 DiagnosticLevel::Warning,
             );
             if !warnings.is_empty() {
-                println!("Warnings");
+                println!("{}", ui.paint("Warnings", Tone::Warning));
                 print!("{warnings}");
                 println!();
             }
@@ -339,39 +487,64 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 TasksFormat::Enhanced => {
                     let done = status.progress.complete + status.progress.shelved;
                     println!(
-                        "Progress: {}/{} done ({} complete, {} shelved), {} in-progress, {} pending",
-                        done,
-                        status.progress.total,
-                        status.progress.complete,
-                        status.progress.shelved,
-                        status.progress.in_progress,
-                        status.progress.pending
+                        "{}",
+                        ui.kv(
+                            "Progress",
+                            format!(
+                                "{}/{} done ({} complete, {} shelved), {} in-progress, {} pending",
+                                done,
+                                status.progress.total,
+                                status.progress.complete,
+                                status.progress.shelved,
+                                status.progress.in_progress,
+                                status.progress.pending
+                            )
+                        )
                     );
                 }
                 TasksFormat::Checkbox => {
                     println!(
-                        "Progress (compat): {}/{} complete, {} in-progress, {} pending",
-                        status.progress.complete,
-                        status.progress.total,
-                        status.progress.in_progress,
-                        status.progress.pending
+                        "{}",
+                        ui.kv(
+                            "Progress (compat)",
+                            format!(
+                                "{}/{} complete, {} in-progress, {} pending",
+                                status.progress.complete,
+                                status.progress.total,
+                                status.progress.in_progress,
+                                status.progress.pending
+                            )
+                        )
                     );
                 }
             }
 
             println!();
-            println!("Ready");
-            for t in &status.ready {
-                println!("  - {}: {}", t.id, t.name);
+            println!("{}", ui.heading("Ready"));
+            // Nested checkbox items read top-down: a parent before its children.
+            let mut ready: Vec<_> = status.ready.iter().collect();
+            if status.format == TasksFormat::Checkbox {
+                ready.sort_by_key(|t| t.header_line_index);
+            }
+            for t in ready {
+                let indent = "  ".repeat(usize::from(t.depth) + 1);
+                println!("{indent}- {}: {}", ui.paint(&t.id, Tone::Active), t.name);
             }
             println!();
-            println!("Blocked");
+            println!("{}", ui.heading("Blocked"));
             for (t, blockers) in &status.blocked {
-                println!("  - {}: {}", t.id, t.name);
+                let indent = "  ".repeat(usize::from(t.depth) + 1);
+                println!("{indent}- {}: {}", ui.paint(&t.id, Tone::Error), t.name);
                 for b in blockers {
-                    println!("    - {b}");
+                    println!("{indent}  - {}", ui.paint(b, Tone::Muted));
                 }
             }
+            release::print_stale_tasks(&ui, &change_id, &stale, &status.items);
+
+            if want_timings {
+                println!();
+                print_timings(&core_tasks::cycle_times_for(status.format, &status.items));
+            }
 
             Ok(())
         }
@@ -547,26 +720,22 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
 
             require_task_mutation_readiness(rt, &change_id, want_json)?;
             best_effort_sync_coordination(rt, "before task start");
+            warn_on_coordination_claim(rt, runtime.mode(), &change_id, task_id);
 
             let _task = task_mutations
                 .start_task(&change_id, task_id)
                 .map_err(to_cli_error)?;
 
             // Emit audit event for task start
-            if let Some(event) = AuditEventBuilder::new()
-                .entity(EntityType::Task)
-                .entity_id(task_id)
-                .scope(&change_id)
-                .op(ops::TASK_STATUS_CHANGE)
-                .from("pending")
-                .to("in-progress")
-                .actor(Actor::Cli)
-                .by(rt.user_identity())
-                .ctx(rt.event_context().clone())
-                .build()
-            {
-                rt.emit_audit_event(&event);
-            }
+            emit_task_event(
+                rt,
+                &change_id,
+                task_id,
+                ops::TASK_STATUS_CHANGE,
+                Some("pending"),
+                "in-progress",
+                None,
+            );
 
             // Best-effort backend sync after mutation
             sync_after_mutation(rt, &change_id);
@@ -606,19 +775,15 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 .map_err(to_cli_error)?;
 
             // Emit audit event for task completion
-            if let Some(event) = AuditEventBuilder::new()
-                .entity(EntityType::Task)
-                .entity_id(task_id)
-                .scope(&change_id)
-                .op(ops::TASK_STATUS_CHANGE)
-                .to("complete")
-                .actor(Actor::Cli)
-                .by(rt.user_identity())
-                .ctx(rt.event_context().clone())
-                .build()
-            {
-                rt.emit_audit_event(&event);
-            }
+            emit_task_event(
+                rt,
+                &change_id,
+                task_id,
+                ops::TASK_STATUS_CHANGE,
+                None,
+                "complete",
+                None,
+            );
 
             // Best-effort backend sync after mutation
             sync_after_mutation(rt, &change_id);
@@ -657,19 +822,15 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 .map_err(to_cli_error)?;
 
             // Emit audit event for task shelve
-            if let Some(event) = AuditEventBuilder::new()
-                .entity(EntityType::Task)
-                .entity_id(task_id)
-                .scope(&change_id)
-                .op(ops::TASK_STATUS_CHANGE)
-                .to("shelved")
-                .actor(Actor::Cli)
-                .by(rt.user_identity())
-                .ctx(rt.event_context().clone())
-                .build()
-            {
-                rt.emit_audit_event(&event);
-            }
+            emit_task_event(
+                rt,
+                &change_id,
+                task_id,
+                ops::TASK_STATUS_CHANGE,
+                None,
+                "shelved",
+                None,
+            );
 
             // Best-effort backend sync after mutation
             sync_after_mutation(rt, &change_id);
@@ -699,20 +860,15 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 .map_err(to_cli_error)?;
 
             // Emit audit event for task unshelve
-            if let Some(event) = AuditEventBuilder::new()
-                .entity(EntityType::Task)
-                .entity_id(task_id)
-                .scope(&change_id)
-                .op(ops::TASK_STATUS_CHANGE)
-                .from("shelved")
-                .to("pending")
-                .actor(Actor::Cli)
-                .by(rt.user_identity())
-                .ctx(rt.event_context().clone())
-                .build()
-            {
-                rt.emit_audit_event(&event);
-            }
+            emit_task_event(
+                rt,
+                &change_id,
+                task_id,
+                ops::TASK_STATUS_CHANGE,
+                Some("shelved"),
+                "pending",
+                None,
+            );
 
             // Best-effort backend sync after mutation
             sync_after_mutation(rt, &change_id);
@@ -744,23 +900,18 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 .task;
 
             // Emit audit event for task add
-            if let Some(event) = AuditEventBuilder::new()
-                .entity(EntityType::Task)
-                .entity_id(&task.id)
-                .scope(&change_id)
-                .op(ops::TASK_ADD)
-                .to("pending")
-                .actor(Actor::Cli)
-                .by(rt.user_identity())
-                .meta(serde_json::json!({
+            emit_task_event(
+                rt,
+                &change_id,
+                &task.id,
+                ops::TASK_ADD,
+                None,
+                "pending",
+                Some(serde_json::json!({
                     "wave": wave,
                     "name": task_name,
-                }))
-                .ctx(rt.event_context().clone())
-                .build()
-            {
-                rt.emit_audit_event(&event);
-            }
+                })),
+            );
 
             // Best-effort backend sync after mutation
             sync_after_mutation(rt, &change_id);
@@ -932,152 +1083,63 @@ pub(crate) fn handle_tasks(rt: &Runtime, args: &[String]) -> CliResult<()> {
                 Ok(())
             }
         }
-        _ => fail(format!("Unknown tasks subcommand '{sub}'")),
-    }
-}
-
-/// Handle `tasks ready [change_id] [--json]`
-fn handle_tasks_ready(rt: &Runtime, args: &[String]) -> CliResult<()> {
-    let want_json = args.iter().any(|a| a == "--json");
-
-    // Check if we have a change_id (arg after "ready" that doesn't start with -)
-    let change_id = args
-        .get(1)
-        .filter(|s| !s.starts_with('-'))
-        .map(|s| s.as_str());
-
-    if let Some(change_id) = change_id {
-        // Single change mode
-        handle_tasks_ready_single(rt, change_id, want_json)
-    } else {
-        // All changes mode
-        handle_tasks_ready_all(rt, want_json)
-    }
-}
+        "lint" => {
+            let path = if runtime.mode() == PersistenceMode::Remote {
+                backend_tasks_path()
+            } else {
+                core_tasks::tracking_file_path(ito_path, &change_id).map_err(to_cli_error)?
+            };
+            let exists = if runtime.mode() == PersistenceMode::Remote {
+                task_mutations
+                    .load_tasks_markdown(&change_id)
+                    .map_err(to_cli_error)?
+                    .is_some()
+            } else {
+                path.exists()
+            };
+            if !exists {
+                return fail(missing_tasks_message(&path, &change_id));
+            }
+            let parsed = task_repo.load_tasks(&change_id).map_err(to_cli_error)?;
+            let found = core_tasks::lint_tasks(&parsed);
+            let errors = found
+                .iter()
+                .filter(|d| d.level == DiagnosticLevel::Error)
+                .count();
 
-/// Show ready tasks for a single change
-fn handle_tasks_ready_single(rt: &Runtime, change_id: &str, want_json: bool) -> CliResult<()> {
-    let ito_path = rt.ito_path();
-    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
-    let repos = runtime.repositories();
-    let change_repo = repos.changes.as_ref();
-    let task_repo = repos.tasks.as_ref();
-    let task_mutations = repos.task_mutations.as_ref();
-    let change_id = resolve_change_id(change_repo, change_id)?;
-    let (path, status) = if runtime.mode() == PersistenceMode::Remote {
-        let path = backend_tasks_path();
-        let raw = task_mutations
-            .load_tasks_markdown(&change_id)
-            .map_err(to_cli_error)?;
-        if raw.is_none() {
-            let message = missing_tasks_message(&path, &change_id);
             if want_json {
-                return print_json(&serde_json::json!({
-                    "action": "ready",
+                let items: Vec<serde_json::Value> =
+                    found.iter().map(|d| json_diagnostic(&path, d)).collect();
+                print_json(&serde_json::json!({
+                    "action": "lint",
                     "change_id": change_id,
                     "path": path.display().to_string(),
-                    "exists": false,
-                    "message": message,
-                    "ready_tasks": [],
-                }));
+                    "errors": errors,
+                    "warnings": found.len() - errors,
+                    "diagnostics": items,
+                }))?;
+            } else if found.is_empty() {
+                println!("No problems found in {}", path.display());
+            } else {
+                for d in &found {
+                    let line = d.line.map(|l| format!(":{l}")).unwrap_or_default();
+                    let code = d.code.map(|c| format!(" [{c}]")).unwrap_or_default();
+                    println!(
+                        "{}{line}: {}{code}: {}",
+                        path.display(),
+                        d.level.as_str(),
+                        d.message
+                    );
+                }
+                println!();
+                println!("{errors} error(s), {} warning(s)", found.len() - errors);
             }
-            println!("{message}");
-            return Ok(());
-        }
-        let status = core_tasks::get_task_status_from_repository(task_repo, &change_id)
-            .map_err(to_cli_error)?;
-        (path, status)
-    } else {
-        let path = core_tasks::tracking_file_path(ito_path, &change_id).map_err(to_cli_error)?;
-        let status = core_tasks::get_task_status(ito_path, &change_id).map_err(to_cli_error)?;
-        (path, summarize_status(status))
-    };
 
-    if let Some(msg) = diagnostics::blocking_task_error_message(&path, &status.diagnostics) {
-        return Err(CliError::msg(msg));
-    }
-
-    if want_json {
-        let json_tasks: Vec<serde_json::Value> = status.ready.iter().map(json_task).collect();
-        return print_json(&serde_json::json!({
-            "action": "ready",
-            "change_id": change_id,
-            "path": path.display().to_string(),
-            "ready_tasks": json_tasks,
-        }));
-    }
-
-    if status.ready.is_empty() {
-        if status.progress.remaining == 0 {
-            println!("All tasks complete for \"{change_id}\"!");
-        } else {
-            println!("No ready tasks for \"{change_id}\" (tasks may be blocked or shelved).");
-        }
-        return Ok(());
-    }
-
-    println!("Ready Tasks for: {change_id}");
-    println!("──────────────────────────────────────────────────");
-    println!();
-
-    for t in &status.ready {
-        println!("Task {}: {}", t.id, t.name);
-        if !t.files.is_empty() {
-            println!("  Files: {}", t.files.join(", "));
-        }
-    }
-
-    println!();
-    println!("Run \"ito tasks start {change_id} <task-id>\" to begin a task");
-
-    Ok(())
-}
-
-/// Show ready tasks across all changes
-fn handle_tasks_ready_all(rt: &Runtime, want_json: bool) -> CliResult<()> {
-    let ito_path = rt.ito_path();
-    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
-    let repos = runtime.repositories();
-    let change_repo = repos.changes.as_ref();
-    let ready_changes = if runtime.mode() == PersistenceMode::Remote {
-        core_tasks::list_ready_tasks_across_changes_with_repo(change_repo, repos.tasks.as_ref())
-            .map_err(to_cli_error)?
-    } else {
-        core_tasks::list_ready_tasks_across_changes(change_repo, ito_path).map_err(to_cli_error)?
-    };
-
-    if ready_changes.is_empty() {
-        if want_json {
-            return print_json(&serde_json::json!([]));
-        } else {
-            println!("No ready changes found.");
-        }
-        return Ok(());
-    }
-
-    let mut all_results: Vec<serde_json::Value> = Vec::new();
-
-    for change in &ready_changes {
-        if want_json {
-            let json_tasks: Vec<serde_json::Value> =
-                change.ready_tasks.iter().map(json_task).collect();
-            all_results.push(serde_json::json!({
-                "action": "ready",
-                "change_id": change.change_id,
-                "ready_tasks": json_tasks,
-            }));
-        } else {
-            println!("{}:", change.change_id);
-            for t in &change.ready_tasks {
-                println!("  {} - {}", t.id, t.name);
+            if errors > 0 {
+                return Err(CliError::silent());
             }
-            println!();
+            Ok(())
         }
+        _ => fail(format!("Unknown tasks subcommand '{sub}'")),
     }
-
-    if want_json {
-        return print_json(&serde_json::json!(all_results));
-    }
-
-    Ok(())
 }
@@ -0,0 +1,45 @@
+use crate::runtime::Runtime;
+use ito_core::audit::{Actor, AgentAttribution, AuditEventBuilder, EntityType};
+
+/// Record a task mutation in the audit log.
+///
+/// The event is attributed to the agent harness when the CLI runs under one
+/// (see [`AgentAttribution::from_env`]) and to the human's git identity
+/// otherwise.
+pub(super) fn emit_task_event(
+    rt: &Runtime,
+    change_id: &str,
+    task_id: &str,
+    op: &str,
+    from: Option<&str>,
+    to: &str,
+    meta: Option<serde_json::Value>,
+) {
+    let (actor, by, meta) = match AgentAttribution::from_env() {
+        Some(agent) => (
+            Actor::Agent,
+            agent.identity(),
+            Some(agent.merge_into_meta(meta)),
+        ),
+        None => (Actor::Cli, rt.user_identity().to_string(), meta),
+    };
+
+    let mut builder = AuditEventBuilder::new()
+        .entity(EntityType::Task)
+        .entity_id(task_id)
+        .scope(change_id)
+        .op(op)
+        .to(to)
+        .actor(actor)
+        .by(by)
+        .ctx(rt.event_context().clone());
+    if let Some(from) = from {
+        builder = builder.from(from);
+    }
+    if let Some(meta) = meta {
+        builder = builder.meta(meta);
+    }
+    if let Some(event) = builder.build() {
+        rt.emit_audit_event(&event);
+    }
+}
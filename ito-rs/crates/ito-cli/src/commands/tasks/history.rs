@@ -0,0 +1,117 @@
+use super::resolve_change_id;
+use super::support::print_json;
+use crate::cli_error::{CliResult, to_cli_error};
+use crate::runtime::Runtime;
+use ito_core::audit::{
+    AgentAttribution, AuditEvent, EntityType, EventFilter, describe_actor,
+    read_audit_events_filtered_from_store,
+};
+
+/// Print the audited status timeline of a change's tasks, optionally for one task.
+pub(super) fn handle_tasks_history(
+    rt: &Runtime,
+    input_change_id: &str,
+    task_id: Option<&str>,
+    want_json: bool,
+) -> CliResult<()> {
+    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+    let change_id = resolve_change_id(runtime.repositories().changes.as_ref(), input_change_id)?;
+
+    let filter = EventFilter {
+        entity: Some(EntityType::Task.as_str().to_string()),
+        scope: Some(change_id.clone()),
+        ..EventFilter::default()
+    };
+    let events: Vec<AuditEvent> = read_audit_events_filtered_from_store(rt.audit_store(), &filter)
+        .into_iter()
+        .filter(|event| task_id.is_none_or(|id| event.entity_id == id))
+        .collect();
+    let timelines = group_by_task(&events);
+
+    if want_json {
+        let tasks: Vec<serde_json::Value> = timelines
+            .iter()
+            .map(|(id, events)| {
+                serde_json::json!({
+                    "task_id": id,
+                    "events": events.iter().map(|e| json_history_event(e)).collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+        return print_json(&serde_json::json!({
+            "change_id": change_id,
+            "task_id": task_id,
+            "tasks": tasks,
+        }));
+    }
+
+    if timelines.is_empty() {
+        match task_id {
+            Some(id) => println!("No history recorded for task {id} in {change_id}."),
+            None => println!("No task history recorded for {change_id}."),
+        }
+        return Ok(());
+    }
+
+    for (idx, (id, events)) in timelines.iter().enumerate() {
+        if idx > 0 {
+            println!();
+        }
+        println!("Task {id}");
+        for event in events {
+            println!("  {}", format_history_line(event));
+        }
+    }
+    Ok(())
+}
+
+/// Group events by task id, keeping tasks in order of first appearance and
+/// each task's events in log order.
+fn group_by_task(events: &[AuditEvent]) -> Vec<(&str, Vec<&AuditEvent>)> {
+    let mut groups: Vec<(&str, Vec<&AuditEvent>)> = Vec::new();
+    for event in events {
+        match groups
+            .iter_mut()
+            .find(|(id, _)| *id == event.entity_id.as_str())
+        {
+            Some((_, group)) => group.push(event),
+            None => groups.push((event.entity_id.as_str(), vec![event])),
+        }
+    }
+    groups
+}
+
+fn format_history_line(event: &AuditEvent) -> String {
+    let transition = match (&event.from, &event.to) {
+        (Some(from), Some(to)) => format!("{from} -> {to}"),
+        (None, Some(to)) => format!("-> {to}"),
+        (Some(from), None) => format!("{from} ->"),
+        (None, None) => String::new(),
+    };
+    format!(
+        "{ts}  {transition:<26} {op:<14} {actor}",
+        ts = &event.ts[..19.min(event.ts.len())],
+        op = event.op,
+        actor = describe_actor(event),
+    )
+}
+
+fn json_history_event(event: &AuditEvent) -> serde_json::Value {
+    let or_unknown = |value: &str| {
+        if value.trim().is_empty() {
+            "unknown".to_string()
+        } else {
+            value.to_string()
+        }
+    };
+    serde_json::json!({
+        "ts": &event.ts,
+        "op": &event.op,
+        "from": &event.from,
+        "to": &event.to,
+        "actor": or_unknown(&event.actor),
+        "by": or_unknown(&event.by),
+        "actor_label": describe_actor(event),
+        "agent": AgentAttribution::from_event(event),
+    })
+}
@@ -0,0 +1,84 @@
+use super::support::{backend_tasks_path, missing_tasks_message, print_json};
+use crate::cli_error::{CliResult, fail, to_cli_error};
+use crate::runtime::Runtime;
+use ito_core::repository_runtime::PersistenceMode;
+use ito_core::tasks as core_tasks;
+use ito_core::tasks::DispatchTask;
+
+use super::resolve_change_id;
+
+/// Print the parallel dispatch plan for a change.
+pub(super) fn handle_tasks_plan(
+    rt: &Runtime,
+    input_change_id: &str,
+    max_parallel: usize,
+    want_json: bool,
+) -> CliResult<()> {
+    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+    let repos = runtime.repositories();
+    let change_id = resolve_change_id(repos.changes.as_ref(), input_change_id)?;
+
+    let (path, exists) = if runtime.mode() == PersistenceMode::Remote {
+        let exists = repos
+            .task_mutations
+            .load_tasks_markdown(&change_id)
+            .map_err(to_cli_error)?
+            .is_some();
+        (backend_tasks_path(), exists)
+    } else {
+        let path =
+            core_tasks::tracking_file_path(rt.ito_path(), &change_id).map_err(to_cli_error)?;
+        let exists = path.exists();
+        (path, exists)
+    };
+    if !exists {
+        return fail(missing_tasks_message(&path, &change_id));
+    }
+
+    let parsed = repos.tasks.load_tasks(&change_id).map_err(to_cli_error)?;
+    let plan = core_tasks::plan_parallel_dispatch(&parsed, max_parallel);
+
+    if want_json {
+        let batches: Vec<serde_json::Value> = plan
+            .batches
+            .iter()
+            .enumerate()
+            .map(|(idx, batch)| {
+                serde_json::json!({
+                    "batch": idx + 1,
+                    "tasks": batch.tasks.iter().map(json_dispatch_task).collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+        let unscheduled: Vec<serde_json::Value> = plan
+            .unscheduled
+            .iter()
+            .map(|(task, blockers)| {
+                serde_json::json!({
+                    "task": json_dispatch_task(task),
+                    "blockers": blockers,
+                })
+            })
+            .collect();
+        return print_json(&serde_json::json!({
+            "change_id": change_id,
+            "max_parallel": plan.max_parallel,
+            "batches": batches,
+            "unscheduled": unscheduled,
+        }));
+    }
+
+    print!("{}", core_tasks::render_dispatch_plan_markdown(&plan));
+    Ok(())
+}
+
+fn json_dispatch_task(task: &DispatchTask) -> serde_json::Value {
+    serde_json::json!({
+        "id": &task.id,
+        "name": &task.name,
+        "wave": task.wave,
+        "action": &task.action,
+        "files": &task.files,
+        "verify": &task.verify,
+    })
+}
@@ -0,0 +1,157 @@
+//! `tasks ready`: list tasks that can be started now, for one change or all.
+
+use super::resolve_change_id;
+use super::support::{
+    backend_tasks_path, json_task, missing_tasks_message, print_json, summarize_status,
+};
+use crate::cli_error::{CliError, CliResult, to_cli_error};
+use crate::diagnostics;
+use crate::runtime::Runtime;
+use ito_core::repository_runtime::PersistenceMode;
+use ito_core::tasks as core_tasks;
+
+/// Handle `tasks ready [change_id] [--json]`
+pub(super) fn handle_tasks_ready(rt: &Runtime, args: &[String]) -> CliResult<()> {
+    let want_json = args.iter().any(|a| a == "--json");
+
+    // Check if we have a change_id (arg after "ready" that doesn't start with -)
+    let change_id = args
+        .get(1)
+        .filter(|s| !s.starts_with('-'))
+        .map(|s| s.as_str());
+
+    if let Some(change_id) = change_id {
+        // Single change mode
+        handle_tasks_ready_single(rt, change_id, want_json)
+    } else {
+        // All changes mode
+        handle_tasks_ready_all(rt, want_json)
+    }
+}
+
+/// Show ready tasks for a single change
+fn handle_tasks_ready_single(rt: &Runtime, change_id: &str, want_json: bool) -> CliResult<()> {
+    let ito_path = rt.ito_path();
+    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+    let repos = runtime.repositories();
+    let change_repo = repos.changes.as_ref();
+    let task_repo = repos.tasks.as_ref();
+    let task_mutations = repos.task_mutations.as_ref();
+    let change_id = resolve_change_id(change_repo, change_id)?;
+    let (path, status) = if runtime.mode() == PersistenceMode::Remote {
+        let path = backend_tasks_path();
+        let raw = task_mutations
+            .load_tasks_markdown(&change_id)
+            .map_err(to_cli_error)?;
+        if raw.is_none() {
+            let message = missing_tasks_message(&path, &change_id);
+            if want_json {
+                return print_json(&serde_json::json!({
+                    "action": "ready",
+                    "change_id": change_id,
+                    "path": path.display().to_string(),
+                    "exists": false,
+                    "message": message,
+                    "ready_tasks": [],
+                }));
+            }
+            println!("{message}");
+            return Ok(());
+        }
+        let status = core_tasks::get_task_status_from_repository(task_repo, &change_id)
+            .map_err(to_cli_error)?;
+        (path, status)
+    } else {
+        let path = core_tasks::tracking_file_path(ito_path, &change_id).map_err(to_cli_error)?;
+        let status = core_tasks::get_task_status(ito_path, &change_id).map_err(to_cli_error)?;
+        (path, summarize_status(status))
+    };
+
+    if let Some(msg) = diagnostics::blocking_task_error_message(&path, &status.diagnostics) {
+        return Err(CliError::msg(msg));
+    }
+
+    if want_json {
+        let json_tasks: Vec<serde_json::Value> = status.ready.iter().map(json_task).collect();
+        return print_json(&serde_json::json!({
+            "action": "ready",
+            "change_id": change_id,
+            "path": path.display().to_string(),
+            "ready_tasks": json_tasks,
+        }));
+    }
+
+    if status.ready.is_empty() {
+        if status.progress.remaining == 0 {
+            println!("All tasks complete for \"{change_id}\"!");
+        } else {
+            println!("No ready tasks for \"{change_id}\" (tasks may be blocked or shelved).");
+        }
+        return Ok(());
+    }
+
+    println!("Ready Tasks for: {change_id}");
+    println!("──────────────────────────────────────────────────");
+    println!();
+
+    for t in &status.ready {
+        println!("Task {}: {}", t.id, t.name);
+        if !t.files.is_empty() {
+            println!("  Files: {}", t.files.join(", "));
+        }
+    }
+
+    println!();
+    println!("Run \"ito tasks start {change_id} <task-id>\" to begin a task");
+
+    Ok(())
+}
+
+/// Show ready tasks across all changes
+fn handle_tasks_ready_all(rt: &Runtime, want_json: bool) -> CliResult<()> {
+    let ito_path = rt.ito_path();
+    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+    let repos = runtime.repositories();
+    let change_repo = repos.changes.as_ref();
+    let ready_changes = if runtime.mode() == PersistenceMode::Remote {
+        core_tasks::list_ready_tasks_across_changes_with_repo(change_repo, repos.tasks.as_ref())
+            .map_err(to_cli_error)?
+    } else {
+        core_tasks::list_ready_tasks_across_changes(change_repo, ito_path).map_err(to_cli_error)?
+    };
+
+    if ready_changes.is_empty() {
+        if want_json {
+            return print_json(&serde_json::json!([]));
+        } else {
+            println!("No ready changes found.");
+        }
+        return Ok(());
+    }
+
+    let mut all_results: Vec<serde_json::Value> = Vec::new();
+
+    for change in &ready_changes {
+        if want_json {
+            let json_tasks: Vec<serde_json::Value> =
+                change.ready_tasks.iter().map(json_task).collect();
+            all_results.push(serde_json::json!({
+                "action": "ready",
+                "change_id": change.change_id,
+                "ready_tasks": json_tasks,
+            }));
+        } else {
+            println!("{}:", change.change_id);
+            for t in &change.ready_tasks {
+                println!("  {} - {}", t.id, t.name);
+            }
+            println!();
+        }
+    }
+
+    if want_json {
+        return print_json(&serde_json::json!(all_results));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,200 @@
+use super::events::emit_task_event;
+use super::support::{json_task, print_json};
+use super::{auto_commit_after_task_mutation, resolve_change_id, sync_after_mutation};
+use crate::cli_error::{CliError, CliResult, fail, to_cli_error};
+use crate::commands::sync::{best_effort_sync_coordination, best_effort_sync_coordination_bg};
+use crate::runtime::Runtime;
+use crate::ui::{Tone, Ui};
+use ito_core::audit::{
+    AuditEvent, EntityType, EventFilter, ops, read_audit_events_filtered_from_store,
+};
+use ito_core::stale_tasks;
+use ito_core::tasks as core_tasks;
+use ito_core::tasks::{StaleTask, TaskItem};
+
+/// Return stale in-progress tasks of a change to pending.
+///
+/// Releases `task_id` alone when given (it must be stale), otherwise every
+/// stale in-progress task. Each release is audited with the task's previous
+/// holder so the hand-over is traceable.
+pub(super) fn handle_tasks_release(
+    rt: &Runtime,
+    input_change_id: &str,
+    task_id: Option<&str>,
+    older_than: Option<&str>,
+    want_json: bool,
+) -> CliResult<()> {
+    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+    let repos = runtime.repositories();
+    let change_id = resolve_change_id(repos.changes.as_ref(), input_change_id)?;
+
+    let (threshold, threshold_label) = match older_than {
+        Some(raw) => {
+            let threshold = stale_tasks::parse_threshold(raw)
+                .map_err(|e| CliError::msg(format!("Invalid --older-than '{raw}': {e}")))?;
+            (threshold, raw.to_string())
+        }
+        None => {
+            let config = rt.typed_config().map_err(to_cli_error)?;
+            let threshold = stale_tasks::configured_threshold(&config).map_err(to_cli_error)?;
+            (threshold, config.tasks.stale_after)
+        }
+    };
+
+    let status = core_tasks::get_task_status_from_repository(repos.tasks.as_ref(), &change_id)
+        .map_err(to_cli_error)?;
+    let events = task_events(rt, &change_id);
+    let stale = stale_tasks::stale_tasks(
+        &status.items,
+        &events,
+        &change_id,
+        threshold,
+        ito_core::time::now().fixed_offset(),
+    );
+
+    let targets: Vec<&StaleTask> = match task_id {
+        Some(id) => match stale.iter().find(|task| task.id == id) {
+            Some(task) => vec![task],
+            None => {
+                return fail(format!(
+                    "Task \"{id}\" is not an in-progress task idle for more than {threshold_label}"
+                ));
+            }
+        },
+        None => stale.iter().collect(),
+    };
+
+    if !targets.is_empty() {
+        best_effort_sync_coordination(rt, "before task release");
+    }
+
+    let task_mutations = repos.task_mutations.as_ref();
+    let mut released = Vec::new();
+    for task in targets {
+        let holder = stale_tasks::last_holder(&events, &change_id, &task.id);
+        task_mutations
+            .release_task(&change_id, &task.id)
+            .map_err(to_cli_error)?;
+        emit_task_event(
+            rt,
+            &change_id,
+            &task.id,
+            ops::TASK_STATUS_CHANGE,
+            Some("in-progress"),
+            "pending",
+            Some(serde_json::json!({
+                "auto_release": true,
+                "previous_holder": holder,
+                "stale_for": task.idle_label(),
+            })),
+        );
+        released.push((task, holder));
+    }
+
+    if !released.is_empty() {
+        sync_after_mutation(rt, &change_id);
+        auto_commit_after_task_mutation(rt, &change_id, "release");
+        best_effort_sync_coordination_bg(rt, "after task release");
+    }
+
+    if want_json {
+        let released: Vec<serde_json::Value> = released
+            .iter()
+            .map(|(task, holder)| {
+                serde_json::json!({
+                    "task_id": task.id,
+                    "stale_for": task.idle_label(),
+                    "last_activity": task.last_activity.to_rfc3339(),
+                    "previous_holder": holder,
+                })
+            })
+            .collect();
+        return print_json(&serde_json::json!({
+            "action": "release",
+            "change_id": change_id,
+            "threshold": threshold_label,
+            "released": released,
+        }));
+    }
+
+    if released.is_empty() {
+        println!(
+            "No in-progress tasks in {change_id} have been idle for more than {threshold_label}."
+        );
+        return Ok(());
+    }
+    for (task, holder) in &released {
+        let holder = holder.as_deref().unwrap_or("unknown holder");
+        eprintln!(
+            "✔ Task \"{}\" returned to pending (stale {}, held by {holder})",
+            task.id,
+            task.idle_label()
+        );
+    }
+    Ok(())
+}
+
+/// Stale in-progress tasks among `items`, judged against `tasks.staleAfter`.
+pub(super) fn configured_stale_tasks(
+    rt: &Runtime,
+    change_id: &str,
+    items: &[TaskItem],
+) -> CliResult<Vec<StaleTask>> {
+    let config = rt.typed_config().map_err(to_cli_error)?;
+    let threshold = stale_tasks::configured_threshold(&config).map_err(to_cli_error)?;
+    Ok(stale_tasks::stale_tasks(
+        items,
+        &task_events(rt, change_id),
+        change_id,
+        threshold,
+        ito_core::time::now().fixed_offset(),
+    ))
+}
+
+/// JSON entries for the `stale_tasks` field of `tasks status --json`.
+pub(super) fn stale_tasks_json(stale: &[StaleTask], items: &[TaskItem]) -> Vec<serde_json::Value> {
+    stale
+        .iter()
+        .filter_map(|s| items.iter().find(|t| t.id == s.id).map(|t| (t, s)))
+        .map(|(task, s)| {
+            serde_json::json!({
+                "task": json_task(task),
+                "stale_for": s.idle_label(),
+                "last_activity": s.last_activity.to_rfc3339(),
+            })
+        })
+        .collect()
+}
+
+/// Print the `Stale` section of `tasks status`, with the release hint.
+pub(super) fn print_stale_tasks(ui: &Ui, change_id: &str, stale: &[StaleTask], items: &[TaskItem]) {
+    if stale.is_empty() {
+        return;
+    }
+    println!();
+    println!("{}", ui.heading("Stale"));
+    for s in stale {
+        let name = items
+            .iter()
+            .find(|t| t.id == s.id)
+            .map_or("", |t| t.name.as_str());
+        println!(
+            "  - {}: {name} {}",
+            ui.paint(&s.id, Tone::Warning),
+            ui.paint(
+                &format!("(in-progress, stale {})", s.idle_label()),
+                Tone::Muted
+            )
+        );
+    }
+    println!("Run \"ito tasks release {change_id} --all-stale\" to return them to pending");
+}
+
+fn task_events(rt: &Runtime, change_id: &str) -> Vec<AuditEvent> {
+    let filter = EventFilter {
+        entity: Some(EntityType::Task.as_str().to_string()),
+        scope: Some(change_id.to_string()),
+        ..EventFilter::default()
+    };
+    read_audit_events_filtered_from_store(rt.audit_store(), &filter)
+}
@@ -1,28 +1,36 @@
+use crate::app::change_target::ambiguous_change_error;
 use crate::cli_error::{CliError, CliResult, to_cli_error};
+use crate::util::render_json;
 use ito_core::ChangeRepository;
+use ito_core::ralph::duration::format_duration;
 use ito_core::tasks::{
     ChangeTargetResolution, TaskDiagnostic, TaskItem, TaskStatus, TaskStatusResult,
-    TaskStatusSummary, TasksFormat,
+    TaskStatusSummary, TaskTiming, TasksFormat, TimingScope,
 };
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-pub(super) fn resolve_change_id(
+pub(crate) fn resolve_change_id(
     change_repo: &dyn ChangeRepository,
     input: &str,
 ) -> CliResult<String> {
-    match change_repo.resolve_target(input) {
+    resolve_change_id_with(change_repo, input, false)
+}
+
+/// Resolve `input`, accepting only an exact change id when `exact` is set.
+pub(crate) fn resolve_change_id_with(
+    change_repo: &dyn ChangeRepository,
+    input: &str,
+    exact: bool,
+) -> CliResult<String> {
+    let resolution = if exact {
+        change_repo.resolve_target_exact(input)
+    } else {
+        change_repo.resolve_target(input)
+    };
+    match resolution {
         ChangeTargetResolution::Unique(id) => Ok(id),
-        ChangeTargetResolution::Ambiguous(matches) => {
-            let mut msg = format!("Change '{input}' is ambiguous. Matches:\n");
-            for id in matches.iter().take(8) {
-                msg.push_str(&format!("  {id}\n"));
-            }
-            if matches.len() > 8 {
-                msg.push_str(&format!("  ... and {} more\n", matches.len() - 8));
-            }
-            msg.push_str("Use a longer prefix or the full canonical change ID.");
-            Err(CliError::msg(msg))
-        }
+        ChangeTargetResolution::Ambiguous(matches) => Err(ambiguous_change_error(input, &matches)),
         ChangeTargetResolution::NotFound => {
             let mut msg = format!("Change '{input}' not found");
             let suggestions = change_repo.suggest_targets(input, 5);
@@ -32,7 +40,7 @@ pub(super) fn resolve_change_id(
                     msg.push_str(&format!("  {suggestion}\n"));
                 }
             }
-            Err(CliError::msg(msg))
+            Err(CliError::not_found("CHANGE_NOT_FOUND", msg))
         }
     }
 }
@@ -46,20 +54,22 @@ pub(super) fn task_status_label(status: TaskStatus) -> &'static str {
     }
 }
 
-pub(super) fn tasks_format_label(format: TasksFormat) -> &'static str {
+pub(crate) fn tasks_format_label(format: TasksFormat) -> &'static str {
     match format {
         TasksFormat::Enhanced => "enhanced",
         TasksFormat::Checkbox => "checkbox",
     }
 }
 
-pub(super) fn json_task(task: &TaskItem) -> serde_json::Value {
+pub(crate) fn json_task(task: &TaskItem) -> serde_json::Value {
     serde_json::json!({
         "id": &task.id,
         "name": &task.name,
         "wave": task.wave,
         "status": task_status_label(task.status),
         "updated_at": &task.updated_at,
+        "started_at": &task.started_at,
+        "completed_at": &task.completed_at,
         "dependencies": &task.dependencies,
         "files": &task.files,
         "action": &task.action,
@@ -67,17 +77,57 @@ pub(super) fn json_task(task: &TaskItem) -> serde_json::Value {
         "done_when": &task.done_when,
         "kind": format!("{:?}", task.kind).to_lowercase(),
         "header_line_index": task.header_line_index,
+        "parent_id": &task.parent_id,
+        "depth": task.depth,
     })
 }
 
-pub(super) fn json_diagnostic(path: &Path, d: &TaskDiagnostic) -> serde_json::Value {
+pub(super) fn json_timing(timing: &TaskTiming) -> serde_json::Value {
+    let (scope, id) = match &timing.scope {
+        TimingScope::Task(id) => ("task", serde_json::json!(id)),
+        TimingScope::Wave(wave) => ("wave", serde_json::json!(wave)),
+    };
     serde_json::json!({
+        "scope": scope,
+        "id": id,
+        "started_at": &timing.started_at,
+        "completed_at": &timing.completed_at,
+        "duration_secs": timing.duration_secs,
+    })
+}
+
+pub(super) fn print_timings(timings: &[TaskTiming]) {
+    println!("Timings");
+    if timings.is_empty() {
+        println!("  No timing data recorded (start and complete tasks with `ito tasks`).");
+        return;
+    }
+    for timing in timings {
+        let label = match &timing.scope {
+            TimingScope::Task(id) => format!("Task {id}"),
+            TimingScope::Wave(wave) => format!("Wave {wave}"),
+        };
+        let elapsed = match timing.duration_secs {
+            Some(secs) => format_duration(Duration::from_secs(secs.unsigned_abs())),
+            None if timing.started_at.is_some() => "in progress".to_string(),
+            None => "-".to_string(),
+        };
+        println!("  - {label}: {elapsed}");
+    }
+}
+
+pub(super) fn json_diagnostic(path: &Path, d: &TaskDiagnostic) -> serde_json::Value {
+    let mut value = serde_json::json!({
         "level": d.level.as_str(),
         "message": &d.message,
         "task_id": &d.task_id,
         "line": d.line,
         "path": path.display().to_string(),
-    })
+    });
+    if let Some(code) = d.code {
+        value["code"] = code.into();
+    }
+    value
 }
 
 pub(super) fn backend_tasks_path() -> PathBuf {
@@ -104,7 +154,7 @@ pub(super) fn summarize_status(status: TaskStatusResult) -> TaskStatusSummary {
 }
 
 pub(super) fn print_json(value: &serde_json::Value) -> CliResult<()> {
-    let rendered = serde_json::to_string_pretty(value).map_err(to_cli_error)?;
+    let rendered = render_json(value).map_err(to_cli_error)?;
     println!("{rendered}");
     Ok(())
 }
@@ -0,0 +1,104 @@
+use clap::{Args, Subcommand};
+
+use crate::cli_error::{CliResult, to_cli_error};
+use crate::runtime::Runtime;
+use ito_core::ChangeTargetResolution;
+use ito_core::harness::transcript::{list_transcripts, read_transcript, transcripts_dir};
+
+/// Inspect recorded harness run transcripts.
+#[derive(Args, Debug, Clone)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+#[command(disable_help_subcommand = true)]
+pub struct TranscriptsArgs {
+    #[command(subcommand)]
+    pub action: TranscriptsAction,
+}
+
+/// Transcripts subcommands.
+#[derive(Subcommand, Debug, Clone)]
+pub enum TranscriptsAction {
+    /// List recorded transcripts for a change
+    #[command(visible_alias = "ls")]
+    List {
+        /// Change id (or Ralph run id such as `unscoped`)
+        change: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Print the transcript recorded for one iteration
+    Show {
+        /// Change id (or Ralph run id such as `unscoped`)
+        change: String,
+
+        /// Iteration number
+        iteration: u32,
+    },
+}
+
+pub(crate) fn handle_transcripts_clap(rt: &Runtime, args: &TranscriptsArgs) -> CliResult<()> {
+    let ito_path = rt.ito_path();
+
+    match &args.action {
+        TranscriptsAction::List { change, json } => {
+            let run_id = resolve_run_id(rt, change)?;
+            let entries = list_transcripts(ito_path, &run_id).map_err(to_cli_error)?;
+
+            if *json {
+                let items: Vec<_> = entries
+                    .iter()
+                    .map(|entry| {
+                        serde_json::json!({
+                            "iteration": entry.iteration,
+                            "path": entry.path,
+                        })
+                    })
+                    .collect();
+                let output = serde_json::json!({
+                    "change_id": run_id,
+                    "transcripts": items,
+                });
+                let rendered = serde_json::to_string_pretty(&output).map_err(to_cli_error)?;
+                println!("{rendered}");
+                return Ok(());
+            }
+
+            if entries.is_empty() {
+                println!("No transcripts recorded for {run_id}.");
+                return Ok(());
+            }
+            println!("Transcripts for {run_id}:");
+            for entry in &entries {
+                println!("  {:>4}  {}", entry.iteration, entry.path.display());
+            }
+            Ok(())
+        }
+        TranscriptsAction::Show { change, iteration } => {
+            let run_id = resolve_run_id(rt, change)?;
+            let contents = read_transcript(ito_path, &run_id, *iteration).map_err(to_cli_error)?;
+            print!("{contents}");
+            Ok(())
+        }
+    }
+}
+
+/// Resolve a change prefix to its canonical id.
+///
+/// Run ids that are not changes (e.g. `unscoped`) are used as given.
+fn resolve_run_id(rt: &Runtime, input: &str) -> CliResult<String> {
+    if transcripts_dir(rt.ito_path(), input).is_dir() {
+        return Ok(input.to_string());
+    }
+
+    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+    let change_repo = runtime.repositories().changes.as_ref();
+    match change_repo.resolve_target(input) {
+        ChangeTargetResolution::Unique(id) => Ok(id),
+        ChangeTargetResolution::NotFound => Ok(input.to_string()),
+        ChangeTargetResolution::Ambiguous(_) => {
+            crate::app::change_target::resolve_change_target(change_repo, input)
+        }
+    }
+}
@@ -1,19 +1,31 @@
-use crate::cli::{ParseIdArgs, UtilArgs, UtilCommand};
+use std::io::Read;
+
+use crate::cli::{ParseIdArgs, RecordSkillArgs, UtilArgs, UtilCommand};
 use crate::cli_error::{CliResult, fail, to_cli_error};
+use crate::runtime::Runtime;
 use ito_common::id::{parse_change_id, parse_module_id};
+use ito_core::audit::AgentAttribution;
+use ito_core::stats::skills::{
+    SkillInvocation, UNKNOWN_HARNESS, log_skill_record_failure, normalize_skill_id,
+    record_skill_invocation,
+};
 
 /// Dispatches `ito util` subcommands.
 ///
 /// # Returns
 ///
 /// `CliResult<()>` — `Ok(())` on success, or an error if the subcommand is missing.
-pub(crate) fn handle_util_clap(args: &UtilArgs) -> CliResult<()> {
+pub(crate) fn handle_util_clap(rt: &Runtime, args: &UtilArgs) -> CliResult<()> {
     let Some(cmd) = &args.command else {
         return fail("Missing required subcommand");
     };
 
     match cmd {
         UtilCommand::ParseId(args) => handle_parse_id(args),
+        UtilCommand::RecordSkill(args) => {
+            handle_record_skill(rt, args);
+            Ok(())
+        }
     }
 }
 
@@ -89,3 +101,70 @@ fn handle_parse_id(args: &ParseIdArgs) -> CliResult<()> {
          or a keyword like \"next\"."
     ))
 }
+
+/// Record a `skill.invoked` audit event for an adapter hook.
+///
+/// Runs inside harness hooks, so it never fails: outside an Ito project it
+/// does nothing, and any other problem is written to the record-skill debug
+/// log.
+fn handle_record_skill(rt: &Runtime, args: &RecordSkillArgs) {
+    let ito_path = rt.ito_path();
+    if !ito_path.is_dir() {
+        return;
+    }
+    if let Err(message) = record_skill(rt, args) {
+        log_skill_record_failure(ito_path, &message);
+    }
+}
+
+fn record_skill(rt: &Runtime, args: &RecordSkillArgs) -> Result<(), String> {
+    let raw = if args.hook_payload {
+        let mut payload = String::new();
+        std::io::stdin()
+            .read_to_string(&mut payload)
+            .map_err(|e| format!("reading hook payload: {e}"))?;
+        skill_from_hook_payload(&payload)?
+    } else {
+        args.skill.clone().unwrap_or_default()
+    };
+    let Some(skill_id) = normalize_skill_id(&raw) else {
+        return Err("missing skill id".to_string());
+    };
+    let harness = args
+        .harness
+        .as_deref()
+        .map(str::trim)
+        .filter(|h| !h.is_empty())
+        .map(String::from)
+        .or_else(|| AgentAttribution::from_env().map(|agent| agent.harness))
+        .unwrap_or_else(|| UNKNOWN_HARNESS.to_string());
+    let invocation = SkillInvocation {
+        skill_id,
+        harness,
+        change_id: args.change.clone().filter(|c| !c.trim().is_empty()),
+    };
+
+    let ito_path = rt.ito_path();
+    let project_root = ito_path.parent().unwrap_or(ito_path);
+    record_skill_invocation(
+        rt.audit_store(),
+        project_root,
+        &invocation,
+        rt.event_context().clone(),
+    )
+    .map(|_| ())
+    .map_err(|e| e.to_string())
+}
+
+/// Extract the skill id from a hook payload (`tool_input.skill`, or
+/// `tool_input.name` for harnesses that use that key).
+fn skill_from_hook_payload(payload: &str) -> Result<String, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(payload).map_err(|e| format!("parsing hook payload: {e}"))?;
+    let input = &value["tool_input"];
+    input["skill"]
+        .as_str()
+        .or_else(|| input["name"].as_str())
+        .map(String::from)
+        .ok_or_else(|| "hook payload has no tool_input.skill".to_string())
+}
@@ -17,8 +17,8 @@ pub(crate) fn handle_view_clap(rt: &Runtime, args: &ViewArgs) -> CliResult<()> {
 fn handle_view_proposal(rt: &Runtime, args: &ViewProposalArgs) -> CliResult<()> {
     let runtime = rt.repository_runtime().map_err(to_cli_error)?;
     let change_repo = runtime.repositories().changes.as_ref();
-    let resolved_change = crate::app::common::resolve_change_target(change_repo, &args.change_id)
-        .map_err(crate::cli_error::CliError::msg)?;
+    let resolved_change =
+        crate::app::change_target::resolve_change_target(change_repo, &args.change_id)?;
     let content =
         collect_proposal_artifacts(&resolved_change, rt.ito_path()).map_err(to_cli_error)?;
 
@@ -2,7 +2,8 @@
 
 use ito_config::types::ItoConfig;
 use ito_config::{ConfigContext, load_cascading_project_config};
-use ito_core::repo_paths::{resolve_env, resolve_worktree_paths};
+use ito_core::repo_paths::{resolve_env_from_cwd, resolve_worktree_paths};
+use ito_core::worktree_create::{WorktreeCreateOptions, create_worktree};
 use ito_core::worktree_ensure::ensure_worktree;
 use ito_core::worktree_init::run_worktree_setup;
 use ito_core::worktree_validate::{
@@ -10,25 +11,71 @@ use ito_core::worktree_validate::{
 };
 use std::path::Path;
 
-use crate::cli::{WorktreeArgs, WorktreeCommand, WorktreeValidateArgs};
+use crate::cli::{WorktreeArgs, WorktreeCommand, WorktreeCreateArgs, WorktreeValidateArgs};
 use crate::cli_error::{CliError, CliResult, fail, silent_fail, to_cli_error};
 use crate::runtime::Runtime;
 
 /// Dispatch `ito worktree` sub-commands.
 pub(crate) fn handle_worktree_clap(rt: &Runtime, args: &WorktreeArgs) -> CliResult<()> {
     match &args.command {
+        WorktreeCommand::Create(create_args) => handle_create(rt, create_args),
         WorktreeCommand::Ensure(change_args) => handle_ensure(rt, &change_args.change),
         WorktreeCommand::Setup(change_args) => handle_setup(rt, &change_args.change),
         WorktreeCommand::Validate(validate_args) => handle_validate(rt, validate_args),
     }
 }
 
+/// Handle `ito worktree create <change-id> [--branch <name>] [--existing-branch]`.
+///
+/// Prints the new worktree path to stdout (or a JSON summary with `--json`);
+/// progress goes to stderr.
+fn handle_create(rt: &Runtime, args: &WorktreeCreateArgs) -> CliResult<()> {
+    let env = resolve_env_from_cwd(&rt.invocation_dir(), rt.ctx()).map_err(to_cli_error)?;
+    let worktree_paths = resolve_worktree_paths(&env, rt.ctx()).map_err(to_cli_error)?;
+    let config = load_resolved_config(&env.worktree_root, &env.ito_root, rt.ctx())?;
+
+    let runtime = rt.repository_runtime().map_err(to_cli_error)?;
+    let change_repo = runtime.repositories().changes.as_ref();
+
+    let options = WorktreeCreateOptions {
+        branch: args.branch.clone(),
+        existing_branch: args.existing_branch,
+    };
+    let created = create_worktree(
+        change_repo,
+        &args.change_id,
+        &options,
+        &config,
+        &env,
+        &worktree_paths,
+    )
+    .map_err(to_cli_error)?;
+
+    if args.json {
+        let rendered = serde_json::to_string_pretty(&created).map_err(to_cli_error)?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    eprintln!(
+        "Created worktree for change '{}' on branch '{}'.",
+        created.change_id, created.branch
+    );
+    for file in &created.copied_files {
+        eprintln!("  copied {}", file.display());
+    }
+    println!("{}", created.path.display());
+
+    Ok(())
+}
+
 /// Handle `ito worktree ensure --change <id>`.
 ///
 /// Prints the resolved worktree path to stdout (a single absolute path on one
 /// line). All progress/informational output goes to stderr.
 fn handle_ensure(rt: &Runtime, change_id: &str) -> CliResult<()> {
-    let env = resolve_env(rt.ctx()).map_err(|e| CliError::msg(e.to_string()))?;
+    let env = resolve_env_from_cwd(&rt.invocation_dir(), rt.ctx())
+        .map_err(|e| CliError::msg(e.to_string()))?;
     let worktree_paths =
         resolve_worktree_paths(&env, rt.ctx()).map_err(|e| CliError::msg(e.to_string()))?;
 
@@ -52,7 +99,8 @@ fn handle_ensure(rt: &Runtime, change_id: &str) -> CliResult<()> {
 fn handle_setup(rt: &Runtime, change_id: &str) -> CliResult<()> {
     validate_change_id(change_id)?;
 
-    let env = resolve_env(rt.ctx()).map_err(|e| CliError::msg(e.to_string()))?;
+    let env = resolve_env_from_cwd(&rt.invocation_dir(), rt.ctx())
+        .map_err(|e| CliError::msg(e.to_string()))?;
     let worktree_paths =
         resolve_worktree_paths(&env, rt.ctx()).map_err(|e| CliError::msg(e.to_string()))?;
 
@@ -101,7 +149,7 @@ fn handle_validate(rt: &Runtime, args: &WorktreeValidateArgs) -> CliResult<()> {
     let change_id = &args.change_args.change;
     validate_change_id(change_id)?;
 
-    let env = resolve_env(rt.ctx()).map_err(to_cli_error)?;
+    let env = resolve_env_from_cwd(&rt.invocation_dir(), rt.ctx()).map_err(to_cli_error)?;
     let worktree_paths = resolve_worktree_paths(&env, rt.ctx()).map_err(to_cli_error)?;
     let current_branch = current_git_branch(&env.worktree_root);
     let validation = validate_change_worktree(
@@ -23,12 +23,14 @@ fn render_task_diagnostics_filters_by_level_and_renders_task_id_when_present() {
             message: "warn".to_string(),
             task_id: Some("T1".to_string()),
             line: Some(3),
+            code: None,
         },
         TaskDiagnostic {
             level: DiagnosticLevel::Error,
             message: "err".to_string(),
             task_id: None,
             line: None,
+            code: None,
         },
     ];
 
@@ -47,6 +49,7 @@ fn blocking_task_error_message_returns_none_when_no_errors() {
         message: "warn".to_string(),
         task_id: None,
         line: None,
+        code: None,
     }];
 
     assert_eq!(blocking_task_error_message(p, &diags), None);
@@ -60,6 +63,7 @@ fn blocking_task_error_message_includes_rendered_errors() {
         message: "bad".to_string(),
         task_id: Some("T2".to_string()),
         line: Some(9),
+        code: None,
     }];
 
     let msg = blocking_task_error_message(p, &diags).expect("expected error message");
@@ -79,6 +83,7 @@ fn render_validation_issues_renders_level_path_and_message() {
         column: Some(2),
         rule_id: None,
         metadata: None,
+        fix: None,
     }];
 
     assert_eq!(
@@ -97,6 +102,7 @@ fn render_validation_issues_renders_rule_id_when_present() {
         column: None,
         rule_id: Some("task_quality".to_string()),
         metadata: None,
+        fix: None,
     }];
 
     assert_eq!(
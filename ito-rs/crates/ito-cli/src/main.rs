@@ -4,6 +4,7 @@ mod cli_error;
 mod commands;
 mod diagnostics;
 mod runtime;
+mod ui;
 mod util;
 
 fn main() {
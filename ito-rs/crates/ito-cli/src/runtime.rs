@@ -1,7 +1,7 @@
+use ito_common::git_fs::GitTreeFs;
+use ito_config::CascadingProjectConfig;
 use ito_config::ConfigContext;
-use ito_config::ito_dir::get_ito_path;
 use ito_config::types::ItoConfig;
-use ito_config::{CascadingProjectConfig, load_cascading_project_config};
 use ito_core::audit::{
     AuditEvent, AuditEventStore, EventContext, default_audit_store, resolve_context,
     resolve_user_identity,
@@ -10,21 +10,23 @@ use ito_core::capabilities::{
     CapabilityPreflight, CompiledCapabilities, preflight_config_with_capabilities,
 };
 use ito_core::errors::{CoreError, CoreResult};
+use ito_core::project::Project;
 use ito_core::repo_index::RepoIndex;
-use ito_core::repository_runtime::{
-    RepositoryRuntime, RepositoryRuntimeBuilder, resolve_repository_runtime,
-};
+use ito_core::repository_runtime::{PersistenceMode, RepositoryRuntime, RepositoryRuntimeBuilder};
 use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 use std::sync::atomic::{AtomicBool, Ordering};
 
-fn resolve_runtime_root() -> PathBuf {
-    let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
-    let fallback = || find_nearest_ito_root(&cwd).unwrap_or_else(|| cwd.clone());
+fn process_cwd() -> PathBuf {
+    std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."))
+}
+
+fn resolve_runtime_root(cwd: &Path) -> PathBuf {
+    let fallback = || find_nearest_ito_root(cwd).unwrap_or_else(|| cwd.to_path_buf());
     let mut command = std::process::Command::new("git");
     command
         .args(["rev-parse", "--show-toplevel"])
-        .current_dir(&cwd);
+        .current_dir(cwd);
     for (key, _value) in std::env::vars_os() {
         let key = key.to_string_lossy();
         if key.starts_with("GIT_") {
@@ -59,48 +61,94 @@ fn find_nearest_ito_root(start: &Path) -> Option<PathBuf> {
     }
 }
 
+/// Resolve the global `-C/--cwd` argument into an absolute directory.
+///
+/// Relative paths are resolved against the caller's working directory. Fails
+/// when the path does not exist or is not a directory.
+pub(crate) fn resolve_start_dir(raw: &Path) -> Result<PathBuf, String> {
+    let path = if raw.is_absolute() {
+        raw.to_path_buf()
+    } else {
+        process_cwd().join(raw)
+    };
+    if !path.exists() {
+        return Err(format!("-C/--cwd path does not exist: {}", raw.display()));
+    }
+    if !path.is_dir() {
+        return Err(format!(
+            "-C/--cwd path is not a directory: {}",
+            raw.display()
+        ));
+    }
+    Ok(path)
+}
+
+/// Per-invocation CLI state layered over the shared [`Project`] facade.
 pub(crate) struct Runtime {
-    ctx: ConfigContext,
-    cwd: PathBuf,
-    ito_path: OnceLock<PathBuf>,
+    project: Project,
+    start_dir: Option<PathBuf>,
     repo_index: OnceLock<RepoIndex>,
     audit_store: OnceLock<Box<dyn AuditEventStore>>,
     event_context: OnceLock<EventContext>,
     user_identity: OnceLock<String>,
-    repository_runtime: OnceLock<RepositoryRuntime>,
-    resolved_config: OnceLock<CascadingProjectConfig>,
+    local_repository_runtime: OnceLock<RepositoryRuntime>,
+    revision_repository_runtime: OnceLock<RepositoryRuntime>,
     suppress_command_side_effects: AtomicBool,
 }
 
 impl Runtime {
     pub(crate) fn new() -> Self {
+        Self::with_start_dir(None)
+    }
+
+    /// Build a runtime rooted at `start_dir` (the global `-C/--cwd` target)
+    /// instead of the process working directory.
+    ///
+    /// `start_dir` must be an absolute, existing directory; see
+    /// [`resolve_start_dir`].
+    pub(crate) fn with_start_dir(start_dir: Option<PathBuf>) -> Self {
+        let root = resolve_runtime_root(start_dir.as_deref().unwrap_or(&process_cwd()));
         Self {
-            ctx: ConfigContext::from_process_env(),
-            cwd: resolve_runtime_root(),
-            ito_path: OnceLock::new(),
+            project: Project::open(root),
+            start_dir,
             repo_index: OnceLock::new(),
             audit_store: OnceLock::new(),
             event_context: OnceLock::new(),
             user_identity: OnceLock::new(),
-            repository_runtime: OnceLock::new(),
-            resolved_config: OnceLock::new(),
+            local_repository_runtime: OnceLock::new(),
+            revision_repository_runtime: OnceLock::new(),
             suppress_command_side_effects: AtomicBool::new(false),
         }
     }
 
     pub(crate) fn ctx(&self) -> &ConfigContext {
-        &self.ctx
+        self.project.ctx()
+    }
+
+    /// The project this invocation operates on.
+    pub(crate) fn project(&self) -> &Project {
+        &self.project
     }
 
     /// Returns the resolved working directory root.
     pub(crate) fn cwd(&self) -> &Path {
-        &self.cwd
+        self.project.root()
+    }
+
+    /// Directory the command was invoked from: the `-C/--cwd` target when
+    /// given, otherwise the process working directory.
+    pub(crate) fn invocation_dir(&self) -> PathBuf {
+        self.start_dir.clone().unwrap_or_else(process_cwd)
+    }
+
+    /// Default target for commands taking an optional project path (`init`,
+    /// `update`): the `-C/--cwd` target when given, otherwise `.`.
+    pub(crate) fn default_target_dir(&self) -> PathBuf {
+        self.start_dir.clone().unwrap_or_else(|| PathBuf::from("."))
     }
 
     pub(crate) fn ito_path(&self) -> &Path {
-        self.ito_path
-            .get_or_init(|| get_ito_path(&self.cwd, &self.ctx))
-            .as_path()
+        self.project.ito_path()
     }
 
     pub(crate) fn repo_index(&self) -> &RepoIndex {
@@ -128,11 +176,7 @@ impl Runtime {
 
     /// Returns the per-invocation cascading project config, loading it at most once.
     pub(crate) fn resolved_config(&self) -> &CascadingProjectConfig {
-        self.resolved_config.get_or_init(|| {
-            let ito_path = self.ito_path();
-            let project_root = ito_path.parent().unwrap_or(ito_path);
-            load_cascading_project_config(project_root, ito_path, &self.ctx)
-        })
+        self.project.config()
     }
 
     /// Validate resolved configuration against the capabilities in this binary.
@@ -173,20 +217,42 @@ impl Runtime {
     }
 
     /// Returns the resolved repository runtime.
+    ///
+    /// When side effects are suppressed, local repositories are used without
+    /// resolving remote or SQLite persistence. After [`Runtime::pin_revision`],
+    /// the repositories read from that revision instead.
     pub(crate) fn repository_runtime(&self) -> CoreResult<&RepositoryRuntime> {
-        if self.repository_runtime.get().is_none() {
-            let runtime = if self.command_side_effects_suppressed() {
-                RepositoryRuntimeBuilder::new(self.ito_path()).build()?
-            } else {
-                resolve_repository_runtime(self.ito_path(), &self.ctx)?
-            };
-            let _ = self.repository_runtime.set(runtime);
+        if let Some(runtime) = self.revision_repository_runtime.get() {
+            return Ok(runtime);
         }
-        self.repository_runtime
+        if !self.command_side_effects_suppressed() {
+            return self.project.repository_runtime();
+        }
+        if self.local_repository_runtime.get().is_none() {
+            let runtime = RepositoryRuntimeBuilder::new(self.ito_path()).build()?;
+            let _ = self.local_repository_runtime.set(runtime);
+        }
+        self.local_repository_runtime
             .get()
             .ok_or_else(|| CoreError::validation("repository runtime unavailable".to_string()))
     }
 
+    /// Read repositories from git revision `rev` for the rest of this
+    /// invocation (the `--at` flag of read-only commands).
+    pub(crate) fn pin_revision(&self, rev: &str) -> CoreResult<()> {
+        if self.project.repository_runtime()?.mode() != PersistenceMode::Filesystem {
+            return Err(CoreError::validation(
+                "--at is only supported with filesystem persistence".to_string(),
+            ));
+        }
+        let fs = GitTreeFs::open(self.cwd(), rev).map_err(|err| CoreError::io("--at", err))?;
+        let runtime = RepositoryRuntimeBuilder::new(self.ito_path())
+            .revision(fs)
+            .build()?;
+        let _ = self.revision_repository_runtime.set(runtime);
+        Ok(())
+    }
+
     /// Emit an audit event using the runtime's writer. Best-effort: never fails.
     pub(crate) fn emit_audit_event(&self, event: &AuditEvent) {
         let _ = self.audit_store().append(event);
@@ -0,0 +1,358 @@
+//! Shared presentation layer for human-readable command output.
+//!
+//! Commands describe *what* to show (tables, key/value lines, status tones);
+//! [`Ui`] decides *how*: whether ANSI color is used and how columns are laid
+//! out for the terminal width. When stdout is not a terminal (pipes, CI,
+//! `TERM=dumb`) output is plain and unconstrained, so it stays stable for
+//! scripts and snapshot tests. Machine-readable output belongs to `--json`.
+
+use clap::builder::styling::{AnsiColor, Style};
+use ito_config::output;
+use std::io::IsTerminal;
+
+/// Below this terminal width tables are rendered as stacked `key: value` blocks.
+const STACKED_LAYOUT_MAX_WIDTH: usize = 60;
+
+/// Narrowest a shrinkable column is truncated to before the table gives up
+/// on fitting and overflows.
+const MIN_SHRUNK_WIDTH: usize = 12;
+
+/// Resolved presentation settings for one output stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Ui {
+    color: bool,
+    width: Option<usize>,
+}
+
+impl Ui {
+    /// Presentation settings for stdout.
+    pub(crate) fn stdout() -> Self {
+        let stdout = std::io::stdout();
+        let is_tty = stdout.is_terminal();
+        Self::detect(is_tty, || terminal_size::terminal_size_of(&stdout))
+    }
+
+    /// Presentation settings for stderr.
+    pub(crate) fn stderr() -> Self {
+        let stderr = std::io::stderr();
+        let is_tty = stderr.is_terminal();
+        Self::detect(is_tty, || terminal_size::terminal_size_of(&stderr))
+    }
+
+    /// Combine the resolved UI options (`NO_COLOR`, `--no-color`,
+    /// `ITO_INTERACTIVE`) with `TERM` and the stream's terminal size.
+    fn detect(
+        is_tty: bool,
+        size: impl FnOnce() -> Option<(terminal_size::Width, terminal_size::Height)>,
+    ) -> Self {
+        let options = output::resolve_ui_options_with_tty(
+            false,
+            std::env::var("NO_COLOR").ok().as_deref(),
+            false,
+            std::env::var("ITO_INTERACTIVE").ok().as_deref(),
+            is_tty,
+        );
+        let dumb = std::env::var("TERM").is_ok_and(|term| term == "dumb");
+        let width = if is_tty && !dumb {
+            size().map(|(terminal_size::Width(w), _)| usize::from(w))
+        } else {
+            None
+        };
+        Self {
+            color: options.interactive && !options.no_color && !dumb,
+            width,
+        }
+    }
+
+    /// Terminal width, when output goes to a terminal.
+    pub(crate) fn width(&self) -> Option<usize> {
+        self.width
+    }
+
+    /// Apply `tone` to `text` when color is enabled.
+    pub(crate) fn paint(&self, text: &str, tone: Tone) -> String {
+        let style = tone.style();
+        if !self.color || text.is_empty() || style == Style::new() {
+            return text.to_string();
+        }
+        format!("{style}{text}{style:#}")
+    }
+
+    /// Render a `label: value` line with the label emphasized.
+    pub(crate) fn kv(&self, label: &str, value: impl std::fmt::Display) -> String {
+        format!("{}: {value}", self.paint(label, Tone::Emphasis))
+    }
+
+    /// Render a section heading.
+    pub(crate) fn heading(&self, text: &str) -> String {
+        self.paint(text, Tone::Emphasis)
+    }
+
+    /// A horizontal rule no wider than the terminal.
+    pub(crate) fn rule(&self, max_width: usize) -> String {
+        let width = self.width.map_or(max_width, |w| w.min(max_width));
+        self.paint(&"─".repeat(width), Tone::Muted)
+    }
+}
+
+/// Semantic color for a piece of output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Tone {
+    /// No styling.
+    Plain,
+    /// Finished or passing.
+    Success,
+    /// Needs attention but not failing.
+    Warning,
+    /// Failing or blocked.
+    Error,
+    /// Actively being worked on.
+    Active,
+    /// Secondary information.
+    Muted,
+    /// Labels and headings.
+    Emphasis,
+}
+
+impl Tone {
+    fn style(self) -> Style {
+        match self {
+            Tone::Plain => Style::new(),
+            Tone::Success => Style::new().fg_color(Some(AnsiColor::Green.into())),
+            Tone::Warning => Style::new().fg_color(Some(AnsiColor::Yellow.into())),
+            Tone::Error => Style::new().fg_color(Some(AnsiColor::Red.into())),
+            Tone::Active => Style::new().fg_color(Some(AnsiColor::Cyan.into())),
+            Tone::Muted => Style::new().dimmed(),
+            Tone::Emphasis => Style::new().bold(),
+        }
+    }
+}
+
+/// Tone for a status string shared by changes, artifacts, and validation.
+///
+/// Accepts change list statuses (`complete`, `in-progress`, `blocked`, ...),
+/// artifact statuses (`done`, `skipped`, `optional`, ...), and validation
+/// levels (`ERROR`, `WARNING`). Unknown statuses are left plain.
+pub(crate) fn status_tone(status: &str) -> Tone {
+    match status.to_ascii_lowercase().as_str() {
+        "complete" | "done" | "valid" => Tone::Success,
+        "in-progress" | "in_progress" | "active" => Tone::Active,
+        "blocked" | "error" | "invalid" => Tone::Error,
+        "paused" | "warning" | "shelved" => Tone::Warning,
+        "draft" | "skipped" | "optional" | "info" => Tone::Muted,
+        _ => Tone::Plain,
+    }
+}
+
+/// One column of a [`Table`].
+#[derive(Debug, Clone)]
+pub(crate) struct Column {
+    label: &'static str,
+    min_width: usize,
+    shrink: bool,
+}
+
+impl Column {
+    /// A column labelled `label` in the stacked layout (empty for no label).
+    pub(crate) fn new(label: &'static str) -> Self {
+        Self {
+            label,
+            min_width: 0,
+            shrink: false,
+        }
+    }
+
+    /// Pad the column to at least `width` characters.
+    pub(crate) fn min_width(mut self, width: usize) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    /// Allow the column to be truncated with an ellipsis to fit the terminal.
+    pub(crate) fn shrink(mut self) -> Self {
+        self.shrink = true;
+        self
+    }
+}
+
+/// A table cell: text plus the tone it is rendered with.
+#[derive(Debug, Clone)]
+pub(crate) struct Cell {
+    text: String,
+    tone: Tone,
+}
+
+impl Cell {
+    /// An unstyled cell.
+    pub(crate) fn plain(text: impl Into<String>) -> Self {
+        Self::toned(text, Tone::Plain)
+    }
+
+    /// A cell rendered with `tone`.
+    pub(crate) fn toned(text: impl Into<String>, tone: Tone) -> Self {
+        Self {
+            text: text.into(),
+            tone,
+        }
+    }
+}
+
+/// Columnar output that negotiates its layout against the terminal width.
+///
+/// Columns are separated by `gap` spaces and padded to their widest cell; the
+/// last column is never padded. When the table is wider than the terminal,
+/// [`Column::shrink`] columns are truncated with an ellipsis; below
+/// [`STACKED_LAYOUT_MAX_WIDTH`] each row becomes a stacked `key: value` block.
+#[derive(Debug, Clone)]
+pub(crate) struct Table {
+    columns: Vec<Column>,
+    rows: Vec<Vec<Cell>>,
+    indent: usize,
+    gap: usize,
+}
+
+impl Table {
+    /// An empty table with a two-space indent and gap.
+    pub(crate) fn new(columns: Vec<Column>) -> Self {
+        Self {
+            columns,
+            rows: Vec::new(),
+            indent: 2,
+            gap: 2,
+        }
+    }
+
+    /// Set the number of spaces between columns.
+    pub(crate) fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Append a row; missing trailing cells render empty.
+    pub(crate) fn row(&mut self, cells: Vec<Cell>) {
+        self.rows.push(cells);
+    }
+
+    /// Render the table, one line per row, each terminated by a newline.
+    pub(crate) fn render(&self, ui: &Ui) -> String {
+        if self.columns.is_empty() {
+            return String::new();
+        }
+        if ui.width().is_some_and(|w| w < STACKED_LAYOUT_MAX_WIDTH) {
+            return self.render_stacked(ui);
+        }
+
+        let mut widths: Vec<usize> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                self.rows
+                    .iter()
+                    .filter_map(|row| row.get(i))
+                    .map(|cell| text_width(&cell.text))
+                    .max()
+                    .unwrap_or(0)
+                    .max(column.min_width)
+            })
+            .collect();
+        if let Some(max) = ui.width() {
+            self.fit_widths(&mut widths, max);
+        }
+
+        let last = self.columns.len() - 1;
+        let indent = " ".repeat(self.indent);
+        let gap = " ".repeat(self.gap);
+        let mut out = String::new();
+        for row in &self.rows {
+            out.push_str(&indent);
+            for (i, width) in widths.iter().enumerate() {
+                let cell = row.get(i);
+                let text = cell.map_or("", |c| c.text.as_str());
+                let tone = cell.map_or(Tone::Plain, |c| c.tone);
+                let text = truncate(text, *width);
+                let padding = if i == last {
+                    0
+                } else {
+                    width.saturating_sub(text_width(&text))
+                };
+                out.push_str(&ui.paint(&text, tone));
+                out.push_str(&" ".repeat(padding));
+                if i != last {
+                    out.push_str(&gap);
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Shrink truncatable columns until the table fits `max` characters.
+    fn fit_widths(&self, widths: &mut [usize], max: usize) {
+        let total =
+            self.indent + widths.iter().sum::<usize>() + self.gap * widths.len().saturating_sub(1);
+        let mut excess = total.saturating_sub(max);
+        for (i, column) in self.columns.iter().enumerate() {
+            if excess == 0 {
+                break;
+            }
+            if !column.shrink {
+                continue;
+            }
+            let floor = column.min_width.max(MIN_SHRUNK_WIDTH);
+            let available = widths[i].saturating_sub(floor);
+            let cut = available.min(excess);
+            widths[i] -= cut;
+            excess -= cut;
+        }
+    }
+
+    fn render_stacked(&self, ui: &Ui) -> String {
+        let max = ui.width().unwrap_or(usize::MAX);
+        let indent = " ".repeat(self.indent);
+        let mut out = String::new();
+        for row in &self.rows {
+            for (i, column) in self.columns.iter().enumerate() {
+                let Some(cell) = row.get(i) else {
+                    continue;
+                };
+                if i == 0 {
+                    let text = truncate(&cell.text, max.saturating_sub(self.indent));
+                    out.push_str(&format!("{indent}{}\n", ui.paint(&text, cell.tone)));
+                    continue;
+                }
+                if cell.text.is_empty() {
+                    continue;
+                }
+                let value = ui.paint(&cell.text, cell.tone);
+                if column.label.is_empty() {
+                    out.push_str(&format!("{indent}  {value}\n"));
+                } else {
+                    out.push_str(&format!("{indent}  {}: {value}\n", column.label));
+                }
+            }
+        }
+        out
+    }
+}
+
+fn text_width(text: &str) -> usize {
+    text.chars().count()
+}
+
+/// Truncate `text` to `width` characters, ending in an ellipsis when cut.
+fn truncate(text: &str, width: usize) -> String {
+    if text_width(text) <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let mut out: String = text.chars().take(width - 1).collect();
+    out.push('…');
+    out
+}
+
+#[cfg(test)]
+#[path = "ui_tests.rs"]
+mod ui_tests;
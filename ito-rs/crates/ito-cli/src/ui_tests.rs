@@ -0,0 +1,109 @@
+use super::*;
+
+fn ui(color: bool, width: Option<usize>) -> Ui {
+    Ui { color, width }
+}
+
+const LONG_NAME: &str = "001-01_add-authentication-flow-with-oauth-and-refresh-tokens";
+
+fn sample_table() -> Table {
+    let mut table = Table::new(vec![
+        Column::new("change").shrink(),
+        Column::new("module").min_width(3),
+        Column::new("status"),
+    ]);
+    table.row(vec![
+        Cell::plain(LONG_NAME),
+        Cell::plain("001"),
+        Cell::toned("Complete", Tone::Success),
+    ]);
+    table.row(vec![
+        Cell::plain("002-01_fix"),
+        Cell::plain("-"),
+        Cell::toned("Draft", Tone::Muted),
+    ]);
+    table
+}
+
+#[test]
+fn plain_table_pads_all_but_the_last_column() {
+    let rendered = sample_table().render(&ui(false, None));
+    assert_eq!(
+        rendered,
+        format!(
+            "  {LONG_NAME}  001  Complete\n  {:<60}  -    Draft\n",
+            "002-01_fix"
+        )
+    );
+}
+
+#[test]
+fn narrow_terminal_truncates_shrinkable_columns_with_an_ellipsis() {
+    let unconstrained = sample_table().render(&ui(false, None));
+    assert_eq!(sample_table().render(&ui(false, Some(100))), unconstrained);
+
+    let rendered = sample_table().render(&ui(false, Some(70)));
+    for line in rendered.lines() {
+        assert!(line.chars().count() <= 70, "line too wide: {line:?}");
+    }
+    assert!(
+        rendered.starts_with(
+            "  001-01_add-authentication-flow-with-oauth-and-refres…  001  Complete\n"
+        ),
+        "{rendered}"
+    );
+}
+
+#[test]
+fn very_narrow_terminal_stacks_rows_as_key_value_pairs() {
+    let rendered = sample_table().render(&ui(false, Some(40)));
+    assert_eq!(
+        rendered,
+        "  001-01_add-authentication-flow-with-o…\n    module: 001\n    status: Complete\n  002-01_fix\n    module: -\n    status: Draft\n"
+    );
+}
+
+#[test]
+fn color_is_applied_after_padding() {
+    let rendered = sample_table().render(&ui(true, None));
+    assert!(rendered.contains("\u{1b}["), "{rendered:?}");
+    let stripped: String = strip_ansi(&rendered);
+    assert_eq!(stripped, sample_table().render(&ui(false, None)));
+}
+
+#[test]
+fn kv_and_rule_are_plain_without_color() {
+    let plain = ui(false, None);
+    assert_eq!(plain.kv("Change", "001-01_x"), "Change: 001-01_x");
+    assert_eq!(plain.rule(50).chars().count(), 50);
+    assert_eq!(ui(false, Some(20)).rule(50).chars().count(), 20);
+}
+
+#[test]
+fn status_tone_covers_change_artifact_and_validation_statuses() {
+    assert_eq!(status_tone("complete"), Tone::Success);
+    assert_eq!(status_tone("done"), Tone::Success);
+    assert_eq!(status_tone("in-progress"), Tone::Active);
+    assert_eq!(status_tone("blocked"), Tone::Error);
+    assert_eq!(status_tone("ERROR"), Tone::Error);
+    assert_eq!(status_tone("WARNING"), Tone::Warning);
+    assert_eq!(status_tone("draft"), Tone::Muted);
+    assert_eq!(status_tone("ready"), Tone::Plain);
+}
+
+fn strip_ansi(text: &str) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            for c in chars.by_ref() {
+                if c == 'm' {
+                    break;
+                }
+            }
+            continue;
+        }
+        out.push(c);
+    }
+    out
+}
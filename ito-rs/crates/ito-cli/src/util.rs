@@ -48,10 +48,11 @@ where
     }
 
     let config_dir = ito_config::ito_config_dir(rt.ctx());
+    let session_id = ito_core::session_state::resolve_session_id(ito_path_for_logging);
     let logger = ExecLogger::new(
         config_dir,
         project_root,
-        Some(ito_path_for_logging),
+        session_id.as_deref(),
         command_id,
         option_env!("ITO_WORKSPACE_VERSION").unwrap_or(env!("CARGO_PKG_VERSION")),
     );
@@ -173,7 +174,7 @@ pub(crate) fn project_root_for_logging(rt: &Runtime, args: &[String]) -> PathBuf
             }
             return PathBuf::from(a);
         }
-        return PathBuf::from(".");
+        return rt.default_target_dir();
     }
 
     let ito_path = rt.ito_path();
@@ -226,6 +227,53 @@ pub(crate) fn split_csv(raw: &str) -> Vec<String> {
     raw.split(',').map(|s| s.trim().to_string()).collect()
 }
 
+/// Version of the `--json` output format, reported as `schema_version` on
+/// every top-level payload rendered by [`render_json`].
+///
+/// Bump it when a field is removed, renamed, or changes meaning. Adding a
+/// field does not change it.
+pub(crate) const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Render a `--json` payload so repeated runs produce identical text.
+///
+/// Object keys are sorted at every level, whatever order the payload's struct
+/// fields or maps use, and a top-level object gains `schema_version`. Arrays
+/// keep the caller's order, so callers sort collections first: entities by
+/// id, files by path.
+pub(crate) fn render_json<T: serde::Serialize + ?Sized>(
+    payload: &T,
+) -> Result<String, serde_json::Error> {
+    let mut value = serde_json::to_value(payload)?;
+    if let serde_json::Value::Object(map) = &mut value {
+        map.entry("schema_version")
+            .or_insert_with(|| JSON_SCHEMA_VERSION.into());
+    }
+    serde_json::to_string_pretty(&sort_json_keys(value))
+}
+
+/// Rebuild every object in `value` with its keys in sorted order.
+///
+/// `serde_json::Map` keeps insertion order when any crate in the build
+/// enables `preserve_order`, so sorting is done here rather than assumed.
+fn sort_json_keys(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<(String, serde_json::Value)> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            serde_json::Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_json_keys(value)))
+                    .collect(),
+            )
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.into_iter().map(sort_json_keys).collect())
+        }
+        other => other,
+    }
+}
+
 /// Log an invalid command if the `logging.invalidCommands.enabled` config option is set.
 ///
 /// This is best-effort: failures to load config or write the log entry are
@@ -244,13 +292,13 @@ pub(crate) fn maybe_log_invalid_command(rt: &Runtime, raw_args: &[String], error
 /// Used for early parse failures (e.g. clap errors) where the full `Runtime`
 /// has not yet been constructed.
 pub(crate) fn maybe_log_invalid_command_early(
+    cwd: &Path,
     ctx: &ito_config::ConfigContext,
     raw_args: &[String],
     error_message: &str,
 ) {
-    let cwd = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
-    let ito_path = ito_config::ito_dir::get_ito_path(&cwd, ctx);
-    let project_root = ito_path.parent().unwrap_or(&cwd);
+    let ito_path = ito_config::ito_dir::get_ito_path(cwd, ctx);
+    let project_root = ito_path.parent().unwrap_or(cwd);
 
     do_maybe_log_invalid_command(project_root, &ito_path, ctx, raw_args, error_message);
 }
@@ -273,10 +321,11 @@ fn do_maybe_log_invalid_command(
     }
 
     let config_dir = ito_config::ito_config_dir(ctx);
+    let session_id = ito_core::session_state::resolve_session_id(ito_path);
     let logger = ito_logging::InvalidCommandLogger::new(
         config_dir,
         project_root,
-        Some(ito_path),
+        session_id.as_deref(),
         option_env!("ITO_WORKSPACE_VERSION").unwrap_or(env!("CARGO_PKG_VERSION")),
     );
     if let Some(l) = logger {
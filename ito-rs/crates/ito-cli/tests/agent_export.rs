@@ -0,0 +1,48 @@
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use ito_test_support::run_rust_candidate;
+
+const CHANGE_ID: &str = "000-01_test-change";
+
+#[test]
+fn agent_export_writes_bundle_and_json() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    fixtures::reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["agent", "export", CHANGE_ID, "--out", "bundle"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let bundle = repo.path().join("bundle");
+    assert!(bundle.join("00-overview.md").is_file());
+    let manifest: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(bundle.join("manifest.json")).expect("manifest"),
+    )
+    .expect("manifest json");
+    assert_eq!(manifest["changeName"], CHANGE_ID);
+    assert!(manifest.get("generatedAt").is_none());
+    let files = manifest["files"].as_array().expect("files");
+    assert_eq!(files.last().expect("apply")["kind"], "apply");
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["agent", "export", CHANGE_ID, "--format", "json", "--stamp"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let export: serde_json::Value = serde_json::from_str(&out.stdout).expect("export json");
+    assert_eq!(export["changeName"], CHANGE_ID);
+    assert!(export["generatedAt"].is_string());
+    assert_eq!(
+        export["artifacts"].as_array().map(Vec::len),
+        export["status"]["artifacts"].as_array().map(Vec::len)
+    );
+}
@@ -29,6 +29,10 @@ fn make_repo_with_mixed_changes() -> tempfile::TempDir {
             .join(".ito/changes/000-01_completed-a/proposal.md"),
         "## Why\nTest fixture A\n\n## What Changes\n- Adds something\n\n## Impact\n- None\n",
     );
+    fixtures::write(
+        td.path().join(".ito/changes/000-01_completed-a/design.md"),
+        "## Context\nTest fixture A\n",
+    );
     fixtures::write(
         td.path().join(".ito/changes/000-01_completed-a/tasks.md"),
         "## 1. Implementation\n- [x] 1.1 Do a thing\n",
@@ -45,6 +49,10 @@ fn make_repo_with_mixed_changes() -> tempfile::TempDir {
             .join(".ito/changes/000-02_completed-b/proposal.md"),
         "## Why\nTest fixture B\n\n## What Changes\n- Adds something else\n\n## Impact\n- None\n",
     );
+    fixtures::write(
+        td.path().join(".ito/changes/000-02_completed-b/design.md"),
+        "## Context\nTest fixture B\n",
+    );
     fixtures::write(
         td.path().join(".ito/changes/000-02_completed-b/tasks.md"),
         "## 1. Implementation\n- [x] 1.1 Do another thing\n",
@@ -36,6 +36,10 @@ fn make_base_repo() -> tempfile::TempDir {
         td.path().join(".ito/changes/000-01_test-change/tasks.md"),
         "## 1. Implementation\n- [x] 1.1 Do a thing\n",
     );
+    write(
+        td.path().join(".ito/changes/000-01_test-change/design.md"),
+        "## Context\nTest fixture\n",
+    );
     write(
         td.path()
             .join(".ito/changes/000-01_test-change/specs/alpha/spec.md"),
@@ -85,3 +89,208 @@ fn archive_with_specs_and_validation_smoke() {
             .expect("module.md");
     assert!(module_md.contains("- [x] 000-01_test-change"));
 }
+
+fn make_pending_task_repo() -> tempfile::TempDir {
+    let base = make_base_repo();
+    write(
+        base.path().join(".ito/changes/000-01_test-change/tasks.md"),
+        "## 1. Implementation\n- [x] 1.1 Do a thing\n- [ ] 1.2 Still to do\n",
+    );
+    base
+}
+
+#[test]
+fn archive_refuses_change_with_pending_tasks() {
+    let base = make_pending_task_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["archive", "000-01_test-change", "-y"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0, "stdout={}", out.stdout);
+    assert!(out.stderr.contains("Refusing to archive"), "{}", out.stderr);
+    assert!(
+        out.stderr.contains("1.2 [pending] Still to do"),
+        "{}",
+        out.stderr
+    );
+    assert!(out.stderr.contains("--force"), "{}", out.stderr);
+    assert!(repo.path().join(".ito/changes/000-01_test-change").exists());
+}
+
+#[test]
+fn forced_archive_requires_yes_when_non_interactive() {
+    let base = make_pending_task_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["archive", "000-01_test-change", "--force"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0, "stdout={}", out.stdout);
+    assert!(out.stderr.contains("requires --yes"), "{}", out.stderr);
+    assert!(repo.path().join(".ito/changes/000-01_test-change").exists());
+}
+
+#[test]
+fn forced_archive_records_outstanding_work() {
+    let base = make_pending_task_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["archive", "000-01_test-change", "--force", "-y"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stderr
+            .contains("Forced: archived with outstanding work"),
+        "{}",
+        out.stderr
+    );
+    assert!(
+        out.stderr.contains("1.2 [pending] Still to do"),
+        "{}",
+        out.stderr
+    );
+    assert!(!repo.path().join(".ito/changes/000-01_test-change").exists());
+}
+
+fn make_conflicting_delta_repo() -> tempfile::TempDir {
+    let base = make_base_repo();
+    write(
+        base.path()
+            .join(".ito/changes/000-01_test-change/specs/alpha/spec.md"),
+        "## ADDED Requirements\n\n### Requirement: Alpha Delta\nThe system SHALL include alpha delta behavior in strict validation.\n\n#### Scenario: Delta ok\n- **WHEN** running validation\n- **THEN** it passes\n\n## MODIFIED Requirements\n\n### Requirement: Alpha Missing\nThe system SHALL modify a requirement that does not exist.\n\n#### Scenario: Missing\n- **WHEN** archiving\n- **THEN** the conflict is reported\n",
+    );
+    base
+}
+
+#[test]
+fn show_preview_spec_prints_diff_and_conflicts() {
+    let base = make_conflicting_delta_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["show", "000-01_test-change", "--preview-spec", "alpha"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stdout.contains("+### Requirement: Alpha Delta"),
+        "{}",
+        out.stdout
+    );
+    assert!(
+        out.stdout
+            .contains("cannot modify missing requirement 'Alpha Missing'"),
+        "{}",
+        out.stdout
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "show",
+            "000-01_test-change",
+            "--preview-spec",
+            "alpha",
+            "--json",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("preview json");
+    assert_eq!(v["spec"], "alpha");
+    assert_eq!(v["conflicts"][0]["operation"], "MODIFIED");
+    assert!(
+        v["merged"]
+            .as_str()
+            .is_some_and(|m| m.contains("### Requirement: Alpha Delta"))
+    );
+}
+
+#[test]
+fn archive_refuses_conflicting_spec_deltas() {
+    let base = make_conflicting_delta_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["archive", "000-01_test-change", "-y"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0, "stdout={}", out.stdout);
+    assert!(
+        out.stderr.contains("spec deltas conflict"),
+        "{}",
+        out.stderr
+    );
+    assert!(
+        out.stderr
+            .contains("alpha: cannot modify missing requirement 'Alpha Missing'"),
+        "{}",
+        out.stderr
+    );
+    assert!(repo.path().join(".ito/changes/000-01_test-change").exists());
+}
+
+#[test]
+fn forced_archive_skips_conflicting_spec_deltas() {
+    let base = make_conflicting_delta_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["archive", "000-01_test-change", "--force", "-y"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stderr
+            .contains("Warning: skipping spec delta operations"),
+        "{}",
+        out.stderr
+    );
+    let spec =
+        std::fs::read_to_string(repo.path().join(".ito/specs/alpha/spec.md")).expect("alpha spec");
+    assert!(spec.contains("### Requirement: Alpha Delta"));
+    assert!(!spec.contains("Alpha Missing"));
+    assert!(!repo.path().join(".ito/changes/000-01_test-change").exists());
+}
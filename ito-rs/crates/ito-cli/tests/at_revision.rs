@@ -0,0 +1,156 @@
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use std::path::Path;
+
+use ito_test_support::run_rust_candidate;
+
+const CHANGE: &str = "000-01_test-change";
+
+fn commit_all(repo: &Path, message: &str, tag: &str) {
+    fixtures::run_git(repo, &["add", "-A"]);
+    fixtures::run_git(
+        repo,
+        &["commit", "--no-verify", "--no-gpg-sign", "-m", message],
+    );
+    fixtures::run_git(repo, &["tag", tag]);
+}
+
+/// Tasks are 0/2 done at `v1` and 1/3 done at `v2` (which also adds
+/// `design.md`); the working tree finishes everything without committing.
+fn repo_with_history() -> tempfile::TempDir {
+    let repo = fixtures::make_repo_all_valid();
+    let root = repo.path();
+    let tasks = root.join(".ito/changes").join(CHANGE).join("tasks.md");
+    fixtures::write(&tasks, "## 1. Work\n- [ ] 1.1 First\n- [ ] 1.2 Second\n");
+    fixtures::git_init_with_initial_commit(root);
+    commit_all(root, "v1", "v1");
+
+    fixtures::write(
+        &tasks,
+        "## 1. Work\n- [x] 1.1 First\n- [ ] 1.2 Second\n- [ ] 1.3 Third\n",
+    );
+    fixtures::write(
+        root.join(".ito/changes").join(CHANGE).join("design.md"),
+        "## Design\nKeep it small.\n",
+    );
+    commit_all(root, "v2", "v2");
+
+    fixtures::write(
+        &tasks,
+        "## 1. Work\n- [x] 1.1 First\n- [x] 1.2 Second\n- [x] 1.3 Third\n",
+    );
+    fixtures::write(
+        root.join(".ito/changes/000-02_later/proposal.md"),
+        "## Why\nNot committed yet\n",
+    );
+    repo
+}
+
+fn ito_json(repo: &Path, args: &[&str]) -> serde_json::Value {
+    let home = tempfile::tempdir().expect("home");
+    let out = run_rust_candidate(
+        assert_cmd::cargo::cargo_bin!("ito"),
+        args,
+        repo,
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "args={args:?} stderr={}", out.stderr);
+    serde_json::from_str(&out.stdout).expect("json output")
+}
+
+fn progress(value: &serde_json::Value) -> (u64, u64) {
+    (
+        value["progress"]["complete"].as_u64().unwrap(),
+        value["progress"]["total"].as_u64().unwrap(),
+    )
+}
+
+#[test]
+fn tasks_status_reports_progress_at_each_revision() {
+    let repo = repo_with_history();
+
+    let v1 = ito_json(
+        repo.path(),
+        &["tasks", "status", CHANGE, "--at", "v1", "--json"],
+    );
+    let v2 = ito_json(
+        repo.path(),
+        &["tasks", "status", CHANGE, "--at", "v2", "--json"],
+    );
+    let head = ito_json(repo.path(), &["tasks", "status", CHANGE, "--json"]);
+
+    assert_eq!(progress(&v1), (0, 2));
+    assert_eq!(progress(&v2), (1, 3));
+    assert_eq!(progress(&head), (3, 3));
+}
+
+#[test]
+fn list_reads_changes_at_the_revision() {
+    let repo = repo_with_history();
+
+    let v1 = ito_json(repo.path(), &["list", "--json", "--at", "v1"]);
+
+    let changes = v1["changes"].as_array().expect("changes");
+    assert_eq!(changes.len(), 1, "{v1}");
+    assert_eq!(changes[0]["name"], CHANGE);
+    assert_eq!(changes[0]["completedTasks"], 0);
+    assert_eq!(changes[0]["totalTasks"], 2);
+}
+
+#[test]
+fn status_reports_artifacts_at_the_revision() {
+    let repo = repo_with_history();
+    let design_status = |rev: &str| {
+        let status = ito_json(
+            repo.path(),
+            &["status", "--change", CHANGE, "--json", "--at", rev],
+        );
+        status["artifacts"]
+            .as_array()
+            .expect("artifacts")
+            .iter()
+            .find(|a| a["id"] == "design")
+            .map(|a| a["status"].clone())
+            .expect("design artifact")
+    };
+
+    assert_ne!(design_status("v1"), "done");
+    assert_eq!(design_status("v2"), "done");
+}
+
+#[test]
+fn changes_absent_at_the_revision_are_not_found() {
+    let repo = repo_with_history();
+    let home = tempfile::tempdir().expect("home");
+
+    let out = run_rust_candidate(
+        assert_cmd::cargo::cargo_bin!("ito"),
+        &["show", "000-02_later", "--json", "--at", "v2"],
+        repo.path(),
+        home.path(),
+    );
+
+    assert_ne!(out.code, 0, "stdout={}", out.stdout);
+    assert!(out.stderr.contains("000-02_later"), "{}", out.stderr);
+}
+
+#[test]
+fn unknown_revision_fails() {
+    let repo = repo_with_history();
+    let home = tempfile::tempdir().expect("home");
+
+    let out = run_rust_candidate(
+        assert_cmd::cargo::cargo_bin!("ito"),
+        &["tasks", "status", CHANGE, "--at", "no-such-tag"],
+        repo.path(),
+        home.path(),
+    );
+
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr.contains("unknown git revision 'no-such-tag'"),
+        "{}",
+        out.stderr
+    );
+}
@@ -436,3 +436,82 @@ fn audit_stream_all_worktrees_dedupes_shared_routed_storage() {
     let event: serde_json::Value = serde_json::from_str(lines[0]).expect("stream json line");
     assert_eq!(event["entity_id"], "1.1");
 }
+
+#[test]
+fn audit_log_since_and_until_filter_by_time_window() {
+    let repo = fixtures::make_empty_repo();
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    fixtures::git_init_with_initial_commit(repo.path());
+    let event = serde_json::json!({
+        "v": 1,
+        "ts": "2026-03-16T12:00:00.000Z",
+        "entity": "task",
+        "entity_id": "1.1",
+        "scope": "test-change",
+        "op": "create",
+        "from": serde_json::Value::Null,
+        "to": "pending",
+        "actor": "cli",
+        "by": "@test",
+        "meta": serde_json::Value::Null,
+        "ctx": {
+            "session_id": "test-session",
+            "harness_session_id": serde_json::Value::Null,
+            "branch": serde_json::Value::Null,
+            "worktree": serde_json::Value::Null,
+            "commit": serde_json::Value::Null
+        }
+    });
+    fixtures::write(
+        repo.path().join(".ito/.state/audit/events.jsonl"),
+        &(serde_json::to_string(&event).expect("event") + "\n"),
+    );
+
+    let count = |args: &[&str]| {
+        let out = run_rust_candidate(rust_path, args, repo.path(), home.path());
+        assert_eq!(out.code, 0, "stderr={}", out.stderr);
+        let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("audit log json");
+        v.as_array().expect("array").len()
+    };
+
+    assert_eq!(count(&["audit", "log", "--json"]), 1);
+    assert_eq!(
+        count(&["audit", "log", "--json", "--since", "2026-03-01"]),
+        1
+    );
+    assert_eq!(count(&["audit", "log", "--json", "--since", "1h"]), 0);
+    assert_eq!(
+        count(&["audit", "log", "--json", "--until", "2026-03-01"]),
+        0
+    );
+    assert_eq!(
+        count(&[
+            "audit",
+            "log",
+            "--json",
+            "--since",
+            "2000-01-01",
+            "--until",
+            "1h"
+        ]),
+        1
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["audit", "log", "--since", "yesterday"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(out.stderr.contains("Invalid time"), "stderr={}", out.stderr);
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["audit", "log", "--since", "1h", "--until", "2d"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+}
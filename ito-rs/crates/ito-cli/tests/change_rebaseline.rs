@@ -0,0 +1,68 @@
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use ito_test_support::run_rust_candidate;
+
+const CHANGE_ID: &str = "000-01_test-change";
+
+#[test]
+fn change_rebaseline_records_baseline_and_pins_it() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    let change_dir = repo.path().join(".ito/changes").join(CHANGE_ID);
+    fixtures::write(change_dir.join(".ito.yaml"), "schema: spec-driven\n");
+
+    // Pinning requires a stored schema copy.
+    let out = run_rust_candidate(
+        rust_path,
+        &["change", "rebaseline", CHANGE_ID, "--pin"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr.contains("no stored schema copy"),
+        "stderr={}",
+        out.stderr
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["change", "rebaseline", CHANGE_ID, "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let result: serde_json::Value = serde_json::from_str(&out.stdout).expect("json output");
+    assert_eq!(result["changeName"], CHANGE_ID);
+    assert_eq!(result["schemaName"], "spec-driven");
+    assert_eq!(result["pinned"], false);
+    assert!(result["previousHash"].is_null());
+    let hash = result["schemaHash"].as_str().expect("schema hash");
+    assert!(change_dir.join(".schema-baseline/schema.yaml").is_file());
+    let meta = std::fs::read_to_string(change_dir.join(".ito.yaml")).expect("read meta");
+    assert!(
+        meta.contains(&format!("schema_hash: \"{hash}\"")),
+        "meta={meta}"
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["change", "rebaseline", CHANGE_ID, "--pin"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stdout.contains("Pinned 000-01_test-change"),
+        "stdout={}",
+        out.stdout
+    );
+    assert!(out.stdout.contains("No artifact status changes."));
+    let meta = std::fs::read_to_string(change_dir.join(".ito.yaml")).expect("read meta");
+    assert!(meta.contains("schema_pinned: true"), "meta={meta}");
+}
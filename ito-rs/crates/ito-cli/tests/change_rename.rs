@@ -0,0 +1,118 @@
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use ito_test_support::run_rust_candidate;
+
+const OLD_ID: &str = "000-01_test-change";
+const NEW_ID: &str = "000-01_renamed-change";
+
+#[test]
+fn change_rename_dry_run_then_apply() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    let ito = repo.path().join(".ito");
+    fixtures::write(
+        ito.join(".state/ralph").join(OLD_ID).join("state.json"),
+        "{\"change_id\":\"000-01_test-change\",\"iteration\":1}\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["change", "rename", OLD_ID, "renamed-change", "--dry-run"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stdout.contains(&format!(
+            "Would move .ito/changes/{OLD_ID} -> .ito/changes/{NEW_ID}"
+        )),
+        "stdout={}",
+        out.stdout
+    );
+    assert!(
+        out.stdout
+            .contains("Would update .ito/modules/000_ungrouped/module.md (1 reference)"),
+        "stdout={}",
+        out.stdout
+    );
+    assert!(ito.join("changes").join(OLD_ID).is_dir());
+    assert!(!ito.join("changes").join(NEW_ID).exists());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["change", "rename", OLD_ID, "renamed-change"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stdout
+            .contains(&format!("Renamed change '{OLD_ID}' to '{NEW_ID}'")),
+        "stdout={}",
+        out.stdout
+    );
+    assert!(ito.join("changes").join(NEW_ID).join("tasks.md").is_file());
+    assert!(!ito.join("changes").join(OLD_ID).exists());
+    let module =
+        std::fs::read_to_string(ito.join("modules/000_ungrouped/module.md")).expect("module");
+    assert!(
+        module.contains(&format!("- [ ] {NEW_ID}\n")),
+        "module={module}"
+    );
+    assert!(
+        ito.join(".state/ralph")
+            .join(NEW_ID)
+            .join("state.json")
+            .is_file()
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["audit", "log", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("audit log json");
+    let renamed = v.as_array().expect("audit log array").iter().any(|e| {
+        e["entity"] == "change" && e["op"] == "rename" && e["from"] == OLD_ID && e["to"] == NEW_ID
+    });
+    assert!(renamed, "audit log={}", out.stdout);
+}
+
+#[test]
+fn change_rename_rejects_existing_target() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    fixtures::write(
+        repo.path()
+            .join(".ito/changes")
+            .join(NEW_ID)
+            .join("proposal.md"),
+        "## Why\nTaken\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["change", "rename", OLD_ID, NEW_ID],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr
+            .contains(&format!("Change '{NEW_ID}' already exists")),
+        "stderr={}",
+        out.stderr
+    );
+    assert!(repo.path().join(".ito/changes").join(OLD_ID).is_dir());
+}
@@ -0,0 +1,116 @@
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use ito_test_support::run_rust_candidate;
+
+const OLD_ID: &str = "000-01_test-change";
+const NEW_ID: &str = "000-02_follow-up";
+
+const TASKS: &str = "# Tasks for: 000-01_test-change\n\n## Wave 1\n\n- **Depends On**: None\n\n### Task 1.1: First\n\n- **Files**: `src/a.rs`\n- **Dependencies**: None\n- **Action**:\n  Do the first thing\n- **Verify**: `cargo test`\n- **Done When**: Done\n- **Updated At**: 2026-01-01\n- **Status**: [ ] pending\n\n### Task 1.2: Second\n\n- **Files**: `src/b.rs`\n- **Dependencies**: None\n- **Action**:\n  Do the second thing\n- **Verify**: `cargo test`\n- **Done When**: Done\n- **Updated At**: 2026-01-01\n- **Status**: [ ] pending\n\n### Task 1.3: Third\n\n- **Files**: `src/c.rs`\n- **Dependencies**: Task 1.1\n- **Action**:\n  Do the third thing\n- **Verify**: `cargo test`\n- **Done When**: Done\n- **Updated At**: 2026-01-01\n- **Status**: [ ] pending\n";
+
+#[test]
+fn change_split_moves_tasks_and_records_audit_events() {
+    let repo = fixtures::make_repo_all_valid();
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    let ito = repo.path().join(".ito");
+    fixtures::write(ito.join("changes").join(OLD_ID).join("tasks.md"), TASKS);
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "change",
+            "split",
+            OLD_ID,
+            "--tasks",
+            "1.2",
+            "--name",
+            "follow-up",
+            "--renumber",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stdout
+            .contains(&format!("Split 1 task from '{OLD_ID}' into '{NEW_ID}'")),
+        "stdout={}",
+        out.stdout
+    );
+    assert!(
+        out.stdout.contains("Renumbered task 1.3 -> 1.2"),
+        "stdout={}",
+        out.stdout
+    );
+
+    let new_tasks =
+        std::fs::read_to_string(ito.join("changes").join(NEW_ID).join("tasks.md")).unwrap();
+    assert!(new_tasks.contains("### Task 1.2: Second\n"), "{new_tasks}");
+    let old_tasks =
+        std::fs::read_to_string(ito.join("changes").join(OLD_ID).join("tasks.md")).unwrap();
+    assert!(!old_tasks.contains("Second"), "{old_tasks}");
+    assert!(old_tasks.contains("### Task 1.2: Third\n"), "{old_tasks}");
+    let proposal =
+        std::fs::read_to_string(ito.join("changes").join(NEW_ID).join("proposal.md")).unwrap();
+    assert!(
+        proposal.starts_with(&format!("> Split from `{OLD_ID}`")),
+        "{proposal}"
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["audit", "log", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("audit log json");
+    let events = v.as_array().expect("audit log array");
+    let split = events
+        .iter()
+        .find(|e| e["entity"] == "change" && e["op"] == "split")
+        .unwrap_or_else(|| panic!("no split event: {}", out.stdout));
+    assert_eq!(split["entity_id"], OLD_ID);
+    assert_eq!(split["to"], NEW_ID);
+    assert_eq!(split["meta"]["tasks"], serde_json::json!(["1.2"]));
+    assert_eq!(split["meta"]["renumbered"]["1.3"], "1.2");
+    let created = events.iter().any(|e| {
+        e["entity"] == "change"
+            && e["entity_id"] == NEW_ID
+            && e["op"] == "create"
+            && e["meta"]["split_from"] == OLD_ID
+    });
+    assert!(created, "audit log={}", out.stdout);
+}
+
+#[test]
+fn change_split_refuses_to_strand_dependents() {
+    let repo = fixtures::make_repo_all_valid();
+    let home = tempfile::tempdir().expect("home");
+    let ito = repo.path().join(".ito");
+    fixtures::write(ito.join("changes").join(OLD_ID).join("tasks.md"), TASKS);
+
+    let out = run_rust_candidate(
+        assert_cmd::cargo::cargo_bin!("ito"),
+        &[
+            "change",
+            "split",
+            OLD_ID,
+            "--tasks",
+            "1.1",
+            "--name",
+            "follow-up",
+        ],
+        repo.path(),
+        home.path(),
+    );
+
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr.contains("depends on task '1.1'"),
+        "stderr={}",
+        out.stderr
+    );
+    assert!(!ito.join("changes").join(NEW_ID).exists());
+}
@@ -0,0 +1,199 @@
+#![cfg(feature = "coordination-branch")]
+
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use ito_test_support::{CmdOutput, run_rust_candidate};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+const CHANGE: &str = "test-change";
+
+const TASKS: &str = "# Tasks for: test-change
+
+## Wave 1
+
+- **Depends On**: None
+
+### Task 1.1: First task
+
+- **Files**: `src/first.rs`
+- **Dependencies**: None
+- **Action**:
+  Do the first thing
+- **Verify**: `true`
+- **Done When**: First thing done
+- **Updated At**: 2026-01-01
+- **Status**: [ ] pending
+
+### Task 1.2: Second task
+
+- **Files**: `src/second.rs`
+- **Dependencies**: None
+- **Action**:
+  Do the second thing
+- **Verify**: `true`
+- **Done When**: Second thing done
+- **Updated At**: 2026-01-01
+- **Status**: [ ] pending
+";
+
+/// Two clones of one bare remote, both on the change branch.
+struct Clones {
+    _remote: tempfile::TempDir,
+    a: tempfile::TempDir,
+    _b_parent: tempfile::TempDir,
+    b: PathBuf,
+    home: tempfile::TempDir,
+}
+
+impl Clones {
+    fn new() -> Self {
+        let remote = fixtures::make_bare_remote();
+        let a = fixtures::make_empty_repo();
+        fixtures::write(
+            a.path().join("ito.json"),
+            r#"{ "changes": { "coordination_branch": { "enabled": true, "storage": "embedded" } } }"#,
+        );
+        fixtures::write(
+            a.path().join(".ito/changes").join(CHANGE).join("tasks.md"),
+            TASKS,
+        );
+        fixtures::integrate_change_for_execution(a.path(), CHANGE);
+        fixtures::add_origin(a.path(), remote.path());
+        fixtures::run_git(a.path(), &["push", "origin", "main", CHANGE]);
+
+        let b_parent = tempfile::tempdir().expect("clone parent");
+        fixtures::run_git(
+            b_parent.path(),
+            &[
+                "clone",
+                "--branch",
+                "main",
+                remote.path().to_string_lossy().as_ref(),
+                "b",
+            ],
+        );
+        let b = b_parent.path().join("b");
+        fixtures::run_git(&b, &["config", "user.email", "other@example.com"]);
+        fixtures::run_git(&b, &["config", "user.name", "Other User"]);
+        fixtures::run_git(&b, &["config", "commit.gpgsign", "false"]);
+        fixtures::run_git(&b, &["switch", CHANGE]);
+
+        Self {
+            _remote: remote,
+            a,
+            _b_parent: b_parent,
+            b,
+            home: tempfile::tempdir().expect("home"),
+        }
+    }
+
+    fn ito(&self, repo: &Path, args: &[&str]) -> CmdOutput {
+        let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+        run_rust_candidate(rust_path, args, repo, self.home.path())
+    }
+
+    fn ito_ok(&self, repo: &Path, args: &[&str]) -> CmdOutput {
+        let out = self.ito(repo, args);
+        assert_eq!(out.code, 0, "args={args:?}\nstderr={}", out.stderr);
+        out
+    }
+
+    fn sync(&self, repo: &Path) -> Value {
+        let out = self.ito_ok(repo, &["change", "sync", "--json"]);
+        serde_json::from_str(&out.stdout).expect("change sync json")
+    }
+}
+
+fn tasks_md(repo: &Path) -> String {
+    std::fs::read_to_string(repo.join(".ito/changes").join(CHANGE).join("tasks.md"))
+        .expect("read tasks.md")
+}
+
+/// Status marker recorded for `task_id`, e.g. `[>] in-progress`.
+fn task_status(repo: &Path, task_id: &str) -> String {
+    let contents = tasks_md(repo);
+    let header = format!("### Task {task_id}:");
+    let section = contents
+        .split_once(&header)
+        .map(|(_, rest)| rest)
+        .expect("task section");
+    section
+        .lines()
+        .find_map(|line| line.strip_prefix("- **Status**: "))
+        .expect("status line")
+        .trim()
+        .to_string()
+}
+
+#[test]
+fn claim_synced_from_one_clone_is_visible_in_the_other() {
+    let clones = Clones::new();
+    let a = clones.a.path();
+
+    clones.ito_ok(a, &["tasks", "start", CHANGE, "1.1"]);
+    let report = clones.sync(a);
+    assert_eq!(
+        report["published"],
+        serde_json::json!(["test-change/.ito.yaml", "test-change/tasks.md"])
+    );
+    assert!(report["commit"].is_string(), "{report}");
+    assert_eq!(report["conflicts"], serde_json::json!([]));
+
+    let out = clones.ito_ok(&clones.b, &["tasks", "start", CHANGE, "1.2"]);
+    assert!(
+        !out.stderr.contains("coordination branch"),
+        "{}",
+        out.stderr
+    );
+    let out = clones.ito(&clones.b, &["tasks", "start", CHANGE, "1.1"]);
+    assert!(
+        out.stderr
+            .contains("task \"1.1\" is already in progress on coordination branch"),
+        "stderr={}",
+        out.stderr
+    );
+
+    let report = clones.sync(&clones.b);
+    assert_eq!(report["conflicts"], serde_json::json!([]));
+    assert_eq!(task_status(&clones.b, "1.1"), "[>] in-progress");
+    assert_eq!(task_status(&clones.b, "1.2"), "[>] in-progress");
+
+    let report = clones.sync(a);
+    assert_eq!(
+        report["pulled"],
+        serde_json::json!(["test-change/tasks.md"])
+    );
+    assert_eq!(task_status(a, "1.2"), "[>] in-progress");
+}
+
+#[test]
+fn conflicting_completions_merge_to_complete() {
+    let clones = Clones::new();
+    let a = clones.a.path();
+    let b = clones.b.as_path();
+
+    clones.ito_ok(a, &["tasks", "start", CHANGE, "1.1"]);
+    clones.ito_ok(a, &["tasks", "complete", CHANGE, "1.1"]);
+    clones.sync(a);
+
+    clones.ito_ok(b, &["tasks", "start", CHANGE, "1.1"]);
+    clones.ito_ok(b, &["tasks", "complete", CHANGE, "1.1"]);
+    clones.ito_ok(b, &["tasks", "start", CHANGE, "1.2"]);
+    clones.ito_ok(b, &["tasks", "complete", CHANGE, "1.2"]);
+    let report = clones.sync(b);
+    assert_eq!(report["conflicts"], serde_json::json!([]));
+    assert!(report["commit"].is_string(), "{report}");
+
+    clones.sync(a);
+    for repo in [a, b] {
+        assert_eq!(task_status(repo, "1.1"), "[x] complete");
+        assert_eq!(task_status(repo, "1.2"), "[x] complete");
+    }
+    assert_eq!(tasks_md(a), tasks_md(b));
+
+    let report = clones.sync(a);
+    assert_eq!(report["published"], serde_json::json!([]));
+    assert_eq!(report["pulled"], serde_json::json!([]));
+}
@@ -0,0 +1,108 @@
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use ito_test_support::run_rust_candidate;
+
+const PROPOSAL: &str = "## Why\nFixture\n\n## What Changes\n- None\n\n## Impact\n- None\n";
+
+fn repo_with_changes(extra: &[&str]) -> tempfile::TempDir {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    fixtures::reset_repo(repo.path(), base.path());
+    for id in extra {
+        fixtures::write(
+            repo.path().join(format!(".ito/changes/{id}/proposal.md")),
+            PROPOSAL,
+        );
+    }
+    repo
+}
+
+#[test]
+fn ambiguous_listing_is_identical_across_commands() {
+    let repo = repo_with_changes(&["000-01_test-alternate"]);
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    let status = run_rust_candidate(
+        rust_path,
+        &["status", "--change", "0-1"],
+        repo.path(),
+        home.path(),
+    );
+    let tasks = run_rust_candidate(
+        rust_path,
+        &["tasks", "status", "0-1"],
+        repo.path(),
+        home.path(),
+    );
+    let show = run_rust_candidate(rust_path, &["show", "0-1"], repo.path(), home.path());
+
+    for out in [&status, &tasks, &show] {
+        assert_eq!(out.code, 2, "stdout={} stderr={}", out.stdout, out.stderr);
+    }
+    assert_eq!(status.stderr, tasks.stderr);
+    assert_eq!(status.stderr, show.stderr);
+    insta::assert_snapshot!("ambiguous_change_listing", status.stderr.trim());
+}
+
+#[test]
+fn exact_id_beats_prefix_matches() {
+    let repo = repo_with_changes(&["000-01_test-change-two"]);
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["status", "--change", "0-1_test-change", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("status json");
+    assert_eq!(v["changeName"], "000-01_test-change");
+}
+
+#[test]
+fn exact_flag_rejects_a_prefix_that_would_otherwise_resolve() {
+    let repo = repo_with_changes(&[]);
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["tasks", "status", "000-01"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+
+    for args in [
+        &["tasks", "status", "000-01", "--exact"][..],
+        &["show", "000-01", "--exact"][..],
+        &[
+            "agent",
+            "instruction",
+            "proposal",
+            "--change",
+            "000-01",
+            "--exact",
+        ][..],
+    ] {
+        let out = run_rust_candidate(rust_path, args, repo.path(), home.path());
+        assert_eq!(out.code, 3, "args={args:?} stderr={}", out.stderr);
+        assert!(
+            out.stderr.contains("Change '000-01' not found"),
+            "args={args:?} stderr={}",
+            out.stderr
+        );
+    }
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["tasks", "status", "000-01_test-change", "--exact"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+}
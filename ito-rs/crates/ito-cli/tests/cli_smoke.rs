@@ -410,6 +410,37 @@ fn agent_instruction_status_archive_smoke() {
         Some("000-01_test-change")
     );
 
+    let out = run_rust_candidate(
+        rust_path,
+        &["status", "--change", "000-01_test-change"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0);
+    assert!(out.stdout.contains("\nNext: "), "stdout={}", out.stdout);
+
+    // next artifact
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "agent",
+            "instruction",
+            "next",
+            "--change",
+            "000-01_test-change",
+            "--json",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("next artifact json");
+    assert_eq!(
+        v.get("changeName").and_then(|v| v.as_str()),
+        Some("000-01_test-change")
+    );
+    assert!(v.get("state").and_then(|v| v.as_str()).is_some());
+
     // archive (skip specs + validate to avoid interactive flows)
     let out = run_rust_candidate(
         rust_path,
@@ -115,6 +115,39 @@ fn snapshot_help_all_subcommand() {
     insta::assert_snapshot!("ito_help_subcommand_all", snapshot(&["help", "--all"]));
 }
 
+/// Extract the Ito-specific dynamic completion block from a generated script.
+fn dynamic_completion_section(script: &str) -> String {
+    let start = script
+        .find("# --- ito dynamic completion ---")
+        .expect("dynamic completion start marker");
+    let end_marker = "# --- end ito dynamic completion ---";
+    let end = script[start..]
+        .find(end_marker)
+        .map(|pos| start + pos + end_marker.len())
+        .expect("dynamic completion end marker");
+    script[start..end].to_string()
+}
+
+#[test]
+fn snapshot_zsh_dynamic_completion() {
+    let script = snapshot(&["completions", "zsh"]);
+    assert!(script.contains(":CHANGE:_ito_complete_changes'"));
+    insta::assert_snapshot!(
+        "ito_completions_zsh_dynamic",
+        dynamic_completion_section(&script)
+    );
+}
+
+#[test]
+fn snapshot_fish_dynamic_completion() {
+    let script = snapshot(&["completions", "fish"]);
+    assert!(script.contains("-l change"));
+    insta::assert_snapshot!(
+        "ito_completions_fish_dynamic",
+        dynamic_completion_section(&script)
+    );
+}
+
 #[test]
 fn snapshot_tasks_help() {
     insta::assert_snapshot!("ito_tasks_help", snapshot(&["tasks", "--help"]));
@@ -169,6 +202,14 @@ fn snapshot_change_preflight_help() {
     );
 }
 
+#[test]
+fn snapshot_change_rebaseline_help() {
+    insta::assert_snapshot!(
+        "ito_change_rebaseline_help",
+        snapshot(&["change", "rebaseline", "--help"])
+    );
+}
+
 #[test]
 fn snapshot_validate_help() {
     insta::assert_snapshot!("ito_validate_help", snapshot(&["validate", "--help"]));
@@ -89,3 +89,38 @@ fn completions_command_runs_for_all_shells() {
         assert!(!out.stdout.trim().is_empty());
     }
 }
+
+#[test]
+fn hidden_complete_helper_lists_ids_and_is_silent_outside_repo() {
+    let repo = tempfile::tempdir().expect("repo");
+    let outside = tempfile::tempdir().expect("outside");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    write(
+        repo.path().join(".ito/changes/000-01_drift/proposal.md"),
+        "## Why\nDemo\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["__complete", "changes"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert_eq!(out.stdout, "000-01_drift\n");
+    assert!(out.stderr.is_empty(), "stderr={}", out.stderr);
+
+    for kind in ["changes", "modules", "schemas", "bogus"] {
+        let out = run_rust_candidate(
+            rust_path,
+            &["__complete", kind],
+            outside.path(),
+            home.path(),
+        );
+        assert_eq!(out.code, 0, "kind={kind} stderr={}", out.stderr);
+        assert!(out.stdout.is_empty(), "kind={kind} stdout={}", out.stdout);
+        assert!(out.stderr.is_empty(), "kind={kind} stderr={}", out.stderr);
+    }
+}
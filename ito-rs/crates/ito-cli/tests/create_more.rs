@@ -264,3 +264,44 @@ fn create_change_sub_module_rejects_remote_persistence_mode() {
         );
     }
 }
+
+#[test]
+fn create_change_with_profile_uses_profile_schema_and_records_it() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    fixtures::write(
+        repo.path().join(".ito/config.json"),
+        r#"{ "profiles": { "bugfix": { "schema": "tdd", "harness": "claude" } } }"#,
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["create", "change", "fix-login", "--profile", "bugfx"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr.contains("Did you mean: bugfix"),
+        "stderr={}",
+        out.stderr
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["create", "change", "fix-login", "--profile", "bugfix"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stdout={} stderr={}", out.stdout, out.stderr);
+    assert!(out.stderr.contains("Schema: tdd"), "stderr={}", out.stderr);
+
+    let meta = std::fs::read_to_string(repo.path().join(".ito/changes/000-01_fix-login/.ito.yaml"))
+        .expect("read .ito.yaml");
+    assert!(meta.contains("schema: tdd"), "meta={meta}");
+    assert!(meta.contains("profile: bugfix"), "meta={meta}");
+}
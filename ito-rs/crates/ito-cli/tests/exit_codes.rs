@@ -0,0 +1,119 @@
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use ito_test_support::run_rust_candidate;
+
+fn json_error(stdout: &str) -> serde_json::Value {
+    let value: serde_json::Value = serde_json::from_str(stdout).expect("JSON error on stdout");
+    value["error"].clone()
+}
+
+#[test]
+fn missing_change_exits_3_with_change_not_found_code() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["status", "--change", "999-99_missing", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 3, "stdout={} stderr={}", out.stdout, out.stderr);
+    let error = json_error(&out.stdout);
+    assert_eq!(error["code"], "CHANGE_NOT_FOUND");
+    assert_eq!(error["category"], "not_found");
+    assert_eq!(error["exit_code"], 3);
+    assert!(out.stderr.contains("Change '999-99_missing' not found"));
+}
+
+#[test]
+fn invalid_argument_exits_2_with_usage_error_code() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["list", "--no-such-flag", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 2, "stdout={} stderr={}", out.stdout, out.stderr);
+    let error = json_error(&out.stdout);
+    assert_eq!(error["code"], "USAGE_ERROR");
+    assert_eq!(error["category"], "usage");
+}
+
+#[test]
+fn failing_validation_exits_4() {
+    let base = fixtures::make_repo_with_spec_change_fixture();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["validate", "--all", "--strict", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 4, "stdout={} stderr={}", out.stdout, out.stderr);
+    // Validation prints its own report rather than an error object.
+    let report: serde_json::Value = serde_json::from_str(&out.stdout).expect("validate json");
+    assert!(report["summary"]["totals"]["failed"].as_u64().unwrap_or(0) > 0);
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["validate", "--all", "--strict"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 4, "stderr={}", out.stderr);
+}
+
+#[test]
+fn ambiguous_change_is_a_usage_error() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    fixtures::write(
+        repo.path()
+            .join(".ito/changes/000-01_test-alternate/proposal.md"),
+        "## Why\nAmbiguous fixture\n\n## What Changes\n- None\n\n## Impact\n- None\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["status", "--change", "0-1", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 2, "stdout={} stderr={}", out.stdout, out.stderr);
+    assert_eq!(json_error(&out.stdout)["code"], "AMBIGUOUS_TARGET");
+}
+
+#[test]
+fn help_exit_codes_documents_the_contract() {
+    let out = assert_cmd::cargo::cargo_bin_cmd!("ito")
+        .args(["help", "exit-codes"])
+        .output()
+        .expect("run ito help exit-codes");
+    assert!(out.status.success());
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    for needle in ["USAGE_ERROR", "CHANGE_NOT_FOUND", "VALIDATION_FAILED", "10"] {
+        assert!(stdout.contains(needle), "missing {needle}:\n{stdout}");
+    }
+}
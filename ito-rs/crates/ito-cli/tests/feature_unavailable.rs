@@ -70,6 +70,7 @@ fn coordination_requests_fail_before_logging_or_project_mutation() {
 
     for (args, requested_by) in [
         (&["sync", "--json"][..], "ito sync"),
+        (&["change", "sync", "--json"][..], "ito change sync"),
         (
             &[
                 "agent",
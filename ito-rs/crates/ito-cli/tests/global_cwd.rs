@@ -0,0 +1,132 @@
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use ito_test_support::run_rust_candidate;
+
+fn fixture_repo() -> tempfile::TempDir {
+    let base = fixtures::make_repo_with_spec_change_fixture();
+    let repo = tempfile::tempdir().expect("repo");
+    fixtures::reset_repo(repo.path(), base.path());
+    repo
+}
+
+#[test]
+fn cwd_flag_matches_running_inside_the_repo() {
+    let repo = fixture_repo();
+    let elsewhere = tempfile::tempdir().expect("elsewhere");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    let repo_arg = repo.path().to_string_lossy().to_string();
+
+    for argv in [
+        vec!["list"],
+        vec!["list", "--specs"],
+        vec!["status", "--change", "000-01_test-change"],
+        vec!["validate", "--all", "--json"],
+    ] {
+        let inside = run_rust_candidate(rust_path, &argv, repo.path(), home.path());
+
+        let mut with_flag = vec!["-C", repo_arg.as_str()];
+        with_flag.extend(&argv);
+        let outside = run_rust_candidate(rust_path, &with_flag, elsewhere.path(), home.path());
+
+        assert_eq!(outside.code, inside.code, "exit code for {argv:?}");
+        assert_eq!(outside.stdout, inside.stdout, "stdout for {argv:?}");
+    }
+}
+
+#[test]
+fn cwd_flag_is_accepted_after_the_subcommand() {
+    let repo = fixture_repo();
+    let elsewhere = tempfile::tempdir().expect("elsewhere");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    let repo_arg = repo.path().to_string_lossy().to_string();
+
+    let inside = run_rust_candidate(rust_path, &["list", "--specs"], repo.path(), home.path());
+    let outside = run_rust_candidate(
+        rust_path,
+        &["list", "--specs", "--cwd", &repo_arg],
+        elsewhere.path(),
+        home.path(),
+    );
+
+    assert_eq!(outside.code, 0, "stderr: {}", outside.stderr);
+    assert_eq!(outside.stdout, inside.stdout);
+}
+
+#[test]
+fn relative_cwd_flag_resolves_against_the_caller_directory() {
+    let parent = tempfile::tempdir().expect("parent");
+    let base = fixtures::make_repo_with_spec_change_fixture();
+    let repo = parent.path().join("project");
+    std::fs::create_dir_all(&repo).expect("repo dir");
+    fixtures::reset_repo(&repo, base.path());
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    let inside = run_rust_candidate(rust_path, &["list", "--specs"], &repo, home.path());
+    let outside = run_rust_candidate(
+        rust_path,
+        &["-C", "project", "list", "--specs"],
+        parent.path(),
+        home.path(),
+    );
+
+    assert_eq!(outside.code, 0, "stderr: {}", outside.stderr);
+    assert_eq!(outside.stdout, inside.stdout);
+}
+
+#[test]
+fn cwd_flag_rejects_missing_paths_and_files() {
+    let elsewhere = tempfile::tempdir().expect("elsewhere");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    fixtures::write(elsewhere.path().join("file.txt"), "not a directory\n");
+
+    let missing = run_rust_candidate(
+        rust_path,
+        &["-C", "does-not-exist", "list"],
+        elsewhere.path(),
+        home.path(),
+    );
+    assert_ne!(missing.code, 0);
+    assert!(
+        missing.stderr.contains("does not exist"),
+        "stderr: {}",
+        missing.stderr
+    );
+
+    let file = run_rust_candidate(
+        rust_path,
+        &["-C", "file.txt", "list"],
+        elsewhere.path(),
+        home.path(),
+    );
+    assert_ne!(file.code, 0);
+    assert!(
+        file.stderr.contains("is not a directory"),
+        "stderr: {}",
+        file.stderr
+    );
+}
+
+#[test]
+fn init_defaults_to_the_cwd_flag_target() {
+    let target = tempfile::tempdir().expect("target");
+    let elsewhere = tempfile::tempdir().expect("elsewhere");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    let target_arg = target.path().to_string_lossy().to_string();
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["-C", &target_arg, "init", "--tools", "none"],
+        elsewhere.path(),
+        home.path(),
+    );
+
+    assert_eq!(out.code, 0, "stderr: {}", out.stderr);
+    assert!(target.path().join(".ito").is_dir());
+    assert!(!elsewhere.path().join(".ito").exists());
+}
@@ -0,0 +1,90 @@
+#![cfg(unix)]
+
+mod support;
+
+use assert_cmd::Command;
+use predicates::str::contains;
+use std::path::Path;
+
+/// Fake `claude` that passes `--version` but rejects its credentials on `-p`.
+fn write_logged_out_claude(bin_dir: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = bin_dir.join("claude");
+    support::write(
+        &path,
+        "#!/bin/sh\n\
+if [ \"$1\" = \"--version\" ]; then echo '2.1.0 (Claude Code)'; exit 0; fi\n\
+echo 'Invalid API key · Please run /login' >&2\n\
+exit 1\n",
+    );
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+/// `ito harness check` run from `bin`, with `bin` as the whole PATH.
+fn harness_check(bin: &Path, home: &Path, args: &[&str]) -> Command {
+    let mut command = Command::cargo_bin("ito").unwrap();
+    command.current_dir(bin);
+    command.env("PATH", bin);
+    command.env("HOME", home);
+    command.env("NO_COLOR", "1");
+    command.args(["harness", "check"]).args(args);
+    command
+}
+
+#[test]
+fn stub_harness_check_passes() {
+    let bin = tempfile::tempdir().expect("bin");
+    let home = tempfile::tempdir().expect("home");
+
+    harness_check(bin.path(), home.path(), &["stub"])
+        .assert()
+        .success()
+        .stdout("PASS  stub  ok\n");
+}
+
+#[test]
+fn missing_binary_fails_with_binary_missing() {
+    let bin = tempfile::tempdir().expect("bin");
+    let home = tempfile::tempdir().expect("home");
+
+    let output = harness_check(bin.path(), home.path(), &["codex", "--json"])
+        .output()
+        .expect("command ran");
+    assert!(!output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "harnesses": [{
+                "harness": "codex",
+                "status": "binary_missing",
+                "ok": false,
+                "detail": "`codex` not found on PATH",
+            }]
+        })
+    );
+}
+
+#[test]
+fn rejected_login_fails_with_auth_error() {
+    let bin = tempfile::tempdir().expect("bin");
+    let home = tempfile::tempdir().expect("home");
+    write_logged_out_claude(bin.path());
+
+    harness_check(bin.path(), home.path(), &["claude"])
+        .assert()
+        .failure()
+        .stdout("FAIL  claude  authentication failed: Invalid API key · Please run /login\n");
+}
+
+#[test]
+fn check_requires_a_harness_name() {
+    let bin = tempfile::tempdir().expect("bin");
+    let home = tempfile::tempdir().expect("home");
+
+    harness_check(bin.path(), home.path(), &[])
+        .assert()
+        .failure()
+        .stderr(contains("required arguments were not provided"));
+}
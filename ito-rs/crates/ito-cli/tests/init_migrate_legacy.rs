@@ -0,0 +1,154 @@
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use std::path::Path;
+
+use ito_test_support::run_rust_candidate;
+
+fn write_legacy_tree(root: &Path) {
+    let legacy = root.join("openspec");
+    fixtures::write(legacy.join("project.md"), "# Legacy project\n");
+    fixtures::write(
+        legacy.join("specs/auth/spec.md"),
+        "## Purpose\nAuth.\n\n## Requirements\n\n### Requirement: Login\nUsers log in.\n",
+    );
+    fixtures::write(
+        legacy.join("changes/add-login/proposal.md"),
+        "## Why\nLogin.\n",
+    );
+    fixtures::write(
+        legacy.join("changes/add-login/tasks.md"),
+        "- [ ] 1.1 Build login\n",
+    );
+    fixtures::write(
+        legacy.join("changes/archive/2024-01-01-old/proposal.md"),
+        "## Why\nOld.\n",
+    );
+    fixtures::write(legacy.join("modules/misc/notes.md"), "notes\n");
+}
+
+#[test]
+fn init_hints_at_detected_legacy_layout() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    write_legacy_tree(repo.path());
+
+    let args = fixtures::init_minimal_args(repo.path());
+    let argv = fixtures::args_to_strs(&args);
+    let out = run_rust_candidate(rust_path, &argv, repo.path(), home.path());
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stderr.contains("Detected a legacy layout") && out.stderr.contains("--migrate-from"),
+        "stderr={}",
+        out.stderr
+    );
+    assert!(!repo.path().join(".ito/specs/auth").exists());
+}
+
+#[test]
+fn init_migrate_from_copies_legacy_tree_and_is_idempotent() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    write_legacy_tree(repo.path());
+    let repo_path = repo.path().to_string_lossy();
+    let argv = [
+        "init",
+        repo_path.as_ref(),
+        "--tools",
+        "none",
+        "--migrate-from",
+        "openspec",
+    ];
+
+    let out = run_rust_candidate(rust_path, &argv, repo.path(), home.path());
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+
+    let ito = repo.path().join(".ito");
+    assert!(ito.join("specs/auth/spec.md").is_file());
+    let change = ito.join("changes/000-01_add-login");
+    assert_eq!(
+        std::fs::read_to_string(change.join("proposal.md")).expect("proposal"),
+        "## Why\nLogin.\n"
+    );
+    assert!(change.join("tasks.md").is_file());
+    assert!(
+        std::fs::read_to_string(change.join(".ito.yaml"))
+            .expect("meta")
+            .contains("schema: spec-driven")
+    );
+    assert!(
+        ito.join("changes/archive/2024-01-01-old/proposal.md")
+            .is_file()
+    );
+
+    assert!(out.stdout.contains("Migrated 3 entries"), "{}", out.stdout);
+    assert!(
+        out.stdout
+            .contains("change changes/add-login -> changes/000-01_add-login"),
+        "{}",
+        out.stdout
+    );
+    assert!(out.stdout.contains("Left in place for manual action:"));
+    assert!(out.stdout.contains("  project.md: no Ito equivalent"));
+    assert!(
+        out.stdout
+            .contains("  modules/misc: module folders must be named NNN_name")
+    );
+
+    // Copy is non-destructive.
+    assert!(repo.path().join("openspec/specs/auth/spec.md").is_file());
+
+    let rerun = run_rust_candidate(rust_path, &argv, repo.path(), home.path());
+    assert_eq!(rerun.code, 0, "stderr={}", rerun.stderr);
+    assert!(
+        rerun
+            .stdout
+            .contains("Migrated 0 entries (3 already present)"),
+        "{}",
+        rerun.stdout
+    );
+    assert!(!ito.join("changes/000-02_add-login").exists());
+}
+
+#[test]
+fn init_migrate_from_with_move_removes_migrated_entries() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    write_legacy_tree(repo.path());
+    let repo_path = repo.path().to_string_lossy();
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "init",
+            repo_path.as_ref(),
+            "--tools",
+            "none",
+            "--migrate-from",
+            "openspec",
+            "--move",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+
+    let legacy = repo.path().join("openspec");
+    assert!(repo.path().join(".ito/specs/auth/spec.md").is_file());
+    assert!(!legacy.join("specs/auth").exists());
+    assert!(!legacy.join("changes/add-login").exists());
+    assert!(legacy.join("project.md").is_file());
+    assert!(legacy.join("modules/misc/notes.md").is_file());
+}
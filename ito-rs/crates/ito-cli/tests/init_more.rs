@@ -10,15 +10,6 @@ use crate::fixtures::installed_orchestrator_agent_paths;
 #[cfg(unix)]
 use ito_test_support::pty::run_pty_interactive;
 
-fn expected_release_tag() -> String {
-    let version = option_env!("ITO_WORKSPACE_VERSION").unwrap_or(env!("CARGO_PKG_VERSION"));
-    let Some(version) = version.strip_prefix('v') else {
-        return format!("v{version}");
-    };
-
-    format!("v{version}")
-}
-
 fn expected_opencode_general_model() -> String {
     use ito_templates::agents::{AgentTier, Harness, default_agent_configs};
 
@@ -42,6 +33,38 @@ fn init_requires_tools_when_non_interactive() {
     assert!(out.stderr.contains("requires --tools"));
 }
 
+#[test]
+fn init_tools_auto_fails_clearly_when_nothing_is_detected() {
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let empty_path = tempfile::tempdir().expect("empty PATH");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::write(repo.path().join("README.md"), "# temp\n");
+
+    let output = ito_test_support::rust_candidate_command(rust_path)
+        .args(["init", "--tools", "auto"])
+        .current_dir(repo.path())
+        .env("CI", "1")
+        .env("NO_COLOR", "1")
+        .env("ITO_INTERACTIVE", "0")
+        .env("HOME", home.path())
+        .env("XDG_CONFIG_HOME", home.path().join(".config"))
+        .env("XDG_DATA_HOME", home.path())
+        .env("PATH", empty_path.path())
+        .output()
+        .expect("run ito");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_ne!(output.status.code(), Some(0));
+    assert!(
+        stderr.contains("--tools auto detected no AI tools"),
+        "stderr={stderr}"
+    );
+    assert!(stderr.contains("--tools claude,codex"), "stderr={stderr}");
+    assert!(!repo.path().join(".ito").exists());
+}
+
 #[test]
 fn init_with_tools_none_installs_ito_skeleton() {
     let base = fixtures::make_empty_repo();
@@ -351,7 +374,7 @@ fn init_does_not_print_project_setup_nudge_when_marker_absent() {
 }
 
 #[test]
-fn init_writes_config_with_release_tag_schema_reference() {
+fn init_writes_config_schema_and_relative_schema_reference() {
     let base = fixtures::make_empty_repo();
     let repo = tempfile::tempdir().expect("work");
     let home = tempfile::tempdir().expect("home");
@@ -365,14 +388,13 @@ fn init_writes_config_with_release_tag_schema_reference() {
     assert_eq!(out.code, 0, "init failed: {}", out.stderr);
 
     let config = std::fs::read_to_string(repo.path().join(".ito/config.json")).unwrap();
-    let expected_release_tag = expected_release_tag();
-    let expected = format!(
-        "\"$schema\": \"https://raw.githubusercontent.com/withakay/ito/{expected_release_tag}/schemas/ito-config.schema.json\""
-    );
     assert!(
-        config.contains(&expected),
-        "expected generated .ito/config.json to include a release-tag schema reference\nexpected fragment: {expected}\nGot:\n{config}"
+        config.contains("\"$schema\": \"./config.schema.json\""),
+        "expected generated .ito/config.json to reference the local schema\nGot:\n{config}"
     );
+    let schema = std::fs::read_to_string(repo.path().join(".ito/config.schema.json")).unwrap();
+    let schema: serde_json::Value = serde_json::from_str(&schema).expect("schema json");
+    assert_eq!(schema, ito_config::schema::config_schema_json());
 }
 
 /// Verifies that `ito init --help` prints the command usage header.
@@ -907,6 +929,56 @@ fn init_tools_parser_covers_all_and_invalid_id() {
     assert!(out.stderr.contains("Unknown tool id"));
 }
 
+#[test]
+fn init_records_selected_tools_in_project_config() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    fixtures::write_local_ito_skills(repo.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "init",
+            repo.path().to_string_lossy().as_ref(),
+            "--tools",
+            "opencode,claude",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+
+    let config = std::fs::read_to_string(repo.path().join(".ito/config.json")).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&config).unwrap();
+    assert_eq!(
+        json["init"]["tools"],
+        serde_json::json!(["claude", "opencode"])
+    );
+    assert!(json["$schema"].is_string(), "template keys should be kept");
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "init",
+            repo.path().to_string_lossy().as_ref(),
+            "--tools",
+            "claud",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr.contains("Did you mean: claude?"),
+        "stderr={}",
+        out.stderr
+    );
+}
+
 #[test]
 fn init_update_preserves_user_files_and_creates_missing() {
     let base = fixtures::make_empty_repo();
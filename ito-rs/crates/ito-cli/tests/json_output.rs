@@ -0,0 +1,103 @@
+#![cfg(not(windows))]
+
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use ito_test_support::{assert_json_round_trips_sorted, run_rust_candidate};
+use std::path::Path;
+
+const PROPOSAL: &str = "## Why\nFixture\n\n## What Changes\n- None\n\n## Impact\n- None\n";
+
+const ALPHA_DELTA: &str = "## ADDED Requirements\n\n### Requirement: Alpha\nThe system SHALL do alpha.\n\n#### Scenario: Alpha works\n- **WHEN** alpha runs\n- **THEN** it works\n";
+
+/// Fixture files, written in the given order so directory creation order
+/// (and with it, on most filesystems, `read_dir` order) can be varied.
+const FILES: &[(&str, &str)] = &[
+    (
+        ".ito/modules/000_ungrouped/module.md",
+        "# Ungrouped\n\n## Purpose\nModule for ad-hoc changes.\n\n## Scope\n- *\n",
+    ),
+    (
+        ".ito/modules/001_billing/module.md",
+        "# Billing\n\n## Purpose\nBilling changes.\n\n## Scope\n- *\n",
+    ),
+    (".ito/changes/000-01_add-alpha/proposal.md", PROPOSAL),
+    (
+        ".ito/changes/000-01_add-alpha/specs/alpha/spec.md",
+        ALPHA_DELTA,
+    ),
+    (
+        ".ito/changes/000-01_add-alpha/tasks.md",
+        "## 1. Implementation\n- [x] 1.1 Write alpha\n- [ ] 1.2 Test alpha\n",
+    ),
+    (".ito/changes/001-01_add-beta/proposal.md", PROPOSAL),
+    (
+        ".ito/changes/001-01_add-beta/tasks.md",
+        "## 1. Implementation\n- [x] 1.1 Write beta\n",
+    ),
+];
+
+fn make_repo(reversed: bool) -> tempfile::TempDir {
+    let repo = tempfile::tempdir().expect("repo");
+    fixtures::write(repo.path().join("README.md"), "# temp\n");
+    let mut files: Vec<_> = FILES.iter().collect();
+    if reversed {
+        files.reverse();
+    }
+    for (path, contents) in files {
+        fixtures::write(repo.path().join(path), contents);
+    }
+    repo
+}
+
+/// Run `args` against a fresh fixture, check the output is stable JSON, and
+/// return it with volatile values replaced by placeholders.
+fn json_output(args: &[&str], reversed: bool) -> String {
+    let repo = make_repo(reversed);
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    let out = run_rust_candidate(rust_path, args, repo.path(), home.path());
+    assert_eq!(out.code, 0, "stdout={} stderr={}", out.stdout, out.stderr);
+    let value = assert_json_round_trips_sorted(&out.stdout);
+    assert_eq!(value["schema_version"], 1, "{}", out.stdout);
+
+    let mut stdout = out.stdout.trim_end().to_string();
+    for modified in last_modified_values(&value) {
+        stdout = stdout.replace(&modified, "<TIMESTAMP>");
+    }
+    replace_repo_path(&stdout, repo.path())
+}
+
+fn last_modified_values(value: &serde_json::Value) -> Vec<String> {
+    value["changes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|change| change["lastModified"].as_str())
+        .map(str::to_string)
+        .collect()
+}
+
+fn replace_repo_path(text: &str, repo: &Path) -> String {
+    let canonical = repo.canonicalize().expect("canonical repo");
+    text.replace(canonical.to_string_lossy().as_ref(), "<REPO>")
+        .replace(repo.to_string_lossy().as_ref(), "<REPO>")
+}
+
+#[test]
+fn list_json_is_sorted_and_independent_of_creation_order() {
+    let forward = json_output(&["list", "--json"], false);
+    let reversed = json_output(&["list", "--json"], true);
+    assert_eq!(forward, reversed);
+    insta::assert_snapshot!("list_json", forward);
+}
+
+#[test]
+fn tasks_status_json_is_sorted_and_independent_of_creation_order() {
+    let args = ["tasks", "status", "000-01_add-alpha", "--json"];
+    let forward = json_output(&args, false);
+    let reversed = json_output(&args, true);
+    assert_eq!(forward, reversed);
+    insta::assert_snapshot!("tasks_status_json", forward);
+}
@@ -377,3 +377,101 @@ fn list_sort_regression() {
         ]
     );
 }
+
+#[test]
+fn list_status_filter_json_shape_and_status_sort() {
+    let repo = make_repo();
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    write(
+        repo.path()
+            .join(".ito/changes/000-02_mid-partial/.ito.yaml"),
+        "orchestrate:\n  depends_on:\n    - 000-01_old-pending\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["list", "--status", "blocked", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert_eq!(extract_names(&out.stdout), vec!["000-02_mid-partial"]);
+    let parsed: serde_json::Value = serde_json::from_str(&out.stdout).expect("list json");
+    let blocked = &parsed["changes"][0];
+    assert_eq!(blocked["listStatus"], "blocked");
+    assert_eq!(blocked["workStatus"], "ready");
+    assert_eq!(blocked["moduleId"], "000");
+    assert_eq!(
+        blocked["blockedBy"],
+        serde_json::json!(["000-01_old-pending"])
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "list", "--status", "ready", "--status", "complete", "--json",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert_eq!(
+        extract_names(&out.stdout),
+        vec!["000-01_old-pending", "000-03_new-complete"]
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["list", "--sort", "status", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert_eq!(
+        extract_names(&out.stdout),
+        vec![
+            "000-01_old-pending",
+            "000-02_mid-partial",
+            "000-03_new-complete"
+        ]
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["list", "--status", "blocked"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("Blocked"), "stdout={}", out.stdout);
+    assert!(!out.stdout.contains("000-01_old-pending"));
+}
+
+#[test]
+fn list_reports_stale_change_scaffolds_without_listing_them() {
+    let repo = make_repo();
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    let stale = repo.path().join(".ito/changes/.tmp-000-04_crashed-99999");
+    write(stale.join(".ito.yaml"), "schema: spec-driven\n");
+    set_mtime_recursive(&stale, filetime::FileTime::from_unix_time(1_000_000, 0));
+
+    let out = run_rust_candidate(rust_path, &["list", "--json"], repo.path(), home.path());
+    assert_eq!(out.code, 0);
+    assert!(
+        out.stderr
+            .contains("incomplete change scaffold from an interrupted create"),
+        "stderr={}",
+        out.stderr
+    );
+    assert!(out.stderr.contains(".tmp-000-04_crashed-99999"));
+    assert_eq!(
+        extract_names(&out.stdout),
+        vec![
+            "000-01_old-pending",
+            "000-02_mid-partial",
+            "000-03_new-complete"
+        ]
+    );
+}
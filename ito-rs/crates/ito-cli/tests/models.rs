@@ -0,0 +1,79 @@
+use predicates::str::contains;
+use serde_json::Value;
+
+fn make_repo(config: &str) -> tempfile::TempDir {
+    let td = tempfile::tempdir().expect("repo");
+    std::fs::write(td.path().join("README.md"), "# temp\n").unwrap();
+    std::fs::create_dir_all(td.path().join(".ito")).unwrap();
+    std::fs::write(td.path().join(".ito/config.json"), config).unwrap();
+    td
+}
+
+fn models_cmd(repo: &std::path::Path, home: &std::path::Path) -> assert_cmd::Command {
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("ito");
+    cmd.current_dir(repo)
+        .args(["models", "pins"])
+        .env("CI", "1")
+        .env("NO_COLOR", "1")
+        .env("ITO_INTERACTIVE", "0")
+        .env("TERM", "dumb")
+        .env("HOME", home)
+        .env("XDG_CONFIG_HOME", home.join("xdg"))
+        .env("ITO_DISABLE_LOGGING", "1");
+    cmd
+}
+
+#[test]
+fn models_pins_json_reports_provenance() {
+    let repo = make_repo(r#"{"models":{"claude":{"quick":"claude-haiku-4-5"}}}"#);
+    let home = tempfile::tempdir().expect("home");
+
+    let out = models_cmd(repo.path(), home.path())
+        .arg("--json")
+        .output()
+        .unwrap();
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let pins: Value = serde_json::from_slice(&out.stdout).expect("pins json");
+    let pins = pins.as_array().expect("pins array");
+
+    let find = |tool: &str, tier: &str| {
+        pins.iter()
+            .find(|pin| pin["tool"] == tool && pin["tier"] == tier)
+            .unwrap_or_else(|| panic!("missing {tool}/{tier} in {pins:?}"))
+    };
+    let quick = find("claude", "quick");
+    assert_eq!(quick["model"], "claude-haiku-4-5");
+    assert_eq!(quick["source"], "config");
+    let general = find("claude", "general");
+    assert_eq!(general["model"], "sonnet");
+    assert_eq!(general["source"], "default");
+    assert_eq!(find("opencode", "thinking")["source"], "default");
+}
+
+#[test]
+fn models_pins_table_marks_configured_entries() {
+    let repo = make_repo(r#"{"models":{"pi":{"general":"my-model"}}}"#);
+    let home = tempfile::tempdir().expect("home");
+
+    models_cmd(repo.path(), home.path())
+        .assert()
+        .success()
+        .stdout(contains("my-model"))
+        .stdout(contains("config"))
+        .stdout(contains("default"));
+}
+
+#[test]
+fn models_pins_rejects_unknown_harness() {
+    let repo = make_repo(r#"{"models":{"claude-code":{"quick":"haiku"}}}"#);
+    let home = tempfile::tempdir().expect("home");
+
+    models_cmd(repo.path(), home.path())
+        .assert()
+        .failure()
+        .stderr(contains("claude-code"));
+}
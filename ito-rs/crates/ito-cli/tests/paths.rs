@@ -0,0 +1,132 @@
+#![cfg(not(windows))]
+
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use ito_test_support::run_rust_candidate;
+use serde_json::Value;
+use std::path::Path;
+
+fn make_repo_with_custom_ito_dir() -> tempfile::TempDir {
+    let repo = tempfile::tempdir().expect("repo");
+    fixtures::write(repo.path().join("README.md"), "# temp\n");
+    fixtures::write(
+        repo.path().join("ito.json"),
+        "{\"projectPath\": \".work\"}\n",
+    );
+    fixtures::write(
+        repo.path().join(".work/modules/000_ungrouped/module.md"),
+        "# Ungrouped\n\n## Purpose\nModule for ad-hoc changes.\n\n## Scope\n- *\n",
+    );
+    repo
+}
+
+fn path_string(path: &Path) -> String {
+    path.to_string_lossy().to_string()
+}
+
+/// Entries of `kind` in report order.
+fn entries<'a>(report: &'a Value, kind: &str) -> Vec<&'a Value> {
+    report["locations"]
+        .as_array()
+        .expect("locations array")
+        .iter()
+        .filter(|entry| entry["kind"] == kind)
+        .collect()
+}
+
+fn entry<'a>(report: &'a Value, kind: &str) -> &'a Value {
+    let found = entries(report, kind);
+    assert_eq!(found.len(), 1, "expected one {kind} entry: {report}");
+    found[0]
+}
+
+#[test]
+fn paths_json_reports_custom_ito_dir_locations() {
+    let repo = make_repo_with_custom_ito_dir();
+    let root = repo.path().canonicalize().expect("canonical repo");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    let out = run_rust_candidate(rust_path, &["paths", "--json"], &root, home.path());
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let report: Value = serde_json::from_str(&out.stdout).expect("paths json");
+
+    let work = root.join(".work");
+    assert_eq!(report["project_root"], path_string(&root));
+    assert_eq!(report["ito_dir_name"], ".work");
+    assert_eq!(report["ito_path"], path_string(&work));
+
+    let ito_dir = entry(&report, "ito_dir");
+    assert_eq!(ito_dir["path"], path_string(&work));
+    assert_eq!(ito_dir["exists"], true);
+
+    let configs = entries(&report, "config");
+    let repo_config = configs
+        .iter()
+        .find(|c| c["path"] == path_string(&root.join("ito.json")))
+        .expect("ito.json config entry");
+    assert_eq!(repo_config["exists"], true);
+    assert_eq!(repo_config["loaded"], true);
+    assert!(repo_config["size_bytes"].as_u64().unwrap_or(0) > 0);
+    assert!(repo_config["modified"].is_string());
+    assert!(
+        configs
+            .iter()
+            .any(|c| c["path"] == path_string(&work.join("config.json")) && c["loaded"] == false),
+        "{report}"
+    );
+
+    let global = entry(&report, "global_config_dir");
+    assert_eq!(
+        global["path"],
+        path_string(&home.path().join(".config/ito"))
+    );
+    assert_eq!(global["exists"], false);
+
+    assert_eq!(
+        entry(&report, "data_dir")["path"],
+        path_string(&home.path().join("ito"))
+    );
+
+    let ralph = entry(&report, "ralph_state");
+    assert_eq!(ralph["path"], path_string(&work.join(".state/ralph")));
+    assert_eq!(ralph["exists"], false);
+
+    let audit = entry(&report, "audit_log");
+    assert_eq!(audit["path"], Value::Null);
+    assert_eq!(audit["storage"], "internal-branch:ito/internal/audit");
+
+    assert_eq!(
+        entry(&report, "audit_fallback_log")["path"],
+        path_string(&work.join(".state-local/audit/events.jsonl"))
+    );
+    assert_eq!(
+        entry(&report, "transcripts")["path"],
+        path_string(&work.join(".state/transcripts"))
+    );
+}
+
+#[test]
+fn paths_table_lists_locations_with_state() {
+    let repo = make_repo_with_custom_ito_dir();
+    let root = repo.path().canonicalize().expect("canonical repo");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    let out = run_rust_candidate(rust_path, &["paths"], &root, home.path());
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("ito dir: .work"), "{}", out.stdout);
+    let config_line = out
+        .stdout
+        .lines()
+        .find(|line| line.contains(&path_string(&root.join("ito.json"))))
+        .expect("ito.json row");
+    assert!(config_line.contains("loaded"), "{config_line}");
+    assert!(out.stdout.contains("ralph state"), "{}", out.stdout);
+    assert!(
+        out.stdout.contains("internal-branch:ito/internal/audit"),
+        "{}",
+        out.stdout
+    );
+}
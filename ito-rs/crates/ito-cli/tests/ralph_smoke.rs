@@ -3,6 +3,8 @@ use std::path::Path;
 use ito_test_support::pty::{run_pty_interactive, run_pty_interactive_with_env};
 use ito_test_support::run_rust_candidate;
 
+#[path = "ralph_smoke/budget.rs"]
+mod budget;
 #[path = "ralph_smoke/readiness.rs"]
 mod readiness;
 
@@ -14,6 +16,19 @@ fn write(path: impl AsRef<Path>, contents: &str) {
     std::fs::write(path, contents).unwrap();
 }
 
+/// Merge the top-level keys of `patch` into the fixture's `.ito/config.json`,
+/// keeping the integration mode and worktree settings the base repo needs.
+fn merge_config(repo: &Path, patch: &str) {
+    let path = repo.join(".ito/config.json");
+    let mut config: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let patch: serde_json::Value = serde_json::from_str(patch).unwrap();
+    for (key, value) in patch.as_object().unwrap() {
+        config[key] = value.clone();
+    }
+    write(&path, &serde_json::to_string_pretty(&config).unwrap());
+}
+
 fn make_base_repo() -> tempfile::TempDir {
     let td = tempfile::tempdir().expect("repo");
     write(td.path().join("README.md"), "# temp\n");
@@ -434,6 +449,66 @@ fn ralph_stub_harness_writes_state_and_status_works() {
     assert!(out.stdout.contains("Task progress:"));
 }
 
+#[test]
+fn ralph_permissions_profile_is_resolved_and_dropped_rules_are_reported() {
+    let base = make_base_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    reset_repo(repo.path(), base.path());
+    merge_config(
+        repo.path(),
+        r#"{ "permission_profiles": { "read-only": { "deny_paths": ["**"], "allow_commands": ["git log"] } } }"#,
+    );
+    let run = |profile: &str| {
+        run_rust_candidate(
+            rust_path,
+            &[
+                "ralph",
+                "--change",
+                "000-01_test-change",
+                "--harness",
+                "stub",
+                "--permissions-profile",
+                profile,
+                "--no-commit",
+                "--no-interactive",
+                "--max-iterations",
+                "1",
+                "do",
+                "work",
+            ],
+            repo.path(),
+            home.path(),
+        )
+    };
+
+    let out = run("read-onl");
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr
+            .contains("Unknown permissions profile 'read-onl'"),
+        "stderr={}",
+        out.stderr
+    );
+    assert!(
+        out.stderr.contains("Did you mean: read-only?"),
+        "stderr={}",
+        out.stderr
+    );
+
+    let out = run("read-only");
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stderr.contains(
+            "Warning: harness `stub` does not support permission policies; dropped: deny edit **, allow command git log"
+        ),
+        "stderr={}",
+        out.stderr
+    );
+}
+
 #[test]
 fn ralph_change_flag_supports_shorthand_resolution() {
     let base = make_base_repo();
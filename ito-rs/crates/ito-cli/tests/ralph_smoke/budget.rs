@@ -0,0 +1,105 @@
+use super::*;
+
+fn run_with_model(
+    repo: &Path,
+    home: &Path,
+    model: &str,
+    strict: bool,
+) -> ito_test_support::CmdOutput {
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    let mut args = vec![
+        "ralph",
+        "--change",
+        "000-01_test-change",
+        "--harness",
+        "stub",
+        "--model",
+        model,
+        "--no-commit",
+        "--no-interactive",
+        "--max-iterations",
+        "1",
+    ];
+    if strict {
+        args.push("--strict-budget");
+    }
+    args.extend(["do", "work"]);
+    run_rust_candidate(rust_path, &args, repo, home)
+}
+
+fn write_context_windows(repo: &Path) {
+    merge_config(
+        repo,
+        r#"{ "stats": { "contextWindows": { "tiny": 10, "roomy": 1000000 } } }"#,
+    );
+}
+
+#[test]
+fn ralph_warns_when_prompt_exceeds_the_model_context_window() {
+    let base = make_base_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    reset_repo(repo.path(), base.path());
+    write_context_windows(repo.path());
+
+    let out = run_with_model(repo.path(), home.path(), "tiny", false);
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stderr.contains("Warning: Prompt is an estimated ")
+            && out
+                .stderr
+                .contains(" tokens, over the 10-token context window of model 'tiny'"),
+        "stderr={}",
+        out.stderr
+    );
+
+    let transcript = std::fs::read_to_string(
+        repo.path()
+            .join(".ito/.state/transcripts/000-01_test-change/1.md"),
+    )
+    .expect("transcript");
+    assert!(transcript.contains("- Prompt tokens (estimated): "));
+    assert!(transcript.contains("- Context window: 10 tokens"));
+
+    let usage = std::fs::read_to_string(repo.path().join(".ito/.state/stats/harness-usage.jsonl"))
+        .expect("usage log");
+    assert!(usage.contains(r#""contextWindow":10"#), "usage={usage}");
+    assert!(
+        usage.contains(r#""promptTokensEstimate":"#),
+        "usage={usage}"
+    );
+}
+
+#[test]
+fn ralph_strict_budget_fails_before_running_the_harness() {
+    let base = make_base_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    reset_repo(repo.path(), base.path());
+    write_context_windows(repo.path());
+
+    let out = run_with_model(repo.path(), home.path(), "tiny", true);
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr.contains(
+            "over the 10-token context window of model 'tiny'; not sending it (--strict-budget)"
+        ),
+        "stderr={}",
+        out.stderr
+    );
+    assert!(
+        !repo
+            .path()
+            .join(".ito/.state/transcripts/000-01_test-change/1.md")
+            .exists()
+    );
+
+    // A model whose window fits the prompt runs normally, even in strict mode.
+    let out = run_with_model(repo.path(), home.path(), "roomy", true);
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        !out.stderr.contains("context window"),
+        "stderr={}",
+        out.stderr
+    );
+}
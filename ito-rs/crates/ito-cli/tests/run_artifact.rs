@@ -0,0 +1,126 @@
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use ito_test_support::run_rust_candidate;
+
+const CHANGE_ID: &str = "000-01_test-change";
+
+fn design_path(repo: &std::path::Path) -> std::path::PathBuf {
+    repo.join(".ito/changes").join(CHANGE_ID).join("design.md")
+}
+
+#[test]
+fn run_artifact_dry_run_prints_prompt_without_running_harness() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["run", "artifact", CHANGE_ID, "design", "--dry-run"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stdout
+            .contains(&format!("# Generate `design` for change `{CHANGE_ID}`")),
+        "stdout={}",
+        out.stdout
+    );
+    assert!(out.stdout.contains("### proposal"), "stdout={}", out.stdout);
+    assert!(!design_path(repo.path()).exists());
+}
+
+#[test]
+fn run_artifact_succeeds_when_harness_writes_the_artifact() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    let script = home.path().join("stub.json");
+    std::fs::write(
+        &script,
+        format!(
+            r#"[{{"stdout": "done\n", "writes": {{".ito/changes/{CHANGE_ID}/design.md": "Design body\n"}}}}]"#
+        ),
+    )
+    .expect("write stub script");
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "run",
+            "artifact",
+            CHANGE_ID,
+            "design",
+            "--harness",
+            "stub",
+            "--stub-script",
+            script.to_str().expect("utf-8 path"),
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stdout
+            .contains(&format!("Generated 'design' for {CHANGE_ID}")),
+        "stdout={}",
+        out.stdout
+    );
+    assert_eq!(
+        std::fs::read_to_string(design_path(repo.path())).expect("design"),
+        "Design body\n"
+    );
+}
+
+#[test]
+fn run_artifact_fails_when_artifact_is_not_produced() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    let script = home.path().join("stub.json");
+    std::fs::write(
+        &script,
+        r#"[{"stdout": "", "stderr": "model unavailable\n", "exitCode": 2}]"#,
+    )
+    .expect("write stub script");
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "run",
+            "artifact",
+            CHANGE_ID,
+            "design",
+            "--harness",
+            "stub",
+            "--stub-script",
+            script.to_str().expect("utf-8 path"),
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr.contains("model unavailable"),
+        "stderr={}",
+        out.stderr
+    );
+    assert!(
+        out.stderr.contains("Artifact 'design' was not produced")
+            && out.stderr.contains("exited with code 2"),
+        "stderr={}",
+        out.stderr
+    );
+    assert!(!design_path(repo.path()).exists());
+}
@@ -0,0 +1,144 @@
+#![cfg(feature = "web")]
+
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use assert_cmd::Command;
+use serde_json::{Value, json};
+
+fn frame(request: &Value) -> String {
+    let body = request.to_string();
+    format!("Content-Length: {}\r\n\r\n{body}", body.len())
+}
+
+fn parse_frames(mut out: &str) -> Vec<Value> {
+    let mut messages = Vec::new();
+    while let Some((headers, rest)) = out.split_once("\r\n\r\n") {
+        let len: usize = headers
+            .trim()
+            .strip_prefix("Content-Length: ")
+            .expect("content-length header")
+            .parse()
+            .expect("length");
+        messages.push(serde_json::from_str(&rest[..len]).expect("json body"));
+        out = &rest[len..];
+    }
+    messages
+}
+
+fn run_session(requests: &[Value]) -> Vec<Value> {
+    let repo = fixtures::make_repo_all_valid();
+    let home = tempfile::tempdir().expect("home");
+    let input: String = requests.iter().map(frame).collect();
+
+    let out = Command::cargo_bin("ito")
+        .expect("binary")
+        .current_dir(repo.path())
+        .env("HOME", home.path())
+        .env("XDG_CONFIG_HOME", home.path().join(".config"))
+        .env("XDG_DATA_HOME", home.path())
+        .env("NO_COLOR", "1")
+        .args(["serve", "--stdio"])
+        .write_stdin(input)
+        .output()
+        .expect("run ito serve --stdio");
+    assert!(
+        out.status.success(),
+        "stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    parse_frames(&String::from_utf8_lossy(&out.stdout))
+}
+
+fn request(id: u64, method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+}
+
+#[test]
+fn stdio_serves_read_only_methods_until_eof() {
+    let responses = run_session(&[
+        request(1, "initialize", json!({ "protocolVersion": 1 })),
+        request(2, "changes/list", json!({})),
+        request(3, "changes/status", json!({ "change": "000-01" })),
+        request(
+            4,
+            "changes/instructions",
+            json!({ "change": "000-01_test-change", "artifact": "proposal" }),
+        ),
+        request(5, "tasks/list", json!({ "change": "000-01_test-change" })),
+        request(
+            6,
+            "validate/spec",
+            json!({ "spec": "alpha", "strict": true }),
+        ),
+        request(7, "schemas/list", json!({})),
+        json!({ "jsonrpc": "2.0", "method": "changes/list" }),
+        request(8, "changes/frobnicate", json!({})),
+    ]);
+
+    // The notification (no id) gets no response.
+    assert_eq!(responses.len(), 8, "{responses:#?}");
+    let by_id = |id: u64| {
+        responses
+            .iter()
+            .find(|r| r["id"] == id)
+            .unwrap_or_else(|| panic!("response {id} missing: {responses:#?}"))
+    };
+
+    let init = &by_id(1)["result"];
+    assert_eq!(init["protocolVersion"], 1);
+    assert!(
+        init["methods"]
+            .as_array()
+            .expect("methods")
+            .contains(&json!("tasks/list"))
+    );
+
+    assert_eq!(
+        by_id(2)["result"]["changes"][0]["name"],
+        "000-01_test-change"
+    );
+    assert_eq!(by_id(3)["result"]["changeName"], "000-01_test-change");
+    assert_eq!(by_id(4)["result"]["artifactId"], "proposal");
+
+    let tasks = &by_id(5)["result"];
+    assert_eq!(tasks["progress"]["complete"], 1);
+    assert_eq!(tasks["tasks"][0]["id"], "1.1");
+
+    assert_eq!(by_id(6)["result"]["valid"], true);
+    assert!(
+        by_id(7)["result"]["schemas"]
+            .as_array()
+            .expect("schemas")
+            .iter()
+            .any(|s| s["name"] == "spec-driven")
+    );
+
+    let unknown = &by_id(8)["error"];
+    assert_eq!(unknown["code"], -32601);
+    assert!(
+        unknown["message"]
+            .as_str()
+            .expect("message")
+            .contains("changes/frobnicate")
+    );
+}
+
+#[test]
+fn stdio_maps_errors_and_rejects_incompatible_protocols() {
+    let responses = run_session(&[
+        request(1, "initialize", json!({ "protocolVersion": 99 })),
+        request(2, "changes/status", json!({ "change": "999-99_missing" })),
+        request(3, "tasks/list", json!({})),
+    ]);
+
+    assert_eq!(responses[0]["error"]["code"], -32001);
+    assert_eq!(responses[1]["error"]["code"], -32000);
+    assert!(
+        responses[1]["error"]["message"]
+            .as_str()
+            .expect("message")
+            .contains("Change '999-99_missing' not found")
+    );
+    assert_eq!(responses[2]["error"]["code"], -32602);
+}
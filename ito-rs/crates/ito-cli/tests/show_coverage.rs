@@ -0,0 +1,100 @@
+mod support;
+
+use ito_test_support::run_rust_candidate;
+use support::write;
+
+fn make_repo() -> tempfile::TempDir {
+    let td = tempfile::tempdir().expect("repo");
+    write(td.path().join("README.md"), "# temp\n");
+    write(
+        td.path().join(".ito/specs/auth/spec.md"),
+        "# Auth\n\n## Purpose\nAuth purpose long enough for strict mode.\n\n## Requirements\n\n### Requirement: Login\nThe system SHALL log in.\n\n#### Scenario: Login\n- **WHEN** login\n- **THEN** ok\n\n### Requirement: Logout\nThe system SHALL log out.\n\n#### Scenario: Logout\n- **WHEN** logout\n- **THEN** ok\n",
+    );
+    write(
+        td.path().join(".ito/changes/000-01_login/proposal.md"),
+        "# Proposal\n",
+    );
+    write(
+        td.path()
+            .join(".ito/changes/000-01_login/specs/auth/spec.md"),
+        "## MODIFIED Requirements\n\n### Requirement: Login\nThe system SHALL log in quickly.\n\n#### Scenario: Login\n- **WHEN** login\n- **THEN** ok\n",
+    );
+    td
+}
+
+#[test]
+fn show_coverage_reports_covered_and_uncovered_requirements() {
+    let base = make_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    support::reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["show", "coverage", "auth"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr: {}", out.stderr);
+    assert!(
+        out.stdout.contains("000-01_login (MODIFIED)"),
+        "{}",
+        out.stdout
+    );
+    assert!(out.stdout.contains("Logout  (uncovered)"), "{}", out.stdout);
+    assert!(
+        out.stdout
+            .contains("2 requirements: 1 covered, 1 uncovered, 0 orphaned references"),
+        "{}",
+        out.stdout
+    );
+}
+
+#[test]
+fn show_coverage_json_lists_touching_changes() {
+    let base = make_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    support::reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["show", "coverage", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr: {}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("json");
+    let spec = &v["specs"][0];
+    assert_eq!(spec["specId"], "auth");
+    assert_eq!(
+        spec["requirements"][0]["touchedBy"][0]["changeId"],
+        "000-01_login"
+    );
+    assert_eq!(
+        spec["requirements"][1]["touchedBy"]
+            .as_array()
+            .map(|a| a.len()),
+        Some(0)
+    );
+    assert_eq!(v["summary"]["uncovered"], 1);
+}
+
+#[test]
+fn show_coverage_unknown_spec_fails() {
+    let base = make_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    support::reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["show", "coverage", "missing"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+}
@@ -0,0 +1,88 @@
+mod support;
+
+use ito_test_support::run_rust_candidate;
+use support::write;
+
+fn make_repo() -> tempfile::TempDir {
+    let td = tempfile::tempdir().expect("repo");
+    write(td.path().join("README.md"), "# temp\n");
+    write(
+        td.path().join(".ito/changes/000-01_login/proposal.md"),
+        "# Login\n\n## Why\nLogin is slow.\n",
+    );
+    write(
+        td.path().join(".ito/changes/000-01_login/tasks.md"),
+        "- [ ] Add cache\n",
+    );
+    write(
+        td.path()
+            .join(".ito/changes/archive/2026-01-01-000-02_logout/proposal.md"),
+        "# Login\n\n## Why\nLogin is fast.\n",
+    );
+    write(
+        td.path()
+            .join(".ito/changes/archive/2026-01-01-000-02_logout/design.md"),
+        "# Design\n",
+    );
+    td
+}
+
+#[test]
+fn show_diff_prints_artifact_matrix_and_hunks() {
+    let base = make_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    support::reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["show", "diff", "000-01_login", "000-02_logout"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr: {}", out.stderr);
+    assert!(out.stdout.contains("A: 000-01_login"), "{}", out.stdout);
+    assert!(out.stdout.contains("B: 000-02_logout"), "{}", out.stdout);
+    assert!(
+        out.stdout.contains("design            - B"),
+        "{}",
+        out.stdout
+    );
+    assert!(
+        out.stdout.contains("-Login is slow.\n+Login is fast.\n"),
+        "{}",
+        out.stdout
+    );
+    assert!(out.stdout.contains("only in A: tasks.md"), "{}", out.stdout);
+}
+
+#[test]
+fn show_diff_json_filters_by_artifact() {
+    let base = make_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    support::reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "show",
+            "diff",
+            "000-01_login",
+            "000-02_logout",
+            "--artifact",
+            "proposal",
+            "--json",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr: {}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("json");
+    assert_eq!(v["changeA"], "000-01_login");
+    assert_eq!(v["artifacts"].as_array().map(|a| a.len()), Some(1));
+    assert_eq!(v["artifacts"][0]["files"][0]["status"], "changed");
+    assert_eq!(v["tasks"]["onlyInA"][0]["name"], "Add cache");
+}
@@ -0,0 +1,71 @@
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use ito_test_support::run_rust_candidate;
+
+const CHANGE_ID: &str = "000-01_test-change";
+
+#[test]
+fn spec_rename_dry_run_then_apply() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    let change_dir = repo.path().join(".ito/changes").join(CHANGE_ID);
+    fixtures::write(
+        change_dir.join("proposal.md"),
+        "## Why\nTest fixture\n\n## What Changes\n- Extends alpha\n\n## Impact\n- alpha spec\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["spec", "rename", "alpha", "beta", "--dry-run"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stdout
+            .contains("Would move .ito/specs/alpha -> .ito/specs/beta"),
+        "stdout={}",
+        out.stdout
+    );
+    assert!(
+        out.stdout.contains(&format!(
+            "Would update .ito/changes/{CHANGE_ID}/proposal.md (2 references)"
+        )),
+        "stdout={}",
+        out.stdout
+    );
+    assert!(repo.path().join(".ito/specs/alpha/spec.md").is_file());
+    assert!(!repo.path().join(".ito/specs/beta").exists());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["spec", "rename", "alpha", "beta"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("Renamed spec 'alpha' to 'beta'"));
+    assert!(repo.path().join(".ito/specs/beta/spec.md").is_file());
+    assert!(change_dir.join("specs/beta/spec.md").is_file());
+    assert!(!change_dir.join("specs/alpha").exists());
+    let proposal = std::fs::read_to_string(change_dir.join("proposal.md")).expect("proposal");
+    assert!(proposal.contains("- Extends beta\n"), "proposal={proposal}");
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["spec", "rename", "missing", "gamma"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr.contains("Spec 'missing' not found"),
+        "stderr={}",
+        out.stderr
+    );
+}
@@ -43,3 +43,188 @@ fn stats_counts_command_end_events() {
         .stdout(contains("ito.tasks.status: 1"))
         .stdout(contains("ito.init: 0"));
 }
+
+fn stats_cost_cmd(repo: &std::path::Path, home: &std::path::Path) -> assert_cmd::Command {
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("ito");
+    cmd.current_dir(repo)
+        .args(["stats", "cost"])
+        .env("CI", "1")
+        .env("NO_COLOR", "1")
+        .env("ITO_INTERACTIVE", "0")
+        .env("TERM", "dumb")
+        .env("HOME", home)
+        .env("XDG_CONFIG_HOME", home.join("xdg"))
+        .env("ITO_DISABLE_LOGGING", "1");
+    cmd
+}
+
+fn write_usage_fixture(repo: &std::path::Path) {
+    let ito = repo.join(".ito");
+    std::fs::create_dir_all(ito.join(".state/stats")).unwrap();
+    std::fs::write(
+        ito.join("config.json"),
+        r#"{"stats":{"pricing":{"sonnet":{"input_per_mtok":3.0,"output_per_mtok":15.0,"cache_read_per_mtok":0.3}}}}"#,
+    )
+    .unwrap();
+    let lines = [
+        r#"{"timestamp":1000,"source":"ralph","changeId":"014-03_cost","harness":"claude","model":"sonnet","usage":{"input_tokens":1000000,"output_tokens":100000,"cache_read_tokens":0}}"#,
+        r#"{"timestamp":2000,"source":"ralph","changeId":"014-03_cost","harness":"claude","model":"sonnet","usage":{"input_tokens":0,"output_tokens":100000,"cache_read_tokens":1000000}}"#,
+        r#"{"timestamp":3000,"source":"run","changeId":"014-03_cost","harness":"codex","model":"gpt-x"}"#,
+        r#"{"timestamp":4000,"source":"ralph","changeId":"014-04_other","harness":"claude","model":"sonnet","usage":{"input_tokens":500000,"output_tokens":0,"cache_read_tokens":0}}"#,
+    ];
+    std::fs::write(
+        ito.join(".state/stats/harness-usage.jsonl"),
+        format!("{}\n", lines.join("\n")),
+    )
+    .unwrap();
+}
+
+#[test]
+fn stats_cost_groups_by_change_and_model_and_marks_unknown() {
+    let repo = make_repo();
+    let home = tempfile::tempdir().expect("home");
+    write_usage_fixture(repo.path());
+
+    stats_cost_cmd(repo.path(), home.path())
+        .assert()
+        .success()
+        .stdout(contains("014-03_cost  sonnet  runs 2"))
+        .stdout(contains("cost $6.3000"))
+        .stdout(contains(
+            "014-03_cost  gpt-x  runs 1  tokens unknown  cost unknown",
+        ))
+        .stdout(contains("Total: $7.8000 (plus unknown"));
+}
+
+#[test]
+fn stats_cost_json_filters_by_change() {
+    let repo = make_repo();
+    let home = tempfile::tempdir().expect("home");
+    write_usage_fixture(repo.path());
+
+    let out = stats_cost_cmd(repo.path(), home.path())
+        .args(["--change", "014-04", "--json"])
+        .output()
+        .expect("run stats cost");
+    assert!(out.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    let groups = report["groups"].as_array().expect("groups");
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0]["change_id"], "014-04_other");
+    assert_eq!(groups[0]["usage"]["input_tokens"], 500000);
+    assert_eq!(report["total_cost_usd"], 1.5);
+    assert_eq!(report["incomplete"], false);
+}
+
+#[test]
+fn stats_cost_reports_empty_log() {
+    let repo = make_repo();
+    let home = tempfile::tempdir().expect("home");
+
+    stats_cost_cmd(repo.path(), home.path())
+        .assert()
+        .success()
+        .stdout(contains("No harness runs recorded."));
+}
+
+fn ito_cmd(repo: &std::path::Path, home: &std::path::Path, args: &[&str]) -> assert_cmd::Command {
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("ito");
+    cmd.current_dir(repo)
+        .args(args)
+        .env("CI", "1")
+        .env("NO_COLOR", "1")
+        .env("ITO_INTERACTIVE", "0")
+        .env("TERM", "dumb")
+        .env("HOME", home)
+        .env("XDG_CONFIG_HOME", home.join("xdg"))
+        .env("ITO_DISABLE_LOGGING", "1")
+        .env_remove("ITO_AGENT_HARNESS");
+    cmd
+}
+
+#[test]
+fn record_skill_then_stats_skills_counts_per_skill_and_harness() {
+    let repo = make_repo();
+    let home = tempfile::tempdir().expect("home");
+    std::fs::create_dir_all(repo.path().join(".ito")).unwrap();
+
+    let record = |args: &[&str]| {
+        let mut full = vec!["util", "record-skill"];
+        full.extend_from_slice(args);
+        ito_cmd(repo.path(), home.path(), &full).assert().success();
+    };
+    record(&[
+        "ito-apply",
+        "--harness",
+        "claude",
+        "--change",
+        "014-03_cost",
+    ]);
+    record(&["ito-apply", "--harness", "opencode"]);
+    record(&["ito-aply", "--harness", "opencode"]);
+    ito_cmd(
+        repo.path(),
+        home.path(),
+        &[
+            "util",
+            "record-skill",
+            "--harness",
+            "claude",
+            "--hook-payload",
+        ],
+    )
+    .write_stdin(r#"{"tool_name":"Skill","tool_input":{"skill":"ito-apply"}}"#)
+    .assert()
+    .success();
+
+    let out = ito_cmd(repo.path(), home.path(), &["stats", "skills", "--json"])
+        .output()
+        .expect("run stats skills");
+    assert!(out.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(report["total"], 4);
+    assert_eq!(report["unknown"], 1);
+    assert_eq!(report["harnesses"]["claude"], 2);
+    assert_eq!(report["harnesses"]["opencode"], 2);
+    let skills = report["skills"].as_array().expect("skills");
+    assert_eq!(skills[0]["skill_id"], "ito-apply");
+    assert_eq!(skills[0]["invocations"], 3);
+    assert_eq!(skills[1]["skill_id"], "ito-aply");
+    assert_eq!(skills[1]["known"], false);
+
+    ito_cmd(repo.path(), home.path(), &["stats", "skills"])
+        .assert()
+        .success()
+        .stdout(contains("ito-apply: 3  [claude 2, opencode 1]"))
+        .stdout(contains("ito-aply: 1  [opencode 1]  (unknown skill)"));
+}
+
+#[test]
+fn record_skill_never_fails_the_hook() {
+    let repo = make_repo();
+    let home = tempfile::tempdir().expect("home");
+
+    // Outside an Ito project nothing is recorded or created.
+    ito_cmd(
+        repo.path(),
+        home.path(),
+        &["util", "record-skill", "ito-apply"],
+    )
+    .assert()
+    .success();
+    assert!(!repo.path().join(".ito").exists());
+
+    // Malformed payloads are logged to the debug file instead of failing.
+    std::fs::create_dir_all(repo.path().join(".ito")).unwrap();
+    ito_cmd(
+        repo.path(),
+        home.path(),
+        &["util", "record-skill", "--hook-payload"],
+    )
+    .write_stdin("not json")
+    .assert()
+    .success();
+    let log = std::fs::read_to_string(repo.path().join(".ito/.state/stats/record-skill.log"))
+        .expect("debug log");
+    assert!(log.contains("parsing hook payload"), "{log}");
+}
@@ -263,7 +263,7 @@ pub(crate) fn installed_orchestrator_agent_paths() -> Vec<String> {
 /// let tmp = tempfile::tempdir().unwrap();
 /// run_git(tmp.path(), &["--version"]);
 /// ```
-fn run_git(repo: &Path, args: &[&str]) {
+pub(crate) fn run_git(repo: &Path, args: &[&str]) {
     let output = std::process::Command::new("git")
         .args(args)
         .current_dir(repo)
@@ -0,0 +1,189 @@
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use std::path::Path;
+
+use ito_test_support::{CmdOutput, rust_candidate_command};
+
+const AGENT_ENV: [&str; 3] = [
+    "ITO_AGENT_HARNESS",
+    "ITO_AGENT_MODEL",
+    "ITO_AGENT_ITERATION",
+];
+
+fn run_cli(
+    program: &Path,
+    args: &[&str],
+    cwd: &Path,
+    home: &Path,
+    env: &[(&str, &str)],
+) -> CmdOutput {
+    let mut cmd = rust_candidate_command(program);
+    cmd.args(args);
+    cmd.current_dir(cwd);
+    cmd.env("CI", "1");
+    cmd.env("NO_COLOR", "1");
+    cmd.env("ITO_INTERACTIVE", "0");
+    cmd.env("TERM", "dumb");
+    cmd.env("HOME", home);
+    cmd.env("XDG_CONFIG_HOME", home.join(".config"));
+    cmd.env("XDG_DATA_HOME", home);
+    for key in AGENT_ENV {
+        cmd.env_remove(key);
+    }
+    for (key, value) in env {
+        cmd.env(key, value);
+    }
+
+    let out = cmd.output().expect("command should run");
+    CmdOutput {
+        code: out.status.code().unwrap_or(1),
+        stdout: String::from_utf8_lossy(&out.stdout).to_string(),
+        stderr: String::from_utf8_lossy(&out.stderr).to_string(),
+    }
+}
+
+fn setup_change(repo: &Path, home: &Path) {
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    std::fs::create_dir_all(repo.join(".ito/changes/test-change")).unwrap();
+    let out = run_cli(
+        rust_path,
+        &["tasks", "init", "test-change"],
+        repo,
+        home,
+        &[],
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    fixtures::integrate_change_for_execution(repo, "test-change");
+}
+
+fn history_json(repo: &Path, home: &Path, task_id: &str) -> Vec<serde_json::Value> {
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    let out = run_cli(
+        rust_path,
+        &["tasks", "history", "test-change", task_id, "--json"],
+        repo,
+        home,
+        &[],
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("history json");
+    let tasks = v["tasks"].as_array().expect("tasks array");
+    assert_eq!(tasks.len(), 1, "stdout={}", out.stdout);
+    assert_eq!(tasks[0]["task_id"], task_id);
+    tasks[0]["events"].as_array().expect("events array").clone()
+}
+
+#[test]
+fn cli_completed_task_is_attributed_to_the_human() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    fixtures::reset_repo(repo.path(), base.path());
+    setup_change(repo.path(), home.path());
+
+    let out = run_cli(
+        rust_path,
+        &["tasks", "complete", "test-change", "1.1"],
+        repo.path(),
+        home.path(),
+        &[],
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+
+    let events = history_json(repo.path(), home.path(), "1.1");
+    let last = events.last().expect("completion event");
+    assert_eq!(last["to"], "complete");
+    assert_eq!(last["actor"], "cli");
+    assert_eq!(last["by"], "@test-user");
+    assert_eq!(last["actor_label"], "human @test-user");
+    assert!(last["agent"].is_null());
+}
+
+#[test]
+fn ralph_completed_task_is_attributed_to_the_agent() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    fixtures::reset_repo(repo.path(), base.path());
+    setup_change(repo.path(), home.path());
+
+    // Ralph passes these variables to the harness, whose `ito` calls inherit them.
+    let out = run_cli(
+        rust_path,
+        &["tasks", "complete", "test-change", "1.1"],
+        repo.path(),
+        home.path(),
+        &[
+            ("ITO_AGENT_HARNESS", "opencode"),
+            ("ITO_AGENT_MODEL", "gpt-5"),
+            ("ITO_AGENT_ITERATION", "3"),
+        ],
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+
+    let events = history_json(repo.path(), home.path(), "1.1");
+    let last = events.last().expect("completion event");
+    assert_eq!(last["actor"], "agent");
+    assert_eq!(last["by"], "@opencode");
+    assert_eq!(last["agent"]["harness"], "opencode");
+    assert_eq!(last["agent"]["model"], "gpt-5");
+    assert_eq!(last["agent"]["iteration"], 3);
+    assert_eq!(
+        last["actor_label"],
+        "agent opencode (model gpt-5, iteration 3)"
+    );
+}
+
+#[test]
+fn history_renders_transitions_in_order() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    fixtures::reset_repo(repo.path(), base.path());
+    setup_change(repo.path(), home.path());
+
+    for action in ["start", "shelve", "unshelve"] {
+        let out = run_cli(
+            rust_path,
+            &["tasks", action, "test-change", "1.1"],
+            repo.path(),
+            home.path(),
+            &[],
+        );
+        assert_eq!(out.code, 0, "{action} stderr={}", out.stderr);
+    }
+
+    let events = history_json(repo.path(), home.path(), "1.1");
+    let transitions: Vec<&str> = events
+        .iter()
+        .map(|e| e["to"].as_str().unwrap_or(""))
+        .collect();
+    assert_eq!(transitions, ["in-progress", "shelved", "pending"]);
+
+    let out = run_cli(
+        rust_path,
+        &["tasks", "history", "test-change", "1.1"],
+        repo.path(),
+        home.path(),
+        &[],
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stdout.starts_with("Task 1.1\n"),
+        "stdout={}",
+        out.stdout
+    );
+    let started = out.stdout.find("pending -> in-progress").expect("start");
+    let shelved = out.stdout.find("-> shelved").expect("shelve");
+    let unshelved = out.stdout.find("shelved -> pending").expect("unshelve");
+    assert!(
+        started < shelved && shelved < unshelved,
+        "stdout={}",
+        out.stdout
+    );
+    assert!(out.stdout.contains("human @test-user"));
+}
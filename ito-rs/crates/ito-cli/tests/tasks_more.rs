@@ -583,3 +583,185 @@ fn tasks_json_lists_are_sorted_by_task_id() {
         .collect();
     assert_eq!(show_ids, vec!["1.1", "1.2"]);
 }
+
+#[test]
+fn tasks_lint_reports_structural_problems_with_codes() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    fixtures::write(
+        repo.path().join(".ito/changes/test-change/tasks.md"),
+        "# Tasks for: test-change\n\n## Wave 1\n\n- **Depends On**: None\n\n### Task 1.1: First\n- **Dependencies**: 9.9\n- **Action**: do it\n- **Verify**: `cargo test`\n- **Done When**: works\n- **Status**: [ ] pending\n\n### Task 1.1: Again\n- **Dependencies**: None\n- **Action**: again\n- **Status**: [ ] pending\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["tasks", "lint", "test-change"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stdout
+            .contains("tasks.md:7: error [unknown-dependency]")
+    );
+    assert!(
+        out.stdout
+            .contains("tasks.md:14: error [duplicate-task-id]")
+    );
+    assert!(out.stdout.contains("tasks.md:14: warning [empty-verify]"));
+    assert!(out.stdout.contains("2 error(s), 2 warning(s)"));
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["tasks", "lint", "test-change", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("lint json");
+    assert_eq!(v["errors"], 2);
+    assert_eq!(v["diagnostics"][0]["code"], "unknown-dependency");
+    assert_eq!(v["diagnostics"][0]["line"], 7);
+}
+
+#[test]
+fn tasks_plan_batches_ready_tasks_without_file_overlap() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    fixtures::write(
+        repo.path().join(".ito/changes/test-change/tasks.md"),
+        "# Tasks for: test-change\n\n## Wave 1\n\n- **Depends On**: None\n\n### Task 1.1: Core\n- **Files**: `src/lib.rs`\n- **Dependencies**: None\n- **Action**: edit lib\n- **Verify**: `cargo test`\n- **Status**: [ ] pending\n\n### Task 1.2: Also core\n- **Files**: `src/lib.rs, src/a.rs`\n- **Dependencies**: None\n- **Action**: edit lib again\n- **Status**: [ ] pending\n\n### Task 1.3: Docs\n- **Files**: `docs/a.md`\n- **Dependencies**: None\n- **Action**: write docs\n- **Status**: [ ] pending\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["tasks", "plan", "test-change", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("plan json");
+    let batches: Vec<Vec<&str>> = v["batches"]
+        .as_array()
+        .expect("batches array")
+        .iter()
+        .map(|b| {
+            b["tasks"]
+                .as_array()
+                .expect("tasks array")
+                .iter()
+                .map(|t| t["id"].as_str().expect("task id"))
+                .collect()
+        })
+        .collect();
+    assert_eq!(batches, vec![vec!["1.1", "1.3"], vec!["1.2"]]);
+    assert_eq!(v["batches"][0]["tasks"][0]["verify"], "cargo test");
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["tasks", "plan", "test-change", "--max-parallel", "1"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("## Batch 3"), "{}", out.stdout);
+    assert!(out.stdout.contains("### 1.3: Docs"), "{}", out.stdout);
+}
+
+#[test]
+fn tasks_status_indents_nested_checkbox_subtasks() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    let change_dir = repo.path().join(".ito/changes/test-change");
+    std::fs::create_dir_all(&change_dir).unwrap();
+    fixtures::write(
+        change_dir.join("tasks.md"),
+        "## Tasks\n- [ ] 1 Parent\n  - [x] 1.1 Done child\n  - [ ] 1.2 Open child\n",
+    );
+    fixtures::integrate_change_for_execution(repo.path(), "test-change");
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["tasks", "status", "test-change"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("1/2 complete"), "{}", out.stdout);
+    assert!(
+        out.stdout
+            .contains("  - 1: Parent\n    - 1.2: Open child\n"),
+        "{}",
+        out.stdout
+    );
+}
+
+#[test]
+fn tasks_release_returns_stale_in_progress_tasks_to_pending() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    fixtures::write(
+        repo.path().join(".ito/changes/test-change/tasks.md"),
+        "# Tasks for: test-change\n\n## Wave 1\n\n- **Depends On**: None\n\n### Task 1.1: Abandoned\n- **Dependencies**: None\n- **Action**: edit lib\n- **Updated At**: 2020-01-01\n- **Status**: [>] in-progress\n\n### Task 1.2: Waiting\n- **Dependencies**: None\n- **Action**: write docs\n- **Updated At**: 2020-01-01\n- **Status**: [ ] pending\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["tasks", "status", "test-change"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("in-progress, stale"), "{}", out.stdout);
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["tasks", "release", "test-change", "1.2"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["tasks", "release", "test-change", "--all-stale", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("release json");
+    assert_eq!(v["threshold"], "24h");
+    assert_eq!(v["released"][0]["task_id"], "1.1");
+
+    let tasks = std::fs::read_to_string(repo.path().join(".ito/changes/test-change/tasks.md"))
+        .expect("tasks.md");
+    assert!(!tasks.contains("[>] in-progress"), "{tasks}");
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["tasks", "history", "test-change", "1.1", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    let v: serde_json::Value = serde_json::from_str(&out.stdout).expect("history json");
+    let event = &v["tasks"][0]["events"][0];
+    assert_eq!(event["from"], "in-progress");
+    assert_eq!(event["to"], "pending");
+}
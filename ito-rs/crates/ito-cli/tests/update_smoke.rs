@@ -434,3 +434,103 @@ fn update_refreshes_codex_audit_instruction_assets() {
     assert!(instruction.contains("stop and request user guidance"));
     assert!(!instruction.contains("stale instruction"));
 }
+
+#[test]
+fn update_refreshes_only_tools_recorded_by_init() {
+    let repo = tempfile::tempdir().expect("repo");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    write(repo.path().join("README.md"), "# temp\n");
+    write_local_ito_skills(repo.path());
+    write(
+        repo.path().join(".ito/config.json"),
+        "{\n  \"init\": {\"tools\": [\"opencode\"]}\n}\n",
+    );
+
+    let out = run_rust_candidate(rust_path, &["update", "."], repo.path(), home.path());
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+
+    assert!(repo.path().join(".opencode/plugins/ito-skills.js").exists());
+    assert!(!repo.path().join(".claude").exists());
+    assert!(!repo.path().join(".codex").exists());
+    assert!(!repo.path().join(".pi").exists());
+}
+
+#[test]
+fn update_without_recorded_tools_refreshes_detected_adapters() {
+    let repo = tempfile::tempdir().expect("repo");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    write(repo.path().join("README.md"), "# temp\n");
+    write_local_ito_skills(repo.path());
+    write(repo.path().join(".ito/config.json"), "{}\n");
+    write(repo.path().join(".claude/settings.json"), "{}\n");
+
+    let out = run_rust_candidate(rust_path, &["update", "."], repo.path(), home.path());
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+
+    assert!(repo.path().join(".claude/hooks/ito-audit.sh").exists());
+    assert!(!repo.path().join(".opencode").exists());
+    assert!(!repo.path().join(".codex").exists());
+}
+
+#[test]
+fn update_tools_flag_overrides_and_records_selection() {
+    let repo = tempfile::tempdir().expect("repo");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    write(repo.path().join("README.md"), "# temp\n");
+    write_local_ito_skills(repo.path());
+    write(
+        repo.path().join(".ito/config.json"),
+        "{\n  \"init\": {\"tools\": [\"opencode\"]}\n}\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["update", ".", "--tools", "codex"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        repo.path()
+            .join(".codex/instructions/ito-skills-bootstrap.md")
+            .exists()
+    );
+    assert!(!repo.path().join(".opencode").exists());
+
+    let config = std::fs::read_to_string(repo.path().join(".ito/config.json")).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&config).unwrap();
+    assert_eq!(json["init"]["tools"], serde_json::json!(["codex"]));
+}
+
+#[test]
+fn update_rejects_unknown_tool_with_suggestion() {
+    let repo = tempfile::tempdir().expect("repo");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    write(repo.path().join("README.md"), "# temp\n");
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["update", ".", "--tools", "opencod"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr.contains("Unknown tool id 'opencod'"),
+        "stderr={}",
+        out.stderr
+    );
+    assert!(
+        out.stderr.contains("Did you mean: opencode?"),
+        "stderr={}",
+        out.stderr
+    );
+}
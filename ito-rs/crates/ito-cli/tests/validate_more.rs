@@ -192,3 +192,176 @@ fn validate_change_reports_audit_drift_against_routed_storage() {
         .expect("audit drift warning");
     assert_eq!(audit_issue["path"], "audit:routed-storage");
 }
+
+#[test]
+fn validate_list_rules_shows_built_in_and_custom_rules() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    fixtures::write(
+        repo.path().join(".ito/config.json"),
+        r#"{
+  "validation": {
+    "custom_rules": [
+      {
+        "id": "org/jira-key",
+        "target": "spec_requirement",
+        "must_match": "[A-Z]+-\\d+",
+        "message": "Requirement must reference a Jira key",
+        "level": "error"
+      },
+      {
+        "id": "org/broken",
+        "target": "spec_requirement",
+        "must_match": "([unclosed",
+        "message": "never compiled"
+      }
+    ]
+  }
+}"#,
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["validate", "--list-rules"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("scenario_grammar"), "{}", out.stdout);
+    assert!(out.stdout.contains("org/jira-key"), "{}", out.stdout);
+    assert!(!out.stdout.contains("org/broken"), "{}", out.stdout);
+    assert!(
+        out.stderr.contains("validation.custom_rules[1]"),
+        "{}",
+        out.stderr
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["validate", "alpha", "--type", "spec", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0, "custom error must fail validation");
+    let report: serde_json::Value = serde_json::from_str(&out.stdout).expect("validate json");
+    let issues = report["items"][0]["issues"]
+        .as_array()
+        .expect("issues array");
+    assert!(
+        issues
+            .iter()
+            .any(|issue| issue["rule_id"] == "org/jira-key" && issue["level"] == "ERROR"),
+        "{issues:?}"
+    );
+}
+
+#[test]
+fn validate_all_checks_module_graph_and_list_renders_mermaid() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    fixtures::write(
+        repo.path().join(".ito/modules/001_api/module.md"),
+        "# Api\n\n## Purpose\nApi module purpose that is long enough.\n\n## Scope\n- *\n\n## Depends On\n- 000\n- 007\n\n## Changes\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["validate", "--all", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(
+        out.code, 0,
+        "missing module dependency must fail validation"
+    );
+    let report: serde_json::Value = serde_json::from_str(&out.stdout).expect("validate json");
+    let graph_item = report["items"]
+        .as_array()
+        .expect("items array")
+        .iter()
+        .find(|item| item["type"] == "module-graph")
+        .expect("module-graph item");
+    assert_eq!(graph_item["valid"], false);
+    assert!(
+        graph_item["issues"][0]["message"]
+            .as_str()
+            .unwrap_or_default()
+            .contains("missing module '007'"),
+        "{graph_item}"
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["list", "--modules", "--graph"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.starts_with("graph TD\n"), "{}", out.stdout);
+    assert!(out.stdout.contains("m001 --> m000"), "{}", out.stdout);
+    assert!(
+        out.stdout.contains("m007[\"007 (missing)\"]"),
+        "{}",
+        out.stdout
+    );
+}
+
+#[test]
+fn validate_fix_applies_mechanical_fixes_and_reports_them() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+    let spec_md = repo.path().join(".ito/specs/alpha/spec.md");
+    let spec = std::fs::read_to_string(&spec_md).unwrap();
+    fixtures::write(&spec_md, &spec.replace('\n', "\r\n"));
+    let module_md = repo.path().join(".ito/modules/000_ungrouped/module.md");
+    let module = std::fs::read_to_string(&module_md).unwrap();
+    fixtures::write(&module_md, &format!("{module}- [ ] 000-02_shipped\n"));
+    fixtures::write(
+        repo.path()
+            .join(".ito/changes/archive/2026-01-01-000-02_shipped/proposal.md"),
+        "## Why\nShipped\n",
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["validate", "--all", "--fix", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stdout={} stderr={}", out.stdout, out.stderr);
+    let report: serde_json::Value = serde_json::from_str(&out.stdout).expect("validate json");
+    let applied = report["fixes"]["applied"].as_array().expect("applied");
+    assert_eq!(applied.len(), 2, "{report}");
+    assert!(report["fixes"]["failed"].as_array().unwrap().is_empty());
+    assert_eq!(std::fs::read_to_string(&spec_md).unwrap(), spec);
+    assert!(
+        std::fs::read_to_string(&module_md)
+            .unwrap()
+            .contains("- [x] 000-02_shipped")
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["validate", "--all", "--fix"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stdout.contains("No fixable issues found."),
+        "{}",
+        out.stdout
+    );
+}
@@ -6,7 +6,7 @@
 //! - `--list-rules` enumerates all built-in rules with active flag and gate.
 //! - `--explain <id>` prints rule metadata.
 //! - `--json` emits the standard `ValidationReport` envelope.
-//! - Documented exit codes: 0 (clean), 1 (rule errors), 2 (usage / config).
+//! - Documented exit codes: 0 (clean), 4 (rule errors), 2 (usage / config).
 
 use std::path::Path;
 
@@ -313,7 +313,7 @@ fn validate_repo_explain_audit_mirror_distinct_rule() {
 fn validate_repo_backend_token_not_committed_fails_when_token_in_config() {
     // 011-06 security check: write a project that has backend.enabled =
     // true and a backend.token set in `.ito/config.json`. The CLI
-    // command should exit 1 with the token-not-committed rule firing.
+    // command should exit 4 with the token-not-committed rule firing.
     let project = tempfile::tempdir().expect("project");
     write(
         project.path().join(".ito/config.json"),
@@ -351,8 +351,8 @@ fn validate_repo_backend_token_not_committed_fails_when_token_in_config() {
         home.path(),
     );
     assert_eq!(
-        out.code, 1,
-        "expected exit 1 (validation failure); stdout: {}",
+        out.code, 4,
+        "expected exit 4 (validation failure); stdout: {}",
         out.stdout,
     );
 
@@ -408,8 +408,8 @@ fn validate_repo_url_scheme_valid_fails_for_non_http_scheme() {
         home.path(),
     );
     assert_eq!(
-        out.code, 1,
-        "expected exit 1 for ftp scheme; stdout: {}",
+        out.code, 4,
+        "expected exit 4 for ftp scheme; stdout: {}",
         out.stdout,
     );
     assert!(
@@ -424,7 +424,7 @@ fn validate_repo_url_scheme_valid_fails_for_non_http_scheme() {
 fn validate_repo_strict_promotes_branch_name_warning_to_failure() {
     // Non-conventional coordination branch name + embedded storage =>
     // only `coordination/branch-name-set` fires (a WARNING). Without
-    // strict, exit 0; with strict, exit 1.
+    // strict, exit 0; with strict, exit 4.
     let project = tempfile::tempdir().expect("project");
     write(
         project.path().join(".ito/config.json"),
@@ -462,7 +462,7 @@ fn validate_repo_strict_promotes_branch_name_warning_to_failure() {
         home.path(),
     );
     assert_eq!(
-        strict.code, 1,
+        strict.code, 4,
         "warning should fail with --strict; stdout: {}",
         strict.stdout,
     );
@@ -0,0 +1,138 @@
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use ito_test_support::run_rust_candidate;
+
+const DEPRECATED_KEY_CONFIG: &str = r#"{"worktrees":{"defaultBranch":"main"}}"#;
+
+fn ralph_status(args: &[&str], repo: &std::path::Path, home: &std::path::Path) -> (String, String) {
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    let mut argv = args.to_vec();
+    argv.extend([
+        "ralph",
+        "--change",
+        "000-01_test-change",
+        "--status",
+        "--no-interactive",
+    ]);
+    let out = run_rust_candidate(rust_path, &argv, repo, home);
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    (out.stdout, out.stderr)
+}
+
+#[test]
+fn ralph_status_output_at_default_and_quiet_levels() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    fixtures::reset_repo(repo.path(), base.path());
+    fixtures::git_init_with_initial_commit(repo.path());
+
+    let (stdout, stderr) = ralph_status(&[], repo.path(), home.path());
+    assert_eq!(
+        stdout,
+        "\n=== Ralph Status for 000-01_test-change ===\n\nNo state found\n"
+    );
+    assert_eq!(stderr, "");
+
+    let (stdout, stderr) = ralph_status(&["-q"], repo.path(), home.path());
+    assert_eq!(stdout, "No state found\n");
+    assert_eq!(stderr, "");
+}
+
+#[test]
+fn init_output_at_default_and_quiet_levels() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    let repo_arg = repo.path().to_string_lossy().to_string();
+
+    fixtures::reset_repo(repo.path(), base.path());
+    let out = run_rust_candidate(
+        rust_path,
+        &["init", &repo_arg, "--tools", "none"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stdout.contains("Ito initialized in"), "{}", out.stdout);
+
+    fixtures::reset_repo(repo.path(), base.path());
+    let out = run_rust_candidate(
+        rust_path,
+        &["--quiet", "init", &repo_arg, "--tools", "none"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert_eq!(out.stdout, "");
+    assert_eq!(out.stderr, "");
+    assert!(repo.path().join(".ito/project.md").exists());
+}
+
+#[test]
+fn verbose_init_reports_installer_actions_and_config_sources() {
+    let base = fixtures::make_empty_repo();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    let repo_arg = repo.path().to_string_lossy().to_string();
+    fixtures::reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["init", &repo_arg, "--tools", "none", "-v"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(out.stderr.contains("install: created "), "{}", out.stderr);
+    assert!(out.stderr.contains("project.md"), "{}", out.stderr);
+    assert!(out.stderr.contains("config: "), "{}", out.stderr);
+}
+
+#[test]
+fn config_warnings_follow_verbosity_and_json_stays_clean() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+    fixtures::reset_repo(repo.path(), base.path());
+    fixtures::write(repo.path().join(".ito/config.json"), DEPRECATED_KEY_CONFIG);
+
+    let out = run_rust_candidate(rust_path, &["list"], repo.path(), home.path());
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stderr
+            .contains("'worktrees.defaultBranch' is deprecated"),
+        "{}",
+        out.stderr
+    );
+
+    let out = run_rust_candidate(rust_path, &["-q", "list"], repo.path(), home.path());
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert_eq!(out.stderr, "");
+
+    // `--json` wins over `-v`: only the JSON document is printed.
+    let out = run_rust_candidate(
+        rust_path,
+        &["-v", "list", "--json"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert_eq!(out.stderr, "");
+    let _: serde_json::Value = serde_json::from_str(&out.stdout).expect("stdout is only JSON");
+}
+
+#[test]
+fn quiet_and_verbose_conflict() {
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    let out = run_rust_candidate(rust_path, &["-q", "-v", "list"], repo.path(), home.path());
+    assert_ne!(out.code, 0);
+    assert!(out.stderr.contains("cannot be used with"), "{}", out.stderr);
+}
@@ -0,0 +1,96 @@
+#[path = "support/mod.rs"]
+mod fixtures;
+
+use ito_test_support::run_rust_candidate;
+
+const CHANGE_ID: &str = "000-01_test-change";
+
+#[test]
+fn workflow_skip_marks_artifact_skipped_in_status() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &[
+            "workflow",
+            "skip",
+            CHANGE_ID,
+            "design",
+            "--reason",
+            "One-line fix",
+        ],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stdout
+            .contains(&format!("Skipped 'design' for {CHANGE_ID}: One-line fix")),
+        "stdout={}",
+        out.stdout
+    );
+    let meta = std::fs::read_to_string(
+        repo.path()
+            .join(".ito/changes")
+            .join(CHANGE_ID)
+            .join(".ito.yaml"),
+    )
+    .expect("change meta");
+    assert!(meta.contains("skipped_artifacts:"), "meta={meta}");
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["status", "--change", CHANGE_ID],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stdout.contains("(skipped: One-line fix)"),
+        "stdout={}",
+        out.stdout
+    );
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["workflow", "unskip", CHANGE_ID, "design"],
+        repo.path(),
+        home.path(),
+    );
+    assert_eq!(out.code, 0, "stderr={}", out.stderr);
+    assert!(
+        out.stdout
+            .contains(&format!("'design' is no longer skipped for {CHANGE_ID}")),
+        "stdout={}",
+        out.stdout
+    );
+}
+
+#[test]
+fn workflow_skip_rejects_unknown_artifact() {
+    let base = fixtures::make_repo_all_valid();
+    let repo = tempfile::tempdir().expect("work");
+    let home = tempfile::tempdir().expect("home");
+    let rust_path = assert_cmd::cargo::cargo_bin!("ito");
+
+    fixtures::reset_repo(repo.path(), base.path());
+
+    let out = run_rust_candidate(
+        rust_path,
+        &["workflow", "skip", CHANGE_ID, "nope", "--reason", "n/a"],
+        repo.path(),
+        home.path(),
+    );
+    assert_ne!(out.code, 0);
+    assert!(
+        out.stderr
+            .contains("Artifact 'nope' is not defined by the change's schema"),
+        "stderr={}",
+        out.stderr
+    );
+}
@@ -3,8 +3,10 @@
 //! This crate uses a narrow `FileSystem` trait to allow unit tests to inject
 //! fake implementations without touching the real disk.
 
+use std::collections::BTreeMap;
 use std::io;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 /// A minimal file-system interface.
 ///
@@ -14,6 +16,9 @@ pub trait FileSystem: Send + Sync {
     /// Read the entire file at `path` into a UTF-8 string.
     fn read_to_string(&self, path: &Path) -> io::Result<String>;
 
+    /// Read the entire file at `path` as raw bytes.
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
     /// Write `contents` to `path`, creating or truncating the file.
     fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()>;
 
@@ -29,6 +34,9 @@ pub trait FileSystem: Send + Sync {
     /// Remove a file.
     fn remove_file(&self, path: &Path) -> io::Result<()>;
 
+    /// Remove an empty directory.
+    fn remove_dir(&self, path: &Path) -> io::Result<()>;
+
     /// Remove a directory and all of its contents.
     fn remove_dir_all(&self, path: &Path) -> io::Result<()>;
 
@@ -37,6 +45,21 @@ pub trait FileSystem: Send + Sync {
 
     /// Return `true` if `path` is a file.
     fn is_file(&self, path: &Path) -> bool;
+
+    /// Return `true` if `path` itself is a symbolic link.
+    ///
+    /// Implementations without symlinks can rely on the default.
+    fn is_symlink(&self, _path: &Path) -> bool {
+        false
+    }
+
+    /// Resolve `path` to its canonical form, following symbolic links.
+    ///
+    /// Implementations without symlinks can rely on the default, which
+    /// returns `path` unchanged.
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        Ok(path.to_path_buf())
+    }
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -48,6 +71,10 @@ impl FileSystem for StdFs {
         std::fs::read_to_string(path)
     }
 
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        std::fs::read(path)
+    }
+
     fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
         std::fs::write(path, contents)
     }
@@ -74,6 +101,10 @@ impl FileSystem for StdFs {
         std::fs::remove_file(path)
     }
 
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        std::fs::remove_dir(path)
+    }
+
     fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
         std::fs::remove_dir_all(path)
     }
@@ -85,4 +116,196 @@ impl FileSystem for StdFs {
     fn is_file(&self, path: &Path) -> bool {
         path.is_file()
     }
+
+    fn is_symlink(&self, path: &Path) -> bool {
+        path.is_symlink()
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        std::fs::canonicalize(path)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum MemEntry {
+    Dir,
+    File(Vec<u8>),
+}
+
+#[derive(Debug, Default)]
+/// An in-memory `FileSystem` for tests and previews.
+///
+/// Paths are used verbatim (no normalization), and writes fail with
+/// `NotFound` when the parent directory was never created, matching `std::fs`.
+pub struct MemFs {
+    entries: Mutex<BTreeMap<PathBuf, MemEntry>>,
+}
+
+impl MemFs {
+    /// Create an empty in-memory file system.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return every file under `root`, keyed by `/`-separated relative path.
+    ///
+    /// The shape matches `ito_test_support::collect_file_bytes`, so an
+    /// in-memory run can be compared directly against a real directory.
+    pub fn file_bytes(&self, root: &Path) -> BTreeMap<String, Vec<u8>> {
+        let entries = self.lock();
+        let mut out = BTreeMap::new();
+        for (path, entry) in entries.iter() {
+            let MemEntry::File(bytes) = entry else {
+                continue;
+            };
+            let Ok(rel) = path.strip_prefix(root) else {
+                continue;
+            };
+            out.insert(rel.to_string_lossy().replace('\\', "/"), bytes.clone());
+        }
+        out
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<PathBuf, MemEntry>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn not_found(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("{} not found", path.display()),
+    )
+}
+
+fn parent_exists(entries: &BTreeMap<PathBuf, MemEntry>, path: &Path) -> bool {
+    match path.parent() {
+        None => true,
+        Some(parent) if parent.as_os_str().is_empty() => true,
+        Some(parent) => matches!(entries.get(parent), Some(MemEntry::Dir)),
+    }
+}
+
+impl FileSystem for MemFs {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        let bytes = self.read(path)?;
+        String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        match self.lock().get(path) {
+            Some(MemEntry::File(bytes)) => Ok(bytes.clone()),
+            Some(MemEntry::Dir) => Err(io::Error::new(
+                io::ErrorKind::IsADirectory,
+                format!("{} is a directory", path.display()),
+            )),
+            None => Err(not_found(path)),
+        }
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        let mut entries = self.lock();
+        if !parent_exists(&entries, path) {
+            return Err(not_found(path));
+        }
+        if matches!(entries.get(path), Some(MemEntry::Dir)) {
+            return Err(io::Error::new(
+                io::ErrorKind::IsADirectory,
+                format!("{} is a directory", path.display()),
+            ));
+        }
+        entries.insert(path.to_path_buf(), MemEntry::File(contents.to_vec()));
+        Ok(())
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.lock().contains_key(path)
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        let mut entries = self.lock();
+        for dir in path.ancestors() {
+            if dir.as_os_str().is_empty() {
+                continue;
+            }
+            match entries.get(dir) {
+                Some(MemEntry::Dir) => {}
+                Some(MemEntry::File(_)) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::AlreadyExists,
+                        format!("{} is a file", dir.display()),
+                    ));
+                }
+                None => {
+                    entries.insert(dir.to_path_buf(), MemEntry::Dir);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let entries = self.lock();
+        if !matches!(entries.get(path), Some(MemEntry::Dir)) {
+            return Err(not_found(path));
+        }
+        Ok(entries
+            .keys()
+            .filter(|p| p.parent() == Some(path))
+            .cloned()
+            .collect())
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        let mut entries = self.lock();
+        match entries.get(path) {
+            Some(MemEntry::File(_)) => {
+                entries.remove(path);
+                Ok(())
+            }
+            Some(MemEntry::Dir) => Err(io::Error::new(
+                io::ErrorKind::IsADirectory,
+                format!("{} is a directory", path.display()),
+            )),
+            None => Err(not_found(path)),
+        }
+    }
+
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        let mut entries = self.lock();
+        if !matches!(entries.get(path), Some(MemEntry::Dir)) {
+            return Err(not_found(path));
+        }
+        if entries.keys().any(|p| p.parent() == Some(path)) {
+            return Err(io::Error::new(
+                io::ErrorKind::DirectoryNotEmpty,
+                format!("{} is not empty", path.display()),
+            ));
+        }
+        entries.remove(path);
+        Ok(())
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+        let mut entries = self.lock();
+        if !matches!(entries.get(path), Some(MemEntry::Dir)) {
+            return Err(not_found(path));
+        }
+        entries.retain(|p, _| !p.starts_with(path));
+        Ok(())
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        matches!(self.lock().get(path), Some(MemEntry::Dir))
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        matches!(self.lock().get(path), Some(MemEntry::File(_)))
+    }
 }
+
+#[cfg(test)]
+#[path = "fs_tests.rs"]
+mod fs_tests;
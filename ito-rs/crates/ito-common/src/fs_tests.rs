@@ -0,0 +1,51 @@
+use super::*;
+
+#[test]
+fn mem_fs_round_trips_files_and_lists_children() {
+    let fs = MemFs::new();
+    let root = Path::new("/repo");
+    fs.create_dir_all(&root.join("a/b")).unwrap();
+    fs.write(&root.join("a/b/file.txt"), b"hello").unwrap();
+    fs.write(&root.join("a/top.txt"), b"top").unwrap();
+
+    assert!(fs.is_dir(&root.join("a")));
+    assert!(fs.is_file(&root.join("a/b/file.txt")));
+    assert_eq!(fs.read(&root.join("a/b/file.txt")).unwrap(), b"hello");
+    assert_eq!(fs.read_to_string(&root.join("a/top.txt")).unwrap(), "top");
+
+    let mut children = fs.read_dir(&root.join("a")).unwrap();
+    children.sort();
+    assert_eq!(children, vec![root.join("a/b"), root.join("a/top.txt")]);
+
+    let files = fs.file_bytes(root);
+    assert_eq!(
+        files.keys().cloned().collect::<Vec<_>>(),
+        vec!["a/b/file.txt".to_string(), "a/top.txt".to_string()]
+    );
+}
+
+#[test]
+fn mem_fs_write_requires_existing_parent() {
+    let fs = MemFs::new();
+    let err = fs.write(Path::new("/missing/file.txt"), b"x").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    assert!(fs.read(Path::new("/missing/file.txt")).is_err());
+}
+
+#[test]
+fn mem_fs_remove_dir_refuses_non_empty_directories() {
+    let fs = MemFs::new();
+    fs.create_dir_all(Path::new("/repo/dir")).unwrap();
+    fs.write(Path::new("/repo/dir/f"), b"x").unwrap();
+
+    assert!(fs.remove_dir(Path::new("/repo/dir")).is_err());
+    fs.remove_file(Path::new("/repo/dir/f")).unwrap();
+    fs.remove_dir(Path::new("/repo/dir")).unwrap();
+    assert!(!fs.exists(Path::new("/repo/dir")));
+
+    fs.create_dir_all(Path::new("/repo/tree/nested")).unwrap();
+    fs.write(Path::new("/repo/tree/nested/f"), b"x").unwrap();
+    fs.remove_dir_all(Path::new("/repo/tree")).unwrap();
+    assert!(!fs.exists(Path::new("/repo/tree/nested/f")));
+    assert!(fs.is_dir(Path::new("/repo")));
+}
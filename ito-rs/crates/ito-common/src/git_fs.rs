@@ -0,0 +1,297 @@
+//! Read-only `FileSystem` over a git revision.
+//!
+//! [`GitTreeFs`] answers reads from the tree of a commit instead of the
+//! working tree, so repositories built over it see the project exactly as it
+//! was at that revision. Paths are the same absolute paths the working tree
+//! uses; anything outside the repository or absent from the tree is
+//! `NotFound`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Arc;
+
+use crate::fs::FileSystem;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TreeEntry {
+    Dir,
+    File { oid: String },
+}
+
+#[derive(Debug)]
+struct Tree {
+    root: PathBuf,
+    /// Other spellings of `root`, e.g. through a symlinked temp directory.
+    aliases: Vec<PathBuf>,
+    revision: String,
+    commit: String,
+    entries: BTreeMap<PathBuf, TreeEntry>,
+    children: BTreeMap<PathBuf, BTreeSet<PathBuf>>,
+}
+
+impl Tree {
+    fn new(
+        root: PathBuf,
+        revision: String,
+        commit: String,
+        entries: BTreeMap<PathBuf, TreeEntry>,
+    ) -> Self {
+        let mut children: BTreeMap<PathBuf, BTreeSet<PathBuf>> = BTreeMap::new();
+        for path in entries.keys() {
+            let parent = path.parent().unwrap_or(Path::new("")).to_path_buf();
+            children.entry(parent).or_default().insert(path.clone());
+        }
+        Self {
+            root,
+            aliases: Vec::new(),
+            revision,
+            commit,
+            entries,
+            children,
+        }
+    }
+}
+
+/// A read-only `FileSystem` backed by the tree of a git commit.
+///
+/// The tree listing is loaded once by [`GitTreeFs::open`]; file contents are
+/// read on demand with `git cat-file`. Cloning is cheap and shares the
+/// listing. Every write or removal fails with
+/// [`io::ErrorKind::ReadOnlyFilesystem`].
+#[derive(Debug, Clone)]
+pub struct GitTreeFs {
+    tree: Arc<Tree>,
+}
+
+impl GitTreeFs {
+    /// Open the repository containing `dir` at `revision`.
+    ///
+    /// `revision` is anything `git rev-parse` accepts (a tag, branch, or
+    /// commit). Fails with `NotFound` when the revision does not name a
+    /// commit.
+    pub fn open(dir: &Path, revision: &str) -> io::Result<Self> {
+        let root = git_stdout(dir, &["rev-parse", "--show-toplevel"])?;
+        let root = PathBuf::from(String::from_utf8_lossy(&root).trim());
+
+        let spec = format!("{revision}^{{commit}}");
+        let commit =
+            git_stdout(&root, &["rev-parse", "--verify", "--quiet", &spec]).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("unknown git revision '{revision}'"),
+                )
+            })?;
+        let commit = String::from_utf8_lossy(&commit).trim().to_string();
+
+        let listing = git_stdout(
+            &root,
+            &["ls-tree", "-r", "-t", "-z", "--full-tree", &commit],
+        )?;
+        let entries = parse_ls_tree(&listing);
+
+        let mut tree = Tree::new(root, revision.to_string(), commit, entries);
+        if let Some(alias) = root_alias(dir, &tree.root) {
+            tree.aliases.push(alias);
+        }
+        Ok(Self {
+            tree: Arc::new(tree),
+        })
+    }
+
+    /// Revision as given to [`GitTreeFs::open`].
+    pub fn revision(&self) -> &str {
+        &self.tree.revision
+    }
+
+    /// Full commit id the revision resolved to.
+    pub fn commit(&self) -> &str {
+        &self.tree.commit
+    }
+
+    /// Repository root the tree's paths are relative to.
+    pub fn root(&self) -> &Path {
+        &self.tree.root
+    }
+
+    /// Tree-relative form of `path`, or `None` when it lies outside the repo.
+    fn relative(&self, path: &Path) -> Option<PathBuf> {
+        let rel = std::iter::once(&self.tree.root)
+            .chain(&self.tree.aliases)
+            .find_map(|root| path.strip_prefix(root).ok())?;
+        let mut out = PathBuf::new();
+        for component in rel.components() {
+            match component {
+                std::path::Component::Normal(part) => out.push(part),
+                std::path::Component::CurDir => {}
+                std::path::Component::ParentDir => {
+                    if !out.pop() {
+                        return None;
+                    }
+                }
+                _ => return None,
+            }
+        }
+        Some(out)
+    }
+
+    fn entry(&self, path: &Path) -> Option<TreeEntry> {
+        let rel = self.relative(path)?;
+        if rel.as_os_str().is_empty() {
+            return Some(TreeEntry::Dir);
+        }
+        self.tree.entries.get(&rel).cloned()
+    }
+
+    fn not_found(&self, path: &Path) -> io::Error {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "{} does not exist at revision '{}'",
+                path.display(),
+                self.tree.revision
+            ),
+        )
+    }
+
+    fn read_only(&self, path: &Path) -> io::Error {
+        io::Error::new(
+            io::ErrorKind::ReadOnlyFilesystem,
+            format!(
+                "cannot modify {}: revision '{}' is read-only",
+                path.display(),
+                self.tree.revision
+            ),
+        )
+    }
+}
+
+impl FileSystem for GitTreeFs {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        let bytes = self.read(path)?;
+        String::from_utf8(bytes).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        match self.entry(path) {
+            Some(TreeEntry::File { oid }) => {
+                git_stdout(&self.tree.root, &["cat-file", "blob", &oid])
+            }
+            Some(TreeEntry::Dir) => Err(io::Error::new(
+                io::ErrorKind::IsADirectory,
+                format!("{} is a directory", path.display()),
+            )),
+            None => Err(self.not_found(path)),
+        }
+    }
+
+    fn write(&self, path: &Path, _contents: &[u8]) -> io::Result<()> {
+        Err(self.read_only(path))
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.entry(path).is_some()
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        Err(self.read_only(path))
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        match self.entry(path) {
+            Some(TreeEntry::Dir) => {}
+            Some(TreeEntry::File { .. }) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotADirectory,
+                    format!("{} is not a directory", path.display()),
+                ));
+            }
+            None => return Err(self.not_found(path)),
+        }
+        let rel = self.relative(path).unwrap_or_default();
+        let Some(children) = self.tree.children.get(&rel) else {
+            return Ok(Vec::new());
+        };
+        Ok(children
+            .iter()
+            .map(|child| self.tree.root.join(child))
+            .collect())
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        Err(self.read_only(path))
+    }
+
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        Err(self.read_only(path))
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+        Err(self.read_only(path))
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        matches!(self.entry(path), Some(TreeEntry::Dir))
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        matches!(self.entry(path), Some(TreeEntry::File { .. }))
+    }
+}
+
+/// `root` as reached from `dir` when `dir` goes through a symlink.
+///
+/// Git reports the canonical top-level path; callers usually build paths from
+/// the directory they started in.
+fn root_alias(dir: &Path, root: &Path) -> Option<PathBuf> {
+    let canonical = dir.canonicalize().ok()?;
+    let depth = canonical.strip_prefix(root).ok()?.components().count();
+    let alias = dir.ancestors().nth(depth)?.to_path_buf();
+    (alias != root).then_some(alias)
+}
+
+/// Parse `git ls-tree -r -t -z` output into tree-relative entries.
+///
+/// Each record is `<mode> <type> <oid>\t<path>\0`. Submodule entries
+/// (`commit`) are listed as empty directories.
+fn parse_ls_tree(listing: &[u8]) -> BTreeMap<PathBuf, TreeEntry> {
+    let mut entries = BTreeMap::new();
+    for record in listing.split(|b| *b == 0) {
+        let record = String::from_utf8_lossy(record);
+        let Some((meta, path)) = record.split_once('\t') else {
+            continue;
+        };
+        let mut fields = meta.split(' ');
+        let (Some(_mode), Some(kind), Some(oid)) = (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        let entry = match kind {
+            "blob" => TreeEntry::File {
+                oid: oid.to_string(),
+            },
+            "tree" | "commit" => TreeEntry::Dir,
+            _ => continue,
+        };
+        entries.insert(PathBuf::from(path), entry);
+    }
+    entries
+}
+
+fn git_stdout(dir: &Path, args: &[&str]) -> io::Result<Vec<u8>> {
+    let output = Command::new("git").arg("-C").arg(dir).args(args).output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(io::Error::other(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            stderr.trim()
+        )));
+    }
+    Ok(output.stdout)
+}
+
+#[cfg(test)]
+#[path = "git_fs_tests.rs"]
+mod git_fs_tests;
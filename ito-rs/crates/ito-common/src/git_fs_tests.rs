@@ -0,0 +1,80 @@
+use super::*;
+
+fn tree_fs(listing: &[u8]) -> GitTreeFs {
+    GitTreeFs {
+        tree: Arc::new(Tree::new(
+            PathBuf::from("/repo"),
+            "v1".to_string(),
+            "abc123".to_string(),
+            parse_ls_tree(listing),
+        )),
+    }
+}
+
+const LISTING: &[u8] = b"040000 tree 1111\t.ito\0\
+040000 tree 2222\t.ito/changes\0\
+040000 tree 3333\t.ito/changes/001-01_demo\0\
+100644 blob 4444\t.ito/changes/001-01_demo/tasks.md\0\
+160000 commit 5555\tvendor/lib\0";
+
+#[test]
+fn ls_tree_records_map_to_files_and_dirs() {
+    let entries = parse_ls_tree(LISTING);
+
+    assert_eq!(entries.get(Path::new(".ito")), Some(&TreeEntry::Dir));
+    assert_eq!(
+        entries.get(Path::new(".ito/changes/001-01_demo/tasks.md")),
+        Some(&TreeEntry::File {
+            oid: "4444".to_string()
+        })
+    );
+    assert_eq!(entries.get(Path::new("vendor/lib")), Some(&TreeEntry::Dir));
+}
+
+#[test]
+fn lookups_resolve_against_the_repository_root() {
+    let fs = tree_fs(LISTING);
+
+    assert!(fs.is_dir(Path::new("/repo")));
+    assert!(fs.is_dir(Path::new("/repo/.ito/changes")));
+    assert!(fs.is_file(Path::new("/repo/.ito/changes/001-01_demo/tasks.md")));
+    assert!(fs.exists(Path::new("/repo/.ito/changes/./001-01_demo")));
+    assert!(!fs.exists(Path::new("/repo/.ito/changes/001-01_demo/design.md")));
+    assert!(!fs.exists(Path::new("/elsewhere/.ito")));
+    assert_eq!(
+        fs.read_dir(Path::new("/repo/.ito/changes")).unwrap(),
+        vec![PathBuf::from("/repo/.ito/changes/001-01_demo")]
+    );
+}
+
+#[test]
+fn missing_paths_are_not_found() {
+    let fs = tree_fs(LISTING);
+
+    let err = fs
+        .read_to_string(Path::new("/repo/.ito/changes/001-01_demo/proposal.md"))
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    let err = fs.read_dir(Path::new("/repo/.ito/modules")).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+}
+
+#[test]
+fn writes_are_rejected() {
+    let fs = tree_fs(LISTING);
+    let path = Path::new("/repo/.ito/changes/001-01_demo/tasks.md");
+
+    let err = fs.write(path, b"- [x] done").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::ReadOnlyFilesystem);
+    assert!(err.to_string().contains("revision 'v1' is read-only"));
+    assert_eq!(
+        fs.remove_file(path).unwrap_err().kind(),
+        io::ErrorKind::ReadOnlyFilesystem
+    );
+    assert_eq!(
+        fs.create_dir_all(Path::new("/repo/.ito/new"))
+            .unwrap_err()
+            .kind(),
+        io::ErrorKind::ReadOnlyFilesystem
+    );
+}
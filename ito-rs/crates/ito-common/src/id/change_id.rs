@@ -56,6 +56,55 @@ pub struct ParsedChangeId {
     pub canonical: ChangeId,
 }
 
+impl ParsedChangeId {
+    /// Return the normalized `NNN-NN_slug` (or `NNN.SS-NN_slug`) form.
+    ///
+    /// Numeric parts are zero-padded and the slug is sanitized with
+    /// [`canonical_change_slug`], so the result is always accepted by
+    /// [`parse_change_id_strict`].
+    pub fn canonical(&self) -> String {
+        let prefix = match &self.sub_module_id {
+            Some(sub_id) => sub_id.as_str(),
+            None => self.module_id.as_str(),
+        };
+        format!(
+            "{prefix}-{}_{}",
+            self.change_num,
+            canonical_change_slug(&self.name)
+        )
+    }
+}
+
+/// Normalize a change name into a canonical slug.
+///
+/// Lowercases ASCII letters, replaces every character outside `[a-z0-9-]`
+/// (including non-ASCII characters) with `-`, collapses runs of `-`, and trims
+/// leading and trailing `-`.
+pub fn canonical_change_slug(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    for c in name.chars() {
+        let c = c.to_ascii_lowercase();
+        if c.is_ascii_lowercase() || c.is_ascii_digit() {
+            out.push(c);
+        } else if !out.is_empty() && !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    while out.ends_with('-') {
+        out.pop();
+    }
+    out
+}
+
+/// Format a canonical change id from its parts.
+///
+/// `prefix` is a module id (`NNN`) or sub-module id (`NNN.SS`); the change
+/// number is zero-padded to two digits and `name` is sanitized with
+/// [`canonical_change_slug`].
+pub fn format_change_id(prefix: &str, change_num: u32, name: &str) -> String {
+    format!("{prefix}-{change_num:02}_{}", canonical_change_slug(name))
+}
+
 /// Parse a change identifier.
 ///
 /// Accepts both the legacy `NNN-NN_name` format and the sub-module
@@ -264,6 +313,93 @@ pub fn parse_change_id(input: &str) -> Result<ParsedChangeId, IdParseError> {
     })
 }
 
+/// Parse a change identifier that must already be in canonical form.
+///
+/// Unlike [`parse_change_id`], which normalizes loose inputs such as
+/// `3-5_Fix-Thing`, this rejects anything that is not byte-for-byte equal to
+/// its canonical form. The error describes the first problem found (padding,
+/// uppercase or unsanitized slug, missing separator) and the hint suggests the
+/// canonical id when one can be derived.
+pub fn parse_change_id_strict(input: &str) -> Result<ParsedChangeId, IdParseError> {
+    if input.trim() != input {
+        return Err(IdParseError::new(
+            format!("Change ID has surrounding whitespace: \"{input}\""),
+            Some(format!("Use \"{}\"", input.trim())),
+        ));
+    }
+
+    let Some((left, name_part)) = input.split_once('_') else {
+        let is_numeric_prefix = match input.split_once('-') {
+            Some((a, b)) => a.split('.').all(is_all_ascii_digits) && is_all_ascii_digits(b),
+            None => false,
+        };
+        if input.is_empty() || is_numeric_prefix {
+            // Empty input and "1-2" (no name at all) are explained by the loose parser.
+            return parse_change_id(input);
+        }
+        return Err(IdParseError::new(
+            format!("Change ID is missing the \"_\" separator before the name: \"{input}\""),
+            Some("Canonical change IDs look like \"NNN-NN_name\" (e.g., \"001-02_my-change\")"),
+        ));
+    };
+
+    let parsed = parse_change_id(input)?;
+    let canonical = parsed.canonical();
+    let hint = Some(format!("Use \"{canonical}\""));
+
+    let (module_sub_part, change_str) = left.split_once('-').unwrap_or((left, ""));
+    let (module_str, sub_str) = match module_sub_part.split_once('.') {
+        Some((module_str, sub_str)) => (module_str, Some(sub_str)),
+        None => (module_sub_part, None),
+    };
+
+    if module_str != parsed.module_id.as_str() {
+        return Err(IdParseError::new(
+            format!(
+                "Module number \"{module_str}\" in change ID \"{input}\" must be exactly 3 digits"
+            ),
+            hint,
+        ));
+    }
+    if let (Some(sub_str), Some(sub_id)) = (sub_str, &parsed.sub_module_id)
+        && !sub_id.as_str().ends_with(&format!(".{sub_str}"))
+    {
+        return Err(IdParseError::new(
+            format!(
+                "Sub-module number \"{sub_str}\" in change ID \"{input}\" must be exactly 2 digits"
+            ),
+            hint,
+        ));
+    }
+    if change_str != parsed.change_num {
+        let problem = if change_str.len() < parsed.change_num.len() {
+            "must be zero-padded to at least 2 digits"
+        } else {
+            "has extra leading zeros"
+        };
+        return Err(IdParseError::new(
+            format!("Change number \"{change_str}\" in change ID \"{input}\" {problem}"),
+            hint,
+        ));
+    }
+    if name_part.chars().any(|c| c.is_ascii_uppercase()) {
+        return Err(IdParseError::new(
+            format!("Change name \"{name_part}\" in change ID \"{input}\" must be lowercase"),
+            hint,
+        ));
+    }
+    if name_part != canonical_change_slug(name_part) {
+        return Err(IdParseError::new(
+            format!(
+                "Change name \"{name_part}\" in change ID \"{input}\" must not have repeated or trailing \"-\""
+            ),
+            hint,
+        ));
+    }
+
+    Ok(parsed)
+}
+
 #[cfg(test)]
 #[path = "change_id_tests.rs"]
 mod change_id_tests;
@@ -108,3 +108,172 @@ fn parse_change_id_sub_module_missing_name_is_error() {
     let err = parse_change_id("005.01-03").unwrap_err();
     assert!(err.error.contains("Invalid change ID format") || err.error.contains("missing name"));
 }
+
+#[test]
+fn canonical_pads_module_and_change_number() {
+    let parsed = parse_change_id("3-5_fix-thing").unwrap();
+    assert_eq!(parsed.canonical(), "003-05_fix-thing");
+}
+
+#[test]
+fn canonical_pads_sub_module_ids() {
+    let parsed = parse_change_id("5.1-3_Foo").unwrap();
+    assert_eq!(parsed.canonical(), "005.01-03_foo");
+}
+
+#[test]
+fn canonical_collapses_repeated_and_trailing_hyphens() {
+    let parsed = parse_change_id("001-02_fix--thing-").unwrap();
+    assert_eq!(parsed.canonical(), "001-02_fix-thing");
+}
+
+#[test]
+fn canonical_keeps_large_change_numbers_unpadded() {
+    let parsed = parse_change_id("001-1234_example").unwrap();
+    assert_eq!(parsed.canonical(), "001-1234_example");
+}
+
+#[test]
+fn canonical_change_slug_lowercases_and_replaces_invalid_chars() {
+    assert_eq!(canonical_change_slug("Fix Thing"), "fix-thing");
+    assert_eq!(canonical_change_slug("fix_the.thing!"), "fix-the-thing");
+    assert_eq!(canonical_change_slug("  --Fix--Thing--  "), "fix-thing");
+    assert_eq!(
+        canonical_change_slug("already-canonical-2"),
+        "already-canonical-2"
+    );
+    assert_eq!(canonical_change_slug(""), "");
+}
+
+#[test]
+fn canonical_change_slug_replaces_unicode() {
+    assert_eq!(canonical_change_slug("café-menü"), "caf-men");
+    assert_eq!(canonical_change_slug("naïve approach"), "na-ve-approach");
+    assert_eq!(canonical_change_slug("日本語"), "");
+    assert_eq!(canonical_change_slug("emoji-🚀-launch"), "emoji-launch");
+}
+
+#[test]
+fn format_change_id_pads_and_sanitizes() {
+    assert_eq!(format_change_id("003", 5, "Fix Thing"), "003-05_fix-thing");
+    assert_eq!(
+        format_change_id("005.01", 12, "add_jwt"),
+        "005.01-12_add-jwt"
+    );
+    assert_eq!(format_change_id("001", 123, "big"), "001-123_big");
+}
+
+#[test]
+fn strict_accepts_canonical_ids() {
+    for id in [
+        "001-02_my-change",
+        "005.01-03_add-jwt",
+        "001-100_bar",
+        "000-01_a1",
+    ] {
+        let parsed = parse_change_id_strict(id).unwrap();
+        assert_eq!(parsed.canonical.as_str(), id);
+        assert_eq!(parsed.canonical(), id);
+    }
+}
+
+#[test]
+fn strict_rejects_unpadded_module_number() {
+    let err = parse_change_id_strict("03-05_fix-thing").unwrap_err();
+    assert_eq!(
+        err.error,
+        "Module number \"03\" in change ID \"03-05_fix-thing\" must be exactly 3 digits"
+    );
+    assert_eq!(err.hint.as_deref(), Some("Use \"003-05_fix-thing\""));
+}
+
+#[test]
+fn strict_rejects_overpadded_module_number() {
+    let err = parse_change_id_strict("0003-05_fix-thing").unwrap_err();
+    assert!(err.error.contains("must be exactly 3 digits"));
+    assert_eq!(err.hint.as_deref(), Some("Use \"003-05_fix-thing\""));
+}
+
+#[test]
+fn strict_rejects_unpadded_change_number() {
+    let err = parse_change_id_strict("003-5_fix-thing").unwrap_err();
+    assert_eq!(
+        err.error,
+        "Change number \"5\" in change ID \"003-5_fix-thing\" must be zero-padded to at least 2 digits"
+    );
+    assert_eq!(err.hint.as_deref(), Some("Use \"003-05_fix-thing\""));
+}
+
+#[test]
+fn strict_rejects_extra_leading_zeros_in_change_number() {
+    let err = parse_change_id_strict("003-005_fix-thing").unwrap_err();
+    assert_eq!(
+        err.error,
+        "Change number \"005\" in change ID \"003-005_fix-thing\" has extra leading zeros"
+    );
+    assert_eq!(err.hint.as_deref(), Some("Use \"003-05_fix-thing\""));
+}
+
+#[test]
+fn strict_rejects_unpadded_sub_module_number() {
+    let err = parse_change_id_strict("005.1-03_add-jwt").unwrap_err();
+    assert_eq!(
+        err.error,
+        "Sub-module number \"1\" in change ID \"005.1-03_add-jwt\" must be exactly 2 digits"
+    );
+    assert_eq!(err.hint.as_deref(), Some("Use \"005.01-03_add-jwt\""));
+}
+
+#[test]
+fn strict_rejects_uppercase_slug() {
+    let err = parse_change_id_strict("003-05_Fix-Thing").unwrap_err();
+    assert_eq!(
+        err.error,
+        "Change name \"Fix-Thing\" in change ID \"003-05_Fix-Thing\" must be lowercase"
+    );
+    assert_eq!(err.hint.as_deref(), Some("Use \"003-05_fix-thing\""));
+}
+
+#[test]
+fn strict_rejects_unsanitized_slug() {
+    let err = parse_change_id_strict("003-05_fix--thing-").unwrap_err();
+    assert!(
+        err.error
+            .contains("must not have repeated or trailing \"-\"")
+    );
+    assert_eq!(err.hint.as_deref(), Some("Use \"003-05_fix-thing\""));
+}
+
+#[test]
+fn strict_rejects_missing_underscore() {
+    let err = parse_change_id_strict("003-05-fix-thing").unwrap_err();
+    assert_eq!(
+        err.error,
+        "Change ID is missing the \"_\" separator before the name: \"003-05-fix-thing\""
+    );
+}
+
+#[test]
+fn strict_reports_missing_name_like_loose_parser() {
+    let err = parse_change_id_strict("003-05").unwrap_err();
+    assert_eq!(err.error, "Change ID missing name: \"003-05\"");
+}
+
+#[test]
+fn strict_rejects_surrounding_whitespace() {
+    let err = parse_change_id_strict(" 003-05_fix-thing ").unwrap_err();
+    assert!(err.error.contains("surrounding whitespace"));
+    assert_eq!(err.hint.as_deref(), Some("Use \"003-05_fix-thing\""));
+}
+
+#[test]
+fn strict_rejects_unicode_slug() {
+    let err = parse_change_id_strict("003-05_café").unwrap_err();
+    assert_eq!(err.error, "Invalid change ID format: \"003-05_café\"");
+}
+
+#[test]
+fn strict_rejects_empty_input() {
+    let err = parse_change_id_strict("").unwrap_err();
+    assert_eq!(err.error, "Change ID cannot be empty");
+}
@@ -6,8 +6,10 @@ mod module_id;
 mod spec_id;
 pub(crate) mod sub_module_id;
 
-pub use change_id::parse_change_id;
 pub use change_id::{ChangeId, ParsedChangeId};
+pub use change_id::{
+    canonical_change_slug, format_change_id, parse_change_id, parse_change_id_strict,
+};
 pub use error::IdParseError;
 pub use module_id::parse_module_id;
 pub use module_id::{ModuleId, ParsedModuleId};
@@ -12,6 +12,9 @@
 /// File-system abstraction used to make I/O testable.
 pub mod fs;
 
+/// Read-only file-system view of a git revision.
+pub mod git_fs;
+
 /// Parsing and validation helpers for Ito identifiers (change/module/spec IDs).
 pub mod id;
 
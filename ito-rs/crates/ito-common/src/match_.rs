@@ -2,41 +2,172 @@
 //!
 //! Used for "did you mean" suggestions in CLI error messages.
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-/// A candidate string scored by edit distance.
-pub struct ScoredCandidate {
+/// Score awarded to a whole-candidate prefix match, before length weighting.
+const PREFIX_BASE: f64 = 0.75;
+
+/// Score awarded to an infix substring match, before length weighting.
+const SUBSTRING_BASE: f64 = 0.6;
+
+/// Weight of the token score (slug tokens of change ids matter most).
+const TOKEN_WEIGHT: f64 = 0.9;
+
+/// Highest score a non-identical candidate can reach.
+const MAX_INEXACT_SCORE: f64 = 0.99;
+
+#[derive(Debug, Clone, PartialEq)]
+/// A candidate string with its similarity to the input.
+pub struct Match {
     /// The candidate string.
     pub candidate: String,
-    /// The edit distance to the input.
-    pub distance: usize,
-    /// The original index in the input list (used for stable tie-breaking).
-    pub index: usize,
+    /// Similarity in `0.0..=1.0`; only an exact match scores `1.0`.
+    pub score: f64,
+}
+
+/// Score every candidate against `input` and keep those scoring at least
+/// `threshold`.
+///
+/// Matches are sorted by descending score and are stable on ties. See
+/// [`similarity`] for how scores are computed.
+pub fn scored_matches(input: &str, candidates: &[String], threshold: f64) -> Vec<Match> {
+    let mut out: Vec<Match> = candidates
+        .iter()
+        .map(|c| Match {
+            candidate: c.clone(),
+            score: similarity(input, c),
+        })
+        .filter(|m| m.score >= threshold)
+        .collect();
+    // `sort_by` is stable, so ties keep their original order.
+    out.sort_by(|a, b| b.score.total_cmp(&a.score));
+    out
 }
 
 /// Return up to `max` candidates closest to `input`.
 ///
-/// Matches are sorted by Levenshtein distance and are stable on ties.
+/// Thin wrapper over [`scored_matches`] without a threshold.
 pub fn nearest_matches(input: &str, candidates: &[String], max: usize) -> Vec<String> {
-    let mut scored = Vec::new();
-    for (idx, c) in candidates.iter().enumerate() {
-        scored.push(ScoredCandidate {
-            candidate: c.clone(),
-            distance: levenshtein(input, c),
-            index: idx,
-        });
+    scored_matches(input, candidates, 0.0)
+        .into_iter()
+        .take(max)
+        .map(|m| m.candidate)
+        .collect()
+}
+
+/// Similarity of `candidate` to `input` in `0.0..=1.0`.
+///
+/// Comparison is case-insensitive and takes the best of:
+///
+/// - normalized Damerau-Levenshtein similarity of the whole strings;
+/// - a bonus when `input` is a prefix or substring of `candidate`, weighted by
+///   how much of the candidate it covers;
+/// - token overlap, where `input` and `candidate` are split on punctuation
+///   (so `NNN-MM_slug` ids yield their slug words) and each input token is
+///   matched against its best candidate token, favoring token prefixes.
+///
+/// Only an exact match scores `1.0`.
+pub fn similarity(input: &str, candidate: &str) -> f64 {
+    if input == candidate {
+        return 1.0;
+    }
+    let input = input.to_lowercase();
+    let candidate = candidate.to_lowercase();
+    if input.is_empty() || candidate.is_empty() {
+        return 0.0;
+    }
+
+    let mut score = edit_similarity(&input, &candidate);
+    let coverage = input.chars().count() as f64 / candidate.chars().count() as f64;
+    if candidate.starts_with(&input) {
+        score = score.max(PREFIX_BASE + (1.0 - PREFIX_BASE) * coverage);
+    } else if candidate.contains(&input) {
+        score = score.max(SUBSTRING_BASE + (1.0 - SUBSTRING_BASE) * coverage);
+    }
+    score = score.max(TOKEN_WEIGHT * token_similarity(&input, &candidate));
+    score.min(MAX_INEXACT_SCORE)
+}
+
+/// Normalized edit similarity: `1 - distance / longest length`.
+fn edit_similarity(a: &str, b: &str) -> f64 {
+    let longest = a.chars().count().max(b.chars().count());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - damerau_levenshtein(a, b) as f64 / longest as f64
+}
+
+/// Average over input tokens of the best match among candidate tokens.
+fn token_similarity(input: &str, candidate: &str) -> f64 {
+    let input_tokens = tokens(input);
+    let candidate_tokens = tokens(candidate);
+    if input_tokens.is_empty() || candidate_tokens.is_empty() {
+        return 0.0;
+    }
+
+    let total: f64 = input_tokens
+        .iter()
+        .map(|query| {
+            candidate_tokens
+                .iter()
+                .map(|token| token_match(query, token))
+                .fold(0.0, f64::max)
+        })
+        .sum();
+    total / input_tokens.len() as f64
+}
+
+fn token_match(query: &str, token: &str) -> f64 {
+    if query == token {
+        return 1.0;
+    }
+    if token.starts_with(query) {
+        let coverage = query.chars().count() as f64 / token.chars().count() as f64;
+        return 0.8 + 0.2 * coverage;
     }
+    edit_similarity(query, token)
+}
 
-    // Match JS behavior: sort by distance, stable on original order.
-    scored.sort_by(|a, b| a.distance.cmp(&b.distance).then(a.index.cmp(&b.index)));
+fn tokens(s: &str) -> Vec<&str> {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
 
-    let mut out = Vec::new();
-    for (idx, candidate) in scored.into_iter().enumerate() {
-        if idx >= max {
-            break;
+/// Compute the Damerau-Levenshtein distance (optimal string alignment
+/// variant) between two strings.
+///
+/// Like [`levenshtein`], but swapping two adjacent characters costs 1.
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let m = a_chars.len();
+    let n = b_chars.len();
+
+    let mut dp = vec![vec![0usize; n + 1]; m + 1];
+    for (i, row) in dp.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, value) in dp[0].iter_mut().enumerate() {
+        *value = j;
+    }
+
+    for i in 1..=m {
+        for j in 1..=n {
+            let cost = usize::from(a_chars[i - 1] != b_chars[j - 1]);
+            let mut best = (dp[i - 1][j] + 1)
+                .min(dp[i][j - 1] + 1)
+                .min(dp[i - 1][j - 1] + cost);
+            if i > 1
+                && j > 1
+                && a_chars[i - 1] == b_chars[j - 2]
+                && a_chars[i - 2] == b_chars[j - 1]
+            {
+                best = best.min(dp[i - 2][j - 2] + 1);
+            }
+            dp[i][j] = best;
         }
-        out.push(candidate.candidate);
     }
-    out
+
+    dp[m][n]
 }
 
 /// Compute the Levenshtein edit distance between two strings.
@@ -14,3 +14,76 @@ fn nearest_matches_is_stable_on_ties() {
     let out = nearest_matches("a", &candidates, 3);
     assert_eq!(out, vec!["aa", "ab", "ac"]);
 }
+
+#[test]
+fn damerau_levenshtein_counts_transpositions_once() {
+    assert_eq!(damerau_levenshtein("scehma", "schema"), 1);
+    assert_eq!(levenshtein("scehma", "schema"), 2);
+    assert_eq!(damerau_levenshtein("", "ab"), 2);
+}
+
+#[test]
+fn exact_match_scores_one_and_ranks_first() {
+    let candidates = vec![
+        "auth-login".to_string(),
+        "001-01_auth".to_string(),
+        "auth".to_string(),
+    ];
+    let out = scored_matches("auth", &candidates, 0.0);
+    assert_eq!(out[0].candidate, "auth");
+    assert_eq!(out[0].score, 1.0);
+    assert!(out[1..].iter().all(|m| m.score < 1.0));
+}
+
+#[test]
+fn transposition_typos_rank_above_unrelated_strings() {
+    let candidates = vec![
+        "minimal".to_string(),
+        "tdd".to_string(),
+        "spec-driven".to_string(),
+    ];
+    assert_eq!(
+        nearest_matches("spec-dirven", &candidates, 1),
+        vec!["spec-driven"]
+    );
+    assert_eq!(nearest_matches("tdd", &candidates, 1), vec!["tdd"]);
+    assert_eq!(nearest_matches("mniimal", &candidates, 1), vec!["minimal"]);
+}
+
+#[test]
+fn slug_token_query_prefers_ids_containing_the_token() {
+    let candidates = vec![
+        "012-01_workflow-tweaks".to_string(),
+        "012-02_add-worktree-sync".to_string(),
+        "012-03_wordtrees".to_string(),
+    ];
+    let out = scored_matches("worktree", &candidates, 0.0);
+    assert_eq!(out[0].candidate, "012-02_add-worktree-sync");
+    assert!(
+        edit_similarity("worktree", "012-02_add-worktree-sync")
+            <= edit_similarity("worktree", "012-03_wordtrees")
+    );
+}
+
+#[test]
+fn short_query_does_not_suggest_unrelated_ids() {
+    let candidates = vec![
+        "003-02_path-fix".to_string(),
+        "001-04_oauth-flow".to_string(),
+        "001-01_add-auth-login".to_string(),
+    ];
+    let out = scored_matches("auth", &candidates, 0.5);
+    let names: Vec<&str> = out.iter().map(|m| m.candidate.as_str()).collect();
+    assert_eq!(names[0], "001-01_add-auth-login");
+    assert!(!names.contains(&"003-02_path-fix"), "{names:?}");
+}
+
+#[test]
+fn threshold_filters_and_empty_candidates_return_empty() {
+    assert!(scored_matches("auth", &[], 0.0).is_empty());
+    assert!(nearest_matches("auth", &[], 3).is_empty());
+
+    let candidates = vec!["zzzz".to_string()];
+    assert!(scored_matches("auth", &candidates, 0.5).is_empty());
+    assert_eq!(scored_matches("auth", &candidates, 0.0).len(), 1);
+}
@@ -534,3 +534,129 @@ fn logging_invalid_commands_can_be_enabled() {
 }
 
 // ito_dir tests live in crate::ito_dir.
+
+#[test]
+fn cascading_project_config_loads_toml_only_repo_config() {
+    let repo = tempfile::tempdir().unwrap();
+    std::fs::write(
+        repo.path().join("ito.toml"),
+        "# Worktrees are disabled because CI checks out a shallow clone.\n\
+         [worktrees]\nenabled = false\n",
+    )
+    .unwrap();
+
+    let ctx = ConfigContext::default();
+    let ito_path = crate::ito_dir::get_ito_path(repo.path(), &ctx);
+
+    let r = load_cascading_project_config(repo.path(), &ito_path, &ctx);
+    assert_eq!(
+        r.merged.pointer("/worktrees/enabled"),
+        Some(&serde_json::json!(false))
+    );
+    assert_eq!(r.loaded_from, vec![repo.path().join("ito.toml")]);
+    assert!(r.shadowed.is_empty());
+}
+
+#[test]
+fn yaml_ito_dir_config_overrides_json_repo_config() {
+    let repo = tempfile::tempdir().unwrap();
+    std::fs::write(
+        repo.path().join("ito.json"),
+        "{\"obj\":{\"a\":1,\"b\":1},\"x\":\"repo\"}",
+    )
+    .unwrap();
+
+    let ctx = ConfigContext::default();
+    let ito_path = crate::ito_dir::get_ito_path(repo.path(), &ctx);
+    std::fs::create_dir_all(&ito_path).unwrap();
+    std::fs::write(
+        ito_path.join("config.yaml"),
+        "$schema: ./schema.json\nobj:\n  b: 2\nx: ito_dir # wins over ito.json\n",
+    )
+    .unwrap();
+
+    let r = load_cascading_project_config(repo.path(), &ito_path, &ctx);
+    assert_eq!(
+        r.merged.get("obj").unwrap(),
+        &serde_json::json!({"a": 1, "b": 2})
+    );
+    assert_eq!(r.merged.get("x").unwrap(), &serde_json::json!("ito_dir"));
+    assert!(r.merged.get("$schema").is_none());
+    assert_eq!(
+        r.loaded_from,
+        vec![repo.path().join("ito.json"), ito_path.join("config.yaml")]
+    );
+}
+
+#[test]
+fn json_wins_when_several_formats_exist_at_one_slot() {
+    let repo = tempfile::tempdir().unwrap();
+    std::fs::write(repo.path().join("ito.json"), "{\"x\":\"json\"}").unwrap();
+    std::fs::write(repo.path().join("ito.yaml"), "x: yaml\n").unwrap();
+
+    let ctx = ConfigContext::default();
+    let ito_path = crate::ito_dir::get_ito_path(repo.path(), &ctx);
+
+    let r = load_cascading_project_config(repo.path(), &ito_path, &ctx);
+    assert_eq!(r.merged.get("x").unwrap(), &serde_json::json!("json"));
+    assert_eq!(r.loaded_from, vec![repo.path().join("ito.json")]);
+    assert_eq!(r.shadowed, vec![repo.path().join("ito.yaml")]);
+}
+
+#[test]
+fn toml_nested_tables_merge_with_defaults_and_other_layers() {
+    let repo = tempfile::tempdir().unwrap();
+    std::fs::write(
+        repo.path().join("ito.json"),
+        "{\"changes\":{\"coordination_branch\":{\"name\":\"ito/custom\"}}}",
+    )
+    .unwrap();
+
+    let ctx = ConfigContext::default();
+    let ito_path = crate::ito_dir::get_ito_path(repo.path(), &ctx);
+    std::fs::create_dir_all(&ito_path).unwrap();
+    std::fs::write(
+        ito_path.join("config.toml"),
+        "[changes.coordination_branch]\nenabled = false\n\n[worktrees]\ndefaultBranch = \"develop\"\n",
+    )
+    .unwrap();
+
+    let r = load_cascading_project_config(repo.path(), &ito_path, &ctx);
+    assert_eq!(
+        r.merged.pointer("/changes/coordination_branch/name"),
+        Some(&serde_json::json!("ito/custom"))
+    );
+    assert_eq!(
+        r.merged.pointer("/changes/coordination_branch/enabled"),
+        Some(&serde_json::json!(false))
+    );
+    // Legacy keys are migrated regardless of format.
+    assert_eq!(
+        r.merged.pointer("/worktrees/default_branch"),
+        Some(&serde_json::json!("develop"))
+    );
+}
+
+#[test]
+fn project_config_paths_list_every_format_candidate() {
+    let repo = tempfile::tempdir().unwrap();
+    let ctx = ConfigContext::default();
+    let ito_path = crate::ito_dir::get_ito_path(repo.path(), &ctx);
+
+    let paths = project_config_paths(repo.path(), &ito_path, &ctx);
+    for candidate in [
+        repo.path().join("ito.json"),
+        repo.path().join("ito.toml"),
+        repo.path().join("ito.yaml"),
+        ito_path.join("config.json"),
+        ito_path.join("config.toml"),
+        ito_path.join("config.yaml"),
+    ] {
+        assert!(
+            paths.contains(&candidate),
+            "missing {}",
+            candidate.display()
+        );
+    }
+    assert_eq!(ito_dir_config_path(&ito_path), ito_path.join("config.json"));
+}
@@ -0,0 +1,84 @@
+//! Config file formats.
+//!
+//! Project config may be written as JSON, TOML or YAML. Every format is parsed
+//! into a [`serde_json::Value`] so the rest of the loading pipeline (merging,
+//! legacy-key migration, typed deserialization) is format-agnostic.
+
+use std::path::Path;
+
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Serialization format of a config file.
+pub enum ConfigFileFormat {
+    /// `*.json`
+    Json,
+    /// `*.toml`
+    Toml,
+    /// `*.yaml` / `*.yml`
+    Yaml,
+}
+
+impl ConfigFileFormat {
+    /// Format implied by `path`'s extension; anything unrecognized is JSON.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => Self::Toml,
+            Some("yaml" | "yml") => Self::Yaml,
+            _ => Self::Json,
+        }
+    }
+
+    /// Human-readable format name used in diagnostics.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Toml => "TOML",
+            Self::Yaml => "YAML",
+        }
+    }
+
+    /// Parse `contents` into a JSON value.
+    ///
+    /// An empty YAML document parses to an empty object.
+    ///
+    /// # Errors
+    ///
+    /// Returns the parser's message when `contents` is not valid in this format.
+    pub fn parse(self, contents: &str) -> Result<Value, String> {
+        match self {
+            Self::Json => serde_json::from_str(contents).map_err(|e| e.to_string()),
+            Self::Toml => {
+                let table: toml::Table = toml::from_str(contents).map_err(|e| e.to_string())?;
+                serde_json::to_value(table).map_err(|e| e.to_string())
+            }
+            Self::Yaml => match serde_yaml::from_str::<Value>(contents) {
+                Ok(Value::Null) => Ok(Value::Object(serde_json::Map::new())),
+                Ok(v) => Ok(v),
+                Err(e) => Err(e.to_string()),
+            },
+        }
+    }
+
+    /// Render `value` in this format, with a trailing newline.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message when `value` cannot be represented in this format
+    /// (for example `null` values in TOML).
+    pub fn render(self, value: &Value) -> Result<String, String> {
+        let mut out = match self {
+            Self::Json => serde_json::to_string_pretty(value).map_err(|e| e.to_string())?,
+            Self::Toml => toml::to_string_pretty(value).map_err(|e| e.to_string())?,
+            Self::Yaml => serde_yaml::to_string(value).map_err(|e| e.to_string())?,
+        };
+        if !out.ends_with('\n') {
+            out.push('\n');
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+#[path = "file_format_tests.rs"]
+mod file_format_tests;
@@ -0,0 +1,54 @@
+use std::path::Path;
+
+use super::ConfigFileFormat;
+
+#[test]
+fn format_follows_extension() {
+    assert_eq!(
+        ConfigFileFormat::from_path(Path::new("ito.toml")),
+        ConfigFileFormat::Toml
+    );
+    assert_eq!(
+        ConfigFileFormat::from_path(Path::new(".ito/config.yaml")),
+        ConfigFileFormat::Yaml
+    );
+    assert_eq!(
+        ConfigFileFormat::from_path(Path::new("config.json")),
+        ConfigFileFormat::Json
+    );
+}
+
+#[test]
+fn toml_and_yaml_parse_to_the_same_value_as_json() {
+    let expected = serde_json::json!({"worktrees": {"enabled": false, "strategy": "bare"}});
+
+    let toml =
+        "# worktrees are disabled on CI\n[worktrees]\nenabled = false\nstrategy = \"bare\"\n";
+    let yaml = "# worktrees are disabled on CI\nworktrees:\n  enabled: false\n  strategy: bare\n";
+
+    assert_eq!(ConfigFileFormat::Toml.parse(toml).unwrap(), expected);
+    assert_eq!(ConfigFileFormat::Yaml.parse(yaml).unwrap(), expected);
+}
+
+#[test]
+fn empty_yaml_is_an_empty_object() {
+    assert_eq!(
+        ConfigFileFormat::Yaml.parse("").unwrap(),
+        serde_json::json!({})
+    );
+}
+
+#[test]
+fn render_round_trips_each_format() {
+    let value = serde_json::json!({"a": 1, "nested": {"b": "two", "list": [1, 2]}});
+
+    for format in [
+        ConfigFileFormat::Json,
+        ConfigFileFormat::Toml,
+        ConfigFileFormat::Yaml,
+    ] {
+        let rendered = format.render(&value).unwrap();
+        assert!(rendered.ends_with('\n'));
+        assert_eq!(format.parse(&rendered).unwrap(), value, "{format:?}");
+    }
+}
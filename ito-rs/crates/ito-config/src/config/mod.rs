@@ -6,6 +6,10 @@
 //! - Project/Ito dir: `<itoDir>/config.json` (and optionally `$PROJECT_DIR/config.json`)
 //! - Global: `~/.config/ito/config.json` (or `$XDG_CONFIG_HOME/ito/config.json`)
 //!
+//! `ito.json` and `<itoDir>/config.json` may instead be written as TOML or YAML
+//! (`ito.toml`, `ito.yaml`, `config.toml`, `config.yaml`). When several formats
+//! exist for the same file, JSON wins and the others are reported as shadowed.
+//!
 //! This module loads these sources, merges them with defaults, and records the
 //! paths that contributed to the final configuration.
 
@@ -16,9 +20,14 @@ use ito_common::fs::{FileSystem, StdFs};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use file_format::ConfigFileFormat;
+
 /// Default config values and JSON serialization helpers.
 pub mod defaults;
 
+/// JSON, TOML and YAML config file parsing.
+pub mod file_format;
+
 /// JSON schema generation for Ito configuration.
 pub mod schema;
 
@@ -28,18 +37,36 @@ pub mod setup_coverage;
 /// Backend server configuration types (multi-tenant API).
 pub mod backend_types;
 
+/// Per-harness agent model pin configuration types.
+pub mod models_types;
+
+/// Harness tool permission configuration types.
+pub mod permission_types;
+
+/// Named change profile configuration types.
+pub mod profile_types;
+
 /// Proposal integration configuration types.
 pub mod proposal_types;
 
+/// Usage statistics and harness cost estimation configuration types.
+pub mod stats_types;
+
 /// Serde models for `config.json`.
 pub mod types;
 
+/// User-defined validation rule configuration types.
+pub mod validation_types;
+
 /// Worktree initialization configuration types (split out to keep `types.rs` under the line limit).
 pub mod worktree_init_types;
 
-const REPO_CONFIG_FILE_NAME: &str = "ito.json";
+/// Repo-level config candidates, in format precedence order.
+const REPO_CONFIG_FILE_NAMES: &[&str] = &["ito.json", "ito.toml", "ito.yaml"];
 const REPO_DOT_CONFIG_FILE_NAME: &str = ".ito.json";
 const ITO_DIR_CONFIG_FILE_NAME: &str = "config.json";
+/// Ito-dir config candidates, in format precedence order.
+const ITO_DIR_CONFIG_FILE_NAMES: &[&str] = &["config.json", "config.toml", "config.yaml"];
 const ITO_DIR_LOCAL_CONFIG_FILE_NAME: &str = "config.local.json";
 const PROJECT_LOCAL_CONFIG_PATH: &str = ".local/ito/config.json";
 
@@ -105,21 +132,27 @@ fn read_to_string_optional_fs<F: FileSystem>(fs: &F, path: &Path) -> Option<Stri
     }
 }
 
-/// Load `ito.json` from `project_root`.
+/// Load `ito.json` (or `ito.toml` / `ito.yaml`) from `project_root`.
 pub fn load_project_config(project_root: &Path) -> Option<ProjectConfig> {
     load_project_config_fs(&StdFs, project_root)
 }
 
 /// Like [`load_project_config`], but uses an injected file-system.
 pub fn load_project_config_fs<F: FileSystem>(fs: &F, project_root: &Path) -> Option<ProjectConfig> {
-    let path = project_root.join(REPO_CONFIG_FILE_NAME);
+    let slot = config_slot(project_root, REPO_CONFIG_FILE_NAMES);
+    let (path, _) = select_slot_file(fs, &slot)?;
     let contents = read_to_string_optional_fs(fs, &path)?;
+    let format = ConfigFileFormat::from_path(&path);
 
-    match serde_json::from_str(&contents) {
+    match format
+        .parse(&contents)
+        .and_then(|v| serde_json::from_value(v).map_err(|e| e.to_string()))
+    {
         Ok(v) => Some(v),
         Err(_) => {
-            eprintln!(
-                "Warning: Invalid JSON in {}, ignoring project config",
+            crate::notice!(
+                "Warning: Invalid {} in {}, ignoring project config",
+                format.as_str(),
                 path.display()
             );
             None
@@ -127,13 +160,50 @@ pub fn load_project_config_fs<F: FileSystem>(fs: &F, project_root: &Path) -> Opt
     }
 }
 
+/// Path of the `<itoDir>` config file that is loaded (and should be written).
+///
+/// Returns the existing `config.json`, `config.toml` or `config.yaml` (in that
+/// precedence order), or `<itoDir>/config.json` when none exists yet.
+pub fn ito_dir_config_path(ito_path: &Path) -> PathBuf {
+    ito_dir_config_path_fs(&StdFs, ito_path)
+}
+
+/// Like [`ito_dir_config_path`], but uses an injected file-system.
+pub fn ito_dir_config_path_fs<F: FileSystem>(fs: &F, ito_path: &Path) -> PathBuf {
+    let slot = config_slot(ito_path, ITO_DIR_CONFIG_FILE_NAMES);
+    match select_slot_file(fs, &slot) {
+        Some((path, _)) => path,
+        None => ito_path.join(ITO_DIR_CONFIG_FILE_NAME),
+    }
+}
+
+/// Candidate paths for one config file, in format precedence order.
+fn config_slot(dir: &Path, names: &[&str]) -> Vec<PathBuf> {
+    names.iter().map(|name| dir.join(name)).collect()
+}
+
+/// Pick the file to load for a config slot.
+///
+/// Returns the first existing candidate together with the existing
+/// candidates it shadows, or `None` when no candidate exists.
+fn select_slot_file<F: FileSystem>(fs: &F, slot: &[PathBuf]) -> Option<(PathBuf, Vec<PathBuf>)> {
+    let mut present = slot.iter().filter(|path| fs.is_file(path));
+    let chosen = present.next()?.clone();
+    Some((chosen, present.cloned().collect()))
+}
+
 fn load_json_object_fs<F: FileSystem>(fs: &F, path: &Path) -> Option<Value> {
     let contents = read_to_string_optional_fs(fs, path)?;
+    let format = ConfigFileFormat::from_path(path);
 
-    let v: Value = match serde_json::from_str(&contents) {
+    let v: Value = match format.parse(&contents) {
         Ok(v) => v,
         Err(_) => {
-            eprintln!("Warning: Invalid JSON in {}, ignoring", path.display());
+            crate::notice!(
+                "Warning: Invalid {} in {}, ignoring",
+                format.as_str(),
+                path.display()
+            );
             return None;
         }
     };
@@ -145,8 +215,9 @@ fn load_json_object_fs<F: FileSystem>(fs: &F, path: &Path) -> Option<Value> {
             Some(Value::Object(obj))
         }
         _ => {
-            eprintln!(
-                "Warning: Expected JSON object in {}, ignoring",
+            crate::notice!(
+                "Warning: Expected {} object in {}, ignoring",
+                format.as_str(),
                 path.display()
             );
             None
@@ -191,7 +262,7 @@ fn migrate_legacy_worktree_keys(config: &mut Value) {
 
     // worktrees.defaultBranch → worktrees.default_branch
     if let Some(legacy_val) = wt.remove("defaultBranch") {
-        eprintln!(
+        crate::notice!(
             "Warning: Config key 'worktrees.defaultBranch' is deprecated. \
              Use 'worktrees.default_branch' instead."
         );
@@ -202,7 +273,7 @@ fn migrate_legacy_worktree_keys(config: &mut Value) {
 
     // worktrees.localFiles → worktrees.apply.copy_from_main
     if let Some(legacy_val) = wt.remove("localFiles") {
-        eprintln!(
+        crate::notice!(
             "Warning: Config key 'worktrees.localFiles' is deprecated. \
              Use 'worktrees.apply.copy_from_main' instead."
         );
@@ -233,7 +304,7 @@ fn ignore_removed_tmux_key(config: &mut Value) {
         return;
     }
 
-    eprintln!(
+    crate::notice!(
         "Warning: Config key 'tools.tmux.enabled' was removed and has no effect. \
          Remove it from Ito configuration; external tmux use requires no Ito setting."
     );
@@ -260,7 +331,8 @@ fn project_path_from_json(v: &Value) -> Option<String> {
 
 /// Returns a repo-local `projectPath` override (Ito working directory name).
 ///
-/// Precedence (low -> high): `ito.json`, then `.ito.json`.
+/// Precedence (low -> high): `ito.json` (or `ito.toml` / `ito.yaml`), then
+/// `.ito.json`.
 ///
 /// NOTE: This does *not* consult `<itoDir>/config.json` to avoid cycles.
 pub fn load_repo_project_path_override(project_root: &Path) -> Option<String> {
@@ -274,8 +346,9 @@ pub fn load_repo_project_path_override_fs<F: FileSystem>(
 ) -> Option<String> {
     let mut out = None;
 
-    let repo = project_root.join(REPO_CONFIG_FILE_NAME);
-    if let Some(v) = load_json_object_fs(fs, &repo)
+    let slot = config_slot(project_root, REPO_CONFIG_FILE_NAMES);
+    if let Some((repo, _)) = select_slot_file(fs, &slot)
+        && let Some(v) = load_json_object_fs(fs, &repo)
         && let Some(p) = project_path_from_json(&v)
     {
         out = Some(p);
@@ -306,6 +379,9 @@ pub struct CascadingProjectConfig {
     /// from a gitignored override" inspect this list rather than the
     /// merged view.
     pub layers: Vec<ResolvedConfigLayer>,
+    /// Existing config files that were ignored because another format of the
+    /// same file takes precedence (e.g. `ito.toml` next to `ito.json`).
+    pub shadowed: Vec<PathBuf>,
 }
 
 #[derive(Debug, Clone)]
@@ -321,27 +397,44 @@ pub struct ResolvedConfigLayer {
 pub type ResolvedConfig = CascadingProjectConfig;
 
 /// Return the ordered list of configuration file paths consulted for a project.
+///
+/// Slots that accept several formats list every candidate (JSON first).
 pub fn project_config_paths(
     project_root: &Path,
     ito_path: &Path,
     ctx: &ConfigContext,
 ) -> Vec<PathBuf> {
-    let mut out: Vec<PathBuf> = Vec::new();
+    project_config_slots(project_root, ito_path, ctx)
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Configuration slots in precedence order (low -> high).
+///
+/// Each slot lists its candidate files in format precedence order; at most
+/// one file per slot is loaded.
+fn project_config_slots(
+    project_root: &Path,
+    ito_path: &Path,
+    ctx: &ConfigContext,
+) -> Vec<Vec<PathBuf>> {
+    let mut out: Vec<Vec<PathBuf>> = Vec::new();
 
     // Global config is the lowest-precedence layer.
     if let Some(path) = global_config_path(ctx) {
-        out.push(path);
+        out.push(vec![path]);
     }
 
     out.extend([
-        project_root.join(REPO_CONFIG_FILE_NAME),
-        project_root.join(REPO_DOT_CONFIG_FILE_NAME),
-        ito_path.join(ITO_DIR_CONFIG_FILE_NAME),
-        ito_path.join(ITO_DIR_LOCAL_CONFIG_FILE_NAME),
-        project_root.join(PROJECT_LOCAL_CONFIG_PATH),
+        config_slot(project_root, REPO_CONFIG_FILE_NAMES),
+        vec![project_root.join(REPO_DOT_CONFIG_FILE_NAME)],
+        config_slot(ito_path, ITO_DIR_CONFIG_FILE_NAMES),
+        vec![ito_path.join(ITO_DIR_LOCAL_CONFIG_FILE_NAME)],
+        vec![project_root.join(PROJECT_LOCAL_CONFIG_PATH)],
     ]);
     if let Some(p) = &ctx.project_dir {
-        out.push(p.join(ITO_DIR_CONFIG_FILE_NAME));
+        out.push(vec![p.join(ITO_DIR_CONFIG_FILE_NAME)]);
     }
 
     out
@@ -351,9 +444,10 @@ pub fn project_config_paths(
 ///
 /// Precedence (low -> high):
 /// 1) `~/.config/ito/config.json` (global defaults; XDG-aware)
-/// 2) `<repo-root>/ito.json`
+/// 2) `<repo-root>/ito.json` (or `ito.toml` / `ito.yaml`)
 /// 3) `<repo-root>/.ito.json`
-/// 4) `<itoDir>/config.json` (team/project defaults, typically committed)
+/// 4) `<itoDir>/config.json` (or `config.toml` / `config.yaml`; team/project
+///    defaults, typically committed)
 /// 5) `<itoDir>/config.local.json` (per-developer overrides, gitignored)
 /// 6) `<repo-root>/.local/ito/config.json` (optional per-developer overrides, gitignored)
 /// 7) `$PROJECT_DIR/config.json` (when set)
@@ -404,12 +498,27 @@ pub fn load_cascading_project_config_fs<F: FileSystem>(
     let mut merged = defaults::default_config_json();
     let mut loaded_from: Vec<PathBuf> = Vec::new();
     let mut layers: Vec<ResolvedConfigLayer> = Vec::new();
+    let mut shadowed: Vec<PathBuf> = Vec::new();
 
-    let paths = project_config_paths(project_root, ito_path, ctx);
-    for path in paths {
+    for slot in project_config_slots(project_root, ito_path, ctx) {
+        let Some((path, slot_shadowed)) = select_slot_file(fs, &slot) else {
+            crate::detail!("config: {} not loaded", slot[0].display());
+            continue;
+        };
+        for ignored in &slot_shadowed {
+            crate::notice!(
+                "Warning: Both {} and {} exist; using {}",
+                path.display(),
+                ignored.display(),
+                path.display()
+            );
+        }
+        shadowed.extend(slot_shadowed);
         let Some(mut v) = load_json_object_fs(fs, &path) else {
+            crate::detail!("config: {} not loaded", path.display());
             continue;
         };
+        crate::detail!("config: loaded {}", path.display());
         // Migrate legacy camelCase worktree keys before merging so that
         // the new key names participate in the normal merge process and
         // override defaults correctly.
@@ -427,6 +536,7 @@ pub fn load_cascading_project_config_fs<F: FileSystem>(
         merged,
         loaded_from,
         layers,
+        shadowed,
     }
 }
 
@@ -461,6 +571,18 @@ pub fn ito_config_dir(ctx: &ConfigContext) -> Option<PathBuf> {
     }
 }
 
+/// Return the per-user data directory (`$XDG_DATA_HOME/ito`, falling back to
+/// `~/.local/share/ito`).
+///
+/// User schema overrides live under `<data dir>/schemas`.
+pub fn ito_data_dir(ctx: &ConfigContext) -> Option<PathBuf> {
+    let data_home = match std::env::var("XDG_DATA_HOME") {
+        Ok(v) if !v.trim().is_empty() => PathBuf::from(v),
+        _ => ctx.home_dir.as_ref()?.join(".local").join("share"),
+    };
+    Some(data_home.join("ito"))
+}
+
 /// Load the global config file.
 pub fn load_global_config(ctx: &ConfigContext) -> GlobalConfig {
     load_global_config_fs(&StdFs, ctx)
@@ -479,7 +601,7 @@ pub fn load_global_config_fs<F: FileSystem>(fs: &F, ctx: &ConfigContext) -> Glob
     match serde_json::from_str(&contents) {
         Ok(v) => v,
         Err(_) => {
-            eprintln!(
+            crate::notice!(
                 "Warning: Invalid JSON in {}, using defaults",
                 path.display()
             );
@@ -510,7 +632,7 @@ pub fn load_global_ito_config_fs<F: FileSystem>(fs: &F, ctx: &ConfigContext) ->
     match serde_json::from_str(&contents) {
         Ok(v) => v,
         Err(_) => {
-            eprintln!(
+            crate::notice!(
                 "Warning: Invalid JSON in {}, using defaults",
                 path.display()
             );
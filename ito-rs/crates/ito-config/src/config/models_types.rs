@@ -0,0 +1,91 @@
+//! Configuration types for pinning the models Ito agent templates use.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Models pinned per harness, keyed by tool id.
+///
+/// Unset entries fall back to the defaults embedded in the agent templates.
+/// Unknown harness or tier keys are rejected (`deny_unknown_fields`) so a typo
+/// such as `claude-code` does not silently leave the embedded model in place.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+#[schemars(description = "Models pinned per harness and agent tier")]
+pub struct ModelsConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Claude Code agent models")]
+    /// Claude Code agent models.
+    pub claude: Option<ModelTierPins>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Codex agent models")]
+    /// Codex agent models.
+    pub codex: Option<ModelTierPins>,
+
+    #[serde(
+        default,
+        rename = "github-copilot",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schemars(description = "GitHub Copilot agent models")]
+    /// GitHub Copilot agent models.
+    pub github_copilot: Option<ModelTierPins>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "OpenCode agent models")]
+    /// OpenCode agent models.
+    pub opencode: Option<ModelTierPins>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Pi agent models")]
+    /// Pi agent models.
+    pub pi: Option<ModelTierPins>,
+}
+
+impl ModelsConfig {
+    /// Pins for the harness with tool id `tool` (e.g. `github-copilot`).
+    pub fn for_tool(&self, tool: &str) -> Option<&ModelTierPins> {
+        match tool {
+            "claude" => self.claude.as_ref(),
+            "codex" => self.codex.as_ref(),
+            "github-copilot" => self.github_copilot.as_ref(),
+            "opencode" => self.opencode.as_ref(),
+            "pi" => self.pi.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Model ids for the `ito-quick`, `ito-general` and `ito-thinking` agents.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+#[schemars(description = "Model ids for each agent tier")]
+pub struct ModelTierPins {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Model for the ito-quick agent")]
+    /// Model for the `ito-quick` agent.
+    pub quick: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Model for the ito-general agent (also used by untiered agents)")]
+    /// Model for the `ito-general` agent and untiered agents that take a model.
+    pub general: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Model for the ito-thinking agent")]
+    /// Model for the `ito-thinking` agent.
+    pub thinking: Option<String>,
+}
+
+impl ModelTierPins {
+    /// Pinned model for the tier named `tier` (`quick`, `general` or `thinking`).
+    pub fn for_tier(&self, tier: &str) -> Option<&str> {
+        let model = match tier {
+            "quick" => &self.quick,
+            "general" => &self.general,
+            "thinking" => &self.thinking,
+            _ => return None,
+        };
+        model.as_deref()
+    }
+}
@@ -0,0 +1,33 @@
+//! Configuration types for harness tool permissions.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "Tool permissions granted to harness sessions")]
+/// File-edit and command permissions granted to a harness session.
+///
+/// Applies when `--allow-all` is off. Path patterns are globs relative to the
+/// working directory; command patterns are prefixes. A deny entry wins over
+/// an allow entry with the same pattern.
+pub struct HarnessPermissionsConfig {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(description = "Path globs the agent may edit without asking")]
+    /// Path globs the agent may edit without asking.
+    pub allow_paths: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(description = "Path globs the agent must not edit")]
+    /// Path globs the agent must not edit.
+    pub deny_paths: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(description = "Command prefixes the agent may run without asking")]
+    /// Command prefixes the agent may run without asking.
+    pub allow_commands: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(description = "Command prefixes the agent must not run")]
+    /// Command prefixes the agent must not run.
+    pub deny_commands: Vec<String>,
+}
@@ -0,0 +1,89 @@
+//! Configuration types for named change profiles.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "Defaults applied to changes created with this profile")]
+/// Defaults applied to changes created with a profile.
+///
+/// A change records its profile in `.ito.yaml`; `ito ralph` and `ito run`
+/// resolve unset flags from it. Explicit command-line flags always win.
+pub struct ProfileConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Schema used by `ito create change --profile` when --schema is omitted"
+    )]
+    /// Schema used when creating a change without `--schema`.
+    pub schema: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Harness used when --harness is omitted")]
+    /// Harness used when `--harness` is omitted.
+    pub harness: Option<ProfileHarness>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Model id passed to the harness when --model is omitted")]
+    /// Model id passed to the harness when `--model` is omitted.
+    pub model: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Ralph loop defaults")]
+    /// Ralph loop defaults.
+    pub ralph: Option<ProfileRalphConfig>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(description = "Harness selected by a profile")]
+/// Harness selected by a profile.
+pub enum ProfileHarness {
+    /// The OpenCode harness.
+    Opencode,
+    /// The Claude Code harness.
+    Claude,
+    /// The OpenAI Codex harness.
+    Codex,
+    /// The GitHub Copilot harness.
+    Copilot,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "Ralph loop defaults for a profile")]
+/// Ralph loop defaults for a profile; unset fields keep the CLI defaults.
+pub struct ProfileRalphConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Minimum iterations before a completion promise is honored")]
+    /// Minimum iterations before a completion promise is honored.
+    pub min_iterations: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Maximum iterations")]
+    /// Maximum iterations.
+    pub max_iterations: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Inactivity timeout (e.g. 15m)")]
+    /// Inactivity timeout (e.g. `15m`).
+    pub timeout: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Do not create git commits per iteration")]
+    /// Do not create git commits per iteration.
+    pub no_commit: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Exit immediately when the harness exits non-zero")]
+    /// Exit immediately when the harness exits non-zero.
+    pub exit_on_error: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Skip completion validation")]
+    /// Skip completion validation.
+    pub skip_validation: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Extra validation command run on a completion promise")]
+    /// Extra validation command run on a completion promise.
+    pub validation_command: Option<String>,
+}
@@ -1,14 +1,41 @@
 //! JSON schema generation for Ito config.
 
 use schemars::schema_for;
-use serde_json::Value;
+use serde_json::{Value, json};
 
+use super::defaults::default_config_json;
 use super::types::ItoConfig;
 
+/// Legacy keys still accepted (and migrated) by config loading.
+///
+/// Each entry is `(definition, key, replacement, item schema)`.
+const DEPRECATED_KEYS: &[(&str, &str, &str, &str)] = &[
+    (
+        "WorktreesConfig",
+        "defaultBranch",
+        "worktrees.default_branch",
+        "string",
+    ),
+    (
+        "WorktreesConfig",
+        "localFiles",
+        "worktrees.apply.copy_from_main",
+        "string[]",
+    ),
+];
+
 /// Generate the JSON schema (as JSON) for [`ItoConfig`].
+///
+/// Field descriptions, enum values, and per-field defaults come from the
+/// config types. Top-level defaults missing from the derived schema are filled
+/// from [`default_config_json`], and deprecated legacy keys are listed with
+/// `deprecated: true` and a replacement hint.
 pub fn config_schema_json() -> Value {
     let schema = schema_for!(ItoConfig);
-    serde_json::to_value(&schema).expect("config schema should serialize to json")
+    let mut value = serde_json::to_value(&schema).expect("config schema should serialize to json");
+    embed_top_level_defaults(&mut value, &default_config_json());
+    add_deprecated_keys(&mut value);
+    value
 }
 
 /// Generate the JSON schema (pretty-printed) for [`ItoConfig`].
@@ -16,6 +43,41 @@ pub fn config_schema_pretty_json() -> String {
     serde_json::to_string_pretty(&config_schema_json()).unwrap_or_else(|_| "{}".to_string())
 }
 
+fn embed_top_level_defaults(schema: &mut Value, defaults: &Value) {
+    let (Some(properties), Some(defaults)) = (
+        schema.get_mut("properties").and_then(Value::as_object_mut),
+        defaults.as_object(),
+    ) else {
+        return;
+    };
+
+    for (key, default) in defaults {
+        if let Some(Value::Object(property)) = properties.get_mut(key) {
+            property.entry("default").or_insert_with(|| default.clone());
+        }
+    }
+}
+
+fn add_deprecated_keys(schema: &mut Value) {
+    for (definition, key, replacement, kind) in DEPRECATED_KEYS {
+        let Some(properties) = schema
+            .pointer_mut(&format!("/definitions/{definition}/properties"))
+            .and_then(Value::as_object_mut)
+        else {
+            continue;
+        };
+
+        let mut property = match *kind {
+            "string[]" => json!({ "type": "array", "items": { "type": "string" } }),
+            other => json!({ "type": other }),
+        };
+        property["deprecated"] = Value::Bool(true);
+        property["description"] = Value::String(format!("Deprecated: use `{replacement}` instead"));
+        property["x-replacedBy"] = Value::String((*replacement).to_string());
+        properties.insert((*key).to_string(), property);
+    }
+}
+
 #[cfg(test)]
 #[path = "schema_tests.rs"]
 mod schema_tests;
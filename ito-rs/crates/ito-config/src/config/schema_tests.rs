@@ -36,3 +36,227 @@ fn schema_describes_proposal_integration_mode_and_default() {
     assert!(encoded.contains("direct_merge"));
     assert!(!encoded.contains("pull_request_auto_merge"));
 }
+
+#[test]
+fn schema_describes_and_defaults_representative_keys() {
+    let schema = config_schema_json();
+
+    let top = &schema["properties"];
+    for key in ["projectPath", "cache", "worktrees", "changes", "audit"] {
+        assert!(
+            top[key]["description"].is_string(),
+            "missing description for {key}"
+        );
+    }
+    assert_eq!(top["cache"]["default"]["ttl_hours"], 24);
+    assert_eq!(top["worktrees"]["default"]["enabled"], false);
+
+    let worktrees = &schema["definitions"]["WorktreesConfig"]["properties"];
+    assert_eq!(worktrees["default_branch"]["default"], "main");
+    assert_eq!(worktrees["strategy"]["default"], "checkout_subdir");
+    assert!(worktrees["strategy"]["description"].is_string());
+
+    let apply = &schema["definitions"]["WorktreeApplyConfig"]["properties"];
+    assert_eq!(apply["integration_mode"]["default"], "commit_pr");
+    assert!(apply["copy_from_main"]["description"].is_string());
+}
+
+#[test]
+fn schema_lists_enum_values_for_worktree_settings() {
+    let schema = config_schema_json();
+
+    let strategies = serde_json::to_string(&schema["definitions"]["WorktreeStrategy"]).unwrap();
+    for value in [
+        "checkout_subdir",
+        "checkout_siblings",
+        "bare_control_siblings",
+    ] {
+        assert!(strategies.contains(value), "missing strategy {value}");
+    }
+
+    let modes = serde_json::to_string(&schema["definitions"]["IntegrationMode"]).unwrap();
+    assert!(modes.contains("commit_pr"));
+    assert!(modes.contains("merge_parent"));
+}
+
+#[test]
+fn schema_marks_legacy_worktree_keys_deprecated() {
+    let schema = config_schema_json();
+    let worktrees = &schema["definitions"]["WorktreesConfig"]["properties"];
+
+    let default_branch = &worktrees["defaultBranch"];
+    assert_eq!(default_branch["deprecated"], true);
+    assert_eq!(default_branch["x-replacedBy"], "worktrees.default_branch");
+    assert!(
+        default_branch["description"]
+            .as_str()
+            .unwrap()
+            .contains("worktrees.default_branch")
+    );
+
+    let local_files = &worktrees["localFiles"];
+    assert_eq!(local_files["deprecated"], true);
+    assert_eq!(local_files["type"], "array");
+    assert_eq!(
+        local_files["x-replacedBy"],
+        "worktrees.apply.copy_from_main"
+    );
+}
+
+#[test]
+fn schema_validates_default_config() {
+    let schema = config_schema_json();
+    let config = crate::config::defaults::default_config_json();
+
+    let mut errors = Vec::new();
+    validate_value(&schema, &schema, &config, "$", &mut errors);
+    assert!(
+        errors.is_empty(),
+        "default config violates schema: {errors:#?}"
+    );
+
+    let invalid = serde_json::json!({ "worktrees": { "strategy": "nope" } });
+    let mut errors = Vec::new();
+    validate_value(&schema, &schema, &invalid, "$", &mut errors);
+    assert!(!errors.is_empty(), "invalid strategy should be rejected");
+}
+
+#[test]
+fn schema_validates_profiles_and_rejects_unknown_harness() {
+    let schema = config_schema_json();
+
+    let valid = serde_json::json!({ "profiles": { "bugfix": {
+        "schema": "tdd",
+        "harness": "claude",
+        "model": "sonnet",
+        "ralph": { "min_iterations": 2, "no_commit": true, "timeout": "15m" }
+    } } });
+    let mut errors = Vec::new();
+    validate_value(&schema, &schema, &valid, "$", &mut errors);
+    assert!(errors.is_empty(), "valid profile rejected: {errors:#?}");
+
+    let invalid = serde_json::json!({ "profiles": { "bugfix": { "harness": "nope" } } });
+    let mut errors = Vec::new();
+    validate_value(&schema, &schema, &invalid, "$", &mut errors);
+    assert!(!errors.is_empty(), "unknown harness should be rejected");
+}
+
+#[test]
+fn schema_validates_harness_permissions_and_permission_profiles() {
+    let schema = config_schema_json();
+
+    let valid = serde_json::json!({
+        "harnesses": { "opencode": { "permissions": {
+            "allow_paths": ["src/**"],
+            "allow_commands": ["cargo test"]
+        } } },
+        "permission_profiles": { "read-only": { "deny_paths": ["**"] } }
+    });
+    let mut errors = Vec::new();
+    validate_value(&schema, &schema, &valid, "$", &mut errors);
+    assert!(errors.is_empty(), "valid permissions rejected: {errors:#?}");
+
+    let invalid =
+        serde_json::json!({ "permission_profiles": { "read-only": { "deny_paths": "**" } } });
+    let mut errors = Vec::new();
+    validate_value(&schema, &schema, &invalid, "$", &mut errors);
+    assert!(
+        !errors.is_empty(),
+        "non-array deny_paths should be rejected"
+    );
+}
+
+/// Minimal JSON schema validator covering the keywords the config schema uses.
+fn validate_value(
+    root: &serde_json::Value,
+    schema: &serde_json::Value,
+    value: &serde_json::Value,
+    path: &str,
+    errors: &mut Vec<String>,
+) {
+    if let Some(reference) = schema.get("$ref").and_then(|r| r.as_str()) {
+        let pointer = reference.trim_start_matches('#');
+        let resolved = root.pointer(pointer).expect("resolvable $ref");
+        validate_value(root, resolved, value, path, errors);
+        return;
+    }
+
+    if let Some(all) = schema.get("allOf").and_then(|v| v.as_array()) {
+        for item in all {
+            validate_value(root, item, value, path, errors);
+        }
+    }
+
+    for key in ["anyOf", "oneOf"] {
+        if let Some(branches) = schema.get(key).and_then(|v| v.as_array()) {
+            let matched = branches.iter().any(|branch| {
+                let mut branch_errors = Vec::new();
+                validate_value(root, branch, value, path, &mut branch_errors);
+                branch_errors.is_empty()
+            });
+            if !matched {
+                errors.push(format!("{path}: no {key} branch matches {value}"));
+            }
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(|v| v.as_array())
+        && !allowed.contains(value)
+    {
+        errors.push(format!("{path}: {value} is not one of {allowed:?}"));
+    }
+
+    if let Some(expected) = schema.get("const")
+        && expected != value
+    {
+        errors.push(format!("{path}: expected const {expected}"));
+    }
+
+    if let Some(types) = schema.get("type") {
+        let types: Vec<&str> = match types {
+            serde_json::Value::String(t) => vec![t.as_str()],
+            serde_json::Value::Array(ts) => ts.iter().filter_map(|t| t.as_str()).collect(),
+            _ => Vec::new(),
+        };
+        let ok = types.iter().any(|t| match *t {
+            "null" => value.is_null(),
+            "boolean" => value.is_boolean(),
+            "string" => value.is_string(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "number" => value.is_number(),
+            "array" => value.is_array(),
+            "object" => value.is_object(),
+            _ => false,
+        });
+        if !ok {
+            errors.push(format!("{path}: {value} is not of type {types:?}"));
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        let properties = schema.get("properties").and_then(|p| p.as_object());
+        for (key, child) in object {
+            let child_path = format!("{path}.{key}");
+            match properties.and_then(|p| p.get(key)) {
+                Some(child_schema) => {
+                    validate_value(root, child_schema, child, &child_path, errors)
+                }
+                None => match schema.get("additionalProperties") {
+                    Some(serde_json::Value::Bool(false)) if properties.is_some() => {
+                        errors.push(format!("{child_path}: unexpected property"));
+                    }
+                    Some(extra) if extra.is_object() => {
+                        validate_value(root, extra, child, &child_path, errors);
+                    }
+                    _ => {}
+                },
+            }
+        }
+    }
+
+    if let (Some(items), Some(array)) = (schema.get("items"), value.as_array()) {
+        for (idx, item) in array.iter().enumerate() {
+            validate_value(root, items, item, &format!("{path}[{idx}]"), errors);
+        }
+    }
+}
@@ -81,6 +81,16 @@ pub const CONFIG_SETUP_COVERAGE: &[ConfigSetupCoverageEntry] = &[
         coverage: ConfigSetupCoverage::RuntimeOnly,
         reason: "audit mirroring is operational behavior",
     },
+    ConfigSetupCoverageEntry {
+        path: "validation",
+        coverage: ConfigSetupCoverage::RuntimeOnly,
+        reason: "custom validation rules are project conventions applied by `ito validate`",
+    },
+    ConfigSetupCoverageEntry {
+        path: "tasks",
+        coverage: ConfigSetupCoverage::RuntimeOnly,
+        reason: "task staleness thresholds are read when task status is reported",
+    },
     ConfigSetupCoverageEntry {
         path: "repository",
         coverage: ConfigSetupCoverage::RuntimeOnly,
@@ -101,6 +111,41 @@ pub const CONFIG_SETUP_COVERAGE: &[ConfigSetupCoverageEntry] = &[
         coverage: ConfigSetupCoverage::RuntimeOnly,
         reason: "agent memory providers are consumed by instruction rendering at runtime",
     },
+    ConfigSetupCoverageEntry {
+        path: "init",
+        coverage: ConfigSetupCoverage::InitManaged,
+        reason: "tool selection is recorded by `ito init` and reused by `ito update`",
+    },
+    ConfigSetupCoverageEntry {
+        path: "install",
+        coverage: ConfigSetupCoverage::RuntimeOnly,
+        reason: "line-ending policy and schema server are edited directly and consulted whenever templates are written",
+    },
+    ConfigSetupCoverageEntry {
+        path: "templates",
+        coverage: ConfigSetupCoverage::RuntimeOnly,
+        reason: "the template release pin is edited directly and read by `ito update`",
+    },
+    ConfigSetupCoverageEntry {
+        path: "stats",
+        coverage: ConfigSetupCoverage::RuntimeOnly,
+        reason: "model pricing and context windows are edited directly and only read by `ito stats cost` and harness runs",
+    },
+    ConfigSetupCoverageEntry {
+        path: "models",
+        coverage: ConfigSetupCoverage::RuntimeOnly,
+        reason: "model pins are edited directly and applied whenever agent templates are written",
+    },
+    ConfigSetupCoverageEntry {
+        path: "profiles",
+        coverage: ConfigSetupCoverage::RuntimeOnly,
+        reason: "profiles are edited directly and resolved when changes are created or run",
+    },
+    ConfigSetupCoverageEntry {
+        path: "permission_profiles",
+        coverage: ConfigSetupCoverage::RuntimeOnly,
+        reason: "permission policies are edited directly and selected by `--permissions-profile`",
+    },
 ];
 
 /// Return the most specific coverage entry for a config path.
@@ -0,0 +1,54 @@
+//! Configuration types for usage statistics and harness cost estimation.
+
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "Usage statistics and harness cost estimation")]
+/// Usage statistics and harness cost estimation settings.
+pub struct StatsConfig {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[schemars(
+        description = "Per-model prices (USD per million tokens) keyed by the model id passed to the harness"
+    )]
+    /// Per-model prices keyed by the model id passed to the harness.
+    ///
+    /// Ito ships no built-in prices; models missing here are reported as
+    /// unknown by `ito stats cost`.
+    pub pricing: BTreeMap<String, ModelPricing>,
+
+    #[serde(
+        default,
+        rename = "contextWindows",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    #[schemars(
+        description = "Per-model context windows (tokens) keyed by the model id passed to the harness; prompts are checked against them before sending"
+    )]
+    /// Per-model context windows in tokens, keyed by the model id passed to
+    /// the harness.
+    ///
+    /// Harness runs warn (or fail with `--strict-budget`) when the estimated
+    /// prompt size exceeds the window. Models missing here are not checked.
+    pub context_windows: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "Price of one model in USD per million tokens")]
+/// Price of one model in US dollars per million tokens.
+pub struct ModelPricing {
+    #[schemars(description = "Price of uncached input tokens")]
+    /// Price of uncached input tokens.
+    pub input_per_mtok: f64,
+
+    #[schemars(description = "Price of output tokens")]
+    /// Price of output tokens.
+    pub output_per_mtok: f64,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Price of cache-read input tokens (defaults to the input price)")]
+    /// Price of cache-read input tokens; defaults to the input price.
+    pub cache_read_per_mtok: Option<f64>,
+}
@@ -11,8 +11,18 @@ use serde_json::Value;
 
 // Re-export backend server types from the dedicated submodule.
 pub use super::backend_types::*;
+// Re-export agent model pin types from the dedicated submodule.
+pub use super::models_types::*;
+// Re-export harness permission types from the dedicated submodule.
+pub use super::permission_types::*;
+// Re-export profile types from the dedicated submodule.
+pub use super::profile_types::*;
 // Re-export proposal integration types from the dedicated submodule.
 pub use super::proposal_types::*;
+// Re-export stats types from the dedicated submodule.
+pub use super::stats_types::*;
+// Re-export validation rule types from the dedicated submodule.
+pub use super::validation_types::*;
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[schemars(description = "Top-level Ito configuration")]
@@ -63,6 +73,16 @@ pub struct ItoConfig {
     /// Audit logging and mirroring configuration.
     pub audit: AuditConfig,
 
+    #[serde(default)]
+    #[schemars(default, description = "Artifact validation settings")]
+    /// Artifact validation settings, including user-defined rules.
+    pub validation: ValidationConfig,
+
+    #[serde(default)]
+    #[schemars(default, description = "Task tracking settings")]
+    /// Task tracking settings.
+    pub tasks: TasksConfig,
+
     #[serde(default)]
     #[schemars(default, description = "Repository runtime configuration")]
     /// Repository runtime configuration for local persistence.
@@ -90,6 +110,117 @@ pub struct ItoConfig {
     /// is configured independently and can pick either a skill delegation or an
     /// inline command template — see [`MemoryOpConfig`].
     pub memory: Option<MemoryConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Selections recorded by `ito init`")]
+    /// Selections recorded by `ito init` and reused by `ito update`.
+    pub init: Option<InitConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "How `ito init` / `ito update` write template files")]
+    /// How `ito init` and `ito update` write template files.
+    pub install: Option<InstallConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Which release's template assets `ito update` installs")]
+    /// Which release's template assets `ito update` installs.
+    pub templates: Option<TemplatesConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Usage statistics and harness cost estimation")]
+    /// Usage statistics and harness cost estimation settings.
+    pub stats: Option<StatsConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Models pinned per harness and agent tier")]
+    /// Models the installed Ito agents use, per harness and tier.
+    ///
+    /// Read by `ito init` and `ito update`; unset entries keep the embedded
+    /// defaults.
+    pub models: Option<ModelsConfig>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[schemars(
+        description = "Named profiles of schema, harness, model, and ralph defaults; `default` applies to changes without a recorded profile"
+    )]
+    /// Named change profiles.
+    ///
+    /// `ito create change --profile <name>` records the profile in the change's
+    /// `.ito.yaml`; the `default` profile applies when none is recorded.
+    pub profiles: BTreeMap<String, ProfileConfig>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[schemars(description = "Named tool permission policies selected with --permissions-profile")]
+    /// Named tool permission policies selected with `--permissions-profile`.
+    pub permission_profiles: BTreeMap<String, HarnessPermissionsConfig>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "Selections recorded by ito init")]
+/// Selections recorded by `ito init`.
+pub struct InitConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "AI tool ids installed by init; `ito update` reuses them when --tools is omitted"
+    )]
+    /// AI tool ids installed by init.
+    ///
+    /// `ito update` reuses this selection when `--tools` is omitted so adapters
+    /// the user originally installed are refreshed rather than dropped.
+    pub tools: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "How ito init / ito update write template files")]
+/// How `ito init` and `ito update` write template files.
+pub struct InstallConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Line endings for files Ito creates; existing files keep their own dominant line ending"
+    )]
+    /// Line endings for files Ito creates.
+    ///
+    /// Existing files always keep their dominant line ending when updated.
+    pub line_endings: Option<LineEndingPolicy>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Base URL of an `ito serve` instance; new config.json files then reference <url>/schema/config.json instead of .ito/config.schema.json"
+    )]
+    /// Base URL of an `ito serve` instance (e.g. `http://127.0.0.1:9009`).
+    ///
+    /// When set, new `config.json` files point `$schema` at the server's
+    /// `/schema/config.json` instead of the on-disk `config.schema.json`.
+    pub schema_server: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "Which release's template assets ito update installs")]
+/// Which release's template assets `ito update` installs.
+pub struct TemplatesConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Release tag (e.g. v0.2.1) whose published template bundle replaces the embedded templates; files missing from the bundle fall back to the embedded copies"
+    )]
+    /// Release tag whose template bundle `ito update` installs (e.g. `v0.2.1`).
+    ///
+    /// The bundle is downloaded once and cached under the global config
+    /// directory. Files missing from it fall back to the embedded templates.
+    pub pin: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[schemars(description = "Line-ending policy for installed template files")]
+/// Line-ending policy for files written by the installer.
+pub enum LineEndingPolicy {
+    /// Match the repository convention, falling back to LF.
+    #[default]
+    Auto,
+    /// Always write LF.
+    Lf,
+    /// Always write CRLF.
+    Crlf,
 }
 
 /// Agent memory configuration — per-operation provider selection.
@@ -274,6 +405,33 @@ impl Default for AuditMirrorConfig {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "Task tracking settings")]
+/// Task tracking settings.
+pub struct TasksConfig {
+    #[serde(default = "TasksConfig::default_stale_after", rename = "staleAfter")]
+    #[schemars(
+        default = "TasksConfig::default_stale_after",
+        description = "How long an in-progress task may go untouched before it is reported as stale (e.g. \"24h\", \"3d\")"
+    )]
+    /// How long an in-progress task may go untouched before it is stale.
+    pub stale_after: String,
+}
+
+impl TasksConfig {
+    fn default_stale_after() -> String {
+        "24h".to_string()
+    }
+}
+
+impl Default for TasksConfig {
+    fn default() -> Self {
+        Self {
+            stale_after: Self::default_stale_after(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[schemars(description = "Backend state API configuration")]
 /// Backend state API configuration for multi-agent coordination.
@@ -409,6 +567,15 @@ pub struct CoordinationBranchConfig {
     )]
     /// Minimum interval in seconds between redundant coordination sync pushes.
     pub sync_interval_seconds: u64,
+
+    #[serde(default)]
+    #[schemars(
+        default,
+        description = "Also sync change artifacts (proposal, specs, design) in `ito change sync`, not only task status and change metadata"
+    )]
+    /// Also sync change artifacts in `ito change sync`, not only task status
+    /// and change metadata.
+    pub sync_artifacts: bool,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema)]
@@ -439,6 +606,7 @@ impl Default for CoordinationBranchConfig {
             storage: CoordinationStorage::default(),
             worktree_path: None,
             sync_interval_seconds: Self::default_sync_interval_seconds(),
+            sync_artifacts: false,
         }
     }
 }
@@ -633,6 +801,37 @@ pub struct OpenCodeHarnessConfig {
     )]
     /// Ito agent tier model mappings.
     pub agents: AgentTiersConfig,
+
+    #[serde(
+        default,
+        rename = "promptBudgetTokens",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schemars(
+        description = "Approximate token budget for Ralph prompts (omitted means no budget)"
+    )]
+    /// Approximate token budget for Ralph prompts sent to this harness.
+    ///
+    /// When omitted, prompts are not truncated.
+    pub prompt_budget_tokens: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Environment passed to harness subprocesses (omitted means inherit everything)"
+    )]
+    /// Environment policy for harness subprocesses.
+    ///
+    /// When omitted, the harness inherits the full parent environment.
+    pub env_policy: Option<HarnessEnvPolicyConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Tool permissions for sessions without --allow-all (omitted means the harness prompts)"
+    )]
+    /// Tool permissions for sessions without `--allow-all`.
+    ///
+    /// `--permissions-profile` replaces this with a named policy.
+    pub permissions: Option<HarnessPermissionsConfig>,
 }
 
 impl Default for OpenCodeHarnessConfig {
@@ -640,6 +839,9 @@ impl Default for OpenCodeHarnessConfig {
         Self {
             provider: None,
             agents: Self::default_agents(),
+            prompt_budget_tokens: None,
+            env_policy: None,
+            permissions: None,
         }
     }
 }
@@ -682,6 +884,37 @@ pub struct ClaudeCodeHarnessConfig {
     )]
     /// Ito agent tier model mappings.
     pub agents: AgentTiersConfig,
+
+    #[serde(
+        default,
+        rename = "promptBudgetTokens",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schemars(
+        description = "Approximate token budget for Ralph prompts (omitted means no budget)"
+    )]
+    /// Approximate token budget for Ralph prompts sent to this harness.
+    ///
+    /// When omitted, prompts are not truncated.
+    pub prompt_budget_tokens: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Environment passed to harness subprocesses (omitted means inherit everything)"
+    )]
+    /// Environment policy for harness subprocesses.
+    ///
+    /// When omitted, the harness inherits the full parent environment.
+    pub env_policy: Option<HarnessEnvPolicyConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Tool permissions for sessions without --allow-all (omitted means the harness prompts)"
+    )]
+    /// Tool permissions for sessions without `--allow-all`.
+    ///
+    /// `--permissions-profile` replaces this with a named policy.
+    pub permissions: Option<HarnessPermissionsConfig>,
 }
 
 impl Default for ClaudeCodeHarnessConfig {
@@ -689,6 +922,9 @@ impl Default for ClaudeCodeHarnessConfig {
         Self {
             provider: Some(ProviderAnthropic::Anthropic),
             agents: Self::default_agents(),
+            prompt_budget_tokens: None,
+            env_policy: None,
+            permissions: None,
         }
     }
 }
@@ -721,6 +957,37 @@ pub struct CodexHarnessConfig {
     )]
     /// Ito agent tier model mappings.
     pub agents: AgentTiersConfig,
+
+    #[serde(
+        default,
+        rename = "promptBudgetTokens",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schemars(
+        description = "Approximate token budget for Ralph prompts (omitted means no budget)"
+    )]
+    /// Approximate token budget for Ralph prompts sent to this harness.
+    ///
+    /// When omitted, prompts are not truncated.
+    pub prompt_budget_tokens: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Environment passed to harness subprocesses (omitted means inherit everything)"
+    )]
+    /// Environment policy for harness subprocesses.
+    ///
+    /// When omitted, the harness inherits the full parent environment.
+    pub env_policy: Option<HarnessEnvPolicyConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Tool permissions for sessions without --allow-all (omitted means the harness prompts)"
+    )]
+    /// Tool permissions for sessions without `--allow-all`.
+    ///
+    /// `--permissions-profile` replaces this with a named policy.
+    pub permissions: Option<HarnessPermissionsConfig>,
 }
 
 impl Default for CodexHarnessConfig {
@@ -728,6 +995,9 @@ impl Default for CodexHarnessConfig {
         Self {
             provider: Some(ProviderOpenAi::OpenAi),
             agents: Self::default_agents(),
+            prompt_budget_tokens: None,
+            env_policy: None,
+            permissions: None,
         }
     }
 }
@@ -768,6 +1038,37 @@ pub struct GitHubCopilotHarnessConfig {
     )]
     /// Ito agent tier model mappings.
     pub agents: AgentTiersConfig,
+
+    #[serde(
+        default,
+        rename = "promptBudgetTokens",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schemars(
+        description = "Approximate token budget for Ralph prompts (omitted means no budget)"
+    )]
+    /// Approximate token budget for Ralph prompts sent to this harness.
+    ///
+    /// When omitted, prompts are not truncated.
+    pub prompt_budget_tokens: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Environment passed to harness subprocesses (omitted means inherit everything)"
+    )]
+    /// Environment policy for harness subprocesses.
+    ///
+    /// When omitted, the harness inherits the full parent environment.
+    pub env_policy: Option<HarnessEnvPolicyConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Tool permissions for sessions without --allow-all (omitted means the harness prompts)"
+    )]
+    /// Tool permissions for sessions without `--allow-all`.
+    ///
+    /// `--permissions-profile` replaces this with a named policy.
+    pub permissions: Option<HarnessPermissionsConfig>,
 }
 
 impl Default for GitHubCopilotHarnessConfig {
@@ -775,6 +1076,9 @@ impl Default for GitHubCopilotHarnessConfig {
         Self {
             provider: Some(ProviderGitHubCopilot::GitHubCopilot),
             agents: Self::default_agents(),
+            prompt_budget_tokens: None,
+            env_policy: None,
+            permissions: None,
         }
     }
 }
@@ -789,6 +1093,50 @@ impl GitHubCopilotHarnessConfig {
     }
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "Harness subprocess environment policy")]
+/// Which parent environment variables a harness subprocess receives.
+///
+/// `PATH`, `HOME` and `TERM` are always passed through.
+pub struct HarnessEnvPolicyConfig {
+    #[serde(default)]
+    #[schemars(
+        default,
+        description = "How the parent environment is passed to the harness"
+    )]
+    /// How the parent environment is passed to the harness.
+    pub mode: HarnessEnvMode,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(description = "Variables passed through in allow-list mode")]
+    /// Variables passed through in `allow-list` mode.
+    pub allow: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Variables that must be set before the harness starts (omitted means the harness defaults)"
+    )]
+    /// Variables that must be set before the harness starts.
+    ///
+    /// They are always passed through. When omitted, the harness defaults
+    /// apply (`ANTHROPIC_API_KEY` for Claude Code, `OPENAI_API_KEY` for Codex).
+    pub required: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[schemars(description = "Harness environment mode")]
+/// How the parent environment reaches a harness subprocess.
+pub enum HarnessEnvMode {
+    /// Pass the full parent environment.
+    #[default]
+    Inherit,
+    /// Pass only the `allow` list plus required variables.
+    AllowList,
+    /// Pass only required variables.
+    Clean,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 /// Allowed providers for Anthropic-backed harnesses.
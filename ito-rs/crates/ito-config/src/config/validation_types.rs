@@ -0,0 +1,92 @@
+//! Configuration types for user-defined validation rules.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "Artifact validation settings")]
+/// Settings consumed by `ito validate` for changes, specs, and modules.
+pub struct ValidationConfig {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(
+        default,
+        description = "User-defined validation rules executed alongside the built-in rules"
+    )]
+    /// User-defined rules declared by the project.
+    ///
+    /// Rules are compiled once per validation run. Definitions that cannot be
+    /// compiled (bad regex, unknown target, no checks) are reported as
+    /// configuration warnings instead of being skipped silently.
+    pub custom_rules: Vec<CustomValidationRuleConfig>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "User-defined validation rule")]
+/// One user-defined validation rule.
+///
+/// `target` and `level` are kept as plain strings so that a typo surfaces as a
+/// configuration warning from `ito validate` rather than making the whole
+/// config file fail to deserialize.
+pub struct CustomValidationRuleConfig {
+    #[schemars(description = "Stable rule id attached to every issue this rule reports")]
+    /// Stable rule id attached to every issue this rule reports.
+    pub id: String,
+
+    #[schemars(
+        description = "Text the rule inspects: spec_requirement, scenario, proposal_section, module_purpose, or delta_description",
+        example = "CustomValidationRuleConfig::example_target"
+    )]
+    /// Text the rule inspects.
+    ///
+    /// One of `spec_requirement`, `scenario`, `proposal_section`,
+    /// `module_purpose`, or `delta_description`.
+    pub target: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Proposal section heading (required for proposal_section)")]
+    /// Proposal `## <section>` heading to inspect when `target` is
+    /// `proposal_section`.
+    pub section: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Regex the target text must match")]
+    /// Regex the target text must match.
+    pub must_match: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Regex the target text must not match")]
+    /// Regex the target text must not match.
+    pub must_not_match: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Maximum length of the target text in characters")]
+    /// Maximum length of the trimmed target text, in characters.
+    pub max_length: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Minimum length of the target text in characters")]
+    /// Minimum length of the trimmed target text, in characters.
+    pub min_length: Option<usize>,
+
+    #[schemars(description = "Message reported when the rule fails")]
+    /// Message reported when the rule fails.
+    pub message: String,
+
+    #[serde(default = "CustomValidationRuleConfig::default_level")]
+    #[schemars(
+        default = "CustomValidationRuleConfig::default_level",
+        description = "Issue level: error, warning, or info"
+    )]
+    /// Issue level: `error`, `warning`, or `info`.
+    pub level: String,
+}
+
+impl CustomValidationRuleConfig {
+    fn default_level() -> String {
+        "warning".to_string()
+    }
+
+    fn example_target() -> &'static str {
+        "spec_requirement"
+    }
+}
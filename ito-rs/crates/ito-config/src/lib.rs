@@ -13,7 +13,7 @@
 /// Resolve the Ito working directory name and path.
 pub mod ito_dir;
 
-/// Console/UI behavior (color and interactivity) derived from CLI + env.
+/// Console/UI behavior (color, interactivity, verbosity) derived from CLI + env.
 pub mod output;
 
 mod config;
@@ -2,8 +2,15 @@
 //!
 //! These helpers translate CLI flags + environment variables into a single
 //! `UiOptions` struct used by higher-level crates.
+//!
+//! The module also holds the process-wide [`Verbosity`]. The CLI sets it once
+//! from `-q/--quiet`, `-v/--verbose`, and `--json`; library crates print
+//! progress through the [`info!`](crate::info), [`notice!`](crate::notice),
+//! and [`detail!`](crate::detail) macros so that setting is honored without
+//! threading it through every call.
 
 use std::io::IsTerminal;
+use std::sync::atomic::{AtomicU8, Ordering};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// UI behavior flags.
@@ -78,5 +85,89 @@ pub fn resolve_interactive(
     }
 }
 
+/// How much non-essential output a command prints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Verbosity {
+    /// Errors and the command's own result only.
+    Quiet = 0,
+    /// Progress messages and warnings (the default).
+    #[default]
+    Normal = 1,
+    /// Adds diagnostic detail such as prompt previews, per-file installer
+    /// actions, and which config files were loaded.
+    Verbose = 2,
+}
+
+impl Verbosity {
+    /// Resolve the verbosity from the global CLI flags.
+    ///
+    /// `json` forces [`Verbosity::Quiet`] so machine-readable output is never
+    /// mixed with progress chatter, whatever `-v` says.
+    pub fn resolve(quiet: bool, verbose: bool, json: bool) -> Self {
+        if quiet || json {
+            Self::Quiet
+        } else if verbose {
+            Self::Verbose
+        } else {
+            Self::Normal
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Quiet,
+            2 => Self::Verbose,
+            _ => Self::Normal,
+        }
+    }
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+/// Set the process-wide verbosity.
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+/// Current process-wide verbosity.
+pub fn verbosity() -> Verbosity {
+    Verbosity::from_u8(VERBOSITY.load(Ordering::Relaxed))
+}
+
+/// Return `true` when output at `level` should be printed.
+pub fn enabled(level: Verbosity) -> bool {
+    verbosity() >= level
+}
+
+/// Print a progress line to stdout unless running quietly.
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::output::enabled($crate::output::Verbosity::Normal) {
+            println!($($arg)*);
+        }
+    };
+}
+
+/// Print a warning or progress line to stderr unless running quietly.
+#[macro_export]
+macro_rules! notice {
+    ($($arg:tt)*) => {
+        if $crate::output::enabled($crate::output::Verbosity::Normal) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Print a diagnostic line to stderr only when running verbosely.
+#[macro_export]
+macro_rules! detail {
+    ($($arg:tt)*) => {
+        if $crate::output::enabled($crate::output::Verbosity::Verbose) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
 #[cfg(test)]
 mod output_tests;
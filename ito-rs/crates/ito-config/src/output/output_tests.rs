@@ -43,3 +43,26 @@ fn resolve_ui_options_combines_sources() {
         }
     );
 }
+
+#[test]
+fn verbosity_resolution_lets_json_and_quiet_win() {
+    assert_eq!(Verbosity::resolve(false, false, false), Verbosity::Normal);
+    assert_eq!(Verbosity::resolve(false, true, false), Verbosity::Verbose);
+    assert_eq!(Verbosity::resolve(true, false, false), Verbosity::Quiet);
+    assert_eq!(Verbosity::resolve(false, true, true), Verbosity::Quiet);
+}
+
+#[test]
+fn set_verbosity_controls_enabled_levels() {
+    set_verbosity(Verbosity::Quiet);
+    assert!(enabled(Verbosity::Quiet));
+    assert!(!enabled(Verbosity::Normal));
+
+    set_verbosity(Verbosity::Verbose);
+    assert!(enabled(Verbosity::Normal));
+    assert!(enabled(Verbosity::Verbose));
+
+    set_verbosity(Verbosity::Normal);
+    assert_eq!(verbosity(), Verbosity::Normal);
+    assert!(!enabled(Verbosity::Verbose));
+}
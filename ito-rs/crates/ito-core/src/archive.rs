@@ -4,23 +4,31 @@
 //! deltas back into the main `specs/` tree.
 //!
 //! This module also includes a small helper for determining whether a
-//! `tasks.md` file is fully complete.
+//! `tasks.md` file is fully complete, and the pre-archive readiness check that
+//! refuses to archive changes with outstanding work unless forced.
 
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use chrono::Utc;
 
 use crate::error_bridge::IntoCoreResult;
 use crate::errors::{CoreError, CoreResult};
 use crate::module_repository::FsModuleRepository;
+use crate::process::{ProcessRequest, ProcessRunner};
+use crate::templates::{TemplatesError, compute_change_status};
 use ito_common::fs::StdFs;
 use ito_common::id::parse_change_id;
 use ito_common::paths;
+use ito_config::ConfigContext;
+use ito_domain::tasks::{TaskRepository, TaskStatus as DomainTaskStatus};
+use serde::Serialize;
 
 #[path = "archive_specs.rs"]
 mod archive_specs;
 
+pub use archive_specs::SpecDeltaConflict;
+
 pub(crate) fn reconcile_spec_markdown(
     base: Option<&str>,
     delta: &str,
@@ -103,6 +111,130 @@ pub fn check_task_completion(contents: &str) -> TaskStatus {
     TaskStatus::HasIncomplete { pending, total }
 }
 
+/// A task that is still pending or in progress at archive time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OutstandingTask {
+    /// Task id.
+    pub id: String,
+    /// Task name.
+    pub name: String,
+    /// Task status (`pending` or `in-progress`).
+    pub status: String,
+}
+
+/// Work still outstanding on a change when it is about to be archived.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ArchiveOutstanding {
+    /// Pending or in-progress tasks, in file order.
+    pub tasks: Vec<OutstandingTask>,
+    /// Required schema artifacts that are neither done nor skipped.
+    pub artifacts: Vec<String>,
+}
+
+impl ArchiveOutstanding {
+    /// `true` when nothing blocks the archive.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty() && self.artifacts.is_empty()
+    }
+
+    /// Human-readable list of what is outstanding, one item per line.
+    pub fn describe(&self) -> String {
+        let mut lines = Vec::new();
+        if !self.tasks.is_empty() {
+            lines.push(format!("Incomplete tasks ({}):", self.tasks.len()));
+            for task in &self.tasks {
+                lines.push(format!("  - {} [{}] {}", task.id, task.status, task.name));
+            }
+        }
+        if !self.artifacts.is_empty() {
+            lines.push(format!(
+                "Incomplete required artifacts ({}):",
+                self.artifacts.len()
+            ));
+            for artifact in &self.artifacts {
+                lines.push(format!("  - {artifact}"));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+/// Collect the work still outstanding on `change_name` before archiving.
+///
+/// Tasks are loaded through `task_repo`, so any persistence mode works.
+/// Artifact completeness is checked against the change's schema only when the
+/// change directory exists locally.
+pub fn check_archive_readiness(
+    task_repo: &dyn TaskRepository,
+    ito_path: &Path,
+    change_name: &str,
+    ctx: &ConfigContext,
+) -> CoreResult<ArchiveOutstanding> {
+    let parsed = task_repo.load_tasks(change_name).into_core()?;
+    let tasks = parsed
+        .tasks
+        .iter()
+        .filter(|task| {
+            matches!(
+                task.status,
+                DomainTaskStatus::Pending | DomainTaskStatus::InProgress
+            )
+        })
+        .map(|task| OutstandingTask {
+            id: task.id.clone(),
+            name: task.name.clone(),
+            status: task.status.as_enhanced_label().to_string(),
+        })
+        .collect();
+
+    let mut artifacts = Vec::new();
+    if paths::change_dir(ito_path, change_name).is_dir() {
+        match compute_change_status(ito_path, change_name, None, ctx) {
+            Ok(status) => {
+                for artifact in status.artifacts {
+                    if !matches!(artifact.status.as_str(), "done" | "skipped" | "optional") {
+                        artifacts.push(artifact.id);
+                    }
+                }
+            }
+            Err(TemplatesError::ChangeNotFound(_)) => {}
+            Err(e) => return Err(CoreError::validation(e.to_string())),
+        }
+    }
+
+    Ok(ArchiveOutstanding { tasks, artifacts })
+}
+
+/// Paths under the change directory with uncommitted git changes.
+///
+/// Best effort: outside a git repository, or when git fails, the result is
+/// empty.
+pub fn uncommitted_change_paths(
+    runner: &dyn ProcessRunner,
+    ito_path: &Path,
+    change_name: &str,
+) -> Vec<String> {
+    let change_dir = paths::change_dir(ito_path, change_name);
+    let Some(change_dir) = change_dir.to_str() else {
+        return Vec::new();
+    };
+    let request = ProcessRequest::new("git")
+        .args(["status", "--porcelain", "--", change_dir])
+        .current_dir(ito_path.parent().unwrap_or(ito_path));
+    let Ok(output) = runner.run(&request) else {
+        return Vec::new();
+    };
+    if !output.success {
+        return Vec::new();
+    }
+    output
+        .stdout
+        .lines()
+        .filter(|line| line.len() > 3)
+        .map(|line| line[3..].trim().to_string())
+        .collect()
+}
+
 /// List available change directories under `{ito_path}/changes`.
 pub fn list_available_changes(ito_path: &Path) -> CoreResult<Vec<String>> {
     let fs = StdFs;
@@ -178,36 +310,180 @@ pub fn categorize_specs(ito_path: &Path, spec_names: &[String]) -> (Vec<String>,
     (new_specs, existing_specs)
 }
 
+/// The spec that would result from applying a change's delta, before archiving.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SpecDeltaPreview {
+    /// Merged spec markdown; `None` when the delta retires the spec.
+    pub merged: Option<String>,
+    /// Delta operations that cannot be applied; they are left out of `merged`.
+    pub conflicts: Vec<SpecDeltaConflict>,
+    /// Unified diff from the current spec to `merged` (empty when unchanged).
+    pub diff: String,
+}
+
+/// Preview applying a delta spec (a change's `specs/<id>/spec.md`) to `base`.
+///
+/// This is the same merge archiving performs, except that operations which
+/// conflict with `base` are reported instead of failing the merge.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Validation`] when either document is malformed.
+pub fn apply_deltas_preview(base: Option<&str>, delta: &str) -> CoreResult<SpecDeltaPreview> {
+    preview_with_labels(base, delta, "current", "preview")
+}
+
+/// Preview how `change_name`'s delta for `spec` would change the main spec.
+///
+/// # Errors
+///
+/// Returns [`CoreError::NotFound`] when the change has no delta for `spec`,
+/// and [`CoreError::Validation`] when either document is malformed.
+pub fn preview_change_spec(
+    ito_path: &Path,
+    change_name: &str,
+    spec: &str,
+) -> CoreResult<SpecDeltaPreview> {
+    let Some((base, delta)) = read_spec_delta(ito_path, change_name, spec)? else {
+        return Err(CoreError::not_found(format!(
+            "Change '{change_name}' has no delta for spec '{spec}'"
+        )));
+    };
+    let label = format!("specs/{spec}/spec.md");
+    preview_with_labels(
+        base.as_deref(),
+        &delta,
+        &label,
+        &format!("{label} (after {change_name})"),
+    )
+}
+
+fn preview_with_labels(
+    base: Option<&str>,
+    delta: &str,
+    from_label: &str,
+    to_label: &str,
+) -> CoreResult<SpecDeltaPreview> {
+    let reconciliation = archive_specs::reconcile_spec_with_conflicts(base, delta)?;
+    let diff = spec_diff(
+        base.unwrap_or_default(),
+        reconciliation.markdown.as_deref().unwrap_or_default(),
+        from_label,
+        to_label,
+    );
+    Ok(SpecDeltaPreview {
+        merged: reconciliation.markdown,
+        conflicts: reconciliation.conflicts,
+        diff,
+    })
+}
+
+/// Read the current main spec (if any) and the change's delta for `spec`.
+fn read_spec_delta(
+    ito_path: &Path,
+    change_name: &str,
+    spec: &str,
+) -> CoreResult<Option<(Option<String>, String)>> {
+    let src = paths::change_specs_dir(ito_path, change_name)
+        .join(spec)
+        .join("spec.md");
+    if !src.exists() {
+        return Ok(None);
+    }
+    let delta = ito_common::io::read_to_string_std(&src)
+        .map_err(|e| CoreError::io(format!("reading spec {}", src.display()), e))?;
+    let dst = paths::spec_markdown_path(ito_path, spec);
+    let base = if dst.exists() {
+        Some(
+            ito_common::io::read_to_string_std(&dst)
+                .map_err(|e| CoreError::io(format!("reading spec {}", dst.display()), e))?,
+        )
+    } else {
+        None
+    };
+    Ok(Some((base, delta)))
+}
+
+fn spec_diff(before: &str, after: &str, from_label: &str, to_label: &str) -> String {
+    if before == after {
+        return String::new();
+    }
+    let patch = diffy::create_patch(before, after).to_string();
+    // Replace diffy's `original`/`modified` labels with the spec paths.
+    let hunks: String = patch
+        .lines()
+        .skip_while(|l| l.starts_with("--- ") || l.starts_with("+++ "))
+        .map(|l| format!("{l}\n"))
+        .collect();
+    format!("--- {from_label}\n+++ {to_label}\n{hunks}")
+}
+
 /// Reconcile change spec deltas into the main specs tree.
 ///
 /// Returns the list of spec ids that were written.
+///
+/// # Errors
+///
+/// Fails without writing anything when any delta conflicts with its current
+/// spec; see [`copy_specs_to_main_skipping_conflicts`] to apply the rest.
 pub fn copy_specs_to_main(
     ito_path: &Path,
     change_name: &str,
     spec_names: &[String],
 ) -> CoreResult<Vec<String>> {
+    let (updated, conflicts) = write_specs_to_main(ito_path, change_name, spec_names, false)?;
+    debug_assert!(conflicts.is_empty());
+    Ok(updated)
+}
+
+/// A conflicting delta operation left unapplied, paired with its spec id.
+pub type SkippedConflict = (String, SpecDeltaConflict);
+
+/// Reconcile change spec deltas into the main specs tree, leaving out delta
+/// operations that conflict with the current spec.
+///
+/// Returns the spec ids that were written and the skipped conflicts, each
+/// paired with its spec id.
+pub fn copy_specs_to_main_skipping_conflicts(
+    ito_path: &Path,
+    change_name: &str,
+    spec_names: &[String],
+) -> CoreResult<(Vec<String>, Vec<SkippedConflict>)> {
+    write_specs_to_main(ito_path, change_name, spec_names, true)
+}
+
+fn write_specs_to_main(
+    ito_path: &Path,
+    change_name: &str,
+    spec_names: &[String],
+    skip_conflicts: bool,
+) -> CoreResult<(Vec<String>, Vec<SkippedConflict>)> {
     let mut pending = Vec::new();
+    let mut conflicts = Vec::new();
     for spec in spec_names {
-        let src = paths::change_specs_dir(ito_path, change_name)
-            .join(spec)
-            .join("spec.md");
-        if !src.exists() {
+        let Some((base, delta)) = read_spec_delta(ito_path, change_name, spec)? else {
             continue;
-        }
-        let delta = ito_common::io::read_to_string_std(&src)
-            .map_err(|e| CoreError::io(format!("reading spec {}", src.display()), e))?;
+        };
         let dst_dir = paths::specs_dir(ito_path).join(spec);
         let dst = dst_dir.join("spec.md");
-        let base = if dst.exists() {
-            Some(
-                ito_common::io::read_to_string_std(&dst)
-                    .map_err(|e| CoreError::io(format!("reading spec {}", dst.display()), e))?,
-            )
-        } else {
-            None
-        };
-        let reconciled = archive_specs::reconcile_spec(base.as_deref(), &delta)?;
-        pending.push((spec.clone(), dst_dir, dst, reconciled));
+        let reconciliation = archive_specs::reconcile_spec_with_conflicts(base.as_deref(), &delta)?;
+        conflicts.extend(
+            reconciliation
+                .conflicts
+                .into_iter()
+                .map(|conflict| (spec.clone(), conflict)),
+        );
+        pending.push((spec.clone(), dst_dir, dst, reconciliation.markdown));
+    }
+    if !skip_conflicts && !conflicts.is_empty() {
+        let details = conflicts
+            .iter()
+            .map(|(spec, conflict)| format!("  - {spec}: {}", conflict.message))
+            .collect::<Vec<_>>()
+            .join("\n");
+        return Err(CoreError::validation(format!(
+            "Spec deltas conflict with the current specs:\n{details}"
+        )));
     }
 
     let mut updated = Vec::with_capacity(pending.len());
@@ -250,7 +526,7 @@ pub fn copy_specs_to_main(
         }
         updated.push(spec);
     }
-    Ok(updated)
+    Ok((updated, conflicts))
 }
 
 /// Mark a change complete in local filesystem-backed module markdown.
@@ -290,6 +566,11 @@ pub fn mark_change_complete_in_module_markdown(
     Ok(())
 }
 
+/// Directory a change is moved to when archived as `archive_name`.
+pub fn archive_destination(ito_path: &Path, archive_name: &str) -> PathBuf {
+    paths::changes_archive_dir(ito_path).join(archive_name)
+}
+
 /// Move a change directory to the archive location.
 pub fn move_to_archive(ito_path: &Path, change_name: &str, archive_name: &str) -> CoreResult<()> {
     let change_dir = paths::change_dir(ito_path, change_name);
@@ -299,11 +580,10 @@ pub fn move_to_archive(ito_path: &Path, change_name: &str, archive_name: &str) -
         )));
     }
 
-    let archive_root = paths::changes_archive_dir(ito_path);
-    ito_common::io::create_dir_all_std(&archive_root)
+    let dst = archive_destination(ito_path, archive_name);
+    ito_common::io::create_dir_all_std(&paths::changes_archive_dir(ito_path))
         .map_err(|e| CoreError::io("creating archive directory", e))?;
 
-    let dst = archive_root.join(archive_name);
     if dst.exists() {
         return Err(CoreError::validation(format!(
             "Archive target already exists: {}",
@@ -314,3 +594,7 @@ pub fn move_to_archive(ito_path: &Path, change_name: &str, archive_name: &str) -
     fs::rename(&change_dir, &dst).map_err(|e| CoreError::io("moving change to archive", e))?;
     Ok(())
 }
+
+#[cfg(test)]
+#[path = "archive_tests.rs"]
+mod archive_tests;
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use serde::Serialize;
+
 use crate::errors::{CoreError, CoreResult};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,7 +18,44 @@ struct RequirementBlock {
     markdown: String,
 }
 
+/// A delta operation that cannot be applied to the current spec.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SpecDeltaConflict {
+    /// Delta operation (`ADDED`, `MODIFIED`, `REMOVED`, or `RENAMED`).
+    pub operation: &'static str,
+    /// Requirement title the operation targets.
+    pub requirement: String,
+    /// Why the operation cannot be applied.
+    pub message: String,
+}
+
+/// Result of reconciling a delta while collecting, rather than failing on,
+/// conflicting operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Reconciliation {
+    /// Merged spec markdown, or `None` when the spec would be retired or
+    /// nothing remains to write.
+    pub(super) markdown: Option<String>,
+    /// Operations skipped because they conflict with the current spec.
+    pub(super) conflicts: Vec<SpecDeltaConflict>,
+}
+
 pub(super) fn reconcile_spec(base: Option<&str>, delta: &str) -> CoreResult<Option<String>> {
+    let reconciliation = reconcile_spec_with_conflicts(base, delta)?;
+    if let Some(conflict) = reconciliation.conflicts.into_iter().next() {
+        return Err(CoreError::validation(conflict.message));
+    }
+    Ok(reconciliation.markdown)
+}
+
+/// Reconcile `delta` into `base`, skipping conflicting operations.
+///
+/// Malformed documents are still errors; only operations that target a
+/// missing requirement or duplicate an existing one become conflicts.
+pub(super) fn reconcile_spec_with_conflicts(
+    base: Option<&str>,
+    delta: &str,
+) -> CoreResult<Reconciliation> {
     let normalized_base = base.map(normalize_newlines);
     let normalized_delta = normalize_newlines(delta);
     let base = normalized_base.as_deref();
@@ -38,48 +77,46 @@ pub(super) fn reconcile_spec(base: Option<&str>, delta: &str) -> CoreResult<Opti
     }
     let initial_requirement_count = requirements.len();
     let mut removed_requirements = 0usize;
-    apply_renames(&mut requirements, &renames)?;
+    let mut conflicts = apply_renames(&mut requirements, &renames);
 
     for (kind, block) in delta_blocks {
         let existing = requirements
             .iter()
             .position(|candidate| candidate.name == block.name);
-        match kind {
-            DeltaKind::Added => {
-                if existing.is_some() {
-                    return Err(CoreError::validation(format!(
-                        "cannot add duplicate requirement '{}'",
-                        block.name
-                    )));
-                }
-                requirements.push(block);
-            }
-            DeltaKind::Modified => {
-                let Some(index) = existing else {
-                    return Err(CoreError::validation(format!(
-                        "cannot modify missing requirement '{}'",
-                        block.name
-                    )));
-                };
-                requirements[index] = block;
-            }
-            DeltaKind::Removed => {
-                let Some(index) = existing else {
-                    return Err(CoreError::validation(format!(
-                        "cannot remove missing requirement '{}'",
-                        block.name
-                    )));
-                };
+        let conflict = |message: String| SpecDeltaConflict {
+            operation: delta_operation_label(kind),
+            requirement: block.name.clone(),
+            message,
+        };
+        match (kind, existing) {
+            (DeltaKind::Added, Some(_)) => conflicts.push(conflict(format!(
+                "cannot add duplicate requirement '{}'",
+                block.name
+            ))),
+            (DeltaKind::Added, None) => requirements.push(block),
+            (DeltaKind::Modified, Some(index)) => requirements[index] = block,
+            (DeltaKind::Modified, None) => conflicts.push(conflict(format!(
+                "cannot modify missing requirement '{}'",
+                block.name
+            ))),
+            (DeltaKind::Removed, Some(index)) => {
                 requirements.remove(index);
                 removed_requirements += 1;
             }
-            DeltaKind::Renamed => unreachable!("renames are parsed separately"),
+            (DeltaKind::Removed, None) => conflicts.push(conflict(format!(
+                "cannot remove missing requirement '{}'",
+                block.name
+            ))),
+            (DeltaKind::Renamed, _) => unreachable!("renames are parsed separately"),
         }
     }
 
     if requirements.is_empty() {
-        if initial_requirement_count > 0 && removed_requirements > 0 {
-            return Ok(None);
+        if (initial_requirement_count > 0 && removed_requirements > 0) || !conflicts.is_empty() {
+            return Ok(Reconciliation {
+                markdown: None,
+                conflicts,
+            });
         }
         return Err(CoreError::validation(
             "delta produced an empty spec without removing existing requirements".to_string(),
@@ -116,7 +153,10 @@ pub(super) fn reconcile_spec(base: Option<&str>, delta: &str) -> CoreResult<Opti
         output.push_str(ito_templates::ITO_END_MARKER);
         output.push('\n');
     }
-    Ok(Some(output))
+    Ok(Reconciliation {
+        markdown: Some(output),
+        conflicts,
+    })
 }
 
 fn normalize_newlines(document: &str) -> Cow<'_, str> {
@@ -142,15 +182,22 @@ fn validate_requirement_headings(document: &str, source: &str) -> CoreResult<()>
 fn apply_renames(
     requirements: &mut [RequirementBlock],
     renames: &[(String, String)],
-) -> CoreResult<()> {
+) -> Vec<SpecDeltaConflict> {
+    let mut conflicts = Vec::new();
     for (from, to) in renames {
+        let conflict = |message: String| SpecDeltaConflict {
+            operation: delta_operation_label(DeltaKind::Renamed),
+            requirement: from.clone(),
+            message,
+        };
         let Some(index) = requirements
             .iter()
             .position(|requirement| requirement.name.as_str() == from.as_str())
         else {
-            return Err(CoreError::validation(format!(
+            conflicts.push(conflict(format!(
                 "cannot rename missing requirement '{from}'"
             )));
+            continue;
         };
         if requirements
             .iter()
@@ -159,9 +206,10 @@ fn apply_renames(
                 candidate != index && requirement.name.as_str() == to.as_str()
             })
         {
-            return Err(CoreError::validation(format!(
+            conflicts.push(conflict(format!(
                 "cannot rename requirement '{from}' to duplicate name '{to}'"
             )));
+            continue;
         }
         let old_header = requirements[index]
             .markdown
@@ -174,7 +222,7 @@ fn apply_renames(
                 .replacen(old_header, &format!("### Requirement: {to}"), 1);
         requirements[index].name = to.clone();
     }
-    Ok(())
+    conflicts
 }
 
 fn validate_current_spec(base: &str, requirements: &[RequirementBlock]) -> CoreResult<()> {
@@ -303,6 +351,15 @@ fn ensure_non_rename_section_has_payload(
     Ok(())
 }
 
+fn delta_operation_label(kind: DeltaKind) -> &'static str {
+    match kind {
+        DeltaKind::Added => "ADDED",
+        DeltaKind::Modified => "MODIFIED",
+        DeltaKind::Removed => "REMOVED",
+        DeltaKind::Renamed => "RENAMED",
+    }
+}
+
 fn delta_kind_name(kind: DeltaKind) -> &'static str {
     match kind {
         DeltaKind::Added => "ADDED Requirements",
@@ -0,0 +1,131 @@
+use super::*;
+use crate::process::{ProcessExecutionError, ProcessOutput};
+use crate::task_repository::FsTaskRepository;
+
+fn write(path: impl AsRef<Path>, contents: &str) {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).expect("parent dirs should exist");
+    }
+    std::fs::write(path, contents).expect("test fixture should write");
+}
+
+fn make_change(ito: &Path, id: &str, tasks: &str) {
+    let dir = ito.join("changes").join(id);
+    write(dir.join("proposal.md"), "## Why\nfixture\n");
+    write(dir.join("design.md"), "## Context\nfixture\n");
+    write(
+        dir.join("specs/alpha/spec.md"),
+        "## ADDED Requirements\n\n### Requirement: A\nText.\n",
+    );
+    write(dir.join("tasks.md"), tasks);
+}
+
+fn ctx() -> ConfigContext {
+    ConfigContext::default()
+}
+
+#[test]
+fn readiness_is_empty_for_a_finished_change() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let ito = tmp.path().join(".ito");
+    make_change(&ito, "001-01_done", "- [x] 1.1 Done\n");
+
+    let outstanding =
+        check_archive_readiness(&FsTaskRepository::new(&ito), &ito, "001-01_done", &ctx())
+            .expect("readiness");
+
+    assert!(outstanding.is_empty(), "{outstanding:?}");
+}
+
+#[test]
+fn readiness_lists_pending_and_in_progress_tasks() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let ito = tmp.path().join(".ito");
+    make_change(
+        &ito,
+        "001-01_wip",
+        "- [x] 1.1 Done\n- [~] 1.2 Working\n- [ ] 1.3 Todo\n",
+    );
+
+    let outstanding =
+        check_archive_readiness(&FsTaskRepository::new(&ito), &ito, "001-01_wip", &ctx())
+            .expect("readiness");
+
+    let ids: Vec<&str> = outstanding.tasks.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, ["1.2", "1.3"]);
+    assert_eq!(outstanding.tasks[0].status, "in-progress");
+    assert!(outstanding.describe().contains("1.3 [pending]"));
+}
+
+#[test]
+fn readiness_lists_missing_required_artifacts() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let ito = tmp.path().join(".ito");
+    make_change(&ito, "001-01_nodesign", "- [x] 1.1 Done\n");
+    std::fs::remove_file(ito.join("changes/001-01_nodesign/design.md")).expect("remove design");
+
+    let outstanding = check_archive_readiness(
+        &FsTaskRepository::new(&ito),
+        &ito,
+        "001-01_nodesign",
+        &ctx(),
+    )
+    .expect("readiness");
+
+    assert!(outstanding.tasks.is_empty());
+    assert_eq!(outstanding.artifacts, ["design"]);
+}
+
+struct StubRunner(Result<ProcessOutput, ()>);
+
+impl ProcessRunner for StubRunner {
+    fn run(&self, _request: &ProcessRequest) -> Result<ProcessOutput, ProcessExecutionError> {
+        self.0.clone().map_err(|()| ProcessExecutionError::Spawn {
+            program: "git".to_string(),
+            source: std::io::Error::other("missing"),
+        })
+    }
+
+    fn run_with_timeout(
+        &self,
+        request: &ProcessRequest,
+        _timeout: std::time::Duration,
+    ) -> Result<ProcessOutput, ProcessExecutionError> {
+        self.run(request)
+    }
+}
+
+fn output(success: bool, stdout: &str) -> ProcessOutput {
+    ProcessOutput {
+        exit_code: if success { 0 } else { 128 },
+        success,
+        stdout: stdout.to_string(),
+        stderr: String::new(),
+        timed_out: false,
+        resources: Default::default(),
+    }
+}
+
+#[test]
+fn uncommitted_paths_parse_porcelain_and_tolerate_git_failures() {
+    let ito = Path::new("/repo/.ito");
+
+    let dirty = StubRunner(Ok(output(
+        true,
+        " M .ito/changes/001-01_a/tasks.md\n?? .ito/changes/001-01_a/notes.md\n",
+    )));
+    assert_eq!(
+        uncommitted_change_paths(&dirty, ito, "001-01_a"),
+        [
+            ".ito/changes/001-01_a/tasks.md",
+            ".ito/changes/001-01_a/notes.md"
+        ]
+    );
+
+    let not_a_repo = StubRunner(Ok(output(false, "")));
+    assert!(uncommitted_change_paths(&not_a_repo, ito, "001-01_a").is_empty());
+
+    let no_git = StubRunner(Err(()));
+    assert!(uncommitted_change_paths(&no_git, ito, "001-01_a").is_empty());
+}
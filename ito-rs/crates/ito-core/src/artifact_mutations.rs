@@ -319,7 +319,7 @@ fn ensure_change_exists(
     Ok(())
 }
 
-fn artifact_path(
+pub(crate) fn artifact_path(
     ito_path: &Path,
     target: &ChangeArtifactRef,
 ) -> ChangeArtifactMutationServiceResult<PathBuf> {
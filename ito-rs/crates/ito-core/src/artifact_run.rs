@@ -0,0 +1,312 @@
+//! One-shot generation of a single change artifact through a harness.
+//!
+//! Where Ralph loops until a change is implemented, an artifact run builds one
+//! prompt for one schema artifact (for example the design doc of a change),
+//! executes it once, and checks whether the artifact's output now exists.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use ito_config::ConfigContext;
+use ito_domain::audit::context::{resolve_context, resolve_user_identity};
+use ito_domain::audit::event::{Actor, AuditEventBuilder, EntityType, ops};
+use ito_domain::audit::writer::AuditWriter;
+
+use crate::errors::{CoreError, CoreResult};
+use crate::harness::budget::check_prompt_budget;
+use crate::harness::{
+    ContextWindows, Harness, HarnessEnvPolicy, HarnessRunConfig, HarnessRunResult,
+    PermissionPolicy, PromptEstimate,
+};
+use crate::stats::cost::{HarnessUsageRecord, record_harness_usage};
+use crate::stats::resources::ResourceRecord;
+use crate::templates::{
+    InstructionsResponse, TemplatesError, artifact_done, load_composed_user_guidance,
+    resolve_rendered_instructions,
+};
+
+/// Default character budget for dependency contents inlined into the prompt.
+pub const DEFAULT_DEPENDENCY_BUDGET_CHARS: usize = 32_000;
+
+/// Options for [`build_artifact_prompt`] and [`run_artifact`].
+#[derive(Debug, Clone)]
+pub struct ArtifactRunOptions {
+    /// Schema override; `None` uses the change's schema.
+    pub schema: Option<String>,
+    /// Model identifier passed to the harness.
+    pub model: Option<String>,
+    /// Which parent environment variables the harness inherits.
+    pub env_policy: HarnessEnvPolicy,
+    /// Tool permissions when `allow_all` is off.
+    pub permissions: Option<PermissionPolicy>,
+    /// Whether tool approval and permission prompts are bypassed.
+    pub allow_all: bool,
+    /// Terminate the harness after this much time without output.
+    pub inactivity_timeout: Option<Duration>,
+    /// Maximum number of characters of dependency contents to inline.
+    pub dependency_budget_chars: usize,
+    /// Context windows of known models; the prompt is checked against the
+    /// window of `model` before it is sent.
+    pub context_windows: ContextWindows,
+    /// Fail instead of warning when the prompt likely exceeds the window.
+    pub strict_budget: bool,
+}
+
+impl Default for ArtifactRunOptions {
+    fn default() -> Self {
+        Self {
+            schema: None,
+            model: None,
+            env_policy: HarnessEnvPolicy::default(),
+            permissions: None,
+            allow_all: false,
+            inactivity_timeout: None,
+            dependency_budget_chars: DEFAULT_DEPENDENCY_BUDGET_CHARS,
+            context_windows: ContextWindows::new(),
+            strict_budget: false,
+        }
+    }
+}
+
+/// Prompt assembled for a single artifact run.
+#[derive(Debug, Clone)]
+pub struct ArtifactPrompt {
+    /// Full prompt text handed to the harness.
+    pub text: String,
+    /// Change directory the artifact is written into.
+    pub change_dir: PathBuf,
+    /// Artifact output path (relative to `change_dir`, may be a glob).
+    pub output_path: String,
+}
+
+/// Result of [`run_artifact`].
+#[derive(Debug, Clone)]
+pub struct ArtifactRunOutcome {
+    /// The prompt that was executed.
+    pub prompt: ArtifactPrompt,
+    /// Raw harness result.
+    pub result: HarnessRunResult,
+    /// Whether the artifact's output exists after the run.
+    pub produced: bool,
+}
+
+/// Build the prompt for generating `artifact_id` of `change`.
+///
+/// The prompt contains the rendered artifact instructions and template, the
+/// contents of completed dependencies (up to
+/// [`ArtifactRunOptions::dependency_budget_chars`]), and any user guidance for
+/// the artifact.
+///
+/// # Errors
+///
+/// Returns [`CoreError::NotFound`] for unknown changes, schemas, or artifacts
+/// and [`CoreError::Validation`] for invalid change names.
+pub fn build_artifact_prompt(
+    ito_path: &Path,
+    change: &str,
+    artifact_id: &str,
+    opts: &ArtifactRunOptions,
+    ctx: &ConfigContext,
+) -> CoreResult<ArtifactPrompt> {
+    let instructions =
+        resolve_rendered_instructions(ito_path, change, opts.schema.as_deref(), artifact_id, ctx)
+            .map_err(templates_error)?;
+    let guidance = load_composed_user_guidance(ito_path, artifact_id).map_err(templates_error)?;
+
+    let change_dir = PathBuf::from(&instructions.change_dir);
+    let text = render_prompt(
+        &instructions,
+        &change_dir,
+        guidance.as_deref(),
+        opts.dependency_budget_chars,
+    );
+    Ok(ArtifactPrompt {
+        text,
+        change_dir,
+        output_path: instructions.output_path,
+    })
+}
+
+/// Generate `artifact_id` of `change` with one harness run.
+///
+/// The harness runs in the project root. Afterwards the artifact's output is
+/// checked with the same rules `ito status` uses, and an `artifact_run` audit
+/// event is appended to `audit` (best-effort). A run that exits cleanly but
+/// does not produce the artifact is reported through
+/// [`ArtifactRunOutcome::produced`], not as an error.
+///
+/// # Errors
+///
+/// Returns the errors of [`build_artifact_prompt`], [`CoreError::Validation`]
+/// when `strict_budget` is set and the prompt exceeds the model's context
+/// window, and [`CoreError::Process`] when the harness cannot be executed.
+pub fn run_artifact(
+    harness: &mut dyn Harness,
+    ito_path: &Path,
+    change: &str,
+    artifact_id: &str,
+    opts: &ArtifactRunOptions,
+    ctx: &ConfigContext,
+    audit: &(impl AuditWriter + ?Sized),
+) -> CoreResult<ArtifactRunOutcome> {
+    let prompt = build_artifact_prompt(ito_path, change, artifact_id, opts, ctx)?;
+    let project_root = ito_path.parent().unwrap_or(ito_path);
+    let prompt_estimate =
+        PromptEstimate::new(&prompt.text, opts.model.as_deref(), &opts.context_windows);
+    check_prompt_budget(&prompt_estimate, opts.model.as_deref(), opts.strict_budget)?;
+
+    let result = harness
+        .run(&HarnessRunConfig {
+            prompt: prompt.text.clone(),
+            model: opts.model.clone(),
+            cwd: project_root.to_path_buf(),
+            env: std::collections::BTreeMap::new(),
+            env_policy: opts.env_policy.clone(),
+            interactive: false,
+            allow_all: opts.allow_all,
+            inactivity_timeout: opts.inactivity_timeout,
+            resume_session: None,
+            transcript_path: None,
+            permissions: opts.permissions.clone(),
+            cancel: None,
+            prompt_estimate: Some(prompt_estimate),
+        })
+        .map_err(|e| CoreError::Process(format!("Harness execution failed: {e}")))?;
+
+    // Usage stats are best-effort: a failed append must not fail the run.
+    let _ = record_harness_usage(
+        ito_path,
+        &HarnessUsageRecord {
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            source: "run".to_string(),
+            change_id: prompt
+                .change_dir
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned()),
+            harness: harness.name().as_str().to_string(),
+            model: opts.model.clone(),
+            usage: result.usage,
+            resources: Some(ResourceRecord::from(&result.resources)),
+            prompt_tokens_estimate: Some(prompt_estimate.tokens),
+            context_window: prompt_estimate.context_window,
+        },
+    );
+    ito_config::detail!("Harness resources: {}", result.resources.summary());
+
+    let produced = artifact_done(&prompt.change_dir, &prompt.output_path);
+
+    if let Some(event) = AuditEventBuilder::new()
+        .entity(EntityType::Change)
+        .entity_id(change)
+        .op(ops::CHANGE_ARTIFACT_RUN)
+        .actor(Actor::Cli)
+        .by(resolve_user_identity())
+        .meta(serde_json::json!({
+            "artifact": artifact_id,
+            "harness": harness.name().as_str(),
+            "exit_code": result.exit_code,
+            "produced": produced,
+        }))
+        .ctx(resolve_context(ito_path))
+        .build()
+    {
+        let _ = audit.append(&event);
+    }
+
+    Ok(ArtifactRunOutcome {
+        prompt,
+        result,
+        produced,
+    })
+}
+
+fn render_prompt(
+    instructions: &InstructionsResponse,
+    change_dir: &Path,
+    guidance: Option<&str>,
+    budget_chars: usize,
+) -> String {
+    let mut out = format!(
+        "# Generate `{}` for change `{}`\n\n",
+        instructions.artifact_id, instructions.change_name
+    );
+    if !instructions.description.is_empty() {
+        out.push_str(&format!("{}\n\n", instructions.description.trim()));
+    }
+    out.push_str(&format!(
+        "Write the artifact to `{}`. Do not modify other change artifacts. Stop once the artifact is written.\n\n",
+        change_dir.join(&instructions.output_path).display()
+    ));
+
+    if let Some(instruction) = &instructions.instruction {
+        out.push_str(&format!("## Instructions\n\n{}\n\n", instruction.trim()));
+    }
+    out.push_str(&format!(
+        "## Template\n\n{}\n\n",
+        instructions.template.trim()
+    ));
+
+    if !instructions.dependencies.is_empty() {
+        out.push_str("## Dependencies\n\n");
+        let mut remaining = budget_chars;
+        for dep in &instructions.dependencies {
+            out.push_str(&format!("### {} (`{}`)\n\n", dep.id, dep.path));
+            if dep.skipped {
+                out.push_str("Skipped for this change.\n\n");
+                continue;
+            }
+            if !dep.done {
+                out.push_str("Not written yet.\n\n");
+                continue;
+            }
+            if dep.path.contains('*') {
+                out.push_str(&format!(
+                    "Read the matching files under `{}`.\n\n",
+                    change_dir.display()
+                ));
+                continue;
+            }
+            let Ok(contents) = std::fs::read_to_string(change_dir.join(&dep.path)) else {
+                out.push_str("Could not be read.\n\n");
+                continue;
+            };
+            out.push_str(&take_within_budget(contents.trim(), &mut remaining));
+            out.push_str("\n\n");
+        }
+    }
+
+    if let Some(guidance) = guidance {
+        out.push_str(&format!("## User Guidance\n\n{}\n", guidance.trim()));
+    }
+    out
+}
+
+/// Take as much of `contents` as `remaining` allows, marking what was cut.
+fn take_within_budget(contents: &str, remaining: &mut usize) -> String {
+    let len = contents.chars().count();
+    if len <= *remaining {
+        *remaining -= len;
+        return contents.to_string();
+    }
+    let kept: String = contents.chars().take(*remaining).collect();
+    let removed = len - *remaining;
+    *remaining = 0;
+    if kept.is_empty() {
+        return format!("[... omitted {removed} chars: dependency budget exhausted]");
+    }
+    format!("{kept}\n[... truncated {removed} chars: dependency budget exhausted]")
+}
+
+fn templates_error(err: TemplatesError) -> CoreError {
+    match err {
+        TemplatesError::ChangeNotFound(_)
+        | TemplatesError::SchemaNotFound(_)
+        | TemplatesError::ArtifactNotFound(_) => CoreError::not_found(err.to_string()),
+        TemplatesError::Io(source) => CoreError::io("resolve artifact instructions", source),
+        other => CoreError::validation(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+#[path = "artifact_run_tests.rs"]
+mod artifact_run_tests;
@@ -0,0 +1,22 @@
+use super::*;
+
+#[test]
+fn take_within_budget_keeps_contents_that_fit() {
+    let mut remaining = 10;
+    assert_eq!(take_within_budget("hello", &mut remaining), "hello");
+    assert_eq!(remaining, 5);
+}
+
+#[test]
+fn take_within_budget_truncates_on_char_boundaries() {
+    let mut remaining = 3;
+    let out = take_within_budget("héllo wörld", &mut remaining);
+    assert_eq!(
+        out,
+        "hél\n[... truncated 8 chars: dependency budget exhausted]"
+    );
+    assert_eq!(remaining, 0);
+
+    let out = take_within_budget("next", &mut remaining);
+    assert_eq!(out, "[... omitted 4 chars: dependency budget exhausted]");
+}
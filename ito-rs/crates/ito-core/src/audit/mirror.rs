@@ -116,7 +116,7 @@ pub(crate) fn sync_audit_mirror_with_runner(
 
     let cleanup_err = cleanup.cleanup_with_runner(runner);
     if let Err(err) = cleanup_err {
-        eprintln!(
+        ito_config::notice!(
             "Warning: failed to remove temporary audit mirror worktree '{}': {}",
             cleanup.worktree_path.display(),
             err
@@ -205,7 +205,7 @@ fn append_jsonl_to_internal_branch_attempt(
 
     let cleanup_err = cleanup.cleanup_with_runner(runner);
     if let Err(err) = cleanup_err {
-        eprintln!(
+        ito_config::notice!(
             "Warning: failed to remove temporary audit worktree '{}': {}",
             cleanup.worktree_path.display(),
             err
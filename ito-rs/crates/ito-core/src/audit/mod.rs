@@ -11,17 +11,27 @@ pub mod worktree;
 pub mod writer;
 
 pub use reader::{
-    EventFilter, read_audit_events, read_audit_events_filtered,
-    read_audit_events_filtered_from_store, read_audit_events_from_store,
+    EventFilter, FilteredAuditEvents, filter_audit_events, parse_time_bound, read_audit_events,
+    read_audit_events_filtered, read_audit_events_filtered_from_store,
+    read_audit_events_filtered_with_stats, read_audit_events_filtered_with_stats_from_store,
+    read_audit_events_from_store,
 };
 pub use reconcile::{ReconcileReport, build_file_state, run_reconcile};
-pub use store::{AuditEventStore, AuditStorageLocation, default_audit_store};
-pub use stream::{StreamConfig, StreamEvent, poll_new_events, read_initial_events};
-pub use worktree::{aggregate_worktree_events, discover_worktrees, find_worktree_for_branch};
+pub use store::{
+    AuditEventStore, AuditStorageLocation, default_audit_store, fallback_audit_log_path,
+};
+pub use stream::{
+    StreamConfig, StreamEvent, StreamSource, follow_events, poll_new_events, read_initial_events,
+};
+pub use worktree::{
+    aggregate_worktree_events, aggregate_worktree_events_filtered, discover_worktrees,
+    find_worktree_for_branch,
+};
 pub use writer::FsAuditWriter;
 
 // Re-export domain audit types so adapters (ito-cli, ito-web) never need
 // a direct ito-domain dependency for audit event construction.
+pub use ito_domain::audit::attribution::{AgentAttribution, describe_actor};
 pub use ito_domain::audit::context::{resolve_context, resolve_user_identity};
 pub use ito_domain::audit::event::{
     Actor, AuditEvent, AuditEventBuilder, EntityType, EventContext, ops,
@@ -2,9 +2,11 @@
 
 use std::path::Path;
 
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use ito_domain::audit::event::AuditEvent;
 
 use super::store::AuditEventStore;
+use crate::errors::{CoreError, CoreResult};
 
 /// Filter criteria for reading audit events.
 #[derive(Debug, Default, Clone)]
@@ -15,10 +17,29 @@ pub struct EventFilter {
     pub scope: Option<String>,
     /// Only include events with this operation.
     pub op: Option<String>,
+    /// Only include events at or after this instant (inclusive).
+    pub since: Option<DateTime<Utc>>,
+    /// Only include events at or before this instant (inclusive).
+    pub until: Option<DateTime<Utc>>,
+}
+
+/// Events selected by an [`EventFilter`], plus what the time window dropped.
+#[derive(Debug, Default, Clone)]
+pub struct FilteredAuditEvents {
+    /// Matching events, in log order.
+    pub events: Vec<AuditEvent>,
+    /// Events excluded because a time window was set and their `ts` was
+    /// missing or could not be parsed.
+    pub unparseable_timestamps: usize,
 }
 
 impl EventFilter {
-    /// Check if an event matches this filter.
+    /// Whether `since` or `until` is set.
+    pub fn has_time_window(&self) -> bool {
+        self.since.is_some() || self.until.is_some()
+    }
+
+    /// Check if an event matches the non-time criteria of this filter.
     fn matches(&self, event: &AuditEvent) -> bool {
         if let Some(entity) = &self.entity
             && event.entity != *entity
@@ -62,14 +83,114 @@ pub fn read_audit_events_filtered_from_store(
     store: &dyn AuditEventStore,
     filter: &EventFilter,
 ) -> Vec<AuditEvent> {
-    let all = read_audit_events_from_store(store);
-    let mut filtered = Vec::new();
-    for event in all {
+    read_audit_events_filtered_with_stats_from_store(store, filter).events
+}
+
+/// Read audit events with a filter from the routed audit store, reporting
+/// how many events the time window excluded for unparseable timestamps.
+pub fn read_audit_events_filtered_with_stats(
+    ito_path: &Path,
+    filter: &EventFilter,
+) -> FilteredAuditEvents {
+    let store = super::store::default_audit_store(ito_path);
+    read_audit_events_filtered_with_stats_from_store(store.as_ref(), filter)
+}
+
+/// Read audit events with a filter from an injected audit store, reporting
+/// how many events the time window excluded for unparseable timestamps.
+pub fn read_audit_events_filtered_with_stats_from_store(
+    store: &dyn AuditEventStore,
+    filter: &EventFilter,
+) -> FilteredAuditEvents {
+    filter_audit_events(read_audit_events_from_store(store), filter)
+}
+
+/// Apply `filter` to events in log order.
+///
+/// Events are append-ordered, so iteration stops at the first event whose
+/// timestamp is after `filter.until`. Without a time window, events are
+/// kept regardless of whether their timestamp parses.
+pub fn filter_audit_events(
+    events: impl IntoIterator<Item = AuditEvent>,
+    filter: &EventFilter,
+) -> FilteredAuditEvents {
+    let mut out = FilteredAuditEvents::default();
+    let windowed = filter.has_time_window();
+
+    for event in events {
+        if windowed {
+            let Some(ts) = parse_event_timestamp(&event.ts) else {
+                out.unparseable_timestamps += 1;
+                continue;
+            };
+            if let Some(until) = filter.until
+                && ts > until
+            {
+                break;
+            }
+            if let Some(since) = filter.since
+                && ts < since
+            {
+                continue;
+            }
+        }
         if filter.matches(&event) {
-            filtered.push(event);
+            out.events.push(event);
         }
     }
-    filtered
+    out
+}
+
+fn parse_event_timestamp(ts: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(ts.trim())
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Parse a human time bound such as `7d`, `2h`, `2025-01-01`, or an RFC 3339
+/// timestamp.
+///
+/// Relative durations (`s`, `m`, `h`, `d`, `w`) are subtracted from `now`.
+/// Bare dates resolve to midnight UTC.
+///
+/// # Errors
+///
+/// Returns a validation error when `raw` matches none of the accepted forms.
+pub fn parse_time_bound(raw: &str, now: DateTime<Utc>) -> CoreResult<DateTime<Utc>> {
+    let input = raw.trim();
+    let invalid = || {
+        CoreError::validation(format!(
+            "Invalid time '{raw}': expected a duration like 7d or 2h, a date like 2025-01-01, or an RFC 3339 timestamp"
+        ))
+    };
+
+    if let Some(unit) = input.chars().last()
+        && unit.is_ascii_alphabetic()
+    {
+        let amount = &input[..input.len() - 1];
+        if !amount.is_empty() && amount.bytes().all(|b| b.is_ascii_digit()) {
+            let n: i64 = amount.parse().map_err(|_| invalid())?;
+            let delta = match unit {
+                's' => Duration::try_seconds(n),
+                'm' => Duration::try_minutes(n),
+                'h' => Duration::try_hours(n),
+                'd' => Duration::try_days(n),
+                'w' => Duration::try_weeks(n),
+                _ => None,
+            }
+            .ok_or_else(invalid)?;
+            return now.checked_sub_signed(delta).ok_or_else(invalid);
+        }
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        return date
+            .and_hms_opt(0, 0, 0)
+            .map(|dt| dt.and_utc())
+            .ok_or_else(invalid);
+    }
+
+    parse_event_timestamp(input).ok_or_else(invalid)
 }
 
 #[cfg(test)]
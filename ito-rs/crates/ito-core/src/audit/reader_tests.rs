@@ -213,6 +213,7 @@ fn combined_filters() {
         entity: Some("task".to_string()),
         scope: Some("ch-1".to_string()),
         op: Some("create".to_string()),
+        ..Default::default()
     };
     let events = read_audit_events_filtered(&ito_path, &filter);
     assert_eq!(events.len(), 1);
@@ -230,3 +231,166 @@ fn reads_events_from_injected_store() {
     assert_eq!(events.len(), 1);
     assert_eq!(events[0].entity_id, "1.1");
 }
+
+fn make_event_at(ts: &str, entity_id: &str) -> AuditEvent {
+    let mut event = make_event("task", entity_id, Some("ch"), "create");
+    event.ts = ts.to_string();
+    event
+}
+
+fn utc(raw: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(raw)
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn multi_day_store() -> MemoryAuditStore {
+    MemoryAuditStore {
+        events: vec![
+            make_event_at("2026-02-01T09:00:00Z", "d1"),
+            make_event_at("2026-02-02T00:00:00Z", "d2-midnight"),
+            make_event_at("2026-02-02T12:00:00Z", "d2-noon"),
+            make_event_at("2026-02-03T00:00:00Z", "d3-midnight"),
+            make_event_at("2026-02-04T18:30:00Z", "d4"),
+        ],
+    }
+}
+
+fn ids(events: &[AuditEvent]) -> Vec<&str> {
+    events.iter().map(|e| e.entity_id.as_str()).collect()
+}
+
+#[test]
+fn time_window_bounds_are_inclusive() {
+    let filter = EventFilter {
+        since: Some(utc("2026-02-02T00:00:00Z")),
+        until: Some(utc("2026-02-03T00:00:00Z")),
+        ..Default::default()
+    };
+
+    let events = read_audit_events_filtered_from_store(&multi_day_store(), &filter);
+
+    assert_eq!(ids(&events), vec!["d2-midnight", "d2-noon", "d3-midnight"]);
+}
+
+#[test]
+fn since_only_keeps_events_from_bound_onwards() {
+    let filter = EventFilter {
+        since: Some(utc("2026-02-03T00:00:00Z")),
+        ..Default::default()
+    };
+
+    let events = read_audit_events_filtered_from_store(&multi_day_store(), &filter);
+
+    assert_eq!(ids(&events), vec!["d3-midnight", "d4"]);
+}
+
+#[test]
+fn until_stops_at_first_event_past_bound() {
+    let mut store = multi_day_store();
+    // Out-of-order tail: reading stops at d4, so this is never reached.
+    store
+        .events
+        .push(make_event_at("2026-02-01T10:00:00Z", "late-append"));
+    let filter = EventFilter {
+        until: Some(utc("2026-02-02T12:00:00Z")),
+        ..Default::default()
+    };
+
+    let events = read_audit_events_filtered_from_store(&store, &filter);
+
+    assert_eq!(ids(&events), vec!["d1", "d2-midnight", "d2-noon"]);
+}
+
+#[test]
+fn time_window_combines_with_field_filters() {
+    let mut store = multi_day_store();
+    store.events[2].op = "status_change".to_string();
+    let filter = EventFilter {
+        op: Some("create".to_string()),
+        since: Some(utc("2026-02-02T00:00:00Z")),
+        until: Some(utc("2026-02-03T00:00:00Z")),
+        ..Default::default()
+    };
+
+    let events = read_audit_events_filtered_from_store(&store, &filter);
+
+    assert_eq!(ids(&events), vec!["d2-midnight", "d3-midnight"]);
+}
+
+#[test]
+fn unparseable_timestamps_kept_without_time_window() {
+    let mut store = multi_day_store();
+    store.events.insert(1, make_event_at("", "missing"));
+    store
+        .events
+        .insert(2, make_event_at("yesterday", "garbage"));
+
+    let result = read_audit_events_filtered_with_stats_from_store(&store, &EventFilter::default());
+
+    assert_eq!(result.events.len(), 7);
+    assert_eq!(result.unparseable_timestamps, 0);
+}
+
+#[test]
+fn unparseable_timestamps_excluded_and_counted_with_time_window() {
+    let mut store = multi_day_store();
+    store.events.insert(1, make_event_at("", "missing"));
+    store
+        .events
+        .insert(2, make_event_at("yesterday", "garbage"));
+    let filter = EventFilter {
+        since: Some(utc("2026-02-01T00:00:00Z")),
+        ..Default::default()
+    };
+
+    let result = read_audit_events_filtered_with_stats_from_store(&store, &filter);
+
+    assert_eq!(result.events.len(), 5);
+    assert_eq!(result.unparseable_timestamps, 2);
+}
+
+#[test]
+fn parse_time_bound_accepts_relative_durations() {
+    let now = utc("2026-02-10T12:00:00Z");
+
+    assert_eq!(
+        parse_time_bound("7d", now).unwrap(),
+        utc("2026-02-03T12:00:00Z")
+    );
+    assert_eq!(
+        parse_time_bound("2h", now).unwrap(),
+        utc("2026-02-10T10:00:00Z")
+    );
+    assert_eq!(
+        parse_time_bound("30m", now).unwrap(),
+        utc("2026-02-10T11:30:00Z")
+    );
+    assert_eq!(
+        parse_time_bound("1w", now).unwrap(),
+        utc("2026-02-03T12:00:00Z")
+    );
+}
+
+#[test]
+fn parse_time_bound_accepts_dates_and_timestamps() {
+    let now = utc("2026-02-10T12:00:00Z");
+
+    assert_eq!(
+        parse_time_bound("2025-01-01", now).unwrap(),
+        utc("2025-01-01T00:00:00Z")
+    );
+    assert_eq!(
+        parse_time_bound("2025-01-01T08:15:00+02:00", now).unwrap(),
+        utc("2025-01-01T06:15:00Z")
+    );
+}
+
+#[test]
+fn parse_time_bound_rejects_garbage() {
+    let now = utc("2026-02-10T12:00:00Z");
+
+    for raw in ["", "d", "7x", "-3d", "yesterday", "2025-13-01"] {
+        assert!(parse_time_bound(raw, now).is_err(), "{raw}");
+    }
+}
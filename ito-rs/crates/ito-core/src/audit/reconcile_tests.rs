@@ -1,5 +1,5 @@
 use super::*;
-use ito_domain::audit::event::{AuditEvent, EventContext, SCHEMA_VERSION};
+use ito_domain::audit::event::{AuditEvent, EventContext, SCHEMA_VERSION, ops};
 fn test_ctx() -> EventContext {
     EventContext {
         session_id: "test".to_string(),
@@ -127,6 +127,37 @@ fn reconcile_no_drift() {
     assert_eq!(report.events_written, 0);
 }
 
+#[test]
+fn reconcile_ignores_project_events() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let ito_path = tmp.path().join(".ito");
+
+    write_tasks(
+        tmp.path(),
+        "ch",
+        "# Tasks\n\n## Wave 1\n\n### Task 1.1: Test\n- **Status**: [ ] pending\n",
+    );
+
+    let writer = default_audit_store(&ito_path);
+    writer
+        .append(&make_event("1.1", "ch", "create", Some("pending")))
+        .unwrap();
+    for op in [ops::PROJECT_INIT, ops::PROJECT_UPDATE] {
+        let mut event = make_event("demo", "ch", op, None);
+        event.entity = "project".to_string();
+        event.scope = None;
+        writer.append(&event).unwrap();
+    }
+
+    let report = run_reconcile(&ito_path, Some("ch"), true);
+    assert!(report.drifts.is_empty(), "{:?}", report.drifts);
+    assert_eq!(report.events_written, 0);
+
+    let report = run_reconcile(&ito_path, None, true);
+    assert!(report.drifts.is_empty(), "{:?}", report.drifts);
+    assert_eq!(report.events_written, 0);
+}
+
 #[test]
 fn reconcile_detects_drift() {
     let tmp = tempfile::tempdir().expect("tempdir");
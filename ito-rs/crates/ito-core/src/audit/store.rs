@@ -203,7 +203,7 @@ impl LocalAuditStore {
                 }
                 Err(err) => {
                     tracing::warn!("legacy tracked audit log import failed: {err}");
-                    eprintln!(
+                    ito_config::notice!(
                         "Warning: durable internal audit storage unavailable; migrating legacy tracked audit log into local fallback store '{}': {err}",
                         self.fallback_path.display()
                     );
@@ -229,7 +229,7 @@ impl LocalAuditStore {
 
     fn warn_and_fallback(&self, err: &str, event: &AuditEvent) {
         tracing::warn!("internal audit branch unavailable: {err}");
-        eprintln!(
+        ito_config::notice!(
             "Warning: durable internal audit storage unavailable; using local fallback store '{}': {err}",
             self.fallback_path.display()
         );
@@ -312,7 +312,8 @@ fn resolve_internal_audit_branch(ito_path: &Path, ctx: &ConfigContext) -> String
     branch
 }
 
-fn fallback_audit_log_path(ito_path: &Path) -> PathBuf {
+/// Local file that receives audit events when the internal branch cannot be written.
+pub fn fallback_audit_log_path(ito_path: &Path) -> PathBuf {
     let runner = SystemProcessRunner;
     if let Some(project_root) = ito_path.parent()
         && let Some(git_dir) = git_dir_path(&runner, project_root)
@@ -3,15 +3,25 @@
 //! Provides a simple polling mechanism that checks routed audit storage for
 //! new events at a configurable interval. Supports monitoring events across
 //! multiple git worktrees without assuming a tracked worktree JSONL file.
+//!
+//! Filesystem-backed stores are tailed incrementally: each poll reads only the
+//! bytes appended since the previous poll, and a file that shrinks or is
+//! replaced (truncation, rotation) is re-opened from the start.
 
 use std::collections::HashSet;
-use std::path::Path;
-use std::time::Duration;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
 use ito_domain::audit::event::AuditEvent;
 
-use super::store::{AuditEventStore, audit_storage_location_key, default_audit_store};
+use super::store::{
+    AuditEventStore, AuditStorageLocation, audit_storage_location_key, default_audit_store,
+};
 use super::worktree::discover_worktrees;
+use super::writer::parse_events_from_jsonl;
 
 /// Configuration for the event stream.
 #[derive(Debug, Clone)]
@@ -22,6 +32,8 @@ pub struct StreamConfig {
     pub all_worktrees: bool,
     /// Number of initial events to emit on startup.
     pub last: usize,
+    /// Only emit events scoped to this change.
+    pub scope: Option<String>,
 }
 
 impl Default for StreamConfig {
@@ -30,6 +42,7 @@ impl Default for StreamConfig {
             poll_interval: Duration::from_millis(500),
             all_worktrees: false,
             last: 10,
+            scope: None,
         }
     }
 }
@@ -46,6 +59,175 @@ pub struct StreamSource {
     /// underlying log is truncated or rotated, a shorter read is treated as a
     /// reset and the next append-only growth resumes from the new length.
     offset: usize,
+    /// Incremental tail used instead of `offset` for filesystem-backed stores.
+    tail: Option<FileTail>,
+    /// Only emit events scoped to this change.
+    scope: Option<String>,
+}
+
+impl StreamSource {
+    /// Open a stream source over `store`, returning it with the last
+    /// `config.last` matching events.
+    ///
+    /// Filesystem-backed stores are tailed by byte offset so later polls do
+    /// not re-read the whole log; other stores fall back to event counting.
+    pub fn open(
+        label: impl Into<String>,
+        store: Box<dyn AuditEventStore>,
+        config: &StreamConfig,
+    ) -> (Self, Vec<StreamEvent>) {
+        let label = label.into();
+        let (all, offset, tail) = match store.location() {
+            AuditStorageLocation::Filesystem(path) => {
+                let (tail, events) = FileTail::open(path);
+                (events, 0, Some(tail))
+            }
+            AuditStorageLocation::Other(_) => {
+                let events = store.read_all();
+                let offset = events.len();
+                (events, offset, None)
+            }
+        };
+
+        let matching: Vec<AuditEvent> = all
+            .into_iter()
+            .filter(|event| scope_matches(config.scope.as_deref(), event))
+            .collect();
+        let start = matching.len().saturating_sub(config.last);
+        let events = matching[start..]
+            .iter()
+            .map(|event| StreamEvent {
+                event: event.clone(),
+                source: label.clone(),
+            })
+            .collect();
+
+        let source = Self {
+            label,
+            store,
+            offset,
+            tail,
+            scope: config.scope.clone(),
+        };
+        (source, events)
+    }
+
+    fn poll(&mut self) -> Vec<AuditEvent> {
+        let events = if let Some(tail) = &mut self.tail {
+            tail.poll()
+        } else {
+            let current_events = self.store.read_all();
+            if current_events.len() <= self.offset {
+                return Vec::new();
+            }
+            let new_events = current_events[self.offset..].to_vec();
+            self.offset = current_events.len();
+            new_events
+        };
+
+        events
+            .into_iter()
+            .filter(|event| scope_matches(self.scope.as_deref(), event))
+            .collect()
+    }
+}
+
+fn scope_matches(scope: Option<&str>, event: &AuditEvent) -> bool {
+    match scope {
+        Some(scope) => event.scope.as_deref() == Some(scope),
+        None => true,
+    }
+}
+
+/// Identity of a log file, used to notice when a path is replaced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileIdentity {
+    #[cfg(unix)]
+    dev: u64,
+    #[cfg(unix)]
+    ino: u64,
+}
+
+impl FileIdentity {
+    fn of(metadata: &std::fs::Metadata) -> Self {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            Self {
+                dev: metadata.dev(),
+                ino: metadata.ino(),
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = metadata;
+            Self {}
+        }
+    }
+}
+
+/// Byte-offset tail over a JSONL audit log.
+///
+/// Only complete lines are consumed; a partially written trailing line is
+/// left for the next poll. A missing file is treated as empty so followers can
+/// start before the first event is written.
+#[derive(Debug)]
+struct FileTail {
+    path: PathBuf,
+    /// Bytes consumed so far (always at a line boundary).
+    offset: u64,
+    identity: Option<FileIdentity>,
+}
+
+impl FileTail {
+    fn open(path: PathBuf) -> (Self, Vec<AuditEvent>) {
+        let mut tail = Self {
+            path,
+            offset: 0,
+            identity: None,
+        };
+        let events = tail.poll();
+        (tail, events)
+    }
+
+    fn poll(&mut self) -> Vec<AuditEvent> {
+        let Ok(mut file) = File::open(&self.path) else {
+            // Not created yet, or removed mid-rotation: wait for it to appear.
+            self.reset(None);
+            return Vec::new();
+        };
+        let Ok(metadata) = file.metadata() else {
+            return Vec::new();
+        };
+
+        let identity = FileIdentity::of(&metadata);
+        if self.identity != Some(identity) || metadata.len() < self.offset {
+            // Replaced or truncated: everything now in the file is new.
+            self.reset(Some(identity));
+        }
+        if metadata.len() == self.offset {
+            return Vec::new();
+        }
+
+        if file.seek(SeekFrom::Start(self.offset)).is_err() {
+            return Vec::new();
+        }
+        let mut bytes = Vec::new();
+        if file.read_to_end(&mut bytes).is_err() {
+            return Vec::new();
+        }
+        let Some(end) = bytes.iter().rposition(|b| *b == b'\n') else {
+            return Vec::new();
+        };
+        let complete = &bytes[..=end];
+        self.offset += complete.len() as u64;
+        parse_events_from_jsonl(&String::from_utf8_lossy(complete))
+    }
+
+    fn reset(&mut self, identity: Option<FileIdentity>) {
+        self.offset = 0;
+        self.identity = identity;
+    }
 }
 
 /// A streamed event with its source label.
@@ -71,21 +253,10 @@ pub fn read_initial_events(
 
     // Main project source
     let main_store = default_audit_store(ito_path);
-    let main_key = audit_storage_location_key(&main_store.location());
-    let main_events = main_store.read_all();
-    let start = main_events.len().saturating_sub(config.last);
-    for event in &main_events[start..] {
-        events.push(StreamEvent {
-            event: event.clone(),
-            source: "main".to_string(),
-        });
-    }
-    sources.push(StreamSource {
-        label: "main".to_string(),
-        store: main_store,
-        offset: main_events.len(),
-    });
-    seen_locations.insert(main_key);
+    seen_locations.insert(audit_storage_location_key(&main_store.location()));
+    let (source, initial) = StreamSource::open("main", main_store, config);
+    events.extend(initial);
+    sources.push(source);
 
     // Worktree sources
     if config.all_worktrees {
@@ -105,23 +276,13 @@ pub fn read_initial_events(
                 continue;
             }
 
-            let wt_events = wt_store.read_all();
             let label = wt
                 .branch
                 .clone()
                 .unwrap_or_else(|| wt.path.display().to_string());
-            let start = wt_events.len().saturating_sub(config.last);
-            for event in &wt_events[start..] {
-                events.push(StreamEvent {
-                    event: event.clone(),
-                    source: label.clone(),
-                });
-            }
-            sources.push(StreamSource {
-                label,
-                store: wt_store,
-                offset: wt_events.len(),
-            });
+            let (source, initial) = StreamSource::open(label, wt_store, config);
+            events.extend(initial);
+            sources.push(source);
         }
     }
 
@@ -132,31 +293,55 @@ pub fn read_initial_events(
 ///
 /// Updates the offsets in each source so subsequent polls only return new events.
 ///
-/// Offsets are based on event counts, so a store that shrinks between polls is
-/// treated as having reset; the shorter snapshot advances no offset until new
-/// events extend the store again.
+/// For non-filesystem stores, offsets are based on event counts, so a store
+/// that shrinks between polls is treated as having reset; the shorter snapshot
+/// advances no offset until new events extend the store again. Filesystem
+/// stores are tailed by byte offset and re-read from the start after a
+/// truncation or rotation.
 pub fn poll_new_events(sources: &mut [StreamSource]) -> Vec<StreamEvent> {
     let mut new_events = Vec::new();
 
     for source in sources.iter_mut() {
-        let current_events = source.store.read_all();
-        if current_events.len() <= source.offset {
-            continue;
-        }
-
-        for event in &current_events[source.offset..] {
+        for event in source.poll() {
             new_events.push(StreamEvent {
-                event: event.clone(),
+                event,
                 source: source.label.clone(),
             });
         }
-
-        source.offset = current_events.len();
     }
 
     new_events
 }
 
+/// Follow `sources`, passing each new event to `emit` until `stop` is set.
+///
+/// Sources are polled every `poll_interval`. The loop returns early when
+/// `emit` returns `false` (for example when the output pipe is closed).
+pub fn follow_events(
+    sources: &mut [StreamSource],
+    poll_interval: Duration,
+    stop: &AtomicBool,
+    mut emit: impl FnMut(&StreamEvent) -> bool,
+) {
+    while !stop.load(Ordering::Relaxed) {
+        let started = Instant::now();
+        for event in poll_new_events(sources) {
+            if !emit(&event) {
+                return;
+            }
+        }
+
+        // Sleep in short slices so a stop request is honored promptly.
+        while !stop.load(Ordering::Relaxed) {
+            let elapsed = started.elapsed();
+            if elapsed >= poll_interval {
+                break;
+            }
+            std::thread::sleep((poll_interval - elapsed).min(Duration::from_millis(50)));
+        }
+    }
+}
+
 #[cfg(test)]
 #[path = "stream_tests.rs"]
 mod stream_tests;
@@ -1,4 +1,5 @@
 use super::*;
+use crate::audit::writer::append_event_to_file;
 use ito_domain::audit::event::{EventContext, SCHEMA_VERSION};
 use std::path::Path;
 
@@ -143,3 +144,136 @@ fn default_config_has_sensible_values() {
     assert!(!config.all_worktrees);
     assert_eq!(config.last, 10);
 }
+
+fn scoped_event(entity_id: &str, scope: &str) -> AuditEvent {
+    let mut event = test_event(entity_id);
+    event.scope = Some(scope.to_string());
+    event
+}
+
+fn open_fs_source(ito_path: &Path, config: &StreamConfig) -> (StreamSource, Vec<StreamEvent>) {
+    let store = crate::audit::FsAuditWriter::new(ito_path);
+    StreamSource::open("main", Box::new(store), config)
+}
+
+#[test]
+fn filesystem_source_renders_initial_tail_for_scope() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let ito_path = tmp.path().join(".ito");
+    let log_path = crate::audit::writer::audit_log_path(&ito_path);
+    for i in 0..30 {
+        let scope = if i % 2 == 0 { "a-change" } else { "b-change" };
+        append_event_to_file(&log_path, &scoped_event(&format!("1.{i}"), scope)).expect("append");
+    }
+
+    let config = StreamConfig {
+        last: 3,
+        scope: Some("b-change".to_string()),
+        ..Default::default()
+    };
+    let (_source, initial) = open_fs_source(&ito_path, &config);
+
+    let ids: Vec<_> = initial.iter().map(|e| e.event.entity_id.as_str()).collect();
+    assert_eq!(ids, vec!["1.25", "1.27", "1.29"]);
+    assert!(initial.iter().all(|e| e.source == "main"));
+}
+
+#[test]
+fn follow_emits_event_appended_by_another_thread_within_one_poll() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let ito_path = tmp.path().join(".ito");
+    let log_path = crate::audit::writer::audit_log_path(&ito_path);
+
+    // The log does not exist yet: following waits for it instead of failing.
+    let config = StreamConfig {
+        poll_interval: Duration::from_millis(100),
+        ..Default::default()
+    };
+    let (source, initial) = open_fs_source(&ito_path, &config);
+    assert!(initial.is_empty());
+
+    let stop = std::sync::Arc::new(AtomicBool::new(false));
+    let (tx, rx) = std::sync::mpsc::channel();
+    let follower = {
+        let stop = stop.clone();
+        let poll_interval = config.poll_interval;
+        std::thread::spawn(move || {
+            let mut sources = vec![source];
+            follow_events(&mut sources, poll_interval, &stop, |event| {
+                tx.send(event.event.entity_id.clone()).is_ok()
+            });
+        })
+    };
+
+    let writer = std::thread::spawn(move || {
+        append_event_to_file(&log_path, &test_event("2.1")).expect("append");
+        Instant::now()
+    });
+    let appended_at = writer.join().expect("writer thread");
+
+    let received = rx
+        .recv_timeout(config.poll_interval * 5)
+        .expect("event should be followed");
+    let latency = appended_at.elapsed();
+    stop.store(true, Ordering::Relaxed);
+    follower.join().expect("follower thread");
+
+    assert_eq!(received, "2.1");
+    // One poll interval plus scheduling slack.
+    assert!(latency < config.poll_interval * 3, "latency {latency:?}");
+}
+
+#[test]
+fn filesystem_source_recovers_from_truncation_and_rotation() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let ito_path = tmp.path().join(".ito");
+    let log_path = crate::audit::writer::audit_log_path(&ito_path);
+    for i in 0..5 {
+        append_event_to_file(&log_path, &test_event(&format!("1.{i}"))).expect("append");
+    }
+
+    let (source, initial) = open_fs_source(&ito_path, &StreamConfig::default());
+    assert_eq!(initial.len(), 5);
+    let mut sources = vec![source];
+
+    // Truncate in place, then append: only the new event is emitted.
+    std::fs::write(&log_path, "").expect("truncate");
+    append_event_to_file(&log_path, &test_event("2.0")).expect("append");
+    let new = poll_new_events(&mut sources);
+    let ids: Vec<_> = new.iter().map(|e| e.event.entity_id.as_str()).collect();
+    assert_eq!(ids, vec!["2.0"]);
+
+    // Rotate: move the log aside and start a fresh file at the same path.
+    std::fs::rename(&log_path, log_path.with_extension("jsonl.1")).expect("rotate");
+    assert!(poll_new_events(&mut sources).is_empty());
+    append_event_to_file(&log_path, &test_event("3.0")).expect("append");
+    append_event_to_file(&log_path, &test_event("3.1")).expect("append");
+    let new = poll_new_events(&mut sources);
+    let ids: Vec<_> = new.iter().map(|e| e.event.entity_id.as_str()).collect();
+    assert_eq!(ids, vec!["3.0", "3.1"]);
+}
+
+#[test]
+fn filesystem_source_waits_for_partial_line_to_complete() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let ito_path = tmp.path().join(".ito");
+    let log_path = crate::audit::writer::audit_log_path(&ito_path);
+    append_event_to_file(&log_path, &test_event("1.0")).expect("append");
+
+    let (source, _initial) = open_fs_source(&ito_path, &StreamConfig::default());
+    let mut sources = vec![source];
+
+    let line = serde_json::to_string(&test_event("1.1")).expect("serialize");
+    let (head, rest) = line.split_at(line.len() / 2);
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&log_path)
+        .expect("open");
+    std::io::Write::write_all(&mut file, head.as_bytes()).expect("write head");
+    assert!(poll_new_events(&mut sources).is_empty());
+
+    std::io::Write::write_all(&mut file, format!("{rest}\n").as_bytes()).expect("write rest");
+    let new = poll_new_events(&mut sources);
+    assert_eq!(new.len(), 1);
+    assert_eq!(new[0].event.entity_id, "1.1");
+}
@@ -8,6 +8,7 @@ use std::path::{Path, PathBuf};
 
 use ito_domain::audit::event::{AuditEvent, WorktreeInfo};
 
+use super::reader::{EventFilter, filter_audit_events};
 use super::store::{audit_storage_location_key, default_audit_store};
 use super::writer::audit_log_path;
 
@@ -190,7 +191,19 @@ pub fn worktree_audit_log_path(worktree: &WorktreeInfo) -> PathBuf {
 pub fn aggregate_worktree_events(
     worktrees: &[WorktreeInfo],
 ) -> Vec<(WorktreeInfo, Vec<AuditEvent>)> {
+    aggregate_worktree_events_filtered(worktrees, &EventFilter::default()).0
+}
+
+/// Read and aggregate events from all worktrees, applying `filter` to each.
+///
+/// Returns the non-empty per-worktree results together with the total number
+/// of events the time window excluded for unparseable timestamps.
+pub fn aggregate_worktree_events_filtered(
+    worktrees: &[WorktreeInfo],
+    filter: &EventFilter,
+) -> (Vec<(WorktreeInfo, Vec<AuditEvent>)>, usize) {
     let mut results = Vec::new();
+    let mut unparseable_timestamps = 0;
     let mut seen_locations = HashSet::new();
 
     for wt in worktrees {
@@ -205,13 +218,14 @@ pub fn aggregate_worktree_events(
             continue;
         }
 
-        let events = store.read_all();
-        if !events.is_empty() {
-            results.push((wt.clone(), events));
+        let filtered = filter_audit_events(store.read_all(), filter);
+        unparseable_timestamps += filtered.unparseable_timestamps;
+        if !filtered.events.is_empty() {
+            results.push((wt.clone(), filtered.events));
         }
     }
 
-    results
+    (results, unparseable_timestamps)
 }
 
 #[cfg(test)]
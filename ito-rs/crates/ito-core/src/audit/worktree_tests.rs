@@ -214,3 +214,50 @@ fn aggregate_worktree_with_events() {
     assert_eq!(results.len(), 1);
     assert_eq!(results[0].1.len(), 1);
 }
+
+#[test]
+fn aggregate_worktree_events_filtered_applies_time_window() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let wt_path = tmp.path().join("wt");
+    let writer = crate::audit::writer::FsAuditWriter::new(&wt_path.join(".ito"));
+    for ts in ["2026-02-07T10:00:00Z", "2026-02-08T10:00:00Z", "not-a-time"] {
+        let event = ito_domain::audit::event::AuditEvent {
+            v: 1,
+            ts: ts.to_string(),
+            entity: "task".to_string(),
+            entity_id: "1.1".to_string(),
+            scope: Some("ch".to_string()),
+            op: "create".to_string(),
+            from: None,
+            to: Some("pending".to_string()),
+            actor: "cli".to_string(),
+            by: "@test".to_string(),
+            meta: None,
+            count: 1,
+            ctx: ito_domain::audit::event::EventContext {
+                session_id: "test".to_string(),
+                harness_session_id: None,
+                branch: None,
+                worktree: None,
+                commit: None,
+            },
+        };
+        ito_domain::audit::writer::AuditWriter::append(&writer, &event).unwrap();
+    }
+
+    let wt_info = WorktreeInfo {
+        path: wt_path,
+        branch: Some("main".to_string()),
+        is_main: true,
+    };
+    let filter = EventFilter {
+        since: Some("2026-02-08T00:00:00Z".parse().unwrap()),
+        ..Default::default()
+    };
+
+    let (results, unparseable) = aggregate_worktree_events_filtered(&[wt_info], &filter);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].1.len(), 1);
+    assert_eq!(results[0].1[0].ts, "2026-02-08T10:00:00Z");
+    assert_eq!(unparseable, 1);
+}
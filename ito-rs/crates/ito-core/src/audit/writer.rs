@@ -72,7 +72,7 @@ impl AuditWriter for FsAuditWriter {
                 return Ok(());
             };
             if let Err(err) = super::mirror::sync_audit_mirror(repo_root, &self.ito_path, &branch) {
-                eprintln!(
+                ito_config::notice!(
                     "Warning: audit mirror sync failed (branch '{}'): {err}",
                     branch
                 );
@@ -54,6 +54,7 @@ fn make_summary(id: &str, completed: u32, total: u32) -> ChangeSummary {
         has_specs: true,
         has_tasks: true,
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
     }
 }
 
@@ -68,6 +69,7 @@ fn make_change(id: &str) -> Change {
         specs: vec![],
         tasks: TasksParseResult::empty(),
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
         last_modified: Utc::now(),
     }
 }
@@ -262,6 +262,7 @@ impl BackendChangeReader for BackendHttpClient {
                 has_specs: summary.has_specs,
                 has_tasks: summary.has_tasks,
                 orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+                metadata: ito_domain::changes::ChangeMetadata::default(),
             });
         }
         Ok(out)
@@ -286,6 +287,11 @@ impl BackendChangeReader for BackendHttpClient {
         };
         let last_modified = parse_timestamp(&change.last_modified)?;
         let sub_module_id = extract_sub_module_id(&change.id);
+        let metadata = change
+            .proposal
+            .as_deref()
+            .map(|proposal| crate::front_matter::proposal_metadata(proposal).metadata)
+            .unwrap_or_default();
         Ok(Change {
             id: change.id,
             module_id: change.module_id,
@@ -305,6 +311,7 @@ impl BackendChangeReader for BackendHttpClient {
             },
             tasks,
             orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+            metadata,
             last_modified,
         })
     }
@@ -473,6 +480,19 @@ impl TaskMutationService for BackendHttpClient {
         Ok(task_mutation_from_api(response))
     }
 
+    fn release_task(
+        &self,
+        change_id: &str,
+        task_id: &str,
+    ) -> TaskMutationServiceResult<TaskMutationResult> {
+        let url = format!(
+            "{}/changes/{change_id}/tasks/{task_id}/release",
+            self.inner.runtime.project_api_prefix()
+        );
+        let response: ApiTaskMutationEnvelope = self.task_post_json(&url, Some("{}"))?;
+        Ok(task_mutation_from_api(response))
+    }
+
     fn add_task(
         &self,
         change_id: &str,
@@ -740,6 +760,8 @@ fn task_list_to_parse_result(list: ApiTaskList) -> TasksParseResult {
             wave: item.wave,
             status,
             updated_at: None,
+            started_at: None,
+            completed_at: None,
             dependencies,
             files: Vec::new(),
             action: String::new(),
@@ -748,6 +770,8 @@ fn task_list_to_parse_result(list: ApiTaskList) -> TasksParseResult {
             kind: TaskKind::Normal,
             header_line_index: 0,
             requirements: item.requirements,
+            parent_id: None,
+            depth: 0,
         });
     }
 
@@ -788,6 +812,7 @@ fn task_list_to_parse_result(list: ApiTaskList) -> TasksParseResult {
                 .to_string(),
             task_id: None,
             line: None,
+            code: None,
         });
     }
 
@@ -845,6 +870,8 @@ fn task_mutation_from_api(response: ApiTaskMutationEnvelope) -> TaskMutationResu
             status: TaskStatus::from_enhanced_label(&response.task.status)
                 .unwrap_or(TaskStatus::Pending),
             updated_at: response.task.updated_at,
+            started_at: response.task.started_at,
+            completed_at: response.task.completed_at,
             dependencies: response.task.dependencies,
             files: response.task.files,
             action: response.task.action,
@@ -856,6 +883,8 @@ fn task_mutation_from_api(response: ApiTaskMutationEnvelope) -> TaskMutationResu
             },
             header_line_index: response.task.header_line_index,
             requirements: response.task.requirements,
+            parent_id: None,
+            depth: 0,
         },
         revision: response.revision,
     }
@@ -956,6 +985,10 @@ struct ApiTaskDetail {
     wave: Option<u32>,
     status: String,
     updated_at: Option<String>,
+    #[serde(default)]
+    started_at: Option<String>,
+    #[serde(default)]
+    completed_at: Option<String>,
     dependencies: Vec<String>,
     files: Vec<String>,
     action: String,
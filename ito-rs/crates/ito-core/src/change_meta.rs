@@ -3,7 +3,7 @@
 use crate::errors::CoreError;
 use ito_common::fs::FileSystem;
 use ito_domain::changes::ChangeOrchestrateMetadata;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::path::Path;
 
@@ -17,20 +17,56 @@ struct ChangeOrchestrateYaml {
     _extra: BTreeMap<String, serde_yaml::Value>,
 }
 
+/// Skip entries accept either a bare artifact id or an `{ id, reason }` map.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum SkippedArtifactYaml {
+    Id(String),
+    Entry {
+        id: String,
+        #[serde(default)]
+        reason: Option<String>,
+    },
+}
+
 #[derive(Debug, Clone, Default, Deserialize)]
 struct ChangeMetaYaml {
     #[serde(default)]
     schema: Option<String>,
     #[serde(default)]
+    schema_hash: Option<String>,
+    #[serde(default)]
+    schema_pinned: bool,
+    #[serde(default)]
     orchestrate: Option<ChangeOrchestrateYaml>,
+    #[serde(default)]
+    skipped_artifacts: Vec<SkippedArtifactYaml>,
+    #[serde(default)]
+    profile: Option<String>,
     #[serde(flatten, default)]
     _extra: BTreeMap<String, serde_yaml::Value>,
 }
 
+/// A schema artifact the change has declared as not applicable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct SkippedArtifact {
+    pub(crate) id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) reason: Option<String>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub(crate) struct ChangeMeta {
     pub(crate) schema: Option<String>,
+    /// Hash of the schema (definition plus templates) the change was baselined against.
+    pub(crate) schema_hash: Option<String>,
+    /// Whether the change resolves its schema from the copy stored in the change directory.
+    pub(crate) schema_pinned: bool,
     pub(crate) orchestrate: ChangeOrchestrateMetadata,
+    /// Artifacts recorded by `ito workflow skip`.
+    pub(crate) skipped_artifacts: Vec<SkippedArtifact>,
+    /// Profile recorded by `ito create change --profile`.
+    pub(crate) profile: Option<String>,
 }
 
 pub(crate) fn parse_change_meta(contents: &str) -> Result<ChangeMeta, CoreError> {
@@ -44,7 +80,18 @@ pub(crate) fn parse_change_meta(contents: &str) -> Result<ChangeMeta, CoreError>
 
     Ok(ChangeMeta {
         schema: yaml.schema,
+        schema_hash: yaml.schema_hash,
+        schema_pinned: yaml.schema_pinned,
         orchestrate: parse_orchestrate_metadata(yaml.orchestrate),
+        skipped_artifacts: yaml
+            .skipped_artifacts
+            .into_iter()
+            .map(|entry| match entry {
+                SkippedArtifactYaml::Id(id) => SkippedArtifact { id, reason: None },
+                SkippedArtifactYaml::Entry { id, reason } => SkippedArtifact { id, reason },
+            })
+            .collect(),
+        profile: yaml.profile,
     })
 }
 
@@ -65,6 +112,63 @@ pub(crate) fn read_change_meta_from_dir<F: FileSystem>(fs: &F, change_dir: &Path
     parse_change_meta_best_effort(&contents)
 }
 
+/// Set (or remove, when `value` is `None`) a top-level scalar key in `.ito.yaml` contents.
+///
+/// Edits are line-based so comments and unknown keys are preserved.
+pub(crate) fn set_change_meta_field(contents: &str, key: &str, value: Option<&str>) -> String {
+    let prefix = format!("{key}:");
+    let mut out = String::new();
+    let mut replaced = false;
+    for line in contents.lines() {
+        if line.starts_with(&prefix) {
+            if let Some(value) = value
+                && !replaced
+            {
+                out.push_str(&format!("{key}: {value}\n"));
+                replaced = true;
+            }
+            continue;
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+    if let Some(value) = value
+        && !replaced
+    {
+        out.push_str(&format!("{key}: {value}\n"));
+    }
+    out
+}
+
+/// Set (or remove, when `block` is `None`) a top-level key whose value is a YAML block.
+///
+/// The key line and its indented or list continuation lines are replaced; the
+/// rest of the file is preserved.
+pub(crate) fn set_change_meta_block(contents: &str, key: &str, block: Option<&str>) -> String {
+    let prefix = format!("{key}:");
+    let mut out = String::new();
+    let mut in_block = false;
+    for line in contents.lines() {
+        if line.starts_with(&prefix) {
+            in_block = true;
+            continue;
+        }
+        if in_block && (line.starts_with(' ') || line.starts_with('-') || line.is_empty()) {
+            continue;
+        }
+        in_block = false;
+        out.push_str(line);
+        out.push('\n');
+    }
+    if let Some(block) = block {
+        out.push_str(&format!("{key}:\n"));
+        for line in block.lines() {
+            out.push_str(&format!("  {line}\n"));
+        }
+    }
+    out
+}
+
 fn parse_orchestrate_metadata(
     orchestrate: Option<ChangeOrchestrateYaml>,
 ) -> ChangeOrchestrateMetadata {
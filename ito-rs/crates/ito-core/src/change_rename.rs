@@ -0,0 +1,371 @@
+//! Rename an active change and migrate everything keyed by its id.
+//!
+//! A change id is the name of its directory, but it is also written into the
+//! module checklist, other changes' `depends_on` lists, the change's own
+//! documents, and the Ralph state and transcript directories. [`plan_change_rename`]
+//! collects every move and edit up front (plus git branches and worktrees named
+//! after the change) and [`apply_change_rename`] performs them.
+
+use std::path::{Path, PathBuf};
+
+use ito_common::fs::StdFs;
+use ito_common::id::{canonical_change_slug, parse_change_id};
+use ito_common::paths;
+use ito_domain::discovery;
+
+use crate::error_bridge::IntoCoreResult;
+use crate::errors::{CoreError, CoreResult};
+use crate::harness::transcript::transcripts_dir;
+use crate::process::{ProcessRequest, ProcessRunner};
+use crate::ralph::state::ralph_state_dir;
+use crate::spec_rename::replace_spec_references;
+
+/// Change files whose textual references to a change id are rewritten.
+const CHANGE_REFERENCE_FILES: &[&str] = &[".ito.yaml", "proposal.md", "design.md", "tasks.md"];
+
+/// A directory move performed by a change rename.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeRenameMove {
+    /// Current directory path.
+    pub from: PathBuf,
+    /// Directory path after the rename.
+    pub to: PathBuf,
+}
+
+/// A file whose references to the old change id are rewritten.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeReferenceEdit {
+    /// File path after all moves have been applied.
+    pub path: PathBuf,
+    /// Number of references replaced.
+    pub occurrences: usize,
+}
+
+/// A git branch named after the change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeBranchRename {
+    /// Current branch name.
+    pub from: String,
+    /// Branch name with the old change id replaced.
+    pub to: String,
+}
+
+/// Every move and edit needed to rename a change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeRenamePlan {
+    /// Current change id.
+    pub old_id: String,
+    /// New change id.
+    pub new_id: String,
+    /// Directory moves, starting with the change directory itself.
+    pub moves: Vec<ChangeRenameMove>,
+    /// Text edits in change files, module files, and Ralph state.
+    pub edits: Vec<ChangeReferenceEdit>,
+    /// Local branches named after the old id; renamed only on request.
+    pub branches: Vec<ChangeBranchRename>,
+    /// Worktrees named after the old id or checked out on one of `branches`.
+    ///
+    /// These are reported, never moved.
+    pub worktrees: Vec<PathBuf>,
+}
+
+/// Plan renaming change `old_id` without touching the filesystem.
+///
+/// `new_name` is either a full change id in the same module or a new slug, in
+/// which case the old id's module and sequence prefix is kept. Fails when
+/// either id is invalid, the change is not active, the new id would move the
+/// change to another module, or the new id (or its sequence number) is
+/// already taken by another change.
+pub fn plan_change_rename(
+    ito_path: &Path,
+    runner: &dyn ProcessRunner,
+    old_id: &str,
+    new_name: &str,
+) -> CoreResult<ChangeRenamePlan> {
+    let old = parse_id(old_id)?;
+    let old_id = old.canonical.as_str().to_string();
+    let prefix = match &old.sub_module_id {
+        Some(sub_id) => sub_id.as_str().to_string(),
+        None => old.module_id.as_str().to_string(),
+    };
+    let number_prefix = format!("{prefix}-{}_", old.change_num);
+
+    let new_id = match parse_change_id(new_name) {
+        Ok(parsed) => {
+            if parsed.module_id != old.module_id || parsed.sub_module_id != old.sub_module_id {
+                return Err(CoreError::validation(format!(
+                    "Cannot rename '{old_id}' to '{}': renaming cannot move a change to another module",
+                    parsed.canonical.as_str()
+                )));
+            }
+            parsed.canonical.as_str().to_string()
+        }
+        Err(_) => {
+            let slug = canonical_change_slug(new_name);
+            if slug.is_empty() {
+                return Err(CoreError::validation(format!(
+                    "Invalid change name '{new_name}'"
+                )));
+            }
+            parse_id(&format!("{number_prefix}{slug}"))?
+                .canonical
+                .as_str()
+                .to_string()
+        }
+    };
+    if new_id == old_id {
+        return Err(CoreError::validation(format!(
+            "Change '{old_id}' already has that name"
+        )));
+    }
+
+    let change_from = paths::change_dir(ito_path, &old_id);
+    if !change_from.is_dir() {
+        return Err(CoreError::not_found(format!("Change '{old_id}' not found")));
+    }
+    let change_to = paths::change_dir(ito_path, &new_id);
+    if change_to.exists() {
+        return Err(CoreError::validation(format!(
+            "Change '{new_id}' already exists"
+        )));
+    }
+
+    let fs = StdFs;
+    let active = discovery::list_change_dir_names(&fs, ito_path).into_core()?;
+    if !new_id.starts_with(&number_prefix) {
+        let new_number = new_id.split('_').next().unwrap_or_default();
+        if let Some(taken) = active
+            .iter()
+            .find(|id| id.split('_').next() == Some(new_number))
+        {
+            return Err(CoreError::validation(format!(
+                "Change number {new_number} is already used by '{taken}'"
+            )));
+        }
+    }
+    for dir in [
+        paths::changes_archive_dir(ito_path),
+        paths::archive_changes_dir(ito_path),
+    ] {
+        for name in discovery::list_dir_names(&fs, &dir).into_core()? {
+            // Archived dirs are named like 2026-01-26-006-05_port-list.
+            if name.get(11..) == Some(new_id.as_str()) {
+                return Err(CoreError::validation(format!(
+                    "Change '{new_id}' already exists in the archive ({name})"
+                )));
+            }
+        }
+    }
+
+    let mut plan = ChangeRenamePlan {
+        old_id: old_id.clone(),
+        new_id: new_id.clone(),
+        moves: vec![ChangeRenameMove {
+            from: change_from,
+            to: change_to,
+        }],
+        edits: Vec::new(),
+        branches: Vec::new(),
+        worktrees: Vec::new(),
+    };
+
+    let ralph_from = ralph_state_dir(ito_path, &old_id);
+    let ralph_to = ralph_state_dir(ito_path, &new_id);
+    for (from, to) in [
+        (ralph_from.clone(), ralph_to.clone()),
+        (
+            transcripts_dir(ito_path, &old_id),
+            transcripts_dir(ito_path, &new_id),
+        ),
+    ] {
+        if !from.is_dir() {
+            continue;
+        }
+        if to.exists() {
+            return Err(CoreError::validation(format!(
+                "{} already exists",
+                to.display()
+            )));
+        }
+        plan.moves.push(ChangeRenameMove { from, to });
+    }
+
+    // Files are counted where they are now and recorded where they will be.
+    let mut referencing = Vec::new();
+    for change_id in &active {
+        let (current, renamed) = if *change_id == old_id {
+            (
+                paths::change_dir(ito_path, &old_id),
+                paths::change_dir(ito_path, &new_id),
+            )
+        } else {
+            let dir = paths::change_dir(ito_path, change_id);
+            (dir.clone(), dir)
+        };
+        for file in CHANGE_REFERENCE_FILES {
+            referencing.push((current.join(file), renamed.join(file)));
+        }
+    }
+    referencing.push((ralph_from.join("state.json"), ralph_to.join("state.json")));
+    for entry in walkdir::WalkDir::new(paths::modules_dir(ito_path))
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file() && e.file_name() == "module.md")
+    {
+        referencing.push((entry.path().to_path_buf(), entry.path().to_path_buf()));
+    }
+    for (current, renamed) in referencing {
+        let occurrences = count_file_references(&current, &old_id)?;
+        if occurrences > 0 {
+            plan.edits.push(ChangeReferenceEdit {
+                path: renamed,
+                occurrences,
+            });
+        }
+    }
+
+    let repo_root = ito_path.parent().unwrap_or(ito_path);
+    plan.branches = change_branches(runner, repo_root, &old_id, &new_id);
+    plan.worktrees = change_worktrees(runner, repo_root, &old_id, &plan.branches);
+
+    Ok(plan)
+}
+
+/// Apply a plan produced by [`plan_change_rename`].
+///
+/// Directories are moved first, then references are rewritten in place. Git
+/// branches are renamed only when `rename_branches` is set.
+pub fn apply_change_rename(
+    plan: &ChangeRenamePlan,
+    runner: &dyn ProcessRunner,
+    repo_root: &Path,
+    rename_branches: bool,
+) -> CoreResult<()> {
+    for mv in &plan.moves {
+        if let Some(parent) = mv.to.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| CoreError::io(format!("creating {}", parent.display()), e))?;
+        }
+        std::fs::rename(&mv.from, &mv.to).map_err(|e| {
+            CoreError::io(
+                format!("moving {} to {}", mv.from.display(), mv.to.display()),
+                e,
+            )
+        })?;
+    }
+
+    for edit in &plan.edits {
+        let contents = ito_common::io::read_to_string_std(&edit.path)
+            .map_err(|e| CoreError::io(format!("reading {}", edit.path.display()), e))?;
+        let (updated, _) = replace_spec_references(&contents, &plan.old_id, &plan.new_id);
+        ito_common::io::write_std(&edit.path, updated)
+            .map_err(|e| CoreError::io(format!("writing {}", edit.path.display()), e))?;
+    }
+
+    if rename_branches {
+        for branch in &plan.branches {
+            let request = ProcessRequest::new("git")
+                .args(["branch", "-m", &branch.from, &branch.to])
+                .current_dir(repo_root.to_path_buf());
+            let out = runner
+                .run(&request)
+                .map_err(|e| CoreError::process(format!("Failed to run git branch: {e}")))?;
+            if !out.success {
+                return Err(CoreError::process(format!(
+                    "Failed to rename branch '{}' to '{}': {}",
+                    branch.from,
+                    branch.to,
+                    out.stderr.trim()
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Local branches whose name is the change id or ends in `/<change id>`.
+fn change_branches(
+    runner: &dyn ProcessRunner,
+    repo_root: &Path,
+    old_id: &str,
+    new_id: &str,
+) -> Vec<ChangeBranchRename> {
+    let request = ProcessRequest::new("git")
+        .args(["for-each-ref", "--format=%(refname:short)", "refs/heads"])
+        .current_dir(repo_root.to_path_buf());
+    let Ok(out) = runner.run(&request) else {
+        return Vec::new();
+    };
+    if !out.success {
+        return Vec::new();
+    }
+    let suffix = format!("/{old_id}");
+    out.stdout
+        .lines()
+        .map(str::trim)
+        .filter(|b| *b == old_id || b.ends_with(&suffix))
+        .map(|b| ChangeBranchRename {
+            from: b.to_string(),
+            to: format!("{}{new_id}", &b[..b.len() - old_id.len()]),
+        })
+        .collect()
+}
+
+/// Linked worktrees whose directory is named after the change or that have
+/// one of `branches` checked out.
+fn change_worktrees(
+    runner: &dyn ProcessRunner,
+    repo_root: &Path,
+    old_id: &str,
+    branches: &[ChangeBranchRename],
+) -> Vec<PathBuf> {
+    let request = ProcessRequest::new("git")
+        .args(["worktree", "list", "--porcelain"])
+        .current_dir(repo_root.to_path_buf());
+    let Ok(out) = runner.run(&request) else {
+        return Vec::new();
+    };
+    if !out.success {
+        return Vec::new();
+    }
+
+    let mut found = Vec::new();
+    // The first entry is the main worktree, which is never named after a change.
+    for block in out.stdout.split("\n\n").skip(1) {
+        let mut path = None;
+        let mut branch = None;
+        for line in block.lines() {
+            if let Some(p) = line.strip_prefix("worktree ") {
+                path = Some(PathBuf::from(p));
+            } else if let Some(b) = line.strip_prefix("branch refs/heads/") {
+                branch = Some(b);
+            }
+        }
+        let Some(path) = path else {
+            continue;
+        };
+        let named = path.file_name().is_some_and(|n| n == old_id);
+        let on_branch = branch.is_some_and(|b| branches.iter().any(|r| r.from == b));
+        if named || on_branch {
+            found.push(path);
+        }
+    }
+    found
+}
+
+fn parse_id(input: &str) -> CoreResult<ito_common::id::ParsedChangeId> {
+    parse_change_id(input).map_err(|e| match e.hint {
+        Some(hint) => CoreError::validation(format!("{} ({hint})", e.error)),
+        None => CoreError::validation(e.error),
+    })
+}
+
+fn count_file_references(path: &Path, old_id: &str) -> CoreResult<usize> {
+    let contents = match ito_common::io::read_to_string_std(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(CoreError::io(format!("reading {}", path.display()), e)),
+    };
+    Ok(replace_spec_references(&contents, old_id, old_id).1)
+}
@@ -7,13 +7,13 @@ use ito_common::paths;
 use ito_domain::changes::{
     Change, ChangeLifecycleFilter, ChangeRepository as DomainChangeRepository, ChangeStatus,
     ChangeSummary, ChangeTargetResolution, ResolveTargetOptions, Spec, extract_module_id,
-    extract_sub_module_id, parse_change_id, parse_module_id,
+    extract_sub_module_id, parse_change_id, parse_module_id, resolve_change_target,
+    slug_matches_tokens, split_canonical_change_id, target_match_ids, tokenize_target_query,
 };
 use ito_domain::discovery;
 use ito_domain::errors::{DomainError, DomainResult};
 use ito_domain::tasks::TaskRepository as DomainTaskRepository;
-use regex::Regex;
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use crate::front_matter;
@@ -22,7 +22,7 @@ use crate::task_repository::FsTaskRepository;
 /// Filesystem-backed change repository.
 pub struct FsChangeRepository<'a, F: FileSystem = StdFs> {
     ito_path: &'a Path,
-    task_repo: FsTaskRepository<'a>,
+    task_repo: FsTaskRepository<'a, F>,
     fs: F,
 }
 
@@ -46,17 +46,25 @@ impl<'a> FsChangeRepository<'a, StdFs> {
     }
 }
 
-impl<'a, F: FileSystem> FsChangeRepository<'a, F> {
+impl<'a, F: FileSystem + Clone> FsChangeRepository<'a, F> {
     /// Create a repository with an explicit filesystem implementation.
+    ///
+    /// Tasks are read through the same filesystem.
     pub fn with_fs(ito_path: &'a Path, fs: F) -> Self {
-        Self::with_task_repo(ito_path, FsTaskRepository::new(ito_path), fs)
+        Self::with_task_repo(
+            ito_path,
+            FsTaskRepository::with_fs(ito_path, fs.clone()),
+            fs,
+        )
     }
+}
 
+impl<'a, F: FileSystem> FsChangeRepository<'a, F> {
     /// Create a repository with an injected task repository.
     ///
     /// Use this when you need to control the task repository instance
     /// (e.g., in tests or when sharing a repo across multiple consumers).
-    pub fn with_task_repo(ito_path: &'a Path, task_repo: FsTaskRepository<'a>, fs: F) -> Self {
+    pub fn with_task_repo(ito_path: &'a Path, task_repo: FsTaskRepository<'a, F>, fs: F) -> Self {
         Self {
             ito_path,
             task_repo,
@@ -69,6 +77,11 @@ impl<'a, F: FileSystem> FsChangeRepository<'a, F> {
         DomainChangeRepository::resolve_target(self, input)
     }
 
+    /// Resolve an input change target, accepting only an exact change id.
+    pub fn resolve_target_exact(&self, input: &str) -> ChangeTargetResolution {
+        DomainChangeRepository::resolve_target_exact(self, input)
+    }
+
     /// Resolve an input change target into a canonical change id using options.
     pub fn resolve_target_with_options(
         &self,
@@ -118,6 +131,19 @@ impl<'a, F: FileSystem> FsChangeRepository<'a, F> {
         DomainChangeRepository::get_summary(self, id)
     }
 
+    /// Build the summary for the active change directory `dir_name` without
+    /// resolving it as a target.
+    ///
+    /// Produces the same summary `list()` would for that directory.
+    pub fn summarize_active_dir(&self, dir_name: &str) -> DomainResult<ChangeSummary> {
+        let location = ChangeLocation {
+            id: dir_name.to_string(),
+            path: self.changes_dir().join(dir_name),
+            lifecycle: ChangeLifecycle::Active,
+        };
+        self.build_summary_for_location(&location)
+    }
+
     fn changes_dir(&self) -> std::path::PathBuf {
         paths::changes_dir(self.ito_path)
     }
@@ -246,76 +272,24 @@ impl<'a, F: FileSystem> FsChangeRepository<'a, F> {
             .find(|loc| loc.id == id)
     }
 
-    fn split_canonical_change_id<'b>(&self, name: &'b str) -> Option<(String, String, &'b str)> {
-        let (module_id, change_num) = parse_change_id(name)?;
-        let slug = name.split_once('_').map(|(_id, s)| s).unwrap_or("");
-        Some((module_id, change_num, slug))
-    }
-
-    fn tokenize_query(&self, input: &str) -> Vec<String> {
-        input
-            .split_whitespace()
-            .map(|s| s.trim().to_lowercase())
-            .filter(|s| !s.is_empty())
-            .collect()
-    }
-
-    fn normalized_slug_text(&self, slug: &str) -> String {
-        let mut out = String::new();
-        for ch in slug.chars() {
-            if ch.is_ascii_alphanumeric() {
-                out.push(ch.to_ascii_lowercase());
-            } else {
-                out.push(' ');
-            }
-        }
-        out
-    }
-
-    fn slug_matches_tokens(&self, slug: &str, tokens: &[String]) -> bool {
-        if tokens.is_empty() {
-            return false;
-        }
-        let text = self.normalized_slug_text(slug);
-        for token in tokens {
-            if !text.contains(token) {
-                return false;
-            }
-        }
-        true
-    }
-
-    fn is_numeric_module_selector(&self, input: &str) -> bool {
-        let trimmed = input.trim();
-        !trimmed.is_empty() && trimmed.chars().all(|ch| ch.is_ascii_digit())
-    }
-
-    fn extract_two_numbers_as_change_id(&self, input: &str) -> Option<(String, String)> {
-        let re = Regex::new(r"\d+").ok()?;
-        let mut parts: Vec<&str> = Vec::new();
-        for m in re.find_iter(input) {
-            parts.push(m.as_str());
-            if parts.len() > 2 {
-                return None;
-            }
-        }
-        if parts.len() != 2 {
-            return None;
-        }
-        let parsed = format!("{}-{}", parts[0], parts[1]);
-        parse_change_id(&parsed)
-    }
-
     fn resolve_unique_change_id(
         &self,
         input: &str,
         filter: ChangeLifecycleFilter,
     ) -> DomainResult<String> {
-        match self.resolve_target_with_options(input, ResolveTargetOptions { lifecycle: filter }) {
+        match self.resolve_target_with_options(
+            input,
+            ResolveTargetOptions {
+                lifecycle: filter,
+                exact: false,
+            },
+        ) {
             ChangeTargetResolution::Unique(id) => Ok(id),
-            ChangeTargetResolution::Ambiguous(matches) => {
-                Err(DomainError::ambiguous_target("change", input, &matches))
-            }
+            ChangeTargetResolution::Ambiguous(matches) => Err(DomainError::ambiguous_target(
+                "change",
+                input,
+                &target_match_ids(&matches),
+            )),
             ChangeTargetResolution::NotFound => Err(DomainError::not_found("change", input)),
         }
     }
@@ -384,18 +358,16 @@ impl<'a, F: FileSystem> FsChangeRepository<'a, F> {
     /// Parses front matter from the content and checks that any declared
     /// `change_id` matches the expected change directory name. Integrity
     /// checksums are also validated when present. If the content has no
-    /// front matter, this is a no-op.
+    /// front matter, or the front matter is not valid YAML, this is a no-op;
+    /// malformed front matter is reported by `ito validate` instead.
     fn validate_artifact_front_matter(
         &self,
         content: &str,
         expected_change_id: &str,
     ) -> DomainResult<()> {
-        let parsed = front_matter::parse(content).map_err(|e| {
-            DomainError::io(
-                "parsing front matter",
-                std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()),
-            )
-        })?;
+        let Ok(parsed) = front_matter::parse(content) else {
+            return Ok(());
+        };
 
         let Some(fm) = &parsed.front_matter else {
             return Ok(());
@@ -468,6 +440,10 @@ impl<'a, F: FileSystem> FsChangeRepository<'a, F> {
         let module_id = extract_module_id(&location.id);
         let sub_module_id = extract_sub_module_id(&location.id);
         let meta = crate::change_meta::read_change_meta_from_dir(&self.fs, &location.path);
+        let metadata = self
+            .read_optional_file(&location.path.join("proposal.md"))?
+            .map(|proposal| front_matter::proposal_metadata(&proposal).metadata)
+            .unwrap_or_default();
 
         Ok(ChangeSummary {
             id: location.id.clone(),
@@ -484,6 +460,7 @@ impl<'a, F: FileSystem> FsChangeRepository<'a, F> {
             has_specs,
             has_tasks,
             orchestrate: meta.orchestrate,
+            metadata,
         })
     }
 }
@@ -495,121 +472,9 @@ impl<'a, F: FileSystem> DomainChangeRepository for FsChangeRepository<'a, F> {
         options: ResolveTargetOptions,
     ) -> ChangeTargetResolution {
         let names = self.list_change_ids(options.lifecycle);
-        if names.is_empty() {
-            return ChangeTargetResolution::NotFound;
-        }
-
-        let input = input.trim();
-        if input.is_empty() {
-            return ChangeTargetResolution::NotFound;
-        }
-
-        if names.iter().any(|name| name == input) {
-            return ChangeTargetResolution::Unique(input.to_string());
-        }
-
-        let mut numeric_matches: BTreeSet<String> = BTreeSet::new();
-        let numeric_selector =
-            parse_change_id(input).or_else(|| self.extract_two_numbers_as_change_id(input));
-        if let Some((module_id, change_num)) = numeric_selector {
-            let numeric_prefix = format!("{module_id}-{change_num}");
-            let with_separator = format!("{numeric_prefix}_");
-            for name in &names {
-                if name == &numeric_prefix || name.starts_with(&with_separator) {
-                    numeric_matches.insert(name.clone());
-                }
-            }
-            if !numeric_matches.is_empty() {
-                let numeric_matches: Vec<String> = numeric_matches.into_iter().collect();
-                if numeric_matches.len() == 1 {
-                    return ChangeTargetResolution::Unique(numeric_matches[0].clone());
-                }
-                return ChangeTargetResolution::Ambiguous(numeric_matches);
-            }
-        }
-
-        if let Some((module, query)) = input.split_once(':') {
-            let query = query.trim();
-            if !query.is_empty() {
-                let module_id = parse_module_id(module);
-                let tokens = self.tokenize_query(query);
-                let mut scoped_matches: BTreeSet<String> = BTreeSet::new();
-                for name in &names {
-                    let Some((name_module, _name_change, slug)) =
-                        self.split_canonical_change_id(name)
-                    else {
-                        continue;
-                    };
-                    if name_module != module_id {
-                        continue;
-                    }
-                    if self.slug_matches_tokens(slug, &tokens) {
-                        scoped_matches.insert(name.clone());
-                    }
-                }
-
-                if scoped_matches.is_empty() {
-                    return ChangeTargetResolution::NotFound;
-                }
-                let scoped_matches: Vec<String> = scoped_matches.into_iter().collect();
-                if scoped_matches.len() == 1 {
-                    return ChangeTargetResolution::Unique(scoped_matches[0].clone());
-                }
-                return ChangeTargetResolution::Ambiguous(scoped_matches);
-            }
-        }
-
-        if self.is_numeric_module_selector(input) {
-            let module_id = parse_module_id(input);
-            let mut module_matches: BTreeSet<String> = BTreeSet::new();
-            for name in &names {
-                let Some((name_module, _name_change, _slug)) = self.split_canonical_change_id(name)
-                else {
-                    continue;
-                };
-                if name_module == module_id {
-                    module_matches.insert(name.clone());
-                }
-            }
-
-            if !module_matches.is_empty() {
-                let module_matches: Vec<String> = module_matches.into_iter().collect();
-                if module_matches.len() == 1 {
-                    return ChangeTargetResolution::Unique(module_matches[0].clone());
-                }
-                return ChangeTargetResolution::Ambiguous(module_matches);
-            }
-        }
-
-        let mut matches: BTreeSet<String> = BTreeSet::new();
-        for name in &names {
-            if name.starts_with(input) {
-                matches.insert(name.clone());
-            }
-        }
-
-        if matches.is_empty() {
-            let tokens = self.tokenize_query(input);
-            for name in &names {
-                let Some((_module, _change, slug)) = self.split_canonical_change_id(name) else {
-                    continue;
-                };
-                if self.slug_matches_tokens(slug, &tokens) {
-                    matches.insert(name.clone());
-                }
-            }
-        }
-
-        if matches.is_empty() {
-            return ChangeTargetResolution::NotFound;
-        }
-
-        let matches: Vec<String> = matches.into_iter().collect();
-        if matches.len() == 1 {
-            return ChangeTargetResolution::Unique(matches[0].clone());
-        }
-
-        ChangeTargetResolution::Ambiguous(matches)
+        resolve_change_target(&names, input, options.exact, |id| {
+            self.describe_target(id, options.lifecycle)
+        })
     }
 
     fn suggest_targets(&self, input: &str, max: usize) -> Vec<String> {
@@ -622,12 +487,11 @@ impl<'a, F: FileSystem> DomainChangeRepository for FsChangeRepository<'a, F> {
         let canonical_names: Vec<String> = names
             .iter()
             .filter_map(|name| {
-                self.split_canonical_change_id(name)
-                    .map(|(_module, _change, _slug)| name.clone())
+                split_canonical_change_id(name).map(|(_module, _change, _slug)| name.clone())
             })
             .collect();
         let mut scored: Vec<(usize, String)> = Vec::new();
-        let tokens = self.tokenize_query(&input);
+        let tokens = tokenize_target_query(&input);
 
         for name in &canonical_names {
             let lower = name.to_lowercase();
@@ -640,10 +504,10 @@ impl<'a, F: FileSystem> DomainChangeRepository for FsChangeRepository<'a, F> {
                 score = score.max(80);
             }
 
-            let Some((_module, _change, slug)) = self.split_canonical_change_id(name) else {
+            let Some((_module, _change, slug)) = split_canonical_change_id(name) else {
                 continue;
             };
-            if !tokens.is_empty() && self.slug_matches_tokens(slug, &tokens) {
+            if !tokens.is_empty() && slug_matches_tokens(slug, &tokens) {
                 score = score.max(70);
             }
 
@@ -690,8 +554,13 @@ impl<'a, F: FileSystem> DomainChangeRepository for FsChangeRepository<'a, F> {
     }
 
     fn exists_with_filter(&self, id: &str, filter: ChangeLifecycleFilter) -> bool {
-        let resolution =
-            self.resolve_target_with_options(id, ResolveTargetOptions { lifecycle: filter });
+        let resolution = self.resolve_target_with_options(
+            id,
+            ResolveTargetOptions {
+                lifecycle: filter,
+                exact: false,
+            },
+        );
         match resolution {
             ChangeTargetResolution::Unique(_) => true,
             ChangeTargetResolution::Ambiguous(_) => false,
@@ -720,6 +589,10 @@ impl<'a, F: FileSystem> DomainChangeRepository for FsChangeRepository<'a, F> {
         let last_modified = self.get_last_modified(&location.path)?;
         let path = location.path;
         let meta = crate::change_meta::read_change_meta_from_dir(&self.fs, &path);
+        let metadata = proposal
+            .as_deref()
+            .map(|proposal| front_matter::proposal_metadata(proposal).metadata)
+            .unwrap_or_default();
 
         let sub_module_id = extract_sub_module_id(&actual_id);
 
@@ -733,6 +606,7 @@ impl<'a, F: FileSystem> DomainChangeRepository for FsChangeRepository<'a, F> {
             specs,
             tasks,
             orchestrate: meta.orchestrate,
+            metadata,
             last_modified,
         })
     }
@@ -73,12 +73,49 @@ fn resolve_target_reports_ambiguity() {
     create_change(&ito_path, "001-12_follow-up", false);
 
     let repo = FsChangeRepository::new(&ito_path);
+    let ChangeTargetResolution::Ambiguous(matches) = repo.resolve_target("1-12") else {
+        panic!("expected an ambiguous target");
+    };
     assert_eq!(
-        repo.resolve_target("1-12"),
-        ChangeTargetResolution::Ambiguous(vec![
-            "001-12_first-change".to_string(),
-            "001-12_follow-up".to_string(),
-        ])
+        target_match_ids(&matches),
+        ["001-12_first-change", "001-12_follow-up"]
+    );
+    assert_eq!(matches[1].title, "follow up");
+    assert!(matches.iter().all(|m| m.work_status.is_some()));
+}
+
+#[test]
+fn resolve_target_exact_id_beats_prefix_matches() {
+    let tmp = TempDir::new().unwrap();
+    let ito_path = setup_test_ito(&tmp);
+    create_change(&ito_path, "001-12_setup", false);
+    create_change(&ito_path, "001-12_setup-wizard", false);
+
+    let repo = FsChangeRepository::new(&ito_path);
+    assert_eq!(
+        repo.resolve_target("1-12_setup"),
+        ChangeTargetResolution::Unique("001-12_setup".to_string())
+    );
+}
+
+#[test]
+fn resolve_target_exact_rejects_prefixes() {
+    let tmp = TempDir::new().unwrap();
+    let ito_path = setup_test_ito(&tmp);
+    create_change(&ito_path, "001-12_setup-wizard", false);
+
+    let repo = FsChangeRepository::new(&ito_path);
+    assert_eq!(
+        repo.resolve_target("001-12"),
+        ChangeTargetResolution::Unique("001-12_setup-wizard".to_string())
+    );
+    assert_eq!(
+        repo.resolve_target_exact("001-12"),
+        ChangeTargetResolution::NotFound
+    );
+    assert_eq!(
+        repo.resolve_target_exact("001-12_setup-wizard"),
+        ChangeTargetResolution::Unique("001-12_setup-wizard".to_string())
     );
 }
 
@@ -117,6 +154,7 @@ fn resolve_target_includes_archive_when_requested() {
             "1-12",
             ResolveTargetOptions {
                 lifecycle: ChangeLifecycleFilter::All,
+                exact: false,
             }
         ),
         ChangeTargetResolution::Unique("001-12_setup-wizard".to_string())
@@ -0,0 +1,661 @@
+//! Split selected tasks of an active change into a new follow-up change.
+//!
+//! [`split_change`] creates the follow-up change in the same module, moves
+//! the chosen enhanced tasks (optionally with every task that depends on
+//! them) into its tracking file, copies the proposal with a note pointing
+//! back at the original, and carries over the delta specs those tasks work
+//! on. Everything is checked before the new change is created, so a rejected
+//! split leaves the tree untouched.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
+use std::path::Path;
+
+use ito_common::fs::StdFs;
+use ito_common::id::parse_change_id;
+use ito_common::paths;
+use ito_domain::tasks::{
+    DiagnosticLevel, TaskItem, TaskKind, TasksFormat, TasksParseResult, parse_tasks_tracking_file,
+};
+
+use crate::change_meta::read_change_meta_from_dir;
+use crate::create::{CreateError, create_change, create_change_in_sub_module};
+use crate::errors::{CoreError, CoreResult};
+use crate::tasks::tracking_file_path;
+use crate::templates::default_schema_name;
+
+/// What to split off and how.
+#[derive(Debug, Clone)]
+pub struct ChangeSplitRequest {
+    change_id: String,
+    task_ids: Vec<String>,
+    name: String,
+    with_dependents: bool,
+    renumber: bool,
+}
+
+impl ChangeSplitRequest {
+    /// Move `task_ids` of `change_id` into a new change named `name`.
+    pub fn new(
+        change_id: impl Into<String>,
+        task_ids: Vec<String>,
+        name: impl Into<String>,
+    ) -> Self {
+        Self {
+            change_id: change_id.into(),
+            task_ids,
+            name: name.into(),
+            with_dependents: false,
+            renumber: false,
+        }
+    }
+
+    /// Also move every task that (transitively) depends on a selected task.
+    pub fn with_dependents(mut self, with_dependents: bool) -> Self {
+        self.with_dependents = with_dependents;
+        self
+    }
+
+    /// Renumber the tasks left in the original change within each wave.
+    pub fn renumber(mut self, renumber: bool) -> Self {
+        self.renumber = renumber;
+        self
+    }
+}
+
+/// A task id rewritten when the original change was renumbered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskRenumber {
+    /// Id before the split.
+    pub from: String,
+    /// Id after the split.
+    pub to: String,
+}
+
+/// A dependency of a moved task on a task that stayed in the original change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetachedDependency {
+    /// Moved task.
+    pub task: String,
+    /// Task it depended on, still in the original change.
+    pub depends_on: String,
+}
+
+/// Outcome of [`split_change`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeSplitResult {
+    /// Change the tasks were taken from.
+    pub old_id: String,
+    /// Newly created change.
+    pub new_id: String,
+    /// Ids of every moved task, in file order.
+    pub moved_tasks: Vec<String>,
+    /// Moved tasks that were not selected but depend on a selected task.
+    pub dependents: Vec<String>,
+    /// Tasks left in the original change whose id changed.
+    pub renumbered: Vec<TaskRenumber>,
+    /// Delta specs only the moved tasks work on; moved to the new change.
+    pub moved_specs: Vec<String>,
+    /// Delta specs both changes work on; copied and flagged for manual review.
+    pub copied_specs: Vec<String>,
+    /// Dependencies dropped from the new tracking file because their target stayed behind.
+    pub detached_dependencies: Vec<DetachedDependency>,
+}
+
+/// Split the requested tasks of an active change into a new change.
+///
+/// The new change is created in the original's module (or sub-module) with
+/// the next free sequence number and the original's schema. Moved tasks keep
+/// their ids; the original tracking file loses them and is renumbered only
+/// on request.
+///
+/// Delta specs are assigned on a best-effort basis: a task works on a spec
+/// when one of its files lies under `specs/<spec>/` or it lists a
+/// requirement id that spec declares. Specs only moved tasks work on are
+/// moved; specs tasks on both sides work on are copied and reported in
+/// [`ChangeSplitResult::copied_specs`].
+///
+/// Fails when the change or a selected task does not exist, the tracking file
+/// is not a valid enhanced tasks file, every task would move, or a task
+/// staying behind depends on a moved one and dependents were not requested.
+pub fn split_change(
+    ito_path: &Path,
+    request: &ChangeSplitRequest,
+) -> CoreResult<ChangeSplitResult> {
+    let old = parse_change_id(&request.change_id).map_err(|e| match e.hint {
+        Some(hint) => CoreError::validation(format!("{} ({hint})", e.error)),
+        None => CoreError::validation(e.error),
+    })?;
+    let old_id = old.canonical.as_str().to_string();
+    let change_dir = paths::change_dir(ito_path, &old_id);
+    if !change_dir.is_dir() {
+        return Err(CoreError::not_found(format!("Change '{old_id}' not found")));
+    }
+
+    let tasks_path = tracking_file_path(ito_path, &old_id)?;
+    let tasks_file = tasks_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "tasks.md".to_string());
+    let contents = ito_common::io::read_to_string_std(&tasks_path)
+        .map_err(|e| CoreError::io(format!("reading {}", tasks_path.display()), e))?;
+    let parsed = parse_tasks_tracking_file(&contents);
+    if parsed.format != TasksFormat::Enhanced {
+        return Err(CoreError::validation(format!(
+            "Cannot split '{old_id}': {tasks_file} uses the checkbox format; only enhanced tasks can be split"
+        )));
+    }
+    if parsed
+        .diagnostics
+        .iter()
+        .any(|d| d.level == DiagnosticLevel::Error)
+    {
+        return Err(CoreError::validation(format!(
+            "Cannot split '{old_id}': {tasks_file} contains errors"
+        )));
+    }
+
+    let moving = select_tasks(&parsed, request, &old_id)?;
+    let tasks: Vec<&TaskItem> = parsed
+        .tasks
+        .iter()
+        .filter(|t| t.kind == TaskKind::Normal)
+        .collect();
+    if tasks.iter().all(|t| moving.contains(t.id.as_str())) {
+        return Err(CoreError::validation(format!(
+            "Cannot split every task out of '{old_id}'; rename the change instead"
+        )));
+    }
+
+    let lines: Vec<&str> = contents.lines().collect();
+    let blocks = task_blocks(&lines, &parsed);
+    let moved: Vec<&TaskItem> = tasks
+        .iter()
+        .copied()
+        .filter(|t| moving.contains(t.id.as_str()))
+        .collect();
+    let mut detached = Vec::new();
+    for task in &moved {
+        for dep in &task.dependencies {
+            if !moving.contains(dep.as_str()) {
+                detached.push(DetachedDependency {
+                    task: task.id.clone(),
+                    depends_on: dep.clone(),
+                });
+            }
+        }
+    }
+
+    let (remaining, renumbered) =
+        remaining_tasks(&lines, &parsed, &blocks, &moving, request.renumber);
+    let specs = assign_delta_specs(&change_dir, &tasks, &moving)?;
+
+    let meta = read_change_meta_from_dir(&StdFs, &change_dir);
+    let schema = meta
+        .schema
+        .unwrap_or_else(|| default_schema_name().to_string());
+    let created = match &old.sub_module_id {
+        Some(sub) => {
+            create_change_in_sub_module(ito_path, &request.name, &schema, sub.as_str(), None)
+        }
+        None => create_change(
+            ito_path,
+            &request.name,
+            &schema,
+            Some(old.module_id.as_str()),
+            None,
+        ),
+    }
+    .map_err(create_error)?;
+    let new_id = created.change_id;
+    let new_dir = created.change_dir;
+
+    let new_tasks_path = tracking_file_path(ito_path, &new_id)?;
+    let new_tasks = moved_tasks_markdown(&new_id, &lines, &parsed, &blocks, &moved, &detached);
+    write(&new_tasks_path, &new_tasks)?;
+
+    let moved_ids: Vec<String> = moved.iter().map(|t| t.id.clone()).collect();
+    let proposal_path = change_dir.join("proposal.md");
+    let proposal = match ito_common::io::read_to_string_std(&proposal_path) {
+        Ok(proposal) => proposal,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => {
+            return Err(CoreError::io(
+                format!("reading {}", proposal_path.display()),
+                e,
+            ));
+        }
+    };
+    let note = split_note(&old_id, &moved_ids, &specs.copied, &detached);
+    write(&new_dir.join("proposal.md"), &insert_note(&proposal, &note))?;
+
+    for spec in specs.moved.iter().chain(&specs.copied) {
+        let from = change_dir.join("specs").join(spec);
+        copy_dir(&from, &new_dir.join("specs").join(spec))?;
+    }
+    write(&tasks_path, &remaining)?;
+    for spec in &specs.moved {
+        let dir = change_dir.join("specs").join(spec);
+        std::fs::remove_dir_all(&dir)
+            .map_err(|e| CoreError::io(format!("removing {}", dir.display()), e))?;
+    }
+
+    let dependents = moved_ids
+        .iter()
+        .filter(|id| !request.task_ids.iter().any(|s| s.trim() == id.as_str()))
+        .cloned()
+        .collect();
+    Ok(ChangeSplitResult {
+        old_id,
+        new_id,
+        moved_tasks: moved_ids,
+        dependents,
+        renumbered,
+        moved_specs: specs.moved,
+        copied_specs: specs.copied,
+        detached_dependencies: detached,
+    })
+}
+
+/// Selected task ids plus, when requested, their transitive dependents.
+fn select_tasks<'a>(
+    parsed: &'a TasksParseResult,
+    request: &ChangeSplitRequest,
+    old_id: &str,
+) -> CoreResult<BTreeSet<&'a str>> {
+    let mut moving: BTreeSet<&str> = BTreeSet::new();
+    for id in &request.task_ids {
+        let id = id.trim();
+        let Some(task) = parsed.tasks.iter().find(|t| t.id == id) else {
+            return Err(CoreError::not_found(format!(
+                "Task '{id}' not found in '{old_id}'"
+            )));
+        };
+        if task.kind != TaskKind::Normal {
+            return Err(CoreError::validation(format!(
+                "Task '{id}' is a checkpoint; only regular tasks can be split off"
+            )));
+        }
+        moving.insert(task.id.as_str());
+    }
+    if moving.is_empty() {
+        return Err(CoreError::validation(
+            "Select at least one task to split off",
+        ));
+    }
+
+    loop {
+        let dependents: Vec<(&TaskItem, &str)> = parsed
+            .tasks
+            .iter()
+            .filter(|t| t.kind == TaskKind::Normal && !moving.contains(t.id.as_str()))
+            .filter_map(|t| {
+                t.dependencies
+                    .iter()
+                    .find(|d| moving.contains(d.as_str()))
+                    .map(|d| (t, d.as_str()))
+            })
+            .collect();
+        let Some((task, dep)) = dependents.first() else {
+            return Ok(moving);
+        };
+        if !request.with_dependents {
+            return Err(CoreError::validation(format!(
+                "Task '{}' stays in '{old_id}' but depends on task '{dep}'; select it too or use --with-dependents",
+                task.id
+            )));
+        }
+        for (task, _) in dependents {
+            moving.insert(task.id.as_str());
+        }
+    }
+}
+
+/// Line ranges of every task block, from its heading up to the next heading
+/// or thematic break outside a code fence.
+fn task_blocks(lines: &[&str], parsed: &TasksParseResult) -> BTreeMap<String, Range<usize>> {
+    let mut blocks = BTreeMap::new();
+    for task in &parsed.tasks {
+        let start = task.header_line_index;
+        let mut end = lines.len();
+        let mut in_fence = false;
+        for (idx, line) in lines.iter().enumerate().skip(start + 1) {
+            let trimmed = line.trim();
+            if trimmed.starts_with("```") {
+                in_fence = !in_fence;
+            }
+            if !in_fence && (trimmed.starts_with('#') || is_thematic_break(trimmed)) {
+                end = idx;
+                break;
+            }
+        }
+        blocks.insert(task.id.clone(), start..end);
+    }
+    blocks
+}
+
+fn is_thematic_break(line: &str) -> bool {
+    let Some(first) = line.chars().next() else {
+        return false;
+    };
+    matches!(first, '-' | '_' | '*') && line.len() >= 3 && line.chars().all(|c| c == first)
+}
+
+/// The original tracking file without the moved task blocks, optionally renumbered.
+fn remaining_tasks(
+    lines: &[&str],
+    parsed: &TasksParseResult,
+    blocks: &BTreeMap<String, Range<usize>>,
+    moving: &BTreeSet<&str>,
+    renumber: bool,
+) -> (String, Vec<TaskRenumber>) {
+    let removed: BTreeSet<usize> = moving
+        .iter()
+        .filter_map(|id| blocks.get(*id))
+        .flat_map(Clone::clone)
+        .collect();
+
+    let mut renames: BTreeMap<String, String> = BTreeMap::new();
+    if renumber {
+        let mut next: BTreeMap<u32, u32> = BTreeMap::new();
+        for task in &parsed.tasks {
+            if task.kind != TaskKind::Normal || moving.contains(task.id.as_str()) {
+                continue;
+            }
+            let Some(wave) = task.wave else {
+                continue;
+            };
+            if !task
+                .id
+                .split_once('.')
+                .is_some_and(|(w, n)| w.parse::<u32>().is_ok() && n.parse::<u32>().is_ok())
+            {
+                continue;
+            }
+            let n = next.entry(wave).or_insert(0);
+            *n += 1;
+            let id = format!("{wave}.{n}");
+            if id != task.id {
+                renames.insert(task.id.clone(), id);
+            }
+        }
+    }
+
+    let headers: BTreeMap<usize, &TaskItem> = parsed
+        .tasks
+        .iter()
+        .map(|t| (t.header_line_index, t))
+        .collect();
+    let mut out = String::new();
+    for (idx, line) in lines.iter().enumerate() {
+        if removed.contains(&idx) {
+            continue;
+        }
+        let line = match headers.get(&idx) {
+            Some(task) if renames.contains_key(&task.id) => {
+                format!("### Task {}: {}", renames[&task.id], task.name)
+            }
+            _ if !renames.is_empty() && line.contains("**Dependencies**:") => {
+                rewrite_dependencies(line, |dep| {
+                    Some(renames.get(dep).cloned().unwrap_or_else(|| dep.to_string()))
+                })
+            }
+            _ => (*line).to_string(),
+        };
+        out.push_str(&line);
+        out.push('\n');
+    }
+
+    let renumbered = renames
+        .into_iter()
+        .map(|(from, to)| TaskRenumber { from, to })
+        .collect();
+    (out, renumbered)
+}
+
+/// Rewrite the task ids of a `- **Dependencies**:` line.
+///
+/// `map` returns the new id, or `None` to drop the dependency. Free-form
+/// values such as `None` or `All previous waves` are left as they are.
+fn rewrite_dependencies(line: &str, map: impl Fn(&str) -> Option<String>) -> String {
+    let Some((head, raw)) = line.split_once("**Dependencies**:") else {
+        return line.to_string();
+    };
+    let raw = raw.trim();
+    let lower = raw.to_ascii_lowercase();
+    if raw.is_empty() || lower == "none" || lower.starts_with("all ") {
+        return line.to_string();
+    }
+    let deps: Vec<String> = raw
+        .split(',')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .filter_map(|d| {
+            let (prefix, id) = match d.strip_prefix("Task ") {
+                Some(id) => ("Task ", id.trim()),
+                None => ("", d),
+            };
+            map(id).map(|id| format!("{prefix}{id}"))
+        })
+        .collect();
+    let deps = if deps.is_empty() {
+        "None".to_string()
+    } else {
+        deps.join(", ")
+    };
+    format!("{head}**Dependencies**: {deps}")
+}
+
+/// Tracking file for the new change: the moved task blocks grouped by wave.
+fn moved_tasks_markdown(
+    new_id: &str,
+    lines: &[&str],
+    parsed: &TasksParseResult,
+    blocks: &BTreeMap<String, Range<usize>>,
+    moved: &[&TaskItem],
+    detached: &[DetachedDependency],
+) -> String {
+    let mut by_wave: BTreeMap<u32, Vec<&TaskItem>> = BTreeMap::new();
+    for task in moved {
+        by_wave
+            .entry(task.wave.unwrap_or(1))
+            .or_default()
+            .push(task);
+    }
+    let waves: BTreeSet<u32> = by_wave.keys().copied().collect();
+
+    let mut out = format!("# Tasks for: {new_id}\n");
+    for (wave, tasks) in &by_wave {
+        let depends_on: Vec<String> = parsed
+            .waves
+            .iter()
+            .find(|w| w.wave == *wave)
+            .map(|w| {
+                w.depends_on
+                    .iter()
+                    .filter(|d| waves.contains(d))
+                    .map(|d| format!("Wave {d}"))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let depends_on = if depends_on.is_empty() {
+            "None".to_string()
+        } else {
+            depends_on.join(", ")
+        };
+        out.push_str(&format!(
+            "\n______________________________________________________________________\n\n## Wave {wave}\n\n- **Depends On**: {depends_on}\n\n"
+        ));
+        for task in tasks {
+            let Some(range) = blocks.get(&task.id) else {
+                continue;
+            };
+            let mut block: Vec<String> = lines[range.clone()]
+                .iter()
+                .map(|line| {
+                    if line.contains("**Dependencies**:") {
+                        rewrite_dependencies(line, |dep| {
+                            let dropped = detached
+                                .iter()
+                                .any(|d| d.task == task.id && d.depends_on == dep);
+                            (!dropped).then(|| dep.to_string())
+                        })
+                    } else {
+                        (*line).to_string()
+                    }
+                })
+                .collect();
+            while block.last().is_some_and(|l| l.trim().is_empty()) {
+                block.pop();
+            }
+            for line in block {
+                out.push_str(&line);
+                out.push('\n');
+            }
+            out.push('\n');
+        }
+    }
+    out.truncate(out.trim_end().len());
+    out.push('\n');
+    out
+}
+
+/// Delta specs split between the two changes.
+struct SpecAssignment {
+    moved: Vec<String>,
+    copied: Vec<String>,
+}
+
+fn assign_delta_specs(
+    change_dir: &Path,
+    tasks: &[&TaskItem],
+    moving: &BTreeSet<&str>,
+) -> CoreResult<SpecAssignment> {
+    let mut assignment = SpecAssignment {
+        moved: Vec::new(),
+        copied: Vec::new(),
+    };
+    let specs_dir = change_dir.join("specs");
+    if !specs_dir.is_dir() {
+        return Ok(assignment);
+    }
+    let mut specs: Vec<String> = std::fs::read_dir(&specs_dir)
+        .map_err(|e| CoreError::io(format!("reading {}", specs_dir.display()), e))?
+        .filter_map(Result::ok)
+        .filter(|e| e.path().join("spec.md").is_file())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    specs.sort();
+
+    for spec in specs {
+        let path = specs_dir.join(&spec).join("spec.md");
+        let markdown = ito_common::io::read_to_string_std(&path)
+            .map_err(|e| CoreError::io(format!("reading {}", path.display()), e))?;
+        let requirement_ids: BTreeSet<&str> = markdown
+            .lines()
+            .filter_map(|l| l.trim().strip_prefix("- **Requirement ID**:"))
+            .map(str::trim)
+            .collect();
+        let spec_dir = format!("specs/{spec}/");
+        let (mut by_moved, mut by_staying) = (false, false);
+        for task in tasks {
+            let touches = task.files.iter().any(|f| f.contains(&spec_dir))
+                || task
+                    .requirements
+                    .iter()
+                    .any(|r| requirement_ids.contains(r.as_str()));
+            if !touches {
+                continue;
+            }
+            if moving.contains(task.id.as_str()) {
+                by_moved = true;
+            } else {
+                by_staying = true;
+            }
+        }
+        match (by_moved, by_staying) {
+            (true, false) => assignment.moved.push(spec),
+            (true, true) => assignment.copied.push(spec),
+            _ => {}
+        }
+    }
+    Ok(assignment)
+}
+
+/// Blockquote recorded at the top of the new change's proposal.
+fn split_note(
+    old_id: &str,
+    moved: &[String],
+    copied_specs: &[String],
+    detached: &[DetachedDependency],
+) -> String {
+    let date = chrono::Local::now().format("%Y-%m-%d");
+    let noun = if moved.len() == 1 { "task" } else { "tasks" };
+    let mut note = format!(
+        "> Split from `{old_id}` on {date} ({noun} {}).\n",
+        moved.join(", ")
+    );
+    for spec in copied_specs {
+        note.push_str(&format!(
+            "> Review by hand: delta spec `{spec}` was copied because tasks left in `{old_id}` also work on it.\n"
+        ));
+    }
+    for dep in detached {
+        note.push_str(&format!(
+            "> Task {} depended on task {} of `{old_id}`, which was not moved.\n",
+            dep.task, dep.depends_on
+        ));
+    }
+    note
+}
+
+/// Insert `note` after the proposal's front matter, or at the top.
+fn insert_note(proposal: &str, note: &str) -> String {
+    let split_at = proposal
+        .strip_prefix("---\n")
+        .and_then(|rest| rest.find("\n---\n").map(|end| 4 + end + 5))
+        .unwrap_or(0);
+    let (front, body) = proposal.split_at(split_at);
+    let body = body.trim_start_matches('\n');
+    if body.is_empty() {
+        format!("{front}{note}")
+    } else {
+        format!("{front}{note}\n{body}")
+    }
+}
+
+fn create_error(err: CreateError) -> CoreError {
+    match err {
+        CreateError::Io(e) => CoreError::io("creating the split change", e),
+        other => CoreError::validation(other.to_string()),
+    }
+}
+
+fn write(path: &Path, contents: &str) -> CoreResult<()> {
+    ito_common::io::write_std(path, contents)
+        .map_err(|e| CoreError::io(format!("writing {}", path.display()), e))
+}
+
+fn copy_dir(from: &Path, to: &Path) -> CoreResult<()> {
+    for entry in walkdir::WalkDir::new(from)
+        .into_iter()
+        .filter_map(Result::ok)
+    {
+        let Ok(rel) = entry.path().strip_prefix(from) else {
+            continue;
+        };
+        let target = to.join(rel);
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&target)
+                .map_err(|e| CoreError::io(format!("creating {}", target.display()), e))?;
+        } else if entry.file_type().is_file() {
+            std::fs::copy(entry.path(), &target)
+                .map_err(|e| CoreError::io(format!("copying {}", entry.path().display()), e))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "change_split_tests.rs"]
+mod change_split_tests;
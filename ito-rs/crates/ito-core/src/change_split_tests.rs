@@ -0,0 +1,44 @@
+use super::*;
+
+#[test]
+fn rewrite_dependencies_maps_and_drops_ids() {
+    let line = "- **Dependencies**: Task 1.1, 1.2, Task 2.1";
+    let out = rewrite_dependencies(line, |dep| {
+        (dep != "1.2").then(|| dep.replace("2.1", "2.4"))
+    });
+    assert_eq!(out, "- **Dependencies**: Task 1.1, Task 2.4");
+
+    let out = rewrite_dependencies("- **Dependencies**: 1.2", |_| None);
+    assert_eq!(out, "- **Dependencies**: None");
+
+    let line = "- **Dependencies**: All Wave 1 tasks";
+    assert_eq!(rewrite_dependencies(line, |_| None), line);
+}
+
+#[test]
+fn insert_note_goes_after_front_matter() {
+    let note = "> Split from `001-01_a`.\n";
+
+    let proposal = "---\nowner:\n---\n## Why\nBecause\n";
+    assert_eq!(
+        insert_note(proposal, note),
+        "---\nowner:\n---\n> Split from `001-01_a`.\n\n## Why\nBecause\n"
+    );
+    assert_eq!(
+        insert_note("## Why\nBecause\n", note),
+        "> Split from `001-01_a`.\n\n## Why\nBecause\n"
+    );
+    assert_eq!(insert_note("", note), note);
+}
+
+#[test]
+fn task_blocks_end_at_next_heading_or_break() {
+    let contents = "# Tasks for: x\n\n## Wave 1\n\n- **Depends On**: None\n\n### Task 1.1: One\n\n- **Status**: [ ] pending\n\n### Task 1.2: Two\n- **Action**:\n  ```sh\n  # not a heading\n  ```\n- **Status**: [ ] pending\n\n---\n\n## Wave 2\n";
+    let parsed = parse_tasks_tracking_file(contents);
+    let lines: Vec<&str> = contents.lines().collect();
+
+    let blocks = task_blocks(&lines, &parsed);
+
+    assert_eq!(blocks["1.1"], 6..10);
+    assert_eq!(blocks["1.2"], 10..17);
+}
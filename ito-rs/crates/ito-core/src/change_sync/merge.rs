@@ -0,0 +1,238 @@
+//! Merge rules for change files synced through the coordination branch.
+//!
+//! Files are merged against the copy recorded at the last sync (the base):
+//! a side that still matches the base yields to the other. When both sides
+//! changed, only the task tracking file can be merged — task by task, the
+//! most advanced status wins and ties go to the later timestamp. Everything
+//! else is reported as a conflict.
+
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use ito_domain::tasks::{TaskItem, TaskStatus, TasksFormat, parse_tasks_tracking_file};
+
+/// Outcome of merging one synced file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum FileMerge {
+    /// Both sides agree, or there is nothing to apply.
+    Unchanged,
+    /// Only the local copy changed; publish it to the branch.
+    Local,
+    /// Only the branch copy changed; write it locally.
+    Remote,
+    /// Both sides changed and merged cleanly; publish and write the result.
+    Merged(String),
+    /// Both sides changed and could not be merged.
+    Conflict(String),
+}
+
+/// Merge one file given its base, local, and branch contents.
+///
+/// Deletions are never propagated: a file missing on one side is copied
+/// from the other unless that side still matches the base.
+pub(crate) fn merge_file(
+    base: Option<&str>,
+    local: Option<&str>,
+    remote: Option<&str>,
+    is_tracking_file: bool,
+) -> FileMerge {
+    let (local, remote) = match (local, remote) {
+        (None, None) => return FileMerge::Unchanged,
+        (Some(_), None) => return FileMerge::Local,
+        (None, Some(remote)) if base == Some(remote) => return FileMerge::Unchanged,
+        (None, Some(_)) => return FileMerge::Remote,
+        (Some(local), Some(remote)) => (local, remote),
+    };
+    if local == remote {
+        return FileMerge::Unchanged;
+    }
+    if base == Some(local) {
+        return FileMerge::Remote;
+    }
+    if base == Some(remote) {
+        return FileMerge::Local;
+    }
+    if !is_tracking_file {
+        return FileMerge::Conflict(
+            "changed both locally and on the coordination branch".to_string(),
+        );
+    }
+
+    match merge_tracking_file(base, local, remote) {
+        Ok(merged) if merged == local => FileMerge::Local,
+        Ok(merged) if merged == remote => FileMerge::Remote,
+        Ok(merged) => FileMerge::Merged(merged),
+        Err(reason) => FileMerge::Conflict(reason),
+    }
+}
+
+/// Merge two copies of a tasks tracking file task by task.
+///
+/// The copies must list the same tasks, unless one of them still lists the
+/// base's tasks, in which case the other side's task list is kept.
+pub(crate) fn merge_tracking_file(
+    base: Option<&str>,
+    local: &str,
+    remote: &str,
+) -> Result<String, String> {
+    let local_tasks = TrackedTasks::parse(local)?;
+    let remote_tasks = TrackedTasks::parse(remote)?;
+    if local_tasks.format != remote_tasks.format {
+        return Err("local and coordination copies use different task formats".to_string());
+    }
+
+    let base_tasks = base.and_then(|base| TrackedTasks::parse(base).ok());
+    let base_ids = base_tasks.as_ref().map(TrackedTasks::ids);
+    let keep_local_layout = if local_tasks.ids() == remote_tasks.ids()
+        || base_ids.as_ref() == Some(&remote_tasks.ids())
+    {
+        true
+    } else if base_ids.as_ref() == Some(&local_tasks.ids()) {
+        false
+    } else {
+        return Err(
+            "tasks were added or removed both locally and on the coordination branch".to_string(),
+        );
+    };
+
+    let (layout, other) = if keep_local_layout {
+        (&local_tasks, &remote_tasks)
+    } else {
+        (&remote_tasks, &local_tasks)
+    };
+    let other_by_id: HashMap<&str, usize> = other
+        .tasks
+        .iter()
+        .enumerate()
+        .map(|(index, task)| (task.id.as_str(), index))
+        .collect();
+
+    let mut out: Vec<&str> = Vec::new();
+    let mut cursor = 0;
+    for (task, range) in layout.tasks.iter().zip(&layout.units) {
+        let Some(&other_index) = other_by_id.get(task.id.as_str()) else {
+            continue;
+        };
+        let other_range = &other.units[other_index];
+        if layout.lines[range.clone()] == other.lines[other_range.clone()] {
+            continue;
+        }
+        if prefer_other(task, &other.tasks[other_index], keep_local_layout) {
+            out.extend_from_slice(&layout.lines[cursor..range.start]);
+            out.extend_from_slice(&other.lines[other_range.clone()]);
+            cursor = range.end;
+        }
+    }
+    out.extend_from_slice(&layout.lines[cursor..]);
+
+    let mut merged = out.join("\n");
+    if layout.trailing_newline {
+        merged.push('\n');
+    }
+    Ok(merged)
+}
+
+/// Parsed tasks with the lines each task occupies.
+struct TrackedTasks<'a> {
+    format: TasksFormat,
+    lines: Vec<&'a str>,
+    trailing_newline: bool,
+    tasks: Vec<TaskItem>,
+    units: Vec<Range<usize>>,
+}
+
+impl<'a> TrackedTasks<'a> {
+    fn parse(contents: &'a str) -> Result<Self, String> {
+        let parsed = parse_tasks_tracking_file(contents);
+        let mut tasks = parsed.tasks;
+        tasks.sort_by_key(|task| task.header_line_index);
+
+        let mut seen = HashSet::new();
+        if let Some(task) = tasks.iter().find(|task| !seen.insert(task.id.as_str())) {
+            return Err(format!("task id '{}' appears more than once", task.id));
+        }
+
+        let lines: Vec<&str> = contents.lines().collect();
+        let units = tasks
+            .iter()
+            .map(|task| task_unit(&lines, parsed.format, task.header_line_index))
+            .collect();
+        Ok(Self {
+            format: parsed.format,
+            lines,
+            trailing_newline: contents.ends_with('\n'),
+            tasks,
+            units,
+        })
+    }
+
+    fn ids(&self) -> Vec<&str> {
+        self.tasks.iter().map(|task| task.id.as_str()).collect()
+    }
+}
+
+/// Lines that belong to the task whose header is at `start`.
+///
+/// A checkbox task is its own line; an enhanced task runs until the next
+/// task or section heading.
+fn task_unit(lines: &[&str], format: TasksFormat, start: usize) -> Range<usize> {
+    let start = start.min(lines.len());
+    if format == TasksFormat::Checkbox {
+        return start..(start + 1).min(lines.len());
+    }
+    let end = lines
+        .iter()
+        .enumerate()
+        .skip(start + 1)
+        .find(|(_, line)| line.starts_with("### ") || line.starts_with("## "))
+        .map_or(lines.len(), |(index, _)| index);
+    start..end
+}
+
+/// Whether `other`'s copy of a task should replace `layout`'s.
+///
+/// Ties with no usable timestamps go to the coordination branch copy so every
+/// clone settles on the same result.
+fn prefer_other(layout: &TaskItem, other: &TaskItem, layout_is_local: bool) -> bool {
+    let (layout_rank, other_rank) = (status_rank(layout.status), status_rank(other.status));
+    if layout_rank != other_rank {
+        return other_rank > layout_rank;
+    }
+    match (last_touched(layout), last_touched(other)) {
+        (Some(a), Some(b)) if a != b => b > a,
+        (None, Some(_)) => true,
+        (Some(_), None) => false,
+        _ => layout_is_local,
+    }
+}
+
+/// How far a status is along the task lifecycle.
+fn status_rank(status: TaskStatus) -> u8 {
+    match status {
+        TaskStatus::Pending => 0,
+        TaskStatus::InProgress => 1,
+        TaskStatus::Shelved => 2,
+        TaskStatus::Complete => 3,
+    }
+}
+
+/// Latest timestamp recorded on a task.
+fn last_touched(task: &TaskItem) -> Option<DateTime<Utc>> {
+    let transitions = [task.started_at.as_deref(), task.completed_at.as_deref()]
+        .into_iter()
+        .flatten()
+        .filter_map(|value| DateTime::parse_from_rfc3339(value).ok())
+        .map(|value| value.with_timezone(&Utc));
+    let updated = task
+        .updated_at
+        .as_deref()
+        .and_then(|value| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok())
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|value| value.and_utc());
+    transitions.chain(updated).max()
+}
+
+#[cfg(test)]
+#[path = "merge_tests.rs"]
+mod merge_tests;
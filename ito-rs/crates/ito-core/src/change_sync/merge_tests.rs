@@ -0,0 +1,160 @@
+use super::*;
+
+/// Enhanced tracking file whose tasks carry the given status and extra lines.
+fn enhanced(tasks: &[(&str, &str, &[&str])]) -> String {
+    let mut out = String::from("# Tasks for: 001-01_demo\n\n## Wave 1\n\n- **Depends On**: None\n");
+    for (id, status, extra) in tasks {
+        out.push_str(&format!(
+            "\n### Task {id}: Do {id}\n\n- **Dependencies**: None\n- **Updated At**: 2026-02-01\n"
+        ));
+        for line in *extra {
+            out.push_str(line);
+            out.push('\n');
+        }
+        out.push_str(&format!("- **Status**: {status}\n"));
+    }
+    out
+}
+
+fn status_of(contents: &str, id: &str) -> TaskStatus {
+    parse_tasks_tracking_file(contents)
+        .tasks
+        .into_iter()
+        .find(|task| task.id == id)
+        .map(|task| task.status)
+        .expect("task present")
+}
+
+const PENDING: &str = "[ ] pending";
+const IN_PROGRESS: &str = "[>] in-progress";
+const COMPLETE: &str = "[x] complete";
+
+#[test]
+fn side_matching_base_yields_to_the_other() {
+    assert_eq!(
+        merge_file(Some("a"), Some("a"), Some("b"), false),
+        FileMerge::Remote
+    );
+    assert_eq!(
+        merge_file(Some("a"), Some("b"), Some("a"), false),
+        FileMerge::Local
+    );
+    assert_eq!(
+        merge_file(Some("a"), Some("b"), Some("b"), false),
+        FileMerge::Unchanged
+    );
+}
+
+#[test]
+fn missing_files_are_copied_not_deleted() {
+    assert_eq!(merge_file(None, Some("a"), None, false), FileMerge::Local);
+    assert_eq!(merge_file(None, None, Some("a"), false), FileMerge::Remote);
+    assert_eq!(
+        merge_file(Some("a"), None, Some("a"), false),
+        FileMerge::Unchanged
+    );
+    assert_eq!(
+        merge_file(Some("a"), Some("a"), None, false),
+        FileMerge::Local
+    );
+}
+
+#[test]
+fn non_tracking_files_changed_on_both_sides_conflict() {
+    assert!(matches!(
+        merge_file(Some("a"), Some("b"), Some("c"), false),
+        FileMerge::Conflict(_)
+    ));
+    assert!(matches!(
+        merge_file(None, Some("b"), Some("c"), false),
+        FileMerge::Conflict(_)
+    ));
+}
+
+#[test]
+fn most_advanced_status_wins_per_task() {
+    let base = enhanced(&[("1.1", PENDING, &[]), ("1.2", PENDING, &[])]);
+    let local = enhanced(&[("1.1", COMPLETE, &[]), ("1.2", PENDING, &[])]);
+    let remote = enhanced(&[("1.1", IN_PROGRESS, &[]), ("1.2", COMPLETE, &[])]);
+
+    let FileMerge::Merged(merged) = merge_file(Some(&base), Some(&local), Some(&remote), true)
+    else {
+        panic!("expected a merged file");
+    };
+    assert_eq!(status_of(&merged, "1.1"), TaskStatus::Complete);
+    assert_eq!(status_of(&merged, "1.2"), TaskStatus::Complete);
+    assert_eq!(
+        merged,
+        enhanced(&[("1.1", COMPLETE, &[]), ("1.2", COMPLETE, &[])])
+    );
+}
+
+#[test]
+fn equal_status_ties_go_to_the_later_timestamp() {
+    let early: &[&str] = &["- **Started**: 2026-02-01T09:00:00Z"];
+    let late: &[&str] = &["- **Started**: 2026-02-01T10:00:00Z"];
+    let local = enhanced(&[("1.1", IN_PROGRESS, late)]);
+    let remote = enhanced(&[("1.1", IN_PROGRESS, early)]);
+
+    assert_eq!(
+        merge_file(None, Some(&local), Some(&remote), true),
+        FileMerge::Local
+    );
+    assert_eq!(
+        merge_file(None, Some(&remote), Some(&local), true),
+        FileMerge::Remote
+    );
+}
+
+#[test]
+fn full_ties_go_to_the_coordination_branch() {
+    let local = "## 1. Work\n- [x] 1.1 Done here\n";
+    let remote = "## 1. Work\n- [x] 1.1 Done there\n";
+    assert_eq!(
+        merge_file(None, Some(local), Some(remote), true),
+        FileMerge::Remote
+    );
+}
+
+#[test]
+fn checkbox_tasks_merge_line_by_line() {
+    let base = "## 1. Work\n- [ ] 1.1 First\n- [ ] 1.2 Second\n";
+    let local = "## 1. Work\n- [x] 1.1 First\n- [ ] 1.2 Second\n";
+    let remote = "## 1. Work\n- [ ] 1.1 First\n- [~] 1.2 Second\n";
+    assert_eq!(
+        merge_file(Some(base), Some(local), Some(remote), true),
+        FileMerge::Merged("## 1. Work\n- [x] 1.1 First\n- [~] 1.2 Second\n".to_string())
+    );
+}
+
+#[test]
+fn tasks_added_on_one_side_keep_that_layout() {
+    let base = "- [ ] 1.1 First\n";
+    let local = "- [ ] 1.1 First\n- [ ] 1.2 Added\n";
+    let remote = "- [x] 1.1 First\n";
+    assert_eq!(
+        merge_file(Some(base), Some(local), Some(remote), true),
+        FileMerge::Merged("- [x] 1.1 First\n- [ ] 1.2 Added\n".to_string())
+    );
+}
+
+#[test]
+fn tasks_added_on_both_sides_conflict() {
+    let base = "- [ ] 1.1 First\n";
+    let local = "- [ ] 1.1 First\n- [ ] 1.2 Mine\n";
+    let remote = "- [x] 1.1 First\n- [ ] 1.3 Theirs\n";
+    assert!(matches!(
+        merge_file(Some(base), Some(local), Some(remote), true),
+        FileMerge::Conflict(_)
+    ));
+}
+
+#[test]
+fn mixed_formats_conflict() {
+    let local = enhanced(&[("1.1", COMPLETE, &[])]);
+    let remote = "- [x] 1.1 First\n";
+    assert!(matches!(
+        merge_file(None, Some(&local), Some(remote), true),
+        FileMerge::Conflict(_)
+    ));
+}
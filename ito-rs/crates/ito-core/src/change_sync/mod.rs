@@ -0,0 +1,492 @@
+//! Sync change metadata through the coordination branch.
+//!
+//! `ito change sync` publishes each change's task tracking file and
+//! `.ito.yaml` (and, when configured, every artifact in the change) to the
+//! coordination branch and pulls the branch's copies back. Commits are built
+//! with a temporary index, so the working tree and the checked-out branch are
+//! never touched.
+//!
+//! Each file is merged against the copy recorded at the last sync, which is
+//! kept in the private ref `refs/ito/synced/<branch>`; the `merge` module has
+//! the rules. Files that cannot be merged are left unchanged on both sides and
+//! reported; once the local copy matches the branch again it syncs normally.
+//! Changes that exist only on the coordination branch are not pulled.
+
+mod merge;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ito_common::fs::StdFs;
+use ito_domain::tasks::{TaskStatus, parse_tasks_tracking_file, tasks_path_checked};
+use serde::Serialize;
+
+use crate::error_bridge::IntoCoreResult;
+use crate::errors::{CoreError, CoreResult};
+use crate::git::{
+    CoordinationGitErrorKind, fetch_coordination_branch_with_runner, is_git_worktree,
+    push_coordination_branch_with_runner,
+};
+use crate::process::{ProcessOutput, ProcessRequest, ProcessRunner, SystemProcessRunner};
+use merge::{FileMerge, merge_file};
+
+/// Commit message used for sync commits on the coordination branch.
+const SYNC_COMMIT_MESSAGE: &str = "chore(coordination): sync change metadata";
+
+/// Change metadata file synced alongside the tracking file.
+const CHANGE_META_FILE: &str = ".ito.yaml";
+
+/// Attempts before giving up when the branch keeps moving during a push.
+const MAX_SYNC_ATTEMPTS: usize = 2;
+
+/// Options for [`sync_changes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeSyncOptions {
+    /// Coordination branch name on `origin`.
+    pub branch: String,
+    /// Also sync every artifact in the change, not only task status and metadata.
+    pub include_artifacts: bool,
+}
+
+/// A file that changed both locally and on the coordination branch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChangeSyncConflict {
+    /// Change the file belongs to.
+    pub change_id: String,
+    /// Path relative to the change directory.
+    pub file: String,
+    /// Why the two copies could not be merged.
+    pub reason: String,
+}
+
+/// Result of one `ito change sync` run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChangeSyncReport {
+    /// Coordination branch that was synced.
+    pub branch: String,
+    /// Commit pushed to the branch, when anything was published.
+    pub commit: Option<String>,
+    /// Files published to the branch, as `<change-id>/<file>`.
+    pub published: Vec<String>,
+    /// Local files updated from the branch, as `<change-id>/<file>`.
+    pub pulled: Vec<String>,
+    /// Files left untouched because both sides changed.
+    pub conflicts: Vec<ChangeSyncConflict>,
+}
+
+/// Sync change metadata with the coordination branch on `origin`.
+pub fn sync_changes(
+    project_root: &Path,
+    ito_path: &Path,
+    options: &ChangeSyncOptions,
+) -> CoreResult<ChangeSyncReport> {
+    sync_changes_with_runner(&SystemProcessRunner, project_root, ito_path, options)
+}
+
+/// [`sync_changes`] with an injectable process runner.
+///
+/// When the branch moves between fetch and push, the whole sync is retried
+/// once against the new branch head.
+pub fn sync_changes_with_runner(
+    runner: &dyn ProcessRunner,
+    project_root: &Path,
+    ito_path: &Path,
+    options: &ChangeSyncOptions,
+) -> CoreResult<ChangeSyncReport> {
+    if !is_git_worktree(runner, project_root) {
+        return Err(CoreError::validation(
+            "ito change sync requires a git repository",
+        ));
+    }
+    let git = Git {
+        runner,
+        repo_root: project_root,
+    };
+    let mut attempt = 1;
+    loop {
+        match sync_once(&git, ito_path, options)? {
+            Some(report) => return Ok(report),
+            None if attempt < MAX_SYNC_ATTEMPTS => attempt += 1,
+            None => {
+                return Err(CoreError::process(format!(
+                    "change sync failed: coordination branch '{}' kept moving; rerun ito change sync",
+                    options.branch
+                )));
+            }
+        }
+    }
+}
+
+/// Status of a task on the coordination branch, if the branch records it.
+///
+/// Fetching is best effort: when `origin` is unreachable the last fetched
+/// copy of the branch is used. Returns `None` outside a git repository or
+/// when the branch does not have the change or task.
+pub fn coordination_task_status(
+    project_root: &Path,
+    ito_path: &Path,
+    branch: &str,
+    change_id: &str,
+    task_id: &str,
+) -> Option<TaskStatus> {
+    coordination_task_status_with_runner(
+        &SystemProcessRunner,
+        project_root,
+        ito_path,
+        branch,
+        change_id,
+        task_id,
+    )
+}
+
+/// [`coordination_task_status`] with an injectable process runner.
+pub fn coordination_task_status_with_runner(
+    runner: &dyn ProcessRunner,
+    project_root: &Path,
+    ito_path: &Path,
+    branch: &str,
+    change_id: &str,
+    task_id: &str,
+) -> Option<TaskStatus> {
+    if !is_git_worktree(runner, project_root) {
+        return None;
+    }
+    let _ = fetch_coordination_branch_with_runner(runner, project_root, branch);
+    let git = Git {
+        runner,
+        repo_root: project_root,
+    };
+    let head = git.resolve(&remote_tracking_ref(branch))?;
+    let tracking = tracking_file_name(ito_path, change_id);
+    let contents = git.read_blob(&head, &branch_path(change_id, &tracking))?;
+    parse_tasks_tracking_file(&contents)
+        .tasks
+        .into_iter()
+        .find(|task| task.id == task_id)
+        .map(|task| task.status)
+}
+
+/// One synced file.
+struct SyncedFile {
+    change_id: String,
+    file: String,
+    local_path: PathBuf,
+    is_tracking_file: bool,
+}
+
+impl SyncedFile {
+    fn label(&self) -> String {
+        format!("{}/{}", self.change_id, self.file)
+    }
+}
+
+/// Run one sync attempt; `None` means the push lost a race and should be retried.
+fn sync_once(
+    git: &Git<'_>,
+    ito_path: &Path,
+    options: &ChangeSyncOptions,
+) -> CoreResult<Option<ChangeSyncReport>> {
+    let branch = options.branch.as_str();
+    let remote_head = match fetch_coordination_branch_with_runner(git.runner, git.repo_root, branch)
+    {
+        Ok(()) => git.resolve(&remote_tracking_ref(branch)),
+        Err(err) if err.kind == CoordinationGitErrorKind::RemoteMissing => None,
+        Err(err) => {
+            return Err(CoreError::process(format!(
+                "change sync failed: {}",
+                err.message
+            )));
+        }
+    };
+    let base_head = git.resolve(&synced_ref(branch));
+    let remote_paths = remote_head
+        .as_deref()
+        .map(|head| git.list_change_files(head))
+        .transpose()?
+        .unwrap_or_default();
+    let base_paths = base_head
+        .as_deref()
+        .map(|head| git.list_change_files(head))
+        .transpose()?
+        .unwrap_or_default();
+
+    let files = collect_files(ito_path, options.include_artifacts, &remote_paths)?;
+    let mut report = ChangeSyncReport {
+        branch: branch.to_string(),
+        commit: None,
+        published: Vec::new(),
+        pulled: Vec::new(),
+        conflicts: Vec::new(),
+    };
+    let mut publish: Vec<(String, String)> = Vec::new();
+    let mut pull: Vec<(PathBuf, String)> = Vec::new();
+    for (path, file) in &files {
+        let read = |head: &Option<String>, paths: &BTreeSet<String>| {
+            head.as_deref()
+                .filter(|_| paths.contains(path))
+                .and_then(|head| git.read_blob(head, path))
+        };
+        let base = read(&base_head, &base_paths);
+        let remote = read(&remote_head, &remote_paths);
+        let local = fs::read_to_string(&file.local_path).ok();
+
+        match merge_file(
+            base.as_deref(),
+            local.as_deref(),
+            remote.as_deref(),
+            file.is_tracking_file,
+        ) {
+            FileMerge::Unchanged => {}
+            FileMerge::Local => {
+                if let Some(local) = local {
+                    publish.push((path.clone(), local));
+                    report.published.push(file.label());
+                }
+            }
+            FileMerge::Remote => {
+                if let Some(remote) = remote {
+                    pull.push((file.local_path.clone(), remote));
+                    report.pulled.push(file.label());
+                }
+            }
+            FileMerge::Merged(merged) => {
+                publish.push((path.clone(), merged.clone()));
+                pull.push((file.local_path.clone(), merged));
+                report.published.push(file.label());
+                report.pulled.push(file.label());
+            }
+            FileMerge::Conflict(reason) => report.conflicts.push(ChangeSyncConflict {
+                change_id: file.change_id.clone(),
+                file: file.file.clone(),
+                reason,
+            }),
+        }
+    }
+
+    let mut synced_head = remote_head.clone();
+    if !publish.is_empty() {
+        let commit = git.commit_files(remote_head.as_deref(), &publish)?;
+        match push_coordination_branch_with_runner(git.runner, git.repo_root, &commit, branch) {
+            Ok(()) => {}
+            Err(err) if err.kind == CoordinationGitErrorKind::NonFastForward => return Ok(None),
+            Err(err) => {
+                return Err(CoreError::process(format!(
+                    "change sync failed: {}",
+                    err.message
+                )));
+            }
+        }
+        synced_head = Some(commit.clone());
+        report.commit = Some(commit);
+    }
+
+    for (path, contents) in &pull {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| CoreError::io(format!("creating {}", parent.display()), err))?;
+        }
+        fs::write(path, contents)
+            .map_err(|err| CoreError::io(format!("writing {}", path.display()), err))?;
+    }
+    if let Some(head) = synced_head {
+        git.run_ok(&["update-ref", &synced_ref(branch), &head], "update-ref")?;
+    }
+    Ok(Some(report))
+}
+
+/// Files to sync, keyed by their path on the coordination branch.
+fn collect_files(
+    ito_path: &Path,
+    include_artifacts: bool,
+    remote_paths: &BTreeSet<String>,
+) -> CoreResult<BTreeMap<String, SyncedFile>> {
+    let mut files = BTreeMap::new();
+    for change_id in ito_domain::discovery::list_change_dir_names(&StdFs, ito_path).into_core()? {
+        if tasks_path_checked(ito_path, &change_id).is_none() {
+            continue;
+        }
+        let change_dir = ito_path.join("changes").join(&change_id);
+        let tracking = tracking_file_name(ito_path, &change_id);
+
+        let mut names = vec![tracking.clone(), CHANGE_META_FILE.to_string()];
+        if include_artifacts {
+            names.extend(local_change_files(&change_dir));
+            let prefix = branch_path(&change_id, "");
+            names.extend(
+                remote_paths
+                    .iter()
+                    .filter_map(|path| path.strip_prefix(&prefix))
+                    .map(ToOwned::to_owned),
+            );
+        }
+        for name in names {
+            if name.split('/').any(|part| part.is_empty() || part == "..") {
+                continue;
+            }
+            files
+                .entry(branch_path(&change_id, &name))
+                .or_insert_with(|| SyncedFile {
+                    change_id: change_id.clone(),
+                    local_path: change_dir.join(&name),
+                    is_tracking_file: name == tracking,
+                    file: name,
+                });
+        }
+    }
+    Ok(files)
+}
+
+/// Regular files under a change directory, relative to it with `/` separators.
+fn local_change_files(change_dir: &Path) -> Vec<String> {
+    walkdir::WalkDir::new(change_dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let relative = entry.path().strip_prefix(change_dir).ok()?;
+            let parts: Vec<String> = relative
+                .components()
+                .map(|part| part.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            Some(parts.join("/"))
+        })
+        .collect()
+}
+
+/// Tracking file of a change relative to its directory, `tasks.md` when the
+/// schema cannot be resolved.
+fn tracking_file_name(ito_path: &Path, change_id: &str) -> String {
+    let change_dir = ito_path.join("changes").join(change_id);
+    crate::tasks::tracking_file_path(ito_path, change_id)
+        .ok()
+        .and_then(|path| {
+            path.strip_prefix(&change_dir)
+                .ok()
+                .map(|relative| relative.to_string_lossy().replace('\\', "/"))
+        })
+        .unwrap_or_else(|| "tasks.md".to_string())
+}
+
+/// Path of a change file on the coordination branch.
+fn branch_path(change_id: &str, file: &str) -> String {
+    format!(".ito/changes/{change_id}/{file}")
+}
+
+fn remote_tracking_ref(branch: &str) -> String {
+    format!("refs/remotes/origin/{branch}")
+}
+
+/// Private ref recording the branch head at the last successful sync.
+fn synced_ref(branch: &str) -> String {
+    format!("refs/ito/synced/{branch}")
+}
+
+/// Git commands for one repository, run through the process runner.
+struct Git<'a> {
+    runner: &'a dyn ProcessRunner,
+    repo_root: &'a Path,
+}
+
+impl Git<'_> {
+    fn run(&self, request: ProcessRequest, operation: &str) -> CoreResult<ProcessOutput> {
+        self.runner
+            .run(&request.current_dir(self.repo_root))
+            .map_err(|err| {
+                CoreError::process(format!("git {operation} command failed to run: {err}"))
+            })
+    }
+
+    fn run_ok(&self, args: &[&str], operation: &str) -> CoreResult<String> {
+        self.run_request_ok(
+            ProcessRequest::new("git").args(args.iter().copied()),
+            operation,
+        )
+    }
+
+    fn run_request_ok(&self, request: ProcessRequest, operation: &str) -> CoreResult<String> {
+        let output = self.run(request, operation)?;
+        if !output.success {
+            let detail = if output.stderr.trim().is_empty() {
+                output.stdout.trim()
+            } else {
+                output.stderr.trim()
+            };
+            return Err(CoreError::process(format!(
+                "change sync failed: git {operation} failed ({detail})"
+            )));
+        }
+        Ok(output.stdout.trim().to_string())
+    }
+
+    /// Commit id `reference` points at, if it exists.
+    fn resolve(&self, reference: &str) -> Option<String> {
+        let spec = format!("{reference}^{{commit}}");
+        let output = self
+            .run(
+                ProcessRequest::new("git").args(["rev-parse", "--verify", "--quiet", &spec]),
+                "rev-parse",
+            )
+            .ok()?;
+        let head = output.stdout.trim();
+        (output.success && !head.is_empty()).then(|| head.to_string())
+    }
+
+    /// Every file under `.ito/changes` in `commit`.
+    fn list_change_files(&self, commit: &str) -> CoreResult<BTreeSet<String>> {
+        let listing = self.run_ok(
+            &["ls-tree", "-r", "--name-only", commit, "--", ".ito/changes"],
+            "ls-tree",
+        )?;
+        Ok(listing.lines().map(ToOwned::to_owned).collect())
+    }
+
+    /// Contents of `path` in `commit`, if present and readable as text.
+    fn read_blob(&self, commit: &str, path: &str) -> Option<String> {
+        let spec = format!("{commit}:{path}");
+        let output = self
+            .run(
+                ProcessRequest::new("git").args(["cat-file", "blob", &spec]),
+                "cat-file",
+            )
+            .ok()?;
+        output.success.then_some(output.stdout)
+    }
+
+    /// Commit `files` on top of `parent` without touching the working tree.
+    fn commit_files(&self, parent: Option<&str>, files: &[(String, String)]) -> CoreResult<String> {
+        let scratch = tempfile::tempdir()
+            .map_err(|err| CoreError::io("creating temporary index directory", err))?;
+        let index = scratch.path().join("index").to_string_lossy().into_owned();
+        let with_index = |args: &[&str]| {
+            ProcessRequest::new("git")
+                .args(args.iter().copied())
+                .env("GIT_INDEX_FILE", index.as_str())
+        };
+
+        match parent {
+            Some(parent) => self.run_request_ok(with_index(&["read-tree", parent]), "read-tree")?,
+            None => self.run_request_ok(with_index(&["read-tree", "--empty"]), "read-tree")?,
+        };
+        for (number, (path, contents)) in files.iter().enumerate() {
+            let blob_path = scratch.path().join(format!("blob-{number}"));
+            fs::write(&blob_path, contents)
+                .map_err(|err| CoreError::io("writing temporary blob", err))?;
+            let blob_path = blob_path.to_string_lossy();
+            let oid = self.run_ok(&["hash-object", "-w", "--", &blob_path], "hash-object")?;
+            let cacheinfo = format!("100644,{oid},{path}");
+            self.run_request_ok(
+                with_index(&["update-index", "--add", "--cacheinfo", &cacheinfo]),
+                "update-index",
+            )?;
+        }
+        let tree = self.run_request_ok(with_index(&["write-tree"]), "write-tree")?;
+
+        let mut args = vec!["commit-tree", tree.as_str()];
+        if let Some(parent) = parent {
+            args.extend(["-p", parent]);
+        }
+        args.extend(["-m", SYNC_COMMIT_MESSAGE]);
+        self.run_ok(&args, "commit-tree")
+    }
+}
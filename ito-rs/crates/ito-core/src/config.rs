@@ -2,48 +2,60 @@
 //!
 //! This module provides low-level functions for reading, writing, and
 //! manipulating JSON configuration files with dot-delimited path navigation.
+//! Files ending in `.toml` or `.yaml` are read and written in their own
+//! format, so edits preserve the format a user chose.
 
 use std::path::{Path, PathBuf};
 
 use crate::errors::{CoreError, CoreResult};
 use ito_config::ConfigContext;
+use ito_config::file_format::ConfigFileFormat;
 use ito_config::load_cascading_project_config;
 use ito_config::types::{
     ArchiveMainIntegrationMode, IntegrationMode, MemoryConfig, MemoryOpConfig,
     ProposalIntegrationMode, RepositoryPersistenceMode, WorktreeStrategy,
 };
 
-/// Read a JSON config file, returning an empty object if the file doesn't exist.
+/// Read a config file, returning an empty object if the file doesn't exist.
+///
+/// The format (JSON, TOML or YAML) follows the file extension.
 ///
 /// # Errors
 ///
-/// Returns [`CoreError::Serde`] if the file contains invalid JSON or is not a JSON object.
+/// Returns [`CoreError::Serde`] if the file cannot be parsed or is not an object.
 pub fn read_json_config(path: &Path) -> CoreResult<serde_json::Value> {
     let Ok(contents) = std::fs::read_to_string(path) else {
         return Ok(serde_json::Value::Object(serde_json::Map::new()));
     };
-    let v: serde_json::Value = serde_json::from_str(&contents).map_err(|e| {
-        CoreError::serde(format!("Invalid JSON in {}", path.display()), e.to_string())
+    let format = ConfigFileFormat::from_path(path);
+    let v = format.parse(&contents).map_err(|e| {
+        CoreError::serde(
+            format!("Invalid {} in {}", format.as_str(), path.display()),
+            e,
+        )
     })?;
     match v {
         serde_json::Value::Object(_) => Ok(v),
         _ => Err(CoreError::serde(
-            format!("Expected JSON object in {}", path.display()),
+            format!("Expected {} object in {}", format.as_str(), path.display()),
             "root value is not an object",
         )),
     }
 }
 
-/// Write a JSON value to a config file (pretty-printed with trailing newline).
+/// Write a value to a config file (pretty-printed with trailing newline).
+///
+/// The format (JSON, TOML or YAML) follows the file extension.
 ///
 /// # Errors
 ///
 /// Returns [`CoreError::Serde`] if serialization fails, or [`CoreError::Io`] if writing fails.
 pub fn write_json_config(path: &Path, value: &serde_json::Value) -> CoreResult<()> {
-    let mut bytes = serde_json::to_vec_pretty(value)
-        .map_err(|e| CoreError::serde("Failed to serialize JSON config", e.to_string()))?;
-    bytes.push(b'\n');
-    ito_common::io::write_atomic_std(path, bytes)
+    let format = ConfigFileFormat::from_path(path);
+    let rendered = format.render(value).map_err(|e| {
+        CoreError::serde(format!("Failed to serialize {} config", format.as_str()), e)
+    })?;
+    ito_common::io::write_atomic_std(path, rendered)
         .map_err(|e| CoreError::io(format!("Failed to write config to {}", path.display()), e))?;
     Ok(())
 }
@@ -422,3 +422,21 @@ fn skill_id_resolves_returns_false_when_no_paths_exist() {
     let paths = known_skills_search_paths(tmp.path());
     assert!(!skill_id_resolves("anything", &paths));
 }
+
+#[test]
+fn config_set_preserves_toml_format() {
+    let td = tempfile::tempdir().unwrap();
+    let path = td.path().join("config.toml");
+    std::fs::write(&path, "[worktrees]\nenabled = false\n").unwrap();
+
+    let mut config = read_json_config(&path).unwrap();
+    json_set_path(&mut config, &["worktrees", "strategy"], json!("bare")).unwrap();
+    write_json_config(&path, &config).unwrap();
+
+    let written = std::fs::read_to_string(&path).unwrap();
+    let reparsed = ConfigFileFormat::Toml.parse(&written).expect("still TOML");
+    assert_eq!(
+        reparsed,
+        json!({"worktrees": {"enabled": false, "strategy": "bare"}})
+    );
+}
@@ -9,6 +9,7 @@ fn output(success: bool, stderr: &str) -> Result<ProcessOutput, ProcessExecution
         stdout: String::new(),
         stderr: stderr.to_string(),
         timed_out: false,
+        resources: Default::default(),
     })
 }
 
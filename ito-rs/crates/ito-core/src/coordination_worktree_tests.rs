@@ -45,6 +45,7 @@ fn ok(stdout: &str) -> Result<ProcessOutput, ProcessExecutionError> {
         stdout: stdout.to_string(),
         stderr: String::new(),
         timed_out: false,
+        resources: Default::default(),
     })
 }
 
@@ -55,6 +56,7 @@ fn fail(stderr: &str) -> Result<ProcessOutput, ProcessExecutionError> {
         stdout: String::new(),
         stderr: stderr.to_string(),
         timed_out: false,
+        resources: Default::default(),
     })
 }
 
@@ -445,6 +447,7 @@ fn auto_commit_stages_and_commits_when_changes_exist() {
             stdout: String::new(),
             stderr: String::new(),
             timed_out: false,
+            resources: Default::default(),
         }),
         ok(""),
     ]);
@@ -537,6 +540,7 @@ fn auto_commit_returns_error_when_commit_fails() {
             stdout: String::new(),
             stderr: String::new(),
             timed_out: false,
+            resources: Default::default(),
         }),
         fail("error: unable to auto-detect email address"),
     ]);
@@ -820,6 +824,7 @@ fn sync_coordination_worktree_fetches_commits_and_pushes_when_healthy() {
             stdout: String::new(),
             stderr: String::new(),
             timed_out: false,
+            resources: Default::default(),
         }),
         ok(""),         // commit
         ok("def456\n"), // rev-parse HEAD (post-commit)
@@ -16,23 +16,37 @@ use std::thread;
 use std::time::Duration;
 
 use ito_common::fs::StdFs;
-use ito_common::id::{parse_change_id, parse_module_id, parse_sub_module_id};
+use ito_common::id::{format_change_id, parse_change_id, parse_module_id, parse_sub_module_id};
 use ito_common::paths;
+use ito_config::ConfigContext;
 #[cfg(feature = "coordination-branch")]
-use ito_config::types::{CoordinationStorage, ItoConfig};
+use ito_config::load_cascading_project_config;
 #[cfg(feature = "coordination-branch")]
-use ito_config::{ConfigContext, load_cascading_project_config};
+use ito_config::types::{CoordinationStorage, ItoConfig};
 
 #[cfg(feature = "coordination-branch")]
 use crate::coordination_worktree::repair_current_worktree_coordination_links;
+use crate::staging;
 
 #[derive(Debug, thiserror::Error)]
 /// Errors that can occur while creating modules or changes.
 pub enum CreateError {
     /// The provided module name is invalid.
-    #[error("Invalid module name '{0}'")]
+    #[error("{0}")]
     InvalidModuleName(String),
 
+    /// The explicitly requested module id is malformed.
+    #[error("{0}")]
+    InvalidModuleId(String),
+
+    /// The explicitly requested module id is already used by another module.
+    #[error("Module id '{0}' is already used by '{1}'")]
+    ModuleIdTaken(String, String),
+
+    /// A module with the same name already exists under a different id.
+    #[error("Module '{0}' already exists as '{1}'")]
+    ModuleAlreadyExists(String, String),
+
     // Match TS: the message is already user-facing (e.g. "Change name must be lowercase ...").
     /// The provided change name is invalid.
     #[error("{0}")]
@@ -87,6 +101,10 @@ pub enum CreateError {
     /// JSON serialization/deserialization error.
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
+
+    /// Scaffold template rendering error.
+    #[error("Failed to render template: {0}")]
+    Template(String),
 }
 
 #[derive(Debug, Clone)]
@@ -139,20 +157,42 @@ pub fn create_module(
     depends_on: Vec<String>,
     description: Option<&str>,
 ) -> Result<CreateModuleResult, CreateError> {
-    let name = name.trim();
-    if name.is_empty() {
-        return Err(CreateError::InvalidModuleName(name.to_string()));
-    }
+    create_module_with_id(ito_path, name, None, scope, depends_on, description)
+}
+
+/// Create (or resolve) a module by name, optionally with an explicit id.
+///
+/// The name is slugified (lowercased, spaces and underscores turned into
+/// hyphens) and must then be a valid kebab-case name. Without `id`, the module
+/// receives the next id after the highest existing one; gaps are not reused.
+/// With `id`, creation fails when another module already uses it.
+///
+/// If a module with the same name already exists (and `id` is absent or
+/// matches it), this returns it with `created=false`.
+pub fn create_module_with_id(
+    ito_path: &Path,
+    name: &str,
+    id: Option<&str>,
+    scope: Vec<String>,
+    depends_on: Vec<String>,
+    description: Option<&str>,
+) -> Result<CreateModuleResult, CreateError> {
+    let name = slugify_name(name);
+    validate_module_name(&name)?;
+    let requested_id = id.map(normalize_module_id).transpose()?;
 
     let modules_dir = paths::modules_dir(ito_path);
     ito_common::io::create_dir_all_std(&modules_dir)?;
 
     // If a module with the same name already exists, return it.
-    if let Some(existing) = find_module_by_name(&modules_dir, name) {
+    if let Some(existing) = find_module_by_name(&modules_dir, &name) {
         // `find_module_by_name` only returns parseable module folder names.
         let parsed = parse_module_id(&existing).expect("module folder should be parseable");
         let module_id = parsed.module_id.to_string();
-        let module_name = parsed.module_name.unwrap_or_else(|| name.to_string());
+        if requested_id.as_ref().is_some_and(|id| *id != module_id) {
+            return Err(CreateError::ModuleAlreadyExists(name, existing));
+        }
+        let module_name = parsed.module_name.unwrap_or_else(|| name.clone());
         let module_dir = modules_dir.join(&existing);
         return Ok(CreateModuleResult {
             module_id,
@@ -164,25 +204,26 @@ pub fn create_module(
         });
     }
 
-    let next_id = next_module_id(&modules_dir)?;
-    let folder = format!("{next_id}_{name}");
+    let module_id = match requested_id {
+        Some(id) => {
+            if let Some(folder) = find_module_by_id(&modules_dir, &id) {
+                return Err(CreateError::ModuleIdTaken(id, folder));
+            }
+            id
+        }
+        None => next_module_id(&modules_dir),
+    };
+    let folder = format!("{module_id}_{name}");
     let module_dir = modules_dir.join(&folder);
-    ito_common::io::create_dir_all_std(&module_dir)?;
 
-    let title = to_title_case(name);
-    let md = generate_module_content(
-        &title,
-        description.or(Some("<!-- Describe the purpose of this module/epic -->")),
-        &scope,
-        &depends_on,
-        &[],
-    );
+    let md = render_module_scaffold(&name, description, &scope, &depends_on)?;
+    ito_common::io::create_dir_all_std(&module_dir)?;
     let module_md = module_dir.join("module.md");
     ito_common::io::write_std(&module_md, md)?;
 
     Ok(CreateModuleResult {
-        module_id: next_id,
-        module_name: name.to_string(),
+        module_id,
+        module_name: name,
         folder_name: folder,
         created: true,
         module_dir,
@@ -190,6 +231,51 @@ pub fn create_module(
     })
 }
 
+/// Context for [`ito_templates::instructions::MODULE_SCAFFOLD_TEMPLATE_PATH`].
+#[derive(Serialize)]
+struct ModuleScaffoldContext<'a> {
+    title: String,
+    name: &'a str,
+    date: String,
+    purpose: Option<&'a str>,
+    scope: &'a [String],
+    depends_on: &'a [String],
+}
+
+fn render_module_scaffold(
+    name: &str,
+    description: Option<&str>,
+    scope: &[String],
+    depends_on: &[String],
+) -> Result<String, CreateError> {
+    let ctx = ModuleScaffoldContext {
+        title: to_title_case(name),
+        name,
+        date: Utc::now().format("%Y-%m-%d").to_string(),
+        purpose: description.map(str::trim).filter(|d| !d.is_empty()),
+        scope,
+        depends_on,
+    };
+    ito_templates::instructions::render_instruction_template(
+        ito_templates::instructions::MODULE_SCAFFOLD_TEMPLATE_PATH,
+        &ctx,
+    )
+    .map_err(|e| CreateError::Template(e.to_string()))
+}
+
+/// Normalize an explicit module id (`7`, `007`) to its 3-digit form.
+fn normalize_module_id(id: &str) -> Result<String, CreateError> {
+    let parsed = parse_module_id(id).map_err(|e| {
+        CreateError::InvalidModuleId(format!("Invalid module id '{id}': {}", e.error))
+    })?;
+    if parsed.module_name.is_some() {
+        return Err(CreateError::InvalidModuleId(format!(
+            "Invalid module id '{id}': expected digits only (e.g., 007)"
+        )));
+    }
+    Ok(parsed.module_id.to_string())
+}
+
 /// Create a new change directory and update the module's `module.md` checklist.
 ///
 /// When `module` is `Some`, the change is scoped to that module:
@@ -386,32 +472,46 @@ fn create_change_inner(
     };
 
     let next_num = allocate_next_change_number(ito_path, &namespace_key)?;
-    let folder = format!("{folder_prefix}-{next_num:02}_{name}");
+    let folder = format_change_id(&folder_prefix, next_num, name);
 
     let changes_dir = paths::changes_dir(ito_path);
     ito_common::io::create_dir_all_std(&changes_dir)?;
+    remove_stale_change_scaffolds(&changes_dir);
     let change_dir = changes_dir.join(&folder);
     if change_dir.exists() {
         return Err(CreateError::ChangeAlreadyExists(folder));
     }
-    ito_common::io::create_dir_all_std(&change_dir)?;
 
-    write_change_metadata(&change_dir, schema)?;
-
-    if let Some(desc) = description {
-        // Match TS: README header uses the change id, not the raw name.
-        let readme = format!("# {folder}\n\n{desc}\n");
-        ito_common::io::write_std(&change_dir.join("README.md"), readme)?;
-    }
-
-    match checklist_target {
-        ChecklistTarget::Module(module_id) => {
-            add_change_to_module(ito_path, &module_id, &folder)?;
+    // Build every file in a staging directory and rename it into place, so a
+    // failure part-way through never leaves a half-created change behind.
+    staging::write_dir_staged(&change_dir, |dir| {
+        write_change_metadata(dir, schema)?;
+        write_proposal_front_matter(dir)?;
+        if let Some(desc) = description {
+            // Match TS: README header uses the change id, not the raw name.
+            let readme = format!("# {folder}\n\n{desc}\n");
+            ito_common::io::write_std(&dir.join("README.md"), readme)?;
         }
+        Ok::<(), CreateError>(())
+    })
+    .map_err(|err| match err {
+        CreateError::Io(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            CreateError::ChangeAlreadyExists(folder.clone())
+        }
+        other => other,
+    })?;
+
+    let checklist = match checklist_target {
+        ChecklistTarget::Module(module_id) => add_change_to_module(ito_path, &module_id, &folder),
         ChecklistTarget::SubModule(sub_module_id) => {
-            add_change_to_sub_module(ito_path, &sub_module_id, &folder)?;
+            add_change_to_sub_module(ito_path, &sub_module_id, &folder)
         }
+    };
+    if let Err(err) = checklist {
+        let _ = fs::remove_dir_all(&change_dir);
+        return Err(err);
     }
+    record_change_schema_baseline(ito_path, &folder);
 
     Ok(CreateChangeResult {
         change_id: folder,
@@ -466,6 +566,21 @@ fn repair_coordination_wiring_for_change_creation(_ito_path: &Path) -> Result<()
     Ok(())
 }
 
+/// Remove staging directories left by an interrupted `create change`.
+fn remove_stale_change_scaffolds(changes_dir: &Path) {
+    match staging::remove_stale_staged_dirs(changes_dir, staging::STALE_STAGING_AGE) {
+        Ok(removed) => {
+            for dir in removed {
+                tracing::warn!(
+                    "removed incomplete change scaffold from an interrupted create: {}",
+                    dir.display()
+                );
+            }
+        }
+        Err(err) => tracing::warn!("failed to remove stale change scaffolds: {err}"),
+    }
+}
+
 /// Identifies where the checklist entry for a new change should be written.
 enum ChecklistTarget {
     /// Write to the parent module's `module.md`.
@@ -481,6 +596,30 @@ fn write_change_metadata(change_dir: &Path, schema: &str) -> Result<(), CreateEr
     Ok(())
 }
 
+/// Seed `proposal.md` with an empty front matter block for typed metadata.
+///
+/// The proposal artifact still counts as missing until a body is written
+/// below the front matter.
+fn write_proposal_front_matter(change_dir: &Path) -> Result<(), CreateError> {
+    let created = Utc::now().format("%Y-%m-%d").to_string();
+    let content = format!("---\nowner:\npriority:\nlabels: []\ncreated: {created}\n---\n");
+    ito_common::io::write_std(&change_dir.join("proposal.md"), content)?;
+    Ok(())
+}
+
+/// Record the schema baseline used to detect template drift later.
+///
+/// Best-effort: a schema that cannot be resolved yet must not block creation.
+fn record_change_schema_baseline(ito_path: &Path, change_id: &str) {
+    let mut ctx = ConfigContext::from_process_env();
+    if ctx.project_dir.is_none() {
+        ctx.project_dir = ito_path.parent().map(Path::to_path_buf);
+    }
+    if let Err(err) = crate::templates::record_schema_baseline(ito_path, change_id, &ctx) {
+        tracing::warn!("failed to record schema baseline for {change_id}: {err}");
+    }
+}
+
 fn allocate_next_change_number(ito_path: &Path, namespace_key: &str) -> Result<u32, CreateError> {
     // Lock file + JSON state mirrors TS implementation.
     let state_dir = ito_path.join("workflows").join(".state");
@@ -775,113 +914,124 @@ fn add_change_to_sub_module(
     Ok(())
 }
 
-fn next_module_id(modules_dir: &Path) -> Result<String, CreateError> {
-    let mut max_seen: u32 = 0;
+fn next_module_id(modules_dir: &Path) -> String {
     let fs = StdFs;
-    if let Ok(entries) = ito_domain::discovery::list_dir_names(&fs, modules_dir) {
-        for folder in entries {
-            if let Ok(parsed) = parse_module_id(&folder)
-                && let Ok(n) = parsed.module_id.as_str().parse::<u32>()
-            {
-                max_seen = max_seen.max(n);
-            }
+    let entries = ito_domain::discovery::list_dir_names(&fs, modules_dir).unwrap_or_default();
+    let ids: Vec<String> = entries
+        .iter()
+        .filter_map(|folder| parse_module_id(folder).ok())
+        .map(|parsed| parsed.module_id.to_string())
+        .collect();
+    ito_domain::modules::next_module_id(ids.iter().map(String::as_str))
+}
+
+/// Turn a human-entered name into a kebab-case slug candidate.
+///
+/// Lowercases, maps whitespace and underscores to hyphens, collapses repeated
+/// hyphens, and trims hyphens from both ends. Other characters are kept so
+/// that validation can reject them with a precise message.
+fn slugify_name(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    for c in name.trim().chars() {
+        let c = if c.is_whitespace() || c == '_' {
+            '-'
+        } else {
+            c.to_ascii_lowercase()
+        };
+        if c == '-' && out.ends_with('-') {
+            continue;
         }
+        out.push(c);
     }
-    Ok(format!("{n:03}", n = max_seen + 1))
+    out.trim_matches('-').to_string()
 }
 
 fn validate_change_name(name: &str) -> Result<(), CreateError> {
+    validate_kebab_name("Change", name).map_err(CreateError::InvalidChangeName)
+}
+
+/// Module names follow the same kebab-case rules as change names.
+fn validate_module_name(name: &str) -> Result<(), CreateError> {
+    validate_kebab_name("Module", name).map_err(CreateError::InvalidModuleName)
+}
+
+/// Validate a kebab-case name, returning a user-facing message on failure.
+///
+/// `kind` prefixes the message (e.g. "Change name cannot be empty").
+fn validate_kebab_name(kind: &str, name: &str) -> Result<(), String> {
     // Mirrors `src/utils/change-utils.ts` validateChangeName.
     if name.is_empty() {
-        return Err(CreateError::InvalidChangeName(
-            "Change name cannot be empty".to_string(),
-        ));
+        return Err(format!("{kind} name cannot be empty"));
     }
     if name.chars().any(|c| c.is_ascii_uppercase()) {
-        return Err(CreateError::InvalidChangeName(
-            "Change name must be lowercase (use kebab-case)".to_string(),
-        ));
+        return Err(format!("{kind} name must be lowercase (use kebab-case)"));
     }
     if name.chars().any(|c| c.is_whitespace()) {
-        return Err(CreateError::InvalidChangeName(
-            "Change name cannot contain spaces (use hyphens instead)".to_string(),
+        return Err(format!(
+            "{kind} name cannot contain spaces (use hyphens instead)"
         ));
     }
     if name.contains('_') {
-        return Err(CreateError::InvalidChangeName(
-            "Change name cannot contain underscores (use hyphens instead)".to_string(),
+        return Err(format!(
+            "{kind} name cannot contain underscores (use hyphens instead)"
         ));
     }
     if name.starts_with('-') {
-        return Err(CreateError::InvalidChangeName(
-            "Change name cannot start with a hyphen".to_string(),
-        ));
+        return Err(format!("{kind} name cannot start with a hyphen"));
     }
     if name.ends_with('-') {
-        return Err(CreateError::InvalidChangeName(
-            "Change name cannot end with a hyphen".to_string(),
-        ));
+        return Err(format!("{kind} name cannot end with a hyphen"));
     }
     if name.contains("--") {
-        return Err(CreateError::InvalidChangeName(
-            "Change name cannot contain consecutive hyphens".to_string(),
-        ));
+        return Err(format!("{kind} name cannot contain consecutive hyphens"));
     }
     if name
         .chars()
         .any(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'))
     {
-        return Err(CreateError::InvalidChangeName(
-            "Change name can only contain lowercase letters, numbers, and hyphens".to_string(),
+        return Err(format!(
+            "{kind} name can only contain lowercase letters, numbers, and hyphens"
         ));
     }
     if name.chars().next().is_some_and(|c| c.is_ascii_digit()) {
-        return Err(CreateError::InvalidChangeName(
-            "Change name must start with a letter".to_string(),
-        ));
+        return Err(format!("{kind} name must start with a letter"));
     }
 
     // Structural check: ^[a-z][a-z0-9]*(-[a-z0-9]+)*$
     let mut parts = name.split('-');
     let Some(first) = parts.next() else {
-        return Err(CreateError::InvalidChangeName(
-            "Change name must follow kebab-case convention (e.g., add-auth, refactor-db)"
-                .to_string(),
+        return Err(format!(
+            "{kind} name must follow kebab-case convention (e.g., add-auth, refactor-db)"
         ));
     };
     if first.is_empty() {
-        return Err(CreateError::InvalidChangeName(
-            "Change name must follow kebab-case convention (e.g., add-auth, refactor-db)"
-                .to_string(),
+        return Err(format!(
+            "{kind} name must follow kebab-case convention (e.g., add-auth, refactor-db)"
         ));
     }
     let mut chars = first.chars();
     if !chars.next().is_some_and(|c| c.is_ascii_lowercase()) {
-        return Err(CreateError::InvalidChangeName(
-            "Change name must follow kebab-case convention (e.g., add-auth, refactor-db)"
-                .to_string(),
+        return Err(format!(
+            "{kind} name must follow kebab-case convention (e.g., add-auth, refactor-db)"
         ));
     }
     if chars.any(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit())) {
-        return Err(CreateError::InvalidChangeName(
-            "Change name must follow kebab-case convention (e.g., add-auth, refactor-db)"
-                .to_string(),
+        return Err(format!(
+            "{kind} name must follow kebab-case convention (e.g., add-auth, refactor-db)"
         ));
     }
     for part in parts {
         if part.is_empty() {
-            return Err(CreateError::InvalidChangeName(
-                "Change name must follow kebab-case convention (e.g., add-auth, refactor-db)"
-                    .to_string(),
+            return Err(format!(
+                "{kind} name must follow kebab-case convention (e.g., add-auth, refactor-db)"
             ));
         }
         if part
             .chars()
             .any(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit()))
         {
-            return Err(CreateError::InvalidChangeName(
-                "Change name must follow kebab-case convention (e.g., add-auth, refactor-db)"
-                    .to_string(),
+            return Err(format!(
+                "{kind} name must follow kebab-case convention (e.g., add-auth, refactor-db)"
             ));
         }
     }
@@ -5,9 +5,10 @@
 //! disk.
 
 use crate::errors::{CoreError, CoreResult};
-use ito_templates::{
-    commands_files, get_adapter_file, get_command_file, get_skill_file, skills_files,
-};
+use crate::installers::{content_hash, load_install_manifest};
+use ito_templates::asset_source::AssetSource;
+use ito_templates::{commands_files, skills_files};
+use serde::Serialize;
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone)]
@@ -204,15 +205,9 @@ pub fn github_manifests(project_root: &Path) -> Vec<FileManifest> {
     let prompts_dir = project_root.join(".github").join("prompts");
     for file in commands_files() {
         let rel_path = file.relative_path;
-        // Convert ito-apply.md -> ito-apply.prompt.md for GitHub
-        let dest_name = if let Some(stripped) = rel_path.strip_suffix(".md") {
-            format!("{stripped}.prompt.md")
-        } else {
-            rel_path.to_string()
-        };
         out.push(FileManifest {
             source: rel_path.to_string(),
-            dest: prompts_dir.join(dest_name),
+            dest: prompts_dir.join(github_prompt_name(rel_path)),
             asset_type: AssetType::Command,
         });
     }
@@ -220,43 +215,56 @@ pub fn github_manifests(project_root: &Path) -> Vec<FileManifest> {
     out
 }
 
+/// Convert a command file name to GitHub's prompt naming
+/// (`ito-apply.md` -> `ito-apply.prompt.md`).
+pub(crate) fn github_prompt_name(rel_path: &str) -> String {
+    match rel_path.strip_suffix(".md") {
+        Some(stripped) => format!("{stripped}.prompt.md"),
+        None => rel_path.to_string(),
+    }
+}
+
 /// Install manifests from embedded assets to disk.
 ///
+/// File contents come from `opts.asset_source`, so a pinned template bundle
+/// overrides the embedded copy of any file it contains.
+///
 /// Skill assets that explicitly use worktree Jinja variables are rendered with
-/// `worktree_ctx` before writing. Other skill files (which may contain `{{` as
+/// `template_ctx` before writing. Other skill files (which may contain `{{` as
 /// user-facing prompt placeholders) are written as-is.
 ///
 /// Every `.md` file that contains an Ito managed block receives a version stamp
 /// immediately after `<!-- ITO:START -->` before being written to disk.
 pub fn install_manifests(
     manifests: &[FileManifest],
-    worktree_ctx: Option<&ito_templates::project_templates::WorktreeTemplateContext>,
+    template_ctx: Option<&ito_templates::project_templates::ProjectTemplateContext>,
     mode: crate::installers::InstallMode,
     opts: &crate::installers::InitOptions,
 ) -> CoreResult<()> {
-    use ito_templates::project_templates::{WorktreeTemplateContext, render_project_template};
+    use ito_templates::project_templates::{ProjectTemplateContext, render_project_template};
 
-    let default_ctx = WorktreeTemplateContext::default();
-    let ctx = worktree_ctx.unwrap_or(&default_ctx);
+    let default_ctx = ProjectTemplateContext::default();
+    let ctx = template_ctx.unwrap_or(&default_ctx);
 
     // Source the version once for all manifests in this batch.
     let version = option_env!("ITO_WORKSPACE_VERSION").unwrap_or(env!("CARGO_PKG_VERSION"));
 
+    let source = &opts.asset_source;
     for manifest in manifests {
         let raw_bytes = match manifest.asset_type {
-            AssetType::Skill => get_skill_file(&manifest.source).ok_or_else(|| {
+            AssetType::Skill => source.skill_file(&manifest.source).ok_or_else(|| {
                 CoreError::NotFound(format!(
                     "Skill file not found in embedded assets: {}",
                     manifest.source
                 ))
             })?,
-            AssetType::Adapter => get_adapter_file(&manifest.source).ok_or_else(|| {
+            AssetType::Adapter => source.adapter_file(&manifest.source).ok_or_else(|| {
                 CoreError::NotFound(format!(
                     "Adapter file not found in embedded assets: {}",
                     manifest.source
                 ))
             })?,
-            AssetType::Command => get_command_file(&manifest.source).ok_or_else(|| {
+            AssetType::Command => source.command_file(&manifest.source).ok_or_else(|| {
                 CoreError::NotFound(format!(
                     "Command file not found in embedded assets: {}",
                     manifest.source
@@ -281,14 +289,14 @@ pub fn install_manifests(
         }
 
         let bytes = if should_render_skill {
-            render_project_template(raw_bytes, ctx).map_err(|e| {
+            render_project_template(&raw_bytes, ctx).map_err(|e| {
                 CoreError::Validation(format!(
                     "Failed to render skill template {}: {}",
                     manifest.source, e
                 ))
             })?
         } else {
-            raw_bytes.to_vec()
+            raw_bytes.into_owned()
         };
 
         // Stamp every managed-block markdown file with the current CLI version.
@@ -320,11 +328,163 @@ pub fn install_manifests(
             }
             ito_common::io::write_std(&manifest.dest, &bytes)
                 .map_err(|e| CoreError::io(format!("writing {}", manifest.dest.display()), e))?;
+            if manifest.asset_type == AssetType::Adapter && needs_executable_bit(&manifest.source) {
+                set_executable(&manifest.dest)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Bytes `install_manifests` writes for an adapter entry.
+///
+/// Returns `None` for non-adapter entries (skills and commands may carry user
+/// content outside their managed blocks) and for unknown sources.
+pub fn expected_adapter_bytes(manifest: &FileManifest) -> Option<Vec<u8>> {
+    expected_adapter_bytes_from(&AssetSource::Embedded, manifest)
+}
+
+/// Bytes `install_manifests` writes for an adapter entry when reading assets
+/// from `source`.
+pub fn expected_adapter_bytes_from(
+    source: &AssetSource,
+    manifest: &FileManifest,
+) -> Option<Vec<u8>> {
+    if manifest.asset_type != AssetType::Adapter {
+        return None;
+    }
+    let bytes = source.adapter_file(&manifest.source)?.into_owned();
+    let version = option_env!("ITO_WORKSPACE_VERSION").unwrap_or(env!("CARGO_PKG_VERSION"));
+    Some(stamp_managed_markdown(bytes, &manifest.source, version))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+/// How an installed adapter file deviates from what Ito recorded.
+pub enum ManifestIssueKind {
+    /// The file is gone.
+    Missing,
+    /// The file was edited after installation.
+    Modified,
+    /// The file is empty or a truncated copy of the expected content.
+    Corrupted,
+    /// A hook script lost its executable bit (unix only).
+    NotExecutable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// One adapter file that does not match its install manifest entry.
+pub struct ManifestIssue {
+    /// Project-relative path (forward slashes).
+    pub path: String,
+    /// What is wrong with it.
+    pub kind: ManifestIssueKind,
+}
+
+/// Check every adapter recorded in the install manifest against the disk.
+///
+/// Only files recorded by a previous `ito init` / `ito update` are checked,
+/// so projects without an install manifest report nothing. Missing, modified
+/// and corrupted files are reported distinctly; a script with the right
+/// content but no executable bit is reported as
+/// [`ManifestIssueKind::NotExecutable`].
+pub fn verify_manifests(project_root: &Path, ito_path: &Path) -> Vec<ManifestIssue> {
+    let Some(manifest) = load_install_manifest(ito_path) else {
+        return Vec::new();
+    };
+    let expected = adapter_manifests(project_root);
+    let mut issues = Vec::new();
+    for (rel, recorded) in &manifest.adapters {
+        let dest = project_root.join(rel);
+        let expected_bytes = expected
+            .iter()
+            .find(|m| m.dest == dest)
+            .and_then(expected_adapter_bytes);
+        let existing = std::fs::read(&dest).ok();
+        let kind = classify_adapter(existing.as_deref(), recorded, expected_bytes.as_deref())
+            .or_else(|| {
+                (needs_executable_bit(rel) && !is_executable(&dest))
+                    .then_some(ManifestIssueKind::NotExecutable)
+            });
+        if let Some(kind) = kind {
+            issues.push(ManifestIssue {
+                path: rel.clone(),
+                kind,
+            });
         }
     }
+    issues
+}
+
+/// Classify an installed adapter against its recorded hash.
+///
+/// `expected` is what this Ito version would install; it lets a truncated copy
+/// of the current template be told apart from a deliberate edit. Returns
+/// `None` when the file matches the recorded or expected bytes.
+pub(crate) fn classify_adapter(
+    existing: Option<&[u8]>,
+    recorded: &str,
+    expected: Option<&[u8]>,
+) -> Option<ManifestIssueKind> {
+    let Some(existing) = existing else {
+        return Some(ManifestIssueKind::Missing);
+    };
+    if content_hash(existing) == recorded || expected.is_some_and(|e| e == existing) {
+        return None;
+    }
+    let truncated = expected.is_some_and(|e| existing.len() < e.len() && e.starts_with(existing));
+    if existing.is_empty() || truncated {
+        Some(ManifestIssueKind::Corrupted)
+    } else {
+        Some(ManifestIssueKind::Modified)
+    }
+}
+
+/// Adapter entries for every supported harness.
+pub(crate) fn adapter_manifests(project_root: &Path) -> Vec<FileManifest> {
+    let mut all = claude_manifests(project_root);
+    all.extend(codex_manifests(project_root));
+    all.extend(github_manifests(project_root));
+    all.extend(opencode_manifests(&project_root.join(".opencode")));
+    all.extend(pi_manifests(project_root));
+    all.retain(|m| m.asset_type == AssetType::Adapter);
+    all
+}
+
+/// Hook scripts are installed executable.
+pub(crate) fn needs_executable_bit(path: &str) -> bool {
+    path.ends_with(".sh")
+}
+
+#[cfg(unix)]
+fn set_executable(path: &Path) -> CoreResult<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let meta = std::fs::metadata(path)
+        .map_err(|e| CoreError::io(format!("reading {}", path.display()), e))?;
+    let mut perms = meta.permissions();
+    perms.set_mode(perms.mode() | 0o111);
+    std::fs::set_permissions(path, perms)
+        .map_err(|e| CoreError::io(format!("setting permissions on {}", path.display()), e))
+}
+
+#[cfg(not(unix))]
+fn set_executable(_path: &Path) -> CoreResult<()> {
     Ok(())
 }
 
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    std::fs::metadata(path).is_ok_and(|m| m.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_path: &Path) -> bool {
+    true
+}
+
 /// True when `path` is a plain `.md` asset (excludes Jinja `.md.j2` templates
 /// which are rendered, not installed verbatim). Centralising this guard keeps
 /// the stamping and marker-scoping checks in one place.
@@ -0,0 +1,543 @@
+//! Installation health checks behind `ito doctor`.
+//!
+//! Each check inspects one part of the setup (git, the Ito directory, config,
+//! adapter files, harness CLIs, the audit log, state permissions) and reports a
+//! [`DoctorCheck`] with a status and, when something is off, a fix hint. All
+//! I/O goes through an injected [`FileSystem`] and [`ProcessRunner`] so checks
+//! can be exercised individually in tests.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::time::Duration;
+
+use ito_common::fs::FileSystem;
+use ito_config::types::ItoConfig;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::distribution::{self, AssetType, FileManifest, ManifestIssueKind};
+use crate::installers::{
+    self, TOOL_CLAUDE, TOOL_CODEX, TOOL_GITHUB_COPILOT, TOOL_OPENCODE, TOOL_PI,
+};
+use crate::process::{ProcessRequest, ProcessRunner};
+
+/// Timeout for `--version` probes so a hung binary cannot stall the report.
+const VERSION_PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Outcome of a single check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DoctorStatus {
+    /// The check passed.
+    Pass,
+    /// Something is off but Ito can still run.
+    Warn,
+    /// Ito will not work correctly until this is fixed.
+    Fail,
+}
+
+impl DoctorStatus {
+    /// Lowercase label used in table and JSON output.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            DoctorStatus::Pass => "pass",
+            DoctorStatus::Warn => "warn",
+            DoctorStatus::Fail => "fail",
+        }
+    }
+}
+
+/// Result of one diagnostic check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DoctorCheck {
+    /// Short check name (e.g. `git`, `adapter: claude`).
+    pub name: String,
+    /// Check outcome.
+    pub status: DoctorStatus,
+    /// What was found.
+    pub detail: String,
+    /// How to fix a warning or failure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix_hint: Option<String>,
+}
+
+impl DoctorCheck {
+    fn pass(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: DoctorStatus::Pass,
+            detail: detail.into(),
+            fix_hint: None,
+        }
+    }
+
+    fn warn(name: impl Into<String>, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: DoctorStatus::Warn,
+            detail: detail.into(),
+            fix_hint: Some(fix.into()),
+        }
+    }
+
+    fn fail(name: impl Into<String>, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: DoctorStatus::Fail,
+            detail: detail.into(),
+            fix_hint: Some(fix.into()),
+        }
+    }
+}
+
+/// All checks from one `ito doctor` run, in execution order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DoctorReport {
+    /// Individual check results.
+    pub checks: Vec<DoctorCheck>,
+}
+
+impl DoctorReport {
+    /// Whether any check failed.
+    pub fn has_failures(&self) -> bool {
+        self.checks
+            .iter()
+            .any(|check| check.status == DoctorStatus::Fail)
+    }
+}
+
+/// Inputs shared by every check.
+pub struct DoctorContext<'a> {
+    /// Project root (the directory containing the Ito directory).
+    pub project_root: &'a Path,
+    /// Resolved Ito directory (usually `<project_root>/.ito`).
+    pub ito_path: &'a Path,
+    /// File-system access.
+    pub fs: &'a dyn FileSystem,
+    /// Process execution for `git` and harness probes.
+    pub runner: &'a dyn ProcessRunner,
+}
+
+/// Run every check and collect the results.
+///
+/// Checks that need the Ito directory are skipped when it is missing; the
+/// `ito dir` failure already explains what to do.
+pub fn run_doctor(ctx: &DoctorContext<'_>) -> DoctorReport {
+    let mut checks = check_git(ctx);
+    let ito_dir = check_ito_dir(ctx);
+    let has_ito_dir = ito_dir.status != DoctorStatus::Fail;
+    checks.push(ito_dir);
+
+    let config = read_config_value(ctx);
+    if has_ito_dir {
+        checks.push(check_config(ctx));
+    }
+    let tools = configured_tools(ctx, config.as_ref());
+    if has_ito_dir {
+        checks.extend(check_adapters(ctx, &tools));
+        checks.extend(check_adapter_integrity(ctx));
+    }
+    checks.extend(check_harnesses(ctx, &tools));
+    if has_ito_dir {
+        checks.push(check_audit_log(ctx));
+        checks.push(check_state_writable(ctx));
+    }
+    DoctorReport { checks }
+}
+
+/// Check that `git` runs and the project is inside a git work tree.
+pub fn check_git(ctx: &DoctorContext<'_>) -> Vec<DoctorCheck> {
+    let version = match ctx.runner.run(&ProcessRequest::new("git").arg("--version")) {
+        Ok(out) if out.success => out.stdout.trim().to_string(),
+        Ok(out) => {
+            return vec![DoctorCheck::fail(
+                "git",
+                format!("`git --version` exited with code {}", out.exit_code),
+                "reinstall git and make sure it is on PATH",
+            )];
+        }
+        Err(e) => {
+            return vec![DoctorCheck::fail(
+                "git",
+                format!("git is not available: {e}"),
+                "install git and make sure it is on PATH",
+            )];
+        }
+    };
+
+    let repo = ctx.runner.run(
+        &ProcessRequest::new("git")
+            .args(["rev-parse", "--show-toplevel"])
+            .current_dir(ctx.project_root),
+    );
+    let repo = match repo {
+        Ok(out) if out.success => {
+            DoctorCheck::pass("git repository", out.stdout.trim().to_string())
+        }
+        _ => DoctorCheck::warn(
+            "git repository",
+            format!(
+                "{} is not inside a git repository",
+                ctx.project_root.display()
+            ),
+            "run `git init` (Ito tracks changes, worktrees and archives through git)",
+        ),
+    };
+    vec![DoctorCheck::pass("git", version), repo]
+}
+
+/// Check that the Ito directory exists.
+pub fn check_ito_dir(ctx: &DoctorContext<'_>) -> DoctorCheck {
+    if ctx.fs.is_dir(ctx.ito_path) {
+        DoctorCheck::pass("ito dir", ctx.ito_path.display().to_string())
+    } else {
+        DoctorCheck::fail(
+            "ito dir",
+            format!("{} does not exist", ctx.ito_path.display()),
+            "run `ito init` in the project root",
+        )
+    }
+}
+
+/// Check that `config.json` parses, matches the config schema, and points at
+/// a config schema that exists.
+pub fn check_config(ctx: &DoctorContext<'_>) -> DoctorCheck {
+    const NAME: &str = "config";
+    let path = ctx.ito_path.join("config.json");
+    if !ctx.fs.exists(&path) {
+        return DoctorCheck::warn(
+            NAME,
+            format!("{} not found; defaults apply", path.display()),
+            "run `ito init` to create it",
+        );
+    }
+    let contents = match ctx.fs.read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            return DoctorCheck::fail(
+                NAME,
+                format!("cannot read {}: {e}", path.display()),
+                "check the file's permissions",
+            );
+        }
+    };
+    let value: Value = match serde_json::from_str(&contents) {
+        Ok(value) => value,
+        Err(e) => {
+            return DoctorCheck::fail(
+                NAME,
+                format!("{} is not valid JSON: {e}", path.display()),
+                "fix the JSON syntax, or restore the file from git",
+            );
+        }
+    };
+    if let Err(e) = serde_json::from_value::<ItoConfig>(value.clone()) {
+        return DoctorCheck::fail(
+            NAME,
+            format!("{} does not match the config schema: {e}", path.display()),
+            "correct the reported key (see .ito/config.schema.json)",
+        );
+    }
+
+    let expected = installers::config_schema_ref(None);
+    match value.get("$schema").and_then(Value::as_str) {
+        Some(schema) if is_release_schema_url(schema) => DoctorCheck::warn(
+            NAME,
+            format!("`$schema` references {schema}"),
+            format!("set `$schema` to {expected} and run `ito update` to write it"),
+        ),
+        Some(schema)
+            if schema.trim_start_matches("./") == installers::CONFIG_SCHEMA_FILE
+                && !ctx
+                    .fs
+                    .exists(&ctx.ito_path.join(installers::CONFIG_SCHEMA_FILE)) =>
+        {
+            DoctorCheck::warn(
+                NAME,
+                format!("`$schema` references {schema}, which does not exist"),
+                "run `ito update` to write it",
+            )
+        }
+        _ => DoctorCheck::pass(NAME, format!("{} is valid", path.display())),
+    }
+}
+
+/// GitHub release URLs written by older Ito versions; they do not resolve for
+/// local builds.
+fn is_release_schema_url(schema: &str) -> bool {
+    schema.starts_with("https://raw.githubusercontent.com/withakay/ito/")
+        && schema.ends_with("/schemas/ito-config.schema.json")
+}
+
+/// Check each tool's adapter files exist and match the embedded versions.
+///
+/// Missing files fail (this includes symlinks whose target is gone); files
+/// that differ from what this Ito version installs warn. Line endings are
+/// ignored when comparing.
+pub fn check_adapters(ctx: &DoctorContext<'_>, tools: &BTreeSet<String>) -> Vec<DoctorCheck> {
+    let mut checks = Vec::new();
+    for tool in tools {
+        let name = format!("adapter: {tool}");
+        let adapters: Vec<FileManifest> = tool_manifests(ctx.project_root, tool)
+            .into_iter()
+            .filter(|manifest| manifest.asset_type == AssetType::Adapter)
+            .collect();
+        if adapters.is_empty() {
+            continue;
+        }
+
+        let mut missing = Vec::new();
+        let mut outdated = Vec::new();
+        for manifest in &adapters {
+            let rel = display_rel(ctx.project_root, &manifest.dest);
+            let Ok(on_disk) = ctx.fs.read_to_string(&manifest.dest) else {
+                missing.push(rel);
+                continue;
+            };
+            let Some(expected) = distribution::expected_adapter_bytes(manifest) else {
+                continue;
+            };
+            if content_hash(on_disk.as_bytes()) != content_hash(&expected) {
+                outdated.push(rel);
+            }
+        }
+
+        let check = if !missing.is_empty() {
+            DoctorCheck::fail(
+                name,
+                format!("missing or unreadable: {}", missing.join(", ")),
+                format!("run `ito update --tools {tool}`"),
+            )
+        } else if !outdated.is_empty() {
+            DoctorCheck::warn(
+                name,
+                format!("differs from this Ito version: {}", outdated.join(", ")),
+                format!("run `ito update --tools {tool}` (local edits will be replaced)"),
+            )
+        } else {
+            DoctorCheck::pass(name, format!("{} file(s) up to date", adapters.len()))
+        };
+        checks.push(check);
+    }
+    checks
+}
+
+/// Check installed adapters against the hashes recorded in the install manifest.
+///
+/// Missing and corrupted (empty or truncated) files fail; local edits and
+/// hook scripts without their executable bit warn. Returns `None` when no
+/// adapters have been recorded yet.
+pub fn check_adapter_integrity(ctx: &DoctorContext<'_>) -> Option<DoctorCheck> {
+    const NAME: &str = "adapter integrity";
+    let manifest = installers::load_install_manifest(ctx.ito_path)?;
+    if manifest.adapters.is_empty() {
+        return None;
+    }
+    let issues = distribution::verify_manifests(ctx.project_root, ctx.ito_path);
+    if issues.is_empty() {
+        return Some(DoctorCheck::pass(
+            NAME,
+            format!(
+                "{} recorded adapter file(s) intact",
+                manifest.adapters.len()
+            ),
+        ));
+    }
+
+    let describe = |kind: ManifestIssueKind| -> Vec<&str> {
+        issues
+            .iter()
+            .filter(|issue| issue.kind == kind)
+            .map(|issue| issue.path.as_str())
+            .collect()
+    };
+    let mut parts = Vec::new();
+    for (kind, label) in [
+        (ManifestIssueKind::Missing, "missing"),
+        (ManifestIssueKind::Corrupted, "corrupted"),
+        (ManifestIssueKind::Modified, "modified"),
+        (ManifestIssueKind::NotExecutable, "not executable"),
+    ] {
+        let paths = describe(kind);
+        if !paths.is_empty() {
+            parts.push(format!("{label}: {}", paths.join(", ")));
+        }
+    }
+    let detail = parts.join("; ");
+    let broken = issues.iter().any(|issue| {
+        matches!(
+            issue.kind,
+            ManifestIssueKind::Missing | ManifestIssueKind::Corrupted
+        )
+    });
+    Some(if broken {
+        DoctorCheck::fail(NAME, detail, "run `ito update` to repair the files")
+    } else {
+        DoctorCheck::warn(
+            NAME,
+            detail,
+            "run `ito update` to restore hook permissions, or `ito update --force-managed` to discard local edits",
+        )
+    })
+}
+
+fn tool_manifests(project_root: &Path, tool: &str) -> Vec<FileManifest> {
+    match tool {
+        TOOL_CLAUDE => distribution::claude_manifests(project_root),
+        TOOL_CODEX => distribution::codex_manifests(project_root),
+        TOOL_GITHUB_COPILOT => distribution::github_manifests(project_root),
+        TOOL_OPENCODE => distribution::opencode_manifests(&project_root.join(".opencode")),
+        TOOL_PI => distribution::pi_manifests(project_root),
+        _ => Vec::new(),
+    }
+}
+
+/// SHA-256 of `bytes` with CRLF normalized to LF.
+fn content_hash(bytes: &[u8]) -> String {
+    let text = String::from_utf8_lossy(bytes).replace("\r\n", "\n");
+    installers::content_hash(text.as_bytes())
+}
+
+fn display_rel(project_root: &Path, path: &Path) -> String {
+    path.strip_prefix(project_root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Check each configured tool's harness CLI resolves on PATH and reports a version.
+///
+/// A missing harness only warns: adapters can be used from an editor without
+/// the CLI, but `ito ralph --harness <name>` will not run.
+pub fn check_harnesses(ctx: &DoctorContext<'_>, tools: &BTreeSet<String>) -> Vec<DoctorCheck> {
+    let mut checks = Vec::new();
+    for tool in tools {
+        let Some(binary) = installers::harness_binary(tool) else {
+            continue;
+        };
+        let name = format!("harness: {binary}");
+        let request = ProcessRequest::new(&binary).arg("--version");
+        let check = match ctx.runner.run_with_timeout(&request, VERSION_PROBE_TIMEOUT) {
+            Ok(out) if out.success => {
+                let version = out
+                    .stdout
+                    .lines()
+                    .chain(out.stderr.lines())
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+                    .unwrap_or("version unknown");
+                DoctorCheck::pass(name, version.to_string())
+            }
+            Ok(out) if out.timed_out => DoctorCheck::warn(
+                name,
+                format!("`{binary} --version` timed out"),
+                format!("check that `{binary}` runs non-interactively"),
+            ),
+            Ok(out) => DoctorCheck::warn(
+                name,
+                format!("`{binary} --version` exited with code {}", out.exit_code),
+                format!("reinstall `{binary}`"),
+            ),
+            Err(_) => DoctorCheck::warn(
+                name,
+                format!("`{binary}` not found on PATH"),
+                format!("install the {tool} CLI, or remove `{tool}` from `init.tools`"),
+            ),
+        };
+        checks.push(check);
+    }
+    checks
+}
+
+/// Check that the local audit log, when present, parses line by line.
+pub fn check_audit_log(ctx: &DoctorContext<'_>) -> DoctorCheck {
+    const NAME: &str = "audit log";
+    let path = crate::audit::writer::audit_log_path(ctx.ito_path);
+    if !ctx.fs.exists(&path) {
+        return DoctorCheck::pass(NAME, "no local audit log yet");
+    }
+    let contents = match ctx.fs.read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            return DoctorCheck::fail(
+                NAME,
+                format!("cannot read {}: {e}", path.display()),
+                "check the file's permissions",
+            );
+        }
+    };
+
+    let mut events = 0usize;
+    let mut bad_lines = Vec::new();
+    for (idx, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<crate::audit::AuditEvent>(line) {
+            Ok(_) => events += 1,
+            Err(_) => bad_lines.push((idx + 1).to_string()),
+        }
+    }
+    if bad_lines.is_empty() {
+        return DoctorCheck::pass(NAME, format!("{events} event(s)"));
+    }
+    DoctorCheck::warn(
+        NAME,
+        format!(
+            "{} unparseable line(s) in {} (line {}); they are skipped when reading",
+            bad_lines.len(),
+            path.display(),
+            bad_lines.join(", ")
+        ),
+        "run `ito audit validate` for details",
+    )
+}
+
+/// Check that `<itoDir>/.state/` can be created and written.
+pub fn check_state_writable(ctx: &DoctorContext<'_>) -> DoctorCheck {
+    const NAME: &str = "state dir";
+    let state_dir = ctx.ito_path.join(".state");
+    let probe = state_dir.join(".doctor-probe");
+    let result = ctx
+        .fs
+        .create_dir_all(&state_dir)
+        .and_then(|()| ctx.fs.write(&probe, b"ok"))
+        .and_then(|()| ctx.fs.remove_file(&probe));
+    match result {
+        Ok(()) => DoctorCheck::pass(NAME, format!("{} is writable", state_dir.display())),
+        Err(e) => DoctorCheck::fail(
+            NAME,
+            format!("{} is not writable: {e}", state_dir.display()),
+            "fix the directory's ownership or permissions",
+        ),
+    }
+}
+
+fn read_config_value(ctx: &DoctorContext<'_>) -> Option<Value> {
+    let contents = ctx
+        .fs
+        .read_to_string(&ctx.ito_path.join("config.json"))
+        .ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Tools recorded under `init.tools`, falling back to adapters present on disk.
+fn configured_tools(ctx: &DoctorContext<'_>, config: Option<&Value>) -> BTreeSet<String> {
+    let recorded = config
+        .and_then(|config| config.pointer("/init/tools"))
+        .and_then(Value::as_array);
+    if let Some(recorded) = recorded {
+        let known = installers::available_tool_ids();
+        return recorded
+            .iter()
+            .filter_map(Value::as_str)
+            .filter(|id| known.contains(id))
+            .map(str::to_string)
+            .collect();
+    }
+    installers::detect_configured_tools_fs(ctx.fs, ctx.project_root)
+}
+
+#[cfg(test)]
+#[path = "doctor_tests.rs"]
+mod doctor_tests;
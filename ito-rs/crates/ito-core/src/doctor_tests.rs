@@ -0,0 +1,311 @@
+use super::*;
+use crate::process::{ProcessExecutionError, ProcessOutput};
+use ito_common::fs::StdFs;
+use std::io;
+use std::path::PathBuf;
+
+/// Answers `--version` for installed programs and fails to spawn anything else.
+struct StubRunner {
+    installed: Vec<&'static str>,
+}
+
+impl StubRunner {
+    fn with_installed(installed: &[&'static str]) -> Self {
+        Self {
+            installed: installed.to_vec(),
+        }
+    }
+}
+
+impl ProcessRunner for StubRunner {
+    fn run(&self, request: &ProcessRequest) -> Result<ProcessOutput, ProcessExecutionError> {
+        if !self.installed.contains(&request.program.as_str()) {
+            return Err(ProcessExecutionError::Spawn {
+                program: request.program.clone(),
+                source: io::Error::new(io::ErrorKind::NotFound, "No such file or directory"),
+            });
+        }
+        let stdout = if request.args.first().map(String::as_str) == Some("rev-parse") {
+            "/repo\n".to_string()
+        } else {
+            format!("{} 1.2.3\n", request.program)
+        };
+        Ok(ok_output(&stdout))
+    }
+
+    fn run_with_timeout(
+        &self,
+        request: &ProcessRequest,
+        _timeout: Duration,
+    ) -> Result<ProcessOutput, ProcessExecutionError> {
+        self.run(request)
+    }
+}
+
+/// `StdFs` that refuses every write.
+struct ReadOnlyFs;
+
+impl FileSystem for ReadOnlyFs {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        StdFs.read_to_string(path)
+    }
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        StdFs.read(path)
+    }
+    fn write(&self, _path: &Path, _contents: &[u8]) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::PermissionDenied, "read-only"))
+    }
+    fn exists(&self, path: &Path) -> bool {
+        StdFs.exists(path)
+    }
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        StdFs.create_dir_all(path)
+    }
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        StdFs.read_dir(path)
+    }
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        StdFs.remove_file(path)
+    }
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        StdFs.remove_dir(path)
+    }
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+        StdFs.remove_dir_all(path)
+    }
+    fn is_dir(&self, path: &Path) -> bool {
+        StdFs.is_dir(path)
+    }
+    fn is_file(&self, path: &Path) -> bool {
+        StdFs.is_file(path)
+    }
+}
+
+fn ok_output(stdout: &str) -> ProcessOutput {
+    ProcessOutput {
+        exit_code: 0,
+        success: true,
+        stdout: stdout.to_string(),
+        stderr: String::new(),
+        timed_out: false,
+        resources: Default::default(),
+    }
+}
+
+fn project_with_config(config: &str) -> tempfile::TempDir {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(td.path().join(".ito")).unwrap();
+    std::fs::write(td.path().join(".ito/config.json"), config).unwrap();
+    td
+}
+
+fn ctx<'a>(
+    root: &'a Path,
+    ito_path: &'a Path,
+    fs: &'a dyn FileSystem,
+    runner: &'a dyn ProcessRunner,
+) -> DoctorContext<'a> {
+    DoctorContext {
+        project_root: root,
+        ito_path,
+        fs,
+        runner,
+    }
+}
+
+fn tools(ids: &[&str]) -> BTreeSet<String> {
+    ids.iter().map(|id| id.to_string()).collect()
+}
+
+#[test]
+fn missing_harness_binary_warns_with_install_hint() {
+    let td = tempfile::tempdir().unwrap();
+    let ito_path = td.path().join(".ito");
+    let runner = StubRunner::with_installed(&["git", "codex"]);
+    let ctx = ctx(td.path(), &ito_path, &StdFs, &runner);
+
+    let checks = check_harnesses(&ctx, &tools(&["claude", "codex", "pi"]));
+
+    assert_eq!(checks.len(), 2, "pi has no harness binary: {checks:?}");
+    let claude = &checks[0];
+    assert_eq!(claude.name, "harness: claude");
+    assert_eq!(claude.status, DoctorStatus::Warn);
+    assert!(claude.detail.contains("not found on PATH"));
+    assert!(claude.fix_hint.as_deref().unwrap().contains("claude"));
+    let codex = &checks[1];
+    assert_eq!(codex.status, DoctorStatus::Pass);
+    assert_eq!(codex.detail, "codex 1.2.3");
+}
+
+#[test]
+fn corrupted_config_json_fails() {
+    let td = project_with_config("{ \"worktrees\": { \"enabled\": tru");
+    let ito_path = td.path().join(".ito");
+    let runner = StubRunner::with_installed(&["git"]);
+    let ctx = ctx(td.path(), &ito_path, &StdFs, &runner);
+
+    let check = check_config(&ctx);
+
+    assert_eq!(check.status, DoctorStatus::Fail);
+    assert!(check.detail.contains("not valid JSON"), "{}", check.detail);
+    assert!(check.fix_hint.is_some());
+}
+
+#[test]
+fn config_with_wrong_types_fails_schema_check() {
+    let td = project_with_config(r#"{ "worktrees": { "enabled": "yes" } }"#);
+    let ito_path = td.path().join(".ito");
+    let runner = StubRunner::with_installed(&[]);
+    let check = check_config(&ctx(td.path(), &ito_path, &StdFs, &runner));
+
+    assert_eq!(check.status, DoctorStatus::Fail);
+    assert!(check.detail.contains("does not match the config schema"));
+}
+
+#[test]
+fn config_with_release_schema_url_warns() {
+    let td = project_with_config(
+        r#"{ "$schema": "https://raw.githubusercontent.com/withakay/ito/v0.0.1/schemas/ito-config.schema.json" }"#,
+    );
+    let ito_path = td.path().join(".ito");
+    let runner = StubRunner::with_installed(&[]);
+    let check = check_config(&ctx(td.path(), &ito_path, &StdFs, &runner));
+
+    assert_eq!(check.status, DoctorStatus::Warn);
+    assert!(
+        check
+            .fix_hint
+            .as_deref()
+            .unwrap()
+            .contains(&installers::config_schema_ref(None))
+    );
+}
+
+#[test]
+fn config_referencing_missing_schema_file_warns() {
+    let config = format!(
+        r#"{{ "$schema": "{}" }}"#,
+        installers::config_schema_ref(None)
+    );
+    let td = project_with_config(&config);
+    let ito_path = td.path().join(".ito");
+    let runner = StubRunner::with_installed(&[]);
+    let check = check_config(&ctx(td.path(), &ito_path, &StdFs, &runner));
+
+    assert_eq!(check.status, DoctorStatus::Warn);
+    assert!(check.detail.contains("does not exist"), "{}", check.detail);
+}
+
+#[test]
+fn current_schema_reference_passes() {
+    let config = format!(
+        r#"{{ "$schema": "{}" }}"#,
+        installers::config_schema_ref(None)
+    );
+    let td = project_with_config(&config);
+    let ito_path = td.path().join(".ito");
+    std::fs::write(ito_path.join(installers::CONFIG_SCHEMA_FILE), "{}").unwrap();
+    let runner = StubRunner::with_installed(&[]);
+    let check = check_config(&ctx(td.path(), &ito_path, &StdFs, &runner));
+
+    assert_eq!(check.status, DoctorStatus::Pass, "{check:?}");
+}
+
+#[test]
+fn missing_git_fails() {
+    let td = tempfile::tempdir().unwrap();
+    let ito_path = td.path().join(".ito");
+    let runner = StubRunner::with_installed(&[]);
+    let checks = check_git(&ctx(td.path(), &ito_path, &StdFs, &runner));
+
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0].status, DoctorStatus::Fail);
+}
+
+#[test]
+fn missing_adapter_files_fail_and_edited_files_warn() {
+    let td = tempfile::tempdir().unwrap();
+    let ito_path = td.path().join(".ito");
+    let runner = StubRunner::with_installed(&[]);
+    let ctx = ctx(td.path(), &ito_path, &StdFs, &runner);
+
+    let checks = check_adapters(&ctx, &tools(&["claude"]));
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0].status, DoctorStatus::Fail);
+    assert_eq!(
+        checks[0].fix_hint.as_deref(),
+        Some("run `ito update --tools claude`")
+    );
+
+    let adapters: Vec<FileManifest> = distribution::claude_manifests(td.path())
+        .into_iter()
+        .filter(|m| m.asset_type == AssetType::Adapter)
+        .collect();
+    for manifest in &adapters {
+        let bytes = distribution::expected_adapter_bytes(manifest).unwrap();
+        std::fs::create_dir_all(manifest.dest.parent().unwrap()).unwrap();
+        std::fs::write(&manifest.dest, bytes).unwrap();
+    }
+    let checks = check_adapters(&ctx, &tools(&["claude"]));
+    assert_eq!(checks[0].status, DoctorStatus::Pass, "{checks:?}");
+
+    std::fs::write(&adapters[0].dest, "edited locally\n").unwrap();
+    let checks = check_adapters(&ctx, &tools(&["claude"]));
+    assert_eq!(checks[0].status, DoctorStatus::Warn);
+}
+
+#[test]
+fn audit_log_with_garbage_lines_warns() {
+    let td = tempfile::tempdir().unwrap();
+    let ito_path = td.path().join(".ito");
+    let log = crate::audit::writer::audit_log_path(&ito_path);
+    std::fs::create_dir_all(log.parent().unwrap()).unwrap();
+    std::fs::write(&log, "not json\n\n{\"also\": \"not an event\"}\n").unwrap();
+    let runner = StubRunner::with_installed(&[]);
+
+    let check = check_audit_log(&ctx(td.path(), &ito_path, &StdFs, &runner));
+
+    assert_eq!(check.status, DoctorStatus::Warn);
+    assert!(check.detail.starts_with("2 unparseable line(s)"));
+}
+
+#[test]
+fn unwritable_state_dir_fails() {
+    let td = tempfile::tempdir().unwrap();
+    let ito_path = td.path().join(".ito");
+    let runner = StubRunner::with_installed(&[]);
+
+    let check = check_state_writable(&ctx(td.path(), &ito_path, &ReadOnlyFs, &runner));
+    assert_eq!(check.status, DoctorStatus::Fail);
+
+    let check = check_state_writable(&ctx(td.path(), &ito_path, &StdFs, &runner));
+    assert_eq!(check.status, DoctorStatus::Pass);
+    assert!(!ito_path.join(".state/.doctor-probe").exists());
+}
+
+#[test]
+fn run_doctor_skips_ito_checks_without_ito_dir() {
+    let td = tempfile::tempdir().unwrap();
+    let ito_path = td.path().join(".ito");
+    let runner = StubRunner::with_installed(&["git"]);
+
+    let report = run_doctor(&ctx(td.path(), &ito_path, &StdFs, &runner));
+
+    assert!(report.has_failures());
+    let names: Vec<&str> = report.checks.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["git", "git repository", "ito dir"]);
+}
+
+#[test]
+fn run_doctor_reads_tools_from_init_config() {
+    let td = project_with_config(r#"{ "init": { "tools": ["codex"] } }"#);
+    let ito_path = td.path().join(".ito");
+    let runner = StubRunner::with_installed(&["git"]);
+
+    let report = run_doctor(&ctx(td.path(), &ito_path, &StdFs, &runner));
+
+    let names: Vec<&str> = report.checks.iter().map(|c| c.name.as_str()).collect();
+    assert!(names.contains(&"adapter: codex"), "{names:?}");
+    assert!(names.contains(&"harness: codex"), "{names:?}");
+    assert!(!names.iter().any(|n| n.contains("claude")), "{names:?}");
+}
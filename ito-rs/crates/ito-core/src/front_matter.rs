@@ -13,6 +13,7 @@ use sha2::{Digest, Sha256};
 use std::collections::BTreeMap;
 
 use crate::errors::CoreError;
+use ito_domain::changes::{ChangeMetadata, ChangePriority};
 
 /// Parsed YAML front matter metadata for an Ito artifact.
 ///
@@ -288,6 +289,120 @@ pub fn validate_id(
     Ok(())
 }
 
+/// Typed proposal metadata plus warnings for values that could not be used.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProposalMetadata {
+    /// Metadata recovered from the front matter.
+    pub metadata: ChangeMetadata,
+    /// Problems found while reading the front matter.
+    pub warnings: Vec<String>,
+}
+
+/// Extract typed change metadata from a proposal document.
+///
+/// This never fails: a proposal without front matter yields empty metadata,
+/// and malformed YAML or unusable values are reported as warnings and skipped
+/// so one bad field does not hide the rest of the change.
+pub fn proposal_metadata(content: &str) -> ProposalMetadata {
+    let front_matter = match parse(content) {
+        Ok(parsed) => match parsed.front_matter {
+            Some(front_matter) => front_matter,
+            None => return ProposalMetadata::default(),
+        },
+        Err(e) => {
+            return ProposalMetadata {
+                metadata: ChangeMetadata::default(),
+                warnings: vec![e.to_string()],
+            };
+        }
+    };
+
+    let mut metadata = ChangeMetadata::default();
+    let mut warnings = Vec::new();
+    for (key, value) in front_matter.extra {
+        match key.as_str() {
+            "owner" => match value {
+                serde_yaml::Value::Null => {}
+                serde_yaml::Value::String(owner) => {
+                    let owner = owner.trim();
+                    if !owner.is_empty() {
+                        metadata.owner = Some(owner.to_string());
+                    }
+                }
+                other => warnings.push(format!(
+                    "front matter `owner` must be a string, found `{}`",
+                    render_yaml(&other)
+                )),
+            },
+            "priority" => match value.as_str().and_then(ChangePriority::parse) {
+                Some(priority) => metadata.priority = Some(priority),
+                None if value.is_null() => {}
+                None => warnings.push(format!(
+                    "unknown priority `{}` (expected one of P0, P1, P2, P3)",
+                    render_yaml(&value)
+                )),
+            },
+            "labels" => match value {
+                serde_yaml::Value::Null => {}
+                // `labels: ui, api` is a common shorthand for a list.
+                serde_yaml::Value::String(labels) => {
+                    metadata.labels = labels
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|label| !label.is_empty())
+                        .map(str::to_string)
+                        .collect();
+                }
+                serde_yaml::Value::Sequence(items) => {
+                    for item in items {
+                        match item {
+                            serde_yaml::Value::String(label) if !label.trim().is_empty() => {
+                                metadata.labels.push(label.trim().to_string());
+                            }
+                            other => warnings.push(format!(
+                                "front matter label `{}` is not a string; skipped",
+                                render_yaml(&other)
+                            )),
+                        }
+                    }
+                }
+                other => warnings.push(format!(
+                    "front matter `labels` must be a list of strings, found `{}`",
+                    render_yaml(&other)
+                )),
+            },
+            "created" => {
+                let raw = render_yaml(&value);
+                match chrono::NaiveDate::parse_from_str(&raw, "%Y-%m-%d") {
+                    Ok(date) => metadata.created = Some(date.format("%Y-%m-%d").to_string()),
+                    Err(_) if value.is_null() => {}
+                    Err(_) => warnings.push(format!(
+                        "front matter `created` must be a YYYY-MM-DD date, found `{raw}`"
+                    )),
+                }
+            }
+            _ => match serde_json::to_value(&value) {
+                Ok(json) => {
+                    metadata.extra.insert(key, json);
+                }
+                Err(e) => warnings.push(format!("front matter `{key}` is not representable: {e}")),
+            },
+        }
+    }
+
+    ProposalMetadata { metadata, warnings }
+}
+
+/// Render a YAML value for messages and date parsing (no trailing newline).
+fn render_yaml(value: &serde_yaml::Value) -> String {
+    match value {
+        serde_yaml::Value::String(s) => s.clone(),
+        other => serde_yaml::to_string(other)
+            .map(|s| s.trim_end().to_string())
+            .unwrap_or_default(),
+    }
+}
+
 #[cfg(test)]
 #[path = "front_matter_tests.rs"]
 mod front_matter_tests;
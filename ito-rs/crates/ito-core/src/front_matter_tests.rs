@@ -229,3 +229,52 @@ fn created_at_dt_returns_none_for_invalid_timestamp() {
     };
     assert!(fm.created_at_dt().is_none());
 }
+
+#[test]
+fn proposal_metadata_is_empty_without_front_matter() {
+    let parsed = proposal_metadata("## Why\n\nBecause.\n");
+    assert!(parsed.metadata.is_empty());
+    assert!(parsed.warnings.is_empty());
+}
+
+#[test]
+fn proposal_metadata_reads_typed_fields() {
+    let content = "---\nowner: alice\npriority: p1\nlabels: [ui, api]\ncreated: 2026-03-04\nticket: ITO-12\n---\n## Why\n";
+    let parsed = proposal_metadata(content);
+
+    assert!(parsed.warnings.is_empty(), "{:?}", parsed.warnings);
+    let metadata = parsed.metadata;
+    assert_eq!(metadata.owner.as_deref(), Some("alice"));
+    assert_eq!(metadata.priority, Some(ChangePriority::P1));
+    assert_eq!(metadata.labels, ["ui", "api"]);
+    assert_eq!(metadata.created.as_deref(), Some("2026-03-04"));
+    assert_eq!(
+        metadata.extra.get("ticket"),
+        Some(&serde_json::json!("ITO-12"))
+    );
+}
+
+#[test]
+fn proposal_metadata_treats_blank_skeleton_fields_as_absent() {
+    let parsed = proposal_metadata("---\nowner:\npriority:\nlabels: []\n---\n");
+    assert!(parsed.metadata.is_empty());
+    assert!(parsed.warnings.is_empty(), "{:?}", parsed.warnings);
+}
+
+#[test]
+fn proposal_metadata_warns_on_malformed_yaml() {
+    let parsed = proposal_metadata("---\nowner: [unterminated\n---\n## Why\n");
+    assert!(parsed.metadata.is_empty());
+    assert_eq!(parsed.warnings.len(), 1);
+}
+
+#[test]
+fn proposal_metadata_warns_on_unknown_priority() {
+    let parsed = proposal_metadata("---\npriority: urgent\nlabels: ui, api\n---\n");
+    assert_eq!(parsed.metadata.priority, None);
+    assert_eq!(parsed.metadata.labels, ["ui", "api"]);
+    assert_eq!(
+        parsed.warnings,
+        ["unknown priority `urgent` (expected one of P0, P1, P2, P3)"]
+    );
+}
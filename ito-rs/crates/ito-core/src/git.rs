@@ -619,7 +619,7 @@ pub(crate) fn reserve_change_on_coordination_branch_with_runner(
     )?;
     if staged.success {
         if let Err(err) = cleanup.cleanup_with_runner(runner) {
-            eprintln!(
+            ito_config::notice!(
                 "Warning: failed to remove temporary coordination worktree '{}': {}",
                 cleanup.worktree_path.display(),
                 err.message
@@ -658,7 +658,7 @@ pub(crate) fn reserve_change_on_coordination_branch_with_runner(
 
     let push = push_coordination_branch_with_runner(runner, &worktree_path, "HEAD", branch);
     if let Err(err) = cleanup.cleanup_with_runner(runner) {
-        eprintln!(
+        ito_config::notice!(
             "Warning: failed to remove temporary coordination worktree '{}': {}",
             cleanup.worktree_path.display(),
             err.message
@@ -703,7 +703,7 @@ fn copy_dir_recursive(source: &Path, target: &Path) -> std::io::Result<()> {
         let metadata = fs::symlink_metadata(&source_path)?;
         let file_type = metadata.file_type();
         if file_type.is_symlink() {
-            eprintln!(
+            ito_config::notice!(
                 "Warning: skipped symlink while reserving coordination change: {}",
                 source_path.display()
             );
@@ -720,7 +720,7 @@ fn copy_dir_recursive(source: &Path, target: &Path) -> std::io::Result<()> {
     Ok(())
 }
 
-fn is_git_worktree(runner: &dyn ProcessRunner, repo_root: &Path) -> bool {
+pub(crate) fn is_git_worktree(runner: &dyn ProcessRunner, repo_root: &Path) -> bool {
     let request = ProcessRequest::new("git")
         .args(["rev-parse", "--is-inside-work-tree"])
         .current_dir(repo_root);
@@ -40,6 +40,7 @@ fn ok_output(stdout: &str) -> ProcessOutput {
         stdout: stdout.to_string(),
         stderr: String::new(),
         timed_out: false,
+        resources: Default::default(),
     }
 }
 
@@ -50,6 +51,7 @@ fn err_output(stderr: &str) -> ProcessOutput {
         stdout: String::new(),
         stderr: stderr.to_string(),
         timed_out: false,
+        resources: Default::default(),
     }
 }
 
@@ -43,6 +43,7 @@ fn ok_output(stdout: &str, stderr: &str) -> ProcessOutput {
         stdout: stdout.to_string(),
         stderr: stderr.to_string(),
         timed_out: false,
+        resources: Default::default(),
     }
 }
 
@@ -53,6 +54,7 @@ fn err_output(stderr: &str) -> ProcessOutput {
         stdout: String::new(),
         stderr: stderr.to_string(),
         timed_out: false,
+        resources: Default::default(),
     }
 }
 
@@ -188,7 +188,7 @@ fn resolve_scope_files(
                 ito_domain::changes::ChangeTargetResolution::Ambiguous(matches) => {
                     return Err(CoreError::validation(format!(
                         "ambiguous change target '{change_id}', matches: {}",
-                        matches.join(", ")
+                        ito_domain::changes::target_match_ids(&matches).join(", ")
                     )));
                 }
                 ito_domain::changes::ChangeTargetResolution::NotFound => {
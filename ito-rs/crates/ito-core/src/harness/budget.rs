@@ -0,0 +1,149 @@
+//! Prompt size estimates checked against model context windows.
+//!
+//! Before a prompt is handed to a harness, its size is estimated in tokens and
+//! compared with the context window of the selected model. Context windows
+//! come from the `stats.contextWindows` project config key, keyed by the model
+//! id passed to the harness:
+//!
+//! ```json
+//! { "stats": { "contextWindows": { "sonnet": 200000 } } }
+//! ```
+//!
+//! Models without a configured window are not checked. A prompt that likely
+//! exceeds the window produces a warning, or an error in strict mode.
+//!
+//! The default estimator counts four characters per token. Building with the
+//! `bpe-tokens` feature counts `cl100k_base` BPE tokens instead, which is
+//! closer for most current models but still only an estimate.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use ito_config::{ConfigContext, load_cascading_project_config};
+
+use crate::config::json_get_path;
+use crate::errors::{CoreError, CoreResult};
+
+/// Project config key holding per-model context windows.
+pub const CONTEXT_WINDOWS_CONFIG_KEY: &str = "stats.contextWindows";
+
+/// Characters counted as one token by [`estimate_tokens_heuristic`].
+pub const HEURISTIC_CHARS_PER_TOKEN: u64 = 4;
+
+/// Context windows in tokens, keyed by the model id passed to the harness.
+pub type ContextWindows = BTreeMap<String, u64>;
+
+/// Estimated size of a prompt and the context window it was checked against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PromptEstimate {
+    /// Estimated prompt size in tokens.
+    pub tokens: u64,
+    /// Context window of the selected model; `None` when it is not configured.
+    pub context_window: Option<u64>,
+}
+
+impl PromptEstimate {
+    /// Estimate `prompt` and look up the context window of `model` in `windows`.
+    ///
+    /// `model: None` (the harness default) has no known window.
+    pub fn new(prompt: &str, model: Option<&str>, windows: &ContextWindows) -> Self {
+        Self {
+            tokens: estimate_tokens(prompt),
+            context_window: model.and_then(|model| windows.get(model).copied()),
+        }
+    }
+
+    /// Whether the estimate is larger than the known context window.
+    pub fn exceeds_window(&self) -> bool {
+        self.context_window
+            .is_some_and(|window| self.tokens > window)
+    }
+}
+
+/// Estimate the number of tokens in `text`.
+///
+/// Uses [`estimate_tokens_heuristic`] unless the `bpe-tokens` feature is enabled.
+pub fn estimate_tokens(text: &str) -> u64 {
+    #[cfg(feature = "bpe-tokens")]
+    {
+        estimate_tokens_bpe(text)
+    }
+    #[cfg(not(feature = "bpe-tokens"))]
+    {
+        estimate_tokens_heuristic(text)
+    }
+}
+
+/// Estimate tokens as characters divided by [`HEURISTIC_CHARS_PER_TOKEN`], rounded up.
+pub fn estimate_tokens_heuristic(text: &str) -> u64 {
+    (text.chars().count() as u64).div_ceil(HEURISTIC_CHARS_PER_TOKEN)
+}
+
+/// Count `cl100k_base` BPE tokens in `text`.
+#[cfg(feature = "bpe-tokens")]
+pub fn estimate_tokens_bpe(text: &str) -> u64 {
+    tiktoken_rs::cl100k_base_singleton()
+        .encode_with_special_tokens(text)
+        .len() as u64
+}
+
+/// Warning text for an estimate over its context window, if it is over.
+pub fn budget_warning(estimate: &PromptEstimate, model: Option<&str>) -> Option<String> {
+    if !estimate.exceeds_window() {
+        return None;
+    }
+    let window = estimate.context_window?;
+    Some(format!(
+        "Prompt is an estimated {tokens} tokens, over the {window}-token context window of model '{model}'",
+        tokens = estimate.tokens,
+        model = model.unwrap_or("(default)"),
+    ))
+}
+
+/// Warn about, or with `strict` reject, a prompt over its context window.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Validation`] when `strict` is set and the estimate
+/// exceeds the window.
+pub fn check_prompt_budget(
+    estimate: &PromptEstimate,
+    model: Option<&str>,
+    strict: bool,
+) -> CoreResult<()> {
+    let Some(message) = budget_warning(estimate, model) else {
+        return Ok(());
+    };
+    if strict {
+        return Err(CoreError::validation(format!(
+            "{message}; not sending it (--strict-budget)"
+        )));
+    }
+    ito_config::notice!("Warning: {message}");
+    Ok(())
+}
+
+/// Load `stats.contextWindows` from the cascading project config.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Validation`] when the configured value is not a map
+/// of model ids to token counts.
+pub fn load_context_windows(
+    project_root: &Path,
+    ito_path: &Path,
+    ctx: &ConfigContext,
+) -> CoreResult<ContextWindows> {
+    let merged = load_cascading_project_config(project_root, ito_path, ctx).merged;
+    let parts: Vec<&str> = CONTEXT_WINDOWS_CONFIG_KEY.split('.').collect();
+    let Some(value) = json_get_path(&merged, &parts) else {
+        return Ok(ContextWindows::new());
+    };
+    serde_json::from_value(value.clone()).map_err(|e| {
+        CoreError::validation(format!("Invalid {CONTEXT_WINDOWS_CONFIG_KEY} value: {e}"))
+    })
+}
+
+#[cfg(test)]
+#[path = "budget_tests.rs"]
+mod budget_tests;
@@ -0,0 +1,117 @@
+use super::*;
+
+fn windows(entries: &[(&str, u64)]) -> ContextWindows {
+    entries
+        .iter()
+        .map(|(model, window)| (model.to_string(), *window))
+        .collect()
+}
+
+#[test]
+fn heuristic_counts_four_chars_per_token_rounding_up() {
+    assert_eq!(estimate_tokens_heuristic(""), 0);
+    assert_eq!(estimate_tokens_heuristic("abc"), 1);
+    assert_eq!(estimate_tokens_heuristic("abcd"), 1);
+    assert_eq!(estimate_tokens_heuristic("abcde"), 2);
+    assert_eq!(estimate_tokens_heuristic(&"x".repeat(4_000)), 1_000);
+}
+
+#[test]
+fn heuristic_counts_characters_not_bytes() {
+    // Four three-byte characters are one token, not three.
+    assert_eq!(estimate_tokens_heuristic("✓✓✓✓"), 1);
+    assert_eq!(estimate_tokens_heuristic("héllo wörld"), 3);
+}
+
+#[cfg(feature = "bpe-tokens")]
+#[test]
+fn bpe_counts_known_strings() {
+    assert_eq!(estimate_tokens_bpe(""), 0);
+    assert_eq!(estimate_tokens_bpe("hello world"), 2);
+}
+
+#[test]
+fn window_comes_from_the_selected_model() {
+    let windows = windows(&[("tiny", 10), ("large", 200_000)]);
+    let prompt = "x".repeat(100);
+
+    let tiny = PromptEstimate::new(&prompt, Some("tiny"), &windows);
+    assert_eq!(tiny.context_window, Some(10));
+    assert!(tiny.exceeds_window());
+
+    let large = PromptEstimate::new(&prompt, Some("large"), &windows);
+    assert!(!large.exceeds_window());
+
+    let unknown = PromptEstimate::new(&prompt, Some("other"), &windows);
+    assert_eq!(unknown.context_window, None);
+    assert!(!unknown.exceeds_window());
+
+    assert_eq!(
+        PromptEstimate::new(&prompt, None, &windows).context_window,
+        None
+    );
+}
+
+#[test]
+fn prompt_exactly_at_the_window_is_not_over() {
+    let estimate = PromptEstimate {
+        tokens: 10,
+        context_window: Some(10),
+    };
+    assert!(!estimate.exceeds_window());
+    assert_eq!(budget_warning(&estimate, Some("tiny")), None);
+}
+
+#[test]
+fn warning_names_the_numbers_and_model() {
+    let estimate = PromptEstimate {
+        tokens: 25,
+        context_window: Some(10),
+    };
+    assert_eq!(
+        budget_warning(&estimate, Some("tiny")).as_deref(),
+        Some("Prompt is an estimated 25 tokens, over the 10-token context window of model 'tiny'")
+    );
+}
+
+#[test]
+fn strict_mode_rejects_an_oversized_prompt() {
+    let over = PromptEstimate {
+        tokens: 25,
+        context_window: Some(10),
+    };
+    assert!(check_prompt_budget(&over, Some("tiny"), false).is_ok());
+
+    let err = check_prompt_budget(&over, Some("tiny"), true).unwrap_err();
+    assert!(matches!(err, CoreError::Validation(_)));
+    assert!(err.to_string().contains("--strict-budget"), "{err}");
+
+    let under = PromptEstimate {
+        tokens: 5,
+        context_window: Some(10),
+    };
+    assert!(check_prompt_budget(&under, Some("tiny"), true).is_ok());
+}
+
+#[test]
+fn context_windows_load_from_project_config() {
+    let td = tempfile::tempdir().expect("tempdir");
+    let ito_path = td.path().join(".ito");
+    std::fs::create_dir_all(&ito_path).expect("mkdir");
+    std::fs::write(
+        ito_path.join("config.json"),
+        r#"{"stats": {"contextWindows": {"tiny": 10}}}"#,
+    )
+    .expect("write config");
+
+    let loaded =
+        load_context_windows(td.path(), &ito_path, &ConfigContext::default()).expect("load");
+    assert_eq!(loaded, windows(&[("tiny", 10)]));
+
+    std::fs::write(
+        ito_path.join("config.json"),
+        r#"{"stats": {"contextWindows": {"tiny": "big"}}}"#,
+    )
+    .expect("write config");
+    assert!(load_context_windows(td.path(), &ito_path, &ConfigContext::default()).is_err());
+}
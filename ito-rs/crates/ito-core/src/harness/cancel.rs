@@ -0,0 +1,45 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// How long a cancelled harness process may take to exit after `SIGTERM`
+/// before it is killed.
+pub const CANCEL_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// Cooperative cancellation handle shared between a harness run and whoever
+/// may want to stop it (a signal handler, a web request, a test).
+///
+/// Clones share the same flag; cancelling any clone cancels them all.
+///
+/// # Examples
+///
+/// ```
+/// use ito_core::harness::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let handle = token.clone();
+/// assert!(!token.is_cancelled());
+/// handle.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a token that has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation. Idempotent.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
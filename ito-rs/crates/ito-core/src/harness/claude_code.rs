@@ -1,9 +1,12 @@
+use super::permissions::active_permissions;
 use super::streaming_cli::CliHarness;
-use super::types::{HarnessName, HarnessRunConfig};
+use super::types::{HarnessName, HarnessRunConfig, TokenUsage};
 
 /// Runs the `claude` CLI in non-interactive print mode (`claude -p`).
 ///
 /// Selected via `ito ralph --harness claude`; requires the Claude Code CLI on PATH.
+/// A [`HarnessRunConfig::permissions`] policy is passed as `--settings`
+/// permission entries.
 ///
 /// # Examples
 ///
@@ -34,11 +37,105 @@ impl CliHarness for ClaudeCodeHarness {
         }
         if config.allow_all {
             args.push("--dangerously-skip-permissions".to_string());
+        } else if let Some(policy) = active_permissions(config) {
+            args.push("--settings".to_string());
+            args.push(policy.claude_settings().to_string());
         }
         args.push("-p".to_string());
         args.push(config.prompt.clone());
         args
     }
+
+    fn extract_final_message(&self, stdout: &str) -> Option<String> {
+        final_message_from_events(stdout)
+    }
+
+    fn parse_usage(&self, stdout: &str) -> Option<TokenUsage> {
+        usage_from_events(stdout)
+    }
+}
+
+/// Find the assistant's final message in Claude Code JSON output.
+///
+/// With `--output-format json` or `stream-json`, stdout is one JSON event per
+/// line. The closing `{"type":"result","result":"..."}` event carries the
+/// final text; when it is missing (e.g. the run was cut short) the text of the
+/// last `assistant` event is used. Plain-text output yields `None`.
+fn final_message_from_events(stdout: &str) -> Option<String> {
+    let mut result = None;
+    let mut last_assistant = None;
+    for line in stdout.lines() {
+        let line = line.trim();
+        if !line.starts_with('{') {
+            continue;
+        }
+        let Ok(event) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        match event.get("type").and_then(serde_json::Value::as_str) {
+            Some("result") => {
+                if let Some(text) = event.get("result").and_then(serde_json::Value::as_str) {
+                    result = Some(text.to_string());
+                }
+            }
+            Some("assistant") => {
+                if let Some(text) = assistant_text(&event) {
+                    last_assistant = Some(text);
+                }
+            }
+            _ => {}
+        }
+    }
+    result.or(last_assistant)
+}
+
+/// Read token usage from the closing `result` event of Claude Code JSON output.
+///
+/// The `result` event totals usage across the whole run; plain-text output or
+/// a run cut short before the event yields `None`.
+fn usage_from_events(stdout: &str) -> Option<TokenUsage> {
+    let mut usage = None;
+    for line in stdout.lines() {
+        let line = line.trim();
+        if !line.starts_with('{') {
+            continue;
+        }
+        let Ok(event) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        if event.get("type").and_then(serde_json::Value::as_str) != Some("result") {
+            continue;
+        }
+        let Some(block) = event.get("usage") else {
+            continue;
+        };
+        let count = |key: &str| block.get(key).and_then(serde_json::Value::as_u64);
+        let (Some(input_tokens), Some(output_tokens)) =
+            (count("input_tokens"), count("output_tokens"))
+        else {
+            continue;
+        };
+        usage = Some(TokenUsage {
+            input_tokens,
+            output_tokens,
+            cache_read_tokens: count("cache_read_input_tokens").unwrap_or(0),
+        });
+    }
+    usage
+}
+
+/// Concatenate the `text` content blocks of an `assistant` event.
+fn assistant_text(event: &serde_json::Value) -> Option<String> {
+    let content = event.pointer("/message/content")?.as_array()?;
+    let text: Vec<&str> = content
+        .iter()
+        .filter(|block| block.get("type").and_then(serde_json::Value::as_str) == Some("text"))
+        .filter_map(|block| block.get("text").and_then(serde_json::Value::as_str))
+        .collect();
+    if text.is_empty() {
+        return None;
+    }
+    Some(text.join("\n"))
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use super::*;
+use crate::harness::PermissionPolicy;
 use std::collections::BTreeMap;
 
 enum Allow {
@@ -16,9 +17,15 @@ fn config(allow: Allow, model: Option<&str>) -> HarnessRunConfig {
         model: model.map(String::from),
         cwd: std::env::temp_dir(),
         env: BTreeMap::new(),
+        env_policy: Default::default(),
         interactive: false,
         allow_all,
         inactivity_timeout: None,
+        resume_session: None,
+        transcript_path: None,
+        permissions: None,
+        cancel: None,
+        prompt_estimate: None,
     }
 }
 
@@ -66,3 +73,112 @@ fn build_args_without_model() {
     let args = harness.build_args(&cfg);
     assert_eq!(args, vec!["-p", "do stuff"]);
 }
+
+fn permission_policy() -> PermissionPolicy {
+    PermissionPolicy {
+        allow_paths: vec!["src/**".to_string()],
+        allow_commands: vec!["cargo test".to_string()],
+        deny_commands: vec!["git push".to_string()],
+        ..PermissionPolicy::default()
+    }
+}
+
+#[test]
+fn build_args_pass_permission_policy_as_settings() {
+    let harness = ClaudeCodeHarness;
+    let mut cfg = config(Allow::None, None);
+    cfg.permissions = Some(permission_policy());
+    let args = harness.build_args(&cfg);
+    assert_eq!(args.len(), 4);
+    assert_eq!(args[0], "--settings");
+    let settings: serde_json::Value = serde_json::from_str(&args[1]).unwrap();
+    assert_eq!(
+        settings,
+        serde_json::json!({
+            "permissions": {
+                "allow": ["Edit(src/**)", "Bash(cargo test:*)"],
+                "deny": ["Bash(git push:*)"]
+            }
+        })
+    );
+    assert_eq!(args[2..], ["-p", "do stuff"]);
+}
+
+#[test]
+fn allow_all_overrides_permission_policy() {
+    let harness = ClaudeCodeHarness;
+    let mut cfg = config(Allow::All, None);
+    cfg.permissions = Some(permission_policy());
+    let args = harness.build_args(&cfg);
+    assert_eq!(
+        args,
+        vec!["--dangerously-skip-permissions", "-p", "do stuff"]
+    );
+}
+
+/// Abbreviated `claude -p --output-format stream-json --verbose` output.
+const STREAM_JSON_FIXTURE: &str = r#"{"type":"system","subtype":"init","session_id":"0b1c","tools":["Bash","Edit"]}
+{"type":"assistant","message":{"role":"assistant","content":[{"type":"text","text":"Running the tests."},{"type":"tool_use","id":"t1","name":"Bash","input":{"command":"cargo test"}}]}}
+{"type":"user","message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"t1","content":"ok"}]}}
+{"type":"assistant","message":{"role":"assistant","content":[{"type":"text","text":"All done.\n<promise>COMPLETE</promise>"}]}}
+{"type":"result","subtype":"success","is_error":false,"result":"All done.\n<promise>COMPLETE</promise>","session_id":"0b1c","usage":{"input_tokens":1200,"cache_creation_input_tokens":300,"cache_read_input_tokens":4500,"output_tokens":640}}
+"#;
+
+#[test]
+fn extract_final_message_prefers_result_event() {
+    let harness = ClaudeCodeHarness;
+    assert_eq!(
+        harness
+            .extract_final_message(STREAM_JSON_FIXTURE)
+            .as_deref(),
+        Some("All done.\n<promise>COMPLETE</promise>")
+    );
+}
+
+#[test]
+fn extract_final_message_falls_back_to_last_assistant_text() {
+    let harness = ClaudeCodeHarness;
+    let truncated: String = STREAM_JSON_FIXTURE
+        .lines()
+        .filter(|line| !line.contains(r#""type":"result""#))
+        .map(|line| format!("{line}\n"))
+        .collect();
+    assert_eq!(
+        harness.extract_final_message(&truncated).as_deref(),
+        Some("All done.\n<promise>COMPLETE</promise>")
+    );
+}
+
+#[test]
+fn extract_final_message_ignores_plain_text_output() {
+    let harness = ClaudeCodeHarness;
+    assert_eq!(
+        harness.extract_final_message("All done.\n<promise>COMPLETE</promise>\n"),
+        None
+    );
+}
+
+#[test]
+fn parse_usage_reads_result_event_usage() {
+    let harness = ClaudeCodeHarness;
+    assert_eq!(
+        harness.parse_usage(STREAM_JSON_FIXTURE),
+        Some(TokenUsage {
+            input_tokens: 1200,
+            output_tokens: 640,
+            cache_read_tokens: 4500,
+        })
+    );
+}
+
+#[test]
+fn parse_usage_is_unknown_without_result_event() {
+    let harness = ClaudeCodeHarness;
+    let truncated: String = STREAM_JSON_FIXTURE
+        .lines()
+        .filter(|line| !line.contains(r#""type":"result""#))
+        .map(|line| format!("{line}\n"))
+        .collect();
+    assert_eq!(harness.parse_usage(&truncated), None);
+    assert_eq!(harness.parse_usage("All done.\n"), None);
+}
@@ -16,9 +16,15 @@ fn config(allow: Allow, model: Option<&str>) -> HarnessRunConfig {
         model: model.map(String::from),
         cwd: std::env::temp_dir(),
         env: BTreeMap::new(),
+        env_policy: Default::default(),
         interactive: false,
         allow_all,
         inactivity_timeout: None,
+        resume_session: None,
+        transcript_path: None,
+        permissions: None,
+        cancel: None,
+        prompt_estimate: None,
     }
 }
 
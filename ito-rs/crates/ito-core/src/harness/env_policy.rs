@@ -0,0 +1,139 @@
+//! Environment policy for harness subprocesses.
+//!
+//! By default a harness inherits the full parent environment. A
+//! [`HarnessEnvPolicy`] can instead restrict the child to an allow-list so
+//! API keys and unrelated secrets from the developer's shell do not leak into
+//! agent tool calls. [`ALWAYS_PASSED_ENV`] and the policy's required variables
+//! survive every mode.
+
+use super::types::HarnessName;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Variables passed to every harness regardless of mode.
+pub const ALWAYS_PASSED_ENV: &[&str] = &["PATH", "HOME", "TERM"];
+
+/// How the parent environment reaches a harness subprocess.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum EnvMode {
+    /// Pass the full parent environment (historical behaviour).
+    #[default]
+    Inherit,
+    /// Pass only the listed variables (plus the always-passed and required ones).
+    AllowList(Vec<String>),
+    /// Pass only the always-passed and required variables.
+    Clean,
+}
+
+/// Environment policy applied when spawning a harness.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HarnessEnvPolicy {
+    /// How the parent environment is filtered.
+    pub mode: EnvMode,
+    /// Variables that must be set before the harness starts.
+    ///
+    /// They are always passed through, and a missing one fails the run before
+    /// the harness is spawned.
+    pub required: Vec<String>,
+}
+
+/// A required variable is not set in the parent environment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingEnvVar {
+    /// Harness that requires the variable.
+    pub harness: HarnessName,
+    /// Name of the missing variable.
+    pub name: String,
+}
+
+impl fmt::Display for MissingEnvVar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "harness `{}` requires environment variable {}, which is not set",
+            self.harness, self.name
+        )
+    }
+}
+
+impl std::error::Error for MissingEnvVar {}
+
+impl HarnessEnvPolicy {
+    /// Extend the allow-list with `names`.
+    ///
+    /// [`EnvMode::Clean`] becomes an allow-list; [`EnvMode::Inherit`] already
+    /// passes everything and is left unchanged.
+    pub fn allow(&mut self, names: impl IntoIterator<Item = String>) {
+        let extra: Vec<String> = names.into_iter().collect();
+        if extra.is_empty() {
+            return;
+        }
+        match &mut self.mode {
+            EnvMode::Inherit => {}
+            EnvMode::Clean => self.mode = EnvMode::AllowList(extra),
+            EnvMode::AllowList(list) => {
+                for name in extra {
+                    if !list.contains(&name) {
+                        list.push(name);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Fail when a required variable is neither in `parent` nor in `overrides`.
+    ///
+    /// Empty values count as missing.
+    pub fn check_required(
+        &self,
+        harness: HarnessName,
+        parent: &BTreeMap<String, String>,
+        overrides: &BTreeMap<String, String>,
+    ) -> Result<(), MissingEnvVar> {
+        for name in &self.required {
+            let set = overrides
+                .get(name)
+                .or_else(|| parent.get(name))
+                .is_some_and(|value| !value.is_empty());
+            if !set {
+                return Err(MissingEnvVar {
+                    harness,
+                    name: name.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// The environment the child should see, or `None` to inherit everything.
+    pub fn filter(&self, parent: &BTreeMap<String, String>) -> Option<BTreeMap<String, String>> {
+        let allowed: &[String] = match &self.mode {
+            EnvMode::Inherit => return None,
+            EnvMode::AllowList(list) => list,
+            EnvMode::Clean => &[],
+        };
+        let keep = |name: &str| {
+            ALWAYS_PASSED_ENV.contains(&name)
+                || self.required.iter().any(|r| r == name)
+                || allowed.iter().any(|a| a == name)
+        };
+        Some(
+            parent
+                .iter()
+                .filter(|(name, _)| keep(name))
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+        )
+    }
+}
+
+/// The current process environment (non-UTF-8 entries are skipped).
+pub fn parent_env() -> BTreeMap<String, String> {
+    std::env::vars_os()
+        .filter_map(|(name, value)| Some((name.into_string().ok()?, value.into_string().ok()?)))
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "env_policy_tests.rs"]
+mod env_policy_tests;
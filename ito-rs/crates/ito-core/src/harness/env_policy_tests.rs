@@ -0,0 +1,69 @@
+use super::*;
+
+fn env(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+fn parent() -> BTreeMap<String, String> {
+    env(&[
+        ("PATH", "/usr/bin"),
+        ("HOME", "/home/dev"),
+        ("TERM", "xterm"),
+        ("ANTHROPIC_API_KEY", "sk-ant"),
+        ("AWS_SECRET_ACCESS_KEY", "hunter2"),
+        ("FOO", "bar"),
+    ])
+}
+
+#[test]
+fn inherit_passes_everything() {
+    assert_eq!(HarnessEnvPolicy::default().filter(&parent()), None);
+}
+
+#[test]
+fn clean_keeps_only_always_passed_and_required() {
+    let policy = HarnessEnvPolicy {
+        mode: EnvMode::Clean,
+        required: vec!["ANTHROPIC_API_KEY".to_string()],
+    };
+    let filtered = policy.filter(&parent()).unwrap();
+    let names: Vec<&str> = filtered.keys().map(String::as_str).collect();
+    assert_eq!(names, ["ANTHROPIC_API_KEY", "HOME", "PATH", "TERM"]);
+}
+
+#[test]
+fn allow_extends_clean_into_allow_list() {
+    let mut policy = HarnessEnvPolicy {
+        mode: EnvMode::Clean,
+        required: Vec::new(),
+    };
+    policy.allow(["FOO".to_string()]);
+    assert_eq!(policy.mode, EnvMode::AllowList(vec!["FOO".to_string()]));
+
+    let filtered = policy.filter(&parent()).unwrap();
+    assert_eq!(filtered.get("FOO").map(String::as_str), Some("bar"));
+    assert!(!filtered.contains_key("AWS_SECRET_ACCESS_KEY"));
+}
+
+#[test]
+fn missing_required_variable_is_named() {
+    let policy = HarnessEnvPolicy {
+        mode: EnvMode::Inherit,
+        required: vec!["OPENAI_API_KEY".to_string()],
+    };
+    let err = policy
+        .check_required(HarnessName::Codex, &parent(), &BTreeMap::new())
+        .unwrap_err();
+    assert_eq!(err.name, "OPENAI_API_KEY");
+    assert!(err.to_string().contains("OPENAI_API_KEY"));
+
+    let overrides = env(&[("OPENAI_API_KEY", "sk-openai")]);
+    assert!(
+        policy
+            .check_required(HarnessName::Codex, &parent(), &overrides)
+            .is_ok()
+    );
+}
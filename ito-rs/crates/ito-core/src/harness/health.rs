@@ -0,0 +1,276 @@
+//! Harness health checks.
+//!
+//! A health check answers "can this harness run a prompt right now?" before a
+//! long Ralph run depends on it: the binary resolves, the CLI is logged in,
+//! and the selected model answers a trivial prompt.
+
+use super::env_policy::HarnessEnvPolicy;
+use super::streaming_cli::CliHarness;
+use super::types::{Harness, HarnessRunConfig, HarnessRunResult};
+use crate::process::{ProcessExecutionError, ProcessOutput, ProcessRequest, ProcessRunner};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+/// Prompt sent by the default health check.
+pub const HEALTH_CHECK_PROMPT: &str = "Reply with OK and nothing else.";
+
+/// How long the health-check prompt may run before it counts as a timeout.
+pub const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How long a native version probe (e.g. `claude --version`) may run.
+pub const VERSION_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Cap on stderr kept in a failed health check.
+const MAX_DETAIL_BYTES: usize = 4 * 1024;
+
+/// Lowercase stderr fragments that indicate missing or expired credentials.
+const AUTH_ERROR_MARKERS: &[&str] = &[
+    "not logged in",
+    "not authenticated",
+    "please log in",
+    "please login",
+    "run /login",
+    "login required",
+    "authentication failed",
+    "authentication error",
+    "authentication_error",
+    "unauthorized",
+    "invalid api key",
+    "invalid x-api-key",
+    "api key not found",
+    "missing api key",
+    "no api key",
+    "token has expired",
+    "token expired",
+    "expired token",
+    "session expired",
+    "credentials",
+];
+
+/// Outcome of [`Harness::health_check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HarnessHealth {
+    /// The harness answered the health-check prompt.
+    Ok,
+    /// The harness ran but rejected its credentials.
+    AuthError {
+        /// Captured stderr (tail, capped).
+        stderr: String,
+    },
+    /// The harness binary could not be found.
+    BinaryMissing {
+        /// Binary that failed to resolve.
+        binary: String,
+    },
+    /// The harness did not finish within the allowed time.
+    Timeout {
+        /// Time allowed before the check gave up.
+        after: Duration,
+    },
+    /// The harness failed for a reason the classifier does not recognize.
+    UnknownFailure {
+        /// Captured stderr (tail, capped), or the error that stopped the run.
+        stderr: String,
+    },
+}
+
+impl HarnessHealth {
+    /// Stable identifier used in JSON output (e.g. `auth_error`).
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            HarnessHealth::Ok => "ok",
+            HarnessHealth::AuthError { .. } => "auth_error",
+            HarnessHealth::BinaryMissing { .. } => "binary_missing",
+            HarnessHealth::Timeout { .. } => "timeout",
+            HarnessHealth::UnknownFailure { .. } => "unknown_failure",
+        }
+    }
+
+    /// Whether the harness is usable.
+    pub const fn is_ok(&self) -> bool {
+        matches!(self, HarnessHealth::Ok)
+    }
+}
+
+impl fmt::Display for HarnessHealth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HarnessHealth::Ok => f.write_str("ok"),
+            HarnessHealth::AuthError { stderr } => {
+                write!(f, "authentication failed")?;
+                write_detail(f, stderr)
+            }
+            HarnessHealth::BinaryMissing { binary } => {
+                write!(f, "`{binary}` not found on PATH")
+            }
+            HarnessHealth::Timeout { after } => {
+                write!(f, "no response within {}s", after.as_secs())
+            }
+            HarnessHealth::UnknownFailure { stderr } => {
+                write!(f, "failed")?;
+                write_detail(f, stderr)
+            }
+        }
+    }
+}
+
+fn write_detail(f: &mut fmt::Formatter<'_>, stderr: &str) -> fmt::Result {
+    let stderr = stderr.trim();
+    if stderr.is_empty() {
+        return Ok(());
+    }
+    write!(f, ": {stderr}")
+}
+
+/// Classify a failed run from its stderr.
+///
+/// Credential problems map to [`HarnessHealth::AuthError`]; everything else
+/// is [`HarnessHealth::UnknownFailure`]. Both keep the (capped) stderr.
+pub fn classify_failure(stderr: &str) -> HarnessHealth {
+    let lower = stderr.to_ascii_lowercase();
+    let stderr = tail(stderr.trim(), MAX_DETAIL_BYTES);
+    if AUTH_ERROR_MARKERS
+        .iter()
+        .any(|marker| lower.contains(marker))
+    {
+        HarnessHealth::AuthError { stderr }
+    } else {
+        HarnessHealth::UnknownFailure { stderr }
+    }
+}
+
+/// Classify a finished health-check run.
+pub fn classify_run(result: &HarnessRunResult, timeout: Duration) -> HarnessHealth {
+    if result.timed_out {
+        return HarnessHealth::Timeout { after: timeout };
+    }
+    if result.exit_code == 0 {
+        return HarnessHealth::Ok;
+    }
+    classify_failure(&failure_output(&result.stdout, &result.stderr))
+}
+
+/// Default health check: run [`HEALTH_CHECK_PROMPT`] through `harness`.
+///
+/// Used by the default [`Harness::health_check`]; the prompt runs with
+/// [`HEALTH_CHECK_TIMEOUT`] as its inactivity timeout.
+pub fn check_with_prompt<H: Harness + ?Sized>(
+    harness: &mut H,
+    cwd: &Path,
+    model: Option<&str>,
+) -> HarnessHealth {
+    let config = probe_config(cwd, model);
+    match harness.run(&config) {
+        Ok(result) => classify_run(&result, HEALTH_CHECK_TIMEOUT),
+        Err(err) => HarnessHealth::UnknownFailure {
+            stderr: err.to_string(),
+        },
+    }
+}
+
+/// Health check for CLI harnesses.
+///
+/// Runs the cheap native probe first (see [`CliHarness::version_args`]) so a
+/// missing or broken binary is reported without a model call, then sends
+/// [`HEALTH_CHECK_PROMPT`] with output captured rather than streamed.
+pub(crate) fn check_cli<H: CliHarness + ?Sized>(
+    harness: &H,
+    runner: &dyn ProcessRunner,
+    cwd: &Path,
+    model: Option<&str>,
+) -> HarnessHealth {
+    let binary = harness.binary();
+    if let Some(args) = harness.version_args() {
+        let request = ProcessRequest::new(binary)
+            .args(args)
+            .current_dir(cwd)
+            .max_output_bytes(MAX_DETAIL_BYTES);
+        if let Some(failure) = probe(runner, binary, &request, VERSION_CHECK_TIMEOUT) {
+            return failure;
+        }
+    }
+
+    let config = probe_config(cwd, model);
+    let request = ProcessRequest::new(binary)
+        .args(harness.build_args(&config))
+        .current_dir(cwd)
+        .max_output_bytes(MAX_DETAIL_BYTES);
+    probe(runner, binary, &request, HEALTH_CHECK_TIMEOUT).unwrap_or(HarnessHealth::Ok)
+}
+
+/// Run one probe; `None` means it succeeded.
+fn probe(
+    runner: &dyn ProcessRunner,
+    binary: &str,
+    request: &ProcessRequest,
+    timeout: Duration,
+) -> Option<HarnessHealth> {
+    match runner.run_with_timeout(request, timeout) {
+        Ok(out) => classify_output(&out, timeout),
+        Err(ProcessExecutionError::Spawn { source, .. })
+            if source.kind() == std::io::ErrorKind::NotFound =>
+        {
+            Some(HarnessHealth::BinaryMissing {
+                binary: binary.to_string(),
+            })
+        }
+        Err(err) => Some(HarnessHealth::UnknownFailure {
+            stderr: err.to_string(),
+        }),
+    }
+}
+
+fn classify_output(out: &ProcessOutput, timeout: Duration) -> Option<HarnessHealth> {
+    if out.timed_out {
+        return Some(HarnessHealth::Timeout { after: timeout });
+    }
+    if out.success {
+        return None;
+    }
+    Some(classify_failure(&failure_output(&out.stdout, &out.stderr)))
+}
+
+/// Stderr, or stdout when a CLI reports its errors there instead.
+fn failure_output(stdout: &str, stderr: &str) -> String {
+    if stderr.trim().is_empty() {
+        stdout.to_string()
+    } else {
+        stderr.to_string()
+    }
+}
+
+fn probe_config(cwd: &Path, model: Option<&str>) -> HarnessRunConfig {
+    HarnessRunConfig {
+        prompt: HEALTH_CHECK_PROMPT.to_string(),
+        model: model.map(str::to_string),
+        cwd: cwd.to_path_buf(),
+        env: BTreeMap::new(),
+        env_policy: HarnessEnvPolicy::default(),
+        interactive: false,
+        allow_all: false,
+        inactivity_timeout: Some(HEALTH_CHECK_TIMEOUT),
+        resume_session: None,
+        transcript_path: None,
+        permissions: None,
+        cancel: None,
+        prompt_estimate: None,
+    }
+}
+
+/// Last `max` bytes of `text`, cut at a char boundary.
+fn tail(text: &str, max: usize) -> String {
+    if text.len() <= max {
+        return text.to_string();
+    }
+    let mut start = text.len() - max;
+    while !text.is_char_boundary(start) {
+        start += 1;
+    }
+    text[start..].to_string()
+}
+
+#[cfg(test)]
+#[path = "health_tests.rs"]
+mod health_tests;
@@ -0,0 +1,207 @@
+use super::*;
+use crate::harness::stub::{StubHarness, StubStep};
+use crate::harness::types::HarnessName;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+#[derive(Debug)]
+struct FakeCliHarness {
+    binary: &'static str,
+}
+
+impl CliHarness for FakeCliHarness {
+    fn harness_name(&self) -> HarnessName {
+        HarnessName::Claude
+    }
+
+    fn binary(&self) -> &str {
+        self.binary
+    }
+
+    fn build_args(&self, config: &HarnessRunConfig) -> Vec<String> {
+        vec!["-p".to_string(), config.prompt.clone()]
+    }
+}
+
+/// Runner that replays scripted outputs and records each invocation's args.
+struct ScriptedRunner {
+    outputs: RefCell<VecDeque<ProcessOutput>>,
+    calls: RefCell<Vec<Vec<String>>>,
+}
+
+impl ScriptedRunner {
+    fn new(outputs: Vec<ProcessOutput>) -> Self {
+        Self {
+            outputs: RefCell::new(outputs.into()),
+            calls: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl ProcessRunner for ScriptedRunner {
+    fn run(&self, _request: &ProcessRequest) -> Result<ProcessOutput, ProcessExecutionError> {
+        unreachable!("health checks always use a timeout")
+    }
+
+    fn run_with_timeout(
+        &self,
+        request: &ProcessRequest,
+        _timeout: Duration,
+    ) -> Result<ProcessOutput, ProcessExecutionError> {
+        self.calls.borrow_mut().push(request.args.clone());
+        Ok(self
+            .outputs
+            .borrow_mut()
+            .pop_front()
+            .expect("expected process output"))
+    }
+}
+
+fn output(exit_code: i32, stdout: &str, stderr: &str) -> ProcessOutput {
+    ProcessOutput {
+        exit_code,
+        success: exit_code == 0,
+        stdout: stdout.to_string(),
+        stderr: stderr.to_string(),
+        timed_out: false,
+        resources: Default::default(),
+    }
+}
+
+#[test]
+fn stub_harness_is_always_healthy() {
+    let mut harness = StubHarness::new(vec![StubStep {
+        stdout: String::new(),
+        stderr: "boom".to_string(),
+        exit_code: 1,
+        writes: Default::default(),
+        delay_ms: 0,
+    }]);
+
+    let health = harness.health_check(&std::env::temp_dir(), None);
+
+    assert_eq!(health, HarnessHealth::Ok);
+}
+
+#[test]
+fn missing_binary_reports_binary_missing() {
+    let mut harness = FakeCliHarness {
+        binary: "ito-health-check-missing-binary",
+    };
+
+    let health = harness.health_check(&std::env::temp_dir(), None);
+
+    assert_eq!(
+        health,
+        HarnessHealth::BinaryMissing {
+            binary: "ito-health-check-missing-binary".to_string()
+        }
+    );
+    assert_eq!(health.kind(), "binary_missing");
+}
+
+#[test]
+fn scripted_login_failure_maps_to_auth_error() {
+    let harness = FakeCliHarness { binary: "claude" };
+    let runner = ScriptedRunner::new(vec![
+        output(0, "2.1.0 (Claude Code)\n", ""),
+        output(1, "", "Invalid API key · Please run /login\n"),
+    ]);
+
+    let health = check_cli(&harness, &runner, Path::new("."), None);
+
+    assert_eq!(
+        health,
+        HarnessHealth::AuthError {
+            stderr: "Invalid API key · Please run /login".to_string()
+        }
+    );
+    assert_eq!(
+        *runner.calls.borrow(),
+        vec![
+            vec!["--version".to_string()],
+            vec!["-p".to_string(), HEALTH_CHECK_PROMPT.to_string()],
+        ]
+    );
+}
+
+#[test]
+fn passing_probes_report_ok() {
+    let harness = FakeCliHarness { binary: "claude" };
+    let runner = ScriptedRunner::new(vec![output(0, "2.1.0\n", ""), output(0, "OK\n", "")]);
+
+    let health = check_cli(&harness, &runner, Path::new("."), None);
+
+    assert!(health.is_ok());
+}
+
+#[test]
+fn failed_version_probe_skips_the_prompt() {
+    let harness = FakeCliHarness { binary: "claude" };
+    let runner = ScriptedRunner::new(vec![output(127, "", "segfault in loader\n")]);
+
+    let health = check_cli(&harness, &runner, Path::new("."), None);
+
+    assert_eq!(
+        health,
+        HarnessHealth::UnknownFailure {
+            stderr: "segfault in loader".to_string()
+        }
+    );
+    assert_eq!(runner.calls.borrow().len(), 1);
+}
+
+#[test]
+fn timed_out_prompt_reports_timeout() {
+    let harness = FakeCliHarness { binary: "claude" };
+    let mut hung = output(-1, "", "");
+    hung.timed_out = true;
+    let runner = ScriptedRunner::new(vec![output(0, "2.1.0\n", ""), hung]);
+
+    let health = check_cli(&harness, &runner, Path::new("."), None);
+
+    assert_eq!(
+        health,
+        HarnessHealth::Timeout {
+            after: HEALTH_CHECK_TIMEOUT
+        }
+    );
+}
+
+#[test]
+fn classifier_separates_auth_errors_from_other_failures() {
+    for stderr in [
+        "Error: Not logged in. Run `codex login`.",
+        "401 Unauthorized",
+        "OAuth token has expired. Please obtain a new token.",
+    ] {
+        assert_eq!(classify_failure(stderr).kind(), "auth_error", "{stderr}");
+    }
+    assert_eq!(
+        classify_failure("model 'gpt-9' does not exist\n"),
+        HarnessHealth::UnknownFailure {
+            stderr: "model 'gpt-9' does not exist".to_string()
+        }
+    );
+}
+
+#[test]
+fn classify_run_falls_back_to_stdout_when_stderr_is_empty() {
+    let result = HarnessRunResult {
+        stdout: "Please login with `copilot auth`\n".to_string(),
+        stderr: String::new(),
+        exit_code: 1,
+        duration: Duration::from_millis(5),
+        timed_out: false,
+        cancelled: false,
+        session_id: None,
+        final_message: None,
+        usage: None,
+        resources: Default::default(),
+    };
+
+    assert_eq!(
+        classify_run(&result, HEALTH_CHECK_TIMEOUT).kind(),
+        "auth_error"
+    );
+}
@@ -7,38 +7,71 @@
 //! trait, which provides a blanket [`Harness`](crate::harness::types::Harness) impl — so adding a new CLI harness
 //! only requires implementing three small methods.
 
+/// Prompt size estimates checked against model context windows.
+pub mod budget;
+
+/// Cooperative cancellation of harness runs.
+pub mod cancel;
+
 /// Claude Code harness implementation.
 pub mod claude_code;
 
 /// OpenAI Codex harness implementation.
 pub mod codex;
 
+/// Environment filtering for harness subprocesses.
+pub mod env_policy;
+
 /// GitHub Copilot harness implementation.
 pub mod github_copilot;
 
+/// Checks that a harness can run a prompt end to end.
+pub mod health;
+
 /// OpenCode harness implementation.
 pub mod opencode;
 
+/// Tool permission policies for harness sessions.
+pub mod permissions;
+
 /// No-op/stub harness used for testing.
 pub mod stub;
 
 /// Streaming CLI harness trait and process management.
 pub mod streaming_cli;
 
+/// Markdown transcripts of harness runs.
+pub mod transcript;
+
 /// Shared harness types.
 pub mod types;
 
+/// Prompt size estimate and the context window it is checked against.
+pub use budget::{ContextWindows, PromptEstimate};
+
+/// Handle used to stop an in-flight harness run.
+pub use cancel::CancellationToken;
+
 /// Run workflows via the Claude Code harness.
 pub use claude_code::ClaudeCodeHarness;
 
 /// Run workflows via the OpenAI Codex harness.
 pub use codex::CodexHarness;
 
+/// Environment policy applied when spawning harnesses.
+pub use env_policy::{EnvMode, HarnessEnvPolicy};
+
 /// Run workflows via the GitHub Copilot harness.
 pub use github_copilot::GitHubCopilotHarness;
 
+/// Outcome of a harness health check.
+pub use health::HarnessHealth;
+
 /// Run workflows via the OpenCode harness.
 pub use opencode::OpencodeHarness;
 
+/// Tool permission policy applied when spawning harnesses.
+pub use permissions::PermissionPolicy;
+
 /// Core harness trait + configuration and result types.
-pub use types::{Harness, HarnessName, HarnessRunConfig, HarnessRunResult};
+pub use types::{Harness, HarnessName, HarnessRunConfig, HarnessRunResult, TokenUsage};
@@ -1,9 +1,13 @@
+use super::permissions::{OPENCODE_CONFIG_CONTENT_ENV, active_permissions};
 use super::streaming_cli::CliHarness;
-use super::types::{HarnessName, HarnessRunConfig};
+use super::types::{HarnessName, HarnessRunConfig, TokenUsage};
 
 /// Harness implementation that executes the `opencode` CLI (`opencode run`).
 ///
 /// Selected via `ito ralph --harness opencode`; requires the OpenCode CLI on PATH.
+/// Supports resuming a previous session via [`HarnessRunConfig::resume_session`].
+/// A [`HarnessRunConfig::permissions`] policy is passed as inline config in
+/// `OPENCODE_CONFIG_CONTENT`.
 ///
 /// # Examples
 ///
@@ -32,9 +36,96 @@ impl CliHarness for OpencodeHarness {
             args.push("-m".to_string());
             args.push(model.to_string());
         }
+        if let Some(session) = config.resume_session.as_deref() {
+            args.push("--session".to_string());
+            args.push(session.to_string());
+        }
         args.push(config.prompt.clone());
         args
     }
+
+    fn build_env(&self, config: &HarnessRunConfig) -> Vec<(String, String)> {
+        match active_permissions(config) {
+            Some(policy) => vec![(
+                OPENCODE_CONFIG_CONTENT_ENV.to_string(),
+                policy.opencode_config().to_string(),
+            )],
+            None => Vec::new(),
+        }
+    }
+
+    fn parse_session_id(&self, stdout: &str, stderr: &str) -> Option<String> {
+        last_session_id(stdout).or_else(|| last_session_id(stderr))
+    }
+
+    fn parse_usage(&self, stdout: &str) -> Option<TokenUsage> {
+        usage_from_events(stdout)
+    }
+}
+
+/// Sum token usage over the `step_finish` events of OpenCode JSON output.
+///
+/// With `--format json` every model step ends with a `step_finish` event whose
+/// `part.tokens` block counts that step (`input`, `output`, `cache.read`).
+/// Output without such events yields `None`.
+fn usage_from_events(stdout: &str) -> Option<TokenUsage> {
+    let mut total: Option<TokenUsage> = None;
+    for line in stdout.lines() {
+        let line = line.trim();
+        if !line.starts_with('{') {
+            continue;
+        }
+        let Ok(event) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        if event.get("type").and_then(serde_json::Value::as_str) != Some("step_finish") {
+            continue;
+        }
+        let Some(tokens) = event.pointer("/part/tokens") else {
+            continue;
+        };
+        let count = |pointer: &str| tokens.pointer(pointer).and_then(serde_json::Value::as_u64);
+        let (Some(input_tokens), Some(output_tokens)) = (count("/input"), count("/output")) else {
+            continue;
+        };
+        let step = TokenUsage {
+            input_tokens,
+            output_tokens,
+            cache_read_tokens: count("/cache/read").unwrap_or(0),
+        };
+        total = Some(total.unwrap_or_default().saturating_add(step));
+    }
+    total
+}
+
+/// Prefix of OpenCode session ids (e.g. `ses_3f9a2c1d7e`).
+const SESSION_ID_PREFIX: &str = "ses_";
+
+/// Find the last OpenCode session id mentioned in `output`.
+///
+/// OpenCode reports the session in its run output and structured events
+/// (`"sessionID":"ses_..."`); the last mention wins so a resumed run that
+/// forks a new session reports the new one.
+fn last_session_id(output: &str) -> Option<String> {
+    let mut found = None;
+    let mut rest = output;
+    while let Some(pos) = rest.find(SESSION_ID_PREFIX) {
+        let preceded_by_word_char = output[..output.len() - rest.len() + pos]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_');
+        let candidate = &rest[pos..];
+        let len = SESSION_ID_PREFIX.len()
+            + candidate[SESSION_ID_PREFIX.len()..]
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric())
+                .count();
+        if !preceded_by_word_char && len > SESSION_ID_PREFIX.len() {
+            found = Some(candidate[..len].to_string());
+        }
+        rest = &candidate[len..];
+    }
+    found
 }
 
 #[cfg(test)]
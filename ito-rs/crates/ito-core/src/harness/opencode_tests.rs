@@ -1,4 +1,5 @@
 use super::*;
+use crate::harness::PermissionPolicy;
 use std::collections::BTreeMap;
 
 enum Allow {
@@ -17,9 +18,15 @@ fn config(allow: Allow, model: Option<&str>) -> HarnessRunConfig {
         model: model.map(String::from),
         cwd: std::env::temp_dir(),
         env: BTreeMap::new(),
+        env_policy: Default::default(),
         interactive: false,
         allow_all,
         inactivity_timeout: None,
+        resume_session: None,
+        transcript_path: None,
+        permissions: None,
+        cancel: None,
+        prompt_estimate: None,
     }
 }
 
@@ -50,3 +57,92 @@ fn build_args_without_model() {
     let args = harness.build_args(&cfg);
     assert_eq!(args, vec!["run", "do stuff"]);
 }
+
+#[test]
+fn build_args_with_resume_session() {
+    let harness = OpencodeHarness;
+    let mut cfg = config(Allow::None, Some("gpt-4"));
+    cfg.resume_session = Some("ses_abc123".to_string());
+    let args = harness.build_args(&cfg);
+    assert_eq!(
+        args,
+        vec!["run", "-m", "gpt-4", "--session", "ses_abc123", "do stuff"]
+    );
+}
+
+#[test]
+fn build_env_passes_permission_policy_as_inline_config() {
+    let harness = OpencodeHarness;
+    let mut cfg = config(Allow::None, None);
+    assert!(harness.build_env(&cfg).is_empty());
+
+    cfg.permissions = Some(PermissionPolicy {
+        allow_paths: vec!["src/**".to_string()],
+        allow_commands: vec!["cargo test".to_string()],
+        ..PermissionPolicy::default()
+    });
+    let env = harness.build_env(&cfg);
+    assert_eq!(env.len(), 1);
+    assert_eq!(env[0].0, "OPENCODE_CONFIG_CONTENT");
+    let content: serde_json::Value = serde_json::from_str(&env[0].1).unwrap();
+    assert_eq!(
+        content,
+        serde_json::json!({
+            "permission": {
+                "edit": { "*": "deny", "src/**": "allow" },
+                "bash": { "*": "deny", "cargo test*": "allow" }
+            }
+        })
+    );
+    assert_eq!(harness.build_args(&cfg), vec!["run", "do stuff"]);
+}
+
+#[test]
+fn parse_session_id_finds_last_session_in_stdout() {
+    let harness = OpencodeHarness;
+    let stdout = "session ses_first1\n{\"type\":\"step\",\"sessionID\":\"ses_Second2\"}\n";
+    assert_eq!(
+        harness.parse_session_id(stdout, ""),
+        Some("ses_Second2".to_string())
+    );
+}
+
+#[test]
+fn parse_session_id_falls_back_to_stderr() {
+    let harness = OpencodeHarness;
+    assert_eq!(
+        harness.parse_session_id("no session here", "resuming ses_xyz\n"),
+        Some("ses_xyz".to_string())
+    );
+}
+
+#[test]
+fn parse_session_id_ignores_embedded_and_empty_prefixes() {
+    let harness = OpencodeHarness;
+    assert_eq!(harness.parse_session_id("uses_abc ses_ alone", ""), None);
+}
+
+const JSON_EVENTS_FIXTURE: &str = r#"{"type":"step_start","sessionID":"ses_abc","part":{"type":"step-start"}}
+{"type":"text","sessionID":"ses_abc","part":{"type":"text","text":"Working."}}
+{"type":"step_finish","sessionID":"ses_abc","part":{"type":"step-finish","tokens":{"input":900,"output":120,"reasoning":0,"cache":{"read":3000,"write":0}},"cost":0}}
+{"type":"step_finish","sessionID":"ses_abc","part":{"type":"step-finish","tokens":{"input":150,"output":80,"reasoning":0,"cache":{"read":3900,"write":0}},"cost":0}}
+"#;
+
+#[test]
+fn parse_usage_sums_step_finish_tokens() {
+    let harness = OpencodeHarness;
+    assert_eq!(
+        harness.parse_usage(JSON_EVENTS_FIXTURE),
+        Some(TokenUsage {
+            input_tokens: 1050,
+            output_tokens: 200,
+            cache_read_tokens: 6900,
+        })
+    );
+}
+
+#[test]
+fn parse_usage_is_unknown_for_plain_text_output() {
+    let harness = OpencodeHarness;
+    assert_eq!(harness.parse_usage("session ses_abc finished\n"), None);
+}
@@ -0,0 +1,142 @@
+//! Tool permission policy for unattended harness sessions.
+//!
+//! Without [`HarnessRunConfig::allow_all`](super::HarnessRunConfig::allow_all)
+//! most harnesses stop to ask before editing files or running commands, which
+//! blocks unattended Ralph loops. A [`PermissionPolicy`] sits between the two:
+//! it pre-approves edits under some paths and commands with some prefixes, and
+//! denies others. Each harness translates the policy into its own settings;
+//! harnesses without permission settings drop it (see
+//! [`PermissionPolicy::unsupported_warning`]).
+
+use super::types::{HarnessName, HarnessRunConfig};
+use serde_json::{Map, Value, json};
+
+/// Environment variable OpenCode reads inline JSON config from.
+pub const OPENCODE_CONFIG_CONTENT_ENV: &str = "OPENCODE_CONFIG_CONTENT";
+
+/// File-edit and command permissions granted to a harness session.
+///
+/// Path patterns are globs relative to the harness working directory; command
+/// patterns are command prefixes (`cargo test` allows `cargo test -p ito`).
+/// A deny entry wins over an allow entry with the same pattern.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PermissionPolicy {
+    /// Paths the agent may edit without asking.
+    pub allow_paths: Vec<String>,
+    /// Paths the agent must not edit.
+    pub deny_paths: Vec<String>,
+    /// Command prefixes the agent may run without asking.
+    pub allow_commands: Vec<String>,
+    /// Command prefixes the agent must not run.
+    pub deny_commands: Vec<String>,
+}
+
+impl PermissionPolicy {
+    /// True when the policy has no rules.
+    pub fn is_empty(&self) -> bool {
+        self.allow_paths.is_empty()
+            && self.deny_paths.is_empty()
+            && self.allow_commands.is_empty()
+            && self.deny_commands.is_empty()
+    }
+
+    /// Every rule as a short label, e.g. `allow edit src/**`.
+    pub fn rules(&self) -> Vec<String> {
+        let mut out = Vec::new();
+        for (verb, kind, patterns) in [
+            ("allow", "edit", &self.allow_paths),
+            ("deny", "edit", &self.deny_paths),
+            ("allow", "command", &self.allow_commands),
+            ("deny", "command", &self.deny_commands),
+        ] {
+            for pattern in patterns {
+                out.push(format!("{verb} {kind} {pattern}"));
+            }
+        }
+        out
+    }
+
+    /// Whether `harness` can enforce permission policies.
+    pub const fn supported_by(harness: HarnessName) -> bool {
+        matches!(harness, HarnessName::Opencode | HarnessName::Claude)
+    }
+
+    /// Warning listing the rules `harness` drops, or `None` when it enforces
+    /// the whole policy.
+    pub fn unsupported_warning(&self, harness: HarnessName) -> Option<String> {
+        if self.is_empty() || Self::supported_by(harness) {
+            return None;
+        }
+        Some(format!(
+            "harness `{harness}` does not support permission policies; dropped: {}",
+            self.rules().join(", ")
+        ))
+    }
+
+    /// OpenCode config (`permission.edit` / `permission.bash` pattern maps).
+    ///
+    /// An allow-list makes `*` default to `deny`; a deny-list alone makes it
+    /// default to `allow`, so unattended runs never stop at an `ask`.
+    pub fn opencode_config(&self) -> Value {
+        let mut permission = Map::new();
+        let commands: Vec<String> = self
+            .allow_commands
+            .iter()
+            .map(|c| format!("{c}*"))
+            .collect();
+        let denied: Vec<String> = self.deny_commands.iter().map(|c| format!("{c}*")).collect();
+        for (key, allow, deny) in [
+            ("edit", &self.allow_paths, &self.deny_paths),
+            ("bash", &commands, &denied),
+        ] {
+            if allow.is_empty() && deny.is_empty() {
+                continue;
+            }
+            let mut rules = Map::new();
+            let fallback = if allow.is_empty() { "allow" } else { "deny" };
+            rules.insert("*".to_string(), json!(fallback));
+            for pattern in allow {
+                rules.insert(pattern.clone(), json!("allow"));
+            }
+            for pattern in deny {
+                rules.insert(pattern.clone(), json!("deny"));
+            }
+            permission.insert(key.to_string(), Value::Object(rules));
+        }
+        json!({ "permission": permission })
+    }
+
+    /// Claude Code settings (`permissions.allow` / `permissions.deny` entries).
+    pub fn claude_settings(&self) -> Value {
+        let edit = |pattern: &String| format!("Edit({pattern})");
+        let bash = |prefix: &String| format!("Bash({prefix}:*)");
+        let allow: Vec<String> = self
+            .allow_paths
+            .iter()
+            .map(edit)
+            .chain(self.allow_commands.iter().map(bash))
+            .collect();
+        let deny: Vec<String> = self
+            .deny_paths
+            .iter()
+            .map(edit)
+            .chain(self.deny_commands.iter().map(bash))
+            .collect();
+        json!({ "permissions": { "allow": allow, "deny": deny } })
+    }
+}
+
+/// The policy a harness should enforce for `config`.
+///
+/// `None` when [`HarnessRunConfig::allow_all`] bypasses permissions or the
+/// policy is empty.
+pub(crate) fn active_permissions(config: &HarnessRunConfig) -> Option<&PermissionPolicy> {
+    if config.allow_all {
+        return None;
+    }
+    config.permissions.as_ref().filter(|p| !p.is_empty())
+}
+
+#[cfg(test)]
+#[path = "permissions_tests.rs"]
+mod permissions_tests;
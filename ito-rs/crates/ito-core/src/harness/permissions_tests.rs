@@ -0,0 +1,67 @@
+use super::*;
+
+fn policy() -> PermissionPolicy {
+    PermissionPolicy {
+        allow_paths: vec!["src/**".to_string()],
+        deny_paths: vec![".env".to_string()],
+        allow_commands: vec!["cargo test".to_string(), "git status".to_string()],
+        deny_commands: vec!["git push".to_string()],
+    }
+}
+
+#[test]
+fn opencode_config_maps_paths_to_edit_and_commands_to_bash() {
+    assert_eq!(
+        policy().opencode_config(),
+        json!({
+            "permission": {
+                "edit": { "*": "deny", "src/**": "allow", ".env": "deny" },
+                "bash": {
+                    "*": "deny",
+                    "cargo test*": "allow",
+                    "git status*": "allow",
+                    "git push*": "deny"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn opencode_deny_only_rules_default_to_allow() {
+    let policy = PermissionPolicy {
+        deny_commands: vec!["rm".to_string()],
+        ..PermissionPolicy::default()
+    };
+    assert_eq!(
+        policy.opencode_config(),
+        json!({ "permission": { "bash": { "*": "allow", "rm*": "deny" } } })
+    );
+}
+
+#[test]
+fn claude_settings_use_edit_and_bash_rules() {
+    assert_eq!(
+        policy().claude_settings(),
+        json!({
+            "permissions": {
+                "allow": ["Edit(src/**)", "Bash(cargo test:*)", "Bash(git status:*)"],
+                "deny": ["Edit(.env)", "Bash(git push:*)"]
+            }
+        })
+    );
+}
+
+#[test]
+fn unsupported_harness_warning_lists_dropped_rules() {
+    assert_eq!(policy().unsupported_warning(HarnessName::Opencode), None);
+    assert_eq!(policy().unsupported_warning(HarnessName::Claude), None);
+    assert_eq!(
+        PermissionPolicy::default().unsupported_warning(HarnessName::Codex),
+        None
+    );
+    assert_eq!(
+        policy().unsupported_warning(HarnessName::Codex).unwrap(),
+        "harness `codex` does not support permission policies; dropped: allow edit src/**, deny edit .env, allow command cargo test, allow command git status, deny command git push"
+    );
+}
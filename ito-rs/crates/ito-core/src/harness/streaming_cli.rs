@@ -1,6 +1,12 @@
-use super::types::{Harness, HarnessName, HarnessRunConfig, HarnessRunResult};
+use super::cancel::{CANCEL_GRACE_PERIOD, CancellationToken};
+use super::env_policy::parent_env;
+use super::health::{self, HarnessHealth};
+use super::transcript::record_transcript;
+use super::types::{Harness, HarnessName, HarnessRunConfig, HarnessRunResult, TokenUsage};
+use crate::process::{SystemProcessRunner, wait_with_resources};
 use miette::{Result, miette};
 use std::io::Write;
+use std::path::Path;
 use std::process::{Command, Stdio};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -49,6 +55,47 @@ pub trait CliHarness: std::fmt::Debug {
     /// Called once per `Harness::run`. The returned args are passed directly
     /// to the binary — the trait handles spawning and streaming.
     fn build_args(&self, config: &HarnessRunConfig) -> Vec<String>;
+
+    /// Extra environment variables for a single invocation.
+    ///
+    /// Applied after [`HarnessRunConfig::env`]; the default adds none.
+    fn build_env(&self, _config: &HarnessRunConfig) -> Vec<(String, String)> {
+        Vec::new()
+    }
+
+    /// Extract the session id from a finished run's output.
+    ///
+    /// Harnesses that can resume sessions (see
+    /// [`HarnessRunConfig::resume_session`]) override this; the default
+    /// reports no session.
+    fn parse_session_id(&self, _stdout: &str, _stderr: &str) -> Option<String> {
+        None
+    }
+
+    /// Extract the assistant's final message from a finished run's stdout.
+    ///
+    /// Harnesses whose output is a structured event stream override this so
+    /// completion detection can scan the message text instead of raw events;
+    /// the default reports no message and callers fall back to stdout.
+    fn extract_final_message(&self, _stdout: &str) -> Option<String> {
+        None
+    }
+
+    /// Extract token usage from a finished run's stdout.
+    ///
+    /// Harnesses whose structured output carries usage blocks override this;
+    /// the default reports no usage, which cost reporting treats as unknown.
+    fn parse_usage(&self, _stdout: &str) -> Option<TokenUsage> {
+        None
+    }
+
+    /// Arguments for a quick native probe run before the health-check prompt.
+    ///
+    /// The default is `--version`, which catches a missing or broken binary
+    /// without a model call; `None` skips the probe.
+    fn version_args(&self) -> Option<Vec<String>> {
+        Some(vec!["--version".to_string()])
+    }
 }
 
 /// Blanket impl: every [`CliHarness`] is automatically a [`Harness`].
@@ -58,8 +105,19 @@ impl<T: CliHarness> Harness for T {
     }
 
     fn run(&mut self, config: &HarnessRunConfig) -> Result<HarnessRunResult> {
+        let parent = parent_env();
+        config
+            .env_policy
+            .check_required(self.harness_name(), &parent, &config.env)
+            .map_err(|e| miette!("{e}"))?;
         let args = self.build_args(config);
-        run_streaming_cli(self.binary(), &args, config)
+        let env = self.build_env(config);
+        let mut result = run_streaming_cli(self.binary(), &args, &env, config)?;
+        result.session_id = self.parse_session_id(&result.stdout, &result.stderr);
+        result.final_message = self.extract_final_message(&result.stdout);
+        result.usage = self.parse_usage(&result.stdout);
+        record_transcript(self.harness_name(), config, &result);
+        Ok(result)
     }
 
     fn stop(&mut self) {
@@ -69,6 +127,10 @@ impl<T: CliHarness> Harness for T {
     fn streams_output(&self) -> bool {
         true
     }
+
+    fn health_check(&mut self, cwd: &Path, model: Option<&str>) -> HarnessHealth {
+        health::check_cli(&*self, &SystemProcessRunner, cwd, model)
+    }
 }
 
 /// Which standard stream a pipe should forward output to.
@@ -85,17 +147,32 @@ enum StreamTarget {
 /// behaviour: output is forwarded to the terminal in real time, an inactivity
 /// timer kills the process when it stalls, and incomplete UTF-8 sequences at
 /// chunk boundaries are handled correctly.
+///
+/// When [`HarnessRunConfig::cancel`] is set the child runs in its own process
+/// group, so cancellation (and a terminal Ctrl-C handled by the caller) reaches
+/// every process the harness started, not only the harness binary.
 fn run_streaming_cli(
     binary: &str,
     args: &[String],
+    env: &[(String, String)],
     config: &HarnessRunConfig,
 ) -> Result<HarnessRunResult> {
     let mut cmd = Command::new(binary);
     cmd.args(args);
     cmd.current_dir(&config.cwd);
+    if let Some(filtered) = config.env_policy.filter(&parent_env()) {
+        cmd.env_clear();
+        cmd.envs(filtered);
+    }
     cmd.envs(&config.env);
+    cmd.envs(env.iter().map(|(k, v)| (k, v)));
     cmd.stdout(Stdio::piped());
     cmd.stderr(Stdio::piped());
+    #[cfg(unix)]
+    if config.cancel.is_some() {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
 
     let start = Instant::now();
     let mut child = cmd
@@ -108,6 +185,7 @@ fn run_streaming_cli(
 
     let last_activity = Arc::new(std::sync::Mutex::new(Instant::now()));
     let timed_out = Arc::new(AtomicBool::new(false));
+    let cancelled = Arc::new(AtomicBool::new(false));
     let done = Arc::new(AtomicBool::new(false));
 
     let last_activity_stdout = Arc::clone(&last_activity);
@@ -125,7 +203,10 @@ fn run_streaming_cli(
         .unwrap_or(DEFAULT_INACTIVITY_TIMEOUT);
     let last_activity_monitor = Arc::clone(&last_activity);
     let timed_out_monitor = Arc::clone(&timed_out);
+    let cancelled_monitor = Arc::clone(&cancelled);
     let done_monitor = Arc::clone(&done);
+    let cancel = config.cancel.clone();
+    let kill_group = config.cancel.is_some();
 
     let monitor_handle = thread::spawn(move || {
         monitor_timeout(
@@ -134,11 +215,15 @@ fn run_streaming_cli(
             &last_activity_monitor,
             &timed_out_monitor,
             &done_monitor,
+            MonitorCancel {
+                token: cancel.as_ref(),
+                cancelled: &cancelled_monitor,
+                kill_group,
+            },
         )
     });
 
-    let status = child
-        .wait()
+    let (status, resources) = wait_with_resources(&mut child, start)
         .map_err(|e| miette!("Failed to wait for {binary}: {e}"))?;
     done.store(true, Ordering::SeqCst);
 
@@ -148,8 +233,9 @@ fn run_streaming_cli(
 
     let duration = start.elapsed();
     let was_timed_out = timed_out.load(Ordering::SeqCst);
+    let was_cancelled = cancelled.load(Ordering::SeqCst);
 
-    let exit_code = if was_timed_out {
+    let exit_code = if was_timed_out || was_cancelled {
         -1
     } else {
         exit_code_from_status(&status)
@@ -161,6 +247,11 @@ fn run_streaming_cli(
         exit_code,
         duration,
         timed_out: was_timed_out,
+        cancelled: was_cancelled,
+        session_id: None,
+        final_message: None,
+        usage: None,
+        resources,
     })
 }
 
@@ -276,20 +367,32 @@ fn stream_pipe(
     collected
 }
 
-/// Monitors a child process for inactivity and forcefully terminates it if no activity occurs within `timeout`.
+/// Cancellation inputs for [`monitor_timeout`].
+struct MonitorCancel<'a> {
+    /// Token checked on every monitor tick.
+    token: Option<&'a CancellationToken>,
+    /// Set to `true` when the token triggered termination.
+    cancelled: &'a AtomicBool,
+    /// Whether the child leads its own process group, so signals go to the group.
+    kill_group: bool,
+}
+
+/// Monitors a child process for inactivity and cancellation, terminating it when either occurs.
 ///
 /// Periodically checks the elapsed time since `last_activity`; if the elapsed time meets or exceeds
-/// `timeout`, prints an inactivity message to stderr, sets `timed_out` to `true`, and attempts to
-/// kill the process with `child_id` (platform-specific: `kill -9` on Unix, `taskkill /F /PID` on Windows).
+/// `timeout`, prints an inactivity message to stderr, sets `timed_out` to `true`, and kills the
+/// process. When the cancellation token fires, sets `cancel.cancelled`, asks the process to exit
+/// (`SIGTERM` on Unix), and kills it if it is still running after [`CANCEL_GRACE_PERIOD`].
 /// The monitor exits early if `done` becomes `true` or if `last_activity` cannot be locked.
 ///
 /// # Parameters
 ///
-/// - `child_id`: process identifier of the child to terminate on timeout.
+/// - `child_id`: process identifier of the child to terminate.
 /// - `timeout`: duration of allowed inactivity before termination.
 /// - `last_activity`: mutex-protected `Instant` updated by output-streaming threads on each read.
 /// - `timed_out`: atomic flag set to `true` when a timeout-triggered termination occurs.
 /// - `done`: atomic flag that, when set to `true`, stops the monitor loop.
+/// - `cancel`: cancellation token and the flag recording a cancellation-triggered termination.
 ///
 /// # Examples
 ///
@@ -298,19 +401,15 @@ fn stream_pipe(
 /// use std::time::{Duration, Instant};
 /// use std::thread;
 ///
-/// // Prepare shared state
 /// let last_activity = Arc::new(Mutex::new(Instant::now()));
 /// let timed_out = Arc::new(AtomicBool::new(false));
+/// let cancelled = Arc::new(AtomicBool::new(false));
 /// let done = Arc::new(AtomicBool::new(false));
 ///
-/// // Clone for the monitor thread
-/// let la = Arc::clone(&last_activity);
-/// let to = Arc::clone(&timed_out);
-/// let dn = Arc::clone(&done);
-///
-/// // Spawn the monitor in a thread (uses a dummy child id 0 for example)
+/// let (la, to, ca, dn) = (last_activity.clone(), timed_out.clone(), cancelled.clone(), done.clone());
 /// let handle = thread::spawn(move || {
-///     super::monitor_timeout(0, Duration::from_millis(10), &la.lock().unwrap(), &to, &dn);
+///     let cancel = super::MonitorCancel { token: None, cancelled: &ca, kill_group: false };
+///     super::monitor_timeout(0, Duration::from_millis(10), &la, &to, &dn, cancel);
 /// });
 ///
 /// // Signal completion to stop the monitor and join
@@ -323,8 +422,9 @@ fn monitor_timeout(
     last_activity: &std::sync::Mutex<Instant>,
     timed_out: &AtomicBool,
     done: &AtomicBool,
+    cancel: MonitorCancel<'_>,
 ) {
-    let check_interval = Duration::from_secs(1);
+    let check_interval = Duration::from_millis(200);
 
     loop {
         thread::sleep(check_interval);
@@ -333,32 +433,74 @@ fn monitor_timeout(
             break;
         }
 
+        if cancel.token.is_some_and(CancellationToken::is_cancelled) {
+            ito_config::notice!("\n=== Cancellation requested, stopping harness process... ===\n");
+            cancel.cancelled.store(true, Ordering::SeqCst);
+            signal_child(child_id, cancel.kill_group, false);
+
+            let deadline = Instant::now() + CANCEL_GRACE_PERIOD;
+            while !done.load(Ordering::SeqCst) && Instant::now() < deadline {
+                thread::sleep(check_interval);
+            }
+            if !done.load(Ordering::SeqCst) {
+                signal_child(child_id, cancel.kill_group, true);
+            }
+            break;
+        }
+
         let elapsed = match last_activity.lock() {
             Ok(last) => last.elapsed(),
             Err(_poisoned) => break,
         };
 
         if elapsed >= timeout {
-            eprintln!(
+            ito_config::notice!(
                 "\n=== Inactivity timeout ({:?}) reached, killing process... ===\n",
                 timeout
             );
             timed_out.store(true, Ordering::SeqCst);
+            signal_child(child_id, cancel.kill_group, true);
+            break;
+        }
+    }
+}
 
-            #[cfg(unix)]
-            {
-                let _ = std::process::Command::new("kill")
-                    .args(["-9", &child_id.to_string()])
-                    .status();
+/// Ask the child (or its whole process group) to exit, or kill it when `force` is set.
+///
+/// Signals directly on Unix and uses `taskkill` on Windows. A child that has
+/// already exited (`ESRCH`) is not an error; other failures are logged.
+fn signal_child(child_id: u32, group: bool, force: bool) {
+    #[cfg(unix)]
+    {
+        let Ok(pid) = libc::pid_t::try_from(child_id) else {
+            return;
+        };
+        let signal = if force { libc::SIGKILL } else { libc::SIGTERM };
+        // SAFETY: `kill`/`killpg` take plain integers and only send a signal.
+        let rc = unsafe {
+            if group {
+                libc::killpg(pid, signal)
+            } else {
+                libc::kill(pid, signal)
             }
-            #[cfg(windows)]
-            {
-                let _ = std::process::Command::new("taskkill")
-                    .args(["/F", "/PID", &child_id.to_string()])
-                    .status();
+        };
+        if rc != 0 {
+            let err = std::io::Error::last_os_error();
+            if err.raw_os_error() != Some(libc::ESRCH) {
+                tracing::warn!("failed to signal harness process {child_id}: {err}");
             }
-
-            break;
         }
     }
+    #[cfg(windows)]
+    {
+        let pid = child_id.to_string();
+        let mut args = vec!["/PID", pid.as_str()];
+        if group {
+            args.push("/T");
+        }
+        if force {
+            args.push("/F");
+        }
+        let _ = std::process::Command::new("taskkill").args(args).status();
+    }
 }
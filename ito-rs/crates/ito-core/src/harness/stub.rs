@@ -1,6 +1,9 @@
+use super::health::HarnessHealth;
+use super::transcript::record_transcript;
 use super::types::{Harness, HarnessName, HarnessRunConfig, HarnessRunResult};
 use miette::{Result, miette};
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
@@ -17,6 +20,17 @@ pub struct StubStep {
     #[serde(default)]
     /// Exit code for this step.
     pub exit_code: i32,
+    #[serde(default)]
+    /// Files to write before returning, keyed by path relative to the run's
+    /// working directory.
+    ///
+    /// Lets scripts stand in for the side effects a real agent would have.
+    pub writes: BTreeMap<String, String>,
+    #[serde(default)]
+    /// How long the step takes, in milliseconds, after writing its files.
+    ///
+    /// The wait ends early when the run is cancelled.
+    pub delay_ms: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -59,6 +73,8 @@ impl StubHarness {
             stdout: "<promise>COMPLETE</promise>\n".to_string(),
             stderr: String::new(),
             exit_code: 0,
+            writes: Default::default(),
+            delay_ms: 0,
         }]))
     }
 
@@ -81,24 +97,62 @@ impl Harness for StubHarness {
         HarnessName::Stub
     }
 
-    fn run(&mut self, _config: &HarnessRunConfig) -> Result<HarnessRunResult> {
+    fn run(&mut self, config: &HarnessRunConfig) -> Result<HarnessRunResult> {
         let started = Instant::now();
         let step = self
             .next_step()
             .ok_or_else(|| miette!("Stub harness has no steps"))?;
 
-        Ok(HarnessRunResult {
+        for (rel, contents) in &step.writes {
+            let path = config.cwd.join(rel);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| miette!("Failed to create {p}: {e}", p = parent.display()))?;
+            }
+            fs::write(&path, contents)
+                .map_err(|e| miette!("Failed to write {p}: {e}", p = path.display()))?;
+        }
+
+        let cancelled = wait_for_delay(step.delay_ms, config);
+        let result = HarnessRunResult {
             stdout: step.stdout,
             stderr: step.stderr,
-            exit_code: step.exit_code,
+            exit_code: if cancelled { -1 } else { step.exit_code },
             duration: started.elapsed().max(Duration::from_millis(1)),
             timed_out: false,
-        })
+            cancelled,
+            session_id: None,
+            final_message: None,
+            usage: None,
+            resources: Default::default(),
+        };
+        record_transcript(HarnessName::Stub, config, &result);
+        Ok(result)
     }
 
     fn stop(&mut self) {
         // No-op
     }
+
+    /// The stub needs no binary or credentials, so it is always healthy.
+    fn health_check(&mut self, _cwd: &Path, _model: Option<&str>) -> HarnessHealth {
+        HarnessHealth::Ok
+    }
+}
+
+/// Sleep for `delay_ms`, returning `true` if the run was cancelled meanwhile.
+fn wait_for_delay(delay_ms: u64, config: &HarnessRunConfig) -> bool {
+    let deadline = Instant::now() + Duration::from_millis(delay_ms);
+    loop {
+        if config.cancel.as_ref().is_some_and(|c| c.is_cancelled()) {
+            return true;
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return false;
+        }
+        std::thread::sleep((deadline - now).min(Duration::from_millis(20)));
+    }
 }
 
 #[cfg(test)]
@@ -7,9 +7,15 @@ fn dummy_config() -> HarnessRunConfig {
         model: None,
         cwd: std::env::temp_dir(),
         env: BTreeMap::new(),
+        env_policy: Default::default(),
         interactive: false,
         allow_all: false,
         inactivity_timeout: None,
+        resume_session: None,
+        transcript_path: None,
+        permissions: None,
+        cancel: None,
+        prompt_estimate: None,
     }
 }
 
@@ -19,6 +25,8 @@ fn name_returns_stub() {
         stdout: "test".to_string(),
         stderr: String::new(),
         exit_code: 0,
+        writes: Default::default(),
+        delay_ms: 0,
     }]);
     assert_eq!(stub.name(), HarnessName::Stub);
 }
@@ -29,6 +37,8 @@ fn streams_output_returns_false() {
         stdout: "test".to_string(),
         stderr: String::new(),
         exit_code: 0,
+        writes: Default::default(),
+        delay_ms: 0,
     }]);
     assert!(!stub.streams_output());
 }
@@ -39,6 +49,8 @@ fn run_sets_timed_out_false() {
         stdout: "test".to_string(),
         stderr: String::new(),
         exit_code: 0,
+        writes: Default::default(),
+        delay_ms: 0,
     }]);
     let config = dummy_config();
     let result = stub.run(&config).unwrap();
@@ -51,6 +63,8 @@ fn run_sets_nonzero_duration() {
         stdout: "test".to_string(),
         stderr: String::new(),
         exit_code: 0,
+        writes: Default::default(),
+        delay_ms: 0,
     }]);
     let config = dummy_config();
     let result = stub.run(&config).unwrap();
@@ -70,3 +84,21 @@ fn from_env_or_default_with_explicit_path() {
     let result = stub.run(&config).unwrap();
     assert_eq!(result.stdout, "hello");
 }
+
+#[test]
+fn run_writes_scripted_files_relative_to_cwd() {
+    let td = tempfile::tempdir().unwrap();
+    let json = r##"[{"stdout": "done", "writes": {"out/design.md": "# Design\n"}}]"##;
+    let steps: Vec<StubStep> = serde_json::from_str(json).unwrap();
+    let mut stub = StubHarness::new(steps);
+    let config = HarnessRunConfig {
+        cwd: td.path().to_path_buf(),
+        ..dummy_config()
+    };
+
+    stub.run(&config).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(td.path().join("out/design.md")).unwrap(),
+        "# Design\n"
+    );
+}
@@ -0,0 +1,174 @@
+//! Markdown transcripts of harness runs.
+//!
+//! When [`HarnessRunConfig::transcript_path`] is set, a harness writes the
+//! prompt and captured output of the run to that file, preceded by a short
+//! header (harness, model, exit code, duration, and the prompt size estimate
+//! when one was made). Ralph stores one transcript per iteration under
+//! `<itoDir>/.state/transcripts/<run-id>/<iteration>.md`.
+
+use std::path::{Path, PathBuf};
+
+use super::types::{HarnessName, HarnessRunConfig, HarnessRunResult};
+use crate::errors::{CoreError, CoreResult};
+
+/// Transcript directory, relative to the Ito directory.
+pub const TRANSCRIPTS_DIR_REL: &str = ".state/transcripts";
+
+/// Note recorded in place of output for interactive runs.
+pub const INTERACTIVE_PLACEHOLDER: &str =
+    "_Interactive run: output went directly to the terminal and was not captured._";
+
+/// A transcript file stored for a run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptEntry {
+    /// Iteration number the transcript was recorded for.
+    pub iteration: u32,
+    /// Absolute path of the transcript file.
+    pub path: PathBuf,
+}
+
+/// Return the directory holding transcripts for every run.
+pub fn transcripts_root(ito_path: &Path) -> PathBuf {
+    ito_path.join(TRANSCRIPTS_DIR_REL)
+}
+
+/// Return the transcript directory for a run (change id or run id).
+pub fn transcripts_dir(ito_path: &Path, run_id: &str) -> PathBuf {
+    transcripts_root(ito_path).join(run_id)
+}
+
+/// Return the transcript path for one iteration of a run.
+pub fn transcript_path(ito_path: &Path, run_id: &str, iteration: u32) -> PathBuf {
+    transcripts_dir(ito_path, run_id).join(format!("{iteration}.md"))
+}
+
+/// Render a transcript document for a finished run.
+///
+/// Interactive runs cannot be teed, so their output sections hold
+/// [`INTERACTIVE_PLACEHOLDER`] instead of captured text.
+pub fn render_transcript(
+    harness: HarnessName,
+    config: &HarnessRunConfig,
+    result: &HarnessRunResult,
+) -> String {
+    let recorded = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    let model = config.model.as_deref().unwrap_or("(default)");
+    let mut out = format!(
+        "# Harness Transcript\n\n- Harness: {harness}\n- Model: {model}\n- Exit code: {code}\n- Duration: {secs:.1}s\n- Recorded: {recorded}\n",
+        harness = harness.as_str(),
+        code = result.exit_code,
+        secs = result.duration.as_secs_f64(),
+    );
+    if result.timed_out {
+        out.push_str("- Timed out: yes\n");
+    }
+    if let Some(estimate) = &config.prompt_estimate {
+        out.push_str(&format!(
+            "- Prompt tokens (estimated): {}\n",
+            estimate.tokens
+        ));
+        if let Some(window) = estimate.context_window {
+            out.push_str(&format!("- Context window: {window} tokens\n"));
+        }
+    }
+
+    push_block(&mut out, "Prompt", &config.prompt);
+    if config.interactive {
+        out.push_str("\n## Output\n\n");
+        out.push_str(INTERACTIVE_PLACEHOLDER);
+        out.push('\n');
+    } else {
+        push_block(&mut out, "Stdout", &result.stdout);
+        push_block(&mut out, "Stderr", &result.stderr);
+    }
+    out
+}
+
+/// Write a transcript for a finished run to `path`, creating parent directories.
+pub fn write_transcript(
+    path: &Path,
+    harness: HarnessName,
+    config: &HarnessRunConfig,
+    result: &HarnessRunResult,
+) -> CoreResult<()> {
+    if let Some(parent) = path.parent() {
+        ito_common::io::create_dir_all_std(parent)
+            .map_err(|e| CoreError::io(format!("creating {}", parent.display()), e))?;
+    }
+    ito_common::io::write_std(path, render_transcript(harness, config, result))
+        .map_err(|e| CoreError::io(format!("writing {}", path.display()), e))
+}
+
+/// Write the transcript requested by `config`, if any.
+///
+/// Failures are reported as a warning on stderr: a missing transcript should
+/// never fail the harness run itself.
+pub fn record_transcript(
+    harness: HarnessName,
+    config: &HarnessRunConfig,
+    result: &HarnessRunResult,
+) {
+    let Some(path) = config.transcript_path.as_deref() else {
+        return;
+    };
+    if let Err(err) = write_transcript(path, harness, config, result) {
+        ito_config::notice!("Warning: failed to write harness transcript: {err}");
+    }
+}
+
+/// List the transcripts recorded for a run, ordered by iteration.
+pub fn list_transcripts(ito_path: &Path, run_id: &str) -> CoreResult<Vec<TranscriptEntry>> {
+    let dir = transcripts_dir(ito_path, run_id);
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(CoreError::io(format!("reading {}", dir.display()), e)),
+    };
+
+    let mut out = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| CoreError::io(format!("reading {}", dir.display()), e))?;
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("md") {
+            continue;
+        }
+        let Some(iteration) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        out.push(TranscriptEntry { iteration, path });
+    }
+    out.sort_by_key(|entry| entry.iteration);
+    Ok(out)
+}
+
+/// Read the transcript recorded for one iteration of a run.
+pub fn read_transcript(ito_path: &Path, run_id: &str, iteration: u32) -> CoreResult<String> {
+    let path = transcript_path(ito_path, run_id, iteration);
+    match ito_common::io::read_to_string_std(&path) {
+        Ok(contents) => Ok(contents),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(CoreError::not_found(format!(
+            "No transcript for iteration {iteration} of '{run_id}'"
+        ))),
+        Err(e) => Err(CoreError::io(format!("reading {}", path.display()), e)),
+    }
+}
+
+fn push_block(out: &mut String, title: &str, body: &str) {
+    // Use a fence longer than any backtick run in the body so it stays intact.
+    let longest_run = body.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run.max(2) + 1);
+    out.push_str(&format!("\n## {title}\n\n{fence}text\n{body}"));
+    if !body.ends_with('\n') {
+        out.push('\n');
+    }
+    out.push_str(&fence);
+    out.push('\n');
+}
+
+#[cfg(test)]
+#[path = "transcript_tests.rs"]
+mod transcript_tests;
@@ -0,0 +1,105 @@
+use super::*;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+fn config(prompt: &str) -> HarnessRunConfig {
+    HarnessRunConfig {
+        prompt: prompt.to_string(),
+        model: None,
+        cwd: std::env::temp_dir(),
+        env: BTreeMap::new(),
+        env_policy: Default::default(),
+        interactive: false,
+        allow_all: false,
+        inactivity_timeout: None,
+        resume_session: None,
+        transcript_path: None,
+        permissions: None,
+        cancel: None,
+        prompt_estimate: None,
+    }
+}
+
+fn result(stdout: &str) -> HarnessRunResult {
+    HarnessRunResult {
+        stdout: stdout.to_string(),
+        stderr: String::new(),
+        exit_code: 1,
+        duration: Duration::from_millis(2500),
+        timed_out: true,
+        cancelled: false,
+        session_id: None,
+        final_message: None,
+        usage: None,
+        resources: Default::default(),
+    }
+}
+
+#[test]
+fn render_transcript_includes_header_fields() {
+    let text = render_transcript(HarnessName::Claude, &config("p"), &result("out"));
+    assert!(text.contains("- Harness: claude"));
+    assert!(text.contains("- Model: (default)"));
+    assert!(text.contains("- Exit code: 1"));
+    assert!(text.contains("- Duration: 2.5s"));
+    assert!(text.contains("- Timed out: yes"));
+}
+
+#[test]
+fn render_transcript_records_the_prompt_estimate() {
+    let text = render_transcript(HarnessName::Stub, &config("p"), &result("out"));
+    assert!(!text.contains("Prompt tokens"));
+
+    let mut cfg = config("p");
+    cfg.prompt_estimate = Some(crate::harness::PromptEstimate {
+        tokens: 1200,
+        context_window: Some(1000),
+    });
+    let text = render_transcript(HarnessName::Stub, &cfg, &result("out"));
+    assert!(text.contains("- Prompt tokens (estimated): 1200\n"));
+    assert!(text.contains("- Context window: 1000 tokens\n"));
+}
+
+#[test]
+fn render_transcript_fences_survive_backticks_in_output() {
+    let text = render_transcript(
+        HarnessName::Stub,
+        &config("p"),
+        &result("```rust\nfn main() {}\n```\n"),
+    );
+    assert!(text.contains("````text\n```rust"));
+}
+
+#[test]
+fn transcript_path_uses_run_and_iteration() {
+    let path = transcript_path(Path::new("/repo/.ito"), "001-01_demo", 7);
+    assert_eq!(
+        path,
+        PathBuf::from("/repo/.ito/.state/transcripts/001-01_demo/7.md")
+    );
+}
+
+#[test]
+fn list_transcripts_orders_by_iteration_and_ignores_other_files() {
+    let td = tempfile::tempdir().unwrap();
+    let dir = transcripts_dir(td.path(), "run");
+    std::fs::create_dir_all(&dir).unwrap();
+    for name in ["10.md", "2.md", "notes.txt", "draft.md"] {
+        std::fs::write(dir.join(name), "x").unwrap();
+    }
+
+    let iterations: Vec<u32> = list_transcripts(td.path(), "run")
+        .unwrap()
+        .into_iter()
+        .map(|entry| entry.iteration)
+        .collect();
+    assert_eq!(iterations, vec![2, 10]);
+    assert!(list_transcripts(td.path(), "missing").unwrap().is_empty());
+}
+
+#[test]
+fn read_transcript_reports_missing_iteration() {
+    let td = tempfile::tempdir().unwrap();
+    let err = read_transcript(td.path(), "run", 1).unwrap_err();
+    assert!(matches!(err, CoreError::NotFound(_)), "{err}");
+}
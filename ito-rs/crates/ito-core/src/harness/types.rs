@@ -1,7 +1,13 @@
+use super::budget::PromptEstimate;
+use super::cancel::CancellationToken;
+use super::env_policy::HarnessEnvPolicy;
+use super::health::{self, HarnessHealth};
+use super::permissions::PermissionPolicy;
+use crate::process::ResourceUsage;
 use miette::Result;
 use std::collections::BTreeMap;
 use std::fmt;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::time::Duration;
 
@@ -47,6 +53,16 @@ impl HarnessName {
         ]
         .into_iter()
     }
+
+    /// Variables this harness needs when an environment policy is configured
+    /// without an explicit `required` list.
+    pub const fn default_required_env(self) -> &'static [&'static str] {
+        match self {
+            HarnessName::Claude => &["ANTHROPIC_API_KEY"],
+            HarnessName::Codex => &["OPENAI_API_KEY"],
+            HarnessName::Opencode | HarnessName::GithubCopilot | HarnessName::Stub => &[],
+        }
+    }
 }
 
 impl fmt::Display for HarnessName {
@@ -60,11 +76,17 @@ impl fmt::Display for HarnessName {
 pub struct HarnessNameParseError {
     /// The raw value that could not be parsed.
     pub input: String,
+    /// Nearest user-facing harness names, closest first.
+    pub suggestions: Vec<String>,
 }
 
 impl fmt::Display for HarnessNameParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Unknown harness name: {}", self.input)
+        write!(f, "Unknown harness name: {}", self.input)?;
+        if !self.suggestions.is_empty() {
+            write!(f, "\nDid you mean: {}?", self.suggestions.join(", "))?;
+        }
+        Ok(())
     }
 }
 
@@ -80,9 +102,15 @@ impl FromStr for HarnessName {
             "codex" => Ok(HarnessName::Codex),
             "copilot" | "github-copilot" => Ok(HarnessName::GithubCopilot),
             "stub" => Ok(HarnessName::Stub),
-            other => Err(HarnessNameParseError {
-                input: other.to_string(),
-            }),
+            other => {
+                let names: Vec<String> = HarnessName::user_facing()
+                    .map(|name| name.as_str().to_string())
+                    .collect();
+                Err(HarnessNameParseError {
+                    input: other.to_string(),
+                    suggestions: crate::nearest_matches(other, &names, 3),
+                })
+            }
         }
     }
 }
@@ -97,13 +125,38 @@ pub struct HarnessRunConfig {
     /// Working directory for the harness process.
     pub cwd: PathBuf,
     /// Environment variables to set for the harness process.
+    ///
+    /// These are applied after [`HarnessRunConfig::env_policy`] filtering.
     pub env: BTreeMap<String, String>,
+    /// Which parent environment variables the harness process inherits.
+    pub env_policy: HarnessEnvPolicy,
     /// Whether the invocation should run in interactive mode.
     pub interactive: bool,
     /// Whether tool approval and permission prompts should be bypassed.
     pub allow_all: bool,
     /// Inactivity timeout - if no output is received for this duration, the harness should terminate.
     pub inactivity_timeout: Option<Duration>,
+    /// Session id to resume instead of starting a new session.
+    ///
+    /// Harnesses without session support ignore this.
+    pub resume_session: Option<String>,
+    /// Where to write a markdown transcript of the run, if anywhere.
+    ///
+    /// See [`crate::harness::transcript`] for the file layout.
+    pub transcript_path: Option<PathBuf>,
+    /// Tool permissions for the session when [`HarnessRunConfig::allow_all`] is off.
+    ///
+    /// Harnesses without permission settings ignore this.
+    pub permissions: Option<PermissionPolicy>,
+    /// Token that stops the run early when cancelled.
+    ///
+    /// CLI harnesses terminate their process group; the stub harness stops
+    /// waiting. Either way the result is flagged [`HarnessRunResult::cancelled`].
+    pub cancel: Option<CancellationToken>,
+    /// Estimated prompt size, recorded in the transcript header when set.
+    ///
+    /// See [`crate::harness::budget`].
+    pub prompt_estimate: Option<PromptEstimate>,
 }
 
 #[derive(Debug, Clone)]
@@ -119,6 +172,45 @@ pub struct HarnessRunResult {
     pub duration: Duration,
     /// True if the harness was terminated due to inactivity timeout.
     pub timed_out: bool,
+    /// True if the run was stopped through [`HarnessRunConfig::cancel`].
+    pub cancelled: bool,
+    /// Session id reported by the harness, when it supports resuming sessions.
+    pub session_id: Option<String>,
+    /// Final assistant message extracted from a structured output stream.
+    ///
+    /// Harnesses that emit event streams (e.g. JSON lines) surface the
+    /// assistant's last text here so completion detection does not have to
+    /// scan raw event payloads. `None` means only `stdout` is available.
+    pub final_message: Option<String>,
+    /// Token usage reported by the harness, when its output includes it.
+    pub usage: Option<TokenUsage>,
+    /// Resources the harness process consumed.
+    pub resources: ResourceUsage,
+}
+
+/// Token counts reported by a harness run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TokenUsage {
+    /// Prompt tokens billed at the input rate (excluding cache reads).
+    pub input_tokens: u64,
+    /// Generated tokens.
+    pub output_tokens: u64,
+    /// Prompt tokens served from the provider's prompt cache.
+    #[serde(default)]
+    pub cache_read_tokens: u64,
+}
+
+impl TokenUsage {
+    /// Field-wise saturating sum of two usage records.
+    pub fn saturating_add(self, other: TokenUsage) -> TokenUsage {
+        TokenUsage {
+            input_tokens: self.input_tokens.saturating_add(other.input_tokens),
+            output_tokens: self.output_tokens.saturating_add(other.output_tokens),
+            cache_read_tokens: self
+                .cache_read_tokens
+                .saturating_add(other.cache_read_tokens),
+        }
+    }
 }
 
 /// Exit codes that indicate a transient process crash (not a logical agent error).
@@ -167,6 +259,11 @@ impl HarnessRunResult {
     ///     exit_code: 128,
     ///     duration: Duration::from_secs(1),
     ///     timed_out: false,
+    ///     cancelled: false,
+    ///     session_id: None,
+    ///     final_message: None,
+    ///     usage: None,
+    ///     resources: Default::default(),
     /// };
     /// assert!(result.is_retriable());
     ///
@@ -216,6 +313,15 @@ pub trait Harness {
     fn streams_output(&self) -> bool {
         false
     }
+
+    /// Check that the harness can run a prompt end to end.
+    ///
+    /// The default sends [`health::HEALTH_CHECK_PROMPT`] through [`Harness::run`]
+    /// from `cwd` with a short inactivity timeout and classifies the result.
+    /// Implementations with cheaper native checks override this.
+    fn health_check(&mut self, cwd: &Path, model: Option<&str>) -> HarnessHealth {
+        health::check_with_prompt(self, cwd, model)
+    }
 }
 
 #[cfg(test)]
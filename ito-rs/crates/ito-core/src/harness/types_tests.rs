@@ -42,6 +42,16 @@ fn from_str_invalid_returns_error() {
     }
 }
 
+#[test]
+fn from_str_typo_suggests_nearest_harness() {
+    let err = "opencod".parse::<HarnessName>().unwrap_err();
+    assert_eq!(
+        err.suggestions.first().map(String::as_str),
+        Some("opencode")
+    );
+    assert!(err.to_string().contains("Did you mean: opencode"), "{err}");
+}
+
 #[test]
 fn as_str_all_variants() {
     assert_eq!(HarnessName::Opencode.as_str(), "opencode");
@@ -69,6 +79,7 @@ fn display_matches_as_str() {
 fn parse_error_display() {
     let err = HarnessNameParseError {
         input: "foo".to_string(),
+        suggestions: Vec::new(),
     };
     assert_eq!(format!("{}", err), "Unknown harness name: foo");
 }
@@ -80,6 +91,11 @@ fn make_result(exit_code: i32) -> HarnessRunResult {
         exit_code,
         duration: Duration::from_secs(1),
         timed_out: false,
+        cancelled: false,
+        session_id: None,
+        final_message: None,
+        usage: None,
+        resources: Default::default(),
     }
 }
 
@@ -465,7 +465,7 @@ fn load_tracking_file(
         .schema
         .apply
         .as_ref()
-        .and_then(|apply| apply.tracks.as_deref())
+        .and_then(|apply| apply.primary_tracking_file())
     else {
         if required {
             return Err(PrepareFailure::validation(
@@ -1,14 +1,64 @@
 use std::path::Path;
 
+use ito_common::fs::FileSystem;
+
+use super::line_endings::{convert_line_endings, detect_line_ending};
 use crate::errors::{CoreError, CoreResult};
 
-pub(super) fn update_agent_model_field(path: &Path, model: &str) -> CoreResult<()> {
-    update_agent_yaml_field(path, "model", &format!("\"{model}\""))
+/// Outcome of rewriting the `model` key of an agent's frontmatter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum ModelFieldUpdate {
+    /// The file was rewritten with the new model.
+    Updated,
+    /// Nothing to do: no frontmatter, or the model is already set.
+    Unchanged,
+    /// The frontmatter could not be edited safely; the file was left alone.
+    Skipped(String),
+}
+
+#[cfg(test)]
+pub(super) fn update_agent_model_field(path: &Path, model: &str) -> CoreResult<ModelFieldUpdate> {
+    update_agent_model_field_fs(&ito_common::fs::StdFs, path, model)
+}
+
+/// Set the top-level `model` key in an agent file's YAML frontmatter.
+///
+/// The frontmatter is parsed first so nested `model` keys and multi-line
+/// values are recognized; only the lines of the top-level entry are replaced
+/// (keeping its quoting style) and every other byte is preserved. Frontmatter
+/// that does not parse as a YAML mapping is reported and left untouched.
+pub(super) fn update_agent_model_field_fs(
+    fs: &dyn FileSystem,
+    path: &Path,
+    model: &str,
+) -> CoreResult<ModelFieldUpdate> {
+    let content = fs.read_to_string(path).unwrap_or_default();
+    let Some((frontmatter, body)) = split_frontmatter(&content) else {
+        return Ok(ModelFieldUpdate::Unchanged);
+    };
+
+    match set_top_level_model(frontmatter, model) {
+        Ok(Some(frontmatter)) => {
+            write_frontmatter(fs, path, &content, &frontmatter, body)?;
+            Ok(ModelFieldUpdate::Updated)
+        }
+        Ok(None) => Ok(ModelFieldUpdate::Unchanged),
+        Err(reason) => Ok(ModelFieldUpdate::Skipped(reason)),
+    }
 }
 
+#[cfg(test)]
 pub(super) fn update_agent_activation_field_from_rendered(
     path: &Path,
     rendered: &[u8],
+) -> CoreResult<()> {
+    update_agent_activation_field_from_rendered_fs(&ito_common::fs::StdFs, path, rendered)
+}
+
+pub(super) fn update_agent_activation_field_from_rendered_fs(
+    fs: &dyn FileSystem,
+    path: &Path,
+    rendered: &[u8],
 ) -> CoreResult<()> {
     let Ok(rendered) = std::str::from_utf8(rendered) else {
         return Ok(());
@@ -17,12 +67,21 @@ pub(super) fn update_agent_activation_field_from_rendered(
         return Ok(());
     };
 
-    update_agent_yaml_field(path, "activation", activation)
+    update_agent_yaml_field(fs, path, "activation", activation)
 }
 
+#[cfg(test)]
 pub(super) fn remove_agent_mode_field_for_direct_activation(
     path: &Path,
     rendered: &[u8],
+) -> CoreResult<()> {
+    remove_agent_mode_field_for_direct_activation_fs(&ito_common::fs::StdFs, path, rendered)
+}
+
+pub(super) fn remove_agent_mode_field_for_direct_activation_fs(
+    fs: &dyn FileSystem,
+    path: &Path,
+    rendered: &[u8],
 ) -> CoreResult<()> {
     let Ok(rendered) = std::str::from_utf8(rendered) else {
         return Ok(());
@@ -34,37 +93,63 @@ pub(super) fn remove_agent_mode_field_for_direct_activation(
         return Ok(());
     }
 
-    remove_agent_yaml_field(path, "mode")
+    remove_agent_yaml_field(fs, path, "mode")
 }
 
-fn update_agent_yaml_field(path: &Path, key: &str, value: &str) -> CoreResult<()> {
-    let content = ito_common::io::read_to_string_or_default(path);
+fn update_agent_yaml_field(
+    fs: &dyn FileSystem,
+    path: &Path,
+    key: &str,
+    value: &str,
+) -> CoreResult<()> {
+    let content = fs.read_to_string(path).unwrap_or_default();
     let Some((frontmatter, body)) = split_frontmatter(&content) else {
         return Ok(());
     };
 
     let frontmatter = update_yaml_field(frontmatter, key, value);
-    let updated = format!("---{frontmatter}\n---{body}");
-    ito_common::io::write_std(path, updated)
-        .map_err(|e| CoreError::io(format!("writing {}", path.display()), e))
+    write_frontmatter(fs, path, &content, &frontmatter, body)
 }
 
-fn remove_agent_yaml_field(path: &Path, key: &str) -> CoreResult<()> {
-    let content = ito_common::io::read_to_string_or_default(path);
+fn remove_agent_yaml_field(fs: &dyn FileSystem, path: &Path, key: &str) -> CoreResult<()> {
+    let content = fs.read_to_string(path).unwrap_or_default();
     let Some((frontmatter, body)) = split_frontmatter(&content) else {
         return Ok(());
     };
 
     let frontmatter = remove_yaml_field(frontmatter, key);
-    let updated = format!("---{frontmatter}\n---{body}");
-    ito_common::io::write_std(path, updated)
+    write_frontmatter(fs, path, &content, &frontmatter, body)
+}
+
+/// Reassemble the file, keeping the original dominant line ending in the frontmatter.
+fn write_frontmatter(
+    fs: &dyn FileSystem,
+    path: &Path,
+    original: &str,
+    frontmatter: &str,
+    body: &str,
+) -> CoreResult<()> {
+    let ending = detect_line_ending(original).unwrap_or_default();
+    let frontmatter = convert_line_endings(frontmatter, ending);
+    let eol = ending.as_str();
+    let updated = format!("---{frontmatter}{eol}---{body}");
+    fs.write(path, updated.as_bytes())
         .map_err(|e| CoreError::io(format!("writing {}", path.display()), e))
 }
 
+/// Split `content` into frontmatter and body.
+///
+/// The frontmatter ends at the first line that is exactly `---`, so values
+/// that merely start with dashes do not close it early.
 fn split_frontmatter(content: &str) -> Option<(&str, &str)> {
     let rest = content.strip_prefix("---")?;
-    let end = rest.find("\n---")?;
-    Some((&rest[..end], &rest[end + 4..]))
+    let end = rest.match_indices('\n').map(|(idx, _)| idx).find(|&idx| {
+        let line = rest[idx + 1..].split('\n').next().unwrap_or_default();
+        line.trim_end() == "---"
+    })?;
+    let frontmatter = &rest[..end];
+    let frontmatter = frontmatter.strip_suffix('\r').unwrap_or(frontmatter);
+    Some((frontmatter, &rest[end + 4..]))
 }
 
 fn frontmatter_field<'a>(content: &'a str, key: &str) -> Option<&'a str> {
@@ -76,6 +161,98 @@ fn frontmatter_field<'a>(content: &'a str, key: &str) -> Option<&'a str> {
     })
 }
 
+/// Return `yaml` with its top-level `model` set to `model`.
+///
+/// `Ok(None)` means the value is already current. Errors describe why the
+/// frontmatter could not be edited safely.
+fn set_top_level_model(yaml: &str, model: &str) -> Result<Option<String>, String> {
+    let yaml = yaml.replace("\r\n", "\n");
+    let original = parse_frontmatter_mapping(&yaml)?;
+    let key = serde_yaml::Value::String("model".to_string());
+    let target = serde_yaml::Value::String(model.to_string());
+    if original.get(&key) == Some(&target) {
+        return Ok(None);
+    }
+
+    let lines: Vec<&str> = yaml.split('\n').collect();
+    let updated = match find_top_level_entry(&lines, "model") {
+        Some((start, end)) => {
+            let value = lines[start]
+                .split_once(':')
+                .map(|(_, value)| value.trim())
+                .unwrap_or_default();
+            let entry = format!("model: {}", quote_like(value, model));
+            let mut out: Vec<&str> = lines[..start].to_vec();
+            out.push(&entry);
+            out.extend_from_slice(&lines[end..]);
+            out.join("\n")
+        }
+        None => {
+            let mut out = yaml.trim_end_matches('\n').to_string();
+            out.push_str(&format!("\nmodel: {}", double_quoted(model)));
+            out
+        }
+    };
+
+    let mut expected = original;
+    expected.insert(key, target);
+    if parse_frontmatter_mapping(&updated)? != expected {
+        return Err("could not locate the top-level model entry".to_string());
+    }
+    Ok(Some(updated))
+}
+
+fn parse_frontmatter_mapping(yaml: &str) -> Result<serde_yaml::Mapping, String> {
+    match serde_yaml::from_str::<serde_yaml::Value>(yaml) {
+        Ok(serde_yaml::Value::Mapping(mapping)) => Ok(mapping),
+        Ok(serde_yaml::Value::Null) => Ok(serde_yaml::Mapping::new()),
+        Ok(_) => Err("frontmatter is not a YAML mapping".to_string()),
+        Err(e) => Err(format!("frontmatter is not valid YAML: {e}")),
+    }
+}
+
+/// Line span `[start, end)` of the top-level `key` entry, including
+/// indented continuation lines but not trailing blank lines.
+fn find_top_level_entry(lines: &[&str], key: &str) -> Option<(usize, usize)> {
+    let start = lines.iter().position(|line| {
+        let Some((candidate, _)) = line.split_once(':') else {
+            return false;
+        };
+        !line.starts_with([' ', '\t']) && candidate.trim_matches(['"', '\'']) == key
+    })?;
+    let mut end = start + 1;
+    for (idx, line) in lines.iter().enumerate().skip(start + 1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if !line.starts_with([' ', '\t']) {
+            break;
+        }
+        end = idx + 1;
+    }
+    Some((start, end))
+}
+
+/// Render `value` using the quoting style of the `previous` scalar.
+fn quote_like(previous: &str, value: &str) -> String {
+    if previous.starts_with('\'') {
+        return format!("'{}'", value.replace('\'', "''"));
+    }
+    let plain_ok = !previous.is_empty()
+        && !previous.starts_with(['"', '|', '>'])
+        && serde_yaml::from_str::<serde_yaml::Value>(value).ok()
+            == Some(serde_yaml::Value::String(value.to_string()));
+    if plain_ok {
+        value.to_string()
+    } else {
+        double_quoted(value)
+    }
+}
+
+fn double_quoted(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
 fn update_yaml_field(yaml: &str, key: &str, value: &str) -> String {
     let mut lines: Vec<String> = yaml.lines().map(str::to_string).collect();
     let prefix = format!("{key}:");
@@ -16,6 +16,92 @@ fn update_agent_model_field_updates_frontmatter_when_present() {
     assert_eq!(s, "no frontmatter\n");
 }
 
+fn update_model(content: &str, model: &str) -> (ModelFieldUpdate, String) {
+    let td = tempfile::tempdir().unwrap();
+    let path = td.path().join("agent.md");
+    std::fs::write(&path, content).unwrap();
+    let outcome = update_agent_model_field(&path, model).unwrap();
+    (outcome, std::fs::read_to_string(&path).unwrap())
+}
+
+#[test]
+fn nested_model_key_is_left_alone() {
+    let (outcome, s) = update_model(
+        "---\nname: test\noptions:\n  model: inner\nmodel: \"old\"\n---\nbody\n",
+        "new",
+    );
+    assert_eq!(outcome, ModelFieldUpdate::Updated);
+    assert_eq!(
+        s,
+        "---\nname: test\noptions:\n  model: inner\nmodel: \"new\"\n---\nbody\n"
+    );
+
+    let (_, s) = update_model("---\noptions:\n  model: inner\n---\nbody\n", "new");
+    assert_eq!(
+        s,
+        "---\noptions:\n  model: inner\nmodel: \"new\"\n---\nbody\n"
+    );
+}
+
+#[test]
+fn top_level_model_keeps_its_quoting_style() {
+    let (_, s) = update_model("---\nmodel: 'old'\nname: t\n---\n", "it's");
+    assert_eq!(s, "---\nmodel: 'it''s'\nname: t\n---\n");
+
+    let (_, s) = update_model("---\nmodel: old\n---\n", "provider/new");
+    assert_eq!(s, "---\nmodel: provider/new\n---\n");
+
+    // A plain value that would not round-trip as a string gets quoted.
+    let (_, s) = update_model("---\nmodel: old\n---\n", "1.5");
+    assert_eq!(s, "---\nmodel: \"1.5\"\n---\n");
+}
+
+#[test]
+fn absent_model_is_appended() {
+    let (outcome, s) = update_model("---\nname: test\n---\nbody\n", "new");
+    assert_eq!(outcome, ModelFieldUpdate::Updated);
+    assert_eq!(s, "---\nname: test\nmodel: \"new\"\n---\nbody\n");
+}
+
+#[test]
+fn multi_line_model_value_is_replaced_whole() {
+    let (_, s) = update_model(
+        "---\nmodel: >-\n  old\n  value\n\nname: test\n---\nbody\n",
+        "new",
+    );
+    assert_eq!(s, "---\nmodel: \"new\"\n\nname: test\n---\nbody\n");
+}
+
+#[test]
+fn block_scalar_description_is_preserved_byte_for_byte() {
+    let description =
+        "description: |\n  Reviews code.\n  ---\n  model: not-a-key\n\n  Trailing   spaces  \n";
+    let (_, s) = update_model(
+        &format!("---\nname: test\n{description}model: old\n---\nbody\n"),
+        "new",
+    );
+    assert_eq!(
+        s,
+        format!("---\nname: test\n{description}model: new\n---\nbody\n")
+    );
+}
+
+#[test]
+fn unchanged_model_does_not_rewrite() {
+    let content = "---\nmodel: 'same'\n---\nbody\n";
+    let (outcome, s) = update_model(content, "same");
+    assert_eq!(outcome, ModelFieldUpdate::Unchanged);
+    assert_eq!(s, content);
+}
+
+#[test]
+fn unparseable_frontmatter_is_skipped_untouched() {
+    let content = "---\nname: [unclosed\nmodel: old\n---\nbody\n";
+    let (outcome, s) = update_model(content, "new");
+    assert!(matches!(outcome, ModelFieldUpdate::Skipped(_)));
+    assert_eq!(s, content);
+}
+
 #[test]
 fn activation_field_is_copied_from_rendered_template() {
     let td = tempfile::tempdir().unwrap();
@@ -68,3 +154,21 @@ fn update_yaml_field_replaces_or_inserts() {
     let updated = update_yaml_field(yaml, "model", "\"new\"");
     assert!(updated.contains("model: \"new\""));
 }
+
+#[test]
+fn crlf_frontmatter_updates_keep_crlf() {
+    let td = tempfile::tempdir().unwrap();
+    let path = td.path().join("agent.md");
+    std::fs::write(
+        &path,
+        "---\r\nname: test\r\nmodel: \"old\"\r\nmode: subagent\r\n---\r\nbody\r\n",
+    )
+    .unwrap();
+
+    update_agent_model_field(&path, "new").unwrap();
+    remove_agent_mode_field_for_direct_activation(&path, b"---\nactivation: direct\n---\n")
+        .unwrap();
+
+    let s = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(s, "---\r\nname: test\r\nmodel: \"new\"\r\n---\r\nbody\r\n");
+}
@@ -0,0 +1,152 @@
+//! Models the installed Ito agents use, per harness and tier.
+//!
+//! The agent templates embed a default model for each harness and tier. The
+//! `models` config section overrides individual entries; everything it leaves
+//! unset keeps the embedded default. Both fresh installs and `ito update`
+//! render agent files from the merged table, so editing the config and
+//! running `ito update` converges existing agent files to the pinned models.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use ito_config::types::ModelsConfig;
+use ito_config::{ConfigContext, load_cascading_project_config};
+use ito_templates::agents::{AgentConfig, AgentTier, Harness, default_agent_configs};
+use serde::Serialize;
+
+use crate::config::json_get_path;
+use crate::errors::{CoreError, CoreResult};
+
+use super::{TOOL_CLAUDE, TOOL_CODEX, TOOL_GITHUB_COPILOT, TOOL_OPENCODE, TOOL_PI};
+
+/// Config key (in the cascading project config) holding the model pins.
+pub const MODELS_CONFIG_KEY: &str = "models";
+
+/// Tool ids and the harness whose agent templates they install.
+pub(super) const TOOL_HARNESSES: [(&str, Harness); 5] = [
+    (TOOL_CLAUDE, Harness::ClaudeCode),
+    (TOOL_CODEX, Harness::Codex),
+    (TOOL_GITHUB_COPILOT, Harness::GitHubCopilot),
+    (TOOL_OPENCODE, Harness::OpenCode),
+    (TOOL_PI, Harness::Pi),
+];
+
+/// Where an effective model comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModelSource {
+    /// Pinned in the `models` config section.
+    Config,
+    /// Embedded agent template default.
+    Default,
+}
+
+impl ModelSource {
+    /// Short label for human output.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            ModelSource::Config => "config",
+            ModelSource::Default => "default",
+        }
+    }
+}
+
+/// The model one harness/tier agent is rendered with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ModelPin {
+    /// Tool id (e.g. `claude`).
+    pub tool: &'static str,
+    /// Tier name: `quick`, `general` or `thinking`.
+    pub tier: &'static str,
+    /// Effective model id.
+    pub model: String,
+    /// Whether the model is pinned in config or the embedded default.
+    pub source: ModelSource,
+}
+
+/// Read the `models` section from the cascading project config.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Validation`] when the section does not match the
+/// schema, e.g. an unknown harness or tier key.
+pub fn configured_models(
+    project_root: &Path,
+    ito_path: &Path,
+    ctx: &ConfigContext,
+) -> CoreResult<Option<ModelsConfig>> {
+    let merged = load_cascading_project_config(project_root, ito_path, ctx).merged;
+    match json_get_path(&merged, &[MODELS_CONFIG_KEY]) {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(value) => serde_json::from_value(value.clone())
+            .map(Some)
+            .map_err(|e| CoreError::validation(format!("Invalid {MODELS_CONFIG_KEY} value: {e}"))),
+    }
+}
+
+/// Embedded agent configs with the models pinned in `models` applied.
+///
+/// Only the model changes; variant, temperature and reasoning effort keep
+/// their embedded values.
+pub fn effective_agent_configs(
+    models: Option<&ModelsConfig>,
+) -> HashMap<(Harness, AgentTier), AgentConfig> {
+    let mut configs = default_agent_configs();
+    let Some(models) = models else {
+        return configs;
+    };
+    for ((harness, tier), config) in &mut configs {
+        if let Some(model) = pinned_model(models, *harness, *tier) {
+            config.model = model.to_string();
+        }
+    }
+    configs
+}
+
+/// Effective model for every harness and tier, with its provenance.
+///
+/// Ordered by tool id, then tier from quick to thinking.
+pub fn model_pins(models: Option<&ModelsConfig>) -> Vec<ModelPin> {
+    let defaults = default_agent_configs();
+    let mut pins = Vec::new();
+    for (tool, harness) in TOOL_HARNESSES {
+        for tier in AgentTier::all() {
+            let pinned = models.and_then(|models| pinned_model(models, harness, *tier));
+            let (model, source) = match pinned {
+                Some(model) => (model.to_string(), ModelSource::Config),
+                None => match defaults.get(&(harness, *tier)) {
+                    Some(config) => (config.model.clone(), ModelSource::Default),
+                    None => continue,
+                },
+            };
+            pins.push(ModelPin {
+                tool,
+                tier: tier_key(*tier),
+                model,
+                source,
+            });
+        }
+    }
+    pins
+}
+
+fn pinned_model(models: &ModelsConfig, harness: Harness, tier: AgentTier) -> Option<&str> {
+    let (tool, _) = TOOL_HARNESSES.iter().find(|(_, h)| *h == harness)?;
+    models
+        .for_tool(tool)?
+        .for_tier(tier_key(tier))
+        .map(str::trim)
+        .filter(|model| !model.is_empty())
+}
+
+fn tier_key(tier: AgentTier) -> &'static str {
+    match tier {
+        AgentTier::Quick => "quick",
+        AgentTier::General => "general",
+        AgentTier::Thinking => "thinking",
+    }
+}
+
+#[cfg(test)]
+#[path = "agent_models_tests.rs"]
+mod agent_models_tests;
@@ -0,0 +1,99 @@
+use super::*;
+use ito_config::types::ModelTierPins;
+
+fn claude_quick(model: &str) -> ModelsConfig {
+    ModelsConfig {
+        claude: Some(ModelTierPins {
+            quick: Some(model.to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn effective_configs_without_pins_are_the_embedded_defaults() {
+    let effective = effective_agent_configs(None);
+    let defaults = default_agent_configs();
+
+    assert_eq!(effective.len(), defaults.len());
+    for (key, config) in &defaults {
+        assert_eq!(effective[key].model, config.model);
+    }
+}
+
+#[test]
+fn pinned_model_replaces_only_the_model() {
+    let models = ModelsConfig {
+        opencode: Some(ModelTierPins {
+            thinking: Some("anthropic/claude-opus-4-1".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let effective = effective_agent_configs(Some(&models));
+    let defaults = default_agent_configs();
+
+    let key = (Harness::OpenCode, AgentTier::Thinking);
+    assert_eq!(effective[&key].model, "anthropic/claude-opus-4-1");
+    assert_eq!(effective[&key].variant, defaults[&key].variant);
+    assert_eq!(effective[&key].temperature, defaults[&key].temperature);
+    let general = (Harness::OpenCode, AgentTier::General);
+    assert_eq!(effective[&general].model, defaults[&general].model);
+}
+
+#[test]
+fn blank_pins_fall_back_to_defaults() {
+    let pins = model_pins(Some(&claude_quick("  ")));
+
+    let quick = pins
+        .iter()
+        .find(|pin| pin.tool == TOOL_CLAUDE && pin.tier == "quick")
+        .unwrap();
+    assert_eq!(quick.model, "haiku");
+    assert_eq!(quick.source, ModelSource::Default);
+}
+
+#[test]
+fn pins_cover_every_harness_and_tier_in_order() {
+    let pins = model_pins(Some(&claude_quick("claude-haiku-4-5")));
+
+    assert_eq!(pins.len(), TOOL_HARNESSES.len() * AgentTier::all().len());
+    assert_eq!(
+        pins[..3]
+            .iter()
+            .map(|pin| (pin.tool, pin.tier, pin.model.as_str(), pin.source))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                TOOL_CLAUDE,
+                "quick",
+                "claude-haiku-4-5",
+                ModelSource::Config
+            ),
+            (TOOL_CLAUDE, "general", "sonnet", ModelSource::Default),
+            (TOOL_CLAUDE, "thinking", "opus", ModelSource::Default),
+        ]
+    );
+    assert_eq!(pins[3].tool, TOOL_CODEX);
+}
+
+#[test]
+fn configured_models_rejects_unknown_harness_keys() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    std::fs::create_dir_all(&ito).unwrap();
+    std::fs::write(
+        ito.join("config.json"),
+        r#"{"models": {"claude-code": {"quick": "haiku"}}}"#,
+    )
+    .unwrap();
+    let ctx = ConfigContext {
+        project_dir: Some(td.path().to_path_buf()),
+        ..Default::default()
+    };
+
+    let err = configured_models(td.path(), &ito, &ctx).unwrap_err();
+    assert!(err.to_string().contains("Invalid models value"), "{err}");
+}
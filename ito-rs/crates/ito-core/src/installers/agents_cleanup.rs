@@ -1,4 +1,6 @@
-use std::{io::ErrorKind, path::Path};
+use std::path::Path;
+
+use ito_common::fs::FileSystem;
 
 use crate::errors::{CoreError, CoreResult};
 
@@ -17,63 +19,65 @@ const OBSOLETE_SPECIALIST_AGENT_REL_PATHS: &[&str] = &[
     "ito-orchestrator-worker/SKILL.md",
 ];
 
-pub(super) fn remove_obsolete_specialist_agents(agent_dir: &Path) -> CoreResult<()> {
+pub(super) fn remove_obsolete_specialist_agents(
+    fs: &dyn FileSystem,
+    agent_dir: &Path,
+) -> CoreResult<()> {
     for obsolete_rel_path in OBSOLETE_SPECIALIST_AGENT_REL_PATHS {
-        remove_obsolete_specialist_agent(agent_dir, obsolete_rel_path)?;
+        remove_obsolete_specialist_agent_fs(fs, agent_dir, obsolete_rel_path)?;
     }
 
     Ok(())
 }
 
+#[cfg(test)]
 pub(super) fn remove_obsolete_specialist_agent(
     agent_dir: &Path,
     obsolete_rel_path: &str,
 ) -> CoreResult<()> {
-    let obsolete = agent_dir.join(obsolete_rel_path);
-    let metadata = match std::fs::symlink_metadata(&obsolete) {
-        Ok(metadata) => metadata,
-        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
-        Err(err) => {
-            return Err(CoreError::io(
-                format!("reading {}", obsolete.display()),
-                err,
-            ));
-        }
-    };
+    remove_obsolete_specialist_agent_fs(&ito_common::fs::StdFs, agent_dir, obsolete_rel_path)
+}
 
-    let file_type = metadata.file_type();
-    if file_type.is_file() || file_type.is_symlink() {
-        std::fs::remove_file(&obsolete)
+fn remove_obsolete_specialist_agent_fs(
+    fs: &dyn FileSystem,
+    agent_dir: &Path,
+    obsolete_rel_path: &str,
+) -> CoreResult<()> {
+    let obsolete = agent_dir.join(obsolete_rel_path);
+    // Check for symlinks first: broken links do not "exist" but must still go.
+    if fs.is_symlink(&obsolete) || fs.is_file(&obsolete) {
+        fs.remove_file(&obsolete)
             .map_err(|e| CoreError::io(format!("removing {}", obsolete.display()), e))?;
-    } else {
+    } else if fs.exists(&obsolete) {
         return Err(CoreError::Validation(format!(
             "expected obsolete specialist agent path to be a file or symlink: {}. Remove the directory manually and rerun the install.",
             obsolete.display()
         )));
+    } else {
+        return Ok(());
     }
-    prune_empty_agent_dirs(agent_dir, obsolete.parent())
+    prune_empty_agent_dirs(fs, agent_dir, obsolete.parent())
 }
 
-fn prune_empty_agent_dirs(agent_dir: &Path, start: Option<&Path>) -> CoreResult<()> {
+fn prune_empty_agent_dirs(
+    fs: &dyn FileSystem,
+    agent_dir: &Path,
+    start: Option<&Path>,
+) -> CoreResult<()> {
     let mut current = start.map(Path::to_path_buf);
 
     while let Some(dir) = current {
         if dir == agent_dir || !dir.starts_with(agent_dir) {
             break;
         }
-        let is_empty = {
-            let mut entries = std::fs::read_dir(&dir)
-                .map_err(|e| CoreError::io(format!("reading {}", dir.display()), e))?;
-            entries
-                .next()
-                .transpose()
-                .map_err(|e| CoreError::io(format!("reading {}", dir.display()), e))?
-                .is_none()
-        };
+        let is_empty = fs
+            .read_dir(&dir)
+            .map_err(|e| CoreError::io(format!("reading {}", dir.display()), e))?
+            .is_empty();
         if !is_empty {
             break;
         }
-        std::fs::remove_dir(&dir)
+        fs.remove_dir(&dir)
             .map_err(|e| CoreError::io(format!("removing {}", dir.display()), e))?;
         current = dir.parent().map(Path::to_path_buf);
     }
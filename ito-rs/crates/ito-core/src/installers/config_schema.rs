@@ -0,0 +1,66 @@
+//! The config JSON schema written next to `config.json`.
+//!
+//! New `config.json` files reference the schema through `$schema`. A GitHub
+//! release URL does not exist for local builds, so by default the installer
+//! writes the schema generated by this binary to `config.schema.json` and
+//! references it relatively. Projects that run `ito serve` can point
+//! `install.schema_server` at it instead.
+
+use std::path::Path;
+
+use ito_config::{ConfigContext, load_cascading_project_config};
+
+use crate::config::json_get_path;
+use crate::errors::{CoreError, CoreResult};
+
+/// Config key (in the cascading project config) naming an `ito serve` base URL.
+pub const SCHEMA_SERVER_CONFIG_KEY: &str = "install.schema_server";
+
+/// File name of the generated schema, inside the Ito directory.
+pub const CONFIG_SCHEMA_FILE: &str = "config.schema.json";
+
+/// Path `ito serve` serves the config schema at.
+pub const CONFIG_SCHEMA_ROUTE: &str = "/schema/config.json";
+
+/// Placeholder in the `config.json` template replaced by [`config_schema_ref`].
+pub(super) const CONFIG_SCHEMA_PLACEHOLDER: &str = "__ITO_CONFIG_SCHEMA__";
+
+/// `$schema` value for a new `config.json`.
+///
+/// With a schema server this is `<server>/schema/config.json`; otherwise the
+/// relative path of the generated [`CONFIG_SCHEMA_FILE`].
+pub fn config_schema_ref(schema_server: Option<&str>) -> String {
+    match schema_server {
+        Some(server) => format!("{}{CONFIG_SCHEMA_ROUTE}", server.trim_end_matches('/')),
+        None => format!("./{CONFIG_SCHEMA_FILE}"),
+    }
+}
+
+/// Bytes of the generated schema file.
+pub(super) fn config_schema_bytes() -> Vec<u8> {
+    let mut text = ito_config::schema::config_schema_pretty_json();
+    text.push('\n');
+    text.into_bytes()
+}
+
+/// Read `install.schema_server` from the cascading project config.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Validation`] when the configured value is not a string.
+pub(super) fn configured_schema_server(
+    project_root: &Path,
+    ito_path: &Path,
+    ctx: &ConfigContext,
+) -> CoreResult<Option<String>> {
+    let merged = load_cascading_project_config(project_root, ito_path, ctx).merged;
+    let parts: Vec<&str> = SCHEMA_SERVER_CONFIG_KEY.split('.').collect();
+    match json_get_path(&merged, &parts) {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::String(url)) if url.trim().is_empty() => Ok(None),
+        Some(serde_json::Value::String(url)) => Ok(Some(url.trim().to_string())),
+        Some(other) => Err(CoreError::validation(format!(
+            "Invalid {SCHEMA_SERVER_CONFIG_KEY} value {other} (expected a URL string)"
+        ))),
+    }
+}
@@ -0,0 +1,126 @@
+//! Managed `.gitignore` block.
+//!
+//! Ito keeps its repository ignore rules between `# ITO:START` and
+//! `# ITO:END` so they can be updated in place. Entries written loose by older
+//! releases are migrated into the block; every other line of the file is
+//! preserved byte-for-byte.
+
+use std::path::Path;
+
+use ito_common::fs::FileSystem;
+
+use super::line_endings::{LineEnding, convert_line_endings, detect_line_ending};
+use crate::errors::{CoreError, CoreResult};
+use crate::managed_block::update_content_with_markers;
+
+/// Start marker of the managed `.gitignore` block.
+const GITIGNORE_BLOCK_START: &str = "# ITO:START";
+/// End marker of the managed `.gitignore` block.
+const GITIGNORE_BLOCK_END: &str = "# ITO:END";
+
+/// Entries Ito maintains in the repository `.gitignore`.
+fn managed_gitignore_entries(ito_dir: &str) -> Vec<String> {
+    vec![
+        // Per-worktree session state.
+        format!("{ito_dir}/session.json"),
+        // Lock and temp files left behind by an interrupted session.json write.
+        format!("{ito_dir}/session.json.lock"),
+        format!("{ito_dir}/session.json.tmp.*"),
+        format!("{ito_dir}/.state/audit/.session"),
+        // Local (per-developer) config overlays should never be committed.
+        format!("{ito_dir}/config.local.json"),
+        ".local/ito/config.json".to_string(),
+        // Harness transcripts are local run history (and may contain secrets).
+        format!("{ito_dir}/.state/transcripts/"),
+        // Harness usage stats are local run history.
+        format!("{ito_dir}/{}/", crate::stats::cost::HARNESS_STATS_DIR_REL),
+        // The repository index is a per-checkout cache.
+        format!("{ito_dir}/{}", crate::repo_index::REPO_INDEX_REL),
+    ]
+}
+
+/// Lines older releases wrote outside the block; removed when found loose.
+fn legacy_gitignore_entries(ito_dir: &str) -> Vec<String> {
+    let mut entries = managed_gitignore_entries(ito_dir);
+    // Audit logs used to be unignored; they are per-worktree and stay untracked.
+    entries.push(format!("!{ito_dir}/.state/audit/"));
+    entries
+}
+
+/// Write Ito's managed block into the repository `.gitignore`.
+///
+/// This is not a templated file: the block is replaced in place (or appended
+/// when absent) and the rest of the file is left alone.
+pub(super) fn update_repo_gitignore_fs(
+    fs: &dyn FileSystem,
+    project_root: &Path,
+    ito_dir: &str,
+) -> CoreResult<()> {
+    let path = project_root.join(".gitignore");
+    let existing = match fs.read_to_string(&path) {
+        Ok(s) => Some(s),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => return Err(CoreError::io(format!("reading {}", path.display()), e)),
+    };
+
+    let updated = render_gitignore(&path, existing.as_deref(), ito_dir)?;
+    if existing.as_deref() == Some(updated.as_str()) {
+        return Ok(());
+    }
+    fs.write(&path, updated.as_bytes())
+        .map_err(|e| CoreError::io(format!("writing {}", path.display()), e))
+}
+
+fn render_gitignore(path: &Path, existing: Option<&str>, ito_dir: &str) -> CoreResult<String> {
+    let body = managed_gitignore_entries(ito_dir).join("\n");
+    let Some(existing) = existing else {
+        return Ok(format!(
+            "{GITIGNORE_BLOCK_START}\n{body}\n{GITIGNORE_BLOCK_END}\n"
+        ));
+    };
+
+    let content = remove_loose_entries(existing, &legacy_gitignore_entries(ito_dir));
+    if has_block_marker(&content) {
+        return update_content_with_markers(
+            path,
+            Some(&content),
+            &body,
+            GITIGNORE_BLOCK_START,
+            GITIGNORE_BLOCK_END,
+        )
+        .map_err(|e| CoreError::validation(e.to_string()));
+    }
+
+    let ending = detect_line_ending(existing).unwrap_or(LineEnding::Lf);
+    let eol = ending.as_str();
+    let mut out = content;
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push_str(eol);
+    }
+    out.push_str(GITIGNORE_BLOCK_START);
+    out.push_str(eol);
+    out.push_str(&convert_line_endings(&body, ending));
+    out.push_str(eol);
+    out.push_str(GITIGNORE_BLOCK_END);
+    out.push_str(eol);
+    Ok(out)
+}
+
+fn has_block_marker(content: &str) -> bool {
+    content
+        .lines()
+        .map(str::trim)
+        .any(|line| line == GITIGNORE_BLOCK_START || line == GITIGNORE_BLOCK_END)
+}
+
+/// Drop lines that exactly match one of `entries`, keeping all other bytes.
+fn remove_loose_entries(content: &str, entries: &[String]) -> String {
+    content
+        .split_inclusive('\n')
+        .filter(|line| !entries.iter().any(|entry| line.trim() == entry))
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "gitignore_tests.rs"]
+mod gitignore_tests;
@@ -0,0 +1,96 @@
+use super::*;
+
+use ito_common::fs::StdFs;
+
+const BLOCK: &str = "# ITO:START\n\
+.ito/session.json\n\
+.ito/session.json.lock\n\
+.ito/session.json.tmp.*\n\
+.ito/.state/audit/.session\n\
+.ito/config.local.json\n\
+.local/ito/config.json\n\
+.ito/.state/transcripts/\n\
+.ito/.state/stats/\n\
+.ito/.state/repo-index.json\n\
+# ITO:END\n";
+
+fn update(root: &Path) -> String {
+    update_repo_gitignore_fs(&StdFs, root, ".ito").unwrap();
+    std::fs::read_to_string(root.join(".gitignore")).unwrap()
+}
+
+#[test]
+fn creates_block_when_gitignore_missing() {
+    let td = tempfile::tempdir().unwrap();
+    assert_eq!(update(td.path()), BLOCK);
+}
+
+#[test]
+fn appends_block_after_existing_content() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::write(td.path().join(".gitignore"), "node_modules").unwrap();
+    assert_eq!(update(td.path()), format!("node_modules\n{BLOCK}"));
+}
+
+#[test]
+fn migrates_legacy_loose_entries_into_block() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::write(
+        td.path().join(".gitignore"),
+        "node_modules\n.ito/session.json\n.ito/.state/\n!.ito/.state/audit/\n.ito/.state/transcripts/\ntarget/\n",
+    )
+    .unwrap();
+    assert_eq!(
+        update(td.path()),
+        format!("node_modules\n.ito/.state/\ntarget/\n{BLOCK}")
+    );
+}
+
+#[test]
+fn leaves_user_entries_for_the_same_path_alone() {
+    let td = tempfile::tempdir().unwrap();
+    let user = "# keep tracked config out\n/.ito/session.json\n.ito/session.json.bak\n";
+    std::fs::write(td.path().join(".gitignore"), user).unwrap();
+    assert_eq!(update(td.path()), format!("{user}{BLOCK}"));
+}
+
+#[test]
+fn replaces_existing_block_in_place() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::write(
+        td.path().join(".gitignore"),
+        "a\n# ITO:START\n.ito/obsolete\n# ITO:END\nb\n",
+    )
+    .unwrap();
+    let s = update(td.path());
+    let block = BLOCK.trim_end_matches('\n');
+    assert_eq!(s, format!("a\n{block}\nb\n"));
+}
+
+#[test]
+fn update_is_idempotent() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::write(td.path().join(".gitignore"), "dist/\n.ito/session.json\n").unwrap();
+    let first = update(td.path());
+    let second = update(td.path());
+    assert_eq!(first, second);
+    assert_eq!(
+        first.lines().filter(|l| *l == ".ito/session.json").count(),
+        1
+    );
+}
+
+#[test]
+fn keeps_crlf_line_endings() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::write(td.path().join(".gitignore"), "dist/\r\n").unwrap();
+    let s = update(td.path());
+    assert_eq!(s, format!("dist/\r\n{}", BLOCK.replace('\n', "\r\n")));
+}
+
+#[test]
+fn rejects_unbalanced_markers() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::write(td.path().join(".gitignore"), "# ITO:START\nfoo\n").unwrap();
+    assert!(update_repo_gitignore_fs(&StdFs, td.path(), ".ito").is_err());
+}
@@ -0,0 +1,66 @@
+//! Audit events recording `ito init` / `ito update` installs.
+
+use std::path::Path;
+
+use ito_domain::audit::context::{resolve_context, resolve_user_identity};
+use ito_domain::audit::event::{Actor, AuditEvent, AuditEventBuilder, EntityType, ops};
+use ito_domain::audit::writer::AuditWriter;
+
+use super::{InitOptions, InstallMode};
+
+/// Build the `project.init` / `project.update` event for an install.
+///
+/// The entity id is the project directory name. The metadata records the
+/// selected tools, the template version, and the install flags.
+pub fn install_audit_event(
+    ito_path: &Path,
+    mode: InstallMode,
+    opts: &InitOptions,
+) -> Option<AuditEvent> {
+    let op = match mode {
+        InstallMode::Init => ops::PROJECT_INIT,
+        InstallMode::Update => ops::PROJECT_UPDATE,
+    };
+    let project_id = ito_path
+        .parent()
+        .and_then(|root| root.canonicalize().ok())
+        .and_then(|root| root.file_name().map(|n| n.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "project".to_string());
+    let template_version =
+        option_env!("ITO_WORKSPACE_VERSION").unwrap_or(env!("CARGO_PKG_VERSION"));
+
+    AuditEventBuilder::new()
+        .entity(EntityType::Project)
+        .entity_id(project_id)
+        .op(op)
+        .actor(Actor::Cli)
+        .by(resolve_user_identity())
+        .meta(serde_json::json!({
+            "tools": opts.tools,
+            "template_version": template_version,
+            "force": opts.force,
+            "update": opts.update,
+            "upgrade": opts.upgrade,
+            "force_managed": opts.force_managed,
+        }))
+        .ctx(resolve_context(ito_path))
+        .build()
+}
+
+/// Record an install in the audit log.
+///
+/// `audit` is optional so library consumers and previews can install without
+/// writing events; `None` records nothing. Writing is best-effort.
+pub fn record_install_event(
+    audit: Option<&dyn AuditWriter>,
+    ito_path: &Path,
+    mode: InstallMode,
+    opts: &InitOptions,
+) {
+    let Some(audit) = audit else {
+        return;
+    };
+    if let Some(event) = install_audit_event(ito_path, mode, opts) {
+        let _ = audit.append(&event);
+    }
+}
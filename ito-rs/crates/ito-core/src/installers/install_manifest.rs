@@ -0,0 +1,149 @@
+//! Hashes of Ito-managed files written by `ito init` / `ito update`.
+//!
+//! The manifest lets `ito update` tell whether a fully managed (non-marker)
+//! file still matches what Ito last installed. Files whose on-disk content no
+//! longer matches the recorded hash were edited locally and are skipped
+//! instead of being silently overwritten.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use ito_common::fs::{FileSystem, StdFs};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::errors::{CoreError, CoreResult};
+
+/// Manifest location relative to the Ito directory.
+pub const INSTALL_MANIFEST_REL: &str = ".state/install-manifest.json";
+
+const INSTALL_MANIFEST_VERSION: u32 = 1;
+
+/// Recorded SHA-256 hashes of installed managed files, keyed by project-relative path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstallManifest {
+    /// Manifest format version.
+    pub version: u32,
+    /// Project-relative path (forward slashes) to hex-encoded SHA-256 of the installed bytes.
+    #[serde(default)]
+    pub files: BTreeMap<String, String>,
+    /// Harness command files installed from user command sources, keyed by
+    /// project-relative path, with the hash of the installed bytes.
+    ///
+    /// Tracked separately so `ito update` can remove copies whose source was
+    /// deleted.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub user_commands: BTreeMap<String, String>,
+    /// Harness adapter files (plugins, hooks, bootstraps), keyed by
+    /// project-relative path, with the hash of the installed bytes.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub adapters: BTreeMap<String, String>,
+}
+
+impl Default for InstallManifest {
+    fn default() -> Self {
+        Self {
+            version: INSTALL_MANIFEST_VERSION,
+            files: BTreeMap::new(),
+            user_commands: BTreeMap::new(),
+            adapters: BTreeMap::new(),
+        }
+    }
+}
+
+impl InstallManifest {
+    /// Record the installed bytes for `rel`.
+    pub fn record(&mut self, rel: &str, bytes: &[u8]) {
+        self.files.insert(rel.to_string(), content_hash(bytes));
+    }
+
+    /// Recorded hash for `rel`, if any.
+    pub fn recorded_hash(&self, rel: &str) -> Option<&str> {
+        self.files.get(rel).map(String::as_str)
+    }
+}
+
+/// Path of the install manifest inside `ito_path`.
+pub fn install_manifest_path(ito_path: &Path) -> PathBuf {
+    ito_path.join(INSTALL_MANIFEST_REL)
+}
+
+/// Manifest key for `path`: project-relative with forward slashes.
+pub(crate) fn manifest_key(project_root: &Path, path: &Path) -> String {
+    let rel = path.strip_prefix(project_root).unwrap_or(path);
+    rel.to_string_lossy().replace('\\', "/")
+}
+
+/// Hex-encoded SHA-256 of `bytes`.
+pub fn content_hash(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    hex::encode(hasher.finalize())
+}
+
+/// Load the install manifest, returning `None` when it is missing or unreadable.
+///
+/// A missing manifest means the project predates drift detection; callers
+/// fall back to the previous overwrite behavior.
+pub fn load_install_manifest(ito_path: &Path) -> Option<InstallManifest> {
+    load_install_manifest_fs(&StdFs, ito_path)
+}
+
+/// Like [`load_install_manifest`], but reads through `fs`.
+pub(crate) fn load_install_manifest_fs(
+    fs: &dyn FileSystem,
+    ito_path: &Path,
+) -> Option<InstallManifest> {
+    let contents = fs.read_to_string(&install_manifest_path(ito_path)).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Write the install manifest to `<itoDir>/.state/install-manifest.json`.
+///
+/// # Errors
+///
+/// Returns an error when the manifest cannot be serialized or written.
+pub fn save_install_manifest(ito_path: &Path, manifest: &InstallManifest) -> CoreResult<()> {
+    save_install_manifest_fs(&StdFs, ito_path, manifest)
+}
+
+/// Like [`save_install_manifest`], but writes through `fs`.
+pub(crate) fn save_install_manifest_fs(
+    fs: &dyn FileSystem,
+    ito_path: &Path,
+    manifest: &InstallManifest,
+) -> CoreResult<()> {
+    let path = install_manifest_path(ito_path);
+    if let Some(parent) = path.parent() {
+        fs.create_dir_all(parent)
+            .map_err(|e| CoreError::io(format!("creating directory {}", parent.display()), e))?;
+    }
+    let mut contents = serde_json::to_string_pretty(manifest)
+        .map_err(|e| CoreError::serde("serializing install manifest", e.to_string()))?;
+    contents.push('\n');
+    fs.write(&path, contents.as_bytes())
+        .map_err(|e| CoreError::io(format!("writing {}", path.display()), e))
+}
+
+/// Returns `true` when `existing` differs from both the recorded hash and the
+/// bytes about to be installed, i.e. the file was edited after Ito wrote it.
+///
+/// Files without a recorded hash are never reported as modified.
+pub(crate) fn is_locally_modified(
+    manifest: &InstallManifest,
+    rel: &str,
+    existing: &[u8],
+    rendered: &[u8],
+) -> bool {
+    let Some(recorded) = manifest.recorded_hash(rel) else {
+        return false;
+    };
+    if existing == rendered {
+        return false;
+    }
+    content_hash(existing) != recorded
+}
+
+#[cfg(test)]
+#[path = "install_manifest_tests.rs"]
+mod install_manifest_tests;
@@ -0,0 +1,41 @@
+use super::*;
+
+#[test]
+fn content_hash_is_hex_sha256() {
+    assert_eq!(
+        content_hash(b""),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+}
+
+#[test]
+fn manifest_round_trips_through_state_dir() {
+    let td = tempfile::tempdir().unwrap();
+    let mut manifest = InstallManifest::default();
+    manifest.record(".pi/settings.json", b"{}\n");
+
+    save_install_manifest(td.path(), &manifest).unwrap();
+    assert!(td.path().join(".state/install-manifest.json").exists());
+    assert_eq!(load_install_manifest(td.path()), Some(manifest));
+}
+
+#[test]
+fn missing_or_invalid_manifest_loads_as_none() {
+    let td = tempfile::tempdir().unwrap();
+    assert_eq!(load_install_manifest(td.path()), None);
+
+    std::fs::create_dir_all(td.path().join(".state")).unwrap();
+    std::fs::write(install_manifest_path(td.path()), "not json").unwrap();
+    assert_eq!(load_install_manifest(td.path()), None);
+}
+
+#[test]
+fn locally_modified_requires_recorded_hash_mismatch() {
+    let mut manifest = InstallManifest::default();
+    manifest.record("a.yml", b"v1");
+
+    assert!(!is_locally_modified(&manifest, "a.yml", b"v1", b"v2"));
+    assert!(is_locally_modified(&manifest, "a.yml", b"edited", b"v2"));
+    assert!(!is_locally_modified(&manifest, "a.yml", b"v2", b"v2"));
+    assert!(!is_locally_modified(&manifest, "b.yml", b"edited", b"v2"));
+}
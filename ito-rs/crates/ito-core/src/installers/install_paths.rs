@@ -0,0 +1,96 @@
+//! Path resolution for template installs.
+//!
+//! Every install target is derived from one resolved project root, so a
+//! symlinked checkout is not written once through the link and again through
+//! its real path. Symlinked target files are resolved once as well, so the
+//! existence check, read, and write for a target all see the same file.
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::path::{Path, PathBuf};
+
+use ito_common::fs::FileSystem;
+use ito_config::ito_dir::lexical_normalize;
+
+/// Lexically normalize `project_root`, then canonicalize it when it exists.
+pub(super) fn resolve_project_root(fs: &dyn FileSystem, project_root: &Path) -> PathBuf {
+    let normalized = lexical_normalize(project_root);
+    if !fs.exists(&normalized) {
+        return normalized;
+    }
+    fs.canonicalize(&normalized).unwrap_or(normalized)
+}
+
+/// Resolve a symlinked install target to the file it points at.
+///
+/// Other targets, including dangling links, are returned unchanged.
+pub(super) fn resolve_target(fs: &dyn FileSystem, target: &Path) -> PathBuf {
+    if fs.is_symlink(target)
+        && let Ok(resolved) = fs.canonicalize(target)
+    {
+        return resolved;
+    }
+    target.to_path_buf()
+}
+
+/// Whether `dir` lives on a case-insensitive file system.
+///
+/// Probes the nearest ancestor whose name contains a cased letter by looking
+/// it up with the case of every letter flipped. Returns `false` when no such
+/// ancestor exists.
+pub(super) fn is_case_insensitive(fs: &dyn FileSystem, dir: &Path) -> bool {
+    for ancestor in dir.ancestors() {
+        let Some(name) = ancestor.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let flipped: String = name.chars().map(flip_case).collect();
+        if flipped != name {
+            return fs.exists(&ancestor.with_file_name(flipped));
+        }
+    }
+    false
+}
+
+fn flip_case(c: char) -> char {
+    if c.is_lowercase() {
+        c.to_uppercase().next().unwrap_or(c)
+    } else {
+        c.to_lowercase().next().unwrap_or(c)
+    }
+}
+
+/// Find two relative paths that name the same file or directory when case is
+/// ignored, e.g. `.github/a.md` and `.GitHub/b.md`.
+///
+/// Returns the first colliding pair in input order.
+pub(super) fn find_case_collision<'a>(
+    rels: impl IntoIterator<Item = &'a str>,
+) -> Option<(String, String)> {
+    // Lowercased path prefix -> (prefix as written, full path it came from).
+    let mut seen: BTreeMap<String, (String, &'a str)> = BTreeMap::new();
+    for rel in rels {
+        let mut prefix = String::new();
+        for part in rel.split('/') {
+            if !prefix.is_empty() {
+                prefix.push('/');
+            }
+            prefix.push_str(part);
+            match seen.entry(prefix.to_lowercase()) {
+                Entry::Vacant(entry) => {
+                    entry.insert((prefix.clone(), rel));
+                }
+                Entry::Occupied(entry) => {
+                    let (written, first) = entry.get();
+                    if *written != prefix {
+                        return Some((first.to_string(), rel.to_string()));
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+#[path = "install_paths_tests.rs"]
+mod install_paths_tests;
@@ -0,0 +1,80 @@
+use super::*;
+
+use ito_common::fs::{MemFs, StdFs};
+
+#[test]
+fn find_case_collision_names_both_paths() {
+    let rels = [".github/prompts/a.md", "AGENTS.md", ".GitHub/skills/b.md"];
+    assert_eq!(
+        find_case_collision(rels),
+        Some((
+            ".github/prompts/a.md".to_string(),
+            ".GitHub/skills/b.md".to_string()
+        ))
+    );
+}
+
+#[test]
+fn find_case_collision_detects_files_differing_only_in_case() {
+    let rels = [".ito/AGENTS.md", ".ito/agents.md"];
+    assert_eq!(
+        find_case_collision(rels),
+        Some((".ito/AGENTS.md".to_string(), ".ito/agents.md".to_string()))
+    );
+}
+
+#[test]
+fn find_case_collision_accepts_shared_directories() {
+    let rels = [
+        ".github/a.md",
+        ".github/b.md",
+        "AGENTS.md",
+        ".ito/AGENTS.md",
+    ];
+    assert_eq!(find_case_collision(rels), None);
+}
+
+#[test]
+fn is_case_insensitive_is_false_for_case_sensitive_fs() {
+    let fs = MemFs::new();
+    fs.create_dir_all(Path::new("/Project")).unwrap();
+    assert!(!is_case_insensitive(&fs, Path::new("/Project")));
+}
+
+#[test]
+fn is_case_insensitive_matches_the_real_file_system() {
+    let td = tempfile::tempdir().unwrap();
+    let dir = td.path().join("Probe");
+    std::fs::create_dir(&dir).unwrap();
+    let expected = td.path().join("pROBE").exists();
+    assert_eq!(is_case_insensitive(&StdFs, &dir), expected);
+}
+
+#[test]
+fn resolve_target_follows_symlinked_files_once() {
+    let fs = MemFs::new();
+    let target = Path::new("/project/AGENTS.md");
+    assert_eq!(resolve_target(&fs, target), target);
+
+    #[cfg(unix)]
+    {
+        let td = tempfile::tempdir().unwrap();
+        let real = td.path().join("real.md");
+        std::fs::write(&real, "x").unwrap();
+        let link = td.path().join("AGENTS.md");
+        std::os::unix::fs::symlink(&real, &link).unwrap();
+        assert_eq!(
+            resolve_target(&StdFs, &link),
+            std::fs::canonicalize(&real).unwrap()
+        );
+    }
+}
+
+#[test]
+fn resolve_project_root_normalizes_missing_roots() {
+    let fs = MemFs::new();
+    assert_eq!(
+        resolve_project_root(&fs, Path::new("/a/./b/../c")),
+        PathBuf::from("/a/c")
+    );
+}
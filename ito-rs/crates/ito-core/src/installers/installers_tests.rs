@@ -1,112 +1,5 @@
 use super::*;
 
-#[test]
-fn gitignore_created_when_missing() {
-    let td = tempfile::tempdir().unwrap();
-    ensure_repo_gitignore_ignores_session_json(td.path(), ".ito").unwrap();
-    let s = std::fs::read_to_string(td.path().join(".gitignore")).unwrap();
-    assert_eq!(s, ".ito/session.json\n");
-}
-
-#[test]
-fn gitignore_noop_when_already_present() {
-    let td = tempfile::tempdir().unwrap();
-    std::fs::write(td.path().join(".gitignore"), ".ito/session.json\n").unwrap();
-    ensure_repo_gitignore_ignores_session_json(td.path(), ".ito").unwrap();
-    let s = std::fs::read_to_string(td.path().join(".gitignore")).unwrap();
-    assert_eq!(s, ".ito/session.json\n");
-}
-
-#[test]
-fn gitignore_does_not_duplicate_on_repeated_calls() {
-    let td = tempfile::tempdir().unwrap();
-    std::fs::write(td.path().join(".gitignore"), "node_modules\n").unwrap();
-    ensure_repo_gitignore_ignores_session_json(td.path(), ".ito").unwrap();
-    ensure_repo_gitignore_ignores_session_json(td.path(), ".ito").unwrap();
-    let s = std::fs::read_to_string(td.path().join(".gitignore")).unwrap();
-    assert_eq!(s, "node_modules\n.ito/session.json\n");
-}
-
-#[test]
-fn gitignore_audit_session_added() {
-    let td = tempfile::tempdir().unwrap();
-    ensure_repo_gitignore_ignores_audit_session(td.path(), ".ito").unwrap();
-    let s = std::fs::read_to_string(td.path().join(".gitignore")).unwrap();
-    assert!(s.contains(".ito/.state/audit/.session"));
-}
-
-#[test]
-fn gitignore_both_session_entries() {
-    let td = tempfile::tempdir().unwrap();
-    ensure_repo_gitignore_ignores_session_json(td.path(), ".ito").unwrap();
-    ensure_repo_gitignore_ignores_audit_session(td.path(), ".ito").unwrap();
-    let s = std::fs::read_to_string(td.path().join(".gitignore")).unwrap();
-    assert!(s.contains(".ito/session.json"));
-    assert!(s.contains(".ito/.state/audit/.session"));
-}
-
-#[test]
-fn gitignore_preserves_existing_content_and_adds_newline_if_missing() {
-    let td = tempfile::tempdir().unwrap();
-    std::fs::write(td.path().join(".gitignore"), "node_modules").unwrap();
-    ensure_repo_gitignore_ignores_session_json(td.path(), ".ito").unwrap();
-    let s = std::fs::read_to_string(td.path().join(".gitignore")).unwrap();
-    assert_eq!(s, "node_modules\n.ito/session.json\n");
-}
-
-#[test]
-fn gitignore_legacy_audit_events_unignore_removed() {
-    let td = tempfile::tempdir().unwrap();
-    std::fs::write(
-        td.path().join(".gitignore"),
-        ".ito/.state/\n!.ito/.state/audit/\n",
-    )
-    .unwrap();
-    remove_repo_gitignore_unignores_audit_events(td.path(), ".ito").unwrap();
-    let s = std::fs::read_to_string(td.path().join(".gitignore")).unwrap();
-    assert_eq!(s, ".ito/.state/\n");
-}
-
-#[test]
-fn gitignore_legacy_audit_events_unignore_noop_when_absent() {
-    let td = tempfile::tempdir().unwrap();
-    std::fs::write(td.path().join(".gitignore"), ".ito/.state/\n").unwrap();
-    remove_repo_gitignore_unignores_audit_events(td.path(), ".ito").unwrap();
-    let s = std::fs::read_to_string(td.path().join(".gitignore")).unwrap();
-    assert_eq!(s, ".ito/.state/\n");
-}
-
-#[test]
-fn gitignore_full_audit_setup() {
-    let td = tempfile::tempdir().unwrap();
-    // Simulate a broad .state/ ignore
-    std::fs::write(
-        td.path().join(".gitignore"),
-        ".ito/.state/\n!.ito/.state/audit/\n",
-    )
-    .unwrap();
-    ensure_repo_gitignore_ignores_audit_session(td.path(), ".ito").unwrap();
-    remove_repo_gitignore_unignores_audit_events(td.path(), ".ito").unwrap();
-    let s = std::fs::read_to_string(td.path().join(".gitignore")).unwrap();
-    assert!(s.contains(".ito/.state/audit/.session"));
-    assert!(!s.contains("!.ito/.state/audit/"));
-}
-
-#[test]
-fn gitignore_ignores_local_configs() {
-    let td = tempfile::tempdir().unwrap();
-    ensure_repo_gitignore_ignores_local_configs(td.path(), ".ito").unwrap();
-    let s = std::fs::read_to_string(td.path().join(".gitignore")).unwrap();
-    assert!(s.contains(".ito/config.local.json"));
-    assert!(s.contains(".local/ito/config.json"));
-}
-
-#[test]
-fn gitignore_exact_line_matching_trims_whitespace() {
-    assert!(gitignore_has_exact_line("  foo  \nbar\n", "foo"));
-    assert!(!gitignore_has_exact_line("foo\n", "bar"));
-}
-
 #[test]
 fn should_install_project_rel_filters_by_tool_id() {
     let mut tools = BTreeSet::new();
@@ -322,3 +215,76 @@ fn write_one_marker_managed_files_error_when_markers_missing_in_update_mode() {
     .unwrap_err();
     assert!(err.to_string().contains("Failed to update markers"));
 }
+
+#[test]
+fn write_one_new_files_follow_the_resolved_line_ending() {
+    let td = tempfile::tempdir().unwrap();
+    let target = td.path().join("plain.txt");
+
+    let opts = InitOptions::new(BTreeSet::new(), false, false).with_line_ending(LineEnding::Crlf);
+    write_one(
+        &target,
+        b"one\ntwo\n",
+        InstallMode::Init,
+        &opts,
+        FileOwnership::ItoManaged,
+    )
+    .unwrap();
+    let s = std::fs::read_to_string(&target).unwrap();
+    assert_eq!(s, "one\r\ntwo\r\n");
+}
+
+#[test]
+fn write_one_marker_managed_files_keep_existing_crlf() {
+    let td = tempfile::tempdir().unwrap();
+    let target = td.path().join("managed.md");
+    let existing = format!(
+        "before\r\n{}\r\nold\r\n{}\r\nafter\r\n",
+        ito_templates::ITO_START_MARKER,
+        ito_templates::ITO_END_MARKER
+    );
+    std::fs::write(&target, existing).unwrap();
+
+    let template = format!(
+        "{}\nnew\nlines\n{}\n",
+        ito_templates::ITO_START_MARKER,
+        ito_templates::ITO_END_MARKER
+    );
+    let opts = InitOptions::new(BTreeSet::new(), false, true);
+    write_one(
+        &target,
+        template.as_bytes(),
+        InstallMode::Update,
+        &opts,
+        FileOwnership::ItoManaged,
+    )
+    .unwrap();
+    let s = std::fs::read_to_string(&target).unwrap();
+    assert_eq!(
+        s,
+        format!(
+            "before\r\n{}\r\nnew\r\nlines\r\n{}\r\nafter\r\n",
+            ito_templates::ITO_START_MARKER,
+            ito_templates::ITO_END_MARKER
+        )
+    );
+}
+
+#[test]
+fn config_template_references_schema_placeholder() {
+    let template = ito_templates::default_project_files()
+        .into_iter()
+        .find(|f| f.relative_path == ".ito/config.json")
+        .expect("config.json template");
+    let config: Value = serde_json::from_slice(template.contents).unwrap();
+    assert_eq!(config["$schema"], config_schema::CONFIG_SCHEMA_PLACEHOLDER);
+}
+
+#[test]
+fn config_schema_ref_prefers_schema_server() {
+    assert_eq!(config_schema_ref(None), "./config.schema.json");
+    assert_eq!(
+        config_schema_ref(Some("http://127.0.0.1:9009/")),
+        "http://127.0.0.1:9009/schema/config.json"
+    );
+}
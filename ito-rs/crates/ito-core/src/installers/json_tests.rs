@@ -208,3 +208,26 @@ fn write_claude_settings_preserves_invalid_json_on_update() {
     let updated = std::fs::read_to_string(&target).unwrap();
     assert_eq!(updated, "not-json\n");
 }
+
+#[test]
+fn write_claude_settings_keeps_crlf_when_merging() {
+    let td = tempfile::tempdir().unwrap();
+    let target = td.path().join(".claude/settings.json");
+    std::fs::create_dir_all(target.parent().unwrap()).unwrap();
+    std::fs::write(
+        &target,
+        "{\r\n  \"permissions\": {\r\n    \"allow\": [\"Bash(ls)\"]\r\n  }\r\n}\r\n",
+    )
+    .unwrap();
+
+    let template = b"{\n  \"permissions\": {\n    \"allow\": [\"Bash(git status)\"]\n  }\n}\n";
+    let opts = InitOptions::new(BTreeSet::new(), false, true);
+    write_claude_settings(&target, template, InstallMode::Update, &opts).unwrap();
+
+    let updated = std::fs::read_to_string(&target).unwrap();
+    assert!(updated.contains("Bash(git status)"));
+    assert_eq!(
+        updated.matches('\n').count(),
+        updated.matches("\r\n").count()
+    );
+}
@@ -0,0 +1,223 @@
+//! Line-ending handling for installed template files.
+//!
+//! Embedded templates are authored with LF. On checkouts that use CRLF (for
+//! example Windows with `core.autocrlf=true`) writing them verbatim makes git
+//! report every installed file as modified, and marker-managed updates flip
+//! endings back and forth. Existing files therefore keep their dominant line
+//! ending; new files follow the `install.line_endings` policy.
+
+use std::borrow::Cow;
+use std::path::Path;
+
+use ito_common::fs::FileSystem;
+use ito_config::types::LineEndingPolicy;
+use ito_config::{ConfigContext, load_cascading_project_config};
+
+use crate::config::json_get_path;
+use crate::errors::{CoreError, CoreResult};
+
+/// Config key (in the cascading project config) selecting the line-ending policy.
+pub const LINE_ENDINGS_CONFIG_KEY: &str = "install.line_endings";
+
+/// Maximum number of tracked files sampled when detecting the repository convention.
+const SAMPLE_LIMIT: usize = 32;
+
+/// Files larger than this are skipped while sampling.
+const SAMPLE_MAX_BYTES: u64 = 256 * 1024;
+
+/// A concrete line ending.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineEnding {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    Crlf,
+}
+
+impl LineEnding {
+    /// The line terminator as a string.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::Crlf => "\r\n",
+        }
+    }
+}
+
+/// Dominant line ending of `text`, or `None` when it has no line breaks.
+///
+/// Ties resolve to LF.
+pub(crate) fn detect_line_ending(text: &str) -> Option<LineEnding> {
+    let crlf = text.matches("\r\n").count();
+    let lf = text.matches('\n').count() - crlf;
+    if crlf == 0 && lf == 0 {
+        return None;
+    }
+    if crlf > lf {
+        Some(LineEnding::Crlf)
+    } else {
+        Some(LineEnding::Lf)
+    }
+}
+
+/// Rewrite every line break in `text` to `ending`.
+pub(crate) fn convert_line_endings(text: &str, ending: LineEnding) -> Cow<'_, str> {
+    match ending {
+        LineEnding::Lf if !text.contains("\r\n") => Cow::Borrowed(text),
+        LineEnding::Lf => Cow::Owned(text.replace("\r\n", "\n")),
+        LineEnding::Crlf => {
+            let normalized = text.replace("\r\n", "\n");
+            Cow::Owned(normalized.replace('\n', "\r\n"))
+        }
+    }
+}
+
+/// Convert UTF-8 `bytes` to `ending`; non-UTF-8 content is returned unchanged.
+pub(crate) fn localize_bytes(bytes: &[u8], ending: LineEnding) -> Cow<'_, [u8]> {
+    let Ok(text) = std::str::from_utf8(bytes) else {
+        return Cow::Borrowed(bytes);
+    };
+    match convert_line_endings(text, ending) {
+        Cow::Borrowed(_) => Cow::Borrowed(bytes),
+        Cow::Owned(converted) => Cow::Owned(converted.into_bytes()),
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn target_line_ending(target: &Path, default: LineEnding) -> LineEnding {
+    target_line_ending_fs(&ito_common::fs::StdFs, target, default)
+}
+
+/// Line ending to use when writing `target`.
+///
+/// An existing text file keeps its dominant ending; otherwise `default` applies.
+pub(crate) fn target_line_ending_fs(
+    fs: &dyn FileSystem,
+    target: &Path,
+    default: LineEnding,
+) -> LineEnding {
+    fs.read(target)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .and_then(|text| detect_line_ending(&text))
+        .unwrap_or(default)
+}
+
+/// Read the `install.line_endings` policy from the cascading project config.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Validation`] when the configured value is not one of
+/// `auto`, `lf`, or `crlf`.
+pub(crate) fn configured_policy(
+    project_root: &Path,
+    ito_path: &Path,
+    ctx: &ConfigContext,
+) -> CoreResult<LineEndingPolicy> {
+    let merged = load_cascading_project_config(project_root, ito_path, ctx).merged;
+    let parts: Vec<&str> = LINE_ENDINGS_CONFIG_KEY.split('.').collect();
+    let Some(value) = json_get_path(&merged, &parts) else {
+        return Ok(LineEndingPolicy::Auto);
+    };
+    serde_json::from_value(value.clone()).map_err(|_| {
+        CoreError::validation(format!(
+            "Invalid {LINE_ENDINGS_CONFIG_KEY} value {value} (expected \"auto\", \"lf\", or \"crlf\")"
+        ))
+    })
+}
+
+/// Resolve `policy` to the line ending used for newly created files.
+///
+/// `auto` honors a repository-wide `eol=` rule in `.gitattributes`, then the
+/// dominant ending of a sample of tracked text files, and falls back to LF.
+pub(crate) fn resolve_new_file_line_ending(
+    project_root: &Path,
+    policy: LineEndingPolicy,
+) -> LineEnding {
+    match policy {
+        LineEndingPolicy::Lf => LineEnding::Lf,
+        LineEndingPolicy::Crlf => LineEnding::Crlf,
+        LineEndingPolicy::Auto => gitattributes_line_ending(project_root)
+            .or_else(|| sample_tracked_line_ending(project_root))
+            .unwrap_or_default(),
+    }
+}
+
+/// The `eol=` attribute applied to every file (`* ... eol=crlf`), if any.
+///
+/// Later matching lines win, mirroring git's attribute precedence.
+fn gitattributes_line_ending(project_root: &Path) -> Option<LineEnding> {
+    let contents = std::fs::read_to_string(project_root.join(".gitattributes")).ok()?;
+    let mut out = None;
+    for line in contents.lines() {
+        let mut fields = line.split_whitespace();
+        if fields.next() != Some("*") {
+            continue;
+        }
+        for attr in fields {
+            match attr {
+                "eol=lf" => out = Some(LineEnding::Lf),
+                "eol=crlf" => out = Some(LineEnding::Crlf),
+                _ => {}
+            }
+        }
+    }
+    out
+}
+
+/// Majority line ending across a sample of tracked text files.
+///
+/// Working-tree bytes are sampled (not the index), so checkouts converted by
+/// `core.autocrlf` report CRLF. Returns `None` outside a git repository or
+/// when no sampled file has line breaks.
+fn sample_tracked_line_ending(project_root: &Path) -> Option<LineEnding> {
+    let output = std::process::Command::new("git")
+        .args(["ls-files", "-z"])
+        .current_dir(project_root)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let listing = String::from_utf8_lossy(&output.stdout);
+    let mut crlf = 0usize;
+    let mut lf = 0usize;
+    for rel in listing.split('\0').filter(|rel| !rel.is_empty()) {
+        if crlf + lf >= SAMPLE_LIMIT {
+            break;
+        }
+        let path = project_root.join(rel);
+        let Ok(metadata) = std::fs::metadata(&path) else {
+            continue;
+        };
+        if !metadata.is_file() || metadata.len() > SAMPLE_MAX_BYTES {
+            continue;
+        }
+        let Ok(bytes) = std::fs::read(&path) else {
+            continue;
+        };
+        if bytes.contains(&0) {
+            continue;
+        }
+        let Ok(text) = String::from_utf8(bytes) else {
+            continue;
+        };
+        match detect_line_ending(&text) {
+            Some(LineEnding::Crlf) => crlf += 1,
+            Some(LineEnding::Lf) => lf += 1,
+            None => {}
+        }
+    }
+
+    match (crlf, lf) {
+        (0, 0) => None,
+        (crlf, lf) if crlf > lf => Some(LineEnding::Crlf),
+        _ => Some(LineEnding::Lf),
+    }
+}
+
+#[cfg(test)]
+#[path = "line_endings_tests.rs"]
+mod line_endings_tests;
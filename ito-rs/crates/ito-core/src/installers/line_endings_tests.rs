@@ -0,0 +1,107 @@
+use super::*;
+
+#[test]
+fn detect_line_ending_picks_the_dominant_ending() {
+    assert_eq!(detect_line_ending("no breaks"), None);
+    assert_eq!(detect_line_ending("a\nb\n"), Some(LineEnding::Lf));
+    assert_eq!(detect_line_ending("a\r\nb\r\n"), Some(LineEnding::Crlf));
+    assert_eq!(detect_line_ending("a\r\nb\r\nc\n"), Some(LineEnding::Crlf));
+    assert_eq!(detect_line_ending("a\r\nb\n"), Some(LineEnding::Lf));
+}
+
+#[test]
+fn convert_line_endings_normalizes_mixed_input() {
+    assert_eq!(
+        convert_line_endings("a\r\nb\nc", LineEnding::Crlf),
+        "a\r\nb\r\nc"
+    );
+    assert_eq!(convert_line_endings("a\r\nb\nc", LineEnding::Lf), "a\nb\nc");
+    assert!(matches!(
+        convert_line_endings("a\nb\n", LineEnding::Lf),
+        Cow::Borrowed(_)
+    ));
+}
+
+#[test]
+fn localize_bytes_leaves_binary_content_alone() {
+    let binary = [0xff, b'\n', 0xfe];
+    assert_eq!(&*localize_bytes(&binary, LineEnding::Crlf), &binary);
+    assert_eq!(&*localize_bytes(b"x\n", LineEnding::Crlf), b"x\r\n");
+}
+
+#[test]
+fn target_line_ending_prefers_the_existing_file() {
+    let td = tempfile::tempdir().unwrap();
+    let crlf = td.path().join("crlf.md");
+    std::fs::write(&crlf, "a\r\nb\r\n").unwrap();
+    let single_line = td.path().join("single.md");
+    std::fs::write(&single_line, "a").unwrap();
+
+    assert_eq!(target_line_ending(&crlf, LineEnding::Lf), LineEnding::Crlf);
+    assert_eq!(
+        target_line_ending(&single_line, LineEnding::Crlf),
+        LineEnding::Crlf
+    );
+    assert_eq!(
+        target_line_ending(&td.path().join("missing.md"), LineEnding::Crlf),
+        LineEnding::Crlf
+    );
+}
+
+#[test]
+fn explicit_policies_ignore_the_repository() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::write(td.path().join(".gitattributes"), "* text eol=crlf\n").unwrap();
+
+    assert_eq!(
+        resolve_new_file_line_ending(td.path(), LineEndingPolicy::Lf),
+        LineEnding::Lf
+    );
+    assert_eq!(
+        resolve_new_file_line_ending(td.path(), LineEndingPolicy::Auto),
+        LineEnding::Crlf
+    );
+}
+
+#[test]
+fn gitattributes_only_honors_rules_for_every_file() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::write(
+        td.path().join(".gitattributes"),
+        "*.sh text eol=lf\n* text=auto eol=crlf\n*.bat eol=crlf\n* eol=lf\n",
+    )
+    .unwrap();
+    assert_eq!(gitattributes_line_ending(td.path()), Some(LineEnding::Lf));
+
+    std::fs::write(td.path().join(".gitattributes"), "*.sh text eol=lf\n").unwrap();
+    assert_eq!(gitattributes_line_ending(td.path()), None);
+}
+
+#[test]
+fn auto_policy_samples_tracked_files() {
+    let td = tempfile::tempdir().unwrap();
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(td.path())
+            .output()
+            .expect("git should run");
+        assert!(status.status.success(), "git {args:?} failed");
+    };
+    git(&["init", "-q"]);
+    std::fs::write(td.path().join("a.md"), "a\r\nb\r\n").unwrap();
+    std::fs::write(td.path().join("b.txt"), "c\r\nd\r\n").unwrap();
+    std::fs::write(td.path().join("c.txt"), "e\nf\n").unwrap();
+    std::fs::write(td.path().join("untracked.txt"), "g\nh\n").unwrap();
+    std::fs::write(td.path().join("untracked2.txt"), "i\nj\n").unwrap();
+    git(&["add", "a.md", "b.txt", "c.txt"]);
+
+    assert_eq!(
+        sample_tracked_line_ending(td.path()),
+        Some(LineEnding::Crlf)
+    );
+    assert_eq!(
+        resolve_new_file_line_ending(td.path(), LineEndingPolicy::Auto),
+        LineEnding::Crlf
+    );
+}
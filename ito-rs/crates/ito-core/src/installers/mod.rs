@@ -6,22 +6,59 @@ use serde_json::{Map, Value};
 
 use crate::errors::{CoreError, CoreResult};
 use agent_frontmatter::{
-    remove_agent_mode_field_for_direct_activation, update_agent_activation_field_from_rendered,
-    update_agent_model_field,
+    ModelFieldUpdate, remove_agent_mode_field_for_direct_activation_fs,
+    update_agent_activation_field_from_rendered_fs, update_agent_model_field_fs,
 };
 use agents_cleanup::remove_obsolete_specialist_agents;
 
-use markers::update_file_with_markers;
+use crate::managed_block::{ManagedBlockEdit, ManagedBlockError};
 
 mod agent_frontmatter;
+mod agent_models;
 mod agents_cleanup;
-mod markers;
+mod config_schema;
+mod gitignore;
+mod install_audit;
+mod install_manifest;
+mod install_paths;
+pub(crate) mod line_endings;
 mod project_guidance_cleanup;
 mod retired_cleanup;
+mod tool_detection;
+mod tool_selection;
+mod user_commands;
 
+pub use agent_models::{
+    MODELS_CONFIG_KEY, ModelPin, ModelSource, configured_models, effective_agent_configs,
+    model_pins,
+};
+pub use config_schema::{
+    CONFIG_SCHEMA_FILE, CONFIG_SCHEMA_ROUTE, SCHEMA_SERVER_CONFIG_KEY, config_schema_ref,
+};
+pub use install_audit::{install_audit_event, record_install_event};
+pub use install_manifest::{
+    INSTALL_MANIFEST_REL, InstallManifest, content_hash, install_manifest_path,
+    load_install_manifest, save_install_manifest,
+};
+pub use line_endings::{LINE_ENDINGS_CONFIG_KEY, LineEnding};
+pub(crate) use tool_detection::harness_binary;
+pub use tool_detection::{
+    DetectedTool, DetectionSource, detect_installed_tools, detect_installed_tools_fs,
+};
+pub use tool_selection::{
+    INIT_TOOLS_CONFIG_KEY, detect_configured_tools, detect_configured_tools_fs,
+    parse_tool_selection, record_tools, recorded_tools, resolve_update_tools,
+};
+pub use user_commands::{
+    GLOBAL_USER_COMMANDS_DIR, USER_COMMANDS_DIR, UserCommand, UserCommandScope, UserCommandsReport,
+    discover_user_commands, sync_user_commands,
+};
+
+use ito_common::fs::{FileSystem, StdFs};
 use ito_config::ConfigContext;
-use ito_config::ito_dir::get_ito_dir_name;
-use ito_templates::project_templates::WorktreeTemplateContext;
+use ito_config::ito_dir::{get_ito_dir_name, get_ito_dir_name_fs};
+use ito_templates::asset_source::AssetSource;
+use ito_templates::project_templates::{ProjectTemplateContext, WorktreeTemplateContext};
 
 /// Tool id for Claude Code.
 pub const TOOL_CLAUDE: &str = "claude";
@@ -34,8 +71,6 @@ pub const TOOL_OPENCODE: &str = "opencode";
 /// Tool id for Pi.
 pub const TOOL_PI: &str = "pi";
 
-const CONFIG_SCHEMA_RELEASE_TAG_PLACEHOLDER: &str = "__ITO_RELEASE_TAG__";
-
 /// Return the set of supported tool ids.
 pub fn available_tool_ids() -> &'static [&'static str] {
     &[
@@ -71,6 +106,29 @@ pub struct InitOptions {
     ///
     /// `upgrade` implies `update` semantics (user-owned files are preserved).
     pub upgrade: bool,
+    /// When `true`, overwrite fully managed files even when they were edited
+    /// locally since the last install (see the install manifest).
+    pub force_managed: bool,
+    /// Line ending for files Ito creates. Existing files keep their own.
+    ///
+    /// `None` defers to the `install.line_endings` config policy, which
+    /// [`install_default_templates`] resolves before writing.
+    pub line_ending: Option<LineEnding>,
+    /// `ito serve` base URL new `config.json` files reference for `$schema`.
+    ///
+    /// `None` references the generated `config.schema.json` next to it.
+    /// [`install_default_templates`] fills this from `install.schema_server`.
+    pub schema_server: Option<String>,
+    /// Models pinned per harness and tier for the rendered agent files.
+    ///
+    /// `None` keeps the embedded defaults. [`install_default_templates`]
+    /// fills this from the `models` config section.
+    pub models: Option<ito_config::types::ModelsConfig>,
+    /// Where template assets are read from.
+    ///
+    /// Defaults to the embedded assets; a pinned release bundle (see
+    /// [`crate::template_bundle`]) overrides them file by file.
+    pub asset_source: AssetSource,
 }
 
 impl InitOptions {
@@ -86,6 +144,11 @@ impl InitOptions {
             force,
             update,
             upgrade: false,
+            force_managed: false,
+            line_ending: None,
+            schema_server: None,
+            models: None,
+            asset_source: AssetSource::Embedded,
         }
     }
 
@@ -102,6 +165,11 @@ impl InitOptions {
             force: false,
             update: true,
             upgrade: true,
+            force_managed: false,
+            line_ending: None,
+            schema_server: None,
+            models: None,
+            asset_source: AssetSource::Embedded,
         }
     }
 
@@ -117,6 +185,41 @@ impl InitOptions {
         self.force = false;
         self
     }
+
+    /// Overwrite locally modified Ito-managed files instead of skipping them.
+    pub fn with_force_managed(mut self, force_managed: bool) -> Self {
+        self.force_managed = force_managed;
+        self
+    }
+
+    /// Write newly created files with `line_ending`, overriding the config policy.
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = Some(line_ending);
+        self
+    }
+
+    /// Reference `server`'s config schema from new `config.json` files.
+    pub fn with_schema_server(mut self, server: impl Into<String>) -> Self {
+        self.schema_server = Some(server.into());
+        self
+    }
+
+    /// Render agent files with the models pinned in `models`.
+    pub fn with_models(mut self, models: ito_config::types::ModelsConfig) -> Self {
+        self.models = Some(models);
+        self
+    }
+
+    /// Read template assets from `source` instead of the embedded assets.
+    pub fn with_asset_source(mut self, source: AssetSource) -> Self {
+        self.asset_source = source;
+        self
+    }
+
+    /// Line ending for newly created files (LF until resolved).
+    fn new_file_line_ending(&self) -> LineEnding {
+        self.line_ending.unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -136,9 +239,10 @@ enum FileOwnership {
 
 /// Install the default project templates and selected tool adapters.
 ///
-/// When `worktree_ctx` is `Some`, templates containing Jinja2 syntax will be
-/// rendered with the given worktree configuration. When `None`, a disabled
-/// default context is used.
+/// Rendered templates (`AGENTS.md`, `.j2` project files, worktree-aware
+/// skills) receive a [`ProjectTemplateContext`] built from `worktree_ctx`, the
+/// repository name, the selected tools, the Ito directory and the release tag.
+/// When `worktree_ctx` is `None`, a disabled default worktree context is used.
 pub fn install_default_templates(
     project_root: &Path,
     ctx: &ConfigContext,
@@ -146,28 +250,33 @@ pub fn install_default_templates(
     opts: &InitOptions,
     worktree_ctx: Option<&WorktreeTemplateContext>,
 ) -> CoreResult<()> {
+    let project_root = &install_paths::resolve_project_root(&StdFs, project_root);
     let ito_dir_name = get_ito_dir_name(project_root, ctx);
     let ito_dir = ito_templates::normalize_ito_dir(&ito_dir_name);
+    let opts = &resolve_line_ending(project_root, &ito_dir, ctx, opts)?;
+    let opts = &resolve_schema_server(project_root, &ito_dir, ctx, opts)?;
+    let opts = &resolve_models(project_root, &ito_dir, ctx, opts)?;
 
     if mode == InstallMode::Update || opts.update || opts.upgrade || opts.force {
         let report = retired_cleanup::cleanup_retired_surfaces(project_root, &opts.tools)?;
         for removed in report.removed {
             let replacement = removed.replacement.unwrap_or("no Ito replacement");
-            eprintln!(
+            ito_config::notice!(
                 "removed retired Ito surface {}; replacement: {replacement}",
                 removed.path.display()
             );
         }
         for preserved in report.preserved {
             let replacement = preserved.replacement.unwrap_or("no Ito replacement");
-            eprintln!(
+            ito_config::notice!(
                 "warning: preserving retired Ito surface {} because it contains user content outside the managed shell; replacement: {replacement}",
                 preserved.path.display()
             );
         }
     }
 
-    install_project_templates(project_root, &ito_dir, mode, opts, worktree_ctx)?;
+    let template_ctx = project_template_context(project_root, &ito_dir, opts, worktree_ctx);
+    install_project_templates(&StdFs, project_root, &ito_dir, mode, opts, &template_ctx)?;
 
     // The removed tmux skill occupied an Ito-owned skill directory in every
     // harness. Update-style installs prune only those exact legacy paths;
@@ -176,22 +285,128 @@ pub fn install_default_templates(
         remove_obsolete_tmux_skills(project_root)?;
     }
 
-    // Repository-local ignore rules for per-worktree state.
-    // This is not a templated file: we update `.gitignore` directly to preserve existing content.
-    if mode == InstallMode::Init {
-        ensure_repo_gitignore_ignores_session_json(project_root, &ito_dir)?;
-        ensure_repo_gitignore_ignores_audit_session(project_root, &ito_dir)?;
-        remove_repo_gitignore_unignores_audit_events(project_root, &ito_dir)?;
-    }
+    gitignore::update_repo_gitignore_fs(&StdFs, project_root, &ito_dir)?;
 
-    // Local (per-developer) config overlays should never be committed.
-    ensure_repo_gitignore_ignores_local_configs(project_root, &ito_dir)?;
+    let ito_path = project_root.join(&ito_dir);
+    let global_commands_dir =
+        ito_config::ito_config_dir(ctx).map(|d| d.join(GLOBAL_USER_COMMANDS_DIR));
+    let user_commands = discover_user_commands(&ito_path, global_commands_dir.as_deref())?;
+    let shadowed: BTreeSet<String> = user_commands
+        .iter()
+        .filter(|c| c.shadows_embedded())
+        .map(|c| c.name.clone())
+        .collect();
 
-    install_adapter_files(project_root, mode, opts, worktree_ctx)?;
-    install_agent_templates(project_root, mode, opts)?;
+    install_adapter_files(
+        project_root,
+        &ito_path,
+        mode,
+        opts,
+        &template_ctx,
+        &shadowed,
+    )?;
+    let report = sync_user_commands(project_root, &ito_path, &user_commands, &opts.tools)?;
+    for cmd in &report.shadowed {
+        ito_config::notice!(
+            "warning: user command {} shadows the embedded Ito command of the same name",
+            cmd.source.display()
+        );
+    }
+    for path in &report.removed {
+        ito_config::notice!(
+            "removed user command {} because its source was deleted",
+            path.display()
+        );
+    }
+    for path in &report.preserved {
+        ito_config::notice!(
+            "warning: keeping {} because it was edited after installation; its user command source was deleted",
+            path.display()
+        );
+    }
+    install_agent_templates(&StdFs, project_root, mode, opts)?;
     Ok(())
 }
 
+/// Install project templates, `.gitignore` rules and agent templates through `fs`.
+///
+/// This is the file-system-agnostic core of [`install_default_templates`],
+/// used to preview or test an install without touching the disk. Harness
+/// adapters, user commands and retired-surface cleanup are not part of it.
+/// The `install.line_endings`, `install.schema_server` and `models` settings
+/// are not consulted either: new files use `opts.line_ending` (falling back to
+/// LF), `opts.schema_server` and `opts.models`.
+pub fn install_project_files_fs<F: FileSystem>(
+    fs: &F,
+    project_root: &Path,
+    ctx: &ConfigContext,
+    mode: InstallMode,
+    opts: &InitOptions,
+    worktree_ctx: Option<&WorktreeTemplateContext>,
+) -> CoreResult<()> {
+    let project_root = &install_paths::resolve_project_root(fs, project_root);
+    let ito_dir_name = get_ito_dir_name_fs(fs, project_root, ctx);
+    let ito_dir = ito_templates::normalize_ito_dir(&ito_dir_name);
+
+    let template_ctx = project_template_context(project_root, &ito_dir, opts, worktree_ctx);
+    install_project_templates(fs, project_root, &ito_dir, mode, opts, &template_ctx)?;
+    gitignore::update_repo_gitignore_fs(fs, project_root, &ito_dir)?;
+    install_agent_templates(fs, project_root, mode, opts)
+}
+
+/// Fill in the line ending for new files from `install.line_endings` unless
+/// the caller already chose one.
+fn resolve_line_ending(
+    project_root: &Path,
+    ito_dir: &str,
+    ctx: &ConfigContext,
+    opts: &InitOptions,
+) -> CoreResult<InitOptions> {
+    if opts.line_ending.is_some() {
+        return Ok(opts.clone());
+    }
+    let policy = line_endings::configured_policy(project_root, &project_root.join(ito_dir), ctx)?;
+    let line_ending = line_endings::resolve_new_file_line_ending(project_root, policy);
+    Ok(opts.clone().with_line_ending(line_ending))
+}
+
+/// Fill in the schema server from `install.schema_server` unless the caller
+/// already chose one.
+fn resolve_schema_server(
+    project_root: &Path,
+    ito_dir: &str,
+    ctx: &ConfigContext,
+    opts: &InitOptions,
+) -> CoreResult<InitOptions> {
+    if opts.schema_server.is_some() {
+        return Ok(opts.clone());
+    }
+    let server =
+        config_schema::configured_schema_server(project_root, &project_root.join(ito_dir), ctx)?;
+    Ok(InitOptions {
+        schema_server: server,
+        ..opts.clone()
+    })
+}
+
+/// Fill in the agent model pins from the `models` config section unless the
+/// caller already chose them.
+fn resolve_models(
+    project_root: &Path,
+    ito_dir: &str,
+    ctx: &ConfigContext,
+    opts: &InitOptions,
+) -> CoreResult<InitOptions> {
+    if opts.models.is_some() {
+        return Ok(opts.clone());
+    }
+    let models = configured_models(project_root, &project_root.join(ito_dir), ctx)?;
+    Ok(InitOptions {
+        models,
+        ..opts.clone()
+    })
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// A known legacy Ito-managed path found in a project.
 pub struct LegacyPathHit {
@@ -273,154 +488,83 @@ fn remove_obsolete_tmux_skills(project_root: &Path) -> CoreResult<()> {
     remove_legacy_paths(project_root, &hits)
 }
 
-fn ensure_repo_gitignore_ignores_local_configs(
-    project_root: &Path,
-    ito_dir: &str,
-) -> CoreResult<()> {
-    // Strategy/worktree settings are often personal preferences; users can keep
-    // them in a local overlay file.
-    let entry = format!("{ito_dir}/config.local.json");
-    ensure_gitignore_contains_line(project_root, &entry)?;
-
-    // Optional convention: keep local configs under `.local/`.
-    let entry = ".local/ito/config.json";
-    ensure_gitignore_contains_line(project_root, entry)?;
-    Ok(())
-}
-
-fn ensure_repo_gitignore_ignores_session_json(
-    project_root: &Path,
-    ito_dir: &str,
-) -> CoreResult<()> {
-    let entry = format!("{ito_dir}/session.json");
-    ensure_gitignore_contains_line(project_root, &entry)
-}
-
-/// Ensure `.ito/.state/audit/.session` is gitignored (per-worktree UUID).
-fn ensure_repo_gitignore_ignores_audit_session(
-    project_root: &Path,
-    ito_dir: &str,
-) -> CoreResult<()> {
-    let entry = format!("{ito_dir}/.state/audit/.session");
-    ensure_gitignore_contains_line(project_root, &entry)
-}
-
-/// Remove the legacy audit events unignore so worktree audit logs stay untracked.
-fn remove_repo_gitignore_unignores_audit_events(
-    project_root: &Path,
-    ito_dir: &str,
-) -> CoreResult<()> {
-    let entry = format!("!{ito_dir}/.state/audit/");
-    remove_gitignore_exact_line(project_root, &entry)
-}
-
-fn ensure_gitignore_contains_line(project_root: &Path, entry: &str) -> CoreResult<()> {
-    let path = project_root.join(".gitignore");
-    let existing = match ito_common::io::read_to_string_std(&path) {
-        Ok(s) => Some(s),
-        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
-        Err(e) => return Err(CoreError::io(format!("reading {}", path.display()), e)),
-    };
-
-    let Some(mut s) = existing else {
-        ito_common::io::write_std(&path, format!("{entry}\n"))
-            .map_err(|e| CoreError::io(format!("writing {}", path.display()), e))?;
-        return Ok(());
-    };
-
-    if gitignore_has_exact_line(&s, entry) {
-        return Ok(());
-    }
-
-    if !s.ends_with('\n') {
-        s.push('\n');
-    }
-    s.push_str(entry);
-    s.push('\n');
-
-    ito_common::io::write_std(&path, s)
-        .map_err(|e| CoreError::io(format!("writing {}", path.display()), e))?;
-    Ok(())
-}
-
-fn remove_gitignore_exact_line(project_root: &Path, entry: &str) -> CoreResult<()> {
-    let path = project_root.join(".gitignore");
-    let existing = match ito_common::io::read_to_string_std(&path) {
-        Ok(s) => s,
-        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
-        Err(e) => return Err(CoreError::io(format!("reading {}", path.display()), e)),
-    };
-
-    let mut filtered = Vec::new();
-    let mut removed = false;
-    for line in existing.lines() {
-        if line.trim() == entry {
-            removed = true;
-            continue;
-        }
-        filtered.push(line);
-    }
-    if !removed {
-        return Ok(());
-    }
-
-    let mut updated = filtered.join("\n");
-    if !updated.is_empty() {
-        updated.push('\n');
-    }
-
-    ito_common::io::write_std(&path, updated)
-        .map_err(|e| CoreError::io(format!("writing {}", path.display()), e))?;
-    Ok(())
-}
-
-fn gitignore_has_exact_line(contents: &str, entry: &str) -> bool {
-    contents.lines().map(|l| l.trim()).any(|l| l == entry)
-}
-
 fn install_project_templates(
+    fs: &dyn FileSystem,
     project_root: &Path,
     ito_dir: &str,
     mode: InstallMode,
     opts: &InitOptions,
-    worktree_ctx: Option<&WorktreeTemplateContext>,
+    template_ctx: &ProjectTemplateContext,
 ) -> CoreResult<()> {
-    use ito_templates::project_templates::render_project_template;
+    use ito_templates::project_templates::{project_template_target, render_project_template};
 
     let selected = &opts.tools;
     let current_date = Utc::now().format("%Y-%m-%d").to_string();
     let state_rel = format!("{ito_dir}/planning/STATE.md");
     let config_json_rel = format!("{ito_dir}/config.json");
-    let release_tag = release_tag();
+    let schema_ref = config_schema::config_schema_ref(opts.schema_server.as_deref());
     let semver = option_env!("ITO_WORKSPACE_VERSION").unwrap_or(env!("CARGO_PKG_VERSION"));
-    let default_ctx = WorktreeTemplateContext::default();
-    let ctx = worktree_ctx.unwrap_or(&default_ctx);
 
-    for f in ito_templates::default_project_files() {
-        let rel = ito_templates::render_rel_path(f.relative_path, ito_dir);
-        let rel = rel.as_ref();
+    // Drift detection only guards update-style installs; plain init refuses to
+    // overwrite existing files and `--force` overwrites by design.
+    let ito_path = project_root.join(ito_dir);
+    let previous_manifest = install_manifest::load_install_manifest_fs(fs, &ito_path);
+    let check_drift = (mode == InstallMode::Update || opts.update || opts.upgrade)
+        && !opts.force
+        && !opts.force_managed;
+    let mut manifest = previous_manifest.clone().unwrap_or_default();
+    let mut locally_modified: Vec<String> = Vec::new();
+
+    // The config schema is generated from this binary rather than embedded,
+    // so it always matches the config this version reads.
+    let templates = opts
+        .asset_source
+        .default_project_files()
+        .into_iter()
+        .map(|f| {
+            let (target_rel, render) = project_template_target(&f.relative_path);
+            let rel = ito_templates::render_rel_path(target_rel, ito_dir).into_owned();
+            let bytes = ito_templates::render_bytes(&f.contents, ito_dir).into_owned();
+            (rel, bytes, render)
+        });
+    let generated = std::iter::once((
+        format!("{ito_dir}/{}", config_schema::CONFIG_SCHEMA_FILE),
+        config_schema::config_schema_bytes(),
+        false,
+    ));
+    let files: Vec<_> = templates
+        .chain(generated)
+        .filter(|(rel, _, _)| should_install_project_rel(rel, selected))
+        .collect();
 
-        if !should_install_project_rel(rel, selected) {
-            continue;
-        }
+    // Two templates differing only in case would overwrite each other.
+    if install_paths::is_case_insensitive(fs, project_root)
+        && let Some((first, second)) =
+            install_paths::find_case_collision(files.iter().map(|(rel, _, _)| rel.as_str()))
+    {
+        return Err(CoreError::validation(format!(
+            "Template paths '{first}' and '{second}' refer to the same file on this case-insensitive file system"
+        )));
+    }
+
+    for (rel, mut bytes, render) in files {
+        let rel = rel.as_str();
 
-        let mut bytes = ito_templates::render_bytes(f.contents, ito_dir).into_owned();
         if let Ok(s) = std::str::from_utf8(&bytes) {
             if rel == state_rel {
                 bytes = s.replace("__CURRENT_DATE__", &current_date).into_bytes();
             } else if rel == config_json_rel {
                 bytes = s
-                    .replace(CONFIG_SCHEMA_RELEASE_TAG_PLACEHOLDER, &release_tag)
+                    .replace(config_schema::CONFIG_SCHEMA_PLACEHOLDER, &schema_ref)
                     .into_bytes();
             }
         }
 
-        // Render worktree-aware project templates (AGENTS.md) with worktree
-        // config. Only AGENTS.md uses Jinja2 for worktree rendering; other
-        // files (e.g., .ito/commands/) may contain `{{` as user-facing prompt
-        // placeholders that must NOT be processed by minijinja.
-        if rel == "AGENTS.md" {
-            bytes = render_project_template(&bytes, ctx).map_err(|e| {
+        // Render AGENTS.md and `.j2` project templates. Other files (e.g.,
+        // .ito/commands/) may contain `{{` as user-facing prompt placeholders
+        // that must NOT be processed by minijinja.
+        if render {
+            bytes = render_project_template(&bytes, template_ctx).map_err(|e| {
                 CoreError::Validation(format!("Failed to render template {rel}: {e}"))
             })?;
         }
@@ -436,26 +580,91 @@ fn install_project_templates(
 
         let ownership = classify_project_file_ownership(rel, ito_dir);
 
-        let target = project_root.join(rel);
+        let target = install_paths::resolve_target(fs, &project_root.join(rel));
         if rel == "AGENTS.md"
             && (mode == InstallMode::Update || opts.update || opts.upgrade)
-            && project_guidance_cleanup::remove_retired_default_guidance(&target)?
+            && project_guidance_cleanup::remove_retired_default_guidance_fs(fs, &target)?
         {
-            eprintln!(
+            ito_config::notice!(
                 "removed retired Ito default project guidance from {}",
                 target.display()
             );
         }
         if rel == ".claude/settings.json" {
-            write_claude_settings(&target, &bytes, mode, opts)?;
+            write_claude_settings_fs(fs, &target, &bytes, mode, opts)?;
+            continue;
+        }
+        // Localize before drift checks so the manifest records the bytes on disk.
+        let line_ending =
+            line_endings::target_line_ending_fs(fs, &target, opts.new_file_line_ending());
+        let bytes = line_endings::localize_bytes(&bytes, line_ending).into_owned();
+        let fully_managed = ownership == FileOwnership::ItoManaged
+            && !std::str::from_utf8(&bytes)
+                .is_ok_and(|text| ito_templates::extract_managed_block(text).is_some());
+        if fully_managed
+            && check_drift
+            && let Some(previous) = &previous_manifest
+            && let Ok(existing) = fs.read(&target)
+            && install_manifest::is_locally_modified(previous, rel, &existing, &bytes)
+        {
+            locally_modified.push(rel.to_string());
             continue;
         }
-        write_one(&target, &bytes, mode, opts, ownership)?;
+
+        write_one_fs(fs, &target, &bytes, mode, opts, ownership)?;
+        if fully_managed {
+            manifest.record(rel, &bytes);
+        }
     }
 
+    warn_locally_modified(&locally_modified);
+    install_manifest::save_install_manifest_fs(fs, &ito_path, &manifest)?;
     Ok(())
 }
 
+fn warn_locally_modified(locally_modified: &[String]) {
+    if locally_modified.is_empty() {
+        return;
+    }
+    ito_config::notice!(
+        "warning: skipped {} locally modified Ito-managed file(s); re-run `ito update --force-managed` to overwrite:",
+        locally_modified.len()
+    );
+    for rel in locally_modified {
+        ito_config::notice!("  - {rel}");
+    }
+}
+
+/// Build the context used to render project templates and skills.
+fn project_template_context(
+    project_root: &Path,
+    ito_dir: &str,
+    opts: &InitOptions,
+    worktree_ctx: Option<&WorktreeTemplateContext>,
+) -> ProjectTemplateContext {
+    ProjectTemplateContext {
+        worktree: worktree_ctx.cloned().unwrap_or_default(),
+        repo_name: repo_name(project_root),
+        tools: opts.tools.iter().cloned().collect(),
+        ito_dir: ito_dir.to_string(),
+        release_tag: release_tag(),
+    }
+}
+
+/// Repository name from the `origin` remote, falling back to the project
+/// directory name.
+fn repo_name(project_root: &Path) -> String {
+    if let Ok(Some((_org, repo))) = crate::git_remote::resolve_org_repo_from_remote(project_root) {
+        return repo;
+    }
+    let root = project_root
+        .canonicalize()
+        .unwrap_or_else(|_| project_root.to_path_buf());
+    root.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
 fn release_tag() -> String {
     let version = option_env!("ITO_WORKSPACE_VERSION").unwrap_or(env!("CARGO_PKG_VERSION"));
     if version.starts_with('v') {
@@ -602,7 +811,7 @@ fn render_and_stamp_agent(
 ///   file as legacy from before managed markers were retrofitted; no user
 ///   content is at risk because there was no marker boundary to honour.
 /// - **Existing target has markers** → marker-scoped update via
-///   `update_file_with_markers`, preserving everything outside the managed
+///   `ManagedBlockEdit::apply_fs`, preserving everything outside the managed
 ///   block byte-for-byte.
 ///
 /// Returns `Ok(())` on success. Errors mirror `write_one` (IO + marker
@@ -612,18 +821,32 @@ pub(crate) fn write_marker_aware_markdown(
     rendered_bytes: &[u8],
     mode: InstallMode,
     opts: &InitOptions,
+) -> CoreResult<()> {
+    write_marker_aware_markdown_fs(&StdFs, target, rendered_bytes, mode, opts)
+}
+
+fn write_marker_aware_markdown_fs(
+    fs: &dyn FileSystem,
+    target: &Path,
+    rendered_bytes: &[u8],
+    mode: InstallMode,
+    opts: &InitOptions,
 ) -> CoreResult<()> {
     if let Some(parent) = target.parent() {
-        ito_common::io::create_dir_all_std(parent)
+        fs.create_dir_all(parent)
             .map_err(|e| CoreError::io(format!("creating directory {}", parent.display()), e))?;
     }
 
+    let line_ending = line_endings::target_line_ending_fs(fs, target, opts.new_file_line_ending());
+    let localized = line_endings::localize_bytes(rendered_bytes, line_ending);
+    let rendered_bytes: &[u8] = &localized;
+
     let wholesale = |target: &Path| -> CoreResult<()> {
-        ito_common::io::write_std(target, rendered_bytes)
+        fs.write(target, rendered_bytes)
             .map_err(|e| CoreError::io(format!("writing {}", target.display()), e))
     };
 
-    if !target.exists() {
+    if !fs.exists(target) {
         return wholesale(target);
     }
 
@@ -638,7 +861,8 @@ pub(crate) fn write_marker_aware_markdown(
         return wholesale(target);
     };
 
-    let existing = ito_common::io::read_to_string_std(target)
+    let existing = fs
+        .read_to_string(target)
         .map_err(|e| CoreError::io(format!("reading {}", target.display()), e))?;
     let has_start = existing.contains(ito_templates::ITO_START_MARKER);
     let has_end = existing.contains(ito_templates::ITO_END_MARKER);
@@ -648,7 +872,7 @@ pub(crate) fn write_marker_aware_markdown(
         (true, false) | (false, true) => {
             // Partial marker pair indicates the user (or some other tool)
             // damaged the managed region. Refusing to write here mirrors
-            // `update_file_with_markers`' error path and prevents silently
+            // `ManagedBlockEdit::apply_fs`' error path and prevents silently
             // clobbering user content. The user must restore the markers
             // (or pass `--force`) before update can proceed.
             return Err(CoreError::Validation(format!(
@@ -659,23 +883,30 @@ Restore both markers manually, or rerun with `--force` to overwrite the file who
         }
     }
 
-    let _ = update_file_with_markers(
-        target,
-        block,
+    ito_managed_block()
+        .apply_fs(fs, target, block)
+        .map_err(|e| managed_block_error(target, e))?;
+    Ok(())
+}
+
+fn ito_managed_block() -> ManagedBlockEdit<'static> {
+    ManagedBlockEdit::new(
         ito_templates::ITO_START_MARKER,
         ito_templates::ITO_END_MARKER,
     )
-    .map_err(|e| match e {
-        markers::FsEditError::Io(io_err) => {
+}
+
+fn managed_block_error(target: &Path, err: ManagedBlockError) -> CoreError {
+    match err {
+        ManagedBlockError::Io(io_err) => {
             CoreError::io(format!("updating markers in {}", target.display()), io_err)
         }
-        markers::FsEditError::Marker(marker_err) => CoreError::Validation(format!(
+        ManagedBlockError::Marker(marker_err) => CoreError::Validation(format!(
             "Failed to update markers in {}: {}",
             target.display(),
             marker_err
         )),
-    })?;
-    Ok(())
+    }
 }
 
 /// Writes a rendered template to `target`, handling Ito-managed marker blocks, overwrite/update semantics,
@@ -692,27 +923,36 @@ Restore both markers manually, or rerun with `--force` to overwrite the file who
 /// Errors are returned for IO failures and for invalid marker states when an update is attempted
 /// (except when `opts.upgrade` is true, in which case a missing marker in an expected marker-managed
 /// file produces a warning and the existing file is preserved).
-fn write_one(
+fn write_one_fs(
+    fs: &dyn FileSystem,
     target: &Path,
     rendered_bytes: &[u8],
     mode: InstallMode,
     opts: &InitOptions,
     ownership: FileOwnership,
 ) -> CoreResult<()> {
+    // Check, read, and write the same file even when `target` is a symlink.
+    let target = &install_paths::resolve_target(fs, target);
     if let Some(parent) = target.parent() {
-        ito_common::io::create_dir_all_std(parent)
+        fs.create_dir_all(parent)
             .map_err(|e| CoreError::io(format!("creating directory {}", parent.display()), e))?;
     }
 
+    // Existing files keep their dominant line ending; new files follow the policy.
+    let line_ending = line_endings::target_line_ending_fs(fs, target, opts.new_file_line_ending());
+    let localized = line_endings::localize_bytes(rendered_bytes, line_ending);
+    let rendered_bytes: &[u8] = &localized;
+
     // Marker-managed files: template contains markers; we extract the inner block.
     if let Ok(text) = std::str::from_utf8(rendered_bytes)
         && let Some(block) = ito_templates::extract_managed_block(text)
     {
-        if target.exists() {
+        if fs.exists(target) {
             // --force always overwrites the file wholesale on init.
             if mode == InstallMode::Init && opts.force {
-                ito_common::io::write_std(target, rendered_bytes)
+                fs.write(target, rendered_bytes)
                     .map_err(|e| CoreError::io(format!("writing {}", target.display()), e))?;
+                ito_config::detail!("install: overwrote {}", target.display());
                 return Ok(());
             }
 
@@ -724,12 +964,14 @@ fn write_one(
                 let updating = mode == InstallMode::Update
                     || (mode == InstallMode::Init && (opts.update || opts.upgrade));
                 if updating {
+                    ito_config::detail!("install: kept user-owned {}", target.display());
                     return Ok(());
                 }
             }
 
             // Read the existing file once and check for both Ito markers.
-            let existing = ito_common::io::read_to_string_std(target)
+            let existing = fs
+                .read_to_string(target)
                 .map_err(|e| CoreError::io(format!("reading {}", target.display()), e))?;
             let has_markers = existing.contains(ito_templates::ITO_START_MARKER)
                 && existing.contains(ito_templates::ITO_END_MARKER);
@@ -739,7 +981,7 @@ fn write_one(
                     // Upgrade fail-safe: when a file is expected to be marker-managed but no
                     // longer contains valid Ito markers, preserve the file unchanged and emit
                     // actionable guidance rather than returning an error.
-                    eprintln!(
+                    ito_config::notice!(
                         "warning: skipping upgrade of {} — Ito markers not found.\n\
                         To restore managed upgrade support, re-add the markers manually:\n\
                         \n\
@@ -786,52 +1028,44 @@ fn write_one(
                     //   instead.
                     //
                     // - Otherwise (template has only markers, no surrounding
-                    //   content), fall through to `update_file_with_markers`
+                    //   content), fall through to `ManagedBlockEdit::apply_fs`
                     //   which prepends the managed block to the existing
                     //   file while preserving user content.
                     if template_is_entirely_managed(text)
                         || template_has_prefix_outside_markers(text)
                     {
-                        ito_common::io::write_std(target, rendered_bytes).map_err(|e| {
+                        fs.write(target, rendered_bytes).map_err(|e| {
                             CoreError::io(format!("writing {}", target.display()), e)
                         })?;
+                        ito_config::detail!("install: rewrote {}", target.display());
                         return Ok(());
                     }
                 }
             }
 
-            update_file_with_markers(
-                target,
-                block,
-                ito_templates::ITO_START_MARKER,
-                ito_templates::ITO_END_MARKER,
-            )
-            .map_err(|e| match e {
-                markers::FsEditError::Io(io_err) => {
-                    CoreError::io(format!("updating markers in {}", target.display()), io_err)
-                }
-                markers::FsEditError::Marker(marker_err) => CoreError::Validation(format!(
-                    "Failed to update markers in {}: {}",
-                    target.display(),
-                    marker_err
-                )),
-            })?;
+            ito_managed_block()
+                .apply_fs(fs, target, block)
+                .map_err(|e| managed_block_error(target, e))?;
+            ito_config::detail!("install: updated managed block in {}", target.display());
         } else {
             // New file: write the template bytes verbatim so output matches embedded assets.
-            ito_common::io::write_std(target, rendered_bytes)
+            fs.write(target, rendered_bytes)
                 .map_err(|e| CoreError::io(format!("writing {}", target.display()), e))?;
+            ito_config::detail!("install: created {}", target.display());
         }
 
         return Ok(());
     }
 
-    if target.exists() {
+    let existed = fs.exists(target);
+    if existed {
         match mode {
             InstallMode::Init => {
                 if opts.force {
                     // --force always overwrites on init.
                 } else if opts.update {
                     if ownership == FileOwnership::UserOwned {
+                        ito_config::detail!("install: kept user-owned {}", target.display());
                         return Ok(());
                     }
                 } else {
@@ -843,35 +1077,41 @@ fn write_one(
             }
             InstallMode::Update => {
                 if ownership == FileOwnership::UserOwned {
+                    ito_config::detail!("install: kept user-owned {}", target.display());
                     return Ok(());
                 }
             }
         }
     }
 
-    ito_common::io::write_std(target, rendered_bytes)
+    fs.write(target, rendered_bytes)
         .map_err(|e| CoreError::io(format!("writing {}", target.display()), e))?;
+    let action = if existed { "overwrote" } else { "created" };
+    ito_config::detail!("install: {action} {}", target.display());
     Ok(())
 }
 
-fn write_claude_settings(
+fn write_claude_settings_fs(
+    fs: &dyn FileSystem,
     target: &Path,
     rendered_bytes: &[u8],
     mode: InstallMode,
     opts: &InitOptions,
 ) -> CoreResult<()> {
     if let Some(parent) = target.parent() {
-        ito_common::io::create_dir_all_std(parent)
+        fs.create_dir_all(parent)
             .map_err(|e| CoreError::io(format!("creating directory {}", parent.display()), e))?;
     }
 
-    if mode == InstallMode::Init && target.exists() && !opts.force && !opts.update {
+    if mode == InstallMode::Init && fs.exists(target) && !opts.force && !opts.update {
         return Err(CoreError::Validation(format!(
             "Refusing to overwrite existing file without markers: {} (re-run with --force)",
             target.display()
         )));
     }
 
+    let line_ending = line_endings::target_line_ending_fs(fs, target, opts.new_file_line_ending());
+
     let template_value: Value = serde_json::from_slice(rendered_bytes).map_err(|e| {
         CoreError::Validation(format!(
             "Failed to parse Claude settings template {}: {}",
@@ -880,7 +1120,7 @@ fn write_claude_settings(
         ))
     })?;
 
-    if !target.exists() || (mode == InstallMode::Init && opts.force) {
+    if !fs.exists(target) || (mode == InstallMode::Init && opts.force) {
         let mut bytes = serde_json::to_vec_pretty(&template_value).map_err(|e| {
             CoreError::Validation(format!(
                 "Failed to render Claude settings template {}: {}",
@@ -889,12 +1129,14 @@ fn write_claude_settings(
             ))
         })?;
         bytes.push(b'\n');
-        ito_common::io::write_std(target, bytes)
+        let bytes = line_endings::localize_bytes(&bytes, line_ending);
+        fs.write(target, &bytes)
             .map_err(|e| CoreError::io(format!("writing {}", target.display()), e))?;
         return Ok(());
     }
 
-    let existing_raw = ito_common::io::read_to_string_std(target)
+    let existing_raw = fs
+        .read_to_string(target)
         .map_err(|e| CoreError::io(format!("reading {}", target.display()), e))?;
     let Ok(mut existing_value) = serde_json::from_str::<Value>(&existing_raw) else {
         // Preserve user-owned files that are not valid JSON during update flows.
@@ -910,7 +1152,8 @@ fn write_claude_settings(
         ))
     })?;
     merged.push(b'\n');
-    ito_common::io::write_std(target, merged)
+    let merged = line_endings::localize_bytes(&merged, line_ending);
+    fs.write(target, &merged)
         .map_err(|e| CoreError::io(format!("writing {}", target.display()), e))?;
     Ok(())
 }
@@ -959,62 +1202,67 @@ fn merge_json_values(existing: &mut Value, template: &Value) {
     }
 }
 
+/// Install embedded harness assets for each selected tool.
+///
+/// Embedded commands named in `shadowed` are skipped; a user command of the
+/// same name is installed in their place. Adapters are generated glue, so every
+/// install rewrites them; their hashes are recorded in the install manifest so
+/// `ito doctor` can report ones that were edited, truncated or removed since.
 fn install_adapter_files(
     project_root: &Path,
+    ito_path: &Path,
     mode: InstallMode,
     opts: &InitOptions,
-    worktree_ctx: Option<&WorktreeTemplateContext>,
+    template_ctx: &ProjectTemplateContext,
+    shadowed: &BTreeSet<String>,
 ) -> CoreResult<()> {
+    use crate::distribution::AssetType;
+
+    let mut manifest = install_manifest::load_install_manifest(ito_path).unwrap_or_default();
+
     for tool in &opts.tools {
-        match tool.as_str() {
+        let mut manifests = match tool.as_str() {
             TOOL_OPENCODE => {
                 let config_dir = project_root.join(".opencode");
-                let manifests = crate::distribution::opencode_manifests(&config_dir);
-                crate::distribution::install_manifests(&manifests, worktree_ctx, mode, opts)?;
-            }
-            TOOL_CLAUDE => {
-                let manifests = crate::distribution::claude_manifests(project_root);
-                crate::distribution::install_manifests(&manifests, worktree_ctx, mode, opts)?;
+                crate::distribution::opencode_manifests(&config_dir)
             }
-            TOOL_CODEX => {
-                let manifests = crate::distribution::codex_manifests(project_root);
-                crate::distribution::install_manifests(&manifests, worktree_ctx, mode, opts)?;
-            }
-            TOOL_GITHUB_COPILOT => {
-                let manifests = crate::distribution::github_manifests(project_root);
-                crate::distribution::install_manifests(&manifests, worktree_ctx, mode, opts)?;
-            }
-            TOOL_PI => {
-                let manifests = crate::distribution::pi_manifests(project_root);
-                crate::distribution::install_manifests(&manifests, worktree_ctx, mode, opts)?;
-            }
-            _ => {}
+            TOOL_CLAUDE => crate::distribution::claude_manifests(project_root),
+            TOOL_CODEX => crate::distribution::codex_manifests(project_root),
+            TOOL_GITHUB_COPILOT => crate::distribution::github_manifests(project_root),
+            TOOL_PI => crate::distribution::pi_manifests(project_root),
+            _ => continue,
+        };
+        manifests.retain(|m| m.asset_type != AssetType::Command || !shadowed.contains(&m.source));
+        crate::distribution::install_manifests(&manifests, Some(template_ctx), mode, opts)?;
+
+        for m in manifests
+            .iter()
+            .filter(|m| m.asset_type == AssetType::Adapter)
+        {
+            let bytes = std::fs::read(&m.dest)
+                .map_err(|e| CoreError::io(format!("reading {}", m.dest.display()), e))?;
+            manifest.adapters.insert(
+                install_manifest::manifest_key(project_root, &m.dest),
+                install_manifest::content_hash(&bytes),
+            );
         }
     }
 
-    Ok(())
+    install_manifest::save_install_manifest(ito_path, &manifest)
 }
 
 /// Install Ito agent templates (ito-quick, ito-general, ito-thinking)
 fn install_agent_templates(
+    fs: &dyn FileSystem,
     project_root: &Path,
     mode: InstallMode,
     opts: &InitOptions,
 ) -> CoreResult<()> {
-    use ito_templates::agents::{AgentTier, Harness, default_agent_configs, get_agent_files};
+    use ito_templates::agents::{AgentTier, get_agent_files};
 
-    let configs = default_agent_configs();
-
-    // Map tool names to harnesses
-    let tool_harness_map = [
-        (TOOL_OPENCODE, Harness::OpenCode),
-        (TOOL_CLAUDE, Harness::ClaudeCode),
-        (TOOL_CODEX, Harness::Codex),
-        (TOOL_GITHUB_COPILOT, Harness::GitHubCopilot),
-        (TOOL_PI, Harness::Pi),
-    ];
+    let configs = effective_agent_configs(opts.models.as_ref());
 
-    for (tool_id, harness) in tool_harness_map {
+    for (tool_id, harness) in agent_models::TOOL_HARNESSES {
         if !opts.tools.contains(tool_id) {
             continue;
         }
@@ -1029,7 +1277,7 @@ fn install_agent_templates(
         let should_remove_obsolete_specialists =
             mode == InstallMode::Update || opts.update || opts.force;
         if should_remove_obsolete_specialists {
-            remove_obsolete_specialist_agents(&agent_dir)?;
+            remove_obsolete_specialist_agents(fs, &agent_dir)?;
         }
 
         // Get agent template files for this harness
@@ -1064,25 +1312,27 @@ fn install_agent_templates(
 
             match mode {
                 InstallMode::Init => {
-                    if target.exists() && !opts.force {
+                    if fs.exists(&target) && !opts.force {
                         if opts.update {
                             let rendered = render_and_stamp_agent(contents, config, &target);
-                            update_existing_agent_template(&target, &rendered, mode, opts, config)?;
+                            update_existing_agent_template(
+                                fs, &target, &rendered, mode, opts, config,
+                            )?;
                         }
                         continue;
                     }
 
                     let rendered = render_and_stamp_agent(contents, config, &target);
-                    write_marker_aware_markdown(&target, &rendered, mode, opts)?;
-                    normalize_agent_frontmatter(&target, &rendered, config)?;
+                    write_marker_aware_markdown_fs(fs, &target, &rendered, mode, opts)?;
+                    normalize_agent_frontmatter(fs, &target, &rendered, config)?;
                 }
                 InstallMode::Update => {
                     let rendered = render_and_stamp_agent(contents, config, &target);
-                    if target.exists() {
-                        update_existing_agent_template(&target, &rendered, mode, opts, config)?;
+                    if fs.exists(&target) {
+                        update_existing_agent_template(fs, &target, &rendered, mode, opts, config)?;
                     } else {
-                        write_marker_aware_markdown(&target, &rendered, mode, opts)?;
-                        normalize_agent_frontmatter(&target, &rendered, config)?;
+                        write_marker_aware_markdown_fs(fs, &target, &rendered, mode, opts)?;
+                        normalize_agent_frontmatter(fs, &target, &rendered, config)?;
                     }
                 }
             }
@@ -1098,22 +1348,24 @@ fn install_agent_templates(
 /// Partial marker pairs are treated like damaged managed regions: preserve the
 /// body for compatibility, but warn so the user can repair the file.
 fn update_existing_agent_template(
+    fs: &dyn FileSystem,
     target: &Path,
     rendered: &[u8],
     mode: InstallMode,
     opts: &InitOptions,
     config: Option<&ito_templates::agents::AgentConfig>,
 ) -> CoreResult<()> {
-    let existing = ito_common::io::read_to_string_std(target)
+    let existing = fs
+        .read_to_string(target)
         .map_err(|e| CoreError::io(format!("reading {}", target.display()), e))?;
     let has_start = existing.contains(ito_templates::ITO_START_MARKER);
     let has_end = existing.contains(ito_templates::ITO_END_MARKER);
 
     match (has_start, has_end) {
-        (true, true) => write_marker_aware_markdown(target, rendered, mode, opts)?,
+        (true, true) => write_marker_aware_markdown_fs(fs, target, rendered, mode, opts)?,
         (false, false) => {}
         (true, false) | (false, true) => {
-            eprintln!(
+            ito_config::notice!(
                 "warning: skipping marker update for {}: file has a partial Ito marker pair \
                 (start={has_start}, end={has_end}). Restore both markers manually, or rerun \
                 with `--force` to overwrite the file wholesale.",
@@ -1122,19 +1374,47 @@ fn update_existing_agent_template(
         }
     }
 
-    normalize_agent_frontmatter(target, rendered, config)
+    normalize_agent_frontmatter(fs, target, rendered, config)
 }
 
 fn normalize_agent_frontmatter(
+    fs: &dyn FileSystem,
     target: &Path,
     rendered: &[u8],
     config: Option<&ito_templates::agents::AgentConfig>,
 ) -> CoreResult<()> {
-    if let Some(config) = config {
-        update_agent_model_field(target, &config.model)?;
+    if let Some(config) = config
+        && let ModelFieldUpdate::Skipped(reason) =
+            update_agent_model_field_fs(fs, target, &config.model)?
+    {
+        tracing::warn!("not updating model in {}: {reason}", target.display());
     }
-    update_agent_activation_field_from_rendered(target, rendered)?;
-    remove_agent_mode_field_for_direct_activation(target, rendered)
+    update_agent_activation_field_from_rendered_fs(fs, target, rendered)?;
+    remove_agent_mode_field_for_direct_activation_fs(fs, target, rendered)
+}
+
+// `StdFs` adapters for the unit tests, which exercise these helpers against
+// real temp directories.
+
+#[cfg(test)]
+fn write_one(
+    target: &Path,
+    rendered_bytes: &[u8],
+    mode: InstallMode,
+    opts: &InitOptions,
+    ownership: FileOwnership,
+) -> CoreResult<()> {
+    write_one_fs(&StdFs, target, rendered_bytes, mode, opts, ownership)
+}
+
+#[cfg(test)]
+fn write_claude_settings(
+    target: &Path,
+    rendered_bytes: &[u8],
+    mode: InstallMode,
+    opts: &InitOptions,
+) -> CoreResult<()> {
+    write_claude_settings_fs(&StdFs, target, rendered_bytes, mode, opts)
 }
 
 #[cfg(test)]
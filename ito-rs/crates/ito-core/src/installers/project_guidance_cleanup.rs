@@ -1,6 +1,8 @@
 use std::io::ErrorKind;
 use std::path::Path;
 
+use ito_common::fs::FileSystem;
+
 use crate::errors::{CoreError, CoreResult};
 
 const RETIRED_DEFAULT_GUIDANCE: &str = r#"
@@ -16,8 +18,16 @@ const RETIRED_DEFAULT_GUIDANCE: &str = r#"
 |multi-agent: explore multiple approaches and synthesize
 <!-- ITO:INTERNAL:END -->"#;
 
+#[cfg(test)]
 pub(super) fn remove_retired_default_guidance(path: &Path) -> CoreResult<bool> {
-    let contents = match std::fs::read_to_string(path) {
+    remove_retired_default_guidance_fs(&ito_common::fs::StdFs, path)
+}
+
+pub(super) fn remove_retired_default_guidance_fs(
+    fs: &dyn FileSystem,
+    path: &Path,
+) -> CoreResult<bool> {
+    let contents = match fs.read_to_string(path) {
         Ok(contents) => contents,
         Err(error) if error.kind() == ErrorKind::NotFound => return Ok(false),
         Err(error) => return Err(CoreError::io(format!("reading {}", path.display()), error)),
@@ -27,7 +37,7 @@ pub(super) fn remove_retired_default_guidance(path: &Path) -> CoreResult<bool> {
     }
 
     let updated = contents.replacen(RETIRED_DEFAULT_GUIDANCE, "", 1);
-    std::fs::write(path, updated)
+    fs.write(path, updated.as_bytes())
         .map_err(|error| CoreError::io(format!("writing {}", path.display()), error))?;
     Ok(true)
 }
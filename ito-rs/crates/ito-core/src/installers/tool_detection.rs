@@ -0,0 +1,129 @@
+//! Detecting which AI tools a developer already uses, for `ito init`.
+//!
+//! Unlike [`super::detect_configured_tools`], which only looks for adapter
+//! files Ito (or the tool) already wrote, this also probes `PATH` for each
+//! tool's CLI so a fresh repository still gets sensible defaults.
+
+use std::path::Path;
+use std::time::Duration;
+
+use ito_common::fs::{FileSystem, StdFs};
+use serde::Serialize;
+
+use crate::harness::streaming_cli::CliHarness;
+use crate::harness::{ClaudeCodeHarness, CodexHarness, GitHubCopilotHarness, OpencodeHarness};
+use crate::process::{ProcessRequest, ProcessRunner};
+
+use super::{
+    TOOL_CLAUDE, TOOL_CODEX, TOOL_GITHUB_COPILOT, TOOL_OPENCODE, available_tool_ids,
+    detect_configured_tools_fs,
+};
+
+/// Timeout for each `--version` probe; detection runs before the init prompt,
+/// so a hung binary must not hold it up for long.
+const DETECTION_PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// How a tool was detected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DetectionSource {
+    /// The tool's CLI answered `--version` on `PATH`.
+    PathBinary,
+    /// The repository already contains the tool's directory or adapter files.
+    RepoDir,
+}
+
+impl DetectionSource {
+    /// Short label for prompts and log lines.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            DetectionSource::PathBinary => "on PATH",
+            DetectionSource::RepoDir => "in repo",
+        }
+    }
+}
+
+/// A tool found by [`detect_installed_tools`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DetectedTool {
+    /// Tool id as accepted by `--tools` (e.g. `claude`).
+    pub id: String,
+    /// Where the tool was found; a CLI on `PATH` takes precedence.
+    pub source: DetectionSource,
+    /// First line of the CLI's `--version` output, when probed successfully.
+    pub version: Option<String>,
+}
+
+/// Detect tools whose CLI is on `PATH` or whose files exist in `project_root`.
+///
+/// Results follow [`available_tool_ids`] order, one entry per tool.
+pub fn detect_installed_tools(
+    runner: &dyn ProcessRunner,
+    project_root: &Path,
+) -> Vec<DetectedTool> {
+    detect_installed_tools_fs(&StdFs, runner, project_root)
+}
+
+/// Like [`detect_installed_tools`], but reads the repository through `fs`.
+pub fn detect_installed_tools_fs<F: FileSystem + ?Sized>(
+    fs: &F,
+    runner: &dyn ProcessRunner,
+    project_root: &Path,
+) -> Vec<DetectedTool> {
+    let in_repo = detect_configured_tools_fs(fs, project_root);
+    let mut detected = Vec::new();
+    for id in available_tool_ids() {
+        let probed = harness_binary(id).and_then(|binary| probe_version(runner, &binary));
+        let tool = match probed {
+            Some(version) => DetectedTool {
+                id: id.to_string(),
+                source: DetectionSource::PathBinary,
+                version,
+            },
+            None if in_repo.contains(*id) => DetectedTool {
+                id: id.to_string(),
+                source: DetectionSource::RepoDir,
+                version: None,
+            },
+            None => continue,
+        };
+        detected.push(tool);
+    }
+    detected
+}
+
+/// CLI binary `ito ralph` spawns for `tool`; `None` for tools without a harness.
+pub(crate) fn harness_binary(tool: &str) -> Option<String> {
+    let binary = match tool {
+        TOOL_CLAUDE => ClaudeCodeHarness.binary().to_string(),
+        TOOL_CODEX => CodexHarness.binary().to_string(),
+        TOOL_GITHUB_COPILOT => GitHubCopilotHarness.binary().to_string(),
+        TOOL_OPENCODE => OpencodeHarness.binary().to_string(),
+        _ => return None,
+    };
+    Some(binary)
+}
+
+/// `Some(version)` when `binary --version` succeeds; the inner value is `None`
+/// if it printed nothing.
+fn probe_version(runner: &dyn ProcessRunner, binary: &str) -> Option<Option<String>> {
+    let request = ProcessRequest::new(binary).arg("--version");
+    let out = runner
+        .run_with_timeout(&request, DETECTION_PROBE_TIMEOUT)
+        .ok()?;
+    if !out.success {
+        return None;
+    }
+    let version = out
+        .stdout
+        .lines()
+        .chain(out.stderr.lines())
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string);
+    Some(version)
+}
+
+#[cfg(test)]
+#[path = "tool_detection_tests.rs"]
+mod tool_detection_tests;
@@ -0,0 +1,115 @@
+use super::*;
+use crate::process::{ProcessExecutionError, ProcessOutput};
+use std::io;
+
+/// Answers `--version` for installed programs and fails to spawn anything else.
+struct StubRunner {
+    installed: Vec<(&'static str, &'static str)>,
+}
+
+impl StubRunner {
+    fn with_installed(installed: &[(&'static str, &'static str)]) -> Self {
+        Self {
+            installed: installed.to_vec(),
+        }
+    }
+}
+
+impl ProcessRunner for StubRunner {
+    fn run(&self, request: &ProcessRequest) -> Result<ProcessOutput, ProcessExecutionError> {
+        let Some((_, version)) = self
+            .installed
+            .iter()
+            .find(|(program, _)| *program == request.program)
+        else {
+            return Err(ProcessExecutionError::Spawn {
+                program: request.program.clone(),
+                source: io::Error::new(io::ErrorKind::NotFound, "No such file or directory"),
+            });
+        };
+        assert_eq!(request.args, vec!["--version".to_string()]);
+        Ok(ProcessOutput {
+            exit_code: 0,
+            success: true,
+            stdout: format!("\n{version}\n"),
+            stderr: String::new(),
+            timed_out: false,
+            resources: Default::default(),
+        })
+    }
+
+    fn run_with_timeout(
+        &self,
+        request: &ProcessRequest,
+        _timeout: Duration,
+    ) -> Result<ProcessOutput, ProcessExecutionError> {
+        self.run(request)
+    }
+}
+
+fn ids(detected: &[DetectedTool]) -> Vec<&str> {
+    detected.iter().map(|tool| tool.id.as_str()).collect()
+}
+
+#[test]
+fn binaries_on_path_are_detected_with_versions() {
+    let td = tempfile::tempdir().unwrap();
+    let runner =
+        StubRunner::with_installed(&[("opencode", "1.0.4"), ("claude", "2.1.0 (Claude Code)")]);
+
+    let detected = detect_installed_tools(&runner, td.path());
+
+    assert_eq!(ids(&detected), vec![TOOL_CLAUDE, TOOL_OPENCODE]);
+    assert_eq!(
+        detected[0],
+        DetectedTool {
+            id: TOOL_CLAUDE.to_string(),
+            source: DetectionSource::PathBinary,
+            version: Some("2.1.0 (Claude Code)".to_string()),
+        }
+    );
+    assert_eq!(detected[1].version.as_deref(), Some("1.0.4"));
+}
+
+#[test]
+fn repo_dirs_are_detected_without_binaries() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(td.path().join(".claude")).unwrap();
+    std::fs::create_dir_all(td.path().join(".opencode")).unwrap();
+    let runner = StubRunner::with_installed(&[]);
+
+    let detected = detect_installed_tools(&runner, td.path());
+
+    assert_eq!(ids(&detected), vec![TOOL_CLAUDE, TOOL_OPENCODE]);
+    assert!(
+        detected
+            .iter()
+            .all(|tool| tool.source == DetectionSource::RepoDir && tool.version.is_none())
+    );
+}
+
+#[test]
+fn binary_on_path_takes_precedence_over_repo_dir() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(td.path().join(".codex")).unwrap();
+    let runner = StubRunner::with_installed(&[("codex", "codex-cli 0.40.0")]);
+
+    let detected = detect_installed_tools(&runner, td.path());
+
+    assert_eq!(
+        detected,
+        vec![DetectedTool {
+            id: TOOL_CODEX.to_string(),
+            source: DetectionSource::PathBinary,
+            version: Some("codex-cli 0.40.0".to_string()),
+        }]
+    );
+}
+
+#[test]
+fn nothing_detected_in_an_empty_repo() {
+    let td = tempfile::tempdir().unwrap();
+    let runner = StubRunner::with_installed(&[("git", "git version 2.45.0")]);
+
+    assert!(detect_installed_tools(&runner, td.path()).is_empty());
+}
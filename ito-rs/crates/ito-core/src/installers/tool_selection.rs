@@ -0,0 +1,161 @@
+//! Selecting which AI tool adapters `ito init` / `ito update` install.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use ito_common::fs::{FileSystem, StdFs};
+
+use crate::config::{json_get_path, json_set_path, read_json_config, write_json_config};
+use crate::errors::{CoreError, CoreResult};
+use ito_config::ito_dir_config_path;
+
+use super::{
+    TOOL_CLAUDE, TOOL_CODEX, TOOL_GITHUB_COPILOT, TOOL_OPENCODE, TOOL_PI, available_tool_ids,
+};
+
+/// Config key (in `<itoDir>/config.json`) recording the tools selected during init.
+pub const INIT_TOOLS_CONFIG_KEY: &str = "init.tools";
+
+/// Parse a `--tools` value: `all`, `none`, or comma-separated tool ids.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Validation`] when the value is empty or names an
+/// unknown tool id; the message suggests the nearest valid id.
+pub fn parse_tool_selection(raw: &str) -> CoreResult<BTreeSet<String>> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Err(CoreError::validation("--tools cannot be empty"));
+    }
+
+    let all_ids = available_tool_ids();
+    match raw {
+        "none" => return Ok(BTreeSet::new()),
+        "all" => return Ok(all_ids.iter().map(|s| (*s).to_string()).collect()),
+        _ => {}
+    }
+
+    let mut selected: BTreeSet<String> = BTreeSet::new();
+    for part in raw.split(',') {
+        let id = part.trim();
+        if id.is_empty() {
+            continue;
+        }
+        if !all_ids.contains(&id) {
+            return Err(CoreError::validation(unknown_tool_message(id)));
+        }
+        selected.insert(id.to_string());
+    }
+    Ok(selected)
+}
+
+fn unknown_tool_message(id: &str) -> String {
+    let candidates: Vec<String> = available_tool_ids()
+        .iter()
+        .map(|s| (*s).to_string())
+        .collect();
+    let mut msg = format!(
+        "Unknown tool id '{id}'. Valid tool ids: {}",
+        candidates.join(", ")
+    );
+    let suggestions = crate::nearest_matches(id, &candidates, 1);
+    if let Some(suggestion) = suggestions.first() {
+        msg.push_str(&format!("\nDid you mean: {suggestion}?"));
+    }
+    msg
+}
+
+/// Read the tool ids recorded by `ito init` from the `<itoDir>` config file.
+///
+/// Returns `None` when nothing was recorded (for example, repositories
+/// initialized before tools were recorded). Unknown ids are dropped.
+pub fn recorded_tools(ito_path: &Path) -> Option<BTreeSet<String>> {
+    let config = read_json_config(&ito_dir_config_path(ito_path)).ok()?;
+    let parts: Vec<&str> = INIT_TOOLS_CONFIG_KEY.split('.').collect();
+    let tools = json_get_path(&config, &parts)?.as_array()?;
+    let all_ids = available_tool_ids();
+    Some(
+        tools
+            .iter()
+            .filter_map(|v| v.as_str())
+            .filter(|id| all_ids.contains(id))
+            .map(str::to_string)
+            .collect(),
+    )
+}
+
+/// Record the selected tool ids under `init.tools` in the `<itoDir>` config file.
+///
+/// # Errors
+///
+/// Returns an error when the config file cannot be parsed or written.
+pub fn record_tools(ito_path: &Path, tools: &BTreeSet<String>) -> CoreResult<()> {
+    let path = ito_dir_config_path(ito_path);
+    let mut config = read_json_config(&path)?;
+    let parts: Vec<&str> = INIT_TOOLS_CONFIG_KEY.split('.').collect();
+    let value = serde_json::Value::Array(
+        tools
+            .iter()
+            .map(|id| serde_json::Value::String(id.clone()))
+            .collect(),
+    );
+    json_set_path(&mut config, &parts, value)?;
+    write_json_config(&path, &config)
+}
+
+/// Detect tools whose adapter files already exist in the project.
+pub fn detect_configured_tools(project_root: &Path) -> BTreeSet<String> {
+    detect_configured_tools_fs(&StdFs, project_root)
+}
+
+/// Like [`detect_configured_tools`], but reads through `fs`.
+pub fn detect_configured_tools_fs<F: FileSystem + ?Sized>(
+    fs: &F,
+    project_root: &Path,
+) -> BTreeSet<String> {
+    let markers: [(&str, &[&str]); 5] = [
+        (TOOL_CLAUDE, &["CLAUDE.md", ".claude"]),
+        (TOOL_CODEX, &[".codex"]),
+        (
+            TOOL_GITHUB_COPILOT,
+            &[
+                ".github/copilot-instructions.md",
+                ".github/prompts",
+                ".github/skills",
+            ],
+        ),
+        (TOOL_OPENCODE, &[".opencode"]),
+        (TOOL_PI, &[".pi"]),
+    ];
+
+    let mut detected = BTreeSet::new();
+    for (tool, paths) in markers {
+        if paths.iter().any(|p| fs.exists(&project_root.join(p))) {
+            detected.insert(tool.to_string());
+        }
+    }
+    detected
+}
+
+/// Resolve the tools `ito update` refreshes when `--tools` is omitted.
+///
+/// Uses the selection recorded by `ito init`. Repositories initialized before
+/// tools were recorded fall back to the adapters whose directories exist, and
+/// to every tool when none are detected (the historical `ito update` default).
+pub fn resolve_update_tools(project_root: &Path, ito_path: &Path) -> BTreeSet<String> {
+    if let Some(recorded) = recorded_tools(ito_path) {
+        return recorded;
+    }
+    let detected = detect_configured_tools(project_root);
+    if !detected.is_empty() {
+        return detected;
+    }
+    available_tool_ids()
+        .iter()
+        .map(|s| (*s).to_string())
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "tool_selection_tests.rs"]
+mod tool_selection_tests;
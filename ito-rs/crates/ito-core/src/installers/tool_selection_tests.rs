@@ -0,0 +1,96 @@
+use super::*;
+
+fn ids(values: &[&str]) -> BTreeSet<String> {
+    values.iter().map(|s| (*s).to_string()).collect()
+}
+
+#[test]
+fn parse_all_expands_to_every_available_tool() {
+    let tools = parse_tool_selection("all").unwrap();
+    assert_eq!(tools, ids(available_tool_ids()));
+}
+
+#[test]
+fn parse_none_selects_nothing() {
+    assert!(parse_tool_selection(" none ").unwrap().is_empty());
+}
+
+#[test]
+fn parse_comma_separated_ids() {
+    let tools = parse_tool_selection("claude, opencode,,").unwrap();
+    assert_eq!(tools, ids(&[TOOL_CLAUDE, TOOL_OPENCODE]));
+}
+
+#[test]
+fn parse_rejects_empty_value() {
+    let err = parse_tool_selection("  ").unwrap_err();
+    assert!(err.to_string().contains("--tools cannot be empty"));
+}
+
+#[test]
+fn parse_unknown_id_suggests_nearest_match() {
+    let err = parse_tool_selection("claude,opencod")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("Unknown tool id 'opencod'"), "{err}");
+    assert!(err.contains("Did you mean: opencode?"), "{err}");
+}
+
+#[test]
+fn record_tools_round_trips_and_preserves_other_keys() {
+    let td = tempfile::tempdir().unwrap();
+    let ito_path = td.path().join(".ito");
+    std::fs::create_dir_all(&ito_path).unwrap();
+    std::fs::write(
+        ito_path.join("config.json"),
+        "{\"worktrees\": {\"enabled\": true}}\n",
+    )
+    .unwrap();
+
+    assert_eq!(recorded_tools(&ito_path), None);
+    record_tools(&ito_path, &ids(&[TOOL_CODEX, TOOL_PI])).unwrap();
+
+    assert_eq!(recorded_tools(&ito_path), Some(ids(&[TOOL_CODEX, TOOL_PI])));
+    let config = read_json_config(&ito_path.join("config.json")).unwrap();
+    assert_eq!(config["worktrees"]["enabled"], true);
+    assert_eq!(config["init"]["tools"], serde_json::json!(["codex", "pi"]));
+}
+
+#[test]
+fn recorded_empty_selection_is_respected() {
+    let td = tempfile::tempdir().unwrap();
+    let ito_path = td.path().join(".ito");
+    std::fs::create_dir_all(td.path().join(".opencode")).unwrap();
+    std::fs::create_dir_all(&ito_path).unwrap();
+    record_tools(&ito_path, &BTreeSet::new()).unwrap();
+
+    assert!(resolve_update_tools(td.path(), &ito_path).is_empty());
+}
+
+#[test]
+fn update_falls_back_to_detected_adapter_directories() {
+    let td = tempfile::tempdir().unwrap();
+    let ito_path = td.path().join(".ito");
+    std::fs::create_dir_all(&ito_path).unwrap();
+    std::fs::write(ito_path.join("config.json"), "{}\n").unwrap();
+    std::fs::create_dir_all(td.path().join(".claude")).unwrap();
+    std::fs::create_dir_all(td.path().join(".opencode")).unwrap();
+    // A plain `.github` directory (CI workflows) does not imply Copilot.
+    std::fs::create_dir_all(td.path().join(".github/workflows")).unwrap();
+
+    assert_eq!(
+        resolve_update_tools(td.path(), &ito_path),
+        ids(&[TOOL_CLAUDE, TOOL_OPENCODE])
+    );
+}
+
+#[test]
+fn update_without_record_or_adapters_installs_every_tool() {
+    let td = tempfile::tempdir().unwrap();
+    let ito_path = td.path().join(".ito");
+
+    assert_eq!(
+        resolve_update_tools(td.path(), &ito_path),
+        ids(available_tool_ids())
+    );
+}
@@ -0,0 +1,219 @@
+//! User-defined command templates installed alongside Ito's embedded commands.
+//!
+//! Teams can drop markdown command files into `<itoDir>/user-commands/`
+//! (project) or `~/.config/ito/commands/` (global). Each selected harness gets
+//! a copy in its command directory, named the same way embedded commands are.
+//! A project command beats a global command of the same name, and either one
+//! beats an embedded command (with a warning).
+//!
+//! Installed copies are tracked in the install manifest so `ito update` can
+//! remove them once their source file is deleted.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use crate::errors::{CoreError, CoreResult};
+
+use super::install_manifest::{self, content_hash, manifest_key};
+use super::{TOOL_CLAUDE, TOOL_CODEX, TOOL_GITHUB_COPILOT, TOOL_OPENCODE, TOOL_PI};
+
+/// Project user command directory, relative to the Ito directory.
+pub const USER_COMMANDS_DIR: &str = "user-commands";
+
+/// Global user command directory, relative to the Ito config directory.
+pub const GLOBAL_USER_COMMANDS_DIR: &str = "commands";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Where a user command was found.
+pub enum UserCommandScope {
+    /// `<itoDir>/user-commands/`.
+    Project,
+    /// `~/.config/ito/commands/`.
+    Global,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// One user command source file.
+pub struct UserCommand {
+    /// File name, e.g. `acme-release-notes.md`.
+    pub name: String,
+    /// Absolute path of the source file.
+    pub source: PathBuf,
+    /// Source directory the command came from.
+    pub scope: UserCommandScope,
+}
+
+impl UserCommand {
+    /// True when an embedded Ito command has the same file name.
+    pub fn shadows_embedded(&self) -> bool {
+        ito_templates::get_command_file(&self.name).is_some()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Outcome of [`sync_user_commands`].
+pub struct UserCommandsReport {
+    /// Harness command files written from user sources.
+    pub installed: Vec<PathBuf>,
+    /// Previously installed copies removed because their source is gone.
+    pub removed: Vec<PathBuf>,
+    /// Stale copies kept because they were edited after installation.
+    pub preserved: Vec<PathBuf>,
+    /// User commands that replace an embedded Ito command of the same name.
+    pub shadowed: Vec<UserCommand>,
+}
+
+/// Collect user commands, applying project-over-global precedence.
+///
+/// Only `*.md` files directly inside each directory are considered. Missing
+/// directories contribute nothing.
+///
+/// # Errors
+///
+/// Returns an error when an existing directory cannot be read.
+pub fn discover_user_commands(
+    ito_path: &Path,
+    global_dir: Option<&Path>,
+) -> CoreResult<Vec<UserCommand>> {
+    let mut by_name: BTreeMap<String, UserCommand> = BTreeMap::new();
+    if let Some(dir) = global_dir {
+        for cmd in read_command_dir(dir, UserCommandScope::Global)? {
+            by_name.insert(cmd.name.clone(), cmd);
+        }
+    }
+    let project_dir = ito_path.join(USER_COMMANDS_DIR);
+    for cmd in read_command_dir(&project_dir, UserCommandScope::Project)? {
+        by_name.insert(cmd.name.clone(), cmd);
+    }
+    Ok(by_name.into_values().collect())
+}
+
+/// Install `commands` into every selected harness and prune stale copies.
+///
+/// Copies recorded in the install manifest whose source no longer exists are
+/// removed, unless the installed file was edited locally (those are reported
+/// in [`UserCommandsReport::preserved`]). Names that belong to an embedded
+/// command are left alone because the embedded installer owns them again.
+///
+/// # Errors
+///
+/// Returns an error when a source cannot be read, a harness file cannot be
+/// written or removed, or the install manifest cannot be saved.
+pub fn sync_user_commands(
+    project_root: &Path,
+    ito_path: &Path,
+    commands: &[UserCommand],
+    tools: &BTreeSet<String>,
+) -> CoreResult<UserCommandsReport> {
+    let mut report = UserCommandsReport::default();
+    for cmd in commands {
+        if cmd.shadows_embedded() {
+            report.shadowed.push(cmd.clone());
+        }
+    }
+
+    let mut installed: BTreeMap<String, String> = BTreeMap::new();
+    for tool in tools {
+        let Some(dir) = harness_commands_dir(tool) else {
+            continue;
+        };
+        for cmd in commands {
+            let bytes = std::fs::read(&cmd.source)
+                .map_err(|e| CoreError::io(format!("reading {}", cmd.source.display()), e))?;
+            let dest = project_root.join(dir).join(dest_name(tool, &cmd.name));
+            if let Some(parent) = dest.parent() {
+                ito_common::io::create_dir_all_std(parent).map_err(|e| {
+                    CoreError::io(format!("creating directory {}", parent.display()), e)
+                })?;
+            }
+            ito_common::io::write_std(&dest, &bytes)
+                .map_err(|e| CoreError::io(format!("writing {}", dest.display()), e))?;
+            installed.insert(manifest_key(project_root, &dest), content_hash(&bytes));
+            report.installed.push(dest);
+        }
+    }
+
+    let mut manifest = install_manifest::load_install_manifest(ito_path).unwrap_or_default();
+    for (rel, recorded) in &manifest.user_commands {
+        if installed.contains_key(rel) || is_embedded_command_path(rel) {
+            continue;
+        }
+        let path = project_root.join(rel);
+        let existing = match std::fs::read(&path) {
+            Ok(existing) => existing,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(CoreError::io(format!("reading {}", path.display()), e)),
+        };
+        if content_hash(&existing) != *recorded {
+            report.preserved.push(path);
+            continue;
+        }
+        std::fs::remove_file(&path)
+            .map_err(|e| CoreError::io(format!("removing {}", path.display()), e))?;
+        report.removed.push(path);
+    }
+
+    if manifest.user_commands != installed {
+        manifest.user_commands = installed;
+        install_manifest::save_install_manifest(ito_path, &manifest)?;
+    }
+    Ok(report)
+}
+
+fn read_command_dir(dir: &Path, scope: UserCommandScope) -> CoreResult<Vec<UserCommand>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(CoreError::io(format!("reading {}", dir.display()), e)),
+    };
+    let mut out = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| CoreError::io(format!("reading {}", dir.display()), e))?;
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if !name.ends_with(".md") || name.starts_with('.') {
+            continue;
+        }
+        out.push(UserCommand {
+            name: name.to_string(),
+            source: path.clone(),
+            scope,
+        });
+    }
+    out.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(out)
+}
+
+/// Project-relative command directory for `tool`, matching `distribution`.
+fn harness_commands_dir(tool: &str) -> Option<&'static str> {
+    match tool {
+        TOOL_OPENCODE => Some(".opencode/commands"),
+        TOOL_CLAUDE => Some(".claude/commands"),
+        TOOL_CODEX => Some(".codex/prompts"),
+        TOOL_GITHUB_COPILOT => Some(".github/prompts"),
+        TOOL_PI => Some(".pi/commands"),
+        _ => None,
+    }
+}
+
+fn dest_name(tool: &str, name: &str) -> String {
+    if tool == TOOL_GITHUB_COPILOT {
+        crate::distribution::github_prompt_name(name)
+    } else {
+        name.to_string()
+    }
+}
+
+fn is_embedded_command_path(rel: &str) -> bool {
+    let file = rel.rsplit('/').next().unwrap_or(rel);
+    let name = match file.strip_suffix(".prompt.md") {
+        Some(stem) if rel.starts_with(".github/") => format!("{stem}.md"),
+        _ => file.to_string(),
+    };
+    ito_templates::get_command_file(&name).is_some()
+}
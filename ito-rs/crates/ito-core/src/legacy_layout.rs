@@ -0,0 +1,455 @@
+//! Detect and adopt pre-Ito planning layouts.
+//!
+//! Projects coming from the older TypeScript tool or from OpenSpec keep their
+//! specs and changes in a directory such as `openspec/` with a slightly
+//! different structure. [`migrate_legacy_layout`] copies that tree into the
+//! Ito directory: specs and modules keep their folder names (flat
+//! `specs/<capability>.md` files gain a folder), archives land under
+//! `changes/archive/`, changes are
+//! converted to canonical `NNN-NN_name` ids (in the ungrouped `000` module
+//! when the legacy name is not already canonical) and receive `.ito.yaml`
+//! metadata. Anything that cannot be mapped is left in place and listed in the
+//! returned [`LegacyMigrationReport`].
+//!
+//! Migration is non-destructive unless [`LegacyMigrationOptions::move_files`]
+//! is set, and re-running it over the same source is a no-op.
+
+use std::path::{Path, PathBuf};
+
+use ito_common::id::{canonical_change_slug, parse_change_id, parse_module_id};
+use ito_common::paths;
+use serde::Serialize;
+
+use crate::create::create_change;
+use crate::errors::{CoreError, CoreResult};
+
+/// Rules for recognizing a legacy layout inside a project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacyLayoutDetection {
+    /// Directory names (relative to the project root) that may hold a legacy layout.
+    pub dir_names: Vec<String>,
+    /// Files whose presence inside a candidate directory marks it as legacy.
+    pub marker_files: Vec<String>,
+}
+
+impl Default for LegacyLayoutDetection {
+    fn default() -> Self {
+        Self {
+            dir_names: vec!["openspec".to_string(), ".openspec".to_string()],
+            marker_files: vec!["project.md".to_string(), "AGENTS.md".to_string()],
+        }
+    }
+}
+
+/// Return the legacy layout directories found under `project_root`.
+///
+/// A candidate directory qualifies when it contains a `specs/` or `changes/`
+/// directory, or any of the configured marker files.
+pub fn detect_legacy_layouts(
+    project_root: &Path,
+    detection: &LegacyLayoutDetection,
+) -> Vec<PathBuf> {
+    let mut out = Vec::new();
+    for name in &detection.dir_names {
+        let dir = project_root.join(name);
+        if !dir.is_dir() {
+            continue;
+        }
+        let has_tree = dir.join("specs").is_dir() || dir.join("changes").is_dir();
+        let has_marker = detection
+            .marker_files
+            .iter()
+            .any(|marker| dir.join(marker).is_file());
+        if has_tree || has_marker {
+            out.push(dir);
+        }
+    }
+    out
+}
+
+/// Options for [`migrate_legacy_layout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LegacyMigrationOptions<'a> {
+    /// Schema recorded in `.ito.yaml` for migrated changes.
+    pub schema: &'a str,
+    /// Remove each legacy entry once it has been copied without conflicts.
+    pub move_files: bool,
+}
+
+/// One legacy entry mapped into the Ito directory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MigratedEntry {
+    /// `spec`, `change`, `archived_change`, or `module`.
+    pub kind: &'static str,
+    /// Path relative to the legacy directory.
+    pub from: String,
+    /// Path relative to the Ito directory.
+    pub to: String,
+}
+
+/// A legacy entry left in place for manual action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UnmappedEntry {
+    /// Path relative to the legacy directory.
+    pub path: String,
+    /// Why the entry was not migrated.
+    pub reason: String,
+}
+
+/// Outcome of [`migrate_legacy_layout`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct LegacyMigrationReport {
+    /// Entries copied (or moved) into the Ito directory by this run.
+    pub migrated: Vec<MigratedEntry>,
+    /// Entries whose content was already present from an earlier run.
+    pub already_present: Vec<MigratedEntry>,
+    /// Entries that need manual attention.
+    pub unmapped: Vec<UnmappedEntry>,
+}
+
+impl LegacyMigrationReport {
+    /// Human-readable report, one entry per line.
+    pub fn describe(&self) -> String {
+        let mut out = String::new();
+        out.push_str(&format!(
+            "Migrated {} entr{}",
+            self.migrated.len(),
+            if self.migrated.len() == 1 { "y" } else { "ies" }
+        ));
+        if !self.already_present.is_empty() {
+            out.push_str(&format!(
+                " ({} already present)",
+                self.already_present.len()
+            ));
+        }
+        out.push('\n');
+        for entry in &self.migrated {
+            out.push_str(&format!(
+                "  {} {} -> {}\n",
+                entry.kind, entry.from, entry.to
+            ));
+        }
+        if !self.unmapped.is_empty() {
+            out.push_str("Left in place for manual action:\n");
+            for entry in &self.unmapped {
+                out.push_str(&format!("  {}: {}\n", entry.path, entry.reason));
+            }
+        }
+        out
+    }
+}
+
+/// Copy (or move) the legacy layout at `source` into `ito_path`.
+///
+/// Existing files in the Ito directory are never overwritten: identical files
+/// count as already migrated, while differing files leave the legacy entry in
+/// place and report it as unmapped.
+pub fn migrate_legacy_layout(
+    source: &Path,
+    ito_path: &Path,
+    options: LegacyMigrationOptions<'_>,
+) -> CoreResult<LegacyMigrationReport> {
+    if !source.is_dir() {
+        return Err(CoreError::not_found(format!(
+            "Legacy layout directory not found: {}",
+            source.display()
+        )));
+    }
+
+    let mut report = LegacyMigrationReport::default();
+    for name in sorted_entry_names(source)? {
+        let path = source.join(&name);
+        match name.as_str() {
+            "specs" if path.is_dir() => migrate_specs(&path, ito_path, options, &mut report)?,
+            "changes" if path.is_dir() => migrate_changes(&path, ito_path, options, &mut report)?,
+            "modules" if path.is_dir() => migrate_modules(&path, ito_path, options, &mut report)?,
+            "archive" if path.is_dir() => {
+                migrate_archive(&path, "archive", ito_path, options, &mut report)?
+            }
+            _ => report.unmapped.push(UnmappedEntry {
+                path: name,
+                reason: "no Ito equivalent".to_string(),
+            }),
+        }
+    }
+    Ok(report)
+}
+
+fn migrate_specs(
+    dir: &Path,
+    ito_path: &Path,
+    options: LegacyMigrationOptions<'_>,
+    report: &mut LegacyMigrationReport,
+) -> CoreResult<()> {
+    for name in sorted_entry_names(dir)? {
+        let from = format!("specs/{name}");
+        let src = dir.join(&name);
+        // Flat `specs/<capability>.md` files become `specs/<capability>/spec.md`.
+        if let Some(capability) = name.strip_suffix(".md")
+            && src.is_file()
+        {
+            let to = format!("specs/{capability}/spec.md");
+            let dst = paths::specs_dir(ito_path).join(capability).join("spec.md");
+            adopt_file("spec", &src, &dst, from, to, options, report)?;
+            continue;
+        }
+        if !src.join("spec.md").is_file() {
+            report.unmapped.push(UnmappedEntry {
+                path: from,
+                reason: "not a spec directory (missing spec.md)".to_string(),
+            });
+            continue;
+        }
+        let to = format!("specs/{name}");
+        let dst = paths::specs_dir(ito_path).join(&name);
+        adopt_entry("spec", &src, &dst, from, to, false, options, report)?;
+    }
+    Ok(())
+}
+
+fn migrate_changes(
+    dir: &Path,
+    ito_path: &Path,
+    options: LegacyMigrationOptions<'_>,
+    report: &mut LegacyMigrationReport,
+) -> CoreResult<()> {
+    for name in sorted_entry_names(dir)? {
+        let from = format!("changes/{name}");
+        let src = dir.join(&name);
+        if !src.is_dir() {
+            report.unmapped.push(UnmappedEntry {
+                path: from,
+                reason: "not a change directory".to_string(),
+            });
+            continue;
+        }
+        if name == "archive" {
+            migrate_archive(&src, "changes/archive", ito_path, options, report)?;
+            continue;
+        }
+
+        let slug = canonical_change_slug(&name);
+        let (folder, created) = match parse_change_id(&name) {
+            Ok(parsed) if parsed.canonical.as_str() == name => (name.clone(), false),
+            _ => match find_converted_change(ito_path, &slug) {
+                Some(existing) => (existing, false),
+                None => match create_change(ito_path, &slug, options.schema, None, None) {
+                    Ok(created) => (created.change_id, true),
+                    Err(e) => {
+                        report.unmapped.push(UnmappedEntry {
+                            path: from,
+                            reason: format!("cannot convert to an Ito change id: {e}"),
+                        });
+                        continue;
+                    }
+                },
+            },
+        };
+
+        let dst = paths::changes_dir(ito_path).join(&folder);
+        let to = format!("changes/{folder}");
+        // Freshly converted changes are seeded by `create_change`; legacy files win.
+        let adopted = adopt_entry("change", &src, &dst, from, to, created, options, report)?;
+        let meta = dst.join(".ito.yaml");
+        if adopted && !meta.exists() {
+            ito_common::io::write_std(&meta, format!("schema: {}\n", options.schema))
+                .map_err(|e| CoreError::io(format!("writing {}", meta.display()), e))?;
+        }
+    }
+    Ok(())
+}
+
+fn migrate_archive(
+    dir: &Path,
+    legacy_prefix: &str,
+    ito_path: &Path,
+    options: LegacyMigrationOptions<'_>,
+    report: &mut LegacyMigrationReport,
+) -> CoreResult<()> {
+    for name in sorted_entry_names(dir)? {
+        let src = dir.join(&name);
+        let from = format!("{legacy_prefix}/{name}");
+        if !src.is_dir() {
+            report.unmapped.push(UnmappedEntry {
+                path: from,
+                reason: "not an archived change directory".to_string(),
+            });
+            continue;
+        }
+        let dst = paths::changes_dir(ito_path).join("archive").join(&name);
+        let to = format!("changes/archive/{name}");
+        adopt_entry(
+            "archived_change",
+            &src,
+            &dst,
+            from,
+            to,
+            false,
+            options,
+            report,
+        )?;
+    }
+    Ok(())
+}
+
+fn migrate_modules(
+    dir: &Path,
+    ito_path: &Path,
+    options: LegacyMigrationOptions<'_>,
+    report: &mut LegacyMigrationReport,
+) -> CoreResult<()> {
+    for name in sorted_entry_names(dir)? {
+        let src = dir.join(&name);
+        let from = format!("modules/{name}");
+        if !src.is_dir() || parse_module_id(&name).is_err() {
+            report.unmapped.push(UnmappedEntry {
+                path: from,
+                reason: "module folders must be named NNN_name".to_string(),
+            });
+            continue;
+        }
+        let dst = paths::modules_dir(ito_path).join(&name);
+        let to = format!("modules/{name}");
+        adopt_entry("module", &src, &dst, from, to, false, options, report)?;
+    }
+    Ok(())
+}
+
+/// Copy `src` into `dst` and record the result; removes `src` when moving.
+///
+/// Returns `false` when the entry conflicted and was left in place.
+#[allow(clippy::too_many_arguments)]
+fn adopt_entry(
+    kind: &'static str,
+    src: &Path,
+    dst: &Path,
+    from: String,
+    to: String,
+    overwrite: bool,
+    options: LegacyMigrationOptions<'_>,
+    report: &mut LegacyMigrationReport,
+) -> CoreResult<bool> {
+    let mut copy = CopyOutcome::default();
+    copy_tree(src, dst, overwrite, &mut copy)?;
+
+    if !copy.conflicts.is_empty() {
+        report.unmapped.push(UnmappedEntry {
+            path: from,
+            reason: format!(
+                "conflicts with existing {}: {}",
+                to,
+                copy.conflicts.join(", ")
+            ),
+        });
+        return Ok(false);
+    }
+
+    if options.move_files {
+        std::fs::remove_dir_all(src)
+            .map_err(|e| CoreError::io(format!("removing {}", src.display()), e))?;
+    }
+    let entry = MigratedEntry { kind, from, to };
+    if copy.written == 0 && !overwrite {
+        report.already_present.push(entry);
+    } else {
+        report.migrated.push(entry);
+    }
+    Ok(true)
+}
+
+/// Single-file counterpart of [`adopt_entry`].
+fn adopt_file(
+    kind: &'static str,
+    src: &Path,
+    dst: &Path,
+    from: String,
+    to: String,
+    options: LegacyMigrationOptions<'_>,
+    report: &mut LegacyMigrationReport,
+) -> CoreResult<()> {
+    let entry = MigratedEntry { kind, from, to };
+    if dst.exists() {
+        if std::fs::read(src).ok() != std::fs::read(dst).ok() {
+            report.unmapped.push(UnmappedEntry {
+                path: entry.from,
+                reason: format!("conflicts with existing {}", entry.to),
+            });
+            return Ok(());
+        }
+        report.already_present.push(entry);
+    } else {
+        if let Some(parent) = dst.parent() {
+            ito_common::io::create_dir_all_std(parent)
+                .map_err(|e| CoreError::io(format!("creating {}", parent.display()), e))?;
+        }
+        std::fs::copy(src, dst)
+            .map_err(|e| CoreError::io(format!("copying {}", src.display()), e))?;
+        report.migrated.push(entry);
+    }
+    if options.move_files {
+        std::fs::remove_file(src)
+            .map_err(|e| CoreError::io(format!("removing {}", src.display()), e))?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+struct CopyOutcome {
+    written: usize,
+    conflicts: Vec<String>,
+}
+
+fn copy_tree(src: &Path, dst: &Path, overwrite: bool, out: &mut CopyOutcome) -> CoreResult<()> {
+    ito_common::io::create_dir_all_std(dst)
+        .map_err(|e| CoreError::io(format!("creating {}", dst.display()), e))?;
+    for name in sorted_entry_names(src)? {
+        let from = src.join(&name);
+        let to = dst.join(&name);
+        if from.is_dir() {
+            copy_tree(&from, &to, overwrite, out)?;
+            continue;
+        }
+        if to.exists() && !overwrite {
+            let same = std::fs::read(&from).ok() == std::fs::read(&to).ok();
+            if !same {
+                out.conflicts.push(name);
+            }
+            continue;
+        }
+        std::fs::copy(&from, &to)
+            .map_err(|e| CoreError::io(format!("copying {}", from.display()), e))?;
+        out.written += 1;
+    }
+    Ok(())
+}
+
+/// Find a change previously converted to `000-NN_<slug>`.
+fn find_converted_change(ito_path: &Path, slug: &str) -> Option<String> {
+    let entries = std::fs::read_dir(paths::changes_dir(ito_path)).ok()?;
+    let mut matches: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|folder| {
+            parse_change_id(folder).is_ok_and(|parsed| {
+                parsed.module_id.as_str() == "000" && parsed.name.as_str() == slug
+            })
+        })
+        .collect();
+    matches.sort();
+    matches.into_iter().next()
+}
+
+fn sorted_entry_names(dir: &Path) -> CoreResult<Vec<String>> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| CoreError::io(format!("reading {}", dir.display()), e))?;
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+#[cfg(test)]
+#[path = "legacy_layout_tests.rs"]
+mod legacy_layout_tests;
@@ -0,0 +1,115 @@
+use super::*;
+
+fn write(path: impl AsRef<Path>, contents: &str) {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).expect("parent dirs should exist");
+    }
+    std::fs::write(path, contents).expect("test fixture should write");
+}
+
+fn options(move_files: bool) -> LegacyMigrationOptions<'static> {
+    LegacyMigrationOptions {
+        schema: "spec-driven",
+        move_files,
+    }
+}
+
+#[test]
+fn detects_configured_directories_with_trees_or_markers() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    std::fs::create_dir_all(tmp.path().join("openspec/specs")).expect("specs");
+    write(tmp.path().join("plans/AGENTS.md"), "# agents\n");
+    std::fs::create_dir_all(tmp.path().join(".openspec")).expect("empty dir");
+
+    assert_eq!(
+        detect_legacy_layouts(tmp.path(), &LegacyLayoutDetection::default()),
+        [tmp.path().join("openspec")]
+    );
+
+    let custom = LegacyLayoutDetection {
+        dir_names: vec!["plans".to_string()],
+        marker_files: vec!["AGENTS.md".to_string()],
+    };
+    assert_eq!(
+        detect_legacy_layouts(tmp.path(), &custom),
+        [tmp.path().join("plans")]
+    );
+}
+
+#[test]
+fn converts_non_canonical_changes_and_is_idempotent() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let legacy = tmp.path().join("openspec");
+    let ito = tmp.path().join(".ito");
+    write(
+        legacy.join("changes/add-auth/proposal.md"),
+        "## Why\nAuth.\n",
+    );
+    write(
+        legacy.join("changes/add-auth/tasks.md"),
+        "- [ ] 1.1 Do it\n",
+    );
+
+    let first = migrate_legacy_layout(&legacy, &ito, options(false)).expect("migrate");
+    assert_eq!(first.migrated.len(), 1);
+    assert_eq!(first.migrated[0].to, "changes/000-01_add-auth");
+    let change = ito.join("changes/000-01_add-auth");
+    assert_eq!(
+        std::fs::read_to_string(change.join("proposal.md")).expect("proposal"),
+        "## Why\nAuth.\n"
+    );
+    assert!(
+        std::fs::read_to_string(change.join(".ito.yaml"))
+            .expect("meta")
+            .contains("schema: spec-driven")
+    );
+
+    let second = migrate_legacy_layout(&legacy, &ito, options(false)).expect("rerun");
+    assert!(second.migrated.is_empty(), "{second:?}");
+    assert_eq!(second.already_present.len(), 1);
+    assert!(!ito.join("changes/000-02_add-auth").exists());
+}
+
+#[test]
+fn conflicting_files_are_left_in_place_even_when_moving() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let legacy = tmp.path().join("openspec");
+    let ito = tmp.path().join(".ito");
+    write(legacy.join("specs/auth/spec.md"), "legacy\n");
+    write(ito.join("specs/auth/spec.md"), "already edited\n");
+
+    let report = migrate_legacy_layout(&legacy, &ito, options(true)).expect("migrate");
+
+    assert!(report.migrated.is_empty());
+    assert_eq!(report.unmapped[0].path, "specs/auth");
+    assert!(report.unmapped[0].reason.contains("spec.md"));
+    assert!(legacy.join("specs/auth/spec.md").exists());
+    assert_eq!(
+        std::fs::read_to_string(ito.join("specs/auth/spec.md")).expect("spec"),
+        "already edited\n"
+    );
+}
+
+#[test]
+fn flat_specs_and_top_level_archive_are_mapped() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let legacy = tmp.path().join(".openspec");
+    let ito = tmp.path().join(".ito");
+    write(legacy.join("specs/billing.md"), "## Purpose\nBilling.\n");
+    write(
+        legacy.join("archive/old-change/proposal.md"),
+        "## Why\nOld.\n",
+    );
+
+    let report = migrate_legacy_layout(&legacy, &ito, options(true)).expect("migrate");
+
+    let targets: Vec<&str> = report.migrated.iter().map(|e| e.to.as_str()).collect();
+    assert_eq!(
+        targets,
+        ["changes/archive/old-change", "specs/billing/spec.md"]
+    );
+    assert!(ito.join("specs/billing/spec.md").is_file());
+    assert!(ito.join("changes/archive/old-change/proposal.md").is_file());
+    assert!(!legacy.join("specs/billing.md").exists());
+}
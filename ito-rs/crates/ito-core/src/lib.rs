@@ -15,6 +15,9 @@ pub mod archive;
 /// Active change artifact mutation services for runtime-selected persistence.
 pub mod artifact_mutations;
 
+/// One-shot generation of a single change artifact through a harness.
+pub mod artifact_run;
+
 /// Backend server authentication setup and token resolution.
 #[cfg(feature = "backend")]
 pub mod backend_auth;
@@ -62,9 +65,19 @@ pub mod backend_sync;
 /// Audit log infrastructure: writer, reader, reconciliation, worktree discovery.
 pub mod audit;
 
+/// Change rename with reference, state, and branch migration.
+pub mod change_rename;
+
+/// Split selected tasks and delta specs of a change into a new change.
+pub mod change_split;
+
 /// Filesystem-backed change repository implementation.
 pub mod change_repository;
 
+/// Sync change metadata (task status, `.ito.yaml`) through the coordination branch.
+#[cfg(feature = "coordination-branch")]
+pub mod change_sync;
+
 mod change_meta;
 
 /// JSON configuration file CRUD operations.
@@ -89,6 +102,9 @@ pub mod create;
 /// Distribution/build metadata helpers.
 pub mod distribution;
 
+/// Installation health diagnostics for `ito doctor`.
+pub mod doctor;
+
 /// Core-layer error types and result alias.
 pub mod errors;
 
@@ -136,12 +152,24 @@ pub mod process;
 /// Installers for project/home templates and harness assets.
 pub mod installers;
 
+/// Safe editing of marker-delimited managed blocks in user files.
+pub mod managed_block;
+
 /// List/query project entities (modules, changes, tasks).
 pub mod list;
 
+/// Ito-aware annotations for directory listings (changes, specs, modules).
+pub mod path_annotations;
+
 /// Read-only inspection of repositories using legacy coordination storage.
 pub mod legacy_coordination;
 
+/// Detect and migrate OpenSpec-style legacy planning layouts.
+pub mod legacy_layout;
+
+/// Every location Ito may read or write for a project (`ito paths`).
+pub mod locations;
+
 /// Agent memory provider resolution and instruction rendering.
 pub mod memory;
 
@@ -151,12 +179,24 @@ pub mod module_repository;
 /// Planning directory initialization (filesystem I/O).
 pub mod planning_init;
 
+/// Named change profiles (schema, harness, model, and Ralph defaults).
+pub mod profiles;
+
+/// Structured checks for missing or unreadable parts of the Ito directory.
+pub mod project_layout;
+
 /// Filesystem-backed task repository implementation.
 pub mod task_repository;
 
 /// Filesystem-backed promoted spec repository implementation.
 pub mod spec_repository;
 
+/// Spec rename with delta and cross-reference updates.
+pub mod spec_rename;
+
+/// Staged multi-file directory writes promoted by a single rename.
+pub mod staging;
+
 /// Task mutation services for filesystem and backend persistence.
 pub mod task_mutations;
 
@@ -177,9 +217,15 @@ pub mod tasks;
 /// Ralph Wiggum loop support.
 pub mod ralph;
 
+/// Stale in-progress task detection backed by the audit log.
+pub mod stale_tasks;
+
 /// Indexing helpers for repository contents.
 pub mod repo_index;
 
+/// Concurrency-safe access to per-worktree `.ito/session.json`.
+pub mod session_state;
+
 /// Display and inspection commands.
 pub mod show;
 
@@ -192,6 +238,12 @@ pub mod viewer;
 /// Repository runtime selection and composition.
 pub mod repository_runtime;
 
+/// Read-only services for Ito state at a git revision.
+pub mod revision;
+
+/// High-level project facade for embedding Ito operations.
+pub mod project;
+
 /// Statistics collection and computation for command usage.
 pub mod stats;
 
@@ -201,6 +253,13 @@ pub mod validate;
 /// Repository-level validation rules driven by `ito_config::types::ItoConfig`.
 pub mod validate_repo;
 
+/// Debounced filesystem watching shared by `--watch` commands.
+#[cfg(feature = "watch")]
+pub mod watch;
+
+/// Non-interactive change worktree creation for `ito worktree create`.
+pub mod worktree_create;
+
 /// Change worktree ensure: verify or create the correct worktree for a change.
 pub mod worktree_ensure;
 
@@ -213,6 +272,9 @@ pub mod worktree_validate;
 /// Schema templates execution and planning.
 pub mod templates;
 
+/// Pinned template bundles downloaded from Ito releases.
+pub mod template_bundle;
+
 // Re-export domain types for CLI and adapter convenience
 pub use ito_domain::backend::{
     AllocateResult, ArchiveResult, ArtifactBundle, BackendArchiveClient, BackendChangeReader,
@@ -223,7 +285,8 @@ pub use ito_domain::backend::{
 pub use ito_domain::changes::{
     Change, ChangeArtifactKind, ChangeArtifactMutationError, ChangeArtifactMutationResult,
     ChangeArtifactMutationService, ChangeArtifactMutationServiceResult, ChangeArtifactRef,
-    ChangeLifecycleFilter, ChangeRepository, ChangeSummary, ChangeTargetResolution,
+    ChangeLifecycleFilter, ChangeRepository, ChangeSummary, ChangeTargetMatch,
+    ChangeTargetResolution, ChangeWorkStatus,
 };
 pub use ito_domain::errors::DomainError;
 pub use ito_domain::modules::{Module, ModuleRepository, ModuleSummary};
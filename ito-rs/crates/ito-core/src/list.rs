@@ -3,6 +3,7 @@
 //! These functions are used by the CLI to produce stable, JSON-friendly
 //! summaries of on-disk Ito state.
 
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, SecondsFormat, Timelike, Utc};
@@ -13,7 +14,8 @@ use ito_common::fs::StdFs;
 use ito_common::paths;
 use ito_config::types::ItoConfig;
 use ito_domain::changes::{
-    ChangeLifecycleFilter, ChangeRepository as DomainChangeRepository, ChangeStatus, ChangeSummary,
+    ChangeLifecycleFilter, ChangeMetadata, ChangeRepository as DomainChangeRepository,
+    ChangeStatus, ChangeSummary, ChangeWorkStatus,
 };
 use ito_domain::modules::ModuleRepository as DomainModuleRepository;
 
@@ -79,6 +81,18 @@ pub struct ChangeListItem {
     pub work_status: String,
     /// True when no remaining work (complete or paused)
     pub completed: bool,
+    /// Module id parsed from the change id.
+    #[serde(rename = "moduleId", skip_serializing_if = "Option::is_none")]
+    pub module_id: Option<String>,
+    /// Listing status: the work status, or `blocked` when a ready change waits on dependencies.
+    #[serde(rename = "listStatus")]
+    pub list_status: String,
+    /// Active changes this change depends on that are not yet complete.
+    #[serde(rename = "blockedBy", skip_serializing_if = "Vec::is_empty")]
+    pub blocked_by: Vec<String>,
+    /// Proposal front matter metadata.
+    #[serde(skip_serializing_if = "ChangeMetadata::is_empty")]
+    pub metadata: ChangeMetadata,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
@@ -113,6 +127,56 @@ pub enum ChangeSortOrder {
     Recent,
     /// Sort by change name.
     Name,
+    /// Sort by listing status (in-progress, ready, blocked, draft, paused, complete), then name.
+    Status,
+    /// Sort by completed-task ratio (most complete first), then name.
+    Progress,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Listing status filter for `ito list --status` (repeatable, OR semantics).
+pub enum ChangeStatusFilter {
+    /// Missing planning artifacts.
+    Draft,
+    /// Ready to start and not waiting on dependencies.
+    Ready,
+    /// At least one task in progress.
+    InProgress,
+    /// Remaining work is shelved.
+    Paused,
+    /// All tasks complete.
+    Complete,
+    /// Ready, but waiting on an incomplete dependency change.
+    Blocked,
+}
+
+impl ChangeStatusFilter {
+    /// Every filter value, in listing-status sort order.
+    pub const ALL: [ChangeStatusFilter; 6] = [
+        ChangeStatusFilter::InProgress,
+        ChangeStatusFilter::Ready,
+        ChangeStatusFilter::Blocked,
+        ChangeStatusFilter::Draft,
+        ChangeStatusFilter::Paused,
+        ChangeStatusFilter::Complete,
+    ];
+
+    /// Parse a kebab-case status name (as printed in `listStatus`).
+    pub fn parse(raw: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| f.as_str() == raw)
+    }
+
+    /// Kebab-case status name.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ChangeStatusFilter::Draft => "draft",
+            ChangeStatusFilter::Ready => "ready",
+            ChangeStatusFilter::InProgress => "in-progress",
+            ChangeStatusFilter::Paused => "paused",
+            ChangeStatusFilter::Complete => "complete",
+            ChangeStatusFilter::Blocked => "blocked",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -156,6 +220,52 @@ pub struct ChangeListSummary {
     pub work_status: String,
     /// True when no remaining work (complete or paused).
     pub completed: bool,
+    /// Module id parsed from the change id.
+    pub module_id: Option<String>,
+    /// Active changes this change depends on that are not yet complete.
+    ///
+    /// Only populated for changes whose work status is `ready`.
+    pub blocked_by: Vec<String>,
+    /// Proposal front matter metadata.
+    pub metadata: ChangeMetadata,
+}
+
+impl ChangeListSummary {
+    /// Listing status: `blocked` for ready changes waiting on dependencies,
+    /// otherwise the work status.
+    pub fn list_status(&self) -> &str {
+        if self.blocked_by.is_empty() {
+            &self.work_status
+        } else {
+            ChangeStatusFilter::Blocked.as_str()
+        }
+    }
+
+    /// Returns `true` when the listing status matches `filter`.
+    pub fn matches_status(&self, filter: ChangeStatusFilter) -> bool {
+        self.list_status() == filter.as_str()
+    }
+}
+
+impl From<&ChangeListSummary> for ChangeListItem {
+    fn from(s: &ChangeListSummary) -> Self {
+        Self {
+            name: s.name.clone(),
+            completed_tasks: s.completed_tasks,
+            shelved_tasks: s.shelved_tasks,
+            in_progress_tasks: s.in_progress_tasks,
+            pending_tasks: s.pending_tasks,
+            total_tasks: s.total_tasks,
+            last_modified: to_iso_millis(s.last_modified),
+            status: s.status.clone(),
+            work_status: s.work_status.clone(),
+            completed: s.completed,
+            module_id: s.module_id.clone(),
+            list_status: s.list_status().to_string(),
+            blocked_by: s.blocked_by.clone(),
+            metadata: s.metadata.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
@@ -196,6 +306,51 @@ pub fn list_modules(module_repo: &dyn DomainModuleRepository) -> CoreResult<Vec<
     Ok(modules)
 }
 
+/// Render the module dependency graph as a Mermaid flowchart.
+///
+/// Each module is a node labelled with its folder name and change count; an
+/// edge `A --> B` means module `A` depends on module `B`. Dependencies on
+/// modules that do not exist are drawn as `(missing)` nodes.
+pub fn render_module_graph_mermaid(
+    modules: &[ModuleListItem],
+    graph: &crate::validate::ModuleGraph,
+) -> String {
+    let mut out = String::from("graph TD\n");
+    for m in modules {
+        let noun = if m.change_count == 1 {
+            "change"
+        } else {
+            "changes"
+        };
+        out.push_str(&format!(
+            "    m{id}[\"{full_name}<br/>{count} {noun}\"]\n",
+            id = m.id,
+            full_name = m.full_name,
+            count = m.change_count,
+        ));
+    }
+
+    let mut missing: Vec<&str> = Vec::new();
+    for node in &graph.nodes {
+        for dep in &node.depends_on {
+            if !modules.iter().any(|m| &m.id == dep) && !missing.contains(&dep.as_str()) {
+                missing.push(dep);
+            }
+        }
+    }
+    missing.sort_unstable();
+    for id in missing {
+        out.push_str(&format!("    m{id}[\"{id} (missing)\"]\n"));
+    }
+
+    for node in &graph.nodes {
+        for dep in &node.depends_on {
+            out.push_str(&format!("    m{} --> m{dep}\n", node.id));
+        }
+    }
+    out
+}
+
 /// List change directories under `{ito_path}/changes`.
 pub fn list_change_dirs(ito_path: &Path) -> CoreResult<Vec<PathBuf>> {
     let fs = StdFs;
@@ -207,35 +362,52 @@ pub fn list_change_dirs(ito_path: &Path) -> CoreResult<Vec<PathBuf>> {
 }
 
 /// List active changes using typed summaries for adapter rendering.
+///
+/// All summaries come from a single repository `list()` call; dependency
+/// blocking is resolved against that same batch.
 pub fn list_changes(
     change_repo: &dyn DomainChangeRepository,
     input: ListChangesInput,
 ) -> CoreResult<Vec<ChangeListSummary>> {
-    let mut summaries: Vec<ChangeSummary> = change_repo.list().into_core()?;
-
-    match input.progress_filter {
-        ChangeProgressFilter::All => {}
-        ChangeProgressFilter::Ready => summaries.retain(|s| s.is_ready()),
-        ChangeProgressFilter::Completed => summaries.retain(is_completed),
-        ChangeProgressFilter::Partial => summaries.retain(is_partial),
-        ChangeProgressFilter::Pending => summaries.retain(is_pending),
-    }
-
-    match input.sort {
-        ChangeSortOrder::Name => summaries.sort_by(|a, b| a.id.cmp(&b.id)),
-        ChangeSortOrder::Recent => {
-            summaries.sort_by(|a, b| b.last_modified.cmp(&a.last_modified).then(a.id.cmp(&b.id)))
-        }
-    }
+    let all: Vec<ChangeSummary> = change_repo.list().into_core()?;
+    Ok(summarize_changes(&all, input))
+}
 
-    Ok(summaries
-        .into_iter()
+/// Filter and sort an already-loaded batch of change summaries.
+///
+/// Callers holding a warm index (see [`crate::repo_index::IncrementalRepoIndex`])
+/// use this instead of [`list_changes`] to skip the repository scan.
+pub fn summarize_changes(all: &[ChangeSummary], input: ListChangesInput) -> Vec<ChangeListSummary> {
+    let finished: BTreeMap<&str, bool> = all
+        .iter()
+        .map(|s| (s.id.as_str(), is_completed(s)))
+        .collect();
+
+    let mut summaries: Vec<ChangeListSummary> = all
+        .iter()
+        .filter(|s| match input.progress_filter {
+            ChangeProgressFilter::All => true,
+            ChangeProgressFilter::Ready => s.is_ready(),
+            ChangeProgressFilter::Completed => is_completed(s),
+            ChangeProgressFilter::Partial => is_partial(s),
+            ChangeProgressFilter::Pending => is_pending(s),
+        })
         .map(|s| {
             let status = match s.status() {
                 ChangeStatus::NoTasks => "no-tasks",
                 ChangeStatus::InProgress => "in-progress",
                 ChangeStatus::Complete => "complete",
             };
+            let blocked_by = if s.work_status() == ChangeWorkStatus::Ready {
+                s.orchestrate
+                    .depends_on
+                    .iter()
+                    .filter(|dep| finished.get(dep.as_str()) == Some(&false))
+                    .cloned()
+                    .collect()
+            } else {
+                Vec::new()
+            };
             ChangeListSummary {
                 name: s.id.clone(),
                 completed_tasks: s.completed_tasks,
@@ -246,10 +418,75 @@ pub fn list_changes(
                 last_modified: s.last_modified,
                 status: status.to_string(),
                 work_status: s.work_status().to_string(),
-                completed: is_completed(&s),
+                completed: is_completed(s),
+                module_id: s.module_id.clone(),
+                blocked_by,
+                metadata: s.metadata.clone(),
             }
         })
-        .collect())
+        .collect();
+
+    sort_change_summaries(&mut summaries, input.sort);
+    summaries
+}
+
+/// Keep only summaries whose listing status matches any of `statuses`.
+///
+/// An empty `statuses` slice keeps everything.
+pub fn filter_changes_by_status(
+    summaries: Vec<ChangeListSummary>,
+    statuses: &[ChangeStatusFilter],
+) -> Vec<ChangeListSummary> {
+    if statuses.is_empty() {
+        return summaries;
+    }
+    summaries
+        .into_iter()
+        .filter(|s| statuses.iter().any(|f| s.matches_status(*f)))
+        .collect()
+}
+
+/// Keep only summaries carrying every label in `labels` and, when given,
+/// owned by `owner`. Matching is case-insensitive.
+pub fn filter_changes_by_metadata(
+    summaries: Vec<ChangeListSummary>,
+    labels: &[String],
+    owner: Option<&str>,
+) -> Vec<ChangeListSummary> {
+    summaries
+        .into_iter()
+        .filter(|s| labels.iter().all(|label| s.metadata.has_label(label)))
+        .filter(|s| owner.is_none_or(|owner| s.metadata.has_owner(owner)))
+        .collect()
+}
+
+fn sort_change_summaries(summaries: &mut [ChangeListSummary], sort: ChangeSortOrder) {
+    match sort {
+        ChangeSortOrder::Name => summaries.sort_by(|a, b| a.name.cmp(&b.name)),
+        ChangeSortOrder::Recent => summaries.sort_by(|a, b| {
+            b.last_modified
+                .cmp(&a.last_modified)
+                .then(a.name.cmp(&b.name))
+        }),
+        ChangeSortOrder::Status => summaries.sort_by(|a, b| {
+            status_rank(a)
+                .cmp(&status_rank(b))
+                .then(a.name.cmp(&b.name))
+        }),
+        ChangeSortOrder::Progress => summaries.sort_by(|a, b| {
+            // Compare completed/total ratios without floating point.
+            let lhs = u64::from(b.completed_tasks) * u64::from(a.total_tasks.max(1));
+            let rhs = u64::from(a.completed_tasks) * u64::from(b.total_tasks.max(1));
+            lhs.cmp(&rhs).then(a.name.cmp(&b.name))
+        }),
+    }
+}
+
+fn status_rank(summary: &ChangeListSummary) -> usize {
+    ChangeStatusFilter::ALL
+        .iter()
+        .position(|f| summary.matches_status(*f))
+        .unwrap_or(ChangeStatusFilter::ALL.len())
 }
 
 /// List only changes that pass centralized authoritative `prepare` readiness.
@@ -362,23 +599,28 @@ pub fn list_specs(ito_path: &Path) -> CoreResult<Vec<SpecListItem>> {
     let specs_dir = paths::specs_dir(ito_path);
     let fs = StdFs;
     for id in ito_domain::discovery::list_spec_dir_names(&fs, ito_path).into_core()? {
-        let spec_md = specs_dir.join(&id).join("spec.md");
-        let content = ito_common::io::read_to_string_or_default(&spec_md);
-        let requirement_count = if content.is_empty() {
-            0
-        } else {
-            count_requirements_in_spec_markdown(&content)
-        };
-        specs.push(SpecListItem {
-            id,
-            requirement_count,
-        });
+        specs.push(spec_list_item(&specs_dir, id));
     }
 
     specs.sort_by(|a, b| a.id.cmp(&b.id));
     Ok(specs)
 }
 
+/// Build the listing entry for the spec directory `id` under `specs_dir`.
+pub(crate) fn spec_list_item(specs_dir: &Path, id: String) -> SpecListItem {
+    let spec_md = specs_dir.join(&id).join("spec.md");
+    let content = ito_common::io::read_to_string_or_default(&spec_md);
+    let requirement_count = if content.is_empty() {
+        0
+    } else {
+        count_requirements_in_spec_markdown(&content)
+    };
+    SpecListItem {
+        id,
+        requirement_count,
+    }
+}
+
 #[cfg(test)]
 fn parse_modular_change_module_id(folder: &str) -> Option<&str> {
     // Accept canonical folder names like:
@@ -455,7 +697,6 @@ fn count_requirements_in_spec_markdown(content: &str) -> u32 {
 }
 
 fn is_completed(s: &ChangeSummary) -> bool {
-    use ito_domain::changes::ChangeWorkStatus;
     let status = s.work_status();
     match status {
         ChangeWorkStatus::Complete => true,
@@ -467,7 +708,6 @@ fn is_completed(s: &ChangeSummary) -> bool {
 }
 
 fn is_partial(s: &ChangeSummary) -> bool {
-    use ito_domain::changes::ChangeWorkStatus;
     let in_active_progress_bucket = match s.work_status() {
         ChangeWorkStatus::Ready => true,
         ChangeWorkStatus::InProgress => true,
@@ -483,7 +723,6 @@ fn is_partial(s: &ChangeSummary) -> bool {
 }
 
 fn is_pending(s: &ChangeSummary) -> bool {
-    use ito_domain::changes::ChangeWorkStatus;
     let in_active_progress_bucket = match s.work_status() {
         ChangeWorkStatus::Ready => true,
         ChangeWorkStatus::InProgress => true,
@@ -224,3 +224,225 @@ fn list_changes_sorts_by_name_and_recent() {
     assert_eq!(by_recent[0].name, "000-02_beta");
     assert_eq!(by_recent[1].name, "000-01_alpha");
 }
+
+/// Build one change per listing status: draft, ready, in-progress, paused,
+/// complete, and blocked (ready but depending on the in-progress change).
+fn make_status_fixture(root: &Path) {
+    write(
+        root.join(".ito/changes/000-01_draft/proposal.md"),
+        "## Why\nfixture\n",
+    );
+    make_change(
+        root,
+        "000-02_ready",
+        "## 1. Implementation\n- [ ] 1.1 todo\n",
+    );
+    make_change(
+        root,
+        "000-03_active",
+        "## 1. Implementation\n- [x] 1.1 done\n- [~] 1.2 doing\n- [ ] 1.3 todo\n",
+    );
+    make_change(
+        root,
+        "000-04_paused",
+        "## Wave 1\n\n### Task 1.1: Done\n- **Dependencies**: None\n- **Updated At**: 2026-01-28\n- **Status**: [x] complete\n\n### Task 1.2: Later\n- **Dependencies**: None\n- **Updated At**: 2026-01-28\n- **Status**: [-] shelved\n",
+    );
+    make_change(
+        root,
+        "000-05_done",
+        "## 1. Implementation\n- [x] 1.1 done\n",
+    );
+    make_change(
+        root,
+        "001-01_blocked",
+        "## 1. Implementation\n- [ ] 1.1 todo\n",
+    );
+    write(
+        root.join(".ito/changes/001-01_blocked/.ito.yaml"),
+        "orchestrate:\n  depends_on:\n    - 000-03_active\n    - 000-05_done\n    - 000-99_archived\n",
+    );
+}
+
+fn list_all(ito_path: &Path, sort: ChangeSortOrder) -> Vec<ChangeListSummary> {
+    let change_repo = crate::change_repository::FsChangeRepository::new(ito_path);
+    list_changes(
+        &change_repo,
+        ListChangesInput {
+            progress_filter: ChangeProgressFilter::All,
+            sort,
+        },
+    )
+    .expect("list should succeed")
+}
+
+#[test]
+fn list_changes_reports_list_status_module_and_blockers() {
+    let repo = tempfile::tempdir().expect("repo tempdir");
+    make_status_fixture(repo.path());
+
+    let summaries = list_all(&repo.path().join(".ito"), ChangeSortOrder::Name);
+    let statuses: Vec<(&str, &str)> = summaries
+        .iter()
+        .map(|s| (s.name.as_str(), s.list_status()))
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![
+            ("000-01_draft", "draft"),
+            ("000-02_ready", "ready"),
+            ("000-03_active", "in-progress"),
+            ("000-04_paused", "paused"),
+            ("000-05_done", "complete"),
+            ("001-01_blocked", "blocked"),
+        ]
+    );
+
+    let blocked = summaries.last().unwrap();
+    assert_eq!(blocked.work_status, "ready");
+    assert_eq!(blocked.module_id.as_deref(), Some("001"));
+    // Complete and unknown (archived) dependencies never block.
+    assert_eq!(blocked.blocked_by, vec!["000-03_active".to_string()]);
+}
+
+#[test]
+fn filter_changes_by_status_uses_or_semantics() {
+    let repo = tempfile::tempdir().expect("repo tempdir");
+    make_status_fixture(repo.path());
+    let summaries = list_all(&repo.path().join(".ito"), ChangeSortOrder::Name);
+
+    let names = |filters: &[ChangeStatusFilter]| -> Vec<String> {
+        filter_changes_by_status(summaries.clone(), filters)
+            .into_iter()
+            .map(|s| s.name)
+            .collect()
+    };
+    assert_eq!(names(&[]).len(), 6);
+    assert_eq!(names(&[ChangeStatusFilter::Ready]), vec!["000-02_ready"]);
+    assert_eq!(
+        names(&[ChangeStatusFilter::Blocked]),
+        vec!["001-01_blocked"]
+    );
+    assert_eq!(
+        names(&[ChangeStatusFilter::InProgress, ChangeStatusFilter::Complete]),
+        vec!["000-03_active", "000-05_done"]
+    );
+}
+
+#[test]
+fn filter_changes_by_metadata_matches_labels_and_owner() {
+    let repo = tempfile::tempdir().expect("repo tempdir");
+    let root = repo.path();
+    make_change(root, "000-01_ui", "- [ ] task1\n");
+    make_change(root, "000-02_api", "- [ ] task1\n");
+    make_change(root, "000-03_plain", "- [ ] task1\n");
+    write(
+        root.join(".ito/changes/000-01_ui/proposal.md"),
+        "---\nowner: alice\npriority: P1\nlabels: [ui, Billing]\n---\n## Why\nfixture\n",
+    );
+    write(
+        root.join(".ito/changes/000-02_api/proposal.md"),
+        "---\nowner: bob\nlabels: [api, billing]\n---\n## Why\nfixture\n",
+    );
+    let summaries = list_all(&root.join(".ito"), ChangeSortOrder::Name);
+
+    let names = |labels: &[&str], owner: Option<&str>| -> Vec<String> {
+        let labels: Vec<String> = labels.iter().map(|l| l.to_string()).collect();
+        filter_changes_by_metadata(summaries.clone(), &labels, owner)
+            .into_iter()
+            .map(|s| s.name)
+            .collect()
+    };
+    assert_eq!(names(&[], None).len(), 3);
+    assert_eq!(names(&["billing"], None), vec!["000-01_ui", "000-02_api"]);
+    assert_eq!(names(&["billing", "ui"], None), vec!["000-01_ui"]);
+    assert_eq!(names(&[], Some("Bob")), vec!["000-02_api"]);
+    assert!(names(&["ui"], Some("bob")).is_empty());
+
+    let ui = &summaries[0];
+    assert_eq!(ui.metadata.owner.as_deref(), Some("alice"));
+    assert_eq!(
+        ui.metadata.priority,
+        Some(ito_domain::changes::ChangePriority::P1)
+    );
+    assert!(summaries[2].metadata.is_empty());
+}
+
+#[test]
+fn list_changes_sorts_by_status_and_progress() {
+    let repo = tempfile::tempdir().expect("repo tempdir");
+    make_status_fixture(repo.path());
+    let ito_path = repo.path().join(".ito");
+
+    let by_status: Vec<String> = list_all(&ito_path, ChangeSortOrder::Status)
+        .into_iter()
+        .map(|s| s.name)
+        .collect();
+    assert_eq!(
+        by_status,
+        vec![
+            "000-03_active",
+            "000-02_ready",
+            "001-01_blocked",
+            "000-01_draft",
+            "000-04_paused",
+            "000-05_done",
+        ]
+    );
+
+    let by_progress: Vec<String> = list_all(&ito_path, ChangeSortOrder::Progress)
+        .into_iter()
+        .map(|s| s.name)
+        .collect();
+    assert_eq!(
+        by_progress,
+        vec![
+            "000-05_done",
+            "000-04_paused",
+            "000-03_active",
+            "000-01_draft",
+            "000-02_ready",
+            "001-01_blocked",
+        ]
+    );
+}
+
+#[test]
+fn change_status_filter_parses_kebab_case_names() {
+    for filter in ChangeStatusFilter::ALL {
+        assert_eq!(ChangeStatusFilter::parse(filter.as_str()), Some(filter));
+    }
+    assert_eq!(ChangeStatusFilter::parse("done"), None);
+}
+
+#[test]
+fn module_graph_mermaid_renders_nodes_counts_and_edges() {
+    let item = |id: &str, name: &str, change_count: usize| ModuleListItem {
+        id: id.to_string(),
+        name: name.to_string(),
+        full_name: format!("{id}_{name}"),
+        change_count,
+        sub_modules: Vec::new(),
+    };
+    let modules = vec![item("001", "base", 1), item("002", "api", 3)];
+    let graph = crate::validate::ModuleGraph {
+        nodes: vec![
+            crate::validate::ModuleGraphNode {
+                id: "001".to_string(),
+                full_name: "001_base".to_string(),
+                depends_on: Vec::new(),
+            },
+            crate::validate::ModuleGraphNode {
+                id: "002".to_string(),
+                full_name: "002_api".to_string(),
+                depends_on: vec!["001".to_string(), "009".to_string()],
+            },
+        ],
+        issues: Vec::new(),
+    };
+
+    let mermaid = render_module_graph_mermaid(&modules, &graph);
+    assert_eq!(
+        mermaid,
+        "graph TD\n    m001[\"001_base<br/>1 change\"]\n    m002[\"002_api<br/>3 changes\"]\n    m009[\"009 (missing)\"]\n    m002 --> m001\n    m002 --> m009\n"
+    );
+}
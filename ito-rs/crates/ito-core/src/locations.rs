@@ -0,0 +1,189 @@
+//! Every location Ito may read or write for a project.
+//!
+//! `ito paths` prints this report so users (and support triage) can see which
+//! config files were loaded and where global files, state, and logs live.
+//! Each path comes from the helper the owning command uses, so the report
+//! cannot drift from what the commands actually touch.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use ito_config::{ito_config_dir, ito_data_dir, project_config_paths};
+use serde::Serialize;
+
+use crate::audit::{AuditStorageLocation, default_audit_store, fallback_audit_log_path};
+use crate::harness::transcript::transcripts_root;
+use crate::project::Project;
+use crate::ralph::state::ralph_state_root;
+
+/// What a reported location is used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LocationKind {
+    /// Project root directory.
+    ProjectRoot,
+    /// Ito working directory (usually `<root>/.ito`).
+    ItoDir,
+    /// A config file consulted for this project, in precedence order.
+    Config,
+    /// Global config directory (`~/.config/ito` or XDG equivalent).
+    GlobalConfigDir,
+    /// Per-user data directory (user schemas, coordination worktrees).
+    DataDir,
+    /// Ralph loop state for all changes.
+    RalphState,
+    /// Where audit events are recorded.
+    AuditLog,
+    /// Local file receiving audit events when the internal branch is unavailable.
+    AuditFallbackLog,
+    /// Recorded harness run transcripts.
+    Transcripts,
+}
+
+impl LocationKind {
+    /// Human-readable label used in table output.
+    pub const fn label(self) -> &'static str {
+        match self {
+            LocationKind::ProjectRoot => "project root",
+            LocationKind::ItoDir => "ito dir",
+            LocationKind::Config => "config",
+            LocationKind::GlobalConfigDir => "global config dir",
+            LocationKind::DataDir => "data dir",
+            LocationKind::RalphState => "ralph state",
+            LocationKind::AuditLog => "audit log",
+            LocationKind::AuditFallbackLog => "audit fallback log",
+            LocationKind::Transcripts => "transcripts",
+        }
+    }
+}
+
+/// One location and what is currently on disk there.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ItoLocation {
+    /// What the location is used for.
+    pub kind: LocationKind,
+    /// Filesystem path, or `None` when it cannot be determined (no home
+    /// directory) or the storage is not a file.
+    pub path: Option<PathBuf>,
+    /// Non-file storage, such as `internal-branch:ito/internal/audit`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage: Option<String>,
+    /// Whether `path` exists.
+    pub exists: bool,
+    /// For config files: whether the file was loaded into the merged config.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loaded: Option<bool>,
+    /// File size in bytes (files only).
+    pub size_bytes: Option<u64>,
+    /// RFC 3339 modification time.
+    pub modified: Option<String>,
+}
+
+impl ItoLocation {
+    fn at(kind: LocationKind, path: PathBuf) -> Self {
+        let metadata = std::fs::metadata(&path).ok();
+        Self {
+            kind,
+            exists: metadata.is_some(),
+            size_bytes: metadata.as_ref().filter(|m| m.is_file()).map(|m| m.len()),
+            modified: metadata
+                .and_then(|m| m.modified().ok())
+                .map(|t| DateTime::<Utc>::from(t).to_rfc3339_opts(SecondsFormat::Secs, true)),
+            path: Some(path),
+            storage: None,
+            loaded: None,
+        }
+    }
+
+    fn maybe_at(kind: LocationKind, path: Option<PathBuf>) -> Self {
+        match path {
+            Some(path) => Self::at(kind, path),
+            None => Self::elsewhere(kind, None),
+        }
+    }
+
+    fn elsewhere(kind: LocationKind, storage: Option<String>) -> Self {
+        Self {
+            kind,
+            path: None,
+            storage,
+            exists: false,
+            loaded: None,
+            size_bytes: None,
+            modified: None,
+        }
+    }
+}
+
+/// Locations for one project, in the order `ito paths` prints them.
+#[derive(Debug, Clone, Serialize)]
+pub struct ItoLocations {
+    /// Resolved project root.
+    pub project_root: PathBuf,
+    /// Name of the Ito directory (`.ito` unless `projectPath` overrides it).
+    pub ito_dir_name: String,
+    /// Resolved Ito directory.
+    pub ito_path: PathBuf,
+    /// Every location, config files in precedence order (low to high).
+    pub locations: Vec<ItoLocation>,
+}
+
+/// Collect every location Ito may touch for `project`.
+pub fn collect_locations(project: &Project) -> ItoLocations {
+    let ctx = project.ctx();
+    let ito_path = project.ito_path();
+    let config_root = ito_path.parent().unwrap_or(ito_path);
+    let loaded_from = &project.config().loaded_from;
+
+    let mut locations = vec![
+        ItoLocation::at(LocationKind::ProjectRoot, project.root().to_path_buf()),
+        ItoLocation::at(LocationKind::ItoDir, ito_path.to_path_buf()),
+    ];
+    for path in project_config_paths(config_root, ito_path, ctx) {
+        let loaded = loaded_from.contains(&path);
+        locations.push(ItoLocation {
+            loaded: Some(loaded),
+            ..ItoLocation::at(LocationKind::Config, path)
+        });
+    }
+    locations.push(ItoLocation::maybe_at(
+        LocationKind::GlobalConfigDir,
+        ito_config_dir(ctx),
+    ));
+    locations.push(ItoLocation::maybe_at(
+        LocationKind::DataDir,
+        ito_data_dir(ctx),
+    ));
+    locations.push(ItoLocation::at(
+        LocationKind::RalphState,
+        ralph_state_root(ito_path),
+    ));
+    locations.push(audit_log_location(ito_path));
+    locations.push(ItoLocation::at(
+        LocationKind::AuditFallbackLog,
+        fallback_audit_log_path(ito_path),
+    ));
+    locations.push(ItoLocation::at(
+        LocationKind::Transcripts,
+        transcripts_root(ito_path),
+    ));
+
+    ItoLocations {
+        project_root: project.root().to_path_buf(),
+        ito_dir_name: ito_path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        ito_path: ito_path.to_path_buf(),
+        locations,
+    }
+}
+
+fn audit_log_location(ito_path: &Path) -> ItoLocation {
+    match default_audit_store(ito_path).location() {
+        AuditStorageLocation::Filesystem(path) => ItoLocation::at(LocationKind::AuditLog, path),
+        AuditStorageLocation::Other(label) => {
+            ItoLocation::elsewhere(LocationKind::AuditLog, Some(label))
+        }
+    }
+}
@@ -0,0 +1,291 @@
+//! Marker-delimited managed blocks in user-owned files.
+//!
+//! Ito owns only the text between a start and an end marker (for example
+//! `<!-- ITO:START -->` / `<!-- ITO:END -->` in `AGENTS.md`); everything
+//! outside the block belongs to the user and is kept byte-for-byte.
+//! [`ManagedBlockEdit`] applies a new block to a file, and
+//! [`ManagedBlockEdit::preview`] computes the result and a unified diff
+//! without touching disk.
+
+use std::path::Path;
+
+use ito_common::fs::{FileSystem, StdFs};
+
+use crate::installers::line_endings::{LineEnding, convert_line_endings, detect_line_ending};
+
+/// Marker layout that makes a managed block impossible to update safely.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum MarkerError {
+    /// The end marker comes before the start marker.
+    #[error("Invalid marker state in {file_path}. End marker appears before start marker.")]
+    EndBeforeStart {
+        /// File the markers were read from.
+        file_path: String,
+    },
+
+    /// Only one of the two markers is present.
+    #[error(
+        "Invalid marker state in {file_path}. Found start: {found_start}, Found end: {found_end}"
+    )]
+    MissingMarker {
+        /// File the markers were read from.
+        file_path: String,
+        /// Whether the start marker was found.
+        found_start: bool,
+        /// Whether the end marker was found.
+        found_end: bool,
+    },
+}
+
+/// Failure while applying a [`ManagedBlockEdit`] to a file.
+#[derive(Debug, thiserror::Error)]
+pub enum ManagedBlockError {
+    /// The existing markers cannot be updated safely.
+    #[error(transparent)]
+    Marker(#[from] MarkerError),
+
+    /// Reading or writing the file failed.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// What [`ManagedBlockEdit::apply`] did to the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManagedBlockOutcome {
+    /// The file did not exist and was created with just the managed block.
+    Created,
+    /// The existing block was replaced; `old` and `new` are the full file contents.
+    Updated {
+        /// File contents before the edit.
+        old: String,
+        /// File contents after the edit.
+        new: String,
+    },
+    /// The block already had the requested content; nothing was written.
+    Unchanged,
+    /// The file had neither marker, so the block was inserted at the top and
+    /// the rest of the file was kept as-is.
+    MarkersMissing,
+}
+
+/// Replaces the managed block delimited by a start and an end marker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ManagedBlockEdit<'a> {
+    start: &'a str,
+    end: &'a str,
+}
+
+impl<'a> ManagedBlockEdit<'a> {
+    /// Edit the block between `start` and `end`. Markers only count when they
+    /// sit on their own line (surrounding whitespace is allowed).
+    pub fn new(start: &'a str, end: &'a str) -> Self {
+        Self { start, end }
+    }
+
+    /// Write `new_content` as the managed block of `path`.
+    ///
+    /// Missing files are created with just the managed block.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ManagedBlockError::Marker`] when only one marker is present
+    /// or the markers are out of order, and [`ManagedBlockError::Io`] when
+    /// the file cannot be written.
+    pub fn apply(
+        &self,
+        path: &Path,
+        new_content: &str,
+    ) -> Result<ManagedBlockOutcome, ManagedBlockError> {
+        self.apply_fs(&StdFs, path, new_content)
+    }
+
+    /// Like [`ManagedBlockEdit::apply`], reading and writing through `fs`.
+    ///
+    /// # Errors
+    ///
+    /// See [`ManagedBlockEdit::apply`].
+    pub fn apply_fs(
+        &self,
+        fs: &dyn FileSystem,
+        path: &Path,
+        new_content: &str,
+    ) -> Result<ManagedBlockOutcome, ManagedBlockError> {
+        let existing = fs.read_to_string(path).ok();
+        let updated = update_content_with_markers(
+            path,
+            existing.as_deref(),
+            new_content,
+            self.start,
+            self.end,
+        )?;
+
+        let outcome = match existing {
+            None => ManagedBlockOutcome::Created,
+            Some(old) if old == updated => return Ok(ManagedBlockOutcome::Unchanged),
+            Some(old) if find_marker_index(&old, self.start, 0).is_none() => {
+                ManagedBlockOutcome::MarkersMissing
+            }
+            Some(old) => ManagedBlockOutcome::Updated {
+                old,
+                new: updated.clone(),
+            },
+        };
+
+        if let Some(parent) = path.parent() {
+            fs.create_dir_all(parent)?;
+        }
+        fs.write(path, updated.as_bytes())?;
+        Ok(outcome)
+    }
+
+    /// Compute what [`ManagedBlockEdit::apply`] would write to `path` given
+    /// its current contents (`None` when the file does not exist), plus a
+    /// unified diff from the current contents. The diff is empty when nothing
+    /// would change.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same [`MarkerError`]s as [`ManagedBlockEdit::apply`].
+    pub fn preview(
+        &self,
+        path: &Path,
+        existing: Option<&str>,
+        new_content: &str,
+    ) -> Result<(String, String), MarkerError> {
+        let updated =
+            update_content_with_markers(path, existing, new_content, self.start, self.end)?;
+        let before = existing.unwrap_or_default();
+        if before == updated {
+            return Ok((updated, String::new()));
+        }
+        let patch = diffy::create_patch(before, &updated).to_string();
+        // Replace diffy's `original`/`modified` labels with the file path.
+        let hunks: String = patch
+            .lines()
+            .skip_while(|l| l.starts_with("--- ") || l.starts_with("+++ "))
+            .map(|l| format!("{l}\n"))
+            .collect();
+        let label = path.display();
+        let from = if existing.is_some() {
+            format!("a/{label}")
+        } else {
+            "/dev/null".to_string()
+        };
+        let diff = format!("--- {from}\n+++ b/{label}\n{hunks}");
+        Ok((updated, diff))
+    }
+}
+
+fn is_marker_on_own_line(content: &str, marker_index: usize, marker_len: usize) -> bool {
+    let bytes = content.as_bytes();
+
+    let mut i = marker_index;
+    while i > 0 {
+        let c = bytes[i - 1];
+        if c == b'\n' {
+            break;
+        }
+        if c != b' ' && c != b'\t' && c != b'\r' {
+            return false;
+        }
+        i -= 1;
+    }
+
+    let mut j = marker_index + marker_len;
+    while j < bytes.len() {
+        let c = bytes[j];
+        if c == b'\n' {
+            break;
+        }
+        if c != b' ' && c != b'\t' && c != b'\r' {
+            return false;
+        }
+        j += 1;
+    }
+
+    true
+}
+
+fn find_marker_index(content: &str, marker: &str, from_index: usize) -> Option<usize> {
+    let mut search_from = from_index;
+    while let Some(rel) = content[search_from..].find(marker) {
+        let idx = search_from + rel;
+        if is_marker_on_own_line(content, idx, marker.len()) {
+            return Some(idx);
+        }
+        search_from = idx + marker.len();
+    }
+    None
+}
+
+/// Replace (or insert) the managed block between `start_marker` and `end_marker`.
+///
+/// The block and the line breaks around the markers follow the dominant line
+/// ending of `existing`; everything outside the block is kept byte-for-byte.
+pub fn update_content_with_markers(
+    file_path: &Path,
+    existing: Option<&str>,
+    new_block_content: &str,
+    start_marker: &str,
+    end_marker: &str,
+) -> Result<String, MarkerError> {
+    let Some(existing) = existing else {
+        return Ok(format!(
+            "{start}\n{body}\n{end}",
+            start = start_marker,
+            body = new_block_content,
+            end = end_marker
+        ));
+    };
+
+    let ending = detect_line_ending(existing).unwrap_or(LineEnding::Lf);
+    let eol = ending.as_str();
+    let body = convert_line_endings(new_block_content, ending);
+
+    let start = find_marker_index(existing, start_marker, 0);
+    let end = match start {
+        Some(start_idx) => find_marker_index(existing, end_marker, start_idx + start_marker.len()),
+        None => find_marker_index(existing, end_marker, 0),
+    };
+
+    match (start, end) {
+        (Some(start_idx), Some(end_idx)) => {
+            if end_idx < start_idx {
+                return Err(MarkerError::EndBeforeStart {
+                    file_path: file_path.display().to_string(),
+                });
+            }
+            let before = &existing[..start_idx];
+            let after = &existing[end_idx + end_marker.len()..];
+            Ok(format!(
+                "{before}{start}{eol}{body}{eol}{end}{after}",
+                before = before,
+                start = start_marker,
+                body = body,
+                end = end_marker,
+                after = after
+            ))
+        }
+        (None, None) => Ok(format!(
+            "{start}{eol}{body}{eol}{end}{eol}{eol}{rest}",
+            start = start_marker,
+            body = body,
+            end = end_marker,
+            rest = existing
+        )),
+        (Some(_), None) => Err(MarkerError::MissingMarker {
+            file_path: file_path.display().to_string(),
+            found_start: true,
+            found_end: false,
+        }),
+        (None, Some(_)) => Err(MarkerError::MissingMarker {
+            file_path: file_path.display().to_string(),
+            found_start: false,
+            found_end: true,
+        }),
+    }
+}
+
+#[cfg(test)]
+#[path = "managed_block_tests.rs"]
+mod managed_block_tests;
@@ -0,0 +1,208 @@
+use super::*;
+use std::path::PathBuf;
+
+const START: &str = "<!-- ITO:START -->";
+const END: &str = "<!-- ITO:END -->";
+
+fn p(name: &str) -> PathBuf {
+    PathBuf::from(name)
+}
+
+#[test]
+fn marker_must_be_on_own_line() {
+    let content = format!("prefix {START}\nX\n{END}\n");
+    let err = update_content_with_markers(&p("f"), Some(&content), "NEW", START, END).unwrap_err();
+    assert_eq!(
+        err,
+        MarkerError::MissingMarker {
+            file_path: "f".to_string(),
+            found_start: false,
+            found_end: true
+        }
+    );
+}
+
+#[test]
+fn replaces_existing_block_preserving_unmanaged_content() {
+    let existing = format!("line1\n{START}\nold\n{END}\nline2\n");
+    let out = update_content_with_markers(&p("f"), Some(&existing), "new", START, END).unwrap();
+    assert_eq!(out, format!("line1\n{START}\nnew\n{END}\nline2\n"));
+}
+
+#[test]
+fn inserts_block_when_missing() {
+    let existing = "hello\nworld\n";
+    let out = update_content_with_markers(&p("f"), Some(existing), "x", START, END).unwrap();
+    assert_eq!(out, format!("{START}\nx\n{END}\n\nhello\nworld\n"));
+}
+
+#[test]
+fn errors_when_only_one_marker_found() {
+    let existing = format!("{START}\nno end\n");
+    let err = update_content_with_markers(&p("f"), Some(&existing), "x", START, END).unwrap_err();
+    assert_eq!(
+        err,
+        MarkerError::MissingMarker {
+            file_path: "f".to_string(),
+            found_start: true,
+            found_end: false
+        }
+    );
+}
+
+#[test]
+fn apply_reports_every_outcome() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("nested").join("AGENTS.md");
+    let edit = ManagedBlockEdit::new(START, END);
+
+    assert_eq!(
+        edit.apply(&file, "hello").unwrap(),
+        ManagedBlockOutcome::Created
+    );
+    assert_eq!(
+        std::fs::read_to_string(&file).unwrap(),
+        format!("{START}\nhello\n{END}")
+    );
+
+    assert_eq!(
+        edit.apply(&file, "hello").unwrap(),
+        ManagedBlockOutcome::Unchanged
+    );
+
+    let outcome = edit.apply(&file, "bye").unwrap();
+    assert_eq!(
+        outcome,
+        ManagedBlockOutcome::Updated {
+            old: format!("{START}\nhello\n{END}"),
+            new: format!("{START}\nbye\n{END}"),
+        }
+    );
+
+    let plain = dir.path().join("README.md");
+    std::fs::write(&plain, "# Readme\n").unwrap();
+    assert_eq!(
+        edit.apply(&plain, "x").unwrap(),
+        ManagedBlockOutcome::MarkersMissing
+    );
+    assert_eq!(
+        std::fs::read_to_string(&plain).unwrap(),
+        format!("{START}\nx\n{END}\n\n# Readme\n")
+    );
+}
+
+#[test]
+fn apply_refuses_a_single_marker_and_leaves_the_file_alone() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("AGENTS.md");
+    let original = format!("mine\n{END}\n");
+    std::fs::write(&file, &original).unwrap();
+
+    let err = ManagedBlockEdit::new(START, END)
+        .apply(&file, "x")
+        .unwrap_err();
+
+    let ManagedBlockError::Marker(marker) = err else {
+        panic!("expected a marker error, got {err:?}");
+    };
+    assert_eq!(
+        marker,
+        MarkerError::MissingMarker {
+            file_path: file.display().to_string(),
+            found_start: false,
+            found_end: true,
+        }
+    );
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), original);
+}
+
+#[test]
+fn apply_keeps_crlf_and_indented_markers() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("AGENTS.md");
+    std::fs::write(&file, format!("top\r\n  {START}\r\nold\r\n\t{END}\r\n")).unwrap();
+
+    let outcome = ManagedBlockEdit::new(START, END)
+        .apply(&file, "new\nlines")
+        .unwrap();
+
+    let ManagedBlockOutcome::Updated { new, .. } = outcome else {
+        panic!("expected an update, got {outcome:?}");
+    };
+    // Indentation before the start marker is kept; the end marker is rewritten
+    // at the start of its line, as the installers always have.
+    assert_eq!(
+        new,
+        format!("top\r\n  {START}\r\nnew\r\nlines\r\n{END}\r\n")
+    );
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), new);
+}
+
+#[test]
+fn preview_returns_content_and_unified_diff_without_writing() {
+    let edit = ManagedBlockEdit::new(START, END);
+    let existing = format!("intro\n{START}\nold\n{END}\noutro\n");
+
+    let (content, diff) = edit
+        .preview(&p("AGENTS.md"), Some(&existing), "new")
+        .unwrap();
+
+    assert_eq!(content, format!("intro\n{START}\nnew\n{END}\noutro\n"));
+    assert_eq!(
+        diff,
+        format!(
+            "--- a/AGENTS.md\n+++ b/AGENTS.md\n@@ -1,5 +1,5 @@\n intro\n {START}\n-old\n+new\n {END}\n outro\n"
+        )
+    );
+
+    let (_, diff) = edit
+        .preview(&p("AGENTS.md"), Some(&content), "new")
+        .unwrap();
+    assert!(diff.is_empty());
+
+    let (_, diff) = edit.preview(&p("NEW.md"), None, "x").unwrap();
+    assert!(diff.starts_with("--- /dev/null\n+++ b/NEW.md\n"), "{diff}");
+
+    let err = edit
+        .preview(&p("AGENTS.md"), Some(&format!("{START}\n")), "x")
+        .unwrap_err();
+    assert!(matches!(err, MarkerError::MissingMarker { .. }));
+}
+
+#[test]
+fn idempotent_when_applying_same_content_twice() {
+    let existing = format!("{START}\nhello\n{END}\n");
+    let once = update_content_with_markers(&p("f"), Some(&existing), "hello", START, END).unwrap();
+    let twice = update_content_with_markers(&p("f"), Some(&once), "hello", START, END).unwrap();
+    assert_eq!(once, twice);
+}
+
+#[test]
+fn crlf_files_keep_crlf_when_block_is_replaced() {
+    let existing = format!("line1\r\n{START}\r\nold\r\n{END}\r\nline2\r\n");
+    let out =
+        update_content_with_markers(&p("f"), Some(&existing), "new\nmore", START, END).unwrap();
+    assert_eq!(
+        out,
+        format!("line1\r\n{START}\r\nnew\r\nmore\r\n{END}\r\nline2\r\n")
+    );
+}
+
+#[test]
+fn crlf_files_keep_crlf_when_block_is_inserted() {
+    let out =
+        update_content_with_markers(&p("f"), Some("hello\r\nworld\r\n"), "x", START, END).unwrap();
+    assert_eq!(
+        out,
+        format!("{START}\r\nx\r\n{END}\r\n\r\nhello\r\nworld\r\n")
+    );
+}
+
+#[test]
+fn mixed_ending_files_keep_bytes_outside_the_block() {
+    let before = "lf line\ncrlf line\r\n";
+    let after = "\r\ncrlf tail\r\nlf tail\nmore\r\n";
+    let existing = format!("{before}{START}\r\nold\r\n{END}{after}");
+    let out = update_content_with_markers(&p("f"), Some(&existing), "new", START, END).unwrap();
+    assert_eq!(out, format!("{before}{START}\r\nnew\r\n{END}{after}"));
+}
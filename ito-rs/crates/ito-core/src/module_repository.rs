@@ -296,6 +296,11 @@ impl<'a, F: FileSystem> FsModuleRepository<'a, F> {
     pub fn list(&self) -> DomainResult<Vec<ModuleSummary>> {
         DomainModuleRepository::list(self)
     }
+
+    /// Id the next created module would receive.
+    pub fn next_module_id(&self) -> DomainResult<String> {
+        DomainModuleRepository::next_module_id(self)
+    }
 }
 
 impl<F: FileSystem> DomainModuleRepository for FsModuleRepository<'_, F> {
@@ -232,3 +232,16 @@ fn regression_change_repository_populates_sub_module_id() {
         "sub-module change should have sub_module_id set"
     );
 }
+
+#[test]
+fn test_next_module_id_skips_gaps() {
+    let tmp = TempDir::new().unwrap();
+    let ito_path = setup_test_ito(&tmp);
+
+    let repo = ModuleRepository::new(&ito_path);
+    assert_eq!(repo.next_module_id().unwrap(), "001");
+
+    create_module(&ito_path, "000", "ungrouped");
+    create_module(&ito_path, "002", "billing");
+    assert_eq!(repo.next_module_id().unwrap(), "003");
+}
@@ -0,0 +1,158 @@
+//! Ito-aware annotations for directory listings.
+//!
+//! File browsers list `.ito/changes/...` and friends as plain folders. This
+//! module tells them what each entry is: a change (with work status, schema,
+//! and task progress), a promoted spec, or a module. Annotation is best
+//! effort: a change that cannot be summarized is still reported as a change,
+//! carrying the error instead of its details.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use ito_common::paths;
+use serde::Serialize;
+
+use crate::change_repository::FsChangeRepository;
+use crate::list::spec_list_item;
+use crate::module_repository::FsModuleRepository;
+use crate::templates::read_change_schema;
+
+/// Task counts for an annotated change directory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AnnotatedTaskProgress {
+    /// Total number of tasks.
+    pub total: u32,
+    /// Completed tasks.
+    pub complete: u32,
+    /// In-progress tasks.
+    pub in_progress: u32,
+    /// Pending tasks.
+    pub pending: u32,
+    /// Shelved tasks.
+    pub shelved: u32,
+}
+
+/// What an entry under the Ito directory represents.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PathAnnotation {
+    /// An active change directory under `changes/`.
+    Change {
+        /// Work status (`draft`, `ready`, `in-progress`, `paused`, `complete`).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        work_status: Option<String>,
+        /// Schema configured for the change.
+        schema: String,
+        /// Task counts from `tasks.md`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        task_progress: Option<AnnotatedTaskProgress>,
+        /// Why the change could not be summarized.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// A promoted spec directory under `specs/`.
+    Spec {
+        /// Count of requirements in `spec.md`.
+        requirement_count: u32,
+    },
+    /// A module directory under `modules/`.
+    Module {
+        /// Number of changes associated with the module.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        change_count: Option<u32>,
+        /// Why modules could not be listed.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+}
+
+/// Annotate the directory entries `names` found in `dir`.
+///
+/// Only the Ito `changes/`, `specs/`, and `modules/` directories produce
+/// annotations; any other `dir` (including paths outside `ito_path`) yields an
+/// empty map. Entries with nothing to report are omitted. Failures are
+/// recorded on the affected entry rather than returned.
+pub fn annotate_dir_entries(
+    ito_path: &Path,
+    dir: &Path,
+    names: &[String],
+) -> BTreeMap<String, PathAnnotation> {
+    let mut out = BTreeMap::new();
+    if dir == paths::changes_dir(ito_path) {
+        let repo = FsChangeRepository::new(ito_path);
+        for name in names {
+            if name == "archive" || !dir.join(name).is_dir() {
+                continue;
+            }
+            out.insert(name.clone(), annotate_change(&repo, ito_path, name));
+        }
+    } else if dir == paths::specs_dir(ito_path) {
+        for name in names {
+            if !dir.join(name).is_dir() {
+                continue;
+            }
+            let item = spec_list_item(dir, name.clone());
+            out.insert(
+                name.clone(),
+                PathAnnotation::Spec {
+                    requirement_count: item.requirement_count,
+                },
+            );
+        }
+    } else if dir == paths::modules_dir(ito_path) {
+        let counts = FsModuleRepository::new(ito_path).list().map(|modules| {
+            modules
+                .into_iter()
+                .map(|m| (format!("{}_{}", m.id, m.name), m.change_count))
+                .collect::<BTreeMap<_, _>>()
+        });
+        for name in names {
+            if !dir.join(name).is_dir() {
+                continue;
+            }
+            let annotation = match &counts {
+                Ok(counts) => match counts.get(name) {
+                    Some(count) => PathAnnotation::Module {
+                        change_count: Some(*count),
+                        error: None,
+                    },
+                    None => continue,
+                },
+                Err(e) => PathAnnotation::Module {
+                    change_count: None,
+                    error: Some(e.to_string()),
+                },
+            };
+            out.insert(name.clone(), annotation);
+        }
+    }
+    out
+}
+
+fn annotate_change(repo: &FsChangeRepository<'_>, ito_path: &Path, name: &str) -> PathAnnotation {
+    let schema = read_change_schema(ito_path, name);
+    match repo.summarize_active_dir(name) {
+        Ok(summary) => PathAnnotation::Change {
+            work_status: Some(summary.work_status().to_string()),
+            schema,
+            task_progress: Some(AnnotatedTaskProgress {
+                total: summary.total_tasks,
+                complete: summary.completed_tasks,
+                in_progress: summary.in_progress_tasks,
+                pending: summary.pending_tasks,
+                shelved: summary.shelved_tasks,
+            }),
+            error: None,
+        },
+        Err(e) => PathAnnotation::Change {
+            work_status: None,
+            schema,
+            task_progress: None,
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+#[cfg(test)]
+#[path = "path_annotations_tests.rs"]
+mod path_annotations_tests;
@@ -0,0 +1,111 @@
+use super::*;
+
+fn write(path: impl AsRef<Path>, contents: &[u8]) {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).expect("parent dirs should exist");
+    }
+    std::fs::write(path, contents).expect("test fixture should write");
+}
+
+fn names(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn annotates_changes_with_status_schema_and_progress() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let ito = tmp.path().join(".ito");
+    let change = ito.join("changes/001-01_alpha");
+    write(change.join("proposal.md"), b"## Why\nfixture\n");
+    write(change.join("tasks.md"), b"- [x] one\n- [ ] two\n");
+    write(
+        change.join("specs/alpha/spec.md"),
+        b"## ADDED Requirements\n\n### Requirement: A\nText.\n",
+    );
+    std::fs::create_dir_all(ito.join("changes/archive")).expect("archive dir");
+
+    let out = annotate_dir_entries(
+        &ito,
+        &ito.join("changes"),
+        &names(&["001-01_alpha", "archive"]),
+    );
+
+    assert_eq!(out.len(), 1);
+    assert_eq!(
+        out["001-01_alpha"],
+        PathAnnotation::Change {
+            work_status: Some("ready".to_string()),
+            schema: "spec-driven".to_string(),
+            task_progress: Some(AnnotatedTaskProgress {
+                total: 2,
+                complete: 1,
+                in_progress: 0,
+                pending: 1,
+                shelved: 0,
+            }),
+            error: None,
+        }
+    );
+}
+
+#[test]
+fn malformed_change_is_reported_with_an_error() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let ito = tmp.path().join(".ito");
+    write(
+        ito.join("changes/001-01_broken/proposal.md"),
+        b"\xff\xfe\x00",
+    );
+
+    let out = annotate_dir_entries(&ito, &ito.join("changes"), &names(&["001-01_broken"]));
+
+    let PathAnnotation::Change {
+        work_status, error, ..
+    } = &out["001-01_broken"]
+    else {
+        panic!("expected change annotation");
+    };
+    assert!(work_status.is_none());
+    assert!(error.is_some());
+}
+
+#[test]
+fn annotates_specs_and_modules() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let ito = tmp.path().join(".ito");
+    write(
+        ito.join("specs/auth/spec.md"),
+        b"## Purpose\nAuth.\n\n## Requirements\n\n### Requirement: One\nA.\n\n### Requirement: Two\nB.\n",
+    );
+    write(ito.join("modules/001_core/module.md"), b"# Core\n");
+    std::fs::create_dir_all(ito.join("changes/001-01_alpha")).expect("change dir");
+
+    let specs = annotate_dir_entries(&ito, &ito.join("specs"), &names(&["auth"]));
+    assert_eq!(
+        specs["auth"],
+        PathAnnotation::Spec {
+            requirement_count: 2
+        }
+    );
+
+    let modules = annotate_dir_entries(&ito, &ito.join("modules"), &names(&["001_core"]));
+    assert_eq!(
+        modules["001_core"],
+        PathAnnotation::Module {
+            change_count: Some(1),
+            error: None,
+        }
+    );
+}
+
+#[test]
+fn other_directories_are_not_annotated() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let ito = tmp.path().join(".ito");
+    std::fs::create_dir_all(tmp.path().join("changes/001-01_alpha")).expect("outside dir");
+
+    let out = annotate_dir_entries(&ito, &tmp.path().join("changes"), &names(&["001-01_alpha"]));
+
+    assert!(out.is_empty());
+}
@@ -1,15 +1,18 @@
 //! Process execution boundary for core-side command invocation.
 
+use std::collections::VecDeque;
 use std::fs;
-use std::io;
+use std::io::{self, Read, Seek, SeekFrom};
 use std::path::{Component, Path, PathBuf};
-use std::process::{Command, Stdio};
+use std::process::{Child, Command, ExitStatus, Stdio};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::thread;
 use std::time::{Duration, Instant};
 
 #[cfg(unix)]
 use std::os::unix::ffi::OsStrExt;
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
 
 #[cfg(windows)]
 use std::os::windows::ffi::OsStrExt;
@@ -26,6 +29,14 @@ pub struct ProcessRequest {
     pub args: Vec<String>,
     /// Optional working directory.
     pub current_dir: Option<PathBuf>,
+    /// Optional cap on captured bytes per stream (stdout and stderr).
+    ///
+    /// When a stream exceeds the cap, its head is dropped and replaced with a
+    /// `[... N bytes truncated]` marker; the tail is kept because failures are
+    /// usually reported at the end of the output.
+    pub max_output_bytes: Option<usize>,
+    /// Extra environment variables set for the child process.
+    pub env: Vec<(String, String)>,
 }
 
 impl ProcessRequest {
@@ -38,6 +49,8 @@ impl ProcessRequest {
             program: program.into(),
             args: Vec::new(),
             current_dir: None,
+            max_output_bytes: None,
+            env: Vec::new(),
         }
     }
 
@@ -73,6 +86,22 @@ impl ProcessRequest {
         self.current_dir = Some(dir.into());
         self
     }
+
+    /// Cap the number of bytes captured from each output stream.
+    ///
+    /// This is a builder method that returns `self` for chaining.
+    pub fn max_output_bytes(mut self, max: usize) -> Self {
+        self.max_output_bytes = Some(max);
+        self
+    }
+
+    /// Set an environment variable for the child process.
+    ///
+    /// This is a builder method that returns `self` for chaining.
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
 }
 
 /// Structured process execution output.
@@ -88,6 +117,42 @@ pub struct ProcessOutput {
     pub stderr: String,
     /// True if execution was forcibly terminated due to timeout.
     pub timed_out: bool,
+    /// Resources the process consumed.
+    pub resources: ResourceUsage,
+}
+
+/// Resources consumed by a finished child process and its reaped descendants.
+///
+/// Wall time is always measured. CPU time and peak memory come from `wait4`
+/// and are only available on Unix; runners that never spawn a process (such
+/// as test doubles) leave them `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResourceUsage {
+    /// Time from spawn until the process was reaped.
+    pub wall_time: Duration,
+    /// CPU time spent in user mode.
+    pub user_cpu: Option<Duration>,
+    /// CPU time spent in the kernel on the process's behalf.
+    pub system_cpu: Option<Duration>,
+    /// Peak resident set size, in bytes.
+    pub max_rss_bytes: Option<u64>,
+}
+
+impl ResourceUsage {
+    /// One-line summary such as `wall 2.1s, user 1.4s, sys 0.2s, max rss 48.3 MiB`.
+    pub fn summary(&self) -> String {
+        let mut parts = vec![format!("wall {:.1}s", self.wall_time.as_secs_f64())];
+        if let Some(user) = self.user_cpu {
+            parts.push(format!("user {:.1}s", user.as_secs_f64()));
+        }
+        if let Some(system) = self.system_cpu {
+            parts.push(format!("sys {:.1}s", system.as_secs_f64()));
+        }
+        if let Some(rss) = self.max_rss_bytes {
+            parts.push(format!("max rss {:.1} MiB", rss as f64 / (1024.0 * 1024.0)));
+        }
+        parts.join(", ")
+    }
 }
 
 /// Process execution failure modes.
@@ -161,24 +226,42 @@ impl ProcessRunner for SystemProcessRunner {
     fn run(&self, request: &ProcessRequest) -> Result<ProcessOutput, ProcessExecutionError> {
         validate_request(request)?;
         let mut command = build_command(request);
-        let output = command
-            .output()
+        let started = Instant::now();
+        let mut child = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
             .map_err(|source| ProcessExecutionError::Spawn {
                 program: request.program.clone(),
                 source,
             })?;
+        let max = request.max_output_bytes;
+        let stdout = drain_pipe(child.stdout.take(), max);
+        let stderr = drain_pipe(child.stderr.take(), max);
+        let (status, resources) = wait_with_resources(&mut child, started).map_err(|source| {
+            ProcessExecutionError::Wait {
+                program: request.program.clone(),
+                source,
+            }
+        })?;
+        let (stdout, stdout_total) = stdout.join().unwrap_or_default();
+        let (stderr, stderr_total) = stderr.join().unwrap_or_default();
         Ok(ProcessOutput {
-            exit_code: output.status.code().unwrap_or(-1),
-            success: output.status.success(),
-            stdout: String::from_utf8_lossy(&output.stdout).to_string(),
-            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+            exit_code: status.code().unwrap_or(-1),
+            success: status.success(),
+            stdout: keep_output_tail(&stdout, stdout_total, max),
+            stderr: keep_output_tail(&stderr, stderr_total, max),
             timed_out: false,
+            resources,
         })
     }
 
     /// Executes the given process request and returns its captured output, enforcing the supplied timeout.
     ///
-    /// On timeout the child process is killed; any output written before termination is returned and `timed_out` is set to `true`.
+    /// On timeout the child's whole process group is killed (on Unix), so grandchildren such as
+    /// test workers do not outlive the command; any output written before termination is returned
+    /// and `timed_out` is set to `true`. Output is capped by [`ProcessRequest::max_output_bytes`].
     ///
     /// # Returns
     ///
@@ -228,6 +311,10 @@ impl ProcessRunner for SystemProcessRunner {
             })?;
 
         let mut command = build_command(request);
+        // Run the child in its own process group so a timeout can take down
+        // everything it spawned, not just the direct child.
+        #[cfg(unix)]
+        command.process_group(0);
         let mut child = command
             .stdin(Stdio::null())
             .stdout(Stdio::from(stdout_file))
@@ -239,44 +326,42 @@ impl ProcessRunner for SystemProcessRunner {
             })?;
 
         let started = Instant::now();
+        let wait_err = |source| ProcessExecutionError::Wait {
+            program: request.program.clone(),
+            source,
+        };
         let mut timed_out = false;
         let mut exit_code = -1;
         let mut success = false;
+        let resources;
 
         loop {
-            if let Some(status) =
-                child
-                    .try_wait()
-                    .map_err(|source| ProcessExecutionError::Wait {
-                        program: request.program.clone(),
-                        source,
-                    })?
+            if let Some((status, usage)) =
+                try_wait_with_resources(&mut child, started).map_err(wait_err)?
             {
                 exit_code = status.code().unwrap_or(-1);
                 success = status.success();
+                resources = usage;
                 break;
             }
 
             if started.elapsed() >= timeout {
                 timed_out = true;
-                let _ = child.kill();
-                let _ = child.wait();
+                kill_process_tree(&mut child);
+                resources = wait_with_resources(&mut child, started)
+                    .map(|(_, usage)| usage)
+                    .unwrap_or_else(|_| ResourceUsage {
+                        wall_time: started.elapsed(),
+                        ..ResourceUsage::default()
+                    });
                 break;
             }
 
             thread::sleep(Duration::from_millis(10));
         }
 
-        let stdout =
-            fs::read_to_string(&stdout_path).map_err(|source| ProcessExecutionError::ReadTemp {
-                path: stdout_path.clone(),
-                source,
-            })?;
-        let stderr =
-            fs::read_to_string(&stderr_path).map_err(|source| ProcessExecutionError::ReadTemp {
-                path: stderr_path.clone(),
-                source,
-            })?;
+        let stdout = read_output_file(&stdout_path, request.max_output_bytes)?;
+        let stderr = read_output_file(&stderr_path, request.max_output_bytes)?;
         let _ = fs::remove_file(&stdout_path);
         let _ = fs::remove_file(&stderr_path);
 
@@ -286,10 +371,203 @@ impl ProcessRunner for SystemProcessRunner {
             stdout,
             stderr,
             timed_out,
+            resources,
         })
     }
 }
 
+/// Read a child's pipe to the end on a background thread.
+///
+/// Only the last `max` bytes are kept, so a chatty child cannot grow memory
+/// without bound; the thread returns that tail and the stream's total size.
+fn drain_pipe(
+    pipe: Option<impl Read + Send + 'static>,
+    max: Option<usize>,
+) -> thread::JoinHandle<(Vec<u8>, usize)> {
+    thread::spawn(move || {
+        let mut tail = VecDeque::new();
+        let mut total = 0;
+        let Some(mut pipe) = pipe else {
+            return (Vec::new(), 0);
+        };
+        let mut buf = [0u8; 8192];
+        loop {
+            match pipe.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => {
+                    total += n;
+                    tail.extend(&buf[..n]);
+                    if let Some(max) = max {
+                        let excess = tail.len().saturating_sub(max);
+                        tail.drain(..excess);
+                    }
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(_) => break,
+            }
+        }
+        (tail.into(), total)
+    })
+}
+
+/// Block until `child` exits, collecting the resources it used.
+///
+/// `started` is when the child was spawned. On Unix the child is reaped with
+/// `wait4`, so `child` must not be waited on again afterwards.
+pub(crate) fn wait_with_resources(
+    child: &mut Child,
+    started: Instant,
+) -> io::Result<(ExitStatus, ResourceUsage)> {
+    #[cfg(unix)]
+    {
+        reap(child, 0, started)?
+            .ok_or_else(|| io::Error::other("wait4 returned without reaping the child"))
+    }
+    #[cfg(not(unix))]
+    {
+        let status = child.wait()?;
+        Ok((status, wall_time_only(started)))
+    }
+}
+
+/// Non-blocking variant of [`wait_with_resources`]; `None` while the child runs.
+fn try_wait_with_resources(
+    child: &mut Child,
+    started: Instant,
+) -> io::Result<Option<(ExitStatus, ResourceUsage)>> {
+    #[cfg(unix)]
+    {
+        reap(child, libc::WNOHANG, started)
+    }
+    #[cfg(not(unix))]
+    {
+        Ok(child
+            .try_wait()?
+            .map(|status| (status, wall_time_only(started))))
+    }
+}
+
+#[cfg(not(unix))]
+fn wall_time_only(started: Instant) -> ResourceUsage {
+    ResourceUsage {
+        wall_time: started.elapsed(),
+        ..ResourceUsage::default()
+    }
+}
+
+#[cfg(unix)]
+fn reap(
+    child: &Child,
+    flags: libc::c_int,
+    started: Instant,
+) -> io::Result<Option<(ExitStatus, ResourceUsage)>> {
+    use std::os::unix::process::ExitStatusExt;
+
+    let pid = child.id() as libc::pid_t;
+    let mut status: libc::c_int = 0;
+    // SAFETY: `rusage` is plain old data, so an all-zero value is valid.
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    loop {
+        // SAFETY: `status` and `usage` are valid for writes for the duration
+        // of the call, and `pid` is our own unreaped child.
+        let reaped = unsafe { libc::wait4(pid, &mut status, flags, &mut usage) };
+        if reaped == 0 {
+            return Ok(None);
+        }
+        if reaped > 0 {
+            break;
+        }
+        let err = io::Error::last_os_error();
+        if err.kind() != io::ErrorKind::Interrupted {
+            return Err(err);
+        }
+    }
+    let cpu = |tv: libc::timeval| {
+        Duration::from_secs(tv.tv_sec as u64) + Duration::from_micros(tv.tv_usec as u64)
+    };
+    Ok(Some((
+        ExitStatus::from_raw(status),
+        ResourceUsage {
+            wall_time: started.elapsed(),
+            user_cpu: Some(cpu(usage.ru_utime)),
+            system_cpu: Some(cpu(usage.ru_stime)),
+            max_rss_bytes: Some(max_rss_bytes(usage.ru_maxrss)),
+        },
+    )))
+}
+
+/// `ru_maxrss` is in bytes on macOS and kilobytes elsewhere.
+#[cfg(unix)]
+fn max_rss_bytes(raw: libc::c_long) -> u64 {
+    let raw = raw.max(0) as u64;
+    if cfg!(target_os = "macos") {
+        raw
+    } else {
+        raw * 1024
+    }
+}
+
+/// Kill the child and, on Unix, every process in its process group.
+fn kill_process_tree(child: &mut std::process::Child) {
+    #[cfg(unix)]
+    {
+        // The child was spawned with `process_group(0)`, so its pid is the
+        // group id. A group that has already exited (`ESRCH`) is fine.
+        if let Ok(group) = libc::pid_t::try_from(child.id()) {
+            // SAFETY: `killpg` takes plain integers and only sends a signal.
+            if unsafe { libc::killpg(group, libc::SIGKILL) } != 0 {
+                let err = io::Error::last_os_error();
+                if err.raw_os_error() != Some(libc::ESRCH) {
+                    tracing::warn!("failed to kill process group {group}: {err}");
+                }
+            }
+        }
+    }
+    let _ = child.kill();
+}
+
+/// Read a captured output file, keeping at most `max` bytes from its tail.
+fn read_output_file(path: &Path, max: Option<usize>) -> Result<String, ProcessExecutionError> {
+    let read_err = |source: io::Error| ProcessExecutionError::ReadTemp {
+        path: path.to_path_buf(),
+        source,
+    };
+    let mut file = fs::File::open(path).map_err(read_err)?;
+    let total = file.metadata().map_err(read_err)?.len() as usize;
+    let start = match max {
+        Some(max) if total > max => total - max,
+        _ => 0,
+    };
+    file.seek(SeekFrom::Start(start as u64)).map_err(read_err)?;
+    let mut bytes = Vec::with_capacity(total - start);
+    file.read_to_end(&mut bytes).map_err(read_err)?;
+    Ok(keep_output_tail(&bytes, total, max))
+}
+
+/// Decode captured output, keeping the last `max` bytes of a stream whose full
+/// size was `total` bytes.
+///
+/// `bytes` may already be a tail of the stream (as read from a temp file); the
+/// marker reports how many bytes of the full stream were dropped.
+pub(crate) fn keep_output_tail(bytes: &[u8], total: usize, max: Option<usize>) -> String {
+    let Some(max) = max else {
+        return String::from_utf8_lossy(bytes).to_string();
+    };
+    if total <= max {
+        return String::from_utf8_lossy(bytes).to_string();
+    }
+    let mut start = bytes.len().saturating_sub(max);
+    // Skip UTF-8 continuation bytes so the kept tail starts on a character.
+    while start < bytes.len() && (bytes[start] & 0b1100_0000) == 0b1000_0000 {
+        start += 1;
+    }
+    let dropped = total - (bytes.len() - start);
+    format!(
+        "[... {dropped} bytes truncated]\n{}",
+        String::from_utf8_lossy(&bytes[start..])
+    )
+}
+
 fn build_command(request: &ProcessRequest) -> Command {
     let mut command = Command::new(&request.program);
     if is_git_program(&request.program) {
@@ -297,6 +575,7 @@ fn build_command(request: &ProcessRequest) -> Command {
         command.env_remove("GIT_WORK_TREE");
     }
     command.args(&request.args);
+    command.envs(request.env.iter().map(|(key, value)| (key, value)));
     if let Some(dir) = &request.current_dir {
         command.current_dir(dir);
     }
@@ -319,6 +598,18 @@ fn validate_request(request: &ProcessRequest) -> Result<(), ProcessExecutionErro
     validate_program(&request.program)?;
     validate_args(&request.program, &request.args)?;
     validate_current_dir(&request.current_dir)?;
+    validate_env(&request.env)?;
+    Ok(())
+}
+
+fn validate_env(env: &[(String, String)]) -> Result<(), ProcessExecutionError> {
+    for (key, value) in env {
+        if key.is_empty() || key.contains('=') || key.contains('\0') || value.contains('\0') {
+            return Err(ProcessExecutionError::InvalidRequest {
+                detail: format!("invalid environment variable '{key}'"),
+            });
+        }
+    }
     Ok(())
 }
 
@@ -95,6 +95,29 @@ fn rejects_current_dir_with_parent_component() {
     }
 }
 
+#[test]
+fn passes_environment_variables_to_child() {
+    let runner = SystemProcessRunner;
+    let request = ProcessRequest::new("sh")
+        .args(["-c", "printf %s \"$ITO_PROCESS_TEST\""])
+        .env("ITO_PROCESS_TEST", "from-request");
+    let output = runner.run(&request).unwrap();
+    assert!(output.success);
+    assert_eq!(output.stdout, "from-request");
+}
+
+#[test]
+fn rejects_invalid_environment_variable_name() {
+    let request = ProcessRequest::new("sh").env("A=B", "value");
+    let result = validate_request(&request);
+    match result {
+        Err(ProcessExecutionError::InvalidRequest { detail }) => {
+            assert!(detail.contains("invalid environment variable"));
+        }
+        other => panic!("expected invalid request, got {other:?}"),
+    }
+}
+
 #[test]
 fn rejects_nul_in_argument() {
     let request = ProcessRequest::new("sh").arg("a\0b");
@@ -132,3 +155,155 @@ fn run_returns_invalid_request_before_spawn() {
         other => panic!("expected invalid request, got {other:?}"),
     }
 }
+
+#[test]
+fn max_output_bytes_keeps_tail_of_large_output() {
+    let runner = SystemProcessRunner;
+    let request = ProcessRequest::new("sh")
+        .args([
+            "-c",
+            "i=0; while [ $i -lt 5000 ]; do echo line-$i; i=$((i+1)); done; echo LAST",
+        ])
+        .max_output_bytes(256);
+    let total: usize = (0..5000)
+        .map(|i| format!("line-{i}\n").len())
+        .sum::<usize>()
+        + "LAST\n".len();
+
+    for output in [
+        runner.run(&request).unwrap(),
+        runner
+            .run_with_timeout(&request, Duration::from_secs(30))
+            .unwrap(),
+    ] {
+        assert!(output.success);
+        assert!(output.stdout.starts_with("[... "), "{}", output.stdout);
+        assert!(output.stdout.contains(" bytes truncated]\n"));
+        assert!(output.stdout.ends_with("LAST\n"));
+        assert!(!output.stdout.contains("line-0\n"));
+        let (marker, kept) = output.stdout.split_once('\n').unwrap();
+        assert!(kept.len() <= 256);
+        let dropped: usize = marker
+            .trim_start_matches("[... ")
+            .trim_end_matches(" bytes truncated]")
+            .parse()
+            .unwrap();
+        assert_eq!(dropped + kept.len(), total);
+    }
+}
+
+#[test]
+fn keep_output_tail_reports_dropped_bytes_and_respects_utf8() {
+    let text = "\u{65E5}".repeat(10); // 30 bytes
+    let out = keep_output_tail(text.as_bytes(), text.len(), Some(8));
+    assert_eq!(out, "[... 24 bytes truncated]\n\u{65E5}\u{65E5}");
+
+    let out = keep_output_tail(b"short", 5, Some(8));
+    assert_eq!(out, "short");
+}
+
+#[cfg(unix)]
+#[test]
+fn timeout_kills_grandchild_processes() {
+    let td = tempfile::tempdir().unwrap();
+    let pid_file = td.path().join("grandchild.pid");
+    let script = format!("sleep 30 & echo $! > '{}'; wait", pid_file.display());
+    let runner = SystemProcessRunner;
+    let request = ProcessRequest::new("sh").args(["-c", script.as_str()]);
+
+    let output = runner
+        .run_with_timeout(&request, Duration::from_millis(500))
+        .unwrap();
+    assert!(output.timed_out);
+
+    let pid = fs::read_to_string(&pid_file).unwrap().trim().to_string();
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        let alive = Command::new("kill")
+            .args(["-0", pid.as_str()])
+            .stderr(Stdio::null())
+            .status()
+            .unwrap()
+            .success();
+        if !alive {
+            break;
+        }
+        assert!(
+            Instant::now() < deadline,
+            "grandchild {pid} survived timeout"
+        );
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn reports_cpu_time_and_memory_of_busy_child() {
+    let runner = SystemProcessRunner;
+    let request =
+        ProcessRequest::new("sh").args(["-c", "i=0; while [ $i -lt 200000 ]; do i=$((i+1)); done"]);
+
+    for output in [
+        runner.run(&request).unwrap(),
+        runner
+            .run_with_timeout(&request, Duration::from_secs(60))
+            .unwrap(),
+    ] {
+        assert!(output.success);
+        let usage = output.resources;
+        let user = usage.user_cpu.expect("user cpu on unix");
+        assert!(user > Duration::ZERO, "{usage:?}");
+        assert!(usage.system_cpu.is_some(), "{usage:?}");
+        assert!(usage.max_rss_bytes.is_some_and(|rss| rss > 0), "{usage:?}");
+        assert!(usage.wall_time >= user / 2, "{usage:?}");
+    }
+}
+
+#[test]
+fn wall_time_covers_the_child_lifetime() {
+    let runner = SystemProcessRunner;
+    let request = ProcessRequest::new("sh").args(["-c", "sleep 0.2"]);
+
+    let output = runner
+        .run_with_timeout(&request, Duration::from_secs(10))
+        .unwrap();
+
+    let wall = output.resources.wall_time;
+    assert!(wall >= Duration::from_millis(200), "{wall:?}");
+    assert!(wall < Duration::from_secs(10), "{wall:?}");
+}
+
+#[test]
+fn test_double_outputs_report_no_cpu_or_memory() {
+    struct CannedRunner;
+    impl ProcessRunner for CannedRunner {
+        fn run(&self, _request: &ProcessRequest) -> Result<ProcessOutput, ProcessExecutionError> {
+            Ok(ProcessOutput {
+                exit_code: 0,
+                success: true,
+                stdout: String::new(),
+                stderr: String::new(),
+                timed_out: false,
+                resources: Default::default(),
+            })
+        }
+
+        fn run_with_timeout(
+            &self,
+            request: &ProcessRequest,
+            _timeout: Duration,
+        ) -> Result<ProcessOutput, ProcessExecutionError> {
+            self.run(request)
+        }
+    }
+
+    let output = CannedRunner.run(&ProcessRequest::new("git")).unwrap();
+    assert_eq!(output.resources.user_cpu, None);
+    assert_eq!(output.resources.system_cpu, None);
+    assert_eq!(output.resources.max_rss_bytes, None);
+    assert_eq!(
+        output.resources.summary(),
+        "wall 0.0s",
+        "only wall time is rendered"
+    );
+}
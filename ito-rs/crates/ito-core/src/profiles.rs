@@ -0,0 +1,202 @@
+//! Named change profiles.
+//!
+//! Profiles live under the `profiles` project config key and bundle the
+//! defaults a kind of work needs (schema, harness, model, Ralph settings):
+//!
+//! ```json
+//! { "profiles": {
+//!     "bugfix": { "schema": "tdd", "harness": "claude", "model": "haiku" },
+//!     "feature": { "harness": "opencode", "ralph": { "min_iterations": 3 } }
+//! } }
+//! ```
+//!
+//! `ito create change --profile <name>` records the profile as `profile:` in
+//! the change's `.ito.yaml`. Later runs resolve their defaults from the
+//! recorded profile, or from the `default` profile when none is recorded.
+//! Explicit command-line flags always take precedence.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use ito_common::fs::StdFs;
+use ito_config::types::ProfileConfig;
+use ito_config::{ConfigContext, load_cascading_project_config};
+
+use crate::change_meta::{read_change_meta_from_dir, set_change_meta_field};
+use crate::config::json_get_path;
+use crate::errors::{CoreError, CoreResult};
+use crate::ralph::{RalphOptions, parse_duration};
+
+/// Profile applied when a change has no recorded profile.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Project config key holding the named profiles.
+pub const PROFILES_CONFIG_KEY: &str = "profiles";
+
+/// `.ito.yaml` key recording a change's profile.
+const PROFILE_META_KEY: &str = "profile";
+
+/// Named profiles keyed by profile name.
+pub type Profiles = BTreeMap<String, ProfileConfig>;
+
+/// Load `profiles` from the cascading project config.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Validation`] when the configured value is not a map
+/// of profile names to valid profiles.
+pub fn load_profiles(
+    project_root: &Path,
+    ito_path: &Path,
+    ctx: &ConfigContext,
+) -> CoreResult<Profiles> {
+    let merged = load_cascading_project_config(project_root, ito_path, ctx).merged;
+    let Some(value) = json_get_path(&merged, &[PROFILES_CONFIG_KEY]) else {
+        return Ok(Profiles::new());
+    };
+    serde_json::from_value(value.clone())
+        .map_err(|e| CoreError::validation(format!("Invalid {PROFILES_CONFIG_KEY} value: {e}")))
+}
+
+/// Look up a profile by name, or the `default` profile when `name` is `None`.
+///
+/// A missing `default` profile is not an error and yields `None`.
+///
+/// # Errors
+///
+/// Returns [`CoreError::NotFound`] naming the closest configured profiles
+/// when `name` is not configured.
+pub fn resolve_profile<'a>(
+    profiles: &'a Profiles,
+    name: Option<&str>,
+) -> CoreResult<Option<&'a ProfileConfig>> {
+    let Some(name) = name else {
+        return Ok(profiles.get(DEFAULT_PROFILE));
+    };
+    if let Some(profile) = profiles.get(name) {
+        return Ok(Some(profile));
+    }
+
+    let names: Vec<String> = profiles.keys().cloned().collect();
+    let mut msg = format!("Unknown profile '{name}'");
+    if names.is_empty() {
+        msg.push_str(&format!(
+            ". No profiles are configured under '{PROFILES_CONFIG_KEY}'"
+        ));
+    } else {
+        msg.push_str(&format!(". Available profiles: {}", names.join(", ")));
+        let suggestions = crate::nearest_matches(name, &names, 3);
+        if !suggestions.is_empty() {
+            msg.push_str(&format!("\nDid you mean: {}?", suggestions.join(", ")));
+        }
+    }
+    Err(CoreError::not_found(msg))
+}
+
+/// Profile recorded in the `.ito.yaml` of `change_id`, if any.
+pub fn read_change_profile(ito_path: &Path, change_id: &str) -> Option<String> {
+    let change_dir = ito_path.join("changes").join(change_id);
+    read_change_meta_from_dir(&StdFs, &change_dir).profile
+}
+
+/// Record `profile` in the `.ito.yaml` of `change_id`.
+pub fn record_change_profile(ito_path: &Path, change_id: &str, profile: &str) -> CoreResult<()> {
+    let path = ito_path.join("changes").join(change_id).join(".ito.yaml");
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(CoreError::io(format!("reading {}", path.display()), e)),
+    };
+    let updated = set_change_meta_field(&contents, PROFILE_META_KEY, Some(profile));
+    std::fs::write(&path, updated)
+        .map_err(|e| CoreError::io(format!("writing {}", path.display()), e))
+}
+
+/// Resolve the profile that applies to `change_id`.
+///
+/// Uses the profile recorded for the change, falling back to the `default`
+/// profile (also used when `change_id` is `None`).
+///
+/// # Errors
+///
+/// Returns an error when the profile config is invalid or the recorded
+/// profile is no longer configured.
+pub fn resolve_change_profile(
+    project_root: &Path,
+    ito_path: &Path,
+    ctx: &ConfigContext,
+    change_id: Option<&str>,
+) -> CoreResult<Option<ProfileConfig>> {
+    let profiles = load_profiles(project_root, ito_path, ctx)?;
+    let recorded = change_id.and_then(|id| read_change_profile(ito_path, id));
+    Ok(resolve_profile(&profiles, recorded.as_deref())?.cloned())
+}
+
+/// Fill Ralph settings from `profile` unless the caller set them explicitly.
+///
+/// `explicit` is asked with the profile key (`model`, `min_iterations`,
+/// `max_iterations`, `timeout`, `no_commit`, `exit_on_error`,
+/// `skip_validation`, `validation_command`) and returns true when the
+/// corresponding command-line flag was given; those fields are left alone.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Validation`] when the profile's `timeout` is not a
+/// valid duration.
+pub fn apply_profile_to_ralph_options(
+    profile: &ProfileConfig,
+    opts: &mut RalphOptions,
+    explicit: &dyn Fn(&str) -> bool,
+) -> CoreResult<()> {
+    if let Some(model) = &profile.model
+        && !explicit("model")
+    {
+        opts.model = Some(model.clone());
+    }
+
+    let Some(ralph) = &profile.ralph else {
+        return Ok(());
+    };
+    if let Some(min) = ralph.min_iterations
+        && !explicit("min_iterations")
+    {
+        opts.min_iterations = min;
+    }
+    if let Some(max) = ralph.max_iterations
+        && !explicit("max_iterations")
+    {
+        opts.max_iterations = Some(max);
+    }
+    if let Some(raw) = &ralph.timeout
+        && !explicit("timeout")
+    {
+        let timeout = parse_duration(raw)
+            .map_err(|e| CoreError::validation(format!("Invalid profile timeout '{raw}': {e}")))?;
+        opts.inactivity_timeout = Some(timeout);
+    }
+    if let Some(no_commit) = ralph.no_commit
+        && !explicit("no_commit")
+    {
+        opts.no_commit = no_commit;
+    }
+    if let Some(exit_on_error) = ralph.exit_on_error
+        && !explicit("exit_on_error")
+    {
+        opts.exit_on_error = exit_on_error;
+    }
+    if let Some(skip_validation) = ralph.skip_validation
+        && !explicit("skip_validation")
+    {
+        opts.skip_validation = skip_validation;
+    }
+    if let Some(command) = &ralph.validation_command
+        && !explicit("validation_command")
+    {
+        opts.validation_command = Some(command.clone());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "profiles_tests.rs"]
+mod profiles_tests;
@@ -0,0 +1,120 @@
+use super::*;
+
+use ito_config::types::{ProfileHarness, ProfileRalphConfig};
+
+fn profiles() -> Profiles {
+    let mut profiles = Profiles::new();
+    profiles.insert(
+        "bugfix".to_string(),
+        ProfileConfig {
+            schema: Some("tdd".to_string()),
+            harness: Some(ProfileHarness::Claude),
+            model: Some("haiku".to_string()),
+            ralph: Some(ProfileRalphConfig {
+                min_iterations: Some(2),
+                ..ProfileRalphConfig::default()
+            }),
+        },
+    );
+    profiles.insert(
+        DEFAULT_PROFILE.to_string(),
+        ProfileConfig {
+            model: Some("sonnet".to_string()),
+            ..ProfileConfig::default()
+        },
+    );
+    profiles
+}
+
+fn write_project(root: &Path, config: &str, meta: &str) -> std::path::PathBuf {
+    let ito = root.join(".ito");
+    let change_dir = ito.join("changes").join("001-01_fix");
+    std::fs::create_dir_all(&change_dir).unwrap();
+    std::fs::write(ito.join("config.json"), config).unwrap();
+    std::fs::write(change_dir.join(".ito.yaml"), meta).unwrap();
+    ito
+}
+
+#[test]
+fn resolve_profile_uses_default_when_no_name_is_given() {
+    let profiles = profiles();
+    let profile = resolve_profile(&profiles, None).unwrap().unwrap();
+    assert_eq!(profile.model.as_deref(), Some("sonnet"));
+
+    assert!(resolve_profile(&Profiles::new(), None).unwrap().is_none());
+}
+
+#[test]
+fn unknown_profile_errors_with_suggestions() {
+    let err = resolve_profile(&profiles(), Some("bugfx")).unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("Unknown profile 'bugfx'"), "{msg}");
+    assert!(msg.contains("Did you mean: bugfix"), "{msg}");
+}
+
+#[test]
+fn recorded_profile_is_read_from_change_metadata() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = write_project(
+        td.path(),
+        r#"{ "profiles": { "bugfix": { "harness": "claude", "model": "haiku" } } }"#,
+        "schema: tdd\ncreated: 2026-01-01\n",
+    );
+
+    assert_eq!(read_change_profile(&ito, "001-01_fix"), None);
+    record_change_profile(&ito, "001-01_fix", "bugfix").unwrap();
+    assert_eq!(
+        std::fs::read_to_string(ito.join("changes/001-01_fix/.ito.yaml")).unwrap(),
+        "schema: tdd\ncreated: 2026-01-01\nprofile: bugfix\n"
+    );
+    assert_eq!(
+        read_change_profile(&ito, "001-01_fix").as_deref(),
+        Some("bugfix")
+    );
+
+    let profile = resolve_change_profile(
+        td.path(),
+        &ito,
+        &ConfigContext::default(),
+        Some("001-01_fix"),
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(profile.model.as_deref(), Some("haiku"));
+    assert_eq!(profile.harness, Some(ProfileHarness::Claude));
+}
+
+#[test]
+fn change_without_recorded_profile_falls_back_to_default() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = write_project(
+        td.path(),
+        r#"{ "profiles": { "default": { "model": "sonnet" } } }"#,
+        "schema: spec-driven\n",
+    );
+
+    let profile = resolve_change_profile(
+        td.path(),
+        &ito,
+        &ConfigContext::default(),
+        Some("001-01_fix"),
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(profile.model.as_deref(), Some("sonnet"));
+}
+
+#[test]
+fn recorded_profile_missing_from_config_is_an_error() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = write_project(td.path(), "{}", "profile: gone\n");
+
+    let err = resolve_change_profile(
+        td.path(),
+        &ito,
+        &ConfigContext::default(),
+        Some("001-01_fix"),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("Unknown profile 'gone'"));
+}
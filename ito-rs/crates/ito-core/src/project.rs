@@ -0,0 +1,164 @@
+//! High-level facade for embedding Ito.
+//!
+//! [`Project`] is the entry point for tools that want to drive Ito without
+//! going through the CLI. It owns configuration-context resolution, locates
+//! the Ito directory, and constructs the repositories for the configured
+//! persistence mode, then exposes the common operations as methods.
+//!
+//! The CLI builds its runtime on this type, so an embedding tool sees the same
+//! behavior as `ito list`, `ito status`, `ito agent instruction`, `ito
+//! validate`, `ito tasks status`, and `ito ralph`.
+//!
+//! # Stability
+//!
+//! The constructors and methods of [`Project`] are a semver-meaningful API:
+//! they only change in breaking releases. The value types they return live in
+//! their own modules and may gain fields in minor releases.
+//!
+//! ```no_run
+//! use ito_core::project::Project;
+//!
+//! let project = Project::open("/path/to/repo");
+//! for change in project.list_changes()? {
+//!     println!("{} {}/{}", change.id, change.completed_tasks, change.total_tasks);
+//! }
+//! # Ok::<(), ito_core::errors::CoreError>(())
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use ito_config::ito_dir::get_ito_path;
+use ito_config::{CascadingProjectConfig, ConfigContext, load_cascading_project_config};
+use ito_domain::changes::ChangeSummary;
+
+use crate::errors::{CoreError, CoreResult};
+use crate::harness::Harness;
+use crate::ralph::{RalphOptions, run_ralph};
+use crate::repository_runtime::{RepositoryRuntime, resolve_repository_runtime};
+use crate::tasks::{TaskStatusSummary, get_task_status_from_repository};
+use crate::templates::{
+    ChangeStatus, InstructionsResponse, WorkflowError, compute_change_status, resolve_instructions,
+};
+use crate::validate::{ValidationReport, validate_change};
+
+/// An Ito project rooted at a directory.
+///
+/// Context, the Ito directory, configuration, and repositories are resolved
+/// lazily and cached for the lifetime of the value.
+pub struct Project {
+    root: PathBuf,
+    ctx: ConfigContext,
+    ito_path: OnceLock<PathBuf>,
+    config: OnceLock<CascadingProjectConfig>,
+    repository_runtime: OnceLock<RepositoryRuntime>,
+}
+
+impl Project {
+    /// Open the project rooted at `root`, reading context from the process
+    /// environment (`HOME`, `XDG_CONFIG_HOME`, `PROJECT_DIR`).
+    pub fn open(root: impl Into<PathBuf>) -> Self {
+        Self::with_context(root, ConfigContext::from_process_env())
+    }
+
+    /// Open the project rooted at `root` with an explicit configuration context.
+    pub fn with_context(root: impl Into<PathBuf>, ctx: ConfigContext) -> Self {
+        Self {
+            root: root.into(),
+            ctx,
+            ito_path: OnceLock::new(),
+            config: OnceLock::new(),
+            repository_runtime: OnceLock::new(),
+        }
+    }
+
+    /// Project root directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Configuration context used for resolution.
+    pub fn ctx(&self) -> &ConfigContext {
+        &self.ctx
+    }
+
+    /// Path to the project's Ito directory (usually `<root>/.ito`).
+    pub fn ito_path(&self) -> &Path {
+        self.ito_path
+            .get_or_init(|| get_ito_path(&self.root, &self.ctx))
+    }
+
+    /// Cascading project configuration, loaded at most once.
+    pub fn config(&self) -> &CascadingProjectConfig {
+        self.config.get_or_init(|| {
+            let ito_path = self.ito_path();
+            let project_root = ito_path.parent().unwrap_or(ito_path);
+            load_cascading_project_config(project_root, ito_path, &self.ctx)
+        })
+    }
+
+    /// Repositories for the configured persistence mode, built at most once.
+    pub fn repository_runtime(&self) -> CoreResult<&RepositoryRuntime> {
+        if let Some(runtime) = self.repository_runtime.get() {
+            return Ok(runtime);
+        }
+        let runtime = resolve_repository_runtime(self.ito_path(), &self.ctx)?;
+        Ok(self.repository_runtime.get_or_init(|| runtime))
+    }
+
+    /// List active changes with task progress.
+    pub fn list_changes(&self) -> CoreResult<Vec<ChangeSummary>> {
+        let changes = &self.repository_runtime()?.repositories().changes;
+        Ok(changes.list()?)
+    }
+
+    /// Artifact status for a change against its schema.
+    pub fn change_status(&self, change_id: &str) -> CoreResult<ChangeStatus> {
+        compute_change_status(self.ito_path(), change_id, None, &self.ctx).map_err(workflow_error)
+    }
+
+    /// Instructions and template for one artifact of a change.
+    pub fn instructions(
+        &self,
+        change_id: &str,
+        artifact: &str,
+    ) -> CoreResult<InstructionsResponse> {
+        resolve_instructions(self.ito_path(), change_id, None, artifact, &self.ctx)
+            .map_err(workflow_error)
+    }
+
+    /// Validate a change.
+    pub fn validate(&self, change_id: &str, strict: bool) -> CoreResult<ValidationReport> {
+        let changes = &self.repository_runtime()?.repositories().changes;
+        validate_change(changes.as_ref(), self.ito_path(), change_id, strict)
+    }
+
+    /// Parsed tasks, progress, and ready/blocked task sets for a change.
+    pub fn tasks(&self, change_id: &str) -> CoreResult<TaskStatusSummary> {
+        let tasks = &self.repository_runtime()?.repositories().tasks;
+        get_task_status_from_repository(tasks.as_ref(), change_id)
+    }
+
+    /// Run the Ralph loop with `opts` using `harness`.
+    pub fn start_ralph(&self, opts: RalphOptions, harness: &mut dyn Harness) -> CoreResult<()> {
+        let repositories = self.repository_runtime()?.repositories();
+        run_ralph(
+            self.ito_path(),
+            repositories.changes.as_ref(),
+            repositories.tasks.as_ref(),
+            repositories.modules.as_ref(),
+            opts,
+            harness,
+        )
+    }
+}
+
+fn workflow_error(error: WorkflowError) -> CoreError {
+    match error {
+        WorkflowError::ChangeNotFound(_)
+        | WorkflowError::SchemaNotFound(_)
+        | WorkflowError::ArtifactNotFound(_) => CoreError::not_found(error.to_string()),
+        WorkflowError::Io(source) => CoreError::io("reading workflow files", source),
+        other => CoreError::validation(other.to_string()),
+    }
+}
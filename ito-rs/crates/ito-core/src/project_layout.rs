@@ -0,0 +1,269 @@
+//! Checks for missing or unreadable parts of an Ito directory.
+//!
+//! Read paths (`ito list`, `ito show`, `ito status`) call
+//! [`check_project_layout`] before touching the filesystem so they can tell
+//! "not an Ito project" apart from "Ito directory present but partially
+//! missing" and from permission problems. Fatal problems come back as
+//! [`ProjectLayoutError`]; problems with individual subdirectories come back as
+//! [`LayoutWarning`]s so callers can continue with whatever does exist.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use ito_common::fs::StdFs;
+use ito_domain::discovery::{self, SkipReason};
+use serde::Serialize;
+use thiserror::Error;
+
+/// `changes/` subdirectory name.
+pub const CHANGES_SUBDIR: &str = "changes";
+/// `specs/` subdirectory name.
+pub const SPECS_SUBDIR: &str = "specs";
+/// `modules/` subdirectory name.
+pub const MODULES_SUBDIR: &str = "modules";
+
+/// A problem that prevents reading the Ito directory at all.
+#[derive(Debug, Error)]
+pub enum ProjectLayoutError {
+    /// No Ito directory exists at the expected location.
+    #[error("Not an Ito project: no Ito directory at {}", path.display())]
+    NotAnItoProject {
+        /// Expected Ito directory.
+        path: PathBuf,
+    },
+
+    /// The Ito directory is a symlink whose target does not exist.
+    #[error("Ito directory {} is a dangling symlink to {}", path.display(), target.display())]
+    DanglingSymlink {
+        /// Ito directory path.
+        path: PathBuf,
+        /// Missing symlink target.
+        target: PathBuf,
+    },
+
+    /// The Ito directory exists but cannot be listed.
+    #[error("Permission denied reading {}", path.display())]
+    PermissionDenied {
+        /// Ito directory path.
+        path: PathBuf,
+    },
+
+    /// Any other failure while inspecting the Ito directory.
+    #[error("Cannot read {}: {source}", path.display())]
+    Io {
+        /// Ito directory path.
+        path: PathBuf,
+        /// Underlying I/O error.
+        #[source]
+        source: io::Error,
+    },
+}
+
+impl ProjectLayoutError {
+    /// Actionable next step for the user.
+    pub fn help(&self) -> String {
+        match self {
+            Self::NotAnItoProject { .. } => {
+                "Run `ito init` to set up Ito here, or run the command from inside an Ito project."
+                    .to_string()
+            }
+            Self::DanglingSymlink { target, .. } => format!(
+                "Restore {} or point the symlink at an existing Ito directory.",
+                target.display()
+            ),
+            Self::PermissionDenied { path } => format!(
+                "Ito needs read and execute (list) permission on {}.",
+                path.display()
+            ),
+            Self::Io { .. } => "Check that the path is accessible and try again.".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+/// Why a subdirectory could not be read.
+pub enum LayoutIssueKind {
+    /// The subdirectory does not exist.
+    Missing,
+    /// The subdirectory is a symlink whose target does not exist.
+    DanglingSymlink,
+    /// The path exists but is not a directory.
+    NotADirectory,
+    /// The subdirectory exists but cannot be listed.
+    PermissionDenied,
+    /// An entry inside the subdirectory has a malformed name and was skipped.
+    MalformedEntry,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// A subdirectory problem that callers report and then work around.
+pub struct LayoutWarning {
+    /// Problem category.
+    pub kind: LayoutIssueKind,
+    /// Affected path.
+    pub path: String,
+    /// Human-readable description.
+    pub message: String,
+    /// Actionable next step.
+    pub help: String,
+}
+
+/// Check that `ito_path` is readable and inspect the requested subdirectories.
+///
+/// Returns one warning per subdirectory in `subdirs` that is missing, dangling,
+/// not a directory, or unreadable, plus one warning per malformed entry inside
+/// a readable `changes/` or `modules/` subdirectory (see
+/// [`skipped_entry_warnings`]).
+///
+/// # Errors
+///
+/// Returns [`ProjectLayoutError`] when the Ito directory itself is missing or
+/// cannot be listed.
+pub fn check_project_layout(
+    ito_path: &Path,
+    subdirs: &[&str],
+) -> Result<Vec<LayoutWarning>, ProjectLayoutError> {
+    match std::fs::symlink_metadata(ito_path) {
+        Ok(meta) if meta.file_type().is_symlink() && !ito_path.exists() => {
+            let target = std::fs::read_link(ito_path).unwrap_or_default();
+            return Err(ProjectLayoutError::DanglingSymlink {
+                path: ito_path.to_path_buf(),
+                target,
+            });
+        }
+        Ok(_) => {}
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Err(ProjectLayoutError::NotAnItoProject {
+                path: ito_path.to_path_buf(),
+            });
+        }
+        Err(source) => {
+            return Err(ProjectLayoutError::Io {
+                path: ito_path.to_path_buf(),
+                source,
+            });
+        }
+    }
+    if !ito_path.is_dir() {
+        return Err(ProjectLayoutError::NotAnItoProject {
+            path: ito_path.to_path_buf(),
+        });
+    }
+    if let Err(e) = std::fs::read_dir(ito_path) {
+        if e.kind() == io::ErrorKind::PermissionDenied {
+            return Err(ProjectLayoutError::PermissionDenied {
+                path: ito_path.to_path_buf(),
+            });
+        }
+        return Err(ProjectLayoutError::Io {
+            path: ito_path.to_path_buf(),
+            source: e,
+        });
+    }
+
+    let mut warnings = Vec::new();
+    for name in subdirs {
+        match inspect_subdir(&ito_path.join(name), name) {
+            Some(warning) => warnings.push(warning),
+            None => warnings.extend(skipped_entry_warnings(ito_path, &[*name])),
+        }
+    }
+    Ok(warnings)
+}
+
+/// Report entries of the `changes/` and `modules/` subdirectories in `subdirs`
+/// whose names discovery had to skip (non-UTF-8 or misnamed modules).
+///
+/// Other subdirectories and unreadable ones produce nothing.
+pub fn skipped_entry_warnings(ito_path: &Path, subdirs: &[&str]) -> Vec<LayoutWarning> {
+    let mut out = Vec::new();
+    for name in subdirs {
+        let listing = match *name {
+            CHANGES_SUBDIR => discovery::list_change_dir_entries(&StdFs, ito_path),
+            MODULES_SUBDIR => discovery::list_module_dir_entries(&StdFs, ito_path),
+            _ => continue,
+        };
+        let Ok(listing) = listing else {
+            continue;
+        };
+        for entry in listing.skipped {
+            let display = ito_path
+                .join(name)
+                .join(&entry.lossy_name)
+                .display()
+                .to_string();
+            out.push(LayoutWarning {
+                kind: LayoutIssueKind::MalformedEntry,
+                message: format!("Skipping {display}: {}", entry.reason),
+                help: match entry.reason {
+                    SkipReason::NonUtf8 => {
+                        "Rename the directory to a valid UTF-8 name.".to_string()
+                    }
+                    SkipReason::InvalidModuleId => {
+                        "Rename the directory to NNN_name (e.g. 001_core).".to_string()
+                    }
+                },
+                path: display,
+            });
+        }
+    }
+    out
+}
+
+fn inspect_subdir(path: &Path, name: &str) -> Option<LayoutWarning> {
+    let display = path.display().to_string();
+    let meta = match std::fs::symlink_metadata(path) {
+        Ok(meta) => meta,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Some(LayoutWarning {
+                kind: LayoutIssueKind::Missing,
+                message: format!("Ito {name} directory is missing: {display}"),
+                help: format!("Create {display} or restore it from version control."),
+                path: display,
+            });
+        }
+        Err(_) => return Some(permission_denied(display, name)),
+    };
+
+    if meta.file_type().is_symlink() && !path.exists() {
+        let target = std::fs::read_link(path).unwrap_or_default();
+        return Some(LayoutWarning {
+            kind: LayoutIssueKind::DanglingSymlink,
+            message: format!(
+                "Ito {name} directory {display} is a dangling symlink to {}",
+                target.display()
+            ),
+            help: format!(
+                "Restore {} or replace the symlink with a directory.",
+                target.display()
+            ),
+            path: display,
+        });
+    }
+    if !path.is_dir() {
+        return Some(LayoutWarning {
+            kind: LayoutIssueKind::NotADirectory,
+            message: format!("Ito {name} path is not a directory: {display}"),
+            help: format!("Move the file aside and create a {name} directory in its place."),
+            path: display,
+        });
+    }
+    match std::fs::read_dir(path) {
+        Ok(_) => None,
+        Err(_) => Some(permission_denied(display, name)),
+    }
+}
+
+fn permission_denied(display: String, name: &str) -> LayoutWarning {
+    LayoutWarning {
+        kind: LayoutIssueKind::PermissionDenied,
+        message: format!("Cannot read Ito {name} directory: {display}"),
+        help: format!("Ito needs read and execute (list) permission on {display}."),
+        path: display,
+    }
+}
+
+#[cfg(test)]
+#[path = "project_layout_tests.rs"]
+mod project_layout_tests;
@@ -0,0 +1,102 @@
+use super::*;
+
+fn kinds(warnings: &[LayoutWarning]) -> Vec<LayoutIssueKind> {
+    warnings.iter().map(|w| w.kind).collect()
+}
+
+#[test]
+fn missing_ito_dir_is_not_an_ito_project() {
+    let td = tempfile::tempdir().unwrap();
+    let err = check_project_layout(&td.path().join(".ito"), &[CHANGES_SUBDIR]).unwrap_err();
+    assert!(matches!(err, ProjectLayoutError::NotAnItoProject { .. }));
+    assert!(err.to_string().starts_with("Not an Ito project"));
+    assert!(err.help().contains("ito init"));
+}
+
+#[test]
+fn complete_layout_has_no_warnings() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(td.path().join("changes")).unwrap();
+    std::fs::create_dir_all(td.path().join("specs")).unwrap();
+    let warnings = check_project_layout(td.path(), &[CHANGES_SUBDIR, SPECS_SUBDIR]).unwrap();
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn missing_changes_dir_is_a_warning() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(td.path().join("specs")).unwrap();
+    let warnings = check_project_layout(td.path(), &[CHANGES_SUBDIR, SPECS_SUBDIR]).unwrap();
+    assert_eq!(kinds(&warnings), vec![LayoutIssueKind::Missing]);
+    assert!(warnings[0].path.ends_with("changes"));
+    assert!(warnings[0].message.contains("changes directory is missing"));
+}
+
+#[test]
+fn file_in_place_of_dir_is_reported() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::write(td.path().join("changes"), "oops").unwrap();
+    let warnings = check_project_layout(td.path(), &[CHANGES_SUBDIR]).unwrap();
+    assert_eq!(kinds(&warnings), vec![LayoutIssueKind::NotADirectory]);
+}
+
+#[cfg(unix)]
+#[test]
+fn dangling_symlink_is_reported_with_target() {
+    let td = tempfile::tempdir().unwrap();
+    let target = td.path().join("elsewhere");
+    std::os::unix::fs::symlink(&target, td.path().join("changes")).unwrap();
+    let warnings = check_project_layout(td.path(), &[CHANGES_SUBDIR]).unwrap();
+    assert_eq!(kinds(&warnings), vec![LayoutIssueKind::DanglingSymlink]);
+    assert!(warnings[0].message.contains("elsewhere"));
+}
+
+#[cfg(unix)]
+#[test]
+fn unreadable_subdir_names_path_and_permission() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let td = tempfile::tempdir().unwrap();
+    let changes = td.path().join("changes");
+    std::fs::create_dir_all(&changes).unwrap();
+    std::fs::set_permissions(&changes, std::fs::Permissions::from_mode(0o000)).unwrap();
+    // Privileged users (e.g. root in CI containers) can read anyway.
+    let readable = std::fs::read_dir(&changes).is_ok();
+
+    let warnings = check_project_layout(td.path(), &[CHANGES_SUBDIR]).unwrap();
+    std::fs::set_permissions(&changes, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    if readable {
+        assert!(warnings.is_empty());
+        return;
+    }
+    assert_eq!(kinds(&warnings), vec![LayoutIssueKind::PermissionDenied]);
+    assert!(warnings[0].help.contains("read and execute"));
+    assert!(warnings[0].help.contains(&changes.display().to_string()));
+}
+
+#[test]
+fn misnamed_module_dir_is_a_warning() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(td.path().join("modules/001_core")).unwrap();
+    std::fs::create_dir_all(td.path().join("modules/misc")).unwrap();
+    let warnings = check_project_layout(td.path(), &[MODULES_SUBDIR]).unwrap();
+    assert_eq!(kinds(&warnings), vec![LayoutIssueKind::MalformedEntry]);
+    assert!(warnings[0].path.ends_with("misc"));
+    assert!(warnings[0].help.contains("NNN_name"));
+}
+
+#[cfg(unix)]
+#[test]
+fn non_utf8_change_dir_is_a_warning() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let td = tempfile::tempdir().unwrap();
+    let changes = td.path().join("changes");
+    std::fs::create_dir_all(changes.join("001-01_demo")).unwrap();
+    std::fs::create_dir_all(changes.join(OsStr::from_bytes(b"bad\xff"))).unwrap();
+    let warnings = check_project_layout(td.path(), &[CHANGES_SUBDIR]).unwrap();
+    assert_eq!(kinds(&warnings), vec![LayoutIssueKind::MalformedEntry]);
+    assert!(warnings[0].message.contains("not valid UTF-8"));
+}
@@ -0,0 +1,206 @@
+//! Completion signal detection for Ralph iterations.
+//!
+//! A [`CompletionDetector`] scans harness output for the signal that the agent
+//! considers its task done. The default [`TagDetector`] looks for
+//! `<promise>TOKEN</promise>`; the [`JsonDetector`] accepts structured payloads
+//! such as `{"status":"COMPLETE","summary":"..."}` so teams can record what the
+//! agent claims to have finished.
+
+use serde_json::Value;
+
+/// Output format the agent uses to signal completion.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CompletionFormat {
+    /// A `<tag>TOKEN</tag>` marker (default tag: `promise`).
+    #[default]
+    Tag,
+    /// A JSON object whose status field equals the token.
+    Json,
+}
+
+impl CompletionFormat {
+    /// Canonical name used in CLI flags and messages.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            CompletionFormat::Tag => "tag",
+            CompletionFormat::Json => "json",
+        }
+    }
+}
+
+impl std::fmt::Display for CompletionFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Default tag name scanned by [`TagDetector`].
+pub const DEFAULT_COMPLETION_TAG: &str = "promise";
+
+/// Default JSON pointer to the status field scanned by [`JsonDetector`].
+pub const DEFAULT_COMPLETION_JSON_POINTER: &str = "/status";
+
+/// A detected completion signal.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompletionSignal {
+    /// Summary payload attached to the signal, when the format carries one.
+    pub summary: Option<String>,
+}
+
+/// Detects a completion signal in harness output.
+pub trait CompletionDetector: std::fmt::Debug {
+    /// Return the signal when `output` contains one.
+    fn detect(&self, output: &str) -> Option<CompletionSignal>;
+
+    /// The literal marker the agent is instructed to emit.
+    fn marker(&self) -> String;
+}
+
+/// Detects `<tag>TOKEN</tag>` markers (whitespace around the token is ignored).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagDetector {
+    open: String,
+    close: String,
+    token: String,
+}
+
+impl TagDetector {
+    /// Detector for `<tag>token</tag>`.
+    pub fn new(tag: &str, token: &str) -> Self {
+        Self {
+            open: format!("<{tag}>"),
+            close: format!("</{tag}>"),
+            token: token.to_string(),
+        }
+    }
+}
+
+impl CompletionDetector for TagDetector {
+    fn detect(&self, output: &str) -> Option<CompletionSignal> {
+        let mut rest = output;
+        loop {
+            let start = rest.find(&self.open)?;
+            let after_start = &rest[start + self.open.len()..];
+            let end = after_start.find(&self.close)?;
+            if after_start[..end].trim() == self.token {
+                return Some(CompletionSignal::default());
+            }
+            rest = &after_start[end + self.close.len()..];
+        }
+    }
+
+    fn marker(&self) -> String {
+        format!("{}{}{}", self.open, self.token, self.close)
+    }
+}
+
+/// Detects JSON completion payloads.
+///
+/// Candidates are fenced ` ```json ` blocks and lines that hold a single JSON
+/// object. A candidate matches when the string at `pointer` equals the token
+/// (trimmed, case-insensitive); its sibling `summary` field becomes the signal
+/// summary. When several candidates match, the last one wins.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonDetector {
+    token: String,
+    pointer: String,
+}
+
+impl JsonDetector {
+    /// Detector matching `token` at `pointer` (default `/status`).
+    pub fn new(token: &str, pointer: Option<&str>) -> Self {
+        Self {
+            token: token.to_string(),
+            pointer: pointer
+                .unwrap_or(DEFAULT_COMPLETION_JSON_POINTER)
+                .to_string(),
+        }
+    }
+
+    /// Pointer to the `summary` field next to the status field.
+    fn summary_pointer(&self) -> String {
+        match self.pointer.rfind('/') {
+            Some(idx) => format!("{}/summary", &self.pointer[..idx]),
+            None => "/summary".to_string(),
+        }
+    }
+
+    fn matches(&self, value: &Value) -> Option<CompletionSignal> {
+        let status = value.pointer(&self.pointer)?.as_str()?;
+        if !status.trim().eq_ignore_ascii_case(self.token.trim()) {
+            return None;
+        }
+        let summary = value
+            .pointer(&self.summary_pointer())
+            .and_then(|summary| match summary {
+                Value::Null => None,
+                Value::String(s) => Some(s.clone()),
+                other => Some(other.to_string()),
+            });
+        Some(CompletionSignal { summary })
+    }
+}
+
+impl CompletionDetector for JsonDetector {
+    fn detect(&self, output: &str) -> Option<CompletionSignal> {
+        json_candidates(output).iter().rev().find_map(|candidate| {
+            serde_json::from_str::<Value>(candidate)
+                .ok()
+                .and_then(|value| self.matches(&value))
+        })
+    }
+
+    fn marker(&self) -> String {
+        let mut segments: Vec<&str> = self.pointer.split('/').skip(1).collect();
+        let field = segments.pop().unwrap_or("status");
+        let mut payload = serde_json::Map::new();
+        payload.insert(field.to_string(), Value::String(self.token.clone()));
+        payload.insert(
+            "summary".to_string(),
+            Value::String("<one-line summary of the completed work>".to_string()),
+        );
+        let mut value = Value::Object(payload);
+        for segment in segments.into_iter().rev() {
+            let mut parent = serde_json::Map::new();
+            parent.insert(segment.to_string(), value);
+            value = Value::Object(parent);
+        }
+        value.to_string()
+    }
+}
+
+/// Fenced `json` blocks and single-line JSON objects in document order.
+///
+/// Lines inside fences of other languages are skipped.
+fn json_candidates(output: &str) -> Vec<String> {
+    let mut candidates = Vec::new();
+    let mut in_fence = false;
+    let mut block: Option<String> = None;
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if let Some(lang) = trimmed.strip_prefix("```") {
+            if in_fence {
+                candidates.extend(block.take());
+            } else if lang.trim().eq_ignore_ascii_case("json") {
+                block = Some(String::new());
+            }
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            if let Some(block) = block.as_mut() {
+                block.push_str(line);
+                block.push('\n');
+            }
+            continue;
+        }
+        if trimmed.starts_with('{') && trimmed.ends_with('}') {
+            candidates.push(trimmed.to_string());
+        }
+    }
+    candidates
+}
+
+#[cfg(test)]
+#[path = "completion_tests.rs"]
+mod completion_tests;
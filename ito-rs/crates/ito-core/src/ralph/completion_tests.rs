@@ -0,0 +1,127 @@
+use super::*;
+
+fn promise(token: &str) -> TagDetector {
+    TagDetector::new(DEFAULT_COMPLETION_TAG, token)
+}
+
+fn found(detector: &dyn CompletionDetector, output: &str) -> bool {
+    detector.detect(output).is_some()
+}
+
+// -- TagDetector -----------------------------------------------------
+
+#[test]
+fn promise_single_match() {
+    assert!(found(&promise("T"), "x\n<promise>T</promise>\ny"));
+}
+#[test]
+fn promise_no_tags() {
+    assert!(!found(&promise("T"), "no tags here"));
+}
+#[test]
+fn promise_second_match() {
+    assert!(found(
+        &promise("T"),
+        "<promise>W</promise><promise>T</promise>"
+    ));
+}
+#[test]
+fn promise_empty_token() {
+    assert!(found(&promise(""), "<promise></promise>"));
+}
+#[test]
+fn promise_empty_stdout() {
+    assert!(!found(&promise("T"), ""));
+}
+#[test]
+fn promise_whitespace_trimmed() {
+    assert!(found(&promise("T"), "<promise>  T  </promise>"));
+}
+#[test]
+fn promise_nested() {
+    assert!(!found(
+        &promise("T"),
+        "<promise><promise>T</promise></promise>"
+    ));
+}
+#[test]
+fn promise_incomplete() {
+    assert!(!found(&promise("T"), "<promise>T"));
+}
+
+#[test]
+fn tag_detector_uses_configured_tag() {
+    let detector = TagDetector::new("done", "T");
+    assert!(found(&detector, "<done>T</done>"));
+    assert!(!found(&detector, "<promise>T</promise>"));
+    assert_eq!(detector.marker(), "<done>T</done>");
+}
+
+// -- JsonDetector ----------------------------------------------------
+
+#[test]
+fn json_fenced_block_with_summary() {
+    let detector = JsonDetector::new("COMPLETE", None);
+    let output = "Finished.\n\n```json\n{\n  \"status\": \"complete\",\n  \"summary\": \"Added the parser\"\n}\n```\n";
+    assert_eq!(
+        detector.detect(output),
+        Some(CompletionSignal {
+            summary: Some("Added the parser".to_string())
+        })
+    );
+}
+
+#[test]
+fn json_single_line_object() {
+    let detector = JsonDetector::new("COMPLETE", None);
+    let signal = detector
+        .detect("done\n{\"status\":\"COMPLETE\"}\n")
+        .expect("signal");
+    assert_eq!(signal.summary, None);
+}
+
+#[test]
+fn json_status_must_match_token() {
+    let detector = JsonDetector::new("COMPLETE", None);
+    assert!(!found(&detector, "{\"status\":\"in-progress\"}"));
+    assert!(!found(&detector, "{\"state\":\"COMPLETE\"}"));
+    assert!(!found(&detector, "<promise>COMPLETE</promise>"));
+}
+
+#[test]
+fn json_ignores_other_fences_and_invalid_json() {
+    let detector = JsonDetector::new("COMPLETE", None);
+    let output = "```text\n{\"status\":\"COMPLETE\"}\n```\n```json\n{not json\n```\n";
+    assert!(!found(&detector, output));
+}
+
+#[test]
+fn json_last_match_wins() {
+    let detector = JsonDetector::new("COMPLETE", None);
+    let output = "{\"status\":\"COMPLETE\",\"summary\":\"first\"}\n{\"status\":\"COMPLETE\",\"summary\":\"second\"}\n";
+    assert_eq!(
+        detector.detect(output).and_then(|s| s.summary).as_deref(),
+        Some("second")
+    );
+}
+
+#[test]
+fn json_custom_pointer_reads_sibling_summary() {
+    let detector = JsonDetector::new("COMPLETE", Some("/result/state"));
+    let output = "{\"result\":{\"state\":\"COMPLETE\",\"summary\":{\"files\":2}}}";
+    assert_eq!(
+        detector.detect(output).and_then(|s| s.summary).as_deref(),
+        Some("{\"files\":2}")
+    );
+    assert!(!found(&detector, "{\"status\":\"COMPLETE\"}"));
+}
+
+#[test]
+fn json_marker_is_detected() {
+    for pointer in [None, Some("/result/state")] {
+        let detector = JsonDetector::new("COMPLETE", pointer);
+        let marker = detector.marker();
+        assert!(!marker.contains('\n'));
+        assert!(found(&detector, &format!("   {marker}")), "{marker}");
+    }
+}
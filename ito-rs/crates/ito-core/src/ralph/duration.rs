@@ -10,6 +10,7 @@ use std::time::Duration;
 /// - `2h` - 2 hours
 /// - `1h30m` - 1 hour 30 minutes
 /// - `1h30m45s` - 1 hour 30 minutes 45 seconds
+/// - `30d` - 30 days
 /// - `90` - 90 seconds (bare number defaults to seconds)
 ///
 /// # Examples
@@ -59,9 +60,10 @@ pub fn parse_duration(s: &str) -> CoreResult<Duration> {
                 's' => 1,
                 'm' => 60,
                 'h' => 3600,
+                'd' => 86_400,
                 _ => {
                     return Err(crate::errors::CoreError::Parse(format!(
-                        "Invalid duration unit '{unit}'. Use 's', 'm', or 'h'"
+                        "Invalid duration unit '{unit}'. Use 's', 'm', 'h', or 'd'"
                     )));
                 }
             };
@@ -19,6 +19,18 @@ fn test_parse_hours() {
     assert_eq!(parse_duration("1h").unwrap(), Duration::from_secs(3600));
 }
 
+#[test]
+fn test_parse_days() {
+    assert_eq!(
+        parse_duration("30d").unwrap(),
+        Duration::from_secs(30 * 86_400)
+    );
+    assert_eq!(
+        parse_duration("1d12h").unwrap(),
+        Duration::from_secs(129_600)
+    );
+}
+
 #[test]
 fn test_parse_combined() {
     assert_eq!(parse_duration("1m30s").unwrap(), Duration::from_secs(90));
@@ -0,0 +1,90 @@
+//! Recent repository activity for Ralph prompts.
+//!
+//! Later iterations benefit from knowing what earlier iterations committed.
+//! This module summarizes commits made since the loop started (subject plus
+//! `git log --stat` diffstat). Every git failure degrades to "no section" so
+//! the iteration itself is never affected.
+
+use crate::process::{ProcessRequest, ProcessRunner};
+use std::path::{Path, PathBuf};
+
+/// Default number of recent commits included in the prompt.
+pub const DEFAULT_GIT_CONTEXT_COMMITS: usize = 5;
+
+/// Default character cap for the recent activity section.
+pub const DEFAULT_GIT_CONTEXT_MAX_CHARS: usize = 4_000;
+
+/// Settings for the "recent repository activity" prompt section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitContextOptions {
+    /// Working directory of the repository to inspect.
+    pub cwd: PathBuf,
+    /// Commit recorded when the loop started; only later commits are shown.
+    ///
+    /// `None` means the repository had no commits when the loop started.
+    pub since_commit: Option<String>,
+    /// Maximum number of commits to include.
+    pub max_commits: usize,
+    /// Maximum length of the rendered log, in characters.
+    pub max_chars: usize,
+}
+
+/// Return the current `HEAD` commit hash, or `None` when there is none.
+pub fn current_head(runner: &dyn ProcessRunner, cwd: &Path) -> Option<String> {
+    let request = ProcessRequest::new("git")
+        .args(["rev-parse", "--verify", "-q", "HEAD"])
+        .current_dir(cwd.to_path_buf());
+    let out = runner.run(&request).ok()?;
+    if !out.success {
+        return None;
+    }
+    let head = out.stdout.trim();
+    if head.is_empty() {
+        None
+    } else {
+        Some(head.to_string())
+    }
+}
+
+/// Render the recent activity section, or `None` when there is nothing to show.
+pub fn load_git_context(runner: &dyn ProcessRunner, options: &GitContextOptions) -> Option<String> {
+    if options.max_commits == 0 {
+        return None;
+    }
+    let range = match options.since_commit.as_deref() {
+        Some(start) => format!("{start}..HEAD"),
+        None => "HEAD".to_string(),
+    };
+    let max_count = format!("--max-count={}", options.max_commits);
+    let request = ProcessRequest::new("git")
+        .args(["log", "--stat", "--format=%h %s", &max_count, &range])
+        .current_dir(options.cwd.clone());
+    let out = runner.run(&request).ok()?;
+    if !out.success {
+        return None;
+    }
+    let log = out.stdout.trim();
+    if log.is_empty() {
+        return None;
+    }
+
+    let log = cap_chars(log, options.max_chars);
+    Some(format!(
+        "## Recent Repository Activity\n\nCommits made by earlier iterations of this loop (newest first):\n\n```text\n{log}\n```"
+    ))
+}
+
+/// Keep the head of `log`, replacing anything past `max_chars` with a marker.
+fn cap_chars(log: &str, max_chars: usize) -> String {
+    let len = log.chars().count();
+    if len <= max_chars {
+        return log.to_string();
+    }
+    let head: String = log.chars().take(max_chars).collect();
+    let removed = len - max_chars;
+    format!("{head}\n[... truncated {removed} chars of git activity]")
+}
+
+#[cfg(test)]
+#[path = "git_context_tests.rs"]
+mod git_context_tests;
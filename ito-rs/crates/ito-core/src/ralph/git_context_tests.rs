@@ -0,0 +1,139 @@
+use super::*;
+use crate::process::{ProcessExecutionError, ProcessOutput, SystemProcessRunner};
+use std::process::Command;
+
+fn git(cwd: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(["-c", "commit.gpgsign=false"])
+        .args(args)
+        .current_dir(cwd)
+        .env("GIT_AUTHOR_NAME", "Test")
+        .env("GIT_AUTHOR_EMAIL", "test@example.com")
+        .env("GIT_COMMITTER_NAME", "Test")
+        .env("GIT_COMMITTER_EMAIL", "test@example.com")
+        .status()
+        .expect("git should run");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+fn commit_file(cwd: &Path, name: &str, body: &str, message: &str) {
+    std::fs::write(cwd.join(name), body).unwrap();
+    git(cwd, &["add", name]);
+    git(cwd, &["commit", "-q", "-m", message]);
+}
+
+/// Repository with a base commit followed by three iteration commits.
+fn repo_with_three_iterations() -> (tempfile::TempDir, String) {
+    let td = tempfile::tempdir().unwrap();
+    git(td.path(), &["init", "-q"]);
+    commit_file(td.path(), "base.txt", "base\n", "Initial commit");
+    let start = current_head(&SystemProcessRunner, td.path()).expect("head");
+    for i in 1..=3 {
+        commit_file(
+            td.path(),
+            &format!("iter{i}.txt"),
+            &"line\n".repeat(i),
+            &format!("Ralph loop iteration {i}"),
+        );
+    }
+    (td, start)
+}
+
+fn options(cwd: &Path, since: Option<String>) -> GitContextOptions {
+    GitContextOptions {
+        cwd: cwd.to_path_buf(),
+        since_commit: since,
+        max_commits: DEFAULT_GIT_CONTEXT_COMMITS,
+        max_chars: DEFAULT_GIT_CONTEXT_MAX_CHARS,
+    }
+}
+
+struct FailingRunner;
+
+impl ProcessRunner for FailingRunner {
+    fn run(&self, request: &ProcessRequest) -> Result<ProcessOutput, ProcessExecutionError> {
+        Err(ProcessExecutionError::Spawn {
+            program: request.program.clone(),
+            source: std::io::Error::new(std::io::ErrorKind::NotFound, "git not installed"),
+        })
+    }
+
+    fn run_with_timeout(
+        &self,
+        request: &ProcessRequest,
+        _timeout: std::time::Duration,
+    ) -> Result<ProcessOutput, ProcessExecutionError> {
+        self.run(request)
+    }
+}
+
+#[test]
+fn section_lists_commits_since_loop_start_with_diffstat() {
+    let (td, start) = repo_with_three_iterations();
+
+    let section =
+        load_git_context(&SystemProcessRunner, &options(td.path(), Some(start))).expect("section");
+
+    assert!(section.starts_with("## Recent Repository Activity"));
+    assert!(section.contains("Ralph loop iteration 1"));
+    assert!(section.contains("Ralph loop iteration 3"));
+    assert!(section.contains("iter2.txt"));
+    assert!(section.contains("1 file changed"));
+    assert!(!section.contains("Initial commit"));
+    let newest = section.find("iteration 3").unwrap();
+    let oldest = section.find("iteration 1").unwrap();
+    assert!(newest < oldest, "commits should be newest first");
+}
+
+#[test]
+fn section_respects_commit_limit() {
+    let (td, start) = repo_with_three_iterations();
+    let mut opts = options(td.path(), Some(start));
+    opts.max_commits = 1;
+
+    let section = load_git_context(&SystemProcessRunner, &opts).expect("section");
+    assert!(section.contains("Ralph loop iteration 3"));
+    assert!(!section.contains("Ralph loop iteration 2"));
+}
+
+#[test]
+fn section_is_truncated_to_char_budget() {
+    let (td, start) = repo_with_three_iterations();
+    let mut opts = options(td.path(), Some(start));
+    opts.max_chars = 40;
+
+    let section = load_git_context(&SystemProcessRunner, &opts).expect("section");
+    assert!(section.contains("[... truncated "));
+    assert!(section.contains(" chars of git activity]"));
+    assert!(!section.contains("Ralph loop iteration 1"));
+}
+
+#[test]
+fn no_section_when_nothing_was_committed_since_start() {
+    let (td, _) = repo_with_three_iterations();
+    let head = current_head(&SystemProcessRunner, td.path());
+    assert!(load_git_context(&SystemProcessRunner, &options(td.path(), head)).is_none());
+}
+
+#[test]
+fn no_section_for_repo_without_commits() {
+    let td = tempfile::tempdir().unwrap();
+    git(td.path(), &["init", "-q"]);
+    assert!(current_head(&SystemProcessRunner, td.path()).is_none());
+    assert!(load_git_context(&SystemProcessRunner, &options(td.path(), None)).is_none());
+}
+
+#[test]
+fn no_section_when_git_is_unavailable() {
+    let td = tempfile::tempdir().unwrap();
+    assert!(current_head(&FailingRunner, td.path()).is_none());
+    assert!(load_git_context(&FailingRunner, &options(td.path(), None)).is_none());
+}
+
+#[test]
+fn zero_commits_disables_section() {
+    let (td, start) = repo_with_three_iterations();
+    let mut opts = options(td.path(), Some(start));
+    opts.max_commits = 0;
+    assert!(load_git_context(&SystemProcessRunner, &opts).is_none());
+}
@@ -3,9 +3,15 @@
 //! The Ralph loop repeatedly runs a harness with a prompt until a completion
 //! promise is detected, persisting lightweight state between iterations.
 
+/// Completion signal detection (tag and JSON formats).
+pub mod completion;
+
 /// Duration parsing/formatting helpers.
 pub mod duration;
 
+/// Recent git activity summarized into Ralph prompts.
+pub mod git_context;
+
 /// Prompt construction for Ralph iterations.
 pub mod prompt;
 
@@ -23,11 +29,19 @@ pub mod task_sources;
 /// Completion validation for Ralph.
 pub mod validation;
 
+pub use completion::{
+    CompletionDetector, CompletionFormat, CompletionSignal, DEFAULT_COMPLETION_JSON_POINTER,
+    DEFAULT_COMPLETION_TAG, JsonDetector, TagDetector,
+};
 pub use duration::{format_duration, parse_duration};
+pub use git_context::{
+    DEFAULT_GIT_CONTEXT_COMMITS, DEFAULT_GIT_CONTEXT_MAX_CHARS, GitContextOptions,
+};
+pub use prompt::{PromptBudget, PromptSection, PromptTruncation, RalphPrompt};
 pub use readiness::{RalphReadinessGate, ResolvedCwd, run_ralph};
 pub use runner::{
-    DEFAULT_ERROR_THRESHOLD, RalphOptions, WorktreeConfig, resolve_effective_cwd,
-    run_ralph_with_readiness,
+    DEFAULT_ERROR_THRESHOLD, DEFAULT_VALIDATION_MAX_OUTPUT_BYTES, DEFAULT_VALIDATION_TIMEOUT,
+    RalphOptions, WorktreeConfig, resolve_effective_cwd, run_ralph_with_readiness,
 };
 pub use task_sources::{
     RalphTaskSource, resolve_github_task_sources, resolve_markdown_task_sources,
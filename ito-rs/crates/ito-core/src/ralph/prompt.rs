@@ -4,13 +4,23 @@
 //! context (change proposal + module), the user's base prompt, and a fixed
 //! preamble describing the iteration rules.
 
+use crate::audit::read_audit_events;
 use crate::errors::{CoreError, CoreResult};
-use crate::tasks::{get_next_task_from_summary, get_task_status_from_repository};
+use crate::process::{ProcessRunner, SystemProcessRunner};
+use crate::ralph::git_context::{GitContextOptions, load_git_context};
+use crate::stale_tasks::stale_tasks;
+use crate::tasks::{
+    StaleTask, TaskItem, TaskStatus, TaskStatusSummary, get_next_task_from_summary,
+    get_task_status_from_repository,
+};
 use crate::validate;
-use ito_domain::changes::{ChangeRepository as DomainChangeRepository, ChangeTargetResolution};
+use ito_domain::changes::{
+    ChangeRepository as DomainChangeRepository, ChangeTargetResolution, target_match_ids,
+};
 use ito_domain::modules::ModuleRepository as DomainModuleRepository;
 use ito_domain::tasks::TaskRepository as DomainTaskRepository;
 use std::path::Path;
+use std::time::Duration;
 
 use ito_common::paths;
 
@@ -31,6 +41,11 @@ pub struct BuildPromptOptions {
     /// The completion promise token (e.g. `COMPLETE`).
     pub completion_promise: String,
 
+    /// Literal completion signal the harness is told to emit.
+    ///
+    /// Defaults to `<promise>{completion_promise}</promise>` when `None`.
+    pub completion_marker: Option<String>,
+
     /// Optional additional context injected mid-loop.
     pub context_content: Option<String>,
 
@@ -38,17 +53,98 @@ pub struct BuildPromptOptions {
     ///
     /// When present, the prompt includes a section explaining completion was rejected.
     pub validation_failure: Option<String>,
+
+    /// Optional size budget for the assembled prompt.
+    ///
+    /// When the prompt exceeds the budget, lower-priority sections are
+    /// truncated (see [`build_ralph_prompt`]).
+    pub budget: Option<PromptBudget>,
+
+    /// Optional "recent repository activity" section built from `git log`.
+    ///
+    /// `None` disables the section; git failures silently omit it.
+    pub git_context: Option<GitContextOptions>,
+
+    /// Offer in-progress tasks untouched for this long as available work.
+    ///
+    /// `None` leaves in-progress tasks to their current holder.
+    pub steal_stale: Option<Duration>,
+}
+
+/// Approximate number of characters per token used by [`PromptBudget::MaxApproxTokens`].
+pub const APPROX_CHARS_PER_TOKEN: usize = 4;
+
+/// Size budget for an assembled Ralph prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptBudget {
+    /// Maximum prompt length in characters.
+    MaxChars(usize),
+    /// Maximum prompt length in approximate tokens.
+    MaxApproxTokens(usize),
+}
+
+impl PromptBudget {
+    /// Return the budget expressed in characters.
+    pub fn max_chars(self) -> usize {
+        match self {
+            PromptBudget::MaxChars(chars) => chars,
+            PromptBudget::MaxApproxTokens(tokens) => tokens.saturating_mul(APPROX_CHARS_PER_TOKEN),
+        }
+    }
+}
+
+/// A prompt section that may be truncated to fit the budget.
+///
+/// Variants are listed in truncation priority order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptSection {
+    /// User-supplied loop context (`context.md`); truncated from the oldest end.
+    Context,
+    /// Validation failure output; truncated from the middle.
+    ValidationOutput,
+    /// Change proposal; trimmed from the end as a last resort.
+    Proposal,
+}
+
+impl PromptSection {
+    /// Human-readable label for notices.
+    pub fn label(self) -> &'static str {
+        match self {
+            PromptSection::Context => "context",
+            PromptSection::ValidationOutput => "validation output",
+            PromptSection::Proposal => "change proposal",
+        }
+    }
+}
+
+/// Record of a section truncated to fit the prompt budget.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromptTruncation {
+    /// Section that was truncated.
+    pub section: PromptSection,
+    /// Number of characters removed from the section.
+    pub removed_chars: usize,
+}
+
+/// An assembled Ralph prompt together with any budget truncations applied.
+#[derive(Debug, Clone)]
+pub struct RalphPrompt {
+    /// Prompt text sent to the harness.
+    pub text: String,
+    /// Sections truncated to fit the budget, in the order they were applied.
+    pub truncations: Vec<PromptTruncation>,
 }
 
 /// Build the standard Ralph preamble for a given iteration.
 ///
 /// This is the outer wrapper around the task content; it communicates the loop
-/// rules and the completion promise the harness must emit.
+/// rules and the completion marker (e.g. `<promise>COMPLETE</promise>`) the
+/// harness must emit.
 pub fn build_prompt_preamble(
     iteration: u32,
     max_iterations: Option<u32>,
     min_iterations: u32,
-    completion_promise: &str,
+    completion_marker: &str,
     context_content: Option<&str>,
     validation_failure: Option<&str>,
     task: &str,
@@ -81,12 +177,12 @@ pub fn build_prompt_preamble(
     };
 
     format!(
-        "# Ralph Wiggum Loop - Iteration {iteration}\n\nYou are in an iterative development loop. Work on the task below until you can genuinely complete it.\n\nImportant: Ralph validates completion promises before exiting (tasks + project checks/tests).\n{context_section}{validation_section}## Your Task\n\n{task}\n\n## Instructions\n\n1. Read the current state of files to understand what's been done\n2. **Update your todo list** - Use the TodoWrite tool to track progress and plan remaining work\n3. Make progress on the task\n4. Run tests/verification if applicable\n5. When the task is GENUINELY COMPLETE, output:\n   <promise>{completion_promise}</promise>\n\n## Critical Rules\n\n- ONLY output <promise>{completion_promise}</promise> when the task is truly done\n- Do NOT lie or output false promises to exit the loop\n- If stuck, try a different approach\n- Check your work before claiming completion\n- The loop will continue until you succeed\n- **IMPORTANT**: Update your todo list at the start of each iteration to show progress\n\n## AUTONOMY REQUIREMENTS (CRITICAL)\n\n- **DO NOT ASK QUESTIONS** - This is an autonomous loop with no human interaction\n- **DO NOT USE THE QUESTION TOOL** - Work independently without prompting for input\n- Make reasonable assumptions when information is missing\n- Use your best judgment to resolve ambiguities\n- If multiple approaches exist, choose the most reasonable one and proceed\n- The orchestrator cannot respond to questions - you must be self-sufficient\n- Trust your training and make decisions autonomously\n\n## Current Iteration: {iteration}{max_str} (min: {min_iterations})\n\nNow, work on the task autonomously. Good luck!",
+        "# Ralph Wiggum Loop - Iteration {iteration}\n\nYou are in an iterative development loop. Work on the task below until you can genuinely complete it.\n\nImportant: Ralph validates completion promises before exiting (tasks + project checks/tests).\n{context_section}{validation_section}## Your Task\n\n{task}\n\n## Instructions\n\n1. Read the current state of files to understand what's been done\n2. **Update your todo list** - Use the TodoWrite tool to track progress and plan remaining work\n3. Make progress on the task\n4. Run tests/verification if applicable\n5. When the task is GENUINELY COMPLETE, output:\n   {completion_marker}\n\n## Critical Rules\n\n- ONLY output {completion_marker} when the task is truly done\n- Do NOT lie or output false promises to exit the loop\n- If stuck, try a different approach\n- Check your work before claiming completion\n- The loop will continue until you succeed\n- **IMPORTANT**: Update your todo list at the start of each iteration to show progress\n\n## AUTONOMY REQUIREMENTS (CRITICAL)\n\n- **DO NOT ASK QUESTIONS** - This is an autonomous loop with no human interaction\n- **DO NOT USE THE QUESTION TOOL** - Work independently without prompting for input\n- Make reasonable assumptions when information is missing\n- Use your best judgment to resolve ambiguities\n- If multiple approaches exist, choose the most reasonable one and proceed\n- The orchestrator cannot respond to questions - you must be self-sufficient\n- Trust your training and make decisions autonomously\n\n## Current Iteration: {iteration}{max_str} (min: {min_iterations})\n\nNow, work on the task autonomously. Good luck!",
         iteration = iteration,
         context_section = context_section,
         validation_section = validation_section,
         task = task,
-        completion_promise = completion_promise,
+        completion_marker = completion_marker,
         max_str = max_str,
         min_iterations = min_iterations,
     )
@@ -95,6 +191,12 @@ pub fn build_prompt_preamble(
 /// Build a full Ralph prompt with optional change/module context.
 ///
 /// When `options.iteration` is set, this includes the iteration preamble.
+///
+/// When `options.budget` is set and the prompt is over budget, sections are
+/// truncated in priority order: the oldest part of the loop context first,
+/// then the middle of the validation output, and only as a last resort the
+/// tail of the change proposal. The task instructions and completion promise
+/// are never truncated, so the result may still exceed a very small budget.
 pub fn build_ralph_prompt(
     ito_path: &Path,
     change_repo: &(impl DomainChangeRepository + ?Sized),
@@ -102,50 +204,212 @@ pub fn build_ralph_prompt(
     module_repo: &(impl DomainModuleRepository + ?Sized),
     user_prompt: &str,
     options: BuildPromptOptions,
-) -> CoreResult<String> {
-    let mut sections: Vec<String> = Vec::new();
+) -> CoreResult<RalphPrompt> {
+    build_ralph_prompt_with_runner(
+        ito_path,
+        change_repo,
+        task_repo,
+        module_repo,
+        user_prompt,
+        options,
+        &SystemProcessRunner,
+    )
+}
 
-    if let Some(change_id) = options.change_id.as_deref()
-        && let Some(ctx) = load_change_context(ito_path, change_repo, change_id)?
-    {
-        sections.push(ctx);
+/// [`build_ralph_prompt`] with an explicit runner for the git activity section.
+pub fn build_ralph_prompt_with_runner(
+    ito_path: &Path,
+    change_repo: &(impl DomainChangeRepository + ?Sized),
+    task_repo: &(impl DomainTaskRepository + ?Sized),
+    module_repo: &(impl DomainModuleRepository + ?Sized),
+    user_prompt: &str,
+    options: BuildPromptOptions,
+    runner: &dyn ProcessRunner,
+) -> CoreResult<RalphPrompt> {
+    let mut parts = PromptParts::default();
+
+    if let Some(change_id) = options.change_id.as_deref() {
+        parts.proposal = load_change_context(ito_path, change_repo, change_id)?;
     }
 
     if let Some(change_id) = options.change_id.as_deref()
-        && let Some(ctx) = load_task_context(task_repo, change_id)?
+        && let Some(ctx) = load_task_context(ito_path, task_repo, change_id, options.steal_stale)?
     {
-        sections.push(ctx);
+        parts.sections.push(ctx);
     }
 
     if let Some(module_id) = options.module_id.as_deref()
         && let Some(ctx) = load_module_context(ito_path, module_repo, module_id)?
     {
-        sections.push(ctx);
+        parts.sections.push(ctx);
+    }
+
+    if let Some(git_options) = options.git_context.as_ref()
+        && let Some(activity) = load_git_context(runner, git_options)
+    {
+        parts.sections.push(activity);
+    }
+
+    parts.sections.push(user_prompt.to_string());
+
+    // Context and validation output are only rendered inside the preamble.
+    if options.iteration.is_some() {
+        parts.context = non_empty_trimmed(options.context_content.as_deref());
+        parts.validation = non_empty_trimmed(options.validation_failure.as_deref());
     }
 
-    sections.push(user_prompt.to_string());
-    let task = sections.join("\n\n---\n\n");
+    Ok(assemble_prompt(parts, &options))
+}
+
+/// Truncatable pieces of a Ralph prompt, kept separate until budgeting is done.
+#[derive(Debug, Default)]
+struct PromptParts {
+    /// Resolved change id and raw proposal content.
+    proposal: Option<(String, String)>,
+    /// Task status, module and user prompt sections (never truncated).
+    sections: Vec<String>,
+    context: Option<String>,
+    validation: Option<String>,
+}
+
+impl PromptParts {
+    fn render(&self, options: &BuildPromptOptions) -> String {
+        let mut sections: Vec<String> = Vec::new();
+        if let Some((id, proposal)) = &self.proposal {
+            sections.push(format!("## Change Proposal ({id})\n\n{proposal}"));
+        }
+        sections.extend(self.sections.iter().cloned());
+        let task = sections.join("\n\n---\n\n");
 
-    if let Some(iteration) = options.iteration {
-        Ok(build_prompt_preamble(
+        let Some(iteration) = options.iteration else {
+            return task;
+        };
+        let default_marker;
+        let completion_marker = match options.completion_marker.as_deref() {
+            Some(marker) => marker,
+            None => {
+                default_marker = format!("<promise>{}</promise>", options.completion_promise);
+                &default_marker
+            }
+        };
+        build_prompt_preamble(
             iteration,
             options.max_iterations,
             options.min_iterations,
-            &options.completion_promise,
-            options.context_content.as_deref(),
-            options.validation_failure.as_deref(),
+            completion_marker,
+            self.context.as_deref(),
+            self.validation.as_deref(),
             &task,
         )
         .trim()
-        .to_string())
+        .to_string()
+    }
+
+    fn section_mut(&mut self, section: PromptSection) -> Option<&mut String> {
+        match section {
+            PromptSection::Context => self.context.as_mut(),
+            PromptSection::ValidationOutput => self.validation.as_mut(),
+            PromptSection::Proposal => self.proposal.as_mut().map(|(_, proposal)| proposal),
+        }
+    }
+}
+
+fn assemble_prompt(mut parts: PromptParts, options: &BuildPromptOptions) -> RalphPrompt {
+    let mut text = parts.render(options);
+    let mut truncations = Vec::new();
+    let Some(max_chars) = options.budget.map(PromptBudget::max_chars) else {
+        return RalphPrompt { text, truncations };
+    };
+
+    for section in [
+        PromptSection::Context,
+        PromptSection::ValidationOutput,
+        PromptSection::Proposal,
+    ] {
+        let overflow = text.chars().count().saturating_sub(max_chars);
+        if overflow == 0 {
+            break;
+        }
+        let Some(content) = parts.section_mut(section) else {
+            continue;
+        };
+        let Some(removed_chars) = truncate_section(content, section, overflow) else {
+            continue;
+        };
+        truncations.push(PromptTruncation {
+            section,
+            removed_chars,
+        });
+        text = parts.render(options);
+    }
+
+    RalphPrompt { text, truncations }
+}
+
+/// Shrink `content` by at least `overflow` characters (including the marker).
+///
+/// Returns the number of characters removed, or `None` when there is nothing
+/// to remove.
+fn truncate_section(
+    content: &mut String,
+    section: PromptSection,
+    overflow: usize,
+) -> Option<usize> {
+    let len = content.chars().count();
+    if len == 0 {
+        return None;
+    }
+
+    // The marker for `len` is at least as long as any smaller count's marker;
+    // the extra 2 covers the newlines joining it to the kept text.
+    let marker_len = truncation_marker(section, len).chars().count() + 2;
+    let removed = overflow.saturating_add(marker_len).min(len);
+    let kept = len - removed;
+    let marker = truncation_marker(section, removed);
+
+    *content = match section {
+        PromptSection::Context => {
+            let tail: String = content.chars().skip(removed).collect();
+            format!("{marker}\n{tail}")
+        }
+        PromptSection::ValidationOutput => {
+            let head_len = kept / 2;
+            let head: String = content.chars().take(head_len).collect();
+            let tail: String = content.chars().skip(head_len + removed).collect();
+            format!("{head}\n{marker}\n{tail}")
+        }
+        PromptSection::Proposal => {
+            let head: String = content.chars().take(kept).collect();
+            format!("{head}\n{marker}")
+        }
+    };
+    Some(removed)
+}
+
+fn truncation_marker(section: PromptSection, removed: usize) -> String {
+    match section {
+        PromptSection::Context => format!("[... truncated {removed} chars of earlier context]"),
+        PromptSection::ValidationOutput => {
+            format!("[... truncated {removed} chars of validation output ...]")
+        }
+        PromptSection::Proposal => format!("[... truncated {removed} chars of proposal]"),
+    }
+}
+
+fn non_empty_trimmed(value: Option<&str>) -> Option<String> {
+    let trimmed = value.unwrap_or("").trim();
+    if trimmed.is_empty() {
+        None
     } else {
-        Ok(task)
+        Some(trimmed.to_string())
     }
 }
 
 fn load_task_context(
+    ito_path: &Path,
     task_repo: &(impl DomainTaskRepository + ?Sized),
     change_id: &str,
+    steal_stale: Option<Duration>,
 ) -> CoreResult<Option<String>> {
     let summary = match get_task_status_from_repository(task_repo, change_id) {
         Ok(summary) => summary,
@@ -153,19 +417,63 @@ fn load_task_context(
         Err(err) => return Err(err),
     };
 
-    let next_task = get_next_task_from_summary(&summary, "tasks.md")?;
-    let ready_tasks = summary
+    let stale = match steal_stale {
+        Some(threshold) => {
+            let events = read_audit_events(ito_path);
+            let now = crate::time::now().fixed_offset();
+            stale_tasks(&summary.items, &events, change_id, threshold, now)
+        }
+        None => Vec::new(),
+    };
+    render_task_context(change_id, &summary, &stale).map(Some)
+}
+
+/// Render the task status section.
+///
+/// `stale` lists in-progress tasks Ralph may take over; they join the ready
+/// queue after the ready tasks and become the next task when nothing else is
+/// ready.
+fn render_task_context(
+    change_id: &str,
+    summary: &TaskStatusSummary,
+    stale: &[StaleTask],
+) -> CoreResult<String> {
+    let next_task = get_next_task_from_summary(summary, "tasks.md")?;
+    let stale_items: Vec<(&TaskItem, &StaleTask)> = stale
+        .iter()
+        .filter_map(|s| summary.items.iter().find(|t| t.id == s.id).map(|t| (t, s)))
+        .collect();
+    let mut ready_tasks = summary
         .ready
         .iter()
         .take(3)
         .map(|task| format!("- {} {}", task.id, task.name))
         .collect::<Vec<_>>();
-    let next_task_section = match next_task {
-        Some(task) => format!(
+    ready_tasks.extend(stale_items.iter().map(|(task, s)| {
+        format!(
+            "- {} {} (stale in-progress {})",
+            task.id,
+            task.name,
+            s.idle_label()
+        )
+    }));
+    // A stale in-progress task only becomes the next task when nothing
+    // pending is ready; checkbox files report their in-progress task as next.
+    let next_task =
+        next_task.filter(|t| t.status != TaskStatus::InProgress || stale_items.is_empty());
+    let next_task_section = match (next_task, stale_items.first()) {
+        (Some(task), _) => format!(
             "## Next Actionable Task\n\n- Current task: {} {}\n- Action: {}\n",
             task.id, task.name, task.action
         ),
-        None => "## Next Actionable Task\n\n- No ready task found. Finish remaining in-progress work or repair the task file if it is blocked.\n"
+        (None, Some((task, s))) => format!(
+            "## Next Actionable Task\n\n- Current task: {id} {name}\n- Action: {action}\n- This task has been in progress without activity for {idle}; its previous holder is presumed gone. Run `ito tasks release {change_id} {id} --older-than {idle}` before starting it.\n",
+            id = task.id,
+            name = task.name,
+            action = task.action,
+            idle = s.idle_label(),
+        ),
+        (None, None) => "## Next Actionable Task\n\n- No ready task found. Finish remaining in-progress work or repair the task file if it is blocked.\n"
             .to_string(),
     };
     let ready_section = if ready_tasks.is_empty() {
@@ -174,7 +482,7 @@ fn load_task_context(
         format!("\nReady queue:\n{}\n", ready_tasks.join("\n"))
     };
 
-    Ok(Some(format!(
+    Ok(format!(
         "## Change Task Status ({change_id})\n\n- Progress: {complete}/{total} complete, {in_progress} in progress, {pending} pending, {shelved} shelved\n{ready_section}\n{next_task_section}\n## Execution Guidance\n\n- Work the next actionable task first and keep the change tasks/specs aligned with the code.\n- Use `ito tasks` commands when task state needs to change.\n- Before claiming completion, make sure project validation and change task validation will pass.\n",
         change_id = change_id,
         complete = summary.progress.complete,
@@ -184,14 +492,14 @@ fn load_task_context(
         shelved = summary.progress.shelved,
         ready_section = ready_section,
         next_task_section = next_task_section,
-    )))
+    ))
 }
 
 fn load_change_context(
     ito_path: &Path,
     change_repo: &(impl DomainChangeRepository + ?Sized),
     change_id: &str,
-) -> CoreResult<Option<String>> {
+) -> CoreResult<Option<(String, String)>> {
     let changes_dir = paths::changes_dir(ito_path);
     let resolved = resolve_change_id(change_repo, change_id)?;
     let Some(resolved) = resolved else {
@@ -205,11 +513,7 @@ fn load_change_context(
 
     let proposal = ito_common::io::read_to_string_std(&proposal_path)
         .map_err(|e| CoreError::io(format!("reading {}", proposal_path.display()), e))?;
-    Ok(Some(format!(
-        "## Change Proposal ({id})\n\n{proposal}",
-        id = resolved,
-        proposal = proposal
-    )))
+    Ok(Some((resolved, proposal)))
 }
 
 fn resolve_change_id(
@@ -222,7 +526,7 @@ fn resolve_change_id(
         ChangeTargetResolution::Ambiguous(matches) => Err(CoreError::Validation(format!(
             "Ambiguous change id '{input}'. Matches: {matches}",
             input = input,
-            matches = matches.join(", ")
+            matches = target_match_ids(&matches).join(", ")
         ))),
     }
 }
@@ -2,15 +2,31 @@ use super::*;
 
 #[test]
 fn build_prompt_preamble_includes_iteration() {
-    let result = build_prompt_preamble(3, Some(10), 1, "DONE_TOKEN", None, None, "Test task");
+    let result = build_prompt_preamble(
+        3,
+        Some(10),
+        1,
+        "<promise>DONE_TOKEN</promise>",
+        None,
+        None,
+        "Test task",
+    );
     assert!(result.contains("3"));
     assert!(result.contains("10"));
 }
 
 #[test]
 fn build_prompt_preamble_includes_completion_promise() {
-    let result = build_prompt_preamble(1, Some(5), 1, "DONE_TOKEN", None, None, "Test task");
-    assert!(result.contains("DONE_TOKEN"));
+    let result = build_prompt_preamble(
+        1,
+        Some(5),
+        1,
+        "<promise>DONE_TOKEN</promise>",
+        None,
+        None,
+        "Test task",
+    );
+    assert!(result.contains("<promise>DONE_TOKEN</promise>"));
 }
 
 #[test]
@@ -43,12 +59,229 @@ fn build_prompt_preamble_includes_validation_failure() {
 
 #[test]
 fn build_prompt_preamble_omits_context_when_none() {
-    let result = build_prompt_preamble(1, Some(5), 1, "DONE_TOKEN", None, None, "Test task");
+    let result = build_prompt_preamble(
+        1,
+        Some(5),
+        1,
+        "<promise>DONE_TOKEN</promise>",
+        None,
+        None,
+        "Test task",
+    );
     assert!(!result.contains("Additional Context"));
 }
 
 #[test]
 fn build_prompt_preamble_omits_validation_when_none() {
-    let result = build_prompt_preamble(1, Some(5), 1, "DONE_TOKEN", None, None, "Test task");
+    let result = build_prompt_preamble(
+        1,
+        Some(5),
+        1,
+        "<promise>DONE_TOKEN</promise>",
+        None,
+        None,
+        "Test task",
+    );
     assert!(!result.contains("Validation Failure"));
 }
+
+fn budget_options(budget: Option<PromptBudget>) -> BuildPromptOptions {
+    BuildPromptOptions {
+        change_id: None,
+        module_id: None,
+        iteration: Some(2),
+        max_iterations: Some(5),
+        min_iterations: 1,
+        completion_promise: "DONE_TOKEN".to_string(),
+        completion_marker: None,
+        context_content: None,
+        validation_failure: None,
+        budget,
+        git_context: None,
+        steal_stale: None,
+    }
+}
+
+fn oversized_parts() -> PromptParts {
+    PromptParts {
+        proposal: Some((
+            "001-01_demo".to_string(),
+            format!("PROPOSAL_START {} PROPOSAL_END", "p".repeat(2_000)),
+        )),
+        sections: vec!["Implement the demo feature.".to_string()],
+        context: Some(format!("OLDEST_NOTE {} NEWEST_NOTE", "c".repeat(4_000))),
+        validation: Some(format!(
+            "VALIDATION_HEAD {} VALIDATION_TAIL",
+            "v".repeat(4_000)
+        )),
+    }
+}
+
+fn unbudgeted_len() -> usize {
+    assemble_prompt(oversized_parts(), &budget_options(None))
+        .text
+        .chars()
+        .count()
+}
+
+#[test]
+fn build_prompt_without_budget_is_untouched() {
+    let prompt = assemble_prompt(oversized_parts(), &budget_options(None));
+    assert!(prompt.truncations.is_empty());
+    assert!(prompt.text.contains("OLDEST_NOTE"));
+    assert!(prompt.text.contains("VALIDATION_HEAD"));
+    assert!(prompt.text.contains("PROPOSAL_END"));
+}
+
+#[test]
+fn build_prompt_truncates_oldest_context_first() {
+    let max_chars = unbudgeted_len() - 1_000;
+    let prompt = assemble_prompt(
+        oversized_parts(),
+        &budget_options(Some(PromptBudget::MaxChars(max_chars))),
+    );
+
+    assert!(prompt.text.chars().count() <= max_chars);
+    assert_eq!(prompt.truncations.len(), 1);
+    assert_eq!(prompt.truncations[0].section, PromptSection::Context);
+    let marker = format!(
+        "[... truncated {} chars of earlier context]",
+        prompt.truncations[0].removed_chars
+    );
+    assert!(prompt.text.contains(&marker));
+    assert!(!prompt.text.contains("OLDEST_NOTE"));
+    assert!(prompt.text.contains("NEWEST_NOTE"));
+    assert!(prompt.text.contains("VALIDATION_HEAD"));
+    assert!(prompt.text.contains("VALIDATION_TAIL"));
+    assert!(prompt.text.contains("PROPOSAL_END"));
+}
+
+#[test]
+fn build_prompt_truncates_validation_middle_after_context() {
+    let max_chars = unbudgeted_len() - 6_000;
+    let prompt = assemble_prompt(
+        oversized_parts(),
+        &budget_options(Some(PromptBudget::MaxChars(max_chars))),
+    );
+
+    assert!(prompt.text.chars().count() <= max_chars);
+    let sections: Vec<_> = prompt.truncations.iter().map(|t| t.section).collect();
+    assert_eq!(
+        sections,
+        vec![PromptSection::Context, PromptSection::ValidationOutput]
+    );
+    assert!(prompt.text.contains("chars of earlier context]"));
+    assert!(prompt.text.contains("chars of validation output ...]"));
+    assert!(prompt.text.contains("VALIDATION_HEAD"));
+    assert!(prompt.text.contains("VALIDATION_TAIL"));
+    assert!(prompt.text.contains("PROPOSAL_START"));
+    assert!(prompt.text.contains("PROPOSAL_END"));
+}
+
+#[test]
+fn build_prompt_trims_proposal_only_as_last_resort() {
+    let max_chars = unbudgeted_len() - 9_000;
+    let prompt = assemble_prompt(
+        oversized_parts(),
+        &budget_options(Some(PromptBudget::MaxChars(max_chars))),
+    );
+
+    assert!(prompt.text.chars().count() <= max_chars);
+    let sections: Vec<_> = prompt.truncations.iter().map(|t| t.section).collect();
+    assert_eq!(
+        sections,
+        vec![
+            PromptSection::Context,
+            PromptSection::ValidationOutput,
+            PromptSection::Proposal
+        ]
+    );
+    assert!(prompt.text.contains("PROPOSAL_START"));
+    assert!(!prompt.text.contains("PROPOSAL_END"));
+    assert!(prompt.text.contains("chars of proposal]"));
+}
+
+#[test]
+fn build_prompt_never_truncates_task_or_completion_promise() {
+    let prompt = assemble_prompt(
+        oversized_parts(),
+        &budget_options(Some(PromptBudget::MaxChars(10))),
+    );
+
+    assert_eq!(prompt.truncations.len(), 3);
+    assert!(prompt.text.contains("Implement the demo feature."));
+    assert!(prompt.text.contains("<promise>DONE_TOKEN</promise>"));
+    assert!(prompt.text.contains("## Instructions"));
+}
+
+#[test]
+fn prompt_budget_converts_approx_tokens_to_chars() {
+    assert_eq!(PromptBudget::MaxChars(100).max_chars(), 100);
+    assert_eq!(
+        PromptBudget::MaxApproxTokens(100).max_chars(),
+        100 * APPROX_CHARS_PER_TOKEN
+    );
+}
+
+#[test]
+fn completion_marker_overrides_default_promise_tag() {
+    let mut options = budget_options(None);
+    options.completion_marker = Some(r#"{"status":"DONE_TOKEN"}"#.to_string());
+    let prompt = assemble_prompt(oversized_parts(), &options);
+
+    assert!(prompt.text.contains(
+        r#"output:
+   {"status":"DONE_TOKEN"}"#
+    ));
+    assert!(!prompt.text.contains("<promise>"));
+}
+
+const STUCK_TASKS: &str = "## Wave 1\n\n- **Depends On**: None\n\n### Task 1.1: Stuck\n- **Action**: finish the parser\n- **Updated At**: 2026-03-01\n- **Status**: [>] in-progress\n\n## Wave 2\n\n- **Depends On**: Wave 1\n\n### Task 2.1: Later\n- **Action**: write docs\n- **Updated At**: 2026-03-01\n- **Status**: [ ] pending\n";
+
+fn stuck_summary() -> TaskStatusSummary {
+    let parsed = crate::tasks::parse_tasks_tracking_file(STUCK_TASKS);
+    let (ready, blocked) = crate::tasks::compute_ready_and_blocked(&parsed);
+    TaskStatusSummary {
+        format: parsed.format,
+        items: parsed.tasks,
+        progress: parsed.progress,
+        diagnostics: parsed.diagnostics,
+        ready,
+        blocked,
+    }
+}
+
+#[test]
+fn task_context_leaves_in_progress_work_to_its_holder_by_default() {
+    let summary = stuck_summary();
+
+    let context = render_task_context("001-01_demo", &summary, &[]).unwrap();
+
+    assert!(!context.contains("stale"), "{context}");
+    assert!(context.contains("No ready task found"), "{context}");
+}
+
+#[test]
+fn task_context_offers_stale_in_progress_tasks_when_stealing() {
+    let summary = stuck_summary();
+    let now = chrono::DateTime::parse_from_rfc3339("2026-03-05T12:00:00Z").unwrap();
+    let threshold = Duration::from_secs(24 * 3_600);
+    let stale = crate::tasks::stale_tasks_in(
+        &summary.items,
+        &crate::tasks::TaskActivity::new(),
+        threshold,
+        now,
+    );
+
+    let context = render_task_context("001-01_demo", &summary, &stale).unwrap();
+
+    assert!(
+        context.contains("- 1.1 Stuck (stale in-progress 3d)"),
+        "{context}"
+    );
+    assert!(context.contains("Current task: 1.1 Stuck"), "{context}");
+    assert!(
+        context.contains("ito tasks release 001-01_demo 1.1 --older-than 3d"),
+        "{context}"
+    );
+}
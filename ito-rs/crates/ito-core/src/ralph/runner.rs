@@ -1,21 +1,28 @@
+use crate::audit::AgentAttribution;
 use crate::error_bridge::IntoCoreResult;
 use crate::errors::{CoreError, CoreResult};
+use crate::harness::budget::{ContextWindows, PromptEstimate, check_prompt_budget};
+use crate::harness::transcript::transcript_path;
 use crate::harness::types::MAX_RETRIABLE_RETRIES;
-use crate::harness::{Harness, HarnessName};
+use crate::harness::{CancellationToken, Harness, HarnessEnvPolicy, HarnessName, PermissionPolicy};
 use crate::process::{ProcessRequest, ProcessRunner, SystemProcessRunner};
+use crate::ralph::completion::{CompletionDetector, CompletionFormat, JsonDetector, TagDetector};
 use crate::ralph::duration::format_duration;
-use crate::ralph::prompt::{BuildPromptOptions, build_ralph_prompt};
+use crate::ralph::git_context::{GitContextOptions, current_head};
+use crate::ralph::prompt::{BuildPromptOptions, PromptBudget, build_ralph_prompt_with_runner};
 use crate::ralph::readiness::{RalphReadinessGate, ResolvedCwd};
 use crate::ralph::state::{
     RalphHistoryEntry, RalphState, append_context, clear_context, load_context, load_state,
     save_state,
 };
 use crate::ralph::validation;
+use crate::stats::cost::{HarnessUsageRecord, record_harness_usage};
+use crate::stats::resources::{ProcessUsageRecord, ResourceRecord, record_process_usage};
 use crate::task_repository::FsTaskRepository;
 use crate::tasks::{get_next_task_from_summary, get_task_status_from_repository};
 use ito_domain::changes::{
     ChangeRepository as DomainChangeRepository, ChangeSummary, ChangeTargetResolution,
-    ChangeWorkStatus,
+    ChangeWorkStatus, target_match_ids,
 };
 use ito_domain::modules::ModuleRepository as DomainModuleRepository;
 use ito_domain::tasks::TaskRepository as DomainTaskRepository;
@@ -60,6 +67,17 @@ pub struct RalphOptions {
     /// Completion token that signals the loop is done (e.g. `COMPLETE`).
     pub completion_promise: String,
 
+    /// How the harness signals completion: a tag marker or a JSON payload.
+    pub completion_format: CompletionFormat,
+
+    /// Tag name wrapping the token in [`CompletionFormat::Tag`] (default `promise`).
+    pub completion_tag: String,
+
+    /// JSON pointer to the status field in [`CompletionFormat::Json`].
+    ///
+    /// `None` uses `/status`; the sibling `summary` field is recorded in history.
+    pub completion_json_pointer: Option<String>,
+
     /// Auto-approve all harness prompts and actions.
     pub allow_all: bool,
 
@@ -89,9 +107,25 @@ pub struct RalphOptions {
     /// Eligible changes are those whose derived work status is `Ready` or `InProgress`.
     pub continue_ready: bool,
 
+    /// Offer in-progress tasks untouched for this long as available work.
+    ///
+    /// Lets a loop pick up tasks left behind by a crashed agent. `None` leaves
+    /// in-progress tasks to their current holder.
+    pub steal_stale: Option<Duration>,
+
     /// Inactivity timeout - restart iteration if no output for this duration.
     pub inactivity_timeout: Option<Duration>,
 
+    /// Resume the previous harness session after crashes and inactivity restarts.
+    ///
+    /// Normal new iterations always start a fresh session. Only harnesses that
+    /// report session ids (currently OpenCode) can resume.
+    pub resume_sessions: bool,
+
+    /// Run [`Harness::health_check`] before the first iteration and fail fast
+    /// when the harness cannot run.
+    pub preflight: bool,
+
     /// Skip all completion validation.
     ///
     /// When set, the loop trusts the completion promise and exits immediately.
@@ -99,9 +133,56 @@ pub struct RalphOptions {
 
     /// Additional validation command to run when a completion promise is detected.
     ///
-    /// This runs after the project validation steps.
+    /// It runs as one more step alongside the project validation steps.
     pub validation_command: Option<String>,
 
+    /// Timeout applied to each completion validation command that does not
+    /// configure its own.
+    ///
+    /// On timeout the command's whole process group is killed.
+    pub validation_timeout: Duration,
+
+    /// Cap on captured bytes per stream for each completion validation command.
+    ///
+    /// Output beyond the cap is dropped from the head so the failure details at
+    /// the end survive into the next iteration's prompt. `None` disables the cap.
+    pub validation_max_output_bytes: Option<usize>,
+
+    /// Optional size budget for each iteration's prompt.
+    ///
+    /// When exceeded, loop context, validation output and (last) the change
+    /// proposal are truncated and a notice is printed. `None` disables the budget.
+    pub prompt_budget: Option<PromptBudget>,
+
+    /// Context windows of known models, keyed by model id.
+    ///
+    /// Each iteration's prompt is estimated and compared with the window of
+    /// [`RalphOptions::model`]; models missing here are not checked.
+    pub context_windows: ContextWindows,
+
+    /// Fail instead of warning when a prompt likely exceeds the model's
+    /// context window.
+    pub strict_budget: bool,
+
+    /// Number of recent loop commits summarized in each prompt.
+    ///
+    /// `None` disables the "recent repository activity" section.
+    pub git_context_commits: Option<usize>,
+
+    /// Character cap for the "recent repository activity" section.
+    pub git_context_max_chars: usize,
+
+    /// Environment policy applied to each harness subprocess.
+    pub env_policy: HarnessEnvPolicy,
+
+    /// Tool permissions for each harness run when `allow_all` is off.
+    pub permissions: Option<PermissionPolicy>,
+
+    /// Record a markdown transcript of each harness run.
+    ///
+    /// Transcripts are written to `<itoDir>/.state/transcripts/<change-id>/<iteration>.md`.
+    pub transcripts: bool,
+
     /// Exit immediately when the harness process returns non-zero.
     ///
     /// When false, Ralph captures the failure output and continues iterating.
@@ -114,11 +195,31 @@ pub struct RalphOptions {
 
     /// Worktree configuration for working directory resolution.
     pub worktree: WorktreeConfig,
+
+    /// Stops the loop when cancelled.
+    ///
+    /// An in-flight harness run is terminated, the partial iteration is recorded
+    /// in state and history without committing, and the run fails with an error
+    /// naming the cancellation (see [`RalphOptions::is_cancelled`]).
+    pub cancel: Option<CancellationToken>,
+}
+
+impl RalphOptions {
+    /// Whether [`RalphOptions::cancel`] has been triggered.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.as_ref().is_some_and(|c| c.is_cancelled())
+    }
 }
 
 /// Default maximum number of non-zero harness exits Ralph tolerates.
 pub const DEFAULT_ERROR_THRESHOLD: u32 = 10;
 
+/// Default timeout for each completion validation command.
+pub const DEFAULT_VALIDATION_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Default cap on captured validation output per stream (64 KiB).
+pub const DEFAULT_VALIDATION_MAX_OUTPUT_BYTES: usize = 64 * 1024;
+
 /// Resolve the effective working directory for a Ralph invocation.
 ///
 /// When worktrees are enabled and a matching worktree exists for
@@ -186,11 +287,16 @@ pub fn run_ralph_with_readiness(
     change_repo: &(impl DomainChangeRepository + ?Sized),
     task_repo: &dyn DomainTaskRepository,
     module_repo: &(impl DomainModuleRepository + ?Sized),
-    opts: RalphOptions,
+    mut opts: RalphOptions,
     harness: &mut dyn Harness,
     readiness: &dyn RalphReadinessGate,
 ) -> CoreResult<()> {
     let process_runner = SystemProcessRunner;
+    if opts.preflight && !opts.status && opts.add_context.is_none() && !opts.clear_context {
+        preflight_harness(harness, opts.model.as_deref())?;
+        // Queue modes recurse per change; one check up front is enough.
+        opts.preflight = false;
+    }
     if opts.continue_ready {
         if opts.continue_module {
             return Err(CoreError::Validation(
@@ -222,7 +328,7 @@ pub fn run_ralph_with_readiness(
                 if eligible_all.is_empty() {
                     let incomplete = repo_incomplete_change_ids(&current_changes);
                     if incomplete.is_empty() {
-                        println!("\nAll changes are complete.");
+                        ito_config::info!("\nAll changes are complete.");
                         return finalize_queue_results("Repository", &succeeded, &failed);
                     }
 
@@ -232,7 +338,7 @@ pub fn run_ralph_with_readiness(
                     )));
                 }
 
-                println!(
+                ito_config::info!(
                     "\nRepository has no additional eligible changes (all eligible changes were already processed in this run)."
                 );
                 return finalize_queue_results("Repository", &succeeded, &failed);
@@ -246,7 +352,7 @@ pub fn run_ralph_with_readiness(
             if preflight_eligible.is_empty() {
                 let incomplete = repo_incomplete_change_ids(&preflight_changes);
                 if incomplete.is_empty() {
-                    println!("\nAll changes are complete.");
+                    ito_config::info!("\nAll changes are complete.");
                     return finalize_queue_results("Repository", &succeeded, &failed);
                 }
                 return Err(CoreError::Validation(format!(
@@ -256,7 +362,7 @@ pub fn run_ralph_with_readiness(
             }
             let preflight_first = preflight_eligible[0].clone();
             if preflight_first != next_change {
-                println!(
+                ito_config::info!(
                     "\nRepository state shifted before start; reorienting from {from} to {to}.",
                     from = next_change,
                     to = preflight_first
@@ -264,7 +370,7 @@ pub fn run_ralph_with_readiness(
                 next_change = preflight_first;
             }
 
-            println!(
+            ito_config::info!(
                 "\nStarting change {change} (lowest eligible change id).",
                 change = next_change
             );
@@ -284,10 +390,13 @@ pub fn run_ralph_with_readiness(
             );
 
             processed.insert(next_change.clone());
+            if opts.is_cancelled() {
+                return result;
+            }
             match result {
                 Ok(()) => succeeded.push(next_change),
                 Err(err) => {
-                    println!(
+                    ito_config::info!(
                         "\nChange {change} failed during continue-ready sweep: {err}\n",
                         change = next_change,
                         err = err
@@ -333,7 +442,7 @@ pub fn run_ralph_with_readiness(
                     let incomplete = module_incomplete_change_ids(&current_changes);
 
                     if incomplete.is_empty() {
-                        println!("\nModule {module} is complete.", module = module_id);
+                        ito_config::info!("\nModule {module} is complete.", module = module_id);
                         return finalize_queue_results(
                             &format!("Module {module_id}"),
                             &succeeded,
@@ -350,7 +459,7 @@ pub fn run_ralph_with_readiness(
 
                 // All ready changes were already processed in this run. Exit cleanly so callers
                 // can re-run the loop after merging/refreshing state.
-                println!(
+                ito_config::info!(
                     "\nModule {module} has no additional ready changes (all ready changes were already processed in this run).",
                     module = module_id
                 );
@@ -364,7 +473,7 @@ pub fn run_ralph_with_readiness(
             if preflight_ready_all.is_empty() {
                 let incomplete = module_incomplete_change_ids(&preflight_changes);
                 if incomplete.is_empty() {
-                    println!("\nModule {module} is complete.", module = module_id);
+                    ito_config::info!("\nModule {module} is complete.", module = module_id);
                     return finalize_queue_results(
                         &format!("Module {module_id}"),
                         &succeeded,
@@ -381,7 +490,7 @@ pub fn run_ralph_with_readiness(
             let preflight_ready = unprocessed_change_ids(&preflight_ready_all, &processed);
 
             if preflight_ready.is_empty() {
-                println!(
+                ito_config::info!(
                     "\nModule {module} has no additional ready changes (all ready changes were already processed in this run).",
                     module = module_id
                 );
@@ -390,7 +499,7 @@ pub fn run_ralph_with_readiness(
 
             let preflight_first = preflight_ready[0].clone();
             if preflight_first != next_change {
-                println!(
+                ito_config::info!(
                     "\nModule state shifted before start; reorienting from {from} to {to}.",
                     from = next_change,
                     to = preflight_first
@@ -398,7 +507,7 @@ pub fn run_ralph_with_readiness(
                 next_change = preflight_first;
             }
 
-            println!(
+            ito_config::info!(
                 "\nStarting module change {change} (lowest ready change id).",
                 change = next_change
             );
@@ -420,10 +529,13 @@ pub fn run_ralph_with_readiness(
 
             // Avoid re-processing the same ready change repeatedly within the same `--continue-module` run.
             processed.insert(next_change.clone());
+            if opts.is_cancelled() {
+                return result;
+            }
             match result {
                 Ok(()) => succeeded.push(next_change.clone()),
                 Err(err) => {
-                    println!(
+                    ito_config::info!(
                         "\nModule change {change} failed during continue-module sweep: {err}\n",
                         change = next_change,
                         err = err
@@ -449,6 +561,7 @@ pub fn run_ralph_with_readiness(
         print_ready_changes(module_id, &ready_changes);
     }
 
+    let detector = completion_detector(&opts);
     let unscoped_target = opts.change_id.is_none() && opts.module_id.is_none();
 
     let (change_id, module_id) = if unscoped_target {
@@ -456,8 +569,8 @@ pub fn run_ralph_with_readiness(
     } else {
         resolve_target(
             change_repo,
-            opts.change_id,
-            opts.module_id,
+            opts.change_id.clone(),
+            opts.module_id.clone(),
             opts.interactive,
         )?
     };
@@ -492,7 +605,7 @@ pub fn run_ralph_with_readiness(
     if opts.status {
         let state = load_state(effective_ito_path, &change_id)?;
         if let Some(state) = state {
-            println!("\n=== Ralph Status for {id} ===\n", id = state.change_id);
+            ito_config::info!("\n=== Ralph Status for {id} ===\n", id = state.change_id);
             println!("Iteration: {iter}", iter = state.iteration);
             println!("History entries: {n}", n = state.history.len());
             if let Some(outcome) = state.last_outcome.as_deref() {
@@ -551,7 +664,7 @@ pub fn run_ralph_with_readiness(
                 }
             }
         } else {
-            println!("\n=== Ralph Status for {id} ===\n", id = change_id);
+            ito_config::info!("\n=== Ralph Status for {id} ===\n", id = change_id);
             println!("No state found");
         }
         return Ok(());
@@ -559,12 +672,12 @@ pub fn run_ralph_with_readiness(
 
     if let Some(text) = opts.add_context.as_deref() {
         append_context(effective_ito_path, &change_id, text)?;
-        println!("Added context to {id}", id = change_id);
+        ito_config::info!("Added context to {id}", id = change_id);
         return Ok(());
     }
     if opts.clear_context {
         clear_context(effective_ito_path, &change_id)?;
-        println!("Cleared Ralph context for {id}", id = change_id);
+        ito_config::info!("Cleared Ralph context for {id}", id = change_id);
         return Ok(());
     }
 
@@ -585,7 +698,12 @@ pub fn run_ralph_with_readiness(
         context_file,
         last_outcome: None,
         last_failure: None,
+        harness_session_id: None,
+        start_commit: None,
     });
+    if state.start_commit.is_none() {
+        state.start_commit = current_head(&process_runner, &resolved_cwd.path);
+    }
 
     let max_iters = opts.max_iterations.unwrap_or(u32::MAX);
     if max_iters == 0 {
@@ -600,33 +718,39 @@ pub fn run_ralph_with_readiness(
     }
 
     // Print startup message so user knows something is happening
-    println!(
+    ito_config::info!(
         "\n=== Starting Ralph for {change} (harness: {harness}) ===",
         change = change_id,
         harness = harness.name()
     );
     if let Some(model) = &opts.model {
-        println!("Model: {model}");
+        ito_config::info!("Model: {model}");
     }
     if let Some(max) = opts.max_iterations {
-        println!("Max iterations: {max}");
+        ito_config::info!("Max iterations: {max}");
     }
     if opts.allow_all {
-        println!("Mode: --yolo (auto-approve all)");
+        ito_config::info!("Mode: --yolo (auto-approve all)");
     }
     if let Some(timeout) = opts.inactivity_timeout {
-        println!("Inactivity timeout: {}", format_duration(timeout));
+        ito_config::info!("Inactivity timeout: {}", format_duration(timeout));
     }
-    println!();
+    ito_config::info!();
 
     let mut last_validation_failure: Option<String> = None;
     let mut harness_error_count: u32 = 0;
     let mut retriable_retry_count: u32 = 0;
+    let mut resume_session: Option<String> = None;
 
     for _ in 0..max_iters {
         let iteration = state.iteration.saturating_add(1);
+        if opts.is_cancelled() {
+            state.last_outcome = Some("cancelled".to_string());
+            save_state(effective_ito_path, &change_id, &state)?;
+            return Err(cancelled_error(&change_id, &state, 0));
+        }
 
-        println!("\n=== Ralph Loop Iteration {i} ===\n", i = iteration);
+        ito_config::info!("\n=== Ralph Loop Iteration {i} ===\n", i = iteration);
 
         let context_content = load_context(effective_ito_path, &change_id)?;
         let change_id_opt = if unscoped_target {
@@ -646,7 +770,7 @@ pub fn run_ralph_with_readiness(
             } else {
                 task_repo
             };
-        let prompt = build_ralph_prompt(
+        let prompt = build_ralph_prompt_with_runner(
             effective_ito_path,
             change_repo,
             task_repo_for_prompt,
@@ -667,30 +791,116 @@ pub fn run_ralph_with_readiness(
                 max_iterations: opts.max_iterations,
                 min_iterations: opts.min_iterations,
                 completion_promise: opts.completion_promise.clone(),
+                completion_marker: Some(detector.marker()),
                 context_content: Some(context_content),
                 validation_failure: last_validation_failure.clone(),
+                budget: opts.prompt_budget,
+                steal_stale: opts.steal_stale,
+                git_context: opts
+                    .git_context_commits
+                    .map(|max_commits| GitContextOptions {
+                        cwd: resolved_cwd.path.clone(),
+                        since_commit: state.start_commit.clone(),
+                        max_commits,
+                        max_chars: opts.git_context_max_chars,
+                    }),
             },
+            &process_runner,
         )?;
 
+        if !prompt.truncations.is_empty() {
+            let summary = prompt
+                .truncations
+                .iter()
+                .map(|t| format!("{} ({} chars)", t.section.label(), t.removed_chars))
+                .collect::<Vec<_>>()
+                .join(", ");
+            ito_config::info!("Prompt exceeded its budget; truncated {summary}");
+        }
+        let prompt = prompt.text;
+        let prompt_estimate =
+            PromptEstimate::new(&prompt, opts.model.as_deref(), &opts.context_windows);
+        check_prompt_budget(&prompt_estimate, opts.model.as_deref(), opts.strict_budget)?;
+
         if opts.verbose {
             println!("--- Prompt sent to harness ---");
             println!("{}", prompt);
             println!("--- End of prompt ---\n");
         }
 
+        let resuming = resume_session.take();
+        if let Some(session_id) = &resuming {
+            ito_config::info!("Resuming harness session {session_id}");
+        }
+
         let started = std::time::Instant::now();
         let run = harness
             .run(&crate::harness::HarnessRunConfig {
                 prompt,
                 model: opts.model.clone(),
                 cwd: resolved_cwd.path.clone(),
-                env: std::collections::BTreeMap::new(),
+                env: AgentAttribution {
+                    harness: harness.name().as_str().to_string(),
+                    model: opts.model.clone(),
+                    iteration: Some(iteration),
+                }
+                .to_env()
+                .into_iter()
+                .collect(),
+                env_policy: opts.env_policy.clone(),
                 interactive: opts.interactive && !opts.allow_all,
                 allow_all: opts.allow_all,
                 inactivity_timeout: opts.inactivity_timeout,
+                resume_session: resuming.clone(),
+                transcript_path: opts
+                    .transcripts
+                    .then(|| transcript_path(ito_path, &change_id, iteration)),
+                permissions: opts.permissions.clone(),
+                cancel: opts.cancel.clone(),
+                prompt_estimate: Some(prompt_estimate),
             })
             .map_err(|e| CoreError::Process(format!("Harness execution failed: {e}")))?;
 
+        // Usage stats are best-effort: a failed append must not stop the loop.
+        let _ = record_harness_usage(
+            ito_path,
+            &HarnessUsageRecord {
+                timestamp: now_ms()?,
+                source: "ralph".to_string(),
+                change_id: Some(change_id.clone()),
+                harness: harness.name().as_str().to_string(),
+                model: opts.model.clone(),
+                usage: run.usage,
+                resources: Some(ResourceRecord::from(&run.resources)),
+                prompt_tokens_estimate: Some(prompt_estimate.tokens),
+                context_window: prompt_estimate.context_window,
+            },
+        );
+        ito_config::detail!("Harness resources: {}", run.resources.summary());
+
+        if let Some(session_id) = &run.session_id
+            && state.harness_session_id.as_ref() != Some(session_id)
+        {
+            state.harness_session_id = Some(session_id.clone());
+            save_state(effective_ito_path, &change_id, &state)?;
+        }
+
+        // A resumed run that fails without reporting a session could not pick the
+        // session up (e.g. it expired): forget it and retry with a fresh session.
+        if let Some(expired) = &resuming
+            && run.exit_code != 0
+            && !run.timed_out
+            && !run.cancelled
+            && run.session_id.is_none()
+        {
+            state.harness_session_id = None;
+            save_state(effective_ito_path, &change_id, &state)?;
+            ito_config::info!(
+                "\n=== Could not resume harness session {expired}. Starting a new session... ===\n"
+            );
+            continue;
+        }
+
         // Pass through output if harness didn't already stream it
         if !harness.streams_output() {
             if !run.stdout.is_empty() {
@@ -701,8 +911,15 @@ pub fn run_ralph_with_readiness(
             }
         }
 
-        // Mirror TS: completion promise is detected from stdout (not stderr).
-        let completion_found = completion_promise_found(&run.stdout, &opts.completion_promise);
+        // Completion is detected from the harness's final message when it
+        // reports one, then from stdout (never stderr).
+        let completion = run
+            .final_message
+            .as_deref()
+            .and_then(|message| detector.detect(message))
+            .or_else(|| detector.detect(&run.stdout));
+        let completion_found = completion.is_some();
+        let completion_summary = completion.and_then(|signal| signal.summary);
 
         let file_changes_count = if harness.name() != HarnessName::Stub {
             count_git_changes(&process_runner, &resolved_cwd.path)? as u32
@@ -710,12 +927,35 @@ pub fn run_ralph_with_readiness(
             0
         };
 
+        // Cancelled mid-run: record the partial iteration, never commit it.
+        if run.cancelled {
+            state.history.push(RalphHistoryEntry {
+                timestamp: now_ms()?,
+                duration: started.elapsed().as_millis() as i64,
+                completion_promise_found: completion_found,
+                file_changes_count,
+                harness_exit_code: run.exit_code,
+                completion_validated: false,
+                effective_cwd: resolved_cwd.path.display().to_string(),
+                completion_summary: completion_summary.clone(),
+                usage: run.usage,
+            });
+            state.iteration = iteration;
+            state.last_outcome = Some("cancelled".to_string());
+            state.last_failure = Some(format!("Iteration {iteration} was cancelled"));
+            save_state(effective_ito_path, &change_id, &state)?;
+            return Err(cancelled_error(&change_id, &state, file_changes_count));
+        }
+
         // Handle timeout - log and continue to next iteration
         if run.timed_out {
             state.last_outcome = Some("timed-out".to_string());
             state.last_failure = Some("Harness run timed out due to inactivity".to_string());
-            println!("\n=== Inactivity timeout reached. Restarting iteration... ===\n");
+            ito_config::info!("\n=== Inactivity timeout reached. Restarting iteration... ===\n");
             retriable_retry_count = 0;
+            if opts.resume_sessions {
+                resume_session = run.session_id.clone().or(resuming);
+            }
             // Don't update state for timed out iterations, just retry
             continue;
         }
@@ -731,12 +971,15 @@ pub fn run_ralph_with_readiness(
                         code = run.exit_code
                     )));
                 }
-                println!(
+                ito_config::info!(
                     "\n=== Harness process crashed (exit code {code}, attempt {count}/{max}). Retrying... ===\n",
                     code = run.exit_code,
                     count = retriable_retry_count,
                     max = MAX_RETRIABLE_RETRIES
                 );
+                if opts.resume_sessions {
+                    resume_session = run.session_id.clone().or(resuming);
+                }
                 continue;
             }
 
@@ -759,6 +1002,8 @@ pub fn run_ralph_with_readiness(
                     harness_exit_code: run.exit_code,
                     completion_validated: false,
                     effective_cwd: resolved_cwd.path.display().to_string(),
+                    completion_summary: completion_summary.clone(),
+                    usage: run.usage,
                 });
                 state.iteration = iteration;
                 save_state(effective_ito_path, &change_id, &state)?;
@@ -786,6 +1031,8 @@ pub fn run_ralph_with_readiness(
                     harness_exit_code: run.exit_code,
                     completion_validated: false,
                     effective_cwd: resolved_cwd.path.display().to_string(),
+                    completion_summary: completion_summary.clone(),
+                    usage: run.usage,
                 });
                 state.iteration = iteration;
                 save_state(effective_ito_path, &change_id, &state)?;
@@ -814,10 +1061,12 @@ pub fn run_ralph_with_readiness(
                 harness_exit_code: run.exit_code,
                 completion_validated: false,
                 effective_cwd: resolved_cwd.path.display().to_string(),
+                completion_summary: completion_summary.clone(),
+                usage: run.usage,
             });
             state.iteration = iteration;
             save_state(effective_ito_path, &change_id, &state)?;
-            println!(
+            ito_config::info!(
                 "\n=== Harness exited with code {code} ({count}/{threshold}). Continuing to let Ralph fix it... ===\n",
                 code = run.exit_code,
                 count = harness_error_count,
@@ -833,7 +1082,7 @@ pub fn run_ralph_with_readiness(
             if file_changes_count > 0 {
                 commit_iteration(&process_runner, iteration, &resolved_cwd.path)?;
             } else {
-                println!(
+                ito_config::info!(
                     "No git changes detected after iteration {iter}; skipping commit.",
                     iter = iteration
                 );
@@ -850,6 +1099,8 @@ pub fn run_ralph_with_readiness(
             harness_exit_code: run.exit_code,
             completion_validated: false,
             effective_cwd: resolved_cwd.path.display().to_string(),
+            completion_summary: completion_summary.clone(),
+            usage: run.usage,
         });
         state.iteration = iteration;
         state.last_outcome = Some("iteration-complete".to_string());
@@ -857,12 +1108,17 @@ pub fn run_ralph_with_readiness(
         save_state(effective_ito_path, &change_id, &state)?;
 
         if completion_found && iteration >= opts.min_iterations {
+            if let Some(summary) = &completion_summary {
+                ito_config::info!("Completion summary: {summary}");
+            }
             if opts.skip_validation {
                 state.last_outcome = Some("unvalidated-complete".to_string());
                 state.last_failure = None;
                 save_state(effective_ito_path, &change_id, &state)?;
-                println!("\n=== Warning: --skip-validation set. Completion is not verified. ===\n");
-                println!(
+                ito_config::info!(
+                    "\n=== Warning: --skip-validation set. Completion is not verified. ===\n"
+                );
+                ito_config::info!(
                     "\n=== Completion promise \"{p}\" detected. Loop complete. ===\n",
                     p = opts.completion_promise
                 );
@@ -895,6 +1151,8 @@ pub fn run_ralph_with_readiness(
                 task_repo_for_validation,
                 change_id_opt,
                 opts.validation_command.as_deref(),
+                opts.validation_timeout,
+                opts.validation_max_output_bytes,
             )?;
             if report.passed {
                 if let Some(last) = state.history.last_mut() {
@@ -903,7 +1161,7 @@ pub fn run_ralph_with_readiness(
                 state.last_outcome = Some("validated-complete".to_string());
                 state.last_failure = None;
                 save_state(effective_ito_path, &change_id, &state)?;
-                println!(
+                ito_config::info!(
                     "\n=== Completion promise \"{p}\" detected (validated). Loop complete. ===\n",
                     p = opts.completion_promise
                 );
@@ -913,7 +1171,7 @@ pub fn run_ralph_with_readiness(
             state.last_outcome = Some("validation-rejected".to_string());
             state.last_failure = last_validation_failure.clone();
             save_state(effective_ito_path, &change_id, &state)?;
-            println!(
+            ito_config::info!(
                 "\n=== Completion promise detected, but validation failed. Continuing... ===\n"
             );
         }
@@ -925,6 +1183,34 @@ pub fn run_ralph_with_readiness(
     Ok(())
 }
 
+/// Print the cancellation summary and build the error returned for it.
+fn cancelled_error(change_id: &str, state: &RalphState, uncommitted: u32) -> CoreError {
+    ito_config::info!("\n=== Ralph cancelled for {change_id} ===\n");
+    ito_config::info!("Iterations recorded: {n}", n = state.iteration);
+    if uncommitted > 0 {
+        ito_config::info!("Uncommitted changes left in the working tree: {uncommitted}");
+    }
+    ito_config::info!("State saved; rerun the same command to resume.");
+    CoreError::Process(format!(
+        "Ralph loop for {change_id} was cancelled at iteration {n}",
+        n = state.iteration
+    ))
+}
+
+/// Fail before the first iteration when `harness` cannot run a prompt.
+fn preflight_harness(harness: &mut dyn Harness, model: Option<&str>) -> CoreResult<()> {
+    let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let name = harness.name();
+    ito_config::info!("Checking harness {name}...");
+    let health = harness.health_check(&cwd, model);
+    if health.is_ok() {
+        return Ok(());
+    }
+    Err(CoreError::Process(format!(
+        "Harness preflight failed for {name}: {health}"
+    )))
+}
+
 fn finalize_queue_results(
     label: &str,
     succeeded: &[String],
@@ -934,17 +1220,17 @@ fn finalize_queue_results(
         return Ok(());
     }
 
-    println!("\n=== {label} Ralph Summary ===", label = label);
+    ito_config::info!("\n=== {label} Ralph Summary ===", label = label);
     if !succeeded.is_empty() {
-        println!("Succeeded:");
+        ito_config::info!("Succeeded:");
         for change in succeeded {
-            println!("  - {change}");
+            ito_config::info!("  - {change}");
         }
     }
     if !failed.is_empty() {
-        println!("Failed:");
+        ito_config::info!("Failed:");
         for (change, reason) in failed {
-            println!("  - {change}: {reason}", change = change, reason = reason);
+            ito_config::info!("  - {change}: {reason}", change = change, reason = reason);
         }
     }
 
@@ -1028,18 +1314,18 @@ fn repo_incomplete_change_ids(changes: &[ChangeSummary]) -> Vec<String> {
 }
 
 fn print_eligible_changes(eligible_changes: &[String]) {
-    println!("\nEligible changes (ready or in-progress):");
+    ito_config::info!("\nEligible changes (ready or in-progress):");
     if eligible_changes.is_empty() {
-        println!("  (none)");
+        ito_config::info!("  (none)");
         return;
     }
 
     for (idx, change_id) in eligible_changes.iter().enumerate() {
         if idx == 0 {
-            println!("  - {change} (selected first)", change = change_id);
+            ito_config::info!("  - {change} (selected first)", change = change_id);
             continue;
         }
-        println!("  - {change}", change = change_id);
+        ito_config::info!("  - {change}", change = change_id);
     }
 }
 
@@ -1054,18 +1340,18 @@ fn module_incomplete_change_ids(changes: &[ChangeSummary]) -> Vec<String> {
 }
 
 fn print_ready_changes(module_id: &str, ready_changes: &[String]) {
-    println!("\nReady changes for module {module}:", module = module_id);
+    ito_config::info!("\nReady changes for module {module}:", module = module_id);
     if ready_changes.is_empty() {
-        println!("  (none)");
+        ito_config::info!("  (none)");
         return;
     }
 
     for (idx, change_id) in ready_changes.iter().enumerate() {
         if idx == 0 {
-            println!("  - {change} (selected first)", change = change_id);
+            ito_config::info!("  - {change} (selected first)", change = change_id);
             continue;
         }
-        println!("  - {change}", change = change_id);
+        ito_config::info!("  - {change}", change = change_id);
     }
 }
 
@@ -1080,13 +1366,20 @@ fn validate_completion(
     task_repo: &dyn DomainTaskRepository,
     change_id: Option<&str>,
     extra_command: Option<&str>,
+    timeout: Duration,
+    max_output_bytes: Option<usize>,
 ) -> CoreResult<CompletionValidationReport> {
     let mut passed = true;
     let mut sections: Vec<String> = Vec::new();
 
     if let Some(change_id) = change_id {
+        let started = std::time::Instant::now();
         let task = validation::check_task_completion(task_repo, change_id)?;
-        sections.push(render_validation_result("Ito task status", &task));
+        sections.push(render_timed_validation_result(
+            "Ito task status",
+            &task,
+            started.elapsed(),
+        ));
         if !task.success {
             passed = false;
         }
@@ -1112,18 +1405,58 @@ fn validate_completion(
         );
     }
 
-    let timeout = Duration::from_secs(5 * 60);
-    let project = validation::run_project_validation(ito_path, timeout)?;
-    sections.push(render_validation_result("Project validation", &project));
-    if !project.success {
-        passed = false;
+    let project_root = ito_path.parent().unwrap_or_else(|| Path::new("."));
+    let plan = validation::validation_plan(ito_path)?;
+    if plan.steps.is_empty() {
+        sections.push(render_validation_result(
+            "Project validation",
+            &validation::no_project_validation(),
+        ));
     }
-
+    let mut steps = plan.steps;
     if let Some(cmd) = extra_command {
-        let project_root = ito_path.parent().unwrap_or_else(|| Path::new("."));
-        let extra = validation::run_extra_validation(project_root, cmd, timeout)?;
-        sections.push(render_validation_result("Extra validation", &extra));
-        if !extra.success {
+        steps.push(validation::ValidationCommand {
+            name: cmd.to_string(),
+            command: cmd.to_string(),
+            timeout: None,
+            step: validation::ValidationStep::ExtraCommand,
+        });
+    }
+    let results = validation::run_validation_steps(
+        project_root,
+        &steps,
+        timeout,
+        max_output_bytes,
+        plan.parallelism,
+    )?;
+    for step in &results {
+        ito_config::detail!(
+            "Validation step `{}` resources: {}",
+            step.name,
+            step.resources.summary()
+        );
+        // Usage stats are best-effort: a failed append must not fail validation.
+        let _ = record_process_usage(
+            ito_path,
+            &ProcessUsageRecord {
+                timestamp: now_ms()?,
+                source: "validation".to_string(),
+                change_id: change_id.map(String::from),
+                name: step.name.clone(),
+                success: step.result.success,
+                resources: ResourceRecord::from(&step.resources),
+            },
+        );
+        let title = match step.step {
+            validation::ValidationStep::ExtraCommand => "Extra validation".to_string(),
+            _ => format!("Project validation: {}", step.name),
+        };
+        sections.push(render_timed_validation_result(
+            &title,
+            &step.result,
+            step.duration,
+        ));
+        if !step.result.success {
             passed = false;
         }
     }
@@ -1143,12 +1476,31 @@ fn should_validate_tasks_from_effective_worktree(
 }
 
 fn render_validation_result(title: &str, r: &validation::ValidationResult) -> String {
+    render_validation_section(title, r, None)
+}
+
+fn render_timed_validation_result(
+    title: &str,
+    r: &validation::ValidationResult,
+    duration: Duration,
+) -> String {
+    render_validation_section(title, r, Some(duration))
+}
+
+fn render_validation_section(
+    title: &str,
+    r: &validation::ValidationResult,
+    duration: Option<Duration>,
+) -> String {
     let mut md = String::new();
     md.push_str(&format!("### {title}\n\n"));
     md.push_str(&format!(
         "- Result: {}\n",
         if r.success { "PASS" } else { "FAIL" }
     ));
+    if let Some(duration) = duration {
+        md.push_str(&format!("- Duration: {:.1}s\n", duration.as_secs_f64()));
+    }
     md.push_str(&format!("- Summary: {}\n", r.message.trim()));
     if let Some(out) = r.output.as_deref() {
         let out = out.trim();
@@ -1185,22 +1537,17 @@ fn render_harness_failure(name: &str, exit_code: i32, stdout: &str, stderr: &str
     md
 }
 
-fn completion_promise_found(stdout: &str, token: &str) -> bool {
-    let mut rest = stdout;
-    loop {
-        let Some(start) = rest.find("<promise>") else {
-            return false;
-        };
-        let after_start = &rest[start + "<promise>".len()..];
-        let Some(end) = after_start.find("</promise>") else {
-            return false;
-        };
-        let inner = &after_start[..end];
-        if inner.trim() == token {
-            return true;
-        }
-
-        rest = &after_start[end + "</promise>".len()..];
+/// Build the completion detector selected by `opts.completion_format`.
+fn completion_detector(opts: &RalphOptions) -> Box<dyn CompletionDetector> {
+    match opts.completion_format {
+        CompletionFormat::Tag => Box::new(TagDetector::new(
+            &opts.completion_tag,
+            &opts.completion_promise,
+        )),
+        CompletionFormat::Json => Box::new(JsonDetector::new(
+            &opts.completion_promise,
+            opts.completion_json_pointer.as_deref(),
+        )),
     }
 }
 
@@ -1217,7 +1564,7 @@ fn resolve_target(
             ChangeTargetResolution::Ambiguous(matches) => {
                 return Err(CoreError::Validation(format!(
                     "Change '{change}' is ambiguous. Matches: {}",
-                    matches.join(", ")
+                    target_match_ids(&matches).join(", ")
                 )));
             }
             ChangeTargetResolution::NotFound => {
@@ -87,47 +87,6 @@ fn resolve_cwd_no_change_targeted_fallback() {
     assert_eq!(result.ito_path, ito_path.to_path_buf());
 }
 
-// -- completion_promise_found ----------------------------------------
-
-#[test]
-fn promise_single_match() {
-    assert!(completion_promise_found("x\n<promise>T</promise>\ny", "T"));
-}
-#[test]
-fn promise_no_tags() {
-    assert!(!completion_promise_found("no tags here", "T"));
-}
-#[test]
-fn promise_second_match() {
-    assert!(completion_promise_found(
-        "<promise>W</promise><promise>T</promise>",
-        "T"
-    ));
-}
-#[test]
-fn promise_empty_token() {
-    assert!(completion_promise_found("<promise></promise>", ""));
-}
-#[test]
-fn promise_empty_stdout() {
-    assert!(!completion_promise_found("", "T"));
-}
-#[test]
-fn promise_whitespace_trimmed() {
-    assert!(completion_promise_found("<promise>  T  </promise>", "T"));
-}
-#[test]
-fn promise_nested() {
-    assert!(!completion_promise_found(
-        "<promise><promise>T</promise></promise>",
-        "T"
-    ));
-}
-#[test]
-fn promise_incomplete() {
-    assert!(!completion_promise_found("<promise>T", "T"));
-}
-
 // -- infer_module_from_change ----------------------------------------
 
 #[test]
@@ -166,6 +125,20 @@ fn render_validation_fail_with_output() {
     assert!(s.contains("FAIL") && s.contains("```text"));
 }
 #[test]
+fn render_timed_validation_lists_duration() {
+    let r = validation::ValidationResult {
+        success: true,
+        message: "`make lint` passed".into(),
+        output: None,
+    };
+    let s =
+        render_timed_validation_result("Project validation: lint", &r, Duration::from_millis(1300));
+    assert_eq!(
+        s,
+        "### Project validation: lint\n\n- Result: PASS\n- Duration: 1.3s\n- Summary: `make lint` passed\n"
+    );
+}
+#[test]
 fn render_validation_whitespace_output() {
     let r = validation::ValidationResult {
         success: true,
@@ -214,6 +187,7 @@ fn summary(id: &str, c: u32, ip: u32, p: u32, sh: u32, plan: bool) -> ChangeSumm
         has_specs: plan,
         has_tasks: plan,
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
     }
 }
 
@@ -323,6 +297,7 @@ fn ok(stdout: &str, code: i32) -> Result<ProcessOutput, ProcessExecutionError> {
         stdout: stdout.into(),
         stderr: String::new(),
         timed_out: false,
+        resources: Default::default(),
     })
 }
 
@@ -348,6 +323,7 @@ fn count_git_changes_returns_zero_on_git_failure() {
         stdout: String::new(),
         stderr: "fatal".into(),
         timed_out: false,
+        resources: Default::default(),
     })]);
     assert_eq!(count_git_changes(&fail, cwd).unwrap(), 0);
 }
@@ -379,6 +355,7 @@ fn commit_iteration_treats_no_staged_changes_after_failed_commit_as_success() {
         stdout: "hook changed files".into(),
         stderr: "commit aborted".into(),
         timed_out: false,
+        resources: Default::default(),
     });
 
     commit_iteration(
@@ -404,6 +381,7 @@ fn commit_iteration_errors_when_failed_commit_still_has_staged_changes() {
         stdout: "hook failed".into(),
         stderr: "validation error".into(),
         timed_out: false,
+        resources: Default::default(),
     });
 
     let result = commit_iteration(
@@ -429,6 +407,7 @@ fn commit_iteration_errors_on_git_add_failure() {
         stdout: String::new(),
         stderr: "e".into(),
         timed_out: false,
+        resources: Default::default(),
     });
 
     // count_git_changes -> git add (fail)
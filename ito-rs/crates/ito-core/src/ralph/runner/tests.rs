@@ -197,7 +197,6 @@ fn render_failure_empty() {
     assert!(!s.contains("Stdout:") && !s.contains("Stderr:"));
 }
 
-
 // -- ChangeSummary filter helpers ------------------------------------
 
 fn summary(id: &str, c: u32, ip: u32, p: u32, sh: u32, plan: bool) -> ChangeSummary {
@@ -216,6 +215,7 @@ fn summary(id: &str, c: u32, ip: u32, p: u32, sh: u32, plan: bool) -> ChangeSumm
         has_specs: plan,
         has_tasks: plan,
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
     }
 }
 
@@ -301,6 +301,7 @@ fn ok(stdout: &str, code: i32) -> Result<ProcessOutput, ProcessExecutionError> {
         stdout: stdout.into(),
         stderr: String::new(),
         timed_out: false,
+        resources: Default::default(),
     })
 }
 
@@ -321,6 +322,7 @@ fn git_changes_and_commit() {
         stdout: String::new(),
         stderr: "fatal".into(),
         timed_out: false,
+        resources: Default::default(),
     })]);
     assert_eq!(count_git_changes(&fail, cwd).unwrap(), 0);
     commit_iteration(&MockRunner::new(vec![ok("", 0), ok("", 0)]), 1, cwd).unwrap();
@@ -330,6 +332,7 @@ fn git_changes_and_commit() {
         stdout: String::new(),
         stderr: "e".into(),
         timed_out: false,
+        resources: Default::default(),
     })]);
     assert!(commit_iteration(&bad, 1, cwd).is_err());
     assert!(now_ms().unwrap() > 0);
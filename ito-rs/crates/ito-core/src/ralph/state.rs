@@ -7,6 +7,7 @@
 //! State is stored under `.ito/.state/ralph/<change-id>/`.
 
 use crate::errors::{CoreError, CoreResult};
+use crate::harness::TokenUsage;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
@@ -31,6 +32,12 @@ pub struct RalphHistoryEntry {
     /// Effective working directory used for the iteration.
     #[serde(default)]
     pub effective_cwd: String,
+    /// Summary payload attached to the completion signal, when present.
+    #[serde(default)]
+    pub completion_summary: Option<String>,
+    /// Token usage reported by the harness for the iteration, when available.
+    #[serde(default)]
+    pub usage: Option<TokenUsage>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -51,17 +58,27 @@ pub struct RalphState {
     /// Most recent harness or validation failure details, when present.
     #[serde(default)]
     pub last_failure: Option<String>,
+    /// Session id reported by the harness for the most recent run, used to
+    /// resume after crashes and inactivity restarts.
+    #[serde(default)]
+    pub harness_session_id: Option<String>,
+    /// `HEAD` when the loop first ran for this change; commits after it are
+    /// summarized in later prompts.
+    #[serde(default)]
+    pub start_commit: Option<String>,
+}
+
+/// Return the directory holding Ralph state for every change.
+pub fn ralph_state_root(ito_path: &Path) -> PathBuf {
+    ito_path.join(".state").join("ralph")
 }
 
 /// Return the on-disk directory for Ralph state for `change_id`.
 pub fn ralph_state_dir(ito_path: &Path, change_id: &str) -> PathBuf {
     if !is_safe_change_id_segment(change_id) {
-        return ito_path
-            .join(".state")
-            .join("ralph")
-            .join("invalid-change-id");
+        return ralph_state_root(ito_path).join("invalid-change-id");
     }
-    ito_path.join(".state").join("ralph").join(change_id)
+    ralph_state_root(ito_path).join(change_id)
 }
 
 /// Return the path to `state.json` for `change_id`.
@@ -23,10 +23,18 @@ fn save_and_load_state_round_trip() {
             harness_exit_code: 0,
             completion_validated: true,
             effective_cwd: "/tmp/worktree".to_string(),
+            completion_summary: Some("Added the parser".to_string()),
+            usage: Some(crate::harness::TokenUsage {
+                input_tokens: 1200,
+                output_tokens: 640,
+                cache_read_tokens: 4500,
+            }),
         }],
         context_file: ".ito/.state/ralph/001-01_test/context.md".to_string(),
         last_outcome: Some("validated-complete".to_string()),
         last_failure: None,
+        start_commit: Some("abc1234".to_string()),
+        harness_session_id: Some("ses_abc123".to_string()),
     };
     save_state(&ito, change_id, &state).unwrap();
     let loaded = load_state(&ito, change_id).unwrap();
@@ -34,6 +42,7 @@ fn save_and_load_state_round_trip() {
     let loaded = loaded.unwrap();
     assert_eq!(loaded.change_id, state.change_id);
     assert_eq!(loaded.iteration, state.iteration);
+    assert_eq!(loaded.harness_session_id, state.harness_session_id);
     assert_eq!(loaded.history.len(), state.history.len());
     assert_eq!(loaded.history[0].timestamp, state.history[0].timestamp);
     assert_eq!(loaded.history[0].duration, state.history[0].duration);
@@ -48,6 +57,11 @@ fn save_and_load_state_round_trip() {
     assert_eq!(loaded.history[0].harness_exit_code, 0);
     assert!(loaded.history[0].completion_validated);
     assert_eq!(loaded.history[0].effective_cwd, "/tmp/worktree");
+    assert_eq!(
+        loaded.history[0].completion_summary.as_deref(),
+        Some("Added the parser")
+    );
+    assert_eq!(loaded.history[0].usage, state.history[0].usage);
     assert_eq!(loaded.context_file, state.context_file);
     assert_eq!(loaded.last_outcome.as_deref(), Some("validated-complete"));
     assert_eq!(loaded.last_failure, None);
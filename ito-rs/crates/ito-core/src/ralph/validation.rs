@@ -4,17 +4,27 @@
 //! - Ito task status (all tasks complete or shelved)
 //! - Project validation commands (build/tests/lints)
 //! - Optional extra validation command provided via CLI
+//!
+//! Command steps come from `ralph.validation.steps` (or the older
+//! `validationCommands` keys) and run concurrently, each with its own timeout.
 
 use crate::error_bridge::IntoCoreResult;
 use crate::errors::{CoreError, CoreResult};
 use serde_json::Value;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::Duration;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
 use ito_domain::tasks::{DiagnosticLevel, TaskRepository as DomainTaskRepository};
 
-use crate::process::{ProcessRequest, ProcessRunner, SystemProcessRunner};
+use crate::process::{ProcessRequest, ProcessRunner, ResourceUsage, SystemProcessRunner};
+use crate::ralph::duration::{format_duration, parse_duration};
+
+/// Number of validation command steps run at once unless
+/// `ralph.validation.parallelism` says otherwise.
+pub const DEFAULT_VALIDATION_PARALLELISM: usize = 2;
 
 /// Result of one validation step.
 #[derive(Debug, Clone)]
@@ -38,6 +48,43 @@ pub enum ValidationStep {
     ExtraCommand,
 }
 
+/// A named shell command run as one completion validation step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationCommand {
+    /// Name shown in the completion report.
+    pub name: String,
+    /// Command run with `sh -c` from the project root.
+    pub command: String,
+    /// Timeout for this step; `None` uses the caller's default.
+    pub timeout: Option<Duration>,
+    /// Which validation step the command belongs to.
+    pub step: ValidationStep,
+}
+
+/// Outcome of one [`ValidationCommand`].
+#[derive(Debug, Clone)]
+pub struct StepResult {
+    /// Name of the step.
+    pub name: String,
+    /// Which validation step the command belongs to.
+    pub step: ValidationStep,
+    /// Pass/fail result with the captured output.
+    pub result: ValidationResult,
+    /// Wall time the command took.
+    pub duration: Duration,
+    /// Resources the command consumed.
+    pub resources: ResourceUsage,
+}
+
+/// Project validation steps and how many of them may run at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationPlan {
+    /// Command steps, in report order.
+    pub steps: Vec<ValidationCommand>,
+    /// Maximum number of steps running at the same time (at least 1).
+    pub parallelism: usize,
+}
+
 /// Check that all tasks for `change_id` are complete or shelved.
 ///
 /// Missing tasks file is treated as success.
@@ -112,45 +159,249 @@ pub fn check_task_completion(
 /// Run project validation commands discovered from configuration sources.
 ///
 /// If no validation is configured, returns success with a warning message.
-pub fn run_project_validation(ito_path: &Path, timeout: Duration) -> CoreResult<ValidationResult> {
+/// Steps run concurrently as described by [`validation_plan`]; each
+/// command's captured output is capped at `max_output_bytes` per stream.
+pub fn run_project_validation(
+    ito_path: &Path,
+    timeout: Duration,
+    max_output_bytes: Option<usize>,
+) -> CoreResult<ValidationResult> {
     let project_root = ito_path.parent().unwrap_or_else(|| Path::new("."));
-    let commands = discover_project_validation_commands(project_root, ito_path)?;
+    let plan = validation_plan(ito_path)?;
 
-    if commands.is_empty() {
-        return Ok(ValidationResult {
-            success: true,
-            message: "Warning: no project validation configured; skipping".to_string(),
-            output: None,
-        });
+    if plan.steps.is_empty() {
+        return Ok(no_project_validation());
     }
 
-    let mut combined: Vec<String> = Vec::new();
-    for cmd in commands {
-        let out = run_shell_with_timeout(project_root, &cmd, timeout)?;
-        combined.push(out.render());
-        if !out.success {
-            return Ok(ValidationResult {
-                success: false,
-                message: format!("Project validation failed: `{cmd}`"),
-                output: Some(combined.join("\n\n")),
-            });
-        }
-    }
+    let results = run_validation_steps(
+        project_root,
+        &plan.steps,
+        timeout,
+        max_output_bytes,
+        plan.parallelism,
+    )?;
+    let failed: Vec<String> = results
+        .iter()
+        .filter(|r| !r.result.success)
+        .map(|r| format!("`{}`", r.name))
+        .collect();
+    let combined: Vec<&str> = results
+        .iter()
+        .filter_map(|r| r.result.output.as_deref())
+        .collect();
 
     Ok(ValidationResult {
-        success: true,
-        message: "Project validation passed".to_string(),
+        success: failed.is_empty(),
+        message: if failed.is_empty() {
+            "Project validation passed".to_string()
+        } else {
+            format!("Project validation failed: {}", failed.join(", "))
+        },
         output: Some(combined.join("\n\n")),
     })
 }
 
+/// Result reported when the project has no validation commands.
+pub fn no_project_validation() -> ValidationResult {
+    ValidationResult {
+        success: true,
+        message: "Warning: no project validation configured; skipping".to_string(),
+        output: None,
+    }
+}
+
+/// Collect the project validation steps for the project owning `ito_path`.
+///
+/// `ralph.validation.steps` (`[{name, command, timeout}]`) in `ito.json` or
+/// `.ito/config.json` takes precedence; otherwise each command found by the
+/// older `validationCommands` lookup becomes a step named after itself.
+/// `timeout` accepts seconds or a duration such as `"5m"`.
+///
+/// # Errors
+///
+/// Returns an error when a config file cannot be read or a configured step
+/// has no command or an invalid timeout.
+pub fn validation_plan(ito_path: &Path) -> CoreResult<ValidationPlan> {
+    let project_root = ito_path.parent().unwrap_or_else(|| Path::new("."));
+    let mut steps: Vec<ValidationCommand> = Vec::new();
+    let mut parallelism: Option<usize> = None;
+
+    for path in [project_root.join("ito.json"), ito_path.join("config.json")] {
+        let Some(config) = read_json_config(&path)? else {
+            continue;
+        };
+        if parallelism.is_none() {
+            parallelism = config
+                .pointer("/ralph/validation/parallelism")
+                .and_then(Value::as_u64)
+                .map(|n| usize::try_from(n).unwrap_or(usize::MAX));
+        }
+        if steps.is_empty() {
+            steps = configured_steps(&path, &config)?;
+        }
+    }
+
+    if steps.is_empty() {
+        steps = discover_project_validation_commands(project_root, ito_path)?
+            .into_iter()
+            .map(|command| ValidationCommand {
+                name: command.clone(),
+                command,
+                timeout: None,
+                step: ValidationStep::ProjectCheck,
+            })
+            .collect();
+    }
+
+    Ok(ValidationPlan {
+        steps,
+        parallelism: parallelism.unwrap_or(DEFAULT_VALIDATION_PARALLELISM).max(1),
+    })
+}
+
+/// Run `steps` from `project_root`, at most `parallelism` at a time.
+///
+/// Every step runs to completion (or its own timeout) regardless of the
+/// others; results come back in the order of `steps`.
+///
+/// # Errors
+///
+/// Returns the first error (in step order) for a command that could not be
+/// started.
+pub fn run_validation_steps(
+    project_root: &Path,
+    steps: &[ValidationCommand],
+    default_timeout: Duration,
+    max_output_bytes: Option<usize>,
+    parallelism: usize,
+) -> CoreResult<Vec<StepResult>> {
+    let next = AtomicUsize::new(0);
+    let slots: Mutex<Vec<Option<CoreResult<StepResult>>>> =
+        Mutex::new(steps.iter().map(|_| None).collect());
+    let workers = parallelism.clamp(1, steps.len().max(1));
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                loop {
+                    let idx = next.fetch_add(1, Ordering::SeqCst);
+                    let Some(step) = steps.get(idx) else {
+                        break;
+                    };
+                    let outcome = run_step(project_root, step, default_timeout, max_output_bytes);
+                    let mut slots = slots.lock().unwrap_or_else(|e| e.into_inner());
+                    slots[idx] = Some(outcome);
+                }
+            });
+        }
+    });
+
+    slots
+        .into_inner()
+        .unwrap_or_else(|e| e.into_inner())
+        .into_iter()
+        .map(|slot| slot.unwrap_or_else(|| Err(CoreError::process("Validation step did not run"))))
+        .collect()
+}
+
+fn run_step(
+    project_root: &Path,
+    step: &ValidationCommand,
+    default_timeout: Duration,
+    max_output_bytes: Option<usize>,
+) -> CoreResult<StepResult> {
+    let timeout = step.timeout.unwrap_or(default_timeout);
+    let started = Instant::now();
+    let out = run_shell_with_timeout(project_root, &step.command, timeout, max_output_bytes)?;
+    let duration = started.elapsed();
+
+    let command = &step.command;
+    let message = if out.timed_out {
+        format!("`{command}` timed out after {}", format_duration(timeout))
+    } else if out.success {
+        format!("`{command}` passed")
+    } else {
+        format!("`{command}` failed (exit {})", out.exit_code)
+    };
+    Ok(StepResult {
+        name: step.name.clone(),
+        step: step.step,
+        result: ValidationResult {
+            success: out.success,
+            message,
+            output: Some(out.render()),
+        },
+        duration,
+        resources: out.resources,
+    })
+}
+
+fn read_json_config(path: &Path) -> CoreResult<Option<Value>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = fs::read_to_string(path)
+        .map_err(|e| CoreError::io(format!("Failed to read {}", path.display()), e))?;
+    Ok(serde_json::from_str(&contents).ok())
+}
+
+fn configured_steps(path: &Path, config: &Value) -> CoreResult<Vec<ValidationCommand>> {
+    let Some(items) = config
+        .pointer("/ralph/validation/steps")
+        .and_then(Value::as_array)
+    else {
+        return Ok(Vec::new());
+    };
+
+    let mut steps = Vec::new();
+    for (idx, item) in items.iter().enumerate() {
+        let non_empty = |key: &str| {
+            item.get(key)
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+        };
+        let Some(command) = non_empty("command") else {
+            return Err(CoreError::validation(format!(
+                "{}: ralph.validation.steps[{idx}] has no `command`",
+                path.display()
+            )));
+        };
+        let name = non_empty("name").unwrap_or(command);
+        let timeout = match item.get("timeout") {
+            None | Some(Value::Null) => None,
+            Some(Value::Number(n)) if n.as_u64().is_some() => n.as_u64().map(Duration::from_secs),
+            Some(Value::String(s)) => Some(parse_duration(s).map_err(|e| {
+                CoreError::validation(format!(
+                    "{}: invalid timeout for validation step '{name}': {e}",
+                    path.display()
+                ))
+            })?),
+            Some(other) => {
+                return Err(CoreError::validation(format!(
+                    "{}: invalid timeout for validation step '{name}': {other}",
+                    path.display()
+                )));
+            }
+        };
+        steps.push(ValidationCommand {
+            name: name.to_string(),
+            command: command.to_string(),
+            timeout,
+            step: ValidationStep::ProjectCheck,
+        });
+    }
+    Ok(steps)
+}
+
 /// Run an extra validation command provided explicitly by the user.
 pub fn run_extra_validation(
     project_root: &Path,
     command: &str,
     timeout: Duration,
+    max_output_bytes: Option<usize>,
 ) -> CoreResult<ValidationResult> {
-    let out = run_shell_with_timeout(project_root, command, timeout)?;
+    let out = run_shell_with_timeout(project_root, command, timeout, max_output_bytes)?;
     Ok(ValidationResult {
         success: out.success,
         message: if out.success {
@@ -287,6 +538,7 @@ struct ShellRunOutput {
     timed_out: bool,
     stdout: String,
     stderr: String,
+    resources: ResourceUsage,
 }
 
 impl ShellRunOutput {
@@ -318,6 +570,7 @@ impl ShellRunOutput {
 ///
 /// Executes `sh -c <cmd>` in `cwd` and returns a `ShellRunOutput` describing the executed command,
 /// whether it succeeded, the exit code, whether it timed out, and captured `stdout`/`stderr`.
+/// When `max_output_bytes` is set, each stream keeps only its last `max_output_bytes` bytes.
 ///
 /// # Errors
 ///
@@ -329,15 +582,22 @@ impl ShellRunOutput {
 /// use std::path::Path;
 /// use std::time::Duration;
 ///
-/// let out = run_shell_with_timeout(Path::new("."), "echo hello", Duration::from_secs(5)).unwrap();
+/// let out = run_shell_with_timeout(Path::new("."), "echo hello", Duration::from_secs(5), None)
+///     .unwrap();
 /// assert!(out.success);
 /// assert!(out.stdout.contains("hello"));
 /// ```
-fn run_shell_with_timeout(cwd: &Path, cmd: &str, timeout: Duration) -> CoreResult<ShellRunOutput> {
+fn run_shell_with_timeout(
+    cwd: &Path,
+    cmd: &str,
+    timeout: Duration,
+    max_output_bytes: Option<usize>,
+) -> CoreResult<ShellRunOutput> {
     let runner = SystemProcessRunner;
-    let request = ProcessRequest::new("sh")
+    let mut request = ProcessRequest::new("sh")
         .args(["-c", cmd])
         .current_dir(cwd.to_path_buf());
+    request.max_output_bytes = max_output_bytes;
     let output = runner.run_with_timeout(&request, timeout).map_err(|e| {
         CoreError::Process(format!("Failed to run validation command '{cmd}': {e}"))
     })?;
@@ -349,21 +609,23 @@ fn run_shell_with_timeout(cwd: &Path, cmd: &str, timeout: Duration) -> CoreResul
         timed_out: output.timed_out,
         stdout: output.stdout,
         stderr: output.stderr,
+        resources: output.resources,
     })
 }
 
+/// Keep the last `max_bytes` of `s`; failures are usually reported at the end.
 fn truncate_for_context(s: &str, max_bytes: usize) -> String {
     if s.len() <= max_bytes {
         return s.to_string();
     }
-    // Find a valid UTF-8 boundary at or before max_bytes to avoid panicking
+    // Find a valid UTF-8 boundary at or after the cut point to avoid panicking
     // on multi-byte characters.
-    let mut end = max_bytes;
-    while end > 0 && !s.is_char_boundary(end) {
-        end -= 1;
+    let mut start = s.len() - max_bytes;
+    while start < s.len() && !s.is_char_boundary(start) {
+        start += 1;
     }
-    let mut out = s[..end].to_string();
-    out.push_str("\n... (truncated) ...");
+    let mut out = "... (truncated) ...\n".to_string();
+    out.push_str(&s[start..]);
     out
 }
 
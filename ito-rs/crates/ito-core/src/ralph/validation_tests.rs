@@ -49,7 +49,8 @@ fn project_validation_discovers_commands_from_repo_json() {
 #[test]
 fn shell_timeout_is_failure() {
     let td = tempfile::tempdir().unwrap();
-    let out = run_shell_with_timeout(td.path(), "sleep 0.1", Duration::from_millis(50)).unwrap();
+    let out =
+        run_shell_with_timeout(td.path(), "sleep 0.1", Duration::from_millis(50), None).unwrap();
     assert!(out.timed_out);
     assert!(!out.success);
 }
@@ -121,6 +122,14 @@ fn truncate_for_context_long_truncated() {
     assert!(result.contains("... (truncated) ..."));
 }
 
+#[test]
+fn truncate_for_context_keeps_tail() {
+    let text = format!("{}TAIL", "a".repeat(15_000));
+    let result = truncate_for_context(&text, 12_000);
+    assert!(result.starts_with("... (truncated) ..."));
+    assert!(result.ends_with("TAIL"));
+}
+
 #[test]
 fn truncate_for_context_multibyte_utf8() {
     // Each CJK character is 3 bytes in UTF-8.
@@ -152,7 +161,7 @@ fn extract_commands_from_json_multiple_paths() {
 #[test]
 fn run_extra_validation_success() {
     let td = tempfile::tempdir().unwrap();
-    let result = run_extra_validation(td.path(), "true", Duration::from_secs(10)).unwrap();
+    let result = run_extra_validation(td.path(), "true", Duration::from_secs(10), None).unwrap();
     assert!(result.success);
     assert!(result.message.contains("passed"));
 }
@@ -160,11 +169,24 @@ fn run_extra_validation_success() {
 #[test]
 fn run_extra_validation_failure() {
     let td = tempfile::tempdir().unwrap();
-    let result = run_extra_validation(td.path(), "false", Duration::from_secs(10)).unwrap();
+    let result = run_extra_validation(td.path(), "false", Duration::from_secs(10), None).unwrap();
     assert!(!result.success);
     assert!(result.message.contains("failed"));
 }
 
+#[test]
+fn run_extra_validation_caps_output_keeping_tail() {
+    let td = tempfile::tempdir().unwrap();
+    let cmd =
+        "i=0; while [ $i -lt 2000 ]; do echo noise-$i; i=$((i+1)); done; echo FINAL-ERROR; exit 1";
+    let result = run_extra_validation(td.path(), cmd, Duration::from_secs(10), Some(1024)).unwrap();
+    assert!(!result.success);
+    let output = result.output.unwrap();
+    assert!(output.contains("bytes truncated]"), "{output}");
+    assert!(output.contains("FINAL-ERROR"), "{output}");
+    assert!(!output.contains("noise-0\n"), "{output}");
+}
+
 #[test]
 fn discover_commands_priority_ito_json_first() {
     let td = tempfile::tempdir().unwrap();
@@ -234,3 +256,151 @@ fn discover_commands_returns_empty_when_nothing_configured() {
     let commands = discover_project_validation_commands(project_root, &ito_path).unwrap();
     assert!(commands.is_empty());
 }
+
+fn command_step(name: &str, command: &str, timeout: Option<Duration>) -> ValidationCommand {
+    ValidationCommand {
+        name: name.to_string(),
+        command: command.to_string(),
+        timeout,
+        step: ValidationStep::ProjectCheck,
+    }
+}
+
+#[test]
+fn validation_plan_reads_configured_steps() {
+    let td = tempfile::tempdir().unwrap();
+    let ito_path = td.path().join(".ito");
+    write(
+        &ito_path.join("config.json"),
+        r#"{"ralph": {"validationCommands": ["make check"], "validation": {
+            "parallelism": 3,
+            "steps": [
+                {"name": "lint", "command": "make lint", "timeout": "2m"},
+                {"command": "make test", "timeout": 90}
+            ]
+        }}}"#,
+    );
+
+    let plan = validation_plan(&ito_path).unwrap();
+
+    assert_eq!(plan.parallelism, 3);
+    assert_eq!(
+        plan.steps,
+        vec![
+            command_step("lint", "make lint", Some(Duration::from_secs(120))),
+            command_step("make test", "make test", Some(Duration::from_secs(90))),
+        ]
+    );
+}
+
+#[test]
+fn validation_plan_falls_back_to_validation_commands() {
+    let td = tempfile::tempdir().unwrap();
+    let ito_path = td.path().join(".ito");
+    write(
+        &td.path().join("ito.json"),
+        r#"{"ralph": {"validationCommands": ["make lint", "make test"]}}"#,
+    );
+
+    let plan = validation_plan(&ito_path).unwrap();
+
+    assert_eq!(plan.parallelism, DEFAULT_VALIDATION_PARALLELISM);
+    assert_eq!(
+        plan.steps,
+        vec![
+            command_step("make lint", "make lint", None),
+            command_step("make test", "make test", None),
+        ]
+    );
+}
+
+#[test]
+fn validation_plan_rejects_steps_without_command() {
+    let td = tempfile::tempdir().unwrap();
+    let ito_path = td.path().join(".ito");
+    write(
+        &td.path().join("ito.json"),
+        r#"{"ralph": {"validation": {"steps": [{"name": "lint"}]}}}"#,
+    );
+
+    let err = validation_plan(&ito_path).unwrap_err();
+
+    let CoreError::Validation(msg) = err else {
+        panic!("expected a validation error, got {err:?}");
+    };
+    assert!(msg.contains("steps[0]"), "{msg}");
+}
+
+#[test]
+fn validation_steps_run_concurrently() {
+    let td = tempfile::tempdir().unwrap();
+    let steps = vec![
+        command_step("a", "sleep 0.6; echo a", None),
+        command_step("b", "sleep 0.6; echo b", None),
+    ];
+
+    let started = Instant::now();
+    let results =
+        run_validation_steps(td.path(), &steps, Duration::from_secs(10), None, 2).unwrap();
+    let elapsed = started.elapsed();
+
+    assert!(elapsed < Duration::from_millis(1100), "took {elapsed:?}");
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].name, "a");
+    assert_eq!(results[1].name, "b");
+    assert!(results.iter().all(|r| r.result.success));
+    assert!(
+        results
+            .iter()
+            .all(|r| r.duration >= Duration::from_millis(500))
+    );
+}
+
+#[test]
+fn validation_step_timeout_only_fails_that_step() {
+    let td = tempfile::tempdir().unwrap();
+    let steps = vec![
+        command_step("slow", "sleep 5", Some(Duration::from_millis(200))),
+        command_step("quick", "sleep 0.3; echo done", None),
+    ];
+
+    let started = Instant::now();
+    let results =
+        run_validation_steps(td.path(), &steps, Duration::from_secs(10), None, 2).unwrap();
+
+    assert!(started.elapsed() < Duration::from_secs(3));
+    assert!(!results[0].result.success);
+    assert!(
+        results[0].result.message.contains("timed out"),
+        "{}",
+        results[0].result.message
+    );
+    assert!(results[1].result.success);
+    assert!(
+        results[1]
+            .result
+            .output
+            .as_deref()
+            .unwrap()
+            .contains("done")
+    );
+}
+
+#[test]
+fn validation_steps_cap_output_per_step() {
+    let td = tempfile::tempdir().unwrap();
+    let noisy = "i=0; while [ $i -lt 2000 ]; do echo noise-$i; i=$((i+1)); done; echo TAIL";
+    let steps = vec![
+        command_step("noisy", noisy, None),
+        command_step("quiet", "echo small", None),
+    ];
+
+    let results =
+        run_validation_steps(td.path(), &steps, Duration::from_secs(10), Some(1024), 2).unwrap();
+
+    let noisy = results[0].result.output.as_deref().unwrap();
+    assert!(noisy.contains("bytes truncated]"), "{noisy}");
+    assert!(noisy.contains("TAIL"), "{noisy}");
+    let quiet = results[1].result.output.as_deref().unwrap();
+    assert!(!quiet.contains("truncated"), "{quiet}");
+}
@@ -1,7 +1,9 @@
 //! Precomputed index of an Ito repository.
 //!
 //! `RepoIndex` gathers a few commonly-used directory listings and ids from the
-//! `.ito/` working directory.
+//! `.ito/` working directory. [`IncrementalRepoIndex`] additionally keeps
+//! parsed change, spec, and module metadata on disk and refreshes only the
+//! entries that changed.
 
 use std::collections::BTreeSet;
 use std::path::Path;
@@ -10,6 +12,17 @@ use crate::error_bridge::IntoCoreResult;
 use crate::errors::CoreResult;
 use ito_common::fs::StdFs;
 
+mod incremental;
+#[cfg(feature = "repo-index-watch")]
+mod watch;
+
+pub use incremental::{
+    IncrementalRepoIndex, IndexedChange, IndexedModule, IndexedSpec, REPO_INDEX_REL,
+    REPO_INDEX_VERSION, RefreshReport,
+};
+#[cfg(feature = "repo-index-watch")]
+pub use watch::RepoIndexWatcher;
+
 #[derive(Debug, Default, Clone)]
 /// Directory listings and ids derived from an Ito repo.
 pub struct RepoIndex {
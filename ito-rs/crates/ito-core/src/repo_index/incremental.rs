@@ -0,0 +1,526 @@
+//! Persistent repository index with incremental refresh.
+//!
+//! Listing ~hundreds of changes re-parses every `tasks.md` on each call. The
+//! incremental index parses each change, spec, and module once, stores the
+//! result under `<itoDir>/.state/repo-index.json` keyed by entity path, and
+//! re-parses only entries whose files changed (by mtime on load, or by the
+//! paths passed to [`IncrementalRepoIndex::refresh`]).
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Component, Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::RepoIndex;
+use crate::change_repository::FsChangeRepository;
+use crate::error_bridge::IntoCoreResult;
+use crate::errors::{CoreError, CoreResult};
+use crate::list::{SpecListItem, spec_list_item};
+use ito_common::fs::StdFs;
+use ito_common::paths;
+use ito_domain::changes::{ChangeMetadata, ChangeOrchestrateMetadata, ChangeSummary};
+use ito_domain::discovery;
+
+/// Index location relative to the Ito directory.
+pub const REPO_INDEX_REL: &str = ".state/repo-index.json";
+
+/// On-disk format version. A stored index with any other version is discarded
+/// and rebuilt from scratch.
+pub const REPO_INDEX_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum EntityKind {
+    Change,
+    Spec,
+    Module,
+}
+
+impl EntityKind {
+    const ALL: [EntityKind; 3] = [EntityKind::Change, EntityKind::Spec, EntityKind::Module];
+
+    fn dir_name(self) -> &'static str {
+        match self {
+            EntityKind::Change => "changes",
+            EntityKind::Spec => "specs",
+            EntityKind::Module => "modules",
+        }
+    }
+
+    fn from_dir_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.dir_name() == name)
+    }
+
+    fn key(self, name: &str) -> String {
+        format!("{}/{name}", self.dir_name())
+    }
+}
+
+/// Change metadata cached by the index; mirrors [`ChangeSummary`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedChange {
+    /// Change directory name.
+    pub id: String,
+    /// Module id parsed from the change id.
+    pub module_id: Option<String>,
+    /// Sub-module id (`NNN.SS`) parsed from the change id.
+    pub sub_module_id: Option<String>,
+    /// Number of completed tasks.
+    pub completed_tasks: u32,
+    /// Number of shelved tasks.
+    pub shelved_tasks: u32,
+    /// Number of in-progress tasks.
+    pub in_progress_tasks: u32,
+    /// Number of pending tasks.
+    pub pending_tasks: u32,
+    /// Total number of tasks.
+    pub total_tasks: u32,
+    /// Whether `proposal.md` exists.
+    pub has_proposal: bool,
+    /// Whether `design.md` exists.
+    pub has_design: bool,
+    /// Whether the change has spec deltas.
+    pub has_specs: bool,
+    /// Whether `tasks.md` has tasks.
+    pub has_tasks: bool,
+    /// Changes that must complete first (from `.ito.yaml`).
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    /// Gate order override (from `.ito.yaml`).
+    #[serde(default)]
+    pub preferred_gates: Vec<String>,
+    /// Proposal front matter metadata.
+    #[serde(default)]
+    pub metadata: ChangeMetadata,
+}
+
+/// Spec metadata cached by the index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedSpec {
+    /// Spec directory name.
+    pub id: String,
+    /// Count of requirements in `spec.md`.
+    pub requirement_count: u32,
+}
+
+/// Module metadata cached by the index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedModule {
+    /// Module directory name (`NNN_name`).
+    pub full_name: String,
+    /// 3-digit module id, when the directory name has one.
+    pub id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+enum IndexedEntity {
+    Change(IndexedChange),
+    Spec(IndexedSpec),
+    Module(IndexedModule),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct IndexEntry {
+    /// Latest modification time under the entity directory (ns since the epoch).
+    mtime_nanos: i64,
+    entity: IndexedEntity,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredIndex {
+    version: u32,
+    #[serde(default)]
+    entries: BTreeMap<String, IndexEntry>,
+}
+
+/// Entries touched by a refresh, as Ito-relative keys such as `changes/001-01_demo`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RefreshReport {
+    /// Entries that were re-parsed (added or updated).
+    pub reparsed: Vec<String>,
+    /// Entries dropped because their directory no longer exists.
+    pub removed: Vec<String>,
+}
+
+impl RefreshReport {
+    /// Returns `true` when nothing was re-parsed or removed.
+    pub fn is_empty(&self) -> bool {
+        self.reparsed.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Persistent index of changes, specs, and modules under an Ito directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncrementalRepoIndex {
+    ito_path: PathBuf,
+    entries: BTreeMap<String, IndexEntry>,
+}
+
+impl IncrementalRepoIndex {
+    /// Build the index from scratch by parsing every entity under `ito_path`.
+    pub fn build(ito_path: &Path) -> CoreResult<Self> {
+        let mut index = Self {
+            ito_path: ito_path.to_path_buf(),
+            entries: BTreeMap::new(),
+        };
+        index.reconcile()?;
+        Ok(index)
+    }
+
+    /// Load the stored index and bring it up to date, or build it when the
+    /// stored copy is missing, unreadable, or from another format version.
+    ///
+    /// Only entries whose directory mtime changed since they were stored are
+    /// re-parsed. Failing to persist the result is not an error.
+    pub fn load_or_build(ito_path: &Path) -> CoreResult<Self> {
+        let stored = std::fs::read_to_string(repo_index_path(ito_path))
+            .ok()
+            .and_then(|contents| serde_json::from_str::<StoredIndex>(&contents).ok())
+            .filter(|stored| stored.version == REPO_INDEX_VERSION);
+
+        let (index, dirty) = match stored {
+            Some(stored) => {
+                let mut index = Self {
+                    ito_path: ito_path.to_path_buf(),
+                    entries: stored.entries,
+                };
+                let report = index.reconcile()?;
+                (index, !report.is_empty())
+            }
+            None => (Self::build(ito_path)?, true),
+        };
+
+        if dirty && let Err(e) = index.save() {
+            tracing::debug!("not persisting repo index: {e}");
+        }
+        Ok(index)
+    }
+
+    /// Write the index to `<itoDir>/.state/repo-index.json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the index cannot be serialized or written.
+    pub fn save(&self) -> CoreResult<()> {
+        let path = repo_index_path(&self.ito_path);
+        if let Some(parent) = path.parent() {
+            ito_common::io::create_dir_all_std(parent).map_err(|e| {
+                CoreError::io(format!("creating directory {}", parent.display()), e)
+            })?;
+        }
+        let stored = StoredIndex {
+            version: REPO_INDEX_VERSION,
+            entries: self.entries.clone(),
+        };
+        let mut contents = serde_json::to_string(&stored)
+            .map_err(|e| CoreError::serde("serializing repo index", e.to_string()))?;
+        contents.push('\n');
+        ito_common::io::write_std(&path, contents.as_bytes())
+            .map_err(|e| CoreError::io(format!("writing {}", path.display()), e))
+    }
+
+    /// Re-parse the entries affected by `paths_changed`.
+    ///
+    /// Paths may be absolute or relative to the Ito directory. A path inside an
+    /// entity directory (e.g. `changes/001-01_demo/tasks.md`) re-parses just
+    /// that entity; a path naming a collection directory (`changes/`) rescans
+    /// that collection's listing. Paths outside `changes/`, `specs/`, and
+    /// `modules/` are ignored.
+    pub fn refresh(&mut self, paths_changed: &[PathBuf]) -> CoreResult<RefreshReport> {
+        let mut collections: BTreeSet<EntityKind> = BTreeSet::new();
+        let mut entities: BTreeSet<(EntityKind, String)> = BTreeSet::new();
+        for path in paths_changed {
+            match self.classify(path) {
+                Some((kind, Some(name))) => {
+                    entities.insert((kind, name));
+                }
+                Some((kind, None)) => {
+                    collections.insert(kind);
+                }
+                None => {}
+            }
+        }
+
+        let mut report = RefreshReport::default();
+        for kind in collections {
+            self.reconcile_kind(kind, &mut report)?;
+        }
+        for (kind, name) in entities {
+            let key = kind.key(&name);
+            if report.reparsed.contains(&key) || report.removed.contains(&key) {
+                continue;
+            }
+            if is_listed_entity_dir(&self.ito_path, kind, &name) {
+                let entry = self.parse_entry(kind, &name)?;
+                self.entries.insert(key.clone(), entry);
+                report.reparsed.push(key);
+            } else if self.entries.remove(&key).is_some() {
+                report.removed.push(key);
+            }
+        }
+        Ok(report)
+    }
+
+    /// The Ito directory this index describes.
+    pub fn ito_path(&self) -> &Path {
+        &self.ito_path
+    }
+
+    /// Active change summaries, sorted by id (same as `ChangeRepository::list`).
+    pub fn change_summaries(&self) -> Vec<ChangeSummary> {
+        self.entries
+            .values()
+            .filter_map(|entry| match &entry.entity {
+                IndexedEntity::Change(change) => Some(change.to_summary(entry.mtime_nanos)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Summary for the active change directory `id`, if indexed.
+    pub fn change_summary(&self, id: &str) -> Option<ChangeSummary> {
+        let entry = self.entries.get(&EntityKind::Change.key(id))?;
+        match &entry.entity {
+            IndexedEntity::Change(change) => Some(change.to_summary(entry.mtime_nanos)),
+            _ => None,
+        }
+    }
+
+    /// Spec listing entries, sorted by id (same as [`crate::list::list_specs`]).
+    pub fn specs(&self) -> Vec<SpecListItem> {
+        self.entries
+            .values()
+            .filter_map(|entry| match &entry.entity {
+                IndexedEntity::Spec(spec) => Some(SpecListItem {
+                    id: spec.id.clone(),
+                    requirement_count: spec.requirement_count,
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Directory listings in the shape of a freshly loaded [`RepoIndex`].
+    pub fn repo_index(&self) -> RepoIndex {
+        let mut index = RepoIndex::default();
+        for entry in self.entries.values() {
+            match &entry.entity {
+                IndexedEntity::Change(change) => index.change_dir_names.push(change.id.clone()),
+                IndexedEntity::Spec(spec) => index.spec_dir_names.push(spec.id.clone()),
+                IndexedEntity::Module(module) => {
+                    index.module_dir_names.push(module.full_name.clone());
+                    if let Some(id) = &module.id {
+                        index.module_ids.insert(id.clone());
+                    }
+                }
+            }
+        }
+        index
+    }
+
+    fn reconcile(&mut self) -> CoreResult<RefreshReport> {
+        let mut report = RefreshReport::default();
+        for kind in EntityKind::ALL {
+            self.reconcile_kind(kind, &mut report)?;
+        }
+        Ok(report)
+    }
+
+    /// Sync one collection with the directory listing, re-parsing new entries
+    /// and entries whose mtime moved.
+    fn reconcile_kind(&mut self, kind: EntityKind, report: &mut RefreshReport) -> CoreResult<()> {
+        let names: BTreeSet<String> = list_entity_dirs(&self.ito_path, kind)?
+            .into_iter()
+            .collect();
+
+        let prefix = format!("{}/", kind.dir_name());
+        let stale: Vec<String> = self
+            .entries
+            .keys()
+            .filter(|key| {
+                key.strip_prefix(&prefix)
+                    .is_some_and(|name| !names.contains(name))
+            })
+            .cloned()
+            .collect();
+        for key in stale {
+            self.entries.remove(&key);
+            report.removed.push(key);
+        }
+
+        for name in names {
+            let key = kind.key(&name);
+            let dir = self.ito_path.join(kind.dir_name()).join(&name);
+            let current = latest_mtime_nanos(&dir);
+            if self
+                .entries
+                .get(&key)
+                .is_some_and(|entry| entry.mtime_nanos == current)
+            {
+                continue;
+            }
+            let entry = self.parse_entry(kind, &name)?;
+            self.entries.insert(key.clone(), entry);
+            report.reparsed.push(key);
+        }
+        Ok(())
+    }
+
+    fn parse_entry(&self, kind: EntityKind, name: &str) -> CoreResult<IndexEntry> {
+        let dir = self.ito_path.join(kind.dir_name()).join(name);
+        match kind {
+            EntityKind::Change => {
+                let summary = FsChangeRepository::new(&self.ito_path)
+                    .summarize_active_dir(name)
+                    .into_core()?;
+                Ok(IndexEntry {
+                    mtime_nanos: to_nanos(summary.last_modified),
+                    entity: IndexedEntity::Change(IndexedChange::from_summary(summary)),
+                })
+            }
+            EntityKind::Spec => {
+                let item = spec_list_item(&paths::specs_dir(&self.ito_path), name.to_string());
+                Ok(IndexEntry {
+                    mtime_nanos: latest_mtime_nanos(&dir),
+                    entity: IndexedEntity::Spec(IndexedSpec {
+                        id: item.id,
+                        requirement_count: item.requirement_count,
+                    }),
+                })
+            }
+            EntityKind::Module => Ok(IndexEntry {
+                mtime_nanos: latest_mtime_nanos(&dir),
+                entity: IndexedEntity::Module(IndexedModule {
+                    full_name: name.to_string(),
+                    id: module_id_from_dir_name(name),
+                }),
+            }),
+        }
+    }
+
+    /// Map a changed path to `(collection, Some(entity))` or `(collection, None)`.
+    fn classify(&self, path: &Path) -> Option<(EntityKind, Option<String>)> {
+        let rel = if path.is_absolute() {
+            match path.strip_prefix(&self.ito_path) {
+                Ok(rel) => rel.to_path_buf(),
+                // Watchers report canonical paths (e.g. `/private/var` on macOS).
+                Err(_) => {
+                    let canonical = std::fs::canonicalize(&self.ito_path).ok()?;
+                    path.strip_prefix(canonical).ok()?.to_path_buf()
+                }
+            }
+        } else {
+            path.to_path_buf()
+        };
+
+        let mut components = rel.components().filter_map(|c| match c {
+            Component::Normal(part) => part.to_str(),
+            _ => None,
+        });
+        let kind = EntityKind::from_dir_name(components.next()?)?;
+        let Some(name) = components.next() else {
+            return Some((kind, None));
+        };
+        if name.starts_with('.') || (kind == EntityKind::Change && name == "archive") {
+            return None;
+        }
+        Some((kind, Some(name.to_string())))
+    }
+}
+
+impl IndexedChange {
+    fn from_summary(summary: ChangeSummary) -> Self {
+        Self {
+            id: summary.id,
+            module_id: summary.module_id,
+            sub_module_id: summary.sub_module_id,
+            completed_tasks: summary.completed_tasks,
+            shelved_tasks: summary.shelved_tasks,
+            in_progress_tasks: summary.in_progress_tasks,
+            pending_tasks: summary.pending_tasks,
+            total_tasks: summary.total_tasks,
+            has_proposal: summary.has_proposal,
+            has_design: summary.has_design,
+            has_specs: summary.has_specs,
+            has_tasks: summary.has_tasks,
+            depends_on: summary.orchestrate.depends_on,
+            preferred_gates: summary.orchestrate.preferred_gates,
+            metadata: summary.metadata,
+        }
+    }
+
+    fn to_summary(&self, mtime_nanos: i64) -> ChangeSummary {
+        ChangeSummary {
+            id: self.id.clone(),
+            module_id: self.module_id.clone(),
+            sub_module_id: self.sub_module_id.clone(),
+            completed_tasks: self.completed_tasks,
+            shelved_tasks: self.shelved_tasks,
+            in_progress_tasks: self.in_progress_tasks,
+            pending_tasks: self.pending_tasks,
+            total_tasks: self.total_tasks,
+            last_modified: DateTime::from_timestamp_nanos(mtime_nanos),
+            has_proposal: self.has_proposal,
+            has_design: self.has_design,
+            has_specs: self.has_specs,
+            has_tasks: self.has_tasks,
+            orchestrate: ChangeOrchestrateMetadata {
+                depends_on: self.depends_on.clone(),
+                preferred_gates: self.preferred_gates.clone(),
+            },
+            metadata: self.metadata.clone(),
+        }
+    }
+}
+
+/// Path of the stored index inside `ito_path`.
+pub(crate) fn repo_index_path(ito_path: &Path) -> PathBuf {
+    ito_path.join(REPO_INDEX_REL)
+}
+
+fn list_entity_dirs(ito_path: &Path, kind: EntityKind) -> CoreResult<Vec<String>> {
+    let fs = StdFs;
+    match kind {
+        EntityKind::Change => discovery::list_change_dir_names(&fs, ito_path).into_core(),
+        EntityKind::Spec => discovery::list_spec_dir_names(&fs, ito_path).into_core(),
+        EntityKind::Module => discovery::list_module_dir_names(&fs, ito_path).into_core(),
+    }
+}
+
+/// Returns `true` when `name` is a directory the discovery listing would include.
+fn is_listed_entity_dir(ito_path: &Path, kind: EntityKind, name: &str) -> bool {
+    ito_path.join(kind.dir_name()).join(name).is_dir()
+}
+
+fn module_id_from_dir_name(name: &str) -> Option<String> {
+    let (id, _) = name.split_once('_')?;
+    (id.len() == 3 && id.chars().all(|c| c.is_ascii_digit())).then(|| id.to_string())
+}
+
+/// Latest mtime under `dir`, computed the same way as change summaries.
+fn latest_mtime_nanos(dir: &Path) -> i64 {
+    let mut latest = DateTime::<Utc>::UNIX_EPOCH;
+    for entry in walkdir::WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+    {
+        if let Ok(metadata) = entry.metadata()
+            && let Ok(modified) = metadata.modified()
+        {
+            let dt: DateTime<Utc> = modified.into();
+            if dt > latest {
+                latest = dt;
+            }
+        }
+    }
+    to_nanos(latest)
+}
+
+fn to_nanos(dt: DateTime<Utc>) -> i64 {
+    dt.timestamp_nanos_opt().unwrap_or_default()
+}
+
+#[cfg(test)]
+#[path = "incremental_tests.rs"]
+mod incremental_tests;
@@ -0,0 +1,67 @@
+use super::*;
+
+fn empty_index(ito_path: &Path) -> IncrementalRepoIndex {
+    IncrementalRepoIndex {
+        ito_path: ito_path.to_path_buf(),
+        entries: BTreeMap::new(),
+    }
+}
+
+#[test]
+fn classify_maps_paths_to_entities_and_collections() {
+    let ito = PathBuf::from("/repo/.ito");
+    let index = empty_index(&ito);
+
+    assert_eq!(
+        index.classify(&ito.join("changes/001-01_demo/specs/x/spec.md")),
+        Some((EntityKind::Change, Some("001-01_demo".to_string())))
+    );
+    assert_eq!(
+        index.classify(Path::new("specs/alpha/spec.md")),
+        Some((EntityKind::Spec, Some("alpha".to_string())))
+    );
+    assert_eq!(
+        index.classify(&ito.join("modules")),
+        Some((EntityKind::Module, None))
+    );
+}
+
+#[test]
+fn classify_ignores_archive_hidden_and_unrelated_paths() {
+    let ito = PathBuf::from("/repo/.ito");
+    let index = empty_index(&ito);
+
+    assert_eq!(
+        index.classify(&ito.join("changes/archive/2026-01-01-x")),
+        None
+    );
+    assert_eq!(index.classify(&ito.join("specs/.DS_Store")), None);
+    assert_eq!(index.classify(&ito.join(".state/repo-index.json")), None);
+    assert_eq!(index.classify(&ito.join("config.json")), None);
+    assert_eq!(
+        index.classify(Path::new("/elsewhere/changes/001-01_demo")),
+        None
+    );
+}
+
+#[test]
+fn module_ids_require_three_digit_prefix() {
+    assert_eq!(module_id_from_dir_name("014_docs"), Some("014".to_string()));
+    assert_eq!(module_id_from_dir_name("14_docs"), None);
+    assert_eq!(module_id_from_dir_name("docs"), None);
+}
+
+#[test]
+fn stored_entries_round_trip_through_json() {
+    let entry = IndexEntry {
+        mtime_nanos: 42,
+        entity: IndexedEntity::Module(IndexedModule {
+            full_name: "001_core".to_string(),
+            id: Some("001".to_string()),
+        }),
+    };
+    let json = serde_json::to_string(&entry).unwrap();
+    assert!(json.contains(r#""kind":"module""#));
+    let parsed: IndexEntry = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, entry);
+}
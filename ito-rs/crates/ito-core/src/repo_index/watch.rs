@@ -0,0 +1,92 @@
+//! Filesystem watcher that keeps an [`IncrementalRepoIndex`] warm.
+//!
+//! Enabled with the `repo-index-watch` feature. Events come from a
+//! [`FsWatchSource`] on the Ito directory; each debounced batch is passed to
+//! [`IncrementalRepoIndex::refresh`] and the index is persisted when anything
+//! changed.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use super::IncrementalRepoIndex;
+use crate::errors::{CoreError, CoreResult};
+use crate::watch::{FsWatchSource, IgnoreGlobs, StopHandle, WatchEvent, WatchSource};
+
+/// Quiet period used to batch bursts of events (editor saves, checkouts).
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Keeps a shared [`IncrementalRepoIndex`] up to date while it is alive.
+///
+/// Dropping the watcher stops the source; the background thread exits on its
+/// next poll.
+pub struct RepoIndexWatcher {
+    stop: StopHandle,
+    index: Arc<Mutex<IncrementalRepoIndex>>,
+}
+
+impl RepoIndexWatcher {
+    /// Start watching the index's Ito directory recursively.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the platform watcher cannot be created or the
+    /// Ito directory cannot be watched.
+    pub fn spawn(index: IncrementalRepoIndex) -> CoreResult<Self> {
+        let source = FsWatchSource::new(
+            vec![index.ito_path().to_path_buf()],
+            IgnoreGlobs::default(),
+            DEBOUNCE,
+        )?;
+        let stop = source.stop_handle();
+        let index = Arc::new(Mutex::new(index));
+
+        let worker_index = Arc::clone(&index);
+        std::thread::Builder::new()
+            .name("ito-repo-index".to_string())
+            .spawn(move || run(source, worker_index))
+            .map_err(|e| CoreError::io("spawning repo index watcher", e))?;
+
+        Ok(Self { stop, index })
+    }
+
+    /// Shared handle to the watched index.
+    pub fn index(&self) -> Arc<Mutex<IncrementalRepoIndex>> {
+        Arc::clone(&self.index)
+    }
+}
+
+impl Drop for RepoIndexWatcher {
+    fn drop(&mut self) {
+        self.stop.stop();
+    }
+}
+
+fn run(mut source: impl WatchSource, index: Arc<Mutex<IncrementalRepoIndex>>) {
+    while let Some(event) = source.next_event() {
+        let paths = match event {
+            WatchEvent::Changed(paths) => paths,
+            WatchEvent::RootRemoved(root) => {
+                tracing::warn!(
+                    "stopping repo index watcher: {} was removed",
+                    root.display()
+                );
+                return;
+            }
+        };
+
+        let Ok(mut index) = index.lock() else {
+            return;
+        };
+        // Writes to `.state/` (including our own save) map to no entity and
+        // produce an empty report, so saving does not retrigger a refresh.
+        match index.refresh(&paths) {
+            Ok(report) if !report.is_empty() => {
+                if let Err(e) = index.save() {
+                    tracing::warn!("failed to persist repo index: {e}");
+                }
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("repo index refresh failed: {e}"),
+        }
+    }
+}
@@ -7,6 +7,8 @@
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use ito_common::fs::{FileSystem, StdFs};
+use ito_common::git_fs::GitTreeFs;
 use ito_config::ito_dir::{absolutize_and_normalize, lexical_normalize};
 use ito_config::types::{ItoConfig, RepositoryPersistenceMode};
 use ito_config::{ConfigContext, load_cascading_project_config};
@@ -35,6 +37,7 @@ use crate::errors::{CoreError, CoreResult};
 use crate::module_repository::FsModuleRepository;
 #[cfg(feature = "backend")]
 use crate::remote_task_repository::RemoteTaskRepository;
+use crate::revision::RevisionMutationService;
 use crate::spec_repository::FsSpecRepository;
 use crate::sqlite_project_store::SqliteBackendProjectStore;
 use crate::task_mutations::FsTaskMutationService;
@@ -91,6 +94,7 @@ pub struct RepositoryRuntime {
     #[cfg(feature = "backend")]
     backend_runtime: Option<BackendRuntime>,
     sqlite_runtime: Option<SqliteRuntime>,
+    revision: Option<GitTreeFs>,
     repositories: RepositorySet,
     change_artifact_mutations: Arc<dyn ChangeArtifactMutationService + Send + Sync>,
 }
@@ -117,6 +121,11 @@ impl RepositoryRuntime {
         self.sqlite_runtime.as_ref()
     }
 
+    /// Git revision the repositories read from, if pinned to one.
+    pub fn revision(&self) -> Option<&GitTreeFs> {
+        self.revision.as_ref()
+    }
+
     /// Selected repository bundle.
     pub fn repositories(&self) -> &RepositorySet {
         &self.repositories
@@ -160,6 +169,7 @@ pub struct RepositoryRuntimeBuilder {
     #[cfg(feature = "backend")]
     backend_runtime: Option<BackendRuntime>,
     sqlite_runtime: Option<SqliteRuntime>,
+    revision: Option<GitTreeFs>,
     #[cfg(feature = "backend")]
     remote_factory: Arc<dyn RemoteRepositoryFactory>,
 }
@@ -173,6 +183,7 @@ impl RepositoryRuntimeBuilder {
             #[cfg(feature = "backend")]
             backend_runtime: None,
             sqlite_runtime: None,
+            revision: None,
             #[cfg(feature = "backend")]
             remote_factory: Arc::new(HttpRemoteRepositoryFactory),
         }
@@ -197,6 +208,16 @@ impl RepositoryRuntimeBuilder {
         self
     }
 
+    /// Read filesystem repositories from a git revision instead of the
+    /// working tree.
+    ///
+    /// Only valid in filesystem mode. Mutation services over a revision
+    /// reject every write.
+    pub fn revision(mut self, fs: GitTreeFs) -> Self {
+        self.revision = Some(fs);
+        self
+    }
+
     /// Override the remote repository factory.
     #[cfg(feature = "backend")]
     pub fn remote_factory(mut self, factory: Arc<dyn RemoteRepositoryFactory>) -> Self {
@@ -206,6 +227,25 @@ impl RepositoryRuntimeBuilder {
 
     /// Build the repository runtime.
     pub fn build(self) -> CoreResult<RepositoryRuntime> {
+        if let Some(fs) = self.revision {
+            if self.mode != PersistenceMode::Filesystem {
+                return Err(CoreError::validation(
+                    "reading from a git revision requires filesystem persistence".to_string(),
+                ));
+            }
+            let ito_path = self.ito_path;
+            let mutations = Arc::new(RevisionMutationService::new(&ito_path, fs.clone()));
+            return Ok(RepositoryRuntime {
+                mode: PersistenceMode::Filesystem,
+                ito_path: ito_path.clone(),
+                #[cfg(feature = "backend")]
+                backend_runtime: None,
+                sqlite_runtime: None,
+                revision: Some(fs.clone()),
+                repositories: revision_repository_set(&ito_path, fs, mutations.clone()),
+                change_artifact_mutations: mutations,
+            });
+        }
         match self.mode {
             PersistenceMode::Filesystem => {
                 let ito_path = self.ito_path;
@@ -216,6 +256,7 @@ impl RepositoryRuntimeBuilder {
                     #[cfg(feature = "backend")]
                     backend_runtime: None,
                     sqlite_runtime: None,
+                    revision: None,
                     repositories,
                     change_artifact_mutations: Arc::new(FsChangeArtifactMutationService::new(
                         ito_path,
@@ -237,6 +278,7 @@ impl RepositoryRuntimeBuilder {
                     #[cfg(feature = "backend")]
                     backend_runtime: None,
                     sqlite_runtime: Some(runtime),
+                    revision: None,
                     repositories,
                     change_artifact_mutations: artifact_mutations,
                 })
@@ -256,6 +298,7 @@ impl RepositoryRuntimeBuilder {
                     ito_path,
                     backend_runtime: Some(runtime),
                     sqlite_runtime: None,
+                    revision: None,
                     repositories,
                     change_artifact_mutations: artifact_mutations,
                 })
@@ -382,6 +425,27 @@ fn filesystem_repository_set(ito_path: &Path) -> RepositorySet {
     }
 }
 
+fn revision_repository_set(
+    ito_path: &Path,
+    fs: GitTreeFs,
+    mutations: Arc<RevisionMutationService>,
+) -> RepositorySet {
+    let ito_path = ito_path.to_path_buf();
+    RepositorySet {
+        changes: Arc::new(OwnedFsChangeRepository::with_fs(
+            ito_path.clone(),
+            fs.clone(),
+        )),
+        modules: Arc::new(OwnedFsModuleRepository::with_fs(
+            ito_path.clone(),
+            fs.clone(),
+        )),
+        tasks: Arc::new(OwnedFsTaskRepository::with_fs(ito_path.clone(), fs.clone())),
+        task_mutations: mutations,
+        specs: Arc::new(OwnedFsSpecRepository::with_fs(ito_path, fs)),
+    }
+}
+
 fn sqlite_repository_set(runtime: &SqliteRuntime) -> CoreResult<RepositorySet> {
     let store = SqliteBackendProjectStore::open(&runtime.db_path)?;
     store.repository_set(&runtime.org, &runtime.repo)
@@ -417,21 +481,28 @@ pub(crate) fn boxed_fs_spec_repository(ito_path: PathBuf) -> Box<dyn SpecReposit
 // ── Owned-path filesystem wrappers ─────────────────────────────────
 
 #[derive(Debug, Clone)]
-struct OwnedFsChangeRepository {
+struct OwnedFsChangeRepository<F: FileSystem + Clone = StdFs> {
     ito_path: PathBuf,
+    fs: F,
 }
 
 impl OwnedFsChangeRepository {
     fn new(ito_path: PathBuf) -> Self {
-        Self { ito_path }
+        Self::with_fs(ito_path, StdFs)
+    }
+}
+
+impl<F: FileSystem + Clone> OwnedFsChangeRepository<F> {
+    fn with_fs(ito_path: PathBuf, fs: F) -> Self {
+        Self { ito_path, fs }
     }
 
-    fn inner(&self) -> FsChangeRepository<'_> {
-        FsChangeRepository::new(&self.ito_path)
+    fn inner(&self) -> FsChangeRepository<'_, F> {
+        FsChangeRepository::with_fs(&self.ito_path, self.fs.clone())
     }
 }
 
-impl ChangeRepository for OwnedFsChangeRepository {
+impl<F: FileSystem + Clone> ChangeRepository for OwnedFsChangeRepository<F> {
     fn resolve_target_with_options(
         &self,
         input: &str,
@@ -503,21 +574,28 @@ impl ChangeRepository for OwnedFsChangeRepository {
 }
 
 #[derive(Debug, Clone)]
-struct OwnedFsModuleRepository {
+struct OwnedFsModuleRepository<F: FileSystem + Clone = StdFs> {
     ito_path: PathBuf,
+    fs: F,
 }
 
 impl OwnedFsModuleRepository {
     fn new(ito_path: PathBuf) -> Self {
-        Self { ito_path }
+        Self::with_fs(ito_path, StdFs)
+    }
+}
+
+impl<F: FileSystem + Clone> OwnedFsModuleRepository<F> {
+    fn with_fs(ito_path: PathBuf, fs: F) -> Self {
+        Self { ito_path, fs }
     }
 
-    fn inner(&self) -> FsModuleRepository<'_> {
-        FsModuleRepository::new(&self.ito_path)
+    fn inner(&self) -> FsModuleRepository<'_, F> {
+        FsModuleRepository::with_fs(&self.ito_path, self.fs.clone())
     }
 }
 
-impl ModuleRepository for OwnedFsModuleRepository {
+impl<F: FileSystem + Clone> ModuleRepository for OwnedFsModuleRepository<F> {
     fn exists(&self, id: &str) -> bool {
         self.inner().exists(id)
     }
@@ -549,21 +627,28 @@ impl ModuleRepository for OwnedFsModuleRepository {
 }
 
 #[derive(Debug, Clone)]
-struct OwnedFsTaskRepository {
+struct OwnedFsTaskRepository<F: FileSystem + Clone = StdFs> {
     ito_path: PathBuf,
+    fs: F,
 }
 
 impl OwnedFsTaskRepository {
     fn new(ito_path: PathBuf) -> Self {
-        Self { ito_path }
+        Self::with_fs(ito_path, StdFs)
+    }
+}
+
+impl<F: FileSystem + Clone> OwnedFsTaskRepository<F> {
+    fn with_fs(ito_path: PathBuf, fs: F) -> Self {
+        Self { ito_path, fs }
     }
 
-    fn inner(&self) -> FsTaskRepository<'_> {
-        FsTaskRepository::new(&self.ito_path)
+    fn inner(&self) -> FsTaskRepository<'_, F> {
+        FsTaskRepository::with_fs(&self.ito_path, self.fs.clone())
     }
 }
 
-impl TaskRepository for OwnedFsTaskRepository {
+impl<F: FileSystem + Clone> TaskRepository for OwnedFsTaskRepository<F> {
     fn load_tasks(
         &self,
         change_id: &str,
@@ -573,21 +658,28 @@ impl TaskRepository for OwnedFsTaskRepository {
 }
 
 #[derive(Debug, Clone)]
-struct OwnedFsSpecRepository {
+struct OwnedFsSpecRepository<F: FileSystem + Clone = StdFs> {
     ito_path: PathBuf,
+    fs: F,
 }
 
 impl OwnedFsSpecRepository {
     fn new(ito_path: PathBuf) -> Self {
-        Self { ito_path }
+        Self::with_fs(ito_path, StdFs)
+    }
+}
+
+impl<F: FileSystem + Clone> OwnedFsSpecRepository<F> {
+    fn with_fs(ito_path: PathBuf, fs: F) -> Self {
+        Self { ito_path, fs }
     }
 
-    fn inner(&self) -> FsSpecRepository<'_> {
-        FsSpecRepository::new(&self.ito_path)
+    fn inner(&self) -> FsSpecRepository<'_, F> {
+        FsSpecRepository::with_fs(&self.ito_path, self.fs.clone())
     }
 }
 
-impl SpecRepository for OwnedFsSpecRepository {
+impl<F: FileSystem + Clone> SpecRepository for OwnedFsSpecRepository<F> {
     fn list(&self) -> ito_domain::errors::DomainResult<Vec<ito_domain::specs::SpecSummary>> {
         self.inner().list()
     }
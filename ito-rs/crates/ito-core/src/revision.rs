@@ -0,0 +1,210 @@
+//! Read-only Ito state at a git revision.
+//!
+//! Repositories built over [`GitTreeFs`] answer queries from a past commit
+//! (see [`RepositoryRuntimeBuilder::revision`]). This module supplies the
+//! pieces that cannot simply swap their filesystem: mutation services that
+//! refuse to write, and change status, whose artifact detection globs the
+//! real filesystem.
+//!
+//! [`RepositoryRuntimeBuilder::revision`]: crate::repository_runtime::RepositoryRuntimeBuilder::revision
+
+use std::path::{Path, PathBuf};
+
+use ito_common::fs::{FileSystem, StdFs};
+use ito_common::git_fs::GitTreeFs;
+use ito_common::paths;
+use ito_config::ConfigContext;
+use ito_domain::changes::{
+    ChangeArtifactMutationError, ChangeArtifactMutationResult, ChangeArtifactMutationService,
+    ChangeArtifactMutationServiceResult, ChangeArtifactRef,
+};
+use ito_domain::tasks::{
+    TaskInitResult, TaskMutationError, TaskMutationResult, TaskMutationService,
+    TaskMutationServiceResult,
+};
+
+use crate::artifact_mutations::artifact_path;
+use crate::templates::{ChangeStatus, TemplatesError, compute_change_status};
+
+/// Task and artifact mutation services pinned to a git revision.
+///
+/// Reads come from the revision's tree; every write fails with a validation
+/// error naming the revision.
+#[derive(Debug, Clone)]
+pub struct RevisionMutationService {
+    ito_path: PathBuf,
+    fs: GitTreeFs,
+}
+
+impl RevisionMutationService {
+    /// Create read-only mutation services for `ito_path` at `fs`'s revision.
+    pub fn new(ito_path: impl Into<PathBuf>, fs: GitTreeFs) -> Self {
+        Self {
+            ito_path: ito_path.into(),
+            fs,
+        }
+    }
+
+    fn read_only(&self, what: &str) -> String {
+        format!(
+            "Cannot modify {what} at revision '{}': historical state is read-only",
+            self.fs.revision()
+        )
+    }
+
+    fn reject_task<T>(&self) -> TaskMutationServiceResult<T> {
+        Err(TaskMutationError::validation(self.read_only("tasks")))
+    }
+
+    fn reject_artifact<T>(&self) -> ChangeArtifactMutationServiceResult<T> {
+        Err(ChangeArtifactMutationError::validation(
+            self.read_only("change artifacts"),
+        ))
+    }
+}
+
+impl TaskMutationService for RevisionMutationService {
+    fn load_tasks_markdown(&self, change_id: &str) -> TaskMutationServiceResult<Option<String>> {
+        let path = crate::tasks::tracking_file_path(&self.ito_path, change_id)
+            .map_err(|err| TaskMutationError::validation(err.to_string()))?;
+        if !self.fs.is_file(&path) {
+            return Ok(None);
+        }
+        let contents = self
+            .fs
+            .read_to_string(&path)
+            .map_err(|err| TaskMutationError::io("reading tasks markdown", err))?;
+        Ok(Some(contents))
+    }
+
+    fn init_tasks(&self, _change_id: &str) -> TaskMutationServiceResult<TaskInitResult> {
+        self.reject_task()
+    }
+
+    fn start_task(
+        &self,
+        _change_id: &str,
+        _task_id: &str,
+    ) -> TaskMutationServiceResult<TaskMutationResult> {
+        self.reject_task()
+    }
+
+    fn complete_task(
+        &self,
+        _change_id: &str,
+        _task_id: &str,
+        _note: Option<String>,
+    ) -> TaskMutationServiceResult<TaskMutationResult> {
+        self.reject_task()
+    }
+
+    fn shelve_task(
+        &self,
+        _change_id: &str,
+        _task_id: &str,
+        _reason: Option<String>,
+    ) -> TaskMutationServiceResult<TaskMutationResult> {
+        self.reject_task()
+    }
+
+    fn unshelve_task(
+        &self,
+        _change_id: &str,
+        _task_id: &str,
+    ) -> TaskMutationServiceResult<TaskMutationResult> {
+        self.reject_task()
+    }
+
+    fn release_task(
+        &self,
+        _change_id: &str,
+        _task_id: &str,
+    ) -> TaskMutationServiceResult<TaskMutationResult> {
+        self.reject_task()
+    }
+
+    fn add_task(
+        &self,
+        _change_id: &str,
+        _title: &str,
+        _wave: Option<u32>,
+    ) -> TaskMutationServiceResult<TaskMutationResult> {
+        self.reject_task()
+    }
+}
+
+impl ChangeArtifactMutationService for RevisionMutationService {
+    fn load_artifact(
+        &self,
+        target: &ChangeArtifactRef,
+    ) -> ChangeArtifactMutationServiceResult<Option<String>> {
+        let path = artifact_path(&self.ito_path, target)?;
+        if !self.fs.is_file(&path) {
+            return Ok(None);
+        }
+        let contents = self
+            .fs
+            .read_to_string(&path)
+            .map_err(|err| ChangeArtifactMutationError::io("reading change artifact", err))?;
+        Ok(Some(contents))
+    }
+
+    fn write_artifact(
+        &self,
+        _target: &ChangeArtifactRef,
+        _content: &str,
+    ) -> ChangeArtifactMutationServiceResult<ChangeArtifactMutationResult> {
+        self.reject_artifact()
+    }
+
+    fn patch_artifact(
+        &self,
+        _target: &ChangeArtifactRef,
+        _patch: &str,
+    ) -> ChangeArtifactMutationServiceResult<ChangeArtifactMutationResult> {
+        self.reject_artifact()
+    }
+}
+
+/// Compute a change's artifact status as of `fs`'s revision.
+///
+/// Artifact detection globs the real filesystem, so the change directory is
+/// copied out of the revision into a temporary `.ito/` first. Schemas still
+/// resolve against the project configuration in `ctx`.
+///
+/// # Errors
+///
+/// Returns [`TemplatesError::ChangeNotFound`] when the change is absent at
+/// the revision, plus any error [`compute_change_status`] reports.
+pub fn compute_change_status_at(
+    fs: &GitTreeFs,
+    ito_path: &Path,
+    change: &str,
+    schema_name: Option<&str>,
+    ctx: &ConfigContext,
+) -> Result<ChangeStatus, TemplatesError> {
+    let change_dir = paths::change_dir(ito_path, change);
+    if !fs.is_dir(&change_dir) {
+        return Err(TemplatesError::ChangeNotFound(change.to_string()));
+    }
+
+    let snapshot = tempfile::tempdir()?;
+    let snapshot_ito = snapshot.path().join(".ito");
+    copy_tree(fs, &change_dir, &paths::change_dir(&snapshot_ito, change))?;
+    compute_change_status(&snapshot_ito, change, schema_name, ctx)
+}
+
+fn copy_tree(fs: &GitTreeFs, from: &Path, to: &Path) -> std::io::Result<()> {
+    StdFs.create_dir_all(to)?;
+    for child in fs.read_dir(from)? {
+        let Some(name) = child.file_name() else {
+            continue;
+        };
+        if fs.is_dir(&child) {
+            copy_tree(fs, &child, &to.join(name))?;
+        } else {
+            StdFs.write(&to.join(name), &fs.read(&child)?)?;
+        }
+    }
+    Ok(())
+}
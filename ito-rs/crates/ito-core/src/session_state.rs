@@ -0,0 +1,303 @@
+//! Per-worktree session state stored in `.ito/session.json`.
+//!
+//! The file is gitignored, but several ito processes can share one worktree
+//! (a ralph loop alongside manual `ito tasks` commands). All access goes
+//! through [`SessionStore`], which writes atomically (temp file + rename) and
+//! uses a monotonically increasing `revision` for optimistic concurrency:
+//! a mutation whose base revision is stale is re-applied to the fresh state.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime};
+
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{CoreError, CoreResult};
+
+/// File name of the session state file inside the Ito directory.
+pub const SESSION_FILE_NAME: &str = "session.json";
+
+const LOCK_SUFFIX: &str = ".lock";
+const MAX_ATTEMPTS: u32 = 50;
+const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+/// Fallback for locks whose holder cannot be checked. Must stay above
+/// [`LOCK_TIMEOUT`] so a live writer's lock is never stolen; crashed writers
+/// are normally detected sooner through the PID recorded in the lock file.
+const STALE_LOCK_AGE: Duration = Duration::from_secs(30);
+
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Typed contents of `.ito/session.json`.
+///
+/// Fields this version of ito does not know about are kept in `extra` so a
+/// rewrite by an older binary does not drop them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionState {
+    /// Incremented on every successful write.
+    #[serde(default)]
+    pub revision: u64,
+    /// Identifier grouping commands run in this worktree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    /// RFC 3339 timestamp of when `session_id` was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// Unknown fields, preserved verbatim.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Read/write access to one worktree's `session.json`.
+#[derive(Debug, Clone)]
+pub struct SessionStore {
+    path: PathBuf,
+}
+
+impl SessionStore {
+    /// Store for `<ito_path>/session.json`.
+    pub fn new(ito_path: &Path) -> Self {
+        Self {
+            path: ito_path.join(SESSION_FILE_NAME),
+        }
+    }
+
+    /// Path of the backing file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Read the current state.
+    ///
+    /// A missing file yields the default state. A file that cannot be parsed
+    /// is renamed aside (`session.json.corrupt-<timestamp>`) and the default
+    /// state is returned so the next write recreates it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error when the file exists but cannot be read.
+    pub fn load(&self) -> CoreResult<SessionState> {
+        let contents = match std::fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(SessionState::default());
+            }
+            Err(e) => return Err(CoreError::io("reading session.json", e)),
+        };
+        match serde_json::from_str(&contents) {
+            Ok(state) => Ok(state),
+            Err(e) => {
+                tracing::warn!("session.json is corrupted ({e}); backing it up and starting over");
+                self.back_up_corrupted();
+                Ok(SessionState::default())
+            }
+        }
+    }
+
+    /// Apply `mutate` to the current state and persist the result.
+    ///
+    /// If another writer committed in between, the state is re-read and
+    /// `mutate` runs again on the fresh copy, so it must be safe to call more
+    /// than once. Returns the value produced by the committed attempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the file cannot be written or the update keeps
+    /// conflicting with other writers.
+    pub fn update<R>(&self, mut mutate: impl FnMut(&mut SessionState) -> R) -> CoreResult<R> {
+        for _ in 0..MAX_ATTEMPTS {
+            let base = self.load()?;
+            let mut next = base.clone();
+            let out = mutate(&mut next);
+            if next == base {
+                return Ok(out);
+            }
+            next.revision = base.revision + 1;
+            if self.commit(base.revision, &next)? {
+                return Ok(out);
+            }
+        }
+        Err(CoreError::validation(format!(
+            "session.json kept changing under concurrent writers; gave up after {MAX_ATTEMPTS} attempts"
+        )))
+    }
+
+    /// Return the persisted session id, creating one if none is stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the state cannot be read or written.
+    pub fn ensure_session_id(&self) -> CoreResult<String> {
+        let fresh = new_session_id();
+        self.update(|state| {
+            if let Some(id) = state.session_id.as_deref()
+                && is_safe_session_id(id)
+            {
+                return id.to_string();
+            }
+            state.session_id = Some(fresh.clone());
+            state.created_at = Some(Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true));
+            fresh.clone()
+        })
+    }
+
+    /// Write `next` if the on-disk revision still equals `expected`.
+    fn commit(&self, expected: u64, next: &SessionState) -> CoreResult<bool> {
+        let _lock = self.lock()?;
+        if self.load()?.revision != expected {
+            return Ok(false);
+        }
+        let body = serde_json::to_string_pretty(next)
+            .map_err(|e| CoreError::serde("serializing session.json", e.to_string()))?;
+        write_atomic_unique(&self.path, body.as_bytes())
+            .map_err(|e| CoreError::io("writing session.json", e))?;
+        Ok(true)
+    }
+
+    /// Short-lived exclusive lock guarding the compare-and-rename step.
+    fn lock(&self) -> CoreResult<LockGuard> {
+        let path = sibling(&self.path, LOCK_SUFFIX);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| CoreError::io("creating session.json directory", e))?;
+        }
+        let started = Instant::now();
+        loop {
+            match std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(mut file) => {
+                    use std::io::Write;
+                    let _ = write!(file, "{}", std::process::id());
+                    return Ok(LockGuard { path });
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    if lock_is_stale(&path) {
+                        let _ = std::fs::remove_file(&path);
+                        continue;
+                    }
+                    if started.elapsed() > LOCK_TIMEOUT {
+                        return Err(CoreError::validation(format!(
+                            "timed out waiting for {}",
+                            path.display()
+                        )));
+                    }
+                    std::thread::sleep(Duration::from_millis(2));
+                }
+                Err(e) => return Err(CoreError::io("locking session.json", e)),
+            }
+        }
+    }
+
+    fn back_up_corrupted(&self) {
+        let stamp = Utc::now().format("%Y%m%dT%H%M%S%.3f");
+        let backup = sibling(&self.path, &format!(".corrupt-{stamp}"));
+        if let Err(e) = std::fs::rename(&self.path, &backup) {
+            tracing::debug!("failed to back up corrupted session.json: {e}");
+        }
+    }
+}
+
+/// Resolve the session id for `ito_path`, or `None` when there is no Ito
+/// directory or the state cannot be persisted.
+pub fn resolve_session_id(ito_path: &Path) -> Option<String> {
+    if !ito_path.is_dir() {
+        return None;
+    }
+    match SessionStore::new(ito_path).ensure_session_id() {
+        Ok(id) => Some(id),
+        Err(e) => {
+            tracing::debug!("failed to resolve session id: {e}");
+            None
+        }
+    }
+}
+
+/// Whether `session_id` is safe to embed in a file name.
+pub fn is_safe_session_id(session_id: &str) -> bool {
+    let session_id = session_id.trim();
+    !session_id.is_empty()
+        && session_id.len() <= 128
+        && session_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+fn new_session_id() -> String {
+    let ts = Utc::now().timestamp();
+    let rand = uuid::Uuid::new_v4().simple().to_string();
+    format!("{ts}-{rand}")
+}
+
+struct LockGuard {
+    path: PathBuf,
+}
+
+impl Drop for LockGuard {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// A lock is stale when the process that wrote it is gone, or when it is
+/// older than [`STALE_LOCK_AGE`].
+fn lock_is_stale(path: &Path) -> bool {
+    let holder = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|pid| pid.trim().parse::<u32>().ok());
+    if holder.is_some_and(|pid| !process_is_alive(pid)) {
+        return true;
+    }
+    let Ok(modified) = std::fs::metadata(path).and_then(|m| m.modified()) else {
+        return false;
+    };
+    SystemTime::now()
+        .duration_since(modified)
+        .is_ok_and(|age| age > STALE_LOCK_AGE)
+}
+
+#[cfg(unix)]
+fn process_is_alive(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return true;
+    };
+    // SAFETY: signal 0 only checks that `pid` exists; nothing is delivered.
+    let rc = unsafe { libc::kill(pid, 0) };
+    rc == 0 || std::io::Error::last_os_error().raw_os_error() != Some(libc::ESRCH)
+}
+
+#[cfg(not(unix))]
+fn process_is_alive(_pid: u32) -> bool {
+    true
+}
+
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// Like `write_atomic_std`, but with a temp name unique per writer so threads
+/// in one process cannot clobber each other's temp files.
+fn write_atomic_unique(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let n = TEMP_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let tmp = sibling(path, &format!(".tmp.{}.{n}", std::process::id()));
+    std::fs::write(&tmp, contents)?;
+
+    #[cfg(windows)]
+    {
+        let _ = std::fs::remove_file(path);
+    }
+
+    let r = std::fs::rename(&tmp, path);
+    if r.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    r
+}
+
+#[cfg(test)]
+#[path = "session_state_tests.rs"]
+mod session_state_tests;
@@ -0,0 +1,157 @@
+use super::*;
+use std::sync::Arc;
+
+fn read_json(path: &Path) -> serde_json::Value {
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn missing_file_loads_default_state() {
+    let td = tempfile::tempdir().unwrap();
+    let store = SessionStore::new(td.path());
+    assert_eq!(store.load().unwrap(), SessionState::default());
+}
+
+#[test]
+fn ensure_session_id_persists_and_is_stable() {
+    let td = tempfile::tempdir().unwrap();
+    let store = SessionStore::new(td.path());
+
+    let first = store.ensure_session_id().unwrap();
+    let second = store.ensure_session_id().unwrap();
+    assert_eq!(first, second);
+    assert!(is_safe_session_id(&first));
+
+    let state = store.load().unwrap();
+    assert_eq!(state.revision, 1);
+    assert!(state.created_at.is_some());
+}
+
+#[test]
+fn update_bumps_revision_on_each_write() {
+    let td = tempfile::tempdir().unwrap();
+    let store = SessionStore::new(td.path());
+    for n in 1..=3u64 {
+        store
+            .update(|s| {
+                s.extra.insert("n".to_string(), n.into());
+            })
+            .unwrap();
+    }
+    assert_eq!(store.load().unwrap().revision, 3);
+}
+
+#[test]
+fn interleaved_updates_from_two_threads_are_all_applied() {
+    let td = tempfile::tempdir().unwrap();
+    let store = Arc::new(SessionStore::new(td.path()));
+
+    let handles: Vec<_> = ["a", "b"]
+        .into_iter()
+        .map(|key| {
+            let store = Arc::clone(&store);
+            std::thread::spawn(move || {
+                for _ in 0..25 {
+                    store
+                        .update(|s| {
+                            let entry = s.extra.entry(key.to_string()).or_insert(0.into());
+                            *entry = (entry.as_u64().unwrap_or(0) + 1).into();
+                        })
+                        .unwrap();
+                }
+            })
+        })
+        .collect();
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    let state = store.load().unwrap();
+    assert_eq!(state.extra["a"], 25);
+    assert_eq!(state.extra["b"], 25);
+    assert_eq!(state.revision, 50);
+}
+
+#[cfg(unix)]
+#[test]
+fn lock_left_by_a_dead_process_is_reclaimed() {
+    let td = tempfile::tempdir().unwrap();
+    let store = SessionStore::new(td.path());
+    let mut child = std::process::Command::new("true").spawn().unwrap();
+    let dead_pid = child.id();
+    child.wait().unwrap();
+    std::fs::write(sibling(&store.path, LOCK_SUFFIX), dead_pid.to_string()).unwrap();
+
+    let started = Instant::now();
+    store.update(|_| {}).unwrap();
+    assert!(started.elapsed() < LOCK_TIMEOUT);
+}
+
+#[test]
+fn fresh_lock_held_by_a_live_process_is_not_stale() {
+    let td = tempfile::tempdir().unwrap();
+    let lock = sibling(&td.path().join(SESSION_FILE_NAME), LOCK_SUFFIX);
+    std::fs::write(&lock, std::process::id().to_string()).unwrap();
+    assert!(!lock_is_stale(&lock));
+}
+
+#[test]
+fn unknown_fields_survive_a_rewrite() {
+    let td = tempfile::tempdir().unwrap();
+    let store = SessionStore::new(td.path());
+    std::fs::write(
+        store.path(),
+        r#"{"session_id":"123-abc","created_at":"2026-01-01T00:00:00Z","future_field":{"x":[1,2]}}"#,
+    )
+    .unwrap();
+
+    store
+        .update(|s| {
+            s.extra.insert("other".to_string(), "value".into());
+        })
+        .unwrap();
+
+    let json = read_json(store.path());
+    assert_eq!(json["future_field"]["x"][1], 2);
+    assert_eq!(json["other"], "value");
+    assert_eq!(json["session_id"], "123-abc");
+    assert_eq!(json["revision"], 1);
+}
+
+#[test]
+fn corrupted_file_is_backed_up_and_recreated() {
+    let td = tempfile::tempdir().unwrap();
+    let store = SessionStore::new(td.path());
+    std::fs::write(store.path(), "{ not json").unwrap();
+
+    let id = store.ensure_session_id().unwrap();
+    assert!(is_safe_session_id(&id));
+    assert_eq!(read_json(store.path())["session_id"], id.as_str());
+
+    let backups: Vec<String> = std::fs::read_dir(td.path())
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .filter(|n| n.starts_with("session.json.corrupt-"))
+        .collect();
+    assert_eq!(backups.len(), 1);
+    let backup = std::fs::read_to_string(td.path().join(&backups[0])).unwrap();
+    assert_eq!(backup, "{ not json");
+}
+
+#[test]
+fn unsafe_stored_session_id_is_replaced() {
+    let td = tempfile::tempdir().unwrap();
+    let store = SessionStore::new(td.path());
+    std::fs::write(store.path(), r#"{"session_id":"../escape"}"#).unwrap();
+
+    let id = store.ensure_session_id().unwrap();
+    assert_ne!(id, "../escape");
+    assert!(is_safe_session_id(&id));
+}
+
+#[test]
+fn resolve_session_id_requires_an_ito_dir() {
+    let td = tempfile::tempdir().unwrap();
+    assert!(resolve_session_id(&td.path().join("missing")).is_none());
+    assert!(resolve_session_id(td.path()).is_some());
+}
@@ -0,0 +1,269 @@
+//! Requirement coverage: which changes touch each requirement of a spec.
+//!
+//! Delta specs from active and archived changes are matched against the
+//! requirements of the current base specs by title. Titles are compared after
+//! normalizing case and whitespace; when that fails, the closest title within
+//! a small edit distance is used and the reference is flagged as a possible
+//! match. References that match nothing are reported as orphaned (usually a
+//! requirement that was renamed after the change was written).
+
+use ito_common::match_::levenshtein;
+use ito_domain::changes::{ChangeLifecycleFilter, ChangeRepository};
+use ito_domain::specs::SpecRepository;
+use serde::Serialize;
+
+use super::{
+    DeltaSpecFile, parse_change_show_json, parse_spec_show_json, read_spec_markdown_from_repository,
+};
+use crate::error_bridge::IntoCoreResult;
+use crate::errors::CoreResult;
+
+/// A change delta that references a requirement.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CoverageReference {
+    /// Change id (without any archive date prefix).
+    #[serde(rename = "changeId")]
+    pub change_id: String,
+    /// Delta operation (`ADDED`, `MODIFIED`, `REMOVED`, `RENAMED`).
+    pub operation: String,
+    /// Whether the change has been archived.
+    pub archived: bool,
+    /// Requirement title as written in the delta.
+    pub title: String,
+    /// True when the title only matched approximately.
+    #[serde(rename = "possibleMatch", skip_serializing_if = "std::ops::Not::not")]
+    pub possible_match: bool,
+}
+
+/// Coverage for one requirement of the base spec.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RequirementCoverage {
+    /// Requirement anchor within the spec.
+    pub anchor: String,
+    /// Requirement title.
+    pub title: String,
+    /// Normalized requirement statement.
+    pub text: String,
+    /// Changes whose deltas reference this requirement.
+    #[serde(rename = "touchedBy")]
+    pub touched_by: Vec<CoverageReference>,
+}
+
+impl RequirementCoverage {
+    /// Returns `true` when no change references this requirement.
+    pub fn is_uncovered(&self) -> bool {
+        self.touched_by.is_empty()
+    }
+}
+
+/// Summary counts for a coverage report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct CoverageSummary {
+    /// Requirements in the base spec(s).
+    pub requirements: u32,
+    /// Requirements referenced by at least one change.
+    pub covered: u32,
+    /// Requirements never referenced by any change.
+    pub uncovered: u32,
+    /// Delta references that match no base requirement.
+    pub orphaned: u32,
+}
+
+impl CoverageSummary {
+    fn add(&mut self, other: CoverageSummary) {
+        self.requirements += other.requirements;
+        self.covered += other.covered;
+        self.uncovered += other.uncovered;
+        self.orphaned += other.orphaned;
+    }
+}
+
+/// Coverage for one spec.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SpecCoverage {
+    /// Spec id.
+    #[serde(rename = "specId")]
+    pub spec_id: String,
+    /// Per-requirement coverage, in spec order.
+    pub requirements: Vec<RequirementCoverage>,
+    /// Delta references that match no requirement in the base spec.
+    pub orphaned: Vec<CoverageReference>,
+    /// Counts for this spec.
+    pub summary: CoverageSummary,
+}
+
+/// Coverage for one or more specs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CoverageReport {
+    /// Per-spec coverage, ordered by spec id.
+    pub specs: Vec<SpecCoverage>,
+    /// Totals across all specs.
+    pub summary: CoverageSummary,
+}
+
+/// Compute requirement coverage for `spec_id`, or for every spec when `None`.
+///
+/// Unmatched `ADDED` deltas in active changes are expected (the requirement
+/// lands in the base spec on archive) and unmatched `REMOVED` deltas in
+/// archived changes are expected (the requirement is gone), so neither is
+/// reported as orphaned.
+///
+/// # Errors
+///
+/// Fails when `spec_id` names a spec that does not exist.
+pub fn compute_spec_coverage(
+    spec_repo: &(impl SpecRepository + ?Sized),
+    change_repo: &(impl ChangeRepository + ?Sized),
+    spec_id: Option<&str>,
+) -> CoreResult<CoverageReport> {
+    let spec_ids: Vec<String> = match spec_id {
+        Some(id) => vec![id.to_string()],
+        None => {
+            let mut ids: Vec<String> = spec_repo
+                .list()
+                .into_core()?
+                .into_iter()
+                .map(|s| s.id)
+                .collect();
+            ids.sort();
+            ids
+        }
+    };
+
+    let deltas = collect_deltas(change_repo)?;
+
+    let mut specs = Vec::with_capacity(spec_ids.len());
+    let mut summary = CoverageSummary::default();
+    for id in spec_ids {
+        let markdown = read_spec_markdown_from_repository(spec_repo, &id)?;
+        let coverage = spec_coverage(&id, &markdown, &deltas);
+        summary.add(coverage.summary);
+        specs.push(coverage);
+    }
+    Ok(CoverageReport { specs, summary })
+}
+
+/// One requirement referenced by a change delta.
+struct DeltaRef {
+    spec: String,
+    reference: CoverageReference,
+}
+
+fn collect_deltas(change_repo: &(impl ChangeRepository + ?Sized)) -> CoreResult<Vec<DeltaRef>> {
+    let mut out = Vec::new();
+    for (filter, archived) in [
+        (ChangeLifecycleFilter::Active, false),
+        (ChangeLifecycleFilter::Archived, true),
+    ] {
+        let mut summaries = change_repo.list_with_filter(filter).into_core()?;
+        summaries.sort_by(|a, b| a.id.cmp(&b.id));
+        for summary in summaries {
+            let change = change_repo
+                .get_with_filter(&summary.id, filter)
+                .into_core()?;
+            let files: Vec<DeltaSpecFile> = change
+                .specs
+                .into_iter()
+                .map(|s| DeltaSpecFile {
+                    spec: s.name,
+                    markdown: s.content,
+                })
+                .collect();
+            let show = parse_change_show_json(&summary.id, &files);
+            for delta in show.deltas {
+                out.push(DeltaRef {
+                    spec: delta.spec,
+                    reference: CoverageReference {
+                        change_id: summary.id.clone(),
+                        operation: delta.operation,
+                        archived,
+                        title: delta.requirement.title,
+                        possible_match: false,
+                    },
+                });
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn spec_coverage(spec_id: &str, markdown: &str, deltas: &[DeltaRef]) -> SpecCoverage {
+    let spec = parse_spec_show_json(spec_id, markdown);
+    let normalized: Vec<String> = spec
+        .requirements
+        .iter()
+        .map(|r| normalize_title(&r.title))
+        .collect();
+    let mut requirements: Vec<RequirementCoverage> = spec
+        .requirements
+        .into_iter()
+        .map(|r| RequirementCoverage {
+            anchor: r.anchor,
+            title: r.title,
+            text: r.text,
+            touched_by: Vec::new(),
+        })
+        .collect();
+
+    let mut orphaned = Vec::new();
+    for delta in deltas.iter().filter(|d| d.spec == spec_id) {
+        let mut reference = delta.reference.clone();
+        let wanted = normalize_title(&reference.title);
+        let index = match normalized.iter().position(|t| *t == wanted) {
+            Some(i) => Some(i),
+            None => {
+                let fuzzy = nearest_title(&wanted, &normalized);
+                reference.possible_match = fuzzy.is_some();
+                fuzzy
+            }
+        };
+        match index {
+            Some(i) => requirements[i].touched_by.push(reference),
+            None if is_expected_unmatched(&reference) => {}
+            None => orphaned.push(reference),
+        }
+    }
+
+    let covered = requirements.iter().filter(|r| !r.is_uncovered()).count() as u32;
+    let summary = CoverageSummary {
+        requirements: requirements.len() as u32,
+        covered,
+        uncovered: requirements.len() as u32 - covered,
+        orphaned: orphaned.len() as u32,
+    };
+    SpecCoverage {
+        spec_id: spec_id.to_string(),
+        requirements,
+        orphaned,
+        summary,
+    }
+}
+
+fn is_expected_unmatched(reference: &CoverageReference) -> bool {
+    match reference.operation.as_str() {
+        "ADDED" => !reference.archived,
+        "REMOVED" => reference.archived,
+        _ => false,
+    }
+}
+
+/// Lowercase and collapse runs of whitespace.
+fn normalize_title(title: &str) -> String {
+    title
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Index of the closest title within roughly a third of the title's length.
+fn nearest_title(wanted: &str, titles: &[String]) -> Option<usize> {
+    let max_distance = (wanted.chars().count() / 3).max(1);
+    titles
+        .iter()
+        .enumerate()
+        .map(|(i, t)| (levenshtein(wanted, t), i))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, i)| i)
+}
@@ -0,0 +1,314 @@
+//! Compare two changes artifact by artifact.
+//!
+//! Each change is read against its own schema, so changes created from
+//! different schemas still line up on shared artifact ids. For every artifact
+//! the report records which change has it and, for files present in both, a
+//! unified diff. Tasks are compared by id.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use ito_config::ConfigContext;
+use ito_domain::changes::{
+    Change, ChangeLifecycleFilter, ChangeRepository, ChangeTargetResolution, ResolveTargetOptions,
+    target_match_ids,
+};
+use ito_domain::tasks::TaskItem;
+use serde::Serialize;
+
+use crate::error_bridge::IntoCoreResult;
+use crate::errors::{CoreError, CoreResult};
+use crate::templates::{artifact_output_files, resolve_change_dir_schema};
+
+/// Files larger than this (in either change) are reported without a diff.
+pub const MAX_DIFF_BYTES: u64 = 256 * 1024;
+
+/// How one artifact file compares between the two changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FileDiffStatus {
+    /// Only the first change has the file.
+    OnlyInA,
+    /// Only the second change has the file.
+    OnlyInB,
+    /// Both changes have the file with the same content.
+    Identical,
+    /// Both changes have the file with different content.
+    Changed,
+}
+
+/// One artifact output file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileDiff {
+    /// Path relative to the change directory.
+    pub path: String,
+    /// Presence and equality of the file.
+    pub status: FileDiffStatus,
+    /// Unified diff from A to B, for changed files within [`MAX_DIFF_BYTES`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+    /// Why a changed file has no diff.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Presence of one schema artifact in each change.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ArtifactDiff {
+    /// Artifact id from the schema.
+    pub id: String,
+    /// Whether the first change has any output for the artifact.
+    #[serde(rename = "inA")]
+    pub in_a: bool,
+    /// Whether the second change has any output for the artifact.
+    #[serde(rename = "inB")]
+    pub in_b: bool,
+    /// Output files present in either change, ordered by path.
+    pub files: Vec<FileDiff>,
+}
+
+/// A task as it appears in one change.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TaskSnapshot {
+    /// Task id.
+    pub id: String,
+    /// Task name.
+    pub name: String,
+    /// Task status label (`pending`, `in-progress`, `complete`, `shelved`).
+    pub status: String,
+}
+
+/// A task id present in both changes with a different name or status.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TaskChange {
+    /// Task id.
+    pub id: String,
+    /// The task in the first change.
+    pub a: TaskSnapshot,
+    /// The task in the second change.
+    pub b: TaskSnapshot,
+}
+
+/// Task set comparison keyed by task id.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct TaskDiff {
+    /// Tasks only the first change has.
+    #[serde(rename = "onlyInA")]
+    pub only_in_a: Vec<TaskSnapshot>,
+    /// Tasks only the second change has.
+    #[serde(rename = "onlyInB")]
+    pub only_in_b: Vec<TaskSnapshot>,
+    /// Tasks whose name or status differs.
+    pub changed: Vec<TaskChange>,
+}
+
+/// Comparison of two changes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChangeDiff {
+    /// Resolved id of the first change.
+    #[serde(rename = "changeA")]
+    pub change_a: String,
+    /// Resolved id of the second change.
+    #[serde(rename = "changeB")]
+    pub change_b: String,
+    /// Per-artifact comparison, in schema order (A's schema first).
+    pub artifacts: Vec<ArtifactDiff>,
+    /// Task comparison.
+    pub tasks: TaskDiff,
+}
+
+/// Compare `change_a` with `change_b`, optionally limited to one artifact id.
+///
+/// Both targets may name active or archived changes; nothing is written.
+///
+/// # Errors
+///
+/// Fails when a target does not resolve to exactly one change, a change's
+/// schema cannot be resolved, or `artifact` is not defined by either schema.
+pub fn diff_changes(
+    change_repo: &(impl ChangeRepository + ?Sized),
+    ctx: &ConfigContext,
+    change_a: &str,
+    change_b: &str,
+    artifact: Option<&str>,
+) -> CoreResult<ChangeDiff> {
+    let a = load_change(change_repo, change_a)?;
+    let b = load_change(change_repo, change_b)?;
+    let generates_a = schema_outputs(&a, ctx)?;
+    let generates_b = schema_outputs(&b, ctx)?;
+
+    let mut ids: Vec<&String> = generates_a.iter().map(|(id, _)| id).collect();
+    for (id, _) in &generates_b {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    if let Some(wanted) = artifact
+        && !ids.iter().any(|id| id.as_str() == wanted)
+    {
+        let known: Vec<&str> = ids.iter().map(|id| id.as_str()).collect();
+        return Err(CoreError::validation(format!(
+            "unknown artifact '{wanted}'; available: {}",
+            known.join(", ")
+        )));
+    }
+
+    let mut artifacts = Vec::new();
+    for id in ids {
+        if artifact.is_some_and(|wanted| wanted != id) {
+            continue;
+        }
+        let files_a = output_files(&a.path, &generates_a, id);
+        let files_b = output_files(&b.path, &generates_b, id);
+        let mut paths: Vec<&PathBuf> = files_a.iter().chain(files_b.iter()).collect();
+        paths.sort();
+        paths.dedup();
+        let files = paths
+            .into_iter()
+            .map(|rel| compare_file(&a, &b, rel, files_a.contains(rel), files_b.contains(rel)))
+            .collect();
+        artifacts.push(ArtifactDiff {
+            id: id.clone(),
+            in_a: !files_a.is_empty(),
+            in_b: !files_b.is_empty(),
+            files,
+        });
+    }
+
+    Ok(ChangeDiff {
+        tasks: diff_tasks(&a, &b),
+        change_a: a.id,
+        change_b: b.id,
+        artifacts,
+    })
+}
+
+pub(super) fn load_change(
+    change_repo: &(impl ChangeRepository + ?Sized),
+    target: &str,
+) -> CoreResult<Change> {
+    let options = ResolveTargetOptions {
+        lifecycle: ChangeLifecycleFilter::All,
+        exact: false,
+    };
+    let id = match change_repo.resolve_target_with_options(target, options) {
+        ChangeTargetResolution::Unique(id) => id,
+        ChangeTargetResolution::Ambiguous(matches) => {
+            return Err(CoreError::validation(format!(
+                "ambiguous change target '{target}', matches: {}",
+                target_match_ids(&matches).join(", ")
+            )));
+        }
+        ChangeTargetResolution::NotFound => {
+            return Err(CoreError::not_found(format!("change '{target}' not found")));
+        }
+    };
+    change_repo
+        .get_with_filter(&id, ChangeLifecycleFilter::All)
+        .into_core()
+}
+
+/// `(artifact id, generates pattern)` pairs from the change's schema.
+fn schema_outputs(change: &Change, ctx: &ConfigContext) -> CoreResult<Vec<(String, String)>> {
+    let resolved = resolve_change_dir_schema(&change.path, ctx)
+        .map_err(|e| CoreError::validation(format!("change '{}': {e}", change.id)))?;
+    Ok(resolved
+        .schema
+        .artifacts
+        .into_iter()
+        .map(|a| (a.id, a.generates))
+        .collect())
+}
+
+fn output_files(change_dir: &Path, outputs: &[(String, String)], id: &str) -> Vec<PathBuf> {
+    outputs
+        .iter()
+        .find(|(artifact, _)| artifact == id)
+        .map(|(_, generates)| artifact_output_files(change_dir, generates))
+        .unwrap_or_default()
+}
+
+fn compare_file(a: &Change, b: &Change, rel: &Path, in_a: bool, in_b: bool) -> FileDiff {
+    let path = rel.to_string_lossy().replace('\\', "/");
+    let mut out = FileDiff {
+        path,
+        status: match (in_a, in_b) {
+            (true, false) => FileDiffStatus::OnlyInA,
+            (false, true) => FileDiffStatus::OnlyInB,
+            _ => FileDiffStatus::Changed,
+        },
+        diff: None,
+        note: None,
+    };
+    if out.status != FileDiffStatus::Changed {
+        return out;
+    }
+
+    let (path_a, path_b) = (a.path.join(rel), b.path.join(rel));
+    let size = |p: &Path| std::fs::metadata(p).map(|m| m.len()).unwrap_or(0);
+    let largest = size(&path_a).max(size(&path_b));
+    if largest > MAX_DIFF_BYTES {
+        out.note = Some(format!(
+            "file too large to diff ({largest} bytes, limit {MAX_DIFF_BYTES})"
+        ));
+        return out;
+    }
+    let (Ok(text_a), Ok(text_b)) = (
+        std::fs::read_to_string(&path_a),
+        std::fs::read_to_string(&path_b),
+    ) else {
+        out.note = Some("file is not UTF-8 text; not diffed".to_string());
+        return out;
+    };
+    if text_a == text_b {
+        out.status = FileDiffStatus::Identical;
+        return out;
+    }
+
+    let patch = diffy::create_patch(&text_a, &text_b).to_string();
+    // Replace diffy's `original`/`modified` labels with the change paths.
+    let hunks: String = patch
+        .lines()
+        .skip_while(|l| l.starts_with("--- ") || l.starts_with("+++ "))
+        .map(|l| format!("{l}\n"))
+        .collect();
+    out.diff = Some(format!(
+        "--- {}/{}\n+++ {}/{}\n{hunks}",
+        a.id, out.path, b.id, out.path
+    ));
+    out
+}
+
+fn diff_tasks(a: &Change, b: &Change) -> TaskDiff {
+    let snapshot = |t: &TaskItem| TaskSnapshot {
+        id: t.id.clone(),
+        name: t.name.clone(),
+        status: t.status.as_enhanced_label().to_string(),
+    };
+    let tasks_b: BTreeMap<&str, TaskSnapshot> = b
+        .tasks
+        .tasks
+        .iter()
+        .map(|t| (t.id.as_str(), snapshot(t)))
+        .collect();
+
+    let mut out = TaskDiff::default();
+    for task in &a.tasks.tasks {
+        let task_a = snapshot(task);
+        match tasks_b.get(task.id.as_str()) {
+            None => out.only_in_a.push(task_a),
+            Some(task_b) if *task_b != task_a => out.changed.push(TaskChange {
+                id: task_a.id.clone(),
+                a: task_a,
+                b: task_b.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+    for task in &b.tasks.tasks {
+        if !a.tasks.tasks.iter().any(|t| t.id == task.id) {
+            out.only_in_b.push(snapshot(task));
+        }
+    }
+    out
+}
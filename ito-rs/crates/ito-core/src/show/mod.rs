@@ -4,6 +4,7 @@
 //! change markdown files from disk and produces lightweight structs that can be
 //! serialized to JSON.
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
 
 use crate::error_bridge::IntoCoreResult;
@@ -13,11 +14,19 @@ use ito_domain::modules::ModuleRepository;
 use ito_domain::specs::SpecRepository;
 use serde::Serialize;
 
-use ito_domain::changes::ChangeRepository;
+use ito_domain::changes::{ChangeMetadata, ChangeRepository};
+
+pub mod coverage;
+pub mod diff;
+pub mod timeline;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 /// One raw scenario block from a spec or delta.
 pub struct Scenario {
+    /// Scenario name from the `#### Scenario:` header.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub name: String,
+
     #[serde(rename = "rawText")]
     /// The original scenario text (preserves newlines).
     pub raw_text: String,
@@ -39,6 +48,12 @@ pub struct ContractRef {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 /// A single requirement statement and its scenarios.
 pub struct Requirement {
+    /// Requirement title from the `### Requirement:` header.
+    pub title: String,
+
+    /// Stable kebab-case anchor derived from the title, unique within its spec.
+    pub anchor: String,
+
     /// The normalized requirement statement.
     pub text: String,
 
@@ -125,6 +140,10 @@ pub struct ChangeShowJson {
 
     /// Parsed deltas from delta spec files.
     pub deltas: Vec<ChangeDelta>,
+
+    /// Proposal front matter metadata (see [`read_change_metadata`]).
+    #[serde(skip_serializing_if = "ChangeMetadata::is_empty")]
+    pub metadata: ChangeMetadata,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
@@ -144,6 +163,10 @@ pub struct ChangeDelta {
 
     /// All requirements extracted for the delta.
     pub requirements: Vec<Requirement>,
+
+    /// Anchor of the base spec requirement with the same title, when one exists.
+    #[serde(rename = "baseAnchor", skip_serializing_if = "Option::is_none")]
+    pub base_anchor: Option<String>,
 }
 
 /// Read the markdown for a spec id from `.ito/specs/<id>/spec.md`.
@@ -170,6 +193,17 @@ pub fn read_change_proposal_markdown(
     Ok(change.proposal)
 }
 
+/// Read the proposal front matter metadata for a change id.
+///
+/// Changes without a proposal, or without front matter, yield empty metadata.
+pub fn read_change_metadata(
+    repo: &(impl ChangeRepository + ?Sized),
+    change_id: &str,
+) -> CoreResult<ChangeMetadata> {
+    let change = repo.get(change_id).into_core()?;
+    Ok(change.metadata)
+}
+
 /// Read the raw markdown for a module's `module.md` file.
 pub fn read_module_markdown(
     module_repo: &(impl ModuleRepository + ?Sized),
@@ -205,7 +239,11 @@ fn render_module_markdown_fallback(module: &ito_domain::modules::Module) -> Stri
 /// Parse spec markdown into a serializable structure.
 pub fn parse_spec_show_json(id: &str, markdown: &str) -> SpecShowJson {
     let overview = extract_section_text(markdown, "Purpose");
-    let requirements = parse_spec_requirements(markdown);
+    let mut requirements = parse_spec_requirements(markdown);
+    let mut anchors = AnchorAllocator::default();
+    for requirement in &mut requirements {
+        requirement.anchor = anchors.allocate(&requirement.title);
+    }
     SpecShowJson {
         id: id.to_string(),
         title: id.to_string(),
@@ -219,6 +257,131 @@ pub fn parse_spec_show_json(id: &str, markdown: &str) -> SpecShowJson {
     }
 }
 
+/// Derive a kebab-case anchor from a requirement title.
+///
+/// ASCII letters and digits are kept (lowercased); every other run of
+/// characters becomes a single `-`. Titles with no usable characters map to
+/// `requirement`.
+pub fn requirement_anchor_slug(title: &str) -> String {
+    let mut slug = String::new();
+    for ch in title.chars() {
+        if ch.is_ascii_alphanumeric() {
+            slug.push(ch.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        return "requirement".to_string();
+    }
+    slug.to_string()
+}
+
+/// Hands out anchors that are unique within one spec by suffixing repeats
+/// with `-2`, `-3`, and so on.
+#[derive(Debug, Default)]
+struct AnchorAllocator {
+    used: BTreeSet<String>,
+}
+
+impl AnchorAllocator {
+    fn allocate(&mut self, title: &str) -> String {
+        let base = requirement_anchor_slug(title);
+        let mut candidate = base.clone();
+        let mut n = 1;
+        while self.used.contains(&candidate) {
+            n += 1;
+            candidate = format!("{base}-{n}");
+        }
+        self.used.insert(candidate.clone());
+        candidate
+    }
+}
+
+/// Select one requirement from a parsed spec by anchor or 1-based index.
+///
+/// # Errors
+///
+/// Returns a not-found error listing the available anchors when `selector`
+/// matches no requirement.
+pub fn select_requirement<'a>(
+    spec: &'a SpecShowJson,
+    selector: &str,
+) -> CoreResult<&'a Requirement> {
+    let selector = selector.trim();
+    let available = || {
+        let anchors: Vec<&str> = spec
+            .requirements
+            .iter()
+            .map(|r| r.anchor.as_str())
+            .collect();
+        if anchors.is_empty() {
+            "(none)".to_string()
+        } else {
+            anchors.join(", ")
+        }
+    };
+
+    if let Ok(one_based) = selector.parse::<usize>() {
+        if one_based == 0 || one_based > spec.requirements.len() {
+            return Err(CoreError::not_found(format!(
+                "Requirement index out of range. Expected 1..={}. Available anchors: {}",
+                spec.requirements.len(),
+                available()
+            )));
+        }
+        return Ok(&spec.requirements[one_based - 1]);
+    }
+
+    let wanted = selector.trim_start_matches('#').to_ascii_lowercase();
+    spec.requirements
+        .iter()
+        .find(|r| r.anchor == wanted)
+        .ok_or_else(|| {
+            CoreError::not_found(format!(
+                "Requirement '{selector}' not found in spec '{}'. Available anchors: {}",
+                spec.id,
+                available()
+            ))
+        })
+}
+
+/// Render one requirement and its scenarios back to spec markdown.
+pub fn render_requirement_markdown(requirement: &Requirement) -> String {
+    let mut out = format!(
+        "### Requirement: {}
+",
+        requirement.title
+    );
+    if let Some(id) = &requirement.requirement_id {
+        out.push_str(&format!("- **Requirement ID**: {id}\n"));
+    }
+    if !requirement.tags.is_empty() {
+        out.push_str(&format!("- **Tags**: {}\n", requirement.tags.join(", ")));
+    }
+    if !requirement.contract_refs.is_empty() {
+        let refs: Vec<&str> = requirement
+            .contract_refs
+            .iter()
+            .map(|r| r.raw.as_str())
+            .collect();
+        out.push_str(&format!("- **Contract Refs**: {}\n", refs.join(", ")));
+    }
+    if !requirement.text.is_empty() {
+        out.push_str(&requirement.text);
+        out.push('\n');
+    }
+    for scenario in &requirement.scenarios {
+        out.push_str(&format!("\n#### Scenario: {}\n", scenario.name));
+        if !scenario.raw_text.is_empty() {
+            out.push_str(&scenario.raw_text);
+            out.push('\n');
+        }
+    }
+    out
+}
+
 /// Bundle all main specs under `.ito/specs/*/spec.md` into a JSON-friendly structure.
 pub fn bundle_main_specs_show_json(ito_path: &Path) -> CoreResult<SpecsBundleJson> {
     use ito_common::fs::StdFs;
@@ -324,6 +487,32 @@ pub fn read_change_delta_spec_files(
     Ok(out)
 }
 
+/// Fill in [`ChangeDelta::base_anchor`] for deltas whose requirement title
+/// matches a requirement in the current base spec.
+///
+/// Deltas against specs that do not exist yet are left unlinked.
+pub fn link_delta_base_anchors(
+    show: &mut ChangeShowJson,
+    spec_repo: &(impl SpecRepository + ?Sized),
+) {
+    let mut base_specs: BTreeMap<String, Option<SpecShowJson>> = BTreeMap::new();
+    for delta in &mut show.deltas {
+        let base = base_specs.entry(delta.spec.clone()).or_insert_with(|| {
+            read_spec_markdown_from_repository(spec_repo, &delta.spec)
+                .ok()
+                .map(|md| parse_spec_show_json(&delta.spec, &md))
+        });
+        let Some(base) = base else {
+            continue;
+        };
+        delta.base_anchor = base
+            .requirements
+            .iter()
+            .find(|r| r.title.eq_ignore_ascii_case(&delta.requirement.title))
+            .map(|r| r.anchor.clone());
+    }
+}
+
 /// Parse a change id plus its delta spec files into a JSON-friendly structure.
 pub fn parse_change_show_json(change_id: &str, delta_specs: &[DeltaSpecFile]) -> ChangeShowJson {
     let mut deltas: Vec<ChangeDelta> = Vec::new();
@@ -336,6 +525,7 @@ pub fn parse_change_show_json(change_id: &str, delta_specs: &[DeltaSpecFile]) ->
         title: change_id.to_string(),
         delta_count: deltas.len() as u32,
         deltas,
+        metadata: ChangeMetadata::default(),
     }
 }
 
@@ -367,6 +557,7 @@ pub fn load_delta_spec_file(path: &Path) -> CoreResult<DeltaSpecFile> {
 
 fn parse_delta_spec_file(file: &DeltaSpecFile) -> Vec<ChangeDelta> {
     let mut out: Vec<ChangeDelta> = Vec::new();
+    let mut anchors = AnchorAllocator::default();
 
     let mut current_op: Option<String> = None;
     let mut i = 0usize;
@@ -383,9 +574,10 @@ fn parse_delta_spec_file(file: &DeltaSpecFile) -> Vec<ChangeDelta> {
             continue;
         }
 
-        if let Some(title) = line.strip_prefix("### Requirement:") {
+        if line.starts_with("### Requirement:") {
             let op = current_op.clone().unwrap_or_else(|| "ADDED".to_string());
-            let (_req_title, requirement, next) = parse_requirement_block(&lines, i);
+            let (_req_title, mut requirement, next) = parse_requirement_block(&lines, i);
+            requirement.anchor = anchors.allocate(&requirement.title);
             i = next;
 
             let description = match op.as_str() {
@@ -401,9 +593,8 @@ fn parse_delta_spec_file(file: &DeltaSpecFile) -> Vec<ChangeDelta> {
                 description,
                 requirement: requirement.clone(),
                 requirements: vec![requirement],
+                base_anchor: None,
             });
-            // Title is currently unused but parsed for parity with TS structure.
-            let _ = title;
             continue;
         }
 
@@ -568,7 +759,8 @@ fn parse_requirement_block(lines: &[&str], start: usize) -> (String, Requirement
         if t.starts_with("### Requirement:") || t.starts_with("## ") {
             break;
         }
-        if let Some(_name) = t.strip_prefix("#### Scenario:") {
+        if let Some(name) = t.strip_prefix("#### Scenario:") {
+            let name = name.trim().to_string();
             i += 1;
             let mut raw_lines: Vec<String> = Vec::new();
             while i < lines.len() {
@@ -583,15 +775,17 @@ fn parse_requirement_block(lines: &[&str], start: usize) -> (String, Requirement
                 i += 1;
             }
             let raw_text = trim_trailing_blank_lines(&raw_lines).join("\n");
-            scenarios.push(Scenario { raw_text });
+            scenarios.push(Scenario { name, raw_text });
             continue;
         }
         i += 1;
     }
 
     (
-        title,
+        title.clone(),
         Requirement {
+            title,
+            anchor: String::new(),
             text,
             requirement_id,
             tags,
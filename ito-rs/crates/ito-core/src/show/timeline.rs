@@ -0,0 +1,296 @@
+//! Merge everything that happened to a change into one timeline.
+//!
+//! A post-mortem draws on three records kept in different places: audit
+//! events, Ralph iteration history, and git commits touching the change
+//! directory. Each is normalized into a [`TimelineEntry`] and the result is
+//! sorted chronologically. A missing source (no Ralph state, no git
+//! repository) contributes nothing.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use ito_domain::changes::ChangeRepository;
+use serde::{Serialize, Serializer};
+
+use super::diff::load_change;
+use crate::audit::{AuditEvent, AuditEventStore, describe_actor, read_audit_events_from_store};
+use crate::errors::CoreResult;
+use crate::process::{ProcessRequest, ProcessRunner};
+use crate::ralph::format_duration;
+use crate::ralph::state::{RalphState, load_state};
+
+/// Git commits included when the caller does not set a limit.
+pub const DEFAULT_COMMIT_LIMIT: usize = 50;
+
+/// Which record a timeline entry came from.
+///
+/// The declaration order breaks ties between entries in the same second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TimelineKind {
+    /// An audit event for the change or one of its tasks.
+    Audit,
+    /// A finished Ralph iteration.
+    Ralph,
+    /// A git commit touching the change directory.
+    Commit,
+}
+
+impl TimelineKind {
+    /// Short label for human output.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            TimelineKind::Audit => "audit",
+            TimelineKind::Ralph => "ralph",
+            TimelineKind::Commit => "commit",
+        }
+    }
+}
+
+/// One normalized event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TimelineEntry {
+    /// When the event happened, serialized as RFC 3339.
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub timestamp: DateTime<Utc>,
+    /// Source of the event.
+    pub kind: TimelineKind,
+    /// One-line description.
+    pub summary: String,
+    /// Supporting detail (actor, exit code, commit hash); may be empty.
+    pub details: String,
+}
+
+/// A git commit touching the change directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineCommit {
+    /// Full commit hash.
+    pub hash: String,
+    /// Committer date.
+    pub timestamp: DateTime<Utc>,
+    /// Subject line.
+    pub subject: String,
+}
+
+/// Timeline for one resolved change.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChangeTimeline {
+    /// Resolved change id.
+    #[serde(rename = "changeId")]
+    pub change_id: String,
+    /// Entries in chronological order.
+    pub entries: Vec<TimelineEntry>,
+}
+
+/// Build the timeline for `target`, an active or archived change.
+///
+/// At most `commit_limit` of the most recent commits are included.
+///
+/// # Errors
+///
+/// Fails when `target` does not resolve to exactly one change or its Ralph
+/// state exists but cannot be read.
+pub fn change_timeline(
+    change_repo: &(impl ChangeRepository + ?Sized),
+    audit_store: &dyn AuditEventStore,
+    runner: &dyn ProcessRunner,
+    ito_path: &Path,
+    target: &str,
+    commit_limit: usize,
+) -> CoreResult<ChangeTimeline> {
+    let change = load_change(change_repo, target)?;
+    let audit = read_audit_events_from_store(audit_store);
+    let ralph = load_state(ito_path, &change.id)?;
+
+    let project_root = ito_path.parent().unwrap_or(ito_path);
+    let mut dirs = vec![ito_path.join("changes").join(&change.id)];
+    if !dirs.contains(&change.path) {
+        dirs.push(change.path.clone());
+    }
+    let dirs: Vec<PathBuf> = dirs
+        .iter()
+        .map(|dir| dir.strip_prefix(project_root).unwrap_or(dir).to_path_buf())
+        .collect();
+    let commits = change_commits(runner, project_root, &dirs, commit_limit);
+
+    let entries = build_timeline(&change.id, &audit, ralph.as_ref(), &commits);
+    Ok(ChangeTimeline {
+        change_id: change.id,
+        entries,
+    })
+}
+
+/// Normalize and merge the three sources for `change_id`.
+///
+/// Entries are ordered by second, then by [`TimelineKind`], then by their
+/// sub-second time and details, so same-second events from sources with
+/// different timestamp precision always come out in the same order.
+pub fn build_timeline(
+    change_id: &str,
+    audit: &[AuditEvent],
+    ralph: Option<&RalphState>,
+    commits: &[TimelineCommit],
+) -> Vec<TimelineEntry> {
+    let mut entries: Vec<TimelineEntry> = audit
+        .iter()
+        .filter(|event| concerns_change(event, change_id))
+        .filter_map(audit_entry)
+        .collect();
+    if let Some(state) = ralph {
+        entries.extend(ralph_entries(state));
+    }
+    entries.extend(commits.iter().map(commit_entry));
+
+    entries.sort_by(|a, b| {
+        a.timestamp
+            .timestamp()
+            .cmp(&b.timestamp.timestamp())
+            .then(a.kind.cmp(&b.kind))
+            .then(a.timestamp.cmp(&b.timestamp))
+            .then_with(|| a.details.cmp(&b.details))
+    });
+    entries
+}
+
+/// Split sorted `entries` into runs sharing a UTC calendar day.
+pub fn group_by_day(entries: &[TimelineEntry]) -> Vec<(NaiveDate, Vec<&TimelineEntry>)> {
+    let mut days: Vec<(NaiveDate, Vec<&TimelineEntry>)> = Vec::new();
+    for entry in entries {
+        let day = entry.timestamp.date_naive();
+        match days.last_mut() {
+            Some((last, group)) if *last == day => group.push(entry),
+            _ => days.push((day, vec![entry])),
+        }
+    }
+    days
+}
+
+/// Recent commits touching any of `dirs` (relative to `project_root`).
+///
+/// Returns nothing when git is unavailable or `project_root` is not a
+/// repository.
+pub fn change_commits(
+    runner: &dyn ProcessRunner,
+    project_root: &Path,
+    dirs: &[PathBuf],
+    limit: usize,
+) -> Vec<TimelineCommit> {
+    if limit == 0 || dirs.is_empty() {
+        return Vec::new();
+    }
+    let request = ProcessRequest::new("git")
+        .args([
+            "log".to_string(),
+            format!("--max-count={limit}"),
+            "--format=%H%x1f%cI%x1f%s".to_string(),
+            "--".to_string(),
+        ])
+        .args(dirs.iter().map(|dir| dir.to_string_lossy().into_owned()))
+        .current_dir(project_root);
+    let Ok(out) = runner.run(&request) else {
+        return Vec::new();
+    };
+    if !out.success {
+        return Vec::new();
+    }
+    out.stdout.lines().filter_map(parse_commit_line).collect()
+}
+
+fn parse_commit_line(line: &str) -> Option<TimelineCommit> {
+    let mut fields = line.splitn(3, '\u{1f}');
+    let hash = fields.next()?.trim();
+    let timestamp = parse_timestamp(fields.next()?)?;
+    let subject = fields.next().unwrap_or_default();
+    if hash.is_empty() {
+        return None;
+    }
+    Some(TimelineCommit {
+        hash: hash.to_string(),
+        timestamp,
+        subject: subject.to_string(),
+    })
+}
+
+fn concerns_change(event: &AuditEvent, change_id: &str) -> bool {
+    event.scope.as_deref() == Some(change_id)
+        || (event.entity == "change" && event.entity_id == change_id)
+}
+
+fn audit_entry(event: &AuditEvent) -> Option<TimelineEntry> {
+    let timestamp = parse_timestamp(&event.ts)?;
+    let mut summary = format!("{} {} {}", event.entity, event.entity_id, event.op);
+    match (&event.from, &event.to) {
+        (Some(from), Some(to)) => summary.push_str(&format!(": {from} -> {to}")),
+        (None, Some(to)) => summary.push_str(&format!(": {to}")),
+        _ => {}
+    }
+    if event.count > 1 {
+        summary.push_str(&format!(" (x{})", event.count));
+    }
+    Some(TimelineEntry {
+        timestamp,
+        kind: TimelineKind::Audit,
+        summary,
+        details: describe_actor(event),
+    })
+}
+
+fn ralph_entries(state: &RalphState) -> Vec<TimelineEntry> {
+    // History may be shorter than the iteration count; number from the end.
+    let first = (state.iteration as usize).saturating_sub(state.history.len()) + 1;
+    state
+        .history
+        .iter()
+        .enumerate()
+        .filter_map(|(index, entry)| {
+            let timestamp = Utc.timestamp_millis_opt(entry.timestamp).single()?;
+            let promise = if entry.completion_promise_found {
+                "promise found"
+            } else {
+                "no promise"
+            };
+            let mut details = format!(
+                "exit code {}, {} file(s) changed",
+                entry.harness_exit_code, entry.file_changes_count
+            );
+            if let Some(summary) = &entry.completion_summary {
+                details.push_str(&format!(", {summary}"));
+            }
+            Some(TimelineEntry {
+                timestamp,
+                kind: TimelineKind::Ralph,
+                summary: format!(
+                    "ralph iteration {} ({}, {promise})",
+                    first + index,
+                    format_duration(Duration::from_millis(
+                        u64::try_from(entry.duration).unwrap_or(0)
+                    ))
+                ),
+                details,
+            })
+        })
+        .collect()
+}
+
+fn commit_entry(commit: &TimelineCommit) -> TimelineEntry {
+    TimelineEntry {
+        timestamp: commit.timestamp,
+        kind: TimelineKind::Commit,
+        summary: commit.subject.clone(),
+        details: commit.hash.clone(),
+    }
+}
+
+fn parse_timestamp(raw: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(raw.trim())
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+fn serialize_timestamp<S: Serializer>(
+    timestamp: &DateTime<Utc>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&timestamp.to_rfc3339_opts(chrono::SecondsFormat::Millis, true))
+}
@@ -0,0 +1,246 @@
+//! Rename a promoted spec and update the changes that reference it.
+//!
+//! A spec lives at `.ito/specs/<id>/` and active changes carry deltas for it
+//! under `changes/<change>/specs/<id>/`. Renaming the directory alone would
+//! orphan those deltas, so [`plan_spec_rename`] collects every move and text
+//! edit up front and [`apply_spec_rename`] performs them. Archived changes are
+//! historical records: they are reported but never modified.
+
+use std::path::{Path, PathBuf};
+
+use ito_common::fs::StdFs;
+use ito_common::id::parse_spec_id;
+use ito_common::paths;
+use ito_domain::discovery;
+
+use crate::error_bridge::IntoCoreResult;
+use crate::errors::{CoreError, CoreResult};
+
+/// Change documents whose textual references to the spec id are rewritten.
+const CHANGE_REFERENCE_FILES: &[&str] = &["proposal.md", "design.md"];
+
+/// A directory move performed by a spec rename.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecRenameMove {
+    /// Change owning the delta directory, or `None` for the spec itself.
+    pub change_id: Option<String>,
+    /// Current directory path.
+    pub from: PathBuf,
+    /// Directory path after the rename.
+    pub to: PathBuf,
+}
+
+/// A file whose references to the old spec id are rewritten.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecReferenceEdit {
+    /// Change owning the file, or `None` for module files.
+    pub change_id: Option<String>,
+    /// File path.
+    pub path: PathBuf,
+    /// Number of references replaced.
+    pub occurrences: usize,
+}
+
+/// Every move and edit needed to rename a spec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecRenamePlan {
+    /// Current spec id.
+    pub old_id: String,
+    /// New spec id.
+    pub new_id: String,
+    /// Directory moves, starting with the spec directory itself.
+    pub moves: Vec<SpecRenameMove>,
+    /// Text edits in change documents and module files.
+    pub edits: Vec<SpecReferenceEdit>,
+    /// Archived changes that still reference the old id (left untouched).
+    pub archived_references: Vec<String>,
+}
+
+impl SpecRenamePlan {
+    /// Active change ids touched by the rename, sorted and de-duplicated.
+    pub fn affected_changes(&self) -> Vec<String> {
+        let mut out: Vec<String> = self
+            .moves
+            .iter()
+            .filter_map(|m| m.change_id.clone())
+            .chain(self.edits.iter().filter_map(|e| e.change_id.clone()))
+            .collect();
+        out.sort();
+        out.dedup();
+        out
+    }
+}
+
+/// Plan renaming spec `old_id` to `new_id` without touching the filesystem.
+///
+/// Fails when either id is invalid, the spec does not exist, the target spec
+/// already exists, or an active change already has a delta for `new_id`.
+pub fn plan_spec_rename(ito_path: &Path, old_id: &str, new_id: &str) -> CoreResult<SpecRenamePlan> {
+    let old_id = parse_id(old_id)?;
+    let new_id = parse_id(new_id)?;
+    if old_id == new_id {
+        return Err(CoreError::validation(format!(
+            "Spec '{old_id}' already has that name"
+        )));
+    }
+
+    let specs_dir = paths::specs_dir(ito_path);
+    let spec_from = specs_dir.join(&old_id);
+    if !spec_from.is_dir() {
+        return Err(CoreError::not_found(format!("Spec '{old_id}' not found")));
+    }
+    let spec_to = specs_dir.join(&new_id);
+    if spec_to.exists() {
+        return Err(CoreError::validation(format!(
+            "Spec '{new_id}' already exists"
+        )));
+    }
+
+    let mut plan = SpecRenamePlan {
+        old_id: old_id.clone(),
+        new_id: new_id.clone(),
+        moves: vec![SpecRenameMove {
+            change_id: None,
+            from: spec_from,
+            to: spec_to,
+        }],
+        edits: Vec::new(),
+        archived_references: Vec::new(),
+    };
+
+    let fs = StdFs;
+    for change_id in discovery::list_change_dir_names(&fs, ito_path).into_core()? {
+        let change_dir = paths::change_dir(ito_path, &change_id);
+        let delta_from = change_dir.join("specs").join(&old_id);
+        if delta_from.is_dir() {
+            let delta_to = change_dir.join("specs").join(&new_id);
+            if delta_to.exists() {
+                return Err(CoreError::validation(format!(
+                    "Change '{change_id}' already has a delta for spec '{new_id}'"
+                )));
+            }
+            plan.moves.push(SpecRenameMove {
+                change_id: Some(change_id.clone()),
+                from: delta_from,
+                to: delta_to,
+            });
+        }
+
+        for file in CHANGE_REFERENCE_FILES {
+            let path = change_dir.join(file);
+            let occurrences = count_file_references(&path, &old_id)?;
+            if occurrences > 0 {
+                plan.edits.push(SpecReferenceEdit {
+                    change_id: Some(change_id.clone()),
+                    path,
+                    occurrences,
+                });
+            }
+        }
+    }
+
+    for module in discovery::list_module_dir_names(&fs, ito_path).into_core()? {
+        let path = paths::modules_dir(ito_path).join(module).join("module.md");
+        let occurrences = count_file_references(&path, &old_id)?;
+        if occurrences > 0 {
+            plan.edits.push(SpecReferenceEdit {
+                change_id: None,
+                path,
+                occurrences,
+            });
+        }
+    }
+
+    let archive_dir = paths::changes_archive_dir(ito_path);
+    for name in discovery::list_dir_names(&fs, &archive_dir).into_core()? {
+        let archived_dir = archive_dir.join(&name);
+        let mut referenced = archived_dir.join("specs").join(&old_id).is_dir();
+        for file in CHANGE_REFERENCE_FILES {
+            if referenced {
+                break;
+            }
+            referenced = count_file_references(&archived_dir.join(file), &old_id)? > 0;
+        }
+        if referenced {
+            plan.archived_references.push(name);
+        }
+    }
+
+    Ok(plan)
+}
+
+/// Apply a plan produced by [`plan_spec_rename`].
+///
+/// Directories are moved first, then references are rewritten in place.
+pub fn apply_spec_rename(plan: &SpecRenamePlan) -> CoreResult<()> {
+    for mv in &plan.moves {
+        std::fs::rename(&mv.from, &mv.to).map_err(|e| {
+            CoreError::io(
+                format!("moving {} to {}", mv.from.display(), mv.to.display()),
+                e,
+            )
+        })?;
+    }
+
+    for edit in &plan.edits {
+        let contents = ito_common::io::read_to_string_std(&edit.path)
+            .map_err(|e| CoreError::io(format!("reading {}", edit.path.display()), e))?;
+        let (updated, _) = replace_spec_references(&contents, &plan.old_id, &plan.new_id);
+        ito_common::io::write_std(&edit.path, updated)
+            .map_err(|e| CoreError::io(format!("writing {}", edit.path.display()), e))?;
+    }
+
+    Ok(())
+}
+
+/// Replace whole-word occurrences of `old_id` in `text` with `new_id`.
+///
+/// Spec ids are made of letters, digits, `-` and `_`, so a match only counts
+/// when it is not adjacent to any of those characters (`cli-init` does not
+/// match inside `cli-init-extra`). Returns the new text and the number of
+/// replacements.
+pub fn replace_spec_references(text: &str, old_id: &str, new_id: &str) -> (String, usize) {
+    let mut out = String::with_capacity(text.len());
+    let mut count = 0;
+    let mut last = 0;
+    for (start, _) in text.match_indices(old_id) {
+        let end = start + old_id.len();
+        let before = text[..start].chars().next_back();
+        let after = text[end..].chars().next();
+        if before.is_some_and(is_id_char) || after.is_some_and(is_id_char) {
+            continue;
+        }
+        out.push_str(&text[last..start]);
+        out.push_str(new_id);
+        last = end;
+        count += 1;
+    }
+    out.push_str(&text[last..]);
+    (out, count)
+}
+
+fn is_id_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+fn parse_id(input: &str) -> CoreResult<String> {
+    parse_spec_id(input)
+        .map(|parsed| parsed.spec_id.as_str().to_string())
+        .map_err(|e| match e.hint {
+            Some(hint) => CoreError::validation(format!("{} ({hint})", e.error)),
+            None => CoreError::validation(e.error),
+        })
+}
+
+fn count_file_references(path: &Path, old_id: &str) -> CoreResult<usize> {
+    let contents = match ito_common::io::read_to_string_std(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(CoreError::io(format!("reading {}", path.display()), e)),
+    };
+    Ok(replace_spec_references(&contents, old_id, old_id).1)
+}
+
+#[cfg(test)]
+#[path = "spec_rename_tests.rs"]
+mod spec_rename_tests;
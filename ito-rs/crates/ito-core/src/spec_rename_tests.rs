@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn replace_spec_references_matches_whole_ids_only() {
+    let text = "Touches cli-init and `specs/cli-init/spec.md`; not cli-init-extra or my_cli-init.";
+    let (out, count) = replace_spec_references(text, "cli-init", "cli-setup");
+    assert_eq!(count, 2);
+    assert_eq!(
+        out,
+        "Touches cli-setup and `specs/cli-setup/spec.md`; not cli-init-extra or my_cli-init."
+    );
+}
+
+#[test]
+fn replace_spec_references_handles_text_edges() {
+    let (out, count) = replace_spec_references("cli-init", "cli-init", "cli-setup");
+    assert_eq!((out.as_str(), count), ("cli-setup", 1));
+
+    let (out, count) = replace_spec_references("no mentions here", "cli-init", "cli-setup");
+    assert_eq!((out.as_str(), count), ("no mentions here", 0));
+}
+
+#[test]
+fn plan_rejects_invalid_or_identical_ids() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(td.path().join("specs/cli-init")).unwrap();
+
+    let err = plan_spec_rename(td.path(), "cli-init", "../escape").unwrap_err();
+    assert!(matches!(err, CoreError::Validation(_)), "{err}");
+
+    let err = plan_spec_rename(td.path(), "cli-init", "cli-init").unwrap_err();
+    assert!(matches!(err, CoreError::Validation(_)), "{err}");
+
+    let err = plan_spec_rename(td.path(), "missing", "cli-setup").unwrap_err();
+    assert!(matches!(err, CoreError::NotFound(_)), "{err}");
+}
@@ -4,24 +4,32 @@ use std::path::Path;
 
 use chrono::{DateTime, Utc};
 
+use ito_common::fs::{FileSystem, StdFs};
 use ito_common::paths;
 use ito_domain::errors::DomainError;
 use ito_domain::specs::{SpecDocument, SpecRepository, SpecSummary};
 
 /// Filesystem-backed promoted spec repository.
-pub struct FsSpecRepository<'a> {
+pub struct FsSpecRepository<'a, F: FileSystem = StdFs> {
     ito_path: &'a Path,
+    fs: F,
 }
 
-impl<'a> FsSpecRepository<'a> {
+impl<'a> FsSpecRepository<'a, StdFs> {
     /// Create a filesystem-backed promoted spec repository.
     pub fn new(ito_path: &'a Path) -> Self {
-        Self { ito_path }
+        Self::with_fs(ito_path, StdFs)
+    }
+}
+
+impl<'a, F: FileSystem> FsSpecRepository<'a, F> {
+    /// Create a promoted spec repository over an explicit filesystem.
+    pub fn with_fs(ito_path: &'a Path, fs: F) -> Self {
+        Self { ito_path, fs }
     }
 
     fn spec_ids(&self) -> Result<Vec<String>, DomainError> {
-        let fs = ito_common::fs::StdFs;
-        let mut ids = ito_domain::discovery::list_spec_dir_names(&fs, self.ito_path)?;
+        let mut ids = ito_domain::discovery::list_spec_dir_names(&self.fs, self.ito_path)?;
         ids.sort();
         Ok(ids)
     }
@@ -37,13 +45,13 @@ impl<'a> FsSpecRepository<'a> {
     }
 }
 
-impl SpecRepository for FsSpecRepository<'_> {
+impl<F: FileSystem> SpecRepository for FsSpecRepository<'_, F> {
     fn list(&self) -> Result<Vec<SpecSummary>, DomainError> {
         let ids = self.spec_ids()?;
         let mut specs = Vec::with_capacity(ids.len());
         for id in ids {
             let path = paths::spec_markdown_path(self.ito_path, &id);
-            if !path.is_file() {
+            if !self.fs.is_file(&path) {
                 continue;
             }
             specs.push(SpecSummary {
@@ -57,10 +65,10 @@ impl SpecRepository for FsSpecRepository<'_> {
 
     fn get(&self, id: &str) -> Result<SpecDocument, DomainError> {
         let path = paths::spec_markdown_path(self.ito_path, id);
-        let markdown = ito_common::io::read_to_string(&path).map_err(|err| {
+        let markdown = self.fs.read_to_string(&path).map_err(|err| {
             DomainError::io(
                 "reading promoted spec",
-                std::io::Error::other(err.to_string()),
+                std::io::Error::other(format!("I/O error reading {}: {err}", path.display())),
             )
         })?;
         Ok(SpecDocument {
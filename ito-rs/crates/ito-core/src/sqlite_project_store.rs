@@ -8,7 +8,6 @@
 //! Database location: configurable via `BackendSqliteConfig::db_path`, with a
 //! default of `<data_dir>/sqlite/ito-backend.db`.
 
-use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
@@ -19,7 +18,8 @@ use ito_common::match_::nearest_matches;
 use ito_domain::backend::BackendProjectStore;
 use ito_domain::changes::{
     Change, ChangeLifecycleFilter, ChangeRepository, ChangeSummary, ChangeTargetResolution,
-    ResolveTargetOptions, Spec, parse_change_id, parse_module_id,
+    ResolveTargetOptions, Spec, parse_change_id, parse_module_id, resolve_change_target,
+    slug_matches_tokens, split_canonical_change_id, tokenize_target_query,
 };
 use ito_domain::errors::{DomainError, DomainResult};
 use ito_domain::modules::{Module, ModuleRepository, ModuleSummary};
@@ -28,14 +28,13 @@ use ito_domain::tasks::{
     TaskInitResult, TaskMutationResult, TaskMutationService, TaskMutationServiceResult,
     TaskRepository, TasksParseResult, parse_tasks_tracking_file,
 };
-use regex::Regex;
 
 use crate::errors::{CoreError, CoreResult};
 use crate::repository_runtime::RepositorySet;
 use crate::task_mutations::task_mutation_error_from_core;
 use crate::tasks::{
-    apply_add_task, apply_complete_task, apply_shelve_task, apply_start_task, apply_unshelve_task,
-    enhanced_tasks_template,
+    apply_add_task, apply_complete_task, apply_release_task, apply_shelve_task, apply_start_task,
+    apply_unshelve_task, enhanced_tasks_template,
 };
 
 #[path = "sqlite_project_store_backend.rs"]
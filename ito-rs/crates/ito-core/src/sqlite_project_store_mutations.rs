@@ -154,7 +154,13 @@ impl TaskMutationService for SqliteTaskMutationService {
         task_id: &str,
     ) -> TaskMutationServiceResult<TaskMutationResult> {
         self.mutate(change_id, |tasks| {
-            apply_start_task(tasks, change_id, task_id, "backend tasks")
+            apply_start_task(
+                tasks,
+                change_id,
+                task_id,
+                "backend tasks",
+                crate::time::now(),
+            )
         })
     }
 
@@ -165,7 +171,7 @@ impl TaskMutationService for SqliteTaskMutationService {
         _note: Option<String>,
     ) -> TaskMutationServiceResult<TaskMutationResult> {
         self.mutate(change_id, |tasks| {
-            apply_complete_task(tasks, task_id, "backend tasks")
+            apply_complete_task(tasks, task_id, "backend tasks", crate::time::now())
         })
     }
 
@@ -176,7 +182,7 @@ impl TaskMutationService for SqliteTaskMutationService {
         _reason: Option<String>,
     ) -> TaskMutationServiceResult<TaskMutationResult> {
         self.mutate(change_id, |tasks| {
-            apply_shelve_task(tasks, task_id, "backend tasks")
+            apply_shelve_task(tasks, task_id, "backend tasks", crate::time::now())
         })
     }
 
@@ -186,7 +192,17 @@ impl TaskMutationService for SqliteTaskMutationService {
         task_id: &str,
     ) -> TaskMutationServiceResult<TaskMutationResult> {
         self.mutate(change_id, |tasks| {
-            apply_unshelve_task(tasks, task_id, "backend tasks")
+            apply_unshelve_task(tasks, task_id, "backend tasks", crate::time::now())
+        })
+    }
+
+    fn release_task(
+        &self,
+        change_id: &str,
+        task_id: &str,
+    ) -> TaskMutationServiceResult<TaskMutationResult> {
+        self.mutate(change_id, |tasks| {
+            apply_release_task(tasks, task_id, "backend tasks", crate::time::now())
         })
     }
 
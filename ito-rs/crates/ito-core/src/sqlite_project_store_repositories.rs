@@ -26,70 +26,6 @@ impl SqliteChangeRepository {
         names.dedup();
         names
     }
-
-    fn split_canonical_change_id<'b>(&self, name: &'b str) -> Option<(String, String, &'b str)> {
-        let (module_id, change_num) = parse_change_id(name)?;
-        let slug = name.split_once('_').map(|(_id, s)| s).unwrap_or("");
-        Some((module_id, change_num, slug))
-    }
-
-    fn tokenize_query(&self, input: &str) -> Vec<String> {
-        let mut out = Vec::new();
-        for part in input.split_whitespace() {
-            let trimmed = part.trim();
-            if trimmed.is_empty() {
-                continue;
-            }
-            out.push(trimmed.to_lowercase());
-        }
-        out
-    }
-
-    fn normalized_slug_text(&self, slug: &str) -> String {
-        let mut out = String::new();
-        for ch in slug.chars() {
-            if ch.is_ascii_alphanumeric() {
-                out.push(ch.to_ascii_lowercase());
-            } else {
-                out.push(' ');
-            }
-        }
-        out
-    }
-
-    fn slug_matches_tokens(&self, slug: &str, tokens: &[String]) -> bool {
-        if tokens.is_empty() {
-            return false;
-        }
-        let text = self.normalized_slug_text(slug);
-        for token in tokens {
-            if !text.contains(token) {
-                return false;
-            }
-        }
-        true
-    }
-
-    fn is_numeric_module_selector(&self, input: &str) -> bool {
-        let trimmed = input.trim();
-        !trimmed.is_empty() && trimmed.chars().all(|ch| ch.is_ascii_digit())
-    }
-
-    fn extract_two_numbers_as_change_id(&self, input: &str) -> Option<(String, String)> {
-        let re = Regex::new(r"\d+").ok()?;
-        let mut parts: Vec<&str> = Vec::new();
-        for m in re.find_iter(input) {
-            parts.push(m.as_str());
-            if parts.len() > 2 {
-                return None;
-            }
-        }
-        if parts.len() != 2 {
-            return None;
-        }
-        let parsed = format!("{}-{}", parts[0], parts[1]);
-        parse_change_id(&parsed)
-    }
 }
 
 impl ChangeRepository for SqliteChangeRepository {
@@ -99,121 +35,9 @@ impl ChangeRepository for SqliteChangeRepository {
         options: ResolveTargetOptions,
     ) -> ChangeTargetResolution {
         let names = self.change_names(options.lifecycle);
-        if names.is_empty() {
-            return ChangeTargetResolution::NotFound;
-        }
-
-        let input = input.trim();
-        if input.is_empty() {
-            return ChangeTargetResolution::NotFound;
-        }
-
-        if names.iter().any(|name| name == input) {
-            return ChangeTargetResolution::Unique(input.to_string());
-        }
-
-        let mut numeric_matches: BTreeSet<String> = BTreeSet::new();
-        let numeric_selector =
-            parse_change_id(input).or_else(|| self.extract_two_numbers_as_change_id(input));
-        if let Some((module_id, change_num)) = numeric_selector {
-            let numeric_prefix = format!("{module_id}-{change_num}");
-            let with_separator = format!("{numeric_prefix}_");
-            for name in &names {
-                if name == &numeric_prefix || name.starts_with(&with_separator) {
-                    numeric_matches.insert(name.clone());
-                }
-            }
-            if !numeric_matches.is_empty() {
-                let numeric_matches: Vec<String> = numeric_matches.into_iter().collect();
-                if numeric_matches.len() == 1 {
-                    return ChangeTargetResolution::Unique(numeric_matches[0].clone());
-                }
-                return ChangeTargetResolution::Ambiguous(numeric_matches);
-            }
-        }
-
-        if let Some((module, query)) = input.split_once(':') {
-            let query = query.trim();
-            if !query.is_empty() {
-                let module_id = parse_module_id(module);
-                let tokens = self.tokenize_query(query);
-                let mut scoped_matches: BTreeSet<String> = BTreeSet::new();
-                for name in &names {
-                    let Some((name_module, _name_change, slug)) =
-                        self.split_canonical_change_id(name)
-                    else {
-                        continue;
-                    };
-                    if name_module != module_id {
-                        continue;
-                    }
-                    if self.slug_matches_tokens(slug, &tokens) {
-                        scoped_matches.insert(name.clone());
-                    }
-                }
-
-                if scoped_matches.is_empty() {
-                    return ChangeTargetResolution::NotFound;
-                }
-                let scoped_matches: Vec<String> = scoped_matches.into_iter().collect();
-                if scoped_matches.len() == 1 {
-                    return ChangeTargetResolution::Unique(scoped_matches[0].clone());
-                }
-                return ChangeTargetResolution::Ambiguous(scoped_matches);
-            }
-        }
-
-        if self.is_numeric_module_selector(input) {
-            let module_id = parse_module_id(input);
-            let mut module_matches: BTreeSet<String> = BTreeSet::new();
-            for name in &names {
-                let Some((name_module, _name_change, _slug)) = self.split_canonical_change_id(name)
-                else {
-                    continue;
-                };
-                if name_module == module_id {
-                    module_matches.insert(name.clone());
-                }
-            }
-
-            if !module_matches.is_empty() {
-                let module_matches: Vec<String> = module_matches.into_iter().collect();
-                if module_matches.len() == 1 {
-                    return ChangeTargetResolution::Unique(module_matches[0].clone());
-                }
-                return ChangeTargetResolution::Ambiguous(module_matches);
-            }
-        }
-
-        let mut matches: BTreeSet<String> = BTreeSet::new();
-        for name in &names {
-            if name.starts_with(input) {
-                matches.insert(name.clone());
-            }
-        }
-
-        if matches.is_empty() {
-            let tokens = self.tokenize_query(input);
-            for name in &names {
-                let Some((_module, _change, slug)) = self.split_canonical_change_id(name) else {
-                    continue;
-                };
-                if self.slug_matches_tokens(slug, &tokens) {
-                    matches.insert(name.clone());
-                }
-            }
-        }
-
-        if matches.is_empty() {
-            return ChangeTargetResolution::NotFound;
-        }
-
-        let matches: Vec<String> = matches.into_iter().collect();
-        if matches.len() == 1 {
-            return ChangeTargetResolution::Unique(matches[0].clone());
-        }
-
-        ChangeTargetResolution::Ambiguous(matches)
+        resolve_change_target(&names, input, options.exact, |id| {
+            self.describe_target(id, options.lifecycle)
+        })
     }
 
     fn suggest_targets(&self, input: &str, max: usize) -> Vec<String> {
@@ -225,13 +49,13 @@ impl ChangeRepository for SqliteChangeRepository {
         let names = self.change_names(ChangeLifecycleFilter::Active);
         let mut canonical_names: Vec<String> = Vec::new();
         for name in &names {
-            if self.split_canonical_change_id(name).is_some() {
+            if split_canonical_change_id(name).is_some() {
                 canonical_names.push(name.clone());
             }
         }
 
         let mut scored: Vec<(usize, String)> = Vec::new();
-        let tokens = self.tokenize_query(&input);
+        let tokens = tokenize_target_query(&input);
 
         for name in &canonical_names {
             let lower = name.to_lowercase();
@@ -244,10 +68,10 @@ impl ChangeRepository for SqliteChangeRepository {
                 score = score.max(80);
             }
 
-            let Some((_module, _change, slug)) = self.split_canonical_change_id(name) else {
+            let Some((_module, _change, slug)) = split_canonical_change_id(name) else {
                 continue;
             };
-            if !tokens.is_empty() && self.slug_matches_tokens(slug, &tokens) {
+            if !tokens.is_empty() && slug_matches_tokens(slug, &tokens) {
                 score = score.max(70);
             }
 
@@ -337,6 +161,11 @@ impl ChangeRepository for SqliteChangeRepository {
                 .collect(),
             tasks,
             orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+            metadata: row
+                .proposal
+                .as_deref()
+                .map(|proposal| crate::front_matter::proposal_metadata(proposal).metadata)
+                .unwrap_or_default(),
             last_modified,
         })
     }
@@ -372,6 +201,11 @@ impl ChangeRepository for SqliteChangeRepository {
                 has_specs: !row.specs.is_empty(),
                 has_tasks: row.tasks_md.is_some(),
                 orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+                metadata: row
+                    .proposal
+                    .as_deref()
+                    .map(|proposal| crate::front_matter::proposal_metadata(proposal).metadata)
+                    .unwrap_or_default(),
             });
         }
         Ok(summaries)
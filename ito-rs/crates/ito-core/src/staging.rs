@@ -0,0 +1,174 @@
+//! Staged directory writes promoted into place with a single rename.
+//!
+//! Multi-file scaffolds (a new change, an archived change) are built in a
+//! hidden sibling directory named `.tmp-<name>-<pid>` and renamed onto the
+//! target only once every file was written. A failure before promotion
+//! removes the staging directory, so readers never observe a half-written
+//! target. Staging directories left behind by a crashed process are found
+//! with [`find_stale_staged_dirs`] and removed with
+//! [`remove_stale_staged_dirs`].
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Name prefix of staging directories.
+pub const STAGING_PREFIX: &str = ".tmp-";
+
+/// Age after which a staging directory is assumed to belong to a crashed run.
+pub const STALE_STAGING_AGE: Duration = Duration::from_secs(10 * 60);
+
+/// A directory being built next to its final location.
+///
+/// Dropping a `StagedDir` without calling [`StagedDir::promote`] removes the
+/// staging directory and everything written into it.
+#[derive(Debug)]
+pub struct StagedDir {
+    path: PathBuf,
+    target: PathBuf,
+    promoted: bool,
+}
+
+impl StagedDir {
+    /// Create an empty staging directory for `target` in its parent directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `target` has no parent or a file name, or when
+    /// the staging directory cannot be created.
+    pub fn new(target: &Path) -> io::Result<Self> {
+        let (Some(parent), Some(name)) = (target.parent(), target.file_name()) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("cannot stage {}", target.display()),
+            ));
+        };
+        std::fs::create_dir_all(parent)?;
+
+        let path = parent.join(format!(
+            "{STAGING_PREFIX}{}-{}",
+            name.to_string_lossy(),
+            std::process::id()
+        ));
+        // A leftover from an earlier run that reused this pid.
+        if path.exists() {
+            std::fs::remove_dir_all(&path)?;
+        }
+        std::fs::create_dir(&path)?;
+
+        Ok(Self {
+            path,
+            target: target.to_path_buf(),
+            promoted: false,
+        })
+    }
+
+    /// Directory to write the staged files into.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Final location the staged directory is promoted to.
+    pub fn target(&self) -> &Path {
+        &self.target
+    }
+
+    /// Rename the staging directory onto its target.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::ErrorKind::AlreadyExists`] when the target already
+    /// exists, or the rename error. The staging directory is removed either way.
+    pub fn promote(mut self) -> io::Result<PathBuf> {
+        if self.target.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", self.target.display()),
+            ));
+        }
+        std::fs::rename(&self.path, &self.target)?;
+        self.promoted = true;
+        Ok(self.target.clone())
+    }
+}
+
+impl Drop for StagedDir {
+    fn drop(&mut self) {
+        if !self.promoted {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+}
+
+/// Build `target` through a staging directory.
+///
+/// `fill` writes the directory contents into the path it is given. The
+/// directory is renamed onto `target` only when `fill` succeeds; otherwise
+/// nothing is left behind.
+///
+/// # Errors
+///
+/// Returns the error from `fill`, from creating the staging directory, or
+/// from promotion (including an already existing `target`).
+pub fn write_dir_staged<T, E>(
+    target: &Path,
+    fill: impl FnOnce(&Path) -> Result<T, E>,
+) -> Result<T, E>
+where
+    E: From<io::Error>,
+{
+    let staged = StagedDir::new(target)?;
+    let value = fill(staged.path())?;
+    staged.promote()?;
+    Ok(value)
+}
+
+/// Staging directories under `parent` older than `max_age`.
+///
+/// Unreadable directories yield an empty list.
+pub fn find_stale_staged_dirs(parent: &Path, max_age: Duration) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(parent) else {
+        return Vec::new();
+    };
+    let now = SystemTime::now();
+    let mut out: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with(STAGING_PREFIX)
+        })
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+        .filter(|entry| {
+            entry
+                .metadata()
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|modified| now.duration_since(modified).ok())
+                .is_some_and(|age| age >= max_age)
+        })
+        .map(|entry| entry.path())
+        .collect();
+    out.sort();
+    out
+}
+
+/// Remove the staging directories reported by [`find_stale_staged_dirs`].
+///
+/// Returns the removed paths.
+///
+/// # Errors
+///
+/// Returns the first removal error.
+pub fn remove_stale_staged_dirs(parent: &Path, max_age: Duration) -> io::Result<Vec<PathBuf>> {
+    let stale = find_stale_staged_dirs(parent, max_age);
+    for dir in &stale {
+        std::fs::remove_dir_all(dir)?;
+    }
+    Ok(stale)
+}
+
+#[cfg(test)]
+#[path = "staging_tests.rs"]
+mod staging_tests;
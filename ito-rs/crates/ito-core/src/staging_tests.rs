@@ -0,0 +1,87 @@
+use super::*;
+
+fn staging_entries(dir: &Path) -> Vec<String> {
+    std::fs::read_dir(dir)
+        .unwrap()
+        .filter_map(Result::ok)
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .filter(|name| name.starts_with(STAGING_PREFIX))
+        .collect()
+}
+
+fn age(path: &Path, secs: i64) {
+    let mtime =
+        filetime::FileTime::from_unix_time(filetime::FileTime::now().unix_seconds() - secs, 0);
+    filetime::set_file_mtime(path, mtime).unwrap();
+}
+
+#[test]
+fn successful_fill_promotes_the_directory() {
+    let td = tempfile::tempdir().unwrap();
+    let target = td.path().join("changes").join("001-01_demo");
+
+    let n = write_dir_staged(&target, |dir| -> io::Result<usize> {
+        std::fs::write(dir.join("a.md"), "a")?;
+        std::fs::write(dir.join("b.md"), "b")?;
+        Ok(2)
+    })
+    .unwrap();
+
+    assert_eq!(n, 2);
+    assert_eq!(std::fs::read_to_string(target.join("a.md")).unwrap(), "a");
+    assert_eq!(std::fs::read_to_string(target.join("b.md")).unwrap(), "b");
+    assert!(staging_entries(&td.path().join("changes")).is_empty());
+}
+
+#[test]
+fn failure_mid_fill_leaves_no_trace() {
+    let td = tempfile::tempdir().unwrap();
+    let target = td.path().join("001-01_demo");
+
+    let err = write_dir_staged(&target, |dir| -> io::Result<()> {
+        std::fs::write(dir.join("a.md"), "a")?;
+        Err(io::Error::other("disk full"))
+    })
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "disk full");
+    assert!(!target.exists());
+    assert!(staging_entries(td.path()).is_empty());
+}
+
+#[test]
+fn promotion_refuses_to_replace_an_existing_target() {
+    let td = tempfile::tempdir().unwrap();
+    let target = td.path().join("001-01_demo");
+    std::fs::create_dir(&target).unwrap();
+    std::fs::write(target.join("keep.md"), "keep").unwrap();
+
+    let err = write_dir_staged(&target, |dir| std::fs::write(dir.join("a.md"), "a")).unwrap_err();
+
+    assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+    assert!(target.join("keep.md").exists());
+    assert!(!target.join("a.md").exists());
+    assert!(staging_entries(td.path()).is_empty());
+}
+
+#[test]
+fn stale_staging_dirs_are_found_and_removed() {
+    let td = tempfile::tempdir().unwrap();
+    let stale = td.path().join(".tmp-001-01_old-4242");
+    let fresh = td.path().join(".tmp-001-02_new-4243");
+    std::fs::create_dir_all(stale.join("nested")).unwrap();
+    std::fs::create_dir(&fresh).unwrap();
+    std::fs::create_dir(td.path().join("001-03_real")).unwrap();
+    age(&stale, 3600);
+
+    assert_eq!(
+        find_stale_staged_dirs(td.path(), STALE_STAGING_AGE),
+        vec![stale.clone()]
+    );
+    let removed = remove_stale_staged_dirs(td.path(), STALE_STAGING_AGE).unwrap();
+
+    assert_eq!(removed, vec![stale.clone()]);
+    assert!(!stale.exists());
+    assert!(fresh.exists());
+    assert!(td.path().join("001-03_real").exists());
+}
@@ -0,0 +1,83 @@
+//! Stale in-progress task detection backed by the audit log.
+//!
+//! Tracking files only record the date a task last changed; task audit events
+//! add exact timestamps and tell who started the task, which matters when a
+//! stuck task is released for someone else to pick up.
+
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset};
+use ito_config::types::ItoConfig;
+
+use crate::audit::{AuditEvent, EntityType, describe_actor};
+use crate::errors::{CoreError, CoreResult};
+use crate::ralph::duration::parse_duration;
+use crate::tasks::{StaleTask, TaskActivity, TaskItem, stale_tasks_in};
+
+/// Latest audited activity for each task of `change_id`.
+pub fn task_activity(events: &[AuditEvent], change_id: &str) -> TaskActivity {
+    let mut activity = TaskActivity::new();
+    for event in task_events(events, change_id) {
+        let Ok(ts) = DateTime::parse_from_rfc3339(&event.ts) else {
+            continue;
+        };
+        let latest = activity.entry(event.entity_id.clone()).or_insert(ts);
+        if ts > *latest {
+            *latest = ts;
+        }
+    }
+    activity
+}
+
+/// Who last moved `task_id` to in-progress, described for humans
+/// (e.g. `agent opencode (iteration 3)`).
+pub fn last_holder(events: &[AuditEvent], change_id: &str, task_id: &str) -> Option<String> {
+    task_events(events, change_id)
+        .filter(|event| event.entity_id == task_id && event.to.as_deref() == Some("in-progress"))
+        .last()
+        .map(describe_actor)
+}
+
+/// Stale in-progress tasks among `tasks`, with audit events filling in
+/// activity the tracking file does not record.
+pub fn stale_tasks(
+    tasks: &[TaskItem],
+    events: &[AuditEvent],
+    change_id: &str,
+    threshold: Duration,
+    now: DateTime<FixedOffset>,
+) -> Vec<StaleTask> {
+    stale_tasks_in(tasks, &task_activity(events, change_id), threshold, now)
+}
+
+/// The configured `tasks.staleAfter` threshold.
+///
+/// # Errors
+///
+/// Returns a validation error when the value is not a duration such as `24h`.
+pub fn configured_threshold(config: &ItoConfig) -> CoreResult<Duration> {
+    parse_threshold(&config.tasks.stale_after)
+        .map_err(|e| CoreError::validation(format!("Invalid tasks.staleAfter in config: {e}")))
+}
+
+/// Parse a staleness threshold such as `24h`, `3d`, or `90m`.
+///
+/// # Errors
+///
+/// Returns a parse error for malformed or zero durations.
+pub fn parse_threshold(raw: &str) -> CoreResult<Duration> {
+    parse_duration(raw)
+}
+
+fn task_events<'a>(
+    events: &'a [AuditEvent],
+    change_id: &'a str,
+) -> impl Iterator<Item = &'a AuditEvent> {
+    events.iter().filter(move |event| {
+        event.entity == EntityType::Task.as_str() && event.scope.as_deref() == Some(change_id)
+    })
+}
+
+#[cfg(test)]
+#[path = "stale_tasks_tests.rs"]
+mod stale_tasks_tests;
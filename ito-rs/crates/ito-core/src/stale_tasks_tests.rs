@@ -0,0 +1,120 @@
+use super::*;
+use crate::audit::{Actor, AuditEventBuilder, EventContext, ops};
+use crate::tasks::parse_tasks_tracking_file;
+
+fn event(task_id: &str, scope: &str, to: &str, by: &str, ts: &str) -> AuditEvent {
+    let mut event = AuditEventBuilder::new()
+        .entity(EntityType::Task)
+        .entity_id(task_id)
+        .scope(scope)
+        .op(ops::TASK_STATUS_CHANGE)
+        .to(to)
+        .actor(Actor::Cli)
+        .by(by)
+        .ctx(EventContext {
+            session_id: "test".to_string(),
+            harness_session_id: None,
+            branch: None,
+            worktree: None,
+            commit: None,
+        })
+        .build()
+        .expect("complete event");
+    event.ts = ts.to_string();
+    event
+}
+
+fn ts(raw: &str) -> DateTime<FixedOffset> {
+    DateTime::parse_from_rfc3339(raw).unwrap()
+}
+
+#[test]
+fn activity_keeps_the_latest_event_per_task_in_the_change() {
+    let events = vec![
+        event(
+            "1.1",
+            "001-01_a",
+            "in-progress",
+            "alice",
+            "2026-03-01T09:00:00.000Z",
+        ),
+        event(
+            "1.1",
+            "001-01_a",
+            "in-progress",
+            "bob",
+            "2026-03-02T09:00:00.000Z",
+        ),
+        event(
+            "1.2",
+            "001-01_a",
+            "complete",
+            "alice",
+            "2026-03-01T10:00:00.000Z",
+        ),
+        event(
+            "1.1",
+            "001-02_b",
+            "complete",
+            "carol",
+            "2026-03-09T09:00:00.000Z",
+        ),
+    ];
+
+    let activity = task_activity(&events, "001-01_a");
+
+    assert_eq!(activity.len(), 2);
+    assert_eq!(activity["1.1"], ts("2026-03-02T09:00:00Z"));
+    assert_eq!(activity["1.2"], ts("2026-03-01T10:00:00Z"));
+    assert_eq!(
+        last_holder(&events, "001-01_a", "1.1").as_deref(),
+        Some("human bob")
+    );
+    assert_eq!(last_holder(&events, "001-01_a", "1.2"), None);
+}
+
+#[test]
+fn checkbox_task_goes_stale_from_its_start_event() {
+    let parsed = parse_tasks_tracking_file("- [x] 1.1 done\n- [~] 1.2 stuck\n");
+    let events = vec![event(
+        "1.2",
+        "001-01_a",
+        "in-progress",
+        "alice",
+        "2026-03-01T09:00:00.000Z",
+    )];
+    let threshold = parse_threshold("24h").unwrap();
+
+    let fresh = stale_tasks(
+        &parsed.tasks,
+        &events,
+        "001-01_a",
+        threshold,
+        ts("2026-03-02T08:00:00Z"),
+    );
+    assert!(fresh.is_empty());
+
+    let stale = stale_tasks(
+        &parsed.tasks,
+        &events,
+        "001-01_a",
+        threshold,
+        ts("2026-03-04T09:00:00Z"),
+    );
+    assert_eq!(stale.len(), 1);
+    assert_eq!(stale[0].id, "1.2");
+    assert_eq!(stale[0].idle_label(), "3d");
+}
+
+#[test]
+fn configured_threshold_defaults_to_a_day() {
+    let mut config = ItoConfig::default();
+    assert_eq!(
+        configured_threshold(&config).unwrap(),
+        Duration::from_secs(86_400)
+    );
+
+    config.tasks.stale_after = "soon".to_string();
+    let err = configured_threshold(&config).unwrap_err();
+    assert!(err.to_string().contains("tasks.staleAfter"), "{err}");
+}
@@ -1,13 +1,19 @@
 //! Statistics collection and computation for Ito command usage.
 //!
 //! This module provides functions to parse execution logs and compute
-//! command usage statistics from `.jsonl` log files.
+//! command usage statistics from `.jsonl` log files. Harness token usage and
+//! cost estimates live in [`cost`]; subprocess CPU and memory usage in
+//! [`resources`]; skill invocation counts in [`skills`].
 
 use crate::errors::CoreResult;
 use std::collections::BTreeMap;
 use std::io::BufRead;
 use std::path::{Path, PathBuf};
 
+pub mod cost;
+pub mod resources;
+pub mod skills;
+
 /// Statistics about command usage, keyed by command ID.
 #[derive(Debug, Clone)]
 pub struct CommandStats {
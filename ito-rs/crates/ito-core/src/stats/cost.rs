@@ -0,0 +1,249 @@
+//! Harness token usage records and estimated cost reports.
+//!
+//! Every harness run started by `ito ralph` or `ito run artifact` appends one
+//! [`HarnessUsageRecord`] to `.ito/.state/stats/harness-usage.jsonl`. Cost
+//! reports combine those records with per-model prices configured under the
+//! `stats.pricing` project config key:
+//!
+//! ```json
+//! { "stats": { "pricing": {
+//!     "sonnet": { "input_per_mtok": 3.0, "output_per_mtok": 15.0, "cache_read_per_mtok": 0.3 }
+//! } } }
+//! ```
+//!
+//! Ito ships no built-in prices. Runs whose harness did not report usage, and
+//! models without a configured price, are reported as unknown rather than
+//! counted as zero.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use ito_config::types::ModelPricing;
+use ito_config::{ConfigContext, load_cascading_project_config};
+use serde::{Deserialize, Serialize};
+
+use crate::config::json_get_path;
+use crate::errors::{CoreError, CoreResult};
+use crate::harness::TokenUsage;
+use crate::stats::resources::ResourceRecord;
+
+/// Stats directory relative to the Ito directory.
+pub const HARNESS_STATS_DIR_REL: &str = ".state/stats";
+
+/// Usage log location relative to the Ito directory.
+pub const HARNESS_USAGE_REL: &str = ".state/stats/harness-usage.jsonl";
+
+/// Project config key holding per-model prices.
+pub const PRICING_CONFIG_KEY: &str = "stats.pricing";
+
+/// One harness run as recorded in the usage log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarnessUsageRecord {
+    /// Wall clock time (ms since epoch) when the run finished.
+    pub timestamp: i64,
+    /// What started the run (`ralph` or `run`).
+    pub source: String,
+    /// Change the run worked on, when known.
+    #[serde(default)]
+    pub change_id: Option<String>,
+    /// Harness identifier (e.g. `claude`).
+    pub harness: String,
+    /// Model requested for the run; `None` means the harness default.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Token usage reported by the harness, when available.
+    #[serde(default)]
+    pub usage: Option<TokenUsage>,
+    /// Resources the harness process consumed; absent in older records.
+    #[serde(default)]
+    pub resources: Option<ResourceRecord>,
+    /// Estimated prompt size in tokens, taken before the prompt was sent.
+    #[serde(default)]
+    pub prompt_tokens_estimate: Option<u64>,
+    /// Context window of the model the prompt was checked against, when known.
+    #[serde(default)]
+    pub context_window: Option<u64>,
+}
+
+/// Path of the usage log for the Ito directory at `ito_path`.
+pub fn harness_usage_path(ito_path: &Path) -> PathBuf {
+    ito_path.join(HARNESS_USAGE_REL)
+}
+
+/// Append `record` to the usage log.
+pub fn record_harness_usage(ito_path: &Path, record: &HarnessUsageRecord) -> CoreResult<()> {
+    let path = harness_usage_path(ito_path);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| CoreError::io(format!("creating {}", parent.display()), e))?;
+    }
+    let line = serde_json::to_string(record)
+        .map_err(|e| CoreError::serde("serializing harness usage record", e.to_string()))?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| CoreError::io(format!("opening {}", path.display()), e))?;
+    writeln!(file, "{line}").map_err(|e| CoreError::io(format!("writing {}", path.display()), e))
+}
+
+/// Load every record from the usage log.
+///
+/// A missing log yields no records; malformed lines are skipped.
+pub fn load_harness_usage(ito_path: &Path) -> CoreResult<Vec<HarnessUsageRecord>> {
+    let path = harness_usage_path(ito_path);
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(CoreError::io(format!("reading {}", path.display()), e)),
+    };
+    Ok(contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line.trim()).ok())
+        .collect())
+}
+
+/// Estimated cost of `usage` in US dollars at `price`.
+///
+/// Cache reads are billed at the input price unless `cache_read_per_mtok` is set.
+pub fn estimate_cost(price: &ModelPricing, usage: TokenUsage) -> f64 {
+    let per_token = |count: u64, per_mtok: f64| count as f64 * per_mtok / 1_000_000.0;
+    per_token(usage.input_tokens, price.input_per_mtok)
+        + per_token(usage.output_tokens, price.output_per_mtok)
+        + per_token(
+            usage.cache_read_tokens,
+            price.cache_read_per_mtok.unwrap_or(price.input_per_mtok),
+        )
+}
+
+/// Per-model prices keyed by the model id passed to the harness.
+pub type Pricing = BTreeMap<String, ModelPricing>;
+
+/// Load `stats.pricing` from the cascading project config.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Validation`] when the configured value is not a map
+/// of model ids to prices.
+pub fn load_pricing(
+    project_root: &Path,
+    ito_path: &Path,
+    ctx: &ConfigContext,
+) -> CoreResult<Pricing> {
+    let merged = load_cascading_project_config(project_root, ito_path, ctx).merged;
+    let parts: Vec<&str> = PRICING_CONFIG_KEY.split('.').collect();
+    let Some(value) = json_get_path(&merged, &parts) else {
+        return Ok(Pricing::new());
+    };
+    serde_json::from_value(value.clone())
+        .map_err(|e| CoreError::validation(format!("Invalid {PRICING_CONFIG_KEY} value: {e}")))
+}
+
+/// Which records a cost report covers.
+#[derive(Debug, Clone, Default)]
+pub struct CostFilter {
+    /// Only runs for this change id (or a change id it is a prefix of, e.g. `014-03`).
+    pub change_id: Option<String>,
+    /// Only runs that finished at or after this time (ms since epoch).
+    pub since_ms: Option<i64>,
+}
+
+impl CostFilter {
+    fn matches(&self, record: &HarnessUsageRecord) -> bool {
+        if self.since_ms.is_some_and(|since| record.timestamp < since) {
+            return false;
+        }
+        let Some(filter) = self.change_id.as_deref() else {
+            return true;
+        };
+        record.change_id.as_deref().is_some_and(|id| {
+            id == filter
+                || id
+                    .strip_prefix(filter)
+                    .is_some_and(|rest| rest.starts_with('_'))
+        })
+    }
+}
+
+/// Usage and estimated cost of the runs for one change and model.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CostGroup {
+    /// Change id; `None` for runs not tied to a change.
+    pub change_id: Option<String>,
+    /// Model id; `None` when the harness default was used.
+    pub model: Option<String>,
+    /// Number of runs in the group.
+    pub runs: u64,
+    /// Runs whose harness did not report usage.
+    pub runs_without_usage: u64,
+    /// Summed usage of the runs that reported it.
+    pub usage: TokenUsage,
+    /// Estimated cost of the runs that reported usage; `None` when unknown.
+    pub cost_usd: Option<f64>,
+}
+
+/// Estimated harness cost grouped by change and model.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CostReport {
+    /// Groups ordered by change id, then model.
+    pub groups: Vec<CostGroup>,
+    /// Sum of the known group costs.
+    pub total_cost_usd: f64,
+    /// True when some runs lacked usage or pricing, so the total is a lower bound.
+    pub incomplete: bool,
+}
+
+/// Aggregate `records` matching `filter` into a [`CostReport`] priced with `pricing`.
+pub fn compute_cost_report(
+    records: &[HarnessUsageRecord],
+    pricing: &Pricing,
+    filter: &CostFilter,
+) -> CostReport {
+    let mut grouped: BTreeMap<(Option<String>, Option<String>), CostGroup> = BTreeMap::new();
+    for record in records.iter().filter(|r| filter.matches(r)) {
+        let key = (record.change_id.clone(), record.model.clone());
+        let group = grouped.entry(key).or_insert_with(|| CostGroup {
+            change_id: record.change_id.clone(),
+            model: record.model.clone(),
+            runs: 0,
+            runs_without_usage: 0,
+            usage: TokenUsage::default(),
+            cost_usd: None,
+        });
+        group.runs += 1;
+        match record.usage {
+            Some(usage) => group.usage = group.usage.saturating_add(usage),
+            None => group.runs_without_usage += 1,
+        }
+    }
+
+    let mut total_cost_usd = 0.0;
+    let mut incomplete = false;
+    let groups: Vec<CostGroup> = grouped
+        .into_values()
+        .map(|mut group| {
+            let price = group.model.as_deref().and_then(|model| pricing.get(model));
+            if let Some(price) = price
+                && group.runs > group.runs_without_usage
+            {
+                let cost = estimate_cost(price, group.usage);
+                total_cost_usd += cost;
+                group.cost_usd = Some(cost);
+            }
+            incomplete |= group.cost_usd.is_none() || group.runs_without_usage > 0;
+            group
+        })
+        .collect();
+
+    CostReport {
+        groups,
+        total_cost_usd,
+        incomplete,
+    }
+}
+
+#[cfg(test)]
+#[path = "cost_tests.rs"]
+mod cost_tests;
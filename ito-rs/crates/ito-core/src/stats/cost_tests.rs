@@ -0,0 +1,155 @@
+use super::*;
+
+fn record(change: &str, model: Option<&str>, usage: Option<TokenUsage>) -> HarnessUsageRecord {
+    HarnessUsageRecord {
+        timestamp: 1_000,
+        source: "ralph".to_string(),
+        change_id: Some(change.to_string()),
+        harness: "claude".to_string(),
+        model: model.map(String::from),
+        usage,
+        resources: None,
+        prompt_tokens_estimate: None,
+        context_window: None,
+    }
+}
+
+fn usage(input: u64, output: u64, cache_read: u64) -> Option<TokenUsage> {
+    Some(TokenUsage {
+        input_tokens: input,
+        output_tokens: output,
+        cache_read_tokens: cache_read,
+    })
+}
+
+fn pricing() -> Pricing {
+    let mut pricing = Pricing::new();
+    pricing.insert(
+        "sonnet".to_string(),
+        ModelPricing {
+            input_per_mtok: 3.0,
+            output_per_mtok: 15.0,
+            cache_read_per_mtok: Some(0.3),
+        },
+    );
+    pricing
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn sums_usage_and_cost_per_change_and_model() {
+    let records = vec![
+        record("014-03_cost", Some("sonnet"), usage(1_000_000, 100_000, 0)),
+        record("014-03_cost", Some("sonnet"), usage(0, 100_000, 1_000_000)),
+        record("014-04_other", Some("sonnet"), usage(500_000, 0, 0)),
+    ];
+    let report = compute_cost_report(&records, &pricing(), &CostFilter::default());
+
+    assert_eq!(report.groups.len(), 2);
+    let first = &report.groups[0];
+    assert_eq!(first.change_id.as_deref(), Some("014-03_cost"));
+    assert_eq!(first.runs, 2);
+    assert_eq!(first.usage, usage(1_000_000, 200_000, 1_000_000).unwrap());
+    // 1M input at $3 + 200k output at $15/M + 1M cache reads at $0.3
+    assert_close(first.cost_usd.unwrap(), 3.0 + 3.0 + 0.3);
+    assert_close(report.groups[1].cost_usd.unwrap(), 1.5);
+    assert_close(report.total_cost_usd, 7.8);
+    assert!(!report.incomplete);
+}
+
+#[test]
+fn unpriced_models_and_missing_usage_are_unknown_not_zero() {
+    let records = vec![
+        record("014-03_cost", Some("mystery"), usage(1_000, 1_000, 0)),
+        record("014-03_cost", None, usage(1_000, 1_000, 0)),
+        record("014-03_cost", Some("sonnet"), None),
+    ];
+    let report = compute_cost_report(&records, &pricing(), &CostFilter::default());
+
+    assert_eq!(report.groups.len(), 3);
+    assert!(report.groups.iter().all(|g| g.cost_usd.is_none()));
+    let sonnet = report
+        .groups
+        .iter()
+        .find(|g| g.model.as_deref() == Some("sonnet"))
+        .unwrap();
+    assert_eq!(sonnet.runs_without_usage, 1);
+    assert_close(report.total_cost_usd, 0.0);
+    assert!(report.incomplete);
+}
+
+#[test]
+fn partial_usage_prices_known_runs_and_flags_report() {
+    let records = vec![
+        record("014-03_cost", Some("sonnet"), usage(1_000_000, 0, 0)),
+        record("014-03_cost", Some("sonnet"), None),
+    ];
+    let report = compute_cost_report(&records, &pricing(), &CostFilter::default());
+
+    assert_close(report.groups[0].cost_usd.unwrap(), 3.0);
+    assert_eq!(report.groups[0].runs_without_usage, 1);
+    assert!(report.incomplete);
+}
+
+#[test]
+fn cache_reads_default_to_input_price() {
+    let price = ModelPricing {
+        input_per_mtok: 2.0,
+        output_per_mtok: 8.0,
+        cache_read_per_mtok: None,
+    };
+    assert_close(estimate_cost(&price, usage(0, 0, 500_000).unwrap()), 1.0);
+}
+
+#[test]
+fn filter_matches_change_prefix_and_since() {
+    let mut late = record("014-03_cost", Some("sonnet"), usage(1, 1, 0));
+    late.timestamp = 5_000;
+    let records = vec![
+        record("014-03_cost", Some("sonnet"), usage(1, 1, 0)),
+        late,
+        record("014-30_other", Some("sonnet"), usage(1, 1, 0)),
+    ];
+    let filter = CostFilter {
+        change_id: Some("014-03".to_string()),
+        since_ms: Some(2_000),
+    };
+    let report = compute_cost_report(&records, &pricing(), &filter);
+
+    assert_eq!(report.groups.len(), 1);
+    assert_eq!(report.groups[0].change_id.as_deref(), Some("014-03_cost"));
+    assert_eq!(report.groups[0].runs, 1);
+}
+
+#[test]
+fn usage_log_round_trips_and_skips_malformed_lines() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    let first = record("014-03_cost", Some("sonnet"), usage(10, 20, 30));
+    let second = record("014-03_cost", None, None);
+    record_harness_usage(&ito, &first).unwrap();
+    std::fs::OpenOptions::new()
+        .append(true)
+        .open(harness_usage_path(&ito))
+        .and_then(|mut f| writeln!(f, "not json"))
+        .unwrap();
+    record_harness_usage(&ito, &second).unwrap();
+
+    assert_eq!(load_harness_usage(&ito).unwrap(), vec![first, second]);
+}
+
+#[test]
+fn missing_usage_log_is_empty() {
+    let td = tempfile::tempdir().unwrap();
+    assert!(
+        load_harness_usage(&td.path().join(".ito"))
+            .unwrap()
+            .is_empty()
+    );
+}
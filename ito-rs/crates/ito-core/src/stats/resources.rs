@@ -0,0 +1,110 @@
+//! Resource usage of harness and validation subprocesses.
+//!
+//! Harness runs carry a [`ResourceRecord`] on their
+//! [`HarnessUsageRecord`](super::cost::HarnessUsageRecord). Ralph's completion
+//! validation steps append one [`ProcessUsageRecord`] each to
+//! `.ito/.state/stats/process-usage.jsonl`, which makes runaway validation
+//! commands visible after the fact.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{CoreError, CoreResult};
+use crate::process::ResourceUsage;
+
+/// Process usage log location relative to the Ito directory.
+pub const PROCESS_USAGE_REL: &str = ".state/stats/process-usage.jsonl";
+
+/// Serialized form of [`ResourceUsage`], in milliseconds and bytes.
+///
+/// CPU and memory fields are `None` on platforms that cannot report them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceRecord {
+    /// Wall-clock time in milliseconds.
+    pub wall_ms: u64,
+    /// User-mode CPU time in milliseconds.
+    #[serde(default)]
+    pub user_cpu_ms: Option<u64>,
+    /// Kernel-mode CPU time in milliseconds.
+    #[serde(default)]
+    pub system_cpu_ms: Option<u64>,
+    /// Peak resident set size in bytes.
+    #[serde(default)]
+    pub max_rss_bytes: Option<u64>,
+}
+
+impl From<&ResourceUsage> for ResourceRecord {
+    fn from(usage: &ResourceUsage) -> Self {
+        let ms = |d: std::time::Duration| u64::try_from(d.as_millis()).unwrap_or(u64::MAX);
+        Self {
+            wall_ms: ms(usage.wall_time),
+            user_cpu_ms: usage.user_cpu.map(ms),
+            system_cpu_ms: usage.system_cpu.map(ms),
+            max_rss_bytes: usage.max_rss_bytes,
+        }
+    }
+}
+
+/// One non-harness subprocess as recorded in the process usage log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessUsageRecord {
+    /// Wall clock time (ms since epoch) when the process finished.
+    pub timestamp: i64,
+    /// What ran the process (e.g. `validation`).
+    pub source: String,
+    /// Change the process ran for, when known.
+    #[serde(default)]
+    pub change_id: Option<String>,
+    /// Step or command name.
+    pub name: String,
+    /// Whether the process succeeded within its timeout.
+    pub success: bool,
+    /// Resources the process consumed.
+    pub resources: ResourceRecord,
+}
+
+/// Path of the process usage log for the Ito directory at `ito_path`.
+pub fn process_usage_path(ito_path: &Path) -> PathBuf {
+    ito_path.join(PROCESS_USAGE_REL)
+}
+
+/// Append `record` to the process usage log.
+pub fn record_process_usage(ito_path: &Path, record: &ProcessUsageRecord) -> CoreResult<()> {
+    let path = process_usage_path(ito_path);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| CoreError::io(format!("creating {}", parent.display()), e))?;
+    }
+    let line = serde_json::to_string(record)
+        .map_err(|e| CoreError::serde("serializing process usage record", e.to_string()))?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| CoreError::io(format!("opening {}", path.display()), e))?;
+    writeln!(file, "{line}").map_err(|e| CoreError::io(format!("writing {}", path.display()), e))
+}
+
+/// Load every record from the process usage log.
+///
+/// A missing log yields no records; malformed lines are skipped.
+pub fn load_process_usage(ito_path: &Path) -> CoreResult<Vec<ProcessUsageRecord>> {
+    let path = process_usage_path(ito_path);
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(CoreError::io(format!("reading {}", path.display()), e)),
+    };
+    Ok(contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line.trim()).ok())
+        .collect())
+}
+
+#[cfg(test)]
+#[path = "resources_tests.rs"]
+mod resources_tests;
@@ -0,0 +1,41 @@
+use super::*;
+use std::time::Duration;
+
+#[test]
+fn record_converts_durations_to_milliseconds() {
+    let usage = ResourceUsage {
+        wall_time: Duration::from_millis(2_500),
+        user_cpu: Some(Duration::from_micros(1_200_900)),
+        system_cpu: None,
+        max_rss_bytes: Some(4096),
+    };
+
+    let record = ResourceRecord::from(&usage);
+
+    assert_eq!(record.wall_ms, 2_500);
+    assert_eq!(record.user_cpu_ms, Some(1_200));
+    assert_eq!(record.system_cpu_ms, None);
+    assert_eq!(record.max_rss_bytes, Some(4096));
+}
+
+#[test]
+fn process_usage_round_trips_through_the_log() {
+    let td = tempfile::tempdir().unwrap();
+    let record = ProcessUsageRecord {
+        timestamp: 1_000,
+        source: "validation".to_string(),
+        change_id: Some("001-01_demo".to_string()),
+        name: "tests".to_string(),
+        success: true,
+        resources: ResourceRecord::from(&ResourceUsage::default()),
+    };
+
+    assert!(load_process_usage(td.path()).unwrap().is_empty());
+    record_process_usage(td.path(), &record).unwrap();
+    record_process_usage(td.path(), &record).unwrap();
+
+    assert_eq!(
+        load_process_usage(td.path()).unwrap(),
+        vec![record.clone(), record]
+    );
+}
@@ -0,0 +1,252 @@
+//! Skill usage analytics.
+//!
+//! Adapter hooks (the Claude `PreToolUse` hook, the OpenCode plugin) call
+//! `ito util record-skill` whenever an agent loads a skill. Each call appends a
+//! `skill.invoked` audit event carrying the skill id, the harness, and the
+//! change being worked on. `ito stats skills` aggregates those events into
+//! invocation counts per skill and per harness.
+//!
+//! Ids are checked against the skill catalog (the embedded lifecycle skills
+//! plus skills installed in the project's harness directories). Unknown ids
+//! are still recorded, with `meta.known` set to `false`, so typos and
+//! third-party skills show up in the report instead of disappearing.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use ito_domain::audit::attribution::AgentAttribution;
+use ito_domain::audit::event::{
+    Actor, AuditEvent, AuditEventBuilder, EntityType, EventContext, ops,
+};
+use ito_domain::audit::writer::AuditWriter;
+use serde::Serialize;
+
+use crate::errors::{CoreError, CoreResult};
+
+/// Debug log for failed recordings, relative to the Ito directory.
+///
+/// Recording runs inside harness hooks and must never fail them, so errors
+/// are appended here instead of being reported.
+pub const SKILL_RECORD_LOG_REL: &str = ".state/stats/record-skill.log";
+
+/// Project directories harness adapters install skills into.
+pub const INSTALLED_SKILL_DIRS: [&str; 5] = [
+    ".claude/skills",
+    ".opencode/skills",
+    ".codex/skills",
+    ".github/skills",
+    ".pi/skills",
+];
+
+/// Harness recorded when neither the caller nor the environment names one.
+pub const UNKNOWN_HARNESS: &str = "unknown";
+
+/// One skill load reported by a harness adapter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkillInvocation {
+    /// Skill id as reported by the harness (e.g. `ito-apply`).
+    pub skill_id: String,
+    /// Harness that loaded the skill (e.g. `claude`, `opencode`).
+    pub harness: String,
+    /// Change the agent was working on, when known.
+    pub change_id: Option<String>,
+}
+
+/// Normalize a skill id reported by a harness.
+///
+/// Surrounding whitespace and a leading `/` (slash-command form) are removed.
+/// Returns `None` for empty ids.
+pub fn normalize_skill_id(raw: &str) -> Option<String> {
+    let id = raw.trim().trim_start_matches('/').trim();
+    (!id.is_empty()).then(|| id.to_string())
+}
+
+/// Skill ids known to this project: the embedded lifecycle skills plus every
+/// directory containing a `SKILL.md` under [`INSTALLED_SKILL_DIRS`].
+pub fn skill_catalog(project_root: &Path) -> BTreeSet<String> {
+    let mut catalog: BTreeSet<String> = ito_templates::LIFECYCLE_SKILL_NAMES
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+    for dir in INSTALLED_SKILL_DIRS {
+        let Ok(entries) = std::fs::read_dir(project_root.join(dir)) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            if !entry.path().join("SKILL.md").is_file() {
+                continue;
+            }
+            if let Some(name) = entry.file_name().to_str() {
+                catalog.insert(name.to_string());
+            }
+        }
+    }
+    catalog
+}
+
+/// Build the `skill.invoked` event for `invocation`.
+///
+/// The event is attributed to the harness (`actor: agent`, `by: @<harness>`);
+/// the model and iteration are added from the environment when the harness
+/// was launched by Ralph. The change, when known, is the event scope.
+pub fn skill_invoked_event(
+    invocation: &SkillInvocation,
+    known: bool,
+    ctx: EventContext,
+) -> Option<AuditEvent> {
+    let attribution = AgentAttribution::from_env()
+        .filter(|agent| agent.harness == invocation.harness)
+        .unwrap_or_else(|| AgentAttribution {
+            harness: invocation.harness.clone(),
+            model: None,
+            iteration: None,
+        });
+    let meta = attribution.merge_into_meta(Some(serde_json::json!({
+        "harness": invocation.harness,
+        "known": known,
+    })));
+
+    let mut builder = AuditEventBuilder::new()
+        .entity(EntityType::Skill)
+        .entity_id(&invocation.skill_id)
+        .op(ops::SKILL_INVOKED)
+        .actor(Actor::Agent)
+        .by(attribution.identity())
+        .meta(meta)
+        .ctx(ctx);
+    if let Some(change_id) = &invocation.change_id {
+        builder = builder.scope(change_id);
+    }
+    builder.build()
+}
+
+/// Record `invocation` through `audit`.
+///
+/// The id is checked against [`skill_catalog`]; unknown ids are recorded with
+/// `meta.known = false`. Returns the event that was written.
+///
+/// # Errors
+///
+/// Returns an error when the event cannot be built or the writer fails.
+pub fn record_skill_invocation(
+    audit: &(impl AuditWriter + ?Sized),
+    project_root: &Path,
+    invocation: &SkillInvocation,
+    ctx: EventContext,
+) -> CoreResult<AuditEvent> {
+    let known = skill_catalog(project_root).contains(&invocation.skill_id);
+    let Some(event) = skill_invoked_event(invocation, known, ctx) else {
+        return Err(CoreError::validation("Incomplete skill.invoked event"));
+    };
+    audit
+        .append(&event)
+        .map_err(|e| CoreError::process(format!("writing skill.invoked event: {e}")))?;
+    Ok(event)
+}
+
+/// Path of the recording debug log for the Ito directory at `ito_path`.
+pub fn skill_record_log_path(ito_path: &Path) -> PathBuf {
+    ito_path.join(SKILL_RECORD_LOG_REL)
+}
+
+/// Append `message` to the recording debug log. Best-effort: never fails.
+pub fn log_skill_record_failure(ito_path: &Path, message: &str) {
+    let path = skill_record_log_path(ito_path);
+    if let Some(parent) = path.parent()
+        && std::fs::create_dir_all(parent).is_err()
+    {
+        return;
+    }
+    let Ok(mut file) = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+    else {
+        return;
+    };
+    let ts = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ");
+    let _ = writeln!(file, "[{ts}] {message}");
+}
+
+/// Invocations of one skill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SkillUsage {
+    /// Skill id.
+    pub skill_id: String,
+    /// Whether the most recent invocation found the id in the skill catalog.
+    pub known: bool,
+    /// Number of invocations.
+    pub invocations: u64,
+    /// Invocations keyed by harness.
+    pub by_harness: BTreeMap<String, u64>,
+}
+
+/// Skill invocation counts.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SkillUsageReport {
+    /// Skills ordered by invocation count (most used first), then id.
+    pub skills: Vec<SkillUsage>,
+    /// Invocations keyed by harness, across all skills.
+    pub harnesses: BTreeMap<String, u64>,
+    /// Total number of invocations.
+    pub total: u64,
+    /// Invocations of skills that were not in the catalog.
+    pub unknown: u64,
+}
+
+/// Aggregate the `skill.invoked` events in `events`; other events are ignored.
+///
+/// Compacted events count once per represented invocation (`count`).
+pub fn compute_skill_usage<'a>(
+    events: impl IntoIterator<Item = &'a AuditEvent>,
+) -> SkillUsageReport {
+    let mut by_skill: BTreeMap<String, SkillUsage> = BTreeMap::new();
+    let mut report = SkillUsageReport::default();
+
+    for event in events {
+        if event.entity != EntityType::Skill.as_str() || event.op != ops::SKILL_INVOKED {
+            continue;
+        }
+        let count = event.count.max(1);
+        let meta = event.meta.as_ref();
+        let harness = meta
+            .and_then(|m| m.get("harness"))
+            .and_then(|h| h.as_str())
+            .unwrap_or(UNKNOWN_HARNESS)
+            .to_string();
+        let known = meta
+            .and_then(|m| m.get("known"))
+            .and_then(|k| k.as_bool())
+            .unwrap_or(true);
+
+        let usage = by_skill
+            .entry(event.entity_id.clone())
+            .or_insert_with(|| SkillUsage {
+                skill_id: event.entity_id.clone(),
+                known,
+                invocations: 0,
+                by_harness: BTreeMap::new(),
+            });
+        usage.known = known;
+        usage.invocations += count;
+        *usage.by_harness.entry(harness.clone()).or_default() += count;
+        *report.harnesses.entry(harness).or_default() += count;
+        report.total += count;
+        if !known {
+            report.unknown += count;
+        }
+    }
+
+    report.skills = by_skill.into_values().collect();
+    report.skills.sort_by(|a, b| {
+        b.invocations
+            .cmp(&a.invocations)
+            .then_with(|| a.skill_id.cmp(&b.skill_id))
+    });
+    report
+}
+
+#[cfg(test)]
+#[path = "skills_tests.rs"]
+mod skills_tests;
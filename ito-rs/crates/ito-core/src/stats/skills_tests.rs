@@ -0,0 +1,127 @@
+use super::*;
+use crate::audit::writer::audit_log_path;
+use crate::audit::{AuditEventStore, FsAuditWriter};
+
+fn ctx() -> EventContext {
+    EventContext {
+        session_id: "test-sid".to_string(),
+        harness_session_id: None,
+        branch: None,
+        worktree: None,
+        commit: None,
+    }
+}
+
+fn invocation(skill_id: &str, harness: &str) -> SkillInvocation {
+    SkillInvocation {
+        skill_id: skill_id.to_string(),
+        harness: harness.to_string(),
+        change_id: None,
+    }
+}
+
+fn event(skill_id: &str, harness: &str, known: bool) -> AuditEvent {
+    skill_invoked_event(&invocation(skill_id, harness), known, ctx()).expect("event")
+}
+
+#[test]
+fn normalize_skill_id_strips_slash_and_whitespace() {
+    assert_eq!(
+        normalize_skill_id(" /ito-apply \n"),
+        Some("ito-apply".into())
+    );
+    assert_eq!(normalize_skill_id("  "), None);
+    assert_eq!(normalize_skill_id("/"), None);
+}
+
+#[test]
+fn catalog_includes_embedded_and_installed_skills() {
+    let td = tempfile::tempdir().expect("tempdir");
+    let installed = td.path().join(".claude/skills/team-deploy");
+    std::fs::create_dir_all(&installed).expect("mkdir");
+    std::fs::write(installed.join("SKILL.md"), "# Deploy\n").expect("write");
+    std::fs::create_dir_all(td.path().join(".opencode/skills/no-entrypoint")).expect("mkdir");
+
+    let catalog = skill_catalog(td.path());
+    assert!(catalog.contains("ito-apply"));
+    assert!(catalog.contains("team-deploy"));
+    assert!(!catalog.contains("no-entrypoint"));
+}
+
+#[test]
+fn recording_appends_a_well_formed_event() {
+    let td = tempfile::tempdir().expect("tempdir");
+    let ito_path = td.path().join(".ito");
+    let writer = FsAuditWriter::new(&ito_path);
+    let mut invocation = invocation("ito-apply", "claude");
+    invocation.change_id = Some("014-03_add-stats".to_string());
+
+    record_skill_invocation(&writer, td.path(), &invocation, ctx()).expect("record");
+
+    let contents = std::fs::read_to_string(audit_log_path(&ito_path)).expect("read log");
+    let lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(lines.len(), 1);
+    let written: AuditEvent = serde_json::from_str(lines[0]).expect("valid event");
+    assert_eq!(written.entity, "skill");
+    assert_eq!(written.entity_id, "ito-apply");
+    assert_eq!(written.op, ops::SKILL_INVOKED);
+    assert_eq!(written.scope.as_deref(), Some("014-03_add-stats"));
+    assert_eq!(written.actor, "agent");
+    assert_eq!(written.by, "@claude");
+    let meta = written.meta.expect("meta");
+    assert_eq!(meta["harness"], "claude");
+    assert_eq!(meta["known"], true);
+    assert_eq!(meta["agent"]["harness"], "claude");
+}
+
+#[test]
+fn unknown_skill_is_recorded_and_flagged() {
+    let td = tempfile::tempdir().expect("tempdir");
+    let ito_path = td.path().join(".ito");
+    let writer = FsAuditWriter::new(&ito_path);
+
+    let written = record_skill_invocation(
+        &writer,
+        td.path(),
+        &invocation("ito-aply", "opencode"),
+        ctx(),
+    )
+    .expect("record");
+    assert_eq!(written.meta.as_ref().expect("meta")["known"], false);
+
+    let report = compute_skill_usage(&writer.read_all());
+    assert_eq!(report.total, 1);
+    assert_eq!(report.unknown, 1);
+    assert_eq!(report.skills[0].skill_id, "ito-aply");
+    assert!(!report.skills[0].known);
+}
+
+#[test]
+fn aggregation_counts_per_skill_and_harness() {
+    let mut compacted = event("ito-review", "claude", true);
+    compacted.count = 3;
+    let mut unrelated = event("ito-apply", "claude", true);
+    unrelated.entity = "task".to_string();
+    let events = vec![
+        event("ito-apply", "claude", true),
+        event("ito-apply", "opencode", true),
+        compacted,
+        unrelated,
+        event("ito-apply", "claude", true),
+    ];
+
+    let report = compute_skill_usage(&events);
+
+    assert_eq!(report.total, 6);
+    assert_eq!(report.unknown, 0);
+    assert_eq!(report.harnesses.get("claude"), Some(&5));
+    assert_eq!(report.harnesses.get("opencode"), Some(&1));
+
+    let ids: Vec<&str> = report.skills.iter().map(|s| s.skill_id.as_str()).collect();
+    assert_eq!(ids, ["ito-apply", "ito-review"]);
+    let apply = &report.skills[0];
+    assert_eq!(apply.invocations, 3);
+    assert_eq!(apply.by_harness.get("claude"), Some(&2));
+    assert_eq!(apply.by_harness.get("opencode"), Some(&1));
+    assert_eq!(report.skills[1].invocations, 3);
+}
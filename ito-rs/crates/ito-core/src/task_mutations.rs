@@ -130,6 +130,21 @@ impl TaskMutationService for FsTaskMutationService {
         })
     }
 
+    fn release_task(
+        &self,
+        change_id: &str,
+        task_id: &str,
+    ) -> TaskMutationServiceResult<TaskMutationResult> {
+        let _ = self.require_tasks_path(change_id)?;
+        let task = crate::tasks::release_task(&self.ito_path, change_id, task_id)
+            .map_err(task_mutation_error_from_core)?;
+        Ok(TaskMutationResult {
+            change_id: change_id.to_string(),
+            task,
+            revision: None,
+        })
+    }
+
     fn add_task(
         &self,
         change_id: &str,
@@ -40,7 +40,7 @@ impl<'a, F: FileSystem> FsTaskRepository<'a, F> {
         let mut tracking_file = "tasks.md".to_string();
         if let Ok(resolved) = resolve_schema(Some(&schema_name), &ctx)
             && let Some(apply) = resolved.schema.apply.as_ref()
-            && let Some(tracks) = apply.tracks.as_deref()
+            && let Some(tracks) = apply.primary_tracking_file()
             && is_safe_tracking_filename(tracks)
         {
             tracking_file = tracks.to_string();
@@ -83,7 +83,7 @@ impl<F: FileSystem> DomainTaskRepository for FsTaskRepository<'_, F> {
         let mut tracking_file = "tasks.md".to_string();
         if let Ok(resolved) = resolve_schema(Some(&schema_name), &ctx)
             && let Some(apply) = resolved.schema.apply.as_ref()
-            && let Some(tracks) = apply.tracks.as_deref()
+            && let Some(tracks) = apply.primary_tracking_file()
         {
             tracking_file = tracks.to_string();
         }
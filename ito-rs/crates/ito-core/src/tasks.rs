@@ -2,9 +2,13 @@
 
 use std::path::{Path, PathBuf};
 
+use chrono::{DateTime, Local};
+
 use crate::error_bridge::IntoCoreResult;
 use crate::errors::{CoreError, CoreResult};
-use crate::templates::{ValidatorId, load_schema_validation, read_change_schema, resolve_schema};
+use crate::templates::{
+    PathVariables, ValidatorId, load_schema_validation, read_change_schema, resolve_schema,
+};
 use ito_config::ConfigContext;
 use ito_domain::changes::ChangeRepository as DomainChangeRepository;
 use ito_domain::tasks::TaskRepository as DomainTaskRepository;
@@ -12,10 +16,12 @@ use ito_domain::tasks::TaskRepository as DomainTaskRepository;
 // Re-export domain types and functions for CLI convenience
 pub use ito_domain::changes::ChangeTargetResolution;
 pub use ito_domain::tasks::{
-    DiagnosticLevel, ProgressInfo, TaskDiagnostic, TaskItem, TaskKind, TaskStatus, TasksFormat,
-    TasksParseResult, WaveInfo, compute_ready_and_blocked, enhanced_tasks_template,
-    parse_tasks_tracking_file, tasks_path, update_checkbox_task_status,
-    update_enhanced_task_status,
+    DiagnosticLevel, DispatchBatch, DispatchPlan, DispatchTask, ProgressInfo, StaleTask,
+    TaskActivity, TaskDiagnostic, TaskItem, TaskKind, TaskStatus, TaskTiming, TasksFormat,
+    TasksParseResult, TimingScope, WaveInfo, compute_ready_and_blocked, cycle_times_for,
+    enhanced_tasks_template, find_stale_in_progress, lint_tasks, parse_tasks_tracking_file,
+    plan_parallel_dispatch, render_dispatch_plan_markdown, stale_tasks_in, task_cycle_times,
+    tasks_path, update_checkbox_task_status, update_enhanced_task_status,
 };
 
 /// Computes and validates filesystem path to a change's tracking file.
@@ -67,8 +73,12 @@ fn checked_tasks_path(ito_path: &Path, change_id: &str) -> CoreResult<PathBuf> {
         .schema
         .apply
         .as_ref()
-        .and_then(|a| a.tracks.as_deref())
+        .and_then(|a| a.primary_tracking_file())
         .unwrap_or("tasks.md");
+    let tracking_file = PathVariables::new(change_id, &resolved.schema.name)
+        .expand_tracking_file(tracking_file)
+        .map_err(|e| CoreError::validation(e.to_string()))?;
+    let tracking_file = tracking_file.as_str();
 
     if !ito_domain::tasks::is_safe_tracking_filename(tracking_file) {
         return Err(CoreError::validation(format!(
@@ -110,7 +120,7 @@ fn resolve_task_id<'a>(
         return Ok(task_id);
     }
 
-    let not_found_err = || CoreError::not_found(format!("Task \"{task_id}\" not found in {file}"));
+    let not_found_err = || task_not_found(parsed, task_id, file);
 
     let Ok(idx) = task_id.parse::<usize>() else {
         return Err(not_found_err());
@@ -122,6 +132,17 @@ fn resolve_task_id<'a>(
     Ok(parsed.tasks[idx - 1].id.as_str())
 }
 
+/// Not-found error for `task_id`, suggesting the nearest task ids in `parsed`.
+fn task_not_found(parsed: &TasksParseResult, task_id: &str, file: &str) -> CoreError {
+    let ids: Vec<String> = parsed.tasks.iter().map(|t| t.id.clone()).collect();
+    let mut msg = format!("Task \"{task_id}\" not found in {file}");
+    let suggestions = crate::nearest_matches(task_id, &ids, 3);
+    if !suggestions.is_empty() {
+        msg.push_str(&format!("\nDid you mean: {}?", suggestions.join(", ")));
+    }
+    CoreError::not_found(msg)
+}
+
 fn parse_numeric_task_id(id: &str) -> Option<(u32, u32)> {
     let (wave, task) = id.split_once('.')?;
     let wave = wave.parse::<u32>().ok()?;
@@ -446,6 +467,7 @@ fn next_task_from_parts(
     }
 }
 
+#[derive(Debug)]
 pub(crate) struct TaskMutationOutcome {
     pub(crate) task: TaskItem,
     pub(crate) updated_content: String,
@@ -470,14 +492,13 @@ pub(crate) fn apply_start_task(
     change_id: &str,
     task_id: &str,
     file_label: &str,
+    now: DateTime<Local>,
 ) -> CoreResult<TaskMutationOutcome> {
     let parsed = parse_tasks_for_mutation(contents, file_label)?;
     let resolved_task_id = resolve_task_id(&parsed, task_id, file_label)?;
 
     let Some(task) = parsed.tasks.iter().find(|t| t.id == resolved_task_id) else {
-        return Err(CoreError::not_found(format!(
-            "Task \"{task_id}\" not found in {file_label}"
-        )));
+        return Err(task_not_found(&parsed, task_id, file_label));
     };
 
     if parsed.format == TasksFormat::Checkbox
@@ -551,12 +572,7 @@ pub(crate) fn apply_start_task(
         return Err(CoreError::validation("Task is blocked"));
     }
 
-    let updated = update_enhanced_task_status(
-        contents,
-        task_id,
-        TaskStatus::InProgress,
-        chrono::Local::now(),
-    );
+    let updated = update_enhanced_task_status(contents, task_id, TaskStatus::InProgress, now);
 
     let mut result = task.clone();
     result.status = TaskStatus::InProgress;
@@ -570,26 +586,20 @@ pub(crate) fn apply_complete_task(
     contents: &str,
     task_id: &str,
     file_label: &str,
+    now: DateTime<Local>,
 ) -> CoreResult<TaskMutationOutcome> {
     let parsed = parse_tasks_for_mutation(contents, file_label)?;
     let resolved_task_id = resolve_task_id(&parsed, task_id, file_label)?;
 
     let Some(task) = parsed.tasks.iter().find(|t| t.id == resolved_task_id) else {
-        return Err(CoreError::not_found(format!(
-            "Task \"{task_id}\" not found in {file_label}"
-        )));
+        return Err(task_not_found(&parsed, task_id, file_label));
     };
 
     let updated = if parsed.format == TasksFormat::Checkbox {
         update_checkbox_task_status(contents, resolved_task_id, TaskStatus::Complete)
             .map_err(CoreError::validation)?
     } else {
-        update_enhanced_task_status(
-            contents,
-            task_id,
-            TaskStatus::Complete,
-            chrono::Local::now(),
-        )
+        update_enhanced_task_status(contents, task_id, TaskStatus::Complete, now)
     };
 
     let mut result = task.clone();
@@ -604,6 +614,7 @@ pub(crate) fn apply_shelve_task(
     contents: &str,
     task_id: &str,
     file_label: &str,
+    now: DateTime<Local>,
 ) -> CoreResult<TaskMutationOutcome> {
     let parsed = parse_tasks_for_mutation(contents, file_label)?;
     if parsed.format == TasksFormat::Checkbox {
@@ -613,9 +624,7 @@ pub(crate) fn apply_shelve_task(
     }
 
     let Some(task) = parsed.tasks.iter().find(|t| t.id == task_id) else {
-        return Err(CoreError::not_found(format!(
-            "Task \"{task_id}\" not found in {file_label}"
-        )));
+        return Err(task_not_found(&parsed, task_id, file_label));
     };
 
     if task.status == TaskStatus::Complete {
@@ -624,8 +633,7 @@ pub(crate) fn apply_shelve_task(
         )));
     }
 
-    let updated =
-        update_enhanced_task_status(contents, task_id, TaskStatus::Shelved, chrono::Local::now());
+    let updated = update_enhanced_task_status(contents, task_id, TaskStatus::Shelved, now);
 
     let mut result = task.clone();
     result.status = TaskStatus::Shelved;
@@ -639,6 +647,7 @@ pub(crate) fn apply_unshelve_task(
     contents: &str,
     task_id: &str,
     file_label: &str,
+    now: DateTime<Local>,
 ) -> CoreResult<TaskMutationOutcome> {
     let parsed = parse_tasks_for_mutation(contents, file_label)?;
     if parsed.format == TasksFormat::Checkbox {
@@ -648,9 +657,7 @@ pub(crate) fn apply_unshelve_task(
     }
 
     let Some(task) = parsed.tasks.iter().find(|t| t.id == task_id) else {
-        return Err(CoreError::not_found(format!(
-            "Task \"{task_id}\" not found in {file_label}"
-        )));
+        return Err(task_not_found(&parsed, task_id, file_label));
     };
 
     if task.status != TaskStatus::Shelved {
@@ -659,8 +666,42 @@ pub(crate) fn apply_unshelve_task(
         )));
     }
 
-    let updated =
-        update_enhanced_task_status(contents, task_id, TaskStatus::Pending, chrono::Local::now());
+    let updated = update_enhanced_task_status(contents, task_id, TaskStatus::Pending, now);
+
+    let mut result = task.clone();
+    result.status = TaskStatus::Pending;
+    Ok(TaskMutationOutcome {
+        task: result,
+        updated_content: updated,
+    })
+}
+
+pub(crate) fn apply_release_task(
+    contents: &str,
+    task_id: &str,
+    file_label: &str,
+    now: DateTime<Local>,
+) -> CoreResult<TaskMutationOutcome> {
+    let parsed = parse_tasks_for_mutation(contents, file_label)?;
+    let resolved_task_id = resolve_task_id(&parsed, task_id, file_label)?;
+
+    let Some(task) = parsed.tasks.iter().find(|t| t.id == resolved_task_id) else {
+        return Err(task_not_found(&parsed, task_id, file_label));
+    };
+
+    if task.status != TaskStatus::InProgress {
+        return Err(CoreError::validation(format!(
+            "Task \"{task_id}\" is not in-progress (current: {})",
+            task.status.as_enhanced_label()
+        )));
+    }
+
+    let updated = if parsed.format == TasksFormat::Checkbox {
+        update_checkbox_task_status(contents, resolved_task_id, TaskStatus::Pending)
+            .map_err(CoreError::validation)?
+    } else {
+        update_enhanced_task_status(contents, resolved_task_id, TaskStatus::Pending, now)
+    };
 
     let mut result = task.clone();
     result.status = TaskStatus::Pending;
@@ -757,6 +798,8 @@ pub(crate) fn apply_add_task(
             wave: Some(wave),
             status: TaskStatus::Pending,
             updated_at: Some(date),
+            started_at: None,
+            completed_at: None,
             dependencies: Vec::new(),
             files: vec!["path/to/file.rs".to_string()],
             action: "[Describe what needs to be done]".to_string(),
@@ -765,6 +808,8 @@ pub(crate) fn apply_add_task(
             kind: TaskKind::Normal,
             header_line_index: 0,
             requirements: Vec::new(),
+            parent_id: None,
+            depth: 0,
         },
         updated_content: out,
     })
@@ -801,7 +846,7 @@ pub fn start_task(ito_path: &Path, change_id: &str, task_id: &str) -> CoreResult
     let contents = ito_common::io::read_to_string_std(&path)
         .map_err(|e| CoreError::io(format!("read {}", path.display()), e))?;
 
-    let outcome = apply_start_task(&contents, change_id, task_id, file)?;
+    let outcome = apply_start_task(&contents, change_id, task_id, file, crate::time::now())?;
     ito_common::io::write_std(&path, outcome.updated_content.as_bytes())
         .map_err(|e| CoreError::io(format!("write {file}"), e))?;
 
@@ -844,7 +889,7 @@ pub fn complete_task(
     let contents = ito_common::io::read_to_string_std(&path)
         .map_err(|e| CoreError::io(format!("read {}", path.display()), e))?;
 
-    let outcome = apply_complete_task(&contents, task_id, file)?;
+    let outcome = apply_complete_task(&contents, task_id, file, crate::time::now())?;
     ito_common::io::write_std(&path, outcome.updated_content.as_bytes())
         .map_err(|e| CoreError::io(format!("write {file}"), e))?;
 
@@ -865,7 +910,7 @@ pub fn shelve_task(
     let contents = ito_common::io::read_to_string_std(&path)
         .map_err(|e| CoreError::io(format!("read {}", path.display()), e))?;
 
-    let outcome = apply_shelve_task(&contents, task_id, file)?;
+    let outcome = apply_shelve_task(&contents, task_id, file, crate::time::now())?;
     ito_common::io::write_std(&path, outcome.updated_content.as_bytes())
         .map_err(|e| CoreError::io(format!("write {file}"), e))?;
 
@@ -881,7 +926,24 @@ pub fn unshelve_task(ito_path: &Path, change_id: &str, task_id: &str) -> CoreRes
     let contents = ito_common::io::read_to_string_std(&path)
         .map_err(|e| CoreError::io(format!("read {}", path.display()), e))?;
 
-    let outcome = apply_unshelve_task(&contents, task_id, file)?;
+    let outcome = apply_unshelve_task(&contents, task_id, file, crate::time::now())?;
+    ito_common::io::write_std(&path, outcome.updated_content.as_bytes())
+        .map_err(|e| CoreError::io(format!("write {file}"), e))?;
+
+    Ok(outcome.task)
+}
+
+/// Release an in-progress task back to pending.
+///
+/// Used to free tasks whose holder stopped working on them (see
+/// [`find_stale_in_progress`]). Supported for both formats.
+pub fn release_task(ito_path: &Path, change_id: &str, task_id: &str) -> CoreResult<TaskItem> {
+    let path = checked_tasks_path(ito_path, change_id)?;
+    let file = tracking_file_label(&path);
+    let contents = ito_common::io::read_to_string_std(&path)
+        .map_err(|e| CoreError::io(format!("read {}", path.display()), e))?;
+
+    let outcome = apply_release_task(&contents, task_id, file, crate::time::now())?;
     ito_common::io::write_std(&path, outcome.updated_content.as_bytes())
         .map_err(|e| CoreError::io(format!("write {file}"), e))?;
 
@@ -934,7 +996,7 @@ pub fn show_task(ito_path: &Path, change_id: &str, task_id: &str) -> CoreResult<
         .iter()
         .find(|t| t.id == task_id)
         .cloned()
-        .ok_or_else(|| CoreError::not_found(format!("Task \"{task_id}\" not found")))
+        .ok_or_else(|| task_not_found(&parsed, task_id, file))
 }
 
 /// Read the raw markdown contents of a change's tracking file.
@@ -118,3 +118,39 @@ fn read_tasks_markdown_rejects_traversal_like_change_id() {
     let result = super::read_tasks_markdown(&ito_path, "../escape");
     assert!(result.is_err(), "traversal-like ids should fail");
 }
+
+#[test]
+fn unknown_task_id_suggests_nearest_ids() {
+    let contents = "- [ ] 1.1 First\n- [ ] 1.2 Second\n- [ ] 2.1 Third\n";
+
+    let err = super::apply_complete_task(contents, "2.2", "tasks.md", chrono::Local::now())
+        .expect_err("unknown id");
+
+    let msg = err.to_string();
+    assert!(msg.contains("Task \"2.2\" not found"), "{msg}");
+    assert!(msg.contains("Did you mean: 1.2, 2.1"), "{msg}");
+}
+
+#[test]
+fn release_returns_in_progress_task_to_pending() {
+    use super::{TaskStatus, apply_release_task, parse_tasks_tracking_file};
+
+    let now = chrono::Local::now();
+    let enhanced = "## Wave 1\n\n- **Depends On**: None\n\n### Task 1.1: Stuck\n- **Updated At**: 2026-03-01\n- **Started**: 2026-03-01T09:00:00+00:00\n- **Status**: [>] in-progress\n";
+    let outcome = apply_release_task(enhanced, "1.1", "tasks.md", now).expect("release");
+    assert_eq!(outcome.task.status, TaskStatus::Pending);
+    let task = &parse_tasks_tracking_file(&outcome.updated_content).tasks[0];
+    assert_eq!(task.status, TaskStatus::Pending);
+    assert_eq!(
+        task.updated_at.as_deref(),
+        Some(now.format("%Y-%m-%d").to_string().as_str())
+    );
+
+    let err = apply_release_task(&outcome.updated_content, "1.1", "tasks.md", now)
+        .expect_err("pending task cannot be released");
+    assert!(err.to_string().contains("is not in-progress"), "{err}");
+
+    let checkbox = "- [x] 1.1 done\n- [~] 1.2 stuck\n";
+    let outcome = apply_release_task(checkbox, "1.2", "tasks.md", now).expect("release");
+    assert_eq!(outcome.updated_content, "- [x] 1.1 done\n- [ ] 1.2 stuck\n");
+}
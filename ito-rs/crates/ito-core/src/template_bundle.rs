@@ -0,0 +1,267 @@
+//! Pinned template bundles.
+//!
+//! Each release publishes its template assets as `ito-templates-<tag>.tar.gz`
+//! next to a `.sha256` checksum file. `ito update --channel <tag>` (or the
+//! `templates.pin` config key) selects such a release: the bundle is
+//! downloaded once, verified, unpacked under
+//! `<global config dir>/templates/<tag>/`, and read through
+//! [`AssetSource::CachedBundle`] with the embedded assets as fallback.
+//!
+//! Downloads go through `curl` and unpacking through `tar`, both of which ship
+//! with every platform Ito supports.
+
+use std::path::{Path, PathBuf};
+
+use crate::config::json_get_path;
+use crate::errors::{CoreError, CoreResult};
+use crate::installers::content_hash;
+use crate::process::{ProcessRequest, ProcessRunner, SystemProcessRunner};
+use ito_config::{ConfigContext, load_cascading_project_config};
+use ito_templates::asset_source::AssetSource;
+
+/// Config key that pins template assets to a release tag.
+pub const TEMPLATES_PIN_CONFIG_KEY: &str = "templates.pin";
+
+/// Environment variable overriding where bundles are downloaded from.
+///
+/// The value is a URL prefix (`<base>/<tag>/<archive>`) or a local directory
+/// laid out the same way, which is handy for mirrors and air-gapped setups.
+pub const TEMPLATES_BASE_URL_ENV: &str = "ITO_TEMPLATES_BASE_URL";
+
+/// Default download location for release bundles.
+pub const DEFAULT_RELEASES_URL: &str = "https://github.com/withakay/ito/releases/download";
+
+/// Cache subdirectory of the global config dir.
+const CACHE_SUBDIR: &str = "templates";
+
+/// Marker written once a bundle is fully unpacked and verified.
+const COMPLETE_MARKER: &str = ".ito-bundle-complete";
+
+/// Outcome of resolving a pinned template release.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PinResolution {
+    /// Source installers should read assets from.
+    pub source: AssetSource,
+    /// Set when the pin could not be honoured and embedded assets are used.
+    pub warning: Option<String>,
+}
+
+/// Normalize a release tag (`0.2.1` -> `v0.2.1`).
+///
+/// # Errors
+///
+/// Returns a validation error for empty tags or tags containing anything
+/// other than ASCII alphanumerics, `.`, `-` and `_`.
+pub fn normalize_tag(raw: &str) -> CoreResult<String> {
+    let raw = raw.trim();
+    let valid = !raw.is_empty()
+        && raw
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'));
+    if !valid || raw.starts_with('.') {
+        return Err(CoreError::validation(format!(
+            "Invalid template release tag '{raw}' (expected e.g. v0.2.1)"
+        )));
+    }
+    if raw.starts_with('v') {
+        Ok(raw.to_string())
+    } else {
+        Ok(format!("v{raw}"))
+    }
+}
+
+/// Archive file name of the bundle published for `tag`.
+pub fn bundle_archive_name(tag: &str) -> String {
+    format!("ito-templates-{tag}.tar.gz")
+}
+
+/// Root of the bundle cache (`<global config dir>/templates`).
+pub fn bundle_cache_root(ctx: &ConfigContext) -> Option<PathBuf> {
+    ito_config::ito_config_dir(ctx).map(|d| d.join(CACHE_SUBDIR))
+}
+
+/// Release tag pinned by `templates.pin`, if any.
+///
+/// # Errors
+///
+/// Returns a validation error when the value is not a string or not a valid
+/// release tag.
+pub fn configured_pin(
+    project_root: &Path,
+    ito_path: &Path,
+    ctx: &ConfigContext,
+) -> CoreResult<Option<String>> {
+    let merged = load_cascading_project_config(project_root, ito_path, ctx).merged;
+    let parts: Vec<&str> = TEMPLATES_PIN_CONFIG_KEY.split('.').collect();
+    match json_get_path(&merged, &parts) {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::String(tag)) if tag.trim().is_empty() => Ok(None),
+        Some(serde_json::Value::String(tag)) => normalize_tag(tag).map(Some),
+        Some(other) => Err(CoreError::validation(format!(
+            "Invalid {TEMPLATES_PIN_CONFIG_KEY} value {other} (expected a release tag string)"
+        ))),
+    }
+}
+
+/// The cached bundle for `tag`, if it was fully unpacked earlier.
+pub fn cached_bundle(cache_root: &Path, tag: &str) -> Option<AssetSource> {
+    let root = cache_root.join(tag);
+    root.join(COMPLETE_MARKER)
+        .is_file()
+        .then(|| AssetSource::CachedBundle {
+            tag: tag.to_string(),
+            root,
+        })
+}
+
+/// Check `archive` against the first field of a `.sha256` file.
+///
+/// # Errors
+///
+/// Returns a validation error when the checksum file is empty or the digest
+/// does not match.
+pub fn verify_checksum(archive: &[u8], checksum_file: &str, name: &str) -> CoreResult<()> {
+    let Some(expected) = checksum_file.split_whitespace().next() else {
+        return Err(CoreError::validation(format!(
+            "Checksum file for {name} is empty"
+        )));
+    };
+    let actual = content_hash(archive);
+    if !expected.eq_ignore_ascii_case(&actual) {
+        return Err(CoreError::validation(format!(
+            "Checksum mismatch for {name}: expected {expected}, got {actual}"
+        )));
+    }
+    Ok(())
+}
+
+/// Resolve the asset source for a pinned release.
+///
+/// A previously cached bundle is used as-is (release bundles are immutable).
+/// Otherwise the bundle is fetched from `base` (see [`TEMPLATES_BASE_URL_ENV`]),
+/// verified and cached. When the download fails — typically because the
+/// machine is offline — the embedded assets are used and a warning is
+/// returned.
+///
+/// # Errors
+///
+/// Returns an error when the downloaded bundle fails checksum verification or
+/// cannot be unpacked; a corrupt or tampered bundle is never installed.
+pub fn resolve_pinned_source(
+    cache_root: &Path,
+    tag: &str,
+    base: &str,
+) -> CoreResult<PinResolution> {
+    if let Some(source) = cached_bundle(cache_root, tag) {
+        return Ok(PinResolution {
+            source,
+            warning: None,
+        });
+    }
+
+    let name = bundle_archive_name(tag);
+    let staging = tempfile::tempdir()
+        .map_err(|e| CoreError::io("creating template bundle download directory", e))?;
+    let archive_path = staging.path().join(&name);
+    let checksum_path = staging.path().join(format!("{name}.sha256"));
+    let fetched = fetch(base, tag, &name, &archive_path)
+        .and_then(|()| fetch(base, tag, &format!("{name}.sha256"), &checksum_path));
+    if let Err(e) = fetched {
+        return Ok(PinResolution {
+            source: AssetSource::Embedded,
+            warning: Some(format!(
+                "could not download templates {tag} ({e}); using the templates embedded in this binary"
+            )),
+        });
+    }
+
+    let archive = std::fs::read(&archive_path)
+        .map_err(|e| CoreError::io(format!("reading {}", archive_path.display()), e))?;
+    let checksum = std::fs::read_to_string(&checksum_path)
+        .map_err(|e| CoreError::io(format!("reading {}", checksum_path.display()), e))?;
+    verify_checksum(&archive, &checksum, &name)?;
+
+    let source = unpack_bundle(cache_root, tag, &archive_path)?;
+    Ok(PinResolution {
+        source,
+        warning: None,
+    })
+}
+
+/// Download location from [`TEMPLATES_BASE_URL_ENV`], or the GitHub releases.
+pub fn bundle_base_url() -> String {
+    std::env::var(TEMPLATES_BASE_URL_ENV)
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_RELEASES_URL.to_string())
+}
+
+fn fetch(base: &str, tag: &str, file: &str, dest: &Path) -> CoreResult<()> {
+    let base = base.trim_end_matches('/');
+    if !base.contains("://") {
+        let src = Path::new(base).join(tag).join(file);
+        std::fs::copy(&src, dest)
+            .map_err(|e| CoreError::io(format!("copying {}", src.display()), e))?;
+        return Ok(());
+    }
+
+    let url = format!("{base}/{tag}/{file}");
+    let request = ProcessRequest::new("curl")
+        .args(["-fsSL", "--retry", "2", "-o"])
+        .arg(dest.to_string_lossy())
+        .arg(&url);
+    let output = SystemProcessRunner
+        .run(&request)
+        .map_err(|e| CoreError::process(format!("downloading {url}: {e}")))?;
+    if !output.success {
+        return Err(CoreError::process(format!(
+            "downloading {url}: {}",
+            output.stderr.trim()
+        )));
+    }
+    Ok(())
+}
+
+fn unpack_bundle(cache_root: &Path, tag: &str, archive: &Path) -> CoreResult<AssetSource> {
+    std::fs::create_dir_all(cache_root)
+        .map_err(|e| CoreError::io(format!("creating {}", cache_root.display()), e))?;
+    let unpacked = tempfile::tempdir_in(cache_root)
+        .map_err(|e| CoreError::io(format!("creating directory in {}", cache_root.display()), e))?;
+
+    let request = ProcessRequest::new("tar")
+        .arg("-xzf")
+        .arg(archive.to_string_lossy())
+        .arg("-C")
+        .arg(unpacked.path().to_string_lossy());
+    let output = SystemProcessRunner
+        .run(&request)
+        .map_err(|e| CoreError::process(format!("unpacking {}: {e}", archive.display())))?;
+    if !output.success {
+        return Err(CoreError::process(format!(
+            "unpacking {}: {}",
+            archive.display(),
+            output.stderr.trim()
+        )));
+    }
+
+    let marker = unpacked.path().join(COMPLETE_MARKER);
+    std::fs::write(&marker, tag)
+        .map_err(|e| CoreError::io(format!("writing {}", marker.display()), e))?;
+
+    let root = cache_root.join(tag);
+    if root.exists() {
+        std::fs::remove_dir_all(&root)
+            .map_err(|e| CoreError::io(format!("removing {}", root.display()), e))?;
+    }
+    let staged = unpacked.keep();
+    std::fs::rename(&staged, &root)
+        .map_err(|e| CoreError::io(format!("moving bundle into {}", root.display()), e))?;
+    Ok(AssetSource::CachedBundle {
+        tag: tag.to_string(),
+        root,
+    })
+}
+
+#[cfg(test)]
+#[path = "template_bundle_tests.rs"]
+mod template_bundle_tests;
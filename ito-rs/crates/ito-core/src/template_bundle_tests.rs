@@ -0,0 +1,104 @@
+use super::*;
+
+/// Publish `archive` and a checksum file under `<base>/<tag>/` like a release.
+fn publish(base: &Path, tag: &str, archive: &[u8], checksum: &str) {
+    let dir = base.join(tag);
+    std::fs::create_dir_all(&dir).unwrap();
+    let name = bundle_archive_name(tag);
+    std::fs::write(dir.join(&name), archive).unwrap();
+    std::fs::write(dir.join(format!("{name}.sha256")), checksum).unwrap();
+}
+
+#[test]
+fn normalize_tag_adds_v_prefix_and_rejects_paths() {
+    assert_eq!(normalize_tag("0.2.1").unwrap(), "v0.2.1");
+    assert_eq!(normalize_tag(" v0.2.1 ").unwrap(), "v0.2.1");
+    assert!(normalize_tag("").is_err());
+    assert!(normalize_tag("../v0.2.1").is_err());
+    assert!(normalize_tag("v0.2.1/x").is_err());
+}
+
+#[test]
+fn checksum_mismatch_is_rejected() {
+    let releases = tempfile::tempdir().unwrap();
+    let cache = tempfile::tempdir().unwrap();
+    publish(
+        releases.path(),
+        "v0.2.1",
+        b"not the real bundle",
+        &format!("{}  bundle\n", content_hash(b"the real bundle")),
+    );
+
+    let err = resolve_pinned_source(cache.path(), "v0.2.1", releases.path().to_str().unwrap())
+        .unwrap_err();
+
+    assert!(err.to_string().contains("Checksum mismatch"), "{err}");
+    assert!(cached_bundle(cache.path(), "v0.2.1").is_none());
+}
+
+#[test]
+fn unreachable_release_falls_back_to_embedded_with_warning() {
+    let cache = tempfile::tempdir().unwrap();
+    let offline = cache.path().join("no-such-mirror");
+
+    let resolved =
+        resolve_pinned_source(cache.path(), "v0.2.1", offline.to_str().unwrap()).unwrap();
+
+    assert_eq!(resolved.source, AssetSource::Embedded);
+    let warning = resolved.warning.unwrap();
+    assert!(warning.contains("v0.2.1"), "{warning}");
+    assert!(warning.contains("embedded"), "{warning}");
+}
+
+#[test]
+fn cached_bundle_is_used_without_downloading() {
+    let cache = tempfile::tempdir().unwrap();
+    let root = cache.path().join("v0.2.1");
+    std::fs::create_dir_all(&root).unwrap();
+    std::fs::write(root.join(COMPLETE_MARKER), "v0.2.1").unwrap();
+
+    let resolved =
+        resolve_pinned_source(cache.path(), "v0.2.1", "https://invalid.example").unwrap();
+
+    assert_eq!(resolved.warning, None);
+    assert_eq!(
+        resolved.source,
+        AssetSource::CachedBundle {
+            tag: "v0.2.1".to_string(),
+            root,
+        }
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn verified_bundle_is_unpacked_into_the_cache() {
+    let work = tempfile::tempdir().unwrap();
+    let assets = work.path().join("assets");
+    std::fs::create_dir_all(assets.join("commands")).unwrap();
+    std::fs::write(assets.join("commands/ito-apply.md"), "pinned apply").unwrap();
+    let archive = work.path().join("bundle.tar.gz");
+    let status = std::process::Command::new("tar")
+        .arg("-czf")
+        .arg(&archive)
+        .arg("-C")
+        .arg(&assets)
+        .arg(".")
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let bytes = std::fs::read(&archive).unwrap();
+    let releases = work.path().join("releases");
+    publish(&releases, "v0.2.1", &bytes, &content_hash(&bytes));
+    let cache = work.path().join("cache");
+
+    let resolved = resolve_pinned_source(&cache, "v0.2.1", releases.to_str().unwrap()).unwrap();
+
+    assert_eq!(resolved.warning, None);
+    assert_eq!(resolved.source.pinned_tag(), Some("v0.2.1"));
+    assert_eq!(
+        resolved.source.command_file("ito-apply.md").as_deref(),
+        Some(b"pinned apply".as_slice())
+    );
+    assert!(cached_bundle(&cache, "v0.2.1").is_some());
+}
@@ -0,0 +1,123 @@
+//! Per-change artifact skips.
+//!
+//! Some schema artifacts do not apply to every change (a one-line fix rarely
+//! needs a design doc). `ito workflow skip` records such artifacts under
+//! `skipped_artifacts` in the change's `.ito.yaml`; status, instruction, and
+//! apply computations then treat them as satisfied dependencies while still
+//! reporting them as `skipped`. Schemas opt an artifact out with
+//! `skippable: false`.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use ito_common::fs::StdFs;
+use ito_common::paths;
+use ito_config::ConfigContext;
+
+use super::schema_drift::{existing_change_dir, resolve_change_schema};
+use super::{SchemaYaml, WorkflowError};
+use crate::change_meta::{SkippedArtifact, read_change_meta_from_dir, set_change_meta_block};
+
+const SKIPPED_ARTIFACTS_KEY: &str = "skipped_artifacts";
+
+/// Skipped artifacts that are honored for `schema`, mapped to their reason.
+///
+/// Entries for artifacts the schema does not define, or marks
+/// `skippable: false`, are ignored.
+pub(crate) fn skipped_artifacts(
+    change_dir: &Path,
+    schema: &SchemaYaml,
+) -> BTreeMap<String, Option<String>> {
+    let meta = read_change_meta_from_dir(&StdFs, change_dir);
+    let mut out = BTreeMap::new();
+    for entry in meta.skipped_artifacts {
+        let skippable = schema
+            .artifacts
+            .iter()
+            .any(|artifact| artifact.id == entry.id && artifact.skippable);
+        if skippable {
+            out.insert(entry.id, entry.reason);
+        }
+    }
+    out
+}
+
+/// Mark `artifact_id` as not applicable to `change`, recording `reason`.
+///
+/// Skipping an already skipped artifact replaces its reason.
+///
+/// # Errors
+///
+/// Returns [`WorkflowError::ArtifactNotFound`] when the change's schema does
+/// not define the artifact and [`WorkflowError::ArtifactNotSkippable`] when the
+/// schema marks it `skippable: false`.
+pub fn skip_artifact(
+    ito_path: &Path,
+    change: &str,
+    artifact_id: &str,
+    reason: &str,
+    ctx: &ConfigContext,
+) -> Result<(), WorkflowError> {
+    let change_dir = existing_change_dir(ito_path, change)?;
+    let resolved = resolve_change_schema(ito_path, change, None, ctx)?;
+    let artifact = resolved
+        .schema
+        .artifacts
+        .iter()
+        .find(|artifact| artifact.id == artifact_id)
+        .ok_or_else(|| WorkflowError::ArtifactNotFound(artifact_id.to_string()))?;
+    if !artifact.skippable {
+        return Err(WorkflowError::ArtifactNotSkippable(artifact_id.to_string()));
+    }
+
+    let mut entries = read_change_meta_from_dir(&StdFs, &change_dir).skipped_artifacts;
+    let reason = Some(reason.trim().to_string()).filter(|r| !r.is_empty());
+    match entries.iter_mut().find(|entry| entry.id == artifact_id) {
+        Some(entry) => entry.reason = reason,
+        None => entries.push(SkippedArtifact {
+            id: artifact_id.to_string(),
+            reason,
+        }),
+    }
+    write_skipped_artifacts(ito_path, change, &entries)
+}
+
+/// Remove a skip recorded for `artifact_id`.
+///
+/// Returns `false` when the artifact was not skipped.
+pub fn unskip_artifact(
+    ito_path: &Path,
+    change: &str,
+    artifact_id: &str,
+) -> Result<bool, WorkflowError> {
+    let change_dir = existing_change_dir(ito_path, change)?;
+    let mut entries = read_change_meta_from_dir(&StdFs, &change_dir).skipped_artifacts;
+    let before = entries.len();
+    entries.retain(|entry| entry.id != artifact_id);
+    if entries.len() == before {
+        return Ok(false);
+    }
+    write_skipped_artifacts(ito_path, change, &entries)?;
+    Ok(true)
+}
+
+fn write_skipped_artifacts(
+    ito_path: &Path,
+    change: &str,
+    entries: &[SkippedArtifact],
+) -> Result<(), WorkflowError> {
+    let path = paths::change_meta_path(ito_path, change);
+    let contents = match ito_common::io::read_to_string_std(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    let block = if entries.is_empty() {
+        None
+    } else {
+        Some(serde_yaml::to_string(entries)?)
+    };
+    let contents = set_change_meta_block(&contents, SKIPPED_ARTIFACTS_KEY, block.as_deref());
+    ito_common::io::write_std(&path, contents)?;
+    Ok(())
+}
@@ -0,0 +1,403 @@
+//! Offline export of everything an agent needs to work on a change.
+//!
+//! Air-gapped agent runs cannot call `ito` for instructions, so
+//! [`export_change_instructions`] collects the change status, the rendered
+//! instructions for every schema artifact (in build order), the contents of
+//! completed dependency artifacts, user guidance, and the apply instructions
+//! in one value. [`write_instruction_bundle`] lays it out as numbered
+//! markdown files plus a `manifest.json`.
+//!
+//! The export is deterministic: artifacts follow the schema build order,
+//! dependency files are sorted, and no timestamp is recorded unless the
+//! caller passes one in [`InstructionExportOptions::generated_at`].
+
+use std::path::Path;
+
+use ito_config::ConfigContext;
+use serde::Serialize;
+
+use super::artifact_output_files;
+use super::{
+    ApplyInstructionsResponse, ChangeStatus, InstructionsResponse, TemplatesError,
+    compute_apply_instructions, compute_change_status, load_user_guidance,
+    load_user_guidance_for_artifact, resolve_rendered_instructions,
+};
+
+/// Default per-file size limit for inlined dependency contents.
+pub const DEFAULT_EXPORT_FILE_LIMIT: usize = 64 * 1024;
+
+/// Line appended to dependency contents cut at the size limit.
+pub const EXPORT_TRUNCATION_MARKER: &str =
+    "[... truncated: file exceeds the export size limit ...]";
+
+/// File name of the bundle manifest.
+pub const EXPORT_MANIFEST_FILE: &str = "manifest.json";
+
+/// Options for [`export_change_instructions`].
+#[derive(Debug, Clone)]
+pub struct InstructionExportOptions {
+    /// Maximum bytes of each dependency file to inline.
+    pub max_file_bytes: usize,
+    /// Timestamp recorded in the export; `None` keeps it reproducible.
+    pub generated_at: Option<String>,
+}
+
+impl Default for InstructionExportOptions {
+    fn default() -> Self {
+        Self {
+            max_file_bytes: DEFAULT_EXPORT_FILE_LIMIT,
+            generated_at: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Every instruction for one change, ready to hand to an offline agent.
+pub struct InstructionExport {
+    #[serde(rename = "changeName")]
+    /// Change directory name.
+    pub change_name: String,
+    #[serde(rename = "schemaName")]
+    /// Resolved schema name.
+    pub schema_name: String,
+    #[serde(rename = "generatedAt", skip_serializing_if = "Option::is_none")]
+    /// When the export was generated, if requested.
+    pub generated_at: Option<String>,
+
+    /// Change status at export time.
+    pub status: ChangeStatus,
+    #[serde(rename = "userGuidance", skip_serializing_if = "Option::is_none")]
+    /// Shared user guidance.
+    pub user_guidance: Option<String>,
+
+    /// Per-artifact instructions in schema build order.
+    pub artifacts: Vec<ExportedArtifact>,
+
+    /// Apply-stage instructions.
+    pub apply: ApplyInstructionsResponse,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Instructions for one artifact plus the dependency contents they refer to.
+pub struct ExportedArtifact {
+    /// Rendered artifact instructions.
+    pub instructions: InstructionsResponse,
+    #[serde(rename = "userGuidance", skip_serializing_if = "Option::is_none")]
+    /// Guidance scoped to this artifact (`.ito/user-prompts/<id>.md`).
+    pub user_guidance: Option<String>,
+    #[serde(rename = "dependencyFiles")]
+    /// Contents of completed dependency artifacts.
+    pub dependency_files: Vec<ExportedFile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// An inlined file, possibly truncated.
+pub struct ExportedFile {
+    /// Path relative to the change directory, with `/` separators.
+    pub path: String,
+
+    /// File contents, ending in [`EXPORT_TRUNCATION_MARKER`] when cut.
+    pub content: String,
+
+    /// Whether `content` was cut at the size limit.
+    pub truncated: bool,
+    #[serde(rename = "sizeBytes")]
+    /// Size of the file on disk.
+    pub size_bytes: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// One file in an exported bundle, as listed in `manifest.json`.
+pub struct BundleFile {
+    /// File name inside the bundle directory.
+    pub path: String,
+
+    /// `overview`, `artifact`, or `apply`.
+    pub kind: String,
+    #[serde(rename = "artifactId", skip_serializing_if = "Option::is_none")]
+    /// Artifact id for `artifact` entries.
+    pub artifact_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Contents of a bundle's `manifest.json`.
+pub struct BundleManifest {
+    #[serde(rename = "changeName")]
+    /// Change directory name.
+    pub change_name: String,
+    #[serde(rename = "schemaName")]
+    /// Resolved schema name.
+    pub schema_name: String,
+    #[serde(rename = "generatedAt", skip_serializing_if = "Option::is_none")]
+    /// When the export was generated, if requested.
+    pub generated_at: Option<String>,
+
+    /// Change status at export time.
+    pub status: ChangeStatus,
+
+    /// Bundle files in reading order.
+    pub files: Vec<BundleFile>,
+}
+
+/// Collect every instruction for `change`.
+///
+/// Artifacts are exported in schema build order with rendered templates.
+/// Dependencies that are done (not skipped) have their output files inlined,
+/// each cut to `opts.max_file_bytes`.
+pub fn export_change_instructions(
+    ito_path: &Path,
+    change: &str,
+    schema_name: Option<&str>,
+    ctx: &ConfigContext,
+    opts: &InstructionExportOptions,
+) -> Result<InstructionExport, TemplatesError> {
+    let status = compute_change_status(ito_path, change, schema_name, ctx)?;
+    let change_dir = ito_common::paths::change_dir(ito_path, change);
+
+    let mut artifacts = Vec::new();
+    for artifact in &status.artifacts {
+        let instructions =
+            resolve_rendered_instructions(ito_path, change, schema_name, &artifact.id, ctx)?;
+        let mut dependency_files = Vec::new();
+        for dep in instructions
+            .dependencies
+            .iter()
+            .filter(|d| d.done && !d.skipped)
+        {
+            for rel in artifact_output_files(&change_dir, &dep.path) {
+                dependency_files.push(read_limited(&change_dir, &rel, opts.max_file_bytes)?);
+            }
+        }
+        artifacts.push(ExportedArtifact {
+            user_guidance: load_user_guidance_for_artifact(ito_path, &artifact.id)?,
+            instructions,
+            dependency_files,
+        });
+    }
+
+    Ok(InstructionExport {
+        change_name: status.change_name.clone(),
+        schema_name: status.schema_name.clone(),
+        generated_at: opts.generated_at.clone(),
+        user_guidance: load_user_guidance(ito_path)?,
+        apply: compute_apply_instructions(ito_path, change, schema_name, ctx)?,
+        status,
+        artifacts,
+    })
+}
+
+/// Write `export` to `out_dir` as numbered markdown files and a manifest.
+///
+/// The bundle holds `00-overview.md`, one `NN-<artifact>.md` per artifact in
+/// build order, a final `NN-apply.md`, and [`EXPORT_MANIFEST_FILE`]. Returns
+/// the manifest that was written.
+pub fn write_instruction_bundle(
+    export: &InstructionExport,
+    out_dir: &Path,
+) -> Result<BundleManifest, TemplatesError> {
+    std::fs::create_dir_all(out_dir)?;
+
+    let mut files = Vec::new();
+    let mut write = |name: String, kind: &str, artifact_id: Option<&str>, body: String| {
+        std::fs::write(out_dir.join(&name), body)?;
+        files.push(BundleFile {
+            path: name,
+            kind: kind.to_string(),
+            artifact_id: artifact_id.map(str::to_string),
+        });
+        Ok::<(), std::io::Error>(())
+    };
+
+    write(
+        "00-overview.md".to_string(),
+        "overview",
+        None,
+        render_overview(export),
+    )?;
+    for (idx, artifact) in export.artifacts.iter().enumerate() {
+        let id = artifact.instructions.artifact_id.as_str();
+        write(
+            format!("{:02}-{id}.md", idx + 1),
+            "artifact",
+            Some(id),
+            render_artifact(export, artifact),
+        )?;
+    }
+    write(
+        format!("{:02}-apply.md", export.artifacts.len() + 1),
+        "apply",
+        None,
+        render_apply(&export.apply),
+    )?;
+
+    let manifest = BundleManifest {
+        change_name: export.change_name.clone(),
+        schema_name: export.schema_name.clone(),
+        generated_at: export.generated_at.clone(),
+        status: export.status.clone(),
+        files,
+    };
+    let mut json = serde_json::to_string_pretty(&manifest).map_err(std::io::Error::other)?;
+    json.push('\n');
+    std::fs::write(out_dir.join(EXPORT_MANIFEST_FILE), json)?;
+    Ok(manifest)
+}
+
+fn read_limited(
+    change_dir: &Path,
+    rel: &Path,
+    limit: usize,
+) -> Result<ExportedFile, TemplatesError> {
+    let text = ito_common::io::read_to_string_std(&change_dir.join(rel))?;
+    let size_bytes = text.len();
+    let truncated = size_bytes > limit;
+    let content = if truncated {
+        let mut end = limit;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        let mut cut = text[..end].to_string();
+        if !cut.ends_with('\n') {
+            cut.push('\n');
+        }
+        cut.push_str(EXPORT_TRUNCATION_MARKER);
+        cut.push('\n');
+        cut
+    } else {
+        text
+    };
+    Ok(ExportedFile {
+        path: rel.to_string_lossy().replace('\\', "/"),
+        content,
+        truncated,
+        size_bytes,
+    })
+}
+
+fn render_overview(export: &InstructionExport) -> String {
+    let mut out = format!(
+        "# Change {}\n\n- Schema: {}\n",
+        export.change_name, export.schema_name
+    );
+    if let Some(at) = &export.generated_at {
+        out.push_str(&format!("- Generated: {at}\n"));
+    }
+    out.push_str("\n## Artifacts\n\n");
+    for artifact in &export.status.artifacts {
+        out.push_str(&format!(
+            "- {} ({}): {}\n",
+            artifact.id, artifact.output_path, artifact.status
+        ));
+    }
+    if let Some(guidance) = &export.user_guidance {
+        out.push_str(&format!("\n## User Guidance\n\n{guidance}\n"));
+    }
+    out
+}
+
+fn render_artifact(export: &InstructionExport, artifact: &ExportedArtifact) -> String {
+    let resp = &artifact.instructions;
+    let status = export
+        .status
+        .artifacts
+        .iter()
+        .find(|a| a.id == resp.artifact_id)
+        .map_or("unknown", |a| a.status.as_str());
+
+    let mut out = format!(
+        "# Artifact {}\n\n- Status: {status}\n- Output: {}\n",
+        resp.artifact_id, resp.output_path
+    );
+    if !resp.dependencies.is_empty() {
+        let deps: Vec<String> = resp
+            .dependencies
+            .iter()
+            .map(|d| {
+                let state = match (d.done, d.skipped) {
+                    (_, true) => "skipped",
+                    (true, false) => "done",
+                    (false, false) => "missing",
+                };
+                format!("{} ({state})", d.id)
+            })
+            .collect();
+        out.push_str(&format!("- Requires: {}\n", deps.join(", ")));
+    }
+    if !resp.unlocks.is_empty() {
+        out.push_str(&format!("- Unlocks: {}\n", resp.unlocks.join(", ")));
+    }
+    if !resp.description.is_empty() {
+        out.push_str(&format!("\n## Description\n\n{}\n", resp.description));
+    }
+    if let Some(instruction) = &resp.instruction {
+        out.push_str(&format!("\n## Instruction\n\n{}\n", instruction.trim_end()));
+    }
+    if let Some(guidance) = &artifact.user_guidance {
+        out.push_str(&format!("\n## User Guidance\n\n{guidance}\n"));
+    }
+    out.push_str(&format!(
+        "\n## Template\n\n{}",
+        fenced(&resp.template, "markdown")
+    ));
+    for file in &artifact.dependency_files {
+        out.push_str(&format!(
+            "\n## Dependency: {}\n\n{}",
+            file.path,
+            fenced(&file.content, "")
+        ));
+    }
+    out
+}
+
+fn render_apply(apply: &ApplyInstructionsResponse) -> String {
+    let mut out = format!(
+        "# Apply\n\n- State: {}\n- Progress: {}/{} complete\n",
+        apply.state, apply.progress.complete, apply.progress.total
+    );
+    if let Some(missing) = &apply.missing_artifacts {
+        out.push_str(&format!("- Missing artifacts: {}\n", missing.join(", ")));
+    }
+    out.push_str(&format!(
+        "\n## Instruction\n\n{}\n",
+        apply.instruction.trim_end()
+    ));
+    if !apply.context_files.is_empty() {
+        out.push_str("\n## Context Files\n\n");
+        for (id, path) in &apply.context_files {
+            out.push_str(&format!("- {id}: {path}\n"));
+        }
+    }
+    if !apply.tasks.is_empty() {
+        out.push_str("\n## Tasks\n\n");
+        for task in &apply.tasks {
+            let mark = if task.done { "x" } else { " " };
+            let indent = "  ".repeat(usize::from(task.depth));
+            out.push_str(&format!(
+                "{indent}- [{mark}] {} {}\n",
+                task.id, task.description
+            ));
+        }
+    }
+    out
+}
+
+/// Wrap `text` in a code fence longer than any backtick run inside it.
+fn fenced(text: &str, lang: &str) -> String {
+    let mut longest = 0;
+    let mut run = 0;
+    for c in text.chars() {
+        if c == '`' {
+            run += 1;
+            longest = longest.max(run);
+        } else {
+            run = 0;
+        }
+    }
+    let fence = "`".repeat(longest.max(2) + 1);
+    let body = text.strip_suffix('\n').unwrap_or(text);
+    format!("{fence}{lang}\n{body}\n{fence}\n")
+}
+
+#[cfg(test)]
+#[path = "instruction_export_tests.rs"]
+mod instruction_export_tests;
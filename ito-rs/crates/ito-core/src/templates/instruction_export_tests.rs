@@ -0,0 +1,25 @@
+use super::*;
+
+#[test]
+fn fenced_outgrows_backtick_runs_in_the_text() {
+    assert_eq!(fenced("plain\n", ""), "```\nplain\n```\n");
+    assert_eq!(
+        fenced("```rust\nfn x() {}\n```\n", "markdown"),
+        "````markdown\n```rust\nfn x() {}\n```\n````\n"
+    );
+}
+
+#[test]
+fn read_limited_cuts_on_a_char_boundary_and_marks_truncation() {
+    let td = tempfile::tempdir().unwrap();
+    std::fs::write(td.path().join("a.md"), "héllo world").unwrap();
+
+    let file = read_limited(td.path(), Path::new("a.md"), 2).unwrap();
+    assert!(file.truncated);
+    assert_eq!(file.size_bytes, 12);
+    assert_eq!(file.content, format!("h\n{EXPORT_TRUNCATION_MARKER}\n"));
+
+    let file = read_limited(td.path(), Path::new("a.md"), 64).unwrap();
+    assert!(!file.truncated);
+    assert_eq!(file.content, "héllo world");
+}
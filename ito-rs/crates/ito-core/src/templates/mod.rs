@@ -11,14 +11,32 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+mod artifact_skips;
 mod guidance;
+mod instruction_export;
+mod next_artifact;
+mod path_variables;
+mod rendering;
 mod review;
 mod schema_assets;
+mod schema_drift;
+mod schema_structure;
 mod task_parsing;
 mod types;
+pub(crate) use artifact_skips::skipped_artifacts;
+pub use artifact_skips::{skip_artifact, unskip_artifact};
 pub use guidance::{
     load_composed_user_guidance, load_user_guidance, load_user_guidance_for_artifact,
 };
+pub use instruction_export::{
+    BundleFile, BundleManifest, DEFAULT_EXPORT_FILE_LIMIT, EXPORT_MANIFEST_FILE,
+    EXPORT_TRUNCATION_MARKER, ExportedArtifact, ExportedFile, InstructionExport,
+    InstructionExportOptions, export_change_instructions, write_instruction_bundle,
+};
+pub use next_artifact::{next_artifact, next_step_hint, select_next_step};
+pub(crate) use path_variables::PathVariables;
+use path_variables::{check_path_variables, expand_change_paths};
+pub use rendering::resolve_rendered_instructions;
 pub use review::compute_review_context;
 pub use schema_assets::{ExportSchemasResult, export_embedded_schemas};
 use schema_assets::{
@@ -26,16 +44,24 @@ use schema_assets::{
     load_embedded_validation_yaml, package_schemas_dir, project_schemas_dir, read_schema_template,
     user_schemas_dir,
 };
+pub use schema_drift::{
+    SCHEMA_BASELINE_DIR, describe_schema_drift, pin_change_schema, rebaseline_change_schema,
+};
+use schema_drift::{detect_schema_drift, resolve_change_schema};
+pub(crate) use schema_drift::{record_schema_baseline, resolve_change_dir_schema};
+use schema_structure::check_schema_structure;
 use task_parsing::{looks_like_enhanced_tasks, parse_checkbox_tasks, parse_enhanced_tasks};
 pub use types::{
-    AgentInstructionResponse, ApplyInstructionsResponse, ApplyYaml, ArtifactStatus, ArtifactYaml,
-    ChangeStatus, DependencyInfo, InstructionsResponse, PeerReviewContext, ProgressInfo,
-    ResolvedSchema, ReviewAffectedSpecInfo, ReviewArtifactInfo, ReviewCoveredRequirement,
-    ReviewTaskSummaryInfo, ReviewTestingPolicy, ReviewTraceabilityInfo, ReviewUnresolvedReference,
-    ReviewValidationIssueInfo, SchemaSource, SchemaYaml, TaskDiagnostic, TaskItem, TemplateInfo,
-    ValidationArtifactYaml, ValidationDefaultsYaml, ValidationLevelYaml,
-    ValidationTrackingSourceYaml, ValidationTrackingYaml, ValidationYaml, ValidatorId,
-    WorkflowError,
+    AgentInstructionResponse, ApplyInstructionsResponse, ApplyTracksYaml, ApplyYaml,
+    ArtifactStatus, ArtifactStatusChange, ArtifactValidateYaml, ArtifactYaml, BlockedArtifact,
+    BuiltinArtifactValidator, ChangeStatus, DependencyInfo, InstructionsResponse, NextArtifact,
+    NextStep, PeerReviewContext, ProgressInfo, ResolvedSchema, ReviewAffectedSpecInfo,
+    ReviewArtifactInfo, ReviewCoveredRequirement, ReviewTaskSummaryInfo, ReviewTestingPolicy,
+    ReviewTraceabilityInfo, ReviewUnresolvedReference, ReviewValidationIssueInfo, SchemaDrift,
+    SchemaDriftSeverity, SchemaRebaselineResult, SchemaSource, SchemaYaml, TaskDiagnostic,
+    TaskItem, TemplateInfo, TrackedFileProgress, ValidationArtifactYaml, ValidationDefaultsYaml,
+    ValidationLevelYaml, ValidationTrackingSourceYaml, ValidationTrackingYaml, ValidationYaml,
+    ValidatorId, WorkflowError,
 };
 
 /// One entry in the schema listing returned by [`list_schemas_detail`].
@@ -237,13 +263,7 @@ pub fn list_schemas_detail(ctx: &ConfigContext) -> SchemaListResponse {
             .iter()
             .map(|a| a.id.clone())
             .collect();
-        let source = match resolved.source {
-            SchemaSource::Project => "project",
-            SchemaSource::User => "user",
-            SchemaSource::Embedded => "embedded",
-            SchemaSource::Package => "package",
-        }
-        .to_string();
+        let source = resolved.source.as_str().to_string();
 
         schemas.push(SchemaListEntry {
             name: name.clone(),
@@ -384,46 +404,84 @@ pub fn compute_change_status(
     if !validate_change_name_input(change) {
         return Err(TemplatesError::InvalidChangeName);
     }
-    let schema_name = schema_name
-        .map(|s| s.to_string())
-        .unwrap_or_else(|| read_change_schema(ito_path, change));
-    let resolved = resolve_schema(Some(&schema_name), ctx)?;
+    let resolved = resolve_change_schema(ito_path, change, schema_name, ctx)?;
 
     let change_dir = paths::change_dir(ito_path, change);
     if !change_dir.exists() {
         return Err(TemplatesError::ChangeNotFound(change.to_string()));
     }
 
-    let mut artifacts_out: Vec<ArtifactStatus> = Vec::new();
-    let mut required_done_count: usize = 0;
-    let done_by_id = compute_done_by_id(&change_dir, &resolved.schema);
-    let required_count = resolved
-        .schema
+    let schema = expand_change_paths(&resolved.schema, change)?;
+    let artifacts_out = artifact_statuses(&change_dir, &schema);
+    let required_count = schema
+        .artifacts
+        .iter()
+        .filter(|artifact| !artifact.optional)
+        .count();
+    let required_done_count = schema
         .artifacts
         .iter()
         .filter(|artifact| !artifact.optional)
+        .filter(|artifact| {
+            artifacts_out.iter().any(|status| {
+                status.id == artifact.id && (status.status == "done" || status.status == "skipped")
+            })
+        })
         .count();
 
-    let order = build_order(&resolved.schema);
-    for id in order {
-        let Some(a) = resolved.schema.artifacts.iter().find(|a| a.id == id) else {
+    let required_artifact_ids: Vec<String> = schema
+        .artifacts
+        .iter()
+        .filter(|artifact| !artifact.optional)
+        .map(|a| a.id.clone())
+        .collect();
+    let apply_requires: Vec<String> = match schema.apply.as_ref() {
+        Some(apply) => apply
+            .requires
+            .clone()
+            .unwrap_or_else(|| required_artifact_ids.clone()),
+        None => required_artifact_ids,
+    };
+    let apply_requires = expand_artifact_requirements(&schema, &apply_requires);
+
+    let is_complete = required_done_count == required_count;
+    Ok(ChangeStatus {
+        change_name: change.to_string(),
+        schema_name: schema.name,
+        is_complete,
+        apply_requires,
+        artifacts: artifacts_out,
+    })
+}
+
+/// Compute per-artifact statuses for a change directory against a schema, in build order.
+///
+/// Skipped artifacts satisfy their dependents and are reported as `skipped`
+/// unless their output exists anyway, in which case they are `done`.
+pub(super) fn artifact_statuses(change_dir: &Path, schema: &SchemaYaml) -> Vec<ArtifactStatus> {
+    let done_by_id = compute_done_by_id(change_dir, schema);
+    let skipped = skipped_artifacts(change_dir, schema);
+    let satisfied = |id: &String| *done_by_id.get(id).unwrap_or(&false) || skipped.contains_key(id);
+    let mut out = Vec::new();
+    for id in build_order(schema) {
+        let Some(a) = schema.artifacts.iter().find(|a| a.id == id) else {
             continue;
         };
         let done = *done_by_id.get(&a.id).unwrap_or(&false);
+        let skip = if done { None } else { skipped.get(&a.id) };
         let mut missing: Vec<String> = Vec::new();
-        if !done {
+        if !done && skip.is_none() {
             for r in &a.requires {
-                if !*done_by_id.get(r).unwrap_or(&false) {
+                if !satisfied(r) {
                     missing.push(r.clone());
                 }
             }
         }
 
         let status = if done {
-            if !a.optional {
-                required_done_count += 1;
-            }
             "done".to_string()
+        } else if skip.is_some() {
+            "skipped".to_string()
         } else if a.optional {
             "optional".to_string()
         } else if missing.is_empty() {
@@ -431,38 +489,15 @@ pub fn compute_change_status(
         } else {
             "blocked".to_string()
         };
-        artifacts_out.push(ArtifactStatus {
+        out.push(ArtifactStatus {
             id: a.id.clone(),
             output_path: a.generates.clone(),
             status,
             missing_deps: missing,
+            skip_reason: skip.cloned().flatten(),
         });
     }
-
-    let required_artifact_ids: Vec<String> = resolved
-        .schema
-        .artifacts
-        .iter()
-        .filter(|artifact| !artifact.optional)
-        .map(|a| a.id.clone())
-        .collect();
-    let apply_requires: Vec<String> = match resolved.schema.apply.as_ref() {
-        Some(apply) => apply
-            .requires
-            .clone()
-            .unwrap_or_else(|| required_artifact_ids.clone()),
-        None => required_artifact_ids,
-    };
-    let apply_requires = expand_artifact_requirements(&resolved.schema, &apply_requires);
-
-    let is_complete = required_done_count == required_count;
-    Ok(ChangeStatus {
-        change_name: change.to_string(),
-        schema_name: resolved.schema.name,
-        is_complete,
-        apply_requires,
-        artifacts: artifacts_out,
-    })
+    out
 }
 
 fn expand_artifact_requirements(schema: &SchemaYaml, roots: &[String]) -> Vec<String> {
@@ -514,6 +549,8 @@ fn collect_artifact_requirement(schema: &SchemaYaml, id: &str, required: &mut BT
 ///             template: "a.tpl".to_string(),
 ///             instruction: None,
 ///             optional: false,
+///             skippable: true,
+///             validate: None,
 ///             requires: vec![],
 ///         },
 ///         ArtifactYaml {
@@ -523,6 +560,8 @@ fn collect_artifact_requirement(schema: &SchemaYaml, id: &str, required: &mut BT
 ///             template: "b.tpl".to_string(),
 ///             instruction: None,
 ///             optional: false,
+///             skippable: true,
+///             validate: None,
 ///             requires: vec!["a".to_string()],
 ///         },
 ///         ArtifactYaml {
@@ -532,6 +571,8 @@ fn collect_artifact_requirement(schema: &SchemaYaml, id: &str, required: &mut BT
 ///             template: "c.tpl".to_string(),
 ///             instruction: None,
 ///             optional: false,
+///             skippable: true,
+///             validate: None,
 ///             requires: vec!["a".to_string()],
 ///         },
 ///     ],
@@ -614,6 +655,7 @@ pub fn resolve_templates(
     ctx: &ConfigContext,
 ) -> Result<(String, BTreeMap<String, TemplateInfo>), TemplatesError> {
     let resolved = resolve_schema(schema_name, ctx)?;
+    check_path_variables(&resolved.schema)?;
 
     let mut templates: BTreeMap<String, TemplateInfo> = BTreeMap::new();
     for a in &resolved.schema.artifacts {
@@ -685,33 +727,36 @@ pub fn resolve_instructions(
     if !validate_change_name_input(change) {
         return Err(TemplatesError::InvalidChangeName);
     }
-    let schema_name = schema_name
-        .map(|s| s.to_string())
-        .unwrap_or_else(|| read_change_schema(ito_path, change));
-    let resolved = resolve_schema(Some(&schema_name), ctx)?;
+    let resolved = resolve_change_schema(ito_path, change, schema_name, ctx)?;
 
     let change_dir = paths::change_dir(ito_path, change);
     if !change_dir.exists() {
         return Err(TemplatesError::ChangeNotFound(change.to_string()));
     }
+    let schema_drift = detect_schema_drift(ito_path, change, &resolved);
+
+    let schema = expand_change_paths(&resolved.schema, change)?;
 
-    let a = resolved
-        .schema
+    let a = schema
         .artifacts
         .iter()
         .find(|a| a.id == artifact_id)
         .ok_or_else(|| TemplatesError::ArtifactNotFound(artifact_id.to_string()))?;
 
-    let done_by_id = compute_done_by_id(&change_dir, &resolved.schema);
+    let done_by_id = compute_done_by_id(&change_dir, &schema);
+    let skipped = skipped_artifacts(&change_dir, &schema);
 
     let deps: Vec<DependencyInfo> = a
         .requires
         .iter()
         .map(|id| {
-            let dep = resolved.schema.artifacts.iter().find(|d| d.id == *id);
+            let dep = schema.artifacts.iter().find(|d| d.id == *id);
+            let done = *done_by_id.get(id).unwrap_or(&false);
+            let dep_skipped = !done && skipped.contains_key(id);
             DependencyInfo {
                 id: id.clone(),
-                done: *done_by_id.get(id).unwrap_or(&false),
+                done: done || dep_skipped,
+                skipped: dep_skipped,
                 path: dep
                     .map(|d| d.generates.clone())
                     .unwrap_or_else(|| id.clone()),
@@ -720,8 +765,7 @@ pub fn resolve_instructions(
         })
         .collect();
 
-    let mut unlocks: Vec<String> = resolved
-        .schema
+    let mut unlocks: Vec<String> = schema
         .artifacts
         .iter()
         .filter(|other| other.requires.iter().any(|r| r == artifact_id))
@@ -742,13 +786,17 @@ pub fn resolve_instructions(
         template,
         dependencies: deps,
         unlocks,
+        skipped: skipped.contains_key(artifact_id),
+        skip_reason: skipped.get(artifact_id).cloned().flatten(),
+        schema_drift,
+        render_warning: None,
     })
 }
 
 /// Compute apply-stage instructions and progress for a change.
 ///
 /// Optional schema artifacts do not block apply by default; they only block when explicitly listed
-/// in `apply.requires`.
+/// in `apply.requires`. Artifacts skipped by the change never block apply.
 pub fn compute_apply_instructions(
     ito_path: &Path,
     change: &str,
@@ -758,16 +806,15 @@ pub fn compute_apply_instructions(
     if !validate_change_name_input(change) {
         return Err(TemplatesError::InvalidChangeName);
     }
-    let schema_name = schema_name
-        .map(|s| s.to_string())
-        .unwrap_or_else(|| read_change_schema(ito_path, change));
-    let resolved = resolve_schema(Some(&schema_name), ctx)?;
+    let resolved = resolve_change_schema(ito_path, change, schema_name, ctx)?;
     let change_dir = paths::change_dir(ito_path, change);
     if !change_dir.exists() {
         return Err(TemplatesError::ChangeNotFound(change.to_string()));
     }
+    let schema_drift = detect_schema_drift(ito_path, change, &resolved);
 
-    let schema = &resolved.schema;
+    let expanded = expand_change_paths(&resolved.schema, change)?;
+    let schema = &expanded;
     let apply = schema.apply.as_ref();
     let required_artifact_ids: Vec<String> = schema
         .artifacts
@@ -781,16 +828,19 @@ pub fn compute_apply_instructions(
     let apply_required_artifact_ids: Vec<String> = apply
         .and_then(|a| a.requires.clone())
         .unwrap_or(required_artifact_ids);
-    let tracks_file: Option<String> = apply.and_then(|a| a.tracks.clone());
+    let tracking_files: Vec<String> = apply
+        .map(|a| a.tracking_files().to_vec())
+        .unwrap_or_default();
     let schema_instruction: Option<String> = apply.and_then(|a| a.instruction.clone());
 
     // Check which required artifacts are missing.
+    let skipped = skipped_artifacts(&change_dir, schema);
     let mut missing_artifacts: Vec<String> = Vec::new();
     for artifact_id in &apply_required_artifact_ids {
         let Some(artifact) = schema.artifacts.iter().find(|a| a.id == *artifact_id) else {
             continue;
         };
-        if !artifact_done(&change_dir, &artifact.generates) {
+        if !artifact_done(&change_dir, &artifact.generates) && !skipped.contains_key(artifact_id) {
             missing_artifacts.push(artifact_id.clone());
         }
     }
@@ -809,80 +859,45 @@ pub fn compute_apply_instructions(
         }
     }
 
-    // Parse tasks if tracking file exists.
-    let mut tasks: Vec<TaskItem> = Vec::new();
-    let mut tracks_file_exists = false;
-    let mut tracks_path: Option<String> = None;
-    let mut tracks_format: Option<String> = None;
-    let tracks_diagnostics: Option<Vec<TaskDiagnostic>> = None;
-
-    if let Some(tf) = &tracks_file {
+    // Parse tasks from every tracking file that exists.
+    let mut tracks: Vec<TrackedFileProgress> = Vec::new();
+    let mut file_tasks: Vec<Vec<TaskItem>> = Vec::new();
+    for tf in &tracking_files {
         let p = change_dir.join(tf);
-        tracks_path = Some(p.to_string_lossy().to_string());
-        tracks_file_exists = p.exists();
-        if tracks_file_exists {
-            let content = ito_common::io::read_to_string_std(&p)?;
-            let checkbox = parse_checkbox_tasks(&content);
-            if !checkbox.is_empty() {
-                tracks_format = Some("checkbox".to_string());
-                tasks = checkbox;
-            } else {
-                let enhanced = parse_enhanced_tasks(&content);
-                if !enhanced.is_empty() {
-                    tracks_format = Some("enhanced".to_string());
-                    tasks = enhanced;
-                } else if looks_like_enhanced_tasks(&content) {
-                    tracks_format = Some("enhanced".to_string());
-                } else {
-                    tracks_format = Some("unknown".to_string());
-                }
-            }
-        }
+        let exists = p.exists();
+        let (format, parsed) = if exists {
+            parse_tracking_file(&p)?
+        } else {
+            (None, Vec::new())
+        };
+        tracks.push(TrackedFileProgress {
+            file: tf.clone(),
+            path: p.to_string_lossy().to_string(),
+            exists,
+            progress: tracking_progress(format.as_deref(), &parsed),
+            format,
+        });
+        file_tasks.push(parsed);
     }
+    let tasks = merge_tracked_tasks(&tracking_files, file_tasks);
+    let tracks_diagnostics: Option<Vec<TaskDiagnostic>> = None;
 
-    // Calculate progress.
-    let total = tasks.len();
-    let complete = tasks.iter().filter(|t| t.done).count();
-    let remaining = total.saturating_sub(complete);
-    let mut in_progress: Option<usize> = None;
-    let mut pending: Option<usize> = None;
-    if tracks_format.as_deref() == Some("enhanced") {
-        let mut in_progress_count = 0;
-        let mut pending_count = 0;
-        for task in &tasks {
-            let Some(status) = task.status.as_deref() else {
-                continue;
-            };
-            let status = status.trim();
-            match status {
-                "in-progress" | "in_progress" | "in progress" => in_progress_count += 1,
-                "pending" => pending_count += 1,
-                _ => {}
-            }
-        }
-        in_progress = Some(in_progress_count);
-        pending = Some(pending_count);
-    }
-    if tracks_format.as_deref() == Some("checkbox") {
-        let mut in_progress_count = 0;
-        for task in &tasks {
-            let Some(status) = task.status.as_deref() else {
-                continue;
-            };
-            if status.trim() == "in-progress" {
-                in_progress_count += 1;
-            }
-        }
-        in_progress = Some(in_progress_count);
-        pending = Some(total.saturating_sub(complete + in_progress_count));
-    }
-    let progress = ProgressInfo {
-        total,
-        complete,
-        remaining,
-        in_progress,
-        pending,
-    };
+    let tracks_file = tracking_files.first().cloned();
+    let tracks_path = tracks.first().map(|t| t.path.clone());
+    let tracks_format = aggregate_tracks_format(&tracks);
+    let progress = aggregate_progress(&tracks);
+    let total = progress.total;
+    let remaining = progress.remaining;
+    let missing_tracks: Vec<String> = tracks
+        .iter()
+        .filter(|t| !t.exists)
+        .map(|t| tracking_filename(&t.file))
+        .collect();
+    let tracks_filenames = tracking_files
+        .iter()
+        .map(|f| tracking_filename(f))
+        .collect::<Vec<_>>()
+        .join(", ");
 
     // Determine state and instruction.
     let (state, instruction) = if !missing_artifacts.is_empty() {
@@ -893,28 +908,29 @@ pub fn compute_apply_instructions(
                 missing_artifacts.join(", ")
             ),
         )
-    } else if tracks_file.is_some() && !tracks_file_exists {
-        let tracks_filename = tracks_file
-            .as_deref()
-            .and_then(|p| Path::new(p).file_name())
-            .map(|s| s.to_string_lossy().to_string())
-            .unwrap_or_else(|| "tasks.md".to_string());
+    } else if !missing_tracks.is_empty() {
+        let (noun, verb) = if missing_tracks.len() == 1 {
+            ("file", "is")
+        } else {
+            ("files", "are")
+        };
         (
             "blocked".to_string(),
             format!(
-                "The {tracks_filename} file is missing and must be created.\nUse ito-proposal to complete the proposal package."
+                "The {} {noun} {verb} missing and must be created.\nUse ito-proposal to complete the proposal package.",
+                missing_tracks.join(", ")
             ),
         )
-    } else if tracks_file.is_some() && tracks_file_exists && total == 0 {
-        let tracks_filename = tracks_file
-            .as_deref()
-            .and_then(|p| Path::new(p).file_name())
-            .map(|s| s.to_string_lossy().to_string())
-            .unwrap_or_else(|| "tasks.md".to_string());
+    } else if tracks_file.is_some() && total == 0 {
+        let (noun, verb) = if tracking_files.len() == 1 {
+            ("file exists", "contains")
+        } else {
+            ("files exist", "contain")
+        };
         (
             "blocked".to_string(),
             format!(
-                "The {tracks_filename} file exists but contains no tasks.\nAdd tasks to {tracks_filename} through ito-proposal."
+                "The {tracks_filenames} {noun} but {verb} no tasks.\nAdd tasks to {tracks_filenames} through ito-proposal."
             ),
         )
     } else if tracks_file.is_some() && remaining == 0 && total > 0 {
@@ -945,6 +961,12 @@ pub fn compute_apply_instructions(
         )
     };
 
+    // Skips only matter while the artifact output is absent.
+    let skipped_artifacts: BTreeMap<String, Option<String>> = skipped
+        .into_iter()
+        .filter(|(id, _)| !context_files.contains_key(id))
+        .collect();
+
     Ok(ApplyInstructionsResponse {
         change_name: change.to_string(),
         change_dir: change_dir.to_string_lossy().to_string(),
@@ -962,13 +984,160 @@ pub fn compute_apply_instructions(
         } else {
             Some(missing_artifacts)
         },
+        skipped_artifacts,
         instruction,
+        schema_drift,
+        tracks,
     })
 }
 
-fn load_schema_yaml(schema_dir: &Path) -> Result<SchemaYaml, WorkflowError> {
+/// Detect the format of a tracking file and parse its tasks.
+fn parse_tracking_file(path: &Path) -> Result<(Option<String>, Vec<TaskItem>), TemplatesError> {
+    let content = ito_common::io::read_to_string_std(path)?;
+    let checkbox = parse_checkbox_tasks(&content);
+    if !checkbox.is_empty() {
+        return Ok((Some("checkbox".to_string()), checkbox));
+    }
+    let enhanced = parse_enhanced_tasks(&content);
+    if !enhanced.is_empty() {
+        return Ok((Some("enhanced".to_string()), enhanced));
+    }
+    let format = if looks_like_enhanced_tasks(&content) {
+        "enhanced"
+    } else {
+        "unknown"
+    };
+    Ok((Some(format.to_string()), Vec::new()))
+}
+
+/// Calculate progress totals for the tasks of one tracking file.
+///
+/// Only leaf tasks are counted, so a checkbox parent is not double-counted
+/// alongside its subtasks.
+fn tracking_progress(format: Option<&str>, tasks: &[TaskItem]) -> ProgressInfo {
+    let leaves: Vec<&TaskItem> = tasks
+        .iter()
+        .filter(|t| {
+            !tasks
+                .iter()
+                .any(|c| c.parent_id.as_deref() == Some(t.id.as_str()))
+        })
+        .collect();
+    let tasks = &leaves;
+    let total = tasks.len();
+    let complete = tasks.iter().filter(|t| t.done).count();
+    let remaining = total.saturating_sub(complete);
+    let mut in_progress: Option<usize> = None;
+    let mut pending: Option<usize> = None;
+    if format == Some("enhanced") {
+        let mut in_progress_count = 0;
+        let mut pending_count = 0;
+        for task in tasks {
+            let Some(status) = task.status.as_deref() else {
+                continue;
+            };
+            let status = status.trim();
+            match status {
+                "in-progress" | "in_progress" | "in progress" => in_progress_count += 1,
+                "pending" => pending_count += 1,
+                _ => {}
+            }
+        }
+        in_progress = Some(in_progress_count);
+        pending = Some(pending_count);
+    }
+    if format == Some("checkbox") {
+        let mut in_progress_count = 0;
+        for task in tasks {
+            let Some(status) = task.status.as_deref() else {
+                continue;
+            };
+            if status.trim() == "in-progress" {
+                in_progress_count += 1;
+            }
+        }
+        in_progress = Some(in_progress_count);
+        pending = Some(total.saturating_sub(complete + in_progress_count));
+    }
+    ProgressInfo {
+        total,
+        complete,
+        remaining,
+        in_progress,
+        pending,
+    }
+}
+
+/// Sum per-file progress; status counts are only reported when some file knows them.
+fn aggregate_progress(tracks: &[TrackedFileProgress]) -> ProgressInfo {
+    let sum_known = |count: fn(&ProgressInfo) -> Option<usize>| {
+        tracks
+            .iter()
+            .filter_map(|t| count(&t.progress))
+            .reduce(|a, b| a + b)
+    };
+    ProgressInfo {
+        total: tracks.iter().map(|t| t.progress.total).sum(),
+        complete: tracks.iter().map(|t| t.progress.complete).sum(),
+        remaining: tracks.iter().map(|t| t.progress.remaining).sum(),
+        in_progress: sum_known(|p| p.in_progress),
+        pending: sum_known(|p| p.pending),
+    }
+}
+
+/// Overall tracking format: the shared format of every existing file, or `mixed`.
+fn aggregate_tracks_format(tracks: &[TrackedFileProgress]) -> Option<String> {
+    let mut formats = tracks.iter().filter_map(|t| t.format.as_deref());
+    let first = formats.next()?;
+    if formats.all(|f| f == first) {
+        Some(first.to_string())
+    } else {
+        Some("mixed".to_string())
+    }
+}
+
+/// Concatenate tasks from every tracking file in schema order.
+///
+/// Task ids that appear in more than one file are prefixed with the file stem
+/// (`backend:1.1`) so each merged task stays addressable.
+fn merge_tracked_tasks(files: &[String], file_tasks: Vec<Vec<TaskItem>>) -> Vec<TaskItem> {
+    let mut files_per_id: BTreeMap<String, usize> = BTreeMap::new();
+    for tasks in &file_tasks {
+        let ids: BTreeSet<&str> = tasks.iter().map(|t| t.id.as_str()).collect();
+        for id in ids {
+            *files_per_id.entry(id.to_string()).or_default() += 1;
+        }
+    }
+
+    let mut merged = Vec::new();
+    for (file, tasks) in files.iter().zip(file_tasks) {
+        let stem = Path::new(file)
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| file.clone());
+        for mut task in tasks {
+            if files_per_id.get(&task.id).is_some_and(|n| *n > 1) {
+                task.id = format!("{stem}:{}", task.id);
+            }
+            merged.push(task);
+        }
+    }
+    merged
+}
+
+fn tracking_filename(file: &str) -> String {
+    Path::new(file)
+        .file_name()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| file.to_string())
+}
+
+/// Load and structurally check `schema.yaml` from a schema directory.
+pub(super) fn load_schema_yaml(schema_dir: &Path) -> Result<SchemaYaml, WorkflowError> {
     let s = ito_common::io::read_to_string_std(&schema_dir.join("schema.yaml"))?;
-    Ok(serde_yaml::from_str(&s)?)
+    let schema: SchemaYaml = serde_yaml::from_str(&s)?;
+    check_schema_structure(&schema)?;
+    Ok(schema)
 }
 
 fn load_validation_yaml(schema_dir: &Path) -> Result<Option<ValidationYaml>, WorkflowError> {
@@ -1004,7 +1173,8 @@ fn compute_done_by_id(change_dir: &Path, schema: &SchemaYaml) -> BTreeMap<String
 /// reuse the same minimal glob semantics as schema artifact completion.
 pub(crate) fn artifact_done(change_dir: &Path, generates: &str) -> bool {
     if !generates.contains('*') {
-        return change_dir.join(generates).exists();
+        let path = change_dir.join(generates);
+        return path.exists() && !is_front_matter_stub(&path);
     }
 
     // Minimal glob support for patterns used by schemas:
@@ -1019,6 +1189,18 @@ pub(crate) fn artifact_done(change_dir: &Path, generates: &str) -> bool {
     dir_contains_filename_suffix(&base_dir, &suffix)
 }
 
+/// True for a markdown file holding nothing but front matter, such as the
+/// `proposal.md` skeleton written by `ito create change`.
+fn is_front_matter_stub(path: &Path) -> bool {
+    let Ok(content) = ito_common::io::read_to_string(path) else {
+        return false;
+    };
+    match crate::front_matter::parse(&content) {
+        Ok(parsed) => parsed.front_matter.is_some() && parsed.body.trim().is_empty(),
+        Err(_) => false,
+    }
+}
+
 fn split_glob_pattern(pattern: &str) -> Option<(String, String)> {
     let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
 
@@ -1048,6 +1230,10 @@ fn dir_contains_filename_suffix(dir: &Path, suffix: &str) -> bool {
     for e in entries.flatten() {
         let path = e.path();
         if e.file_type().ok().is_some_and(|t| t.is_dir()) {
+            // The schema baseline copy holds templates, not artifact outputs.
+            if e.file_name() == SCHEMA_BASELINE_DIR {
+                continue;
+            }
             if dir_contains_filename_suffix(&path, suffix) {
                 return true;
             }
@@ -1061,4 +1247,46 @@ fn dir_contains_filename_suffix(dir: &Path, suffix: &str) -> bool {
     false
 }
 
+/// Files matching a `generates` pattern, relative to `change_dir` and sorted.
+///
+/// Uses the same minimal glob semantics as [`artifact_done`]; a literal
+/// pattern yields at most one file.
+pub(crate) fn artifact_output_files(change_dir: &Path, generates: &str) -> Vec<PathBuf> {
+    if !generates.contains('*') {
+        let rel = PathBuf::from(generates.strip_prefix("./").unwrap_or(generates));
+        return if change_dir.join(&rel).is_file() {
+            vec![rel]
+        } else {
+            Vec::new()
+        };
+    }
+    let Some((base, suffix)) = split_glob_pattern(generates) else {
+        return Vec::new();
+    };
+    let mut out = Vec::new();
+    collect_files_with_suffix(change_dir, &change_dir.join(base), &suffix, &mut out);
+    out.sort();
+    out
+}
+
+fn collect_files_with_suffix(root: &Path, dir: &Path, suffix: &str, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for e in entries.flatten() {
+        let path = e.path();
+        if e.file_type().ok().is_some_and(|t| t.is_dir()) {
+            if e.file_name() != SCHEMA_BASELINE_DIR {
+                collect_files_with_suffix(root, &path, suffix, out);
+            }
+            continue;
+        }
+        if e.file_name().to_string_lossy().ends_with(suffix)
+            && let Ok(rel) = path.strip_prefix(root)
+        {
+            out.push(rel.to_path_buf());
+        }
+    }
+}
+
 // (intentionally no checkbox counting helpers here; checkbox tasks are parsed into TaskItems)
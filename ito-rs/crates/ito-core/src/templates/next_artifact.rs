@@ -0,0 +1,113 @@
+//! "What should I do next?" for a change.
+//!
+//! Picks the first artifact that can be written right now (in schema build
+//! order) or reports that the change is ready to apply, complete, or blocked.
+
+use std::path::Path;
+
+use ito_config::ConfigContext;
+
+use super::{
+    BlockedArtifact, ChangeStatus, NextArtifact, NextStep, TemplatesError, compute_change_status,
+    resolve_instructions,
+};
+
+/// Select the next step for a change from its computed status.
+///
+/// Artifacts are considered in the order they appear in `status`, which is
+/// the schema build order, so the choice is deterministic. Optional artifacts
+/// are only suggested when apply depends on them.
+pub fn select_next_step(status: &ChangeStatus) -> NextStep {
+    let required_by_apply = |id: &str| status.apply_requires.iter().any(|r| r == id);
+
+    let ready = status.artifacts.iter().find(|artifact| {
+        artifact.status == "ready"
+            || (artifact.status == "optional"
+                && artifact.missing_deps.is_empty()
+                && required_by_apply(artifact.id.as_str()))
+    });
+    if let Some(artifact) = ready {
+        return NextStep::Ready {
+            artifact_id: artifact.id.clone(),
+        };
+    }
+
+    let apply_satisfied = status.apply_requires.iter().all(|id| {
+        status
+            .artifacts
+            .iter()
+            .any(|a| a.id == *id && (a.status == "done" || a.status == "skipped"))
+    });
+    if apply_satisfied {
+        if status.is_complete {
+            return NextStep::Complete;
+        }
+        return NextStep::ReadyToApply;
+    }
+
+    let blocked = status
+        .artifacts
+        .iter()
+        .filter(|artifact| {
+            artifact.status == "blocked"
+                || (artifact.status == "optional" && required_by_apply(artifact.id.as_str()))
+        })
+        .map(|artifact| BlockedArtifact {
+            id: artifact.id.clone(),
+            missing_deps: artifact.missing_deps.clone(),
+        })
+        .collect();
+    NextStep::Blocked { blocked }
+}
+
+/// Compute the next step for `change`, with instructions for the selected artifact.
+///
+/// # Errors
+///
+/// Returns the same errors as [`compute_change_status`] and
+/// [`resolve_instructions`] (invalid change name, missing change or schema,
+/// unreadable templates).
+pub fn next_artifact(
+    ito_path: &Path,
+    change: &str,
+    schema_name: Option<&str>,
+    ctx: &ConfigContext,
+) -> Result<NextArtifact, TemplatesError> {
+    let status = compute_change_status(ito_path, change, schema_name, ctx)?;
+    let step = select_next_step(&status);
+    let instructions = match &step {
+        NextStep::Ready { artifact_id } => Some(Box::new(resolve_instructions(
+            ito_path,
+            change,
+            schema_name,
+            artifact_id,
+            ctx,
+        )?)),
+        NextStep::ReadyToApply | NextStep::Complete | NextStep::Blocked { .. } => None,
+    };
+
+    Ok(NextArtifact {
+        change_name: status.change_name,
+        schema_name: status.schema_name,
+        step,
+        instructions,
+    })
+}
+
+/// One-line hint describing `step`, e.g. `Next: design (blocked by: proposal)`.
+pub fn next_step_hint(step: &NextStep) -> String {
+    match step {
+        NextStep::Ready { artifact_id } => format!("Next: {artifact_id}"),
+        NextStep::ReadyToApply => "Next: apply (remaining artifacts are blocked)".to_string(),
+        NextStep::Complete => "Next: apply".to_string(),
+        NextStep::Blocked { blocked } => match blocked.first() {
+            Some(artifact) if !artifact.missing_deps.is_empty() => format!(
+                "Next: {} (blocked by: {})",
+                artifact.id,
+                artifact.missing_deps.join(", ")
+            ),
+            Some(artifact) => format!("Next: {} (blocked)", artifact.id),
+            None => "Next: nothing is ready".to_string(),
+        },
+    }
+}
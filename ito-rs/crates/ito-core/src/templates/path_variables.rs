@@ -0,0 +1,143 @@
+//! Variables in schema output paths.
+//!
+//! `generates` and `apply.tracks` may reference `{change_id}`,
+//! `{change_slug}`, `{module_id}`, and `{schema}`, so outputs copied out of
+//! different changes do not collide (`reports/{change_id}-summary.md`). Paths
+//! are expanded per change before any glob matching, so a variable may sit
+//! inside a glob pattern.
+
+use ito_common::id::parse_change_id;
+
+use super::{ApplyTracksYaml, SchemaYaml, WorkflowError};
+
+/// Artifact label used in errors for variables in `apply.tracks`.
+const APPLY_TRACKS_LABEL: &str = "apply.tracks";
+
+/// Module reported for change ids without a parseable module prefix.
+const UNGROUPED_MODULE_ID: &str = "000";
+
+/// Variable values for one change.
+#[derive(Debug, Clone)]
+pub(crate) struct PathVariables {
+    change_id: String,
+    change_slug: String,
+    module_id: String,
+    schema: String,
+}
+
+impl PathVariables {
+    /// Values for `change_id` resolved against the schema named `schema`.
+    pub(crate) fn new(change_id: &str, schema: &str) -> Self {
+        let parsed = parse_change_id(change_id).ok();
+        Self {
+            change_id: change_id.to_string(),
+            change_slug: parsed
+                .as_ref()
+                .map_or_else(|| change_id.to_string(), |p| p.name.clone()),
+            module_id: parsed.map_or_else(
+                || UNGROUPED_MODULE_ID.to_string(),
+                |p| p.module_id.as_str().to_string(),
+            ),
+            schema: schema.to_string(),
+        }
+    }
+
+    fn value(&self, name: &str) -> Option<&str> {
+        match name {
+            "change_id" => Some(&self.change_id),
+            "change_slug" => Some(&self.change_slug),
+            "module_id" => Some(&self.module_id),
+            "schema" => Some(&self.schema),
+            _ => None,
+        }
+    }
+
+    /// Expand the variables in `path`, an output path of `artifact`.
+    pub(crate) fn expand(&self, artifact: &str, path: &str) -> Result<String, WorkflowError> {
+        substitute(artifact, path, |name| self.value(name))
+    }
+
+    /// Expand the variables in an `apply.tracks` file.
+    pub(crate) fn expand_tracking_file(&self, path: &str) -> Result<String, WorkflowError> {
+        self.expand(APPLY_TRACKS_LABEL, path)
+    }
+}
+
+/// Copy of `schema` with `generates` and `apply.tracks` expanded for a change.
+pub(crate) fn expand_change_paths(
+    schema: &SchemaYaml,
+    change_id: &str,
+) -> Result<SchemaYaml, WorkflowError> {
+    let vars = PathVariables::new(change_id, &schema.name);
+    let mut expanded = schema.clone();
+    for artifact in &mut expanded.artifacts {
+        artifact.generates = vars.expand(&artifact.id, &artifact.generates)?;
+    }
+    if let Some(tracks) = expanded
+        .apply
+        .as_mut()
+        .and_then(|apply| apply.tracks.as_mut())
+    {
+        match tracks {
+            ApplyTracksYaml::One(file) => *file = vars.expand_tracking_file(file)?,
+            ApplyTracksYaml::Many(files) => {
+                for file in files {
+                    *file = vars.expand_tracking_file(file)?;
+                }
+            }
+        }
+    }
+    Ok(expanded)
+}
+
+/// Reject unknown variables in a schema's output paths without a change.
+pub(crate) fn check_path_variables(schema: &SchemaYaml) -> Result<(), WorkflowError> {
+    let known = PathVariables::new("", &schema.name);
+    let check = |artifact: &str, path: &str| {
+        substitute(artifact, path, |name| known.value(name).map(|_| "")).map(|_| ())
+    };
+    for artifact in &schema.artifacts {
+        check(&artifact.id, &artifact.generates)?;
+    }
+    for file in schema
+        .apply
+        .as_ref()
+        .map_or(&[][..], |apply| apply.tracking_files())
+    {
+        check(APPLY_TRACKS_LABEL, file)?;
+    }
+    Ok(())
+}
+
+/// Replace every `{name}` in `path` with `lookup(name)`.
+///
+/// Braces that do not enclose a plain identifier are kept verbatim.
+fn substitute<'a>(
+    artifact: &str,
+    path: &str,
+    lookup: impl Fn(&str) -> Option<&'a str>,
+) -> Result<String, WorkflowError> {
+    let mut out = String::with_capacity(path.len());
+    let mut rest = path;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let tail = &rest[start + 1..];
+        let name_len = tail
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(tail.len());
+        if name_len == 0 || !tail[name_len..].starts_with('}') {
+            out.push('{');
+            rest = tail;
+            continue;
+        }
+        let name = &tail[..name_len];
+        let value = lookup(name).ok_or_else(|| WorkflowError::UnknownPathVariable {
+            artifact: artifact.to_string(),
+            variable: name.to_string(),
+        })?;
+        out.push_str(value);
+        rest = &tail[name_len + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
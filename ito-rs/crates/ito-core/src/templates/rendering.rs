@@ -0,0 +1,73 @@
+//! Rendering artifact templates with change context pre-filled.
+
+use super::{InstructionsResponse, TemplatesError, load_user_guidance, resolve_instructions};
+use ito_common::id::parse_change_id;
+use ito_config::ConfigContext;
+use serde::Serialize;
+use std::path::Path;
+
+/// Variables available to artifact templates when rendering is requested.
+#[derive(Debug, Serialize)]
+struct ArtifactTemplateContext<'a> {
+    change_name: &'a str,
+    change_dir: &'a str,
+    module_id: Option<String>,
+    schema_name: &'a str,
+    artifact_id: &'a str,
+    output_path: &'a str,
+    completed_dependencies: Vec<String>,
+    user_guidance: Option<String>,
+}
+
+/// Like [`resolve_instructions`], but renders the artifact template with the change context.
+///
+/// The template is rendered through minijinja with `change_name`, `change_dir`,
+/// `module_id`, `schema_name`, `artifact_id`, `output_path`,
+/// `completed_dependencies` (paths of dependency artifacts that already exist)
+/// and `user_guidance` in scope.
+///
+/// Templates are not required to be valid Jinja: many contain literal `{{ ... }}`
+/// placeholders meant for the agent. When rendering fails the raw template is
+/// kept and `render_warning` explains why, instead of returning an error.
+pub fn resolve_rendered_instructions(
+    ito_path: &Path,
+    change: &str,
+    schema_name: Option<&str>,
+    artifact_id: &str,
+    ctx: &ConfigContext,
+) -> Result<InstructionsResponse, TemplatesError> {
+    let mut response = resolve_instructions(ito_path, change, schema_name, artifact_id, ctx)?;
+    let user_guidance = load_user_guidance(ito_path)?;
+
+    let change_dir = Path::new(&response.change_dir);
+    let completed_dependencies = response
+        .dependencies
+        .iter()
+        .filter(|dep| dep.done)
+        .map(|dep| change_dir.join(&dep.path).to_string_lossy().to_string())
+        .collect();
+    let context = ArtifactTemplateContext {
+        change_name: &response.change_name,
+        change_dir: &response.change_dir,
+        module_id: parse_change_id(change)
+            .ok()
+            .map(|parsed| parsed.module_id.to_string()),
+        schema_name: &response.schema_name,
+        artifact_id: &response.artifact_id,
+        output_path: &response.output_path,
+        completed_dependencies,
+        user_guidance,
+    };
+
+    match ito_templates::instructions::render_template_str(&response.template, &context) {
+        Ok(rendered) => response.template = rendered,
+        Err(e) => {
+            response.render_warning = Some(format!(
+                "Template for artifact '{}' is not renderable ({e}); returning raw template text",
+                response.artifact_id
+            ));
+        }
+    }
+
+    Ok(response)
+}
@@ -1,8 +1,8 @@
+use super::schema_structure::check_schema_structure;
 use super::{ResolvedSchema, SchemaSource, SchemaYaml, ValidationYaml, WorkflowError};
 use ito_config::ConfigContext;
 use ito_templates::{get_schema_file, schema_files};
 use std::collections::BTreeSet;
-use std::env;
 use std::fs;
 use std::path::{Component, Path, PathBuf};
 
@@ -70,11 +70,10 @@ pub(super) fn project_schemas_dir(ctx: &ConfigContext) -> Option<PathBuf> {
     )
 }
 
-/// Resolves the per-user schemas directory using XDG conventions.
+/// Resolves the per-user schemas directory: `schemas` under
+/// [`ito_config::ito_data_dir`] (`$XDG_DATA_HOME/ito`, or
+/// `~/.local/share/ito` when `XDG_DATA_HOME` is unset or empty).
 ///
-/// If the `XDG_DATA_HOME` environment variable is set and not empty, its value is used;
-/// otherwise the function falls back to `ctx.home_dir` joined with `.local/share`.
-/// When a data home can be determined, the function returns that path with `ito/schemas` appended.
 /// Returns `None` if neither `XDG_DATA_HOME` nor `ctx.home_dir` are available.
 ///
 /// # Examples
@@ -87,14 +86,7 @@ pub(super) fn project_schemas_dir(ctx: &ConfigContext) -> Option<PathBuf> {
 /// assert!(dir.ends_with("ito/schemas"));
 /// ```
 pub(super) fn user_schemas_dir(ctx: &ConfigContext) -> Option<PathBuf> {
-    let data_home = match env::var("XDG_DATA_HOME") {
-        Ok(v) if !v.trim().is_empty() => Some(PathBuf::from(v)),
-        _ => ctx
-            .home_dir
-            .as_ref()
-            .map(|h| h.join(".local").join("share")),
-    }?;
-    Some(data_home.join("ito").join("schemas"))
+    Some(ito_config::ito_data_dir(ctx)?.join("schemas"))
 }
 
 /// Lists top-level embedded schema names included in the binary.
@@ -134,8 +126,8 @@ pub(super) fn embedded_schema_names() -> Vec<String> {
 /// `SchemaYaml`.
 ///
 /// Returns `Ok(Some(schema))` when the file exists and parses successfully, `Ok(None)` when the
-/// embedded file is not present, and `Err(WorkflowError)` if the embedded bytes are not valid UTF-8
-/// or if YAML deserialization (or other I/O) fails.
+/// embedded file is not present, and `Err(WorkflowError)` if the embedded bytes are not valid UTF-8,
+/// if YAML deserialization (or other I/O) fails, or if the schema fails its structural checks.
 ///
 /// # Examples
 ///
@@ -157,7 +149,8 @@ pub(super) fn load_embedded_schema_yaml(name: &str) -> Result<Option<SchemaYaml>
             format!("embedded schema is not utf-8 ({path}): {e}"),
         )
     })?;
-    let schema = serde_yaml::from_str(s)?;
+    let schema: SchemaYaml = serde_yaml::from_str(s)?;
+    check_schema_structure(&schema)?;
     Ok(Some(schema))
 }
 
@@ -182,15 +175,23 @@ pub(super) fn load_embedded_validation_yaml(
     Ok(Some(parsed))
 }
 
+/// Maximum nesting depth for template include directives.
+const MAX_INCLUDE_DEPTH: usize = 8;
+
 /// Load a schema template string for a resolved schema.
 ///
 /// Loads the template from the embedded asset bundle at `{schema}/templates/{template}` when
 /// the resolved schema's source is `SchemaSource::Embedded`; otherwise reads
 /// `<schema_dir>/templates/<template>` from the filesystem.
 ///
-/// Returns the template contents as a `String`. Returns a `WorkflowError` if the embedded
-/// template is missing, the embedded bytes are not valid UTF-8, or a filesystem I/O error
-/// occurs when reading a non-embedded template.
+/// A line consisting only of `<!-- include: partials/preamble.md -->` is replaced by the
+/// named file, resolved relative to the schema directory (or the embedded schema tree).
+/// Partials may include further partials up to a small depth limit.
+///
+/// Returns the expanded template contents as a `String`. Returns a `WorkflowError` if the
+/// embedded template is missing, the embedded bytes are not valid UTF-8, a filesystem I/O
+/// error occurs when reading a non-embedded template, or an include is unsafe, missing,
+/// cyclic, or nested too deeply.
 ///
 /// # Examples
 ///
@@ -201,6 +202,15 @@ pub(super) fn load_embedded_validation_yaml(
 pub(super) fn read_schema_template(
     resolved: &ResolvedSchema,
     template: &str,
+) -> Result<String, WorkflowError> {
+    let raw = read_raw_schema_template(resolved, template)?;
+    let mut chain = vec![format!("templates/{template}")];
+    expand_includes(resolved, &raw, &mut chain)
+}
+
+fn read_raw_schema_template(
+    resolved: &ResolvedSchema,
+    template: &str,
 ) -> Result<String, WorkflowError> {
     if !is_safe_relative_path(template) {
         return Err(WorkflowError::Io(std::io::Error::new(
@@ -230,6 +240,93 @@ pub(super) fn read_schema_template(
     ito_common::io::read_to_string_std(&path).map_err(WorkflowError::from)
 }
 
+/// Expand include directives in `text`.
+///
+/// `chain` holds the schema-relative paths of the files currently being
+/// expanded, outermost first; its last entry is the file `text` came from.
+fn expand_includes(
+    resolved: &ResolvedSchema,
+    text: &str,
+    chain: &mut Vec<String>,
+) -> Result<String, WorkflowError> {
+    let mut out = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let Some(partial) = include_directive(line) else {
+            out.push_str(line);
+            continue;
+        };
+        let including = chain.last().cloned().unwrap_or_default();
+        if !is_safe_relative_path(partial) {
+            return Err(WorkflowError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("invalid include path in {including}: {partial}"),
+            )));
+        }
+        if chain.iter().any(|p| p == partial) {
+            let cycle = format!("{} -> {partial}", chain.join(" -> "));
+            return Err(WorkflowError::IncludeCycle(cycle));
+        }
+        if chain.len() > MAX_INCLUDE_DEPTH {
+            return Err(WorkflowError::IncludeDepthExceeded(
+                chain[0].clone(),
+                MAX_INCLUDE_DEPTH,
+            ));
+        }
+        let Some(body) = read_schema_file(resolved, partial)? else {
+            return Err(WorkflowError::PartialNotFound {
+                template: including,
+                partial: partial.to_string(),
+            });
+        };
+
+        chain.push(partial.to_string());
+        let expanded = expand_includes(resolved, &body, chain)?;
+        chain.pop();
+
+        out.push_str(&expanded);
+        if line.ends_with('\n') && !expanded.ends_with('\n') {
+            out.push('\n');
+        }
+    }
+    Ok(out)
+}
+
+/// Return the partial path named by an `<!-- include: path -->` line.
+fn include_directive(line: &str) -> Option<&str> {
+    let inner = line.trim().strip_prefix("<!--")?.strip_suffix("-->")?;
+    let path = inner.trim().strip_prefix("include:")?.trim();
+    Some(path)
+}
+
+/// Read a raw schema file (for example `schema.yaml`) for a resolved schema.
+///
+/// Returns `Ok(None)` when the file does not exist in the schema's source.
+pub(super) fn read_schema_file(
+    resolved: &ResolvedSchema,
+    relative: &str,
+) -> Result<Option<String>, WorkflowError> {
+    if resolved.source == SchemaSource::Embedded {
+        let path = format!("{}/{relative}", resolved.schema.name);
+        let Some(bytes) = get_schema_file(&path) else {
+            return Ok(None);
+        };
+        let text = std::str::from_utf8(bytes).map_err(|e| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("embedded schema file is not utf-8 ({path}): {e}"),
+            )
+        })?;
+        return Ok(Some(text.to_string()));
+    }
+
+    let path = resolved.schema_dir.join(relative);
+    match ito_common::io::read_to_string_std(&path) {
+        Ok(text) => Ok(Some(text)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(WorkflowError::from(e)),
+    }
+}
+
 pub(super) fn is_safe_relative_path(path: &str) -> bool {
     if path.is_empty() {
         return false;
@@ -274,7 +371,9 @@ pub struct ExportSchemasResult {
 
 /// Export all embedded schema files into a target directory.
 ///
-/// Existing destination files are not overwritten unless `force` is `true`.
+/// Files are copied verbatim, so template partials stay separate files and
+/// include directives are left unexpanded. Existing destination files are not
+/// overwritten unless `force` is `true`.
 pub fn export_embedded_schemas(
     to_dir: &Path,
     force: bool,
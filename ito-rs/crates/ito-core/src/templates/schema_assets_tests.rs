@@ -1,4 +1,4 @@
-use super::{is_safe_relative_path, is_safe_schema_name};
+use super::{include_directive, is_safe_relative_path, is_safe_schema_name};
 
 #[test]
 fn safe_relative_path_validation_blocks_traversal_and_absolute_paths() {
@@ -20,3 +20,21 @@ fn safe_schema_name_rejects_dot_segments_and_periods() {
     assert!(!is_safe_schema_name("spec.driven"));
     assert!(!is_safe_schema_name(""));
 }
+
+#[test]
+fn include_directive_matches_whole_comment_lines_only() {
+    assert_eq!(
+        include_directive("<!-- include: partials/preamble.md -->\n"),
+        Some("partials/preamble.md")
+    );
+    assert_eq!(
+        include_directive("  <!--include:partials/a.md-->"),
+        Some("partials/a.md")
+    );
+
+    assert_eq!(include_directive("<!-- ITO:START -->"), None);
+    assert_eq!(
+        include_directive("See <!-- include: partials/a.md --> here"),
+        None
+    );
+}
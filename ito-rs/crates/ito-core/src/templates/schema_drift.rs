@@ -0,0 +1,463 @@
+//! Schema baselines and drift detection for in-flight changes.
+//!
+//! When a change is created (or re-baselined) Ito stores a copy of the schema
+//! it was planned against under `<change>/.schema-baseline/` and records the
+//! schema's content hash as `schema_hash` in the change's `.ito.yaml`.
+//! Instruction generation compares that hash against the schema as currently
+//! resolved and reports a [`SchemaDrift`] when they differ. Setting
+//! `schema_pinned: true` makes the change resolve its schema from the stored
+//! copy instead.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use ito_common::fs::StdFs;
+use ito_common::paths;
+use ito_config::ConfigContext;
+use sha2::{Digest, Sha256};
+
+use super::schema_assets::{read_schema_file, read_schema_template};
+use super::{
+    ArtifactStatusChange, ResolvedSchema, SchemaDrift, SchemaDriftSeverity, SchemaRebaselineResult,
+    SchemaSource, SchemaYaml, WorkflowError, artifact_done, artifact_statuses, load_schema_yaml,
+    read_change_schema, resolve_schema, validate_change_name_input,
+};
+use crate::change_meta::{ChangeMeta, read_change_meta_from_dir, set_change_meta_field};
+
+/// Directory (relative to the change directory) holding the baseline schema copy.
+pub const SCHEMA_BASELINE_DIR: &str = ".schema-baseline";
+
+/// Raw schema contents used for hashing and snapshotting.
+struct SchemaContents {
+    schema_yaml: String,
+    validation_yaml: Option<String>,
+    /// Template file name to contents, with includes expanded.
+    templates: BTreeMap<String, String>,
+}
+
+impl SchemaContents {
+    fn load(resolved: &ResolvedSchema) -> Result<Self, WorkflowError> {
+        let schema_yaml = read_schema_file(resolved, "schema.yaml")?
+            .ok_or_else(|| WorkflowError::SchemaNotFound(resolved.schema.name.clone()))?;
+        let validation_yaml = read_schema_file(resolved, "validation.yaml")?;
+        let mut templates = BTreeMap::new();
+        for artifact in &resolved.schema.artifacts {
+            if templates.contains_key(&artifact.template) {
+                continue;
+            }
+            let text = read_schema_template(resolved, &artifact.template)?;
+            templates.insert(artifact.template.clone(), text);
+        }
+        Ok(Self {
+            schema_yaml,
+            validation_yaml,
+            templates,
+        })
+    }
+
+    fn hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(b"schema.yaml\0");
+        hasher.update(self.schema_yaml.as_bytes());
+        hasher.update(b"\0");
+        for (name, text) in &self.templates {
+            hasher.update(b"templates/");
+            hasher.update(name.as_bytes());
+            hasher.update(b"\0");
+            hasher.update(text.as_bytes());
+            hasher.update(b"\0");
+        }
+        format!("{:x}", hasher.finalize())
+    }
+
+    fn write_snapshot(&self, dir: &Path) -> Result<(), WorkflowError> {
+        if dir.exists() {
+            std::fs::remove_dir_all(dir)?;
+        }
+        ito_common::io::create_dir_all_std(&dir.join("templates"))?;
+        ito_common::io::write_std(&dir.join("schema.yaml"), &self.schema_yaml)?;
+        if let Some(validation) = &self.validation_yaml {
+            ito_common::io::write_std(&dir.join("validation.yaml"), validation)?;
+        }
+        for (name, text) in &self.templates {
+            let path = dir.join("templates").join(name);
+            if let Some(parent) = path.parent() {
+                ito_common::io::create_dir_all_std(parent)?;
+            }
+            ito_common::io::write_std(&path, text)?;
+        }
+        Ok(())
+    }
+}
+
+/// Load the schema copy stored in the change directory, if any.
+fn load_baseline_schema(change_dir: &Path) -> Option<ResolvedSchema> {
+    let dir = change_dir.join(SCHEMA_BASELINE_DIR);
+    let schema = load_schema_yaml(&dir).ok()?;
+    Some(ResolvedSchema {
+        schema,
+        schema_dir: dir,
+        source: SchemaSource::Pinned,
+    })
+}
+
+/// Resolve the effective schema for a change.
+///
+/// Pinned changes resolve to the schema copy stored in the change directory
+/// unless an explicit, different `schema_name` is requested.
+pub(super) fn resolve_change_schema(
+    ito_path: &Path,
+    change: &str,
+    schema_name: Option<&str>,
+    ctx: &ConfigContext,
+) -> Result<ResolvedSchema, WorkflowError> {
+    let change_schema = read_change_schema(ito_path, change);
+    let name = schema_name.unwrap_or(&change_schema);
+    if name == change_schema {
+        let change_dir = paths::change_dir(ito_path, change);
+        let meta = read_change_meta_from_dir(&StdFs, &change_dir);
+        if meta.schema_pinned
+            && let Some(pinned) = load_baseline_schema(&change_dir)
+        {
+            return Ok(pinned);
+        }
+    }
+    resolve_schema(Some(name), ctx)
+}
+
+/// Resolve the effective schema for the change stored in `change_dir`.
+///
+/// Unlike [`resolve_change_schema`] this works for any change directory,
+/// including archived ones, and always uses the schema the change records.
+pub(crate) fn resolve_change_dir_schema(
+    change_dir: &Path,
+    ctx: &ConfigContext,
+) -> Result<ResolvedSchema, WorkflowError> {
+    let meta = read_change_meta_from_dir(&StdFs, change_dir);
+    if meta.schema_pinned
+        && let Some(pinned) = load_baseline_schema(change_dir)
+    {
+        return Ok(pinned);
+    }
+    resolve_schema(meta.schema.as_deref(), ctx)
+}
+
+/// Compare a change's recorded schema baseline against the resolved schema.
+///
+/// Returns `None` when the change has no recorded hash, is pinned, was
+/// resolved against a different schema, the current schema files cannot be
+/// read, or the hashes match.
+pub(super) fn detect_schema_drift(
+    ito_path: &Path,
+    change: &str,
+    resolved: &ResolvedSchema,
+) -> Option<SchemaDrift> {
+    if resolved.source == SchemaSource::Pinned
+        || resolved.schema.name != read_change_schema(ito_path, change)
+    {
+        return None;
+    }
+    let change_dir = paths::change_dir(ito_path, change);
+    let meta = read_change_meta_from_dir(&StdFs, &change_dir);
+    detect_drift(&change_dir, &meta, resolved)
+}
+
+fn detect_drift(
+    change_dir: &Path,
+    meta: &ChangeMeta,
+    current: &ResolvedSchema,
+) -> Option<SchemaDrift> {
+    let recorded_hash = meta.schema_hash.as_deref()?;
+    let current_contents = SchemaContents::load(current).ok()?;
+    let current_hash = current_contents.hash();
+    if current_hash == recorded_hash {
+        return None;
+    }
+
+    let mut drift = SchemaDrift {
+        schema_name: current.schema.name.clone(),
+        recorded_hash: recorded_hash.to_string(),
+        current_hash,
+        severity: SchemaDriftSeverity::Low,
+        baseline_available: false,
+        artifacts_added: Vec::new(),
+        artifacts_removed: Vec::new(),
+        artifacts_changed: Vec::new(),
+        templates_changed: Vec::new(),
+        orphaned_outputs: Vec::new(),
+    };
+    let Some(baseline) = load_baseline_schema(change_dir) else {
+        return Some(drift);
+    };
+    let Ok(baseline_contents) = SchemaContents::load(&baseline) else {
+        return Some(drift);
+    };
+
+    drift.baseline_available = true;
+    diff_schemas(
+        &mut drift,
+        change_dir,
+        (&baseline.schema, &baseline_contents),
+        (&current.schema, &current_contents),
+    );
+    Some(drift)
+}
+
+fn diff_schemas(
+    drift: &mut SchemaDrift,
+    change_dir: &Path,
+    (old, old_contents): (&SchemaYaml, &SchemaContents),
+    (new, new_contents): (&SchemaYaml, &SchemaContents),
+) {
+    for artifact in &new.artifacts {
+        let Some(previous) = old.artifacts.iter().find(|a| a.id == artifact.id) else {
+            drift.artifacts_added.push(artifact.id.clone());
+            continue;
+        };
+        let old_requires: BTreeSet<&String> = previous.requires.iter().collect();
+        let new_requires: BTreeSet<&String> = artifact.requires.iter().collect();
+        if previous.generates != artifact.generates
+            || previous.optional != artifact.optional
+            || old_requires != new_requires
+        {
+            drift.artifacts_changed.push(artifact.id.clone());
+        }
+        if old_contents.templates.get(&previous.template)
+            != new_contents.templates.get(&artifact.template)
+        {
+            drift.templates_changed.push(artifact.id.clone());
+        }
+    }
+
+    for artifact in &old.artifacts {
+        if new.artifacts.iter().any(|a| a.id == artifact.id) {
+            continue;
+        }
+        drift.artifacts_removed.push(artifact.id.clone());
+        if artifact_done(change_dir, &artifact.generates) {
+            drift.orphaned_outputs.push(artifact.generates.clone());
+        }
+    }
+
+    if !drift.artifacts_added.is_empty()
+        || !drift.artifacts_removed.is_empty()
+        || !drift.artifacts_changed.is_empty()
+    {
+        drift.severity = SchemaDriftSeverity::High;
+    }
+}
+
+/// Record the change's current schema as its new baseline.
+///
+/// Stores a copy of the resolved schema in the change directory, writes
+/// `schema_hash` to `.ito.yaml`, clears any pin, and returns the artifacts
+/// whose status differs between the old baseline and the current schema.
+pub fn rebaseline_change_schema(
+    ito_path: &Path,
+    change: &str,
+    ctx: &ConfigContext,
+) -> Result<SchemaRebaselineResult, WorkflowError> {
+    let change_dir = existing_change_dir(ito_path, change)?;
+    let meta = read_change_meta_from_dir(&StdFs, &change_dir);
+    let schema_name = read_change_schema(ito_path, change);
+    let current = resolve_schema(Some(&schema_name), ctx)?;
+    let contents = SchemaContents::load(&current)?;
+
+    let status_changes = match load_baseline_schema(&change_dir) {
+        Some(baseline) => status_changes(&change_dir, &baseline.schema, &current.schema),
+        None => Vec::new(),
+    };
+
+    let schema_hash = contents.hash();
+    contents.write_snapshot(&change_dir.join(SCHEMA_BASELINE_DIR))?;
+    let quoted_hash = format!("\"{schema_hash}\"");
+    update_meta(
+        ito_path,
+        change,
+        &[
+            ("schema_hash", Some(quoted_hash.as_str())),
+            ("schema_pinned", None),
+        ],
+    )?;
+
+    Ok(SchemaRebaselineResult {
+        change_name: change.to_string(),
+        schema_name: current.schema.name,
+        previous_hash: meta.schema_hash,
+        schema_hash,
+        pinned: false,
+        status_changes,
+    })
+}
+
+/// Pin the change to the schema copy stored in its directory.
+///
+/// Returns the artifacts whose status differs between the current schema and
+/// the pinned copy.
+///
+/// # Errors
+///
+/// Returns [`WorkflowError::SchemaBaselineMissing`] when the change has no
+/// stored schema copy.
+pub fn pin_change_schema(
+    ito_path: &Path,
+    change: &str,
+    ctx: &ConfigContext,
+) -> Result<SchemaRebaselineResult, WorkflowError> {
+    let change_dir = existing_change_dir(ito_path, change)?;
+    let meta = read_change_meta_from_dir(&StdFs, &change_dir);
+    let Some(baseline) = load_baseline_schema(&change_dir) else {
+        return Err(WorkflowError::SchemaBaselineMissing(change.to_string()));
+    };
+    let contents = SchemaContents::load(&baseline)?;
+    let schema_hash = contents.hash();
+
+    let schema_name = read_change_schema(ito_path, change);
+    let status_changes = match resolve_schema(Some(&schema_name), ctx) {
+        Ok(current) => status_changes(&change_dir, &current.schema, &baseline.schema),
+        Err(_) => Vec::new(),
+    };
+
+    let quoted_hash = format!("\"{schema_hash}\"");
+    update_meta(
+        ito_path,
+        change,
+        &[
+            ("schema_hash", Some(quoted_hash.as_str())),
+            ("schema_pinned", Some("true")),
+        ],
+    )?;
+
+    Ok(SchemaRebaselineResult {
+        change_name: change.to_string(),
+        schema_name: baseline.schema.name,
+        previous_hash: meta.schema_hash,
+        schema_hash,
+        pinned: true,
+        status_changes,
+    })
+}
+
+/// Record the baseline for a freshly created change.
+pub(crate) fn record_schema_baseline(
+    ito_path: &Path,
+    change: &str,
+    ctx: &ConfigContext,
+) -> Result<(), WorkflowError> {
+    rebaseline_change_schema(ito_path, change, ctx).map(|_| ())
+}
+
+pub(super) fn existing_change_dir(ito_path: &Path, change: &str) -> Result<PathBuf, WorkflowError> {
+    if !validate_change_name_input(change) {
+        return Err(WorkflowError::InvalidChangeName);
+    }
+    let change_dir = paths::change_dir(ito_path, change);
+    if !change_dir.exists() {
+        return Err(WorkflowError::ChangeNotFound(change.to_string()));
+    }
+    Ok(change_dir)
+}
+
+fn update_meta(
+    ito_path: &Path,
+    change: &str,
+    fields: &[(&str, Option<&str>)],
+) -> Result<(), WorkflowError> {
+    let path = paths::change_meta_path(ito_path, change);
+    let mut contents = match ito_common::io::read_to_string_std(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    for (key, value) in fields {
+        contents = set_change_meta_field(&contents, key, *value);
+    }
+    ito_common::io::write_std(&path, contents)?;
+    Ok(())
+}
+
+fn status_changes(
+    change_dir: &Path,
+    before: &SchemaYaml,
+    after: &SchemaYaml,
+) -> Vec<ArtifactStatusChange> {
+    let before: Vec<(String, String)> = artifact_statuses(change_dir, before)
+        .into_iter()
+        .map(|a| (a.id, a.status))
+        .collect();
+    let after: Vec<(String, String)> = artifact_statuses(change_dir, after)
+        .into_iter()
+        .map(|a| (a.id, a.status))
+        .collect();
+
+    let mut ids: Vec<&String> = before.iter().map(|(id, _)| id).collect();
+    for (id, _) in &after {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+
+    fn lookup(list: &[(String, String)], id: &str) -> Option<String> {
+        list.iter()
+            .find(|(other, _)| other == id)
+            .map(|(_, status)| status.clone())
+    }
+    ids.into_iter()
+        .filter_map(|id| {
+            let before = lookup(&before, id);
+            let after = lookup(&after, id);
+            (before != after).then(|| ArtifactStatusChange {
+                id: id.clone(),
+                before,
+                after,
+            })
+        })
+        .collect()
+}
+
+/// One-line summary of a drift, suitable for CLI warnings.
+pub fn describe_schema_drift(drift: &SchemaDrift) -> String {
+    let mut parts = Vec::new();
+    if !drift.artifacts_added.is_empty() {
+        parts.push(format!(
+            "added artifacts: {}",
+            drift.artifacts_added.join(", ")
+        ));
+    }
+    if !drift.artifacts_removed.is_empty() {
+        parts.push(format!(
+            "removed artifacts: {}",
+            drift.artifacts_removed.join(", ")
+        ));
+    }
+    if !drift.artifacts_changed.is_empty() {
+        parts.push(format!(
+            "changed artifacts: {}",
+            drift.artifacts_changed.join(", ")
+        ));
+    }
+    if !drift.templates_changed.is_empty() {
+        parts.push(format!(
+            "changed templates: {}",
+            drift.templates_changed.join(", ")
+        ));
+    }
+    if !drift.orphaned_outputs.is_empty() {
+        parts.push(format!(
+            "files from removed artifacts: {}",
+            drift.orphaned_outputs.join(", ")
+        ));
+    }
+    if parts.is_empty() {
+        if drift.baseline_available {
+            parts.push("schema definition changed".to_string());
+        } else {
+            parts.push("baseline copy unavailable".to_string());
+        }
+    }
+    format!(
+        "Schema '{}' changed since this change was baselined ({})",
+        drift.schema_name,
+        parts.join("; ")
+    )
+}
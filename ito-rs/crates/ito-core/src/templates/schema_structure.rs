@@ -0,0 +1,78 @@
+//! Structural checks for `schema.yaml`, run whenever a schema is loaded.
+//!
+//! A typo in `requires` used to surface much later as an artifact that stays
+//! "blocked" on a dependency that does not exist. Rejecting the schema at
+//! load time, with every problem listed and the nearest artifact id
+//! suggested, means status and instruction code only ever see consistent
+//! schemas.
+
+use std::collections::BTreeSet;
+
+use super::{SchemaYaml, WorkflowError};
+
+/// Reject `schema` if its artifact graph refers to itself or to unknown ids.
+pub(super) fn check_schema_structure(schema: &SchemaYaml) -> Result<(), WorkflowError> {
+    let problems = schema_problems(schema);
+    if problems.is_empty() {
+        return Ok(());
+    }
+    Err(WorkflowError::SchemaInvalid {
+        schema: schema.name.clone(),
+        problems,
+    })
+}
+
+/// Every structural problem in `schema`, in artifact order.
+fn schema_problems(schema: &SchemaYaml) -> Vec<String> {
+    let ids: Vec<String> = schema.artifacts.iter().map(|a| a.id.clone()).collect();
+    let mut problems = Vec::new();
+
+    let mut seen = BTreeSet::new();
+    let mut reported = BTreeSet::new();
+    for id in &ids {
+        if !seen.insert(id.as_str()) && reported.insert(id.as_str()) {
+            problems.push(format!("duplicate artifact id '{id}'"));
+        }
+    }
+
+    for artifact in &schema.artifacts {
+        for required in &artifact.requires {
+            if *required == artifact.id {
+                problems.push(format!("artifact '{}' requires itself", artifact.id));
+            } else if !seen.contains(required.as_str()) {
+                problems.push(format!(
+                    "artifact '{}' requires unknown artifact '{required}'{}",
+                    artifact.id,
+                    suggestion(required, &ids)
+                ));
+            }
+        }
+    }
+
+    let apply_requires = schema
+        .apply
+        .as_ref()
+        .and_then(|apply| apply.requires.as_deref())
+        .unwrap_or_default();
+    for required in apply_requires {
+        if !seen.contains(required.as_str()) {
+            problems.push(format!(
+                "apply.requires names unknown artifact '{required}'{}",
+                suggestion(required, &ids)
+            ));
+        }
+    }
+
+    problems
+}
+
+fn suggestion(unknown: &str, ids: &[String]) -> String {
+    match crate::nearest_matches(unknown, ids, 1).first() {
+        Some(nearest) => format!(" (did you mean '{nearest}'?)"),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+#[path = "schema_structure_tests.rs"]
+mod schema_structure_tests;
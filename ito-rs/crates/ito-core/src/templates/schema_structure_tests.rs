@@ -0,0 +1,161 @@
+use super::*;
+use crate::templates::{embedded_schema_names, load_embedded_schema_yaml, load_schema_yaml};
+
+fn parse(yaml: &str) -> SchemaYaml {
+    serde_yaml::from_str(yaml).expect("schema yaml")
+}
+
+fn problems_of(yaml: &str) -> Vec<String> {
+    match check_schema_structure(&parse(yaml)) {
+        Err(WorkflowError::SchemaInvalid { schema, problems }) => {
+            assert_eq!(schema, "demo");
+            problems
+        }
+        other => panic!("expected SchemaInvalid, got {other:?}"),
+    }
+}
+
+const VALID: &str = "name: demo
+version: 1
+artifacts:
+  - id: proposal
+    generates: proposal.md
+    template: proposal.md
+  - id: design
+    generates: design.md
+    template: design.md
+    requires: [proposal]
+  - id: tasks
+    generates: tasks.md
+    template: tasks.md
+    requires: [proposal, design]
+apply:
+  requires: [tasks]
+  tracks: tasks.md
+";
+
+#[test]
+fn unknown_requires_suggests_the_nearest_artifact() {
+    let yaml = VALID.replace(
+        "requires: [proposal, design]",
+        "requires: [proposal, desing]",
+    );
+    assert_eq!(
+        problems_of(&yaml),
+        vec!["artifact 'tasks' requires unknown artifact 'desing' (did you mean 'design'?)"]
+    );
+}
+
+#[test]
+fn duplicate_artifact_ids_are_reported_once() {
+    let yaml = "name: demo
+artifacts:
+  - id: design
+    generates: design.md
+    template: design.md
+  - id: design
+    generates: other.md
+    template: design.md
+  - id: design
+    generates: third.md
+    template: design.md
+";
+    assert_eq!(problems_of(yaml), vec!["duplicate artifact id 'design'"]);
+}
+
+#[test]
+fn artifact_requiring_itself_is_reported() {
+    let yaml = VALID.replace("requires: [proposal]", "requires: [proposal, design]");
+    assert_eq!(
+        problems_of(&yaml),
+        vec!["artifact 'design' requires itself"]
+    );
+}
+
+#[test]
+fn unknown_apply_requires_is_reported() {
+    let yaml = VALID.replace("requires: [tasks]", "requires: [taks]");
+    assert_eq!(
+        problems_of(&yaml),
+        vec!["apply.requires names unknown artifact 'taks' (did you mean 'tasks'?)"]
+    );
+}
+
+#[test]
+fn every_problem_is_reported_together() {
+    let yaml = VALID
+        .replace("requires: [proposal]", "requires: [design]")
+        .replace("requires: [proposal, design]", "requires: [propsal]")
+        .replace("requires: [tasks]", "requires: [tasks, desgn]");
+    let problems = problems_of(&yaml);
+    assert_eq!(
+        problems,
+        vec![
+            "artifact 'design' requires itself".to_string(),
+            "artifact 'tasks' requires unknown artifact 'propsal' (did you mean 'proposal'?)"
+                .to_string(),
+            "apply.requires names unknown artifact 'desgn' (did you mean 'design'?)".to_string(),
+        ]
+    );
+
+    let err = check_schema_structure(&parse(&yaml)).expect_err("invalid schema");
+    assert_eq!(
+        err.to_string(),
+        format!("Schema 'demo' is invalid:\n  - {}", problems.join("\n  - "))
+    );
+}
+
+#[test]
+fn valid_schema_loads_unchanged() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    std::fs::write(dir.path().join("schema.yaml"), VALID).expect("write schema");
+
+    let schema = load_schema_yaml(dir.path()).expect("valid schema loads");
+    let graph: Vec<(&str, Vec<&str>)> = schema
+        .artifacts
+        .iter()
+        .map(|a| {
+            (
+                a.id.as_str(),
+                a.requires.iter().map(String::as_str).collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        graph,
+        vec![
+            ("proposal", vec![]),
+            ("design", vec!["proposal"]),
+            ("tasks", vec!["proposal", "design"]),
+        ]
+    );
+    assert_eq!(
+        schema.apply.and_then(|apply| apply.requires),
+        Some(vec!["tasks".to_string()])
+    );
+}
+
+#[test]
+fn invalid_project_schema_fails_to_load() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let yaml = VALID.replace("requires: [proposal]", "requires: [proposl]");
+    std::fs::write(dir.path().join("schema.yaml"), yaml).expect("write schema");
+
+    assert!(matches!(
+        load_schema_yaml(dir.path()),
+        Err(WorkflowError::SchemaInvalid { .. })
+    ));
+}
+
+#[test]
+fn embedded_schemas_are_structurally_valid() {
+    let names = embedded_schema_names();
+    assert!(!names.is_empty());
+    for name in names {
+        match load_embedded_schema_yaml(&name) {
+            Ok(Some(_)) => {}
+            Ok(None) => panic!("embedded schema '{name}' has no schema.yaml"),
+            Err(err) => panic!("embedded schema '{name}' is invalid: {err}"),
+        }
+    }
+}
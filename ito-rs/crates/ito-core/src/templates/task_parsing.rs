@@ -1,7 +1,10 @@
+use ito_domain::tasks::CheckboxNesting;
+
 use super::TaskItem;
 
 pub(super) fn parse_checkbox_tasks(contents: &str) -> Vec<TaskItem> {
     let mut tasks: Vec<TaskItem> = Vec::new();
+    let mut nesting = CheckboxNesting::new();
     for line in contents.lines() {
         let l = line.trim_start();
         let bytes = l.as_bytes();
@@ -23,11 +26,15 @@ pub(super) fn parse_checkbox_tasks(contents: &str) -> Vec<TaskItem> {
             '~' | '>' => (false, &l[6..], Some("in-progress".to_string())),
             _ => continue,
         };
+        let id = (tasks.len() + 1).to_string();
+        let (parent_id, depth) = nesting.place(line, &id);
         tasks.push(TaskItem {
-            id: (tasks.len() + 1).to_string(),
+            id,
             description: rest.trim().to_string(),
             done,
             status,
+            parent_id,
+            depth,
         });
     }
     tasks
@@ -72,6 +79,8 @@ pub(super) fn parse_enhanced_tasks(contents: &str) -> Vec<TaskItem> {
             description: desc,
             done: *current_done,
             status: current_status.take(),
+            parent_id: None,
+            depth: 0,
         });
         *current_done = false;
     }
@@ -1,4 +1,4 @@
-use super::parse_enhanced_tasks;
+use super::{parse_checkbox_tasks, parse_enhanced_tasks};
 
 #[test]
 fn parse_enhanced_tasks_extracts_ids_status_and_done() {
@@ -21,3 +21,22 @@ fn parse_enhanced_tasks_extracts_ids_status_and_done() {
     assert!(!tasks[1].done);
     assert_eq!(tasks[1].status.as_deref(), Some("in-progress"));
 }
+
+#[test]
+fn parse_checkbox_tasks_links_indented_subtasks_to_their_parent() {
+    let tasks =
+        parse_checkbox_tasks("- [ ] Parent\n  - [x] Child\n    - [ ] Grandchild\n- [ ] Next\n");
+    let shape: Vec<(&str, Option<&str>, u8)> = tasks
+        .iter()
+        .map(|t| (t.id.as_str(), t.parent_id.as_deref(), t.depth))
+        .collect();
+    assert_eq!(
+        shape,
+        vec![
+            ("1", None, 0),
+            ("2", Some("1"), 1),
+            ("3", Some("2"), 2),
+            ("4", None, 0),
+        ]
+    );
+}
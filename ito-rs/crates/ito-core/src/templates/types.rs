@@ -29,6 +29,49 @@ pub enum WorkflowError {
     /// Artifact id failed sanitization for user-guidance lookup.
     InvalidArtifactId(String),
 
+    #[error("Artifact '{0}' cannot be skipped")]
+    /// The schema marks the artifact `skippable: false`.
+    ArtifactNotSkippable(String),
+
+    #[error("Artifact '{artifact}' uses unknown path variable '{{{variable}}}'")]
+    /// A `generates` or `apply.tracks` path names a variable Ito does not define.
+    UnknownPathVariable {
+        /// Artifact id (or `apply.tracks`) whose path uses the variable.
+        artifact: String,
+        /// Variable name, without braces.
+        variable: String,
+    },
+
+    #[error("Schema '{schema}' is invalid:\n  - {}", .problems.join("\n  - "))]
+    /// `schema.yaml` refers to unknown or duplicate artifact ids; every problem is listed.
+    SchemaInvalid {
+        /// Schema name from `schema.yaml`.
+        schema: String,
+        /// One message per problem, with a suggestion for unknown ids.
+        problems: Vec<String>,
+    },
+
+    #[error("Change '{0}' has no recorded schema baseline")]
+    /// The change has no stored schema copy to compare against or pin.
+    SchemaBaselineMissing(String),
+
+    #[error("Template '{template}' includes missing partial '{partial}'")]
+    /// A template include directive names a partial that does not exist.
+    PartialNotFound {
+        /// Template or partial containing the include directive.
+        template: String,
+        /// Schema-relative path of the missing partial.
+        partial: String,
+    },
+
+    #[error("Template include cycle: {0}")]
+    /// Template includes form a cycle; the payload lists the include chain.
+    IncludeCycle(String),
+
+    #[error("Template includes in '{0}' nest deeper than {1} levels")]
+    /// Template includes exceed the supported nesting depth.
+    IncludeDepthExceeded(String, usize),
+
     #[error(transparent)]
     /// IO error while reading or writing workflow files.
     Io(#[from] std::io::Error),
@@ -47,11 +90,14 @@ pub struct ArtifactStatus {
     /// Path (relative to the change directory) the artifact should generate.
     pub output_path: String,
 
-    /// Computed state: `done`, `ready`, `blocked`, or `optional`.
+    /// Computed state: `done`, `skipped`, `ready`, `blocked`, or `optional`.
     pub status: String,
     #[serde(rename = "missingDeps", skip_serializing_if = "Vec::is_empty")]
     /// Artifact ids that are required but not yet complete.
     pub missing_deps: Vec<String>,
+    #[serde(rename = "skipReason", skip_serializing_if = "Option::is_none")]
+    /// Reason recorded when the artifact was skipped.
+    pub skip_reason: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -88,8 +134,10 @@ pub struct TemplateInfo {
 pub struct DependencyInfo {
     /// Dependency artifact id.
     pub id: String,
-    /// Whether the dependency is complete.
+    /// Whether the dependency is complete (or skipped).
     pub done: bool,
+    /// Whether the dependency was skipped rather than generated.
+    pub skipped: bool,
     /// Dependency output path.
     pub path: String,
     /// Optional schema description for the dependency.
@@ -129,6 +177,144 @@ pub struct InstructionsResponse {
 
     /// Artifact ids that become unblocked once this artifact is complete.
     pub unlocks: Vec<String>,
+    /// Whether the change has skipped this artifact.
+    pub skipped: bool,
+    #[serde(rename = "skipReason", skip_serializing_if = "Option::is_none")]
+    /// Reason recorded when the artifact was skipped.
+    pub skip_reason: Option<String>,
+    #[serde(rename = "schemaDrift", skip_serializing_if = "Option::is_none")]
+    /// Differences between the schema the change was baselined against and the current one.
+    pub schema_drift: Option<SchemaDrift>,
+    #[serde(rename = "renderWarning", skip_serializing_if = "Option::is_none")]
+    /// Why a rendering request fell back to the raw template text.
+    pub render_warning: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// A required artifact that cannot be started yet.
+pub struct BlockedArtifact {
+    /// Artifact id.
+    pub id: String,
+    #[serde(rename = "missingDeps")]
+    /// Artifact ids that must be completed first.
+    pub missing_deps: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "state", rename_all = "camelCase")]
+/// What a change needs next, derived from its artifact statuses.
+pub enum NextStep {
+    /// An artifact is ready to be written.
+    Ready {
+        #[serde(rename = "artifactId")]
+        /// The first ready artifact in build order.
+        artifact_id: String,
+    },
+    /// Every artifact required by apply is satisfied, but the change is not complete.
+    ReadyToApply,
+    /// Every required artifact is done or skipped.
+    Complete,
+    /// Nothing is ready and required artifacts are waiting on unfinished dependencies.
+    Blocked {
+        /// Blocked artifacts with the dependencies they are waiting on.
+        blocked: Vec<BlockedArtifact>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// The next step for a change, with instructions when an artifact is ready.
+pub struct NextArtifact {
+    #[serde(rename = "changeName")]
+    /// Change directory name.
+    pub change_name: String,
+    #[serde(rename = "schemaName")]
+    /// Resolved schema name.
+    pub schema_name: String,
+    #[serde(flatten)]
+    /// Selected next step.
+    pub step: NextStep,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Instructions for the selected artifact when `step` is [`NextStep::Ready`].
+    pub instructions: Option<Box<InstructionsResponse>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+/// How disruptive a schema change is for an in-flight change.
+pub enum SchemaDriftSeverity {
+    /// Only templates or descriptive text changed; existing artifacts stay valid.
+    Low,
+    /// Artifacts were added, removed, or had their outputs or dependencies changed.
+    High,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Differences between a change's recorded schema baseline and the current schema.
+pub struct SchemaDrift {
+    #[serde(rename = "schemaName")]
+    /// Schema name.
+    pub schema_name: String,
+    #[serde(rename = "recordedHash")]
+    /// Schema hash recorded in the change's `.ito.yaml`.
+    pub recorded_hash: String,
+    #[serde(rename = "currentHash")]
+    /// Hash of the schema as currently resolved.
+    pub current_hash: String,
+
+    /// Overall severity of the drift.
+    pub severity: SchemaDriftSeverity,
+    #[serde(rename = "baselineAvailable")]
+    /// Whether the baseline schema copy was available to diff against.
+    pub baseline_available: bool,
+    #[serde(rename = "artifactsAdded")]
+    /// Artifact ids present in the current schema but not in the baseline.
+    pub artifacts_added: Vec<String>,
+    #[serde(rename = "artifactsRemoved")]
+    /// Artifact ids present in the baseline but not in the current schema.
+    pub artifacts_removed: Vec<String>,
+    #[serde(rename = "artifactsChanged")]
+    /// Artifact ids whose output path, dependencies, or optionality changed.
+    pub artifacts_changed: Vec<String>,
+    #[serde(rename = "templatesChanged")]
+    /// Artifact ids whose template contents changed.
+    pub templates_changed: Vec<String>,
+    #[serde(rename = "orphanedOutputs")]
+    /// Existing change files produced by artifacts the current schema no longer defines.
+    pub orphaned_outputs: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// One artifact whose status differs between two schema versions.
+pub struct ArtifactStatusChange {
+    /// Artifact id.
+    pub id: String,
+    /// Status under the previous schema (`None` when the artifact did not exist).
+    pub before: Option<String>,
+    /// Status under the new schema (`None` when the artifact no longer exists).
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Result of re-baselining or pinning a change's schema.
+pub struct SchemaRebaselineResult {
+    #[serde(rename = "changeName")]
+    /// Change directory name.
+    pub change_name: String,
+    #[serde(rename = "schemaName")]
+    /// Schema name.
+    pub schema_name: String,
+    #[serde(rename = "previousHash")]
+    /// Schema hash recorded before the operation, if any.
+    pub previous_hash: Option<String>,
+    #[serde(rename = "schemaHash")]
+    /// Schema hash recorded after the operation.
+    pub schema_hash: String,
+
+    /// Whether the change now resolves its schema from the pinned copy.
+    pub pinned: bool,
+    #[serde(rename = "statusChanges")]
+    /// Artifacts whose status changes as a result of the operation.
+    pub status_changes: Vec<ArtifactStatusChange>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -143,6 +329,16 @@ pub struct TaskItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     /// Optional workflow status string (format-dependent).
     pub status: Option<String>,
+    #[serde(rename = "parentId", skip_serializing_if = "Option::is_none")]
+    /// Id of the enclosing task for indented checkbox subtasks.
+    pub parent_id: Option<String>,
+    #[serde(skip_serializing_if = "is_top_level")]
+    /// Nesting depth (`0` for top-level tasks).
+    pub depth: u8,
+}
+
+fn is_top_level(depth: &u8) -> bool {
+    *depth == 0
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -213,9 +409,36 @@ pub struct ApplyInstructionsResponse {
     #[serde(rename = "missingArtifacts", skip_serializing_if = "Option::is_none")]
     /// Missing artifacts that block applying the change.
     pub missing_artifacts: Option<Vec<String>>,
+    #[serde(
+        rename = "skippedArtifacts",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    /// Artifacts the change skipped, mapped to the recorded reason.
+    pub skipped_artifacts: BTreeMap<String, Option<String>>,
 
     /// Human-readable instruction to display to the user.
     pub instruction: String,
+    #[serde(rename = "schemaDrift", skip_serializing_if = "Option::is_none")]
+    /// Differences between the schema the change was baselined against and the current one.
+    pub schema_drift: Option<SchemaDrift>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    /// Per-file progress when the schema tracks one or more files.
+    pub tracks: Vec<TrackedFileProgress>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// Progress for one tracking file listed in `apply.tracks`.
+pub struct TrackedFileProgress {
+    /// Tracking filename relative to the change directory.
+    pub file: String,
+    /// Full path to the tracking file.
+    pub path: String,
+    /// Whether the tracking file exists.
+    pub exists: bool,
+    /// Detected tracking file format (when the file exists).
+    pub format: Option<String>,
+    /// Task progress totals for this file.
+    pub progress: ProgressInfo,
 }
 
 /// Artifact presence information for review instruction rendering.
@@ -376,6 +599,8 @@ pub enum SchemaSource {
     Embedded,
     /// Schema provided by the legacy package/repository filesystem path.
     Package,
+    /// Schema copy pinned inside the change directory.
+    Pinned,
 }
 
 impl SchemaSource {
@@ -386,6 +611,7 @@ impl SchemaSource {
             SchemaSource::User => "user",
             SchemaSource::Embedded => "embedded",
             SchemaSource::Package => "package",
+            SchemaSource::Pinned => "pinned",
         }
     }
 }
@@ -441,6 +667,57 @@ pub struct ArtifactYaml {
     #[serde(default)]
     /// Artifact ids that must be completed first.
     pub requires: Vec<String>,
+    #[serde(default = "default_skippable")]
+    /// Whether a change may mark this artifact as not applicable (default: `true`).
+    pub skippable: bool,
+    #[serde(default)]
+    /// Optional validators run against the artifact's files by `ito validate`.
+    pub validate: Option<ArtifactValidateYaml>,
+}
+
+fn default_skippable() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+/// Artifact-level validation from a schema (`artifacts[].validate`).
+///
+/// Both validators are optional; when both are set the builtin runs first.
+pub struct ArtifactValidateYaml {
+    #[serde(default)]
+    /// Builtin validator applied to each artifact file.
+    pub builtin: Option<BuiltinArtifactValidator>,
+    #[serde(default)]
+    /// Shell command run once per artifact file. `{file}` and `{change_dir}`
+    /// are replaced with shell-quoted absolute paths.
+    pub command: Option<String>,
+    #[serde(default)]
+    /// Command timeout in seconds (defaults to the caller's timeout).
+    pub timeout_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+/// Builtin artifact validators (`validate.builtin`).
+pub enum BuiltinArtifactValidator {
+    /// The file parses as YAML.
+    Yaml,
+    /// The file parses as JSON.
+    Json,
+    /// The markdown body (after any front matter) is not blank.
+    MarkdownNonempty,
+}
+
+impl BuiltinArtifactValidator {
+    /// The name used in `schema.yaml`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BuiltinArtifactValidator::Yaml => "yaml",
+            BuiltinArtifactValidator::Json => "json",
+            BuiltinArtifactValidator::MarkdownNonempty => "markdown-nonempty",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -450,13 +727,47 @@ pub struct ApplyYaml {
     /// Artifacts required to consider the change ready to apply.
     pub requires: Option<Vec<String>>,
     #[serde(default)]
-    /// Optional task tracking filename (relative to change dir).
-    pub tracks: Option<String>,
+    /// Optional task tracking filename(s) (relative to change dir).
+    pub tracks: Option<ApplyTracksYaml>,
     #[serde(default)]
     /// Optional instruction text displayed during apply.
     pub instruction: Option<String>,
 }
 
+impl ApplyYaml {
+    /// All configured tracking files, in schema order.
+    pub fn tracking_files(&self) -> &[String] {
+        self.tracks.as_ref().map_or(&[], ApplyTracksYaml::files)
+    }
+
+    /// The first configured tracking file.
+    ///
+    /// Single-file consumers (task mutation, validation) operate on this file.
+    pub fn primary_tracking_file(&self) -> Option<&str> {
+        self.tracking_files().first().map(String::as_str)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+/// `apply.tracks` value: a single tracking file or a list of them.
+pub enum ApplyTracksYaml {
+    /// One tracking file (`tracks: tasks.md`).
+    One(String),
+    /// Several tracking files whose progress is aggregated (`tracks: [a.md, b.md]`).
+    Many(Vec<String>),
+}
+
+impl ApplyTracksYaml {
+    /// Configured tracking files, in schema order.
+    pub fn files(&self) -> &[String] {
+        match self {
+            ApplyTracksYaml::One(file) => std::slice::from_ref(file),
+            ApplyTracksYaml::Many(files) => files,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(deny_unknown_fields)]
 /// Schema validation configuration (`validation.yaml`).
@@ -6,6 +6,7 @@ fn schema_source_as_str_returns_expected_labels() {
     assert_eq!(SchemaSource::User.as_str(), "user");
     assert_eq!(SchemaSource::Embedded.as_str(), "embedded");
     assert_eq!(SchemaSource::Package.as_str(), "package");
+    assert_eq!(SchemaSource::Pinned.as_str(), "pinned");
 }
 
 #[test]
@@ -3,7 +3,15 @@
 //! These live in `ito-core` rather than `ito-domain` because they depend on the
 //! system clock (`Local::now()`), which is non-deterministic I/O.
 
-use chrono::Local;
+use chrono::{DateTime, Local};
+
+/// Current local time.
+///
+/// Domain helpers take the clock as a parameter; adapters call this at the
+/// edge so tests can pass a fixed time instead.
+pub fn now() -> DateTime<Local> {
+    Local::now()
+}
 
 /// Current local time formatted as `HH:MM:SS`.
 pub fn now_time() -> String {
@@ -0,0 +1,261 @@
+//! Schema-declared artifact validators.
+//!
+//! A schema artifact may declare a `validate:` block naming a builtin
+//! validator and/or a shell command:
+//!
+//! ```yaml
+//! artifacts:
+//!   - id: design
+//!     generates: design.yaml
+//!     template: design.yaml
+//!     validate:
+//!       builtin: yaml
+//!       command: check-design {file}
+//!       timeout_secs: 30
+//! ```
+//!
+//! [`validate_change_artifacts`] runs them against every file the artifact
+//! generated in a change directory. Commands from schema files are arbitrary
+//! code, so they only run for schemas the project owns (project-local or
+//! pinned in the change) unless the caller trusts other sources explicitly.
+
+use std::path::Path;
+use std::time::Duration;
+
+use ito_common::paths;
+use serde_json::json;
+
+use super::{ValidationIssue, error, resolve_validation_context, warning, with_metadata};
+use crate::memory::shell_quote;
+use crate::process::{ProcessRequest, ProcessRunner};
+use crate::templates::{
+    ArtifactYaml, BuiltinArtifactValidator, ResolvedSchema, SchemaSource, artifact_output_files,
+    resolve_schema,
+};
+
+/// Timeout for validation commands whose schema does not set `timeout_secs`.
+pub const DEFAULT_ARTIFACT_COMMAND_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Captured stdout/stderr kept per validation command.
+pub const DEFAULT_ARTIFACT_COMMAND_OUTPUT_BYTES: usize = 64 * 1024;
+
+/// How [`validate_change_artifacts`] treats schema validation commands.
+#[derive(Debug, Clone)]
+pub struct ArtifactValidationOptions {
+    /// Run `validate.command` entries at all (`--no-artifact-commands` clears it).
+    pub run_commands: bool,
+    /// Run commands from user, embedded, and package schemas (`--trust-embedded`).
+    pub trust_non_project_schemas: bool,
+    /// Timeout for commands without `timeout_secs`.
+    pub default_timeout: Duration,
+    /// Cap on captured stdout and stderr per command.
+    pub max_output_bytes: usize,
+}
+
+impl Default for ArtifactValidationOptions {
+    fn default() -> Self {
+        Self {
+            run_commands: true,
+            trust_non_project_schemas: false,
+            default_timeout: DEFAULT_ARTIFACT_COMMAND_TIMEOUT,
+            max_output_bytes: DEFAULT_ARTIFACT_COMMAND_OUTPUT_BYTES,
+        }
+    }
+}
+
+/// Run the schema's artifact validators against a change's artifact files.
+///
+/// Issues use the artifact id as their path and carry the file (and, for
+/// commands, the command and its output) as metadata. Artifacts without files
+/// are not checked; schema resolution failures are left to
+/// [`super::validate_change`], so this returns no issues for them.
+pub fn validate_change_artifacts(
+    ito_path: &Path,
+    change_id: &str,
+    runner: &dyn ProcessRunner,
+    options: &ArtifactValidationOptions,
+) -> Vec<ValidationIssue> {
+    let (ctx, schema_name) = resolve_validation_context(ito_path, change_id);
+    let Ok(resolved) = resolve_schema(Some(&schema_name), &ctx) else {
+        return Vec::new();
+    };
+    let change_dir = paths::change_dir(ito_path, change_id);
+    schema_artifact_issues(&resolved, &change_dir, runner, options)
+}
+
+pub(crate) fn schema_artifact_issues(
+    resolved: &ResolvedSchema,
+    change_dir: &Path,
+    runner: &dyn ProcessRunner,
+    options: &ArtifactValidationOptions,
+) -> Vec<ValidationIssue> {
+    let trusted = options.trust_non_project_schemas || is_project_owned(resolved.source);
+    let mut issues = Vec::new();
+    for artifact in &resolved.schema.artifacts {
+        let Some(validate) = &artifact.validate else {
+            continue;
+        };
+        let files = artifact_output_files(change_dir, &artifact.generates);
+        if files.is_empty() {
+            continue;
+        }
+
+        if let Some(builtin) = validate.builtin {
+            for rel in &files {
+                let file = change_dir.join(rel);
+                let label = rel.to_string_lossy();
+                if let Some(problem) = run_builtin(builtin, &file) {
+                    issues.push(with_metadata(
+                        error(&artifact.id, format!("{label}: {problem}")),
+                        json!({ "file": label, "builtin": builtin.as_str() }),
+                    ));
+                }
+            }
+        }
+
+        let Some(command) = &validate.command else {
+            continue;
+        };
+        if !options.run_commands {
+            continue;
+        }
+        if !trusted {
+            issues.push(warning(
+                &artifact.id,
+                format!(
+                    "Skipped validation command from {source} schema '{name}'; pass --trust-embedded to run it",
+                    source = resolved.source.as_str(),
+                    name = resolved.schema.name,
+                ),
+            ));
+            continue;
+        }
+        let timeout = validate
+            .timeout_secs
+            .map_or(options.default_timeout, Duration::from_secs);
+        for rel in &files {
+            if let Some(issue) = run_command(
+                artifact,
+                command,
+                change_dir,
+                &rel.to_string_lossy(),
+                timeout,
+                runner,
+                options,
+            ) {
+                issues.push(issue);
+            }
+        }
+    }
+    issues
+}
+
+/// Schemas committed with the project; their commands run without opt-in.
+fn is_project_owned(source: SchemaSource) -> bool {
+    matches!(source, SchemaSource::Project | SchemaSource::Pinned)
+}
+
+/// Check one file with a builtin validator, returning the problem if any.
+fn run_builtin(builtin: BuiltinArtifactValidator, file: &Path) -> Option<String> {
+    let contents = match ito_common::io::read_to_string_std(file) {
+        Ok(contents) => contents,
+        Err(e) => return Some(format!("failed to read file: {e}")),
+    };
+    match builtin {
+        BuiltinArtifactValidator::Yaml => serde_yaml::from_str::<serde_yaml::Value>(&contents)
+            .err()
+            .map(|e| format!("invalid YAML: {e}")),
+        BuiltinArtifactValidator::Json => serde_json::from_str::<serde_json::Value>(&contents)
+            .err()
+            .map(|e| format!("invalid JSON: {e}")),
+        BuiltinArtifactValidator::MarkdownNonempty => match crate::front_matter::parse(&contents) {
+            Ok(doc) if doc.body.trim().is_empty() => Some("markdown body is empty".to_string()),
+            Ok(_) => None,
+            Err(e) => Some(format!("invalid front matter: {e}")),
+        },
+    }
+}
+
+/// Run an artifact's validation command for one file.
+fn run_command(
+    artifact: &ArtifactYaml,
+    template: &str,
+    change_dir: &Path,
+    label: &str,
+    timeout: Duration,
+    runner: &dyn ProcessRunner,
+    options: &ArtifactValidationOptions,
+) -> Option<ValidationIssue> {
+    let command = expand_placeholders(template, &change_dir.join(label), change_dir);
+    let request = ProcessRequest::new("sh")
+        .args(["-c", command.as_str()])
+        .current_dir(change_dir)
+        .max_output_bytes(options.max_output_bytes);
+    let output = match runner.run_with_timeout(&request, timeout) {
+        Ok(output) => output,
+        Err(e) => {
+            return Some(with_metadata(
+                error(
+                    &artifact.id,
+                    format!("{label}: failed to run validation command: {e}"),
+                ),
+                json!({ "file": label, "command": command }),
+            ));
+        }
+    };
+    if output.success && !output.timed_out {
+        return None;
+    }
+
+    let message = if output.timed_out {
+        format!(
+            "{label}: validation command timed out after {}s",
+            timeout.as_secs_f64()
+        )
+    } else {
+        format!(
+            "{label}: validation command failed (exit code {})",
+            output.exit_code
+        )
+    };
+    Some(with_metadata(
+        error(&artifact.id, message),
+        json!({
+            "file": label,
+            "command": command,
+            "exit_code": output.exit_code,
+            "timed_out": output.timed_out,
+            "stdout": output.stdout,
+            "stderr": output.stderr,
+        }),
+    ))
+}
+
+/// Replace `{file}` and `{change_dir}` with shell-quoted paths.
+///
+/// Substitution is a single pass, so placeholders inside substituted paths
+/// are left alone. Other braces are kept verbatim.
+fn expand_placeholders(template: &str, file: &Path, change_dir: &Path) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        if let Some(after) = tail.strip_prefix("{file}") {
+            out.push_str(&shell_quote(&file.to_string_lossy()));
+            rest = after;
+        } else if let Some(after) = tail.strip_prefix("{change_dir}") {
+            out.push_str(&shell_quote(&change_dir.to_string_lossy()));
+            rest = after;
+        } else {
+            out.push('{');
+            rest = &tail[1..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+#[path = "artifact_validators_tests.rs"]
+mod artifact_validators_tests;
@@ -0,0 +1,285 @@
+use super::*;
+use crate::process::{ProcessExecutionError, ProcessOutput, SystemProcessRunner};
+use crate::templates::SchemaYaml;
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+/// Records every command it is asked to run and reports success.
+#[derive(Default)]
+struct RecordingRunner {
+    commands: RefCell<Vec<String>>,
+}
+
+impl ProcessRunner for RecordingRunner {
+    fn run(&self, request: &ProcessRequest) -> Result<ProcessOutput, ProcessExecutionError> {
+        self.run_with_timeout(request, DEFAULT_ARTIFACT_COMMAND_TIMEOUT)
+    }
+
+    fn run_with_timeout(
+        &self,
+        request: &ProcessRequest,
+        _timeout: Duration,
+    ) -> Result<ProcessOutput, ProcessExecutionError> {
+        self.commands
+            .borrow_mut()
+            .push(request.args.last().cloned().unwrap_or_default());
+        Ok(ProcessOutput {
+            exit_code: 0,
+            success: true,
+            stdout: String::new(),
+            stderr: String::new(),
+            timed_out: false,
+            resources: Default::default(),
+        })
+    }
+}
+
+fn schema(source: SchemaSource, artifacts_yaml: &str) -> ResolvedSchema {
+    let yaml = format!("name: demo\nartifacts:\n{artifacts_yaml}");
+    ResolvedSchema {
+        schema: serde_yaml::from_str::<SchemaYaml>(&yaml).expect("schema yaml"),
+        schema_dir: PathBuf::from("/unused"),
+        source,
+    }
+}
+
+fn change_dir_with(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let td = tempfile::tempdir().expect("tempdir");
+    for (name, contents) in files {
+        std::fs::write(td.path().join(name), contents).expect("write artifact");
+    }
+    td
+}
+
+fn design_with(validate_yaml: &str) -> String {
+    format!(
+        "  - id: design\n    generates: design.yaml\n    template: design.yaml\n    validate:\n{validate_yaml}"
+    )
+}
+
+#[test]
+fn builtin_yaml_accepts_valid_yaml() {
+    let resolved = schema(SchemaSource::Project, &design_with("      builtin: yaml\n"));
+    let td = change_dir_with(&[("design.yaml", "components:\n  - api\n  - web\n")]);
+
+    let issues = schema_artifact_issues(
+        &resolved,
+        td.path(),
+        &RecordingRunner::default(),
+        &ArtifactValidationOptions::default(),
+    );
+    assert!(issues.is_empty(), "{issues:?}");
+}
+
+#[test]
+fn builtin_yaml_reports_invalid_yaml_against_the_artifact() {
+    let resolved = schema(SchemaSource::Project, &design_with("      builtin: yaml\n"));
+    let td = change_dir_with(&[("design.yaml", "components: [api, web\n")]);
+
+    let issues = schema_artifact_issues(
+        &resolved,
+        td.path(),
+        &RecordingRunner::default(),
+        &ArtifactValidationOptions::default(),
+    );
+    assert_eq!(issues.len(), 1, "{issues:?}");
+    assert_eq!(issues[0].level, "ERROR");
+    assert_eq!(issues[0].path, "design");
+    assert!(issues[0].message.contains("invalid YAML"), "{issues:?}");
+    let metadata = issues[0].metadata.as_ref().expect("metadata");
+    assert_eq!(metadata["file"], "design.yaml");
+    assert_eq!(metadata["builtin"], "yaml");
+}
+
+#[test]
+fn builtin_markdown_nonempty_ignores_front_matter() {
+    let resolved = schema(
+        SchemaSource::Project,
+        "  - id: notes\n    generates: notes.md\n    template: notes.md\n    validate:\n      builtin: markdown-nonempty\n",
+    );
+    let td = change_dir_with(&[("notes.md", "---\nstatus: draft\n---\n\n  \n")]);
+
+    let issues = schema_artifact_issues(
+        &resolved,
+        td.path(),
+        &RecordingRunner::default(),
+        &ArtifactValidationOptions::default(),
+    );
+    assert_eq!(issues.len(), 1, "{issues:?}");
+    assert!(issues[0].message.contains("markdown body is empty"));
+}
+
+#[test]
+fn missing_artifact_files_are_not_validated() {
+    let resolved = schema(
+        SchemaSource::Project,
+        &design_with("      builtin: json\n      command: exit 1\n"),
+    );
+    let td = change_dir_with(&[]);
+    let runner = RecordingRunner::default();
+
+    let issues = schema_artifact_issues(
+        &resolved,
+        td.path(),
+        &runner,
+        &ArtifactValidationOptions::default(),
+    );
+    assert!(issues.is_empty(), "{issues:?}");
+    assert!(runner.commands.borrow().is_empty());
+}
+
+#[test]
+fn failing_command_reports_exit_code_and_captured_output() {
+    let resolved = schema(
+        SchemaSource::Project,
+        &design_with("      command: \"echo checked {file}; echo 'missing owner' >&2; exit 3\"\n"),
+    );
+    let td = change_dir_with(&[("design.yaml", "owner: ''\n")]);
+
+    let issues = schema_artifact_issues(
+        &resolved,
+        td.path(),
+        &SystemProcessRunner,
+        &ArtifactValidationOptions::default(),
+    );
+    assert_eq!(issues.len(), 1, "{issues:?}");
+    assert_eq!(issues[0].path, "design");
+    assert!(issues[0].message.contains("exit code 3"), "{issues:?}");
+    let metadata = issues[0].metadata.as_ref().expect("metadata");
+    assert_eq!(metadata["exit_code"], 3);
+    assert_eq!(metadata["timed_out"], false);
+    assert!(
+        metadata["stdout"]
+            .as_str()
+            .expect("stdout")
+            .contains("design.yaml"),
+        "{metadata}"
+    );
+    assert!(
+        metadata["stderr"]
+            .as_str()
+            .expect("stderr")
+            .contains("missing owner"),
+        "{metadata}"
+    );
+}
+
+#[test]
+fn passing_command_reports_nothing() {
+    let resolved = schema(
+        SchemaSource::Project,
+        &design_with("      command: test -s {file}\n"),
+    );
+    let td = change_dir_with(&[("design.yaml", "owner: me\n")]);
+
+    let issues = schema_artifact_issues(
+        &resolved,
+        td.path(),
+        &SystemProcessRunner,
+        &ArtifactValidationOptions::default(),
+    );
+    assert!(issues.is_empty(), "{issues:?}");
+}
+
+#[test]
+fn command_timeout_is_an_error() {
+    let resolved = schema(
+        SchemaSource::Project,
+        &design_with("      command: sleep 5\n"),
+    );
+    let td = change_dir_with(&[("design.yaml", "owner: me\n")]);
+    let options = ArtifactValidationOptions {
+        default_timeout: Duration::from_millis(200),
+        ..ArtifactValidationOptions::default()
+    };
+
+    let issues = schema_artifact_issues(&resolved, td.path(), &SystemProcessRunner, &options);
+    assert_eq!(issues.len(), 1, "{issues:?}");
+    assert_eq!(issues[0].level, "ERROR");
+    assert!(issues[0].message.contains("timed out"), "{issues:?}");
+    assert_eq!(
+        issues[0].metadata.as_ref().expect("metadata")["timed_out"],
+        true
+    );
+}
+
+#[test]
+fn commands_from_non_project_schemas_need_explicit_trust() {
+    let artifacts = design_with("      builtin: yaml\n      command: check {file}\n");
+    let td = change_dir_with(&[("design.yaml", "owner: me\n")]);
+
+    for source in [
+        SchemaSource::Embedded,
+        SchemaSource::User,
+        SchemaSource::Package,
+    ] {
+        let runner = RecordingRunner::default();
+        let issues = schema_artifact_issues(
+            &schema(source, &artifacts),
+            td.path(),
+            &runner,
+            &ArtifactValidationOptions::default(),
+        );
+        assert!(runner.commands.borrow().is_empty(), "{source:?}");
+        assert_eq!(issues.len(), 1, "{issues:?}");
+        assert_eq!(issues[0].level, "WARNING");
+        assert!(issues[0].message.contains("--trust-embedded"), "{issues:?}");
+    }
+
+    let runner = RecordingRunner::default();
+    let trusted = ArtifactValidationOptions {
+        trust_non_project_schemas: true,
+        ..ArtifactValidationOptions::default()
+    };
+    let issues = schema_artifact_issues(
+        &schema(SchemaSource::Embedded, &artifacts),
+        td.path(),
+        &runner,
+        &trusted,
+    );
+    assert!(issues.is_empty(), "{issues:?}");
+    assert_eq!(runner.commands.borrow().len(), 1);
+
+    for source in [SchemaSource::Project, SchemaSource::Pinned] {
+        let runner = RecordingRunner::default();
+        schema_artifact_issues(
+            &schema(source, &artifacts),
+            td.path(),
+            &runner,
+            &ArtifactValidationOptions::default(),
+        );
+        assert_eq!(runner.commands.borrow().len(), 1, "{source:?}");
+    }
+}
+
+#[test]
+fn no_artifact_commands_skips_commands_but_keeps_builtins() {
+    let resolved = schema(
+        SchemaSource::Project,
+        &design_with("      builtin: json\n      command: check {file}\n"),
+    );
+    let td = change_dir_with(&[("design.yaml", "owner: me\n")]);
+    let runner = RecordingRunner::default();
+    let options = ArtifactValidationOptions {
+        run_commands: false,
+        ..ArtifactValidationOptions::default()
+    };
+
+    let issues = schema_artifact_issues(&resolved, td.path(), &runner, &options);
+    assert!(runner.commands.borrow().is_empty());
+    assert_eq!(issues.len(), 1, "{issues:?}");
+    assert!(issues[0].message.contains("invalid JSON"), "{issues:?}");
+}
+
+#[test]
+fn placeholders_are_shell_quoted_in_one_pass() {
+    let command = expand_placeholders(
+        "lint {file} --root {change_dir} {other}",
+        Path::new("/tmp/it's {change_dir}/design.yaml"),
+        Path::new("/tmp/change"),
+    );
+    assert_eq!(
+        command,
+        "lint '/tmp/it'\\''s {change_dir}/design.yaml' --root '/tmp/change' {other}"
+    );
+}
@@ -89,7 +89,7 @@ pub(crate) fn validate_configured_schema_rules(
         let Some(tracks) = schema
             .apply
             .as_ref()
-            .and_then(|apply| apply.tracks.as_deref())
+            .and_then(|apply| apply.primary_tracking_file())
         else {
             return Ok(rep.finish());
         };
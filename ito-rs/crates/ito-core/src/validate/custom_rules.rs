@@ -0,0 +1,407 @@
+//! User-defined validation rules declared in `validation.custom_rules`.
+//!
+//! Projects use these rules to enforce local conventions that will never be
+//! upstream rules (ticket keys in requirement text, scenario length limits,
+//! owning teams in module purposes). Rules are compiled once per validation
+//! run via [`CustomRuleSet::compile`] and then applied to specs, changes, and
+//! modules alongside the built-in checks. Every issue carries the rule's id.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use ito_common::paths;
+use regex::Regex;
+use serde::Serialize;
+
+use ito_config::types::{CustomValidationRuleConfig, ValidationConfig};
+
+use super::{
+    CoreError, CoreResult, DomainChangeRepository, DomainModuleRepository, LEVEL_ERROR, LEVEL_INFO,
+    LEVEL_WARNING, ValidationIssue, ValidationLevel, extract_section, issue,
+    parse_change_show_json, parse_spec_show_json, read_change_delta_spec_files, resolve_module,
+    warning, with_metadata, with_rule_id,
+};
+use crate::show::{Requirement, read_change_proposal_markdown};
+
+/// Target names accepted in `validation.custom_rules[].target`.
+pub const CUSTOM_RULE_TARGETS: &[&str] = &[
+    "spec_requirement",
+    "scenario",
+    "proposal_section",
+    "module_purpose",
+    "delta_description",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Text a custom rule inspects.
+pub enum CustomRuleTarget {
+    /// Requirement text in main specs and change delta specs.
+    SpecRequirement,
+    /// Scenario text in main specs and change delta specs.
+    Scenario,
+    /// A named `## <section>` of a change proposal.
+    ProposalSection(String),
+    /// The `## Purpose` section of `module.md`.
+    ModulePurpose,
+    /// Delta descriptions in change delta specs.
+    DeltaDescription,
+}
+
+impl CustomRuleTarget {
+    /// Stable name used in config and rule listings.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CustomRuleTarget::SpecRequirement => "spec_requirement",
+            CustomRuleTarget::Scenario => "scenario",
+            CustomRuleTarget::ProposalSection(_) => "proposal_section",
+            CustomRuleTarget::ModulePurpose => "module_purpose",
+            CustomRuleTarget::DeltaDescription => "delta_description",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum CustomCheck {
+    MustMatch(Regex),
+    MustNotMatch(Regex),
+    MaxLength(usize),
+    MinLength(usize),
+}
+
+impl CustomCheck {
+    fn passes(&self, text: &str) -> bool {
+        let trimmed = text.trim();
+        match self {
+            CustomCheck::MustMatch(re) => re.is_match(trimmed),
+            CustomCheck::MustNotMatch(re) => !re.is_match(trimmed),
+            CustomCheck::MaxLength(max) => trimmed.chars().count() <= *max,
+            CustomCheck::MinLength(min) => trimmed.chars().count() >= *min,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            CustomCheck::MustMatch(_) => "must_match",
+            CustomCheck::MustNotMatch(_) => "must_not_match",
+            CustomCheck::MaxLength(_) => "max_length",
+            CustomCheck::MinLength(_) => "min_length",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A compiled user-defined validation rule.
+pub struct CustomRule {
+    /// Rule id attached to every issue.
+    pub id: String,
+    /// Text the rule inspects.
+    pub target: CustomRuleTarget,
+    /// Issue level reported on failure.
+    pub level: ValidationLevel,
+    /// Message reported on failure.
+    pub message: String,
+    checks: Vec<CustomCheck>,
+}
+
+impl CustomRule {
+    fn evaluate(&self, path: &str, text: &str) -> Option<ValidationIssue> {
+        let failed = self.checks.iter().find(|c| !c.passes(text))?;
+        let found = issue(self.level, path, self.message.clone());
+        let found = with_metadata(
+            found,
+            serde_json::json!({
+                "source": "custom",
+                "target": self.target.as_str(),
+                "check": failed.name(),
+            }),
+        );
+        Some(with_rule_id(found, self.id.clone()))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+/// The compiled set of user-defined rules for one validation run.
+pub struct CustomRuleSet {
+    rules: Vec<CustomRule>,
+}
+
+impl CustomRuleSet {
+    /// Compile the rules declared in `validation.custom_rules`.
+    ///
+    /// Returns the compiled set together with configuration warnings for
+    /// every definition that could not be compiled; those definitions are
+    /// left out of the set.
+    pub fn compile(config: &ValidationConfig) -> (Self, Vec<ValidationIssue>) {
+        let mut rules = Vec::new();
+        let mut warnings = Vec::new();
+        let mut seen: BTreeSet<String> = BTreeSet::new();
+
+        for (idx, def) in config.custom_rules.iter().enumerate() {
+            let path = format!("validation.custom_rules[{idx}]");
+            match compile_rule(def) {
+                Ok(rule) => {
+                    if !seen.insert(rule.id.clone()) {
+                        warnings.push(warning(
+                            &path,
+                            format!("Duplicate custom rule id '{}'; rule ignored", rule.id),
+                        ));
+                        continue;
+                    }
+                    rules.push(rule);
+                }
+                Err(msg) => warnings.push(warning(&path, msg)),
+            }
+        }
+
+        (Self { rules }, warnings)
+    }
+
+    /// Whether no custom rules are active.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// The compiled rules, in declaration order.
+    pub fn rules(&self) -> &[CustomRule] {
+        &self.rules
+    }
+
+    /// Apply requirement and scenario rules to a main spec.
+    pub fn check_spec_markdown(&self, markdown: &str) -> Vec<ValidationIssue> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        let json = parse_spec_show_json("<spec>", markdown);
+        let mut out = Vec::new();
+        for (idx, req) in json.requirements.iter().enumerate() {
+            self.check_requirement(&format!("requirements[{idx}]"), req, &mut out);
+        }
+        out
+    }
+
+    /// Apply requirement and scenario rules to `.ito/specs/<id>/spec.md`.
+    pub fn check_spec(&self, ito_path: &Path, spec_id: &str) -> CoreResult<Vec<ValidationIssue>> {
+        if self.is_empty() {
+            return Ok(Vec::new());
+        }
+        let path = paths::spec_markdown_path(ito_path, spec_id);
+        let markdown = ito_common::io::read_to_string_std(&path)
+            .map_err(|e| CoreError::io(format!("reading spec {spec_id}"), e))?;
+        Ok(self.check_spec_markdown(&markdown))
+    }
+
+    /// Apply delta, requirement, scenario, and proposal rules to a change.
+    pub fn check_change(
+        &self,
+        change_repo: &(impl DomainChangeRepository + ?Sized),
+        change_id: &str,
+    ) -> CoreResult<Vec<ValidationIssue>> {
+        let mut out = Vec::new();
+        if self.is_empty() {
+            return Ok(out);
+        }
+
+        let files = read_change_delta_spec_files(change_repo, change_id)?;
+        let show = parse_change_show_json(change_id, &files);
+        for (idx, delta) in show.deltas.iter().enumerate() {
+            let base = format!("deltas[{idx}]");
+            for rule in self.rules_for(|t| *t == CustomRuleTarget::DeltaDescription) {
+                out.extend(rule.evaluate(&base, &delta.description));
+            }
+            for (ridx, req) in delta.requirements.iter().enumerate() {
+                self.check_requirement(&format!("{base}.requirements[{ridx}]"), req, &mut out);
+            }
+        }
+
+        let has_proposal_rules = self
+            .rules
+            .iter()
+            .any(|r| matches!(r.target, CustomRuleTarget::ProposalSection(_)));
+        if has_proposal_rules
+            && let Some(proposal) = read_change_proposal_markdown(change_repo, change_id)?
+        {
+            for rule in &self.rules {
+                let CustomRuleTarget::ProposalSection(section) = &rule.target else {
+                    continue;
+                };
+                let text = extract_section(&proposal, section);
+                let path = format!("proposal.{}", section.to_ascii_lowercase());
+                out.extend(rule.evaluate(&path, &text));
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Apply module purpose rules to a module resolved from user input.
+    pub fn check_module(
+        &self,
+        module_repo: &(impl DomainModuleRepository + ?Sized),
+        ito_path: &Path,
+        module_input: &str,
+    ) -> CoreResult<Vec<ValidationIssue>> {
+        if self.is_empty() {
+            return Ok(Vec::new());
+        }
+        let Some(resolved) = resolve_module(module_repo, ito_path, module_input)? else {
+            return Ok(Vec::new());
+        };
+        let Ok(md) = ito_common::io::read_to_string_std(&resolved.module_md) else {
+            return Ok(Vec::new());
+        };
+        Ok(self.check_module_markdown(&md))
+    }
+
+    /// Apply module purpose rules to `module.md` contents.
+    pub fn check_module_markdown(&self, markdown: &str) -> Vec<ValidationIssue> {
+        let purpose = extract_section(markdown, "Purpose");
+        let mut out = Vec::new();
+        for rule in self.rules_for(|t| *t == CustomRuleTarget::ModulePurpose) {
+            out.extend(rule.evaluate("purpose", &purpose));
+        }
+        out
+    }
+
+    fn check_requirement(&self, base: &str, req: &Requirement, out: &mut Vec<ValidationIssue>) {
+        for rule in self.rules_for(|t| *t == CustomRuleTarget::SpecRequirement) {
+            out.extend(rule.evaluate(base, &req.text));
+        }
+        for (sidx, sc) in req.scenarios.iter().enumerate() {
+            let path = format!("{base}.scenarios[{sidx}]");
+            for rule in self.rules_for(|t| *t == CustomRuleTarget::Scenario) {
+                out.extend(rule.evaluate(&path, &sc.raw_text));
+            }
+        }
+    }
+
+    fn rules_for(
+        &self,
+        pred: impl Fn(&CustomRuleTarget) -> bool,
+    ) -> impl Iterator<Item = &CustomRule> {
+        self.rules.iter().filter(move |r| pred(&r.target))
+    }
+}
+
+fn compile_rule(def: &CustomValidationRuleConfig) -> Result<CustomRule, String> {
+    let id = def.id.trim();
+    if id.is_empty() {
+        return Err("Custom rule is missing an id; rule ignored".to_string());
+    }
+
+    let target = match def.target.trim() {
+        "spec_requirement" => CustomRuleTarget::SpecRequirement,
+        "scenario" => CustomRuleTarget::Scenario,
+        "module_purpose" => CustomRuleTarget::ModulePurpose,
+        "delta_description" => CustomRuleTarget::DeltaDescription,
+        "proposal_section" => {
+            let Some(section) = def
+                .section
+                .as_deref()
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+            else {
+                return Err(format!(
+                    "Custom rule '{id}' targets proposal_section but has no section; rule ignored"
+                ));
+            };
+            CustomRuleTarget::ProposalSection(section.to_string())
+        }
+        other => {
+            return Err(format!(
+                "Custom rule '{id}' has unknown target '{other}' (expected one of: {}); rule ignored",
+                CUSTOM_RULE_TARGETS.join(", ")
+            ));
+        }
+    };
+
+    let level = match def.level.trim().to_ascii_lowercase().as_str() {
+        "error" => LEVEL_ERROR,
+        "warning" => LEVEL_WARNING,
+        "info" => LEVEL_INFO,
+        other => {
+            return Err(format!(
+                "Custom rule '{id}' has unknown level '{other}' (expected error, warning, or info); rule ignored"
+            ));
+        }
+    };
+
+    let mut checks = Vec::new();
+    if let Some(pattern) = def.must_match.as_deref() {
+        let re = Regex::new(pattern)
+            .map_err(|e| format!("Custom rule '{id}' has an invalid must_match regex: {e}"))?;
+        checks.push(CustomCheck::MustMatch(re));
+    }
+    if let Some(pattern) = def.must_not_match.as_deref() {
+        let re = Regex::new(pattern)
+            .map_err(|e| format!("Custom rule '{id}' has an invalid must_not_match regex: {e}"))?;
+        checks.push(CustomCheck::MustNotMatch(re));
+    }
+    if let Some(max) = def.max_length {
+        checks.push(CustomCheck::MaxLength(max));
+    }
+    if let Some(min) = def.min_length {
+        checks.push(CustomCheck::MinLength(min));
+    }
+    if checks.is_empty() {
+        return Err(format!(
+            "Custom rule '{id}' declares no check (must_match, must_not_match, max_length, min_length); rule ignored"
+        ));
+    }
+
+    Ok(CustomRule {
+        id: id.to_string(),
+        target,
+        level,
+        message: def.message.clone(),
+        checks,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// One entry in the `ito validate --list-rules` listing.
+pub struct ValidationRuleInfo {
+    /// Rule id.
+    pub rule_id: String,
+    /// `built-in` or `custom`.
+    pub source: &'static str,
+    /// What the rule applies to.
+    pub scope: String,
+    /// Configured level, or `None` when the level is set per schema.
+    pub level: Option<String>,
+}
+
+/// List built-in opt-in rules and the compiled custom rules.
+///
+/// Built-in rules are enabled per schema in `validation.yaml`, so they carry
+/// no level of their own.
+pub fn list_validation_rules(custom: &CustomRuleSet) -> Vec<ValidationRuleInfo> {
+    let mut out = Vec::new();
+    let built_in: [(&str, &[&str]); 3] = [
+        ("delta-specs artifact", super::delta_rules::artifact_rules()),
+        ("proposal", super::delta_rules::proposal_rules()),
+        ("tracking", super::tracking_rules::rules()),
+    ];
+    for (scope, ids) in built_in {
+        for id in ids {
+            out.push(ValidationRuleInfo {
+                rule_id: (*id).to_string(),
+                source: "built-in",
+                scope: scope.to_string(),
+                level: None,
+            });
+        }
+    }
+    for rule in custom.rules() {
+        let scope = match &rule.target {
+            CustomRuleTarget::ProposalSection(section) => format!("proposal_section:{section}"),
+            other => other.as_str().to_string(),
+        };
+        out.push(ValidationRuleInfo {
+            rule_id: rule.id.clone(),
+            source: "custom",
+            scope,
+            level: Some(rule.level.to_string()),
+        });
+    }
+    out
+}
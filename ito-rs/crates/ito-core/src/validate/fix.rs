@@ -0,0 +1,381 @@
+//! Mechanical fixes for validation issues.
+//!
+//! Validators attach a [`FixAction`] to issues that can be resolved without
+//! judgement (see [`super::with_fix`]). `ito validate --fix` collects those
+//! issues, applies them with [`apply_fixes`], and validates again.
+//!
+//! Every action records the old and new state so the fix report can be
+//! reverted by hand. Actions re-check that state before writing and fail
+//! instead of clobbering files that changed since validation ran.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use ito_common::fs::StdFs;
+use ito_common::id::parse_change_id;
+use ito_common::paths;
+use ito_domain::discovery;
+use ito_domain::modules::ModuleRepository as DomainModuleRepository;
+use serde::Serialize;
+
+use super::{ValidationIssue, info, validate_change_dirs_repo_integrity, warning, with_fix};
+use super::{resolve_module, with_line};
+use crate::error_bridge::IntoCoreResult;
+use crate::errors::{CoreError, CoreResult};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+/// A mechanical change that resolves one validation issue.
+pub enum FixAction {
+    /// Rename `from` to `to`.
+    RenamePath {
+        /// Current path.
+        from: PathBuf,
+        /// New path.
+        to: PathBuf,
+    },
+    /// Replace the whole contents of `path`.
+    RewriteFile {
+        /// File to rewrite.
+        path: PathBuf,
+        /// Expected current contents.
+        old: String,
+        /// Replacement contents.
+        new: String,
+    },
+    /// Replace one line of `path`, keeping its line ending.
+    UpdateLine {
+        /// File to edit.
+        path: PathBuf,
+        /// 1-based line number.
+        line: u32,
+        /// Expected current line (without line ending).
+        old: String,
+        /// Replacement line (without line ending).
+        new: String,
+    },
+}
+
+impl FixAction {
+    /// One-line description of the change, e.g. `rename a -> b`.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::RenamePath { from, to } => {
+                format!("rename {} -> {}", from.display(), to.display())
+            }
+            Self::RewriteFile { path, .. } => format!("rewrite {}", path.display()),
+            Self::UpdateLine {
+                path,
+                line,
+                old,
+                new,
+            } => format!(
+                "{}:{line}: '{}' -> '{}'",
+                path.display(),
+                old.trim(),
+                new.trim()
+            ),
+        }
+    }
+
+    /// Apply the action.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::Validation`] when the on-disk state no longer
+    /// matches what the action expects, or an I/O error.
+    pub fn apply(&self) -> CoreResult<()> {
+        match self {
+            Self::RenamePath { from, to } => {
+                if !from.exists() {
+                    return Err(CoreError::validation(format!(
+                        "{} no longer exists",
+                        from.display()
+                    )));
+                }
+                if to.exists() {
+                    return Err(CoreError::validation(format!(
+                        "{} already exists",
+                        to.display()
+                    )));
+                }
+                std::fs::rename(from, to)
+                    .map_err(|e| CoreError::io(format!("renaming {}", from.display()), e))
+            }
+            Self::RewriteFile { path, old, new } => {
+                let current = read(path)?;
+                if current != *old {
+                    return Err(changed_since_validation(path));
+                }
+                write(path, new)
+            }
+            Self::UpdateLine {
+                path,
+                line,
+                old,
+                new,
+            } => {
+                let current = read(path)?;
+                let idx = (*line as usize).saturating_sub(1);
+                let mut out = String::with_capacity(current.len() + new.len());
+                let mut found = false;
+                for (i, raw) in current.split_inclusive('\n').enumerate() {
+                    if i != idx {
+                        out.push_str(raw);
+                        continue;
+                    }
+                    let body = raw.trim_end_matches(['\n', '\r']);
+                    if body != old {
+                        return Err(changed_since_validation(path));
+                    }
+                    out.push_str(new);
+                    out.push_str(&raw[body.len()..]);
+                    found = true;
+                }
+                if !found {
+                    return Err(changed_since_validation(path));
+                }
+                write(path, &out)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// A fix that was attempted, with the issue it addressed.
+pub struct FixOutcome {
+    /// Logical path of the issue.
+    pub path: String,
+    /// Issue message.
+    pub message: String,
+    /// The attempted action.
+    pub fix: FixAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Why the action could not be applied.
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+/// Result of [`apply_fixes`].
+pub struct FixReport {
+    /// Fixes written to disk.
+    pub applied: Vec<FixOutcome>,
+    /// Fixes that could not be applied.
+    pub failed: Vec<FixOutcome>,
+}
+
+impl FixReport {
+    /// True when no fix was attempted.
+    pub fn is_empty(&self) -> bool {
+        self.applied.is_empty() && self.failed.is_empty()
+    }
+}
+
+/// Apply the fix attached to each issue, once per distinct action.
+pub fn apply_fixes(issues: &[ValidationIssue]) -> FixReport {
+    let mut report = FixReport::default();
+    let mut seen: Vec<&FixAction> = Vec::new();
+    for issue in issues {
+        let Some(fix) = issue.fix.as_ref() else {
+            continue;
+        };
+        if seen.contains(&fix) {
+            continue;
+        }
+        seen.push(fix);
+
+        let mut outcome = FixOutcome {
+            path: issue.path.clone(),
+            message: issue.message.clone(),
+            fix: fix.clone(),
+            error: None,
+        };
+        match fix.apply() {
+            Ok(()) => report.applied.push(outcome),
+            Err(e) => {
+                outcome.error = Some(e.to_string());
+                report.failed.push(outcome);
+            }
+        }
+    }
+    report
+}
+
+/// Fixable issues for change directories: non-canonical directory names.
+///
+/// With `change_id`, only that change directory is considered.
+///
+/// # Errors
+///
+/// Returns an error when the change directories cannot be listed.
+pub fn fixable_change_issues(
+    ito_path: &Path,
+    change_id: Option<&str>,
+) -> CoreResult<Vec<ValidationIssue>> {
+    Ok(validate_change_dirs_repo_integrity(ito_path)?
+        .into_iter()
+        .filter(|(dir, _)| change_id.is_none_or(|id| id == dir))
+        .flat_map(|(_, issues)| issues)
+        .filter(|issue| issue.fix.is_some())
+        .collect())
+}
+
+/// Fixable issues for main specs: non-LF line endings in `spec.md`.
+///
+/// With `spec_id`, only that spec is considered.
+///
+/// # Errors
+///
+/// Returns an error when the specs directory cannot be listed.
+pub fn fixable_spec_issues(
+    ito_path: &Path,
+    spec_id: Option<&str>,
+) -> CoreResult<Vec<ValidationIssue>> {
+    let ids = match spec_id {
+        Some(id) => vec![id.to_string()],
+        None => discovery::list_spec_dir_names(&StdFs, ito_path).into_core()?,
+    };
+    let mut out = Vec::new();
+    for id in ids {
+        let path = paths::spec_markdown_path(ito_path, &id);
+        if let Ok(markdown) = std::fs::read_to_string(&path)
+            && let Some(issue) = line_ending_issue(&path, &markdown)
+        {
+            out.push(issue);
+        }
+    }
+    Ok(out)
+}
+
+/// Fixable issues for modules: archived changes left unchecked in `module.md`.
+///
+/// With `module_id`, only that module is considered.
+///
+/// # Errors
+///
+/// Returns an error when modules cannot be listed or resolved.
+pub fn fixable_module_issues(
+    module_repo: &(impl DomainModuleRepository + ?Sized),
+    ito_path: &Path,
+    module_id: Option<&str>,
+) -> CoreResult<Vec<ValidationIssue>> {
+    let inputs = match module_id {
+        Some(id) => vec![id.to_string()],
+        None => discovery::list_module_dir_names(&StdFs, ito_path).into_core()?,
+    };
+    let mut out = Vec::new();
+    for input in inputs {
+        let Some(resolved) = resolve_module(module_repo, ito_path, &input)? else {
+            continue;
+        };
+        if let Ok(md) = std::fs::read_to_string(&resolved.module_md) {
+            out.extend(module_checklist_issues(ito_path, &resolved.module_md, &md));
+        }
+    }
+    Ok(out)
+}
+
+/// Report `spec.md` files that do not use LF line endings.
+///
+/// Consistent CRLF is informational (it may follow `install.line_endings`);
+/// mixed endings are a warning. Both carry a fix normalizing to LF.
+pub(crate) fn line_ending_issue(path: &Path, contents: &str) -> Option<ValidationIssue> {
+    let crlf = contents.matches("\r\n").count();
+    if crlf == 0 {
+        return None;
+    }
+    let lf = contents.matches('\n').count();
+    let issue = if crlf == lf {
+        info("file", "Spec uses CRLF line endings; Ito specs use LF")
+    } else {
+        warning("file", "Spec mixes CRLF and LF line endings")
+    };
+    Some(with_fix(
+        issue,
+        FixAction::RewriteFile {
+            path: path.to_path_buf(),
+            old: contents.to_string(),
+            new: contents.replace("\r\n", "\n"),
+        },
+    ))
+}
+
+/// Report unchecked `module.md` checklist entries for archived changes.
+pub(crate) fn module_checklist_issues(
+    ito_path: &Path,
+    module_md: &Path,
+    md: &str,
+) -> Vec<ValidationIssue> {
+    let mut archived: Option<BTreeSet<String>> = None;
+    let mut out = Vec::new();
+    for (idx, line) in md.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let Some(rest) = trimmed
+            .strip_prefix("- [ ] ")
+            .or_else(|| trimmed.strip_prefix("* [ ] "))
+        else {
+            continue;
+        };
+        let Some(change_id) = rest.split_whitespace().next() else {
+            continue;
+        };
+        let archived = archived.get_or_insert_with(|| archived_change_ids(ito_path));
+        if !archived.contains(change_id) {
+            continue;
+        }
+        let line_no = (idx + 1) as u32;
+        out.push(with_fix(
+            with_line(
+                warning(
+                    "changes",
+                    format!(
+                        "Change '{change_id}' is archived but unchecked in the module checklist"
+                    ),
+                ),
+                line_no,
+            ),
+            FixAction::UpdateLine {
+                path: module_md.to_path_buf(),
+                line: line_no,
+                old: line.to_string(),
+                new: line.replacen("[ ]", "[x]", 1),
+            },
+        ));
+    }
+    out
+}
+
+/// Ids of archived changes in both archive locations, without date prefixes.
+fn archived_change_ids(ito_path: &Path) -> BTreeSet<String> {
+    let mut out = BTreeSet::new();
+    for dir in [
+        paths::changes_archive_dir(ito_path),
+        paths::archive_changes_dir(ito_path),
+    ] {
+        let Ok(names) = discovery::list_dir_names(&StdFs, &dir) else {
+            continue;
+        };
+        for name in names {
+            // Archived dirs are named like 2026-01-26-006-05_port-list.
+            let id = name.get(11..).unwrap_or_default();
+            if parse_change_id(id).is_ok() {
+                out.insert(id.to_string());
+            }
+        }
+    }
+    out
+}
+
+fn read(path: &Path) -> CoreResult<String> {
+    std::fs::read_to_string(path)
+        .map_err(|e| CoreError::io(format!("reading {}", path.display()), e))
+}
+
+fn write(path: &Path, contents: &str) -> CoreResult<()> {
+    std::fs::write(path, contents)
+        .map_err(|e| CoreError::io(format!("writing {}", path.display()), e))
+}
+
+fn changed_since_validation(path: &Path) -> CoreError {
+    CoreError::validation(format!("{} changed since validation", path.display()))
+}
@@ -13,7 +13,7 @@
 //! ```
 
 use super::{
-    LEVEL_ERROR, LEVEL_INFO, LEVEL_WARNING, ValidationIssue, ValidationLevel,
+    FixAction, LEVEL_ERROR, LEVEL_INFO, LEVEL_WARNING, ValidationIssue, ValidationLevel,
     format_specs::FormatSpecRef,
 };
 
@@ -34,6 +34,7 @@ pub fn issue(
         column: None,
         rule_id: None,
         metadata: None,
+        fix: None,
     }
 }
 
@@ -86,6 +87,15 @@ pub fn with_metadata(mut i: ValidationIssue, metadata: serde_json::Value) -> Val
     i
 }
 
+/// Attach a mechanical fix to an existing issue.
+///
+/// Use this only when the fix needs no judgement; `ito validate --fix` applies
+/// it without asking.
+pub fn with_fix(mut i: ValidationIssue, fix: FixAction) -> ValidationIssue {
+    i.fix = Some(fix);
+    i
+}
+
 /// Attach a stable rule id to an existing issue.
 pub fn with_rule_id(mut i: ValidationIssue, rule_id: impl Into<String>) -> ValidationIssue {
     i.rule_id = Some(rule_id.into());
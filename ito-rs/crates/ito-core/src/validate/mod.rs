@@ -17,27 +17,56 @@ use ito_common::paths;
 use crate::show::{parse_change_show_json, parse_spec_show_json, read_change_delta_spec_files};
 use crate::templates::{
     ResolvedSchema, ValidationLevelYaml, ValidationYaml, ValidatorId, artifact_done,
-    load_schema_validation, read_change_schema, resolve_schema,
+    load_schema_validation, read_change_schema, resolve_schema, skipped_artifacts,
 };
 use ito_config::ConfigContext;
 use ito_domain::changes::ChangeRepository as DomainChangeRepository;
 use ito_domain::modules::ModuleRepository as DomainModuleRepository;
 
+mod artifact_validators;
 mod authority_rules;
+mod custom_rules;
 mod delta_rules;
 mod domain_discovery_rules;
+mod fix;
 mod format_specs;
 mod issue;
+mod module_graph;
 mod repo_integrity;
 mod report;
 mod rules_engine;
 mod tracking_rules;
+#[cfg(feature = "watch")]
+mod watch;
 
+pub use artifact_validators::{
+    ArtifactValidationOptions, DEFAULT_ARTIFACT_COMMAND_OUTPUT_BYTES,
+    DEFAULT_ARTIFACT_COMMAND_TIMEOUT, validate_change_artifacts,
+};
 pub(crate) use authority_rules::validate_configured_schema_rules;
+pub use custom_rules::{
+    CUSTOM_RULE_TARGETS, CustomRule, CustomRuleSet, CustomRuleTarget, ValidationRuleInfo,
+    list_validation_rules,
+};
+pub use fix::{
+    FixAction, FixOutcome, FixReport, apply_fixes, fixable_change_issues, fixable_module_issues,
+    fixable_spec_issues,
+};
 pub(crate) use issue::with_format_spec;
-pub use issue::{error, info, issue, warning, with_line, with_loc, with_metadata, with_rule_id};
+pub use issue::{
+    error, info, issue, warning, with_fix, with_line, with_loc, with_metadata, with_rule_id,
+};
+pub use module_graph::{
+    ModuleGraph, ModuleGraphNode, load_module_graph, module_graph_issues,
+    parse_module_dependencies, validate_module_graph,
+};
 pub use repo_integrity::validate_change_dirs_repo_integrity;
 pub use report::{ReportBuilder, report};
+#[cfg(feature = "watch")]
+pub use watch::{
+    RunIssue, ValidationDelta, ValidationRun, WatchOutcome, WatchSummary, diff_runs,
+    watch_validation,
+};
 
 /// Severity level for a [`ValidationIssue`].
 pub type ValidationLevel = &'static str;
@@ -81,6 +110,9 @@ pub struct ValidationIssue {
     #[serde(skip_serializing_if = "Option::is_none")]
     /// Optional structured metadata for tooling.
     pub metadata: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Optional mechanical fix applied by `ito validate --fix`.
+    pub fix: Option<FixAction>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
@@ -205,7 +237,13 @@ pub fn validate_spec(ito_path: &Path, spec_id: &str, strict: bool) -> CoreResult
     let path = paths::spec_markdown_path(ito_path, spec_id);
     let markdown = ito_common::io::read_to_string_std(&path)
         .map_err(|e| CoreError::io(format!("reading spec {}", spec_id), e))?;
-    Ok(validate_spec_markdown(&markdown, strict))
+    let report = validate_spec_markdown(&markdown, strict);
+    let Some(line_endings) = fix::line_ending_issue(&path, &markdown) else {
+        return Ok(report);
+    };
+    let mut issues = report.issues;
+    issues.push(line_endings);
+    Ok(ValidationReport::new(issues, strict))
 }
 
 /// Validate a change using schema-driven rules when available, with legacy
@@ -241,6 +279,8 @@ pub fn validate_change(
         }
     };
 
+    validate_proposal_front_matter(&mut rep, change_repo, change_id);
+
     if let Some(resolved) = &resolved {
         match load_schema_validation(resolved) {
             Ok(Some(validation)) => {
@@ -273,7 +313,7 @@ pub fn validate_change(
                 .schema
                 .apply
                 .as_ref()
-                .and_then(|a| a.tracks.as_deref())
+                .and_then(|a| a.primary_tracking_file())
                 .unwrap_or("tasks.md");
 
             if !ito_domain::tasks::is_safe_tracking_filename(tracks_rel) {
@@ -306,6 +346,26 @@ pub fn validate_change(
     Ok(rep.finish())
 }
 
+/// Warn about proposal front matter that cannot be read into typed metadata.
+///
+/// Bad metadata never fails a change; the affected fields are simply dropped.
+fn validate_proposal_front_matter(
+    rep: &mut ReportBuilder,
+    change_repo: &(impl DomainChangeRepository + ?Sized),
+    change_id: &str,
+) {
+    let Ok(change) = change_repo.get(change_id) else {
+        return;
+    };
+    let Some(proposal) = change.proposal.as_deref() else {
+        return;
+    };
+    let report_path = format!("changes/{change_id}/proposal.md");
+    for message in crate::front_matter::proposal_metadata(proposal).warnings {
+        rep.push(warning(&report_path, message));
+    }
+}
+
 /// Returns true for built-in schemas that predate schema-driven `validation.yaml`.
 fn is_legacy_delta_schema(schema_name: &str) -> bool {
     schema_name == "spec-driven" || schema_name == "tdd"
@@ -344,6 +404,7 @@ fn validate_apply_required_artifacts(
         None => required_schema_artifact_ids(resolved),
     };
 
+    let skipped = skipped_artifacts(&change_dir, &resolved.schema);
     for id in required_ids {
         let Some(a) = resolved.schema.artifacts.iter().find(|a| a.id == id) else {
             rep.push(error(
@@ -352,7 +413,7 @@ fn validate_apply_required_artifacts(
             ));
             continue;
         };
-        if artifact_done(&change_dir, &a.generates) {
+        if artifact_done(&change_dir, &a.generates) || skipped.contains_key(&id) {
             continue;
         }
         rep.push(warning(
@@ -395,6 +456,7 @@ fn validate_change_against_schema_validation(
         .unwrap_or(ValidationLevelYaml::Warning)
         .as_level_str();
 
+    let skipped = skipped_artifacts(&change_dir, &resolved.schema);
     for (artifact_id, cfg) in &validation.artifacts {
         let Some(schema_artifact) = resolved
             .schema
@@ -410,7 +472,7 @@ fn validate_change_against_schema_validation(
         };
 
         let present = artifact_done(&change_dir, &schema_artifact.generates);
-        if cfg.required && !present {
+        if cfg.required && !present && !skipped.contains_key(artifact_id) {
             rep.push(issue(
                 missing_level,
                 format!("artifacts.{artifact_id}"),
@@ -522,7 +584,7 @@ fn validate_change_against_schema_validation(
                     .schema
                     .apply
                     .as_ref()
-                    .and_then(|a| a.tracks.as_deref());
+                    .and_then(|a| a.primary_tracking_file());
 
                 let Some(tracks_rel) = tracks_rel else {
                     if tracking.required {
@@ -685,6 +747,7 @@ fn validate_tasks_tracking_path(
                 column: None,
                 rule_id: None,
                 metadata: None,
+                fix: None,
             },
             TASKS_TRACKING_V1,
         ));
@@ -950,6 +1013,10 @@ pub fn validate_module(
         ));
     }
 
+    for issue in fix::module_checklist_issues(ito_path, &r.module_md, &md) {
+        rep.push(issue);
+    }
+
     // Validate sub-modules.
     validate_sub_modules_under_module(&mut rep, module_repo, &r.module_dir, &r.id, strict);
 
@@ -1142,7 +1209,7 @@ pub fn validate_tasks_file(
             .schema
             .apply
             .as_ref()
-            .and_then(|a| a.tracks.as_deref())
+            .and_then(|a| a.primary_tracking_file())
         {
             tracking_file = tracks.to_string();
         }
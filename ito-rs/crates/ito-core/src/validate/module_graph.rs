@@ -0,0 +1,227 @@
+//! Module dependency graph checks.
+//!
+//! Modules declare the modules they build on in a `## Depends On` section of
+//! `module.md` (one bullet per module id, optionally followed by a note):
+//!
+//! ```markdown
+//! ## Depends On
+//! - 001 (workflow-enhancements - for instruction artifacts)
+//! - 004_ui-tweaks
+//! ```
+//!
+//! `## Dependencies` is accepted as an alias for the same section.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use ito_common::fs::StdFs;
+use ito_common::id;
+use ito_common::paths;
+use ito_domain::discovery;
+
+use super::{ValidationIssue, ValidationReport, error, extract_section, warning};
+
+/// One module in the dependency graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleGraphNode {
+    /// 3-digit module id.
+    pub id: String,
+    /// Directory name under `.ito/modules/` (e.g. `001_workflow-enhancements`).
+    pub full_name: String,
+    /// Canonical ids of the modules this module depends on, in declaration order.
+    pub depends_on: Vec<String>,
+}
+
+/// Module dependency graph parsed from every `module.md`.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleGraph {
+    /// Modules sorted by id.
+    pub nodes: Vec<ModuleGraphNode>,
+    /// Parse problems found while loading (unreadable files, bad entries).
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ModuleGraph {
+    /// Look up a module by its 3-digit id.
+    pub fn node(&self, id: &str) -> Option<&ModuleGraphNode> {
+        self.nodes.iter().find(|n| n.id == id)
+    }
+}
+
+/// Load the module dependency graph from `{ito_path}/modules`.
+pub fn load_module_graph(ito_path: &Path) -> ModuleGraph {
+    let mut graph = ModuleGraph::default();
+    let dir_names = match discovery::list_module_dir_names(&StdFs, ito_path) {
+        Ok(names) => names,
+        Err(err) => {
+            graph
+                .issues
+                .push(error("modules", format!("Failed to list modules: {err}")));
+            return graph;
+        }
+    };
+
+    for full_name in dir_names {
+        let Ok(parsed) = id::parse_module_id(&full_name) else {
+            continue;
+        };
+        let module_md = paths::modules_dir(ito_path)
+            .join(&full_name)
+            .join("module.md");
+        let markdown = ito_common::io::read_to_string_std(&module_md).unwrap_or_default();
+        let (depends_on, issues) = parse_module_dependencies(&full_name, &markdown);
+        graph.issues.extend(issues);
+        graph.nodes.push(ModuleGraphNode {
+            id: parsed.module_id.to_string(),
+            full_name,
+            depends_on,
+        });
+    }
+
+    graph.nodes.sort_by(|a, b| a.id.cmp(&b.id));
+    graph
+}
+
+/// Parse the `## Depends On` section of a module's `module.md`.
+///
+/// Returns canonical module ids plus warnings for entries that do not start
+/// with a module id.
+pub fn parse_module_dependencies(
+    full_name: &str,
+    markdown: &str,
+) -> (Vec<String>, Vec<ValidationIssue>) {
+    let mut section = extract_section(markdown, "Depends On");
+    if section.trim().is_empty() {
+        section = extract_section(markdown, "Dependencies");
+    }
+
+    let mut ids = Vec::new();
+    let mut issues = Vec::new();
+    for line in section.lines() {
+        let line = line.trim();
+        let Some(entry) = line
+            .strip_prefix("- ")
+            .or_else(|| line.strip_prefix("* "))
+            .map(str::trim)
+        else {
+            continue;
+        };
+        let token = entry
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches([',', ';', ':']);
+        match id::parse_module_id(token) {
+            Ok(parsed) => {
+                let dep = parsed.module_id.to_string();
+                if !ids.contains(&dep) {
+                    ids.push(dep);
+                }
+            }
+            Err(_) => issues.push(warning(
+                format!("modules/{full_name}/depends_on"),
+                format!("Dependency entry '{entry}' does not start with a module id"),
+            )),
+        }
+    }
+
+    (ids, issues)
+}
+
+/// Validate that module dependencies exist and form a DAG.
+///
+/// Reports an error for each reference to a module that does not exist and for
+/// each dependency cycle (with the cycle path), and a warning for modules that
+/// list themselves.
+pub fn validate_module_graph(ito_path: &Path) -> ValidationReport {
+    let graph = load_module_graph(ito_path);
+    ValidationReport::new(module_graph_issues(&graph), false)
+}
+
+/// Compute dependency issues for an already loaded graph.
+pub fn module_graph_issues(graph: &ModuleGraph) -> Vec<ValidationIssue> {
+    let mut issues = graph.issues.clone();
+    let known: BTreeSet<&str> = graph.nodes.iter().map(|n| n.id.as_str()).collect();
+
+    let mut edges: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for node in &graph.nodes {
+        let path = format!("modules/{}/depends_on", node.full_name);
+        let targets = edges.entry(node.id.as_str()).or_default();
+        for dep in &node.depends_on {
+            if dep == &node.id {
+                issues.push(warning(
+                    path.clone(),
+                    format!("Module '{}' lists itself as a dependency", node.full_name),
+                ));
+            } else if !known.contains(dep.as_str()) {
+                issues.push(error(
+                    path.clone(),
+                    format!(
+                        "Module '{}' depends on missing module '{dep}'",
+                        node.full_name
+                    ),
+                ));
+            } else {
+                targets.push(dep.as_str());
+            }
+        }
+    }
+
+    for cycle in find_cycles(&edges) {
+        issues.push(error(
+            "modules/depends_on",
+            format!("Module dependency cycle: {}", cycle.join(" -> ")),
+        ));
+    }
+
+    issues
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mark {
+    Unvisited,
+    InProgress,
+    Done,
+}
+
+/// Find dependency cycles with a depth-first search.
+///
+/// Each cycle is reported once, as a path that starts and ends at the same
+/// module (e.g. `["001", "002", "003", "001"]`).
+fn find_cycles<'a>(edges: &BTreeMap<&'a str, Vec<&'a str>>) -> Vec<Vec<String>> {
+    fn visit<'a>(
+        node: &'a str,
+        edges: &BTreeMap<&'a str, Vec<&'a str>>,
+        marks: &mut BTreeMap<&'a str, Mark>,
+        stack: &mut Vec<&'a str>,
+        cycles: &mut Vec<Vec<String>>,
+    ) {
+        marks.insert(node, Mark::InProgress);
+        stack.push(node);
+        for &next in edges.get(node).map(Vec::as_slice).unwrap_or_default() {
+            match marks.get(next).copied().unwrap_or(Mark::Unvisited) {
+                Mark::Unvisited => visit(next, edges, marks, stack, cycles),
+                Mark::InProgress => {
+                    let start = stack.iter().position(|n| *n == next).unwrap_or(0);
+                    let mut cycle: Vec<String> =
+                        stack[start..].iter().map(|n| n.to_string()).collect();
+                    cycle.push(next.to_string());
+                    cycles.push(cycle);
+                }
+                Mark::Done => {}
+            }
+        }
+        stack.pop();
+        marks.insert(node, Mark::Done);
+    }
+
+    let mut marks = BTreeMap::new();
+    let mut cycles = Vec::new();
+    for &node in edges.keys() {
+        if marks.get(node).copied().unwrap_or(Mark::Unvisited) == Mark::Unvisited {
+            let mut stack = Vec::new();
+            visit(node, edges, &mut marks, &mut stack, &mut cycles);
+        }
+    }
+    cycles
+}
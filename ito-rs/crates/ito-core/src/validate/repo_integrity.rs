@@ -5,9 +5,10 @@
 
 use crate::error_bridge::IntoCoreResult;
 use crate::errors::{CoreError, CoreResult};
-use crate::validate::{ValidationIssue, error};
+use crate::validate::{FixAction, ValidationIssue, error, warning, with_fix};
 use ito_common::fs::StdFs;
 use ito_common::id;
+use ito_common::paths;
 use ito_domain::discovery;
 use rusqlite::Connection;
 use std::collections::{BTreeMap, BTreeSet};
@@ -66,6 +67,23 @@ CREATE TABLE change_dir (
     for dir_name in &change_dirs {
         match id::parse_change_id(dir_name) {
             Ok(p) => {
+                if let Err(e) = id::parse_change_id_strict(dir_name) {
+                    let canonical = p.canonical();
+                    let changes_dir = paths::changes_dir(ito_path);
+                    by_dir.entry(dir_name.clone()).or_default().push(with_fix(
+                        warning(
+                            "id",
+                            format!(
+                                "Change directory name '{dir_name}' is not canonical: {} (rename to '{canonical}')",
+                                e.error
+                            ),
+                        ),
+                        FixAction::RenamePath {
+                            from: changes_dir.join(dir_name),
+                            to: changes_dir.join(&canonical),
+                        },
+                    ));
+                }
                 let numeric_id = format!("{}-{}", p.module_id, p.change_num);
                 let slug = p.name;
                 sqlite(conn.execute(
@@ -9,6 +9,7 @@ fn issue(level: &str, path: &str, message: &str) -> ValidationIssue {
         column: None,
         rule_id: None,
         metadata: None,
+        fix: None,
     }
 }
 
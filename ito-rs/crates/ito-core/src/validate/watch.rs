@@ -0,0 +1,142 @@
+//! Re-run loop and issue diffing behind `ito validate --watch`.
+//!
+//! The caller supplies how to validate and how to report; this module owns
+//! the ordering (one run up front, one per [`WatchEvent::Changed`]) and the
+//! comparison between consecutive runs.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::watch::{WatchEvent, WatchSource};
+
+/// One issue reported by a validation run, tagged with the item it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunIssue {
+    /// Validated item id (change, spec, or module); empty for run-level failures.
+    pub item: String,
+    /// Issue severity (`ERROR`, `WARNING`, `INFO`).
+    pub level: String,
+    /// Logical path within the item.
+    pub path: String,
+    /// Human-readable message.
+    pub message: String,
+    /// Optional 1-based line number.
+    pub line: Option<u32>,
+}
+
+impl RunIssue {
+    /// Identity used to match issues across runs.
+    ///
+    /// Line numbers are left out so editing above an issue does not report it
+    /// as resolved and new again.
+    fn key(&self) -> (&str, &str, &str, &str) {
+        (&self.item, &self.level, &self.path, &self.message)
+    }
+}
+
+/// Outcome of one validation run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationRun {
+    /// Whether the run passed at the requested strictness.
+    pub valid: bool,
+    /// Number of items validated.
+    pub items: usize,
+    /// Every issue reported by the run.
+    pub issues: Vec<RunIssue>,
+}
+
+/// Difference between two consecutive [`ValidationRun`]s.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationDelta {
+    /// Issues present now but not in the previous run.
+    pub new: Vec<RunIssue>,
+    /// Issues from the previous run that are gone.
+    pub resolved: Vec<RunIssue>,
+    /// Number of issues present in both runs.
+    pub unchanged: usize,
+}
+
+/// Compare `current` with `previous` (`None` for the first run).
+///
+/// Issues are matched as a multiset, so a duplicated issue that loses one
+/// copy is reported as resolved once.
+pub fn diff_runs(previous: Option<&ValidationRun>, current: &ValidationRun) -> ValidationDelta {
+    let mut remaining: BTreeMap<(&str, &str, &str, &str), usize> = BTreeMap::new();
+    for issue in previous.map(|p| p.issues.as_slice()).unwrap_or_default() {
+        *remaining.entry(issue.key()).or_default() += 1;
+    }
+
+    let mut delta = ValidationDelta::default();
+    for issue in &current.issues {
+        match remaining.get_mut(&issue.key()) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                delta.unchanged += 1;
+            }
+            _ => delta.new.push(issue.clone()),
+        }
+    }
+    for issue in previous.map(|p| p.issues.as_slice()).unwrap_or_default() {
+        if let Some(count) = remaining.get_mut(&issue.key())
+            && *count > 0
+        {
+            *count -= 1;
+            delta.resolved.push(issue.clone());
+        }
+    }
+    delta
+}
+
+/// Why [`watch_validation`] returned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatchOutcome {
+    /// The source was stopped (for example by Ctrl-C) or closed.
+    Stopped,
+    /// A watched root was removed.
+    RootRemoved(PathBuf),
+}
+
+/// Final state of a watch session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchSummary {
+    /// Why watching ended.
+    pub outcome: WatchOutcome,
+    /// Number of validation runs, including the initial one.
+    pub runs: usize,
+    /// The most recent run; its `valid` flag decides the exit code.
+    pub last: ValidationRun,
+}
+
+/// Validate once, then again after every change reported by `source`.
+///
+/// `report` receives the paths that triggered the run (empty for the first
+/// run), the run itself, and its delta against the previous run.
+pub fn watch_validation<S, V, R>(source: &mut S, mut validate: V, mut report: R) -> WatchSummary
+where
+    S: WatchSource + ?Sized,
+    V: FnMut() -> ValidationRun,
+    R: FnMut(&[PathBuf], &ValidationRun, &ValidationDelta),
+{
+    let mut last = validate();
+    report(&[], &last, &diff_runs(None, &last));
+    let mut runs = 1;
+
+    let outcome = loop {
+        match source.next_event() {
+            Some(WatchEvent::Changed(paths)) => {
+                let run = validate();
+                report(&paths, &run, &diff_runs(Some(&last), &run));
+                last = run;
+                runs += 1;
+            }
+            Some(WatchEvent::RootRemoved(root)) => break WatchOutcome::RootRemoved(root),
+            None => break WatchOutcome::Stopped,
+        }
+    };
+
+    WatchSummary {
+        outcome,
+        runs,
+        last,
+    }
+}
@@ -18,6 +18,7 @@ impl ProcessRunner for NoopRunner {
             stdout: String::new(),
             stderr: String::new(),
             timed_out: false,
+            resources: Default::default(),
         })
     }
     fn run_with_timeout(
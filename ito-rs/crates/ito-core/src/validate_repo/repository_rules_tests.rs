@@ -22,6 +22,7 @@ fn ok_output(success: bool, exit_code: i32) -> ProcessOutput {
         stdout: String::new(),
         stderr: String::new(),
         timed_out: false,
+        resources: Default::default(),
     }
 }
 
@@ -19,6 +19,7 @@ impl FakeRunner {
                 stdout: stdout.to_string(),
                 stderr: String::new(),
                 timed_out: false,
+                resources: Default::default(),
             }),
             seen: RefCell::new(Vec::new()),
         }
@@ -32,6 +33,7 @@ impl FakeRunner {
                 stdout: String::new(),
                 stderr: stderr.to_string(),
                 timed_out: false,
+                resources: Default::default(),
             }),
             seen: RefCell::new(Vec::new()),
         }
@@ -43,6 +43,7 @@ impl ProcessRunner for CannedRunner {
             stdout: self.stdout.clone(),
             stderr: String::new(),
             timed_out: false,
+            resources: Default::default(),
         })
     }
     fn run_with_timeout(
@@ -0,0 +1,252 @@
+//! Debounced filesystem watching for `--watch` style commands.
+//!
+//! [`FsWatchSource`] wraps a recursive `notify` watcher and turns bursts of
+//! raw events into one [`WatchEvent`] per quiet period. Paths matching the
+//! [`IgnoreGlobs`] (`.state/` and editor temp files by default) never wake the
+//! caller. Consumers are written against the [`WatchSource`] trait so their
+//! re-run logic can be driven by scripted events in tests.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::errors::{CoreError, CoreResult};
+
+/// Quiet period that ends a burst of events (editor saves, checkouts).
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Globs ignored unless the caller replaces them: Ito's own state directory
+/// plus swap, backup, and lock files written by common editors.
+pub const DEFAULT_IGNORE_GLOBS: &[&str] = &[
+    ".state", "*.swp", "*.swx", "*~", ".#*", "#*#", "4913", "*.tmp",
+];
+
+/// How often a blocked [`FsWatchSource`] re-checks its stop flag and roots.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+type EventReceiver = Receiver<notify::Result<notify::Event>>;
+
+/// One debounced notification from a [`WatchSource`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatchEvent {
+    /// Files under a watched root changed. Paths are sorted and deduplicated.
+    Changed(Vec<PathBuf>),
+    /// A watched root no longer exists; watching cannot continue.
+    RootRemoved(PathBuf),
+}
+
+/// A blocking stream of [`WatchEvent`]s.
+pub trait WatchSource {
+    /// Wait for the next event.
+    ///
+    /// Returns `None` once the source has been stopped or closed.
+    fn next_event(&mut self) -> Option<WatchEvent>;
+}
+
+/// Glob patterns for paths that should not trigger a re-run.
+///
+/// Each glob is matched against the path relative to its watched root and
+/// against every component of that path, so `.state` ignores the whole
+/// directory and `*.swp` ignores swap files at any depth.
+#[derive(Debug, Clone, Default)]
+pub struct IgnoreGlobs {
+    patterns: Vec<glob::Pattern>,
+}
+
+impl IgnoreGlobs {
+    /// Compile `globs` as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error naming the first invalid glob.
+    pub fn new<I, S>(globs: I) -> CoreResult<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut patterns = Vec::new();
+        for raw in globs {
+            let raw = raw.as_ref();
+            let pattern = glob::Pattern::new(raw)
+                .map_err(|e| CoreError::validation(format!("Invalid ignore glob '{raw}': {e}")))?;
+            patterns.push(pattern);
+        }
+        Ok(Self { patterns })
+    }
+
+    /// Compile [`DEFAULT_IGNORE_GLOBS`] followed by `extra`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error naming the first invalid glob.
+    pub fn with_defaults<I, S>(extra: I) -> CoreResult<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut globs: Vec<String> = DEFAULT_IGNORE_GLOBS
+            .iter()
+            .map(|g| (*g).to_string())
+            .collect();
+        globs.extend(extra.into_iter().map(|g| g.as_ref().to_string()));
+        Self::new(globs)
+    }
+
+    /// Whether `relative` (a path inside a watched root) is ignored.
+    pub fn is_ignored(&self, relative: &Path) -> bool {
+        self.patterns.iter().any(|pattern| {
+            pattern.matches_path(relative)
+                || relative
+                    .components()
+                    .any(|c| pattern.matches(&c.as_os_str().to_string_lossy()))
+        })
+    }
+}
+
+/// Stops a [`FsWatchSource`] from another thread (for example a Ctrl-C handler).
+#[derive(Debug, Clone)]
+pub struct StopHandle(Arc<AtomicBool>);
+
+impl StopHandle {
+    /// Ask the source to stop; its next [`WatchSource::next_event`] returns `None`.
+    pub fn stop(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether [`StopHandle::stop`] has been called.
+    pub fn is_stopped(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// [`WatchSource`] backed by the platform's recursive filesystem watcher.
+pub struct FsWatchSource {
+    _watcher: RecommendedWatcher,
+    events: EventReceiver,
+    roots: Vec<PathBuf>,
+    ignore: IgnoreGlobs,
+    debounce: Duration,
+    stop: Arc<AtomicBool>,
+}
+
+impl FsWatchSource {
+    /// Start watching every root recursively.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when no roots are given, a root is missing, or the
+    /// platform watcher cannot be created.
+    pub fn new(roots: Vec<PathBuf>, ignore: IgnoreGlobs, debounce: Duration) -> CoreResult<Self> {
+        if roots.is_empty() {
+            return Err(CoreError::validation("Nothing to watch"));
+        }
+        let (tx, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx)
+            .map_err(|e| CoreError::process(format!("creating filesystem watcher: {e}")))?;
+        for root in &roots {
+            if !root.is_dir() {
+                return Err(CoreError::not_found(format!(
+                    "Cannot watch {}: directory not found",
+                    root.display()
+                )));
+            }
+            watcher
+                .watch(root, RecursiveMode::Recursive)
+                .map_err(|e| CoreError::process(format!("watching {}: {e}", root.display())))?;
+        }
+
+        Ok(Self {
+            _watcher: watcher,
+            events,
+            roots,
+            ignore,
+            debounce,
+            stop: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    /// Handle that makes this source return `None` from its next poll.
+    pub fn stop_handle(&self) -> StopHandle {
+        StopHandle(Arc::clone(&self.stop))
+    }
+
+    fn stopped(&self) -> bool {
+        self.stop.load(Ordering::SeqCst)
+    }
+
+    fn missing_root(&self) -> Option<PathBuf> {
+        self.roots.iter().find(|root| !root.is_dir()).cloned()
+    }
+
+    fn collect(&self, event: notify::Result<notify::Event>, paths: &mut Vec<PathBuf>) {
+        let event = match event {
+            Ok(event) => event,
+            Err(e) => {
+                tracing::debug!("watch error: {e}");
+                return;
+            }
+        };
+        // Reads (including our own re-runs) must not retrigger anything.
+        if event.kind.is_access() {
+            return;
+        }
+        for path in event.paths {
+            if !self.is_ignored(&path) {
+                paths.push(path);
+            }
+        }
+    }
+
+    fn is_ignored(&self, path: &Path) -> bool {
+        let relative = self
+            .roots
+            .iter()
+            .find_map(|root| path.strip_prefix(root).ok())
+            .unwrap_or(path);
+        self.ignore.is_ignored(relative)
+    }
+}
+
+impl WatchSource for FsWatchSource {
+    fn next_event(&mut self) -> Option<WatchEvent> {
+        let mut paths: Vec<PathBuf> = Vec::new();
+        while paths.is_empty() {
+            if self.stopped() {
+                return None;
+            }
+            if let Some(root) = self.missing_root() {
+                return Some(WatchEvent::RootRemoved(root));
+            }
+            match self.events.recv_timeout(POLL_INTERVAL) {
+                Ok(event) => self.collect(event, &mut paths),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return None,
+            }
+        }
+
+        loop {
+            if self.stopped() {
+                return None;
+            }
+            match self.events.recv_timeout(self.debounce) {
+                Ok(event) => self.collect(event, &mut paths),
+                Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+
+        if let Some(root) = self.missing_root() {
+            return Some(WatchEvent::RootRemoved(root));
+        }
+        paths.sort();
+        paths.dedup();
+        Some(WatchEvent::Changed(paths))
+    }
+}
+
+#[cfg(test)]
+#[path = "watch_tests.rs"]
+mod watch_tests;
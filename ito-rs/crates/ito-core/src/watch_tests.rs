@@ -0,0 +1,32 @@
+use super::*;
+
+#[test]
+fn default_ignores_cover_state_dir_and_editor_files() {
+    let ignore = IgnoreGlobs::with_defaults(Vec::<String>::new()).unwrap();
+
+    assert!(ignore.is_ignored(Path::new(".state/audit/events.jsonl")));
+    assert!(ignore.is_ignored(Path::new("changes/001-01_a/.proposal.md.swp")));
+    assert!(ignore.is_ignored(Path::new("specs/auth/spec.md~")));
+    assert!(ignore.is_ignored(Path::new("specs/auth/.#spec.md")));
+    assert!(ignore.is_ignored(Path::new("specs/auth/4913")));
+    assert!(!ignore.is_ignored(Path::new("specs/auth/spec.md")));
+    assert!(!ignore.is_ignored(Path::new("changes/001-01_a/tasks.md")));
+}
+
+#[test]
+fn extra_ignores_match_relative_paths() {
+    let ignore = IgnoreGlobs::with_defaults(["changes/archive/**"]).unwrap();
+
+    assert!(ignore.is_ignored(Path::new("changes/archive/2026-01-01-a/proposal.md")));
+    assert!(!ignore.is_ignored(Path::new("changes/001-01_a/proposal.md")));
+}
+
+#[test]
+fn invalid_ignore_glob_is_a_validation_error() {
+    let err = IgnoreGlobs::new(["[unclosed"]).unwrap_err();
+
+    let CoreError::Validation(msg) = err else {
+        panic!("expected a validation error, got {err:?}");
+    };
+    assert!(msg.contains("[unclosed"), "{msg}");
+}
@@ -0,0 +1,391 @@
+//! Non-interactive change worktree creation.
+//!
+//! `create_worktree` backs `ito worktree create <change-id>`. Unlike
+//! [`crate::worktree_ensure::ensure_worktree`] it does not run readiness gates
+//! or Worktrunk; it adds a plain `git worktree` for the change under the
+//! configured layout directory, copies `worktrees.apply.copy_from_main` files
+//! from the main worktree, and records the change → branch/path association in
+//! `.ito/.state/worktrees.json`.
+//!
+//! Because the worktree is registered with git, [`crate::audit::find_worktree_for_branch`]
+//! and worktree event aggregation discover it without further setup.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use ito_config::types::{ItoConfig, WorktreeInitConfig};
+use ito_domain::changes::{ChangeRepository, ChangeTargetResolution, target_match_ids};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{CoreError, CoreResult};
+use crate::process::{ProcessRequest, ProcessRunner, SystemProcessRunner};
+use crate::repo_paths::{ResolvedEnv, ResolvedWorktreePaths, WorktreeFeature, WorktreeSelector};
+use crate::worktree_init;
+
+/// Association file relative to the Ito directory.
+pub const WORKTREE_RECORDS_REL: &str = ".state/worktrees.json";
+
+const WORKTREE_RECORDS_VERSION: u32 = 1;
+
+/// Options for [`create_worktree`].
+#[derive(Debug, Clone, Default)]
+pub struct WorktreeCreateOptions {
+    /// Branch to create; defaults to `ito/<change-id>`.
+    pub branch: Option<String>,
+    /// Check out an existing branch instead of creating a new one.
+    pub existing_branch: bool,
+}
+
+/// Result of a successful [`create_worktree`] call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CreatedWorktree {
+    /// Canonical change id the worktree was created for.
+    pub change_id: String,
+    /// Branch checked out in the new worktree.
+    pub branch: String,
+    /// Absolute path of the new worktree.
+    pub path: PathBuf,
+    /// Files copied from the main worktree, relative to its root.
+    pub copied_files: Vec<PathBuf>,
+}
+
+/// One recorded change worktree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorktreeRecord {
+    /// Branch checked out in the worktree.
+    pub branch: String,
+    /// Absolute worktree path.
+    pub path: PathBuf,
+}
+
+/// Change worktrees created by `ito worktree create`, keyed by change id.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorktreeRecords {
+    /// File format version.
+    pub version: u32,
+    /// Change id to worktree record.
+    #[serde(default)]
+    pub worktrees: BTreeMap<String, WorktreeRecord>,
+}
+
+impl Default for WorktreeRecords {
+    fn default() -> Self {
+        Self {
+            version: WORKTREE_RECORDS_VERSION,
+            worktrees: BTreeMap::new(),
+        }
+    }
+}
+
+/// Default branch name for a change worktree.
+pub fn default_worktree_branch(change_id: &str) -> String {
+    format!("ito/{change_id}")
+}
+
+/// Load recorded change worktrees, returning an empty set when the file is
+/// missing or unreadable.
+pub fn load_worktree_records(ito_path: &Path) -> WorktreeRecords {
+    let Ok(contents) = std::fs::read_to_string(ito_path.join(WORKTREE_RECORDS_REL)) else {
+        return WorktreeRecords::default();
+    };
+    serde_json::from_str(&contents).unwrap_or_default()
+}
+
+/// Recorded worktree for `change_id`, if `ito worktree create` made one.
+pub fn recorded_worktree_for_change(ito_path: &Path, change_id: &str) -> Option<WorktreeRecord> {
+    load_worktree_records(ito_path).worktrees.remove(change_id)
+}
+
+/// Create a git worktree for a change.
+///
+/// # Errors
+///
+/// Fails when worktrees are disabled, the change cannot be resolved, the
+/// branch already exists (unless `existing_branch` is set) or is missing
+/// (when it is), the target directory is non-empty, or git fails.
+pub fn create_worktree(
+    change_repo: &(impl ChangeRepository + ?Sized),
+    change: &str,
+    options: &WorktreeCreateOptions,
+    config: &ItoConfig,
+    env: &ResolvedEnv,
+    worktree_paths: &ResolvedWorktreePaths,
+) -> CoreResult<CreatedWorktree> {
+    create_worktree_with_runner(
+        &SystemProcessRunner,
+        change_repo,
+        change,
+        options,
+        config,
+        env,
+        worktree_paths,
+    )
+}
+
+/// Testable inner implementation of [`create_worktree`].
+pub(crate) fn create_worktree_with_runner(
+    runner: &dyn ProcessRunner,
+    change_repo: &(impl ChangeRepository + ?Sized),
+    change: &str,
+    options: &WorktreeCreateOptions,
+    config: &ItoConfig,
+    env: &ResolvedEnv,
+    worktree_paths: &ResolvedWorktreePaths,
+) -> CoreResult<CreatedWorktree> {
+    let WorktreeFeature::Enabled = worktree_paths.feature else {
+        return Err(CoreError::validation(
+            "Worktrees are disabled (worktrees.enabled = false).\n\
+             Fix: run `ito config set worktrees.enabled true` or set it in .ito/config.json.",
+        ));
+    };
+
+    let change_id = resolve_change_id(change_repo, change)?;
+    let branch = options
+        .branch
+        .clone()
+        .unwrap_or_else(|| default_worktree_branch(&change_id));
+    validate_branch_name(runner, &env.project_root, &branch)?;
+
+    let worktree_path = worktree_paths
+        .path_for_selector(&WorktreeSelector::Change(change_id.clone()))
+        .ok_or_else(|| {
+            CoreError::validation(format!(
+                "Cannot resolve worktree path for change '{change_id}'.\n\
+                 Fix: check 'worktrees.strategy' and 'worktrees.layout' in .ito/config.json.",
+            ))
+        })?;
+    if !dir_is_empty_or_missing(&worktree_path)? {
+        return Err(CoreError::validation(format!(
+            "Target directory '{}' already exists and is not empty.\n\
+             Fix: remove it or run `ito worktree ensure --change {change_id}` to reuse an existing worktree.",
+            worktree_path.display(),
+        )));
+    }
+
+    let branch_exists = local_branch_exists(runner, &env.project_root, &branch)?;
+    if branch_exists && !options.existing_branch {
+        return Err(CoreError::validation(format!(
+            "Branch '{branch}' already exists.\n\
+             Fix: pass --existing-branch to check it out in the new worktree, or choose another name with --branch.",
+        )));
+    }
+    if !branch_exists && options.existing_branch {
+        return Err(CoreError::validation(format!(
+            "Branch '{branch}' does not exist, so --existing-branch cannot be used.\n\
+             Fix: drop --existing-branch to create it.",
+        )));
+    }
+
+    if let Some(parent) = worktree_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|err| {
+            CoreError::io(
+                format!(
+                    "Cannot create worktrees directory '{}'.\n\
+                     Fix: ensure the path is writable.",
+                    parent.display(),
+                ),
+                err,
+            )
+        })?;
+    }
+
+    let target = worktree_path.to_string_lossy().to_string();
+    let mut args: Vec<String> = vec!["worktree".into(), "add".into()];
+    if branch_exists {
+        args.extend([target.clone(), branch.clone()]);
+    } else {
+        let base = base_ref(runner, &env.project_root, &config.worktrees.default_branch)?;
+        args.extend(["-b".into(), branch.clone(), target.clone(), base]);
+    }
+    run_git(runner, &env.project_root, &args).map_err(|detail| {
+        CoreError::process(format!(
+            "Cannot create worktree for change '{change_id}' at '{target}'.\n\
+             git reported: {detail}",
+        ))
+    })?;
+
+    let finish = || -> CoreResult<Vec<PathBuf>> {
+        let source_root = worktree_paths
+            .main_worktree_root
+            .as_deref()
+            .unwrap_or(&env.project_root);
+        let copy_config = WorktreeInitConfig {
+            include: config.worktrees.apply.copy_from_main.clone(),
+            setup: None,
+        };
+        let copied = worktree_init::copy_include_files(&copy_config, source_root, &worktree_path)?;
+        record_worktree(
+            &env.ito_root,
+            &change_id,
+            WorktreeRecord {
+                branch: branch.clone(),
+                path: worktree_path.clone(),
+            },
+        )?;
+        Ok(copied)
+    };
+    let copied_files = match finish() {
+        Ok(copied) => copied,
+        Err(error) => {
+            rollback(runner, &env.project_root, &target, &branch, branch_exists);
+            return Err(error);
+        }
+    };
+
+    Ok(CreatedWorktree {
+        change_id,
+        branch,
+        path: worktree_path,
+        copied_files,
+    })
+}
+
+fn resolve_change_id(
+    change_repo: &(impl ChangeRepository + ?Sized),
+    input: &str,
+) -> CoreResult<String> {
+    match change_repo.resolve_target(input) {
+        ChangeTargetResolution::Unique(id) => Ok(id),
+        ChangeTargetResolution::Ambiguous(matches) => Err(CoreError::validation(format!(
+            "Change '{input}' is ambiguous. Matches: {}\n\
+             Fix: use a longer prefix or the full canonical change ID.",
+            target_match_ids(&matches).join(", ")
+        ))),
+        ChangeTargetResolution::NotFound => {
+            Err(CoreError::not_found(format!("Change '{input}' not found")))
+        }
+    }
+}
+
+fn validate_branch_name(
+    runner: &dyn ProcessRunner,
+    project_root: &Path,
+    branch: &str,
+) -> CoreResult<()> {
+    let valid = !branch.starts_with('-')
+        && run_git(
+            runner,
+            project_root,
+            &["check-ref-format".into(), "--branch".into(), branch.into()],
+        )
+        .is_ok();
+    if valid {
+        return Ok(());
+    }
+    Err(CoreError::validation(format!(
+        "Invalid branch name '{branch}'.\n\
+         Fix: choose a name accepted by `git check-ref-format --branch`.",
+    )))
+}
+
+fn dir_is_empty_or_missing(path: &Path) -> CoreResult<bool> {
+    match std::fs::read_dir(path) {
+        Ok(mut entries) => Ok(entries.next().is_none()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(true),
+        Err(err) => Err(CoreError::io(
+            format!("Cannot inspect target directory '{}'.", path.display()),
+            err,
+        )),
+    }
+}
+
+fn local_branch_exists(
+    runner: &dyn ProcessRunner,
+    project_root: &Path,
+    branch: &str,
+) -> CoreResult<bool> {
+    let project = project_root.to_string_lossy().to_string();
+    let branch_ref = format!("refs/heads/{branch}");
+    let output = runner
+        .run(&ProcessRequest::new("git").args([
+            "-C",
+            &project,
+            "show-ref",
+            "--verify",
+            "--quiet",
+            &branch_ref,
+        ]))
+        .map_err(|error| CoreError::process(format!("Cannot inspect branch: {error}")))?;
+    match output.exit_code {
+        0 => Ok(true),
+        1 => Ok(false),
+        _ => Err(CoreError::process(format!(
+            "Cannot inspect branch '{branch}': {}",
+            output.stderr.trim()
+        ))),
+    }
+}
+
+/// Base for a new branch: the configured default branch when it exists locally, else `HEAD`.
+fn base_ref(
+    runner: &dyn ProcessRunner,
+    project_root: &Path,
+    default_branch: &str,
+) -> CoreResult<String> {
+    if !default_branch.is_empty() && local_branch_exists(runner, project_root, default_branch)? {
+        return Ok(default_branch.to_string());
+    }
+    Ok("HEAD".to_string())
+}
+
+fn run_git(runner: &dyn ProcessRunner, project_root: &Path, args: &[String]) -> Result<(), String> {
+    let project = project_root.to_string_lossy().to_string();
+    let mut full = vec!["-C".to_string(), project];
+    full.extend(args.iter().cloned());
+    let output = runner
+        .run(&ProcessRequest::new("git").args(full))
+        .map_err(|err| err.to_string())?;
+    if output.success {
+        return Ok(());
+    }
+    let detail = if output.stderr.trim().is_empty() {
+        output.stdout.trim().to_string()
+    } else {
+        output.stderr.trim().to_string()
+    };
+    Err(detail)
+}
+
+/// Best-effort removal of a worktree (and branch, if we created it) after a
+/// failure following `git worktree add`.
+fn rollback(
+    runner: &dyn ProcessRunner,
+    project_root: &Path,
+    target: &str,
+    branch: &str,
+    branch_preexisted: bool,
+) {
+    let _ = run_git(
+        runner,
+        project_root,
+        &[
+            "worktree".into(),
+            "remove".into(),
+            "--force".into(),
+            target.into(),
+        ],
+    );
+    if !branch_preexisted {
+        let _ = run_git(
+            runner,
+            project_root,
+            &["branch".into(), "-D".into(), branch.into()],
+        );
+    }
+}
+
+fn record_worktree(ito_path: &Path, change_id: &str, record: WorktreeRecord) -> CoreResult<()> {
+    let mut records = load_worktree_records(ito_path);
+    records.worktrees.insert(change_id.to_string(), record);
+
+    let path = ito_path.join(WORKTREE_RECORDS_REL);
+    if let Some(parent) = path.parent() {
+        ito_common::io::create_dir_all_std(parent)
+            .map_err(|e| CoreError::io(format!("creating directory {}", parent.display()), e))?;
+    }
+    let mut contents = serde_json::to_string_pretty(&records)
+        .map_err(|e| CoreError::serde("serializing worktree records", e.to_string()))?;
+    contents.push('\n');
+    ito_common::io::write_std(&path, contents.as_bytes())
+        .map_err(|e| CoreError::io(format!("writing {}", path.display()), e))
+}
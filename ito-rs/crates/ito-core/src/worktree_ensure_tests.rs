@@ -119,6 +119,7 @@ fn ok_output() -> Result<ProcessOutput, ProcessExecutionError> {
         stdout: String::new(),
         stderr: String::new(),
         timed_out: false,
+        resources: Default::default(),
     })
 }
 
@@ -129,6 +130,7 @@ fn fail_output(stderr: &str) -> Result<ProcessOutput, ProcessExecutionError> {
         stdout: String::new(),
         stderr: stderr.to_string(),
         timed_out: false,
+        resources: Default::default(),
     })
 }
 
@@ -296,6 +298,7 @@ fn ensure_creates_worktree_when_absent() {
                 stdout: self.target_path.display().to_string(),
                 stderr: String::new(),
                 timed_out: false,
+                resources: Default::default(),
             })
         }
 
@@ -731,6 +734,7 @@ fn ensure_with_include_files_copies_them() {
                 stdout: String::new(),
                 stderr: String::new(),
                 timed_out: false,
+                resources: Default::default(),
             })
         }
 
@@ -50,6 +50,7 @@ fn ok_output() -> Result<ProcessOutput, ProcessExecutionError> {
         stdout: String::new(),
         stderr: String::new(),
         timed_out: false,
+        resources: Default::default(),
     })
 }
 
@@ -60,6 +61,7 @@ fn fail_output(stderr: &str) -> Result<ProcessOutput, ProcessExecutionError> {
         stdout: String::new(),
         stderr: stderr.to_string(),
         timed_out: false,
+        resources: Default::default(),
     })
 }
 
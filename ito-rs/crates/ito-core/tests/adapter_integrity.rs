@@ -0,0 +1,124 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use ito_config::ConfigContext;
+use ito_core::distribution::{ManifestIssue, ManifestIssueKind, verify_manifests};
+use ito_core::installers::{
+    InitOptions, InstallMode, TOOL_CLAUDE, install_default_templates, load_install_manifest,
+};
+
+const AUDIT_HOOK: &str = ".claude/hooks/ito-audit.sh";
+
+fn install(project: &Path, mode: InstallMode, opts: InitOptions) {
+    let ctx = ConfigContext {
+        project_dir: Some(project.to_path_buf()),
+        ..Default::default()
+    };
+    install_default_templates(project, &ctx, mode, &opts, None).expect("install should succeed");
+}
+
+fn claude_tools() -> BTreeSet<String> {
+    BTreeSet::from([TOOL_CLAUDE.to_string()])
+}
+
+fn init_project() -> tempfile::TempDir {
+    let td = tempfile::tempdir().expect("tempdir");
+    install(
+        td.path(),
+        InstallMode::Init,
+        InitOptions::new(claude_tools(), false, false),
+    );
+    td
+}
+
+fn update(project: &Path) {
+    install(
+        project,
+        InstallMode::Update,
+        InitOptions::new(claude_tools(), false, true),
+    );
+}
+
+fn issues(project: &Path) -> Vec<ManifestIssue> {
+    verify_manifests(project, &project.join(".ito"))
+}
+
+fn hook(project: &Path) -> String {
+    std::fs::read_to_string(project.join(AUDIT_HOOK)).expect("read hook")
+}
+
+#[test]
+fn init_records_adapter_hashes_and_verifies_clean() {
+    let td = init_project();
+    let manifest = load_install_manifest(&td.path().join(".ito")).expect("manifest");
+    assert!(manifest.adapters.contains_key(AUDIT_HOOK));
+    assert_eq!(issues(td.path()), Vec::new());
+}
+
+#[test]
+fn truncated_hook_is_reported_corrupted_and_repaired_on_update() {
+    let td = init_project();
+    let original = hook(td.path());
+    let half = &original.as_bytes()[..original.len() / 2];
+    std::fs::write(td.path().join(AUDIT_HOOK), half).unwrap();
+
+    assert_eq!(
+        issues(td.path()),
+        vec![ManifestIssue {
+            path: AUDIT_HOOK.to_string(),
+            kind: ManifestIssueKind::Corrupted,
+        }]
+    );
+
+    update(td.path());
+    assert_eq!(hook(td.path()), original);
+    assert_eq!(issues(td.path()), Vec::new());
+}
+
+#[test]
+fn missing_hook_is_reported_and_restored() {
+    let td = init_project();
+    std::fs::remove_file(td.path().join(AUDIT_HOOK)).unwrap();
+    assert_eq!(issues(td.path())[0].kind, ManifestIssueKind::Missing);
+
+    update(td.path());
+    assert!(td.path().join(AUDIT_HOOK).exists());
+}
+
+#[test]
+fn user_modified_adapter_is_reported_modified_and_refreshed_on_update() {
+    let td = init_project();
+    let original = hook(td.path());
+    let edited = format!("{original}\n# local tweak\n");
+    std::fs::write(td.path().join(AUDIT_HOOK), &edited).unwrap();
+
+    assert_eq!(issues(td.path())[0].kind, ManifestIssueKind::Modified);
+
+    update(td.path());
+    assert_eq!(hook(td.path()), original);
+    assert_eq!(issues(td.path()), Vec::new());
+}
+
+#[cfg(unix)]
+#[test]
+fn hook_executable_bit_is_verified_and_restored() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let td = init_project();
+    let path = td.path().join(AUDIT_HOOK);
+    let mode = |p: &Path| std::fs::metadata(p).unwrap().permissions().mode();
+    assert_ne!(mode(&path) & 0o111, 0, "hooks are installed executable");
+
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+    assert_eq!(
+        issues(td.path()),
+        vec![ManifestIssue {
+            path: AUDIT_HOOK.to_string(),
+            kind: ManifestIssueKind::NotExecutable,
+        }]
+    );
+
+    update(td.path());
+    assert_ne!(mode(&path) & 0o111, 0);
+    assert_eq!(issues(td.path()), Vec::new());
+}
@@ -0,0 +1,188 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Mutex;
+
+use ito_config::ConfigContext;
+use ito_core::artifact_run::{ArtifactRunOptions, build_artifact_prompt, run_artifact};
+use ito_core::audit::{AuditEvent, AuditWriter};
+use ito_core::harness::stub::{StubHarness, StubStep};
+
+#[derive(Default)]
+struct RecordingWriter {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+impl AuditWriter for RecordingWriter {
+    fn append(&self, event: &AuditEvent) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.events.lock().unwrap().push(event.clone());
+        Ok(())
+    }
+}
+
+fn setup(project_root: &Path) {
+    let schema_dir = project_root.join(".ito/templates/schemas/demo");
+    std::fs::create_dir_all(schema_dir.join("templates")).expect("create schema dirs");
+    std::fs::write(
+        schema_dir.join("schema.yaml"),
+        r#"name: demo
+version: 1
+artifacts:
+  - id: proposal
+    generates: proposal.md
+    template: proposal.md
+    requires: []
+  - id: design
+    generates: design.md
+    description: Technical design
+    template: design.md
+    instruction: Explain the chosen approach.
+    requires: ["proposal"]
+"#,
+    )
+    .expect("write schema.yaml");
+    std::fs::write(schema_dir.join("templates/proposal.md"), "## Why\n").expect("template");
+    std::fs::write(schema_dir.join("templates/design.md"), "## Decisions\n").expect("template");
+
+    let change_dir = project_root.join(".ito/changes/demo-change");
+    std::fs::create_dir_all(&change_dir).expect("create change dir");
+    std::fs::write(change_dir.join(".ito.yaml"), "schema: demo\n").expect("change meta");
+    std::fs::write(
+        change_dir.join("proposal.md"),
+        "## Why\nThe proposal body.\n",
+    )
+    .expect("proposal");
+    std::fs::create_dir_all(project_root.join(".ito/user-prompts")).expect("prompts dir");
+    std::fs::write(
+        project_root.join(".ito/user-prompts/design.md"),
+        "Prefer boring technology.\n",
+    )
+    .expect("guidance");
+}
+
+fn ctx_for(project_root: &Path) -> ConfigContext {
+    ConfigContext {
+        project_dir: Some(project_root.to_path_buf()),
+        ..Default::default()
+    }
+}
+
+fn step(exit_code: i32, writes: &[(&str, &str)]) -> StubStep {
+    StubStep {
+        stdout: "harness output\n".to_string(),
+        stderr: String::new(),
+        exit_code,
+        writes: writes
+            .iter()
+            .map(|(path, contents)| (path.to_string(), contents.to_string()))
+            .collect::<BTreeMap<_, _>>(),
+        delay_ms: 0,
+    }
+}
+
+#[test]
+fn build_artifact_prompt_inlines_dependencies_and_guidance() {
+    let td = tempfile::tempdir().expect("tempdir");
+    let project_root = td.path();
+    setup(project_root);
+    let ito_path = project_root.join(".ito");
+
+    let prompt = build_artifact_prompt(
+        &ito_path,
+        "demo-change",
+        "design",
+        &ArtifactRunOptions::default(),
+        &ctx_for(project_root),
+    )
+    .expect("prompt");
+
+    assert!(
+        prompt
+            .text
+            .contains("# Generate `design` for change `demo-change`")
+    );
+    assert!(prompt.text.contains("Explain the chosen approach."));
+    assert!(prompt.text.contains("## Decisions"));
+    assert!(prompt.text.contains("The proposal body."));
+    assert!(prompt.text.contains("Prefer boring technology."));
+    assert_eq!(prompt.output_path, "design.md");
+
+    let truncated = build_artifact_prompt(
+        &ito_path,
+        "demo-change",
+        "design",
+        &ArtifactRunOptions {
+            dependency_budget_chars: 6,
+            ..ArtifactRunOptions::default()
+        },
+        &ctx_for(project_root),
+    )
+    .expect("prompt");
+    assert!(!truncated.text.contains("The proposal body."));
+    assert!(truncated.text.contains("dependency budget exhausted"));
+}
+
+#[test]
+fn run_artifact_reports_produced_artifact_and_records_audit_event() {
+    let td = tempfile::tempdir().expect("tempdir");
+    let project_root = td.path();
+    setup(project_root);
+    let ito_path = project_root.join(".ito");
+    let audit = RecordingWriter::default();
+    let mut harness = StubHarness::new(vec![step(
+        0,
+        &[(
+            ".ito/changes/demo-change/design.md",
+            "## Decisions\nUse a stub.\n",
+        )],
+    )]);
+
+    let outcome = run_artifact(
+        &mut harness,
+        &ito_path,
+        "demo-change",
+        "design",
+        &ArtifactRunOptions::default(),
+        &ctx_for(project_root),
+        &audit,
+    )
+    .expect("run");
+
+    assert!(outcome.produced);
+    assert_eq!(outcome.result.exit_code, 0);
+    let events = audit.events.lock().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].entity_id, "demo-change");
+    assert_eq!(events[0].op, "artifact_run");
+    let meta = events[0].meta.as_ref().expect("meta");
+    assert_eq!(meta["artifact"], "design");
+    assert_eq!(meta["harness"], "stub");
+    assert_eq!(meta["produced"], true);
+}
+
+#[test]
+fn run_artifact_reports_missing_artifact_when_harness_writes_nothing() {
+    let td = tempfile::tempdir().expect("tempdir");
+    let project_root = td.path();
+    setup(project_root);
+    let ito_path = project_root.join(".ito");
+    let audit = RecordingWriter::default();
+    let mut harness = StubHarness::new(vec![step(1, &[])]);
+
+    let outcome = run_artifact(
+        &mut harness,
+        &ito_path,
+        "demo-change",
+        "design",
+        &ArtifactRunOptions::default(),
+        &ctx_for(project_root),
+        &audit,
+    )
+    .expect("run");
+
+    assert!(!outcome.produced);
+    assert_eq!(outcome.result.exit_code, 1);
+    assert_eq!(outcome.result.stdout, "harness output\n");
+    let events = audit.events.lock().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].meta.as_ref().expect("meta")["produced"], false);
+}
@@ -106,6 +106,7 @@ fn make_summary(id: &str, completed: u32, total: u32) -> ChangeSummary {
         has_specs: true,
         has_tasks: true,
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
     }
 }
 
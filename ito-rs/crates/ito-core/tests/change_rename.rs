@@ -0,0 +1,137 @@
+//! Integration tests for renaming a change.
+
+use std::fs;
+use std::path::Path;
+
+use ito_core::change_rename::{apply_change_rename, plan_change_rename};
+use ito_core::errors::CoreError;
+use ito_core::process::SystemProcessRunner;
+
+const OLD_ID: &str = "001-01_add-login";
+const NEW_ID: &str = "001-01_add-sign-in";
+const OTHER: &str = "001-02_add-logout";
+
+fn write(path: &Path, contents: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+fn fixture() -> tempfile::TempDir {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path();
+
+    write(
+        &ito.join("changes").join(OLD_ID).join("proposal.md"),
+        "## Why\nLogin\n",
+    );
+    write(
+        &ito.join("changes").join(OLD_ID).join("tasks.md"),
+        "- [ ] Add login form\n",
+    );
+    write(
+        &ito.join("changes").join(OTHER).join(".ito.yaml"),
+        "schema: spec-driven\ndepends_on:\n  - 001-01_add-login\n",
+    );
+    write(
+        &ito.join("changes").join(OTHER).join("proposal.md"),
+        "## Why\nLogout\n",
+    );
+    write(
+        &ito.join("modules/001_auth/module.md"),
+        "# Auth\n\n## Changes\n- [ ] 001-01_add-login\n- [ ] 001-02_add-logout\n",
+    );
+    write(
+        &ito.join(".state/ralph").join(OLD_ID).join("state.json"),
+        "{\"change_id\":\"001-01_add-login\",\"iteration\":2}\n",
+    );
+
+    td
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn rename_moves_change_and_updates_checklist_and_ralph_state() {
+    let td = fixture();
+    let ito = td.path();
+    let runner = SystemProcessRunner;
+
+    let plan = plan_change_rename(ito, &runner, OLD_ID, "add-sign-in").unwrap();
+    assert_eq!(plan.new_id, NEW_ID);
+    apply_change_rename(&plan, &runner, ito, false).unwrap();
+
+    assert!(!ito.join("changes").join(OLD_ID).exists());
+    assert_eq!(
+        read(&ito.join("changes").join(NEW_ID).join("tasks.md")),
+        "- [ ] Add login form\n"
+    );
+    assert_eq!(
+        read(&ito.join("modules/001_auth/module.md")),
+        "# Auth\n\n## Changes\n- [ ] 001-01_add-sign-in\n- [ ] 001-02_add-logout\n"
+    );
+    assert!(
+        read(&ito.join("changes").join(OTHER).join(".ito.yaml"))
+            .contains("  - 001-01_add-sign-in\n")
+    );
+    assert!(!ito.join(".state/ralph").join(OLD_ID).exists());
+    assert_eq!(
+        read(&ito.join(".state/ralph").join(NEW_ID).join("state.json")),
+        "{\"change_id\":\"001-01_add-sign-in\",\"iteration\":2}\n"
+    );
+}
+
+#[test]
+fn rename_accepts_full_id_in_same_module() {
+    let td = fixture();
+    let plan =
+        plan_change_rename(td.path(), &SystemProcessRunner, OLD_ID, "001-03_sign-in").unwrap();
+    assert_eq!(plan.new_id, "001-03_sign-in");
+
+    let err =
+        plan_change_rename(td.path(), &SystemProcessRunner, OLD_ID, "002-01_sign-in").unwrap_err();
+    assert!(matches!(err, CoreError::Validation(_)), "{err:?}");
+}
+
+#[test]
+fn rename_rejects_collisions() {
+    let td = fixture();
+    let ito = td.path();
+
+    let err =
+        plan_change_rename(ito, &SystemProcessRunner, OLD_ID, "001-02_add-logout").unwrap_err();
+    assert!(matches!(err, CoreError::Validation(_)), "{err:?}");
+
+    let err = plan_change_rename(ito, &SystemProcessRunner, OLD_ID, "001-02_other").unwrap_err();
+    assert!(err.to_string().contains("001-02"), "{err}");
+
+    write(
+        &ito.join("changes/archive/2026-01-01-001-01_add-sign-in/proposal.md"),
+        "## Why\n",
+    );
+    let err = plan_change_rename(ito, &SystemProcessRunner, OLD_ID, "add-sign-in").unwrap_err();
+    assert!(err.to_string().contains("archive"), "{err}");
+}
+
+#[test]
+fn plan_has_no_side_effects() {
+    let td = fixture();
+    let ito = td.path();
+
+    let plan = plan_change_rename(ito, &SystemProcessRunner, OLD_ID, "add-sign-in").unwrap();
+    assert_eq!(plan.moves.len(), 2);
+    let edited: Vec<_> = plan
+        .edits
+        .iter()
+        .map(|e| e.path.strip_prefix(ito).unwrap().to_path_buf())
+        .collect();
+    assert!(edited.contains(&Path::new("modules/001_auth/module.md").to_path_buf()));
+    assert!(
+        edited.contains(&Path::new(".state/ralph/001-01_add-sign-in/state.json").to_path_buf())
+    );
+
+    assert!(ito.join("changes").join(OLD_ID).is_dir());
+    assert!(!ito.join("changes").join(NEW_ID).exists());
+    assert!(read(&ito.join("modules/001_auth/module.md")).contains(OLD_ID));
+}
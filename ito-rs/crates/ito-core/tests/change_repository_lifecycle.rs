@@ -252,6 +252,14 @@ impl TaskMutationService for FakeTaskMutations {
         Err(TaskMutationError::validation("unused in test"))
     }
 
+    fn release_task(
+        &self,
+        _change_id: &str,
+        _task_id: &str,
+    ) -> TaskMutationServiceResult<TaskMutationResult> {
+        Err(TaskMutationError::validation("unused in test"))
+    }
+
     fn add_task(
         &self,
         _change_id: &str,
@@ -305,6 +313,7 @@ fn remote_runtime_ignores_local_change_dirs() {
         has_specs: true,
         has_tasks: true,
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
     };
     let repos = RepositorySet {
         changes: Arc::new(FakeRemoteChangeRepo::new(summary)),
@@ -48,6 +48,7 @@ impl BackendChangeReader for FakeReader {
                     specs: vec![],
                     tasks: TasksParseResult::empty(),
                     orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+                    metadata: ito_domain::changes::ChangeMetadata::default(),
                     last_modified: Utc::now(),
                 });
             }
@@ -72,6 +73,7 @@ fn make_summary(id: &str, module_id: Option<&str>) -> ChangeSummary {
         has_specs: true,
         has_tasks: true,
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
     }
 }
 
@@ -184,6 +186,7 @@ fn backend_resolve_lifecycle_filter_respected() {
         "001-01_alpha",
         ResolveTargetOptions {
             lifecycle: ChangeLifecycleFilter::Active,
+            exact: false,
         },
     );
     assert_eq!(
@@ -284,6 +287,7 @@ fn sqlite_resolve_archived_filter_returns_not_found() {
         "001-01_alpha",
         ResolveTargetOptions {
             lifecycle: ChangeLifecycleFilter::Archived,
+            exact: false,
         },
     );
     assert_eq!(result, ChangeTargetResolution::NotFound);
@@ -299,6 +303,7 @@ fn sqlite_resolve_all_filter_finds_active_changes() {
         "001-01_alpha",
         ResolveTargetOptions {
             lifecycle: ChangeLifecycleFilter::All,
+            exact: false,
         },
     );
     assert_eq!(
@@ -0,0 +1,178 @@
+//! Integration tests for splitting tasks out of a change.
+
+use std::fs;
+use std::path::Path;
+
+use ito_core::change_split::{ChangeSplitRequest, split_change};
+use ito_core::errors::CoreError;
+use ito_domain::tasks::{DiagnosticLevel, parse_tasks_tracking_file};
+
+const OLD_ID: &str = "001-01_add-login";
+const NEW_ID: &str = "001-02_session-store";
+
+fn write(path: &Path, contents: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap()
+}
+
+fn task(id: &str, name: &str, deps: &str, requirements: &str) -> String {
+    format!(
+        "### Task {id}: {name}\n\n- **Files**: `src/lib.rs`\n- **Dependencies**: {deps}\n- **Action**:\n  Do it\n- **Verify**: `cargo test`\n- **Done When**: Done\n- **Requirements**: {requirements}\n- **Updated At**: 2026-01-01\n- **Status**: [ ] pending\n\n"
+    )
+}
+
+/// Tasks 1.2 and 1.4 work on the `session` spec (1.4 depends on 1.2); 1.1
+/// and 1.3 share the `auth` spec; 1.5 depends on 1.1.
+fn fixture() -> tempfile::TempDir {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    let change = ito.join("changes").join(OLD_ID);
+
+    write(
+        &ito.join("modules/001_auth/module.md"),
+        "# Auth\n\n## Purpose\nAuth\n\n## Changes\n- [ ] 001-01_add-login\n",
+    );
+    write(&change.join(".ito.yaml"), "schema: spec-driven\n");
+    write(
+        &change.join("proposal.md"),
+        "---\nowner:\n---\n## Why\nUsers need to log in.\n",
+    );
+    let tasks = [
+        "# Tasks for: 001-01_add-login\n\n## Wave 1\n\n- **Depends On**: None\n\n".to_string(),
+        task("1.1", "Login form", "None", "AUTH-1"),
+        task("1.2", "Session store", "None", "SESSION-1"),
+        task("1.3", "Logout", "None", "AUTH-2"),
+        task("1.4", "Session expiry", "Task 1.2", "SESSION-1"),
+        task("1.5", "Login audit", "Task 1.1", ""),
+    ];
+    write(&change.join("tasks.md"), &tasks.concat());
+    write(
+        &change.join("specs/auth/spec.md"),
+        "## ADDED Requirements\n\n### Requirement: Login\n- **Requirement ID**: AUTH-1\n\n### Requirement: Logout\n- **Requirement ID**: AUTH-2\n",
+    );
+    write(
+        &change.join("specs/session/spec.md"),
+        "## ADDED Requirements\n\n### Requirement: Sessions\n- **Requirement ID**: SESSION-1\n",
+    );
+    td
+}
+
+fn ids(contents: &str) -> Vec<String> {
+    parse_tasks_tracking_file(contents)
+        .tasks
+        .into_iter()
+        .map(|t| t.id)
+        .collect()
+}
+
+#[test]
+fn split_moves_independent_tasks_and_their_spec() {
+    let td = fixture();
+    let ito = td.path().join(".ito");
+    let request = ChangeSplitRequest::new(
+        OLD_ID,
+        vec!["1.2".to_string(), "1.4".to_string()],
+        "session-store",
+    )
+    .renumber(true);
+
+    let result = split_change(&ito, &request).unwrap();
+
+    assert_eq!(result.new_id, NEW_ID);
+    assert_eq!(result.moved_tasks, vec!["1.2", "1.4"]);
+    assert!(result.dependents.is_empty());
+    assert_eq!(result.moved_specs, vec!["session"]);
+    assert!(result.copied_specs.is_empty());
+
+    let old_dir = ito.join("changes").join(OLD_ID);
+    let new_dir = ito.join("changes").join(NEW_ID);
+    let new_tasks = read(&new_dir.join("tasks.md"));
+    assert_eq!(ids(&new_tasks), vec!["1.2", "1.4"]);
+    assert!(
+        !parse_tasks_tracking_file(&new_tasks)
+            .diagnostics
+            .iter()
+            .any(|d| d.level == DiagnosticLevel::Error)
+    );
+    assert!(new_tasks.contains("- **Dependencies**: Task 1.2\n"));
+
+    let old_tasks = read(&old_dir.join("tasks.md"));
+    assert_eq!(ids(&old_tasks), vec!["1.1", "1.2", "1.3"]);
+    assert!(old_tasks.contains("### Task 1.2: Logout\n"));
+    assert!(old_tasks.contains("### Task 1.3: Login audit\n"));
+    assert!(!old_tasks.contains("Session"));
+
+    assert!(new_dir.join("specs/session/spec.md").is_file());
+    assert!(!old_dir.join("specs/session").exists());
+    assert!(old_dir.join("specs/auth/spec.md").is_file());
+    assert!(read(&ito.join("modules/001_auth/module.md")).contains(NEW_ID));
+}
+
+#[test]
+fn split_rejects_tasks_depended_on_by_tasks_left_behind() {
+    let td = fixture();
+    let ito = td.path().join(".ito");
+    let before = read(&ito.join("changes").join(OLD_ID).join("tasks.md"));
+
+    let err = split_change(
+        &ito,
+        &ChangeSplitRequest::new(OLD_ID, vec!["1.2".to_string()], "session-store"),
+    )
+    .unwrap_err();
+
+    let CoreError::Validation(msg) = err else {
+        panic!("expected a validation error, got {err:?}");
+    };
+    assert!(msg.contains("Task '1.4'"), "{msg}");
+    assert!(msg.contains("--with-dependents"), "{msg}");
+    assert!(!ito.join("changes").join(NEW_ID).exists());
+    assert_eq!(
+        read(&ito.join("changes").join(OLD_ID).join("tasks.md")),
+        before
+    );
+
+    let result = split_change(
+        &ito,
+        &ChangeSplitRequest::new(OLD_ID, vec!["1.2".to_string()], "session-store")
+            .with_dependents(true),
+    )
+    .unwrap();
+    assert_eq!(result.moved_tasks, vec!["1.2", "1.4"]);
+    assert_eq!(result.dependents, vec!["1.4"]);
+}
+
+#[test]
+fn split_annotates_proposal_and_flags_shared_specs() {
+    let td = fixture();
+    let ito = td.path().join(".ito");
+
+    let result = split_change(
+        &ito,
+        &ChangeSplitRequest::new(OLD_ID, vec!["1.3".to_string()], "logout"),
+    )
+    .unwrap();
+
+    assert_eq!(result.copied_specs, vec!["auth"]);
+    let new_dir = ito.join("changes").join(&result.new_id);
+    let proposal = read(&new_dir.join("proposal.md"));
+    assert!(proposal.starts_with("---\nowner:\n---\n> Split from `001-01_add-login` on "));
+    assert!(proposal.contains("(task 1.3)"), "{proposal}");
+    assert!(
+        proposal.contains("> Review by hand: delta spec `auth`"),
+        "{proposal}"
+    );
+    assert!(proposal.ends_with("## Why\nUsers need to log in.\n"));
+    assert!(new_dir.join("specs/auth/spec.md").is_file());
+    assert!(
+        ito.join("changes")
+            .join(OLD_ID)
+            .join("specs/auth/spec.md")
+            .is_file()
+    );
+    let old_tasks = read(&ito.join("changes").join(OLD_ID).join("tasks.md"));
+    assert_eq!(ids(&old_tasks), vec!["1.1", "1.2", "1.4", "1.5"]);
+}
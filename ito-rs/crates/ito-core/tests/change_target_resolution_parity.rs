@@ -13,8 +13,8 @@ use ito_core::repository_runtime::{PersistenceMode, RepositoryRuntimeBuilder, Sq
 use ito_core::sqlite_project_store::{SqliteBackendProjectStore, UpsertChangeParams};
 use ito_domain::backend::BackendChangeReader;
 use ito_domain::changes::{
-    Change, ChangeLifecycleFilter, ChangeRepository, ChangeSummary, ChangeTargetResolution,
-    ResolveTargetOptions,
+    Change, ChangeLifecycleFilter, ChangeRepository, ChangeSummary, ChangeTargetMatch,
+    ChangeTargetResolution, ResolveTargetOptions,
 };
 use ito_domain::errors::{DomainError, DomainResult};
 use ito_domain::tasks::TasksParseResult;
@@ -112,6 +112,7 @@ fn summary(id: &str) -> ChangeSummary {
         has_specs: false,
         has_tasks: true,
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
     }
 }
 
@@ -126,6 +127,7 @@ fn change(id: &str) -> Change {
         specs: Vec::new(),
         tasks: TasksParseResult::empty(),
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
         last_modified: Utc::now(),
     }
 }
@@ -149,6 +151,19 @@ impl BackendChangeReader for FakeReader {
     }
 }
 
+/// Work status depends on each mode's fixture data; only ids are compared.
+fn without_work_status(resolution: ChangeTargetResolution) -> ChangeTargetResolution {
+    match resolution {
+        ChangeTargetResolution::Ambiguous(matches) => ChangeTargetResolution::Ambiguous(
+            matches
+                .into_iter()
+                .map(|m| ChangeTargetMatch::new(m.id, None))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
 #[test]
 fn change_target_resolution_matches_across_repository_modes() {
     let change_ids = [
@@ -192,8 +207,8 @@ fn change_target_resolution_matches_across_repository_modes() {
         (
             "025",
             ChangeTargetResolution::Ambiguous(vec![
-                "025-07_feature-one".to_string(),
-                "025-08_feature-two".to_string(),
+                ChangeTargetMatch::new("025-07_feature-one", None),
+                ChangeTargetMatch::new("025-08_feature-two", None),
             ]),
         ),
         (
@@ -206,15 +221,15 @@ fn change_target_resolution_matches_across_repository_modes() {
     for (input, expected) in cases {
         let options = ResolveTargetOptions::default();
         assert_eq!(
-            fs_repo.resolve_target_with_options(input, options),
+            without_work_status(fs_repo.resolve_target_with_options(input, options)),
             expected
         );
         assert_eq!(
-            sqlite_repo.resolve_target_with_options(input, options),
+            without_work_status(sqlite_repo.resolve_target_with_options(input, options)),
             expected
         );
         assert_eq!(
-            remote_repo.resolve_target_with_options(input, options),
+            without_work_status(remote_repo.resolve_target_with_options(input, options)),
             expected
         );
     }
@@ -236,6 +251,7 @@ fn sqlite_resolver_honors_archived_lifecycle_like_filesystem() {
     let default_options = ResolveTargetOptions::default();
     let archived_options = ResolveTargetOptions {
         lifecycle: ChangeLifecycleFilter::All,
+        exact: false,
     };
 
     assert_eq!(
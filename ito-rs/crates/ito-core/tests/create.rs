@@ -1,4 +1,8 @@
-use ito_core::create::{CreateError, create_change, create_change_in_sub_module, create_module};
+use ito_core::create::{
+    CreateError, create_change, create_change_in_sub_module, create_module, create_module_with_id,
+};
+use ito_core::module_repository::FsModuleRepository;
+use ito_core::validate::validate_module;
 
 fn write(path: impl AsRef<std::path::Path>, contents: &str) {
     let path = path.as_ref();
@@ -84,6 +88,124 @@ fn create_module_writes_description_to_purpose_section() {
     );
 }
 
+#[test]
+fn create_module_assigns_next_id_after_highest_without_filling_gaps() {
+    let td = tempfile::tempdir().expect("tempdir should succeed");
+    let ito = td.path().join(".ito");
+    create_module_fixture(&ito, "000", "ungrouped");
+    create_module_fixture(&ito, "002", "billing");
+
+    let r = create_module(&ito, "search", vec![], vec![], None).expect("create_module");
+
+    assert_eq!(r.module_id, "003");
+    assert_eq!(r.folder_name, "003_search");
+}
+
+#[test]
+fn create_module_honors_explicit_id() {
+    let td = tempfile::tempdir().expect("tempdir should succeed");
+    let ito = td.path().join(".ito");
+    create_module_fixture(&ito, "002", "billing");
+
+    let r = create_module_with_id(&ito, "search", Some("7"), vec![], vec![], None)
+        .expect("create_module_with_id");
+
+    assert!(r.created);
+    assert_eq!(r.module_id, "007");
+    assert_eq!(r.folder_name, "007_search");
+}
+
+#[test]
+fn create_module_rejects_explicit_id_already_in_use() {
+    let td = tempfile::tempdir().expect("tempdir should succeed");
+    let ito = td.path().join(".ito");
+    create_module_fixture(&ito, "002", "billing");
+
+    let err = create_module_with_id(&ito, "search", Some("002"), vec![], vec![], None)
+        .expect_err("colliding id should fail");
+
+    let CreateError::ModuleIdTaken(id, folder) = err else {
+        panic!("expected ModuleIdTaken, got {err:?}");
+    };
+    assert_eq!(id, "002");
+    assert_eq!(folder, "002_billing");
+    assert!(!ito.join("modules/002_search").exists());
+}
+
+#[test]
+fn create_module_rejects_existing_name_under_a_different_explicit_id() {
+    let td = tempfile::tempdir().expect("tempdir should succeed");
+    let ito = td.path().join(".ito");
+    create_module_fixture(&ito, "002", "billing");
+
+    let err = create_module_with_id(&ito, "billing", Some("005"), vec![], vec![], None)
+        .expect_err("existing name should fail");
+    assert!(
+        matches!(err, CreateError::ModuleAlreadyExists(_, _)),
+        "{err:?}"
+    );
+
+    let same = create_module_with_id(&ito, "billing", Some("2"), vec![], vec![], None)
+        .expect("matching id resolves the existing module");
+    assert!(!same.created);
+    assert_eq!(same.folder_name, "002_billing");
+}
+
+#[test]
+fn create_module_slugifies_the_name() {
+    let td = tempfile::tempdir().expect("tempdir should succeed");
+    let ito = td.path().join(".ito");
+
+    let r = create_module(&ito, "  Billing  Platform_v2 ", vec![], vec![], None)
+        .expect("create_module");
+
+    assert_eq!(r.module_name, "billing-platform-v2");
+    assert_eq!(r.folder_name, "001_billing-platform-v2");
+    let md = std::fs::read_to_string(&r.module_md).expect("read module.md");
+    assert!(md.starts_with("# Billing Platform V2\n"), "{md}");
+}
+
+#[test]
+fn create_module_rejects_names_that_are_not_safe_path_segments() {
+    let td = tempfile::tempdir().expect("tempdir should succeed");
+    let ito = td.path().join(".ito");
+
+    for (name, expected) in [
+        ("", "Module name cannot be empty"),
+        (" - ", "Module name cannot be empty"),
+        ("../etc", "Module name can only contain lowercase letters"),
+        ("a/b", "Module name can only contain lowercase letters"),
+        ("2fa", "Module name must start with a letter"),
+    ] {
+        let err = create_module(&ito, name, vec![], vec![], None).expect_err(name);
+        let CreateError::InvalidModuleName(msg) = err else {
+            panic!("expected InvalidModuleName for {name:?}, got {err:?}");
+        };
+        assert!(msg.contains(expected), "{name:?}: {msg}");
+    }
+    assert!(!ito.join("modules").exists());
+}
+
+#[test]
+fn create_module_scaffold_passes_module_validation() {
+    let td = tempfile::tempdir().expect("tempdir should succeed");
+    let ito = td.path().join(".ito");
+
+    let r =
+        create_module(&ito, "billing", vec!["*".to_string()], vec![], None).expect("create_module");
+
+    let md = std::fs::read_to_string(&r.module_md).expect("read module.md");
+    for section in ["## Purpose", "## Scope", "## Changes"] {
+        assert!(md.contains(section), "missing {section}: {md}");
+    }
+    assert!(md.contains("billing module (created "), "{md}");
+
+    let module_repo = FsModuleRepository::new(&ito);
+    let (_name, report) =
+        validate_module(&module_repo, &ito, &r.module_id, true).expect("validate_module");
+    assert!(report.valid, "{report:?}");
+}
+
 #[test]
 fn create_change_creates_change_dir_and_updates_module_md() {
     let td = tempfile::tempdir().expect("tempdir should succeed");
@@ -450,3 +572,62 @@ fn create_change_reports_actionable_error_when_coordination_worktree_missing() {
     assert!(message.contains("ito init --update --tools none"));
     assert!(message.contains(missing_coordination_root.to_string_lossy().as_ref()));
 }
+
+fn change_dir_entries(ito: &std::path::Path) -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(ito.join("changes"))
+        .expect("read changes dir")
+        .filter_map(Result::ok)
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn create_change_scaffold_contents_are_unchanged_by_staging() {
+    let td = tempfile::tempdir().expect("tempdir should succeed");
+    let ito = td.path().join(".ito");
+
+    let r = create_change(&ito, "add-thing", "spec-driven", None, Some("Why"))
+        .expect("create_change should succeed");
+
+    let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+    let read = |name: &str| std::fs::read_to_string(r.change_dir.join(name)).expect("read file");
+    assert!(read(".ito.yaml").starts_with(&format!("schema: spec-driven\ncreated: {today}\n")));
+    assert_eq!(
+        read("proposal.md"),
+        format!("---\nowner:\npriority:\nlabels: []\ncreated: {today}\n---\n")
+    );
+    assert_eq!(read("README.md"), "# 000-01_add-thing\n\nWhy\n");
+    assert_eq!(change_dir_entries(&ito), vec!["000-01_add-thing"]);
+}
+
+#[test]
+fn create_change_failure_after_scaffold_leaves_no_change_dir() {
+    let td = tempfile::tempdir().expect("tempdir should succeed");
+    let ito = td.path().join(".ito");
+    // An unreadable module.md makes the checklist update fail after the
+    // change files were written.
+    std::fs::create_dir_all(ito.join("modules/001_demo/module.md")).expect("create fixture");
+
+    let err = create_change(&ito, "add-thing", "spec-driven", Some("001"), None)
+        .expect_err("checklist update should fail");
+    assert!(matches!(err, CreateError::Io(_)), "unexpected error: {err}");
+    assert!(change_dir_entries(&ito).is_empty());
+}
+
+#[test]
+fn create_change_removes_stale_staging_dirs() {
+    let td = tempfile::tempdir().expect("tempdir should succeed");
+    let ito = td.path().join(".ito");
+    let stale = ito.join("changes/.tmp-000-01_crashed-99999");
+    write(stale.join(".ito.yaml"), "schema: spec-driven\n");
+    let an_hour_ago =
+        filetime::FileTime::from_unix_time(filetime::FileTime::now().unix_seconds() - 3600, 0);
+    filetime::set_file_mtime(&stale, an_hour_ago).expect("age staging dir");
+
+    let r = create_change(&ito, "crashed", "spec-driven", None, None).expect("create_change");
+
+    assert_eq!(r.change_id, "000-01_crashed");
+    assert_eq!(change_dir_entries(&ito), vec!["000-01_crashed"]);
+}
@@ -3,7 +3,7 @@ use ito_core::distribution::{
     opencode_manifests, pi_manifests,
 };
 use ito_core::installers::{InitOptions, InstallMode};
-use ito_templates::project_templates::WorktreeTemplateContext;
+use ito_templates::project_templates::ProjectTemplateContext;
 use std::collections::BTreeSet;
 use std::path::Path;
 
@@ -352,7 +352,7 @@ fn install_manifests_put_worktree_guidance_in_apply_lifecycle() {
     let manifests = claude_manifests(&project_root);
 
     // Install with a disabled worktree context (the most common case)
-    let ctx = WorktreeTemplateContext::default();
+    let ctx = ProjectTemplateContext::default();
     let (mode, opts) = legacy_init_args();
     install_manifests(&manifests, Some(&ctx), mode, &opts).unwrap();
 
@@ -380,7 +380,7 @@ fn install_manifests_keeps_non_worktree_placeholders_verbatim() {
     let project_root = td.path().join("project");
 
     let manifests = claude_manifests(&project_root);
-    let ctx = WorktreeTemplateContext::default();
+    let ctx = ProjectTemplateContext::default();
     let (mode, opts) = legacy_init_args();
     install_manifests(&manifests, Some(&ctx), mode, &opts).unwrap();
 
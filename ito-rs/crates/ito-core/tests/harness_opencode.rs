@@ -80,9 +80,15 @@ fn opencode_harness_runs_opencode_binary_and_returns_outputs() {
             model: Some("m1".to_string()),
             cwd: dir.path().to_path_buf(),
             env: BTreeMap::new(),
+            env_policy: Default::default(),
             interactive: false,
             allow_all: false,
             inactivity_timeout: None,
+            resume_session: None,
+            transcript_path: None,
+            permissions: None,
+            cancel: None,
+            prompt_estimate: None,
         })
         .unwrap();
 
@@ -98,6 +104,52 @@ fn opencode_harness_runs_opencode_binary_and_returns_outputs() {
     assert_eq!(r.exit_code, 42);
 }
 
+#[test]
+fn opencode_harness_reports_session_id_and_resumes_it() {
+    let dir = tempfile::tempdir().unwrap();
+    let bin = dir.path().join("opencode");
+    write_executable(
+        &bin,
+        "#!/bin/sh\n\necho \"ARGS:$@\"\necho '{\"type\":\"step_start\",\"sessionID\":\"ses_7f3a9c\"}'\nexit 0\n",
+    );
+
+    let _path_guard = PathGuard::prepend(dir.path());
+
+    let mut config = HarnessRunConfig {
+        prompt: "hello".to_string(),
+        model: None,
+        cwd: dir.path().to_path_buf(),
+        env: BTreeMap::new(),
+        env_policy: Default::default(),
+        interactive: false,
+        allow_all: false,
+        inactivity_timeout: None,
+        resume_session: None,
+        transcript_path: None,
+        permissions: None,
+        cancel: None,
+        prompt_estimate: None,
+    };
+    let mut h = OpencodeHarness;
+    let first = h.run(&config).unwrap();
+    assert!(
+        first.stdout.contains("ARGS:run hello"),
+        "stdout={:?}",
+        first.stdout
+    );
+    assert_eq!(first.session_id.as_deref(), Some("ses_7f3a9c"));
+
+    config.resume_session = first.session_id;
+    let second = h.run(&config).unwrap();
+    assert!(
+        second
+            .stdout
+            .contains("ARGS:run --session ses_7f3a9c hello"),
+        "stdout={:?}",
+        second.stdout
+    );
+}
+
 #[test]
 fn opencode_harness_errors_when_opencode_missing() {
     let dir = tempfile::tempdir().unwrap();
@@ -111,9 +163,15 @@ fn opencode_harness_errors_when_opencode_missing() {
             model: None,
             cwd: dir.path().to_path_buf(),
             env: BTreeMap::new(),
+            env_policy: Default::default(),
             interactive: false,
             allow_all: false,
             inactivity_timeout: None,
+            resume_session: None,
+            transcript_path: None,
+            permissions: None,
+            cancel: None,
+            prompt_estimate: None,
         })
         .expect_err("should error");
 
@@ -145,9 +203,12 @@ fn opencode_harness_errors_when_opencode_missing() {
 ///         model: Some("m1".to_string()),
 ///         cwd: dir.path().to_path_buf(),
 ///         env: BTreeMap::new(),
+///         env_policy: Default::default(),
 ///         interactive: false,
 ///         allow_all: true,
 ///         inactivity_timeout: None,
+///         resume_session: None,
+///         transcript_path: None,
 ///     })
 ///     .unwrap();
 ///
@@ -171,9 +232,15 @@ fn claude_harness_passes_model_and_allow_all_flags() {
             model: Some("m1".to_string()),
             cwd: dir.path().to_path_buf(),
             env: BTreeMap::new(),
+            env_policy: Default::default(),
             interactive: false,
             allow_all: true,
             inactivity_timeout: None,
+            resume_session: None,
+            transcript_path: None,
+            permissions: None,
+            cancel: None,
+            prompt_estimate: None,
         })
         .unwrap();
 
@@ -205,9 +272,15 @@ fn codex_harness_passes_model_and_allow_all_flags() {
             model: Some("m1".to_string()),
             cwd: dir.path().to_path_buf(),
             env: BTreeMap::new(),
+            env_policy: Default::default(),
             interactive: false,
             allow_all: true,
             inactivity_timeout: None,
+            resume_session: None,
+            transcript_path: None,
+            permissions: None,
+            cancel: None,
+            prompt_estimate: None,
         })
         .unwrap();
 
@@ -238,9 +311,15 @@ fn github_copilot_harness_passes_model_and_allow_all_flags() {
             model: Some("m1".to_string()),
             cwd: dir.path().to_path_buf(),
             env: BTreeMap::new(),
+            env_policy: Default::default(),
             interactive: false,
             allow_all: true,
             inactivity_timeout: None,
+            resume_session: None,
+            transcript_path: None,
+            permissions: None,
+            cancel: None,
+            prompt_estimate: None,
         })
         .unwrap();
 
@@ -267,9 +346,15 @@ fn claude_harness_errors_when_claude_missing() {
             model: None,
             cwd: dir.path().to_path_buf(),
             env: BTreeMap::new(),
+            env_policy: Default::default(),
             interactive: false,
             allow_all: false,
             inactivity_timeout: None,
+            resume_session: None,
+            transcript_path: None,
+            permissions: None,
+            cancel: None,
+            prompt_estimate: None,
         })
         .expect_err("should error");
 
@@ -290,9 +375,15 @@ fn codex_harness_errors_when_codex_missing() {
             model: None,
             cwd: dir.path().to_path_buf(),
             env: BTreeMap::new(),
+            env_policy: Default::default(),
             interactive: false,
             allow_all: false,
             inactivity_timeout: None,
+            resume_session: None,
+            transcript_path: None,
+            permissions: None,
+            cancel: None,
+            prompt_estimate: None,
         })
         .expect_err("should error");
 
@@ -313,9 +404,15 @@ fn copilot_harness_errors_when_copilot_missing() {
             model: None,
             cwd: dir.path().to_path_buf(),
             env: BTreeMap::new(),
+            env_policy: Default::default(),
             interactive: false,
             allow_all: false,
             inactivity_timeout: None,
+            resume_session: None,
+            transcript_path: None,
+            permissions: None,
+            cancel: None,
+            prompt_estimate: None,
         })
         .expect_err("should error");
 
@@ -2,7 +2,9 @@
 // The underlying harness code is cross-platform; only the test scaffolding is Unix-specific.
 #![cfg(unix)]
 
-use ito_core::harness::{Harness, HarnessRunConfig, OpencodeHarness};
+use ito_core::harness::{
+    CancellationToken, EnvMode, Harness, HarnessEnvPolicy, HarnessRunConfig, OpencodeHarness,
+};
 use std::collections::BTreeMap;
 use std::os::unix::fs::PermissionsExt;
 use std::time::Duration;
@@ -43,9 +45,15 @@ fn inactivity_timeout_kills_stalled_process() {
             model: None,
             cwd: dir.path().to_path_buf(),
             env,
+            env_policy: Default::default(),
             interactive: false,
             allow_all: false,
             inactivity_timeout: Some(Duration::from_secs(2)),
+            resume_session: None,
+            transcript_path: None,
+            permissions: None,
+            cancel: None,
+            prompt_estimate: None,
         })
         .unwrap();
 
@@ -68,7 +76,7 @@ fn inactivity_timeout_kills_stalled_process() {
     );
 
     // Verify the test completed in reasonable time (< 10 seconds)
-    // The timeout is 2 seconds, plus check interval (1s), plus some buffer
+    // The timeout is 2 seconds, plus check interval (200ms), plus some buffer
     assert!(
         elapsed < Duration::from_secs(10),
         "Test should complete quickly, took {:?}",
@@ -106,9 +114,15 @@ fn no_timeout_when_process_exits_normally() {
             model: None,
             cwd: dir.path().to_path_buf(),
             env,
+            env_policy: Default::default(),
             interactive: false,
             allow_all: false,
             inactivity_timeout: Some(Duration::from_secs(2)),
+            resume_session: None,
+            transcript_path: None,
+            permissions: None,
+            cancel: None,
+            prompt_estimate: None,
         })
         .unwrap();
 
@@ -134,10 +148,163 @@ fn no_timeout_when_process_exits_normally() {
 
     // Verify the test completed in reasonable time (well before a 10-second bound).
     // We don't assert below the inactivity timeout because monitor thread teardown
-    // can take up to one check interval (~1 second) even after normal exit.
+    // can take up to one check interval (~200ms) even after normal exit.
     assert!(
         elapsed < Duration::from_secs(10),
         "Test should complete quickly, took {:?}",
         elapsed
     );
 }
+
+fn process_alive(pid: &str) -> bool {
+    std::process::Command::new("kill")
+        .args(["-0", pid])
+        .stderr(std::process::Stdio::null())
+        .status()
+        .is_ok_and(|s| s.success())
+}
+
+#[test]
+fn cancellation_terminates_harness_process_group() {
+    let dir = tempfile::tempdir().unwrap();
+    let bin = dir.path().join("opencode");
+    let pid_file = dir.path().join("grandchild.pid");
+
+    // The harness starts a long-running child of its own, like an agent running a tool.
+    write_executable(
+        &bin,
+        "#!/bin/sh\nsleep 60 &\necho $! > \"$PID_FILE\"\necho 'Started'\nwait\n",
+    );
+
+    let mut env = BTreeMap::new();
+    env.insert("PATH".to_string(), child_path_with_prepend(dir.path()));
+    env.insert(
+        "PID_FILE".to_string(),
+        pid_file.to_string_lossy().to_string(),
+    );
+
+    let cancel = CancellationToken::new();
+    let trigger = {
+        let cancel = cancel.clone();
+        let pid_file = pid_file.clone();
+        std::thread::spawn(move || {
+            while !pid_file.exists() {
+                std::thread::sleep(Duration::from_millis(50));
+            }
+            std::thread::sleep(Duration::from_millis(200));
+            cancel.cancel();
+        })
+    };
+
+    let mut h = OpencodeHarness;
+    let start = std::time::Instant::now();
+    let r = h
+        .run(&HarnessRunConfig {
+            prompt: "test".to_string(),
+            model: None,
+            cwd: dir.path().to_path_buf(),
+            env,
+            env_policy: Default::default(),
+            interactive: false,
+            allow_all: false,
+            inactivity_timeout: Some(Duration::from_secs(60)),
+            resume_session: None,
+            transcript_path: None,
+            permissions: None,
+            cancel: Some(cancel),
+            prompt_estimate: None,
+        })
+        .unwrap();
+    trigger.join().unwrap();
+
+    assert!(r.cancelled, "run should be flagged cancelled");
+    assert!(!r.timed_out);
+    assert_eq!(r.exit_code, -1);
+    assert!(r.stdout.contains("Started"));
+    assert!(
+        start.elapsed() < Duration::from_secs(20),
+        "cancellation should not wait for the harness, took {:?}",
+        start.elapsed()
+    );
+
+    // The grandchild may linger briefly as a zombie before init reaps it.
+    let pid = std::fs::read_to_string(&pid_file).unwrap();
+    let pid = pid.trim();
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    while process_alive(pid) && std::time::Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    assert!(!process_alive(pid), "grandchild {pid} should be terminated");
+}
+
+fn env_dump_config(dir: &std::path::Path, env_policy: HarnessEnvPolicy) -> HarnessRunConfig {
+    let mut env = BTreeMap::new();
+    env.insert("PATH".to_string(), child_path_with_prepend(dir));
+    HarnessRunConfig {
+        prompt: "test".to_string(),
+        model: None,
+        cwd: dir.to_path_buf(),
+        env,
+        env_policy,
+        interactive: false,
+        allow_all: false,
+        inactivity_timeout: Some(Duration::from_secs(10)),
+        resume_session: None,
+        transcript_path: None,
+        permissions: None,
+        cancel: None,
+        prompt_estimate: None,
+    }
+}
+
+#[test]
+fn allow_list_policy_filters_harness_env() {
+    // SAFETY: test-only, unique variable names not read elsewhere.
+    unsafe {
+        std::env::set_var("ITO_TEST_ENV_POLICY_ALLOWED", "passed");
+        std::env::set_var("ITO_TEST_ENV_POLICY_SECRET", "leaked");
+    }
+    let dir = tempfile::tempdir().unwrap();
+    write_executable(&dir.path().join("opencode"), "#!/bin/sh\nenv\n");
+
+    let policy = HarnessEnvPolicy {
+        mode: EnvMode::AllowList(vec!["ITO_TEST_ENV_POLICY_ALLOWED".to_string()]),
+        required: Vec::new(),
+    };
+    let r = OpencodeHarness
+        .run(&env_dump_config(dir.path(), policy))
+        .unwrap();
+
+    assert_eq!(r.exit_code, 0, "stderr: {}", r.stderr);
+    assert!(r.stdout.contains("ITO_TEST_ENV_POLICY_ALLOWED=passed"));
+    assert!(r.stdout.contains("PATH="));
+    assert!(
+        !r.stdout.contains("ITO_TEST_ENV_POLICY_SECRET"),
+        "secret leaked into harness env:\n{}",
+        r.stdout
+    );
+}
+
+#[test]
+fn missing_required_env_fails_before_spawn() {
+    let dir = tempfile::tempdir().unwrap();
+    let marker = dir.path().join("spawned");
+    write_executable(
+        &dir.path().join("opencode"),
+        &format!("#!/bin/sh\ntouch '{}'\n", marker.display()),
+    );
+
+    let policy = HarnessEnvPolicy {
+        mode: EnvMode::Clean,
+        required: vec!["ITO_TEST_ENV_POLICY_NEVER_SET".to_string()],
+    };
+    let err = OpencodeHarness
+        .run(&env_dump_config(dir.path(), policy))
+        .unwrap_err();
+
+    assert!(
+        err.to_string().contains("ITO_TEST_ENV_POLICY_NEVER_SET"),
+        "{err}"
+    );
+    assert!(!marker.exists(), "harness should not have been spawned");
+}
@@ -14,9 +14,15 @@ fn stub_harness_default_returns_complete_promise() {
             model: None,
             cwd: std::env::current_dir().unwrap(),
             env: BTreeMap::new(),
+            env_policy: Default::default(),
             interactive: false,
             allow_all: false,
             inactivity_timeout: None,
+            resume_session: None,
+            transcript_path: None,
+            permissions: None,
+            cancel: None,
+            prompt_estimate: None,
         })
         .expect("run");
     assert!(r.stdout.contains("<promise>COMPLETE</promise>"));
@@ -49,9 +55,12 @@ fn stub_harness_default_returns_complete_promise() {
 ///     model: None,
 ///     cwd: std::env::current_dir().unwrap(),
 ///     env: BTreeMap::new(),
+///     env_policy: Default::default(),
 ///     interactive: false,
 ///     allow_all: false,
 ///     inactivity_timeout: None,
+///     resume_session: None,
+///     transcript_path: None,
 /// };
 ///
 /// let r1 = h.run(&cfg).unwrap();
@@ -89,9 +98,15 @@ fn stub_harness_from_json_path_runs_steps_and_repeats_last() {
         model: None,
         cwd: std::env::current_dir().unwrap(),
         env: BTreeMap::new(),
+        env_policy: Default::default(),
         interactive: false,
         allow_all: false,
         inactivity_timeout: None,
+        resume_session: None,
+        transcript_path: None,
+        permissions: None,
+        cancel: None,
+        prompt_estimate: None,
     };
 
     let r1 = h.run(&cfg).unwrap();
@@ -142,9 +157,12 @@ fn stub_harness_from_env_prefers_env_over_default() {
 ///         model: None,
 ///         cwd: std::env::current_dir().unwrap(),
 ///         env: BTreeMap::new(),
+///         env_policy: Default::default(),
 ///         interactive: false,
 ///         allow_all: false,
 ///         inactivity_timeout: None,
+///         resume_session: None,
+///         transcript_path: None,
 ///     })
 ///     .expect_err("should error");
 /// let msg = err.to_string();
@@ -159,9 +177,15 @@ fn stub_harness_errors_on_empty_steps() {
             model: None,
             cwd: std::env::current_dir().unwrap(),
             env: BTreeMap::new(),
+            env_policy: Default::default(),
             interactive: false,
             allow_all: false,
             inactivity_timeout: None,
+            resume_session: None,
+            transcript_path: None,
+            permissions: None,
+            cancel: None,
+            prompt_estimate: None,
         })
         .expect_err("should error");
     let msg = err.to_string();
@@ -181,9 +205,15 @@ fn stub_step_defaults_match_json_schema() {
             model: None,
             cwd: std::env::current_dir().unwrap(),
             env: BTreeMap::new(),
+            env_policy: Default::default(),
             interactive: false,
             allow_all: false,
             inactivity_timeout: None,
+            resume_session: None,
+            transcript_path: None,
+            permissions: None,
+            cancel: None,
+            prompt_estimate: None,
         })
         .unwrap();
 
@@ -205,3 +235,88 @@ fn stub_harness_errors_on_missing_and_invalid_json() {
     let err = StubHarness::from_json_path(&invalid).expect_err("should error");
     assert!(err.to_string().contains("Invalid stub script JSON"));
 }
+
+fn transcript_config(path: std::path::PathBuf, interactive: bool) -> HarnessRunConfig {
+    HarnessRunConfig {
+        prompt: "Implement the thing".to_string(),
+        model: Some("stub-model".to_string()),
+        cwd: std::env::current_dir().unwrap(),
+        env: BTreeMap::new(),
+        env_policy: Default::default(),
+        interactive,
+        allow_all: false,
+        inactivity_timeout: None,
+        resume_session: None,
+        transcript_path: Some(path),
+        permissions: None,
+        cancel: None,
+        prompt_estimate: None,
+    }
+}
+
+#[test]
+fn stub_harness_writes_transcript_with_header() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(".state/transcripts/001-01_demo/3.md");
+    let mut h = StubHarness::new(vec![ito_core::harness::stub::StubStep {
+        stdout: "did work\n".to_string(),
+        stderr: "a warning\n".to_string(),
+        exit_code: 4,
+        writes: Default::default(),
+        delay_ms: 0,
+    }]);
+
+    h.run(&transcript_config(path.clone(), false)).unwrap();
+
+    let transcript = std::fs::read_to_string(&path).expect("transcript written");
+    assert!(transcript.starts_with("# Harness Transcript\n"));
+    assert!(transcript.contains("- Harness: stub"));
+    assert!(transcript.contains("- Model: stub-model"));
+    assert!(transcript.contains("- Exit code: 4"));
+    assert!(transcript.contains("- Duration: "));
+    assert!(transcript.contains("## Prompt"));
+    assert!(transcript.contains("Implement the thing"));
+    assert!(transcript.contains("## Stdout"));
+    assert!(transcript.contains("did work"));
+    assert!(transcript.contains("## Stderr"));
+    assert!(transcript.contains("a warning"));
+}
+
+#[test]
+fn stub_harness_interactive_run_records_placeholder() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("1.md");
+    let mut h = StubHarness::new(vec![ito_core::harness::stub::StubStep {
+        stdout: "secret terminal output\n".to_string(),
+        stderr: String::new(),
+        exit_code: 0,
+        writes: Default::default(),
+        delay_ms: 0,
+    }]);
+
+    let result = h.run(&transcript_config(path.clone(), true)).unwrap();
+    assert_eq!(result.exit_code, 0);
+
+    let transcript = std::fs::read_to_string(&path).expect("transcript written");
+    assert!(transcript.contains("- Exit code: 0"));
+    assert!(transcript.contains("Implement the thing"));
+    assert!(transcript.contains(ito_core::harness::transcript::INTERACTIVE_PLACEHOLDER));
+    assert!(!transcript.contains("secret terminal output"));
+}
+
+#[test]
+fn stub_harness_run_succeeds_when_transcript_cannot_be_written() {
+    let dir = tempfile::tempdir().unwrap();
+    let blocker = dir.path().join("blocker");
+    std::fs::write(&blocker, "not a directory").unwrap();
+    let mut h = StubHarness::new(vec![ito_core::harness::stub::StubStep {
+        stdout: "ok\n".to_string(),
+        stderr: String::new(),
+        exit_code: 0,
+        writes: Default::default(),
+        delay_ms: 0,
+    }]);
+
+    let result = h.run(&transcript_config(blocker.join("1.md"), false));
+    assert!(result.is_ok());
+}
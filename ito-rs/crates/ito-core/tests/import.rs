@@ -234,6 +234,7 @@ fn summary(id: &str) -> ChangeSummary {
         has_specs: true,
         has_tasks: true,
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
     }
 }
 
@@ -248,6 +249,7 @@ fn change(id: &str) -> Change {
         specs: Vec::new(),
         tasks: TasksParseResult::empty(),
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
         last_modified: Utc::now(),
     }
 }
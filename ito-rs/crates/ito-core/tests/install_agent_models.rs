@@ -0,0 +1,121 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use ito_config::ConfigContext;
+use ito_core::installers::{InitOptions, InstallMode, install_default_templates};
+
+fn tools() -> BTreeSet<String> {
+    ["claude", "opencode"]
+        .iter()
+        .map(|tool| tool.to_string())
+        .collect()
+}
+
+fn install(project: &Path, mode: InstallMode, opts: InitOptions) {
+    let ctx = ConfigContext {
+        project_dir: Some(project.to_path_buf()),
+        ..Default::default()
+    };
+    install_default_templates(project, &ctx, mode, &opts, None).expect("install should succeed");
+}
+
+/// Pin models in the repo-local `ito.json`, which `ito init` never writes.
+fn pin_models(project: &Path, models: &str) {
+    std::fs::write(
+        project.join("ito.json"),
+        format!("{{\"models\": {models}}}\n"),
+    )
+    .unwrap();
+}
+
+/// Top-level `model` value of an agent file's frontmatter, unquoted.
+fn agent_model(path: &Path) -> String {
+    let text = std::fs::read_to_string(path).expect("agent file");
+    let frontmatter = text
+        .strip_prefix("---\n")
+        .and_then(|rest| rest.split("\n---").next())
+        .expect("frontmatter");
+    let line = frontmatter
+        .lines()
+        .find_map(|line| line.strip_prefix("model:"))
+        .expect("model key");
+    line.trim().trim_matches('"').to_string()
+}
+
+#[test]
+fn configured_models_flow_into_freshly_rendered_agents() {
+    let td = tempfile::tempdir().expect("tempdir");
+    pin_models(
+        td.path(),
+        r#"{"claude": {"quick": "claude-haiku-4-5"}, "opencode": {"thinking": "anthropic/claude-opus-4-1"}}"#,
+    );
+
+    install(
+        td.path(),
+        InstallMode::Init,
+        InitOptions::new(tools(), false, false),
+    );
+
+    let claude = td.path().join(".claude/agents");
+    assert_eq!(
+        agent_model(&claude.join("ito-quick.md")),
+        "claude-haiku-4-5"
+    );
+    assert_eq!(agent_model(&claude.join("ito-general.md")), "sonnet");
+    let opencode = td.path().join(".opencode/agents");
+    assert_eq!(
+        agent_model(&opencode.join("ito-thinking.md")),
+        "anthropic/claude-opus-4-1"
+    );
+    assert_eq!(
+        agent_model(&opencode.join("ito-quick.md")),
+        "anthropic/claude-haiku-4-5"
+    );
+}
+
+#[test]
+fn update_rewrites_installed_agents_to_configured_models() {
+    let td = tempfile::tempdir().expect("tempdir");
+    install(
+        td.path(),
+        InstallMode::Init,
+        InitOptions::new(tools(), false, false),
+    );
+    let quick = td.path().join(".claude/agents/ito-quick.md");
+    assert_eq!(agent_model(&quick), "haiku");
+
+    pin_models(td.path(), r#"{"claude": {"quick": "claude-haiku-4-5"}}"#);
+    install(
+        td.path(),
+        InstallMode::Update,
+        InitOptions::new(tools(), false, true),
+    );
+    assert_eq!(agent_model(&quick), "claude-haiku-4-5");
+
+    // Dropping the pin converges back to the embedded default.
+    std::fs::remove_file(td.path().join("ito.json")).unwrap();
+    install(
+        td.path(),
+        InstallMode::Update,
+        InitOptions::new(tools(), false, true),
+    );
+    assert_eq!(agent_model(&quick), "haiku");
+}
+
+#[test]
+fn explicit_models_option_overrides_config() {
+    let td = tempfile::tempdir().expect("tempdir");
+    pin_models(td.path(), r#"{"claude": {"quick": "from-config"}}"#);
+    let models = serde_json::from_str(r#"{"claude": {"quick": "from-caller"}}"#).unwrap();
+
+    install(
+        td.path(),
+        InstallMode::Init,
+        InitOptions::new(tools(), false, false).with_models(models),
+    );
+
+    assert_eq!(
+        agent_model(&td.path().join(".claude/agents/ito-quick.md")),
+        "from-caller"
+    );
+}
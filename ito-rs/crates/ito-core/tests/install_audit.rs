@@ -0,0 +1,75 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::sync::Mutex;
+
+use ito_config::ConfigContext;
+use ito_core::audit::{AuditEvent, AuditWriter};
+use ito_core::installers::{
+    InitOptions, InstallMode, TOOL_PI, install_default_templates, record_install_event,
+};
+
+#[derive(Default)]
+struct RecordingWriter {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+impl AuditWriter for RecordingWriter {
+    fn append(&self, event: &AuditEvent) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.events.lock().unwrap().push(event.clone());
+        Ok(())
+    }
+}
+
+fn install(project: &Path, mode: InstallMode, opts: &InitOptions) {
+    let ctx = ConfigContext {
+        project_dir: Some(project.to_path_buf()),
+        ..Default::default()
+    };
+    install_default_templates(project, &ctx, mode, opts, None).expect("install should succeed");
+}
+
+fn pi_tools() -> BTreeSet<String> {
+    BTreeSet::from([TOOL_PI.to_string()])
+}
+
+#[test]
+fn init_and_update_record_one_project_event_each() {
+    let td = tempfile::tempdir().expect("tempdir");
+    let ito_path = td.path().join(".ito");
+    let writer = RecordingWriter::default();
+
+    let init = InitOptions::new(pi_tools(), false, false);
+    install(td.path(), InstallMode::Init, &init);
+    record_install_event(Some(&writer), &ito_path, InstallMode::Init, &init);
+
+    let update = InitOptions::new(pi_tools(), false, true);
+    install(td.path(), InstallMode::Update, &update);
+    record_install_event(Some(&writer), &ito_path, InstallMode::Update, &update);
+
+    let events = writer.events.lock().unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].entity, "project");
+    assert_eq!(events[0].op, "init");
+    assert_eq!(events[1].op, "update");
+
+    let meta = events[0].meta.as_ref().expect("meta");
+    assert_eq!(meta["tools"], serde_json::json!([TOOL_PI]));
+    assert!(
+        meta["template_version"]
+            .as_str()
+            .is_some_and(|v| !v.is_empty())
+    );
+    assert_eq!(events[1].meta.as_ref().expect("meta")["update"], true);
+}
+
+#[test]
+fn no_writer_records_nothing() {
+    let td = tempfile::tempdir().expect("tempdir");
+    let ito_path = td.path().join(".ito");
+    let opts = InitOptions::new(pi_tools(), false, false);
+    install(td.path(), InstallMode::Init, &opts);
+
+    record_install_event(None, &ito_path, InstallMode::Init, &opts);
+
+    assert!(!ito_path.join(".state/audit/events.jsonl").exists());
+}
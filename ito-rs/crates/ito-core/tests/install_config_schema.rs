@@ -0,0 +1,91 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use ito_config::ConfigContext;
+use ito_core::installers::{
+    CONFIG_SCHEMA_FILE, InitOptions, InstallMode, config_schema_ref, install_default_templates,
+    load_install_manifest, save_install_manifest,
+};
+use serde_json::Value;
+
+fn install(project: &Path, mode: InstallMode, opts: InitOptions) {
+    let ctx = ConfigContext {
+        project_dir: Some(project.to_path_buf()),
+        ..Default::default()
+    };
+    install_default_templates(project, &ctx, mode, &opts, None).expect("install should succeed");
+}
+
+fn read_json(path: &Path) -> Value {
+    serde_json::from_str(&std::fs::read_to_string(path).expect("read json")).expect("valid json")
+}
+
+#[test]
+fn init_writes_schema_file_and_matching_reference() {
+    let td = tempfile::tempdir().expect("tempdir");
+    install(
+        td.path(),
+        InstallMode::Init,
+        InitOptions::new(BTreeSet::new(), false, false),
+    );
+
+    let ito = td.path().join(".ito");
+    let config = read_json(&ito.join("config.json"));
+    assert_eq!(config["$schema"], config_schema_ref(None));
+    assert_eq!(
+        read_json(&ito.join(CONFIG_SCHEMA_FILE)),
+        ito_config::schema::config_schema_json()
+    );
+    let manifest = load_install_manifest(&ito).expect("manifest written");
+    assert!(
+        manifest
+            .recorded_hash(&format!(".ito/{CONFIG_SCHEMA_FILE}"))
+            .is_some()
+    );
+}
+
+#[test]
+fn init_references_configured_schema_server() {
+    let td = tempfile::tempdir().expect("tempdir");
+    install(
+        td.path(),
+        InstallMode::Init,
+        InitOptions::new(BTreeSet::new(), false, false).with_schema_server("http://127.0.0.1:9009"),
+    );
+
+    let config = read_json(&td.path().join(".ito/config.json"));
+    assert_eq!(
+        config["$schema"],
+        "http://127.0.0.1:9009/schema/config.json"
+    );
+}
+
+#[test]
+fn update_refreshes_stale_schema_file() {
+    let td = tempfile::tempdir().expect("tempdir");
+    install(
+        td.path(),
+        InstallMode::Init,
+        InitOptions::new(BTreeSet::new(), false, false),
+    );
+    let ito = td.path().join(".ito");
+    let rel = format!(".ito/{CONFIG_SCHEMA_FILE}");
+
+    // Simulate the schema written by an older Ito version.
+    let stale = "{\"title\": \"old\"}\n";
+    std::fs::write(ito.join(CONFIG_SCHEMA_FILE), stale).unwrap();
+    let mut manifest = load_install_manifest(&ito).unwrap();
+    manifest.record(&rel, stale.as_bytes());
+    save_install_manifest(&ito, &manifest).unwrap();
+
+    install(
+        td.path(),
+        InstallMode::Update,
+        InitOptions::new(BTreeSet::new(), false, true),
+    );
+
+    assert_eq!(
+        read_json(&ito.join(CONFIG_SCHEMA_FILE)),
+        ito_config::schema::config_schema_json()
+    );
+}
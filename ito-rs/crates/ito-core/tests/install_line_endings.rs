@@ -0,0 +1,149 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use ito_config::ConfigContext;
+use ito_core::errors::CoreResult;
+use ito_core::installers::{
+    InitOptions, InstallMode, TOOL_PI, content_hash, install_default_templates,
+    load_install_manifest,
+};
+
+const PI_SETTINGS: &str = ".pi/settings.json";
+
+fn install(project: &Path, mode: InstallMode, opts: InitOptions) -> CoreResult<()> {
+    let ctx = ConfigContext {
+        project_dir: Some(project.to_path_buf()),
+        ..Default::default()
+    };
+    install_default_templates(project, &ctx, mode, &opts, None)
+}
+
+fn pi_tools() -> BTreeSet<String> {
+    BTreeSet::from([TOOL_PI.to_string()])
+}
+
+fn init(project: &Path) {
+    install(
+        project,
+        InstallMode::Init,
+        InitOptions::new(pi_tools(), false, false),
+    )
+    .expect("init should succeed");
+}
+
+fn update(project: &Path) {
+    install(
+        project,
+        InstallMode::Update,
+        InitOptions::new(pi_tools(), false, true),
+    )
+    .expect("update should succeed");
+}
+
+fn read(project: &Path, rel: &str) -> String {
+    std::fs::read_to_string(project.join(rel)).expect("read installed file")
+}
+
+fn is_all_crlf(text: &str) -> bool {
+    text.contains('\n') && text.matches('\n').count() == text.matches("\r\n").count()
+}
+
+#[test]
+fn crlf_policy_writes_new_files_with_crlf() {
+    let td = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        td.path().join("ito.json"),
+        r#"{"install":{"line_endings":"crlf"}}"#,
+    )
+    .unwrap();
+
+    init(td.path());
+
+    assert!(is_all_crlf(&read(td.path(), "AGENTS.md")));
+    let settings = read(td.path(), PI_SETTINGS);
+    assert!(is_all_crlf(&settings));
+
+    // The manifest records the bytes on disk, so an update does not mistake
+    // the converted file for a local edit.
+    let manifest = load_install_manifest(&td.path().join(".ito")).expect("manifest");
+    assert_eq!(
+        manifest.recorded_hash(PI_SETTINGS),
+        Some(content_hash(settings.as_bytes()).as_str())
+    );
+    update(td.path());
+    assert_eq!(read(td.path(), PI_SETTINGS), settings);
+}
+
+#[test]
+fn auto_policy_follows_gitattributes_eol_rule() {
+    let td = tempfile::tempdir().expect("tempdir");
+    std::fs::write(td.path().join(".gitattributes"), "* text=auto eol=crlf\n").unwrap();
+
+    init(td.path());
+
+    assert!(is_all_crlf(&read(td.path(), "AGENTS.md")));
+}
+
+#[test]
+fn auto_policy_defaults_to_lf() {
+    let td = tempfile::tempdir().expect("tempdir");
+
+    init(td.path());
+
+    assert!(!read(td.path(), "AGENTS.md").contains('\r'));
+}
+
+#[test]
+fn update_keeps_crlf_in_existing_files() {
+    let td = tempfile::tempdir().expect("tempdir");
+    init(td.path());
+
+    // Simulate a `core.autocrlf=true` checkout of the installed files.
+    for rel in ["AGENTS.md", PI_SETTINGS] {
+        let crlf = read(td.path(), rel).replace('\n', "\r\n");
+        std::fs::write(td.path().join(rel), crlf).unwrap();
+    }
+
+    update(td.path());
+
+    assert!(is_all_crlf(&read(td.path(), "AGENTS.md")));
+    assert!(is_all_crlf(&read(td.path(), PI_SETTINGS)));
+}
+
+#[test]
+fn update_leaves_mixed_endings_outside_the_managed_block() {
+    let td = tempfile::tempdir().expect("tempdir");
+    init(td.path());
+
+    let agents = read(td.path(), "AGENTS.md").replace('\n', "\r\n");
+    let mixed = format!("{agents}\r\n## Local notes\nkeep LF here\r\nand CRLF here\r\n");
+    std::fs::write(td.path().join("AGENTS.md"), &mixed).unwrap();
+
+    update(td.path());
+
+    let updated = read(td.path(), "AGENTS.md");
+    let end = ito_templates::ITO_END_MARKER;
+    let tail = &updated[updated.find(end).unwrap()..];
+    let original_tail = &mixed[mixed.find(end).unwrap()..];
+    assert_eq!(tail, original_tail);
+    let head = &updated[..updated.find(end).unwrap()];
+    assert!(is_all_crlf(head));
+}
+
+#[test]
+fn invalid_line_ending_policy_is_rejected() {
+    let td = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        td.path().join("ito.json"),
+        r#"{"install":{"line_endings":"cr"}}"#,
+    )
+    .unwrap();
+
+    let err = install(
+        td.path(),
+        InstallMode::Init,
+        InitOptions::new(pi_tools(), false, false),
+    )
+    .expect_err("invalid policy");
+    assert!(err.to_string().contains("install.line_endings"), "{err}");
+}
@@ -0,0 +1,139 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use ito_config::ConfigContext;
+use ito_core::installers::{
+    InitOptions, InstallMode, TOOL_PI, content_hash, install_default_templates,
+    load_install_manifest,
+};
+
+const PI_SETTINGS: &str = ".pi/settings.json";
+
+fn install(project: &Path, mode: InstallMode, opts: InitOptions) {
+    let ctx = ConfigContext {
+        project_dir: Some(project.to_path_buf()),
+        ..Default::default()
+    };
+    install_default_templates(project, &ctx, mode, &opts, None).expect("install should succeed");
+}
+
+fn pi_tools() -> BTreeSet<String> {
+    BTreeSet::from([TOOL_PI.to_string()])
+}
+
+fn init_project() -> tempfile::TempDir {
+    let td = tempfile::tempdir().expect("tempdir");
+    install(
+        td.path(),
+        InstallMode::Init,
+        InitOptions::new(pi_tools(), false, false),
+    );
+    td
+}
+
+fn update(project: &Path, force_managed: bool) {
+    install(
+        project,
+        InstallMode::Update,
+        InitOptions::new(pi_tools(), false, true).with_force_managed(force_managed),
+    );
+}
+
+fn installed_settings(project: &Path) -> String {
+    std::fs::read_to_string(project.join(PI_SETTINGS)).expect("read pi settings")
+}
+
+#[test]
+fn init_records_rendered_hashes_for_managed_files() {
+    let td = init_project();
+    let manifest = load_install_manifest(&td.path().join(".ito")).expect("manifest written");
+
+    let settings = installed_settings(td.path());
+    assert_eq!(
+        manifest.recorded_hash(PI_SETTINGS),
+        Some(content_hash(settings.as_bytes()).as_str())
+    );
+    // Marker-managed and user-owned files are not tracked.
+    assert_eq!(manifest.recorded_hash("AGENTS.md"), None);
+    assert_eq!(manifest.recorded_hash(".ito/config.json"), None);
+}
+
+#[test]
+fn update_refreshes_unmodified_managed_file() {
+    let td = init_project();
+    let ito_path = td.path().join(".ito");
+    let template = installed_settings(td.path());
+
+    // Simulate a file written by an older template version.
+    let stale = "{\"old\": true}\n";
+    std::fs::write(td.path().join(PI_SETTINGS), stale).unwrap();
+    let mut manifest = load_install_manifest(&ito_path).unwrap();
+    manifest.record(PI_SETTINGS, stale.as_bytes());
+    ito_core::installers::save_install_manifest(&ito_path, &manifest).unwrap();
+
+    update(td.path(), false);
+    assert_eq!(installed_settings(td.path()), template);
+}
+
+#[test]
+fn update_skips_locally_modified_managed_file() {
+    let td = init_project();
+    let edited = "{\"edited\": true}\n";
+    std::fs::write(td.path().join(PI_SETTINGS), edited).unwrap();
+
+    update(td.path(), false);
+    assert_eq!(installed_settings(td.path()), edited);
+
+    // The recorded hash is kept so the file stays flagged on later updates.
+    let manifest = load_install_manifest(&td.path().join(".ito")).unwrap();
+    assert_ne!(
+        manifest.recorded_hash(PI_SETTINGS),
+        Some(content_hash(edited.as_bytes()).as_str())
+    );
+}
+
+#[test]
+fn update_force_managed_overwrites_locally_modified_file() {
+    let td = init_project();
+    let template = installed_settings(td.path());
+    std::fs::write(td.path().join(PI_SETTINGS), "{\"edited\": true}\n").unwrap();
+
+    update(td.path(), true);
+    assert_eq!(installed_settings(td.path()), template);
+}
+
+#[test]
+fn update_without_manifest_overwrites_as_before() {
+    let td = init_project();
+    let template = installed_settings(td.path());
+    std::fs::remove_file(td.path().join(".ito/.state/install-manifest.json")).unwrap();
+    std::fs::write(td.path().join(PI_SETTINGS), "{\"edited\": true}\n").unwrap();
+
+    update(td.path(), false);
+    assert_eq!(installed_settings(td.path()), template);
+    assert!(load_install_manifest(&td.path().join(".ito")).is_some());
+}
+
+#[test]
+fn manifest_is_written_inside_custom_ito_dir() {
+    let td = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        td.path().join("ito.json"),
+        "{\"projectPath\":\".repo-ito\"}",
+    )
+    .unwrap();
+    install(
+        td.path(),
+        InstallMode::Init,
+        InitOptions::new(pi_tools(), false, false),
+    );
+    let template = installed_settings(td.path());
+
+    update(td.path(), false);
+    assert_eq!(installed_settings(td.path()), template);
+    let manifest = load_install_manifest(&td.path().join(".repo-ito")).expect("manifest");
+    assert_eq!(
+        manifest.recorded_hash(PI_SETTINGS),
+        Some(content_hash(template.as_bytes()).as_str())
+    );
+}
@@ -0,0 +1,85 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use ito_common::fs::{FileSystem, MemFs, StdFs};
+use ito_config::ConfigContext;
+use ito_core::installers::{
+    InitOptions, InstallMode, LineEnding, available_tool_ids, install_project_files_fs,
+};
+use ito_test_support::collect_file_bytes;
+
+fn all_tools() -> BTreeSet<String> {
+    available_tool_ids().iter().map(|t| t.to_string()).collect()
+}
+
+fn install<F: FileSystem>(fs: &F, project: &Path, mode: InstallMode, opts: &InitOptions) {
+    let ctx = ConfigContext {
+        project_dir: Some(project.to_path_buf()),
+        ..Default::default()
+    };
+    install_project_files_fs(fs, project, &ctx, mode, opts, None).expect("install should succeed");
+}
+
+fn init_opts() -> InitOptions {
+    InitOptions::new(all_tools(), false, false).with_line_ending(LineEnding::Lf)
+}
+
+fn update_opts() -> InitOptions {
+    InitOptions::new(all_tools(), false, true).with_line_ending(LineEnding::Lf)
+}
+
+fn mem_project(root: &Path) -> MemFs {
+    let fs = MemFs::new();
+    fs.create_dir_all(root).unwrap();
+    fs
+}
+
+#[test]
+fn init_in_memory_matches_real_file_system() {
+    let td = tempfile::tempdir().expect("tempdir");
+    install(&StdFs, td.path(), InstallMode::Init, &init_opts());
+
+    let root = Path::new("/project");
+    let fs = mem_project(root);
+    install(&fs, root, InstallMode::Init, &init_opts());
+
+    let real = collect_file_bytes(td.path());
+    assert!(real.contains_key("AGENTS.md"));
+    assert!(real.contains_key(".gitignore"));
+    assert_eq!(fs.file_bytes(root), real);
+}
+
+#[test]
+fn update_over_user_content_in_memory_matches_real_file_system() {
+    let seed = |fs: &dyn FileSystem, root: &Path| {
+        fs.write(&root.join(".gitignore"), b"node_modules").unwrap();
+        fs.write(&root.join("AGENTS.md"), b"# Team notes\n")
+            .unwrap();
+    };
+
+    let td = tempfile::tempdir().expect("tempdir");
+    seed(&StdFs, td.path());
+    install(&StdFs, td.path(), InstallMode::Init, &update_opts());
+    install(&StdFs, td.path(), InstallMode::Update, &update_opts());
+
+    let root = Path::new("/project");
+    let fs = mem_project(root);
+    seed(&fs, root);
+    install(&fs, root, InstallMode::Init, &update_opts());
+    install(&fs, root, InstallMode::Update, &update_opts());
+
+    let real = collect_file_bytes(td.path());
+    let agents = String::from_utf8(real["AGENTS.md"].clone()).unwrap();
+    assert!(agents.contains("# Team notes"));
+    assert_eq!(fs.file_bytes(root), real);
+}
+
+#[test]
+fn in_memory_install_does_not_touch_disk() {
+    let td = tempfile::tempdir().expect("tempdir");
+    let fs = mem_project(td.path());
+    install(&fs, td.path(), InstallMode::Init, &init_opts());
+
+    assert!(!fs.file_bytes(td.path()).is_empty());
+    assert!(collect_file_bytes(td.path()).is_empty());
+}
@@ -0,0 +1,80 @@
+//! Installs through a symlinked project root must behave like installs
+//! through the real path.
+#![cfg(unix)]
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use ito_config::ConfigContext;
+use ito_core::installers::{InitOptions, InstallMode, install_default_templates};
+use ito_test_support::collect_file_bytes;
+
+fn install(project: &Path, mode: InstallMode, opts: &InitOptions) {
+    let ctx = ConfigContext {
+        project_dir: Some(project.to_path_buf()),
+        ..Default::default()
+    };
+    install_default_templates(project, &ctx, mode, opts, None).expect("install should succeed");
+}
+
+#[test]
+fn symlinked_root_installs_into_the_real_directory_once() {
+    let td = tempfile::tempdir().expect("tempdir");
+    let real = td.path().join("real");
+    std::fs::create_dir(&real).unwrap();
+    let link = td.path().join("link");
+    std::os::unix::fs::symlink(&real, &link).unwrap();
+
+    install(
+        &link,
+        InstallMode::Init,
+        &InitOptions::new(BTreeSet::new(), false, false),
+    );
+    let after_init = collect_file_bytes(&real);
+    assert!(after_init.contains_key("AGENTS.md"));
+    assert!(after_init.contains_key(".ito/AGENTS.md"));
+
+    // Updating through the link and then through the real path is a no-op.
+    install(
+        &link,
+        InstallMode::Update,
+        &InitOptions::new(BTreeSet::new(), false, true),
+    );
+    install(
+        &real,
+        InstallMode::Update,
+        &InitOptions::new(BTreeSet::new(), false, true),
+    );
+    assert_eq!(collect_file_bytes(&real), after_init);
+}
+
+#[test]
+fn symlinked_marker_file_keeps_user_content_and_link() {
+    let td = tempfile::tempdir().expect("tempdir");
+    let project = td.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    install(
+        &project,
+        InstallMode::Init,
+        &InitOptions::new(BTreeSet::new(), false, false),
+    );
+
+    // Move AGENTS.md elsewhere and link it back, as some monorepos do.
+    let shared = td.path().join("shared-AGENTS.md");
+    let agents = project.join("AGENTS.md");
+    let original = std::fs::read_to_string(&agents).unwrap();
+    std::fs::write(&shared, format!("# Team notes\n\n{original}")).unwrap();
+    std::fs::remove_file(&agents).unwrap();
+    std::os::unix::fs::symlink(&shared, &agents).unwrap();
+
+    install(
+        &project,
+        InstallMode::Update,
+        &InitOptions::new(BTreeSet::new(), false, true),
+    );
+
+    assert!(agents.is_symlink());
+    let updated = std::fs::read_to_string(&shared).unwrap();
+    assert!(updated.starts_with("# Team notes\n\n"), "{updated}");
+    assert_eq!(updated.matches(ito_templates::ITO_START_MARKER).count(), 1);
+}
@@ -0,0 +1,210 @@
+use std::path::Path;
+
+use ito_config::ConfigContext;
+use ito_core::templates::{
+    EXPORT_MANIFEST_FILE, EXPORT_TRUNCATION_MARKER, InstructionExportOptions,
+    export_change_instructions, write_instruction_bundle,
+};
+
+const CHANGE: &str = "000-01_demo";
+
+const SCHEMA: &str = r#"name: demo
+version: 1
+artifacts:
+  - id: proposal
+    generates: proposal.md
+    template: proposal.md
+    requires: []
+  - id: specs
+    generates: specs/**/*.md
+    template: spec.md
+    requires: ["proposal"]
+  - id: tasks
+    generates: tasks.md
+    template: tasks.md
+    requires: ["specs", "proposal"]
+apply:
+  requires: [tasks]
+  tracks: tasks.md
+"#;
+
+fn write(path: &Path, contents: &str) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, contents).unwrap();
+}
+
+fn fixture() -> (tempfile::TempDir, ConfigContext) {
+    let td = tempfile::tempdir().unwrap();
+    let root = td.path();
+    let schema_dir = root.join(".ito/templates/schemas/demo");
+    write(&schema_dir.join("schema.yaml"), SCHEMA);
+    for template in ["proposal.md", "spec.md", "tasks.md"] {
+        write(
+            &schema_dir.join("templates").join(template),
+            &format!("# {template} for {{{{ change_name }}}}\n"),
+        );
+    }
+
+    let change = root.join(".ito/changes").join(CHANGE);
+    write(
+        &change.join("proposal.md"),
+        &format!("## Why\n{}\n", "x".repeat(200)),
+    );
+    write(
+        &change.join("specs/auth/spec.md"),
+        "## ADDED Requirements\n",
+    );
+    write(
+        &root.join(".ito/user-prompts/tasks.md"),
+        "Keep tasks small.\n",
+    );
+
+    let ctx = ConfigContext {
+        project_dir: Some(root.to_path_buf()),
+        ..Default::default()
+    };
+    (td, ctx)
+}
+
+fn options(max_file_bytes: usize) -> InstructionExportOptions {
+    InstructionExportOptions {
+        max_file_bytes,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn export_lists_artifacts_in_build_order_with_dependency_contents() {
+    let (td, ctx) = fixture();
+    let ito = td.path().join(".ito");
+
+    let export =
+        export_change_instructions(&ito, CHANGE, Some("demo"), &ctx, &options(1024)).unwrap();
+
+    let ids: Vec<&str> = export
+        .artifacts
+        .iter()
+        .map(|a| a.instructions.artifact_id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["proposal", "specs", "tasks"]);
+    assert_eq!(export.status.artifacts[2].status, "ready");
+    assert!(export.generated_at.is_none());
+
+    let tasks = &export.artifacts[2];
+    assert_eq!(
+        tasks.instructions.template,
+        format!("# tasks.md for {CHANGE}\n")
+    );
+    assert_eq!(tasks.user_guidance.as_deref(), Some("Keep tasks small."));
+    let paths: Vec<&str> = tasks
+        .dependency_files
+        .iter()
+        .map(|f| f.path.as_str())
+        .collect();
+    assert_eq!(paths, vec!["specs/auth/spec.md", "proposal.md"]);
+    assert!(tasks.dependency_files.iter().all(|f| !f.truncated));
+    assert!(export.artifacts[0].dependency_files.is_empty());
+}
+
+#[test]
+fn export_truncates_large_dependency_files() {
+    let (td, ctx) = fixture();
+    let ito = td.path().join(".ito");
+
+    let export =
+        export_change_instructions(&ito, CHANGE, Some("demo"), &ctx, &options(32)).unwrap();
+
+    let proposal = &export.artifacts[1].dependency_files[0];
+    assert_eq!(proposal.path, "proposal.md");
+    assert!(proposal.truncated);
+    assert_eq!(proposal.size_bytes, 208);
+    assert!(
+        proposal
+            .content
+            .ends_with(&format!("\n{EXPORT_TRUNCATION_MARKER}\n"))
+    );
+    assert_eq!(
+        proposal.content.len(),
+        32 + 1 + EXPORT_TRUNCATION_MARKER.len() + 1
+    );
+}
+
+#[test]
+fn bundle_writes_numbered_files_and_manifest_deterministically() {
+    let (td, ctx) = fixture();
+    let ito = td.path().join(".ito");
+    let export =
+        export_change_instructions(&ito, CHANGE, Some("demo"), &ctx, &options(1024)).unwrap();
+
+    let out = td.path().join("bundle");
+    let manifest = write_instruction_bundle(&export, &out).unwrap();
+
+    let mut names: Vec<String> = std::fs::read_dir(&out)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    assert_eq!(
+        names,
+        vec![
+            "00-overview.md",
+            "01-proposal.md",
+            "02-specs.md",
+            "03-tasks.md",
+            "04-apply.md",
+            EXPORT_MANIFEST_FILE,
+        ]
+    );
+    let listed: Vec<&str> = manifest.files.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(listed, names[..5].to_vec());
+
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(out.join(EXPORT_MANIFEST_FILE)).unwrap())
+            .unwrap();
+    assert_eq!(json["changeName"], CHANGE);
+    assert_eq!(json["schemaName"], "demo");
+    assert!(json.get("generatedAt").is_none());
+    assert_eq!(json["files"][3]["kind"], "artifact");
+    assert_eq!(json["files"][3]["artifactId"], "tasks");
+    assert_eq!(json["files"][4]["kind"], "apply");
+
+    let tasks = std::fs::read_to_string(out.join("03-tasks.md")).unwrap();
+    assert!(
+        tasks.contains("- Requires: specs (done), proposal (done)\n"),
+        "{tasks}"
+    );
+    assert!(
+        tasks.contains("## Dependency: specs/auth/spec.md\n"),
+        "{tasks}"
+    );
+    assert!(tasks.contains("Keep tasks small."), "{tasks}");
+
+    // A second export over the same change produces identical files.
+    let again = td.path().join("again");
+    write_instruction_bundle(
+        &export_change_instructions(&ito, CHANGE, Some("demo"), &ctx, &options(1024)).unwrap(),
+        &again,
+    )
+    .unwrap();
+    for name in &names {
+        assert_eq!(
+            std::fs::read(out.join(name)).unwrap(),
+            std::fs::read(again.join(name)).unwrap(),
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn stamp_is_recorded_only_when_requested() {
+    let (td, ctx) = fixture();
+    let ito = td.path().join(".ito");
+    let opts = InstructionExportOptions {
+        generated_at: Some("2026-01-01T00:00:00Z".to_string()),
+        ..Default::default()
+    };
+
+    let export = export_change_instructions(&ito, CHANGE, Some("demo"), &ctx, &opts).unwrap();
+    let json = serde_json::to_value(&export).unwrap();
+    assert_eq!(json["generatedAt"], "2026-01-01T00:00:00Z");
+}
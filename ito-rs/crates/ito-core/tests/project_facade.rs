@@ -0,0 +1,142 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use ito_config::ConfigContext;
+use ito_core::harness::stub::StubHarness;
+use ito_core::project::Project;
+use ito_core::ralph::{CompletionFormat, RalphOptions};
+use support::write;
+
+const CHANGE_ID: &str = "000-01_facade";
+
+fn fixture_repo() -> tempfile::TempDir {
+    let td = tempfile::tempdir().expect("repo");
+    let root = td.path();
+    write(
+        &root.join(".ito/modules/000_ungrouped/module.md"),
+        "# Ungrouped\n\n## Purpose\nModule for ad-hoc changes. This purpose is long enough.\n\n## Scope\n- *\n\n## Changes\n- [ ] 000-01_facade\n",
+    );
+    write(
+        &root.join(".ito/changes/000-01_facade/proposal.md"),
+        "## Why\nExercise the project facade.\n\n## What Changes\n- Adds a small delta\n\n## Impact\n- None\n",
+    );
+    write(
+        &root.join(".ito/changes/000-01_facade/tasks.md"),
+        "## 1. Implementation\n- [x] 1.1 Do a thing\n- [ ] 1.2 Do another thing\n",
+    );
+    write(
+        &root.join(".ito/changes/000-01_facade/specs/alpha/spec.md"),
+        "## ADDED Requirements\n\n### Requirement: Alpha Delta\nThe system SHALL expose alpha behavior.\n\n#### Scenario: Delta ok\n- **WHEN** running validation\n- **THEN** it passes\n",
+    );
+    td
+}
+
+fn open(repo: &tempfile::TempDir) -> Project {
+    Project::with_context(
+        repo.path(),
+        ConfigContext {
+            project_dir: Some(repo.path().to_path_buf()),
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn facade_lists_changes_with_progress() {
+    let repo = fixture_repo();
+    let project = open(&repo);
+
+    assert_eq!(project.ito_path(), repo.path().join(".ito"));
+    let changes = project.list_changes().expect("list changes");
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].id, CHANGE_ID);
+    assert_eq!(changes[0].completed_tasks, 1);
+    assert_eq!(changes[0].total_tasks, 2);
+}
+
+#[test]
+fn facade_reports_status_instructions_validation_and_tasks() {
+    let repo = fixture_repo();
+    let project = open(&repo);
+
+    let status = project.change_status(CHANGE_ID).expect("status");
+    assert_eq!(status.change_name, CHANGE_ID);
+    assert!(status.artifacts.iter().any(|a| a.id == "proposal"));
+
+    let instructions = project
+        .instructions(CHANGE_ID, "proposal")
+        .expect("instructions");
+    assert_eq!(instructions.artifact_id, "proposal");
+    assert!(instructions.template.contains("## Why"));
+
+    let report = project.validate(CHANGE_ID, false).expect("validate");
+    assert!(report.valid, "unexpected issues: {:?}", report.issues);
+
+    let tasks = project.tasks(CHANGE_ID).expect("tasks");
+    assert_eq!(tasks.progress.total, 2);
+    assert_eq!(tasks.progress.complete, 1);
+}
+
+#[test]
+fn facade_maps_missing_changes_to_not_found() {
+    let repo = fixture_repo();
+    let project = open(&repo);
+
+    let err = project
+        .change_status("000-99_missing")
+        .expect_err("missing change");
+    assert!(matches!(err, ito_core::errors::CoreError::NotFound(_)));
+}
+
+#[test]
+fn facade_reports_ralph_status_without_running_the_harness() {
+    let repo = fixture_repo();
+    let project = open(&repo);
+    let mut harness = StubHarness::from_env_or_default(None).expect("stub harness");
+
+    let opts = RalphOptions {
+        prompt: String::new(),
+        change_id: Some(CHANGE_ID.to_string()),
+        module_id: None,
+        model: None,
+        min_iterations: 1,
+        max_iterations: Some(1),
+        completion_promise: "COMPLETE".to_string(),
+        completion_format: CompletionFormat::Tag,
+        completion_tag: "promise".to_string(),
+        completion_json_pointer: None,
+        allow_all: false,
+        no_commit: true,
+        interactive: false,
+        status: true,
+        add_context: None,
+        clear_context: false,
+        verbose: false,
+        continue_module: false,
+        continue_ready: false,
+        steal_stale: None,
+        inactivity_timeout: None,
+        resume_sessions: false,
+        preflight: false,
+        skip_validation: true,
+        validation_command: None,
+        validation_timeout: ito_core::ralph::DEFAULT_VALIDATION_TIMEOUT,
+        validation_max_output_bytes: Some(ito_core::ralph::DEFAULT_VALIDATION_MAX_OUTPUT_BYTES),
+        prompt_budget: None,
+        context_windows: Default::default(),
+        strict_budget: false,
+        git_context_commits: None,
+        git_context_max_chars: ito_core::ralph::DEFAULT_GIT_CONTEXT_MAX_CHARS,
+        env_policy: Default::default(),
+        permissions: None,
+        transcripts: false,
+        exit_on_error: false,
+        error_threshold: 10,
+        worktree: ito_core::ralph::WorktreeConfig::default(),
+        cancel: None,
+    };
+
+    project
+        .start_ralph(opts, &mut harness)
+        .expect("ralph status");
+}
@@ -0,0 +1,46 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use ito_config::ConfigContext;
+use ito_core::installers::{InitOptions, InstallMode, install_default_templates};
+use ito_templates::project_templates::WorktreeTemplateContext;
+
+fn install(project: &Path, worktree: Option<&WorktreeTemplateContext>) {
+    let ctx = ConfigContext {
+        project_dir: Some(project.to_path_buf()),
+        ..Default::default()
+    };
+    let opts = InitOptions::new(BTreeSet::new(), false, false);
+    install_default_templates(project, &ctx, InstallMode::Init, &opts, worktree)
+        .expect("install should succeed");
+}
+
+#[test]
+fn agents_md_is_rendered_with_the_worktree_context() {
+    let td = tempfile::tempdir().expect("tempdir");
+    let worktree = WorktreeTemplateContext {
+        enabled: true,
+        strategy: "checkout_subdir".to_string(),
+        integration_mode: "commit_pr".to_string(),
+        default_branch: "trunk".to_string(),
+        ..WorktreeTemplateContext::default()
+    };
+    install(td.path(), Some(&worktree));
+
+    let agents = std::fs::read_to_string(td.path().join("AGENTS.md")).expect("AGENTS.md");
+    assert!(!agents.contains("{%"));
+    assert!(agents.contains("**Default branch:** `trunk`"));
+}
+
+#[test]
+fn command_files_with_literal_braces_are_installed_verbatim() {
+    let td = tempfile::tempdir().expect("tempdir");
+    install(td.path(), None);
+
+    let installed = std::fs::read_to_string(td.path().join(".ito/commands/research-stack.md"))
+        .expect("installed command");
+    assert!(
+        installed.contains("technology choices and stack options for: **{{topic}}**"),
+        "{installed}"
+    );
+}
@@ -1,5 +1,5 @@
 use ito_core::harness::{Harness, HarnessName, HarnessRunConfig, HarnessRunResult};
-use ito_core::ralph::{RalphOptions, run_ralph_with_readiness};
+use ito_core::ralph::{CompletionFormat, RalphOptions, run_ralph_with_readiness};
 use ito_domain::changes::{
     Change, ChangeRepository, ChangeSummary, ChangeTargetResolution, ResolveTargetOptions,
 };
@@ -57,6 +57,11 @@ impl Harness for FixedHarness {
             exit_code,
             duration: Duration::from_millis(1),
             timed_out: false,
+            cancelled: false,
+            session_id: None,
+            final_message: None,
+            usage: None,
+            resources: Default::default(),
         })
     }
 
@@ -82,6 +87,41 @@ impl Harness for CwdCapturingHarness {
             exit_code: 0,
             duration: Duration::from_millis(1),
             timed_out: false,
+            cancelled: false,
+            session_id: None,
+            final_message: None,
+            usage: None,
+            resources: Default::default(),
+        })
+    }
+
+    fn stop(&mut self) {}
+}
+
+/// Harness that captures the environment overrides it receives.
+#[derive(Debug)]
+struct EnvCapturingHarness {
+    captured_env: Vec<std::collections::BTreeMap<String, String>>,
+}
+
+impl Harness for EnvCapturingHarness {
+    fn name(&self) -> HarnessName {
+        HarnessName::Stub
+    }
+
+    fn run(&mut self, config: &HarnessRunConfig) -> miette::Result<HarnessRunResult> {
+        self.captured_env.push(config.env.clone());
+        Ok(HarnessRunResult {
+            stdout: "<promise>COMPLETE</promise>\n".to_string(),
+            stderr: String::new(),
+            exit_code: 0,
+            duration: Duration::from_millis(1),
+            timed_out: false,
+            cancelled: false,
+            session_id: None,
+            final_message: None,
+            usage: None,
+            resources: Default::default(),
         })
     }
 
@@ -106,6 +146,11 @@ impl Harness for PromptCapturingHarness {
             exit_code: 0,
             duration: Duration::from_millis(1),
             timed_out: false,
+            cancelled: false,
+            session_id: None,
+            final_message: None,
+            usage: None,
+            resources: Default::default(),
         })
     }
 
@@ -161,6 +206,9 @@ fn default_opts() -> RalphOptions {
         min_iterations: 1,
         max_iterations: Some(3),
         completion_promise: "COMPLETE".to_string(),
+        completion_format: CompletionFormat::Tag,
+        completion_tag: "promise".to_string(),
+        completion_json_pointer: None,
         allow_all: false,
         no_commit: true,
         interactive: false,
@@ -170,13 +218,61 @@ fn default_opts() -> RalphOptions {
         verbose: false,
         continue_module: false,
         continue_ready: false,
+        steal_stale: None,
         inactivity_timeout: None,
+        resume_sessions: false,
+        preflight: false,
         skip_validation: false,
         validation_command: None,
+        validation_timeout: ito_core::ralph::DEFAULT_VALIDATION_TIMEOUT,
+        validation_max_output_bytes: Some(ito_core::ralph::DEFAULT_VALIDATION_MAX_OUTPUT_BYTES),
+        prompt_budget: None,
+        context_windows: Default::default(),
+        strict_budget: false,
+        git_context_commits: None,
+        git_context_max_chars: ito_core::ralph::DEFAULT_GIT_CONTEXT_MAX_CHARS,
+        env_policy: Default::default(),
+        permissions: None,
+        transcripts: false,
         exit_on_error: false,
         error_threshold: 10,
         worktree: ito_core::ralph::WorktreeConfig::default(),
+        cancel: None,
+    }
+}
+
+/// Harness that reports a JSON completion payload only in its final message,
+/// the way structured event-stream harnesses surface the assistant's text.
+#[derive(Debug)]
+struct FinalMessageHarness {
+    prompts: Vec<String>,
+}
+
+impl Harness for FinalMessageHarness {
+    fn name(&self) -> HarnessName {
+        HarnessName::Stub
+    }
+
+    fn run(&mut self, config: &HarnessRunConfig) -> miette::Result<HarnessRunResult> {
+        self.prompts.push(config.prompt.clone());
+        Ok(HarnessRunResult {
+            stdout: "{\"type\":\"result\",\"result\":\"...\"}\n".to_string(),
+            stderr: String::new(),
+            exit_code: 0,
+            duration: Duration::from_millis(1),
+            timed_out: false,
+            cancelled: false,
+            session_id: None,
+            final_message: Some(
+                "Done.\n```json\n{\"status\": \"complete\", \"summary\": \"Wired the parser\"}\n```"
+                    .to_string(),
+            ),
+            usage: None,
+            resources: Default::default(),
+        })
     }
+
+    fn stop(&mut self) {}
 }
 
 fn run_ralph_for_test(
@@ -451,6 +547,174 @@ fn run_ralph_skip_validation_exits_immediately() {
     assert_eq!(h.idx, 1);
 }
 
+#[test]
+fn run_ralph_json_completion_records_summary_from_final_message() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    std::fs::create_dir_all(&ito).unwrap();
+    write_fixture_ito(&ito, "006-09_fixture");
+
+    let mut h = FinalMessageHarness { prompts: vec![] };
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    opts.max_iterations = Some(2);
+    opts.skip_validation = true;
+    opts.completion_format = CompletionFormat::Json;
+    run_ralph_for_test(&ito, opts, &mut h).unwrap();
+
+    assert_eq!(h.prompts.len(), 1);
+    assert!(h.prompts[0].contains(r#"{"status":"COMPLETE","summary":"#));
+    assert!(!h.prompts[0].contains("<promise>"));
+
+    let state = ito_core::ralph::state::load_state(&ito, "006-09_fixture")
+        .unwrap()
+        .expect("ralph state");
+    let entry = state.history.last().expect("history entry");
+    assert!(entry.completion_promise_found);
+    assert_eq!(
+        entry.completion_summary.as_deref(),
+        Some("Wired the parser")
+    );
+}
+
+#[test]
+fn run_ralph_custom_completion_tag() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    std::fs::create_dir_all(&ito).unwrap();
+    write_fixture_ito(&ito, "006-09_fixture");
+
+    let mut h = FixedHarness::new(
+        HarnessName::Stub,
+        vec![
+            (
+                "<promise>COMPLETE</promise>\n".to_string(),
+                String::new(),
+                0,
+            ),
+            ("<done>COMPLETE</done>\n".to_string(), String::new(), 0),
+            ("should not run\n".to_string(), String::new(), 0),
+        ],
+    );
+
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    opts.max_iterations = Some(3);
+    opts.skip_validation = true;
+    opts.completion_tag = "done".to_string();
+    run_ralph_for_test(&ito, opts, &mut h).unwrap();
+    assert_eq!(h.idx, 2);
+}
+
+#[test]
+fn run_ralph_records_transcript_per_iteration() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    std::fs::create_dir_all(&ito).unwrap();
+    write_fixture_ito(&ito, "006-09_fixture");
+    write_tasks(&ito, "006-09_fixture", "# Tasks\n\n- [ ] todo\n");
+
+    let mut h =
+        ito_core::harness::stub::StubHarness::new(vec![ito_core::harness::stub::StubStep {
+            stdout: "working\n<promise>COMPLETE</promise>\n".to_string(),
+            stderr: String::new(),
+            exit_code: 0,
+            writes: Default::default(),
+            delay_ms: 0,
+        }]);
+
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    opts.skip_validation = true;
+    opts.transcripts = true;
+    run_ralph_for_test(&ito, opts, &mut h).unwrap();
+
+    let entries = ito_core::harness::transcript::list_transcripts(&ito, "006-09_fixture").unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].iteration, 1);
+    assert_eq!(
+        entries[0].path,
+        ito.join(".state/transcripts/006-09_fixture/1.md")
+    );
+
+    let transcript =
+        ito_core::harness::transcript::read_transcript(&ito, "006-09_fixture", 1).unwrap();
+    assert!(transcript.contains("- Harness: stub"));
+    assert!(transcript.contains("- Exit code: 0"));
+    assert!(
+        transcript.contains("do the thing"),
+        "prompt should be recorded"
+    );
+    assert!(transcript.contains("<promise>COMPLETE</promise>"));
+}
+
+#[test]
+fn run_ralph_skips_transcripts_when_disabled() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    std::fs::create_dir_all(&ito).unwrap();
+    write_fixture_ito(&ito, "006-09_fixture");
+    write_tasks(&ito, "006-09_fixture", "# Tasks\n\n- [ ] todo\n");
+
+    let mut h =
+        ito_core::harness::stub::StubHarness::new(vec![ito_core::harness::stub::StubStep {
+            stdout: "<promise>COMPLETE</promise>\n".to_string(),
+            stderr: String::new(),
+            exit_code: 0,
+            writes: Default::default(),
+            delay_ms: 0,
+        }]);
+
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    opts.skip_validation = true;
+    run_ralph_for_test(&ito, opts, &mut h).unwrap();
+
+    assert!(!ito.join(".state/transcripts").exists());
+}
+
+#[test]
+fn run_ralph_cancelled_mid_iteration_saves_state() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    std::fs::create_dir_all(&ito).unwrap();
+    write_fixture_ito(&ito, "006-09_fixture");
+    write_tasks(&ito, "006-09_fixture", "# Tasks\n\n- [ ] todo\n");
+
+    let mut h =
+        ito_core::harness::stub::StubHarness::new(vec![ito_core::harness::stub::StubStep {
+            stdout: "<promise>COMPLETE</promise>\n".to_string(),
+            stderr: String::new(),
+            exit_code: 0,
+            writes: Default::default(),
+            delay_ms: 60_000,
+        }]);
+
+    let cancel = ito_core::harness::CancellationToken::new();
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    opts.skip_validation = true;
+    opts.cancel = Some(cancel.clone());
+
+    let trigger = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(200));
+        cancel.cancel();
+    });
+    let started = std::time::Instant::now();
+    let err = run_ralph_for_test(&ito, opts, &mut h).unwrap_err();
+    trigger.join().unwrap();
+
+    assert!(started.elapsed() < Duration::from_secs(30));
+    assert!(err.to_string().contains("cancelled"), "{err}");
+    let state = ito_core::ralph::state::load_state(&ito, "006-09_fixture")
+        .unwrap()
+        .unwrap();
+    assert_eq!(state.iteration, 1);
+    assert_eq!(state.last_outcome.as_deref(), Some("cancelled"));
+    assert_eq!(state.history.len(), 1);
+    assert!(!state.history[0].completion_validated);
+}
+
 #[test]
 fn run_ralph_loop_writes_state_and_honors_min_iterations() {
     let td = tempfile::tempdir().unwrap();
@@ -822,6 +1086,11 @@ impl Harness for RecordingCompletingHarness {
             exit_code: 0,
             duration: Duration::from_millis(1),
             timed_out: false,
+            cancelled: false,
+            session_id: None,
+            final_message: None,
+            usage: None,
+            resources: Default::default(),
         })
     }
 
@@ -845,6 +1114,11 @@ impl Harness for CompletingHarness {
             exit_code: 0,
             duration: Duration::from_millis(1),
             timed_out: false,
+            cancelled: false,
+            session_id: None,
+            final_message: None,
+            usage: None,
+            resources: Default::default(),
         })
     }
 
@@ -1080,6 +1354,39 @@ fn run_ralph_worktree_disabled_uses_fallback_cwd() {
     );
 }
 
+#[test]
+fn run_ralph_passes_agent_attribution_to_harness() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    std::fs::create_dir_all(&ito).unwrap();
+    write_fixture_ito(&ito, "006-09_fixture");
+
+    let mut h = EnvCapturingHarness {
+        captured_env: Vec::new(),
+    };
+
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    opts.model = Some("test-model".to_string());
+    opts.min_iterations = 1;
+    opts.max_iterations = Some(1);
+    run_ralph_for_test(&ito, opts, &mut h).unwrap();
+
+    let env = h.captured_env.first().expect("harness ran");
+    assert_eq!(
+        env.get("ITO_AGENT_HARNESS").map(String::as_str),
+        Some("stub")
+    );
+    assert_eq!(
+        env.get("ITO_AGENT_MODEL").map(String::as_str),
+        Some("test-model")
+    );
+    assert_eq!(
+        env.get("ITO_AGENT_ITERATION").map(String::as_str),
+        Some("1")
+    );
+}
+
 #[test]
 fn run_ralph_worktree_enabled_state_written_to_effective_ito() {
     let td = tempfile::tempdir().unwrap();
@@ -1274,3 +1581,246 @@ fn run_ralph_non_retriable_exit_still_counts_against_threshold() {
     let msg = format!("{err}");
     assert!(msg.contains("exceeded non-zero exit threshold"), "{msg}");
 }
+
+// --- Harness session resumption tests ---
+
+/// One scripted run for [`SessionHarness`]: exit code, timed out, reported session id.
+type SessionStep = (i32, bool, Option<&'static str>);
+
+/// Harness that reports scripted session ids and records the session it was asked to resume.
+#[derive(Debug)]
+struct SessionHarness {
+    steps: Vec<SessionStep>,
+    resumed: Vec<Option<String>>,
+}
+
+impl SessionHarness {
+    fn new(steps: Vec<SessionStep>) -> Self {
+        Self {
+            steps,
+            resumed: Vec::new(),
+        }
+    }
+}
+
+impl Harness for SessionHarness {
+    fn name(&self) -> HarnessName {
+        HarnessName::Stub
+    }
+
+    fn run(&mut self, config: &HarnessRunConfig) -> miette::Result<HarnessRunResult> {
+        let (exit_code, timed_out, session_id) = self.steps[self.resumed.len()];
+        self.resumed.push(config.resume_session.clone());
+        let stdout = if exit_code == 0 && !timed_out {
+            "<promise>COMPLETE</promise>\n".to_string()
+        } else {
+            String::new()
+        };
+        Ok(HarnessRunResult {
+            stdout,
+            stderr: String::new(),
+            exit_code,
+            duration: Duration::from_millis(1),
+            timed_out,
+            cancelled: false,
+            session_id: session_id.map(String::from),
+            final_message: None,
+            usage: None,
+            resources: Default::default(),
+        })
+    }
+
+    fn stop(&mut self) {}
+}
+
+fn session_opts() -> RalphOptions {
+    let mut opts = default_opts();
+    opts.change_id = Some("006-09_fixture".to_string());
+    opts.skip_validation = true;
+    opts.resume_sessions = true;
+    opts.max_iterations = Some(5);
+    opts
+}
+
+fn saved_session_id(ito: &Path) -> Option<String> {
+    ito_core::ralph::state::load_state(ito, "006-09_fixture")
+        .unwrap()
+        .expect("ralph state")
+        .harness_session_id
+}
+
+#[test]
+fn run_ralph_resumes_session_after_crash_and_inactivity_timeout() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    write_fixture_ito(&ito, "006-09_fixture");
+
+    let mut h = SessionHarness::new(vec![
+        (137, false, Some("ses_first")),
+        (-1, true, None),
+        (0, false, Some("ses_first")),
+    ]);
+    run_ralph_for_test(&ito, session_opts(), &mut h).unwrap();
+
+    assert_eq!(
+        h.resumed,
+        vec![
+            None,
+            Some("ses_first".to_string()),
+            Some("ses_first".to_string())
+        ]
+    );
+    assert_eq!(saved_session_id(&ito), Some("ses_first".to_string()));
+}
+
+#[test]
+fn run_ralph_starts_fresh_session_for_normal_iterations() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    write_fixture_ito(&ito, "006-09_fixture");
+
+    let mut h = SessionHarness::new(vec![
+        (0, false, Some("ses_first")),
+        (0, false, Some("ses_second")),
+    ]);
+    let mut opts = session_opts();
+    opts.min_iterations = 2;
+    run_ralph_for_test(&ito, opts, &mut h).unwrap();
+
+    assert_eq!(h.resumed, vec![None, None]);
+    assert_eq!(saved_session_id(&ito), Some("ses_second".to_string()));
+}
+
+#[test]
+fn run_ralph_does_not_resume_sessions_unless_enabled() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    write_fixture_ito(&ito, "006-09_fixture");
+
+    let mut h = SessionHarness::new(vec![(137, false, Some("ses_first")), (0, false, None)]);
+    let mut opts = session_opts();
+    opts.resume_sessions = false;
+    run_ralph_for_test(&ito, opts, &mut h).unwrap();
+
+    assert_eq!(h.resumed, vec![None, None]);
+}
+
+#[test]
+fn run_ralph_falls_back_to_new_session_when_resume_fails() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    write_fixture_ito(&ito, "006-09_fixture");
+
+    let mut h = SessionHarness::new(vec![
+        (137, false, Some("ses_expired")),
+        (1, false, None),
+        (0, false, Some("ses_fresh")),
+    ]);
+    let mut opts = session_opts();
+    opts.error_threshold = 1;
+    run_ralph_for_test(&ito, opts, &mut h).unwrap();
+
+    assert_eq!(h.resumed, vec![None, Some("ses_expired".to_string()), None]);
+    assert_eq!(saved_session_id(&ito), Some("ses_fresh".to_string()));
+}
+
+#[test]
+fn profile_defaults_fill_unset_ralph_options() {
+    let profile = ito_config::types::ProfileConfig {
+        model: Some("haiku".to_string()),
+        ralph: Some(ito_config::types::ProfileRalphConfig {
+            min_iterations: Some(2),
+            max_iterations: Some(9),
+            timeout: Some("15m".to_string()),
+            no_commit: Some(false),
+            validation_command: Some("make check".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let mut opts = default_opts();
+
+    ito_core::profiles::apply_profile_to_ralph_options(&profile, &mut opts, &|_| false).unwrap();
+
+    assert_eq!(opts.model.as_deref(), Some("haiku"));
+    assert_eq!(opts.min_iterations, 2);
+    assert_eq!(opts.max_iterations, Some(9));
+    assert_eq!(opts.inactivity_timeout, Some(Duration::from_secs(15 * 60)));
+    assert!(!opts.no_commit);
+    assert_eq!(opts.validation_command.as_deref(), Some("make check"));
+}
+
+#[test]
+fn explicit_flags_override_profile_defaults() {
+    let profile = ito_config::types::ProfileConfig {
+        model: Some("haiku".to_string()),
+        ralph: Some(ito_config::types::ProfileRalphConfig {
+            max_iterations: Some(9),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let mut opts = default_opts();
+    opts.model = Some("opus".to_string());
+
+    ito_core::profiles::apply_profile_to_ralph_options(&profile, &mut opts, &|key| key == "model")
+        .unwrap();
+
+    assert_eq!(opts.model.as_deref(), Some("opus"));
+    assert_eq!(opts.max_iterations, Some(9));
+}
+
+#[test]
+fn preflight_failure_stops_before_the_first_iteration() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    write_fixture_ito(&ito, "006-09_fixture");
+
+    let mut h = FixedHarness::new(
+        HarnessName::Claude,
+        vec![(
+            String::new(),
+            "Invalid API key · Please run /login".to_string(),
+            1,
+        )],
+    );
+    let mut opts = session_opts();
+    opts.preflight = true;
+    let err = run_ralph_for_test(&ito, opts, &mut h).unwrap_err();
+
+    let msg = err.to_string();
+    assert!(
+        msg.contains("Harness preflight failed for claude: authentication failed"),
+        "{msg}"
+    );
+    assert_eq!(h.idx, 1, "only the health-check prompt ran");
+    assert!(
+        ito_core::ralph::state::load_state(&ito, "006-09_fixture")
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn passing_preflight_runs_the_loop() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    write_fixture_ito(&ito, "006-09_fixture");
+
+    let mut h = FixedHarness::new(
+        HarnessName::Stub,
+        vec![
+            ("OK\n".to_string(), String::new(), 0),
+            (
+                "<promise>COMPLETE</promise>\n".to_string(),
+                String::new(),
+                0,
+            ),
+        ],
+    );
+    let mut opts = session_opts();
+    opts.preflight = true;
+    run_ralph_for_test(&ito, opts, &mut h).unwrap();
+
+    assert_eq!(h.idx, 2);
+}
@@ -16,3 +16,178 @@ fn repo_index_loads_and_excludes_archive_change_dir() {
     assert!(idx.module_dir_names.contains(&"001_demo".to_string()));
     assert!(idx.spec_dir_names.contains(&"demo".to_string()));
 }
+
+mod incremental {
+    use std::path::{Path, PathBuf};
+
+    use filetime::FileTime;
+    use ito_core::change_repository::FsChangeRepository;
+    use ito_core::list::{ListChangesInput, list_changes, list_specs, summarize_changes};
+    use ito_core::repo_index::{
+        IncrementalRepoIndex, REPO_INDEX_REL, REPO_INDEX_VERSION, RepoIndex,
+    };
+
+    const CHANGES: [&str; 3] = ["001-01_alpha", "001-02_beta", "002-01_gamma"];
+
+    fn write(path: &Path, contents: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    /// Bump the mtime so refreshes are observable on coarse-grained filesystems.
+    fn touch_later(path: &Path, secs: i64) {
+        filetime::set_file_mtime(path, FileTime::from_unix_time(2_000_000_000 + secs, 0)).unwrap();
+    }
+
+    fn fixture() -> (tempfile::TempDir, PathBuf) {
+        let td = tempfile::tempdir().unwrap();
+        let ito = td.path().join(".ito");
+        for id in CHANGES {
+            let dir = ito.join("changes").join(id);
+            write(&dir.join("proposal.md"), "# Proposal\n");
+            write(
+                &dir.join("tasks.md"),
+                "## 1. Work\n- [x] 1.1 First\n- [ ] 1.2 Second\n",
+            );
+        }
+        std::fs::create_dir_all(ito.join("changes").join("archive")).unwrap();
+        write(
+            &ito.join("specs").join("alpha").join("spec.md"),
+            "## Requirements\n\n### Requirement: One\nThe system SHALL work.\n",
+        );
+        std::fs::create_dir_all(ito.join("modules").join("001_core")).unwrap();
+        std::fs::create_dir_all(ito.join("modules").join("002_extra")).unwrap();
+        (td, ito)
+    }
+
+    fn repo_index_listing(index: &RepoIndex) -> (Vec<String>, Vec<String>, Vec<String>) {
+        (
+            index.change_dir_names.clone(),
+            index.spec_dir_names.clone(),
+            index.module_dir_names.clone(),
+        )
+    }
+
+    #[test]
+    fn query_surface_matches_the_filesystem_listing() {
+        let (_td, ito) = fixture();
+        let index = IncrementalRepoIndex::build(&ito).unwrap();
+
+        let repo = FsChangeRepository::new(&ito);
+        let from_repo = list_changes(&repo, ListChangesInput::default()).unwrap();
+        let from_index = summarize_changes(&index.change_summaries(), ListChangesInput::default());
+        assert_eq!(from_index, from_repo);
+
+        assert_eq!(index.specs(), list_specs(&ito).unwrap());
+        let fresh = RepoIndex::load(&ito).unwrap();
+        assert_eq!(
+            repo_index_listing(&index.repo_index()),
+            repo_index_listing(&fresh)
+        );
+        assert_eq!(index.repo_index().module_ids, fresh.module_ids);
+    }
+
+    #[test]
+    fn single_file_change_reparses_exactly_one_entry() {
+        let (_td, ito) = fixture();
+        let mut index = IncrementalRepoIndex::build(&ito).unwrap();
+
+        let tasks = ito.join("changes").join("001-02_beta").join("tasks.md");
+        write(&tasks, "## 1. Work\n- [x] 1.1 First\n- [x] 1.2 Second\n");
+        touch_later(&tasks, 1);
+
+        let report = index.refresh(&[tasks]).unwrap();
+        assert_eq!(report.reparsed, vec!["changes/001-02_beta".to_string()]);
+        assert!(report.removed.is_empty());
+
+        let beta = index.change_summary("001-02_beta").unwrap();
+        assert_eq!(beta.completed_tasks, 2);
+        assert_eq!(index, IncrementalRepoIndex::build(&ito).unwrap());
+    }
+
+    #[test]
+    fn refreshed_index_equals_a_from_scratch_build() {
+        let (_td, ito) = fixture();
+        let mut index = IncrementalRepoIndex::build(&ito).unwrap();
+
+        // Edit, add, and remove entities, then refresh with the touched paths.
+        let alpha_tasks = ito.join("changes").join("001-01_alpha").join("tasks.md");
+        write(&alpha_tasks, "## 1. Work\n- [ ] 1.1 Only\n");
+        touch_later(&alpha_tasks, 2);
+        let delta = ito.join("changes").join("003-01_delta").join("proposal.md");
+        write(&delta, "# Proposal\n");
+        std::fs::remove_dir_all(ito.join("changes").join("002-01_gamma")).unwrap();
+        let spec = ito.join("specs").join("beta").join("spec.md");
+        write(&spec, "## Requirements\n");
+        std::fs::remove_dir_all(ito.join("modules").join("002_extra")).unwrap();
+
+        let report = index
+            .refresh(&[
+                alpha_tasks,
+                delta,
+                ito.join("changes").join("002-01_gamma"),
+                spec,
+                ito.join("modules"),
+                ito.join("changes").join("archive").join("x"),
+                ito.join("config.json"),
+            ])
+            .unwrap();
+        assert_eq!(
+            report.reparsed,
+            vec![
+                "changes/001-01_alpha".to_string(),
+                "changes/003-01_delta".to_string(),
+                "specs/beta".to_string(),
+            ]
+        );
+        assert_eq!(
+            report.removed,
+            vec![
+                "modules/002_extra".to_string(),
+                "changes/002-01_gamma".to_string(),
+            ]
+        );
+        assert_eq!(index, IncrementalRepoIndex::build(&ito).unwrap());
+    }
+
+    #[test]
+    fn load_or_build_persists_and_reparses_only_stale_entries() {
+        let (_td, ito) = fixture();
+        let built = IncrementalRepoIndex::load_or_build(&ito).unwrap();
+        assert!(ito.join(REPO_INDEX_REL).is_file());
+
+        let tasks = ito.join("changes").join("001-01_alpha").join("tasks.md");
+        write(&tasks, "## 1. Work\n- [x] 1.1 First\n- [x] 1.2 Second\n");
+        touch_later(&tasks, 3);
+
+        let loaded = IncrementalRepoIndex::load_or_build(&ito).unwrap();
+        assert_ne!(loaded, built);
+        assert_eq!(loaded, IncrementalRepoIndex::build(&ito).unwrap());
+        assert_eq!(
+            loaded
+                .change_summary("001-01_alpha")
+                .unwrap()
+                .completed_tasks,
+            2
+        );
+    }
+
+    #[test]
+    fn stored_index_with_other_version_is_rebuilt() {
+        let (_td, ito) = fixture();
+        write(
+            &ito.join(REPO_INDEX_REL),
+            &format!(
+                r#"{{"version":{},"entries":{{"changes/999-01_ghost":{{"mtime_nanos":0,"entity":{{"kind":"spec","id":"ghost","requirement_count":1}}}}}}}}"#,
+                REPO_INDEX_VERSION + 1
+            ),
+        );
+
+        let loaded = IncrementalRepoIndex::load_or_build(&ito).unwrap();
+        assert_eq!(loaded, IncrementalRepoIndex::build(&ito).unwrap());
+        assert!(loaded.change_summary("999-01_ghost").is_none());
+
+        let stored = std::fs::read_to_string(ito.join(REPO_INDEX_REL)).unwrap();
+        assert!(stored.contains(&format!("\"version\":{REPO_INDEX_VERSION}")));
+    }
+}
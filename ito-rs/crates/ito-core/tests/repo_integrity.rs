@@ -60,3 +60,26 @@ fn invalid_change_dir_names_are_reported() {
             .contains("Invalid change directory name 'not-a-change'")
     }));
 }
+
+#[test]
+fn non_canonical_change_dir_names_are_warned_with_suggested_rename() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+
+    mkdir(&ito.join("modules").join("003_demo"));
+    mkdir(&ito.join("changes").join("3-5_Fix-Thing"));
+    mkdir(&ito.join("changes").join("003-06_already-canonical"));
+
+    let issues = validate_change_dirs_repo_integrity(&ito).unwrap();
+    assert!(!issues.contains_key("003-06_already-canonical"));
+
+    let d = issues
+        .get("3-5_Fix-Thing")
+        .expect("non-canonical dir issues");
+    let w = d
+        .iter()
+        .find(|i| i.message.contains("is not canonical"))
+        .expect("canonical name warning");
+    assert_eq!(w.level, "WARNING");
+    assert!(w.message.contains("rename to '003-05_fix-thing'"));
+}
@@ -396,6 +396,14 @@ impl TaskMutationService for FakeTaskMutations {
         Self::unsupported()
     }
 
+    fn release_task(
+        &self,
+        _change_id: &str,
+        _task_id: &str,
+    ) -> TaskMutationServiceResult<TaskMutationResult> {
+        Self::unsupported()
+    }
+
     fn add_task(
         &self,
         _change_id: &str,
@@ -457,6 +465,7 @@ fn remote_runtime_uses_remote_factory() {
         has_specs: true,
         has_tasks: true,
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
     };
     let change = Change {
         id: "025-04_demo".to_string(),
@@ -471,6 +480,7 @@ fn remote_runtime_uses_remote_factory() {
         }],
         tasks: TasksParseResult::empty(),
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
         last_modified: Utc::now(),
     };
     let module_summary = ModuleSummary {
@@ -596,6 +606,7 @@ fn repository_modes_return_consistent_change_names() {
         has_specs: true,
         has_tasks: true,
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
     };
     let change = Change {
         id: change_id.to_string(),
@@ -610,6 +621,7 @@ fn repository_modes_return_consistent_change_names() {
         }],
         tasks: TasksParseResult::empty(),
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
         last_modified: Utc::now(),
     };
     let module_summary = ModuleSummary {
@@ -716,7 +728,7 @@ fn resolve_target_parity_between_filesystem_and_sqlite() {
                 proposal: Some("# Proposal"),
                 design: None,
                 tasks_md: Some("## 1. Implementation\n- [ ] 1.1 Todo"),
-                specs: &[],
+                specs: &[("alpha", "## ADDED Requirements")],
             })
             .expect("upsert sqlite change");
     }
@@ -0,0 +1,174 @@
+mod support;
+
+use std::path::Path;
+
+use ito_common::fs::FileSystem;
+use ito_common::git_fs::GitTreeFs;
+use ito_core::change_repository::FsChangeRepository;
+use ito_core::module_repository::FsModuleRepository;
+use ito_core::repository_runtime::RepositoryRuntimeBuilder;
+use ito_core::task_repository::FsTaskRepository;
+use ito_domain::tasks::TaskRepository;
+use support::write;
+
+const CHANGE: &str = "001-01_demo";
+
+fn git(repo: &Path, args: &[&str]) {
+    let output = std::process::Command::new("git")
+        .args(args)
+        .current_dir(repo)
+        .env_remove("GIT_DIR")
+        .env_remove("GIT_WORK_TREE")
+        .output()
+        .expect("git should run");
+    assert!(
+        output.status.success(),
+        "git {} failed: {}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn commit_all(repo: &Path, message: &str, tag: &str) {
+    git(repo, &["add", "-A"]);
+    git(
+        repo,
+        &["commit", "--no-verify", "--no-gpg-sign", "-m", message],
+    );
+    git(repo, &["tag", tag]);
+}
+
+/// Repo whose tasks.md has 0/2 done at `v1` and 1/3 done at `v2`; the
+/// working tree has everything done but is uncommitted.
+fn repo_with_history() -> tempfile::TempDir {
+    let td = tempfile::tempdir().expect("repo");
+    let root = td.path();
+    git(root, &["init", "--initial-branch=main"]);
+    git(root, &["config", "user.email", "test@example.com"]);
+    git(root, &["config", "user.name", "Test User"]);
+
+    let ito = root.join(".ito");
+    write(
+        &ito.join("modules/001_demo/module.md"),
+        "# Demo\n\n## Purpose\nDemo module.\n",
+    );
+    write(
+        &ito.join("changes").join(CHANGE).join("proposal.md"),
+        "## Why\nDemo\n",
+    );
+    let tasks = ito.join("changes").join(CHANGE).join("tasks.md");
+    write(&tasks, "## 1. Work\n- [ ] 1.1 First\n- [ ] 1.2 Second\n");
+    commit_all(root, "v1", "v1");
+
+    write(
+        &tasks,
+        "## 1. Work\n- [x] 1.1 First\n- [ ] 1.2 Second\n- [ ] 1.3 Third\n",
+    );
+    commit_all(root, "v2", "v2");
+
+    write(
+        &tasks,
+        "## 1. Work\n- [x] 1.1 First\n- [x] 1.2 Second\n- [x] 1.3 Third\n",
+    );
+    td
+}
+
+#[test]
+fn task_progress_reflects_each_revision() {
+    let repo = repo_with_history();
+    let ito = repo.path().join(".ito");
+
+    let at_v1 = GitTreeFs::open(repo.path(), "v1").expect("v1");
+    let at_v2 = GitTreeFs::open(repo.path(), "v2").expect("v2");
+
+    let v1 = FsTaskRepository::with_fs(&ito, at_v1)
+        .load_tasks(CHANGE)
+        .expect("tasks at v1");
+    let v2 = FsTaskRepository::with_fs(&ito, at_v2)
+        .load_tasks(CHANGE)
+        .expect("tasks at v2");
+    let head = FsTaskRepository::new(&ito)
+        .load_tasks(CHANGE)
+        .expect("working tree tasks");
+
+    assert_eq!((v1.progress.complete, v1.progress.total), (0, 2));
+    assert_eq!((v2.progress.complete, v2.progress.total), (1, 3));
+    assert_eq!((head.progress.complete, head.progress.total), (3, 3));
+}
+
+#[test]
+fn change_and_module_repositories_read_the_revision() {
+    let repo = repo_with_history();
+    let ito = repo.path().join(".ito");
+    write(
+        &ito.join("changes/001-02_later/proposal.md"),
+        "## Why\nNot committed\n",
+    );
+    let fs = GitTreeFs::open(repo.path(), "v2").expect("v2");
+
+    let changes = FsChangeRepository::with_fs(&ito, fs.clone());
+    let ids: Vec<String> = changes
+        .list()
+        .expect("list")
+        .into_iter()
+        .map(|c| c.id)
+        .collect();
+    assert_eq!(ids, vec![CHANGE.to_string()]);
+    let summary = changes.get_summary(CHANGE).expect("summary");
+    assert_eq!((summary.completed_tasks, summary.total_tasks), (1, 3));
+
+    let modules = FsModuleRepository::with_fs(&ito, fs)
+        .list()
+        .expect("modules");
+    assert_eq!(modules.len(), 1);
+}
+
+#[test]
+fn paths_missing_at_the_revision_are_not_found() {
+    let repo = repo_with_history();
+    let fs = GitTreeFs::open(repo.path(), "v1").expect("v1");
+    let design = repo
+        .path()
+        .join(".ito/changes")
+        .join(CHANGE)
+        .join("design.md");
+    write(&design, "## Design\n");
+
+    assert!(!fs.exists(&design));
+    let err = fs.read_to_string(&design).expect_err("absent at v1");
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+
+    let err = GitTreeFs::open(repo.path(), "v9").expect_err("unknown revision");
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+    assert!(err.to_string().contains("unknown git revision 'v9'"));
+}
+
+#[test]
+fn revision_runtime_rejects_task_mutations() {
+    let repo = repo_with_history();
+    let ito = repo.path().join(".ito");
+    let fs = GitTreeFs::open(repo.path(), "v1").expect("v1");
+    let runtime = RepositoryRuntimeBuilder::new(&ito)
+        .revision(fs)
+        .build()
+        .expect("runtime");
+
+    let mutations = &runtime.repositories().task_mutations;
+    let markdown = mutations
+        .load_tasks_markdown(CHANGE)
+        .expect("load")
+        .expect("tasks.md at v1");
+    assert!(markdown.contains("- [ ] 1.1 First"));
+
+    let err = mutations
+        .complete_task(CHANGE, "1.2", None)
+        .expect_err("revision is read-only");
+    assert!(
+        err.to_string()
+            .contains("Cannot modify tasks at revision 'v1'"),
+        "{err}"
+    );
+    let on_disk =
+        std::fs::read_to_string(ito.join("changes").join(CHANGE).join("tasks.md")).unwrap();
+    assert!(on_disk.contains("- [x] 1.2 Second"));
+}
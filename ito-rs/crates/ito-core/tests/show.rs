@@ -2,10 +2,12 @@ use ito_core::change_repository::FsChangeRepository;
 use ito_core::errors::CoreError;
 use ito_core::module_repository::FsModuleRepository;
 use ito_core::show::{
-    DeltaSpecFile, bundle_main_specs_markdown, bundle_main_specs_show_json, load_delta_spec_file,
-    parse_change_show_json, parse_spec_show_json, read_change_delta_spec_files,
-    read_module_markdown,
+    DeltaSpecFile, bundle_main_specs_markdown, bundle_main_specs_show_json,
+    link_delta_base_anchors, load_delta_spec_file, parse_change_show_json, parse_spec_show_json,
+    read_change_delta_spec_files, read_module_markdown, render_requirement_markdown,
+    requirement_anchor_slug, select_requirement,
 };
+use ito_core::spec_repository::FsSpecRepository;
 use std::path::Path;
 
 fn write(path: &Path, contents: &str) {
@@ -497,3 +499,152 @@ fn bundle_main_specs_show_json_returns_io_error_when_spec_md_is_missing() {
         "expected context to mention orphan spec, got: {context}"
     );
 }
+
+const ANCHOR_SPEC: &str = r#"
+## Purpose
+
+Anchors fixture.
+
+## Requirements
+
+### Requirement: User can log in (OAuth 2.0)
+Users SHALL log in.
+
+#### Scenario: Happy path
+- **WHEN** valid credentials
+- **THEN** a session starts
+
+### Requirement: Rate limiting
+Requests SHALL be limited.
+
+### Requirement: Rate-limiting!
+Requests SHALL be limited again.
+
+### Requirement: ???
+Odd title.
+"#;
+
+#[test]
+fn requirement_anchor_slug_strips_punctuation() {
+    assert_eq!(
+        requirement_anchor_slug("User can log in (OAuth 2.0)"),
+        "user-can-log-in-oauth-2-0"
+    );
+    assert_eq!(
+        requirement_anchor_slug("  Rate-limiting!  "),
+        "rate-limiting"
+    );
+    assert_eq!(requirement_anchor_slug("Café menu"), "caf-menu");
+    assert_eq!(requirement_anchor_slug("???"), "requirement");
+}
+
+#[test]
+fn parse_spec_show_json_assigns_deduplicated_anchors() {
+    let json = parse_spec_show_json("auth", ANCHOR_SPEC);
+    let anchors: Vec<&str> = json
+        .requirements
+        .iter()
+        .map(|r| r.anchor.as_str())
+        .collect();
+    assert_eq!(
+        anchors,
+        vec![
+            "user-can-log-in-oauth-2-0",
+            "rate-limiting",
+            "rate-limiting-2",
+            "requirement",
+        ]
+    );
+    assert_eq!(json.requirements[0].title, "User can log in (OAuth 2.0)");
+    assert_eq!(json.requirements[0].scenarios[0].name, "Happy path");
+
+    let value = serde_json::to_value(&json).unwrap();
+    assert_eq!(value["requirements"][2]["anchor"], "rate-limiting-2");
+}
+
+#[test]
+fn select_requirement_by_anchor_or_index() {
+    let json = parse_spec_show_json("auth", ANCHOR_SPEC);
+
+    let by_anchor = select_requirement(&json, "rate-limiting-2").unwrap();
+    assert_eq!(by_anchor.title, "Rate-limiting!");
+
+    let by_hash = select_requirement(&json, "#Rate-Limiting").unwrap();
+    assert_eq!(by_hash.title, "Rate limiting");
+
+    let by_index = select_requirement(&json, "1").unwrap();
+    assert_eq!(by_index.anchor, "user-can-log-in-oauth-2-0");
+}
+
+#[test]
+fn select_requirement_not_found_lists_available_anchors() {
+    let json = parse_spec_show_json("auth", ANCHOR_SPEC);
+
+    let err = select_requirement(&json, "logout").unwrap_err();
+    let msg = err.to_string();
+    assert!(
+        msg.contains("Requirement 'logout' not found in spec 'auth'"),
+        "{msg}"
+    );
+    assert!(
+        msg.contains(
+            "Available anchors: user-can-log-in-oauth-2-0, rate-limiting, rate-limiting-2, requirement"
+        ),
+        "{msg}"
+    );
+
+    for out_of_range in ["0", "5"] {
+        let msg = select_requirement(&json, out_of_range)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            msg.contains("Requirement index out of range. Expected 1..=4"),
+            "{msg}"
+        );
+        assert!(msg.contains("Available anchors: "), "{msg}");
+    }
+}
+
+#[test]
+fn render_requirement_markdown_includes_scenarios() {
+    let json = parse_spec_show_json("auth", ANCHOR_SPEC);
+    let md = render_requirement_markdown(&json.requirements[0]);
+    assert_eq!(
+        md,
+        "### Requirement: User can log in (OAuth 2.0)\nUsers SHALL log in.\n\n#### Scenario: Happy path\n- **WHEN** valid credentials\n- **THEN** a session starts\n"
+    );
+}
+
+#[test]
+fn change_show_deltas_carry_anchors_and_link_to_base_spec() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    write(&ito.join("specs/auth/spec.md"), ANCHOR_SPEC);
+
+    let files = vec![
+        DeltaSpecFile {
+            spec: "auth".to_string(),
+            markdown: "## MODIFIED Requirements\n\n### Requirement: Rate-limiting!\nChanged.\n\n## ADDED Requirements\n\n### Requirement: Logout\nUsers SHALL log out.\n".to_string(),
+        },
+        DeltaSpecFile {
+            spec: "billing".to_string(),
+            markdown: "## ADDED Requirements\n\n### Requirement: Invoices\nInvoices SHALL exist.\n".to_string(),
+        },
+    ];
+
+    let mut json = parse_change_show_json("001-01_demo", &files);
+    assert_eq!(json.deltas[0].requirement.anchor, "rate-limiting");
+    assert_eq!(json.deltas[1].requirement.anchor, "logout");
+
+    link_delta_base_anchors(&mut json, &FsSpecRepository::new(&ito));
+    assert_eq!(
+        json.deltas[0].base_anchor.as_deref(),
+        Some("rate-limiting-2")
+    );
+    assert_eq!(json.deltas[1].base_anchor, None);
+    assert_eq!(json.deltas[2].base_anchor, None);
+
+    let value = serde_json::to_value(&json).unwrap();
+    assert_eq!(value["deltas"][0]["baseAnchor"], "rate-limiting-2");
+    assert!(value["deltas"][1].get("baseAnchor").is_none());
+}
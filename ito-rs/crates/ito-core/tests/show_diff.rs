@@ -0,0 +1,192 @@
+use std::path::Path;
+
+use ito_config::ConfigContext;
+use ito_core::change_repository::FsChangeRepository;
+use ito_core::show::diff::{FileDiffStatus, MAX_DIFF_BYTES, diff_changes};
+
+fn write(path: &Path, contents: &str) {
+    let Some(parent) = path.parent() else {
+        panic!("path has no parent: {}", path.display());
+    };
+    std::fs::create_dir_all(parent).unwrap();
+    std::fs::write(path, contents).unwrap();
+}
+
+fn tasks_md(change_id: &str, tasks: &[(&str, &str, &str)]) -> String {
+    let mut out = format!("# Tasks for: {change_id}\n\n## Wave 1\n\n- **Depends On**: None\n");
+    for (id, name, status) in tasks {
+        let mark = if *status == "complete" { "x" } else { " " };
+        out.push_str(&format!(
+            "\n### Task {id}: {name}\n- **Dependencies**: None\n- **Updated At**: 2026-02-01\n- **Status**: [{mark}] {status}\n"
+        ));
+    }
+    out
+}
+
+/// An active change `001-01_alpha` and an archived change `001-02_beta`.
+fn setup(root: &Path) -> std::path::PathBuf {
+    let ito = root.join(".ito");
+    let a = ito.join("changes/001-01_alpha");
+    write(
+        &a.join("proposal.md"),
+        "# Alpha\n\n## Why\nLogin is slow.\n",
+    );
+    write(&a.join("specs/auth/spec.md"), "## ADDED Requirements\n");
+    write(
+        &a.join("tasks.md"),
+        &tasks_md(
+            "001-01_alpha",
+            &[("1.1", "First", "pending"), ("1.2", "Second", "pending")],
+        ),
+    );
+
+    let b = ito.join("changes/archive/2026-01-01-001-02_beta");
+    write(
+        &b.join("proposal.md"),
+        "# Alpha\n\n## Why\nLogin is fast.\n",
+    );
+    write(&b.join("design.md"), "# Design\n");
+    write(&b.join("specs/auth/spec.md"), "## ADDED Requirements\n");
+    write(&b.join("specs/billing/spec.md"), "## ADDED Requirements\n");
+    write(
+        &b.join("tasks.md"),
+        &tasks_md(
+            "001-02_beta",
+            &[("1.1", "First", "complete"), ("1.3", "Third", "pending")],
+        ),
+    );
+    ito
+}
+
+fn ctx(root: &Path) -> ConfigContext {
+    ConfigContext {
+        project_dir: Some(root.to_path_buf()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn diff_reports_artifact_presence_matrix() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = setup(td.path());
+    let repo = FsChangeRepository::new(&ito);
+
+    let diff = diff_changes(&repo, &ctx(td.path()), "001-01_alpha", "001-02_beta", None).unwrap();
+
+    assert_eq!(diff.change_a, "001-01_alpha");
+    assert_eq!(diff.change_b, "001-02_beta");
+    let matrix: Vec<(&str, bool, bool)> = diff
+        .artifacts
+        .iter()
+        .map(|a| (a.id.as_str(), a.in_a, a.in_b))
+        .collect();
+    assert_eq!(
+        matrix,
+        vec![
+            ("domain-discovery", false, false),
+            ("proposal", true, true),
+            ("specs", true, true),
+            ("design", false, true),
+            ("tasks", true, true),
+        ]
+    );
+
+    let specs = diff.artifacts.iter().find(|a| a.id == "specs").unwrap();
+    let files: Vec<(&str, FileDiffStatus)> = specs
+        .files
+        .iter()
+        .map(|f| (f.path.as_str(), f.status))
+        .collect();
+    assert_eq!(
+        files,
+        vec![
+            ("specs/auth/spec.md", FileDiffStatus::Identical),
+            ("specs/billing/spec.md", FileDiffStatus::OnlyInB),
+        ]
+    );
+}
+
+#[test]
+fn diff_includes_unified_hunk_for_changed_files() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = setup(td.path());
+    let repo = FsChangeRepository::new(&ito);
+
+    let diff = diff_changes(
+        &repo,
+        &ctx(td.path()),
+        "001-01_alpha",
+        "001-02_beta",
+        Some("proposal"),
+    )
+    .unwrap();
+
+    assert_eq!(diff.artifacts.len(), 1);
+    let file = &diff.artifacts[0].files[0];
+    assert_eq!(file.status, FileDiffStatus::Changed);
+    let patch = file.diff.as_deref().unwrap();
+    assert!(patch.starts_with("--- 001-01_alpha/proposal.md\n+++ 001-02_beta/proposal.md\n@@"));
+    assert!(patch.contains("\n-Login is slow.\n+Login is fast.\n"));
+}
+
+#[test]
+fn diff_compares_tasks_by_id() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = setup(td.path());
+    let repo = FsChangeRepository::new(&ito);
+
+    let tasks = diff_changes(&repo, &ctx(td.path()), "001-01_alpha", "001-02_beta", None)
+        .unwrap()
+        .tasks;
+
+    let ids = |tasks: &[ito_core::show::diff::TaskSnapshot]| -> Vec<String> {
+        tasks.iter().map(|t| t.id.clone()).collect()
+    };
+    assert_eq!(ids(&tasks.only_in_a), vec!["1.2"]);
+    assert_eq!(ids(&tasks.only_in_b), vec!["1.3"]);
+    assert_eq!(tasks.changed.len(), 1);
+    assert_eq!(tasks.changed[0].id, "1.1");
+    assert_eq!(tasks.changed[0].a.status, "pending");
+    assert_eq!(tasks.changed[0].b.status, "complete");
+}
+
+#[test]
+fn diff_skips_files_over_the_size_cap() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = setup(td.path());
+    let big = "x".repeat(MAX_DIFF_BYTES as usize + 1);
+    write(&ito.join("changes/001-01_alpha/design.md"), &big);
+    let repo = FsChangeRepository::new(&ito);
+
+    let diff = diff_changes(
+        &repo,
+        &ctx(td.path()),
+        "001-01_alpha",
+        "001-02_beta",
+        Some("design"),
+    )
+    .unwrap();
+
+    let file = &diff.artifacts[0].files[0];
+    assert_eq!(file.status, FileDiffStatus::Changed);
+    assert!(file.diff.is_none());
+    assert!(file.note.as_deref().unwrap().contains("too large to diff"));
+}
+
+#[test]
+fn diff_rejects_unknown_artifact() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = setup(td.path());
+    let repo = FsChangeRepository::new(&ito);
+
+    let err = diff_changes(
+        &repo,
+        &ctx(td.path()),
+        "001-01_alpha",
+        "001-02_beta",
+        Some("nope"),
+    )
+    .unwrap_err();
+
+    assert!(err.to_string().contains("unknown artifact 'nope'"));
+}
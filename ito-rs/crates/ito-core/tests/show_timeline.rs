@@ -0,0 +1,355 @@
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use ito_core::audit::{AuditEventStore, AuditStorageLocation};
+use ito_core::change_repository::FsChangeRepository;
+use ito_core::process::{ProcessExecutionError, ProcessOutput, ProcessRequest, ProcessRunner};
+use ito_core::ralph::state::{RalphHistoryEntry, RalphState, save_state};
+use ito_core::show::timeline::{
+    TimelineCommit, TimelineKind, build_timeline, change_timeline, group_by_day,
+};
+use ito_domain::audit::event::{AuditEvent, EventContext, SCHEMA_VERSION};
+use ito_domain::audit::writer::AuditWriter;
+
+const CHANGE: &str = "001-01_alpha";
+
+#[derive(Default)]
+struct MemoryAuditStore {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+impl AuditWriter for MemoryAuditStore {
+    fn append(&self, event: &AuditEvent) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.events
+            .lock()
+            .expect("memory audit store lock")
+            .push(event.clone());
+        Ok(())
+    }
+}
+
+impl AuditEventStore for MemoryAuditStore {
+    fn read_all(&self) -> Vec<AuditEvent> {
+        self.events.lock().expect("memory audit store lock").clone()
+    }
+
+    fn location(&self) -> AuditStorageLocation {
+        AuditStorageLocation::Other("memory".to_string())
+    }
+}
+
+/// Replies to `git log` with `stdout`, or fails to spawn when `None`.
+struct GitStub {
+    stdout: Option<String>,
+    requests: Mutex<Vec<ProcessRequest>>,
+}
+
+impl GitStub {
+    fn new(stdout: Option<&str>) -> Self {
+        Self {
+            stdout: stdout.map(str::to_string),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl ProcessRunner for GitStub {
+    fn run(&self, request: &ProcessRequest) -> Result<ProcessOutput, ProcessExecutionError> {
+        self.requests.lock().unwrap().push(request.clone());
+        let Some(stdout) = &self.stdout else {
+            return Err(ProcessExecutionError::Spawn {
+                program: request.program.clone(),
+                source: io::Error::new(io::ErrorKind::NotFound, "No such file or directory"),
+            });
+        };
+        Ok(ProcessOutput {
+            exit_code: 0,
+            success: true,
+            stdout: stdout.clone(),
+            stderr: String::new(),
+            timed_out: false,
+            resources: Default::default(),
+        })
+    }
+
+    fn run_with_timeout(
+        &self,
+        request: &ProcessRequest,
+        _timeout: Duration,
+    ) -> Result<ProcessOutput, ProcessExecutionError> {
+        self.run(request)
+    }
+}
+
+fn at(day: u32, h: u32, m: u32, s: u32, ms: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, day, h, m, s).unwrap() + chrono::Duration::milliseconds(ms)
+}
+
+fn event(ts: DateTime<Utc>, entity: &str, entity_id: &str, scope: Option<&str>) -> AuditEvent {
+    AuditEvent {
+        v: SCHEMA_VERSION,
+        ts: ts.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        entity: entity.to_string(),
+        entity_id: entity_id.to_string(),
+        scope: scope.map(str::to_string),
+        op: "status_change".to_string(),
+        from: Some("pending".to_string()),
+        to: Some("in-progress".to_string()),
+        actor: "cli".to_string(),
+        by: "@test".to_string(),
+        meta: None,
+        count: 1,
+        ctx: EventContext {
+            session_id: "s1".to_string(),
+            harness_session_id: None,
+            branch: None,
+            worktree: None,
+            commit: None,
+        },
+    }
+}
+
+fn iteration(finished: DateTime<Utc>, promise: bool) -> RalphHistoryEntry {
+    RalphHistoryEntry {
+        timestamp: finished.timestamp_millis(),
+        duration: 42_000,
+        completion_promise_found: promise,
+        file_changes_count: 3,
+        harness_exit_code: 0,
+        completion_validated: promise,
+        effective_cwd: "/repo".to_string(),
+        completion_summary: None,
+        usage: None,
+    }
+}
+
+fn ralph(history: Vec<RalphHistoryEntry>) -> RalphState {
+    RalphState {
+        change_id: CHANGE.to_string(),
+        iteration: u32::try_from(history.len()).unwrap(),
+        history,
+        context_file: String::new(),
+        last_outcome: None,
+        last_failure: None,
+        harness_session_id: None,
+        start_commit: None,
+    }
+}
+
+fn commit(ts: DateTime<Utc>, hash: &str, subject: &str) -> TimelineCommit {
+    TimelineCommit {
+        hash: hash.to_string(),
+        timestamp: ts,
+        subject: subject.to_string(),
+    }
+}
+
+/// An `.ito` tree holding one active change.
+fn project() -> tempfile::TempDir {
+    let td = tempfile::tempdir().unwrap();
+    let change = td.path().join(".ito/changes").join(CHANGE);
+    std::fs::create_dir_all(&change).unwrap();
+    std::fs::write(change.join("proposal.md"), "# Alpha\n\n## Why\nBecause.\n").unwrap();
+    td
+}
+
+fn summaries(entries: &[ito_core::show::timeline::TimelineEntry]) -> Vec<(TimelineKind, &str)> {
+    entries
+        .iter()
+        .map(|entry| (entry.kind, entry.summary.as_str()))
+        .collect()
+}
+
+#[test]
+fn entries_from_all_sources_sort_chronologically() {
+    let audit = vec![
+        event(at(1, 9, 0, 0, 0), "change", CHANGE, None),
+        event(at(1, 10, 0, 0, 700), "task", "1.1", Some(CHANGE)),
+        event(at(1, 9, 30, 0, 0), "task", "9.9", Some("001-02_other")),
+    ];
+    let state = ralph(vec![
+        iteration(at(1, 10, 0, 0, 200), false),
+        iteration(at(2, 8, 0, 0, 0), true),
+    ]);
+    let commits = vec![
+        commit(at(2, 9, 0, 0, 0), "bbbb", "Finish alpha"),
+        commit(at(1, 10, 0, 0, 0), "aaaa", "Start alpha"),
+    ];
+
+    let entries = build_timeline(CHANGE, &audit, Some(&state), &commits);
+
+    // The 10:00:00 second holds one entry per source; ties go audit, ralph, commit.
+    assert_eq!(
+        summaries(&entries),
+        vec![
+            (
+                TimelineKind::Audit,
+                "change 001-01_alpha status_change: pending -> in-progress"
+            ),
+            (
+                TimelineKind::Audit,
+                "task 1.1 status_change: pending -> in-progress"
+            ),
+            (TimelineKind::Ralph, "ralph iteration 1 (42s, no promise)"),
+            (TimelineKind::Commit, "Start alpha"),
+            (
+                TimelineKind::Ralph,
+                "ralph iteration 2 (42s, promise found)"
+            ),
+            (TimelineKind::Commit, "Finish alpha"),
+        ]
+    );
+    assert_eq!(entries[0].details, "human @test");
+    assert_eq!(entries[2].details, "exit code 0, 3 file(s) changed");
+    assert_eq!(entries[3].details, "aaaa");
+}
+
+#[test]
+fn same_second_ties_are_deterministic() {
+    let ts = at(1, 10, 0, 0, 0);
+    let forward = vec![commit(ts, "aaaa", "One"), commit(ts, "bbbb", "Two")];
+    let reversed: Vec<TimelineCommit> = forward.iter().rev().cloned().collect();
+
+    let a = build_timeline(CHANGE, &[], None, &forward);
+    let b = build_timeline(CHANGE, &[], None, &reversed);
+
+    assert_eq!(a, b);
+    assert_eq!(a[0].details, "aaaa");
+}
+
+#[test]
+fn entries_group_by_utc_day() {
+    let commits = vec![
+        commit(at(1, 9, 0, 0, 0), "aaaa", "One"),
+        commit(at(1, 23, 59, 59, 0), "bbbb", "Two"),
+        commit(at(3, 0, 0, 0, 0), "cccc", "Three"),
+    ];
+    let entries = build_timeline(CHANGE, &[], None, &commits);
+
+    let days: Vec<(NaiveDate, Vec<&str>)> = group_by_day(&entries)
+        .into_iter()
+        .map(|(day, group)| {
+            (
+                day,
+                group.iter().map(|entry| entry.summary.as_str()).collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        days,
+        vec![
+            (
+                NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
+                vec!["One", "Two"]
+            ),
+            (NaiveDate::from_ymd_opt(2026, 2, 3).unwrap(), vec!["Three"]),
+        ]
+    );
+}
+
+#[test]
+fn missing_sources_contribute_nothing() {
+    let td = project();
+    let ito = td.path().join(".ito");
+    let repo = FsChangeRepository::new(&ito);
+    let no_git = GitStub::new(None);
+
+    let timeline = change_timeline(
+        &repo,
+        &MemoryAuditStore::default(),
+        &no_git,
+        &ito,
+        "001-01",
+        10,
+    )
+    .unwrap();
+    assert_eq!(timeline.change_id, CHANGE);
+    assert!(timeline.entries.is_empty());
+
+    let audit = MemoryAuditStore::default();
+    audit
+        .append(&event(at(1, 9, 0, 0, 0), "change", CHANGE, None))
+        .unwrap();
+    let timeline = change_timeline(&repo, &audit, &no_git, &ito, CHANGE, 10).unwrap();
+    assert_eq!(
+        summaries(&timeline.entries),
+        vec![(
+            TimelineKind::Audit,
+            "change 001-01_alpha status_change: pending -> in-progress"
+        )]
+    );
+
+    save_state(
+        &ito,
+        CHANGE,
+        &ralph(vec![iteration(at(1, 10, 0, 0, 0), true)]),
+    )
+    .unwrap();
+    let timeline = change_timeline(
+        &repo,
+        &MemoryAuditStore::default(),
+        &no_git,
+        &ito,
+        CHANGE,
+        10,
+    )
+    .unwrap();
+    assert_eq!(
+        summaries(&timeline.entries),
+        vec![(
+            TimelineKind::Ralph,
+            "ralph iteration 1 (42s, promise found)"
+        )]
+    );
+}
+
+#[test]
+fn commits_come_from_git_log_on_the_change_directory() {
+    let td = project();
+    let ito = td.path().join(".ito");
+    let repo = FsChangeRepository::new(&ito);
+    let git = GitStub::new(Some(
+        "bbbb\u{1f}2026-02-02T09:00:00+01:00\u{1f}Finish alpha\naaaa\u{1f}2026-02-01T10:00:00Z\u{1f}Start alpha\n",
+    ));
+
+    let timeline =
+        change_timeline(&repo, &MemoryAuditStore::default(), &git, &ito, CHANGE, 5).unwrap();
+
+    assert_eq!(
+        summaries(&timeline.entries),
+        vec![
+            (TimelineKind::Commit, "Start alpha"),
+            (TimelineKind::Commit, "Finish alpha"),
+        ]
+    );
+    assert_eq!(timeline.entries[1].timestamp, at(2, 8, 0, 0, 0));
+
+    let requests = git.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].program, "git");
+    assert!(requests[0].args.contains(&"--max-count=5".to_string()));
+    let change_dir = Path::new(".ito/changes").join(CHANGE);
+    assert!(
+        requests[0]
+            .args
+            .iter()
+            .any(|arg| Path::new(arg) == change_dir),
+        "{:?}",
+        requests[0].args
+    );
+}
+
+#[test]
+fn zero_commit_limit_skips_git() {
+    let td = project();
+    let ito = td.path().join(".ito");
+    let repo = FsChangeRepository::new(&ito);
+    let git = GitStub::new(Some(""));
+
+    change_timeline(&repo, &MemoryAuditStore::default(), &git, &ito, CHANGE, 0).unwrap();
+
+    assert!(git.requests.lock().unwrap().is_empty());
+}
@@ -0,0 +1,123 @@
+use ito_core::change_repository::FsChangeRepository;
+use ito_core::show::coverage::compute_spec_coverage;
+use ito_core::spec_repository::FsSpecRepository;
+use std::path::Path;
+
+fn write(path: &Path, contents: &str) {
+    let Some(parent) = path.parent() else {
+        panic!("path has no parent: {}", path.display());
+    };
+    std::fs::create_dir_all(parent).unwrap();
+    std::fs::write(path, contents).unwrap();
+}
+
+fn requirement(title: &str) -> String {
+    format!(
+        "### Requirement: {title}\nThe system SHALL support {title}.\n\n#### Scenario: Works\n- **WHEN** used\n- **THEN** it works\n\n"
+    )
+}
+
+fn setup(ito: &Path) {
+    let base = format!(
+        "# Auth\n\n## Purpose\n\nAuthentication behaviour for the service.\n\n## Requirements\n\n{}{}{}",
+        requirement("Password login"),
+        requirement("Session expiry"),
+        requirement("Token refresh"),
+    );
+    write(&ito.join("specs/auth/spec.md"), &base);
+
+    write(
+        &ito.join("changes/archive/2026-01-02-001-01_login/specs/auth/spec.md"),
+        &format!("## ADDED Requirements\n\n{}", requirement("Password login")),
+    );
+    write(
+        &ito.join("changes/archive/2026-01-02-001-01_login/proposal.md"),
+        "# Proposal\n",
+    );
+    write(
+        &ito.join("changes/001-02_refresh/specs/auth/spec.md"),
+        &format!(
+            "## MODIFIED Requirements\n\n{}{}## ADDED Requirements\n\n{}",
+            requirement("Token refreshes"),
+            requirement("Remember me"),
+            requirement("Single sign-on"),
+        ),
+    );
+    write(
+        &ito.join("changes/001-02_refresh/proposal.md"),
+        "# Proposal\n",
+    );
+}
+
+#[test]
+fn coverage_links_requirements_to_changes_and_reports_gaps() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    setup(&ito);
+
+    let specs = FsSpecRepository::new(&ito);
+    let changes = FsChangeRepository::new(&ito);
+    let report = compute_spec_coverage(&specs, &changes, Some("auth")).unwrap();
+
+    assert_eq!(report.specs.len(), 1);
+    let auth = &report.specs[0];
+    assert_eq!(auth.spec_id, "auth");
+
+    let login = &auth.requirements[0];
+    assert_eq!(login.title, "Password login");
+    assert_eq!(login.touched_by.len(), 1);
+    assert_eq!(login.touched_by[0].change_id, "001-01_login");
+    assert!(login.touched_by[0].archived);
+    assert!(!login.touched_by[0].possible_match);
+
+    assert!(auth.requirements[1].is_uncovered());
+
+    let refresh = &auth.requirements[2];
+    assert_eq!(refresh.touched_by.len(), 1);
+    assert_eq!(refresh.touched_by[0].change_id, "001-02_refresh");
+    assert!(refresh.touched_by[0].possible_match);
+
+    // The modified "Remember me" matches nothing; the active ADDED delta is expected.
+    assert_eq!(auth.orphaned.len(), 1);
+    assert_eq!(auth.orphaned[0].title, "Remember me");
+
+    assert_eq!(report.summary.requirements, 3);
+    assert_eq!(report.summary.covered, 2);
+    assert_eq!(report.summary.uncovered, 1);
+    assert_eq!(report.summary.orphaned, 1);
+}
+
+#[test]
+fn coverage_without_spec_id_covers_every_spec() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    setup(&ito);
+    write(
+        &ito.join("specs/billing/spec.md"),
+        &format!(
+            "# Billing\n\n## Purpose\n\nBilling.\n\n## Requirements\n\n{}",
+            requirement("Invoices")
+        ),
+    );
+
+    let specs = FsSpecRepository::new(&ito);
+    let changes = FsChangeRepository::new(&ito);
+    let report = compute_spec_coverage(&specs, &changes, None).unwrap();
+
+    let ids: Vec<&str> = report.specs.iter().map(|s| s.spec_id.as_str()).collect();
+    assert_eq!(ids, vec!["auth", "billing"]);
+    assert_eq!(report.summary.requirements, 4);
+    assert_eq!(report.summary.uncovered, 2);
+}
+
+#[test]
+fn coverage_for_missing_spec_is_an_error() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    setup(&ito);
+
+    let specs = FsSpecRepository::new(&ito);
+    let changes = FsChangeRepository::new(&ito);
+    let err = compute_spec_coverage(&specs, &changes, Some("nope")).unwrap_err();
+    assert!(err.to_string().contains("nope"), "{err}");
+}
@@ -0,0 +1,140 @@
+use std::path::Path;
+
+use ito_core::archive::{
+    apply_deltas_preview, copy_specs_to_main, copy_specs_to_main_skipping_conflicts,
+    preview_change_spec,
+};
+
+const BASE: &str = "# Alpha\n\n## Purpose\n\nAlpha behavior.\n\n## Requirements\n\n### Requirement: Login\nUsers SHALL log in.\n\n#### Scenario: Valid login\n- **WHEN** credentials are valid\n- **THEN** the user is logged in\n\n### Requirement: Logout\nUsers SHALL log out.\n";
+
+fn write(path: &Path, contents: &str) {
+    std::fs::create_dir_all(path.parent().expect("parent")).expect("create dirs");
+    std::fs::write(path, contents).expect("write");
+}
+
+#[test]
+fn preview_of_clean_add_appends_requirement() {
+    let delta = "## ADDED Requirements\n\n### Requirement: Audit\nLogins SHALL be audited.\n";
+    let preview = apply_deltas_preview(Some(BASE), delta).expect("preview");
+
+    assert!(preview.conflicts.is_empty());
+    let merged = preview.merged.expect("merged spec");
+    assert!(merged.contains("### Requirement: Login"));
+    assert!(merged.contains("### Requirement: Audit\nLogins SHALL be audited."));
+    assert!(preview.diff.starts_with("--- current\n+++ preview\n"));
+    assert!(preview.diff.contains("+### Requirement: Audit"));
+}
+
+#[test]
+fn preview_of_modify_replaces_existing_requirement() {
+    let delta = "## MODIFIED Requirements\n\n### Requirement: Logout\nUsers SHALL log out from every device.\n";
+    let preview = apply_deltas_preview(Some(BASE), delta).expect("preview");
+
+    assert!(preview.conflicts.is_empty());
+    let merged = preview.merged.expect("merged spec");
+    assert!(merged.contains("Users SHALL log out from every device."));
+    assert!(preview.diff.contains("-Users SHALL log out."));
+    assert!(
+        preview
+            .diff
+            .contains("+Users SHALL log out from every device.")
+    );
+}
+
+#[test]
+fn preview_of_removal_drops_requirement() {
+    let delta = "## REMOVED Requirements\n\n### Requirement: Logout\nNo longer needed.\n";
+    let preview = apply_deltas_preview(Some(BASE), delta).expect("preview");
+
+    assert!(preview.conflicts.is_empty());
+    let merged = preview.merged.expect("merged spec");
+    assert!(!merged.contains("### Requirement: Logout"));
+    assert!(preview.diff.contains("-### Requirement: Logout"));
+}
+
+#[test]
+fn preview_of_rename_changes_heading_only() {
+    let delta = "## RENAMED Requirements\n\n- FROM: `### Requirement: Logout`\n- TO: `### Requirement: Sign out`\n";
+    let preview = apply_deltas_preview(Some(BASE), delta).expect("preview");
+
+    assert!(preview.conflicts.is_empty());
+    let merged = preview.merged.expect("merged spec");
+    assert!(merged.contains("### Requirement: Sign out\nUsers SHALL log out."));
+    assert!(preview.diff.contains("-### Requirement: Logout"));
+    assert!(preview.diff.contains("+### Requirement: Sign out"));
+}
+
+#[test]
+fn preview_reports_conflicts_and_applies_the_rest() {
+    let delta = "## ADDED Requirements\n\n### Requirement: Login\nDuplicate.\n\n### Requirement: Audit\nLogins SHALL be audited.\n\n## MODIFIED Requirements\n\n### Requirement: Missing\nNothing to modify.\n\n## REMOVED Requirements\n\n### Requirement: Gone\nNothing to remove.\n";
+    let preview = apply_deltas_preview(Some(BASE), delta).expect("preview");
+
+    let conflicts: Vec<(&str, &str)> = preview
+        .conflicts
+        .iter()
+        .map(|c| (c.operation, c.requirement.as_str()))
+        .collect();
+    assert_eq!(
+        conflicts,
+        [
+            ("ADDED", "Login"),
+            ("MODIFIED", "Missing"),
+            ("REMOVED", "Gone")
+        ]
+    );
+    assert!(
+        preview.conflicts[1]
+            .message
+            .contains("cannot modify missing requirement 'Missing'")
+    );
+    let merged = preview.merged.expect("merged spec");
+    assert!(merged.contains("### Requirement: Audit"));
+    assert!(!merged.contains("Duplicate."));
+}
+
+#[test]
+fn archive_copy_refuses_conflicts_unless_skipping() {
+    let td = tempfile::tempdir().expect("tempdir");
+    let ito = td.path().join(".ito");
+    let change = "001-01_conflicting";
+    write(&ito.join("specs/alpha/spec.md"), BASE);
+    write(
+        &ito.join(format!("changes/{change}/specs/alpha/spec.md")),
+        "## ADDED Requirements\n\n### Requirement: Audit\nLogins SHALL be audited.\n\n## MODIFIED Requirements\n\n### Requirement: Missing\nNothing to modify.\n",
+    );
+    let specs = ["alpha".to_string()];
+
+    let preview = preview_change_spec(&ito, change, "alpha").expect("preview");
+    assert_eq!(preview.conflicts.len(), 1);
+    assert!(preview.diff.starts_with(&format!(
+        "--- specs/alpha/spec.md\n+++ specs/alpha/spec.md (after {change})\n"
+    )));
+
+    let err = copy_specs_to_main(&ito, change, &specs).expect_err("conflict");
+    assert!(
+        err.to_string()
+            .contains("alpha: cannot modify missing requirement 'Missing'")
+    );
+    assert_eq!(
+        std::fs::read_to_string(ito.join("specs/alpha/spec.md")).unwrap(),
+        BASE
+    );
+
+    let (updated, skipped) =
+        copy_specs_to_main_skipping_conflicts(&ito, change, &specs).expect("forced copy");
+    assert_eq!(updated, ["alpha"]);
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(skipped[0].0, "alpha");
+    let written = std::fs::read_to_string(ito.join("specs/alpha/spec.md")).unwrap();
+    assert_eq!(Some(written), preview.merged);
+}
+
+#[test]
+fn preview_of_missing_delta_is_not_found() {
+    let td = tempfile::tempdir().expect("tempdir");
+    let ito = td.path().join(".ito");
+    std::fs::create_dir_all(ito.join("changes/001-01_empty")).unwrap();
+
+    let err = preview_change_spec(&ito, "001-01_empty", "alpha").expect_err("no delta");
+    assert!(err.to_string().contains("has no delta for spec 'alpha'"));
+}
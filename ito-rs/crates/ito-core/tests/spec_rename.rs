@@ -0,0 +1,189 @@
+//! Integration tests for renaming a spec across changes.
+
+use std::fs;
+use std::path::Path;
+
+use ito_core::errors::CoreError;
+use ito_core::spec_rename::{apply_spec_rename, plan_spec_rename};
+
+const CHANGE_A: &str = "001-01_add-login";
+const CHANGE_B: &str = "001-02_add-logout";
+const ARCHIVED: &str = "2025-01-01-000-01_bootstrap";
+
+fn write(path: &Path, contents: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+fn fixture() -> tempfile::TempDir {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path();
+
+    write(
+        &ito.join("specs/auth-session/spec.md"),
+        "# Auth Session\n\n## Requirements\n",
+    );
+    write(&ito.join("specs/auth-session-tokens/spec.md"), "# Tokens\n");
+
+    write(
+        &ito.join("changes").join(CHANGE_A).join("proposal.md"),
+        "## Why\nLogin\n\n## Capabilities\n- `auth-session`: login flow\n- `auth-session-tokens`: unchanged\n",
+    );
+    write(
+        &ito.join("changes").join(CHANGE_A).join("design.md"),
+        "Extends auth-session with remember-me.\n",
+    );
+    write(
+        &ito.join("changes")
+            .join(CHANGE_A)
+            .join("specs/auth-session/spec.md"),
+        "## ADDED Requirements\n",
+    );
+
+    write(
+        &ito.join("changes").join(CHANGE_B).join("proposal.md"),
+        "## Why\nLogout touches auth-session twice: auth-session.\n",
+    );
+    write(
+        &ito.join("changes")
+            .join(CHANGE_B)
+            .join("specs/auth-session/spec.md"),
+        "## MODIFIED Requirements\n",
+    );
+
+    write(
+        &ito.join("modules/001_auth/module.md"),
+        "# Auth\n\nSpecs: auth-session\n",
+    );
+
+    write(
+        &ito.join("changes/archive")
+            .join(ARCHIVED)
+            .join("proposal.md"),
+        "Introduced auth-session.\n",
+    );
+    write(
+        &ito.join("changes/archive")
+            .join(ARCHIVED)
+            .join("specs/auth-session/spec.md"),
+        "## ADDED Requirements\n",
+    );
+
+    td
+}
+
+#[test]
+fn rename_moves_spec_and_deltas_and_rewrites_references() {
+    let td = fixture();
+    let ito = td.path();
+
+    let plan = plan_spec_rename(ito, "auth-session", "identity-session").unwrap();
+    assert_eq!(plan.moves.len(), 3);
+    assert_eq!(
+        plan.affected_changes(),
+        vec![CHANGE_A.to_string(), CHANGE_B.to_string()]
+    );
+    assert_eq!(plan.archived_references, vec![ARCHIVED.to_string()]);
+
+    apply_spec_rename(&plan).unwrap();
+
+    assert!(!ito.join("specs/auth-session").exists());
+    assert!(ito.join("specs/identity-session/spec.md").is_file());
+    assert!(ito.join("specs/auth-session-tokens/spec.md").is_file());
+    for change in [CHANGE_A, CHANGE_B] {
+        let specs = ito.join("changes").join(change).join("specs");
+        assert!(!specs.join("auth-session").exists(), "{change}");
+        assert!(specs.join("identity-session/spec.md").is_file(), "{change}");
+    }
+
+    let proposal_a =
+        fs::read_to_string(ito.join("changes").join(CHANGE_A).join("proposal.md")).unwrap();
+    assert!(proposal_a.contains("- `identity-session`: login flow"));
+    assert!(proposal_a.contains("- `auth-session-tokens`: unchanged"));
+    let design_a =
+        fs::read_to_string(ito.join("changes").join(CHANGE_A).join("design.md")).unwrap();
+    assert_eq!(design_a, "Extends identity-session with remember-me.\n");
+    let proposal_b =
+        fs::read_to_string(ito.join("changes").join(CHANGE_B).join("proposal.md")).unwrap();
+    assert_eq!(
+        proposal_b,
+        "## Why\nLogout touches identity-session twice: identity-session.\n"
+    );
+    let module = fs::read_to_string(ito.join("modules/001_auth/module.md")).unwrap();
+    assert!(module.contains("Specs: identity-session"));
+
+    // Archived changes are historical records and stay untouched.
+    let archived = ito.join("changes/archive").join(ARCHIVED);
+    assert!(archived.join("specs/auth-session/spec.md").is_file());
+    assert_eq!(
+        fs::read_to_string(archived.join("proposal.md")).unwrap(),
+        "Introduced auth-session.\n"
+    );
+}
+
+#[test]
+fn plan_reports_edits_per_file_with_counts() {
+    let td = fixture();
+    let plan = plan_spec_rename(td.path(), "auth-session", "identity-session").unwrap();
+
+    let edits: Vec<(Option<&str>, String, usize)> = plan
+        .edits
+        .iter()
+        .map(|edit| {
+            (
+                edit.change_id.as_deref(),
+                edit.path.file_name().unwrap().to_string_lossy().to_string(),
+                edit.occurrences,
+            )
+        })
+        .collect();
+    assert_eq!(
+        edits,
+        vec![
+            (Some(CHANGE_A), "proposal.md".to_string(), 1),
+            (Some(CHANGE_A), "design.md".to_string(), 1),
+            (Some(CHANGE_B), "proposal.md".to_string(), 2),
+            (None, "module.md".to_string(), 1),
+        ]
+    );
+}
+
+#[test]
+fn planning_alone_makes_no_changes() {
+    let td = fixture();
+    let ito = td.path();
+    let before = snapshot(ito);
+
+    let plan = plan_spec_rename(ito, "auth-session", "identity-session").unwrap();
+    assert!(!plan.moves.is_empty());
+
+    assert_eq!(snapshot(ito), before);
+    assert!(!ito.join("specs/identity-session").exists());
+}
+
+#[test]
+fn rename_refuses_existing_target_spec() {
+    let td = fixture();
+    let err = plan_spec_rename(td.path(), "auth-session", "auth-session-tokens").unwrap_err();
+    assert!(matches!(err, CoreError::Validation(_)), "{err}");
+}
+
+fn snapshot(root: &Path) -> Vec<(String, String)> {
+    let mut out = Vec::new();
+    for entry in walkdir::WalkDir::new(root).sort_by_file_name() {
+        let entry = entry.unwrap();
+        let rel = entry
+            .path()
+            .strip_prefix(root)
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+        let contents = if entry.file_type().is_file() {
+            fs::read_to_string(entry.path()).unwrap()
+        } else {
+            String::new()
+        };
+        out.push((rel, contents));
+    }
+    out
+}
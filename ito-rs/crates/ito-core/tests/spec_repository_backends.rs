@@ -256,6 +256,14 @@ impl TaskMutationService for FakeTaskMutations {
         Err(TaskMutationError::validation("unused in test"))
     }
 
+    fn release_task(
+        &self,
+        _change_id: &str,
+        _task_id: &str,
+    ) -> TaskMutationServiceResult<TaskMutationResult> {
+        Err(TaskMutationError::validation("unused in test"))
+    }
+
     fn add_task(
         &self,
         _change_id: &str,
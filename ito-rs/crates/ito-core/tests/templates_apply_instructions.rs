@@ -92,7 +92,21 @@ artifacts:
     assert_eq!(r.progress.pending, Some(1));
     assert_eq!(r.tasks.len(), 3);
 
-    // 5) All tasks complete -> all_done.
+    // 5) Nested checkbox subtasks: only leaf tasks count toward progress.
+    write(
+        &change_dir.join("tasks.md"),
+        "- [ ] Parent\n  - [x] Child A\n  - [x] Child B\n  - [x] Child C\n",
+    );
+    let r = compute_apply_instructions(&ito_path, change, Some("demo"), &ctx)
+        .expect("compute_apply_instructions");
+    assert_eq!(r.tasks.len(), 4);
+    assert_eq!(r.tasks[1].parent_id.as_deref(), Some("1"));
+    assert_eq!(r.tasks[1].depth, 1);
+    assert_eq!(r.progress.total, 3);
+    assert_eq!(r.progress.complete, 3);
+    assert_eq!(r.state, "all_done");
+
+    // 6) All tasks complete -> all_done.
     write(
         &change_dir.join("tasks.md"),
         "## 1. Implementation\n- [x] A\n- [x] B\n",
@@ -0,0 +1,113 @@
+use ito_config::ConfigContext;
+use ito_core::templates::{ApplyInstructionsResponse, compute_apply_instructions};
+use std::path::{Path, PathBuf};
+
+fn write(path: &Path, contents: &str) {
+    let Some(parent) = path.parent() else {
+        panic!("path has no parent: {}", path.display());
+    };
+    std::fs::create_dir_all(parent).expect("create dir should succeed");
+    std::fs::write(path, contents).expect("write should succeed");
+}
+
+const CHANGE: &str = "001-01_demo";
+
+struct Fixture {
+    _td: tempfile::TempDir,
+    ito_path: PathBuf,
+    ctx: ConfigContext,
+}
+
+impl Fixture {
+    fn new(tracks: &str) -> Self {
+        let td = tempfile::tempdir().expect("tempdir should succeed");
+        let project_root = td.path().to_path_buf();
+        let ito_path = project_root.join(".ito");
+        write(
+            &project_root.join(".ito/templates/schemas/demo/schema.yaml"),
+            &format!("name: demo\nversion: 1\nartifacts: []\napply:\n  tracks: {tracks}\n"),
+        );
+        std::fs::create_dir_all(ito_path.join("changes").join(CHANGE)).expect("create change");
+        let ctx = ConfigContext {
+            project_dir: Some(project_root),
+            ..Default::default()
+        };
+        Self {
+            _td: td,
+            ito_path,
+            ctx,
+        }
+    }
+
+    fn write_tracking(&self, file: &str, contents: &str) {
+        write(
+            &self.ito_path.join("changes").join(CHANGE).join(file),
+            contents,
+        );
+    }
+
+    fn apply(&self) -> ApplyInstructionsResponse {
+        compute_apply_instructions(&self.ito_path, CHANGE, Some("demo"), &self.ctx)
+            .expect("compute_apply_instructions")
+    }
+}
+
+#[test]
+fn single_tracking_file_keeps_single_file_fields() {
+    let f = Fixture::new("tasks.md");
+    f.write_tracking("tasks.md", "- [x] Done\n- [ ] Todo\n");
+
+    let r = f.apply();
+    assert_eq!(r.state, "ready");
+    assert_eq!(r.tracks_file.as_deref(), Some("tasks.md"));
+    assert_eq!(r.tracks_format.as_deref(), Some("checkbox"));
+    assert!(
+        r.tracks_path
+            .as_deref()
+            .is_some_and(|p| p.ends_with("tasks.md"))
+    );
+    assert_eq!(r.progress.total, 2);
+    assert_eq!(r.progress.complete, 1);
+    assert_eq!(r.tracks.len(), 1);
+    assert!(r.tracks[0].exists);
+    assert_eq!(r.tasks[0].id, "1");
+}
+
+#[test]
+fn multiple_tracking_files_aggregate_progress_and_prefix_colliding_ids() {
+    let f = Fixture::new("[backend.md, frontend.md]");
+    f.write_tracking("backend.md", "- [x] Schema\n- [x] Api\n- [x] Migrations\n");
+    f.write_tracking("frontend.md", "- [x] Layout\n- [ ] Wiring\n");
+
+    let r = f.apply();
+    assert_eq!(r.state, "ready");
+    assert_eq!(r.tracks_file.as_deref(), Some("backend.md"));
+    assert_eq!(r.progress.total, 5);
+    assert_eq!(r.progress.complete, 4);
+    assert_eq!(r.progress.remaining, 1);
+    assert_eq!(r.tracks.len(), 2);
+    assert_eq!(r.tracks[0].progress.remaining, 0);
+    assert_eq!(r.tracks[1].progress.remaining, 1);
+
+    let ids: Vec<&str> = r.tasks.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        vec!["backend:1", "backend:2", "3", "frontend:1", "frontend:2"]
+    );
+
+    f.write_tracking("frontend.md", "- [x] Layout\n- [x] Wiring\n");
+    assert_eq!(f.apply().state, "all_done");
+}
+
+#[test]
+fn missing_second_tracking_file_blocks_apply() {
+    let f = Fixture::new("[backend.md, frontend.md]");
+    f.write_tracking("backend.md", "- [x] Schema\n");
+
+    let r = f.apply();
+    assert_eq!(r.state, "blocked");
+    assert!(r.instruction.contains("frontend.md"), "{}", r.instruction);
+    assert!(r.tracks[0].exists);
+    assert!(!r.tracks[1].exists);
+    assert_eq!(r.progress.total, 1);
+}
@@ -0,0 +1,164 @@
+use std::path::{Path, PathBuf};
+
+use ito_config::ConfigContext;
+use ito_core::templates::{
+    WorkflowError, compute_apply_instructions, compute_change_status, resolve_instructions,
+    skip_artifact, unskip_artifact,
+};
+
+const CHANGE: &str = "demo-change";
+
+struct Fixture {
+    _td: tempfile::TempDir,
+    ito_path: PathBuf,
+    change_dir: PathBuf,
+    ctx: ConfigContext,
+}
+
+fn write(path: &Path, contents: &str) {
+    std::fs::create_dir_all(path.parent().expect("parent")).expect("create parent");
+    std::fs::write(path, contents).expect("write file");
+}
+
+fn fixture() -> Fixture {
+    let td = tempfile::tempdir().expect("tempdir should succeed");
+    let project_root = td.path().to_path_buf();
+    let ito_path = project_root.join(".ito");
+    let change_dir = ito_path.join("changes").join(CHANGE);
+    write(&change_dir.join(".ito.yaml"), "schema: demo\n");
+    write(&change_dir.join("proposal.md"), "## Why\nFix\n");
+
+    write(
+        &project_root.join(".ito/templates/schemas/demo/schema.yaml"),
+        r#"name: demo
+version: 1
+artifacts:
+  - id: proposal
+    generates: proposal.md
+    template: proposal.md
+    skippable: false
+    requires: []
+  - id: design
+    generates: design.md
+    template: design.md
+    requires: ["proposal"]
+  - id: tasks
+    generates: tasks.md
+    template: tasks.md
+    requires: ["design"]
+apply:
+  requires: ["design", "tasks"]
+  tracks: tasks.md
+"#,
+    );
+    for template in ["proposal.md", "design.md", "tasks.md"] {
+        write(
+            &project_root
+                .join(".ito/templates/schemas/demo/templates")
+                .join(template),
+            "# Template\n",
+        );
+    }
+
+    let ctx = ConfigContext {
+        project_dir: Some(project_root),
+        ..Default::default()
+    };
+    Fixture {
+        _td: td,
+        ito_path,
+        change_dir,
+        ctx,
+    }
+}
+
+#[test]
+fn skipping_an_artifact_unblocks_its_dependents() {
+    let f = fixture();
+
+    let status = compute_change_status(&f.ito_path, CHANGE, None, &f.ctx).expect("status");
+    let tasks = status.artifacts.iter().find(|a| a.id == "tasks").unwrap();
+    assert_eq!(tasks.missing_deps, vec!["design".to_string()]);
+
+    skip_artifact(&f.ito_path, CHANGE, "design", "One-line fix", &f.ctx).expect("skip");
+
+    let status = compute_change_status(&f.ito_path, CHANGE, None, &f.ctx).expect("status");
+    let design = status.artifacts.iter().find(|a| a.id == "design").unwrap();
+    assert_eq!(design.status, "skipped");
+    assert_eq!(design.skip_reason.as_deref(), Some("One-line fix"));
+    let tasks = status.artifacts.iter().find(|a| a.id == "tasks").unwrap();
+    assert_eq!(tasks.status, "ready");
+    assert!(tasks.missing_deps.is_empty());
+    assert!(!status.is_complete);
+
+    let instructions =
+        resolve_instructions(&f.ito_path, CHANGE, None, "tasks", &f.ctx).expect("instructions");
+    let dep = &instructions.dependencies[0];
+    assert_eq!(dep.id, "design");
+    assert!(dep.done);
+    assert!(dep.skipped);
+
+    let design =
+        resolve_instructions(&f.ito_path, CHANGE, None, "design", &f.ctx).expect("instructions");
+    assert!(design.skipped);
+    assert_eq!(design.skip_reason.as_deref(), Some("One-line fix"));
+}
+
+#[test]
+fn apply_proceeds_with_a_skipped_required_artifact() {
+    let f = fixture();
+    write(&f.change_dir.join("tasks.md"), "- [ ] 1.1 Do it\n");
+
+    let apply = compute_apply_instructions(&f.ito_path, CHANGE, None, &f.ctx).expect("apply");
+    assert_eq!(apply.state, "blocked");
+    assert_eq!(apply.missing_artifacts, Some(vec!["design".to_string()]));
+
+    skip_artifact(&f.ito_path, CHANGE, "design", "One-line fix", &f.ctx).expect("skip");
+
+    let apply = compute_apply_instructions(&f.ito_path, CHANGE, None, &f.ctx).expect("apply");
+    assert_eq!(apply.state, "ready");
+    assert!(apply.missing_artifacts.is_none());
+    assert_eq!(
+        apply.skipped_artifacts.get("design"),
+        Some(&Some("One-line fix".to_string()))
+    );
+}
+
+#[test]
+fn non_skippable_artifacts_reject_skip() {
+    let f = fixture();
+
+    let err = skip_artifact(&f.ito_path, CHANGE, "proposal", "Not needed", &f.ctx)
+        .expect_err("proposal is not skippable");
+    assert!(matches!(err, WorkflowError::ArtifactNotSkippable(id) if id == "proposal"));
+
+    let err = skip_artifact(&f.ito_path, CHANGE, "nope", "Not needed", &f.ctx)
+        .expect_err("unknown artifact");
+    assert!(matches!(err, WorkflowError::ArtifactNotFound(_)));
+
+    let meta = std::fs::read_to_string(f.change_dir.join(".ito.yaml")).expect("meta");
+    assert_eq!(meta, "schema: demo\n");
+}
+
+#[test]
+fn unskip_restores_blocking_and_preserves_other_metadata() {
+    let f = fixture();
+    write(
+        &f.change_dir.join(".ito.yaml"),
+        "schema: demo\nskipped_artifacts:\n  - design\nschema_pinned: false\n",
+    );
+
+    // Bare ids recorded by hand are honored too.
+    let status = compute_change_status(&f.ito_path, CHANGE, None, &f.ctx).expect("status");
+    let design = status.artifacts.iter().find(|a| a.id == "design").unwrap();
+    assert_eq!(design.status, "skipped");
+    assert_eq!(design.skip_reason, None);
+
+    assert!(unskip_artifact(&f.ito_path, CHANGE, "design").expect("unskip"));
+    assert!(!unskip_artifact(&f.ito_path, CHANGE, "design").expect("unskip again"));
+
+    let meta = std::fs::read_to_string(f.change_dir.join(".ito.yaml")).expect("meta");
+    assert_eq!(meta, "schema: demo\nschema_pinned: false\n");
+    let apply = compute_apply_instructions(&f.ito_path, CHANGE, None, &f.ctx).expect("apply");
+    assert_eq!(apply.state, "blocked");
+}
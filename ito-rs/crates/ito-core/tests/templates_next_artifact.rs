@@ -0,0 +1,174 @@
+use std::path::Path;
+
+use ito_config::ConfigContext;
+use ito_core::templates::{
+    ArtifactStatus, BlockedArtifact, ChangeStatus, NextArtifact, NextStep, next_artifact,
+    next_step_hint, select_next_step,
+};
+
+fn write_schema(project_root: &Path, schema_yaml: &str, templates: &[&str]) {
+    let schema_dir = project_root.join(".ito/templates/schemas/demo");
+    std::fs::create_dir_all(schema_dir.join("templates")).expect("create schema dirs");
+    std::fs::write(schema_dir.join("schema.yaml"), schema_yaml).expect("write schema.yaml");
+    for template in templates {
+        std::fs::write(
+            schema_dir.join("templates").join(template),
+            format!("# {template}\n"),
+        )
+        .expect("write template");
+    }
+}
+
+fn mark_done(ito_path: &Path, file: &str) {
+    std::fs::write(ito_path.join("changes/demo-change").join(file), "done").expect("write output");
+}
+
+fn ctx_for(project_root: &Path) -> ConfigContext {
+    ConfigContext {
+        project_dir: Some(project_root.to_path_buf()),
+        ..Default::default()
+    }
+}
+
+const DIAMOND_SCHEMA: &str = r#"name: demo
+version: 1
+artifacts:
+  - id: a
+    generates: a.md
+    template: a.md
+    requires: []
+  - id: c
+    generates: c.md
+    template: c.md
+    requires: ["a"]
+  - id: b
+    generates: b.md
+    template: b.md
+    requires: ["a"]
+  - id: d
+    generates: d.md
+    template: d.md
+    requires: ["b", "c"]
+"#;
+
+#[test]
+fn next_artifact_walks_a_diamond_in_build_order_until_complete() {
+    let td = tempfile::tempdir().expect("tempdir should succeed");
+    let project_root = td.path();
+    let ito_path = project_root.join(".ito");
+    std::fs::create_dir_all(ito_path.join("changes/demo-change")).expect("create change dir");
+    write_schema(
+        project_root,
+        DIAMOND_SCHEMA,
+        &["a.md", "b.md", "c.md", "d.md"],
+    );
+    let ctx = ctx_for(project_root);
+
+    let mut selected = Vec::new();
+    loop {
+        let next =
+            next_artifact(&ito_path, "demo-change", Some("demo"), &ctx).expect("next_artifact");
+        assert_eq!(next.change_name, "demo-change");
+        assert_eq!(next.schema_name, "demo");
+        let artifact_id = match next.step {
+            NextStep::Ready { artifact_id } => artifact_id,
+            step => {
+                assert_eq!(step, NextStep::Complete);
+                assert!(next.instructions.is_none());
+                break;
+            }
+        };
+        let instructions = next.instructions.expect("instructions for ready artifact");
+        assert_eq!(instructions.artifact_id, artifact_id);
+        assert_eq!(instructions.template, format!("# {artifact_id}.md\n"));
+        mark_done(&ito_path, &instructions.output_path);
+        selected.push(artifact_id);
+    }
+
+    assert_eq!(selected, vec!["a", "b", "c", "d"]);
+}
+
+#[test]
+fn next_artifact_reports_ready_to_apply_when_only_blocked_artifacts_remain() {
+    let td = tempfile::tempdir().expect("tempdir should succeed");
+    let project_root = td.path();
+    let ito_path = project_root.join(".ito");
+    std::fs::create_dir_all(ito_path.join("changes/demo-change")).expect("create change dir");
+    write_schema(
+        project_root,
+        r#"name: demo
+version: 1
+apply:
+  requires: ["a"]
+artifacts:
+  - id: a
+    generates: a.md
+    template: a.md
+    requires: []
+  - id: extra
+    generates: extra.md
+    template: extra.md
+    optional: true
+    requires: []
+  - id: b
+    generates: b.md
+    template: b.md
+    requires: ["extra"]
+"#,
+        &["a.md", "b.md", "extra.md"],
+    );
+    mark_done(&ito_path, "a.md");
+
+    let next = next_artifact(
+        &ito_path,
+        "demo-change",
+        Some("demo"),
+        &ctx_for(project_root),
+    )
+    .expect("next_artifact");
+    assert_eq!(next.step, NextStep::ReadyToApply);
+    assert!(next.instructions.is_none());
+}
+
+#[test]
+fn next_step_reports_blocking_artifacts_when_nothing_is_ready() {
+    // Schemas are validated on load, so a blocked graph cannot come from
+    // `schema.yaml`; build the status by hand instead.
+    let status = ChangeStatus {
+        change_name: "demo-change".to_string(),
+        schema_name: "demo".to_string(),
+        is_complete: false,
+        apply_requires: vec!["design".to_string()],
+        artifacts: vec![ArtifactStatus {
+            id: "design".to_string(),
+            output_path: "design.md".to_string(),
+            status: "blocked".to_string(),
+            missing_deps: vec!["proposal".to_string()],
+            skip_reason: None,
+        }],
+    };
+
+    let next = NextArtifact {
+        change_name: status.change_name.clone(),
+        schema_name: status.schema_name.clone(),
+        step: select_next_step(&status),
+        instructions: None,
+    };
+    assert_eq!(
+        next.step,
+        NextStep::Blocked {
+            blocked: vec![BlockedArtifact {
+                id: "design".to_string(),
+                missing_deps: vec!["proposal".to_string()],
+            }],
+        }
+    );
+    assert_eq!(
+        next_step_hint(&next.step),
+        "Next: design (blocked by: proposal)"
+    );
+
+    let json = serde_json::to_value(&next).expect("serialize");
+    assert_eq!(json["state"], "blocked");
+    assert_eq!(json["blocked"][0]["missingDeps"][0], "proposal");
+}
@@ -0,0 +1,147 @@
+use ito_config::ConfigContext;
+use ito_core::templates::{
+    WorkflowError, compute_apply_instructions, compute_change_status, resolve_instructions,
+    resolve_templates,
+};
+use std::path::{Path, PathBuf};
+
+const CHANGE: &str = "001-01_demo";
+
+fn write(path: &Path, contents: &str) {
+    let Some(parent) = path.parent() else {
+        panic!("path has no parent: {}", path.display());
+    };
+    std::fs::create_dir_all(parent).expect("create dir should succeed");
+    std::fs::write(path, contents).expect("write should succeed");
+}
+
+struct Fixture {
+    _td: tempfile::TempDir,
+    ito_path: PathBuf,
+    ctx: ConfigContext,
+}
+
+impl Fixture {
+    fn new(schema_yaml: &str) -> Self {
+        let td = tempfile::tempdir().expect("tempdir should succeed");
+        let project_root = td.path().to_path_buf();
+        let ito_path = project_root.join(".ito");
+        let schema_dir = project_root.join(".ito/templates/schemas/demo");
+        write(&schema_dir.join("schema.yaml"), schema_yaml);
+        write(&schema_dir.join("templates/report.md"), "# Report\n");
+        std::fs::create_dir_all(ito_path.join("changes").join(CHANGE)).expect("create change");
+        let ctx = ConfigContext {
+            project_dir: Some(project_root),
+            ..Default::default()
+        };
+        Self {
+            _td: td,
+            ito_path,
+            ctx,
+        }
+    }
+
+    fn write_output(&self, file: &str, contents: &str) {
+        write(
+            &self.ito_path.join("changes").join(CHANGE).join(file),
+            contents,
+        );
+    }
+
+    fn report_status(&self) -> (String, String) {
+        let status = compute_change_status(&self.ito_path, CHANGE, Some("demo"), &self.ctx)
+            .expect("status should succeed");
+        let report = status
+            .artifacts
+            .into_iter()
+            .find(|a| a.id == "report")
+            .expect("report artifact");
+        (report.output_path, report.status)
+    }
+}
+
+fn schema_generating(generates: &str) -> String {
+    format!(
+        "name: demo\nversion: 1\nartifacts:\n  - id: report\n    generates: \"{generates}\"\n    template: report.md\n"
+    )
+}
+
+#[test]
+fn plain_generates_path_expands_change_variables() {
+    let fx = Fixture::new(&schema_generating(
+        "reports/{schema}/{module_id}/{change_id}-summary.md",
+    ));
+
+    let expected = "reports/demo/001/001-01_demo-summary.md";
+    assert_eq!(
+        fx.report_status(),
+        (expected.to_string(), "ready".to_string())
+    );
+
+    let instructions = resolve_instructions(&fx.ito_path, CHANGE, Some("demo"), "report", &fx.ctx)
+        .expect("instructions should succeed");
+    assert_eq!(instructions.output_path, expected);
+
+    fx.write_output(expected, "# Summary\n");
+    assert_eq!(fx.report_status().1, "done");
+
+    let apply = compute_apply_instructions(&fx.ito_path, CHANGE, Some("demo"), &fx.ctx)
+        .expect("apply should succeed");
+    let context = apply.context_files.get("report").expect("report context");
+    assert!(context.ends_with(expected), "{context}");
+}
+
+#[test]
+fn glob_matching_applies_after_substitution() {
+    let fx = Fixture::new(&schema_generating("notes/{change_slug}/*.md"));
+    assert_eq!(fx.report_status().0, "notes/demo/*.md");
+
+    fx.write_output("notes/other/a.md", "# Other change\n");
+    assert_eq!(fx.report_status().1, "ready");
+
+    fx.write_output("notes/demo/a.md", "# Demo\n");
+    assert_eq!(fx.report_status().1, "done");
+}
+
+#[test]
+fn apply_tracks_expands_change_variables() {
+    let fx = Fixture::new(
+        "name: demo\nversion: 1\nartifacts: []\napply:\n  tracks: \"{change_slug}-tasks.md\"\n",
+    );
+    fx.write_output(
+        "demo-tasks.md",
+        "## 1. Implementation\n- [x] 1.1 Done\n- [ ] 1.2 Todo\n",
+    );
+
+    let apply = compute_apply_instructions(&fx.ito_path, CHANGE, Some("demo"), &fx.ctx)
+        .expect("apply should succeed");
+    assert_eq!(apply.tracks_file.as_deref(), Some("demo-tasks.md"));
+    assert_eq!(apply.progress.total, 2);
+    assert_eq!(apply.progress.remaining, 1);
+}
+
+#[test]
+fn unknown_variable_names_the_artifact_and_variable() {
+    let fx = Fixture::new(&schema_generating("reports/{owner}.md"));
+
+    let err = compute_change_status(&fx.ito_path, CHANGE, Some("demo"), &fx.ctx)
+        .expect_err("unknown variable should fail");
+    assert!(
+        matches!(
+            &err,
+            WorkflowError::UnknownPathVariable { artifact, variable }
+                if artifact == "report" && variable == "owner"
+        ),
+        "{err:?}"
+    );
+    assert_eq!(
+        err.to_string(),
+        "Artifact 'report' uses unknown path variable '{owner}'"
+    );
+
+    let err = resolve_templates(Some("demo"), &fx.ctx).expect_err("templates should fail");
+    assert!(
+        matches!(err, WorkflowError::UnknownPathVariable { .. }),
+        "{err:?}"
+    );
+}
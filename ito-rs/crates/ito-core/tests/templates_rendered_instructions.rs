@@ -0,0 +1,131 @@
+use ito_config::ConfigContext;
+use ito_core::templates::{resolve_instructions, resolve_rendered_instructions};
+use std::path::{Path, PathBuf};
+
+fn write(path: &Path, contents: &str) {
+    let Some(parent) = path.parent() else {
+        panic!("path has no parent: {}", path.display());
+    };
+    std::fs::create_dir_all(parent).expect("create dir should succeed");
+    std::fs::write(path, contents).expect("write should succeed");
+}
+
+const CHANGE: &str = "001-01_demo";
+
+const SCHEMA: &str = r#"name: demo
+version: 1
+artifacts:
+  - id: proposal
+    generates: proposal.md
+    template: proposal.md
+    requires: []
+  - id: design
+    generates: design.md
+    template: design.md
+    requires: [proposal]
+"#;
+
+struct Fixture {
+    _td: tempfile::TempDir,
+    ito_path: PathBuf,
+    schema_dir: PathBuf,
+    ctx: ConfigContext,
+}
+
+impl Fixture {
+    fn new(design_template: &str) -> Self {
+        let td = tempfile::tempdir().expect("tempdir should succeed");
+        let project_root = td.path().to_path_buf();
+        let ito_path = project_root.join(".ito");
+        let schema_dir = project_root.join(".ito/templates/schemas/demo");
+        write(&schema_dir.join("schema.yaml"), SCHEMA);
+        write(&schema_dir.join("templates/proposal.md"), "# Proposal\n");
+        write(&schema_dir.join("templates/design.md"), design_template);
+        write(
+            &ito_path.join("changes").join(CHANGE).join(".ito.yaml"),
+            "schema: demo\n",
+        );
+
+        let ctx = ConfigContext {
+            project_dir: Some(project_root),
+            ..Default::default()
+        };
+        Self {
+            _td: td,
+            ito_path,
+            schema_dir,
+            ctx,
+        }
+    }
+}
+
+#[test]
+fn rendered_instructions_fill_change_context() {
+    let f = Fixture::new(
+        "# Design for {{ change_name }} (module {{ module_id }}, schema {{ schema_name }})\n\
+{% for dep in completed_dependencies %}- {{ dep }}\n{% endfor %}\
+{% if user_guidance %}Guidance: {{ user_guidance }}\n{% endif %}",
+    );
+    let change_dir = f.ito_path.join("changes").join(CHANGE);
+    write(&change_dir.join("proposal.md"), "# Proposal\n\nDone.\n");
+    write(
+        &f.ito_path.join("user-prompts/guidance.md"),
+        "Prefer small steps.\n",
+    );
+
+    let r = resolve_rendered_instructions(&f.ito_path, CHANGE, None, "design", &f.ctx)
+        .expect("resolve_rendered_instructions");
+    assert!(r.render_warning.is_none());
+    assert!(
+        r.template
+            .starts_with("# Design for 001-01_demo (module 001, schema demo)\n"),
+        "template={}",
+        r.template
+    );
+    let proposal_path = change_dir.join("proposal.md").to_string_lossy().to_string();
+    assert!(r.template.contains(&format!("- {proposal_path}\n")));
+    assert!(r.template.contains("Guidance: Prefer small steps."));
+}
+
+#[test]
+fn literal_agent_placeholders_fall_back_to_raw_template_with_warning() {
+    let template = "# Design for {{ change_name }}\n\nReplace {{ your-component }} below.\n";
+    let f = Fixture::new(template);
+
+    let r = resolve_rendered_instructions(&f.ito_path, CHANGE, None, "design", &f.ctx)
+        .expect("resolve_rendered_instructions");
+    assert_eq!(r.template, template);
+    let warning = r.render_warning.as_deref().expect("render warning");
+    assert!(warning.contains("'design'"), "warning={warning}");
+
+    let json = serde_json::to_value(&r).expect("serialize response");
+    assert!(json["renderWarning"].is_string());
+}
+
+#[test]
+fn escaped_braces_survive_rendering() {
+    let f = Fixture::new("# {{ change_name }}\n\nKeep {{ '{{' }} placeholder }} for the agent.\n");
+
+    let r = resolve_rendered_instructions(&f.ito_path, CHANGE, None, "design", &f.ctx)
+        .expect("resolve_rendered_instructions");
+    assert!(r.render_warning.is_none());
+    assert_eq!(
+        r.template,
+        "# 001-01_demo\n\nKeep {{ placeholder }} for the agent.\n"
+    );
+}
+
+#[test]
+fn unrendered_instructions_keep_placeholders() {
+    let f = Fixture::new("# Design for {{ change_name }}\n");
+    write(
+        &f.schema_dir.join("templates/proposal.md"),
+        "# {{ change_name }}\n",
+    );
+
+    let r = resolve_instructions(&f.ito_path, CHANGE, None, "proposal", &f.ctx)
+        .expect("resolve_instructions");
+    assert_eq!(r.template, "# {{ change_name }}\n");
+    assert!(r.render_warning.is_none());
+    assert!(serde_json::to_value(&r).expect("serialize response")["renderWarning"].is_null());
+}
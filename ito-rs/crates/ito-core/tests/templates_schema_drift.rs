@@ -0,0 +1,204 @@
+use ito_config::ConfigContext;
+use ito_core::templates::{
+    ArtifactStatusChange, SCHEMA_BASELINE_DIR, SchemaDriftSeverity, compute_apply_instructions,
+    compute_change_status, pin_change_schema, rebaseline_change_schema, resolve_instructions,
+};
+use std::path::{Path, PathBuf};
+
+fn write(path: &Path, contents: &str) {
+    let Some(parent) = path.parent() else {
+        panic!("path has no parent: {}", path.display());
+    };
+    std::fs::create_dir_all(parent).expect("create dir should succeed");
+    std::fs::write(path, contents).expect("write should succeed");
+}
+
+const CHANGE: &str = "001-01_demo";
+
+const BASE_SCHEMA: &str = r#"name: demo
+version: 1
+artifacts:
+  - id: proposal
+    generates: proposal.md
+    template: proposal.md
+    requires: []
+  - id: notes
+    generates: notes.md
+    template: notes.md
+    requires: []
+"#;
+
+struct Fixture {
+    _td: tempfile::TempDir,
+    ito_path: PathBuf,
+    schema_dir: PathBuf,
+    ctx: ConfigContext,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        let td = tempfile::tempdir().expect("tempdir should succeed");
+        let project_root = td.path().to_path_buf();
+        let ito_path = project_root.join(".ito");
+        let schema_dir = project_root.join(".ito/templates/schemas/demo");
+        write(&schema_dir.join("schema.yaml"), BASE_SCHEMA);
+        write(&schema_dir.join("templates/proposal.md"), "# Proposal\n");
+        write(&schema_dir.join("templates/notes.md"), "# Notes\n");
+
+        let change_dir = ito_path.join("changes").join(CHANGE);
+        write(
+            &change_dir.join(".ito.yaml"),
+            "schema: demo\ncreated: 2026-01-01\n",
+        );
+
+        let ctx = ConfigContext {
+            project_dir: Some(project_root),
+            ..Default::default()
+        };
+        let fixture = Self {
+            _td: td,
+            ito_path,
+            schema_dir,
+            ctx,
+        };
+        rebaseline_change_schema(&fixture.ito_path, CHANGE, &fixture.ctx)
+            .expect("initial baseline");
+        fixture
+    }
+
+    fn change_dir(&self) -> PathBuf {
+        self.ito_path.join("changes").join(CHANGE)
+    }
+}
+
+#[test]
+fn baseline_records_hash_and_schema_copy_without_drift() {
+    let f = Fixture::new();
+
+    let meta = std::fs::read_to_string(f.change_dir().join(".ito.yaml")).expect("read meta");
+    assert!(meta.contains("schema: demo"));
+    assert!(meta.contains("created: 2026-01-01"));
+    assert!(meta.contains("schema_hash: "));
+    let baseline = f.change_dir().join(SCHEMA_BASELINE_DIR);
+    assert!(baseline.join("schema.yaml").is_file());
+    assert!(baseline.join("templates/proposal.md").is_file());
+
+    let r = resolve_instructions(&f.ito_path, CHANGE, None, "proposal", &f.ctx)
+        .expect("resolve_instructions");
+    assert!(r.schema_drift.is_none());
+}
+
+#[test]
+fn added_artifact_is_reported_as_high_severity_drift() {
+    let f = Fixture::new();
+    write(
+        &f.schema_dir.join("schema.yaml"),
+        &format!(
+            "{BASE_SCHEMA}  - id: design\n    generates: design.md\n    template: design.md\n    requires: [proposal]\n"
+        ),
+    );
+    write(&f.schema_dir.join("templates/design.md"), "# Design\n");
+
+    let r = resolve_instructions(&f.ito_path, CHANGE, None, "proposal", &f.ctx)
+        .expect("resolve_instructions");
+    let drift = r.schema_drift.expect("drift should be reported");
+    assert_eq!(drift.severity, SchemaDriftSeverity::High);
+    assert!(drift.baseline_available);
+    assert_eq!(drift.artifacts_added, vec!["design".to_string()]);
+    assert!(drift.artifacts_removed.is_empty());
+    assert!(drift.templates_changed.is_empty());
+
+    let apply = compute_apply_instructions(&f.ito_path, CHANGE, None, &f.ctx)
+        .expect("compute_apply_instructions");
+    assert_eq!(
+        apply.schema_drift.map(|d| d.artifacts_added),
+        Some(vec!["design".to_string()])
+    );
+}
+
+#[test]
+fn removed_artifact_with_existing_output_is_reported_and_rebaselined() {
+    let f = Fixture::new();
+    write(&f.change_dir().join("notes.md"), "# Notes\n\nDone.\n");
+    write(
+        &f.schema_dir.join("schema.yaml"),
+        r#"name: demo
+version: 1
+artifacts:
+  - id: proposal
+    generates: proposal.md
+    template: proposal.md
+    requires: []
+"#,
+    );
+
+    let apply = compute_apply_instructions(&f.ito_path, CHANGE, None, &f.ctx)
+        .expect("compute_apply_instructions");
+    let drift = apply.schema_drift.expect("drift should be reported");
+    assert_eq!(drift.severity, SchemaDriftSeverity::High);
+    assert_eq!(drift.artifacts_removed, vec!["notes".to_string()]);
+    assert_eq!(drift.orphaned_outputs, vec!["notes.md".to_string()]);
+
+    let result =
+        rebaseline_change_schema(&f.ito_path, CHANGE, &f.ctx).expect("rebaseline should succeed");
+    assert!(!result.pinned);
+    assert_eq!(
+        result.previous_hash.as_deref(),
+        Some(drift.recorded_hash.as_str())
+    );
+    assert_eq!(result.schema_hash, drift.current_hash);
+    assert_eq!(
+        result.status_changes,
+        vec![ArtifactStatusChange {
+            id: "notes".to_string(),
+            before: Some("done".to_string()),
+            after: None,
+        }]
+    );
+
+    let apply = compute_apply_instructions(&f.ito_path, CHANGE, None, &f.ctx)
+        .expect("compute_apply_instructions");
+    assert!(apply.schema_drift.is_none());
+}
+
+#[test]
+fn template_only_change_is_low_severity() {
+    let f = Fixture::new();
+    write(
+        &f.schema_dir.join("templates/proposal.md"),
+        "# Proposal\n\n## Why\n",
+    );
+
+    let r = resolve_instructions(&f.ito_path, CHANGE, None, "proposal", &f.ctx)
+        .expect("resolve_instructions");
+    let drift = r.schema_drift.expect("drift should be reported");
+    assert_eq!(drift.severity, SchemaDriftSeverity::Low);
+    assert_eq!(drift.templates_changed, vec!["proposal".to_string()]);
+    assert!(drift.artifacts_added.is_empty());
+    assert!(drift.artifacts_removed.is_empty());
+    assert!(drift.artifacts_changed.is_empty());
+}
+
+#[test]
+fn pinned_change_resolves_the_stored_schema_copy() {
+    let f = Fixture::new();
+    write(
+        &f.schema_dir.join("templates/proposal.md"),
+        "# Proposal v2\n",
+    );
+
+    let result = pin_change_schema(&f.ito_path, CHANGE, &f.ctx).expect("pin should succeed");
+    assert!(result.pinned);
+    assert!(result.status_changes.is_empty());
+    let meta = std::fs::read_to_string(f.change_dir().join(".ito.yaml")).expect("read meta");
+    assert!(meta.contains("schema_pinned: true"));
+
+    let r = resolve_instructions(&f.ito_path, CHANGE, None, "proposal", &f.ctx)
+        .expect("resolve_instructions");
+    assert!(r.schema_drift.is_none());
+    assert_eq!(r.template, "# Proposal\n");
+
+    let status = compute_change_status(&f.ito_path, CHANGE, None, &f.ctx).expect("status");
+    assert_eq!(status.schema_name, "demo");
+    assert_eq!(status.artifacts.len(), 2);
+}
@@ -263,3 +263,123 @@ fn resolve_instructions_rejects_traversal_template_path() {
         other => panic!("unexpected error: {other:?}"),
     }
 }
+
+fn write_include_schema(project: &std::path::Path, files: &[(&str, &str)]) {
+    let schema_dir = project.join(".ito/templates/schemas/with-partials");
+    std::fs::create_dir_all(&schema_dir).expect("schema dir");
+    std::fs::write(
+        schema_dir.join("schema.yaml"),
+        "name: with-partials\nversion: 1\nartifacts:\n  - id: proposal\n    generates: proposal.md\n    template: proposal.md\n",
+    )
+    .expect("write schema");
+    for (rel, contents) in files {
+        let path = schema_dir.join(rel);
+        std::fs::create_dir_all(path.parent().expect("parent")).expect("parent dir");
+        std::fs::write(path, contents).expect("write schema file");
+    }
+}
+
+fn resolve_with_partials(
+    files: &[(&str, &str)],
+) -> Result<ito_core::templates::InstructionsResponse, WorkflowError> {
+    let root = tempfile::tempdir().expect("tempdir should succeed");
+    let ito_path = root.path().join(".ito");
+    std::fs::create_dir_all(ito_path.join("changes/demo-change")).expect("create change dir");
+    write_include_schema(root.path(), files);
+
+    let ctx = ConfigContext {
+        project_dir: Some(root.path().to_path_buf()),
+        ..Default::default()
+    };
+    resolve_instructions(
+        &ito_path,
+        "demo-change",
+        Some("with-partials"),
+        "proposal",
+        &ctx,
+    )
+}
+
+#[test]
+fn resolve_instructions_expands_template_includes() {
+    let out = resolve_with_partials(&[
+        (
+            "templates/proposal.md",
+            "# Proposal\n<!-- include: partials/preamble.md -->\n## Why\n",
+        ),
+        ("partials/preamble.md", "Shared preamble.\n"),
+    ])
+    .expect("instructions should resolve");
+
+    assert_eq!(out.template, "# Proposal\nShared preamble.\n## Why\n");
+}
+
+#[test]
+fn resolve_instructions_expands_nested_template_includes() {
+    let out = resolve_with_partials(&[
+        (
+            "templates/proposal.md",
+            "# Proposal\n<!-- include: partials/outer.md -->\n",
+        ),
+        (
+            "partials/outer.md",
+            "Outer start\n<!-- include: partials/inner.md -->\nOuter end\n",
+        ),
+        ("partials/inner.md", "Inner"),
+    ])
+    .expect("instructions should resolve");
+
+    assert_eq!(out.template, "# Proposal\nOuter start\nInner\nOuter end\n");
+}
+
+#[test]
+fn resolve_instructions_reports_missing_partial_with_including_file() {
+    let err = resolve_with_partials(&[
+        (
+            "templates/proposal.md",
+            "<!-- include: partials/outer.md -->\n",
+        ),
+        (
+            "partials/outer.md",
+            "<!-- include: partials/missing.md -->\n",
+        ),
+    ])
+    .expect_err("missing partial should fail");
+
+    match err {
+        WorkflowError::PartialNotFound { template, partial } => {
+            assert_eq!(template, "partials/outer.md");
+            assert_eq!(partial, "partials/missing.md");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn resolve_instructions_reports_include_cycles() {
+    let err = resolve_with_partials(&[
+        ("templates/proposal.md", "<!-- include: partials/a.md -->\n"),
+        ("partials/a.md", "<!-- include: partials/b.md -->\n"),
+        ("partials/b.md", "<!-- include: partials/a.md -->\n"),
+    ])
+    .expect_err("include cycle should fail");
+
+    match err {
+        WorkflowError::IncludeCycle(chain) => assert_eq!(
+            chain,
+            "templates/proposal.md -> partials/a.md -> partials/b.md -> partials/a.md"
+        ),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn resolve_instructions_rejects_include_traversal() {
+    let err = resolve_with_partials(&[(
+        "templates/proposal.md",
+        "<!-- include: ../../secrets.md -->\n",
+    )])
+    .expect_err("traversal should fail");
+
+    assert!(err.to_string().contains("invalid include path"));
+}
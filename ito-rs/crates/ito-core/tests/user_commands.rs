@@ -0,0 +1,164 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use ito_config::ConfigContext;
+use ito_core::installers::{
+    InitOptions, InstallMode, TOOL_CLAUDE, TOOL_OPENCODE, UserCommandScope, discover_user_commands,
+    install_default_templates, load_install_manifest, sync_user_commands,
+};
+
+const RELEASE_NOTES: &str = "---\ndescription: Draft release notes\n---\n\nWrite notes.\n";
+
+fn tools() -> BTreeSet<String> {
+    BTreeSet::from([TOOL_CLAUDE.to_string(), TOOL_OPENCODE.to_string()])
+}
+
+fn install(project: &Path, xdg: &Path, mode: InstallMode) {
+    let ctx = ConfigContext {
+        project_dir: Some(project.to_path_buf()),
+        xdg_config_home: Some(xdg.to_path_buf()),
+        ..Default::default()
+    };
+    let opts = InitOptions::new(tools(), false, mode == InstallMode::Update);
+    install_default_templates(project, &ctx, mode, &opts, None).expect("install should succeed");
+}
+
+fn write(path: &Path, contents: &str) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, contents).unwrap();
+}
+
+#[test]
+fn user_command_is_installed_into_every_selected_harness() {
+    let project = tempfile::tempdir().unwrap();
+    let xdg = tempfile::tempdir().unwrap();
+    write(
+        &project
+            .path()
+            .join(".ito/user-commands/acme-release-notes.md"),
+        RELEASE_NOTES,
+    );
+
+    install(project.path(), xdg.path(), InstallMode::Init);
+
+    for dir in [".claude/commands", ".opencode/commands"] {
+        let installed = project.path().join(dir).join("acme-release-notes.md");
+        assert_eq!(std::fs::read_to_string(installed).unwrap(), RELEASE_NOTES);
+    }
+    let manifest = load_install_manifest(&project.path().join(".ito")).unwrap();
+    assert!(
+        manifest
+            .user_commands
+            .contains_key(".claude/commands/acme-release-notes.md")
+    );
+}
+
+#[test]
+fn project_command_beats_global_command() {
+    let project = tempfile::tempdir().unwrap();
+    let xdg = tempfile::tempdir().unwrap();
+    write(&xdg.path().join("ito/commands/acme.md"), "global\n");
+    write(&xdg.path().join("ito/commands/team.md"), "team\n");
+    write(
+        &project.path().join(".ito/user-commands/acme.md"),
+        "project\n",
+    );
+
+    let commands = discover_user_commands(
+        &project.path().join(".ito"),
+        Some(&xdg.path().join("ito/commands")),
+    )
+    .unwrap();
+    let found: Vec<(&str, UserCommandScope)> = commands
+        .iter()
+        .map(|c| (c.name.as_str(), c.scope))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            ("acme.md", UserCommandScope::Project),
+            ("team.md", UserCommandScope::Global),
+        ]
+    );
+
+    install(project.path(), xdg.path(), InstallMode::Init);
+    let installed = project.path().join(".claude/commands/acme.md");
+    assert_eq!(std::fs::read_to_string(installed).unwrap(), "project\n");
+    assert!(project.path().join(".claude/commands/team.md").exists());
+}
+
+#[test]
+fn shadowing_an_embedded_command_is_reported() {
+    let project = tempfile::tempdir().unwrap();
+    let ito_path = project.path().join(".ito");
+    write(
+        &ito_path.join("user-commands/ito-apply.md"),
+        "custom apply\n",
+    );
+
+    let commands = discover_user_commands(&ito_path, None).unwrap();
+    let report = sync_user_commands(project.path(), &ito_path, &commands, &tools()).unwrap();
+
+    assert_eq!(report.shadowed.len(), 1);
+    assert_eq!(report.shadowed[0].name, "ito-apply.md");
+
+    let xdg = tempfile::tempdir().unwrap();
+    install(project.path(), xdg.path(), InstallMode::Init);
+    let installed = project.path().join(".claude/commands/ito-apply.md");
+    assert_eq!(
+        std::fs::read_to_string(installed).unwrap(),
+        "custom apply\n"
+    );
+}
+
+#[test]
+fn update_removes_copies_whose_source_was_deleted() {
+    let project = tempfile::tempdir().unwrap();
+    let xdg = tempfile::tempdir().unwrap();
+    let source = project
+        .path()
+        .join(".ito/user-commands/acme-release-notes.md");
+    write(&source, RELEASE_NOTES);
+    install(project.path(), xdg.path(), InstallMode::Init);
+
+    std::fs::remove_file(&source).unwrap();
+    install(project.path(), xdg.path(), InstallMode::Update);
+
+    for dir in [".claude/commands", ".opencode/commands"] {
+        assert!(
+            !project
+                .path()
+                .join(dir)
+                .join("acme-release-notes.md")
+                .exists()
+        );
+    }
+    // Embedded commands are untouched.
+    assert!(
+        project
+            .path()
+            .join(".claude/commands/ito-apply.md")
+            .exists()
+    );
+    let manifest = load_install_manifest(&project.path().join(".ito")).unwrap();
+    assert!(manifest.user_commands.is_empty());
+}
+
+#[test]
+fn update_keeps_locally_edited_copy_of_deleted_command() {
+    let project = tempfile::tempdir().unwrap();
+    let ito_path = project.path().join(".ito");
+    let source = ito_path.join("user-commands/acme.md");
+    write(&source, "v1\n");
+    let commands = discover_user_commands(&ito_path, None).unwrap();
+    sync_user_commands(project.path(), &ito_path, &commands, &tools()).unwrap();
+
+    std::fs::remove_file(&source).unwrap();
+    let edited = project.path().join(".claude/commands/acme.md");
+    std::fs::write(&edited, "edited\n").unwrap();
+    let report = sync_user_commands(project.path(), &ito_path, &[], &tools()).unwrap();
+
+    assert_eq!(report.preserved, vec![edited.clone()]);
+    assert!(edited.exists());
+    assert!(!project.path().join(".opencode/commands/acme.md").exists());
+}
@@ -0,0 +1,161 @@
+use ito_config::types::{CustomValidationRuleConfig, ValidationConfig};
+use ito_core::change_repository::FsChangeRepository;
+use ito_core::validate::{CustomRuleSet, ValidationReport, validate_spec_markdown};
+use std::path::Path;
+
+fn write(path: &Path, contents: &str) {
+    let Some(parent) = path.parent() else {
+        panic!("path has no parent: {}", path.display());
+    };
+    std::fs::create_dir_all(parent).unwrap();
+    std::fs::write(path, contents).unwrap();
+}
+
+fn rule(id: &str, target: &str) -> CustomValidationRuleConfig {
+    CustomValidationRuleConfig {
+        id: id.to_string(),
+        target: target.to_string(),
+        message: format!("{id} failed"),
+        level: "warning".to_string(),
+        ..CustomValidationRuleConfig::default()
+    }
+}
+
+fn compile(rules: Vec<CustomValidationRuleConfig>) -> CustomRuleSet {
+    let (set, warnings) = CustomRuleSet::compile(&ValidationConfig {
+        custom_rules: rules,
+    });
+    assert!(warnings.is_empty(), "unexpected warnings: {warnings:?}");
+    set
+}
+
+const SPEC: &str = r#"
+## Purpose
+
+This purpose text is intentionally long enough to avoid strict-mode warnings.
+
+## Requirements
+
+### Requirement: Tracked
+The system SHALL do the tracked thing (PROJ-123).
+
+#### Scenario: Short
+- **WHEN** it runs
+- **THEN** it works
+
+### Requirement: Untracked
+The system SHALL do the untracked thing.
+
+#### Scenario: Long
+- **WHEN** the user performs a very long sequence of steps that keeps going well past the limit
+- **THEN** it works
+"#;
+
+#[test]
+fn must_match_rule_flags_requirement_missing_jira_key() {
+    let mut jira = rule("org/jira-key", "spec_requirement");
+    jira.must_match = Some(r"[A-Z]+-\d+".to_string());
+    let set = compile(vec![jira]);
+
+    let issues = set.check_spec_markdown(SPEC);
+
+    assert_eq!(issues.len(), 1, "{issues:?}");
+    assert_eq!(issues[0].path, "requirements[1]");
+    assert_eq!(issues[0].level, "WARNING");
+    assert_eq!(issues[0].rule_id.as_deref(), Some("org/jira-key"));
+    assert_eq!(issues[0].message, "org/jira-key failed");
+}
+
+#[test]
+fn max_length_rule_flags_long_scenarios() {
+    let mut length = rule("org/scenario-length", "scenario");
+    length.max_length = Some(60);
+    length.level = "error".to_string();
+    let set = compile(vec![length]);
+
+    let issues = set.check_spec_markdown(SPEC);
+
+    assert_eq!(issues.len(), 1, "{issues:?}");
+    assert_eq!(issues[0].path, "requirements[1].scenarios[0]");
+    assert_eq!(issues[0].level, "ERROR");
+}
+
+#[test]
+fn invalid_definitions_are_reported_as_config_warnings() {
+    let mut bad_regex = rule("org/bad-regex", "spec_requirement");
+    bad_regex.must_match = Some("([unclosed".to_string());
+    let mut bad_target = rule("org/bad-target", "requirement_text");
+    bad_target.max_length = Some(10);
+    let mut ok = rule("org/ok", "scenario");
+    ok.min_length = Some(1);
+
+    let (set, warnings) = CustomRuleSet::compile(&ValidationConfig {
+        custom_rules: vec![bad_regex, bad_target, ok],
+    });
+
+    assert_eq!(set.rules().len(), 1);
+    assert_eq!(set.rules()[0].id, "org/ok");
+    assert_eq!(warnings.len(), 2, "{warnings:?}");
+    assert_eq!(warnings[0].path, "validation.custom_rules[0]");
+    assert!(warnings[0].message.contains("invalid must_match regex"));
+    assert_eq!(warnings[1].path, "validation.custom_rules[1]");
+    assert!(
+        warnings[1]
+            .message
+            .contains("unknown target 'requirement_text'")
+    );
+}
+
+#[test]
+fn custom_issues_count_toward_strict_mode_failures() {
+    let mut jira = rule("org/jira-key", "spec_requirement");
+    jira.must_match = Some(r"[A-Z]+-\d+".to_string());
+    let set = compile(vec![jira]);
+
+    let base = validate_spec_markdown(SPEC, true);
+    assert!(base.valid, "{:?}", base.issues);
+
+    let mut issues = base.issues;
+    issues.extend(set.check_spec_markdown(SPEC));
+
+    let strict = ValidationReport::new(issues.clone(), true);
+    assert!(!strict.valid);
+    assert_eq!(strict.summary.warnings, 1);
+
+    let lenient = ValidationReport::new(issues, false);
+    assert!(lenient.valid);
+}
+
+#[test]
+fn change_rules_cover_deltas_and_proposal_sections() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    let change_id = "001-01_demo";
+    write(
+        &ito.join("changes").join(change_id).join("proposal.md"),
+        "## Why\nBecause.\n\n## What Changes\n- Things\n",
+    );
+    write(
+        &ito.join("changes")
+            .join(change_id)
+            .join("specs")
+            .join("auth")
+            .join("spec.md"),
+        "## ADDED Requirements\n\n### Requirement: R\nThe system SHALL do it.\n\n#### Scenario: S\n- **WHEN** ok\n- **THEN** ok\n",
+    );
+
+    let mut why = rule("org/why-ticket", "proposal_section");
+    why.section = Some("Why".to_string());
+    why.must_match = Some(r"[A-Z]+-\d+".to_string());
+    let mut req = rule("org/jira-key", "spec_requirement");
+    req.must_match = Some(r"[A-Z]+-\d+".to_string());
+    let set = compile(vec![why, req]);
+
+    let change_repo = FsChangeRepository::new(&ito);
+    let issues = set.check_change(&change_repo, change_id).unwrap();
+
+    let ids: Vec<_> = issues.iter().filter_map(|i| i.rule_id.as_deref()).collect();
+    assert_eq!(ids, vec!["org/jira-key", "org/why-ticket"], "{issues:?}");
+    assert_eq!(issues[0].path, "deltas[0].requirements[0]");
+    assert_eq!(issues[1].path, "proposal.why");
+}
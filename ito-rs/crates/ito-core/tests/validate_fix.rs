@@ -0,0 +1,149 @@
+use ito_core::module_repository::FsModuleRepository;
+use ito_core::validate::{
+    FixAction, apply_fixes, fixable_change_issues, fixable_module_issues, fixable_spec_issues,
+    validate_change_dirs_repo_integrity, validate_spec,
+};
+use std::path::Path;
+
+fn write(path: &Path, contents: &str) {
+    let Some(parent) = path.parent() else {
+        panic!("path has no parent: {}", path.display());
+    };
+    std::fs::create_dir_all(parent).unwrap();
+    std::fs::write(path, contents).unwrap();
+}
+
+fn write_module(ito: &Path, checklist: &str) -> std::path::PathBuf {
+    let module_md = ito.join("modules/001_demo/module.md");
+    write(
+        &module_md,
+        &format!("# Demo\n\n## Purpose\nDemo module for fix tests.\n\n## Changes\n{checklist}"),
+    );
+    module_md
+}
+
+const SPEC: &str = "# Auth\n\n## Purpose\nAuthenticate users before they reach protected pages.\n\n## Requirements\n\n### Requirement: Login\nThe system SHALL authenticate users.\n\n#### Scenario: Valid login\n- **WHEN** valid credentials are given\n- **THEN** access is granted\n";
+
+#[test]
+fn fix_renames_non_canonical_change_dir() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    write_module(&ito, "- [ ] 001-02_demo\n");
+    write(&ito.join("changes/1-2_demo/proposal.md"), "# Demo\n");
+
+    let issues = fixable_change_issues(&ito, None).unwrap();
+    assert_eq!(issues.len(), 1);
+    let report = apply_fixes(&issues);
+
+    assert!(report.failed.is_empty(), "{:?}", report.failed);
+    assert_eq!(
+        report.applied[0].fix,
+        FixAction::RenamePath {
+            from: ito.join("changes/1-2_demo"),
+            to: ito.join("changes/001-02_demo"),
+        }
+    );
+    assert!(ito.join("changes/001-02_demo/proposal.md").exists());
+    assert!(!ito.join("changes/1-2_demo").exists());
+    let remaining = validate_change_dirs_repo_integrity(&ito).unwrap();
+    assert!(
+        remaining.values().all(|issues| issues.is_empty()),
+        "{remaining:?}"
+    );
+}
+
+#[test]
+fn fix_normalizes_crlf_spec_to_lf() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    let spec_md = ito.join("specs/auth/spec.md");
+    write(&spec_md, &SPEC.replace('\n', "\r\n"));
+
+    let before = validate_spec(&ito, "auth", false).unwrap();
+    assert!(
+        before
+            .issues
+            .iter()
+            .any(|i| i.message.contains("CRLF") && i.fix.is_some())
+    );
+
+    let report = apply_fixes(&fixable_spec_issues(&ito, Some("auth")).unwrap());
+
+    assert_eq!(report.applied.len(), 1);
+    assert_eq!(std::fs::read_to_string(&spec_md).unwrap(), SPEC);
+    let after = validate_spec(&ito, "auth", false).unwrap();
+    assert!(after.issues.iter().all(|i| !i.message.contains("CRLF")));
+}
+
+#[test]
+fn fix_checks_off_archived_changes_in_module_checklist() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    let module_md = write_module(&ito, "- [ ] 001-01_done\n- [ ] 001-02_active\n");
+    write(
+        &ito.join("changes/archive/2026-01-26-001-01_done/proposal.md"),
+        "# Done\n",
+    );
+    write(&ito.join("changes/001-02_active/proposal.md"), "# Active\n");
+    let module_repo = FsModuleRepository::new(&ito);
+
+    let issues = fixable_module_issues(&module_repo, &ito, None).unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].line, Some(7));
+    let report = apply_fixes(&issues);
+
+    assert!(report.failed.is_empty(), "{:?}", report.failed);
+    let md = std::fs::read_to_string(&module_md).unwrap();
+    assert!(md.ends_with("## Changes\n- [x] 001-01_done\n- [ ] 001-02_active\n"));
+    assert!(
+        fixable_module_issues(&module_repo, &ito, None)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn fix_refuses_to_overwrite_a_file_changed_since_validation() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    let spec_md = ito.join("specs/auth/spec.md");
+    write(&spec_md, &SPEC.replace('\n', "\r\n"));
+
+    let issues = fixable_spec_issues(&ito, Some("auth")).unwrap();
+    std::fs::write(&spec_md, "edited\r\n").unwrap();
+    let report = apply_fixes(&issues);
+
+    assert!(report.applied.is_empty());
+    assert_eq!(report.failed.len(), 1);
+    assert!(
+        report.failed[0]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("changed since validation")
+    );
+    assert_eq!(std::fs::read_to_string(&spec_md).unwrap(), "edited\r\n");
+}
+
+#[test]
+fn fix_is_a_no_op_when_nothing_is_fixable() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    let module_md = write_module(&ito, "- [ ] 001-01_active\n");
+    write(&ito.join("changes/001-01_active/proposal.md"), "# Active\n");
+    write(&ito.join("specs/auth/spec.md"), SPEC);
+    let module_repo = FsModuleRepository::new(&ito);
+
+    let mut issues = fixable_change_issues(&ito, None).unwrap();
+    issues.extend(fixable_spec_issues(&ito, None).unwrap());
+    issues.extend(fixable_module_issues(&module_repo, &ito, None).unwrap());
+    let before = std::fs::read_to_string(&module_md).unwrap();
+    let report = apply_fixes(&issues);
+
+    assert!(report.is_empty());
+    assert_eq!(std::fs::read_to_string(&module_md).unwrap(), before);
+    assert_eq!(
+        std::fs::read_to_string(ito.join("specs/auth/spec.md")).unwrap(),
+        SPEC
+    );
+}
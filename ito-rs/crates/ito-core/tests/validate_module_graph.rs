@@ -0,0 +1,80 @@
+use ito_core::validate::{load_module_graph, validate_module_graph};
+use std::path::Path;
+
+fn write_module(ito: &Path, full_name: &str, depends_on: &[&str]) {
+    let dir = ito.join("modules").join(full_name);
+    std::fs::create_dir_all(&dir).unwrap();
+    let mut md = format!(
+        "# {full_name}\n\n## Purpose\nA module purpose that is long enough.\n\n## Scope\n- *\n\n"
+    );
+    if !depends_on.is_empty() {
+        md.push_str("## Depends On\n");
+        for dep in depends_on {
+            md.push_str(&format!("- {dep}\n"));
+        }
+        md.push('\n');
+    }
+    md.push_str("## Changes\n");
+    std::fs::write(dir.join("module.md"), md).unwrap();
+}
+
+#[test]
+fn valid_dag_passes_and_parses_annotated_entries() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    write_module(&ito, "001_base", &[]);
+    write_module(&ito, "002_api", &["001 (base - for shared types)"]);
+    write_module(&ito, "003_ui", &["001_base", "002"]);
+
+    let report = validate_module_graph(&ito);
+    assert!(report.valid, "{:?}", report.issues);
+    assert!(report.issues.is_empty(), "{:?}", report.issues);
+
+    let graph = load_module_graph(&ito);
+    assert_eq!(graph.node("003").unwrap().depends_on, vec!["001", "002"]);
+    assert_eq!(graph.node("002").unwrap().depends_on, vec!["001"]);
+}
+
+#[test]
+fn missing_dependency_is_an_error() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    write_module(&ito, "001_base", &["042"]);
+
+    let report = validate_module_graph(&ito);
+    assert!(!report.valid);
+    assert_eq!(report.issues.len(), 1, "{:?}", report.issues);
+    assert_eq!(report.issues[0].level, "ERROR");
+    assert_eq!(report.issues[0].path, "modules/001_base/depends_on");
+    assert!(report.issues[0].message.contains("missing module '042'"));
+}
+
+#[test]
+fn three_node_cycle_reports_cycle_path() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    write_module(&ito, "001_a", &["002"]);
+    write_module(&ito, "002_b", &["003"]);
+    write_module(&ito, "003_c", &["001"]);
+
+    let report = validate_module_graph(&ito);
+    assert!(!report.valid);
+    assert_eq!(report.issues.len(), 1, "{:?}", report.issues);
+    assert_eq!(
+        report.issues[0].message,
+        "Module dependency cycle: 001 -> 002 -> 003 -> 001"
+    );
+}
+
+#[test]
+fn self_reference_is_a_warning() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    write_module(&ito, "001_base", &["001"]);
+
+    let report = validate_module_graph(&ito);
+    assert!(report.valid, "{:?}", report.issues);
+    assert_eq!(report.issues.len(), 1);
+    assert_eq!(report.issues[0].level, "WARNING");
+    assert!(report.issues[0].message.contains("lists itself"));
+}
@@ -0,0 +1,169 @@
+#![cfg(feature = "watch")]
+
+//! Tests for the `ito validate --watch` re-run loop and watcher plumbing.
+
+use std::collections::VecDeque;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use ito_core::validate::{
+    RunIssue, ValidationDelta, ValidationRun, WatchOutcome, diff_runs, watch_validation,
+};
+use ito_core::watch::{FsWatchSource, IgnoreGlobs, WatchEvent, WatchSource};
+
+/// Replays a fixed list of events, then reports the source as stopped.
+struct ScriptedSource(VecDeque<WatchEvent>);
+
+impl WatchSource for ScriptedSource {
+    fn next_event(&mut self) -> Option<WatchEvent> {
+        self.0.pop_front()
+    }
+}
+
+fn issue(item: &str, message: &str, line: Option<u32>) -> RunIssue {
+    RunIssue {
+        item: item.to_string(),
+        level: "ERROR".to_string(),
+        path: "proposal.md".to_string(),
+        message: message.to_string(),
+        line,
+    }
+}
+
+fn run(issues: Vec<RunIssue>) -> ValidationRun {
+    ValidationRun {
+        valid: issues.is_empty(),
+        items: 2,
+        issues,
+    }
+}
+
+#[test]
+fn diff_reports_new_resolved_and_unchanged_issues() {
+    let previous = run(vec![
+        issue("a", "Missing Why", Some(1)),
+        issue("a", "Too short", Some(4)),
+    ]);
+    let current = run(vec![
+        issue("a", "Too short", Some(9)),
+        issue("b", "No deltas", None),
+    ]);
+
+    let delta = diff_runs(Some(&previous), &current);
+
+    assert_eq!(delta.new, vec![issue("b", "No deltas", None)]);
+    assert_eq!(delta.resolved, vec![issue("a", "Missing Why", Some(1))]);
+    assert_eq!(delta.unchanged, 1);
+
+    let first = diff_runs(None, &previous);
+    assert_eq!(first.new.len(), 2);
+    assert_eq!(first.unchanged, 0);
+}
+
+#[test]
+fn watch_reruns_once_per_change_and_keeps_last_state() {
+    let mut source = ScriptedSource(VecDeque::from([
+        WatchEvent::Changed(vec![PathBuf::from("specs/alpha/spec.md")]),
+        WatchEvent::Changed(vec![PathBuf::from("changes/001-01_a/proposal.md")]),
+    ]));
+    let mut script = VecDeque::from([
+        run(vec![issue("a", "Missing Why", None)]),
+        run(vec![]),
+        run(vec![issue("a", "Too short", None)]),
+    ]);
+    let mut reports: Vec<(Vec<PathBuf>, bool, ValidationDelta)> = Vec::new();
+
+    let summary = watch_validation(
+        &mut source,
+        || script.pop_front().expect("one run per event"),
+        |paths, run, delta| reports.push((paths.to_vec(), run.valid, delta.clone())),
+    );
+
+    assert_eq!(summary.outcome, WatchOutcome::Stopped);
+    assert_eq!(summary.runs, 3);
+    assert!(!summary.last.valid);
+    assert_eq!(reports.len(), 3);
+    assert!(reports[0].0.is_empty());
+    assert_eq!(reports[0].2.new.len(), 1);
+    assert_eq!(reports[1].0, vec![PathBuf::from("specs/alpha/spec.md")]);
+    assert!(reports[1].1);
+    assert_eq!(reports[1].2.resolved.len(), 1);
+    assert_eq!(reports[2].2.new, vec![issue("a", "Too short", None)]);
+}
+
+#[test]
+fn watch_stops_when_root_is_removed() {
+    let mut source = ScriptedSource(VecDeque::from([
+        WatchEvent::RootRemoved(PathBuf::from(".ito/changes/001-01_a")),
+        WatchEvent::Changed(vec![PathBuf::from("never/seen.md")]),
+    ]));
+    let mut runs = 0;
+
+    let summary = watch_validation(
+        &mut source,
+        || {
+            runs += 1;
+            run(vec![])
+        },
+        |_, _, _| {},
+    );
+
+    assert_eq!(
+        summary.outcome,
+        WatchOutcome::RootRemoved(PathBuf::from(".ito/changes/001-01_a"))
+    );
+    assert_eq!(runs, 1);
+    assert!(summary.last.valid);
+}
+
+#[test]
+fn fs_watcher_reports_changes_and_root_removal() {
+    let td = tempfile::tempdir().unwrap();
+    let root = td.path().join("specs");
+    fs::create_dir_all(root.join(".state")).unwrap();
+    let root = root.canonicalize().unwrap();
+    let ignore = IgnoreGlobs::with_defaults(Vec::<String>::new()).unwrap();
+    let source = FsWatchSource::new(vec![root.clone()], ignore, Duration::from_millis(50)).unwrap();
+    let stop = source.stop_handle();
+
+    // Drive the blocking source on a worker so a missed event fails the test
+    // instead of hanging it.
+    let (tx, rx) = mpsc::channel();
+    let worker = std::thread::spawn(move || {
+        let mut source = source;
+        while let Some(event) = source.next_event() {
+            let removed = matches!(event, WatchEvent::RootRemoved(_));
+            if tx.send(event).is_err() || removed {
+                break;
+            }
+        }
+    });
+    let timeout = Duration::from_secs(10);
+
+    fs::write(root.join(".state/cache.json"), "{}").unwrap();
+    fs::write(root.join("spec.md.swp"), "swap").unwrap();
+    fs::write(root.join("spec.md"), "# Alpha\n").unwrap();
+    let Ok(WatchEvent::Changed(paths)) = rx.recv_timeout(timeout) else {
+        stop.stop();
+        panic!("expected a change event");
+    };
+    assert!(paths.contains(&root.join("spec.md")), "{paths:?}");
+    assert!(paths.iter().all(|p| !p.starts_with(root.join(".state"))));
+    assert!(!paths.contains(&root.join("spec.md.swp")), "{paths:?}");
+
+    fs::remove_dir_all(&root).unwrap();
+    let removed = loop {
+        match rx.recv_timeout(timeout) {
+            Ok(WatchEvent::RootRemoved(path)) => break path,
+            Ok(WatchEvent::Changed(_)) => {}
+            Err(e) => {
+                stop.stop();
+                panic!("expected the root removal to be reported: {e}");
+            }
+        }
+    };
+    assert_eq!(removed, root);
+    worker.join().unwrap();
+}
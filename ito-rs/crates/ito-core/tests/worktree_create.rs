@@ -0,0 +1,260 @@
+//! Integration tests for `ito worktree create` using real git repositories.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use ito_config::types::{ItoConfig, WorktreeLayoutConfig, WorktreeStrategy};
+use ito_core::change_repository::FsChangeRepository;
+use ito_core::errors::CoreError;
+use ito_core::repo_paths::{GitRepoKind, ResolvedEnv, ResolvedWorktreePaths, WorktreeFeature};
+use ito_core::worktree_create::{
+    CreatedWorktree, WorktreeCreateOptions, create_worktree, recorded_worktree_for_change,
+};
+
+const CHANGE_ID: &str = "001-01_add-login";
+
+struct Fixture {
+    _tmp: tempfile::TempDir,
+    project_root: PathBuf,
+    worktrees_root: PathBuf,
+    config: ItoConfig,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        let tmp = tempfile::tempdir().unwrap();
+        let project_root = tmp.path().join("repo");
+        fs::create_dir_all(&project_root).unwrap();
+        run_git(&project_root, &["init", "--initial-branch=main"]);
+        run_git(&project_root, &["config", "user.email", "test@example.com"]);
+        run_git(&project_root, &["config", "user.name", "Test"]);
+        fs::write(project_root.join("README.md"), "# Test\n").unwrap();
+        let change_dir = project_root.join(".ito/changes").join(CHANGE_ID);
+        fs::create_dir_all(&change_dir).unwrap();
+        fs::write(change_dir.join("proposal.md"), "## Why\nLogin\n").unwrap();
+        run_git(&project_root, &["add", "."]);
+        run_git(&project_root, &["commit", "-m", "initial"]);
+
+        // Local-only files that should be copied into new worktrees.
+        fs::write(project_root.join(".env"), "SECRET=test123\n").unwrap();
+        fs::create_dir_all(project_root.join("config")).unwrap();
+        fs::write(project_root.join("config/dev.local.toml"), "debug = true\n").unwrap();
+        fs::write(project_root.join("config/shared.toml"), "shared = true\n").unwrap();
+
+        let mut config = ItoConfig::default();
+        config.worktrees.enabled = true;
+        config.worktrees.strategy = WorktreeStrategy::CheckoutSiblings;
+        config.worktrees.layout = WorktreeLayoutConfig {
+            base_dir: None,
+            dir_name: "ito-worktrees".to_string(),
+        };
+        config.worktrees.apply.copy_from_main =
+            vec![".env".to_string(), "config/*.local.toml".to_string()];
+
+        Self {
+            worktrees_root: tmp.path().join("ito-worktrees"),
+            _tmp: tmp,
+            project_root,
+            config,
+        }
+    }
+
+    fn env(&self) -> ResolvedEnv {
+        ResolvedEnv {
+            worktree_root: self.project_root.clone(),
+            project_root: self.project_root.clone(),
+            ito_root: self.project_root.join(".ito"),
+            git_repo_kind: GitRepoKind::NonBare,
+        }
+    }
+
+    fn paths(&self, feature: WorktreeFeature) -> ResolvedWorktreePaths {
+        ResolvedWorktreePaths {
+            feature,
+            strategy: WorktreeStrategy::CheckoutSiblings,
+            worktrees_root: Some(self.worktrees_root.clone()),
+            main_worktree_root: Some(self.project_root.clone()),
+        }
+    }
+
+    fn create(
+        &self,
+        change: &str,
+        options: &WorktreeCreateOptions,
+    ) -> Result<CreatedWorktree, CoreError> {
+        let ito_path = self.project_root.join(".ito");
+        let repo = FsChangeRepository::new(&ito_path);
+        create_worktree(
+            &repo,
+            change,
+            options,
+            &self.config,
+            &self.env(),
+            &self.paths(WorktreeFeature::Enabled),
+        )
+    }
+}
+
+fn run_git(cwd: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(cwd)
+        .env_remove("GIT_DIR")
+        .env_remove("GIT_WORK_TREE")
+        .output()
+        .unwrap_or_else(|e| panic!("Failed to run git {args:?}: {e}"));
+    assert!(
+        output.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn create_adds_worktree_on_default_branch_and_records_it() {
+    let fx = Fixture::new();
+
+    // Prefix resolution is accepted like other change commands.
+    let created = fx
+        .create("001-01", &WorktreeCreateOptions::default())
+        .unwrap();
+
+    assert_eq!(created.change_id, CHANGE_ID);
+    assert_eq!(created.branch, format!("ito/{CHANGE_ID}"));
+    assert_eq!(created.path, fx.worktrees_root.join(CHANGE_ID));
+    assert!(created.path.join("README.md").is_file());
+
+    let branch = run_git(&created.path, &["branch", "--show-current"]);
+    assert_eq!(branch.trim(), format!("ito/{CHANGE_ID}"));
+
+    let listing = run_git(&fx.project_root, &["worktree", "list", "--porcelain"]);
+    assert!(listing.contains(&format!("branch refs/heads/ito/{CHANGE_ID}")));
+
+    let record = recorded_worktree_for_change(&fx.project_root.join(".ito"), CHANGE_ID)
+        .expect("worktree should be recorded");
+    assert_eq!(record.branch, created.branch);
+    assert_eq!(record.path, created.path);
+}
+
+#[test]
+fn create_copies_configured_files_from_main() {
+    let fx = Fixture::new();
+
+    let created = fx
+        .create(CHANGE_ID, &WorktreeCreateOptions::default())
+        .unwrap();
+
+    assert_eq!(
+        fs::read_to_string(created.path.join(".env")).unwrap(),
+        "SECRET=test123\n"
+    );
+    assert!(created.path.join("config/dev.local.toml").is_file());
+    assert!(!created.path.join("config/shared.toml").exists());
+
+    let mut copied = created.copied_files.clone();
+    copied.sort();
+    assert_eq!(
+        copied,
+        vec![
+            PathBuf::from(".env"),
+            PathBuf::from("config/dev.local.toml")
+        ]
+    );
+}
+
+#[test]
+fn create_uses_custom_branch_name() {
+    let fx = Fixture::new();
+    let options = WorktreeCreateOptions {
+        branch: Some("feature/login".to_string()),
+        existing_branch: false,
+    };
+
+    let created = fx.create(CHANGE_ID, &options).unwrap();
+
+    assert_eq!(created.branch, "feature/login");
+    let branch = run_git(&created.path, &["branch", "--show-current"]);
+    assert_eq!(branch.trim(), "feature/login");
+}
+
+#[test]
+fn create_rejects_existing_branch_unless_requested() {
+    let fx = Fixture::new();
+    run_git(&fx.project_root, &["branch", &format!("ito/{CHANGE_ID}")]);
+
+    let err = fx
+        .create(CHANGE_ID, &WorktreeCreateOptions::default())
+        .unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("already exists"), "{msg}");
+    assert!(msg.contains("--existing-branch"), "{msg}");
+    assert!(!fx.worktrees_root.join(CHANGE_ID).exists());
+
+    let options = WorktreeCreateOptions {
+        branch: None,
+        existing_branch: true,
+    };
+    let created = fx.create(CHANGE_ID, &options).unwrap();
+    let branch = run_git(&created.path, &["branch", "--show-current"]);
+    assert_eq!(branch.trim(), format!("ito/{CHANGE_ID}"));
+}
+
+#[test]
+fn create_with_existing_branch_flag_requires_branch() {
+    let fx = Fixture::new();
+    let options = WorktreeCreateOptions {
+        branch: None,
+        existing_branch: true,
+    };
+
+    let msg = fx.create(CHANGE_ID, &options).unwrap_err().to_string();
+    assert!(msg.contains("does not exist"), "{msg}");
+}
+
+#[test]
+fn create_rejects_non_empty_target_dir() {
+    let fx = Fixture::new();
+    let target = fx.worktrees_root.join(CHANGE_ID);
+    fs::create_dir_all(&target).unwrap();
+    fs::write(target.join("stray.txt"), "x").unwrap();
+
+    let msg = fx
+        .create(CHANGE_ID, &WorktreeCreateOptions::default())
+        .unwrap_err()
+        .to_string();
+    assert!(msg.contains("is not empty"), "{msg}");
+
+    let branches = run_git(&fx.project_root, &["branch", "--list", "ito/*"]);
+    assert!(branches.trim().is_empty(), "no branch should be created");
+}
+
+#[test]
+fn create_fails_when_worktrees_disabled() {
+    let fx = Fixture::new();
+    let ito_path = fx.project_root.join(".ito");
+    let repo = FsChangeRepository::new(&ito_path);
+
+    let msg = create_worktree(
+        &repo,
+        CHANGE_ID,
+        &WorktreeCreateOptions::default(),
+        &fx.config,
+        &fx.env(),
+        &fx.paths(WorktreeFeature::Disabled),
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(msg.contains("worktrees.enabled = false"), "{msg}");
+}
+
+#[test]
+fn create_reports_unknown_change() {
+    let fx = Fixture::new();
+
+    let err = fx
+        .create("999-99_missing", &WorktreeCreateOptions::default())
+        .unwrap_err();
+    assert!(matches!(err, CoreError::NotFound(_)), "{err}");
+}
@@ -0,0 +1,142 @@
+//! Who or what performed an audited mutation.
+//!
+//! A human running the CLI is recorded as [`Actor::Cli`] with their git
+//! identity in `by`. When the CLI runs inside an agent harness launched by
+//! Ralph, the harness process carries the [`AGENT_HARNESS_ENV`] family of
+//! variables; the event is then recorded as [`Actor::Agent`] with an
+//! [`AgentAttribution`] under `meta.agent`.
+
+use serde::{Deserialize, Serialize};
+
+use super::event::{Actor, AuditEvent};
+
+/// Environment variable naming the harness that is driving the CLI.
+pub const AGENT_HARNESS_ENV: &str = "ITO_AGENT_HARNESS";
+/// Environment variable naming the model the harness is running.
+pub const AGENT_MODEL_ENV: &str = "ITO_AGENT_MODEL";
+/// Environment variable carrying the Ralph iteration number.
+pub const AGENT_ITERATION_ENV: &str = "ITO_AGENT_ITERATION";
+
+/// Key under an event's `meta` object that holds the agent attribution.
+pub const AGENT_META_KEY: &str = "agent";
+
+/// Label used when an event does not say who performed it.
+pub const UNKNOWN_ACTOR: &str = "unknown";
+
+/// The agent harness behind a mutation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgentAttribution {
+    /// Harness name (e.g. `opencode`, `claude`).
+    pub harness: String,
+    /// Model identifier, when the harness was given one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Ralph iteration that performed the mutation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iteration: Option<u32>,
+}
+
+impl AgentAttribution {
+    /// Read the attribution from the current process environment.
+    ///
+    /// Returns `None` unless [`AGENT_HARNESS_ENV`] is set to a non-empty value.
+    pub fn from_env() -> Option<Self> {
+        Self::from_env_with(|name| std::env::var(name).ok())
+    }
+
+    /// Read the attribution through `get_env`.
+    pub fn from_env_with(mut get_env: impl FnMut(&str) -> Option<String>) -> Option<Self> {
+        let mut read = |name: &str| {
+            get_env(name)
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let harness = read(AGENT_HARNESS_ENV)?;
+        let model = read(AGENT_MODEL_ENV);
+        let iteration = read(AGENT_ITERATION_ENV).and_then(|value| value.parse().ok());
+        Some(Self {
+            harness,
+            model,
+            iteration,
+        })
+    }
+
+    /// Environment variables that make a child CLI process report this attribution.
+    pub fn to_env(&self) -> Vec<(String, String)> {
+        let mut env = vec![(AGENT_HARNESS_ENV.to_string(), self.harness.clone())];
+        if let Some(model) = &self.model {
+            env.push((AGENT_MODEL_ENV.to_string(), model.clone()));
+        }
+        if let Some(iteration) = self.iteration {
+            env.push((AGENT_ITERATION_ENV.to_string(), iteration.to_string()));
+        }
+        env
+    }
+
+    /// Identity recorded in the event's `by` field (e.g. `@opencode`).
+    pub fn identity(&self) -> String {
+        format!("@{}", self.harness)
+    }
+
+    /// Merge this attribution into `meta` under [`AGENT_META_KEY`].
+    ///
+    /// Non-object metadata is preserved under a `value` key.
+    pub fn merge_into_meta(&self, meta: Option<serde_json::Value>) -> serde_json::Value {
+        let mut map = match meta {
+            Some(serde_json::Value::Object(map)) => map,
+            Some(other) => {
+                let mut map = serde_json::Map::new();
+                map.insert("value".to_string(), other);
+                map
+            }
+            None => serde_json::Map::new(),
+        };
+        let agent = serde_json::to_value(self).unwrap_or(serde_json::Value::Null);
+        map.insert(AGENT_META_KEY.to_string(), agent);
+        serde_json::Value::Object(map)
+    }
+
+    /// Read the attribution recorded on `event`, if any.
+    pub fn from_event(event: &AuditEvent) -> Option<Self> {
+        let agent = event.meta.as_ref()?.get(AGENT_META_KEY)?;
+        serde_json::from_value(agent.clone()).ok()
+    }
+}
+
+/// Describe who performed `event` for display.
+///
+/// Humans render as `human @jack`, agents as
+/// `agent opencode (model gpt-5, iteration 3)`, and other sources as
+/// `<actor> <by>`. Missing fields render as [`UNKNOWN_ACTOR`].
+pub fn describe_actor(event: &AuditEvent) -> String {
+    let by = match event.by.trim() {
+        "" => UNKNOWN_ACTOR,
+        by => by,
+    };
+    match event.actor.trim() {
+        "" => UNKNOWN_ACTOR.to_string(),
+        actor if actor == Actor::Cli.as_str() => format!("human {by}"),
+        actor if actor == Actor::Agent.as_str() => {
+            let Some(agent) = AgentAttribution::from_event(event) else {
+                return format!("agent {by}");
+            };
+            let mut details = Vec::new();
+            if let Some(model) = &agent.model {
+                details.push(format!("model {model}"));
+            }
+            if let Some(iteration) = agent.iteration {
+                details.push(format!("iteration {iteration}"));
+            }
+            if details.is_empty() {
+                format!("agent {}", agent.harness)
+            } else {
+                format!("agent {} ({})", agent.harness, details.join(", "))
+            }
+        }
+        actor => format!("{actor} {by}"),
+    }
+}
+
+#[cfg(test)]
+#[path = "attribution_tests.rs"]
+mod attribution_tests;
@@ -0,0 +1,123 @@
+use super::*;
+use crate::audit::event::{AuditEventBuilder, EntityType, EventContext, ops};
+
+fn event(actor: Actor, by: &str, meta: Option<serde_json::Value>) -> AuditEvent {
+    let mut builder = AuditEventBuilder::new()
+        .entity(EntityType::Task)
+        .entity_id("1.1")
+        .scope("test-change")
+        .op(ops::TASK_STATUS_CHANGE)
+        .to("complete")
+        .actor(actor)
+        .by(by)
+        .ctx(EventContext {
+            session_id: "test-session-id".to_string(),
+            harness_session_id: None,
+            branch: None,
+            worktree: None,
+            commit: None,
+        });
+    if let Some(meta) = meta {
+        builder = builder.meta(meta);
+    }
+    builder.build().expect("should build")
+}
+
+#[test]
+fn from_env_requires_harness() {
+    let attribution = AgentAttribution::from_env_with(|name| match name {
+        AGENT_MODEL_ENV => Some("gpt-5".to_string()),
+        _ => None,
+    });
+    assert_eq!(attribution, None);
+}
+
+#[test]
+fn from_env_reads_all_fields_and_ignores_bad_iteration() {
+    let attribution = AgentAttribution::from_env_with(|name| match name {
+        AGENT_HARNESS_ENV => Some(" opencode ".to_string()),
+        AGENT_MODEL_ENV => Some("gpt-5".to_string()),
+        AGENT_ITERATION_ENV => Some("three".to_string()),
+        _ => None,
+    })
+    .expect("attribution");
+    assert_eq!(attribution.harness, "opencode");
+    assert_eq!(attribution.model.as_deref(), Some("gpt-5"));
+    assert_eq!(attribution.iteration, None);
+}
+
+#[test]
+fn to_env_round_trips_through_from_env() {
+    let attribution = AgentAttribution {
+        harness: "claude".to_string(),
+        model: Some("sonnet".to_string()),
+        iteration: Some(4),
+    };
+    let env = attribution.to_env();
+    let parsed = AgentAttribution::from_env_with(|name| {
+        env.iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+    });
+    assert_eq!(parsed, Some(attribution));
+}
+
+#[test]
+fn merge_into_meta_keeps_existing_fields() {
+    let attribution = AgentAttribution {
+        harness: "codex".to_string(),
+        model: None,
+        iteration: Some(2),
+    };
+    let meta = attribution.merge_into_meta(Some(serde_json::json!({"wave": 1})));
+    assert_eq!(meta["wave"], 1);
+    assert_eq!(meta["agent"]["harness"], "codex");
+    assert_eq!(meta["agent"]["iteration"], 2);
+    assert!(meta["agent"].get("model").is_none());
+}
+
+#[test]
+fn describe_actor_for_human_and_agent() {
+    assert_eq!(
+        describe_actor(&event(Actor::Cli, "@jack", None)),
+        "human @jack"
+    );
+
+    let agent = AgentAttribution {
+        harness: "opencode".to_string(),
+        model: Some("gpt-5".to_string()),
+        iteration: Some(3),
+    };
+    let meta = agent.merge_into_meta(None);
+    assert_eq!(
+        describe_actor(&event(Actor::Agent, &agent.identity(), Some(meta))),
+        "agent opencode (model gpt-5, iteration 3)"
+    );
+    assert_eq!(
+        describe_actor(&event(Actor::Agent, "@opencode", None)),
+        "agent @opencode"
+    );
+    assert_eq!(
+        describe_actor(&event(Actor::Ralph, "@ralph", None)),
+        "ralph @ralph"
+    );
+}
+
+#[test]
+fn describe_actor_reports_missing_fields_as_unknown() {
+    let mut missing = event(Actor::Cli, "@jack", None);
+    missing.actor.clear();
+    assert_eq!(describe_actor(&missing), UNKNOWN_ACTOR);
+
+    let mut anonymous = event(Actor::Cli, "@jack", None);
+    anonymous.by.clear();
+    assert_eq!(describe_actor(&anonymous), "human unknown");
+}
+
+#[test]
+fn events_without_actor_fields_deserialize_as_unknown() {
+    let line = r#"{"v":1,"ts":"2026-01-01T00:00:00.000Z","entity":"task","entity_id":"1.1","scope":"c","op":"status_change","to":"complete","ctx":{"session_id":"s"}}"#;
+    let event: AuditEvent = serde_json::from_str(line).expect("deserialize");
+    assert_eq!(event.actor, "");
+    assert_eq!(describe_actor(&event), UNKNOWN_ACTOR);
+}
@@ -19,7 +19,7 @@ pub struct AuditEvent {
     pub v: u32,
     /// UTC timestamp in RFC 3339 format with millisecond precision.
     pub ts: String,
-    /// Entity type (task, change, module, wave, planning, config).
+    /// Entity type (task, change, module, wave, planning, config, spec, project, skill).
     pub entity: String,
     /// Entity identifier (task id, change id, module id, config key, etc.).
     pub entity_id: String,
@@ -34,9 +34,13 @@ pub struct AuditEvent {
     /// New state value.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub to: Option<String>,
-    /// Mutation source (cli, reconcile, ralph).
+    /// Mutation source (cli, reconcile, ralph, agent).
+    ///
+    /// Empty when an older or hand-written event omitted it.
+    #[serde(default)]
     pub actor: String,
     /// User/agent identity (e.g., @jack).
+    #[serde(default)]
     pub by: String,
     /// Optional operation-specific metadata.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -72,6 +76,12 @@ pub enum EntityType {
     Planning,
     /// A configuration key.
     Config,
+    /// A promoted spec.
+    Spec,
+    /// The project as a whole (init, update).
+    Project,
+    /// A skill invoked by an agent harness.
+    Skill,
 }
 
 impl EntityType {
@@ -84,6 +94,9 @@ impl EntityType {
             EntityType::Wave => "wave",
             EntityType::Planning => "planning",
             EntityType::Config => "config",
+            EntityType::Spec => "spec",
+            EntityType::Project => "project",
+            EntityType::Skill => "skill",
         }
     }
 }
@@ -98,12 +111,17 @@ impl std::fmt::Display for EntityType {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Actor {
-    /// Event emitted from a normal CLI command.
+    /// Event emitted from a normal CLI command run by a human.
     Cli,
     /// Compensating event emitted by reconciliation.
     Reconcile,
     /// Event emitted by the Ralph automation loop.
     Ralph,
+    /// Event emitted by a CLI command run from an agent harness.
+    ///
+    /// The harness, model, and iteration are recorded in `meta.agent`
+    /// (see [`super::attribution::AgentAttribution`]).
+    Agent,
 }
 
 impl Actor {
@@ -113,6 +131,7 @@ impl Actor {
             Actor::Cli => "cli",
             Actor::Reconcile => "reconcile",
             Actor::Ralph => "ralph",
+            Actor::Agent => "agent",
         }
     }
 }
@@ -180,6 +199,15 @@ pub mod ops {
     pub const CHANGE_CREATE: &str = "create";
     /// Change archived.
     pub const CHANGE_ARCHIVE: &str = "archive";
+    /// Change renamed; `from` is the old id and `to` the new one.
+    pub const CHANGE_RENAME: &str = "rename";
+    /// Tasks were split off into a new change; `from` is the original id and
+    /// `to` the new one, with the moved and renumbered task ids in `meta`.
+    pub const CHANGE_SPLIT: &str = "split";
+    /// A spec referenced by the change was renamed.
+    pub const CHANGE_SPEC_RENAMED: &str = "spec_renamed";
+    /// A single change artifact was generated through a harness.
+    pub const CHANGE_ARTIFACT_RUN: &str = "artifact_run";
 
     // Module operations
     /// Module created.
@@ -211,6 +239,21 @@ pub mod ops {
     /// Config key unset.
     pub const CONFIG_UNSET: &str = "unset";
 
+    // Spec operations
+    /// Spec renamed.
+    pub const SPEC_RENAME: &str = "rename";
+
+    // Project operations
+    /// Ito initialized in the project.
+    pub const PROJECT_INIT: &str = "init";
+    /// Ito instructions and tool adapters refreshed in the project.
+    pub const PROJECT_UPDATE: &str = "update";
+
+    // Skill operations
+    /// Skill invoked by an agent harness; the harness and whether the skill
+    /// is in the catalog are recorded in `meta`.
+    pub const SKILL_INVOKED: &str = "invoked";
+
     // Reconciliation
     /// Reconciliation compensating event.
     pub const RECONCILED: &str = "reconciled";
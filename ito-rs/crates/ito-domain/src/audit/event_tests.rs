@@ -154,6 +154,9 @@ fn entity_type_round_trip() {
         EntityType::Wave,
         EntityType::Planning,
         EntityType::Config,
+        EntityType::Spec,
+        EntityType::Project,
+        EntityType::Skill,
     ];
     for variant in variants {
         let json = serde_json::to_string(&variant).expect("serialize");
@@ -167,11 +170,12 @@ fn actor_serializes_to_lowercase() {
     assert_eq!(Actor::Cli.as_str(), "cli");
     assert_eq!(Actor::Reconcile.as_str(), "reconcile");
     assert_eq!(Actor::Ralph.as_str(), "ralph");
+    assert_eq!(Actor::Agent.as_str(), "agent");
 }
 
 #[test]
 fn actor_round_trip() {
-    let variants = [Actor::Cli, Actor::Reconcile, Actor::Ralph];
+    let variants = [Actor::Cli, Actor::Reconcile, Actor::Ralph, Actor::Agent];
     for variant in variants {
         let json = serde_json::to_string(&variant).expect("serialize");
         let parsed: Actor = serde_json::from_str(&json).expect("deserialize");
@@ -277,6 +281,9 @@ fn entity_type_as_str_matches_serde() {
         EntityType::Wave,
         EntityType::Planning,
         EntityType::Config,
+        EntityType::Spec,
+        EntityType::Project,
+        EntityType::Skill,
     ];
     for variant in variants {
         let serde_str = serde_json::to_string(&variant)
@@ -6,7 +6,7 @@
 
 use std::collections::HashMap;
 
-use super::event::{AuditEvent, ops};
+use super::event::{AuditEvent, EntityType, ops};
 
 /// Key for uniquely identifying an entity in the materialized state.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -32,11 +32,28 @@ pub struct AuditState {
 ///
 /// Events are processed in order. For each event, the `to` field (if present)
 /// becomes the current status of the entity identified by
-/// `(entity, entity_id, scope)`.
+/// `(entity, entity_id, scope)`. A change rename instead moves the change and
+/// everything scoped to it from the old id to the new one, and a change split
+/// moves the split-off tasks to the new change.
 pub fn materialize_state(events: &[AuditEvent]) -> AuditState {
     let mut entities: HashMap<EntityKey, String> = HashMap::new();
 
     for event in events {
+        if event.entity == EntityType::Change.as_str()
+            && event.op == ops::CHANGE_RENAME
+            && let (Some(from), Some(to)) = (&event.from, &event.to)
+        {
+            rename_change(&mut entities, from, to);
+            continue;
+        }
+        if event.entity == EntityType::Change.as_str()
+            && event.op == ops::CHANGE_SPLIT
+            && let (Some(from), Some(to)) = (&event.from, &event.to)
+        {
+            split_change(&mut entities, from, to, event.meta.as_ref());
+            continue;
+        }
+
         let key = EntityKey {
             entity: event.entity.clone(),
             entity_id: event.entity_id.clone(),
@@ -61,6 +78,71 @@ pub fn materialize_state(events: &[AuditEvent]) -> AuditState {
     }
 }
 
+/// Re-key the change `from` and all entities scoped to it under `to`.
+fn rename_change(entities: &mut HashMap<EntityKey, String>, from: &str, to: &str) {
+    let moved: Vec<EntityKey> = entities
+        .keys()
+        .filter(|k| {
+            k.scope.as_deref() == Some(from)
+                || (k.entity == EntityType::Change.as_str() && k.entity_id == from)
+        })
+        .cloned()
+        .collect();
+    for mut key in moved {
+        let Some(status) = entities.remove(&key) else {
+            continue;
+        };
+        if key.scope.as_deref() == Some(from) {
+            key.scope = Some(to.to_string());
+        } else {
+            key.entity_id = to.to_string();
+        }
+        entities.insert(key, status);
+    }
+}
+
+/// Move the tasks listed in `meta.tasks` from change `from` to change `to`,
+/// then apply the `meta.renumbered` id map to the tasks left behind.
+fn split_change(
+    entities: &mut HashMap<EntityKey, String>,
+    from: &str,
+    to: &str,
+    meta: Option<&serde_json::Value>,
+) {
+    let task = EntityType::Task.as_str();
+    let key = |id: &str, scope: &str| EntityKey {
+        entity: task.to_string(),
+        entity_id: id.to_string(),
+        scope: Some(scope.to_string()),
+    };
+    let Some(meta) = meta else {
+        return;
+    };
+
+    let moved = meta["tasks"].as_array().into_iter().flatten();
+    for id in moved.filter_map(|id| id.as_str()) {
+        if let Some(status) = entities.remove(&key(id, from)) {
+            entities.insert(key(id, to), status);
+        }
+    }
+
+    let renumbered: Vec<(&str, &str)> = meta["renumbered"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(old, new)| Some((old.as_str(), new.as_str()?)))
+        .collect();
+    let statuses: Vec<(&str, Option<String>)> = renumbered
+        .iter()
+        .map(|(old, new)| (*new, entities.remove(&key(old, from))))
+        .collect();
+    for (new, status) in statuses {
+        if let Some(status) = status {
+            entities.insert(key(new, from), status);
+        }
+    }
+}
+
 #[cfg(test)]
 #[path = "materialize_tests.rs"]
 mod materialize_tests;
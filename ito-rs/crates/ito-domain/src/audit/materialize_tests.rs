@@ -153,6 +153,28 @@ fn archive_event_without_to_uses_sentinel() {
     assert_eq!(state.entities.get(&key), Some(&"archived".to_string()));
 }
 
+#[test]
+fn project_events_pass_through_without_tracked_state() {
+    let events = vec![
+        make_event(
+            "task",
+            "1.1",
+            Some("009-02"),
+            "create",
+            None,
+            Some("pending"),
+        ),
+        make_event("project", "demo", None, "init", None, None),
+        make_event("project", "demo", None, "update", None, None),
+    ];
+
+    let state = materialize_state(&events);
+
+    assert_eq!(state.event_count, 3);
+    assert_eq!(state.entities.len(), 1);
+    assert!(state.entities.keys().all(|key| key.entity == "task"));
+}
+
 #[test]
 fn reconciled_events_update_state() {
     let events = vec![
@@ -267,3 +289,105 @@ fn last_event_wins() {
 
     assert_eq!(state.entities.get(&key), Some(&"c".to_string()));
 }
+
+#[test]
+fn change_rename_moves_change_and_scoped_entities() {
+    let events = vec![
+        make_event("change", "001-01_old", None, "create", None, Some("active")),
+        make_event(
+            "task",
+            "1.1",
+            Some("001-01_old"),
+            "status_change",
+            None,
+            Some("complete"),
+        ),
+        make_event(
+            "change",
+            "001-01_old",
+            None,
+            "rename",
+            Some("001-01_old"),
+            Some("001-01_new"),
+        ),
+    ];
+
+    let state = materialize_state(&events);
+    let change = |id: &str| EntityKey {
+        entity: "change".to_string(),
+        entity_id: id.to_string(),
+        scope: None,
+    };
+    let task = |scope: &str| EntityKey {
+        entity: "task".to_string(),
+        entity_id: "1.1".to_string(),
+        scope: Some(scope.to_string()),
+    };
+
+    assert_eq!(
+        state.entities.get(&change("001-01_new")),
+        Some(&"active".to_string())
+    );
+    assert_eq!(
+        state.entities.get(&task("001-01_new")),
+        Some(&"complete".to_string())
+    );
+    assert!(!state.entities.contains_key(&change("001-01_old")));
+    assert!(!state.entities.contains_key(&task("001-01_old")));
+}
+
+#[test]
+fn change_split_moves_tasks_and_renumbers_the_rest() {
+    let task_event = |id: &str, to: &str| {
+        make_event(
+            "task",
+            id,
+            Some("001-01_old"),
+            "status_change",
+            None,
+            Some(to),
+        )
+    };
+    let mut split = make_event(
+        "change",
+        "001-01_old",
+        None,
+        "split",
+        Some("001-01_old"),
+        Some("001-02_new"),
+    );
+    split.meta = Some(serde_json::json!({
+        "tasks": ["1.2"],
+        "renumbered": {"1.3": "1.2"},
+    }));
+    let events = vec![
+        make_event("change", "001-01_old", None, "create", None, Some("active")),
+        task_event("1.1", "complete"),
+        task_event("1.2", "in-progress"),
+        task_event("1.3", "pending"),
+        split,
+    ];
+
+    let state = materialize_state(&events);
+    let task = |id: &str, scope: &str| {
+        state
+            .entities
+            .get(&EntityKey {
+                entity: "task".to_string(),
+                entity_id: id.to_string(),
+                scope: Some(scope.to_string()),
+            })
+            .cloned()
+    };
+
+    assert_eq!(task("1.1", "001-01_old").as_deref(), Some("complete"));
+    assert_eq!(task("1.2", "001-01_old").as_deref(), Some("pending"));
+    assert_eq!(task("1.3", "001-01_old"), None);
+    assert_eq!(task("1.2", "001-02_new").as_deref(), Some("in-progress"));
+    let change = EntityKey {
+        entity: "change".to_string(),
+        entity_id: "001-01_old".to_string(),
+        scope: None,
+    };
+    assert_eq!(state.entities.get(&change), Some(&"active".to_string()));
+}
@@ -4,12 +4,14 @@
 //! reconciliation diff logic, and the writer trait. All types are storage-agnostic;
 //! concrete writers live in `ito-core`.
 
+pub mod attribution;
 pub mod context;
 pub mod event;
 pub mod materialize;
 pub mod reconcile;
 pub mod writer;
 
+pub use attribution::{AgentAttribution, describe_actor};
 pub use context::{GitContext, resolve_user_identity};
 pub use event::{
     Actor, AuditEvent, AuditEventBuilder, EntityType, EventContext, TaggedAuditEvent, WorktreeInfo,
@@ -219,6 +219,9 @@ fn parse_entity_type(s: &str) -> EntityType {
         "wave" => EntityType::Wave,
         "planning" => EntityType::Planning,
         "config" => EntityType::Config,
+        "spec" => EntityType::Spec,
+        "project" => EntityType::Project,
+        "skill" => EntityType::Skill,
         // Default to Task for any unrecognized entity type
         _ => EntityType::Task,
     }
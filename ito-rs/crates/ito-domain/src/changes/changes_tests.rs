@@ -97,6 +97,7 @@ fn test_change_sub_module_id_field() {
         has_specs: false,
         has_tasks: false,
         orchestrate: ChangeOrchestrateMetadata::default(),
+        metadata: ChangeMetadata::default(),
     };
 
     assert_eq!(summary.sub_module_id.as_deref(), Some("005.01"));
@@ -126,6 +127,7 @@ fn test_change_summary_status() {
         has_specs: false,
         has_tasks: false,
         orchestrate: ChangeOrchestrateMetadata::default(),
+        metadata: ChangeMetadata::default(),
     };
 
     assert_eq!(summary.status(), ChangeStatus::NoTasks);
@@ -155,6 +157,7 @@ fn test_change_work_status() {
         has_specs: false,
         has_tasks: false,
         orchestrate: ChangeOrchestrateMetadata::default(),
+        metadata: ChangeMetadata::default(),
     };
 
     assert_eq!(summary.work_status(), ChangeWorkStatus::Draft);
@@ -181,3 +184,10 @@ fn test_change_work_status() {
     summary.completed_tasks = 3;
     assert_eq!(summary.work_status(), ChangeWorkStatus::Complete);
 }
+
+#[test]
+fn change_priority_parse_is_case_insensitive() {
+    assert_eq!(ChangePriority::parse("p2"), Some(ChangePriority::P2));
+    assert_eq!(ChangePriority::parse(" P0 "), Some(ChangePriority::P0));
+    assert_eq!(ChangePriority::parse("P4"), None);
+}
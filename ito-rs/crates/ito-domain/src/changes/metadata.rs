@@ -0,0 +1,104 @@
+//! Typed metadata declared in a change proposal's YAML front matter.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Priority declared in proposal front matter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ChangePriority {
+    /// Drop everything.
+    P0,
+    /// Next up.
+    P1,
+    /// Normal priority.
+    P2,
+    /// Nice to have.
+    P3,
+}
+
+impl ChangePriority {
+    /// Every priority, highest first.
+    pub const ALL: [ChangePriority; 4] = [
+        ChangePriority::P0,
+        ChangePriority::P1,
+        ChangePriority::P2,
+        ChangePriority::P3,
+    ];
+
+    /// Parse a priority name (case-insensitive, e.g. `p1` or `P1`).
+    pub fn parse(raw: &str) -> Option<Self> {
+        let raw = raw.trim();
+        Self::ALL
+            .into_iter()
+            .find(|priority| priority.as_str().eq_ignore_ascii_case(raw))
+    }
+
+    /// Canonical name (`P0`..`P3`).
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            ChangePriority::P0 => "P0",
+            ChangePriority::P1 => "P1",
+            ChangePriority::P2 => "P2",
+            ChangePriority::P3 => "P3",
+        }
+    }
+}
+
+impl std::fmt::Display for ChangePriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Metadata from a proposal's front matter.
+///
+/// Every field is optional; proposals without front matter produce the
+/// default (empty) value. The creation date is stored as a `YYYY-MM-DD`
+/// string so `chrono` does not need its `serde` feature; use
+/// [`ChangeMetadata::created_date`] to parse it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangeMetadata {
+    /// Person or team responsible for the change.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Declared priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<ChangePriority>,
+    /// Free-form labels, in declaration order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    /// Creation date (`YYYY-MM-DD`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    /// Front matter keys not captured by the typed fields.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl ChangeMetadata {
+    /// True when no metadata was declared.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Parse `created` into a date, if present and valid.
+    pub fn created_date(&self) -> Option<NaiveDate> {
+        self.created
+            .as_deref()
+            .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+    }
+
+    /// Whether `label` is among the declared labels (case-insensitive).
+    pub fn has_label(&self, label: &str) -> bool {
+        self.labels.iter().any(|l| l.eq_ignore_ascii_case(label))
+    }
+
+    /// Whether the owner equals `owner` (case-insensitive).
+    pub fn has_owner(&self, owner: &str) -> bool {
+        self.owner
+            .as_deref()
+            .is_some_and(|o| o.eq_ignore_ascii_case(owner))
+    }
+}
@@ -3,9 +3,12 @@
 //! This module provides domain models for Ito changes and a repository
 //! for loading and querying change data.
 
+mod metadata;
 mod mutations;
 mod repository;
+mod target;
 
+pub use metadata::{ChangeMetadata, ChangePriority};
 pub use mutations::{
     ChangeArtifactKind, ChangeArtifactMutationError, ChangeArtifactMutationResult,
     ChangeArtifactMutationService, ChangeArtifactMutationServiceResult, ChangeArtifactRef,
@@ -13,6 +16,10 @@ pub use mutations::{
 pub use repository::{
     ChangeLifecycleFilter, ChangeRepository, ChangeTargetResolution, ResolveTargetOptions,
 };
+pub use target::{
+    ChangeTargetMatch, resolve_change_target, slug_matches_tokens, split_canonical_change_id,
+    target_match_ids, tokenize_target_query,
+};
 
 use chrono::{DateTime, Utc};
 use std::path::PathBuf;
@@ -122,6 +129,8 @@ pub struct Change {
     pub tasks: TasksParseResult,
     /// Per-change orchestration metadata.
     pub orchestrate: ChangeOrchestrateMetadata,
+    /// Metadata from the proposal's front matter.
+    pub metadata: ChangeMetadata,
     /// Last modification time of any artifact
     pub last_modified: DateTime<Utc>,
 }
@@ -226,6 +235,8 @@ pub struct ChangeSummary {
     pub has_tasks: bool,
     /// Per-change orchestration metadata.
     pub orchestrate: ChangeOrchestrateMetadata,
+    /// Metadata from the proposal's front matter.
+    pub metadata: ChangeMetadata,
 }
 
 impl ChangeSummary {
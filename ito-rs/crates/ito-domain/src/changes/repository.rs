@@ -1,6 +1,6 @@
 //! Change repository port definitions.
 
-use super::{Change, ChangeSummary};
+use super::{Change, ChangeSummary, ChangeTargetMatch};
 use crate::errors::DomainResult;
 
 /// Lifecycle filter for change repository queries.
@@ -57,8 +57,8 @@ impl ChangeLifecycleFilter {
 pub enum ChangeTargetResolution {
     /// Exactly one canonical change id matched.
     Unique(String),
-    /// Multiple canonical change ids matched the target.
-    Ambiguous(Vec<String>),
+    /// Multiple changes matched the target, in id order.
+    Ambiguous(Vec<ChangeTargetMatch>),
     /// No changes matched the target.
     NotFound,
 }
@@ -68,6 +68,8 @@ pub enum ChangeTargetResolution {
 pub struct ResolveTargetOptions {
     /// Lifecycle filter to apply when resolving targets.
     pub lifecycle: ChangeLifecycleFilter,
+    /// Only accept an id equal to the input (no prefix or fuzzy matching).
+    pub exact: bool,
 }
 
 /// Port for accessing change data.
@@ -80,6 +82,20 @@ pub trait ChangeRepository {
         self.resolve_target_with_options(input, ResolveTargetOptions::default())
     }
 
+    /// Resolve an input change target, accepting only an exact change id.
+    ///
+    /// Used when callers pass `--exact`: a prefix that would otherwise resolve
+    /// is reported as not found.
+    fn resolve_target_exact(&self, input: &str) -> ChangeTargetResolution {
+        self.resolve_target_with_options(
+            input,
+            ResolveTargetOptions {
+                exact: true,
+                ..ResolveTargetOptions::default()
+            },
+        )
+    }
+
     /// Resolve an input change target into a canonical change id using options.
     fn resolve_target_with_options(
         &self,
@@ -87,6 +103,15 @@ pub trait ChangeRepository {
         options: ResolveTargetOptions,
     ) -> ChangeTargetResolution;
 
+    /// Describe a candidate of an ambiguous target (title and work status).
+    fn describe_target(&self, id: &str, filter: ChangeLifecycleFilter) -> ChangeTargetMatch {
+        let work_status = self
+            .get_summary_with_filter(id, filter)
+            .ok()
+            .map(|summary| summary.work_status());
+        ChangeTargetMatch::new(id, work_status)
+    }
+
     /// Return best-effort suggestions for a change target.
     fn suggest_targets(&self, input: &str, max: usize) -> Vec<String>;
 
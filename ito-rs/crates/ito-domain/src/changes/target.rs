@@ -0,0 +1,209 @@
+//! Change target resolution.
+//!
+//! Every [`ChangeRepository`](super::ChangeRepository) backend resolves user
+//! input (`014-03`, `14-3_add-auth`, `014:auth`, a slug fragment) against its
+//! list of change ids with [`resolve_change_target`], so all commands and all
+//! persistence modes agree on what a target means.
+//!
+//! Resolution rules, first match wins:
+//!
+//! 1. An id equal to the input.
+//! 2. A change selector (`NNN-NN[_slug]`, or any input with exactly two
+//!    numbers) selects changes by module and change number. An id equal to
+//!    the canonical form of the input wins over the other numeric matches.
+//! 3. `NNN:query` matches slug tokens within module `NNN`.
+//! 4. A bare number selects every change in that module.
+//! 5. Id prefixes, then slug tokens.
+//!
+//! Exact resolution only applies rule 1. Ambiguous matches are returned in id
+//! order.
+
+use std::collections::BTreeSet;
+
+use super::{ChangeTargetResolution, ChangeWorkStatus, parse_change_id, parse_module_id};
+
+/// One candidate of an ambiguous change target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeTargetMatch {
+    /// Canonical change id.
+    pub id: String,
+    /// Human-readable title derived from the id's slug (`add-auth` -> `add auth`).
+    pub title: String,
+    /// Work status, when the change summary could be loaded.
+    pub work_status: Option<ChangeWorkStatus>,
+}
+
+impl ChangeTargetMatch {
+    /// Build a match for `id`, deriving the title from its slug.
+    pub fn new(id: impl Into<String>, work_status: Option<ChangeWorkStatus>) -> Self {
+        let id = id.into();
+        let title = id
+            .split_once('_')
+            .map(|(_prefix, slug)| slug.replace(['-', '_'], " "))
+            .unwrap_or_default();
+        Self {
+            id,
+            title,
+            work_status,
+        }
+    }
+}
+
+/// Ids of `matches`, in order.
+pub fn target_match_ids(matches: &[ChangeTargetMatch]) -> Vec<String> {
+    matches.iter().map(|m| m.id.clone()).collect()
+}
+
+/// Resolve `input` against the change ids in `names`.
+///
+/// With `exact`, only an id equal to the (trimmed) input matches. `describe`
+/// builds the listing entry for each candidate of an ambiguous target.
+pub fn resolve_change_target(
+    names: &[String],
+    input: &str,
+    exact: bool,
+    describe: impl Fn(&str) -> ChangeTargetMatch,
+) -> ChangeTargetResolution {
+    let input = input.trim();
+    if names.is_empty() || input.is_empty() {
+        return ChangeTargetResolution::NotFound;
+    }
+    if names.iter().any(|name| name == input) {
+        return ChangeTargetResolution::Unique(input.to_string());
+    }
+    if exact {
+        return ChangeTargetResolution::NotFound;
+    }
+
+    let candidates = target_candidates(names, input);
+    match candidates.len() {
+        0 => ChangeTargetResolution::NotFound,
+        1 => ChangeTargetResolution::Unique(candidates[0].clone()),
+        _ => ChangeTargetResolution::Ambiguous(candidates.iter().map(|id| describe(id)).collect()),
+    }
+}
+
+fn target_candidates(names: &[String], input: &str) -> Vec<String> {
+    let numeric_selector = parse_change_id(input).or_else(|| extract_two_numbers(input));
+    if let Some((module_id, change_num)) = numeric_selector {
+        let numeric_prefix = format!("{module_id}-{change_num}");
+        let canonical = match input.split_once('_') {
+            Some((_id, slug)) => format!("{numeric_prefix}_{slug}"),
+            None => numeric_prefix.clone(),
+        };
+        if names.iter().any(|name| name == &canonical) {
+            return vec![canonical];
+        }
+
+        let with_separator = format!("{numeric_prefix}_");
+        let numeric_matches: BTreeSet<&String> = names
+            .iter()
+            .filter(|name| *name == &numeric_prefix || name.starts_with(&with_separator))
+            .collect();
+        if !numeric_matches.is_empty() {
+            return numeric_matches.into_iter().cloned().collect();
+        }
+    }
+
+    if let Some((module, query)) = input.split_once(':') {
+        let query = query.trim();
+        if !query.is_empty() {
+            let module_id = parse_module_id(module);
+            let tokens = tokenize_target_query(query);
+            let scoped_matches: BTreeSet<&String> = names
+                .iter()
+                .filter(|name| {
+                    split_canonical_change_id(name).is_some_and(|(name_module, _change, slug)| {
+                        name_module == module_id && slug_matches_tokens(slug, &tokens)
+                    })
+                })
+                .collect();
+            return scoped_matches.into_iter().cloned().collect();
+        }
+    }
+
+    if input.chars().all(|ch| ch.is_ascii_digit()) {
+        let module_id = parse_module_id(input);
+        let module_matches: BTreeSet<&String> = names
+            .iter()
+            .filter(|name| {
+                split_canonical_change_id(name)
+                    .is_some_and(|(name_module, _change, _slug)| name_module == module_id)
+            })
+            .collect();
+        if !module_matches.is_empty() {
+            return module_matches.into_iter().cloned().collect();
+        }
+    }
+
+    let mut matches: BTreeSet<&String> = names
+        .iter()
+        .filter(|name| name.starts_with(input))
+        .collect();
+    if matches.is_empty() {
+        let tokens = tokenize_target_query(input);
+        matches = names
+            .iter()
+            .filter(|name| {
+                split_canonical_change_id(name)
+                    .is_some_and(|(_module, _change, slug)| slug_matches_tokens(slug, &tokens))
+            })
+            .collect();
+    }
+    matches.into_iter().cloned().collect()
+}
+
+/// Split a canonical change id into `(module, change, slug)`.
+///
+/// Module and change numbers are normalized; the slug is empty when the id
+/// has none. Returns `None` for ids that are not change ids.
+pub fn split_canonical_change_id(name: &str) -> Option<(String, String, &str)> {
+    let (module_id, change_num) = parse_change_id(name)?;
+    let slug = name.split_once('_').map(|(_id, s)| s).unwrap_or("");
+    Some((module_id, change_num, slug))
+}
+
+/// Lowercased whitespace-separated tokens of a target query.
+pub fn tokenize_target_query(input: &str) -> Vec<String> {
+    input
+        .split_whitespace()
+        .map(|s| s.trim().to_lowercase())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Whether every token occurs in `slug` (punctuation treated as spaces).
+///
+/// An empty token list never matches.
+pub fn slug_matches_tokens(slug: &str, tokens: &[String]) -> bool {
+    if tokens.is_empty() {
+        return false;
+    }
+    let text: String = slug
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() {
+                ch.to_ascii_lowercase()
+            } else {
+                ' '
+            }
+        })
+        .collect();
+    tokens.iter().all(|token| text.contains(token))
+}
+
+/// Interpret input containing exactly two numbers as a change selector.
+fn extract_two_numbers(input: &str) -> Option<(String, String)> {
+    let parts: Vec<&str> = input
+        .split(|ch: char| !ch.is_ascii_digit())
+        .filter(|part| !part.is_empty())
+        .collect();
+    if parts.len() != 2 {
+        return None;
+    }
+    parse_change_id(&format!("{}-{}", parts[0], parts[1]))
+}
+
+#[cfg(test)]
+#[path = "target_tests.rs"]
+mod target_tests;
@@ -0,0 +1,101 @@
+use super::*;
+
+fn names(ids: &[&str]) -> Vec<String> {
+    ids.iter().map(|id| id.to_string()).collect()
+}
+
+fn resolve(ids: &[&str], input: &str, exact: bool) -> ChangeTargetResolution {
+    resolve_change_target(&names(ids), input, exact, |id| {
+        ChangeTargetMatch::new(id, None)
+    })
+}
+
+#[test]
+fn exact_id_beats_prefix_matches() {
+    let ids = ["014-01_foo", "014-01_foo-bar", "014-10_baz"];
+
+    assert_eq!(
+        resolve(&ids, "014-01_foo", false),
+        ChangeTargetResolution::Unique("014-01_foo".to_string())
+    );
+    // Non-canonical spelling of an existing id still wins over its prefix matches.
+    assert_eq!(
+        resolve(&ids, "14-1_foo", false),
+        ChangeTargetResolution::Unique("014-01_foo".to_string())
+    );
+    assert_eq!(
+        resolve(&ids, "014-1", false),
+        ChangeTargetResolution::Ambiguous(vec![
+            ChangeTargetMatch::new("014-01_foo", None),
+            ChangeTargetMatch::new("014-01_foo-bar", None),
+        ])
+    );
+}
+
+#[test]
+fn exact_rejects_a_prefix_that_would_otherwise_resolve() {
+    let ids = ["014-01_foo", "014-10_baz"];
+
+    assert_eq!(
+        resolve(&ids, "014-01", false),
+        ChangeTargetResolution::Unique("014-01_foo".to_string())
+    );
+    assert_eq!(
+        resolve(&ids, "014-01", true),
+        ChangeTargetResolution::NotFound
+    );
+    assert_eq!(
+        resolve(&ids, " 014-01_foo ", true),
+        ChangeTargetResolution::Unique("014-01_foo".to_string())
+    );
+}
+
+#[test]
+fn ambiguous_matches_are_sorted_and_described() {
+    let ids = ["001-02_b", "001-01_a"];
+    let resolution = resolve_change_target(&names(&ids), "001", false, |id| {
+        ChangeTargetMatch::new(id, Some(ChangeWorkStatus::Draft))
+    });
+
+    let ChangeTargetResolution::Ambiguous(matches) = resolution else {
+        panic!("expected ambiguity, got {resolution:?}");
+    };
+    let ids: Vec<&str> = matches.iter().map(|m| m.id.as_str()).collect();
+    assert_eq!(ids, ["001-01_a", "001-02_b"]);
+    assert!(
+        matches
+            .iter()
+            .all(|m| m.work_status == Some(ChangeWorkStatus::Draft))
+    );
+}
+
+#[test]
+fn match_title_comes_from_the_slug() {
+    assert_eq!(
+        ChangeTargetMatch::new("014-03_add-auth_flow", None).title,
+        "add auth flow"
+    );
+    assert_eq!(ChangeTargetMatch::new("014-03", None).title, "");
+}
+
+#[test]
+fn module_scoped_and_slug_queries() {
+    let ids = ["001-12_setup-wizard", "002-12_setup-wizard", "002-13_other"];
+
+    assert_eq!(
+        resolve(&ids, "2:setup", false),
+        ChangeTargetResolution::Unique("002-12_setup-wizard".to_string())
+    );
+    assert_eq!(
+        resolve(&ids, "3:setup", false),
+        ChangeTargetResolution::NotFound
+    );
+    assert_eq!(
+        resolve(&ids, "other", false),
+        ChangeTargetResolution::Unique("002-13_other".to_string())
+    );
+    assert_eq!(
+        resolve(&ids, "change 1 12", false),
+        ChangeTargetResolution::Unique("001-12_setup-wizard".to_string())
+    );
+}
@@ -10,15 +10,51 @@ use crate::errors::{DomainError, DomainResult};
 use ito_common::fs::FileSystem;
 use ito_common::paths;
 
-fn list_child_dirs<F: FileSystem>(fs: &F, dir: &Path) -> DomainResult<Vec<String>> {
+/// Why a directory entry was left out of a listing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The name is not valid UTF-8.
+    NonUtf8,
+    /// The name does not follow the `NNN_name` module directory format.
+    InvalidModuleId,
+}
+
+impl std::fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NonUtf8 => f.write_str("name is not valid UTF-8"),
+            Self::InvalidModuleId => f.write_str("module folders must be named NNN_name"),
+        }
+    }
+}
+
+/// A directory entry that could not be listed under its own name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedEntry {
+    /// Entry name with invalid UTF-8 replaced by `U+FFFD`.
+    pub lossy_name: String,
+    /// Why the entry was skipped.
+    pub reason: SkipReason,
+}
+
+/// Directory names found by a listing, plus entries that were skipped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DirListing {
+    /// Valid directory names, sorted.
+    pub names: Vec<String>,
+    /// Malformed entries, sorted by lossy name.
+    pub skipped: Vec<SkippedEntry>,
+}
+
+fn list_child_dirs<F: FileSystem>(fs: &F, dir: &Path) -> DomainResult<DirListing> {
+    let mut out = DirListing::default();
     if !fs.exists(dir) {
-        return Ok(Vec::new());
+        return Ok(out);
     }
 
     let entries = fs
         .read_dir(dir)
         .map_err(|source| DomainError::io("listing directory entries", source))?;
-    let mut out: Vec<String> = Vec::new();
     for path in entries {
         if !fs.is_dir(&path) {
             continue;
@@ -27,45 +63,90 @@ fn list_child_dirs<F: FileSystem>(fs: &F, dir: &Path) -> DomainResult<Vec<String
         let Some(name) = path.file_name() else {
             continue;
         };
-        let name = name.to_string_lossy().to_string();
+        let Some(name) = name.to_str() else {
+            let lossy_name = name.to_string_lossy().to_string();
+            if !lossy_name.starts_with('.') {
+                out.skipped.push(SkippedEntry {
+                    lossy_name,
+                    reason: SkipReason::NonUtf8,
+                });
+            }
+            continue;
+        };
         if name.starts_with('.') {
             continue;
         }
-        out.push(name);
+        out.names.push(name.to_string());
     }
 
-    out.sort();
+    out.names.sort();
+    out.skipped.sort_by(|a, b| a.lossy_name.cmp(&b.lossy_name));
     Ok(out)
 }
 
+/// The 3-digit id prefix of a `NNN_name` module directory name.
+fn module_dir_id(name: &str) -> Option<&str> {
+    let (id_part, _) = name.split_once('_')?;
+    (id_part.len() == 3 && id_part.chars().all(|c| c.is_ascii_digit())).then_some(id_part)
+}
+
+/// List child directories under `dir`, reporting entries with non-UTF-8 names.
+///
+/// Names are sorted. Non-directory and hidden entries are ignored.
+pub fn list_dir_entries<F: FileSystem>(fs: &F, dir: &Path) -> DomainResult<DirListing> {
+    list_child_dirs(fs, dir)
+}
+
 /// List child directory names under `dir`.
 ///
-/// Returned names are sorted. Non-directory entries are ignored.
+/// Returned names are sorted. Non-directory entries are ignored, as are
+/// entries reported by [`list_dir_entries`] as skipped.
 pub fn list_dir_names<F: FileSystem>(fs: &F, dir: &Path) -> DomainResult<Vec<String>> {
-    list_child_dirs(fs, dir)
+    Ok(list_child_dirs(fs, dir)?.names)
+}
+
+/// List change directories under `{ito_path}/changes`, excluding `archive`.
+pub fn list_change_dir_entries<F: FileSystem>(fs: &F, ito_path: &Path) -> DomainResult<DirListing> {
+    let mut out = list_child_dirs(fs, paths::changes_dir(ito_path).as_path())?;
+    out.names.retain(|n| n != "archive");
+    Ok(out)
 }
 
 /// List change directory names under `{ito_path}/changes`, excluding `archive`.
 pub fn list_change_dir_names<F: FileSystem>(fs: &F, ito_path: &Path) -> DomainResult<Vec<String>> {
-    let mut out = list_child_dirs(fs, paths::changes_dir(ito_path).as_path())?;
-    out.retain(|n| n != "archive");
+    Ok(list_change_dir_entries(fs, ito_path)?.names)
+}
+
+/// List module directories under `{ito_path}/modules`.
+///
+/// Directories not named `NNN_name` are reported as skipped.
+pub fn list_module_dir_entries<F: FileSystem>(fs: &F, ito_path: &Path) -> DomainResult<DirListing> {
+    let mut out = list_child_dirs(fs, paths::modules_dir(ito_path).as_path())?;
+    let (names, invalid): (Vec<String>, Vec<String>) = out
+        .names
+        .into_iter()
+        .partition(|name| module_dir_id(name).is_some());
+    out.names = names;
+    out.skipped
+        .extend(invalid.into_iter().map(|lossy_name| SkippedEntry {
+            lossy_name,
+            reason: SkipReason::InvalidModuleId,
+        }));
+    out.skipped.sort_by(|a, b| a.lossy_name.cmp(&b.lossy_name));
     Ok(out)
 }
 
 /// List module directory names under `{ito_path}/modules`.
 pub fn list_module_dir_names<F: FileSystem>(fs: &F, ito_path: &Path) -> DomainResult<Vec<String>> {
-    list_child_dirs(fs, paths::modules_dir(ito_path).as_path())
+    Ok(list_module_dir_entries(fs, ito_path)?.names)
 }
 
 /// Extract module ids (3-digit prefixes) from the module directory names.
 pub fn list_module_ids<F: FileSystem>(fs: &F, ito_path: &Path) -> DomainResult<BTreeSet<String>> {
     let mut ids: BTreeSet<String> = BTreeSet::new();
     for name in list_module_dir_names(fs, ito_path)? {
-        let Some((id_part, _)) = name.split_once('_') else {
-            continue;
-        };
-        if id_part.len() == 3 && id_part.chars().all(|c| c.is_ascii_digit()) {
-            ids.insert(id_part.to_string());
+        if let Some(id) = module_dir_id(&name) {
+            ids.insert(id.to_string());
         }
     }
     Ok(ids)
@@ -73,7 +154,7 @@ pub fn list_module_ids<F: FileSystem>(fs: &F, ito_path: &Path) -> DomainResult<B
 
 /// List spec directory names under `{ito_path}/specs`.
 pub fn list_spec_dir_names<F: FileSystem>(fs: &F, ito_path: &Path) -> DomainResult<Vec<String>> {
-    list_child_dirs(fs, paths::specs_dir(ito_path).as_path())
+    list_dir_names(fs, paths::specs_dir(ito_path).as_path())
 }
 
 // Spec-facing API.
@@ -26,9 +26,55 @@ fn list_modules_only_returns_directories() {
 
     let fs = StdFs;
     let modules = list_modules(&fs, &ito_path).unwrap();
+    assert_eq!(modules, vec!["001_project-setup".to_string()]);
+}
+
+#[test]
+fn list_module_entries_reports_misnamed_directories() {
+    let td = tempfile::tempdir().unwrap();
+    let ito_path = td.path().join(".ito");
+    test_lib::fs::create_dir_all(ito_path.join("modules/001_project-setup")).unwrap();
+    test_lib::fs::create_dir_all(ito_path.join("modules/not-a-module")).unwrap();
+
+    let listing = list_module_dir_entries(&StdFs, &ito_path).unwrap();
+    assert_eq!(listing.names, vec!["001_project-setup".to_string()]);
+    assert_eq!(
+        listing.skipped,
+        vec![SkippedEntry {
+            lossy_name: "not-a-module".to_string(),
+            reason: SkipReason::InvalidModuleId,
+        }]
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn list_change_entries_skips_non_utf8_names() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let td = tempfile::tempdir().unwrap();
+    let ito_path = td.path().join(".ito");
+    let changes = ito_path.join("changes");
+    test_lib::fs::create_dir_all(changes.join("001-01_first")).unwrap();
+    test_lib::fs::create_dir_all(changes.join("001-02_second")).unwrap();
+    test_lib::fs::create_dir_all(changes.join(OsStr::from_bytes(b"bad-\xff-name"))).unwrap();
+
+    let listing = list_change_dir_entries(&StdFs, &ito_path).unwrap();
+    assert_eq!(
+        listing.names,
+        vec!["001-01_first".to_string(), "001-02_second".to_string()]
+    );
+    assert_eq!(
+        listing.skipped,
+        vec![SkippedEntry {
+            lossy_name: "bad-\u{fffd}-name".to_string(),
+            reason: SkipReason::NonUtf8,
+        }]
+    );
     assert_eq!(
-        modules,
-        vec!["001_project-setup".to_string(), "not-a-module".to_string()]
+        list_changes(&StdFs, &ito_path).unwrap(),
+        vec!["001-01_first".to_string(), "001-02_second".to_string()]
     );
 }
 
@@ -73,5 +73,19 @@ pub struct SubModuleSummary {
     pub change_count: u32,
 }
 
+/// Next module id after the given existing ids, as a 3-digit string.
+///
+/// The next id is one past the highest existing id; gaps left by deleted or
+/// skipped modules are not reused, so `000` and `002` yield `003`. Ids that
+/// are not numeric are ignored.
+pub fn next_module_id<'a>(existing_ids: impl IntoIterator<Item = &'a str>) -> String {
+    let max_seen = existing_ids
+        .into_iter()
+        .filter_map(|id| id.parse::<u32>().ok())
+        .max();
+    let next = max_seen.map_or(1, |n| n + 1);
+    format!("{next:03}")
+}
+
 #[cfg(test)]
 mod modules_tests;
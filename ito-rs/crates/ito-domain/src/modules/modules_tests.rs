@@ -102,3 +102,14 @@ fn test_module_summary_with_sub_modules() {
     assert_eq!(summary.sub_modules.len(), 1);
     assert_eq!(summary.sub_modules[0].change_count, 2);
 }
+
+#[test]
+fn next_module_id_starts_at_001() {
+    assert_eq!(next_module_id([]), "001");
+}
+
+#[test]
+fn next_module_id_does_not_reuse_gaps() {
+    assert_eq!(next_module_id(["000", "002"]), "003");
+    assert_eq!(next_module_id(["007", "001", "x"]), "008");
+}
@@ -1,6 +1,6 @@
 //! Module repository port definitions.
 
-use super::{Module, ModuleSummary, SubModule, SubModuleSummary};
+use super::{Module, ModuleSummary, SubModule, SubModuleSummary, next_module_id};
 use crate::errors::{DomainError, DomainResult};
 
 /// Port for accessing module data.
@@ -17,6 +17,14 @@ pub trait ModuleRepository {
     /// List all modules.
     fn list(&self) -> DomainResult<Vec<ModuleSummary>>;
 
+    /// Id the next created module would receive.
+    ///
+    /// See [`next_module_id`] for the allocation policy.
+    fn next_module_id(&self) -> DomainResult<String> {
+        let modules = self.list()?;
+        Ok(next_module_id(modules.iter().map(|m| m.id.as_str())))
+    }
+
     /// List all sub-modules belonging to a parent module.
     ///
     /// `parent_id` is the parent module identifier (e.g., `"024"`).
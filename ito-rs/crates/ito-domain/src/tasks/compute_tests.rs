@@ -42,6 +42,8 @@ fn task(
         wave,
         status,
         updated_at: None,
+        started_at: None,
+        completed_at: None,
         dependencies: deps.iter().map(|s| (*s).to_string()).collect(),
         files: Vec::new(),
         action: String::new(),
@@ -50,6 +52,8 @@ fn task(
         kind: TaskKind::Normal,
         header_line_index,
         requirements: Vec::new(),
+        parent_id: None,
+        depth: 0,
     }
 }
 
@@ -0,0 +1,185 @@
+//! Parallel dispatch planning.
+//!
+//! Turns the task graph into an ordered list of batches that can be handed to
+//! subagents. Each batch only contains tasks that are ready once every earlier
+//! batch is done, never pairs two tasks touching overlapping files, and never
+//! exceeds the requested parallelism.
+
+use super::{TaskItem, TaskStatus, TasksParseResult, compute_ready_and_blocked};
+
+/// Prompt-relevant fields of a task scheduled in a dispatch batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DispatchTask {
+    /// Task id.
+    pub id: String,
+    /// Task name.
+    pub name: String,
+    /// Wave the task belongs to, if any.
+    pub wave: Option<u32>,
+    /// Action text describing the work.
+    pub action: String,
+    /// Files the task touches.
+    pub files: Vec<String>,
+    /// Verification command, if any.
+    pub verify: Option<String>,
+}
+
+impl From<&TaskItem> for DispatchTask {
+    fn from(task: &TaskItem) -> Self {
+        Self {
+            id: task.id.clone(),
+            name: task.name.clone(),
+            wave: task.wave,
+            action: task.action.clone(),
+            files: task.files.clone(),
+            verify: task.verify.clone(),
+        }
+    }
+}
+
+/// Tasks that can run concurrently.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DispatchBatch {
+    /// Tasks in the batch, in ready order.
+    pub tasks: Vec<DispatchTask>,
+}
+
+/// Ordered dispatch batches for the pending work in a tasks file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DispatchPlan {
+    /// Maximum tasks per batch used to build the plan.
+    pub max_parallel: usize,
+    /// Batches in execution order.
+    pub batches: Vec<DispatchBatch>,
+    /// Pending tasks that can never become ready, with blocker reasons.
+    pub unscheduled: Vec<(DispatchTask, Vec<String>)>,
+}
+
+/// Group pending tasks into batches that are safe to dispatch in parallel.
+///
+/// Batches follow [`compute_ready_and_blocked`]: a task is scheduled only
+/// after every batch it depends on (directly or through wave gating). Tasks
+/// already in progress are treated as finished. Two tasks whose `files`
+/// entries overlap (same path, or one is a directory containing the other)
+/// land in different batches, and no batch holds more than `max_parallel`
+/// tasks (a value of 0 is treated as 1).
+pub fn plan_parallel_dispatch(parsed: &TasksParseResult, max_parallel: usize) -> DispatchPlan {
+    let max_parallel = max_parallel.max(1);
+    let mut state = parsed.clone();
+    for task in &mut state.tasks {
+        if task.status == TaskStatus::InProgress {
+            task.status = TaskStatus::Complete;
+        }
+    }
+
+    let mut batches = Vec::new();
+    loop {
+        let (ready, _) = compute_ready_and_blocked(&state);
+        let mut batch: Vec<&TaskItem> = Vec::new();
+        for task in &ready {
+            if batch.len() == max_parallel {
+                break;
+            }
+            if batch
+                .iter()
+                .any(|other| files_overlap(&other.files, &task.files))
+            {
+                continue;
+            }
+            batch.push(task);
+        }
+        if batch.is_empty() {
+            break;
+        }
+
+        for task in &mut state.tasks {
+            if batch.iter().any(|b| b.id == task.id) {
+                task.status = TaskStatus::Complete;
+            }
+        }
+        batches.push(DispatchBatch {
+            tasks: batch.into_iter().map(DispatchTask::from).collect(),
+        });
+    }
+
+    let (_, blocked) = compute_ready_and_blocked(&state);
+    let unscheduled = blocked
+        .iter()
+        .map(|(task, reasons)| (DispatchTask::from(task), reasons.clone()))
+        .collect();
+
+    DispatchPlan {
+        max_parallel,
+        batches,
+        unscheduled,
+    }
+}
+
+fn files_overlap(a: &[String], b: &[String]) -> bool {
+    a.iter().any(|x| b.iter().any(|y| paths_overlap(x, y)))
+}
+
+fn paths_overlap(a: &str, b: &str) -> bool {
+    let a = a.trim().trim_start_matches("./").trim_end_matches('/');
+    let b = b.trim().trim_start_matches("./").trim_end_matches('/');
+    if a.is_empty() || b.is_empty() {
+        return false;
+    }
+    a == b || is_within(a, b) || is_within(b, a)
+}
+
+fn is_within(path: &str, dir: &str) -> bool {
+    path.strip_prefix(dir)
+        .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Render a plan as markdown suitable for pasting into an agent prompt.
+pub fn render_dispatch_plan_markdown(plan: &DispatchPlan) -> String {
+    let mut out = String::new();
+    out.push_str(&format!(
+        "# Dispatch Plan\n\nRun batches in order. Tasks within a batch may run in parallel (max {}).\n",
+        plan.max_parallel
+    ));
+    if plan.batches.is_empty() {
+        out.push_str("\nNo pending tasks to dispatch.\n");
+    }
+
+    for (idx, batch) in plan.batches.iter().enumerate() {
+        out.push_str(&format!("\n## Batch {}\n", idx + 1));
+        for task in &batch.tasks {
+            out.push_str(&format!("\n### {}: {}\n\n", task.id, task.name));
+            if let Some(wave) = task.wave {
+                out.push_str(&format!("- **Wave**: {wave}\n"));
+            }
+            if !task.files.is_empty() {
+                let files: Vec<String> = task.files.iter().map(|f| format!("`{f}`")).collect();
+                out.push_str(&format!("- **Files**: {}\n", files.join(", ")));
+            }
+            if let Some(verify) = task.verify.as_deref() {
+                out.push_str(&format!("- **Verify**: `{verify}`\n"));
+            }
+            let action = task.action.trim();
+            if !action.is_empty() {
+                out.push_str(&format!("- **Action**:\n\n{action}\n"));
+            }
+        }
+    }
+
+    if !plan.unscheduled.is_empty() {
+        out.push_str("\n## Unscheduled\n\n");
+        for (task, reasons) in &plan.unscheduled {
+            out.push_str(&format!(
+                "- {}: {} ({})\n",
+                task.id,
+                task.name,
+                reasons.join("; ")
+            ));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+#[path = "dispatch_tests.rs"]
+mod dispatch_tests;
@@ -0,0 +1,175 @@
+use super::*;
+use crate::tasks::{ProgressInfo, TaskKind, TasksFormat, WaveInfo};
+
+fn task(id: &str, wave: u32, deps: &[&str], files: &[&str]) -> TaskItem {
+    TaskItem {
+        id: id.to_string(),
+        name: format!("Task {id}"),
+        wave: Some(wave),
+        status: TaskStatus::Pending,
+        updated_at: None,
+        started_at: None,
+        completed_at: None,
+        dependencies: deps.iter().map(|s| (*s).to_string()).collect(),
+        files: files.iter().map(|s| (*s).to_string()).collect(),
+        action: format!("Do {id}"),
+        verify: Some("cargo test".to_string()),
+        done_when: None,
+        kind: TaskKind::Normal,
+        header_line_index: 0,
+        requirements: Vec::new(),
+        parent_id: None,
+        depth: 0,
+    }
+}
+
+fn wave(wave: u32, depends_on: &[u32]) -> WaveInfo {
+    WaveInfo {
+        wave,
+        depends_on: depends_on.to_vec(),
+        header_line_index: 0,
+        depends_on_line_index: None,
+    }
+}
+
+fn parsed(tasks: Vec<TaskItem>, waves: Vec<WaveInfo>) -> TasksParseResult {
+    TasksParseResult {
+        format: TasksFormat::Enhanced,
+        tasks,
+        waves,
+        diagnostics: Vec::new(),
+        progress: ProgressInfo {
+            total: 0,
+            complete: 0,
+            shelved: 0,
+            in_progress: 0,
+            pending: 0,
+            remaining: 0,
+        },
+    }
+}
+
+fn batch_ids(plan: &DispatchPlan) -> Vec<Vec<&str>> {
+    plan.batches
+        .iter()
+        .map(|b| b.tasks.iter().map(|t| t.id.as_str()).collect())
+        .collect()
+}
+
+#[test]
+fn overlapping_files_are_split_across_batches() {
+    let plan = plan_parallel_dispatch(
+        &parsed(
+            vec![
+                task("1.1", 1, &[], &["src/lib.rs"]),
+                task("1.2", 1, &[], &["src/lib.rs", "src/a.rs"]),
+                task("1.3", 1, &[], &["src/b.rs"]),
+                task("1.4", 1, &[], &["src/nested/"]),
+                task("1.5", 1, &[], &["src/nested/c.rs"]),
+            ],
+            vec![wave(1, &[])],
+        ),
+        4,
+    );
+
+    assert_eq!(
+        batch_ids(&plan),
+        vec![vec!["1.1", "1.3", "1.4"], vec!["1.2", "1.5"]]
+    );
+    assert!(plan.unscheduled.is_empty());
+}
+
+#[test]
+fn wave_boundaries_are_respected() {
+    let plan = plan_parallel_dispatch(
+        &parsed(
+            vec![
+                task("1.1", 1, &[], &["a.rs"]),
+                task("1.2", 1, &[], &["b.rs"]),
+                task("2.1", 2, &[], &["c.rs"]),
+                task("2.2", 2, &[], &["d.rs"]),
+            ],
+            vec![wave(1, &[]), wave(2, &[1])],
+        ),
+        8,
+    );
+
+    assert_eq!(
+        batch_ids(&plan),
+        vec![vec!["1.1", "1.2"], vec!["2.1", "2.2"]]
+    );
+}
+
+#[test]
+fn serial_chain_produces_single_task_batches() {
+    let plan = plan_parallel_dispatch(
+        &parsed(
+            vec![
+                task("1.1", 1, &[], &["a.rs"]),
+                task("1.2", 1, &["1.1"], &["b.rs"]),
+                task("1.3", 1, &["1.2"], &["c.rs"]),
+            ],
+            vec![wave(1, &[])],
+        ),
+        4,
+    );
+
+    assert_eq!(
+        batch_ids(&plan),
+        vec![vec!["1.1"], vec!["1.2"], vec!["1.3"]]
+    );
+}
+
+#[test]
+fn batch_size_is_capped_and_in_progress_counts_as_done() {
+    let mut started = task("1.1", 1, &[], &["a.rs"]);
+    started.status = TaskStatus::InProgress;
+    let plan = plan_parallel_dispatch(
+        &parsed(
+            vec![
+                started,
+                task("1.2", 1, &["1.1"], &["b.rs"]),
+                task("1.3", 1, &[], &["c.rs"]),
+                task("1.4", 1, &[], &["d.rs"]),
+            ],
+            vec![wave(1, &[])],
+        ),
+        2,
+    );
+
+    assert_eq!(batch_ids(&plan), vec![vec!["1.2", "1.3"], vec!["1.4"]]);
+}
+
+#[test]
+fn tasks_with_unknown_dependencies_are_unscheduled() {
+    let plan = plan_parallel_dispatch(
+        &parsed(
+            vec![task("1.1", 1, &[], &[]), task("1.2", 1, &["9.9"], &[])],
+            vec![wave(1, &[])],
+        ),
+        0,
+    );
+
+    assert_eq!(plan.max_parallel, 1);
+    assert_eq!(batch_ids(&plan), vec![vec!["1.1"]]);
+    assert_eq!(plan.unscheduled.len(), 1);
+    assert_eq!(plan.unscheduled[0].0.id, "1.2");
+}
+
+#[test]
+fn markdown_lists_batches_with_prompt_fields() {
+    let plan = plan_parallel_dispatch(
+        &parsed(
+            vec![task("1.1", 1, &[], &["src/lib.rs"])],
+            vec![wave(1, &[])],
+        ),
+        2,
+    );
+    let md = render_dispatch_plan_markdown(&plan);
+
+    assert!(md.contains("## Batch 1"));
+    assert!(md.contains("### 1.1: Task 1.1"));
+    assert!(md.contains("- **Files**: `src/lib.rs`"));
+    assert!(md.contains("- **Verify**: `cargo test`"));
+    assert!(md.contains("Do 1.1"));
+}
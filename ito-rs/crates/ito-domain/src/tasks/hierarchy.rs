@@ -0,0 +1,164 @@
+//! Nested checkbox tasks.
+//!
+//! Checkbox tracking files express subtasks through list indentation:
+//!
+//! ```text
+//! - [ ] 1 Build the parser
+//!   - [x] 1.1 Tokenize
+//!   - [ ] 1.2 Parse
+//! ```
+//!
+//! [`CheckboxNesting`] assigns each checkbox line a parent and depth while the
+//! file is scanned. Progress is computed under a [`ProgressPolicy`]; by default
+//! only leaf tasks are counted, so a parent is neither double-counted nor left
+//! pending once its children are done.
+
+use super::{DiagnosticLevel, ProgressInfo, TaskDiagnostic, TaskItem, TaskStatus};
+
+/// Stable diagnostic code for a parent marked complete with open subtasks.
+pub const DIAG_PARENT_COMPLETE_WITH_OPEN_CHILDREN: &str = "parent-complete-with-open-children";
+
+/// Columns a tab advances when measuring checkbox indentation.
+const TAB_WIDTH: usize = 4;
+
+/// How task progress is counted when tasks are nested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgressPolicy {
+    /// Count only tasks without subtasks.
+    #[default]
+    LeafOnly,
+    /// Count every task; a parent counts as complete once all its subtasks are done.
+    AllTasks,
+}
+
+/// Tracks indentation while scanning checkbox lines to derive parent/child links.
+#[derive(Debug, Default)]
+pub struct CheckboxNesting {
+    open: Vec<(usize, String)>,
+}
+
+impl CheckboxNesting {
+    /// Create a tracker with no open parents.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a checkbox task found on `line` with id `id`.
+    ///
+    /// Returns the id of the enclosing task (the nearest earlier task indented
+    /// less than this one) and the nesting depth (`0` for top-level tasks).
+    pub fn place(&mut self, line: &str, id: &str) -> (Option<String>, u8) {
+        let indent = indent_width(line);
+        while self.open.last().is_some_and(|(open, _)| *open >= indent) {
+            self.open.pop();
+        }
+        let parent = self.open.last().map(|(_, id)| id.clone());
+        let depth = u8::try_from(self.open.len()).unwrap_or(u8::MAX);
+        self.open.push((indent, id.to_string()));
+        (parent, depth)
+    }
+}
+
+fn indent_width(line: &str) -> usize {
+    let mut width = 0;
+    for c in line.chars() {
+        match c {
+            ' ' => width += 1,
+            '\t' => width += TAB_WIDTH - width % TAB_WIDTH,
+            _ => break,
+        }
+    }
+    width
+}
+
+/// Index of each task's parent within `tasks`.
+///
+/// Parents always precede their children, so the nearest earlier task with a
+/// matching id is the parent even when ids repeat.
+fn parent_indices(tasks: &[TaskItem]) -> Vec<Option<usize>> {
+    tasks
+        .iter()
+        .enumerate()
+        .map(|(i, task)| {
+            let parent = task.parent_id.as_deref()?;
+            tasks[..i].iter().rposition(|t| t.id == parent)
+        })
+        .collect()
+}
+
+/// Status used for progress: a parent whose subtasks are all done counts as complete.
+fn effective_statuses(tasks: &[TaskItem], parents: &[Option<usize>]) -> Vec<TaskStatus> {
+    let mut has_children = vec![false; tasks.len()];
+    let mut children_done = vec![true; tasks.len()];
+    let mut statuses: Vec<TaskStatus> = tasks.iter().map(|t| t.status).collect();
+    // Children follow their parents, so walking backwards settles every
+    // subtree before its parent is visited.
+    for (i, parent) in parents.iter().enumerate().rev() {
+        if has_children[i] && children_done[i] && !statuses[i].is_done() {
+            statuses[i] = TaskStatus::Complete;
+        }
+        if let Some(parent) = *parent {
+            has_children[parent] = true;
+            children_done[parent] &= statuses[i].is_done();
+        }
+    }
+    statuses
+}
+
+/// Compute progress counts for `tasks` under `policy`.
+pub fn compute_progress_with_policy(tasks: &[TaskItem], policy: ProgressPolicy) -> ProgressInfo {
+    let parents = parent_indices(tasks);
+    let statuses = effective_statuses(tasks, &parents);
+    let mut is_parent = vec![false; tasks.len()];
+    for parent in parents.iter().flatten() {
+        is_parent[*parent] = true;
+    }
+
+    let counted: Vec<TaskStatus> = statuses
+        .into_iter()
+        .zip(is_parent)
+        .filter(|(_, is_parent)| policy == ProgressPolicy::AllTasks || !is_parent)
+        .map(|(status, _)| status)
+        .collect();
+    let count = |status: TaskStatus| counted.iter().filter(|s| **s == status).count();
+    let total = counted.len();
+    let done = counted.iter().filter(|s| s.is_done()).count();
+    ProgressInfo {
+        total,
+        complete: count(TaskStatus::Complete),
+        shelved: count(TaskStatus::Shelved),
+        in_progress: count(TaskStatus::InProgress),
+        pending: count(TaskStatus::Pending),
+        remaining: total.saturating_sub(done),
+    }
+}
+
+/// Warn about parents marked complete while a direct subtask is still open.
+pub(super) fn nesting_diagnostics(tasks: &[TaskItem]) -> Vec<TaskDiagnostic> {
+    let parents = parent_indices(tasks);
+    let mut diagnostics = Vec::new();
+    for (i, parent) in parents.iter().enumerate() {
+        let Some(parent) = *parent else {
+            continue;
+        };
+        let (parent_task, child) = (&tasks[parent], &tasks[i]);
+        if parent_task.status != TaskStatus::Complete || child.status.is_done() {
+            continue;
+        }
+        diagnostics.push(TaskDiagnostic {
+            level: DiagnosticLevel::Warning,
+            message: format!(
+                "Task \"{}\" is marked complete but subtask \"{}\" is not done",
+                parent_task.id, child.id
+            ),
+            task_id: Some(parent_task.id.clone()),
+            line: Some(child.header_line_index + 1),
+            code: Some(DIAG_PARENT_COMPLETE_WITH_OPEN_CHILDREN),
+        });
+    }
+    diagnostics
+}
+
+#[cfg(test)]
+#[path = "hierarchy_tests.rs"]
+mod hierarchy_tests;
@@ -0,0 +1,43 @@
+//! Tests for nested checkbox tasks and progress policies.
+
+use super::*;
+use crate::tasks::parse_tasks_tracking_file;
+
+#[test]
+fn nesting_tracks_parents_by_indentation() {
+    let mut nesting = CheckboxNesting::new();
+    assert_eq!(nesting.place("- [ ] 1 a", "1"), (None, 0));
+    assert_eq!(
+        nesting.place("  - [ ] 1.1 b", "1.1"),
+        (Some("1".to_string()), 1)
+    );
+    assert_eq!(
+        nesting.place("\t\t- [ ] 1.1.1 c", "1.1.1"),
+        (Some("1.1".to_string()), 2)
+    );
+    assert_eq!(
+        nesting.place("  - [ ] 1.2 d", "1.2"),
+        (Some("1".to_string()), 1)
+    );
+    assert_eq!(nesting.place("- [ ] 2 e", "2"), (None, 0));
+}
+
+#[test]
+fn parent_with_all_children_done_counts_complete_under_all_tasks_policy() {
+    let parsed = parse_tasks_tracking_file("- [ ] 1 Parent\n  - [x] 1.1 A\n  - [x] 1.2 B\n");
+
+    let all = compute_progress_with_policy(&parsed.tasks, ProgressPolicy::AllTasks);
+    assert_eq!((all.total, all.complete, all.remaining), (3, 3, 0));
+
+    let leaves = compute_progress_with_policy(&parsed.tasks, ProgressPolicy::LeafOnly);
+    assert_eq!((leaves.total, leaves.complete, leaves.remaining), (2, 2, 0));
+}
+
+#[test]
+fn flat_files_count_the_same_under_both_policies() {
+    let parsed = parse_tasks_tracking_file("- [x] one\n- [~] two\n- [ ] three\n");
+    assert_eq!(
+        compute_progress_with_policy(&parsed.tasks, ProgressPolicy::LeafOnly),
+        compute_progress_with_policy(&parsed.tasks, ProgressPolicy::AllTasks)
+    );
+}
@@ -0,0 +1,144 @@
+//! Structural lint rules for parsed tasks files.
+//!
+//! The parser reports what it cannot read; linting reports files that parse
+//! but are semantically broken. Each rule has a stable code so tooling can
+//! filter or suppress individual findings.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{DiagnosticLevel, TaskDiagnostic, TaskKind, TasksFormat, TasksParseResult};
+
+/// Two tasks share the same id.
+pub const LINT_DUPLICATE_TASK_ID: &str = "duplicate-task-id";
+/// A dependency names a task that does not exist.
+pub const LINT_UNKNOWN_DEPENDENCY: &str = "unknown-dependency";
+/// A dependency points at a task in a later wave.
+pub const LINT_FORWARD_WAVE_DEPENDENCY: &str = "forward-wave-dependency";
+/// A task has no `Verify` command.
+pub const LINT_EMPTY_VERIFY: &str = "empty-verify";
+/// A task has no `Done When` criteria.
+pub const LINT_EMPTY_DONE_WHEN: &str = "empty-done-when";
+/// A wave heading has no tasks under it.
+pub const LINT_EMPTY_WAVE: &str = "empty-wave";
+
+/// Run structural lint rules over a parsed tasks file.
+///
+/// Duplicate ids and unknown dependencies are errors; the remaining rules are
+/// warnings. Verify/Done-when and wave rules only apply to the enhanced format.
+/// Diagnostics are ordered by line number.
+pub fn lint_tasks(parsed: &TasksParseResult) -> Vec<TaskDiagnostic> {
+    let mut out = Vec::new();
+
+    let mut waves_by_id: BTreeMap<&str, Option<u32>> = BTreeMap::new();
+    for task in &parsed.tasks {
+        if waves_by_id.contains_key(task.id.as_str()) {
+            out.push(diagnostic(
+                DiagnosticLevel::Error,
+                LINT_DUPLICATE_TASK_ID,
+                format!("Duplicate task id: {}", task.id),
+                Some(&task.id),
+                task.header_line_index,
+            ));
+            continue;
+        }
+        waves_by_id.insert(task.id.as_str(), task.wave);
+    }
+
+    for task in &parsed.tasks {
+        for dep in &task.dependencies {
+            if dep.is_empty() || dep == "Checkpoint" || dep == &task.id {
+                continue;
+            }
+            let Some(dep_wave) = waves_by_id.get(dep.as_str()) else {
+                out.push(diagnostic(
+                    DiagnosticLevel::Error,
+                    LINT_UNKNOWN_DEPENDENCY,
+                    format!("Task {} depends on unknown task {dep}", task.id),
+                    Some(&task.id),
+                    task.header_line_index,
+                ));
+                continue;
+            };
+            if let (Some(own), Some(theirs)) = (task.wave, *dep_wave)
+                && theirs > own
+            {
+                out.push(diagnostic(
+                    DiagnosticLevel::Warning,
+                    LINT_FORWARD_WAVE_DEPENDENCY,
+                    format!(
+                        "Task {} (wave {own}) depends on {dep} in later wave {theirs}",
+                        task.id
+                    ),
+                    Some(&task.id),
+                    task.header_line_index,
+                ));
+            }
+        }
+    }
+
+    if parsed.format == TasksFormat::Enhanced {
+        for task in &parsed.tasks {
+            if task.kind == TaskKind::Checkpoint {
+                continue;
+            }
+            if is_blank(task.verify.as_deref()) {
+                out.push(diagnostic(
+                    DiagnosticLevel::Warning,
+                    LINT_EMPTY_VERIFY,
+                    format!("Task {} has no Verify command", task.id),
+                    Some(&task.id),
+                    task.header_line_index,
+                ));
+            }
+            if is_blank(task.done_when.as_deref()) {
+                out.push(diagnostic(
+                    DiagnosticLevel::Warning,
+                    LINT_EMPTY_DONE_WHEN,
+                    format!("Task {} has no Done When criteria", task.id),
+                    Some(&task.id),
+                    task.header_line_index,
+                ));
+            }
+        }
+
+        let populated: BTreeSet<u32> = parsed.tasks.iter().filter_map(|t| t.wave).collect();
+        for wave in &parsed.waves {
+            if !populated.contains(&wave.wave) {
+                out.push(diagnostic(
+                    DiagnosticLevel::Warning,
+                    LINT_EMPTY_WAVE,
+                    format!("Wave {} has no tasks", wave.wave),
+                    None,
+                    wave.header_line_index,
+                ));
+            }
+        }
+    }
+
+    out.sort_by_key(|d| d.line);
+    out
+}
+
+fn diagnostic(
+    level: DiagnosticLevel,
+    code: &'static str,
+    message: String,
+    task_id: Option<&str>,
+    line_index: usize,
+) -> TaskDiagnostic {
+    TaskDiagnostic {
+        level,
+        message,
+        task_id: task_id.map(str::to_string),
+        line: Some(line_index + 1),
+        code: Some(code),
+    }
+}
+
+fn is_blank(value: Option<&str>) -> bool {
+    value.is_none_or(|v| v.trim().is_empty())
+}
+
+#[cfg(test)]
+#[path = "lint_tests.rs"]
+mod lint_tests;
@@ -0,0 +1,137 @@
+use super::*;
+use crate::tasks::parse_tasks_tracking_file;
+
+const BROKEN: &str = "# Tasks for: 001-01_demo
+
+## Wave 1
+
+- **Depends On**: None
+
+### Task 1.1: First
+- **Dependencies**: 2.1
+- **Action**: do it
+- **Verify**: `cargo test`
+- **Done When**: works
+- **Status**: [ ] pending
+
+### Task 1.2: Second
+- **Dependencies**: 9.9
+- **Action**: do it
+- **Status**: [ ] pending
+
+### Task 1.1: Duplicate
+- **Dependencies**: None
+- **Action**: again
+- **Verify**: `cargo test`
+- **Done When**: works
+- **Status**: [ ] pending
+
+## Wave 2
+
+- **Depends On**: Wave 1
+
+### Task 2.1: Later
+- **Dependencies**: None
+- **Action**: later
+- **Verify**: `cargo test`
+- **Done When**: works
+- **Status**: [ ] pending
+
+## Wave 3
+
+- **Depends On**: Wave 2
+";
+
+const CLEAN: &str = "# Tasks for: 001-01_demo
+
+## Wave 1
+
+- **Depends On**: None
+
+### Task 1.1: First
+- **Dependencies**: None
+- **Action**: do it
+- **Verify**: `cargo test`
+- **Done When**: works
+- **Status**: [ ] pending
+
+## Wave 2
+
+- **Depends On**: Wave 1
+
+### Task 2.1: Second
+- **Dependencies**: 1.1
+- **Action**: do more
+- **Verify**: `cargo test`
+- **Done When**: works
+- **Status**: [ ] pending
+";
+
+#[test]
+fn every_rule_fires_with_code_level_and_line() {
+    let parsed = parse_tasks_tracking_file(BROKEN);
+    let found: Vec<(Option<&str>, DiagnosticLevel, Option<usize>)> = lint_tasks(&parsed)
+        .iter()
+        .map(|d| (d.code, d.level, d.line))
+        .collect();
+
+    assert_eq!(
+        found,
+        vec![
+            (
+                Some(LINT_FORWARD_WAVE_DEPENDENCY),
+                DiagnosticLevel::Warning,
+                Some(7)
+            ),
+            (
+                Some(LINT_UNKNOWN_DEPENDENCY),
+                DiagnosticLevel::Error,
+                Some(14)
+            ),
+            (Some(LINT_EMPTY_VERIFY), DiagnosticLevel::Warning, Some(14)),
+            (
+                Some(LINT_EMPTY_DONE_WHEN),
+                DiagnosticLevel::Warning,
+                Some(14)
+            ),
+            (
+                Some(LINT_DUPLICATE_TASK_ID),
+                DiagnosticLevel::Error,
+                Some(19)
+            ),
+            (Some(LINT_EMPTY_WAVE), DiagnosticLevel::Warning, Some(37)),
+        ]
+    );
+}
+
+#[test]
+fn diagnostics_name_the_offending_task() {
+    let parsed = parse_tasks_tracking_file(BROKEN);
+    let diagnostics = lint_tasks(&parsed);
+
+    let unknown = diagnostics
+        .iter()
+        .find(|d| d.code == Some(LINT_UNKNOWN_DEPENDENCY))
+        .unwrap();
+    assert_eq!(unknown.task_id.as_deref(), Some("1.2"));
+    assert!(unknown.message.contains("9.9"));
+
+    let wave = diagnostics
+        .iter()
+        .find(|d| d.code == Some(LINT_EMPTY_WAVE))
+        .unwrap();
+    assert_eq!(wave.task_id, None);
+    assert!(wave.message.contains("Wave 3"));
+}
+
+#[test]
+fn clean_file_has_no_diagnostics() {
+    let parsed = parse_tasks_tracking_file(CLEAN);
+    assert_eq!(lint_tasks(&parsed), Vec::new());
+}
+
+#[test]
+fn checkbox_files_only_get_id_and_dependency_rules() {
+    let parsed = parse_tasks_tracking_file("- [ ] First\n- [x] Second\n");
+    assert_eq!(lint_tasks(&parsed), Vec::new());
+}
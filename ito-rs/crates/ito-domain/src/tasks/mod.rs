@@ -9,19 +9,42 @@
 //! - [`TaskRepository`](crate::tasks::TaskRepository): Port for accessing task data (implemented by core).
 //! - [`parse_tasks_tracking_file`](crate::tasks::parse_tasks_tracking_file): Normalizes markdown into [`TasksParseResult`](crate::tasks::TasksParseResult).
 //! - [`compute_ready_and_blocked`](crate::tasks::compute_ready_and_blocked): Determines which tasks are actionable based on waves/deps.
+//! - [`plan_parallel_dispatch`](crate::tasks::plan_parallel_dispatch): Groups ready tasks into parallel-safe batches.
+//! - [`lint_tasks`](crate::tasks::lint_tasks): Flags structurally broken tasks files (duplicate ids, bad deps, empty waves).
+//! - [`find_stale_in_progress`](crate::tasks::find_stale_in_progress): Flags in-progress tasks nobody has touched for too long.
 //! - [`update_enhanced_task_status`](crate::tasks::update_enhanced_task_status): Modifies markdown content to reflect status changes.
 
 mod checkbox;
 mod compute;
 mod cycle;
+mod dispatch;
+mod hierarchy;
+mod lint;
 mod mutations;
 mod parse;
 mod relational;
 mod repository;
+mod stale;
+mod timing;
 mod update;
 
 /// Compute ready vs blocked tasks for a parsed tracking file.
 pub use compute::compute_ready_and_blocked;
+/// Wave-aware batches of tasks that are safe to dispatch in parallel.
+pub use dispatch::{
+    DispatchBatch, DispatchPlan, DispatchTask, plan_parallel_dispatch,
+    render_dispatch_plan_markdown,
+};
+/// Nested checkbox tasks and leaf-aware progress counting.
+pub use hierarchy::{
+    CheckboxNesting, DIAG_PARENT_COMPLETE_WITH_OPEN_CHILDREN, ProgressPolicy,
+    compute_progress_with_policy,
+};
+/// Structural lint rules and their stable codes.
+pub use lint::{
+    LINT_DUPLICATE_TASK_ID, LINT_EMPTY_DONE_WHEN, LINT_EMPTY_VERIFY, LINT_EMPTY_WAVE,
+    LINT_FORWARD_WAVE_DEPENDENCY, LINT_UNKNOWN_DEPENDENCY, lint_tasks,
+};
 /// Task mutation result types and port.
 pub use mutations::{
     TaskInitResult, TaskMutationError, TaskMutationResult, TaskMutationService,
@@ -43,6 +66,10 @@ pub use parse::tasks_path_checked;
 pub use parse::tracking_path_checked;
 /// Repository port for loading and querying task data.
 pub use repository::TaskRepository;
+/// Detection of in-progress tasks left untouched past a threshold.
+pub use stale::{StaleTask, TaskActivity, find_stale_in_progress, stale_tasks_in};
+/// Per-task and per-wave cycle times from transition timestamps.
+pub use timing::{TaskTiming, TimingScope, cycle_times_for, task_cycle_times};
 /// Update checkbox-format task status markers.
 pub use update::update_checkbox_task_status;
 /// Update enhanced-format task status and metadata.
@@ -117,6 +117,12 @@ pub trait TaskMutationService: Send + Sync {
         change_id: &str,
         task_id: &str,
     ) -> TaskMutationServiceResult<TaskMutationResult>;
+    /// Return an in-progress task to pending, e.g. after its holder crashed.
+    fn release_task(
+        &self,
+        change_id: &str,
+        task_id: &str,
+    ) -> TaskMutationServiceResult<TaskMutationResult>;
     /// Add a new task.
     fn add_task(
         &self,
@@ -14,6 +14,9 @@ use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
 use super::checkbox::split_checkbox_task_label;
+use super::hierarchy::{
+    CheckboxNesting, ProgressPolicy, compute_progress_with_policy, nesting_diagnostics,
+};
 
 static ENHANCED_HEADING_RE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(
@@ -50,6 +53,12 @@ static STATUS_RE: LazyLock<Regex> = LazyLock::new(|| {
 static UPDATED_AT_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"\*\*Updated At\*\*:\s*(\d{4}-\d{2}-\d{2})\s*$").unwrap());
 
+static STARTED_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\*\*Started\*\*:\s*(\S+)\s*$").unwrap());
+
+static COMPLETED_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\*\*Completed\*\*:\s*(\S+)\s*$").unwrap());
+
 static FILES_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"\*\*Files\*\*:\s*`([^`]+)`\s*$").unwrap());
 
@@ -131,6 +140,8 @@ pub struct TaskDiagnostic {
     pub task_id: Option<String>,
     /// Optional 0-based line index.
     pub line: Option<usize>,
+    /// Stable rule code for lint diagnostics (e.g. `duplicate-task-id`).
+    pub code: Option<&'static str>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -165,6 +176,10 @@ pub struct TaskItem {
     pub status: TaskStatus,
     /// Optional `YYYY-MM-DD` updated date.
     pub updated_at: Option<String>,
+    /// RFC 3339 timestamp of the first move to in-progress (enhanced format).
+    pub started_at: Option<String>,
+    /// RFC 3339 timestamp of completion (enhanced format).
+    pub completed_at: Option<String>,
     /// Explicit task dependencies by id.
     pub dependencies: Vec<String>,
     /// File paths mentioned for the task.
@@ -181,6 +196,10 @@ pub struct TaskItem {
     pub header_line_index: usize,
     /// Requirement IDs this task covers (traceability metadata).
     pub requirements: Vec<String>,
+    /// Id of the enclosing task for indented checkbox subtasks.
+    pub parent_id: Option<String>,
+    /// Nesting depth (`0` for top-level tasks).
+    pub depth: u8,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -256,6 +275,11 @@ impl TasksParseResult {
             },
         }
     }
+
+    /// Recompute progress counts under a non-default [`ProgressPolicy`].
+    pub fn progress_with_policy(&self, policy: ProgressPolicy) -> ProgressInfo {
+        compute_progress_with_policy(&self.tasks, policy)
+    }
 }
 
 /// Builds a default enhanced-format `tasks.md` template for a given change.
@@ -322,8 +346,10 @@ pub fn parse_tasks_tracking_file(contents: &str) -> TasksParseResult {
 /// Recognizes list items starting with `- ` or `* ` followed by a status checkbox
 /// (`[ ]`, `[x]`, `[~]`, `[>]`) and an optional label of the form `ID: Name`.
 /// Each matched line produces a TaskItem; when an explicit ID is absent a sequential
-/// numeric ID is assigned. The result uses the Checkbox format and contains no wave
-/// metadata or diagnostics; progress is computed from the parsed tasks.
+/// numeric ID is assigned. Indented items become subtasks of the nearest less-indented
+/// item above them. The result uses the Checkbox format and contains no wave metadata;
+/// progress counts leaf tasks only, and parents marked complete with open subtasks
+/// produce warnings.
 ///
 /// # Examples
 ///
@@ -340,6 +366,7 @@ pub fn parse_tasks_tracking_file(contents: &str) -> TasksParseResult {
 fn parse_checkbox_tasks(contents: &str) -> TasksParseResult {
     // Minimal compat: tasks are numbered 1..N.
     let mut tasks: Vec<TaskItem> = Vec::new();
+    let mut nesting = CheckboxNesting::new();
     for (line_idx, line) in contents.lines().enumerate() {
         let l = line.trim_start();
         let bytes = l.as_bytes();
@@ -372,12 +399,15 @@ fn parse_checkbox_tasks(contents: &str) -> TasksParseResult {
             Some((id, name)) => (id.to_string(), name.to_string()),
             None => ((tasks.len() + 1).to_string(), rest.to_string()),
         };
+        let (parent_id, depth) = nesting.place(line, &id);
         tasks.push(TaskItem {
             id,
             name,
             wave: None,
             status,
             updated_at: None,
+            started_at: None,
+            completed_at: None,
             dependencies: Vec::new(),
             files: Vec::new(),
             action: String::new(),
@@ -386,14 +416,17 @@ fn parse_checkbox_tasks(contents: &str) -> TasksParseResult {
             kind: TaskKind::Normal,
             header_line_index: line_idx,
             requirements: Vec::new(),
+            parent_id,
+            depth,
         });
     }
     let progress = compute_progress(&tasks);
+    let diagnostics = nesting_diagnostics(&tasks);
     TasksParseResult {
         format: TasksFormat::Checkbox,
         tasks,
         waves: Vec::new(),
-        diagnostics: Vec::new(),
+        diagnostics,
         progress,
     }
 }
@@ -429,6 +462,8 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
     let deps_re = &*DEPS_RE;
     let status_re = &*STATUS_RE;
     let updated_at_re = &*UPDATED_AT_RE;
+    let started_re = &*STARTED_RE;
+    let completed_re = &*COMPLETED_RE;
     let files_re = &*FILES_RE;
     let verify_re = &*VERIFY_RE;
     let done_when_re = &*DONE_WHEN_RE;
@@ -455,6 +490,8 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
         kind: TaskKind,
         deps_raw: Option<String>,
         updated_at_raw: Option<String>,
+        started_at_raw: Option<String>,
+        completed_at_raw: Option<String>,
         status_raw: Option<String>,
         status_marker_raw: Option<char>,
         files: Vec<String>,
@@ -502,6 +539,8 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
             current.desc = None;
             current.deps_raw = None;
             current.updated_at_raw = None;
+            current.started_at_raw = None;
+            current.completed_at_raw = None;
             current.status_raw = None;
             current.kind = TaskKind::Normal;
             return;
@@ -511,6 +550,8 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
         let header_line_index = current.header_line_index;
         let deps_raw = current.deps_raw.take().unwrap_or_default();
         let updated_at_raw = current.updated_at_raw.take();
+        let started_at_raw = current.started_at_raw.take();
+        let completed_at_raw = current.completed_at_raw.take();
         let status_raw = current.status_raw.take();
         let status_marker_raw = current.status_marker_raw.take();
         let files = std::mem::take(&mut current.files);
@@ -533,6 +574,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                     message: "Invalid or missing status".to_string(),
                     task_id: Some(id.clone()),
                     line: Some(header_line_index + 1),
+                    code: None,
                 });
                 TaskStatus::Pending
             }
@@ -550,6 +592,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                             message: "Status marker for complete should be [x]".to_string(),
                             task_id: Some(id.clone()),
                             line: Some(header_line_index + 1),
+                            code: None,
                         });
                     }
                 }
@@ -560,6 +603,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                             message: "Status marker for shelved should be [-]".to_string(),
                             task_id: Some(id.clone()),
                             line: Some(header_line_index + 1),
+                            code: None,
                         });
                     }
                 }
@@ -570,6 +614,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                             message: "Only complete tasks should use [x]".to_string(),
                             task_id: Some(id.clone()),
                             line: Some(header_line_index + 1),
+                            code: None,
                         });
                     }
                 }
@@ -587,6 +632,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                         message: format!("Invalid Updated At date: {s} (expected YYYY-MM-DD)"),
                         task_id: Some(id.clone()),
                         line: Some(header_line_index + 1),
+                        code: None,
                     });
                     None
                 }
@@ -597,17 +643,37 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                     message: "Missing Updated At field (expected YYYY-MM-DD)".to_string(),
                     task_id: Some(id.clone()),
                     line: Some(header_line_index + 1),
+                    code: None,
                 });
                 None
             }
         };
 
+        let mut timestamp = |label: &str, raw: Option<String>| {
+            let raw = raw?;
+            if DateTime::parse_from_rfc3339(&raw).is_ok() {
+                return Some(raw);
+            }
+            diagnostics.push(TaskDiagnostic {
+                level: DiagnosticLevel::Warning,
+                message: format!("Invalid {label} timestamp: {raw} (expected RFC 3339)"),
+                task_id: Some(id.clone()),
+                line: Some(header_line_index + 1),
+                code: None,
+            });
+            None
+        };
+        let started_at = timestamp("Started", started_at_raw);
+        let completed_at = timestamp("Completed", completed_at_raw);
+
         tasks.push(TaskItem {
             id,
             name: desc,
             wave,
             status,
             updated_at,
+            started_at,
+            completed_at,
             dependencies: deps,
             files,
             action,
@@ -616,6 +682,8 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
             kind: current.kind,
             header_line_index,
             requirements,
+            parent_id: None,
+            depth: 0,
         });
         current.kind = TaskKind::Normal;
     }
@@ -628,6 +696,8 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
         kind: TaskKind::Normal,
         deps_raw: None,
         updated_at_raw: None,
+        started_at_raw: None,
+        completed_at_raw: None,
         status_raw: None,
         status_marker_raw: None,
         files: Vec::new(),
@@ -690,6 +760,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                     message: format!("Wave {w}: duplicate Depends On line; using the first one"),
                     task_id: None,
                     line: Some(line_idx + 1),
+                    code: None,
                 });
             } else {
                 entry.depends_on_raw = Some(raw);
@@ -709,6 +780,8 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
             current_task.kind = TaskKind::Normal;
             current_task.deps_raw = None;
             current_task.updated_at_raw = None;
+            current_task.started_at_raw = None;
+            current_task.completed_at_raw = None;
             current_task.status_raw = None;
             current_task.status_marker_raw = None;
             current_task.files.clear();
@@ -727,6 +800,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                     ),
                     task_id: None,
                     line: Some(line_idx + 1),
+                    code: None,
                 });
             }
             continue;
@@ -746,6 +820,14 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                 current_task.updated_at_raw = Some(cap[1].trim().to_string());
                 continue;
             }
+            if let Some(cap) = started_re.captures(line) {
+                current_task.started_at_raw = Some(cap[1].to_string());
+                continue;
+            }
+            if let Some(cap) = completed_re.captures(line) {
+                current_task.completed_at_raw = Some(cap[1].to_string());
+                continue;
+            }
             if let Some(cap) = status_re.captures(line) {
                 let marker = cap
                     .get(1)
@@ -782,6 +864,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                             ),
                             task_id: Some(tid.clone()),
                             line: Some(line_idx + 1),
+                            code: None,
                         });
                     }
                 } else {
@@ -819,6 +902,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                     message: format!("Wave {w}: Depends On is empty"),
                     task_id: None,
                     line: Some(builder.header_line_index + 1),
+                    code: None,
                 });
             } else if trimmed.eq_ignore_ascii_case("none") {
                 // no deps
@@ -845,6 +929,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                                     .unwrap_or(builder.header_line_index)
                                     + 1,
                             ),
+                            code: None,
                         }),
                     }
                 }
@@ -855,6 +940,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                 message: format!("Wave {w}: missing Depends On line"),
                 task_id: None,
                 line: Some(builder.header_line_index + 1),
+                code: None,
             });
 
             // Preserve behavior for readiness calculations, but refuse to operate due to error.
@@ -876,6 +962,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                             .unwrap_or(builder.header_line_index)
                             + 1,
                     ),
+                    code: None,
                 });
                 continue;
             }
@@ -890,6 +977,7 @@ fn parse_enhanced_tasks(contents: &str) -> TasksParseResult {
                             .unwrap_or(builder.header_line_index)
                             + 1,
                     ),
+                    code: None,
                 });
             }
         }
@@ -986,33 +1074,7 @@ fn parse_dependencies_with_checkpoint(raw: &str, kind: TaskKind) -> (Vec<String>
 }
 
 fn compute_progress(tasks: &[TaskItem]) -> ProgressInfo {
-    let total = tasks.len();
-    let complete = tasks
-        .iter()
-        .filter(|t| t.status == TaskStatus::Complete)
-        .count();
-    let shelved = tasks
-        .iter()
-        .filter(|t| t.status == TaskStatus::Shelved)
-        .count();
-    let in_progress = tasks
-        .iter()
-        .filter(|t| t.status == TaskStatus::InProgress)
-        .count();
-    let pending = tasks
-        .iter()
-        .filter(|t| t.status == TaskStatus::Pending)
-        .count();
-    let done = tasks.iter().filter(|t| t.status.is_done()).count();
-    let remaining = total.saturating_sub(done);
-    ProgressInfo {
-        total,
-        complete,
-        shelved,
-        in_progress,
-        pending,
-        remaining,
-    }
+    compute_progress_with_policy(tasks, ProgressPolicy::default())
 }
 
 /// Path to `{ito_path}/changes/{change_id}/tasks.md`.
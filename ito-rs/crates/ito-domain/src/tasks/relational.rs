@@ -22,6 +22,7 @@ pub(super) fn validate_relational(tasks: &[TaskItem], waves: &[WaveInfo]) -> Vec
             message: "Relational validation failed: could not open in-memory SQLite".to_string(),
             task_id: None,
             line: None,
+            code: None,
         });
         return diagnostics;
     };
@@ -31,6 +32,7 @@ pub(super) fn validate_relational(tasks: &[TaskItem], waves: &[WaveInfo]) -> Vec
             message: "Relational validation failed: could not enable foreign_keys".to_string(),
             task_id: None,
             line: None,
+            code: None,
         });
         return diagnostics;
     }
@@ -74,6 +76,7 @@ CREATE TABLE task_dep (
             message: "Relational validation failed: could not create SQLite schema".to_string(),
             task_id: None,
             line: None,
+            code: None,
         });
         return diagnostics;
     }
@@ -113,6 +116,7 @@ CREATE TABLE task_dep (
                 message: format!("Duplicate task id: {}", t.id),
                 task_id: Some(t.id.clone()),
                 line: Some(t.header_line_index + 1),
+                code: None,
             });
             continue;
         }
@@ -133,6 +137,7 @@ CREATE TABLE task_dep (
                 message: format!("Duplicate task id: {}", t.id),
                 task_id: Some(t.id.clone()),
                 line: Some(t.header_line_index + 1),
+                code: None,
             });
         }
     }
@@ -150,6 +155,7 @@ CREATE TABLE task_dep (
                     message: "Task cannot depend on itself".to_string(),
                     task_id: Some(t.id.clone()),
                     line: Some(t.header_line_index + 1),
+                    code: None,
                 });
                 continue;
             }
@@ -160,6 +166,7 @@ CREATE TABLE task_dep (
                     message: format!("Missing dependency: {dep}"),
                     task_id: Some(t.id.clone()),
                     line: Some(t.header_line_index + 1),
+                    code: None,
                 });
                 continue;
             }
@@ -188,6 +195,7 @@ WHERE t.wave_num IS NOT d.wave_num
                     .to_string(),
                 task_id: None,
                 line: None,
+                code: None,
             });
             return diagnostics;
         };
@@ -198,6 +206,7 @@ WHERE t.wave_num IS NOT d.wave_num
                 message: "Relational validation failed: could not run cross-wave query".to_string(),
                 task_id: None,
                 line: None,
+                code: None,
             });
             return diagnostics;
         };
@@ -220,6 +229,7 @@ WHERE t.wave_num IS NOT d.wave_num
                 ),
                 task_id: Some(task_id),
                 line: Some(line.max(1) as usize),
+                code: None,
             });
         }
     }
@@ -241,6 +251,7 @@ WHERE t.status <> 'shelved' AND d.status = 'shelved'
                     .to_string(),
                 task_id: None,
                 line: None,
+                code: None,
             });
             return diagnostics;
         };
@@ -252,6 +263,7 @@ WHERE t.status <> 'shelved' AND d.status = 'shelved'
                     .to_string(),
                 task_id: None,
                 line: None,
+                code: None,
             });
             return diagnostics;
         };
@@ -271,6 +283,7 @@ WHERE t.status <> 'shelved' AND d.status = 'shelved'
                 message: format!("Dependency is shelved: {dep_task_id}"),
                 task_id: Some(task_id),
                 line: Some(line.max(1) as usize),
+                code: None,
             });
         }
     }
@@ -300,6 +313,7 @@ WHERE t.status <> 'shelved' AND d.status = 'shelved'
                 message: format!("Dependency cycle detected: {path}"),
                 task_id: None,
                 line: None,
+                code: None,
             });
         }
     }
@@ -324,6 +338,7 @@ WHERE t.status <> 'shelved' AND d.status = 'shelved'
                 message: format!("Wave dependency cycle detected: {path}"),
                 task_id: None,
                 line: None,
+                code: None,
             });
         }
     }
@@ -33,6 +33,8 @@ fn task(
         wave,
         status,
         updated_at: None,
+        started_at: None,
+        completed_at: None,
         dependencies: deps.iter().map(|s| (*s).to_string()).collect(),
         files: Vec::new(),
         action: String::new(),
@@ -41,6 +43,8 @@ fn task(
         kind: TaskKind::Normal,
         header_line_index,
         requirements: Vec::new(),
+        parent_id: None,
+        depth: 0,
     }
 }
 
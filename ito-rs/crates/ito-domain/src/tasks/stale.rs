@@ -0,0 +1,116 @@
+//! Detection of in-progress tasks that have been left untouched.
+//!
+//! Agents that crash mid-task leave it `in-progress` forever, which hides the
+//! rest of the wave from [`compute_ready_and_blocked`](super::compute_ready_and_blocked).
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
+
+use super::{TaskItem, TaskStatus, TasksParseResult};
+
+/// Most recent activity per task id, from sources outside the tracking file
+/// (typically audit events).
+pub type TaskActivity = BTreeMap<String, DateTime<FixedOffset>>;
+
+/// An in-progress task whose last recorded activity is older than the threshold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleTask {
+    /// Task id.
+    pub id: String,
+    /// Most recent recorded activity for the task.
+    pub last_activity: DateTime<FixedOffset>,
+    /// Time elapsed between `last_activity` and the evaluation time.
+    pub idle: Duration,
+}
+
+impl StaleTask {
+    /// Idle time in its largest whole unit, e.g. `3d`, `5h`, or `40m`.
+    pub fn idle_label(&self) -> String {
+        let secs = self.idle.as_secs();
+        match secs {
+            s if s >= 86_400 => format!("{}d", s / 86_400),
+            s if s >= 3_600 => format!("{}h", s / 3_600),
+            s => format!("{}m", s / 60),
+        }
+    }
+}
+
+/// In-progress tasks untouched for longer than `threshold` as of `now`.
+///
+/// A task's last activity is the latest of its `Started` timestamp, its
+/// `Updated At` date (counted as the end of that day), and its entry in
+/// `activity`. Audit activity is the only signal for checkbox files, which
+/// record no timestamps; tasks with no recorded activity at all are never
+/// reported, since their age is unknown.
+pub fn find_stale_in_progress(
+    parsed: &TasksParseResult,
+    activity: &TaskActivity,
+    threshold: Duration,
+    now: DateTime<FixedOffset>,
+) -> Vec<StaleTask> {
+    stale_tasks_in(&parsed.tasks, activity, threshold, now)
+}
+
+/// [`find_stale_in_progress`] for an already-parsed task list.
+pub fn stale_tasks_in(
+    tasks: &[TaskItem],
+    activity: &TaskActivity,
+    threshold: Duration,
+    now: DateTime<FixedOffset>,
+) -> Vec<StaleTask> {
+    let mut out = Vec::new();
+    for task in tasks {
+        if task.status != TaskStatus::InProgress {
+            continue;
+        }
+        let Some(last_activity) = last_activity(task, activity, now.offset()) else {
+            continue;
+        };
+        let Ok(idle) = (now - last_activity).to_std() else {
+            continue;
+        };
+        if idle > threshold {
+            out.push(StaleTask {
+                id: task.id.clone(),
+                last_activity,
+                idle,
+            });
+        }
+    }
+    out
+}
+
+fn last_activity(
+    task: &TaskItem,
+    activity: &TaskActivity,
+    offset: &FixedOffset,
+) -> Option<DateTime<FixedOffset>> {
+    let started = task
+        .started_at
+        .as_deref()
+        .and_then(|raw| DateTime::parse_from_rfc3339(raw).ok());
+    let updated = task
+        .updated_at
+        .as_deref()
+        .and_then(|raw| end_of_day(raw, offset));
+    [started, updated, activity.get(&task.id).copied()]
+        .into_iter()
+        .flatten()
+        .max()
+}
+
+/// `Updated At` only records a date, so assume activity until its last moment.
+fn end_of_day(raw: &str, offset: &FixedOffset) -> Option<DateTime<FixedOffset>> {
+    let next = NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+        .ok()?
+        .succ_opt()?;
+    offset
+        .from_local_datetime(&next.and_hms_opt(0, 0, 0)?)
+        .single()
+}
+
+#[cfg(test)]
+#[path = "stale_tests.rs"]
+mod stale_tests;
@@ -0,0 +1,59 @@
+use super::*;
+use crate::tasks::parse_tasks_tracking_file;
+
+const ENHANCED: &str = "## Wave 1\n\n- **Depends On**: None\n\n### Task 1.1: Stuck\n- **Updated At**: 2026-03-01\n- **Started**: 2026-03-01T09:00:00+00:00\n- **Status**: [>] in-progress\n\n### Task 1.2: Waiting\n- **Updated At**: 2026-03-01\n- **Status**: [ ] pending\n";
+
+fn ts(raw: &str) -> DateTime<FixedOffset> {
+    DateTime::parse_from_rfc3339(raw).unwrap()
+}
+
+fn hours(n: u64) -> Duration {
+    Duration::from_secs(n * 3_600)
+}
+
+#[test]
+fn updated_at_date_counts_until_the_end_of_the_day() {
+    let parsed = parse_tasks_tracking_file(ENHANCED);
+    let activity = TaskActivity::new();
+
+    // Last activity is 2026-03-02T00:00:00Z; exactly 24h later is not stale.
+    let at_boundary = ts("2026-03-03T00:00:00+00:00");
+    assert!(find_stale_in_progress(&parsed, &activity, hours(24), at_boundary).is_empty());
+
+    let just_past = ts("2026-03-03T00:00:01+00:00");
+    let stale = find_stale_in_progress(&parsed, &activity, hours(24), just_past);
+    assert_eq!(stale.len(), 1);
+    assert_eq!(stale[0].id, "1.1");
+    assert_eq!(stale[0].last_activity, ts("2026-03-02T00:00:00+00:00"));
+}
+
+#[test]
+fn audit_activity_refreshes_a_task() {
+    let parsed = parse_tasks_tracking_file(ENHANCED);
+    let now = ts("2026-03-05T12:00:00+00:00");
+    assert_eq!(
+        find_stale_in_progress(&parsed, &TaskActivity::new(), hours(24), now)[0].idle_label(),
+        "3d"
+    );
+
+    let mut activity = TaskActivity::new();
+    activity.insert("1.1".to_string(), ts("2026-03-05T06:00:00+00:00"));
+    assert!(find_stale_in_progress(&parsed, &activity, hours(24), now).is_empty());
+
+    let stale = find_stale_in_progress(&parsed, &activity, hours(4), now);
+    assert_eq!(stale[0].idle_label(), "6h");
+}
+
+#[test]
+fn checkbox_tasks_rely_on_audit_activity() {
+    let parsed = parse_tasks_tracking_file("- [x] one\n- [~] two\n- [ ] three\n");
+    let now = ts("2026-03-05T12:00:00+00:00");
+    assert!(find_stale_in_progress(&parsed, &TaskActivity::new(), hours(1), now).is_empty());
+
+    let id = parsed.tasks[1].id.clone();
+    let activity = TaskActivity::from([(id.clone(), ts("2026-03-05T11:15:00+00:00"))]);
+    let stale = find_stale_in_progress(&parsed, &activity, Duration::from_secs(600), now);
+    assert_eq!(stale.len(), 1);
+    assert_eq!(stale[0].id, id);
+    assert_eq!(stale[0].idle_label(), "45m");
+}
@@ -0,0 +1,116 @@
+//! Cycle-time reporting from task transition timestamps.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, FixedOffset};
+
+use super::{TaskItem, TaskStatus, TasksFormat, TasksParseResult};
+
+/// What a [`TaskTiming`] entry measures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimingScope {
+    /// A single task, by id.
+    Task(String),
+    /// A whole wave, by number.
+    Wave(u32),
+}
+
+/// Start/completion timestamps and the elapsed time between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskTiming {
+    /// Task or wave this entry describes.
+    pub scope: TimingScope,
+    /// RFC 3339 start timestamp.
+    pub started_at: Option<String>,
+    /// RFC 3339 completion timestamp.
+    pub completed_at: Option<String>,
+    /// Seconds from start to completion, when both are known.
+    pub duration_secs: Option<i64>,
+}
+
+/// Per-task and per-wave cycle times for an enhanced tracking file.
+///
+/// Task entries come first in file order, followed by wave entries in wave
+/// order. Only tasks and waves with at least one recorded timestamp are
+/// reported; checkbox files carry no timestamps and yield an empty list.
+///
+/// A wave starts at its earliest task start and completes at its latest task
+/// completion once every non-shelved task in it is complete.
+pub fn task_cycle_times(parsed: &TasksParseResult) -> Vec<TaskTiming> {
+    cycle_times_for(parsed.format, &parsed.tasks)
+}
+
+/// [`task_cycle_times`] for an already-parsed task list.
+pub fn cycle_times_for(format: TasksFormat, tasks: &[TaskItem]) -> Vec<TaskTiming> {
+    if format == TasksFormat::Checkbox {
+        return Vec::new();
+    }
+
+    let mut out = Vec::new();
+    let mut waves: BTreeMap<u32, Vec<&TaskItem>> = BTreeMap::new();
+    for task in tasks {
+        if let Some(wave) = task.wave {
+            waves.entry(wave).or_default().push(task);
+        }
+        if task.started_at.is_none() && task.completed_at.is_none() {
+            continue;
+        }
+        out.push(timing(
+            TimingScope::Task(task.id.clone()),
+            task.started_at.as_deref().and_then(parse),
+            task.completed_at.as_deref().and_then(parse),
+        ));
+    }
+
+    for (wave, tasks) in waves {
+        let started = tasks
+            .iter()
+            .filter_map(|t| t.started_at.as_deref().and_then(parse))
+            .min();
+        let active: Vec<&&TaskItem> = tasks
+            .iter()
+            .filter(|t| t.status != TaskStatus::Shelved)
+            .collect();
+        let completions: Vec<DateTime<FixedOffset>> = active
+            .iter()
+            .filter(|t| t.status == TaskStatus::Complete)
+            .filter_map(|t| t.completed_at.as_deref().and_then(parse))
+            .collect();
+        let completed = if !active.is_empty() && completions.len() == active.len() {
+            completions.into_iter().max()
+        } else {
+            None
+        };
+        if started.is_none() && completed.is_none() {
+            continue;
+        }
+        out.push(timing(TimingScope::Wave(wave), started, completed));
+    }
+
+    out
+}
+
+fn parse(raw: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(raw).ok()
+}
+
+fn timing(
+    scope: TimingScope,
+    started: Option<DateTime<FixedOffset>>,
+    completed: Option<DateTime<FixedOffset>>,
+) -> TaskTiming {
+    let duration_secs = match (started, completed) {
+        (Some(s), Some(c)) => Some((c - s).num_seconds().max(0)),
+        _ => None,
+    };
+    TaskTiming {
+        scope,
+        started_at: started.map(|t| t.to_rfc3339()),
+        completed_at: completed.map(|t| t.to_rfc3339()),
+        duration_secs,
+    }
+}
+
+#[cfg(test)]
+#[path = "timing_tests.rs"]
+mod timing_tests;
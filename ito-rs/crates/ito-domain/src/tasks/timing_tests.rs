@@ -0,0 +1,103 @@
+use super::*;
+use crate::tasks::{parse_tasks_tracking_file, update_enhanced_task_status};
+use chrono::{Local, TimeZone};
+
+const TWO_TASK_WAVE: &str = "## Wave 1\n\n- **Depends On**: None\n\n### Task 1.1: First\n- **Updated At**: 2026-01-01\n- **Status**: [ ] pending\n\n### Task 1.2: Second\n- **Updated At**: 2026-01-01\n- **Status**: [ ] pending\n";
+
+fn at(hour: u32, minute: u32) -> chrono::DateTime<Local> {
+    Local
+        .with_ymd_and_hms(2026, 2, 15, hour, minute, 0)
+        .unwrap()
+}
+
+#[test]
+fn start_and_complete_round_trip_through_the_parser() {
+    let md = update_enhanced_task_status(TWO_TASK_WAVE, "1.1", TaskStatus::InProgress, at(9, 0));
+    let md = update_enhanced_task_status(&md, "1.1", TaskStatus::Complete, at(9, 30));
+
+    let parsed = parse_tasks_tracking_file(&md);
+    let task = &parsed.tasks[0];
+    assert_eq!(
+        task.started_at.as_deref(),
+        Some(at(9, 0).to_rfc3339().as_str())
+    );
+    assert_eq!(
+        task.completed_at.as_deref(),
+        Some(at(9, 30).to_rfc3339().as_str())
+    );
+
+    let timings = task_cycle_times(&parsed);
+    assert_eq!(timings[0].scope, TimingScope::Task("1.1".to_string()));
+    assert_eq!(timings[0].duration_secs, Some(30 * 60));
+}
+
+#[test]
+fn repeated_transitions_keep_the_first_timestamps() {
+    let md = update_enhanced_task_status(TWO_TASK_WAVE, "1.1", TaskStatus::InProgress, at(9, 0));
+    let md = update_enhanced_task_status(&md, "1.1", TaskStatus::InProgress, at(10, 0));
+    let md = update_enhanced_task_status(&md, "1.1", TaskStatus::Complete, at(11, 0));
+    let md = update_enhanced_task_status(&md, "1.1", TaskStatus::Complete, at(12, 0));
+
+    assert_eq!(md.matches("- **Started**:").count(), 1);
+    assert_eq!(md.matches("- **Completed**:").count(), 1);
+    let task = &parse_tasks_tracking_file(&md).tasks[0];
+    assert_eq!(
+        task.started_at.as_deref(),
+        Some(at(9, 0).to_rfc3339().as_str())
+    );
+    assert_eq!(
+        task.completed_at.as_deref(),
+        Some(at(11, 0).to_rfc3339().as_str())
+    );
+}
+
+#[test]
+fn reopening_a_task_clears_completed() {
+    let md = update_enhanced_task_status(TWO_TASK_WAVE, "1.1", TaskStatus::InProgress, at(9, 0));
+    let md = update_enhanced_task_status(&md, "1.1", TaskStatus::Complete, at(9, 30));
+    let md = update_enhanced_task_status(&md, "1.1", TaskStatus::InProgress, at(10, 0));
+
+    assert!(!md.contains("- **Completed**:"));
+    let task = &parse_tasks_tracking_file(&md).tasks[0];
+    assert_eq!(
+        task.started_at.as_deref(),
+        Some(at(9, 0).to_rfc3339().as_str())
+    );
+    assert_eq!(task.completed_at, None);
+}
+
+#[test]
+fn wave_timing_spans_first_start_to_last_completion() {
+    let mut md = TWO_TASK_WAVE.to_string();
+    md = update_enhanced_task_status(&md, "1.1", TaskStatus::InProgress, at(9, 0));
+    md = update_enhanced_task_status(&md, "1.2", TaskStatus::InProgress, at(9, 15));
+    md = update_enhanced_task_status(&md, "1.1", TaskStatus::Complete, at(10, 0));
+
+    let timings = task_cycle_times(&parse_tasks_tracking_file(&md));
+    let wave = timings
+        .iter()
+        .find(|t| t.scope == TimingScope::Wave(1))
+        .unwrap();
+    assert_eq!(wave.completed_at, None, "wave still has an open task");
+
+    md = update_enhanced_task_status(&md, "1.2", TaskStatus::Complete, at(11, 0));
+    let timings = task_cycle_times(&parse_tasks_tracking_file(&md));
+    let scopes: Vec<&TimingScope> = timings.iter().map(|t| &t.scope).collect();
+    assert_eq!(
+        scopes,
+        [
+            &TimingScope::Task("1.1".to_string()),
+            &TimingScope::Task("1.2".to_string()),
+            &TimingScope::Wave(1),
+        ]
+    );
+    assert_eq!(timings[1].duration_secs, Some(105 * 60));
+    assert_eq!(timings[2].duration_secs, Some(2 * 60 * 60));
+}
+
+#[test]
+fn checkbox_files_report_no_timing_data() {
+    let parsed = parse_tasks_tracking_file("- [x] 1.1 Done\n- [ ] 1.2 Todo\n");
+    assert_eq!(parsed.format, TasksFormat::Checkbox);
+    assert!(task_cycle_times(&parsed).is_empty());
+}
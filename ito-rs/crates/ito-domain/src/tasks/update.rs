@@ -1,6 +1,6 @@
 //! Helpers for updating task status in `tasks.md`.
 
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Local, SecondsFormat};
 use regex::Regex;
 
 use super::TaskStatus;
@@ -123,6 +123,11 @@ pub fn update_checkbox_task_status(
 /// `- **Updated At**: YYYY-MM-DD` lines as needed, and returns the modified file contents
 /// (ensuring a trailing newline).
 ///
+/// Transition timestamps are maintained alongside the status: moving to in-progress writes
+/// `- **Started**: <rfc3339>` and completing writes `- **Completed**: <rfc3339>`. Existing
+/// values are kept on repeated transitions, and `Completed` is removed when the task moves
+/// to any other status.
+///
 /// # Examples
 ///
 /// ```
@@ -155,6 +160,7 @@ pub fn update_enhanced_task_status(
     };
 
     let date = now.format("%Y-%m-%d").to_string();
+    let timestamp = now.to_rfc3339_opts(SecondsFormat::Secs, false);
     let updated_at_line = format!("- **Updated At**: {date}");
 
     let mut lines: Vec<String> = Vec::new();
@@ -213,6 +219,8 @@ pub fn update_enhanced_task_status(
             }
             (Some(_status_idx), Some(_updated_idx)) => {}
         }
+
+        update_transition_timestamps(&mut lines, start, new_status, &timestamp);
     }
 
     // Preserve trailing newline behavior similar to TS templates.
@@ -220,3 +228,48 @@ pub fn update_enhanced_task_status(
     out.push('\n');
     out
 }
+
+const STARTED_PREFIX: &str = "- **Started**:";
+const COMPLETED_PREFIX: &str = "- **Completed**:";
+
+/// Maintain the `Started`/`Completed` lines of the task block whose heading is at `start`.
+///
+/// New lines are inserted immediately before the block's `Status` line.
+fn update_transition_timestamps(
+    lines: &mut Vec<String>,
+    start: usize,
+    new_status: TaskStatus,
+    timestamp: &str,
+) {
+    let find = |lines: &[String], prefix: &str| {
+        let end = task_block_end(lines, start);
+        (start + 1..end).find(|&i| lines[i].trim_start().starts_with(prefix))
+    };
+
+    if new_status != TaskStatus::Complete
+        && let Some(i) = find(lines, COMPLETED_PREFIX)
+    {
+        lines.remove(i);
+    }
+
+    let wanted = match new_status {
+        TaskStatus::InProgress => STARTED_PREFIX,
+        TaskStatus::Complete => COMPLETED_PREFIX,
+        TaskStatus::Pending | TaskStatus::Shelved => return,
+    };
+    if find(lines, wanted).is_some() {
+        return;
+    }
+    let at = find(lines, "- **Status**:").unwrap_or_else(|| task_block_end(lines, start));
+    lines.insert(at, format!("{wanted} {timestamp}"));
+}
+
+/// Index one past the last line of the task block whose heading is at `start`.
+fn task_block_end(lines: &[String], start: usize) -> usize {
+    lines
+        .iter()
+        .enumerate()
+        .skip(start + 1)
+        .find(|(_, line)| line.starts_with("### ") || line.starts_with("## "))
+        .map_or(lines.len(), |(i, _)| i)
+}
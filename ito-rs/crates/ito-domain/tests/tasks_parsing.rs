@@ -667,3 +667,73 @@ fn update_checkbox_task_status_preserves_bullet_style() {
     let out2 = tasks::update_checkbox_task_status(md, "2", tasks::TaskStatus::Complete).unwrap();
     assert!(out2.contains("* [x] second"));
 }
+
+const NESTED_CHECKBOX_TASKS: &str = "\
+- [ ] 1 Build the parser
+  - [ ] 1.1 Tokenizer
+    - [x] 1.1.1 Identifiers
+    - [x] 1.1.2 Literals
+  - [ ] 1.2 Grammar
+    - [~] 1.2.1 Expressions
+    - [ ] 1.2.2 Statements
+- [x] 2 Ship it
+  - [ ] 2.1 Write release notes
+";
+
+#[test]
+fn parse_checkbox_tasks_builds_nested_hierarchy() {
+    let parsed = tasks::parse_tasks_tracking_file(NESTED_CHECKBOX_TASKS);
+    assert_eq!(parsed.format, tasks::TasksFormat::Checkbox);
+
+    let shape: Vec<(&str, Option<&str>, u8)> = parsed
+        .tasks
+        .iter()
+        .map(|t| (t.id.as_str(), t.parent_id.as_deref(), t.depth))
+        .collect();
+    assert_eq!(
+        shape,
+        vec![
+            ("1", None, 0),
+            ("1.1", Some("1"), 1),
+            ("1.1.1", Some("1.1"), 2),
+            ("1.1.2", Some("1.1"), 2),
+            ("1.2", Some("1"), 1),
+            ("1.2.1", Some("1.2"), 2),
+            ("1.2.2", Some("1.2"), 2),
+            ("2", None, 0),
+            ("2.1", Some("2"), 1),
+        ]
+    );
+}
+
+#[test]
+fn parse_checkbox_tasks_counts_only_leaf_tasks_by_default() {
+    let parsed = tasks::parse_tasks_tracking_file(NESTED_CHECKBOX_TASKS);
+    // Leaves: 1.1.1, 1.1.2, 1.2.1, 1.2.2, 2.1.
+    assert_eq!(parsed.progress.total, 5);
+    assert_eq!(parsed.progress.complete, 2);
+    assert_eq!(parsed.progress.in_progress, 1);
+    assert_eq!(parsed.progress.pending, 2);
+    assert_eq!(parsed.progress.remaining, 3);
+
+    // Counting every task derives the unchecked 1.1 as complete from its
+    // children; 1 and 2 still have open subtasks, so their own markers count.
+    let all = parsed.progress_with_policy(tasks::ProgressPolicy::AllTasks);
+    assert_eq!(all.total, 9);
+    assert_eq!(all.complete, 4);
+    assert_eq!(all.remaining, 5);
+}
+
+#[test]
+fn parse_checkbox_tasks_warns_when_parent_is_complete_with_open_subtasks() {
+    let parsed = tasks::parse_tasks_tracking_file(NESTED_CHECKBOX_TASKS);
+    assert_eq!(parsed.diagnostics.len(), 1, "{:?}", parsed.diagnostics);
+    let diagnostic = &parsed.diagnostics[0];
+    assert_eq!(diagnostic.level, tasks::DiagnosticLevel::Warning);
+    assert_eq!(
+        diagnostic.code,
+        Some(tasks::DIAG_PARENT_COMPLETE_WITH_OPEN_CHILDREN)
+    );
+    assert_eq!(diagnostic.task_id.as_deref(), Some("2"));
+    assert_eq!(diagnostic.line, Some(9));
+}
@@ -7,13 +7,14 @@
 //!
 //! The logger intentionally stores only coarse metadata:
 //! - a stable-but-anonymized `project_id` derived from a per-user salt
-//! - a `session_id` persisted under `.ito/session.json` when available
+//! - a `session_id` supplied by the caller (persisted by ito-core in
+//!   `.ito/session.json`), or a fresh one per process when unavailable
 
 #![warn(missing_docs)]
 
 use chrono::{SecondsFormat, Utc};
 use rand::RngCore;
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 use sha2::Digest;
 use std::fs::OpenOptions;
 use std::io::Write;
@@ -62,10 +63,13 @@ impl Logger {
     /// - telemetry is disabled (`ITO_DISABLE_LOGGING`)
     /// - the config directory is not available
     /// - the telemetry salt cannot be read/created
+    ///
+    /// `session_id` is normally the worktree's persisted session id; when it
+    /// is missing or unsafe a fresh id is generated for this process.
     pub fn new(
         config_dir: Option<PathBuf>,
         project_root: &Path,
-        ito_path: Option<&Path>,
+        session_id: Option<&str>,
         command_id: &str,
         ito_version: &str,
     ) -> Option<Self> {
@@ -78,7 +82,7 @@ impl Logger {
         let salt_path = config_dir.join(SALT_FILE_NAME);
         let salt = load_or_create_salt(&salt_path)?;
         let project_id = compute_project_id(&salt, project_root);
-        let session_id = resolve_session_id(ito_path);
+        let session_id = resolve_session_id(session_id);
         let file_path = log_file_path(&config_dir, &project_id, &session_id);
 
         if let Some(parent) = file_path.parent()
@@ -99,8 +103,8 @@ impl Logger {
 
     /// Session identifier for this execution.
     ///
-    /// When an `.ito/` directory exists, this is persisted in
-    /// `.ito/session.json` to allow grouping commands across runs.
+    /// When the caller supplied a persisted id, commands across runs share
+    /// it; otherwise it is unique to this process.
     pub fn session_id(&self) -> &str {
         &self.session_id
     }
@@ -181,50 +185,15 @@ impl Logger {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct SessionState {
-    session_id: String,
-    created_at: String,
-}
-
-fn resolve_session_id(ito_path: Option<&Path>) -> String {
-    let session_id = new_session_id();
-
-    let Some(ito_path) = ito_path else {
-        return session_id;
-    };
-    if !ito_path.is_dir() {
-        return session_id;
-    }
-
-    let path = ito_path.join("session.json");
-    if let Ok(contents) = std::fs::read_to_string(&path) {
-        match serde_json::from_str::<SessionState>(&contents) {
-            Ok(SessionState {
-                session_id,
-                created_at: _,
-            }) if is_safe_session_id(&session_id) => {
-                return session_id;
-            }
-            Ok(_) => {}
-            Err(e) => {
-                log::debug!("telemetry: failed to parse session.json: {e}");
-            }
+fn resolve_session_id(session_id: Option<&str>) -> String {
+    match session_id {
+        Some(id) if is_safe_session_id(id) => id.to_string(),
+        Some(id) => {
+            log::debug!("telemetry: ignoring unsafe session id {id:?}");
+            new_session_id()
         }
+        None => new_session_id(),
     }
-
-    let created_at = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
-    let state = SessionState {
-        session_id: session_id.clone(),
-        created_at,
-    };
-    if let Ok(contents) = serde_json::to_string(&state)
-        && let Err(e) = std::fs::write(&path, contents)
-    {
-        log::debug!("telemetry: failed to write session.json: {e}");
-    }
-
-    session_id
 }
 
 fn new_session_id() -> String {
@@ -344,11 +313,11 @@ impl InvalidCommandLogger {
     /// Create a logger for invalid commands.
     ///
     /// Returns `None` when telemetry is disabled or the config directory
-    /// is unavailable.
+    /// is unavailable. `session_id` behaves as in [`Logger::new`].
     pub fn new(
         config_dir: Option<PathBuf>,
         project_root: &Path,
-        ito_path: Option<&Path>,
+        session_id: Option<&str>,
         ito_version: &str,
     ) -> Option<Self> {
         if logging_disabled() {
@@ -359,7 +328,7 @@ impl InvalidCommandLogger {
         let salt_path = config_dir.join(SALT_FILE_NAME);
         let salt = load_or_create_salt(&salt_path)?;
         let project_id = compute_project_id(&salt, project_root);
-        let session_id = resolve_session_id(ito_path);
+        let session_id = resolve_session_id(session_id);
         let file_path = invalid_command_log_file_path(&config_dir, &project_id, &session_id);
 
         if let Some(parent) = file_path.parent()
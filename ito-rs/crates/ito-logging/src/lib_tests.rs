@@ -57,3 +57,12 @@ fn unsafe_session_ids_are_rejected() {
         "1739330000-550e8400e29b41d4a716446655440000"
     ));
 }
+
+#[test]
+fn supplied_session_id_is_used_when_safe() {
+    assert_eq!(resolve_session_id(Some("123-abc")), "123-abc");
+    let generated = resolve_session_id(Some("../escape"));
+    assert_ne!(generated, "../escape");
+    assert!(is_safe_session_id(&generated));
+    assert!(is_safe_session_id(&resolve_session_id(None)));
+}
@@ -8,6 +8,12 @@ if [ -z "${HOOK_PAYLOAD}" ]; then
   HOOK_PAYLOAD='{}'
 fi
 
+# Skill loads are only recorded for `ito stats skills`; recording never fails.
+if printf '%s' "${HOOK_PAYLOAD}" | grep -Eq '"tool_name"[[:space:]]*:[[:space:]]*"Skill"'; then
+  printf '%s' "${HOOK_PAYLOAD}" | ito util record-skill --harness claude --hook-payload >/dev/null 2>&1 || true
+  exit 0
+fi
+
 # Attempt audit validation — warn on failure but never block.
 # Blocking here creates a catch-22: the fix command (ito audit reconcile --fix)
 # also requires Bash, which this hook would block.
@@ -50,6 +50,9 @@ const MUTATING_TOOLS = new Set([
 
 const FILE_EDITING_TOOLS = new Set(['Edit', 'Write', 'MultiEdit', 'apply_patch']);
 
+// Tools that load a skill; each load is recorded for `ito stats skills`.
+const SKILL_TOOLS = new Set(['skill', 'Skill']);
+
 const RELEVANT_WORKTREE_GUARD_TOOLS = new Set([
   'Bash',
   'Edit',
@@ -84,6 +87,7 @@ export const ItoPlugin = async ({ client, directory }) => {
   const disableWorktreeDetection = process.env.ITO_OPENCODE_WORKTREE_DETECT_DISABLED === '1';
   const disableContext = process.env.ITO_OPENCODE_CONTEXT_DISABLED === '1';
   const disableCompactionContext = process.env.ITO_OPENCODE_COMPACTION_DISABLED === '1';
+  const disableSkillUsage = process.env.ITO_OPENCODE_SKILL_USAGE_DISABLED === '1';
 
   const toastTimeoutMsRaw = Number.parseInt(process.env.ITO_OPENCODE_TOAST_TIMEOUT_MS || '', 10);
   const toastTimeoutMs = Number.isFinite(toastTimeoutMsRaw) && toastTimeoutMsRaw > 0
@@ -289,6 +293,26 @@ export const ItoPlugin = async ({ client, directory }) => {
     }
   };
 
+  const recordSkillUsage = (input, output) => {
+    if (disableSkillUsage) {
+      return;
+    }
+    const args = output?.args || input?.args || {};
+    const skillId = typeof args.name === 'string' ? args.name : args.skill;
+    if (typeof skillId !== 'string' || !skillId.trim()) {
+      debug('skill:no_id');
+      return;
+    }
+
+    // Use cached context only; recording must stay cheap.
+    const recordArgs = ['util', 'record-skill', skillId.trim(), '--harness', 'opencode'];
+    if (lastContext?.target?.kind === 'change' && typeof lastContext.target.id === 'string') {
+      recordArgs.push('--change', lastContext.target.id);
+    }
+    debug('skill:record', skillId);
+    runIto(recordArgs);
+  };
+
   const maybeToastWorktree = async () => {
     if (disableWorktreeDetection) {
       debug('worktree:disabled');
@@ -615,6 +639,11 @@ Use OpenCode's native \`skill\` tool to load Ito workflows.
         pendingContinuationNotice = null;
       }
 
+      if (SKILL_TOOLS.has(toolName) || SKILL_TOOLS.has(input?.tool)) {
+        recordSkillUsage(input, output);
+        return;
+      }
+
       if (FILE_EDITING_TOOLS.has(toolName) || toolName === 'Bash') {
         maybeWarnForManagedFileWrites(toolName, input, output);
       }
@@ -0,0 +1,168 @@
+//! Where installers read template assets from.
+//!
+//! Assets normally come from the binary ([`AssetSource::Embedded`]). A pinned
+//! release bundle unpacked on disk ([`AssetSource::CachedBundle`]) takes
+//! precedence file by file; anything the bundle lacks (or cannot be read)
+//! falls back to the embedded copy.
+//!
+//! A bundle mirrors the `assets/` layout of this crate:
+//!
+//! ```text
+//! <root>/default/project/...
+//! <root>/skills/...
+//! <root>/adapters/...
+//! <root>/commands/...
+//! ```
+
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+use crate::{
+    EmbeddedFile, default_project_files, get_adapter_file, get_command_file, get_skill_file,
+    normalize_embedded_path,
+};
+
+const PROJECT_SUBDIR: &str = "default/project";
+const SKILLS_SUBDIR: &str = "skills";
+const ADAPTERS_SUBDIR: &str = "adapters";
+const COMMANDS_SUBDIR: &str = "commands";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Source of template assets for an install.
+pub enum AssetSource {
+    /// Assets embedded in this binary.
+    #[default]
+    Embedded,
+    /// A release bundle unpacked at `root`, backed by the embedded assets.
+    CachedBundle {
+        /// Release tag the bundle was published for (e.g. `v0.2.1`).
+        tag: String,
+        /// Directory the bundle was unpacked into.
+        root: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A template file resolved through an [`AssetSource`].
+pub struct AssetFile {
+    /// Path relative to the template root directory.
+    pub relative_path: String,
+    /// Raw file contents.
+    pub contents: Cow<'static, [u8]>,
+}
+
+impl From<EmbeddedFile> for AssetFile {
+    fn from(file: EmbeddedFile) -> Self {
+        Self {
+            relative_path: file.relative_path.to_string(),
+            contents: Cow::Borrowed(file.contents),
+        }
+    }
+}
+
+impl AssetSource {
+    /// Release tag of the bundle, or `None` for embedded assets.
+    pub fn pinned_tag(&self) -> Option<&str> {
+        match self {
+            Self::Embedded => None,
+            Self::CachedBundle { tag, .. } => Some(tag),
+        }
+    }
+
+    /// Default project template files.
+    ///
+    /// Bundle files replace embedded files with the same relative path; files
+    /// only present in the bundle are included too. Sorted by path.
+    pub fn default_project_files(&self) -> Vec<AssetFile> {
+        let mut files: Vec<AssetFile> = default_project_files()
+            .into_iter()
+            .map(AssetFile::from)
+            .collect();
+        if let Some(dir) = self.bundle_dir(PROJECT_SUBDIR) {
+            for bundled in bundle_files(&dir) {
+                match files
+                    .iter_mut()
+                    .find(|f| f.relative_path == bundled.relative_path)
+                {
+                    Some(existing) => *existing = bundled,
+                    None => files.push(bundled),
+                }
+            }
+        }
+        files.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+        files
+    }
+
+    /// Skill file at `path`, relative to the skills root.
+    pub fn skill_file(&self, path: &str) -> Option<Cow<'static, [u8]>> {
+        self.resolve(SKILLS_SUBDIR, path, get_skill_file)
+    }
+
+    /// Adapter file at `path`, relative to the adapters root.
+    pub fn adapter_file(&self, path: &str) -> Option<Cow<'static, [u8]>> {
+        self.resolve(ADAPTERS_SUBDIR, path, get_adapter_file)
+    }
+
+    /// Command file at `path`, relative to the commands root.
+    pub fn command_file(&self, path: &str) -> Option<Cow<'static, [u8]>> {
+        self.resolve(COMMANDS_SUBDIR, path, get_command_file)
+    }
+
+    fn resolve(
+        &self,
+        subdir: &str,
+        path: &str,
+        embedded: fn(&str) -> Option<&'static [u8]>,
+    ) -> Option<Cow<'static, [u8]>> {
+        if let Some(dir) = self.bundle_dir(subdir)
+            && let Some(rel) = normalize_embedded_path(path)
+            && let Ok(bytes) = std::fs::read(dir.join(rel))
+        {
+            return Some(Cow::Owned(bytes));
+        }
+        embedded(path).map(Cow::Borrowed)
+    }
+
+    fn bundle_dir(&self, subdir: &str) -> Option<PathBuf> {
+        match self {
+            Self::Embedded => None,
+            Self::CachedBundle { root, .. } => Some(root.join(subdir)),
+        }
+    }
+}
+
+fn bundle_files(dir: &Path) -> Vec<AssetFile> {
+    let mut out = Vec::new();
+    collect_bundle_files(dir, dir, &mut out);
+    out
+}
+
+fn collect_bundle_files(root: &Path, dir: &Path, out: &mut Vec<AssetFile>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_bundle_files(root, &path, out);
+            continue;
+        }
+        let Ok(rel) = path.strip_prefix(root) else {
+            continue;
+        };
+        let Some(rel) = rel.to_str() else {
+            continue;
+        };
+        let Ok(contents) = std::fs::read(&path) else {
+            continue;
+        };
+        out.push(AssetFile {
+            relative_path: rel.replace('\\', "/"),
+            contents: Cow::Owned(contents),
+        });
+    }
+}
+
+#[cfg(test)]
+#[path = "asset_source_tests.rs"]
+mod asset_source_tests;
@@ -0,0 +1,68 @@
+use super::*;
+
+fn bundle_with(files: &[(&str, &str)]) -> (tempfile::TempDir, AssetSource) {
+    let td = tempfile::tempdir().unwrap();
+    for (rel, contents) in files {
+        let path = td.path().join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+    let source = AssetSource::CachedBundle {
+        tag: "v0.2.1".to_string(),
+        root: td.path().to_path_buf(),
+    };
+    (td, source)
+}
+
+#[test]
+fn embedded_source_matches_embedded_accessors() {
+    let source = AssetSource::Embedded;
+    assert_eq!(source.pinned_tag(), None);
+    assert_eq!(
+        source.command_file("ito-apply.md").as_deref(),
+        get_command_file("ito-apply.md")
+    );
+    assert_eq!(
+        source.default_project_files().len(),
+        default_project_files().len()
+    );
+}
+
+#[test]
+fn bundle_files_win_over_embedded_files() {
+    let (_td, source) = bundle_with(&[
+        ("commands/ito-apply.md", "pinned apply"),
+        ("default/project/AGENTS.md", "pinned agents"),
+        ("default/project/EXTRA.md", "bundle only"),
+    ]);
+
+    assert_eq!(source.pinned_tag(), Some("v0.2.1"));
+    assert_eq!(
+        source.command_file("ito-apply.md").as_deref(),
+        Some(b"pinned apply".as_slice())
+    );
+    let project = source.default_project_files();
+    let agents = project
+        .iter()
+        .find(|f| f.relative_path == "AGENTS.md")
+        .unwrap();
+    assert_eq!(agents.contents.as_ref(), b"pinned agents");
+    assert!(project.iter().any(|f| f.relative_path == "EXTRA.md"));
+}
+
+#[test]
+fn missing_bundle_files_fall_back_to_embedded() {
+    let (_td, source) = bundle_with(&[("commands/ito-apply.md", "pinned apply")]);
+
+    assert_eq!(
+        source.skill_file("ito-apply/SKILL.md").as_deref(),
+        get_skill_file("ito-apply/SKILL.md")
+    );
+    assert_eq!(
+        source.adapter_file("claude/session-start.sh").as_deref(),
+        get_adapter_file("claude/session-start.sh")
+    );
+    let project = source.default_project_files();
+    assert!(project.iter().any(|f| f.relative_path == "CLAUDE.md"));
+    assert_eq!(source.command_file("../escape.md"), None);
+}
@@ -9,6 +9,9 @@ static INSTRUCTIONS_DIR: Dir<'static> = include_dir!("$CARGO_MANIFEST_DIR/assets
 /// Embedded template path for the reversible legacy-coordination migration prompt.
 pub const MIGRATE_TO_MAIN_TEMPLATE_PATH: &str = "agent/migrate-to-main.md.j2";
 
+/// Embedded template path for the `module.md` written by `ito create module`.
+pub const MODULE_SCAFFOLD_TEMPLATE_PATH: &str = "scaffold/module.md.j2";
+
 /// List all embedded instruction template paths.
 pub fn list_instruction_templates() -> Vec<&'static str> {
     let mut out = Vec::new();
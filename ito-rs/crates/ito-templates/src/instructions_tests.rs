@@ -1403,3 +1403,55 @@ fn finish_template_includes_archive_check_when_prompt_suppressed() {
     assert!(!out.contains("Experimental Coordination Compatibility"));
     assert!(!out.contains("ito sync"));
 }
+
+#[derive(Serialize)]
+struct ModuleScaffoldCtx<'a> {
+    title: &'a str,
+    name: &'a str,
+    date: &'a str,
+    purpose: Option<&'a str>,
+    scope: Vec<&'a str>,
+    depends_on: Vec<&'a str>,
+}
+
+#[test]
+fn module_scaffold_renders_placeholders_for_missing_sections() {
+    let out = render_instruction_template(
+        MODULE_SCAFFOLD_TEMPLATE_PATH,
+        &ModuleScaffoldCtx {
+            title: "Billing",
+            name: "billing",
+            date: "2026-01-02",
+            purpose: None,
+            scope: Vec::new(),
+            depends_on: Vec::new(),
+        },
+    )
+    .unwrap();
+
+    assert_eq!(
+        out,
+        "# Billing\n\n## Purpose\n<!-- Describe the purpose of the billing module (created 2026-01-02) -->\n\n## Scope\n<!-- List the scope of this module -->\n\n## Changes\n<!-- Changes will be listed here as they are created -->\n"
+    );
+}
+
+#[test]
+fn module_scaffold_renders_provided_sections() {
+    let out = render_instruction_template(
+        MODULE_SCAFFOLD_TEMPLATE_PATH,
+        &ModuleScaffoldCtx {
+            title: "Billing",
+            name: "billing",
+            date: "2026-01-02",
+            purpose: Some("Invoices and payment collection."),
+            scope: vec!["invoices", "payments"],
+            depends_on: vec!["001"],
+        },
+    )
+    .unwrap();
+
+    assert_eq!(
+        out,
+        "# Billing\n\n## Purpose\nInvoices and payment collection.\n\n## Scope\n- invoices\n- payments\n\n## Depends On\n- 001\n\n## Changes\n<!-- Changes will be listed here as they are created -->\n"
+    );
+}
@@ -15,6 +15,9 @@ use include_dir::{Dir, include_dir};
 /// Embedded agent definitions.
 pub mod agents;
 
+/// Embedded or pinned-bundle asset resolution.
+pub mod asset_source;
+
 /// Embedded instruction artifacts.
 pub mod instructions;
 
@@ -116,6 +119,7 @@ pub fn adapters_files() -> Vec<EmbeddedFile> {
 /// # Returns
 ///
 /// `Some(&[u8])` with the file contents if a file exists at `path`, `None` otherwise.
+/// The lookup is exact after path normalization (see [`normalize_embedded_path`]).
 ///
 /// # Examples
 ///
@@ -127,12 +131,21 @@ pub fn adapters_files() -> Vec<EmbeddedFile> {
 /// }
 /// ```
 pub fn get_skill_file(path: &str) -> Option<&'static [u8]> {
-    SKILLS_DIR.get_file(path).map(|f| f.contents())
+    get_embedded_file(&SKILLS_DIR, path)
+}
+
+/// Finds an embedded skill file, falling back to a case-insensitive match.
+///
+/// The returned [`EmbeddedFile::relative_path`] is the canonical embedded path,
+/// so callers can warn when it differs from the requested path.
+pub fn find_skill_file_ci(path: &str) -> Option<EmbeddedFile> {
+    find_embedded_file_ci(&SKILLS_DIR, path)
 }
 
 /// Retrieves an embedded adapter file by its relative path within the adapters assets.
 ///
 /// Returns `Some(&[u8])` with the file contents if the path exists, `None` otherwise.
+/// The lookup is exact after path normalization (see [`normalize_embedded_path`]).
 ///
 /// # Examples
 ///
@@ -142,7 +155,23 @@ pub fn get_skill_file(path: &str) -> Option<&'static [u8]> {
 /// assert!(!bytes.is_empty());
 /// ```
 pub fn get_adapter_file(path: &str) -> Option<&'static [u8]> {
-    ADAPTERS_DIR.get_file(path).map(|f| f.contents())
+    get_embedded_file(&ADAPTERS_DIR, path)
+}
+
+/// Finds an embedded adapter file, falling back to a case-insensitive match.
+///
+/// The returned [`EmbeddedFile::relative_path`] is the canonical embedded path,
+/// so callers can warn when it differs from the requested path.
+///
+/// # Examples
+///
+/// ```
+/// use ito_templates::find_adapter_file_ci;
+/// let file = find_adapter_file_ci("claude/Session-Start.sh").expect("adapter exists");
+/// assert_eq!(file.relative_path, "claude/session-start.sh");
+/// ```
+pub fn find_adapter_file_ci(path: &str) -> Option<EmbeddedFile> {
+    find_embedded_file_ci(&ADAPTERS_DIR, path)
 }
 
 /// Lists embedded shared command files.
@@ -198,6 +227,7 @@ pub fn get_preset_file(path: &str) -> Option<&'static [u8]> {
 /// # Returns
 ///
 /// `Some(&[u8])` with the file contents if a matching embedded schema exists, `None` otherwise.
+/// The lookup is exact after path normalization (see [`normalize_embedded_path`]).
 ///
 /// # Examples
 ///
@@ -207,7 +237,15 @@ pub fn get_preset_file(path: &str) -> Option<&'static [u8]> {
 /// assert!(!bytes.is_empty());
 /// ```
 pub fn get_schema_file(path: &str) -> Option<&'static [u8]> {
-    SCHEMAS_DIR.get_file(path).map(|f| f.contents())
+    get_embedded_file(&SCHEMAS_DIR, path)
+}
+
+/// Finds an embedded schema file, falling back to a case-insensitive match.
+///
+/// The returned [`EmbeddedFile::relative_path`] is the canonical embedded path,
+/// so callers can warn when it differs from the requested path.
+pub fn find_schema_file_ci(path: &str) -> Option<EmbeddedFile> {
+    find_embedded_file_ci(&SCHEMAS_DIR, path)
 }
 
 /// Fetches the contents of an embedded command file by its path relative to the commands asset root.
@@ -215,6 +253,7 @@ pub fn get_schema_file(path: &str) -> Option<&'static [u8]> {
 /// # Returns
 ///
 /// `Some(&[u8])` with the file contents if a file at `path` exists, `None` otherwise.
+/// The lookup is exact after path normalization (see [`normalize_embedded_path`]).
 ///
 /// # Examples
 ///
@@ -226,7 +265,74 @@ pub fn get_schema_file(path: &str) -> Option<&'static [u8]> {
 /// }
 /// ```
 pub fn get_command_file(path: &str) -> Option<&'static [u8]> {
-    COMMANDS_DIR.get_file(path).map(|f| f.contents())
+    get_embedded_file(&COMMANDS_DIR, path)
+}
+
+/// Finds an embedded command file, falling back to a case-insensitive match.
+///
+/// The returned [`EmbeddedFile::relative_path`] is the canonical embedded path,
+/// so callers can warn when it differs from the requested path.
+pub fn find_command_file_ci(path: &str) -> Option<EmbeddedFile> {
+    find_embedded_file_ci(&COMMANDS_DIR, path)
+}
+
+/// Normalize a lookup path for the embedded asset accessors.
+///
+/// Converts `\` separators to `/`, strips leading `./` (and any empty or `.`
+/// segments), and rejects absolute paths and `..` traversal by returning `None`.
+///
+/// # Examples
+///
+/// ```
+/// use ito_templates::normalize_embedded_path;
+/// assert_eq!(
+///     normalize_embedded_path(".\\claude\\session-start.sh").as_deref(),
+///     Some("claude/session-start.sh")
+/// );
+/// assert_eq!(normalize_embedded_path("../secrets.txt"), None);
+/// ```
+pub fn normalize_embedded_path(path: &str) -> Option<String> {
+    let path = path.replace('\\', "/");
+    if path.starts_with('/') {
+        return None;
+    }
+
+    let mut segments = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => continue,
+            ".." => return None,
+            other => segments.push(other),
+        }
+    }
+    if segments.is_empty() {
+        return None;
+    }
+    Some(segments.join("/"))
+}
+
+fn get_embedded_file(dir: &'static Dir<'static>, path: &str) -> Option<&'static [u8]> {
+    let path = normalize_embedded_path(path)?;
+    dir.get_file(&path).map(|f| f.contents())
+}
+
+fn find_embedded_file_ci(dir: &'static Dir<'static>, path: &str) -> Option<EmbeddedFile> {
+    let path = normalize_embedded_path(path)?;
+    if let Some(file) = dir.get_file(&path) {
+        return Some(EmbeddedFile {
+            relative_path: file.path().to_str().unwrap_or_default(),
+            contents: file.contents(),
+        });
+    }
+
+    // Pick the lexicographically first candidate so the result is stable if
+    // several embedded paths differ only by case.
+    let mut candidates = dir_files(dir)
+        .into_iter()
+        .filter(|file| file.relative_path.eq_ignore_ascii_case(&path))
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|file| file.relative_path);
+    candidates.into_iter().next()
 }
 
 fn dir_files(dir: &'static Dir<'static>) -> Vec<EmbeddedFile> {
@@ -791,3 +791,87 @@ fn every_shipped_markdown_has_managed_markers() {
         "markdown assets missing managed markers — start: {missing_start:?}, end: {missing_end:?}"
     );
 }
+
+#[test]
+fn normalize_embedded_path_cleans_separators_and_rejects_traversal() {
+    assert_eq!(
+        normalize_embedded_path("./claude/session-start.sh").as_deref(),
+        Some("claude/session-start.sh")
+    );
+    assert_eq!(
+        normalize_embedded_path("claude\\hooks\\.\\x.sh").as_deref(),
+        Some("claude/hooks/x.sh")
+    );
+    assert_eq!(normalize_embedded_path("claude/../secrets"), None);
+    assert_eq!(normalize_embedded_path("/etc/passwd"), None);
+    assert_eq!(normalize_embedded_path("./"), None);
+}
+
+#[test]
+fn get_skill_file_normalizes_paths_and_rejects_traversal() {
+    assert!(get_skill_file("./ito-loop/SKILL.md").is_some());
+    assert!(get_skill_file("ito-loop\\SKILL.md").is_some());
+    assert!(get_skill_file("ito-loop/../ito-loop/SKILL.md").is_none());
+    assert!(get_skill_file("ito-loop/skill.md").is_none());
+}
+
+#[test]
+fn find_skill_file_ci_returns_canonical_path() {
+    let file = find_skill_file_ci("./ITO-LOOP\\skill.md").expect("case-insensitive match");
+    assert_eq!(file.relative_path, "ito-loop/SKILL.md");
+    assert_eq!(Some(file.contents), get_skill_file("ito-loop/SKILL.md"));
+    assert!(find_skill_file_ci("../ito-loop/SKILL.md").is_none());
+}
+
+#[test]
+fn get_adapter_file_normalizes_paths_and_rejects_traversal() {
+    assert!(get_adapter_file("./claude/session-start.sh").is_some());
+    assert!(get_adapter_file("claude\\session-start.sh").is_some());
+    assert!(get_adapter_file("claude/../claude/session-start.sh").is_none());
+    assert!(get_adapter_file("claude/Session-Start.sh").is_none());
+}
+
+#[test]
+fn find_adapter_file_ci_returns_canonical_path() {
+    let file = find_adapter_file_ci("claude/Session-Start.sh").expect("case-insensitive match");
+    assert_eq!(file.relative_path, "claude/session-start.sh");
+    assert_eq!(
+        Some(file.contents),
+        get_adapter_file("claude/session-start.sh")
+    );
+
+    let exact = find_adapter_file_ci("claude/session-start.sh").expect("exact match");
+    assert_eq!(exact.relative_path, "claude/session-start.sh");
+    assert!(find_adapter_file_ci("..\\claude/session-start.sh").is_none());
+}
+
+#[test]
+fn get_command_file_normalizes_paths_and_rejects_traversal() {
+    assert!(get_command_file("./ito-apply.md").is_some());
+    assert!(get_command_file(".\\ito-apply.md").is_some());
+    assert!(get_command_file("../commands/ito-apply.md").is_none());
+    assert!(get_command_file("ITO-APPLY.md").is_none());
+}
+
+#[test]
+fn find_command_file_ci_returns_canonical_path() {
+    let file = find_command_file_ci("ITO-APPLY.MD").expect("case-insensitive match");
+    assert_eq!(file.relative_path, "ito-apply.md");
+    assert!(find_command_file_ci("../ito-apply.md").is_none());
+    assert!(find_command_file_ci("missing.md").is_none());
+}
+
+#[test]
+fn get_schema_file_normalizes_paths_and_rejects_traversal() {
+    assert!(get_schema_file("./spec-driven/schema.yaml").is_some());
+    assert!(get_schema_file("spec-driven\\schema.yaml").is_some());
+    assert!(get_schema_file("spec-driven/../spec-driven/schema.yaml").is_none());
+    assert!(get_schema_file("Spec-Driven/schema.yaml").is_none());
+}
+
+#[test]
+fn find_schema_file_ci_returns_canonical_path() {
+    let file = find_schema_file_ci("Spec-Driven/Schema.YAML").expect("case-insensitive match");
+    assert_eq!(file.relative_path, "spec-driven/schema.yaml");
+    assert!(find_schema_file_ci("spec-driven/../schema.yaml").is_none());
+}
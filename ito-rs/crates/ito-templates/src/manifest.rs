@@ -1,5 +1,6 @@
 //! Expected Ito-managed file manifest generation.
 
+use crate::project_templates::project_template_target;
 use crate::{commands_files, default_project_files, skills_files};
 
 /// Tool-specific harness surface.
@@ -49,8 +50,9 @@ pub fn generate_manifest(tools: &[HarnessTool]) -> Vec<ManifestEntry> {
     let mut entries = Vec::new();
 
     for file in default_project_files() {
+        let (target, _) = project_template_target(file.relative_path);
         entries.push(ManifestEntry {
-            relative_path: file.relative_path.to_string(),
+            relative_path: target.to_string(),
             source_path: file.relative_path.to_string(),
             source: ManifestSource::Project,
             harness: None,
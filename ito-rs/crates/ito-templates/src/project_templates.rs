@@ -1,8 +1,13 @@
 //! Jinja2 rendering for project templates (AGENTS.md, skills).
 //!
 //! Project templates may contain `minijinja` syntax (`{% ... %}` / `{{ ... }}`)
-//! that gets rendered with a [`WorktreeTemplateContext`](crate::project_templates::WorktreeTemplateContext) before being written
+//! that gets rendered with a [`ProjectTemplateContext`](crate::project_templates::ProjectTemplateContext) before being written
 //! to disk. Templates without Jinja2 syntax are returned unchanged.
+//!
+//! Only `AGENTS.md` and embedded project files ending in `.j2` are rendered;
+//! see [`project_template_target`](crate::project_templates::project_template_target).
+//! Other files (e.g. commands) may contain literal `{{` placeholders and are
+//! installed verbatim.
 
 use serde::Serialize;
 
@@ -64,7 +69,80 @@ impl Default for WorktreeTemplateContext {
     }
 }
 
-/// Render a project template with the given worktree context.
+/// Context for rendering project templates.
+///
+/// The worktree fields are flattened to the top level, so templates keep
+/// using `{{ strategy }}` or `{{ default_branch }}` alongside the project
+/// variables below.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectTemplateContext {
+    /// Resolved worktree configuration, including the default branch.
+    #[serde(flatten)]
+    pub worktree: WorktreeTemplateContext,
+    /// Repository name, from the `origin` remote or the project directory.
+    /// Empty string when not resolved.
+    pub repo_name: String,
+    /// Selected tool ids (e.g. `"claude"`, `"opencode"`).
+    pub tools: Vec<String>,
+    /// Ito directory name (e.g. `".ito"`).
+    pub ito_dir: String,
+    /// Release tag of the installing CLI (e.g. `"v0.1.0"`). Empty string when
+    /// not resolved.
+    pub release_tag: String,
+}
+
+impl From<WorktreeTemplateContext> for ProjectTemplateContext {
+    /// Wrap a worktree context with empty project variables and `.ito` as the
+    /// Ito directory.
+    fn from(worktree: WorktreeTemplateContext) -> Self {
+        Self {
+            worktree,
+            repo_name: String::new(),
+            tools: Vec::new(),
+            ito_dir: ".ito".to_string(),
+            release_tag: String::new(),
+        }
+    }
+}
+
+impl Default for ProjectTemplateContext {
+    fn default() -> Self {
+        Self::from(WorktreeTemplateContext::default())
+    }
+}
+
+/// Suffix that opts an embedded project file into Jinja2 rendering.
+pub const JINJA_TEMPLATE_SUFFIX: &str = ".j2";
+
+/// Resolve where an embedded project file installs and whether it is rendered.
+///
+/// `AGENTS.md` is always rendered. Any other file is rendered only when its
+/// embedded path ends in [`JINJA_TEMPLATE_SUFFIX`], which is stripped from the
+/// destination. Everything else is installed verbatim.
+///
+/// # Examples
+///
+/// ```
+/// use ito_templates::project_templates::project_template_target;
+/// assert_eq!(project_template_target("AGENTS.md"), ("AGENTS.md", true));
+/// assert_eq!(project_template_target(".ito/guide.md.j2"), (".ito/guide.md", true));
+/// assert_eq!(project_template_target(".ito/commands/x.md"), (".ito/commands/x.md", false));
+/// ```
+pub fn project_template_target(rel: &str) -> (&str, bool) {
+    if rel == "AGENTS.md" {
+        return (rel, true);
+    }
+    match rel.strip_suffix(JINJA_TEMPLATE_SUFFIX) {
+        Some(target) => (target, true),
+        None => (rel, false),
+    }
+}
+
+/// Render a project template with the given context.
+///
+/// `ctx` is normally a [`ProjectTemplateContext`]; a bare
+/// [`WorktreeTemplateContext`] works for templates that only use worktree
+/// variables.
 ///
 /// If the template bytes are not valid UTF-8 or do not contain Jinja2 syntax
 /// (`{%` or `{{`), the bytes are returned unchanged. Otherwise the template is
@@ -74,9 +152,9 @@ impl Default for WorktreeTemplateContext {
 ///
 /// Returns a `minijinja::Error` if the template contains Jinja2 syntax but
 /// fails to render (e.g., undefined variable in strict mode).
-pub fn render_project_template(
+pub fn render_project_template<T: Serialize>(
     template_bytes: &[u8],
-    ctx: &WorktreeTemplateContext,
+    ctx: &T,
 ) -> Result<Vec<u8>, minijinja::Error> {
     let Ok(text) = std::str::from_utf8(template_bytes) else {
         return Ok(template_bytes.to_vec());
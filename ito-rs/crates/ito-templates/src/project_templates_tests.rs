@@ -215,3 +215,78 @@ fn render_agents_md_with_worktrees_disabled() {
     assert!(text.contains("Worktrees are not configured for this project."));
     assert!(text.contains("Do NOT create git worktrees by default."));
 }
+
+fn project_ctx() -> ProjectTemplateContext {
+    ProjectTemplateContext {
+        worktree: WorktreeTemplateContext {
+            default_branch: "trunk".to_string(),
+            ..WorktreeTemplateContext::default()
+        },
+        repo_name: "widgets".to_string(),
+        tools: vec!["claude".to_string(), "opencode".to_string()],
+        ito_dir: ".ito".to_string(),
+        release_tag: "v1.2.3".to_string(),
+    }
+}
+
+#[test]
+fn project_context_exposes_project_and_flattened_worktree_variables() {
+    let template = b"{{ repo_name }} on {{ default_branch }} via {{ tools | join(\", \") }} in {{ ito_dir }} ({{ release_tag }}, enabled={{ enabled }})";
+    let rendered = render_project_template(template, &project_ctx()).unwrap();
+    assert_eq!(
+        String::from_utf8(rendered).unwrap(),
+        "widgets on trunk via claude, opencode in .ito (v1.2.3, enabled=false)"
+    );
+}
+
+#[test]
+fn render_agents_md_with_project_context() {
+    let agents_md = crate::default_project_files()
+        .into_iter()
+        .find(|f| f.relative_path == "AGENTS.md")
+        .expect("AGENTS.md should exist in project templates");
+
+    let mut ctx = project_ctx();
+    ctx.worktree.enabled = true;
+    ctx.worktree.strategy = "checkout_subdir".to_string();
+    let rendered = render_project_template(agents_md.contents, &ctx).unwrap();
+    let text = String::from_utf8(rendered).unwrap();
+
+    assert!(!text.contains("{%"));
+    assert!(text.contains("**Default branch:** `trunk`"));
+}
+
+#[test]
+fn only_agents_md_and_j2_files_opt_into_rendering() {
+    assert_eq!(project_template_target("AGENTS.md"), ("AGENTS.md", true));
+    assert_eq!(
+        project_template_target(".ito/guide.md.j2"),
+        (".ito/guide.md", true)
+    );
+    assert_eq!(
+        project_template_target(".ito/commands/research-stack.md"),
+        (".ito/commands/research-stack.md", false)
+    );
+    assert_eq!(
+        project_template_target(".ito/AGENTS.md"),
+        (".ito/AGENTS.md", false)
+    );
+}
+
+#[test]
+fn j2_project_template_renders_while_literal_braces_stay_verbatim_elsewhere() {
+    let ctx = project_ctx();
+    let (target, render) = project_template_target(".ito/repo.md.j2");
+    assert_eq!(target, ".ito/repo.md");
+    assert!(render);
+    let rendered = render_project_template(b"# {{ repo_name }}\n", &ctx).unwrap();
+    assert_eq!(rendered, b"# widgets\n");
+
+    // Generated manifests list the installed name, not the `.j2` source.
+    let manifest = crate::manifest::generate_manifest(&[]);
+    assert!(
+        manifest
+            .iter()
+            .all(|entry| !entry.relative_path.ends_with(JINJA_TEMPLATE_SUFFIX))
+    );
+}
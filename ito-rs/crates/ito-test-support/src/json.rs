@@ -0,0 +1,101 @@
+//! Assertions for the CLI's stable `--json` output.
+
+use serde::de::{self, Deserializer, MapAccess, SeqAccess, Visitor};
+use std::fmt;
+
+/// Assert that `json` is pretty-printed with every object's keys sorted and
+/// round-trips through [`serde_json::Value`] unchanged; returns the parsed value.
+///
+/// Key order is checked on the raw text, so the assertion holds regardless of
+/// whether `serde_json` preserves insertion order.
+///
+/// # Panics
+///
+/// Panics when `json` is not valid JSON, an object's keys are out of order or
+/// duplicated, or re-rendering the parsed value differs from the input.
+pub fn assert_json_round_trips_sorted(json: &str) -> serde_json::Value {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    if let Err(e) = (&mut deserializer).deserialize_any(SortedKeys) {
+        panic!("JSON keys are not sorted: {e}\n{json}");
+    }
+
+    let value: serde_json::Value = match serde_json::from_str(json) {
+        Ok(value) => value,
+        Err(e) => panic!("invalid JSON: {e}\n{json}"),
+    };
+    let rendered = serde_json::to_string_pretty(&value).expect("render JSON value");
+    assert_eq!(
+        rendered,
+        json.trim_end(),
+        "JSON does not round-trip unchanged"
+    );
+    value
+}
+
+/// Walks a JSON document, failing on the first object with unsorted keys.
+struct SortedKeys;
+
+impl<'de> de::DeserializeSeed<'de> for SortedKeys {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for SortedKeys {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("any JSON value")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let mut previous: Option<String> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if let Some(previous) = &previous
+                && previous.as_str() >= key.as_str()
+            {
+                return Err(de::Error::custom(format!(
+                    "key `{key}` follows `{previous}`"
+                )));
+            }
+            map.next_value_seed(SortedKeys)?;
+            previous = Some(key);
+        }
+        Ok(())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while seq.next_element_seed(SortedKeys)?.is_some() {}
+        Ok(())
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E>(self) -> Result<(), E> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "json_tests.rs"]
+mod json_tests;
@@ -0,0 +1,28 @@
+use super::*;
+
+#[test]
+fn accepts_sorted_pretty_json() {
+    let json = "{\n  \"a\": [\n    {\n      \"x\": 1,\n      \"y\": null\n    }\n  ],\n  \"b\": \"two\"\n}\n";
+    let value = assert_json_round_trips_sorted(json);
+    assert_eq!(value["b"], "two");
+}
+
+#[test]
+#[should_panic(expected = "key `a` follows `b`")]
+fn rejects_unsorted_top_level_keys() {
+    assert_json_round_trips_sorted("{\n  \"b\": 1,\n  \"a\": 2\n}");
+}
+
+#[test]
+#[should_panic(expected = "key `id` follows `name`")]
+fn rejects_unsorted_keys_nested_in_arrays() {
+    assert_json_round_trips_sorted(
+        "{\n  \"items\": [\n    {\n      \"name\": \"x\",\n      \"id\": \"1\"\n    }\n  ]\n}",
+    );
+}
+
+#[test]
+#[should_panic(expected = "does not round-trip")]
+fn rejects_compact_json() {
+    assert_json_round_trips_sorted("{\"a\":1}");
+}
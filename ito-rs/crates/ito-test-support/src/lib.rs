@@ -9,6 +9,11 @@ use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Output};
 
+pub use json::assert_json_round_trips_sorted;
+
+/// Assertions for stable `--json` output.
+pub mod json;
+
 /// In-memory mock implementations of domain repository traits for unit testing.
 pub mod mock_repos;
 
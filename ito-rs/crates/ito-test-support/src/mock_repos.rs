@@ -86,6 +86,7 @@ impl MockChangeRepository {
             has_specs: !change.specs.is_empty(),
             has_tasks: progress.total > 0,
             orchestrate: change.orchestrate.clone(),
+            metadata: change.metadata.clone(),
         };
         self.summaries.push(summary);
         self.changes.insert(change.id.clone(), change);
@@ -108,11 +109,26 @@ impl ChangeRepository for MockChangeRepository {
         if !options.lifecycle.includes_active() {
             return ChangeTargetResolution::NotFound;
         }
-        let matches: Vec<&String> = self.changes.keys().filter(|k| k.contains(input)).collect();
+        let matches: Vec<&String> = self
+            .changes
+            .keys()
+            .filter(|k| {
+                if options.exact {
+                    k.as_str() == input
+                } else {
+                    k.contains(input)
+                }
+            })
+            .collect();
         match matches.len() {
             0 => ChangeTargetResolution::NotFound,
             1 => ChangeTargetResolution::Unique(matches[0].clone()),
-            _ => ChangeTargetResolution::Ambiguous(matches.into_iter().cloned().collect()),
+            _ => ChangeTargetResolution::Ambiguous(
+                matches
+                    .into_iter()
+                    .map(|id| self.describe_target(id, options.lifecycle))
+                    .collect(),
+            ),
         }
     }
 
@@ -312,6 +328,7 @@ pub fn make_change(id: &str) -> Change {
         specs: Vec::new(),
         tasks: TasksParseResult::empty(),
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
         last_modified: Utc::now(),
     }
 }
@@ -359,6 +376,7 @@ pub fn make_change_summary(id: &str) -> ChangeSummary {
         has_specs: false,
         has_tasks: false,
         orchestrate: ito_domain::changes::ChangeOrchestrateMetadata::default(),
+        metadata: ito_domain::changes::ChangeMetadata::default(),
     }
 }
 
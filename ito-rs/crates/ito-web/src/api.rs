@@ -8,6 +8,9 @@ use axum::{
     response::Json,
     routing::get,
 };
+use ito_config::ConfigContext;
+use ito_core::path_annotations::{self, PathAnnotation};
+use ito_core::templates::{self, InstructionsResponse, NextArtifact, TemplatesError};
 use serde::{Deserialize, Serialize};
 use std::path::{Component, Path as StdPath, PathBuf};
 use std::sync::Arc;
@@ -32,6 +35,17 @@ pub struct Entry {
     path: String,
     is_dir: bool,
     size: Option<u64>,
+    /// What the entry is within the Ito directory, when requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    annotation: Option<PathAnnotation>,
+}
+
+/// Directory listing query parameters.
+#[derive(Debug, Default, Deserialize)]
+pub struct ListQuery {
+    /// Annotate entries under the Ito directory (changes, specs, modules).
+    #[serde(default)]
+    annotate: bool,
 }
 
 /// Directory listing response.
@@ -57,6 +71,7 @@ pub struct SaveRequest {
 
 /// Create the API router.
 pub fn router(root: PathBuf) -> Router {
+    let health = crate::health::router(root.clone());
     let state = Arc::new(AppState { root });
 
     Router::new()
@@ -66,7 +81,10 @@ pub fn router(root: PathBuf) -> Router {
         .route("/templates/list", get(list_templates))
         .route("/templates/source", get(get_template_source))
         .route("/templates/render", axum::routing::post(render_template))
+        .route("/instructions/{change}/{artifact}", get(get_instructions))
+        .route("/changes/{change}/next", get(get_next_artifact))
         .with_state(state)
+        .merge(health)
         // Avoid parsing arbitrarily large JSON bodies.
         .layer(DefaultBodyLimit::max(MAX_REQUEST_BODY_BYTES))
 }
@@ -175,20 +193,137 @@ async fn render_template(
     }))
 }
 
+#[derive(Debug, Deserialize)]
+pub struct InstructionsQuery {
+    schema: Option<String>,
+    #[serde(default)]
+    rendered: bool,
+}
+
+async fn get_instructions(
+    State(state): State<Arc<AppState>>,
+    Path((change, artifact)): Path<(String, String)>,
+    Query(q): Query<InstructionsQuery>,
+) -> Result<Json<InstructionsResponse>, (StatusCode, String)> {
+    let root = state.root.clone();
+    let response = tokio::task::spawn_blocking(move || {
+        let ctx = ConfigContext {
+            project_dir: Some(root.clone()),
+            ..ConfigContext::from_process_env()
+        };
+        let ito_path = ito_config::ito_dir::get_ito_path(&root, &ctx);
+        let resolve = if q.rendered {
+            templates::resolve_rendered_instructions
+        } else {
+            templates::resolve_instructions
+        };
+        resolve(&ito_path, &change, q.schema.as_deref(), &artifact, &ctx)
+    })
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .map_err(templates_error_response)?;
+
+    Ok(Json(response))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NextArtifactQuery {
+    schema: Option<String>,
+}
+
+/// The next ready artifact for a change, or why none is ready.
+async fn get_next_artifact(
+    State(state): State<Arc<AppState>>,
+    Path(change): Path<String>,
+    Query(q): Query<NextArtifactQuery>,
+) -> Result<Json<NextArtifact>, (StatusCode, String)> {
+    let root = state.root.clone();
+    let response = tokio::task::spawn_blocking(move || {
+        let ctx = ConfigContext {
+            project_dir: Some(root.clone()),
+            ..ConfigContext::from_process_env()
+        };
+        let ito_path = ito_config::ito_dir::get_ito_path(&root, &ctx);
+        templates::next_artifact(&ito_path, &change, q.schema.as_deref(), &ctx)
+    })
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .map_err(templates_error_response)?;
+
+    Ok(Json(response))
+}
+
+fn templates_error_response(e: TemplatesError) -> (StatusCode, String) {
+    let status = match e {
+        TemplatesError::InvalidChangeName => StatusCode::BAD_REQUEST,
+        TemplatesError::ChangeNotFound(_)
+        | TemplatesError::SchemaNotFound(_)
+        | TemplatesError::ArtifactNotFound(_) => StatusCode::NOT_FOUND,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    (status, e.to_string())
+}
+
 /// List root directory.
 async fn list_root(
     State(state): State<Arc<AppState>>,
+    Query(q): Query<ListQuery>,
 ) -> Result<Json<ListResponse>, (StatusCode, String)> {
-    list_directory(&state.root, "").await
+    let mut listing = list_directory(&state.root, "").await?;
+    if q.annotate {
+        annotate_listing(&state.root, &state.root, &mut listing).await?;
+    }
+    Ok(listing)
 }
 
 /// List a directory.
 async fn list_dir(
     State(state): State<Arc<AppState>>,
     Path(path): Path<String>,
+    Query(q): Query<ListQuery>,
 ) -> Result<Json<ListResponse>, (StatusCode, String)> {
     let full_path = safe_path(&state.root, &path)?;
-    list_directory(&full_path, &path).await
+    let mut listing = list_directory(&full_path, &path).await?;
+    if q.annotate {
+        annotate_listing(&state.root, &full_path, &mut listing).await?;
+    }
+    Ok(listing)
+}
+
+/// Attach Ito annotations to the entries of `dir`.
+///
+/// Directories outside the project's Ito directory are left untouched.
+async fn annotate_listing(
+    root: &StdPath,
+    dir: &StdPath,
+    listing: &mut ListResponse,
+) -> Result<(), (StatusCode, String)> {
+    let root = root.to_path_buf();
+    let dir = dir.to_path_buf();
+    let names: Vec<String> = listing.entries.iter().map(|e| e.name.clone()).collect();
+    let mut annotations = tokio::task::spawn_blocking(move || {
+        let ctx = ConfigContext {
+            project_dir: Some(root.clone()),
+            ..ConfigContext::from_process_env()
+        };
+        let Ok(ito_path) = ito_config::ito_dir::get_ito_path(&root, &ctx).canonicalize() else {
+            return Default::default();
+        };
+        let Ok(dir) = dir.canonicalize() else {
+            return Default::default();
+        };
+        if !dir.starts_with(&ito_path) {
+            return Default::default();
+        }
+        path_annotations::annotate_dir_entries(&ito_path, &dir, &names)
+    })
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    for entry in &mut listing.entries {
+        entry.annotation = annotations.remove(&entry.name);
+    }
+    Ok(())
 }
 
 async fn list_directory(
@@ -242,6 +377,7 @@ async fn list_directory(
             path: entry_path,
             is_dir,
             size,
+            annotation: None,
         });
     }
 
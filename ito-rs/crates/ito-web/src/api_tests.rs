@@ -155,3 +155,180 @@ async fn template_routes_list_validate_and_render_embedded_templates() {
             .contains("# Ito Backend Configuration Guide")
     );
 }
+
+#[tokio::test]
+async fn instructions_route_renders_change_context_on_request() {
+    let project = tempfile::tempdir().expect("project root");
+    let schema_dir = project.path().join(".ito/templates/schemas/demo");
+    std::fs::create_dir_all(schema_dir.join("templates")).expect("schema templates directory");
+    std::fs::write(
+        schema_dir.join("schema.yaml"),
+        "name: demo\nversion: 1\nartifacts:\n  - id: proposal\n    generates: proposal.md\n    template: proposal.md\n    requires: []\n",
+    )
+    .expect("schema file");
+    std::fs::write(
+        schema_dir.join("templates/proposal.md"),
+        "# Proposal for {{ change_name }}\n",
+    )
+    .expect("template file");
+    let change_dir = project.path().join(".ito/changes/001-01_demo");
+    std::fs::create_dir_all(&change_dir).expect("change directory");
+    std::fs::write(change_dir.join(".ito.yaml"), "schema: demo\n").expect("change metadata");
+    let app = router(project.path().to_path_buf());
+
+    let (raw_status, raw_body) = send(&app, get("/instructions/001-01_demo/proposal")).await;
+    assert_eq!(raw_status, StatusCode::OK);
+    let raw_body: Value = serde_json::from_str(&raw_body).expect("instructions JSON");
+    assert_eq!(raw_body["template"], "# Proposal for {{ change_name }}\n");
+
+    let (status, body) = send(
+        &app,
+        get("/instructions/001-01_demo/proposal?rendered=true"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let body: Value = serde_json::from_str(&body).expect("instructions JSON");
+    assert_eq!(body["template"], "# Proposal for 001-01_demo\n");
+    assert!(body.get("renderWarning").is_none());
+
+    let (missing_status, _) = send(&app, get("/instructions/001-02_missing/proposal")).await;
+    assert_eq!(missing_status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn next_route_reports_ready_artifact_then_completion() {
+    let project = tempfile::tempdir().expect("project root");
+    let schema_dir = project.path().join(".ito/templates/schemas/demo");
+    std::fs::create_dir_all(schema_dir.join("templates")).expect("schema templates directory");
+    std::fs::write(
+        schema_dir.join("schema.yaml"),
+        "name: demo\nversion: 1\nartifacts:\n  - id: proposal\n    generates: proposal.md\n    template: proposal.md\n    requires: []\n",
+    )
+    .expect("schema file");
+    std::fs::write(schema_dir.join("templates/proposal.md"), "# Proposal\n")
+        .expect("template file");
+    let change_dir = project.path().join(".ito/changes/001-01_demo");
+    std::fs::create_dir_all(&change_dir).expect("change directory");
+    std::fs::write(change_dir.join(".ito.yaml"), "schema: demo\n").expect("change metadata");
+    let app = router(project.path().to_path_buf());
+
+    let (status, body) = send(&app, get("/changes/001-01_demo/next")).await;
+    assert_eq!(status, StatusCode::OK);
+    let body: Value = serde_json::from_str(&body).expect("next artifact JSON");
+    assert_eq!(body["state"], "ready");
+    assert_eq!(body["artifactId"], "proposal");
+    assert_eq!(body["instructions"]["template"], "# Proposal\n");
+
+    std::fs::write(change_dir.join("proposal.md"), "## Why\n").expect("proposal");
+    let (status, body) = send(&app, get("/changes/001-01_demo/next")).await;
+    assert_eq!(status, StatusCode::OK);
+    let body: Value = serde_json::from_str(&body).expect("next artifact JSON");
+    assert_eq!(body["state"], "complete");
+    assert!(body.get("instructions").is_none());
+
+    let (missing_status, _) = send(&app, get("/changes/001-02_missing/next")).await;
+    assert_eq!(missing_status, StatusCode::NOT_FOUND);
+}
+
+fn ito_fixture() -> tempfile::TempDir {
+    let project = tempfile::tempdir().expect("project root");
+    let ito = project.path().join(".ito");
+    let change = ito.join("changes/001-01_alpha");
+    std::fs::create_dir_all(change.join("specs/alpha")).expect("change directory");
+    std::fs::write(change.join("proposal.md"), "## Why\nfixture\n").expect("proposal");
+    std::fs::write(change.join("tasks.md"), "- [x] one\n- [ ] two\n").expect("tasks");
+    std::fs::write(
+        change.join("specs/alpha/spec.md"),
+        "## ADDED Requirements\n\n### Requirement: A\nText.\n",
+    )
+    .expect("delta spec");
+    let broken = ito.join("changes/001-02_broken");
+    std::fs::create_dir_all(&broken).expect("broken change directory");
+    std::fs::write(broken.join("proposal.md"), b"\xff\xfe\x00").expect("invalid proposal");
+    std::fs::create_dir_all(ito.join("specs/auth")).expect("spec directory");
+    std::fs::write(
+        ito.join("specs/auth/spec.md"),
+        "## Purpose\nAuth.\n\n## Requirements\n\n### Requirement: Login\nText.\n",
+    )
+    .expect("spec");
+    std::fs::create_dir_all(ito.join("modules/001_core")).expect("module directory");
+    std::fs::write(ito.join("modules/001_core/module.md"), "# Core\n").expect("module");
+    std::fs::create_dir_all(project.path().join("docs/changes/001-01_alpha"))
+        .expect("lookalike directory");
+    project
+}
+
+fn entry<'a>(body: &'a Value, name: &str) -> &'a Value {
+    body["entries"]
+        .as_array()
+        .expect("entries array")
+        .iter()
+        .find(|entry| entry["name"] == name)
+        .expect("listed entry")
+}
+
+#[tokio::test]
+async fn annotated_listing_describes_changes_specs_and_modules() {
+    let project = ito_fixture();
+    let app = router(project.path().to_path_buf());
+
+    let (status, body) = send(&app, get("/list/.ito/changes?annotate=true")).await;
+    assert_eq!(status, StatusCode::OK);
+    let body: Value = serde_json::from_str(&body).expect("listing JSON");
+    let alpha = &entry(&body, "001-01_alpha")["annotation"];
+    assert_eq!(alpha["kind"], "change");
+    assert_eq!(alpha["work_status"], "ready");
+    assert_eq!(alpha["schema"], "spec-driven");
+    assert_eq!(alpha["task_progress"]["total"], 2);
+    assert_eq!(alpha["task_progress"]["complete"], 1);
+
+    let (_, specs) = send(&app, get("/list/.ito/specs?annotate=true")).await;
+    let specs: Value = serde_json::from_str(&specs).expect("listing JSON");
+    let auth = &entry(&specs, "auth")["annotation"];
+    assert_eq!(auth["kind"], "spec");
+    assert_eq!(auth["requirement_count"], 1);
+
+    let (_, modules) = send(&app, get("/list/.ito/modules?annotate=true")).await;
+    let modules: Value = serde_json::from_str(&modules).expect("listing JSON");
+    let core = &entry(&modules, "001_core")["annotation"];
+    assert_eq!(core["kind"], "module");
+    assert_eq!(core["change_count"], 2);
+
+    let (_, plain) = send(&app, get("/list/.ito/changes")).await;
+    let plain: Value = serde_json::from_str(&plain).expect("listing JSON");
+    assert!(entry(&plain, "001-01_alpha").get("annotation").is_none());
+}
+
+#[tokio::test]
+async fn annotated_listing_tolerates_malformed_changes() {
+    let project = ito_fixture();
+
+    let (status, body) = send(
+        &router(project.path().to_path_buf()),
+        get("/list/.ito/changes?annotate=true"),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    let body: Value = serde_json::from_str(&body).expect("listing JSON");
+    let broken = &entry(&body, "001-02_broken")["annotation"];
+    assert_eq!(broken["kind"], "change");
+    assert!(broken["error"].as_str().is_some_and(|e| !e.is_empty()));
+    assert!(broken.get("work_status").is_none());
+    assert_eq!(entry(&body, "001-01_alpha")["annotation"]["kind"], "change");
+}
+
+#[tokio::test]
+async fn annotated_listing_ignores_paths_outside_the_ito_dir() {
+    let project = ito_fixture();
+
+    let (status, body) = send(
+        &router(project.path().to_path_buf()),
+        get("/list/docs/changes?annotate=true"),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    let body: Value = serde_json::from_str(&body).expect("listing JSON");
+    assert!(entry(&body, "001-01_alpha").get("annotation").is_none());
+}
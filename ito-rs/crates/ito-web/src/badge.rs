@@ -0,0 +1,283 @@
+//! Embeddable SVG status badges.
+//!
+//! `GET /badge/change/{id}.svg` shows a change's task progress colored by its
+//! work status; `GET /badge/project.svg` counts ready, in-progress, and
+//! complete changes. Badges are public (like the config schema) so they can be
+//! embedded in PR descriptions and dashboards, and they are self-contained SVG
+//! with no external fonts or images.
+
+use axum::{
+    Router,
+    extract::{Path, Query, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use ito_config::ConfigContext;
+use ito_core::change_repository::FsChangeRepository;
+use ito_core::{ChangeSummary, ChangeTargetResolution, ChangeWorkStatus};
+use serde::Deserialize;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Badges change as work progresses, so caches may only keep them briefly.
+const CACHE_CONTROL: &str = "public, max-age=60, must-revalidate";
+
+const LABEL_COLOR: &str = "#555";
+const GREY: &str = "#9f9f9f";
+const BLUE: &str = "#007ec6";
+const YELLOW: &str = "#dfb317";
+const ORANGE: &str = "#fe7d37";
+const GREEN: &str = "#4c1";
+
+/// Horizontal padding on each side of a badge segment's text.
+const PADDING: u32 = 6;
+
+struct BadgeState {
+    root: PathBuf,
+}
+
+/// Query parameters accepted by both badge routes.
+#[derive(Debug, Default, Deserialize)]
+pub struct BadgeQuery {
+    /// Replace the left-hand label text.
+    label: Option<String>,
+    /// `1`/`true` to answer 404 for unknown changes instead of a grey badge.
+    strict: Option<String>,
+}
+
+impl BadgeQuery {
+    fn strict(&self) -> bool {
+        matches!(self.strict.as_deref(), Some("1" | "true"))
+    }
+}
+
+/// A rendered badge: left label, right message, and message color.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Badge {
+    pub(crate) label: String,
+    pub(crate) message: String,
+    pub(crate) color: &'static str,
+}
+
+/// Create the badge router (mounted at `/badge`).
+pub fn router(root: PathBuf) -> Router {
+    Router::new()
+        .route("/change/{file}", get(change_badge))
+        .route("/project.svg", get(project_badge))
+        .with_state(Arc::new(BadgeState { root }))
+}
+
+async fn change_badge(
+    State(state): State<Arc<BadgeState>>,
+    Path(file): Path<String>,
+    Query(q): Query<BadgeQuery>,
+) -> Response {
+    let Some(id) = file.strip_suffix(".svg").map(str::to_string) else {
+        return (StatusCode::NOT_FOUND, "badges are served as .svg").into_response();
+    };
+    let root = state.root.clone();
+    let lookup = id.clone();
+    let summary = tokio::task::spawn_blocking(move || {
+        let ito_path = ito_path(&root);
+        let repo = FsChangeRepository::new(&ito_path);
+        match repo.resolve_target(&lookup) {
+            ChangeTargetResolution::Unique(id) => repo.get_summary(&id).ok(),
+            ChangeTargetResolution::Ambiguous(_) | ChangeTargetResolution::NotFound => None,
+        }
+    })
+    .await;
+    let summary = match summary {
+        Ok(summary) => summary,
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    };
+
+    let mut badge = match &summary {
+        Some(summary) => change_status_badge(summary),
+        None => Badge {
+            label: id,
+            message: "not found".to_string(),
+            color: GREY,
+        },
+    };
+    if let Some(label) = q.label.clone() {
+        badge.label = label;
+    }
+    let status = if summary.is_none() && q.strict() {
+        StatusCode::NOT_FOUND
+    } else {
+        StatusCode::OK
+    };
+    svg_response(status, &badge)
+}
+
+async fn project_badge(
+    State(state): State<Arc<BadgeState>>,
+    Query(q): Query<BadgeQuery>,
+) -> Response {
+    let root = state.root.clone();
+    let changes = tokio::task::spawn_blocking(move || {
+        let ito_path = ito_path(&root);
+        FsChangeRepository::new(&ito_path).list()
+    })
+    .await;
+    let changes = match changes {
+        Ok(Ok(changes)) => changes,
+        Ok(Err(e)) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    };
+
+    let mut badge = project_status_badge(&changes);
+    if let Some(label) = q.label {
+        badge.label = label;
+    }
+    svg_response(StatusCode::OK, &badge)
+}
+
+fn ito_path(root: &std::path::Path) -> PathBuf {
+    let ctx = ConfigContext {
+        project_dir: Some(root.to_path_buf()),
+        ..ConfigContext::from_process_env()
+    };
+    ito_config::ito_dir::get_ito_path(root, &ctx)
+}
+
+fn svg_response(status: StatusCode, badge: &Badge) -> Response {
+    (
+        status,
+        [
+            (header::CONTENT_TYPE, "image/svg+xml; charset=utf-8"),
+            (header::CACHE_CONTROL, CACHE_CONTROL),
+        ],
+        render_svg(badge),
+    )
+        .into_response()
+}
+
+/// Badge for one change: `7/12 tasks`, or its work status when it has no tasks.
+pub(crate) fn change_status_badge(summary: &ChangeSummary) -> Badge {
+    let status = summary.work_status();
+    let message = if summary.total_tasks > 0 {
+        format!("{}/{} tasks", summary.completed_tasks, summary.total_tasks)
+    } else {
+        status.to_string()
+    };
+    Badge {
+        label: summary.id.clone(),
+        message,
+        color: work_status_color(status),
+    }
+}
+
+/// Badge for the whole project: counts of ready, in-progress, and complete changes.
+pub(crate) fn project_status_badge(changes: &[ChangeSummary]) -> Badge {
+    let count =
+        |status: ChangeWorkStatus| changes.iter().filter(|c| c.work_status() == status).count();
+    let ready = count(ChangeWorkStatus::Ready);
+    let in_progress = count(ChangeWorkStatus::InProgress);
+    let complete = count(ChangeWorkStatus::Complete);
+    let color = if in_progress > 0 {
+        YELLOW
+    } else if ready > 0 {
+        BLUE
+    } else if complete > 0 {
+        GREEN
+    } else {
+        GREY
+    };
+    Badge {
+        label: "ito".to_string(),
+        message: format!("{ready} ready | {in_progress} in progress | {complete} complete"),
+        color,
+    }
+}
+
+fn work_status_color(status: ChangeWorkStatus) -> &'static str {
+    match status {
+        ChangeWorkStatus::Draft => GREY,
+        ChangeWorkStatus::Ready => BLUE,
+        ChangeWorkStatus::InProgress => YELLOW,
+        ChangeWorkStatus::Paused => ORANGE,
+        ChangeWorkStatus::Complete => GREEN,
+    }
+}
+
+/// Render `badge` as a flat, self-contained SVG.
+pub(crate) fn render_svg(badge: &Badge) -> String {
+    let label_width = segment_width(&badge.label);
+    let message_width = segment_width(&badge.message);
+    let width = label_width + message_width;
+    let label = xml_escape(&badge.label);
+    let message = xml_escape(&badge.message);
+    // Text is centered in each segment; x positions are in tenths to keep
+    // odd widths exact.
+    let label_x = label_width * 5;
+    let message_x = label_width * 10 + message_width * 5;
+
+    format!(
+        concat!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{label}: {message}">"##,
+            r##"<title>{label}: {message}</title>"##,
+            r##"<linearGradient id="s" x2="0" y2="100%"><stop offset="0" stop-color="#bbb" stop-opacity=".1"/><stop offset="1" stop-opacity=".1"/></linearGradient>"##,
+            r##"<clipPath id="r"><rect width="{width}" height="20" rx="3" fill="#fff"/></clipPath>"##,
+            r##"<g clip-path="url(#r)"><rect width="{label_width}" height="20" fill="{label_color}"/><rect x="{label_width}" width="{message_width}" height="20" fill="{color}"/><rect width="{width}" height="20" fill="url(#s)"/></g>"##,
+            r##"<g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="110" transform="scale(.1)">"##,
+            r##"<text x="{label_x}" y="140">{label}</text><text x="{message_x}" y="140">{message}</text>"##,
+            r##"</g></svg>"##,
+        ),
+        width = width,
+        label = label,
+        message = message,
+        label_width = label_width,
+        message_width = message_width,
+        label_color = LABEL_COLOR,
+        color = badge.color,
+        label_x = label_x,
+        message_x = message_x,
+    )
+}
+
+/// Width of a badge segment holding `text`, including padding.
+pub(crate) fn segment_width(text: &str) -> u32 {
+    text_width(text) + 2 * PADDING
+}
+
+/// Approximate rendered width of `text` in 11px Verdana.
+///
+/// Exact metrics would need the font; per-class estimates keep badges from
+/// clipping or leaving large gaps.
+pub(crate) fn text_width(text: &str) -> u32 {
+    text.chars().map(char_width).sum()
+}
+
+fn char_width(c: char) -> u32 {
+    match c {
+        'i' | 'j' | 'l' | '.' | ',' | ':' | ';' | '\'' | '|' | '!' => 4,
+        ' ' | 'f' | 't' | 'r' | 'I' | '(' | ')' | '[' | ']' | '-' | '/' => 5,
+        'm' | 'w' | 'M' | 'W' | '%' => 11,
+        c if c.is_ascii_uppercase() => 8,
+        c if c.is_ascii() => 7,
+        // Wide scripts and symbols.
+        _ => 11,
+    }
+}
+
+/// Escape text for use in SVG element content and attribute values.
+pub(crate) fn xml_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+#[path = "badge_tests.rs"]
+mod badge_tests;
@@ -0,0 +1,146 @@
+use axum::Router;
+use axum::body::{Body, to_bytes};
+use axum::http::{Request, StatusCode, header};
+use tower::ServiceExt;
+
+use super::*;
+
+fn badge(label: &str, message: &str) -> Badge {
+    Badge {
+        label: label.to_string(),
+        message: message.to_string(),
+        color: BLUE,
+    }
+}
+
+async fn send(app: &Router, uri: &str) -> (StatusCode, Option<String>, String) {
+    let request = Request::builder()
+        .uri(uri)
+        .body(Body::empty())
+        .expect("GET request");
+    let response = app.clone().oneshot(request).await.expect("router response");
+    let status = response.status();
+    let cache = response
+        .headers()
+        .get(header::CACHE_CONTROL)
+        .map(|v| v.to_str().expect("ASCII header").to_string());
+    let body = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("response body");
+    (
+        status,
+        cache,
+        String::from_utf8(body.to_vec()).expect("UTF-8 body"),
+    )
+}
+
+fn write(path: &std::path::Path, contents: &str) {
+    std::fs::create_dir_all(path.parent().expect("parent")).expect("create dirs");
+    std::fs::write(path, contents).expect("write file");
+}
+
+/// A project with one change that has 1 of 3 tasks done.
+fn project() -> tempfile::TempDir {
+    let project = tempfile::tempdir().expect("project root");
+    let change = project.path().join(".ito/changes/001-01_add-badges");
+    write(&change.join("proposal.md"), "## Why\nBadges.\n");
+    write(
+        &change.join("tasks.md"),
+        "## 1. Implementation\n- [x] 1.1 Render SVG\n- [ ] 1.2 Add routes\n- [ ] 1.3 Document\n",
+    );
+    write(
+        &change.join("specs/badges/spec.md"),
+        "## ADDED Requirements\n",
+    );
+    project
+}
+
+#[test]
+fn xml_escape_handles_markup_characters() {
+    assert_eq!(xml_escape(r#"a&b<c>"d'"#), "a&amp;b&lt;c&gt;&quot;d&apos;");
+    assert_eq!(xml_escape("014-03_plain"), "014-03_plain");
+}
+
+#[test]
+fn render_svg_escapes_label_and_message() {
+    let svg = render_svg(&badge("<script>&", "\"quoted\""));
+
+    assert!(svg.contains("<text x=\""), "{svg}");
+    assert!(svg.contains(">&lt;script&gt;&amp;</text>"), "{svg}");
+    assert!(svg.contains("aria-label=\"&lt;script&gt;&amp;: &quot;quoted&quot;\""));
+    assert!(!svg.contains("<script>"));
+}
+
+#[test]
+fn segment_widths_grow_with_text_and_sum_to_badge_width() {
+    assert_eq!(text_width(""), 0);
+    assert_eq!(segment_width(""), 2 * PADDING);
+    assert!(text_width("WWW") > text_width("iii"));
+    assert_eq!(text_width("7/12"), 7 + 5 + 7 + 7);
+
+    let b = badge("014-03", "7/12 tasks");
+    let width = segment_width("014-03") + segment_width("7/12 tasks");
+    let svg = render_svg(&b);
+    assert!(
+        svg.starts_with(&format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"20\""
+        )),
+        "{svg}"
+    );
+    assert!(!svg.contains("http://fonts"), "badge must not load fonts");
+}
+
+#[tokio::test]
+async fn change_badge_shows_task_progress() {
+    let project = project();
+    let app = router(project.path().to_path_buf());
+
+    let (status, cache, body) = send(&app, "/change/001-01_add-badges.svg").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(cache.as_deref(), Some(CACHE_CONTROL));
+    assert!(body.contains(">001-01_add-badges</text>"), "{body}");
+    assert!(body.contains(">1/3 tasks</text>"), "{body}");
+
+    let (_, _, body) = send(
+        &app,
+        "/change/001-01_add-badges.svg?label=login%20%26%20auth",
+    )
+    .await;
+    assert!(body.contains(">login &amp; auth</text>"), "{body}");
+}
+
+#[tokio::test]
+async fn unknown_change_renders_grey_badge_unless_strict() {
+    let project = project();
+    let app = router(project.path().to_path_buf());
+
+    let (status, _, body) = send(&app, "/change/999-01_missing.svg").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains(">not found</text>"), "{body}");
+    assert!(body.contains(GREY), "{body}");
+
+    let (status, cache, body) = send(&app, "/change/999-01_missing.svg?strict=1").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(cache.as_deref(), Some(CACHE_CONTROL));
+    assert!(body.contains(">not found</text>"), "{body}");
+
+    let (status, _, _) = send(&app, "/change/001-01_add-badges.png").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn project_badge_counts_changes_by_status() {
+    let project = project();
+    let app = router(project.path().to_path_buf());
+
+    let (status, cache, body) = send(&app, "/project.svg").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(cache.as_deref(), Some(CACHE_CONTROL));
+    assert!(body.contains(">ito</text>"), "{body}");
+    assert!(
+        body.contains(">1 ready | 0 in progress | 0 complete</text>"),
+        "{body}"
+    );
+}
@@ -0,0 +1,214 @@
+//! Fingerprint-keyed caching for expensive JSON endpoints.
+//!
+//! A [`FingerprintCache`] keeps the last computed value together with the
+//! fingerprint of the inputs it was computed from. Handlers compute a cheap
+//! fingerprint (see [`ito_dir_fingerprint`]) on every request and only rerun
+//! the expensive computation when it changed or the client asked for a
+//! refresh. [`cached_json_response`] then answers with an `ETag` and honors
+//! `If-None-Match` with `304 Not Modified`.
+
+use axum::{
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Json, Response},
+};
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
+
+/// Clients must revalidate with the `ETag` before reusing a cached body.
+const CACHE_CONTROL: &str = "no-cache";
+
+/// Runtime state directory name, excluded from [`ito_dir_fingerprint`].
+const STATE_DIR_NAME: &str = ".state";
+
+/// Fingerprint reported when the Ito directory does not exist.
+const MISSING_FINGERPRINT: &str = "missing";
+
+/// Query parameters accepted by cached endpoints.
+#[derive(Debug, Default, Deserialize)]
+pub struct RefreshQuery {
+    /// `1`/`true` to recompute even when the fingerprint is unchanged.
+    refresh: Option<String>,
+}
+
+impl RefreshQuery {
+    pub(crate) fn refresh(&self) -> bool {
+        matches!(self.refresh.as_deref(), Some("1" | "true"))
+    }
+}
+
+/// A computed value and when, and from which inputs, it was computed.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct Cached<T> {
+    /// Fingerprint of the inputs the value was computed from.
+    pub(crate) fingerprint: String,
+    /// RFC 3339 time the value was computed.
+    pub(crate) computed_at: String,
+    /// Increments on every computation so refreshed values get a new `ETag`.
+    #[serde(skip)]
+    pub(crate) generation: u64,
+    #[serde(flatten)]
+    pub(crate) value: T,
+}
+
+impl<T> Cached<T> {
+    /// Strong entity tag identifying this computation.
+    pub(crate) fn etag(&self) -> String {
+        format!("\"{}-{}\"", self.fingerprint, self.generation)
+    }
+}
+
+/// Single-entry cache keyed on an input fingerprint.
+///
+/// Computations run while the cache lock is held, so concurrent requests for
+/// a stale entry wait for one computation instead of each starting their own.
+/// Call it from blocking context (e.g. inside `spawn_blocking`).
+pub(crate) struct FingerprintCache<T> {
+    state: Mutex<CacheState<T>>,
+}
+
+struct CacheState<T> {
+    entry: Option<Cached<T>>,
+    generation: u64,
+}
+
+impl<T: Clone> FingerprintCache<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: Mutex::new(CacheState {
+                entry: None,
+                generation: 0,
+            }),
+        }
+    }
+
+    /// Return the cached value for `fingerprint`, computing it when the cache
+    /// is empty, holds another fingerprint, or `refresh` is set.
+    ///
+    /// Errors are returned to the caller and not cached.
+    pub(crate) fn get_or_compute<E>(
+        &self,
+        fingerprint: &str,
+        refresh: bool,
+        compute: impl FnOnce() -> Result<T, E>,
+    ) -> Result<Cached<T>, E> {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if !refresh
+            && let Some(entry) = &state.entry
+            && entry.fingerprint == fingerprint
+        {
+            return Ok(entry.clone());
+        }
+
+        let value = compute()?;
+        state.generation += 1;
+        let entry = Cached {
+            fingerprint: fingerprint.to_string(),
+            computed_at: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            generation: state.generation,
+            value,
+        };
+        state.entry = Some(entry.clone());
+        Ok(entry)
+    }
+}
+
+/// Answer with `cached` as JSON, or `304 Not Modified` when the request's
+/// `If-None-Match` already names its `ETag`.
+pub(crate) fn cached_json_response<T: Serialize>(
+    headers: &HeaderMap,
+    cached: &Cached<T>,
+) -> Response {
+    let etag = cached.etag();
+    if if_none_match(headers, &etag) {
+        return (
+            StatusCode::NOT_MODIFIED,
+            [
+                (header::ETAG, etag.as_str()),
+                (header::CACHE_CONTROL, CACHE_CONTROL),
+            ],
+        )
+            .into_response();
+    }
+    (
+        StatusCode::OK,
+        [
+            (header::ETAG, etag.as_str()),
+            (header::CACHE_CONTROL, CACHE_CONTROL),
+        ],
+        Json(cached),
+    )
+        .into_response()
+}
+
+/// Whether any `If-None-Match` header lists `etag` (or `*`).
+///
+/// Comparison is weak, as RFC 9110 requires for `If-None-Match`.
+fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+}
+
+/// Cheap fingerprint of everything under `ito_path`.
+///
+/// Combines the number of entries with the newest modification time, so
+/// editing, adding, or removing a file changes it without reading any file
+/// contents. `.state/` directories hold runtime bookkeeping (including the
+/// probe `ito doctor` writes) and are skipped. Symlinked directories (as used
+/// by coordination worktrees) are followed once each.
+pub(crate) fn ito_dir_fingerprint(ito_path: &Path) -> String {
+    if !ito_path.is_dir() {
+        return MISSING_FINGERPRINT.to_string();
+    }
+    let mut entries: u64 = 0;
+    let mut newest: u128 = 0;
+    let mut visited = HashSet::new();
+    let mut pending = vec![ito_path.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        if let Ok(canonical) = dir.canonicalize()
+            && !visited.insert(canonical)
+        {
+            continue;
+        }
+        let Ok(read_dir) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in read_dir.flatten() {
+            if entry.file_name() == STATE_DIR_NAME {
+                continue;
+            }
+            entries += 1;
+            let path = entry.path();
+            let Ok(metadata) = std::fs::metadata(&path) else {
+                continue;
+            };
+            if let Some(nanos) = metadata
+                .modified()
+                .ok()
+                .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+                .map(|since| since.as_nanos())
+            {
+                newest = newest.max(nanos);
+            }
+            if metadata.is_dir() {
+                pending.push(path);
+            }
+        }
+    }
+    format!("{entries:x}-{newest:x}")
+}
+
+#[cfg(test)]
+#[path = "cache_tests.rs"]
+mod cache_tests;
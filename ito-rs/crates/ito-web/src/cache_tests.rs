@@ -0,0 +1,128 @@
+use std::cell::Cell;
+use std::time::{Duration, SystemTime};
+
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
+
+use super::*;
+
+fn counting_cache() -> (FingerprintCache<u32>, Cell<u32>) {
+    (FingerprintCache::new(), Cell::new(0))
+}
+
+#[test]
+fn cache_reuses_value_until_fingerprint_changes() {
+    let (cache, runs) = counting_cache();
+    let compute = || {
+        runs.set(runs.get() + 1);
+        Ok::<_, ()>(runs.get())
+    };
+
+    let first = cache.get_or_compute("a", false, compute).unwrap();
+    let second = cache.get_or_compute("a", false, compute).unwrap();
+    assert_eq!(runs.get(), 1);
+    assert_eq!(second.value, first.value);
+    assert_eq!(second.etag(), first.etag());
+
+    let changed = cache.get_or_compute("b", false, compute).unwrap();
+    assert_eq!(runs.get(), 2);
+    assert_eq!(changed.fingerprint, "b");
+    assert_ne!(changed.etag(), first.etag());
+}
+
+#[test]
+fn refresh_recomputes_and_changes_the_etag() {
+    let (cache, runs) = counting_cache();
+    let compute = || {
+        runs.set(runs.get() + 1);
+        Ok::<_, ()>(runs.get())
+    };
+
+    let first = cache.get_or_compute("a", false, compute).unwrap();
+    let refreshed = cache.get_or_compute("a", true, compute).unwrap();
+    assert_eq!(runs.get(), 2);
+    assert_eq!(refreshed.value, 2);
+    assert_ne!(refreshed.etag(), first.etag());
+}
+
+#[test]
+fn errors_are_not_cached() {
+    let cache: FingerprintCache<u32> = FingerprintCache::new();
+    assert!(cache.get_or_compute("a", false, || Err("boom")).is_err());
+    let ok = cache
+        .get_or_compute("a", false, || Ok::<_, &str>(7))
+        .unwrap();
+    assert_eq!(ok.value, 7);
+}
+
+#[test]
+fn if_none_match_accepts_lists_weak_tags_and_wildcards() {
+    let etag = "\"abc-1\"";
+    let mut headers = HeaderMap::new();
+    assert!(!if_none_match(&headers, etag));
+
+    headers.insert(
+        header::IF_NONE_MATCH,
+        HeaderValue::from_static("\"other\", W/\"abc-1\""),
+    );
+    assert!(if_none_match(&headers, etag));
+
+    headers.insert(header::IF_NONE_MATCH, HeaderValue::from_static("*"));
+    assert!(if_none_match(&headers, etag));
+
+    headers.insert(header::IF_NONE_MATCH, HeaderValue::from_static("\"abc-2\""));
+    assert!(!if_none_match(&headers, etag));
+}
+
+#[test]
+fn cached_json_response_answers_not_modified_for_matching_etag() {
+    let cache: FingerprintCache<serde_json::Value> = FingerprintCache::new();
+    let cached = cache
+        .get_or_compute("a", false, || {
+            Ok::<_, ()>(serde_json::json!({ "ok": true }))
+        })
+        .unwrap();
+
+    let fresh = cached_json_response(&HeaderMap::new(), &cached);
+    assert_eq!(fresh.status(), StatusCode::OK);
+    assert_eq!(
+        fresh.headers()[header::ETAG].to_str().unwrap(),
+        cached.etag()
+    );
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::IF_NONE_MATCH,
+        HeaderValue::from_str(&cached.etag()).unwrap(),
+    );
+    let unchanged = cached_json_response(&headers, &cached);
+    assert_eq!(unchanged.status(), StatusCode::NOT_MODIFIED);
+}
+
+#[test]
+fn fingerprint_tracks_edits_additions_and_ignores_state() {
+    let td = tempfile::tempdir().unwrap();
+    let ito = td.path().join(".ito");
+    assert_eq!(ito_dir_fingerprint(&ito), MISSING_FINGERPRINT);
+
+    std::fs::create_dir_all(ito.join("specs/auth")).unwrap();
+    let spec = ito.join("specs/auth/spec.md");
+    std::fs::write(&spec, "# Auth\n").unwrap();
+    let initial = ito_dir_fingerprint(&ito);
+    assert_eq!(ito_dir_fingerprint(&ito), initial);
+
+    std::fs::create_dir_all(ito.join(".state")).unwrap();
+    std::fs::write(ito.join(".state/probe"), "ok").unwrap();
+    assert_eq!(ito_dir_fingerprint(&ito), initial);
+
+    std::fs::File::options()
+        .write(true)
+        .open(&spec)
+        .unwrap()
+        .set_modified(SystemTime::now() + Duration::from_secs(60))
+        .unwrap();
+    let touched = ito_dir_fingerprint(&ito);
+    assert_ne!(touched, initial);
+
+    std::fs::write(ito.join("specs/auth/notes.md"), "notes").unwrap();
+    assert_ne!(ito_dir_fingerprint(&ito), touched);
+}
@@ -0,0 +1,203 @@
+//! Project health endpoints.
+//!
+//! `GET /api/validation` validates every change, spec, and module, and
+//! `GET /api/health` runs the `ito doctor` checks. Both are too slow to rerun
+//! on every page load in large repositories, so results are cached until the
+//! Ito directory's fingerprint changes (see [`crate::cache`]); `?refresh=1`
+//! forces recomputation.
+
+use axum::{
+    Router,
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use ito_common::fs::StdFs;
+use ito_config::ConfigContext;
+use ito_core::change_repository::FsChangeRepository;
+use ito_core::doctor::{DoctorContext, DoctorReport, run_doctor};
+use ito_core::module_repository::FsModuleRepository;
+use ito_core::process::SystemProcessRunner;
+use ito_core::repo_index::RepoIndex;
+use ito_core::templates;
+use ito_core::validate::{self as core_validate, ValidationIssue, ValidationReport};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::cache::{FingerprintCache, RefreshQuery, cached_json_response, ito_dir_fingerprint};
+
+/// Changes on the spec-driven schema get delta validation.
+const SPEC_DRIVEN_SCHEMA: &str = "spec-driven";
+
+struct HealthState {
+    root: PathBuf,
+    validation: FingerprintCache<ValidationSummary>,
+    doctor: FingerprintCache<DoctorReport>,
+}
+
+/// Validation results for every change, spec, and module.
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationSummary {
+    items: Vec<ValidationItem>,
+    passed: u32,
+    failed: u32,
+}
+
+/// Validation result for one change, spec, or module.
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationItem {
+    id: String,
+    #[serde(rename = "type")]
+    kind: &'static str,
+    valid: bool,
+    issues: Vec<ValidationIssue>,
+}
+
+/// Create the health router (merged into `/api`).
+pub fn router(root: PathBuf) -> Router {
+    Router::new()
+        .route("/validation", get(get_validation))
+        .route("/health", get(get_health))
+        .with_state(Arc::new(HealthState {
+            root,
+            validation: FingerprintCache::new(),
+            doctor: FingerprintCache::new(),
+        }))
+}
+
+async fn get_validation(
+    State(state): State<Arc<HealthState>>,
+    Query(q): Query<RefreshQuery>,
+    headers: HeaderMap,
+) -> Response {
+    let cached = tokio::task::spawn_blocking(move || {
+        let ito_path = ito_path(&state.root);
+        let fingerprint = ito_dir_fingerprint(&ito_path);
+        state
+            .validation
+            .get_or_compute(&fingerprint, q.refresh(), || validate_project(&ito_path))
+    })
+    .await;
+    match cached {
+        Ok(Ok(cached)) => cached_json_response(&headers, &cached),
+        Ok(Err(e)) => (StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+async fn get_health(
+    State(state): State<Arc<HealthState>>,
+    Query(q): Query<RefreshQuery>,
+    headers: HeaderMap,
+) -> Response {
+    let cached = tokio::task::spawn_blocking(move || {
+        let ito_path = ito_path(&state.root);
+        let fingerprint = ito_dir_fingerprint(&ito_path);
+        state.doctor.get_or_compute(&fingerprint, q.refresh(), || {
+            Ok::<_, String>(run_doctor(&DoctorContext {
+                project_root: &state.root,
+                ito_path: &ito_path,
+                fs: &StdFs,
+                runner: &SystemProcessRunner,
+            }))
+        })
+    })
+    .await;
+    match cached {
+        Ok(Ok(cached)) => cached_json_response(&headers, &cached),
+        Ok(Err(e)) => (StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+fn ito_path(root: &Path) -> PathBuf {
+    let ctx = ConfigContext {
+        project_dir: Some(root.to_path_buf()),
+        ..ConfigContext::from_process_env()
+    };
+    ito_config::ito_dir::get_ito_path(root, &ctx)
+}
+
+/// Validate every change, spec, and module under `ito_path` (non-strict).
+///
+/// A reduced form of `ito validate --all`: it skips audit consistency and
+/// custom rules, which need the CLI runtime.
+pub(crate) fn validate_project(ito_path: &Path) -> Result<ValidationSummary, String> {
+    let index = RepoIndex::load(ito_path).map_err(|e| e.to_string())?;
+    let change_repo = FsChangeRepository::new(ito_path);
+    let module_repo = FsModuleRepository::new(ito_path);
+    let mut items = Vec::new();
+
+    for id in &index.change_dir_names {
+        let mut report = if templates::read_change_schema(ito_path, id) == SPEC_DRIVEN_SCHEMA {
+            or_failure(core_validate::validate_change(
+                &change_repo,
+                ito_path,
+                id,
+                false,
+            ))
+        } else {
+            ValidationReport::new(Vec::new(), false)
+        };
+        if let Ok(task_issues) = core_validate::validate_tasks_file(ito_path, id, false) {
+            let mut issues = report.issues;
+            issues.extend(task_issues);
+            report = ValidationReport::new(issues, false);
+        }
+        items.push(ValidationItem::new(id, "change", report));
+    }
+
+    for id in &index.spec_dir_names {
+        let report = or_failure(core_validate::validate_spec(ito_path, id, false));
+        items.push(ValidationItem::new(id, "spec", report));
+    }
+
+    for id in &index.module_dir_names {
+        let report = or_failure(
+            core_validate::validate_module(&module_repo, ito_path, id, false)
+                .map(|(_name, report)| report),
+        );
+        items.push(ValidationItem::new(id, "module", report));
+    }
+    if !index.module_dir_names.is_empty() {
+        let report = core_validate::validate_module_graph(ito_path);
+        items.push(ValidationItem::new("module-graph", "module-graph", report));
+    }
+
+    let passed = items.iter().filter(|item| item.valid).count() as u32;
+    let failed = items.len() as u32 - passed;
+    Ok(ValidationSummary {
+        items,
+        passed,
+        failed,
+    })
+}
+
+fn or_failure<E: std::fmt::Display>(result: Result<ValidationReport, E>) -> ValidationReport {
+    result.unwrap_or_else(|e| {
+        ValidationReport::new(
+            vec![core_validate::error(
+                "validate",
+                format!("Validation failed: {e}"),
+            )],
+            false,
+        )
+    })
+}
+
+impl ValidationItem {
+    fn new(id: &str, kind: &'static str, report: ValidationReport) -> Self {
+        Self {
+            id: id.to_string(),
+            kind,
+            valid: report.valid,
+            issues: report.issues,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "health_tests.rs"]
+mod health_tests;
@@ -0,0 +1,138 @@
+use std::time::{Duration, SystemTime};
+
+use axum::Router;
+use axum::body::{Body, to_bytes};
+use axum::http::{Request, StatusCode, header};
+use serde_json::Value;
+use tower::ServiceExt;
+
+use super::router;
+
+struct Reply {
+    status: StatusCode,
+    etag: Option<String>,
+    body: Value,
+}
+
+async fn send(app: &Router, uri: &str, if_none_match: Option<&str>) -> Reply {
+    let mut request = Request::builder().uri(uri);
+    if let Some(etag) = if_none_match {
+        request = request.header(header::IF_NONE_MATCH, etag);
+    }
+    let response = app
+        .clone()
+        .oneshot(request.body(Body::empty()).expect("GET request"))
+        .await
+        .expect("router response");
+    let status = response.status();
+    let etag = response
+        .headers()
+        .get(header::ETAG)
+        .map(|value| value.to_str().expect("ASCII ETag").to_string());
+    let bytes = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("response body");
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        serde_json::from_slice(&bytes).expect("JSON body")
+    };
+    Reply { status, etag, body }
+}
+
+fn project() -> tempfile::TempDir {
+    let project = tempfile::tempdir().expect("project root");
+    let module = project.path().join(".ito/modules/001_demo");
+    std::fs::create_dir_all(&module).expect("module directory");
+    std::fs::write(
+        module.join("module.md"),
+        "# Demo\n\n## Purpose\nA demo module used by the health endpoint tests.\n\n## Scope\n- *\n\n## Changes\n<!-- none -->\n",
+    )
+    .expect("module.md");
+    project
+}
+
+fn touch(path: &std::path::Path) {
+    std::fs::File::options()
+        .write(true)
+        .open(path)
+        .expect("open for touch")
+        .set_modified(SystemTime::now() + Duration::from_secs(60))
+        .expect("set mtime");
+}
+
+#[tokio::test]
+async fn validation_is_computed_once_then_served_as_not_modified() {
+    let project = project();
+    let app = router(project.path().to_path_buf());
+
+    let first = send(&app, "/validation", None).await;
+    assert_eq!(first.status, StatusCode::OK);
+    let etag = first.etag.expect("ETag header");
+    let items = first.body["items"].as_array().expect("items array");
+    assert!(
+        items
+            .iter()
+            .any(|item| item["id"] == "001_demo" && item["type"] == "module")
+    );
+    assert!(first.body["computed_at"].is_string());
+    assert!(first.body["fingerprint"].is_string());
+
+    let second = send(&app, "/validation", Some(&etag)).await;
+    assert_eq!(second.status, StatusCode::NOT_MODIFIED);
+    assert_eq!(second.etag.as_deref(), Some(etag.as_str()));
+    assert_eq!(second.body, Value::Null);
+
+    let repeat = send(&app, "/validation", None).await;
+    assert_eq!(repeat.status, StatusCode::OK);
+    assert_eq!(repeat.etag.as_deref(), Some(etag.as_str()));
+    assert_eq!(repeat.body["computed_at"], first.body["computed_at"]);
+}
+
+#[tokio::test]
+async fn touching_a_file_invalidates_the_cached_validation() {
+    let project = project();
+    let app = router(project.path().to_path_buf());
+
+    let first = send(&app, "/validation", None).await;
+    let etag = first.etag.expect("ETag header");
+
+    touch(&project.path().join(".ito/modules/001_demo/module.md"));
+
+    let after = send(&app, "/validation", Some(&etag)).await;
+    assert_eq!(after.status, StatusCode::OK);
+    assert_ne!(after.etag.as_deref(), Some(etag.as_str()));
+    assert_ne!(after.body["fingerprint"], first.body["fingerprint"]);
+}
+
+#[tokio::test]
+async fn refresh_bypasses_the_cache() {
+    let project = project();
+    let app = router(project.path().to_path_buf());
+
+    let first = send(&app, "/validation", None).await;
+    let etag = first.etag.expect("ETag header");
+
+    let refreshed = send(&app, "/validation?refresh=1", Some(&etag)).await;
+    assert_eq!(refreshed.status, StatusCode::OK);
+    assert_ne!(refreshed.etag.as_deref(), Some(etag.as_str()));
+    assert_eq!(refreshed.body["fingerprint"], first.body["fingerprint"]);
+
+    let cached = send(&app, "/validation", refreshed.etag.as_deref()).await;
+    assert_eq!(cached.status, StatusCode::NOT_MODIFIED);
+}
+
+#[tokio::test]
+async fn health_reports_doctor_checks_with_an_etag() {
+    let project = project();
+    let app = router(project.path().to_path_buf());
+
+    let first = send(&app, "/health", None).await;
+    assert_eq!(first.status, StatusCode::OK);
+    let checks = first.body["checks"].as_array().expect("checks array");
+    assert!(checks.iter().any(|check| check["name"] == "state dir"));
+
+    // The doctor's write probe lives under `.state/` and must not invalidate.
+    let second = send(&app, "/health", first.etag.as_deref()).await;
+    assert_eq!(second.status, StatusCode::NOT_MODIFIED);
+}
@@ -11,7 +11,11 @@
 
 mod api;
 mod auth;
+mod badge;
+mod cache;
 mod frontend;
+mod health;
+mod schema;
 mod server;
 mod terminal;
 
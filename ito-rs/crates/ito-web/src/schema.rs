@@ -0,0 +1,24 @@
+//! JSON schema endpoints.
+//!
+//! The config schema is generated per request from `ito-config`, so editors
+//! pointed at a running server always validate against the config this
+//! binary reads.
+
+use axum::{
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+
+#[cfg(test)]
+#[path = "schema_tests.rs"]
+mod schema_tests;
+
+/// Serve the `config.json` schema (`GET /schema/config.json`).
+pub async fn config_schema() -> Response {
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/schema+json")],
+        ito_config::schema::config_schema_pretty_json(),
+    )
+        .into_response()
+}
@@ -0,0 +1,34 @@
+use super::config_schema;
+use axum::{
+    body::to_bytes,
+    http::{StatusCode, header},
+};
+use ito_config::defaults::default_config_json;
+use ito_config::types::ItoConfig;
+use serde_json::Value;
+
+#[tokio::test]
+async fn config_schema_describes_the_default_config() {
+    let response = config_schema().await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/schema+json"
+    );
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let schema: Value = serde_json::from_slice(&body).unwrap();
+
+    assert!(
+        schema["$schema"]
+            .as_str()
+            .unwrap()
+            .starts_with("http://json-schema.org/")
+    );
+    assert_eq!(schema["type"], "object");
+    let properties = schema["properties"].as_object().unwrap();
+    let defaults = default_config_json();
+    for key in defaults.as_object().unwrap().keys() {
+        assert!(properties.contains_key(key), "schema is missing `{key}`");
+    }
+    serde_json::from_value::<ItoConfig>(defaults).expect("default config matches the schema type");
+}
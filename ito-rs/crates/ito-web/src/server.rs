@@ -1,9 +1,10 @@
 //! HTTP server bootstrap and route assembly.
 //!
 //! [`serve`] is the single entry point: it wires up frontend routes, the REST
-//! API, the WebSocket terminal, authentication middleware, and CORS, then binds
-//! to the configured address. All business logic lives in `ito-core`; this
-//! module only handles transport-level concerns.
+//! API, the WebSocket terminal, the config schema, status badges,
+//! authentication middleware, and CORS, then binds to the configured address.
+//! All business logic lives in `ito-core`; this module only handles
+//! transport-level concerns.
 
 use axum::{Router, middleware, routing::get};
 use std::net::SocketAddr;
@@ -13,7 +14,9 @@ use tower_http::cors::CorsLayer;
 
 use crate::api;
 use crate::auth::{self, AuthState};
+use crate::badge;
 use crate::frontend;
+use crate::schema;
 use crate::terminal::{self, TerminalState};
 
 /// Configuration passed to [`serve`] to start the web server.
@@ -70,6 +73,10 @@ pub async fn serve(config: ServeConfig) -> miette::Result<()> {
             auth_state,
             auth::auth_middleware,
         ))
+        // Public: editors fetch the schema without a token.
+        .route("/schema/config.json", get(schema::config_schema))
+        // Public: status badges are embedded in PRs and dashboards.
+        .nest("/badge", badge::router(root.clone()))
         // CORS for development
         .layer(CorsLayer::permissive());
 